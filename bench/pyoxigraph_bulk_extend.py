@@ -0,0 +1,49 @@
+"""
+Compares the insertion of quads in an on-disk pyoxigraph store with Store.bulk_extend and with a loop of Store.add.
+Fails if bulk_extend is not at least 5 times faster.
+Usage: python pyoxigraph_bulk_extend.py [--quads 1000000]
+"""
+import time
+from argparse import ArgumentParser
+from tempfile import TemporaryDirectory
+
+from pyoxigraph import Literal, NamedNode, Quad, Store
+
+parser = ArgumentParser(
+    prog='PyoxigraphBulkExtend',
+    description='Benchmarks Store.bulk_extend against a loop of Store.add')
+parser.add_argument('--quads', type=int, default=1_000_000)
+parser.add_argument('--min-speedup', type=float, default=5.)
+args = parser.parse_args()
+
+predicate = NamedNode('http://example.com/p')
+
+
+def quads():
+    for i in range(args.quads):
+        yield Quad(NamedNode(f'http://example.com/{i}'), predicate, Literal(str(i)))
+
+
+def run(insert) -> float:
+    with TemporaryDirectory() as path:
+        store = Store(path)
+        start = time.perf_counter()
+        insert(store)
+        duration = time.perf_counter() - start
+        assert len(store) == args.quads
+        return duration
+
+
+def add_loop(store: Store):
+    for quad in quads():
+        store.add(quad)
+
+
+add_duration = run(add_loop)
+print(f'add loop: {args.quads / add_duration:.0f} quads/s')
+bulk_duration = run(lambda store: store.bulk_extend(quads()))
+print(f'bulk_extend: {args.quads / bulk_duration:.0f} quads/s')
+speedup = add_duration / bulk_duration
+print(f'speedup: {speedup:.1f}x')
+if speedup < args.min_speedup:
+    raise SystemExit(f'bulk_extend is expected to be at least {args.min_speedup}x faster than an add loop')
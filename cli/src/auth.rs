@@ -0,0 +1,276 @@
+use crate::store_target;
+use anyhow::{Context, bail};
+use oxhttp::model::header::{AUTHORIZATION, CONTENT_TYPE, WWW_AUTHENTICATE};
+use oxhttp::model::{Body, HeaderValue, Request, Response, StatusCode};
+use oxigraph::model::{GraphName, GraphNameRef};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// The kind of operation a request is doing
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Operation {
+    /// SPARQL query, service description retrieval or any other read (web UI, metrics...)
+    Query,
+    /// SPARQL update or saved query write
+    Update,
+    /// Graph store write (`PUT`, `POST` and `DELETE` methods)
+    Load,
+    /// Graph store read (`GET` and `HEAD` methods), namespaces, statistics or saved queries retrieval
+    Dump,
+}
+
+impl Operation {
+    /// Paths that are not classified otherwise are queries and require read credentials
+    ///
+    /// The server has no health check path that would be served without calling the authorizer.
+    fn from_request(request: &Request<Body>) -> Self {
+        match (request.uri().path(), request.method().as_ref()) {
+            ("/query", _) | ("/update", "GET") => Self::Query,
+            ("/update", _) => Self::Update,
+            (path, "GET" | "HEAD") if path.starts_with("/store") => Self::Dump,
+            ("/namespaces" | "/status", _) | ("/ui/saved-queries", "GET" | "HEAD") => Self::Dump,
+            ("/ui/saved-queries", _) => Self::Update,
+            (path, _) if path.starts_with("/store") => Self::Load,
+            _ => Self::Query,
+        }
+    }
+}
+
+/// Error returned by an [`Authorizer`] when the credentials are missing or invalid
+#[derive(Debug)]
+pub struct AuthorizationError {
+    /// The challenges returned to the client in `WWW-Authenticate` headers
+    pub challenges: Vec<String>,
+}
+
+/// Decides if a request is allowed to execute an operation
+pub trait Authorizer: Send + Sync {
+    /// `graph` is the target graph of graph store operations, `None` if the operation might touch the full dataset
+    fn authorize(
+        &self,
+        request: &Request<Body>,
+        operation: Operation,
+        graph: Option<GraphNameRef<'_>>,
+    ) -> Result<(), AuthorizationError>;
+}
+
+impl<T: Authorizer + ?Sized> Authorizer for Box<T> {
+    fn authorize(
+        &self,
+        request: &Request<Body>,
+        operation: Operation,
+        graph: Option<GraphNameRef<'_>>,
+    ) -> Result<(), AuthorizationError> {
+        (**self).authorize(request, operation, graph)
+    }
+}
+
+/// Authorizer allowing queries and dumps to everyone but requiring credentials for updates and loads
+#[derive(Default)]
+pub struct WriteCredentialsAuthorizer {
+    basic: Option<BasicCredentials>,
+    bearer_tokens: Vec<String>,
+}
+
+struct BasicCredentials {
+    user: String,
+    password_sha256: [u8; 32],
+}
+
+impl WriteCredentialsAuthorizer {
+    /// Returns `None` if no credentials are configured
+    pub fn new(
+        update_auth: Option<&str>,
+        bearer_token_file: Option<&Path>,
+    ) -> anyhow::Result<Option<Self>> {
+        let mut authorizer = Self::default();
+        if let Some(update_auth) = update_auth {
+            authorizer = authorizer.with_basic_credentials(update_auth)?;
+        }
+        if let Some(bearer_token_file) = bearer_token_file {
+            let tokens = fs::read_to_string(bearer_token_file).with_context(|| {
+                format!(
+                    "Failed to read bearer tokens from {}",
+                    bearer_token_file.display()
+                )
+            })?;
+            for token in tokens.lines() {
+                authorizer = authorizer.with_bearer_token(token);
+            }
+            if authorizer.bearer_tokens.is_empty() {
+                bail!(
+                    "The bearer token file {} does not contain any token",
+                    bearer_token_file.display()
+                );
+            }
+        }
+        Ok(
+            (authorizer.basic.is_some() || !authorizer.bearer_tokens.is_empty())
+                .then_some(authorizer),
+        )
+    }
+
+    /// Allows basic authentication using `user:password_hash` credentials where the hash is the hex-encoded SHA-256 of the password
+    pub fn with_basic_credentials(mut self, credentials: &str) -> anyhow::Result<Self> {
+        let Some((user, password_hash)) = credentials.split_once(':') else {
+            bail!("The credentials must be of the form user:password_hash");
+        };
+        let mut password_sha256 = [0; 32];
+        if password_hash.len() != 64 || !password_hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+            bail!("The password hash must be a hex-encoded SHA-256 hash");
+        }
+        for (i, byte) in password_sha256.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&password_hash[2 * i..2 * i + 2], 16)
+                .context("The password hash must be a hex-encoded SHA-256 hash")?;
+        }
+        self.basic = Some(BasicCredentials {
+            user: user.into(),
+            password_sha256,
+        });
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn with_bearer_token(mut self, token: &str) -> Self {
+        let token = token.trim();
+        if !token.is_empty() {
+            self.bearer_tokens.push(token.into());
+        }
+        self
+    }
+
+    fn has_valid_credentials(&self, authorization: &str) -> bool {
+        let Some((scheme, credentials)) = authorization.trim().split_once(' ') else {
+            return false;
+        };
+        let credentials = credentials.trim();
+        if scheme.eq_ignore_ascii_case("basic") {
+            let Some(basic) = &self.basic else {
+                return false;
+            };
+            let Some(decoded) = decode_base64(credentials) else {
+                return false;
+            };
+            let Ok(decoded) = String::from_utf8(decoded) else {
+                return false;
+            };
+            let Some((user, password)) = decoded.split_once(':') else {
+                return false;
+            };
+            // We always compute the hash to not leak if the user exists
+            let password_sha256 = Sha256::digest(password.as_bytes());
+            constant_time_eq(&password_sha256, &basic.password_sha256)
+                & constant_time_eq(user.as_bytes(), basic.user.as_bytes())
+        } else if scheme.eq_ignore_ascii_case("bearer") {
+            self.bearer_tokens.iter().fold(false, |found, token| {
+                found | constant_time_eq(token.as_bytes(), credentials.as_bytes())
+            })
+        } else {
+            false
+        }
+    }
+}
+
+impl Authorizer for WriteCredentialsAuthorizer {
+    fn authorize(
+        &self,
+        request: &Request<Body>,
+        operation: Operation,
+        _graph: Option<GraphNameRef<'_>>,
+    ) -> Result<(), AuthorizationError> {
+        if matches!(operation, Operation::Query | Operation::Dump) {
+            return Ok(());
+        }
+        if request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .is_some_and(|h| self.has_valid_credentials(h))
+        {
+            return Ok(());
+        }
+        let mut challenges = Vec::new();
+        if self.basic.is_some() {
+            challenges.push("Basic realm=\"Oxigraph\", charset=\"UTF-8\"".into());
+        }
+        if !self.bearer_tokens.is_empty() {
+            challenges.push("Bearer realm=\"Oxigraph\"".into());
+        }
+        Err(AuthorizationError { challenges })
+    }
+}
+
+/// Calls the authorizer before handling the SPARQL and graph store requests
+pub fn auth_middleware(
+    authorizer: impl Authorizer + 'static,
+    on_request: impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static,
+) -> impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static {
+    move |request| {
+        let operation = Operation::from_request(request);
+        let graph = if matches!(operation, Operation::Load | Operation::Dump) {
+            store_target(request).ok().flatten().map(GraphName::from)
+        } else {
+            None
+        };
+        match authorizer.authorize(request, operation, graph.as_ref().map(GraphName::as_ref)) {
+            Ok(()) => on_request(request),
+            Err(AuthorizationError { challenges }) => {
+                let mut response = Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header(CONTENT_TYPE, "text/plain; charset=utf-8");
+                for challenge in challenges {
+                    let Ok(challenge) = HeaderValue::try_from(challenge) else {
+                        continue;
+                    };
+                    response = response.header(WWW_AUTHENTICATE, challenge);
+                }
+                response
+                    .body("Valid credentials are required to execute this operation".into())
+                    .unwrap()
+            }
+        }
+    }
+}
+
+/// The user name of the request HTTP basic authentication credentials, if any
+///
+/// The password is not checked, this function must only be called on authorized requests.
+pub(crate) fn basic_auth_user(request: &Request<Body>) -> Option<String> {
+    let authorization = request.headers().get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, credentials) = authorization.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(decode_base64(credentials.trim())?).ok()?;
+    let (user, _) = decoded.split_once(':')?;
+    Some(user.into())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let input = input.trim_end_matches('=').as_bytes();
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for c in input {
+        buffer = (buffer << 6)
+            | u32::from(match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'+' => 62,
+                b'/' => 63,
+                _ => return None,
+            });
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from((buffer >> bits) & 0xFF).ok()?);
+        }
+    }
+    Some(output)
+}
@@ -0,0 +1,148 @@
+use crate::{RemoteAddress, SparqlEndpoint};
+use axum::Router;
+use axum::body::{Body as AxumBody, Bytes, HttpBody};
+use axum::extract::{ConnectInfo, State};
+use axum::http::header::HOST;
+use axum::http::uri::{Authority, Scheme};
+use axum::http::{Request, Response, StatusCode, Uri};
+use axum::response::IntoResponse;
+use http_body::Frame;
+use oxhttp::model::Body;
+use std::future::poll_fn;
+use std::io::{self, Read};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::runtime::Handle;
+use tokio::sync::{mpsc, oneshot};
+
+/// Size of the chunks the response bodies are sent with
+const RESPONSE_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Serves all the paths with the endpoint
+pub fn axum_router<S: Clone + Send + Sync + 'static>(endpoint: SparqlEndpoint) -> Router<S> {
+    Router::new()
+        .fallback(handle_axum_request)
+        .with_state(Arc::new(endpoint))
+}
+
+async fn handle_axum_request(
+    State(endpoint): State<Arc<SparqlEndpoint>>,
+    request: Request<AxumBody>,
+) -> Response<AxumBody> {
+    let (mut parts, body) = request.into_parts();
+    if let Some(ConnectInfo(address)) = parts.extensions.get::<ConnectInfo<SocketAddr>>() {
+        let address = RemoteAddress(*address);
+        parts.extensions.insert(address);
+    }
+    // The endpoint builds its base IRIs from the request URI, we make it absolute like oxhttp does
+    if parts.uri.authority().is_none() {
+        let Some(host) = parts.headers.get(HOST) else {
+            return (StatusCode::BAD_REQUEST, "No host header in HTTP request").into_response();
+        };
+        let mut uri_parts = parts.uri.into_parts();
+        uri_parts.scheme.get_or_insert(Scheme::HTTP);
+        uri_parts.authority = match Authority::try_from(host.as_bytes()) {
+            Ok(authority) => Some(authority),
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid host header value: {e}"),
+                )
+                    .into_response();
+            }
+        };
+        parts.uri = match Uri::from_parts(uri_parts) {
+            Ok(uri) => uri,
+            Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        };
+    }
+    let body = AxumBodyReader {
+        body,
+        runtime: Handle::current(),
+        chunk: Bytes::new(),
+    };
+    let (head_sender, head_receiver) = oneshot::channel();
+    let (chunk_sender, chunk_receiver) = mpsc::channel(1);
+    // The endpoint is blocking, the bodies are streamed from and to the blocking thread
+    tokio::task::spawn_blocking(move || {
+        let mut request = Request::from_parts(parts, Body::from_read(body));
+        let (parts, mut body) = endpoint.handle(&mut request).into_parts();
+        drop(request);
+        if head_sender.send(parts).is_err() {
+            return; // The request has been cancelled
+        }
+        loop {
+            let mut buffer = vec![0; RESPONSE_CHUNK_SIZE];
+            let chunk = match body.read(&mut buffer) {
+                Ok(0) => return,
+                Ok(len) => {
+                    buffer.truncate(len);
+                    Ok(Bytes::from(buffer))
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => Err(e),
+            };
+            let is_error = chunk.is_err();
+            if chunk_sender.blocking_send(chunk).is_err() || is_error {
+                return;
+            }
+        }
+    });
+    match head_receiver.await {
+        Ok(parts) => Response::from_parts(parts, AxumBody::new(ChannelBody(chunk_receiver))),
+        Err(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "The request handler failed without returning a response",
+        )
+            .into_response(),
+    }
+}
+
+/// Blocking reader over an axum request body
+struct AxumBodyReader {
+    body: AxumBody,
+    runtime: Handle,
+    chunk: Bytes,
+}
+
+impl Read for AxumBodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            let body = &mut self.body;
+            match self
+                .runtime
+                .block_on(poll_fn(|cx| Pin::new(&mut *body).poll_frame(cx)))
+            {
+                Some(Ok(frame)) => {
+                    if let Ok(data) = frame.into_data() {
+                        self.chunk = data;
+                    }
+                }
+                Some(Err(e)) => return Err(io::Error::other(e)),
+                None => return Ok(0),
+            }
+        }
+        let data = self.chunk.split_to(buf.len().min(self.chunk.len()));
+        buf[..data.len()].copy_from_slice(&data);
+        Ok(data.len())
+    }
+}
+
+/// Response body receiving its chunks from the blocking thread
+struct ChannelBody(mpsc::Receiver<io::Result<Bytes>>);
+
+impl HttpBody for ChannelBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, io::Error>>> {
+        self.0
+            .poll_recv(cx)
+            .map(|chunk| chunk.map(|chunk| chunk.map(Frame::data)))
+    }
+}
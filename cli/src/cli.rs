@@ -1,6 +1,10 @@
 use clap::{Parser, Subcommand, ValueHint};
+use std::net::IpAddr;
 use std::path::PathBuf;
 
+const STORAGE_COMPRESSIONS: [&str; 3] = ["none", "lz4", "zstd"];
+const HASH_ALGORITHMS: [&str; 2] = ["sha256", "sha384"];
+
 #[derive(Parser)]
 #[command(about, version, name = "oxigraph")]
 /// Oxigraph command line toolkit and SPARQL HTTP server
@@ -21,14 +25,55 @@ pub enum Command {
         /// Host and port to listen to
         #[arg(short, long, default_value = "localhost:7878", value_hint = ValueHint::Hostname)]
         bind: String,
-        /// Allows cross-origin requests
+        /// Allows cross-origin requests from all origins
+        ///
+        /// This is equivalent to `--cors-allow-origin '*'`
         #[arg(long)]
         cors: bool,
-        /// If the SPARQL queries should look for triples in all the dataset graphs by default (i.e., without `GRAPH` operations)
+        /// Origin allowed to do cross-origin requests like "https://example.com"
         ///
-        /// This is equivalent as setting the union-default-graph option in all SPARQL queries
+        /// Can be repeated to allow multiple origins. "*" allows all origins.
+        #[arg(long, value_hint = ValueHint::Url)]
+        cors_allow_origin: Vec<String>,
+        /// Headers allowed in cross-origin requests, separated by commas
+        ///
+        /// By default, all headers requested by the browser are allowed.
+        #[arg(long, value_delimiter = ',')]
+        cors_allow_headers: Vec<String>,
+        /// Requires HTTP basic authentication for SPARQL updates and graph store writes
+        ///
+        /// The value is of the form `user:password_hash` where `password_hash` is the hex-encoded SHA-256 hash of the password.
+        /// SPARQL queries and graph store reads remain open to everyone.
         #[arg(long)]
-        union_default_graph: bool,
+        update_auth: Option<String>,
+        /// File containing the bearer tokens allowed for SPARQL updates and graph store writes, one per line
+        ///
+        /// SPARQL queries and graph store reads remain open to everyone.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        auth_bearer_token_file: Option<PathBuf>,
+        /// Records the PROV-O provenance of the graph store writes, naming the load activities with this IRI prefix followed by a random identifier
+        ///
+        /// If authentication is enabled, the user and the client address are also recorded.
+        /// The client address is the address of the TCP connection peer unless it is a `--trusted-proxy`.
+        #[arg(long, value_hint = ValueHint::Url)]
+        provenance_base: Option<String>,
+        /// Named graph in which the provenance of the graph store writes is recorded
+        ///
+        /// By default `http://oxigraph.org/provenance`.
+        #[arg(long, value_hint = ValueHint::Url, requires = "provenance_base")]
+        provenance_graph: Option<String>,
+        /// IP address of a reverse proxy whose `Forwarded` and `X-Forwarded-For` headers are trusted to find the client address recorded in the provenance
+        ///
+        /// Can be repeated to allow multiple proxies.
+        /// The client address is the rightmost address of the forwarding chain that is not a trusted proxy.
+        #[arg(long, requires = "provenance_base")]
+        trusted_proxy: Vec<IpAddr>,
+        /// If the SPARQL queries should look for triples in all the dataset graphs by default (i.e., without `GRAPH` operations)
+        ///
+        /// This is equivalent as setting the union-default-graph option in all SPARQL queries.
+        /// The setting is saved in the store and kept on the next restarts. Use `--union-default-graph false` to remove it.
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        union_default_graph: Option<bool>,
         /// Timeout for request processing in seconds
         ///
         /// Currently only used for SPARQL queries
@@ -36,6 +81,39 @@ pub enum Command {
         /// Might be used to set up things like HTTP query timeout
         #[arg(long)]
         timeout_s: Option<u64>,
+        /// Exposes Prometheus metrics about the requests and the store on the `/metrics` endpoint
+        #[arg(long)]
+        metrics: bool,
+        /// Serves a web UI on the `/ui` endpoint with a query editor, a graph and prefix browser and saved queries
+        #[arg(long)]
+        ui: bool,
+        /// File in which the SPARQL queries slower than `--slow-query-threshold` are appended as JSON lines
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        slow_query_log: Option<PathBuf>,
+        /// Minimal duration in milliseconds of the SPARQL queries written to the slow query log
+        ///
+        /// The duration includes the results serialization.
+        #[arg(long, default_value_t = 1000, requires = "slow_query_log")]
+        slow_query_threshold: u64,
+        /// Maximal number of characters of the query text written to the slow query log
+        ///
+        /// Longer queries are truncated.
+        #[arg(long, requires = "slow_query_log")]
+        slow_query_max_length: Option<usize>,
+        /// Issues an `Oxigraph-Continuation-Token` header on the full pages of the SELECT queries ending with ORDER BY and LIMIT
+        ///
+        /// Sending the same query with the token in the `cursor` parameter returns the next page.
+        #[arg(long)]
+        pagination_cursors: bool,
+        /// Compression algorithm of the on-disk storage: "none", "lz4" or "zstd"
+        ///
+        /// By default, the two first levels of the storage are not compressed and the other ones use LZ4.
+        /// The algorithm only applies to the data written afterward,
+        /// the optimize command with the same option rewrites all the data.
+        ///
+        /// Zstandard requires Oxigraph to be linked to a RocksDB build with Zstandard support.
+        #[arg(long, requires = "location", value_parser = STORAGE_COMPRESSIONS)]
+        storage_compression: Option<String>,
     },
     /// Start Oxigraph HTTP server in read-only mode
     ///
@@ -48,9 +126,21 @@ pub enum Command {
         /// Host and port to listen to
         #[arg(short, long, default_value = "localhost:7878")]
         bind: String,
-        /// Allow cross-origin requests
+        /// Allow cross-origin requests from all origins
+        ///
+        /// This is equivalent to `--cors-allow-origin '*'`
         #[arg(long)]
         cors: bool,
+        /// Origin allowed to do cross-origin requests like "https://example.com"
+        ///
+        /// Can be repeated to allow multiple origins. "*" allows all origins.
+        #[arg(long, value_hint = ValueHint::Url)]
+        cors_allow_origin: Vec<String>,
+        /// Headers allowed in cross-origin requests, separated by commas
+        ///
+        /// By default, all headers requested by the browser are allowed.
+        #[arg(long, value_delimiter = ',')]
+        cors_allow_headers: Vec<String>,
         /// If the SPARQL queries should look for triples in all the dataset graphs by default (i.e. without `GRAPH` operations)
         ///
         /// This is equivalent as setting the union-default-graph option in all SPARQL queries
@@ -63,6 +153,30 @@ pub enum Command {
         /// Might be used to set up things like HTTP query timeout
         #[arg(long)]
         timeout_s: Option<u64>,
+        /// Exposes Prometheus metrics about the requests and the store on the `/metrics` endpoint
+        #[arg(long)]
+        metrics: bool,
+        /// Serves a web UI on the `/ui` endpoint with a query editor, a graph and prefix browser and saved queries
+        #[arg(long)]
+        ui: bool,
+        /// File in which the SPARQL queries slower than `--slow-query-threshold` are appended as JSON lines
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        slow_query_log: Option<PathBuf>,
+        /// Minimal duration in milliseconds of the SPARQL queries written to the slow query log
+        ///
+        /// The duration includes the results serialization.
+        #[arg(long, default_value_t = 1000, requires = "slow_query_log")]
+        slow_query_threshold: u64,
+        /// Maximal number of characters of the query text written to the slow query log
+        ///
+        /// Longer queries are truncated.
+        #[arg(long, requires = "slow_query_log")]
+        slow_query_max_length: Option<usize>,
+        /// Issues an `Oxigraph-Continuation-Token` header on the full pages of the SELECT queries ending with ORDER BY and LIMIT
+        ///
+        /// Sending the same query with the token in the `cursor` parameter returns the next page.
+        #[arg(long)]
+        pagination_cursors: bool,
     },
     /// Create a database backup into a target directory
     ///
@@ -96,16 +210,68 @@ pub enum Command {
         ///
         /// If multiple files are provided, they are loaded in parallel.
         ///
+        /// Files compressed with gzip, bzip2, xz or zstd are decompressed on the fly
+        /// if their name ends with the compression extension like "dump.nt.gz".
+        ///
         /// If no file is given, stdin is used as if it were the input file content.
         /// In this case, the content format must be specified using the --format option.
         #[arg(short, long, num_args = 0.., value_hint = ValueHint::FilePath)]
         file: Vec<PathBuf>,
+        /// URL(s) of RDF documents to fetch and load
+        ///
+        /// Redirections are followed and the format is given by the returned Content-Type
+        /// or, if it is not an RDF format, guessed from the URL extension.
+        /// The final URL is used as base IRI.
+        ///
+        /// Each document is loaded atomically.
+        #[arg(
+            long,
+            num_args = 1..,
+            value_hint = ValueHint::Url,
+            conflicts_with_all = ["file", "format", "non_atomic", "base", "lenient", "register_namespaces", "checkpoint", "csv_mapping"]
+        )]
+        url: Vec<String>,
+        /// Directory of RDF files to load, each file into its own named graph
+        ///
+        /// The directory is walked recursively and the format of each file is guessed from its extension.
+        /// Files with an unknown extension are skipped.
+        /// Files compressed with gzip, bzip2, xz or zstd are decompressed on the fly.
+        ///
+        /// The files are loaded in parallel, each one atomically unless --non-atomic is set.
+        /// A failure to load a file is reported but does not stop the loading of the other files unless --fail-fast is set.
+        /// A summary of the number of quads read from each file is printed at the end.
+        #[arg(
+            long,
+            value_hint = ValueHint::DirPath,
+            conflicts_with_all = ["file", "url", "format", "graph", "register_namespaces", "checkpoint", "csv_mapping"]
+        )]
+        directory: Option<PathBuf>,
+        /// How the named graph of each file of --directory is chosen
+        ///
+        /// With "filename", the graph IRI is the path of the file relative to the directory,
+        /// percent-encoded, appended to --graph-base.
+        ///
+        /// Any other value is the path of a JSON file mapping relative file paths to graph IRIs,
+        /// like {"people/alice.ttl": "http://example.com/graph/alice"}.
+        /// The files missing from the mapping are named after their file name.
+        #[arg(long, default_value = "filename", requires = "directory", value_hint = ValueHint::FilePath)]
+        graph_from: String,
+        /// IRI the relative file paths are appended to in order to build the graph names with --directory
+        ///
+        /// By default, the file URL of the directory is used, like "file:///data/dumps/".
+        #[arg(long, requires = "directory", value_hint = ValueHint::Url)]
+        graph_base: Option<String>,
+        /// Stops loading the remaining files of --directory as soon as a file fails to load
+        #[arg(long, requires = "directory")]
+        fail_fast: bool,
         /// The format of the file(s) to load
         ///
         /// It can be an extension like "nt" or a MIME type like "application/n-triples".
         ///
         /// By default, the format is guessed from the loaded file extension.
-        #[arg(long, required_unless_present = "file")]
+        ///
+        /// CSV files are loaded with "csv": each row becomes a blank node with one triple per non-empty cell.
+        #[arg(long, required_unless_present_any = ["file", "url", "directory"])]
         format: Option<String>,
         /// Saves data to the database during loading, instead of only doing it at the end.
         ///
@@ -128,6 +294,37 @@ pub enum Command {
         /// Only available when loading a graph file (N-Triples, Turtle...) and not a dataset file (N-Quads, TriG...).
         #[arg(long, value_hint = ValueHint::Url)]
         graph: Option<String>,
+        /// Registers the prefixes declared in the loaded file(s) as store namespaces
+        ///
+        /// They are then used when dumping the store or returning RDF from the HTTP server.
+        #[arg(long)]
+        register_namespaces: bool,
+        /// File in which the loading progress is regularly saved
+        ///
+        /// If the loading is interrupted, running the same command again resumes it from the last saved progress.
+        /// The file is removed once the loading succeeds.
+        ///
+        /// Only available when loading a single uncompressed N-Triples or N-Quads file. Implies --non-atomic.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        checkpoint: Option<PathBuf>,
+        /// JSON file mapping CSV column names to the IRIs of the properties to use for their cells
+        ///
+        /// It must contain an object like {"name": "http://schema.org/name"}.
+        /// The properties of the other columns are the column names used as fragments of the base IRI,
+        /// by default the URL of the loaded file.
+        ///
+        /// Only available when loading CSV files with --format csv.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        csv_mapping: Option<PathBuf>,
+        /// Compression algorithm of the on-disk storage: "none", "lz4" or "zstd"
+        ///
+        /// By default, the two first levels of the storage are not compressed and the other ones use LZ4.
+        /// The algorithm only applies to the data written afterward,
+        /// the optimize command with the same option rewrites all the data.
+        ///
+        /// Zstandard requires Oxigraph to be linked to a RocksDB build with Zstandard support.
+        #[arg(long, value_parser = STORAGE_COMPRESSIONS)]
+        storage_compression: Option<String>,
     },
     /// Dump the store content into a file
     Dump {
@@ -183,6 +380,7 @@ pub enum Command {
         /// The results format
         ///
         /// It can be an extension like "nt" or a MIME type like "application/n-triples".
+        /// "table" renders the results as a human-readable text table.
         ///
         /// By default, the format is guessed from the results file extension.
         #[arg(long, required_unless_present = "results_file")]
@@ -227,14 +425,51 @@ pub enum Command {
         #[arg(long, value_hint = ValueHint::Url)]
         update_base: Option<String>,
     },
+    /// Start an interactive prompt to execute SPARQL queries and updates against the store
+    ///
+    /// Queries and updates might span multiple lines and are terminated by a ";" at the end of a line.
+    /// Type "\help" to get the list of the available commands.
+    Repl {
+        /// Directory in which Oxigraph data are persisted
+        ///
+        /// If not present, an in-memory storage will be used.
+        #[arg(short, long, value_hint = ValueHint::DirPath)]
+        location: Option<PathBuf>,
+        /// Open the store in read-only mode and disallow updates
+        #[arg(long, requires = "location")]
+        read_only: bool,
+        /// If the SPARQL queries should look for triples in all the dataset graphs by default (ie. without `GRAPH` operations)
+        #[arg(long)]
+        union_default_graph: bool,
+    },
+    /// Print statistics about the store content
+    ///
+    /// By default, a full scan of the store is done to count the quads in each graph
+    /// and the number of distinct subjects, predicates and objects.
+    Stats {
+        /// Directory in which Oxigraph data are persisted
+        #[arg(short, long, value_hint = ValueHint::DirPath)]
+        location: PathBuf,
+        /// Estimate the number of quads from the RocksDB metadata instead of scanning the store
+        ///
+        /// This is much faster on big stores but the counts might be significantly off.
+        #[arg(long)]
+        approximate: bool,
+    },
     /// Optimize the database storage
     ///
     /// Done by default in the background when serving requests.
     /// It is likely to not be useful in most of the cases except if you provide a read-only SPARQL endpoint under heavy load.
+    /// It also computes the statistics used by the SPARQL optimizer to order joins.
     Optimize {
         /// Directory in which Oxigraph data are persisted
         #[arg(short, long, value_hint = ValueHint::DirPath)]
         location: PathBuf,
+        /// Compression algorithm with which all the data is rewritten: "none", "lz4" or "zstd"
+        ///
+        /// Zstandard requires Oxigraph to be linked to a RocksDB build with Zstandard support.
+        #[arg(long, value_parser = STORAGE_COMPRESSIONS)]
+        storage_compression: Option<String>,
     },
     /// Convert an RDF file from one format to another
     Convert {
@@ -270,6 +505,12 @@ pub enum Command {
         /// Base IRI of the file to write
         #[arg(long, value_hint = ValueHint::Url)]
         to_base: Option<String>,
+        /// Prefix to use in the file to write like "ex=http://example.com/"
+        ///
+        /// Can be repeated to set multiple prefixes.
+        /// They are added to the prefixes declared in the file to read.
+        #[arg(long, value_name = "NAME=IRI")]
+        prefix: Vec<String>,
         /// Attempt to keep converting even if the data file is invalid
         ///
         /// This disables most of the validation on RDF content.
@@ -278,15 +519,94 @@ pub enum Command {
         /// Only load the given named graph from the input file
         ///
         /// By default, all graphs are loaded.
-        #[arg(long, conflicts_with = "from_default_graph", value_hint = ValueHint::Url)]
+        #[arg(long, conflicts_with_all = ["from_default_graph", "merge_graphs"], value_hint = ValueHint::Url)]
         from_graph: Option<String>,
         /// Only load the default graph from the input file
-        #[arg(long, conflicts_with = "from_graph")]
+        #[arg(long, conflicts_with_all = ["from_graph", "merge_graphs"])]
         from_default_graph: bool,
+        /// Merge all the graphs of the input file into the default graph
+        ///
+        /// Useful to convert a dataset to a graph format like Turtle.
+        #[arg(long, conflicts_with_all = ["from_graph", "from_default_graph"])]
+        merge_graphs: bool,
         /// Name of the graph to map the default graph to
         ///
         /// By default, the default graph is used.
         #[arg(long, value_hint = ValueHint::Url)]
         to_graph: Option<String>,
     },
+    /// Sign an RDF file with an Ed25519 private key
+    ///
+    /// The signature is computed on the hash of the canonical form of the file content
+    /// following the RDF Dataset Canonicalization (RDFC-1.0) algorithm.
+    /// Hence, it does not depend on the serialization or on the blank node identifiers.
+    ///
+    /// The file content is written back with the signature embedded
+    /// into the <https://oxigraph.org/ns/signature#graph> named graph.
+    /// The content of this graph is ignored when computing the signature.
+    Sign {
+        /// File to sign
+        ///
+        /// If no file is given, stdin is used as if it were the input file content.
+        /// In this case, the content format must be specified using the --from-format option.
+        #[arg(short, long, value_hint = ValueHint::FilePath)]
+        from_file: Option<PathBuf>,
+        /// The format of the file to sign
+        ///
+        /// It can be an extension like "nq" or a MIME type like "application/n-quads".
+        ///
+        /// By default, the format is guessed from the input file extension.
+        #[arg(long, required_unless_present = "from_file")]
+        from_format: Option<String>,
+        /// Base IRI of the file to sign
+        #[arg(long, value_hint = ValueHint::Url)]
+        from_base: Option<String>,
+        /// Ed25519 private key file in the DER PKCS#8 format
+        ///
+        /// It can be generated with "openssl genpkey -algorithm ed25519 -outform DER -out key.der".
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        key: PathBuf,
+        /// Hash function used to canonicalize and to hash the file content
+        #[arg(long, value_parser = HASH_ALGORITHMS, default_value = "sha256")]
+        hash_algorithm: String,
+        /// File to write the signed content to
+        ///
+        /// If no file is given, stdout is used.
+        #[arg(short, long, value_hint = ValueHint::FilePath)]
+        to_file: Option<PathBuf>,
+        /// The format of the file to write
+        ///
+        /// It can be an extension like "nq" or a MIME type like "application/n-quads".
+        /// It must support named graphs.
+        ///
+        /// By default, the format is guessed from the target file extension or the format of the input file is used.
+        #[arg(long)]
+        to_format: Option<String>,
+    },
+    /// Verify the signature embedded into an RDF file by the sign operation
+    ///
+    /// Fails if the file has been modified since it has been signed.
+    Verify {
+        /// File to verify
+        ///
+        /// If no file is given, stdin is used as if it were the input file content.
+        /// In this case, the content format must be specified using the --format option.
+        #[arg(short, long, value_hint = ValueHint::FilePath)]
+        file: Option<PathBuf>,
+        /// The format of the file to verify
+        ///
+        /// It can be an extension like "nq" or a MIME type like "application/n-quads".
+        ///
+        /// By default, the format is guessed from the input file extension.
+        #[arg(long, required_unless_present = "file")]
+        format: Option<String>,
+        /// Base IRI of the file to verify
+        #[arg(long, value_hint = ValueHint::Url)]
+        base: Option<String>,
+        /// Ed25519 public key file, either the raw 32 bytes key or a DER SubjectPublicKeyInfo structure
+        ///
+        /// It can be extracted from the private key with "openssl pkey -in key.der -inform DER -pubout -outform DER -out public.der".
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        public_key: PathBuf,
+    },
 }
@@ -0,0 +1,197 @@
+use oxhttp::model::header::{
+    ETAG, HeaderMap, HeaderName, IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use oxhttp::model::{Body, HeaderValue, Request};
+use oxigraph::store::GraphModification;
+use oxsdatatypes::{Integer, TimezoneOffset};
+use std::fmt;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The validators of a graph, used to evaluate [conditional requests](https://www.rfc-editor.org/rfc/rfc9110#section-13)
+pub struct GraphValidators {
+    etag: String,
+    last_modified: Option<HttpDate>,
+}
+
+impl GraphValidators {
+    pub fn new(modification: &GraphModification) -> Self {
+        Self {
+            etag: format!("\"{}\"", modification.counter),
+            last_modified: HttpDate::from_modification(modification),
+        }
+    }
+
+    /// Sets the `ETag` and `Last-Modified` headers
+    pub fn add_to_headers(&self, headers: &mut HeaderMap) {
+        if let Ok(etag) = HeaderValue::from_str(&self.etag) {
+            headers.insert(ETAG, etag);
+        }
+        if let Some(last_modified) = self
+            .last_modified
+            .and_then(|date| HeaderValue::from_str(&date.to_string()).ok())
+        {
+            headers.insert(LAST_MODIFIED, last_modified);
+        }
+    }
+}
+
+/// If a `GET` or `HEAD` request on an existing graph should get a `304 Not Modified` response
+///
+/// `If-Modified-Since` is ignored if `If-None-Match` is set.
+pub fn is_not_modified(request: &Request<Body>, validators: Option<&GraphValidators>) -> bool {
+    if let Some(if_none_match) = header(request, &IF_NONE_MATCH) {
+        return if_none_match.trim() == "*"
+            || validators.is_some_and(|v| etag_list_contains(if_none_match, &v.etag, false));
+    }
+    let (Some(if_modified_since), Some(last_modified)) = (
+        header(request, &IF_MODIFIED_SINCE).and_then(HttpDate::parse),
+        validators.and_then(|v| v.last_modified),
+    ) else {
+        return false;
+    };
+    last_modified <= if_modified_since
+}
+
+/// If the `If-Match` header of a write request is not satisfied by the current state of the graph
+///
+/// `validators` is `None` if the graph modifications are not known.
+pub fn is_precondition_failed(
+    request: &Request<Body>,
+    validators: Option<&GraphValidators>,
+    exists: bool,
+) -> bool {
+    let Some(if_match) = header(request, &IF_MATCH) else {
+        return false;
+    };
+    if if_match.trim() == "*" {
+        return !exists;
+    }
+    !(exists && validators.is_some_and(|v| etag_list_contains(if_match, &v.etag, true)))
+}
+
+fn header<'a>(request: &'a Request<Body>, name: &HeaderName) -> Option<&'a str> {
+    request.headers().get(name)?.to_str().ok()
+}
+
+/// Looks for `etag` in a comma separated list of entity tags
+///
+/// The strong comparison does not match weak entity tags.
+fn etag_list_contains(list: &str, etag: &str, strong: bool) -> bool {
+    let mut rest = list;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_ascii_whitespace());
+        if rest.is_empty() {
+            return false;
+        }
+        let (is_weak, tag) = match rest.strip_prefix("W/") {
+            Some(tag) => (true, tag),
+            None => (false, rest),
+        };
+        let Some(end) = tag
+            .strip_prefix('"')
+            .and_then(|quoted| quoted.find('"'))
+            .map(|end| end + 2)
+        else {
+            return false; // Invalid entity tag
+        };
+        if &tag[..end] == etag && !(strong && is_weak) {
+            return true;
+        }
+        rest = &tag[end..];
+    }
+}
+
+/// A [HTTP date](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7), always in GMT
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct HttpDate {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl HttpDate {
+    /// Returns `None` if the timestamp is not representable (e.g. years after 9999)
+    fn from_modification(modification: &GraphModification) -> Option<Self> {
+        let timestamp = modification.timestamp.adjust(Some(TimezoneOffset::UTC))?;
+        Some(Self {
+            year: timestamp
+                .year()
+                .try_into()
+                .ok()
+                .filter(|year| (1..=9999).contains(year))?,
+            month: timestamp.month(),
+            day: timestamp.day(),
+            hour: timestamp.hour(),
+            minute: timestamp.minute(),
+            second: i64::from(Integer::try_from(timestamp.second()).ok()?)
+                .try_into()
+                .ok()?,
+        })
+    }
+
+    /// Parses the IMF-fixdate format and the obsolete RFC 850 and asctime formats
+    fn parse(value: &str) -> Option<Self> {
+        let tokens = value
+            .split([' ', ',', '-'])
+            .filter(|token| !token.is_empty())
+            .collect::<Vec<_>>();
+        let ([_, day, month, year, time, "GMT"] | [_, month, day, time, year]) = tokens.as_slice()
+        else {
+            return None;
+        };
+        let mut year = year.parse::<u16>().ok()?;
+        if year < 100 {
+            // RFC 850 two digits years
+            year += if year < 70 { 2000 } else { 1900 };
+        }
+        let mut time = time.split(':').map(|part| part.parse::<u8>().ok());
+        let (Some(Some(hour)), Some(Some(minute)), Some(Some(second)), None) =
+            (time.next(), time.next(), time.next(), time.next())
+        else {
+            return None;
+        };
+        let date = Self {
+            year,
+            month: u8::try_from(MONTHS.iter().position(|m| m == month)? + 1).ok()?,
+            day: day.parse().ok()?,
+            hour,
+            minute,
+            second,
+        };
+        (date.day <= 31 && date.hour < 24 && date.minute < 60 && date.second <= 60).then_some(date)
+    }
+
+    /// Day of the week, 0 being Sunday, using Sakamoto's method
+    fn weekday(self) -> usize {
+        const OFFSETS: [usize; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = usize::from(self.year) - usize::from(self.month < 3);
+        (year + year / 4 - year / 100
+            + year / 400
+            + OFFSETS[usize::from(self.month) - 1]
+            + usize::from(self.day))
+            % 7
+    }
+}
+
+impl fmt::Display for HttpDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            WEEKDAYS[self.weekday()],
+            self.day,
+            MONTHS[usize::from(self.month) - 1],
+            self.year,
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+}
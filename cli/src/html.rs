@@ -0,0 +1,154 @@
+use oxigraph::io::{RdfSerializer, WriterQuadSerializer};
+use oxigraph::model::{LiteralRef, TermRef, TripleRef, Variable};
+use oxigraph::sparql::QuerySolution;
+use std::io::{self, Write};
+
+pub const HTML_MEDIA_TYPE: &str = "text/html; charset=utf-8";
+
+const PAGE_START: &str = "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>SPARQL query results</title>\n<style>\nbody { font-family: sans-serif; }\ntable { border-collapse: collapse; }\nth, td { border: 1px solid #ccc; padding: 0.2em 0.5em; text-align: left; vertical-align: top; }\nspan[title] { text-decoration: underline dotted; }\n</style>\n</head>\n<body>\n";
+const PAGE_END: &str = "</body>\n</html>\n";
+
+/// Writes query solutions as a standalone HTML page with one table row per solution
+///
+/// Rows are written as soon as they are serialized, nothing is buffered.
+pub struct HtmlSolutionsWriter<W: Write> {
+    writer: W,
+    variables: Vec<Variable>,
+}
+
+impl<W: Write> HtmlSolutionsWriter<W> {
+    pub fn start(mut writer: W, variables: Vec<Variable>) -> io::Result<Self> {
+        writer.write_all(PAGE_START.as_bytes())?;
+        writer.write_all(b"<table>\n<thead>\n<tr>")?;
+        for variable in &variables {
+            writer.write_all(b"<th>")?;
+            write_escaped(&mut writer, &variable.to_string())?;
+            writer.write_all(b"</th>")?;
+        }
+        writer.write_all(b"</tr>\n</thead>\n<tbody>\n")?;
+        Ok(Self { writer, variables })
+    }
+
+    pub fn serialize(&mut self, solution: &QuerySolution) -> io::Result<()> {
+        self.writer.write_all(b"<tr>")?;
+        for variable in &self.variables {
+            self.writer.write_all(b"<td>")?;
+            if let Some(term) = solution.get(variable) {
+                write_term(&mut self.writer, term.as_ref())?;
+            }
+            self.writer.write_all(b"</td>")?;
+        }
+        self.writer.write_all(b"</tr>\n")
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(b"</tbody>\n</table>\n")?;
+        self.writer.write_all(PAGE_END.as_bytes())?;
+        Ok(self.writer)
+    }
+}
+
+/// Writes a boolean query result as a standalone HTML page
+pub fn write_html_boolean<W: Write>(mut writer: W, value: bool) -> io::Result<W> {
+    writer.write_all(PAGE_START.as_bytes())?;
+    writeln!(writer, "<p>{value}</p>")?;
+    writer.write_all(PAGE_END.as_bytes())?;
+    Ok(writer)
+}
+
+/// Writes triples as a standalone HTML page containing a preformatted block of the given RDF syntax
+pub struct HtmlTriplesWriter<W: Write> {
+    serializer: WriterQuadSerializer<HtmlEscapeWriter<W>>,
+}
+
+impl<W: Write> HtmlTriplesWriter<W> {
+    pub fn start(mut writer: W, serializer: RdfSerializer) -> io::Result<Self> {
+        writer.write_all(PAGE_START.as_bytes())?;
+        writer.write_all(b"<pre>")?;
+        Ok(Self {
+            serializer: serializer.for_writer(HtmlEscapeWriter { inner: writer }),
+        })
+    }
+
+    pub fn serialize_triple<'a>(&mut self, triple: impl Into<TripleRef<'a>>) -> io::Result<()> {
+        self.serializer.serialize_triple(triple)
+    }
+
+    pub fn finish(self) -> io::Result<W> {
+        let mut writer = self.serializer.finish()?.inner;
+        writer.write_all(b"</pre>\n")?;
+        writer.write_all(PAGE_END.as_bytes())?;
+        Ok(writer)
+    }
+}
+
+/// Named nodes are links if they are HTTP(S) IRIs and literals get their datatype or language tag as tooltip
+fn write_term(writer: &mut impl Write, term: TermRef<'_>) -> io::Result<()> {
+    if let TermRef::NamedNode(node) = term {
+        let iri = node.as_str();
+        if iri.starts_with("http://") || iri.starts_with("https://") {
+            writer.write_all(b"<a href=\"")?;
+            write_escaped(writer, iri)?;
+            writer.write_all(b"\">")?;
+            write_escaped(writer, iri)?;
+            writer.write_all(b"</a>")
+        } else {
+            write_escaped(writer, iri)
+        }
+    } else if let TermRef::Literal(literal) = term {
+        writer.write_all(b"<span title=\"")?;
+        write_escaped(writer, &literal_annotation(literal))?;
+        writer.write_all(b"\">")?;
+        write_escaped(writer, literal.value())?;
+        writer.write_all(b"</span>")
+    } else {
+        write_escaped(writer, &term.to_string())
+    }
+}
+
+fn literal_annotation(literal: LiteralRef<'_>) -> String {
+    let Some(language) = literal.language() else {
+        return literal.datatype().as_str().into();
+    };
+    #[cfg(feature = "rdf-12")]
+    if let Some(direction) = literal.direction() {
+        return format!("@{language}--{direction}");
+    }
+    format!("@{language}")
+}
+
+fn write_escaped(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    HtmlEscapeWriter { inner: writer }.write_all(value.as_bytes())
+}
+
+/// Escapes everything written into it as HTML text
+///
+/// The escaped characters are all ASCII so it is safe to work on UTF-8 bytes.
+struct HtmlEscapeWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> Write for HtmlEscapeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut start = 0;
+        for (i, b) in buf.iter().enumerate() {
+            let escaped: &[u8] = match b {
+                b'&' => b"&amp;",
+                b'<' => b"&lt;",
+                b'>' => b"&gt;",
+                b'"' => b"&quot;",
+                b'\'' => b"&#39;",
+                _ => continue,
+            };
+            self.inner.write_all(&buf[start..i])?;
+            self.inner.write_all(escaped)?;
+            start = i + 1;
+        }
+        self.inner.write_all(&buf[start..])?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,33 @@
+use oxhttp::model::{Body, HeaderValue, Request, Response};
+use rand::random;
+use tracing::field::Empty;
+
+/// The header used to correlate a request with its logs
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Handles each request inside of a span identified by the request id
+///
+/// The id is the value of the `X-Request-Id` request header if set, a random number otherwise.
+/// It is returned in the `X-Request-Id` response header.
+pub fn request_id_middleware(
+    on_request: impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static,
+) -> impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static {
+    move |request| {
+        let id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .cloned()
+            .unwrap_or_else(|| HeaderValue::from(random::<u64>()));
+        let span = tracing::info_span!(
+            "request",
+            id = id.to_str().unwrap_or_default(),
+            method = %request.method(),
+            path = request.uri().path(),
+            status = Empty
+        );
+        let mut response = span.in_scope(|| on_request(request));
+        span.record("status", response.status().as_u16());
+        response.headers_mut().insert(REQUEST_ID_HEADER, id);
+        response
+    }
+}
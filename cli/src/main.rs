@@ -1,58 +1,53 @@
 #![allow(clippy::print_stderr, clippy::cast_precision_loss, clippy::use_debug)]
 use crate::cli::{Args, Command};
-use crate::service_description::{EndpointKind, generate_service_description};
-use anyhow::{Context, bail, ensure};
+use crate::repl::Repl;
+use crate::table::{write_results_table, write_table};
+use anyhow::{Context, anyhow, bail, ensure};
 use clap::Parser;
-use flate2::read::MultiGzDecoder;
+use json_event_parser::{JsonEvent, ReaderJsonParser};
 use oxhttp::Server;
-use oxhttp::model::header::{
-    ACCEPT, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
-    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_HEADERS, ACCESS_CONTROL_REQUEST_METHOD,
-    CONTENT_TYPE, LOCATION, ORIGIN,
+use oxigraph::io::{
+    ContentEncoding, CsvRdfParser, DecodingReader, JsonLdProfileSet, LoadedDocument, RdfFormat,
+    RdfParseError, RdfParser, RdfSerializer,
 };
-use oxhttp::model::uri::{Authority, PathAndQuery, Scheme};
-use oxhttp::model::{Body, HeaderValue, Method, Request, Response, StatusCode, Uri};
-use oxigraph::io::{JsonLdProfileSet, LoadedDocument, RdfFormat, RdfParser, RdfSerializer};
-use oxigraph::model::{
-    GraphName, GraphNameRef, IriParseError, NamedNode, NamedNodeRef, NamedOrBlankNode,
+use oxigraph::model::dataset::CanonicalizationHashAlgorithm;
+use oxigraph::model::signing::{Ed25519Signer, Ed25519Verifier, sign_embedded, verify_embedded};
+use oxigraph::model::{Dataset, GraphName, GraphNameRef, NamedNode, NamedNodeRef};
+use oxigraph::sparql::results::QueryResultsFormat;
+use oxigraph::sparql::{DefaultGraphMode, QueryResults};
+use oxigraph::store::{
+    BulkLoader, LoadProvenance, LoaderError, StorageCompression, Store, StoreOptions, StoreStats,
+};
+use oxigraph_cli::{
+    CorsConfig, Metrics, RemoteAddress, SlowQueryLog, SparqlEndpoint, SparqlEndpointConfig,
+    WriteCredentialsAuthorizer, default_sparql_evaluator, rdf_serializer,
 };
-use oxigraph::sparql::results::{QueryResultsFormat, QueryResultsSerializer};
-use oxigraph::sparql::{CancellationToken, QueryResults, SparqlEvaluator};
-use oxigraph::store::{BulkLoader, LoaderError, Store};
-use oxiri::Iri;
-use rand::random;
 use rayon_core::ThreadPoolBuilder;
-#[cfg(feature = "geosparql")]
-use spargeo::GEOSPARQL_EXTENSION_FUNCTIONS;
-use std::borrow::Cow;
-use std::cell::RefCell;
-use std::cmp::{max, min};
-#[cfg(target_os = "linux")]
+use std::cmp::max;
+use std::collections::HashMap;
 use std::env;
 use std::ffi::OsStr;
 use std::fs::File;
-use std::io::{self, BufWriter, Read, Write, stdin, stdout};
-use std::net::ToSocketAddrs;
+use std::io::{self, BufWriter, Read, Write, stderr, stdin, stdout};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
 #[cfg(target_os = "linux")]
 use std::os::unix::net::UnixDatagram;
-use std::path::Path;
-use std::rc::Rc;
-use std::str::FromStr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread::available_parallelism;
 use std::time::{Duration, Instant};
-use std::{fmt, fs, str, thread};
-use url::{Url, form_urlencoded};
+use std::{fs, thread};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use url::Url;
 
 mod cli;
-mod service_description;
+mod repl;
+mod table;
 
-const MAX_SPARQL_BODY_SIZE: u64 = 1024 * 1024 * 128; // 128MB
 const HTTP_TIMEOUT: Duration = Duration::from_secs(60);
-const HTML_ROOT_PAGE: &str = include_str!("../templates/query.html");
-#[expect(clippy::large_include_file)]
-const YASGUI_JS: &str = include_str!("../templates/yasgui/yasgui.min.js");
-const YASGUI_CSS: &str = include_str!("../templates/yasgui/yasgui.min.css");
-const LOGO: &str = include_str!("../logo.svg");
+const STORE_SIZE_METRIC_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
 
 pub fn main() -> anyhow::Result<()> {
     let matches = Args::parse();
@@ -61,34 +56,97 @@ pub fn main() -> anyhow::Result<()> {
             location,
             bind,
             cors,
+            cors_allow_origin,
+            cors_allow_headers,
+            update_auth,
+            auth_bearer_token_file,
+            provenance_base,
+            provenance_graph,
+            trusted_proxy,
             union_default_graph,
             timeout_s,
-        } => serve(
-            if let Some(location) = location {
-                Store::open(location)
+            metrics,
+            ui,
+            slow_query_log,
+            slow_query_threshold,
+            slow_query_max_length,
+            pagination_cursors,
+            storage_compression,
+        } => {
+            let store = if let Some(location) = location {
+                Store::open_with_options(location, store_options(storage_compression.as_deref())?)
             } else {
                 Store::new()
-            }?,
-            &bind,
-            false,
-            cors,
-            union_default_graph,
-            timeout_s,
-        ),
+            }?;
+            if let Some(union_default_graph) = union_default_graph {
+                store.set_default_query_dataset(if union_default_graph {
+                    DefaultGraphMode::Union
+                } else {
+                    DefaultGraphMode::DefaultGraphOnly
+                })?;
+            }
+            let union_default_graph = store.default_query_dataset() == DefaultGraphMode::Union;
+            serve(
+                store,
+                &bind,
+                false,
+                CorsConfig::new(cors, cors_allow_origin, cors_allow_headers),
+                WriteCredentialsAuthorizer::new(
+                    update_auth.as_deref(),
+                    auth_bearer_token_file.as_deref(),
+                )?,
+                union_default_graph,
+                timeout_s,
+                metrics.then(Metrics::new),
+                ui,
+                open_slow_query_log(
+                    slow_query_log.as_deref(),
+                    slow_query_threshold,
+                    slow_query_max_length,
+                )?,
+                load_provenance(provenance_base, provenance_graph)?,
+                trusted_proxy,
+                pagination_cursors,
+            )
+        }
         Command::ServeReadOnly {
             location,
             bind,
             cors,
+            cors_allow_origin,
+            cors_allow_headers,
             union_default_graph,
             timeout_s,
-        } => serve(
-            Store::open_read_only(location)?,
-            &bind,
-            true,
-            cors,
-            union_default_graph,
-            timeout_s,
-        ),
+            metrics,
+            ui,
+            slow_query_log,
+            slow_query_threshold,
+            slow_query_max_length,
+            pagination_cursors,
+        } => {
+            let store = Store::open_read_only(location)?;
+            let union_default_graph =
+                union_default_graph || store.default_query_dataset() == DefaultGraphMode::Union;
+            serve(
+                store,
+                &bind,
+                true,
+                CorsConfig::new(cors, cors_allow_origin, cors_allow_headers),
+                None,
+                union_default_graph,
+                timeout_s,
+                metrics.then(Metrics::new),
+                ui,
+                open_slow_query_log(
+                    slow_query_log.as_deref(),
+                    slow_query_threshold,
+                    slow_query_max_length,
+                )?,
+                None,
+                Vec::new(),
+                pagination_cursors,
+            )
+        }
         Command::Backup {
             location,
             destination,
@@ -100,14 +158,31 @@ pub fn main() -> anyhow::Result<()> {
         Command::Load {
             location,
             file,
+            url,
+            directory,
+            graph_from,
+            graph_base,
+            fail_fast,
             non_atomic,
             lenient,
             format,
             base,
             graph,
+            register_namespaces,
+            checkpoint,
+            csv_mapping,
+            storage_compression,
         } => {
-            let store = Store::open(&location)?;
-            let format = if let Some(format) = format {
+            let store = Store::open_with_options(
+                &location,
+                store_options(storage_compression.as_deref())?,
+            )?;
+            let is_csv = format.as_deref().is_some_and(is_csv_format_name);
+            ensure!(
+                is_csv || csv_mapping.is_none(),
+                "The --csv-mapping option is only available when loading CSV files with --format csv"
+            );
+            let format = if let Some(format) = format.filter(|_| !is_csv) {
                 Some(rdf_format_from_name(&format)?)
             } else {
                 None
@@ -120,14 +195,98 @@ pub fn main() -> anyhow::Result<()> {
             } else {
                 None
             };
+            if !url.is_empty() {
+                return load_urls(&store, &url, graph.as_ref());
+            }
+            if let Some(directory) = directory {
+                let graph_mapping = if graph_from == "filename" {
+                    HashMap::new()
+                } else {
+                    read_iri_mapping(Path::new(&graph_from), "graph", "file")?
+                        .into_iter()
+                        .collect()
+                };
+                return load_directory(
+                    &store,
+                    &directory,
+                    &graph_mapping,
+                    graph_base,
+                    base.as_deref(),
+                    lenient,
+                    non_atomic,
+                    fail_fast,
+                );
+            }
             if !lenient {
                 eprintln!(
                     "Some files like Wikidata dumps contain invalid IRIs or language tags. If you want to load them anyway use the `--lenient` option."
                 );
             }
             #[expect(clippy::cast_precision_loss)]
-            if file.is_empty() {
-                // We read from stdin
+            if is_csv {
+                ensure!(
+                    checkpoint.is_none(),
+                    "The --checkpoint option is not available when loading CSV files"
+                );
+                let mapping = if let Some(mapping) = &csv_mapping {
+                    read_iri_mapping(mapping, "CSV", "CSV column")?
+                } else {
+                    Vec::new()
+                };
+                let start = Instant::now();
+                let mut loader = store.bulk_loader().on_progress(move |size| {
+                    let elapsed = start.elapsed();
+                    eprintln!(
+                        "{size} triples loaded in {}s ({} t/s)",
+                        elapsed.as_secs(),
+                        ((size as f64) / elapsed.as_secs_f64()).round()
+                    )
+                });
+                if non_atomic {
+                    loader = loader.without_atomicity();
+                }
+                if file.is_empty() {
+                    bulk_load_csv(
+                        &mut loader,
+                        stdin().lock(),
+                        csv_parser(base.as_deref(), &mapping, graph)?,
+                        lenient,
+                    )?;
+                } else {
+                    for file in &file {
+                        // The file URL is the default base IRI like in the W3C CSV to RDF conversion
+                        let base = if let Some(base) = &base {
+                            base.clone()
+                        } else {
+                            Url::from_file_path(fs::canonicalize(file)?)
+                                .ok()
+                                .with_context(|| {
+                                    format!("Not able to build the URL of {}", file.display())
+                                })?
+                                .into()
+                        };
+                        let parser = csv_parser(Some(&base), &mapping, graph.clone())?;
+                        let fp = File::open(file)?;
+                        if let Some(encoding) = file
+                            .extension()
+                            .and_then(OsStr::to_str)
+                            .and_then(ContentEncoding::from_extension)
+                        {
+                            bulk_load_csv(
+                                &mut loader,
+                                DecodingReader::new(fp, encoding),
+                                parser,
+                                lenient,
+                            )
+                        } else {
+                            bulk_load_csv(&mut loader, fp, parser, lenient)
+                        }
+                        .with_context(|| format!("Error while loading file {}", file.display()))?;
+                    }
+                }
+                loader.commit()?;
+            } else if file.is_empty() || checkpoint.is_some() {
+                // We load a single input from the current thread
                 let start = Instant::now();
                 let mut loader = store.bulk_loader().on_progress(move |size| {
                     let elapsed = start.elapsed();
@@ -140,21 +299,50 @@ pub fn main() -> anyhow::Result<()> {
                 if non_atomic {
                     loader = loader.without_atomicity();
                 }
+                if register_namespaces {
+                    loader = loader.register_namespaces();
+                }
                 if lenient {
                     loader = loader.on_parse_error(move |e| {
                         eprintln!("Parsing error: {e}");
                         Ok(())
                     })
                 }
-                bulk_load_read(
-                    &mut loader,
-                    stdin().lock(),
-                    format.context("The --format option must be set when loading from stdin")?,
-                    base.as_deref(),
-                    graph,
-                    lenient,
-                )?;
-                loader.commit()?;
+                if let Some(checkpoint) = checkpoint {
+                    let [file] = file.as_slice() else {
+                        bail!("The --checkpoint option requires exactly one file to load")
+                    };
+                    ensure!(
+                        file.extension()
+                            .and_then(OsStr::to_str)
+                            .and_then(ContentEncoding::from_extension)
+                            .is_none(),
+                        "The --checkpoint option does not support compressed files"
+                    );
+                    let format = if let Some(format) = format {
+                        format
+                    } else {
+                        rdf_format_from_path(file)?
+                    };
+                    loader = loader.with_checkpoint(&checkpoint);
+                    loader.resume_from_checkpoint(
+                        bulk_load_parser(format, base.as_deref(), graph, lenient)?,
+                        File::open(file)?,
+                    )?;
+                    loader.commit()?;
+                    fs::remove_file(&checkpoint)?;
+                } else {
+                    bulk_load_read(
+                        &mut loader,
+                        stdin().lock(),
+                        format
+                            .context("The --format option must be set when loading from stdin")?,
+                        base.as_deref(),
+                        graph,
+                        lenient,
+                    )?;
+                    loader.commit()?;
+                }
             } else {
                 ThreadPoolBuilder::new()
                     .num_threads(max(1, available_parallelism()?.get() / 2))
@@ -178,6 +366,9 @@ pub fn main() -> anyhow::Result<()> {
                                         f.display()
                                     )
                                 });
+                                if register_namespaces {
+                                    loader = loader.register_namespaces();
+                                }
                                 if lenient {
                                     let f = file.clone();
                                     loader = loader.on_parse_error(move |e| {
@@ -186,7 +377,11 @@ pub fn main() -> anyhow::Result<()> {
                                     })
                                 }
                                 if let Err(error) = {
-                                    if file.extension().is_some_and(|e| e == OsStr::new("gz")) {
+                                    if let Some(encoding) = file
+                                        .extension()
+                                        .and_then(OsStr::to_str)
+                                        .and_then(ContentEncoding::from_extension)
+                                    {
                                         let fp = match File::open(&file) {
                                             Ok(fp) => fp,
                                             Err(error) => {
@@ -200,7 +395,7 @@ pub fn main() -> anyhow::Result<()> {
                                         };
                                         bulk_load_read(
                                             &mut loader,
-                                            MultiGzDecoder::new(fp),
+                                            DecodingReader::new(fp, encoding),
                                             format.unwrap_or_else(|| {
                                                 rdf_format_from_path(&file.with_extension(""))
                                                     .unwrap()
@@ -314,104 +509,57 @@ pub fn main() -> anyhow::Result<()> {
             }
             let (results, explanation) = prepared.explain();
             let print_result = (|| {
-                match results? {
-                    QueryResults::Solutions(solutions) => {
-                        let format = if let Some(name) = results_format {
-                            if let Some(format) = QueryResultsFormat::from_extension(&name) {
-                                format
-                            } else if let Some(format) = QueryResultsFormat::from_media_type(&name)
-                            {
-                                format
-                            } else {
-                                bail!("The file format '{name}' is unknown")
-                            }
-                        } else if let Some(results_file) = &results_file {
-                            format_from_path(results_file, |ext| {
-                                QueryResultsFormat::from_extension(ext).with_context(|| {
-                                    format!("The file extension '{ext}' is unknown")
-                                })
-                            })?
-                        } else {
-                            bail!("The --results-format option must be set when writing to stdout")
-                        };
-                        if let Some(results_file) = results_file {
-                            let mut serializer = QueryResultsSerializer::from_format(format)
-                                .serialize_solutions_to_writer(
-                                    BufWriter::new(File::create(results_file)?),
-                                    solutions.variables().to_vec(),
-                                )?;
-                            for solution in solutions {
-                                serializer.serialize(&solution?)?;
-                            }
-                            close_file_writer(serializer.finish()?)?;
-                        } else {
-                            let mut serializer = QueryResultsSerializer::from_format(format)
-                                .serialize_solutions_to_writer(
-                                    stdout().lock(),
-                                    solutions.variables().to_vec(),
-                                )?;
-                            for solution in solutions {
-                                serializer.serialize(&solution?)?;
-                            }
-                            serializer.finish()?.flush()?;
-                        }
+                if results_format.as_deref() == Some("table") {
+                    if let Some(results_file) = results_file {
+                        close_file_writer(write_results_table(
+                            BufWriter::new(File::create(results_file)?),
+                            results?,
+                        )?)?;
+                    } else {
+                        write_results_table(stdout().lock(), results?)?.flush()?;
                     }
-                    QueryResults::Boolean(result) => {
-                        let format = if let Some(name) = results_format {
-                            if let Some(format) = QueryResultsFormat::from_extension(&name) {
-                                format
-                            } else if let Some(format) = QueryResultsFormat::from_media_type(&name)
-                            {
-                                format
-                            } else {
-                                bail!("The file format '{name}' is unknown")
-                            }
-                        } else if let Some(results_file) = &results_file {
-                            format_from_path(results_file, |ext| {
-                                QueryResultsFormat::from_extension(ext).with_context(|| {
-                                    format!("The file extension '{ext}' is unknown")
-                                })
-                            })?
-                        } else {
-                            bail!("The --results-format option must be set when writing to stdout")
-                        };
-                        if let Some(results_file) = results_file {
-                            close_file_writer(
-                                QueryResultsSerializer::from_format(format)
-                                    .serialize_boolean_to_writer(
-                                        BufWriter::new(File::create(results_file)?),
-                                        result,
-                                    )?,
-                            )?;
-                        } else {
-                            QueryResultsSerializer::from_format(format)
-                                .serialize_boolean_to_writer(stdout().lock(), result)?
-                                .flush()?;
-                        }
+                    return Ok(());
+                }
+                let results = results?;
+                if let QueryResults::Graph(_) | QueryResults::Dataset(_) = results {
+                    let format = if let Some(name) = &results_format {
+                        rdf_format_from_name(name)
+                    } else if let Some(results_file) = &results_file {
+                        rdf_format_from_path(results_file)
+                    } else {
+                        bail!("The --results-format option must be set when writing to stdout")
+                    }?;
+                    if let Some(results_file) = results_file {
+                        close_file_writer(
+                            results
+                                .write_graph(BufWriter::new(File::create(results_file)?), format)?,
+                        )?;
+                    } else {
+                        results.write_graph(stdout().lock(), format)?.flush()?;
                     }
-                    QueryResults::Graph(triples) => {
-                        let format = if let Some(name) = &results_format {
-                            rdf_format_from_name(name)
-                        } else if let Some(results_file) = &results_file {
-                            rdf_format_from_path(results_file)
-                        } else {
-                            bail!("The --results-format option must be set when writing to stdout")
-                        }?;
-                        let serializer = RdfSerializer::from_format(format);
-                        if let Some(results_file) = results_file {
-                            let mut serializer =
-                                serializer.for_writer(BufWriter::new(File::create(results_file)?));
-                            for triple in triples {
-                                serializer.serialize_triple(triple?.as_ref())?;
-                            }
-                            close_file_writer(serializer.finish()?)?;
+                } else {
+                    let format = if let Some(name) = results_format {
+                        if let Some(format) = QueryResultsFormat::from_extension(&name) {
+                            format
+                        } else if let Some(format) = QueryResultsFormat::from_media_type(&name) {
+                            format
                         } else {
-                            let mut serializer = serializer.for_writer(stdout().lock());
-                            for triple in triples {
-                                serializer.serialize_triple(triple?.as_ref())?;
-                            }
-                            serializer.finish()?.flush()?;
+                            bail!("The file format '{name}' is unknown")
                         }
+                    } else if let Some(results_file) = &results_file {
+                        format_from_path(results_file, |ext| {
+                            QueryResultsFormat::from_extension(ext)
+                                .with_context(|| format!("The file extension '{ext}' is unknown"))
+                        })?
+                    } else {
+                        bail!("The --results-format option must be set when writing to stdout")
+                    };
+                    if let Some(results_file) = results_file {
+                        close_file_writer(
+                            results.write(BufWriter::new(File::create(results_file)?), format)?,
+                        )?;
+                    } else {
+                        results.write(stdout().lock(), format)?.flush()?;
                     }
                 }
                 Ok(())
@@ -462,9 +610,47 @@ pub fn main() -> anyhow::Result<()> {
                 .execute()?;
             Ok(())
         }
-        Command::Optimize { location } => {
-            let store = Store::open(location)?;
-            store.optimize()?;
+        Command::Repl {
+            location,
+            read_only,
+            union_default_graph,
+        } => {
+            let store = if let Some(location) = location {
+                if read_only {
+                    Store::open_read_only(location)
+                } else {
+                    Store::open(location)
+                }
+            } else {
+                Store::new()
+            }?;
+            Repl::new(store, read_only, union_default_graph).run()
+        }
+        Command::Stats {
+            location,
+            approximate,
+        } => {
+            let store = Store::open_read_only(location)?;
+            write_stats(stdout().lock(), &store.stats(approximate)?)?.flush()?;
+            Ok(())
+        }
+        Command::Optimize {
+            location,
+            storage_compression,
+        } => {
+            let store =
+                Store::open_with_options(location, store_options(storage_compression.as_deref())?)?;
+            if storage_compression.is_some() {
+                // A full compaction is required to rewrite the existing data with the new compression
+                let report = store.compact()?;
+                store.recompute_statistics()?;
+                eprintln!(
+                    "Storage size went from {} to {} bytes",
+                    report.size_before, report.size_after
+                );
+            } else {
+                store.optimize()?;
+            }
             Ok(())
         }
         Command::Convert {
@@ -474,9 +660,11 @@ pub fn main() -> anyhow::Result<()> {
             to_file,
             to_format,
             to_base,
+            prefix,
             lenient,
             from_graph,
             from_default_graph,
+            merge_graphs,
             to_graph,
         } => {
             let from_format = if let Some(format) = from_format {
@@ -501,17 +689,28 @@ pub fn main() -> anyhow::Result<()> {
                 bail!("The --to-format option must be set when writing to stdout")
             };
             let serializer = RdfSerializer::from_format(to_format);
+            let prefixes = prefix
+                .iter()
+                .map(|prefix| {
+                    let (name, iri) = prefix.split_once('=').with_context(|| {
+                        format!("The prefix {prefix} must be of the form name=iri")
+                    })?;
+                    Ok((name, iri))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
 
             let from_graph = if let Some(from_graph) = from_graph {
-                Some(
+                GraphSelection::Graph(
                     NamedNode::new(&from_graph)
                         .with_context(|| format!("The source graph name {from_graph} is invalid"))?
                         .into(),
                 )
             } else if from_default_graph {
-                Some(GraphName::DefaultGraph)
+                GraphSelection::Graph(GraphName::DefaultGraph)
+            } else if merge_graphs {
+                GraphSelection::Merge
             } else {
-                None
+                GraphSelection::All
             };
             let to_graph = if let Some(to_graph) = to_graph {
                 NamedNode::new(&to_graph)
@@ -521,53 +720,426 @@ pub fn main() -> anyhow::Result<()> {
                 GraphName::DefaultGraph
             };
 
+            let from_file = from_file.as_deref();
             match (from_file, to_file) {
                 (Some(from_file), Some(to_file)) => close_file_writer(do_convert(
                     parser,
                     File::open(from_file)?,
+                    Some(from_file),
                     serializer,
                     BufWriter::new(File::create(to_file)?),
                     lenient,
                     &from_graph,
                     &to_graph,
                     to_base.as_deref(),
+                    &prefixes,
                 )?),
                 (Some(from_file), None) => do_convert(
                     parser,
                     File::open(from_file)?,
+                    Some(from_file),
                     serializer,
                     stdout().lock(),
                     lenient,
                     &from_graph,
                     &to_graph,
                     to_base.as_deref(),
+                    &prefixes,
                 )?
                 .flush(),
                 (None, Some(to_file)) => close_file_writer(do_convert(
                     parser,
                     stdin().lock(),
+                    None,
                     serializer,
                     BufWriter::new(File::create(to_file)?),
                     lenient,
                     &from_graph,
                     &to_graph,
                     to_base.as_deref(),
+                    &prefixes,
                 )?),
                 (None, None) => do_convert(
                     parser,
                     stdin().lock(),
+                    None,
                     serializer,
                     stdout().lock(),
                     lenient,
                     &from_graph,
                     &to_graph,
                     to_base.as_deref(),
+                    &prefixes,
                 )?
                 .flush(),
             }?;
             Ok(())
         }
+        Command::Sign {
+            from_file,
+            from_format,
+            from_base,
+            key,
+            hash_algorithm,
+            to_file,
+            to_format,
+        } => {
+            let from_format = if let Some(format) = from_format {
+                rdf_format_from_name(&format)?
+            } else if let Some(file) = &from_file {
+                rdf_format_from_path(file)?
+            } else {
+                bail!("The --from-format option must be set when reading from stdin")
+            };
+            let to_format = if let Some(format) = to_format {
+                rdf_format_from_name(&format)?
+            } else if let Some(file) = &to_file {
+                rdf_format_from_path(file)?
+            } else {
+                from_format
+            };
+            ensure!(
+                to_format.supports_datasets(),
+                "The {to_format} format does not support named graphs, it can't contain the signature graph"
+            );
+            let hash_algorithm = match hash_algorithm.as_str() {
+                "sha256" => CanonicalizationHashAlgorithm::Sha256,
+                "sha384" => CanonicalizationHashAlgorithm::Sha384,
+                _ => bail!("The hash algorithm {hash_algorithm} is not supported"),
+            };
+            let signer = Ed25519Signer::from_pkcs8(
+                &fs::read(&key)
+                    .with_context(|| format!("Failed to read the key file {}", key.display()))?,
+            )?;
+            let mut dataset =
+                read_dataset(from_format, from_base.as_deref(), from_file.as_deref())?;
+            sign_embedded(&mut dataset, &signer, hash_algorithm)?;
+            if let Some(to_file) = to_file {
+                close_file_writer(write_dataset(
+                    &dataset,
+                    to_format,
+                    BufWriter::new(File::create(to_file)?),
+                )?)?;
+            } else {
+                write_dataset(&dataset, to_format, stdout().lock())?.flush()?;
+            }
+            Ok(())
+        }
+        Command::Verify {
+            file,
+            format,
+            base,
+            public_key,
+        } => {
+            let format = if let Some(format) = format {
+                rdf_format_from_name(&format)?
+            } else if let Some(file) = &file {
+                rdf_format_from_path(file)?
+            } else {
+                bail!("The --format option must be set when reading from stdin")
+            };
+            let verifier =
+                Ed25519Verifier::from_public_key(&fs::read(&public_key).with_context(|| {
+                    format!(
+                        "Failed to read the public key file {}",
+                        public_key.display()
+                    )
+                })?)?;
+            let dataset = read_dataset(format, base.as_deref(), file.as_deref())?;
+            verify_embedded(&dataset, &verifier)?;
+            eprintln!("The signature is valid");
+            Ok(())
+        }
+    }
+}
+
+fn store_options(storage_compression: Option<&str>) -> anyhow::Result<StoreOptions> {
+    let options = StoreOptions::new();
+    Ok(match storage_compression {
+        None => options,
+        Some("none") => options.with_compression(StorageCompression::None),
+        Some("lz4") => options.with_compression(StorageCompression::Lz4),
+        Some("zstd") => options
+            .with_compression(StorageCompression::Zstd)
+            .with_zstd_dictionary_size(16 * 1024),
+        Some(compression) => bail!("Unsupported storage compression: {compression}"),
+    })
+}
+
+#[cfg(any(
+    feature = "native-tls",
+    feature = "rustls-native",
+    feature = "rustls-webpki"
+))]
+fn load_urls(store: &Store, urls: &[String], graph: Option<&NamedNode>) -> anyhow::Result<()> {
+    for url in urls {
+        let iri = NamedNode::new(url).with_context(|| format!("The URL {url} is invalid"))?;
+        let start = Instant::now();
+        store
+            .load_from_iri(&iri, graph.map(|g| g.as_ref().into()))
+            .with_context(|| format!("Error while loading {url}"))?;
+        eprintln!("{url} loaded in {}s", start.elapsed().as_secs());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(
+    feature = "native-tls",
+    feature = "rustls-native",
+    feature = "rustls-webpki"
+)))]
+fn load_urls(_store: &Store, _urls: &[String], _graph: Option<&NamedNode>) -> anyhow::Result<()> {
+    bail!(
+        "Loading from URLs requires Oxigraph to be built with one of the native-tls, rustls-native or rustls-webpki features"
+    )
+}
+
+/// A file to load with `load --directory`
+struct DirectoryFile {
+    path: PathBuf,
+    /// The path relative to the loaded directory, with `/` separators
+    relative_path: String,
+    graph: NamedNode,
+}
+
+enum DirectoryFileOutcome {
+    Loaded(u64),
+    Failed(String),
+    Skipped,
+}
+
+fn load_directory(
+    store: &Store,
+    directory: &Path,
+    graph_mapping: &HashMap<String, NamedNode>,
+    graph_base: Option<String>,
+    base_iri: Option<&str>,
+    lenient: bool,
+    non_atomic: bool,
+    fail_fast: bool,
+) -> anyhow::Result<()> {
+    let graph_base = if let Some(graph_base) = graph_base {
+        graph_base
+    } else {
+        Url::from_directory_path(fs::canonicalize(directory)?)
+            .ok()
+            .with_context(|| format!("Not able to build the URL of {}", directory.display()))?
+            .into()
+    };
+    let mut paths = Vec::new();
+    list_files_recursively(directory, &mut paths)
+        .with_context(|| format!("Error while listing the files of {}", directory.display()))?;
+    let mut files = Vec::new();
+    for path in paths {
+        let relative_path = path
+            .strip_prefix(directory)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if directory_file_format(&path).is_none() {
+            eprintln!("Skipping {relative_path}: the file extension is not a known RDF format");
+            continue;
+        }
+        let graph = if let Some(graph) = graph_mapping.get(&relative_path) {
+            graph.clone()
+        } else {
+            let iri = format!("{graph_base}{}", percent_encode_path(&relative_path));
+            NamedNode::new(&iri)
+                .with_context(|| format!("The graph name {iri} of {relative_path} is invalid"))?
+        };
+        files.push(DirectoryFile {
+            path,
+            relative_path,
+            graph,
+        });
+    }
+    if !lenient {
+        eprintln!(
+            "Some files like Wikidata dumps contain invalid IRIs or language tags. If you want to load them anyway use the `--lenient` option."
+        );
+    }
+
+    let start = Instant::now();
+    let loaded_quads = Arc::new(AtomicU64::new(0));
+    let done_files = Arc::new(AtomicUsize::new(0));
+    let aborted = AtomicBool::new(false);
+    let outcomes = Mutex::new(
+        (0..files.len())
+            .map(|_| DirectoryFileOutcome::Skipped)
+            .collect::<Vec<_>>(),
+    );
+    ThreadPoolBuilder::new()
+        .num_threads(max(1, available_parallelism()?.get() / 2))
+        .thread_name(|i| format!("Oxigraph bulk loader thread {i}"))
+        .build()?
+        .scope(|s| {
+            for (i, file) in files.iter().enumerate() {
+                let loaded_quads = Arc::clone(&loaded_quads);
+                let done_files = Arc::clone(&done_files);
+                let (aborted, outcomes) = (&aborted, &outcomes);
+                let file_count = files.len();
+                s.spawn(move |_| {
+                    if aborted.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    // The progress callback gets the number of quads loaded by this loader,
+                    // we only add the new ones to the shared counter
+                    let file_loaded_quads = AtomicU64::new(0);
+                    let progress_done_files = Arc::clone(&done_files);
+                    let mut loader = store.bulk_loader().on_progress(move |size| {
+                        let new =
+                            size.saturating_sub(file_loaded_quads.swap(size, Ordering::Relaxed));
+                        let total = loaded_quads.fetch_add(new, Ordering::Relaxed) + new;
+                        let elapsed = start.elapsed();
+                        eprintln!(
+                            "{total} triples loaded in {}s ({} t/s), {}/{file_count} files done",
+                            elapsed.as_secs(),
+                            ((total as f64) / elapsed.as_secs_f64()).round(),
+                            progress_done_files.load(Ordering::Relaxed),
+                        )
+                    });
+                    if non_atomic {
+                        loader = loader.without_atomicity();
+                    }
+                    let outcome = match load_directory_file(&mut loader, file, base_iri, lenient)
+                        .and_then(|count| {
+                            loader.commit()?;
+                            Ok(count)
+                        }) {
+                        Ok(count) => DirectoryFileOutcome::Loaded(count),
+                        Err(e) => {
+                            eprintln!("Error while loading file {}: {e:#}", file.path.display());
+                            if fail_fast {
+                                aborted.store(true, Ordering::Relaxed);
+                            }
+                            DirectoryFileOutcome::Failed(format!("{e:#}"))
+                        }
+                    };
+                    done_files.fetch_add(1, Ordering::Relaxed);
+                    outcomes.lock().unwrap()[i] = outcome;
+                })
+            }
+        });
+
+    let outcomes = outcomes
+        .into_inner()
+        .map_err(|_| anyhow!("A loading thread panicked"))?;
+    let mut rows = Vec::new();
+    let (mut loaded_count, mut failed_count, mut quad_count) = (0, 0, 0);
+    for (file, outcome) in files.iter().zip(&outcomes) {
+        let status = match outcome {
+            DirectoryFileOutcome::Loaded(count) => {
+                loaded_count += 1;
+                quad_count += count;
+                count.to_string()
+            }
+            DirectoryFileOutcome::Failed(error) => {
+                failed_count += 1;
+                format!("failed: {error}")
+            }
+            DirectoryFileOutcome::Skipped => "skipped".into(),
+        };
+        rows.push(vec![
+            file.relative_path.clone(),
+            file.graph.to_string(),
+            status,
+        ]);
+    }
+    let mut stderr = stderr().lock();
+    write_table(
+        &mut stderr,
+        &["file".into(), "graph".into(), "quads".into()],
+        &rows,
+    )?;
+    writeln!(
+        stderr,
+        "{quad_count} quads read from {loaded_count} files in {}s",
+        start.elapsed().as_secs()
+    )?;
+    ensure!(
+        failed_count == 0 && loaded_count == files.len(),
+        "{} of the {} files have not been loaded",
+        files.len() - loaded_count,
+        files.len()
+    );
+    Ok(())
+}
+
+/// Loads a file with its graph as default graph and returns the number of read quads
+fn load_directory_file(
+    loader: &mut BulkLoader<'_>,
+    file: &DirectoryFile,
+    base_iri: Option<&str>,
+    lenient: bool,
+) -> anyhow::Result<u64> {
+    let (format, encoding) =
+        directory_file_format(&file.path).context("The file format is not supported")?;
+    let parser = bulk_load_parser(format, base_iri, Some(file.graph.clone()), lenient)?;
+    let fp = File::open(&file.path)?;
+    let reader: Box<dyn Read> = if let Some(encoding) = encoding {
+        Box::new(DecodingReader::new(fp, encoding))
+    } else {
+        Box::new(fp)
+    };
+    let mut count = 0;
+    loader.load_ok_quads::<_, LoaderError>(parser.for_reader(reader).filter_map(|result| {
+        match result {
+            Ok(quad) => {
+                count += 1;
+                Some(Ok(quad))
+            }
+            Err(RdfParseError::Syntax(e)) if lenient => {
+                eprintln!("Parsing error on file {}: {e}", file.path.display());
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }))?;
+    Ok(count)
+}
+
+/// The RDF format and the compression of a file, guessed from its extensions like `.ttl.gz`
+fn directory_file_format(path: &Path) -> Option<(RdfFormat, Option<ContentEncoding>)> {
+    let extension = path.extension()?.to_str()?;
+    if let Some(encoding) = ContentEncoding::from_extension(extension) {
+        let format =
+            RdfFormat::from_extension(Path::new(path.file_stem()?).extension()?.to_str()?)?;
+        Some((format, Some(encoding)))
+    } else {
+        Some((RdfFormat::from_extension(extension)?, None))
+    }
+}
+
+/// Lists the files of the directory and of its subdirectories, sorted by path
+fn list_files_recursively(directory: &Path, output: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(directory)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(fs::DirEntry::path);
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            list_files_recursively(&path, output)?;
+        } else {
+            output.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Percent-encodes all the characters of a relative path except the unreserved ones and the `/` separators
+fn percent_encode_path(path: &str) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('%');
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            encoded.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+        }
     }
+    encoded
 }
 
 fn bulk_load_read(
@@ -578,19 +1150,10 @@ fn bulk_load_read(
     to_graph_name: Option<NamedNode>,
     lenient: bool,
 ) -> anyhow::Result<()> {
-    let mut parser = RdfParser::from_format(format);
-    if let Some(to_graph_name) = to_graph_name {
-        parser = parser.with_default_graph(to_graph_name);
-    }
-    if let Some(base_iri) = base_iri {
-        parser = parser
-            .with_base_iri(base_iri)
-            .with_context(|| format!("Invalid base IRI {base_iri}"))?;
-    }
-    if lenient {
-        parser = parser.lenient();
-    }
-    loader.load_from_reader(parser, reader)?;
+    loader.load_from_reader(
+        bulk_load_parser(format, base_iri, to_graph_name, lenient)?,
+        reader,
+    )?;
     Ok(())
 }
 
@@ -602,6 +1165,19 @@ fn bulk_load_file(
     to_graph_name: Option<NamedNode>,
     lenient: bool,
 ) -> anyhow::Result<()> {
+    loader.parallel_load_from_file(
+        bulk_load_parser(format, base_iri, to_graph_name, lenient)?,
+        path,
+    )?;
+    Ok(())
+}
+
+fn bulk_load_parser(
+    format: RdfFormat,
+    base_iri: Option<&str>,
+    to_graph_name: Option<NamedNode>,
+    lenient: bool,
+) -> anyhow::Result<RdfParser> {
     let mut parser = RdfParser::from_format(format);
     if let Some(to_graph_name) = to_graph_name {
         parser = parser.with_default_graph(to_graph_name);
@@ -614,8 +1190,77 @@ fn bulk_load_file(
     if lenient {
         parser = parser.lenient();
     }
-    loader.parallel_load_from_file(parser, path)?;
-    Ok(())
+    Ok(parser)
+}
+
+fn bulk_load_csv(
+    loader: &mut BulkLoader<'_>,
+    reader: impl Read,
+    parser: CsvRdfParser,
+    lenient: bool,
+) -> anyhow::Result<()> {
+    loader.load_ok_quads::<_, LoaderError>(parser.for_reader(reader).filter_map(|result| {
+        match result {
+            Ok(quad) => Some(Ok(quad)),
+            Err(e) if lenient => {
+                eprintln!("Parsing error: {e}");
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }))?;
+    Ok(())
+}
+
+fn csv_parser(
+    base_iri: Option<&str>,
+    mapping: &[(String, NamedNode)],
+    to_graph_name: Option<NamedNode>,
+) -> anyhow::Result<CsvRdfParser> {
+    let mut parser = CsvRdfParser::new();
+    if let Some(to_graph_name) = to_graph_name {
+        parser = parser.with_default_graph(to_graph_name);
+    }
+    if let Some(base_iri) = base_iri {
+        parser = parser
+            .with_base_iri(base_iri)
+            .with_context(|| format!("Invalid base IRI {base_iri}"))?;
+    }
+    for (column, iri) in mapping {
+        parser = parser.with_column_iri(column, iri.clone());
+    }
+    Ok(parser)
+}
+
+/// Reads a JSON object mapping CSV column names to property IRIs
+/// Reads a JSON object mapping keys like CSV columns to IRIs
+fn read_iri_mapping(
+    path: &Path,
+    name: &str,
+    key_kind: &str,
+) -> anyhow::Result<Vec<(String, NamedNode)>> {
+    let mut parser = ReaderJsonParser::new(
+        File::open(path).with_context(|| format!("Error while opening file {}", path.display()))?,
+    );
+    ensure!(
+        matches!(parser.parse_next()?, JsonEvent::StartObject),
+        "The {name} mapping file {} must contain a JSON object",
+        path.display()
+    );
+    let mut mapping = Vec::new();
+    loop {
+        let key = match parser.parse_next()? {
+            JsonEvent::ObjectKey(key) => key.into_owned(),
+            JsonEvent::EndObject => return Ok(mapping),
+            _ => bail!("Invalid {name} mapping file {}", path.display()),
+        };
+        let JsonEvent::String(iri) = parser.parse_next()? else {
+            bail!("The IRI of the {key_kind} '{key}' must be a string")
+        };
+        let iri = NamedNode::new(iri.into_owned())
+            .with_context(|| format!("Invalid IRI for the {key_kind} '{key}'"))?;
+        mapping.push((key, iri));
+    }
 }
 
 fn dump<W: Write>(
@@ -628,22 +1273,60 @@ fn dump<W: Write>(
         format.supports_datasets() || from_graph_name.is_some(),
         "The --graph option is required when writing a format not supporting datasets like NTriples, Turtle or RDF/XML. Use --graph \"default\" to dump only the default graph."
     );
+    let serializer = rdf_serializer(store, format)?;
     Ok(if let Some(from_graph_name) = from_graph_name {
-        store.dump_graph_to_writer(from_graph_name, format, writer)
+        store.dump_graph_to_writer(from_graph_name, serializer, writer)
     } else {
-        store.dump_to_writer(format, writer)
+        store.dump_to_writer(serializer, writer)
     }?)
 }
 
+fn write_stats<W: Write>(mut writer: W, stats: &StoreStats) -> io::Result<W> {
+    writeln!(writer, "Quads: {}", stats.quads)?;
+    writeln!(writer, "Named graphs: {}", stats.named_graphs)?;
+    for (name, count) in [
+        ("subjects", stats.distinct_subjects),
+        ("predicates", stats.distinct_predicates),
+        ("objects", stats.distinct_objects),
+    ] {
+        if let Some(count) = count {
+            writeln!(writer, "Distinct {name}: ~{count}")?;
+        }
+    }
+    writeln!(writer, "Quads per graph:")?;
+    for (graph_name, count) in &stats.graph_quads {
+        writeln!(writer, "  {graph_name}: {count}")?;
+    }
+    if !stats.column_family_sizes.is_empty() {
+        writeln!(writer, "Column family sizes (bytes):")?;
+        for (name, size) in &stats.column_family_sizes {
+            writeln!(writer, "  {name}: {size}")?;
+        }
+    }
+    Ok(writer)
+}
+
+/// The graphs of the input file to convert
+enum GraphSelection {
+    /// All graphs are kept as is
+    All,
+    /// Only the given graph is kept and moved to the default graph
+    Graph(GraphName),
+    /// All graphs are merged into the default graph
+    Merge,
+}
+
 fn do_convert<R: Read, W: Write>(
     mut parser: RdfParser,
     reader: R,
+    from_file: Option<&Path>,
     mut serializer: RdfSerializer,
     writer: W,
     lenient: bool,
-    from_graph: &Option<GraphName>,
+    from_graph: &GraphSelection,
     default_graph: &GraphName,
     to_base: Option<&str>,
+    prefixes: &[(&str, &str)],
 ) -> anyhow::Result<W> {
     if lenient {
         parser = parser.lenient();
@@ -671,7 +1354,7 @@ fn do_convert<R: Read, W: Write>(
             .with_base_iri(base_iri)
             .with_context(|| format!("Invalid base IRI: {base_iri}"))?;
     }
-    for (prefix_name, prefix_iri) in parser.prefixes() {
+    for (prefix_name, prefix_iri) in parser.prefixes().chain(prefixes.iter().copied()) {
         serializer = serializer
             .with_prefix(prefix_name, prefix_iri)
             .with_context(|| format!("Invalid IRI for prefix {prefix_name}: {prefix_iri}"))?;
@@ -680,12 +1363,16 @@ fn do_convert<R: Read, W: Write>(
     for quad_result in first.into_iter().chain(parser) {
         match quad_result {
             Ok(mut quad) => {
-                if let Some(from_graph) = from_graph {
-                    if quad.graph_name == *from_graph {
-                        quad.graph_name = GraphName::DefaultGraph;
-                    } else {
-                        continue;
+                match from_graph {
+                    GraphSelection::All => (),
+                    GraphSelection::Graph(from_graph) => {
+                        if quad.graph_name == *from_graph {
+                            quad.graph_name = GraphName::DefaultGraph;
+                        } else {
+                            continue;
+                        }
                     }
+                    GraphSelection::Merge => quad.graph_name = GraphName::DefaultGraph,
                 }
                 if quad.graph_name.is_default_graph() {
                     quad.graph_name = default_graph.clone();
@@ -693,10 +1380,16 @@ fn do_convert<R: Read, W: Write>(
                 serializer.serialize_quad(&quad)?;
             }
             Err(e) => {
+                let e = if let Some(from_file) = from_file {
+                    anyhow::Error::from(e)
+                        .context(format!("Parsing error on file {}", from_file.display()))
+                } else {
+                    anyhow::Error::from(e).context("Parsing error")
+                };
                 if lenient {
-                    eprintln!("Parsing error: {e}");
+                    eprintln!("{e:#}");
                 } else {
-                    return Err(e.into());
+                    return Err(e);
                 }
             }
         }
@@ -704,6 +1397,38 @@ fn do_convert<R: Read, W: Write>(
     Ok(serializer.finish()?)
 }
 
+fn read_dataset(
+    format: RdfFormat,
+    base: Option<&str>,
+    file: Option<&Path>,
+) -> anyhow::Result<Dataset> {
+    let mut parser = RdfParser::from_format(format);
+    if let Some(base) = base {
+        parser = parser
+            .with_base_iri(base)
+            .with_context(|| format!("Invalid base IRI {base}"))?;
+    }
+    Ok(if let Some(file) = file {
+        parser
+            .for_reader(File::open(file)?)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("Parsing error on file {}", file.display()))?
+    } else {
+        parser
+            .for_reader(stdin().lock())
+            .collect::<Result<_, _>>()
+            .context("Parsing error")?
+    })
+}
+
+fn write_dataset<W: Write>(dataset: &Dataset, format: RdfFormat, writer: W) -> io::Result<W> {
+    let mut serializer = RdfSerializer::from_format(format).for_writer(writer);
+    for quad in dataset {
+        serializer.serialize_quad(quad)?;
+    }
+    serializer.finish()
+}
+
 fn format_from_path<T>(
     path: &Path,
     from_extension: impl FnOnce(&str) -> anyhow::Result<T>,
@@ -729,6 +1454,10 @@ fn rdf_format_from_path(path: &Path) -> anyhow::Result<RdfFormat> {
     })
 }
 
+fn is_csv_format_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case("csv") || name.eq_ignore_ascii_case("text/csv")
+}
+
 fn rdf_format_from_name(name: &str) -> anyhow::Result<RdfFormat> {
     if let Some(t) = RdfFormat::from_extension(name) {
         return Ok(t);
@@ -739,1099 +1468,154 @@ fn rdf_format_from_name(name: &str) -> anyhow::Result<RdfFormat> {
     bail!("The file format '{name}' is unknown")
 }
 
+#[expect(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 fn serve(
     store: Store,
     bind: &str,
     read_only: bool,
-    cors: bool,
+    cors: Option<CorsConfig>,
+    authorizer: Option<WriteCredentialsAuthorizer>,
     union_default_graph: bool,
     timeout_s: Option<u64>,
+    metrics: Option<Metrics>,
+    ui: bool,
+    slow_query_log: Option<SlowQueryLog>,
+    load_provenance: Option<LoadProvenance>,
+    trusted_proxies: Vec<IpAddr>,
+    pagination_cursors: bool,
 ) -> anyhow::Result<()> {
+    init_logging()?;
     let timeout = timeout_s.map(Duration::from_secs);
-    let mut server = if cors {
-        Server::new(cors_middleware(move |request| {
-            handle_request(
-                request,
-                store.clone(),
-                read_only,
-                union_default_graph,
-                timeout,
-            )
-            .unwrap_or_else(|(status, message)| error(status, message))
-        }))
-    } else {
-        Server::new(move |request| {
-            handle_request(
-                request,
-                store.clone(),
-                read_only,
-                union_default_graph,
-                timeout,
-            )
-            .unwrap_or_else(|(status, message)| error(status, message))
-        })
-    }
-    .with_global_timeout(timeout.unwrap_or(HTTP_TIMEOUT))
-    .with_server_name(concat!("Oxigraph/", env!("CARGO_PKG_VERSION")))?
-    .with_max_concurrent_connections(available_parallelism()?.get() * 128);
-    for socket in bind.to_socket_addrs()? {
-        server = server.bind(socket);
-    }
-    let server = server.spawn()?;
-    #[cfg(target_os = "linux")]
-    systemd_notify_ready()?;
-    eprintln!("Listening for requests at http://{bind}");
-    server.join()?;
-    Ok(())
-}
-
-fn cors_middleware(
-    on_request: impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static,
-) -> impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static {
-    move |request| {
-        if *request.method() == Method::OPTIONS {
-            let mut response = Response::builder().status(StatusCode::NO_CONTENT);
-            let request_headers = request.headers();
-            if request_headers.get(ORIGIN).is_some() {
-                response = response.header(
-                    ACCESS_CONTROL_ALLOW_ORIGIN.clone(),
-                    HeaderValue::from_static("*"),
-                );
-            }
-            if let Some(method) = request_headers.get(ACCESS_CONTROL_REQUEST_METHOD) {
-                response = response.header(ACCESS_CONTROL_ALLOW_METHODS, method.clone());
-            }
-            if let Some(headers) = request_headers.get(ACCESS_CONTROL_REQUEST_HEADERS) {
-                response = response.header(ACCESS_CONTROL_ALLOW_HEADERS, headers.clone());
-            }
-            response.body(Body::empty()).unwrap()
-        } else {
-            let mut response = on_request(request);
-            if request.headers().get(ORIGIN).is_some() {
-                response
-                    .headers_mut()
-                    .append(ACCESS_CONTROL_ALLOW_ORIGIN, HeaderValue::from_static("*"));
-            }
-            response
-        }
-    }
-}
-
-type HttpError = (StatusCode, String);
-
-fn handle_request(
-    request: &mut Request<Body>,
-    store: Store,
-    read_only: bool,
-    union_default_graph: bool,
-    timeout: Option<Duration>,
-) -> Result<Response<Body>, HttpError> {
-    match (request.uri().path(), request.method().as_ref()) {
-        ("/", "HEAD") => Response::builder()
-            .header(CONTENT_TYPE, "text/html")
-            .body(Body::empty())
-            .map_err(internal_server_error),
-        ("/", "GET") => Response::builder()
-            .header(CONTENT_TYPE, "text/html")
-            .body(HTML_ROOT_PAGE.into())
-            .map_err(internal_server_error),
-        ("/yasgui.min.css", "HEAD") => Response::builder()
-            .header(CONTENT_TYPE, "text/css")
-            .body(Body::empty())
-            .map_err(internal_server_error),
-        ("/yasgui.min.css", "GET") => Response::builder()
-            .header(CONTENT_TYPE, "text/css")
-            .body(YASGUI_CSS.into())
-            .map_err(internal_server_error),
-        ("/yasgui.min.js", "HEAD") => Response::builder()
-            .header(CONTENT_TYPE, "application/javascript")
-            .body(Body::empty())
-            .map_err(internal_server_error),
-        ("/yasgui.min.js", "GET") => Response::builder()
-            .header(CONTENT_TYPE, "application/javascript")
-            .body(YASGUI_JS.into())
-            .map_err(internal_server_error),
-        ("/logo.svg", "HEAD") => Response::builder()
-            .header(CONTENT_TYPE, "image/svg+xml")
-            .body(Body::empty())
-            .map_err(internal_server_error),
-        ("/logo.svg", "GET") => Response::builder()
-            .header(CONTENT_TYPE, "image/svg+xml")
-            .body(LOGO.into())
-            .map_err(internal_server_error),
-        ("/query", "GET") => {
-            let query = url_query(request);
-            if query.is_empty() {
-                let format = rdf_content_negotiation(request)?;
-                let description = generate_service_description(
-                    format,
-                    EndpointKind::Query,
-                    union_default_graph,
-                    &request_original_target_url(request)?.to_string(),
-                );
-                Response::builder()
-                    .header(CONTENT_TYPE, format.media_type())
-                    .body(description.into())
-                    .map_err(internal_server_error)
-            } else {
-                configure_and_evaluate_sparql_query(
-                    &store,
-                    &[url_query(request)],
-                    None,
-                    request,
-                    union_default_graph,
-                    timeout,
-                )
-            }
-        }
-        ("/query", "POST" | "QUERY") => {
-            let content_type =
-                content_type(request).ok_or_else(|| bad_request("No Content-Type given"))?;
-            if content_type == "application/sparql-query" {
-                let query = limited_string_body(request)?;
-                configure_and_evaluate_sparql_query(
-                    &store,
-                    &[url_query(request)],
-                    Some(query),
-                    request,
-                    union_default_graph,
-                    timeout,
-                )
-            } else if content_type == "application/x-www-form-urlencoded" {
-                let buffer = limited_body(request)?;
-                configure_and_evaluate_sparql_query(
-                    &store,
-                    &[url_query(request), &buffer],
-                    None,
-                    request,
-                    union_default_graph,
-                    timeout,
-                )
-            } else {
-                Err(unsupported_media_type(&content_type))
-            }
-        }
-        ("/update", "GET") => {
-            if read_only {
-                return Err(the_server_is_read_only());
-            }
-            let format = rdf_content_negotiation(request)?;
-            let description = generate_service_description(
-                format,
-                EndpointKind::Update,
-                union_default_graph,
-                &request_original_target_url(request)?.to_string(),
-            );
-            Response::builder()
-                .header(CONTENT_TYPE, format.media_type())
-                .body(description.into())
-                .map_err(internal_server_error)
-        }
-        ("/update", "POST") => {
-            if read_only {
-                return Err(the_server_is_read_only());
-            }
-            let content_type =
-                content_type(request).ok_or_else(|| bad_request("No Content-Type given"))?;
-            if content_type == "application/sparql-update" {
-                let update = limited_string_body(request)?;
-                configure_and_evaluate_sparql_update(
-                    &store,
-                    &[url_query(request)],
-                    Some(update),
-                    request,
-                    union_default_graph,
-                )
-            } else if content_type == "application/x-www-form-urlencoded" {
-                let buffer = limited_body(request)?;
-                configure_and_evaluate_sparql_update(
-                    &store,
-                    &[url_query(request), &buffer],
-                    None,
-                    request,
-                    union_default_graph,
-                )
-            } else {
-                Err(unsupported_media_type(&content_type))
-            }
-        }
-        (path, "GET") if path.starts_with("/store") => {
-            if let Some(target) = store_target(request)? {
-                assert_that_graph_exists(&store, &target)?;
-                let format = rdf_content_negotiation(request)?;
-
-                let quads = store.quads_for_pattern(
-                    None,
-                    None,
-                    None,
-                    Some(GraphName::from(target).as_ref()),
-                );
-                ReadForWrite::build_response(
-                    move |w| Ok((RdfSerializer::from_format(format).for_writer(w), quads)),
-                    |(mut serializer, mut quads)| {
-                        Ok(if let Some(q) = quads.next() {
-                            serializer.serialize_triple(&q?.into())?;
-                            Some((serializer, quads))
-                        } else {
-                            serializer.finish()?;
-                            None
-                        })
-                    },
-                    format.media_type(),
-                )
-            } else {
-                let format = rdf_content_negotiation(request)?;
-                if !format.supports_datasets() {
-                    return Err(bad_request(format!(
-                        "It is not possible to serialize the full RDF dataset using {format} that does not support named graphs"
-                    )));
-                }
-                ReadForWrite::build_response(
-                    move |w| {
-                        Ok((
-                            RdfSerializer::from_format(format).for_writer(w),
-                            store.iter(),
-                        ))
-                    },
-                    |(mut serializer, mut quads)| {
-                        Ok(if let Some(q) = quads.next() {
-                            serializer.serialize_quad(&q?)?;
-                            Some((serializer, quads))
-                        } else {
-                            serializer.finish()?;
-                            None
-                        })
-                    },
-                    format.media_type(),
-                )
-            }
-        }
-        (path, "PUT") if path.starts_with("/store") => {
-            if read_only {
-                return Err(the_server_is_read_only());
-            }
-            let content_type =
-                content_type(request).ok_or_else(|| bad_request("No Content-Type given"))?;
-            if let Some(target) = store_target(request)? {
-                let format = RdfFormat::from_media_type(&content_type)
-                    .ok_or_else(|| unsupported_media_type(&content_type))?;
-                let new = !match &target {
-                    NamedGraphName::NamedNode(target) => {
-                        if store
-                            .contains_named_graph(target)
-                            .map_err(internal_server_error)?
-                        {
-                            store.clear_graph(target).map_err(internal_server_error)?;
-                            true
-                        } else {
-                            store
-                                .insert_named_graph(target)
-                                .map_err(internal_server_error)?;
-                            false
-                        }
-                    }
-                    NamedGraphName::DefaultGraph => {
-                        store
-                            .clear_graph(GraphNameRef::DefaultGraph)
-                            .map_err(internal_server_error)?;
-                        true
-                    }
-                };
-                web_load_graph(&store, request, format, &GraphName::from(target))?;
-                Response::builder()
-                    .status(if new {
-                        StatusCode::CREATED
-                    } else {
-                        StatusCode::NO_CONTENT
-                    })
-                    .body(Body::empty())
-                    .map_err(internal_server_error)
-            } else {
-                let format = RdfFormat::from_media_type(&content_type)
-                    .ok_or_else(|| unsupported_media_type(&content_type))?;
-                store.clear().map_err(internal_server_error)?;
-                web_load_dataset(&store, request, format)?;
-                Response::builder()
-                    .status(StatusCode::NO_CONTENT)
-                    .body(Body::empty())
-                    .map_err(internal_server_error)
-            }
-        }
-        (path, "DELETE") if path.starts_with("/store") => {
-            if read_only {
-                return Err(the_server_is_read_only());
-            }
-            if let Some(target) = store_target(request)? {
-                match target {
-                    NamedGraphName::DefaultGraph => store
-                        .clear_graph(GraphNameRef::DefaultGraph)
-                        .map_err(internal_server_error)?,
-                    NamedGraphName::NamedNode(target) => {
-                        if store
-                            .contains_named_graph(&target)
-                            .map_err(internal_server_error)?
-                        {
-                            store
-                                .remove_named_graph(&target)
-                                .map_err(internal_server_error)?;
-                        } else {
-                            return Err((
-                                StatusCode::NOT_FOUND,
-                                format!("The graph {target} does not exists"),
-                            ));
-                        }
-                    }
-                }
-            } else {
-                store.clear().map_err(internal_server_error)?;
-            }
-            Response::builder()
-                .status(StatusCode::NO_CONTENT)
-                .body(Body::empty())
-                .map_err(internal_server_error)
-        }
-        (path, "POST") if path.starts_with("/store") => {
-            if read_only {
-                return Err(the_server_is_read_only());
-            }
-            let content_type =
-                content_type(request).ok_or_else(|| bad_request("No Content-Type given"))?;
-            if let Some(target) = store_target(request)? {
-                let format = RdfFormat::from_media_type(&content_type)
-                    .ok_or_else(|| unsupported_media_type(&content_type))?;
-                let new = assert_that_graph_exists(&store, &target).is_ok();
-                web_load_graph(&store, request, format, &GraphName::from(target))?;
-                Response::builder()
-                    .status(if new {
-                        StatusCode::CREATED
-                    } else {
-                        StatusCode::NO_CONTENT
-                    })
-                    .body(Body::empty())
-                    .map_err(internal_server_error)
-            } else {
-                let format = RdfFormat::from_media_type(&content_type)
-                    .ok_or_else(|| unsupported_media_type(&content_type))?;
-                if format.supports_datasets() {
-                    web_load_dataset(&store, request, format)?;
-                    Response::builder().status(StatusCode::NO_CONTENT)
-                } else {
-                    let graph =
-                        resolve_with_base(request, &format!("/store/{:x}", random::<u128>()))?;
-                    web_load_graph(&store, request, format, &graph.clone().into())?;
-                    Response::builder()
-                        .status(StatusCode::CREATED)
-                        .header(LOCATION, graph.into_string())
-                }
-                .body(Body::empty())
-                .map_err(internal_server_error)
-            }
-        }
-        (path, "HEAD") if path.starts_with("/store") => {
-            if let Some(target) = store_target(request)? {
-                assert_that_graph_exists(&store, &target)?;
-            }
-            Response::builder()
-                .body(Body::empty())
-                .map_err(internal_server_error)
-        }
-        _ => Err((
-            StatusCode::NOT_FOUND,
-            format!(
-                "{} {} is not supported by this server",
-                request.method(),
-                request.uri().path()
-            ),
-        )),
-    }
-}
-
-fn base_url(request: &Request<Body>) -> String {
-    let uri = request.uri();
-    if uri.query().is_some() {
-        // We remove the query
-        let mut parts = uri.clone().into_parts();
-        if let Some(path_and_query) = &mut parts.path_and_query {
-            if path_and_query.query().is_some() {
-                *path_and_query = PathAndQuery::try_from(path_and_query.path()).unwrap();
-            }
-        };
-        Uri::from_parts(parts).unwrap().to_string()
-    } else {
-        uri.to_string()
-    }
-}
-
-fn resolve_with_base(request: &Request<Body>, url: &str) -> Result<NamedNode, HttpError> {
-    Ok(Iri::parse(base_url(request))
-        .map_err(bad_request)?
-        .resolve(url)
-        .map_err(bad_request)?
-        .into())
-}
-
-fn url_query(request: &Request<Body>) -> &[u8] {
-    request.uri().query().unwrap_or_default().as_bytes()
-}
-
-fn url_query_parameter<'a>(request: &'a Request<Body>, param: &str) -> Option<Cow<'a, str>> {
-    form_urlencoded::parse(url_query(request))
-        .find(|(k, _)| k == param)
-        .map(|(_, v)| v)
-}
-
-fn limited_string_body(request: &mut Request<Body>) -> Result<String, HttpError> {
-    String::from_utf8(limited_body(request)?)
-        .map_err(|e| bad_request(format!("Invalid UTF-8 body: {e}")))
-}
-
-fn limited_body(request: &mut Request<Body>) -> Result<Vec<u8>, HttpError> {
-    let body = request.body_mut();
-    if let Some(body_len) = body.len() {
-        if body_len > MAX_SPARQL_BODY_SIZE {
-            // it's too big
-            return Err(bad_request(format!(
-                "SPARQL body payloads are limited to {MAX_SPARQL_BODY_SIZE} bytes, found {body_len} bytes"
-            )));
-        }
-        let mut payload = Vec::with_capacity(
-            body_len
-                .try_into()
-                .map_err(|_| bad_request("Huge body size"))?,
-        );
-        body.read_to_end(&mut payload)
-            .map_err(internal_server_error)?;
-        Ok(payload)
-    } else {
-        let mut payload = Vec::new();
-        body.take(MAX_SPARQL_BODY_SIZE + 1)
-            .read_to_end(&mut payload)
-            .map_err(internal_server_error)?;
-        if payload.len()
-            > MAX_SPARQL_BODY_SIZE
-                .try_into()
-                .map_err(internal_server_error)?
-        {
-            return Err(bad_request(format!(
-                "SPARQL body payloads are limited to {MAX_SPARQL_BODY_SIZE} bytes"
-            )));
-        }
-        Ok(payload)
-    }
-}
-
-fn configure_and_evaluate_sparql_query(
-    store: &Store,
-    encoded: &[&[u8]],
-    mut query: Option<String>,
-    request: &Request<Body>,
-    default_use_default_graph_as_union: bool,
-    timeout: Option<Duration>,
-) -> Result<Response<Body>, HttpError> {
-    let mut default_graph_uris = Vec::new();
-    let mut named_graph_uris = Vec::new();
-    let mut use_default_graph_as_union = false;
-    for encoded in encoded {
-        for (k, v) in form_urlencoded::parse(encoded) {
-            match k.as_ref() {
-                "query" => {
-                    if query.is_some() {
-                        return Err(bad_request("Multiple query parameters provided"));
-                    }
-                    query = Some(v.into_owned())
-                }
-                "default-graph-uri" => default_graph_uris.push(v.into_owned()),
-                "union-default-graph" => use_default_graph_as_union = true,
-                "named-graph-uri" => named_graph_uris.push(v.into_owned()),
-                _ => (),
-            }
-        }
-    }
-    if default_graph_uris.is_empty() && named_graph_uris.is_empty() {
-        use_default_graph_as_union |= default_use_default_graph_as_union;
-    }
-    let query = query.ok_or_else(|| bad_request("You should set the 'query' parameter"))?;
-    evaluate_sparql_query(
-        store,
-        &query,
-        use_default_graph_as_union,
-        default_graph_uris,
-        named_graph_uris,
-        request,
-        timeout,
-    )
-}
-
-fn evaluate_sparql_query(
-    store: &Store,
-    query: &str,
-    use_default_graph_as_union: bool,
-    default_graph_uris: Vec<String>,
-    named_graph_uris: Vec<String>,
-    request: &Request<Body>,
-    timeout: Option<Duration>,
-) -> Result<Response<Body>, HttpError> {
-    let mut evaluator = default_sparql_evaluator()
-        .with_base_iri(base_url(request))
-        .map_err(bad_request)?;
-
-    if let Some(timeout) = timeout {
-        let cancellation_token = CancellationToken::new();
-        evaluator = evaluator.with_cancellation_token(cancellation_token.clone());
+    if let Some(metrics) = &metrics {
+        let metrics = metrics.clone();
+        let store = store.clone();
         thread::Builder::new()
-            .name("SPARQL evaluation timeout".into())
-            .spawn(move || {
-                thread::sleep(timeout);
-                cancellation_token.cancel();
-            })
-            .map_err(internal_server_error)?;
-    }
-
-    let mut prepared = evaluator.parse_query(query).map_err(bad_request)?;
-
-    if use_default_graph_as_union {
-        if !default_graph_uris.is_empty() || !named_graph_uris.is_empty() {
-            return Err(bad_request(
-                "default-graph-uri or named-graph-uri and union-default-graph should not be set at the same time",
-            ));
-        }
-        prepared.dataset_mut().set_default_graph_as_union()
-    } else if !default_graph_uris.is_empty() || !named_graph_uris.is_empty() {
-        prepared.dataset_mut().set_default_graph(
-            default_graph_uris
-                .into_iter()
-                .map(|e| Ok(NamedNode::new(e)?.into()))
-                .collect::<Result<Vec<GraphName>, IriParseError>>()
-                .map_err(bad_request)?,
-        );
-        prepared.dataset_mut().set_available_named_graphs(
-            named_graph_uris
-                .into_iter()
-                .map(|e| Ok(NamedNode::new(e)?.into()))
-                .collect::<Result<Vec<NamedOrBlankNode>, IriParseError>>()
-                .map_err(bad_request)?,
-        );
+            .name("Store size metric refresh".into())
+            .spawn(move || refresh_store_size_metric(&store, &metrics))?;
     }
-
-    let results = prepared
-        .on_store(store)
-        .execute()
-        .map_err(internal_server_error)?;
-    match results {
-        QueryResults::Solutions(solutions) => {
-            let format = query_results_content_negotiation(request)?;
-            ReadForWrite::build_response(
-                move |w| {
-                    Ok((
-                        QueryResultsSerializer::from_format(format)
-                            .serialize_solutions_to_writer(w, solutions.variables().to_vec())?,
-                        solutions,
-                    ))
-                },
-                |(mut serializer, mut solutions)| {
-                    Ok(if let Some(solution) = solutions.next() {
-                        serializer.serialize(&solution.map_err(io::Error::other)?)?;
-                        Some((serializer, solutions))
-                    } else {
-                        serializer.finish()?;
-                        None
-                    })
-                },
-                format.media_type(),
-            )
-        }
-        QueryResults::Boolean(result) => {
-            let format = query_results_content_negotiation(request)?;
-            let mut body = Vec::new();
-            QueryResultsSerializer::from_format(format)
-                .serialize_boolean_to_writer(&mut body, result)
-                .map_err(internal_server_error)?;
-            Response::builder()
-                .header(CONTENT_TYPE, format.media_type())
-                .body(body.into())
-                .map_err(internal_server_error)
-        }
-        QueryResults::Graph(triples) => {
-            let format = rdf_content_negotiation(request)?;
-            ReadForWrite::build_response(
-                move |w| Ok((RdfSerializer::from_format(format).for_writer(w), triples)),
-                |(mut serializer, mut triples)| {
-                    Ok(if let Some(t) = triples.next() {
-                        serializer.serialize_triple(&t.map_err(io::Error::other)?)?;
-                        Some((serializer, triples))
-                    } else {
-                        serializer.finish()?;
-                        None
-                    })
-                },
-                format.media_type(),
-            )
-        }
+    let mut config = SparqlEndpointConfig::new();
+    if read_only {
+        config = config.with_read_only();
     }
-}
-
-fn default_sparql_evaluator() -> SparqlEvaluator {
-    #[cfg_attr(not(feature = "geosparql"), expect(unused_mut))]
-    let mut evaluator = SparqlEvaluator::new();
-    #[cfg(feature = "geosparql")]
-    for (name, implementation) in GEOSPARQL_EXTENSION_FUNCTIONS {
-        evaluator = evaluator.with_custom_function(name.into(), implementation)
+    if union_default_graph {
+        config = config.with_union_default_graph();
     }
-    evaluator
-}
-
-fn configure_and_evaluate_sparql_update(
-    store: &Store,
-    encoded: &[&[u8]],
-    mut update: Option<String>,
-    request: &Request<Body>,
-    default_use_default_graph_as_union: bool,
-) -> Result<Response<Body>, HttpError> {
-    let mut use_default_graph_as_union = false;
-    let mut default_graph_uris = Vec::new();
-    let mut named_graph_uris = Vec::new();
-    for encoded in encoded {
-        for (k, v) in form_urlencoded::parse(encoded) {
-            match k.as_ref() {
-                "update" => {
-                    if update.is_some() {
-                        return Err(bad_request("Multiple update parameters provided"));
-                    }
-                    update = Some(v.into_owned())
-                }
-                "using-graph-uri" => default_graph_uris.push(v.into_owned()),
-                "using-union-graph" => use_default_graph_as_union = true,
-                "using-named-graph-uri" => named_graph_uris.push(v.into_owned()),
-                _ => (),
-            }
-        }
-    }
-    if default_graph_uris.is_empty() && named_graph_uris.is_empty() {
-        use_default_graph_as_union |= default_use_default_graph_as_union;
-    }
-    let update = update.ok_or_else(|| bad_request("You should set the 'update' parameter"))?;
-    evaluate_sparql_update(
-        store,
-        &update,
-        use_default_graph_as_union,
-        default_graph_uris,
-        named_graph_uris,
-        request,
-    )
-}
-
-fn evaluate_sparql_update(
-    store: &Store,
-    update: &str,
-    use_default_graph_as_union: bool,
-    default_graph_uris: Vec<String>,
-    named_graph_uris: Vec<String>,
-    request: &Request<Body>,
-) -> Result<Response<Body>, HttpError> {
-    let mut prepared = default_sparql_evaluator()
-        .with_base_iri(base_url(request).as_str())
-        .map_err(bad_request)?
-        .parse_update(update)
-        .map_err(bad_request)?;
-
-    if use_default_graph_as_union {
-        if !default_graph_uris.is_empty() || !named_graph_uris.is_empty() {
-            return Err(bad_request(
-                "using-graph-uri or using-named-graph-uri and using-union-graph should not be set at the same time",
-            ));
-        }
-        for using in prepared.using_datasets_mut() {
-            if !using.is_default_dataset() {
-                return Err(bad_request(
-                    "using-union-graph must not be used with a SPARQL UPDATE containing USING",
-                ));
-            }
-            using.set_default_graph_as_union();
-        }
-    } else if !default_graph_uris.is_empty() || !named_graph_uris.is_empty() {
-        let default_graph_uris = default_graph_uris
-            .into_iter()
-            .map(|e| Ok(NamedNode::new(e)?.into()))
-            .collect::<Result<Vec<GraphName>, IriParseError>>()
-            .map_err(bad_request)?;
-        let named_graph_uris = named_graph_uris
-            .into_iter()
-            .map(|e| Ok(NamedNode::new(e)?.into()))
-            .collect::<Result<Vec<NamedOrBlankNode>, IriParseError>>()
-            .map_err(bad_request)?;
-        for using in prepared.using_datasets_mut() {
-            if !using.is_default_dataset() {
-                return Err(bad_request(
-                    "using-graph-uri and using-named-graph-uri must not be used with a SPARQL UPDATE containing USING",
-                ));
-            }
-            using.set_default_graph(default_graph_uris.clone());
-            using.set_available_named_graphs(named_graph_uris.clone());
-        }
-    }
-    prepared
-        .on_store(store)
-        .execute()
-        .map_err(internal_server_error)?;
-    Response::builder()
-        .status(StatusCode::NO_CONTENT)
-        .body(Body::empty())
-        .map_err(internal_server_error)
-}
-
-fn store_target(request: &Request<Body>) -> Result<Option<NamedGraphName>, HttpError> {
-    if request.uri().path() == "/store" {
-        if let Some(graph) = url_query_parameter(request, "graph") {
-            if url_query_parameter(request, "default").is_some() {
-                Err(bad_request(
-                    "Both graph and default parameters should not be set at the same time",
-                ))
-            } else {
-                Ok(Some(NamedGraphName::NamedNode(resolve_with_base(
-                    request, &graph,
-                )?)))
-            }
-        } else if url_query_parameter(request, "default").is_some() {
-            Ok(Some(NamedGraphName::DefaultGraph))
-        } else {
-            Ok(None)
-        }
-    } else {
-        Ok(Some(NamedGraphName::NamedNode(resolve_with_base(
-            request, "",
-        )?)))
-    }
-}
-
-fn assert_that_graph_exists(store: &Store, target: &NamedGraphName) -> Result<(), HttpError> {
-    if match target {
-        NamedGraphName::DefaultGraph => true,
-        NamedGraphName::NamedNode(target) => store
-            .contains_named_graph(target)
-            .map_err(internal_server_error)?,
-    } {
-        Ok(())
-    } else {
-        Err((
-            StatusCode::NOT_FOUND,
-            format!(
-                "The graph {} does not exists",
-                GraphName::from(target.clone())
-            ),
-        ))
-    }
-}
-
-#[derive(Eq, PartialEq, Debug, Clone, Hash)]
-enum NamedGraphName {
-    NamedNode(NamedNode),
-    DefaultGraph,
-}
-
-impl From<NamedGraphName> for GraphName {
-    fn from(graph_name: NamedGraphName) -> Self {
-        match graph_name {
-            NamedGraphName::NamedNode(node) => node.into(),
-            NamedGraphName::DefaultGraph => Self::DefaultGraph,
-        }
-    }
-}
-
-fn rdf_content_negotiation(request: &Request<Body>) -> Result<RdfFormat, HttpError> {
-    content_negotiation(
-        request,
-        RdfFormat::from_media_type,
-        RdfFormat::NQuads,
-        &[
-            ("application", RdfFormat::NQuads),
-            ("text", RdfFormat::NQuads),
-        ],
-        "application/n-quads or text/turtle",
-    )
-}
-
-fn query_results_content_negotiation(
-    request: &Request<Body>,
-) -> Result<QueryResultsFormat, HttpError> {
-    content_negotiation(
-        request,
-        QueryResultsFormat::from_media_type,
-        QueryResultsFormat::Json,
-        &[
-            ("application", QueryResultsFormat::Json),
-            ("text", QueryResultsFormat::Json),
-        ],
-        "application/sparql-results+json or text/tsv",
-    )
-}
-
-fn content_negotiation<F: Copy>(
-    request: &Request<Body>,
-    parse: impl Fn(&str) -> Option<F>,
-    default: F,
-    default_by_base: &[(&str, F)],
-    example: &str,
-) -> Result<F, HttpError> {
-    let header = request
-        .headers()
-        .get(ACCEPT)
-        .map(|h| h.to_str())
-        .transpose()
-        .map_err(|_| bad_request("The Accept header should be a valid ASCII string"))?
-        .unwrap_or_default();
-
-    if header.is_empty() {
-        return Ok(default);
-    }
-    let mut result = None;
-    let mut result_score = 0_f32;
-    for mut possible in header.split(',') {
-        let mut score = 1.;
-        if let Some((possible_type, last_parameter)) = possible.rsplit_once(';') {
-            if let Some((name, value)) = last_parameter.split_once('=') {
-                if name.trim().eq_ignore_ascii_case("q") {
-                    score = f32::from_str(value.trim()).map_err(|_| {
-                        bad_request(format!("Invalid Accept media type score: {value}"))
-                    })?;
-                    possible = possible_type;
-                }
-            }
-        }
-        if score <= result_score {
-            continue;
-        }
-        let (possible_base, possible_sub) = possible
-            .split_once(';')
-            .unwrap_or((possible, ""))
-            .0
-            .split_once('/')
-            .ok_or_else(|| bad_request(format!("Invalid media type: '{possible}'")))?;
-        let possible_base = possible_base.trim();
-        let possible_sub = possible_sub.trim();
-
-        let mut format = None;
-        if possible_base == "*" && possible_sub == "*" {
-            format = Some(default);
-        } else if possible_sub == "*" {
-            for (base, sub_format) in default_by_base {
-                if *base == possible_base {
-                    format = Some(*sub_format);
-                }
-            }
-        } else {
-            format = parse(possible);
-        }
-        if let Some(format) = format {
-            result = Some(format);
-            result_score = score;
-        }
-    }
-
-    result.ok_or_else(|| {
-        (
-            StatusCode::NOT_ACCEPTABLE,
-            format!("The accept header does not provide any accepted format like {example}"),
-        )
-    })
-}
-
-fn content_type(request: &Request<Body>) -> Option<String> {
-    let value = request.headers().get(CONTENT_TYPE)?.to_str().ok()?;
-    Some(
-        value
-            .split_once(';')
-            .map_or(value, |(b, _)| b)
-            .trim()
-            .to_ascii_lowercase(),
-    )
-}
-
-fn web_load_graph(
-    store: &Store,
-    request: &mut Request<Body>,
-    format: RdfFormat,
-    to_graph_name: &GraphName,
-) -> Result<(), HttpError> {
-    let base_iri = if let GraphName::NamedNode(graph_name) = to_graph_name {
-        Some(graph_name.as_str())
-    } else {
-        None
-    };
-    let mut parser = RdfParser::from_format(format)
-        .without_named_graphs()
-        .with_default_graph(to_graph_name.clone());
-    if url_query_parameter(request, "lenient").is_some() {
-        parser = parser.lenient();
+    if let Some(timeout) = timeout {
+        config = config.with_timeout(timeout);
     }
-    if let Some(base_iri) = base_iri {
-        parser = parser.with_base_iri(base_iri).map_err(bad_request)?;
-    }
-    if url_query_parameter(request, "no_transaction").is_some() {
-        let mut loader = web_bulk_loader(store, request);
-        loader
-            .load_from_reader(parser, request.body_mut())
-            .map_err(loader_to_http_error)?;
-        loader.commit().map_err(internal_server_error)
-    } else {
-        store
-            .load_from_reader(parser, request.body_mut())
-            .map_err(loader_to_http_error)
+    if let Some(cors) = cors {
+        config = config.with_cors(cors);
     }
-}
-
-fn web_load_dataset(
-    store: &Store,
-    request: &mut Request<Body>,
-    format: RdfFormat,
-) -> Result<(), HttpError> {
-    let mut parser = RdfParser::from_format(format);
-    if url_query_parameter(request, "lenient").is_some() {
-        parser = parser.lenient();
+    if let Some(authorizer) = authorizer {
+        config = config.with_authorizer(authorizer);
     }
-    if url_query_parameter(request, "no_transaction").is_some() {
-        let mut loader = web_bulk_loader(store, request);
-        loader
-            .load_from_reader(parser, request.body_mut())
-            .map_err(loader_to_http_error)?;
-        loader.commit().map_err(internal_server_error)
-    } else {
-        store
-            .load_from_reader(parser, request.body_mut())
-            .map_err(loader_to_http_error)
+    if let Some(metrics) = metrics {
+        config = config.with_metrics(metrics);
     }
-}
-
-fn web_bulk_loader<'a>(store: &'a Store, request: &Request<Body>) -> BulkLoader<'a> {
-    let start = Instant::now();
-    let mut loader = store.bulk_loader().on_progress(move |size| {
-        let elapsed = start.elapsed();
-        eprintln!(
-            "{} triples loaded in {}s ({} t/s)",
-            size,
-            elapsed.as_secs(),
-            ((size as f64) / elapsed.as_secs_f64()).round()
-        )
-    });
-    if url_query_parameter(request, "lenient").is_some() {
-        loader = loader.on_parse_error(move |e| {
-            eprintln!("Parsing error: {e}");
-            Ok(())
-        })
+    if ui {
+        config = config.with_ui();
     }
-    loader
-}
-
-fn error(status: StatusCode, message: impl fmt::Display) -> Response<Body> {
-    Response::builder()
-        .status(status)
-        .header(CONTENT_TYPE, "text/plain; charset=utf-8")
-        .body(message.to_string().into())
-        .unwrap()
-}
-
-fn bad_request(message: impl fmt::Display) -> HttpError {
-    (StatusCode::BAD_REQUEST, message.to_string())
-}
-
-fn the_server_is_read_only() -> HttpError {
-    (StatusCode::FORBIDDEN, "The server is read-only".into())
-}
-
-fn unsupported_media_type(content_type: &str) -> HttpError {
-    (
-        StatusCode::UNSUPPORTED_MEDIA_TYPE,
-        format!("No supported content Content-Type given: {content_type}"),
-    )
-}
-
-fn internal_server_error(message: impl fmt::Display) -> HttpError {
-    eprintln!("Internal server error: {message}");
-    (StatusCode::INTERNAL_SERVER_ERROR, message.to_string())
-}
-
-fn loader_to_http_error(e: LoaderError) -> HttpError {
-    match e {
-        LoaderError::Parsing(e) => bad_request(e),
-        LoaderError::Storage(e) => internal_server_error(e),
-        LoaderError::InvalidBaseIri { .. } => bad_request(e),
+    if let Some(slow_query_log) = slow_query_log {
+        config = config.with_slow_query_log(slow_query_log);
     }
+    if let Some(load_provenance) = load_provenance {
+        config = config.with_load_provenance(load_provenance);
+    }
+    config = config.with_trusted_proxies(trusted_proxies);
+    if pagination_cursors {
+        config = config.with_pagination_cursors();
+    }
+    let endpoint = SparqlEndpoint::new(store, config);
+    let mut server = Server::new(move |request| {
+        if let Some(address) = connection_peer_address() {
+            request.extensions_mut().insert(RemoteAddress(address));
+        }
+        endpoint.handle(request)
+    })
+    .with_global_timeout(timeout.unwrap_or(HTTP_TIMEOUT))
+    .with_server_name(concat!("Oxigraph/", env!("CARGO_PKG_VERSION")))?
+    .with_max_concurrent_connections(available_parallelism()?.get() * 128);
+    for socket in bind.to_socket_addrs()? {
+        server = server.bind(socket);
+    }
+    let server = server.spawn()?;
+    #[cfg(target_os = "linux")]
+    systemd_notify_ready()?;
+    eprintln!("Listening for requests at http://{bind}");
+    server.join()?;
+    Ok(())
 }
 
-/// Hacky tool to allow implementing read on top of a write loop
-struct ReadForWrite<O, U: (Fn(O) -> io::Result<Option<O>>)> {
-    buffer: Rc<RefCell<Vec<u8>>>,
-    position: usize,
-    add_more_data: U,
-    state: Option<O>,
+/// The address of the peer of the connection handled by the current thread
+///
+/// OxHTTP handles each connection in its own thread named "{peer address}: responding thread of OxHTTP".
+fn connection_peer_address() -> Option<SocketAddr> {
+    thread::current().name()?.split_once(": ")?.0.parse().ok()
 }
 
-impl<O: 'static, U: (Fn(O) -> io::Result<Option<O>>) + 'static> ReadForWrite<O, U> {
-    fn build_response(
-        initial_state_builder: impl FnOnce(ReadForWriteWriter) -> io::Result<O>,
-        add_more_data: U,
-        content_type: &'static str,
-    ) -> Result<Response<Body>, HttpError> {
-        let buffer = Rc::new(RefCell::new(Vec::new()));
-        let state = initial_state_builder(ReadForWriteWriter {
-            buffer: Rc::clone(&buffer),
-        })
-        .map_err(internal_server_error)?;
-        Response::builder()
-            .header(CONTENT_TYPE, content_type)
-            .body(Body::from_read(Self {
-                buffer,
-                position: 0,
-                add_more_data,
-                state: Some(state),
-            }))
-            .map_err(internal_server_error)
+fn load_provenance(
+    base: Option<String>,
+    graph: Option<String>,
+) -> anyhow::Result<Option<LoadProvenance>> {
+    let Some(base) = base else {
+        return Ok(None);
+    };
+    let mut provenance =
+        LoadProvenance::new(base).context("The provenance base must be an absolute IRI")?;
+    if let Some(graph) = graph {
+        provenance = provenance.with_graph(
+            NamedNode::new(graph).context("The provenance graph must be an absolute IRI")?,
+        );
     }
+    Ok(Some(provenance))
 }
 
-impl<O, U: (Fn(O) -> io::Result<Option<O>>)> Read for ReadForWrite<O, U> {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        while self.position == self.buffer.borrow().len() {
-            // We read more data
-            if let Some(state) = self.state.take() {
-                self.buffer.borrow_mut().clear();
-                self.position = 0;
-                self.state = match (self.add_more_data)(state) {
-                    Ok(state) => state,
-                    Err(e) => {
-                        eprintln!("Internal server error while streaming results: {e}");
-                        self.buffer
-                            .borrow_mut()
-                            .write_all(e.to_string().as_bytes())?;
-                        None
-                    }
-                }
-            } else {
-                return Ok(0); // End
-            }
-        }
-        let buffer = self.buffer.borrow();
-        let len = min(buffer.len() - self.position, buf.len());
-        buf[..len].copy_from_slice(&buffer[self.position..self.position + len]);
-        self.position += len;
-        Ok(len)
-    }
+fn open_slow_query_log(
+    path: Option<&Path>,
+    threshold_ms: u64,
+    max_query_length: Option<usize>,
+) -> anyhow::Result<Option<SlowQueryLog>> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    Ok(Some(
+        SlowQueryLog::open(path, Duration::from_millis(threshold_ms), max_query_length)
+            .with_context(|| {
+                format!("Failed to open the slow query log file {}", path.display())
+            })?,
+    ))
 }
 
-struct ReadForWriteWriter {
-    buffer: Rc<RefCell<Vec<u8>>>,
+/// Writes to stderr the spans and events selected by the directives of the `RUST_LOG` environment variable
+///
+/// If the variable is not set, only the server events (bulk load progress, parsing and internal errors) are written.
+/// The spans are written when they close, with their durations.
+fn init_logging() -> anyhow::Result<()> {
+    if env::var_os(EnvFilter::DEFAULT_ENV).is_none() {
+        return tracing_subscriber::fmt()
+            .with_env_filter(EnvFilter::new("oxigraph_cli=info"))
+            .without_time()
+            .with_target(false)
+            .with_level(false)
+            .with_writer(stderr)
+            .try_init()
+            .map_err(|e| anyhow!(e));
+    }
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env()?)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(stderr)
+        .try_init()
+        .map_err(|e| anyhow!(e))
 }
 
-impl Write for ReadForWriteWriter {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.buffer.borrow_mut().write(buf)
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        Ok(())
-    }
-
-    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        self.buffer.borrow_mut().write_all(buf)
+fn refresh_store_size_metric(store: &Store, metrics: &Metrics) -> ! {
+    loop {
+        match store.stats(true) {
+            Ok(stats) => metrics.set_store_quads(stats.quads),
+            Err(e) => eprintln!("Error while computing the store size: {e}"),
+        }
+        thread::sleep(STORE_SIZE_METRIC_REFRESH_INTERVAL);
     }
 }
 
@@ -1851,63 +1635,17 @@ fn systemd_notify_ready() -> io::Result<()> {
     Ok(())
 }
 
-fn request_original_target_url<B>(request: &Request<B>) -> Result<Uri, HttpError> {
-    let mut parts = request.uri().clone().into_parts();
-    if let Some(host) = request.headers().get("X-Forwarded-Host") {
-        parts.authority = Some(
-            Authority::try_from(host.as_bytes())
-                .map_err(|e| bad_request(format!("Bad X-Forwarded-Host header: {e}")))?,
-        );
-    }
-    if let Some(proto) = request.headers().get("X-Forwarded-Proto") {
-        parts.scheme = Some(
-            Scheme::try_from(proto.as_bytes())
-                .map_err(|e| bad_request(format!("Bad X-Forwarded-Proto header: {e}")))?,
-        );
-    }
-    if let Some(forwarded) = request.headers().get("Forwarded") {
-        for pair in forwarded.as_bytes().split(|c| *c == b';') {
-            let Some((key_value_separation, _)) =
-                pair.iter().enumerate().find(|(_, c)| **c == b'=')
-            else {
-                return Err(bad_request("Bad Forwarded header"));
-            };
-            let (key, value) = pair.split_at(key_value_separation);
-            let value = value[1..].trim_ascii(); // We remove the split value
-            match key.trim_ascii() {
-                b"host" => {
-                    parts.authority = Some(
-                        Authority::try_from(value)
-                            .map_err(|e| bad_request(format!("Bad Forwarded header: {e}")))?,
-                    );
-                }
-                b"proto" => {
-                    parts.scheme = Some(
-                        Scheme::try_from(value.trim_ascii())
-                            .map_err(|e| bad_request(format!("Bad Forwarded header: {e}")))?,
-                    );
-                }
-                _ => (),
-            }
-        }
-    }
-    Uri::from_parts(parts).map_err(internal_server_error)
-}
-
 #[cfg(test)]
-#[expect(clippy::panic_in_result_fn)]
 mod tests {
     use super::*;
-    use anyhow::{Result, anyhow};
+    use anyhow::Result;
     use assert_cmd::Command;
     use assert_fs::prelude::*;
     use assert_fs::{NamedTempFile, TempDir};
     use flate2::Compression;
     use flate2::write::GzEncoder;
-    use oxhttp::model::header::ACCEPT;
     use predicates::prelude::*;
     use std::fs::remove_dir_all;
-    use std::io::read_to_string;
 
     fn cli_command() -> Command {
         let mut command = Command::new(env!("CARGO"));
@@ -2000,6 +1738,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cli_load_and_optimize_with_storage_compression() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        let input_file = NamedTempFile::new("input.nt")?;
+        input_file
+            .write_str("<http://example.com/s> <http://example.com/p> <http://example.com/o> .")?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--file")
+            .arg(input_file.path())
+            .arg("--storage-compression")
+            .arg("none")
+            .assert()
+            .success();
+
+        cli_command()
+            .arg("optimize")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--storage-compression")
+            .arg("lz4")
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Storage size went from"));
+
+        cli_command()
+            .arg("query")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--query")
+            .arg("ASK { <http://example.com/s> ?p ?o }")
+            .arg("--results-format")
+            .arg("csv")
+            .assert()
+            .success()
+            .stdout("true");
+        Ok(())
+    }
+
     #[test]
     fn cli_load_and_dump_dataset() -> Result<()> {
         let store_dir = TempDir::new()?;
@@ -2029,6 +1808,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cli_load_resume_from_checkpoint() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        let input_file = NamedTempFile::new("input.nq")?;
+        let first_line =
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o1> .\n";
+        input_file.write_str(&format!(
+            "{first_line}<http://example.com/s> <http://example.com/p> <http://example.com/o2> .\n"
+        ))?;
+        // We simulate an interrupted loading that already saved the first line
+        let checkpoint_file = NamedTempFile::new("checkpoint")?;
+        checkpoint_file.write_str(&format!(
+            "offset {}\nblank_node_prefix b\n",
+            first_line.len()
+        ))?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--file")
+            .arg(input_file.path())
+            .arg("--checkpoint")
+            .arg(checkpoint_file.path())
+            .assert()
+            .success();
+        checkpoint_file.assert(predicate::path::missing());
+
+        let output_file = NamedTempFile::new("output.nq")?;
+        cli_command()
+            .arg("dump")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--file")
+            .arg(output_file.path())
+            .assert()
+            .success();
+        output_file
+            .assert("<http://example.com/s> <http://example.com/p> <http://example.com/o2> .\n");
+        Ok(())
+    }
+
     #[test]
     fn cli_load_gzip_dataset() -> Result<()> {
         let store_dir = TempDir::new()?;
@@ -2152,6 +1972,146 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cli_load_url_conflicts_with_file() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--url")
+            .arg("http://example.com/data.ttl")
+            .arg("--file")
+            .arg("data.ttl")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+        Ok(())
+    }
+
+    #[test]
+    fn cli_load_directory() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        let input_dir = TempDir::new()?;
+        input_dir
+            .child("a b.nt")
+            .write_str("<http://example.com/s> <http://example.com/p> <http://example.com/o> .")?;
+        input_dir
+            .child("sub/c.ttl")
+            .write_str("<http://example.com/s> <http://example.com/p> 1, 2 .")?;
+        input_dir.child("sub/invalid.nt").write_str("invalid")?;
+        input_dir.child("notes.md").write_str("# Notes")?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--directory")
+            .arg(input_dir.path())
+            .arg("--graph-base")
+            .arg("http://example.com/graph/")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Skipping notes.md"))
+            .stderr(predicate::str::contains(
+                "| sub/c.ttl      | <http://example.com/graph/sub/c.ttl>      | 2 ",
+            ))
+            .stderr(predicate::str::contains(
+                "1 of the 3 files have not been loaded",
+            ));
+
+        cli_command()
+            .arg("query")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--query")
+            .arg("ASK { GRAPH <http://example.com/graph/a%20b.nt> { <http://example.com/s> ?p ?o } }")
+            .arg("--results-format")
+            .arg("csv")
+            .assert()
+            .success()
+            .stdout("true");
+        Ok(())
+    }
+
+    #[test]
+    fn cli_load_csv() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        let input_file = NamedTempFile::new("people.csv")?;
+        input_file.write_str("name,age\n\"Foo, Bar\",12\n")?;
+        let mapping_file = NamedTempFile::new("mapping.json")?;
+        mapping_file.write_str(r#"{"name": "http://schema.org/name"}"#)?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--file")
+            .arg(input_file.path())
+            .arg("--format")
+            .arg("csv")
+            .arg("--csv-mapping")
+            .arg(mapping_file.path())
+            .arg("--base")
+            .arg("http://example.com/people.csv")
+            .assert()
+            .success();
+
+        cli_command()
+            .arg("dump")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--format")
+            .arg("nq")
+            .assert()
+            .success()
+            .stdout(
+                predicate::str::contains("<http://schema.org/name> \"Foo, Bar\" .\n").and(
+                    predicate::str::contains("<http://example.com/people.csv#age> \"12\" .\n"),
+                ),
+            );
+
+        // The mapping is only allowed with CSV
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--format")
+            .arg("nq")
+            .arg("--csv-mapping")
+            .arg(mapping_file.path())
+            .write_stdin("")
+            .assert()
+            .failure();
+        Ok(())
+    }
+
+    #[test]
+    fn cli_load_and_dump_with_namespaces() -> Result<()> {
+        let store_dir = TempDir::new()?;
+        cli_command()
+            .arg("load")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--format")
+            .arg("ttl")
+            .arg("--register-namespaces")
+            .write_stdin("@prefix ex: <http://example.com/> . ex:s ex:p ex:o .")
+            .assert()
+            .success();
+
+        cli_command()
+            .arg("dump")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--format")
+            .arg("ttl")
+            .arg("--graph")
+            .arg("default")
+            .assert()
+            .success()
+            .stdout("@prefix ex: <http://example.com/> .\nex:s ex:p ex:o .\n");
+        Ok(())
+    }
+
     #[test]
     fn cli_backup() -> Result<()> {
         let store_dir = initialized_cli_store(
@@ -2212,30 +2172,103 @@ mod tests {
             .arg("nt")
             .write_stdin("CONSTRUCT { ?s ?p ?o } WHERE { ?s ?p ?o }")
             .assert()
-            .stdout("<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n")
+            .stdout("<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n")
+            .success();
+        Ok(())
+    }
+
+    #[test]
+    fn cli_select_query_file() -> Result<()> {
+        let store_dir = initialized_cli_store(
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+        )?;
+        let input_file = NamedTempFile::new("input.rq")?;
+        input_file.write_str("SELECT ?s WHERE { ?s ?p ?o }")?;
+        let output_file = NamedTempFile::new("output.tsv")?;
+        cli_command()
+            .arg("query")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--query-file")
+            .arg(input_file.path())
+            .arg("--results-file")
+            .arg(output_file.path())
+            .assert()
+            .success();
+        output_file.assert("?s\n<http://example.com/s>\n");
+        Ok(())
+    }
+
+    #[test]
+    fn cli_select_query_table() -> Result<()> {
+        let store_dir = initialized_cli_store(
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+        )?;
+        cli_command()
+            .arg("query")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--query")
+            .arg("SELECT ?s ?o ?u WHERE { ?s ?p ?o }")
+            .arg("--results-format")
+            .arg("table")
+            .assert()
+            .stdout("+------------------------+------------------------+----+\n| ?s                     | ?o                     | ?u |\n+------------------------+------------------------+----+\n| <http://example.com/s> | <http://example.com/o> |    |\n+------------------------+------------------------+----+\n1 row\n")
+            .success();
+        Ok(())
+    }
+
+    #[test]
+    fn cli_repl() {
+        cli_command()
+            .arg("repl")
+            .write_stdin("INSERT DATA { <http://example.com/s> <http://example.com/p> \"o\" ;\n  <http://example.com/p2> <http://example.com/o2> } ;\nSELECT ?o WHERE {\n  <http://example.com/s> ?p ?o\n} ORDER BY ?o ;\n\\format csv\nASK { ?s ?p \"o\" };\n\\foo\nSELECT ?s WHERE { ?s ?p }\n")
+            .assert()
+            .stdout("Update done\n+-------------------------+\n| ?o                      |\n+-------------------------+\n| <http://example.com/o2> |\n| \"o\"                     |\n+-------------------------+\n2 rows\nResults format is SPARQL Results in CSV\ntrue\n")
+            .stderr(
+                predicate::str::contains("Unknown command \\foo")
+                    .and(predicate::str::contains("Not a valid SPARQL query")),
+            )
+            .success();
+    }
+
+    #[test]
+    fn cli_repl_read_only_update() -> Result<()> {
+        let store_dir = initialized_cli_store(
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+        )?;
+        cli_command()
+            .arg("repl")
+            .arg("--location")
+            .arg(store_dir.path())
+            .arg("--read-only")
+            .write_stdin(
+                "DELETE WHERE { ?s ?p ?o } ;\nSELECT (COUNT(*) AS ?c) WHERE { ?s ?p ?o } ;\n",
+            )
+            .assert()
+            .stdout(predicate::str::contains(
+                "| \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> |",
+            ))
+            .stderr(predicate::str::contains("Updates are not allowed"))
             .success();
         Ok(())
     }
 
     #[test]
-    fn cli_select_query_file() -> Result<()> {
+    fn cli_stats() -> Result<()> {
         let store_dir = initialized_cli_store(
-            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\nGRAPH <http://example.com/g> { <http://example.com/s> <http://example.com/p> <http://example.com/o2> }",
         )?;
-        let input_file = NamedTempFile::new("input.rq")?;
-        input_file.write_str("SELECT ?s WHERE { ?s ?p ?o }")?;
-        let output_file = NamedTempFile::new("output.tsv")?;
         cli_command()
-            .arg("query")
+            .arg("stats")
             .arg("--location")
             .arg(store_dir.path())
-            .arg("--query-file")
-            .arg(input_file.path())
-            .arg("--results-file")
-            .arg(output_file.path())
             .assert()
+            .stdout(
+                predicate::str::starts_with("Quads: 2\nNamed graphs: 1\nDistinct subjects: ~1\nDistinct predicates: ~1\nDistinct objects: ~2\nQuads per graph:\n  DEFAULT: 1\n  <http://example.com/g>: 1\n")
+                    .and(predicate::str::contains("  spog: ")),
+            )
             .success();
-        output_file.assert("?s\n<http://example.com/s>\n");
         Ok(())
     }
 
@@ -2388,826 +2421,172 @@ mod tests {
     }
 
     #[test]
-    fn cli_convert_with_context() -> Result<()> {
-        let context_file = NamedTempFile::new("context.jsonld")?;
-        context_file.write_str("{\"@context\":{\"@vocab\":\"http://schema.org/\"}}")?;
+    fn cli_convert_with_prefix() {
         cli_command()
             .arg("convert")
             .arg("--from-format")
-            .arg("jsonld")
-            .arg("--to-format")
             .arg("nt")
-            .write_stdin(format!(
-                "{{\"@context\":\"{}\",\"@id\":\"http://example.com\",\"name\":\"example\"}}",
-                Url::from_file_path(context_file.path())
-                    .map_err(|()| anyhow!("Invalid context file path"))?
-            ))
+            .arg("--to-format")
+            .arg("ttl")
+            .arg("--prefix")
+            .arg("ex=http://example.com/")
+            .arg("--prefix")
+            .arg("schema=http://schema.org/")
+            .write_stdin("<http://example.com/s> <http://schema.org/p> <http://example.com/o> .")
             .assert()
-            .stdout("<http://example.com> <http://schema.org/name> \"example\" .\n");
-        Ok(())
-    }
-
-    #[test]
-    fn get_ui() -> Result<()> {
-        ServerTest::new()?.test_status(
-            Request::builder().uri("http://localhost/").body(())?,
-            StatusCode::OK,
-        )
-    }
-
-    #[test]
-    fn post_dataset_file() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store")
-            .header(CONTENT_TYPE, "application/trig")
-            .body("<http://example.com> <http://example.com> <http://example.com> .")?;
-        ServerTest::new()?.test_status(request, StatusCode::NO_CONTENT)
-    }
-
-    #[test]
-    fn post_wrong_file() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store")
-            .header(CONTENT_TYPE, "application/trig")
-            .body("<http://example.com>")?;
-        ServerTest::new()?.test_status(request, StatusCode::BAD_REQUEST)
-    }
-
-    #[test]
-    fn post_unsupported_file() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store")
-            .header(CONTENT_TYPE, "text/foo")
-            .body(())?;
-        ServerTest::new()?.test_status(request, StatusCode::UNSUPPORTED_MEDIA_TYPE)
-    }
-
-    #[test]
-    fn get_query() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store")
-            .header(CONTENT_TYPE, "application/trig")
-            .body("<http://example.com> <http://example.com> <http://example.com> .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "text/csv")
-            .body(())?;
-        server.test_body(
-            request,
-            "s,p,o\r\nhttp://example.com,http://example.com,http://example.com\r\n",
-        )
-    }
-
-    #[test]
-    fn get_query_accept_star() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "*/*")
-            .body(())?;
-        ServerTest::new()?.test_body(
-            request,
-            r#"{"head":{"vars":["s","p","o"]},"results":{"bindings":[]}}"#,
-        )
-    }
-
-    #[test]
-    fn get_query_accept_substar() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "text/*")
-            .body(())?;
-        ServerTest::new()?.test_body(
-            request,
-            r#"{"head":{"vars":["s","p","o"]},"results":{"bindings":[]}}"#,
-        )
-    }
-
-    #[test]
-    fn get_query_accept_good() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "application/sparql-results+json;charset=utf-8")
-            .body(())?;
-        ServerTest::new()?.test_body(
-            request,
-            r#"{"head":{"vars":["s","p","o"]},"results":{"bindings":[]}}"#,
-        )
+            .stdout("@prefix ex: <http://example.com/> .\n@prefix schema: <http://schema.org/> .\nex:s schema:p ex:o .\n")
+            .success();
     }
 
     #[test]
-    fn get_query_accept_bad() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "application/foo")
-            .body(())?;
-        ServerTest::new()?.test_status(request, StatusCode::NOT_ACCEPTABLE)
+    fn cli_convert_with_invalid_prefix() {
+        cli_command()
+            .arg("convert")
+            .arg("--from-format")
+            .arg("nt")
+            .arg("--to-format")
+            .arg("ttl")
+            .arg("--prefix")
+            .arg("ex")
+            .write_stdin("")
+            .assert()
+            .failure();
     }
 
     #[test]
-    fn get_query_accept_explicit_priority() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "text/foo;q=0.5 , text/json ; q = 0.7")
-            .body(())?;
-        ServerTest::new()?.test_body(
-            request,
-            r#"{"head":{"vars":["s","p","o"]},"results":{"bindings":[]}}"#,
-        )
+    fn cli_convert_merge_graphs() {
+        cli_command()
+            .arg("convert")
+            .arg("--from-format")
+            .arg("trig")
+            .arg("--to-format")
+            .arg("nt")
+            .arg("--merge-graphs")
+            .write_stdin("@base <http://example.com/> . <s> <p> <o> . <g> { <sg> <pg> <og> . }")
+            .assert()
+            .stdout("<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<http://example.com/sg> <http://example.com/pg> <http://example.com/og> .\n")
+            .success();
     }
 
     #[test]
-    fn get_query_accept_implicit_priority() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
-            )
-            .header(ACCEPT, "text/json,text/foo")
-            .body(())?;
-        ServerTest::new()?.test_body(
-            request,
-            r#"{"head":{"vars":["s","p","o"]},"results":{"bindings":[]}}"#,
-        )
-    }
-    #[test]
-    fn get_query_accept_implicit_and_explicit_priority() -> Result<()> {
-        let request = Request::builder()
-            .uri(
-                "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}",
+    fn cli_convert_syntax_error() -> Result<()> {
+        let input_file = NamedTempFile::new("input.nt")?;
+        input_file.write_str("<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<http://example.com/s> <http://example.com/p> .\n")?;
+        cli_command()
+            .arg("convert")
+            .arg("--from-file")
+            .arg(input_file.path())
+            .arg("--to-format")
+            .arg("nq")
+            .assert()
+            .stderr(
+                predicate::str::contains(format!(
+                    "Parsing error on file {}",
+                    input_file.path().display()
+                ))
+                .and(predicate::str::contains("line 2")),
             )
-            .header(ACCEPT, "text/foo;q=0.9,text/csv")
-            .body(())?;
-        ServerTest::new()?.test_body(request, "s,p,o\r\n")
-    }
-
-    #[test]
-    fn get_bad_query() -> Result<()> {
-        ServerTest::new()?.test_status(
-            Request::builder()
-                .uri("http://localhost/query?query=SELECT")
-                .body(())?,
-            StatusCode::BAD_REQUEST,
-        )
-    }
-
-    #[test]
-    fn get_query_union_graph() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/1")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("<http://example.com> <http://example.com> <http://example.com> .")?;
-        server.test_status(request, StatusCode::CREATED)?;
-
-        let request =Request::builder().uri(
-            "http://localhost/query?query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}&union-default-graph"
-        ).header(ACCEPT, "text/csv")
-            .body(())?;
-        server.test_body(
-            request,
-            "s,p,o\r\nhttp://example.com,http://example.com,http://example.com\r\n",
-        )
-    }
-
-    #[test]
-    fn get_query_union_graph_in_url_and_urlencoded() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/1")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("<http://example.com> <http://example.com> <http://example.com> .")?;
-        server.test_status(request, StatusCode::CREATED)?;
-
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/query?union-default-graph")
-            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
-            .header(ACCEPT, "text/csv")
-            .body("query=SELECT%20?s%20?p%20?o%20WHERE%20{%20?s%20?p%20?o%20}")?;
-        server.test_body(
-            request,
-            "s,p,o\r\nhttp://example.com,http://example.com,http://example.com\r\n",
-        )
-    }
-
-    #[test]
-    fn get_query_union_graph_and_default_graph() -> Result<()> {
-        ServerTest::new()?.test_status(Request::builder().uri(
-            "http://localhost/query?query=SELECT%20*%20WHERE%20{%20?s%20?p%20?o%20}&union-default-graph&default-graph-uri=http://example.com",
-        ).body(())?, StatusCode::BAD_REQUEST)
-    }
-
-    #[test]
-    fn get_query_description() -> Result<()> {
-        ServerTest::new()?.test_status(
-            Request::builder().uri("http://localhost/query").body(())?,
-            StatusCode::OK,
-        )
-    }
-
-    #[test]
-    fn post_query() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/query")
-            .header(CONTENT_TYPE, "application/sparql-query")
-            .body("SELECT * WHERE { ?s ?p ?o }")?;
-        ServerTest::new()?.test_status(request, StatusCode::OK)
-    }
-
-    #[test]
-    fn post_bad_query() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/query")
-            .header(CONTENT_TYPE, "application/sparql-query")
-            .body("SELECT")?;
-        ServerTest::new()?.test_status(request, StatusCode::BAD_REQUEST)
-    }
-
-    #[test]
-    fn post_unknown_query() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/query")
-            .header(CONTENT_TYPE, "application/sparql-todo")
-            .body("SELECT")?;
-        ServerTest::new()?.test_status(request, StatusCode::UNSUPPORTED_MEDIA_TYPE)
-    }
-
-    #[test]
-    fn post_federated_query_wikidata() -> Result<()> {
-        let request = Request::builder().method(Method::POST).uri("http://localhost/query")
-            .header(CONTENT_TYPE, "application/sparql-query")
-            .body("SELECT * WHERE { SERVICE <https://query.wikidata.org/sparql> { <https://en.wikipedia.org/wiki/Paris> ?p ?o } }")?;
-        ServerTest::new()?.test_status(request, StatusCode::OK)
-    }
-
-    #[test]
-    fn post_federated_query_dbpedia() -> Result<()> {
-        let request = Request::builder().method(Method::POST).uri("http://localhost/query")
-            .header(CONTENT_TYPE, "application/sparql-query")
-            .body("SELECT * WHERE { SERVICE <https://dbpedia.org/sparql> { <http://dbpedia.org/resource/Paris> ?p ?o } }")?;
-        ServerTest::new()?.test_status(request, StatusCode::OK)
-    }
-
-    #[test]
-    fn get_update_description() -> Result<()> {
-        ServerTest::new()?.test_status(
-            Request::builder().uri("http://localhost/update").body(())?,
-            StatusCode::OK,
-        )
-    }
-
-    #[test]
-    fn post_update() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/update")
-            .header(CONTENT_TYPE, "application/sparql-update")
-            .body(
-                "INSERT DATA { <http://example.com> <http://example.com> <http://example.com> }",
-            )?;
-        ServerTest::new()?.test_status(request, StatusCode::NO_CONTENT)
-    }
-
-    #[test]
-    fn post_bad_update() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/update")
-            .header(CONTENT_TYPE, "application/sparql-update")
-            .body("INSERT")?;
-        ServerTest::new()?.test_status(request, StatusCode::BAD_REQUEST)
-    }
-
-    #[test]
-    fn post_update_read_only() -> Result<()> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/update")
-            .header(CONTENT_TYPE, "application/sparql-update")
-            .body(
-                "INSERT DATA { <http://example.com> <http://example.com> <http://example.com> }",
-            )?;
-        ServerTest::check_status(
-            ServerTest::new()?.exec_read_only(request),
-            StatusCode::FORBIDDEN,
-        )
-    }
-
-    #[test]
-    fn graph_store_url_normalization() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        // PUT
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("<http://example.com> <http://example.com> <http://example.com> .")?;
-        server.test_status(request, StatusCode::CREATED)?;
-
-        // GET good URI
-        server.test_status(
-            Request::builder()
-                .uri("http://localhost/store?graph=http://example.com")
-                .body(())?,
-            StatusCode::OK,
-        )?;
-
-        // GET bad URI
-        server.test_status(
-            Request::builder()
-                .uri("http://localhost/store?graph=http://example.com/")
-                .body(())?,
-            StatusCode::NOT_FOUND,
-        )
+            .failure();
+        Ok(())
     }
 
     #[test]
-    fn graph_store_base_url() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        // POST
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("<> <http://example.com/p> <http://example.com/o1> .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(ACCEPT, "application/n-triples")
-            .body(())?;
-        server.test_body(
-            request,
-            "<http://example.com> <http://example.com/p> <http://example.com/o1> .\n",
-        )?;
-
-        // PUT
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("<> <http://example.com/p> <http://example.com/o2> .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(ACCEPT, "application/n-triples")
-            .body(())?;
-        server.test_body(
-            request,
-            "<http://example.com> <http://example.com/p> <http://example.com/o2> .\n",
-        )
+    fn cli_convert_with_context() -> Result<()> {
+        let context_file = NamedTempFile::new("context.jsonld")?;
+        context_file.write_str("{\"@context\":{\"@vocab\":\"http://schema.org/\"}}")?;
+        cli_command()
+            .arg("convert")
+            .arg("--from-format")
+            .arg("jsonld")
+            .arg("--to-format")
+            .arg("nt")
+            .write_stdin(format!(
+                "{{\"@context\":\"{}\",\"@id\":\"http://example.com\",\"name\":\"example\"}}",
+                Url::from_file_path(context_file.path())
+                    .map_err(|()| anyhow!("Invalid context file path"))?
+            ))
+            .assert()
+            .stdout("<http://example.com> <http://schema.org/name> \"example\" .\n");
+        Ok(())
     }
 
     #[test]
-    fn graph_store_protocol() -> Result<()> {
-        // Tests from https://www.w3.org/2009/sparql/docs/tests/data-sparql11/http-rdf-update/
-
-        let server = ServerTest::new()?;
-
-        // PUT - Initial state
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/1.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(
-                r#"
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-<http://$HOST$/$GRAPHSTORE$/person/1> a foaf:Person;
-    foaf:businessCard [
-        a v:VCard;
-        v:fn "John Doe"
-    ].
-"#,
-            )?;
-        server.test_status(request, StatusCode::CREATED)?;
-
-        // GET of PUT - Initial state
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=/store/person/1.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // HEAD on an existing graph
-        server.test_status(
-            Request::builder()
-                .method(Method::HEAD)
-                .uri("http://localhost/store/person/1.ttl")
-                .body(())?,
-            StatusCode::OK,
-        )?;
-
-        // HEAD on a non-existing graph
-        server.test_status(
-            Request::builder()
-                .method(Method::HEAD)
-                .uri("http://localhost/store/person/4.ttl")
-                .body(())?,
-            StatusCode::NOT_FOUND,
-        )?;
-
-        // PUT - graph already in store
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/1.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(
-                r#"
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-<http://$HOST$/$GRAPHSTORE$/person/1> a foaf:Person;
-    foaf:businessCard [
-        a v:VCard;
-        v:fn "Jane Doe"
-    ].
-"#,
-            )?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of PUT - graph already in store
-        let request = Request::builder()
-            .uri("http://localhost/store/person/1.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // PUT - default graph
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store?default")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(
-                r#"
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-[]  a foaf:Person;
-    foaf:businessCard [
-        a v:VCard;
-        v:given-name "Alice"
-    ] .
-"#,
-            )?;
-        server.test_status(request, StatusCode::NO_CONTENT)?; // The default graph always exists in Oxigraph
-
-        // GET of PUT - default graph
-        let request = Request::builder()
-            .uri("http://localhost/store?default")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // PUT - mismatched payload
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/1.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body("@prefix foo")?;
-        server.test_status(request, StatusCode::BAD_REQUEST)?;
-
-        // PUT - empty graph
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/2.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(())?;
-        server.test_status(request, StatusCode::CREATED)?;
-
-        // GET of PUT - empty graph
-        let request = Request::builder()
-            .uri("http://localhost/store/person/2.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // PUT - replace empty graph
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/2.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(
-                r#"
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-[]  a foaf:Person;
-    foaf:businessCard [
-        a v:VCard;
-        v:given-name "Alice"
-    ] .
-"#,
-            )?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of replacement for empty graph
-        let request = Request::builder()
-            .uri("http://localhost/store/person/2.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // DELETE - existing graph
-        server.test_status(
-            Request::builder()
-                .method(Method::DELETE)
-                .uri("http://localhost/store/person/2.ttl")
-                .body(())?,
-            StatusCode::NO_CONTENT,
-        )?;
-
-        // GET of DELETE - existing graph
-        server.test_status(
-            Request::builder()
-                .uri("http://localhost/store/person/2.ttl")
-                .body(())?,
-            StatusCode::NOT_FOUND,
+    fn cli_sign_and_verify() -> Result<()> {
+        let seed = [7; 32];
+        let key_file = NamedTempFile::new("key.der")?;
+        // PKCS#8 v1 document wrapping the seed
+        key_file.write_binary(
+            &[&hex::decode("302e020100300506032b657004220420")?, &seed[..]].concat(),
         )?;
-
-        // DELETE - non-existent graph
-        server.test_status(
-            Request::builder()
-                .method(Method::DELETE)
-                .uri("http://localhost/store/person/2.ttl")
-                .body(())?,
-            StatusCode::NOT_FOUND,
+        let public_key_file = NamedTempFile::new("public.der")?;
+        public_key_file.write_binary(Ed25519Signer::from_seed(&seed)?.public_key())?;
+        let input_file = NamedTempFile::new("input.trig")?;
+        input_file.write_str(
+            "@base <http://example.com/> . _:foo <p> \"Foo\" . <g> { <s> <p> _:foo . }",
         )?;
+        let signed_file = NamedTempFile::new("signed.nq")?;
+        cli_command()
+            .arg("sign")
+            .arg("--from-file")
+            .arg(input_file.path())
+            .arg("--key")
+            .arg(key_file.path())
+            .arg("--to-file")
+            .arg(signed_file.path())
+            .assert()
+            .success();
+        cli_command()
+            .arg("verify")
+            .arg("--file")
+            .arg(signed_file.path())
+            .arg("--public-key")
+            .arg(public_key_file.path())
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("The signature is valid"));
 
-        // POST - existing graph
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/1.ttl")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(())?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // TODO: POST - multipart/form-data
-        // TODO: GET of POST - multipart/form-data
-
-        // POST - create new graph
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(
-                r#"
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-[]  a foaf:Person;
-    foaf:businessCard [
-        a v:VCard;
-        v:given-name "Alice"
-    ] .
-"#,
-            )?;
-        let response = server.exec(request);
-        assert_eq!(response.status(), StatusCode::CREATED);
-        let location = response.headers().get(LOCATION).unwrap().to_str()?;
-
-        // GET of POST - create new graph
-        let request = Request::builder()
-            .uri(location)
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // POST - empty graph to existing graph
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri(location)
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(())?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of POST - after noop
-        let request = Request::builder()
-            .uri(location)
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)
-    }
-
-    #[test]
-    fn graph_store_lenient_bulk() -> Result<()> {
-        let server = ServerTest::new()?;
-        let invalid_data = "
-@prefix foaf: <http://xmlns.com/foaf/0.1/> .
-@prefix v: <http://www.w3.org/2006/vcard/ns#> .
-
-<http://$HOST$/$GRAPHSTORE$/person/1> a foaf:Person . foo";
-
-        // POST
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store/person/1.ttl?no_transaction&lenient")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(invalid_data)?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of POST
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=/store/person/1.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // POST dataset
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store?lenient&no_transaction")
-            .header(CONTENT_TYPE, "application/trig; charset=utf-8")
-            .body(invalid_data)?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of POST dataset
-        let request = Request::builder()
-            .uri("http://localhost/store?default")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // PUT
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store/person/1.ttl?lenient&no_transaction")
-            .header(CONTENT_TYPE, "text/turtle; charset=utf-8")
-            .body(invalid_data)?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of PUT - Initial state
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=/store/person/1.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // PUT dataset
-        let request = Request::builder()
-            .method(Method::PUT)
-            .uri("http://localhost/store?lenient&no_transaction")
-            .header(CONTENT_TYPE, "application/trig; charset=utf-8")
-            .body(invalid_data)?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET of PUT dataset
-        let request = Request::builder()
-            .uri("http://localhost/store?default")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::OK)?;
-
-        // GET of PUT dataset - replacement
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=/store/person/1.ttl")
-            .header(ACCEPT, "text/turtle")
-            .body(())?;
-        server.test_status(request, StatusCode::NOT_FOUND)
+        let signed = fs::read_to_string(signed_file.path())?;
+        cli_command()
+            .arg("verify")
+            .arg("--format")
+            .arg("nq")
+            .arg("--public-key")
+            .arg(public_key_file.path())
+            .write_stdin(signed.replace("_:foo", "_:bar"))
+            .assert()
+            .success();
+        cli_command()
+            .arg("verify")
+            .arg("--format")
+            .arg("nq")
+            .arg("--public-key")
+            .arg(public_key_file.path())
+            .write_stdin(signed.replace("\"Foo\"", "\"Fop\""))
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "The signature does not match the dataset",
+            ));
+        Ok(())
     }
 
     #[test]
-    fn lenient_load() -> Result<()> {
-        let server = ServerTest::new()?;
-
-        // POST
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store?lenient&graph=http://example.com")
-            .header(CONTENT_TYPE, "text/turtle")
-            .body("< s> < p> \"\\uD83D\\uDC68\" .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(ACCEPT, "application/n-triples")
-            .body(())?;
-        server.test_body(
-            request,
-            "<http://example.com/ s> <http://example.com/ p> \"\u{1f468}\" .\n",
-        )?;
-
-        // PUT
-        let request = Request::builder().method(Method::PUT).uri(
-            "http://localhost/store?lenient&graph=http://example.com",
-        )
-        .header(CONTENT_TYPE, "text/turtle")
-        .body("< s> < p> \"\\uD83D\\uDC68\\u200D\\uD83D\\uDC69\\u200D\\uD83D\\uDC67\\u200D\\uD83D\\uDC67\" .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET
-        let request = Request::builder()
-            .uri("http://localhost/store?graph=http://example.com")
-            .header(ACCEPT, "application/n-triples")
-            .body(())?;
-        server.test_body(
-            request,
-            "<http://example.com/ s> <http://example.com/ p> \"\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}\" .\n",
-        )?;
-
-        // POST dataset
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("http://localhost/store?lenient")
-            .header(CONTENT_TYPE, "application/trig")
-            .body("<s> <p> \"\"@abcdefghijklmn .")?;
-        server.test_status(request, StatusCode::NO_CONTENT)?;
-
-        // GET
-        let request = Request::builder()
-            .uri("http://localhost/store")
-            .header(ACCEPT, "application/n-quads")
-            .body(())?;
-        server.test_body(request, "<s> <p> \"\"@abcdefghijklmn .\n<http://example.com/ s> <http://example.com/ p> \"\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}\" <http://example.com> .\n")
-    }
-
-    struct ServerTest {
-        store: Store,
-    }
-
-    impl ServerTest {
-        fn new() -> Result<Self> {
-            Ok(Self {
-                store: Store::new()?,
-            })
-        }
-
-        fn exec(&self, request: Request<impl Into<Body>>) -> Response<Body> {
-            handle_request(
-                &mut request.map(Into::into),
-                self.store.clone(),
-                false,
-                false,
-                None,
-            )
-            .unwrap_or_else(|(status, message)| error(status, message))
-        }
-
-        fn exec_read_only(&self, request: Request<impl Into<Body>>) -> Response<Body> {
-            handle_request(
-                &mut request.map(Into::into),
-                self.store.clone(),
-                true,
-                false,
-                None,
-            )
-            .unwrap_or_else(|(status, message)| error(status, message))
-        }
-
-        fn test_status(
-            &self,
-            request: Request<impl Into<Body>>,
-            expected_status: StatusCode,
-        ) -> Result<()> {
-            Self::check_status(self.exec(request), expected_status)
-        }
-
-        fn check_status(mut response: Response<Body>, expected_status: StatusCode) -> Result<()> {
-            let body = read_to_string(response.body_mut())?;
-            assert_eq!(response.status(), expected_status, "Error message: {body}");
-            Ok(())
-        }
-
-        fn test_body(&self, request: Request<impl Into<Body>>, expected_body: &str) -> Result<()> {
-            let mut response = self.exec(request);
-            let body = read_to_string(response.body_mut())?;
-            assert_eq!(response.status(), StatusCode::OK, "Error message: {body}");
-            assert_eq!(&body, expected_body);
-            Ok(())
-        }
+    fn cli_sign_to_triple_format() -> Result<()> {
+        let key_file = NamedTempFile::new("key.der")?;
+        key_file.write_binary(&hex::decode(
+            "302e020100300506032b6570042204200707070707070707070707070707070707070707070707070707070707070707",
+        )?)?;
+        cli_command()
+            .arg("sign")
+            .arg("--from-format")
+            .arg("ttl")
+            .arg("--key")
+            .arg(key_file.path())
+            .write_stdin("<http://example.com/s> <http://example.com/p> <http://example.com/o> .")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("does not support named graphs"));
+        Ok(())
     }
 
     #[test]
@@ -3216,44 +2595,4 @@ mod tests {
 
         Args::command().debug_assert()
     }
-
-    #[test]
-    fn test_request_original_target_url() {
-        assert_eq!(
-            request_original_target_url(
-                &Request::builder()
-                    .uri("http://example.com/foo")
-                    .body(())
-                    .unwrap()
-            )
-            .unwrap()
-            .to_string(),
-            "http://example.com/foo"
-        );
-        assert_eq!(
-            request_original_target_url(
-                &Request::builder()
-                    .uri("http://example.com/foo")
-                    .header("X-Forwarded-Proto", "https")
-                    .header("X-Forwarded-Host", "example.org")
-                    .body(())
-                    .unwrap()
-            )
-            .unwrap()
-            .to_string(),
-            "https://example.org/foo"
-        );
-        assert_eq!(
-            request_original_target_url(
-                &Request::builder()
-                    .uri("http://example.com/foo")
-                    .header("Forwarded", "by=foo ; proto = https ; host = example.org")
-                    .body(())
-                    .unwrap()
-            )
-            .unwrap()
-            .to_string(),
-            "https://example.org/foo"
-        );
-    }
 }
@@ -0,0 +1,207 @@
+use oxhttp::model::{Body, Method, Request, Response};
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::encoding::text::encode;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
+use prometheus_client::registry::Registry;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+pub const METRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// The Prometheus metrics of the HTTP server, exposed on the `/metrics` endpoint
+///
+/// Cloning is cheap, all clones are updating the same metrics.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Arc<Registry>,
+    requests: Family<RequestLabels, Counter>,
+    query_duration: Histogram,
+    update_duration: Histogram,
+    result_rows: Counter,
+    store_quads: Gauge,
+    active_transactions: Gauge,
+    bulk_loaded_quads: Counter,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RequestLabels {
+    endpoint: &'static str,
+    status: u16,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::with_registry(Registry::default())
+    }
+
+    /// Registers the server metrics with the `oxigraph` prefix in an existing registry
+    ///
+    /// The metrics already in the registry are exposed on the `/metrics` endpoint alongside the server ones.
+    pub fn with_registry(mut registry: Registry) -> Self {
+        let requests = Family::<RequestLabels, Counter>::default();
+        let query_duration = Histogram::new(exponential_buckets(0.001, 2., 16));
+        let update_duration = Histogram::new(exponential_buckets(0.001, 2., 16));
+        let result_rows = Counter::default();
+        let store_quads = Gauge::default();
+        let active_transactions = Gauge::default();
+        let bulk_loaded_quads = Counter::default();
+        let sub_registry = registry.sub_registry_with_prefix("oxigraph");
+        sub_registry.register(
+            "http_requests",
+            "Number of HTTP requests by endpoint and response status code",
+            requests.clone(),
+        );
+        sub_registry.register(
+            "query_duration_seconds",
+            "Duration of the SPARQL query evaluations, including the results streaming",
+            query_duration.clone(),
+        );
+        sub_registry.register(
+            "update_duration_seconds",
+            "Duration of the SPARQL update evaluations",
+            update_duration.clone(),
+        );
+        sub_registry.register(
+            "query_result_rows",
+            "Number of solutions and triples streamed as SPARQL query results",
+            result_rows.clone(),
+        );
+        sub_registry.register(
+            "store_quads",
+            "Number of quads in the store, refreshed periodically and possibly approximate",
+            store_quads.clone(),
+        );
+        sub_registry.register(
+            "active_transactions",
+            "Number of SPARQL updates and graph store writes currently running",
+            active_transactions.clone(),
+        );
+        sub_registry.register(
+            "bulk_loaded_quads",
+            "Number of quads loaded using the bulk loader",
+            bulk_loaded_quads.clone(),
+        );
+        Self {
+            registry: Arc::new(registry),
+            requests,
+            query_duration,
+            update_duration,
+            result_rows,
+            store_quads,
+            active_transactions,
+            bulk_loaded_quads,
+        }
+    }
+
+    /// Encodes all the metrics of the registry using the OpenMetrics text format
+    pub fn encode(&self) -> Result<String, fmt::Error> {
+        let mut buffer = String::new();
+        encode(&mut buffer, &self.registry)?;
+        Ok(buffer)
+    }
+
+    /// Starts a timer recording the query duration when dropped
+    pub fn query_timer(&self) -> DurationTimer {
+        DurationTimer::new(self.query_duration.clone())
+    }
+
+    /// Starts a timer recording the update duration when dropped
+    pub fn update_timer(&self) -> DurationTimer {
+        DurationTimer::new(self.update_duration.clone())
+    }
+
+    pub fn result_rows(&self) -> Counter {
+        self.result_rows.clone()
+    }
+
+    pub fn set_store_quads(&self, quads: usize) {
+        self.store_quads
+            .set(i64::try_from(quads).unwrap_or(i64::MAX));
+    }
+
+    /// Returns a callback for [`BulkLoader::on_progress`](oxigraph::store::BulkLoader::on_progress)
+    pub fn bulk_load_progress(&self) -> impl Fn(u64) + Send + Sync + 'static {
+        let bulk_loaded_quads = self.bulk_loaded_quads.clone();
+        let previously_loaded = AtomicU64::new(0);
+        move |loaded| {
+            // The bulk loader gives the total number of loaded quads, we only add the new ones
+            bulk_loaded_quads
+                .inc_by(loaded.saturating_sub(previously_loaded.swap(loaded, Ordering::Relaxed)));
+        }
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Records the duration between its creation and its drop into a histogram
+pub struct DurationTimer {
+    histogram: Histogram,
+    start: Instant,
+}
+
+impl DurationTimer {
+    fn new(histogram: Histogram) -> Self {
+        Self {
+            histogram,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for DurationTimer {
+    fn drop(&mut self) {
+        self.histogram.observe(self.start.elapsed().as_secs_f64());
+    }
+}
+
+/// Counts the requests and the write operations in progress
+pub fn metrics_middleware(
+    metrics: Metrics,
+    on_request: impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static,
+) -> impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static {
+    move |request| {
+        let endpoint = endpoint_label(request.uri().path());
+        let is_write = matches!(
+            (endpoint, request.method()),
+            ("/update", &Method::POST) | ("/store", &Method::PUT | &Method::POST | &Method::DELETE)
+        );
+        if is_write {
+            metrics.active_transactions.inc();
+        }
+        let response = on_request(request);
+        if is_write {
+            metrics.active_transactions.dec();
+        }
+        metrics
+            .requests
+            .get_or_create(&RequestLabels {
+                endpoint,
+                status: response.status().as_u16(),
+            })
+            .inc();
+        response
+    }
+}
+
+/// Maps the request path to a small set of values to keep the number of time series bounded
+fn endpoint_label(path: &str) -> &'static str {
+    match path {
+        "/" => "/",
+        "/query" => "/query",
+        "/update" => "/update",
+        "/namespaces" => "/namespaces",
+        "/status" => "/status",
+        "/metrics" => "/metrics",
+        _ if path.starts_with("/store") => "/store",
+        _ => "other",
+    }
+}
@@ -0,0 +1,408 @@
+//! Stable pagination of the SPARQL query results with continuation tokens
+//!
+//! The pages of a `SELECT` query ending with `ORDER BY` and `LIMIT` get a continuation token
+//! made of a fingerprint of the query, the store version and the sort keys of the last solution of the page.
+//! The next page is requested by sending the same query again with the token in the `cursor` parameter.
+//! The query is then rewritten to only return the solutions sorted after these keys instead of using `OFFSET`,
+//! so the page boundaries do not depend on the number of solutions skipped.
+//!
+//! It is only correct if the sort keys identify the solutions and if each sort variable only has values of a single type
+//! that the SPARQL `<` and `>` operators order like `ORDER BY` does.
+//! The pages breaking these rules are returned without continuation token instead of silently skipping solutions in the next page:
+//! one more solution than the page length is evaluated to detect a tie with the last solution of the page.
+
+use crate::{HttpError, bad_request};
+use oxhttp::model::StatusCode;
+use oxigraph::model::vocab::{rdf, xsd};
+use oxigraph::model::{Literal, Term, Variable};
+use oxigraph::sparql::QuerySolution;
+use oxsdatatypes::{Boolean, Date, DateTime, Decimal, Double, Float, Integer, Time};
+use sha2::{Digest, Sha256};
+use spargebra::Query;
+use spargebra::algebra::{Expression, Function, GraphPattern, OrderExpression};
+use std::mem;
+use std::str::FromStr;
+
+pub const CONTINUATION_TOKEN_HEADER: &str = "oxigraph-continuation-token";
+
+/// A `SELECT` query ending with `ORDER BY` on projected variables followed by `LIMIT`
+///
+/// The pages get continuation tokens only if the sort keys identify the solutions and each of them only has values of a single type.
+pub struct PaginatedQuery {
+    fingerprint: String,
+    /// The sort variables and if they are sorted in descending order
+    keys: Vec<(Variable, bool)>,
+    length: usize,
+    /// The sort keys of the cursor applied to the query
+    cursor: Option<Vec<Option<Term>>>,
+}
+
+impl PaginatedQuery {
+    /// Returns `None` if the query cannot be paginated
+    ///
+    /// `fingerprint` should contain the query text and all the request parameters changing its results.
+    pub fn new(query: &Query, fingerprint: &[&str]) -> Option<Self> {
+        let Query::Select {
+            pattern:
+                GraphPattern::Slice {
+                    inner,
+                    length: Some(length),
+                    ..
+                },
+            ..
+        } = query
+        else {
+            return None;
+        };
+        let mut projection = None;
+        let mut pattern = &**inner;
+        let order = loop {
+            match pattern {
+                GraphPattern::Distinct { inner } | GraphPattern::Reduced { inner } => {
+                    pattern = inner
+                }
+                GraphPattern::Project { inner, variables } => {
+                    projection = Some(variables);
+                    pattern = inner
+                }
+                GraphPattern::OrderBy { expression, .. } => break expression,
+                _ => return None,
+            }
+        };
+        let projection = projection?;
+        let keys = order
+            .iter()
+            .map(|expression| {
+                let (expression, descending) = match expression {
+                    OrderExpression::Asc(expression) => (expression, false),
+                    OrderExpression::Desc(expression) => (expression, true),
+                };
+                let Expression::Variable(variable) = expression else {
+                    return None;
+                };
+                // The value of the key must be in the returned solutions
+                projection
+                    .contains(variable)
+                    .then(|| (variable.clone(), descending))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let mut hasher = Sha256::new();
+        for part in fingerprint {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        Some(Self {
+            fingerprint: hex::encode(&hasher.finalize()[..16]),
+            keys,
+            length: *length,
+            cursor: None,
+        })
+    }
+
+    /// Rewrites `query` to also return the solution following the page, used to detect ties with the last solution of the page
+    pub fn fetch_next_solution(&self, query: &mut Query) {
+        if let Query::Select {
+            pattern: GraphPattern::Slice { length, .. },
+            ..
+        } = query
+        {
+            *length = Some(self.length + 1);
+        }
+    }
+
+    /// Returns the token allowing to fetch the page following `page`
+    ///
+    /// `page` must be evaluated from a query rewritten with [`fetch_next_solution`](Self::fetch_next_solution),
+    /// the solution following the page is removed from it.
+    /// Returns `None` if there are no more solutions or if no cursor can safely select the following page:
+    /// if a sort variable has values of different types or of an unsupported type like a blank node,
+    /// or if the solution following the page has the same sort keys as its last solution.
+    pub fn continuation_token(
+        &self,
+        store_version: u64,
+        page: &mut Vec<QuerySolution>,
+    ) -> Option<String> {
+        if page.len() <= self.length {
+            return None;
+        }
+        page.truncate(self.length + 1);
+        let next = page.pop()?;
+        let last = page.last()?;
+        for (i, (variable, _)) in self.keys.iter().enumerate() {
+            let cursor_value = self.cursor.as_ref().and_then(|cursor| cursor[i].as_ref());
+            let mut first = None;
+            for value in cursor_value.into_iter().chain(
+                page.iter()
+                    .chain([&next])
+                    .filter_map(|solution| solution.get(variable)),
+            ) {
+                let value = key_value(value)?;
+                match &first {
+                    None => first = Some(value),
+                    Some(first) if !first.has_same_type(&value) => return None,
+                    Some(_) => (),
+                }
+            }
+        }
+        if self.keys.iter().all(
+            |(variable, _)| match (last.get(variable), next.get(variable)) {
+                (Some(last), Some(next)) => key_value(last) == key_value(next),
+                (None, None) => true,
+                _ => false,
+            },
+        ) {
+            return None;
+        }
+        let mut token = format!("{}\n{store_version}", self.fingerprint);
+        for (variable, _) in &self.keys {
+            token.push('\n');
+            match last.get(variable) {
+                Some(value @ (Term::NamedNode(_) | Term::Literal(_))) => {
+                    token.push_str(&value.to_string())
+                }
+                Some(_) => return None,
+                None => (),
+            }
+        }
+        Some(hex::encode(token))
+    }
+
+    /// Rewrites `query` to return the page following the one `cursor` has been issued for
+    ///
+    /// Fails with a 410 status if the cursor has been issued for a different query or if the store has been modified since.
+    pub fn apply_cursor(
+        &mut self,
+        query: &mut Query,
+        cursor: &str,
+        store_version: u64,
+    ) -> Result<(), HttpError> {
+        let cursor = hex::decode(cursor)
+            .ok()
+            .and_then(|cursor| String::from_utf8(cursor).ok())
+            .ok_or_else(|| bad_request("Invalid pagination cursor"))?;
+        let mut parts = cursor.split('\n');
+        if parts.next() != Some(self.fingerprint.as_str()) {
+            return Err(cursor_for_another_query());
+        }
+        if parts.next().and_then(|version| u64::from_str(version).ok()) != Some(store_version) {
+            return Err((
+                StatusCode::GONE,
+                "The store has been modified since the pagination cursor has been issued".into(),
+            ));
+        }
+        let values = parts
+            .map(|value| {
+                (!value.is_empty())
+                    .then(|| Term::from_str(value))
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| bad_request(format!("Invalid pagination cursor: {e}")))?;
+        if values.len() != self.keys.len() {
+            return Err(bad_request("Invalid pagination cursor"));
+        }
+        let mut filter = None;
+        for ((variable, descending), value) in self.keys.iter().zip(&values).rev() {
+            let after = sorted_after(variable, *descending, value.as_ref());
+            filter = Some(match filter {
+                None => after,
+                Some(next_keys_after) => Expression::Or(
+                    Box::new(after),
+                    Box::new(Expression::And(
+                        Box::new(same_value(variable, value.as_ref())),
+                        Box::new(next_keys_after),
+                    )),
+                ),
+            });
+        }
+        let Query::Select {
+            pattern: GraphPattern::Slice { inner, start, .. },
+            ..
+        } = query
+        else {
+            return Err(cursor_for_another_query());
+        };
+        // The filter replaces the offset
+        *start = 0;
+        let Some(ordered) = ordered_pattern_mut(inner) else {
+            return Err(cursor_for_another_query());
+        };
+        let unfiltered = mem::take(&mut **ordered);
+        **ordered = GraphPattern::Filter {
+            expr: filter.unwrap_or_else(|| Literal::from(true).into()),
+            inner: Box::new(unfiltered),
+        };
+        self.cursor = Some(values);
+        Ok(())
+    }
+}
+
+pub fn cursor_for_another_query() -> HttpError {
+    (
+        StatusCode::GONE,
+        "The pagination cursor has been issued for a different query".into(),
+    )
+}
+
+/// The pattern sorted by the `ORDER BY`
+fn ordered_pattern_mut(pattern: &mut GraphPattern) -> Option<&mut Box<GraphPattern>> {
+    match pattern {
+        GraphPattern::Distinct { inner }
+        | GraphPattern::Reduced { inner }
+        | GraphPattern::Project { inner, .. } => ordered_pattern_mut(inner),
+        GraphPattern::OrderBy { inner, .. } => Some(inner),
+        _ => None,
+    }
+}
+
+/// If the value of `variable` is sorted after `value`
+///
+/// Follows the `ORDER BY` ordering: unbound values, then blank nodes, then IRIs, then literals.
+/// The values that cannot be compared with `value` are kept, the page then gets no continuation token.
+fn sorted_after(variable: &Variable, descending: bool, value: Option<&Term>) -> Expression {
+    let var = Expression::Variable(variable.clone());
+    let call = |function, arg: &Expression| Expression::FunctionCall(function, vec![arg.clone()]);
+    let or = |a, b| Expression::Or(Box::new(a), Box::new(b));
+    let and = |a, b| Expression::And(Box::new(a), Box::new(b));
+    let unbound = Expression::Not(Box::new(Expression::Bound(variable.clone())));
+    match (value, descending) {
+        (None, false) => Expression::Bound(variable.clone()),
+        (None, true) => Literal::from(false).into(),
+        // IRIs cannot be compared with < and >, we compare their strings
+        (Some(Term::NamedNode(value)), false) => or(
+            call(Function::IsLiteral, &var),
+            and(
+                call(Function::IsIri, &var),
+                Expression::Greater(
+                    Box::new(call(Function::Str, &var)),
+                    Box::new(Literal::from(value.as_str()).into()),
+                ),
+            ),
+        ),
+        (Some(Term::NamedNode(value)), true) => or(
+            or(unbound, call(Function::IsBlank, &var)),
+            and(
+                call(Function::IsIri, &var),
+                Expression::Less(
+                    Box::new(call(Function::Str, &var)),
+                    Box::new(Literal::from(value.as_str()).into()),
+                ),
+            ),
+        ),
+        (Some(value), false) => Expression::Coalesce(vec![
+            Expression::Greater(Box::new(var), Box::new(term_expression(value))),
+            Expression::Bound(variable.clone()),
+        ]),
+        (Some(value), true) => or(
+            or(
+                unbound,
+                Expression::Not(Box::new(call(Function::IsLiteral, &var))),
+            ),
+            Expression::Coalesce(vec![
+                Expression::Less(Box::new(var), Box::new(term_expression(value))),
+                Literal::from(true).into(),
+            ]),
+        ),
+    }
+}
+
+/// If the value of `variable` is sorted like `value`
+fn same_value(variable: &Variable, value: Option<&Term>) -> Expression {
+    match value {
+        Some(Term::Literal(value)) => Expression::Coalesce(vec![
+            Expression::Equal(
+                Box::new(Expression::Variable(variable.clone())),
+                Box::new(value.clone().into()),
+            ),
+            Literal::from(false).into(),
+        ]),
+        Some(value) => Expression::SameTerm(
+            Box::new(Expression::Variable(variable.clone())),
+            Box::new(term_expression(value)),
+        ),
+        None => Expression::Not(Box::new(Expression::Bound(variable.clone()))),
+    }
+}
+
+fn term_expression(term: &Term) -> Expression {
+    match term {
+        Term::NamedNode(node) => node.clone().into(),
+        Term::Literal(literal) => literal.clone().into(),
+        // The cursors only contain IRIs and literals
+        _ => Literal::from(false).into(),
+    }
+}
+
+/// The value of a sort key, the values equal for `ORDER BY` are equal
+#[derive(PartialEq)]
+enum KeyValue<'a> {
+    BlankNode(&'a str),
+    NamedNode(&'a str),
+    String(&'a str),
+    LangString(&'a str, &'a str),
+    Boolean(Boolean),
+    Integer(Integer),
+    Decimal(Decimal),
+    Float(Float),
+    Double(Double),
+    DateTime(DateTime),
+    Date(Date),
+    Time(Time),
+}
+
+impl KeyValue<'_> {
+    /// If the SPARQL `<` and `>` operators order the two values like `ORDER BY`
+    fn has_same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::LangString(_, a), Self::LangString(_, b)) => a.eq_ignore_ascii_case(b),
+            // The values with and without timezone are not always comparable
+            (Self::DateTime(a), Self::DateTime(b)) => {
+                a.timezone_offset().is_some() == b.timezone_offset().is_some()
+            }
+            (Self::Date(a), Self::Date(b)) => {
+                a.timezone_offset().is_some() == b.timezone_offset().is_some()
+            }
+            (Self::Time(a), Self::Time(b)) => {
+                a.timezone_offset().is_some() == b.timezone_offset().is_some()
+            }
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+/// Returns `None` if the term cannot be used as a pagination sort key
+fn key_value(term: &Term) -> Option<KeyValue<'_>> {
+    let literal = match term {
+        Term::BlankNode(node) => return Some(KeyValue::BlankNode(node.as_str())),
+        Term::NamedNode(node) => return Some(KeyValue::NamedNode(node.as_str())),
+        Term::Literal(literal) => literal,
+        #[cfg(feature = "rdf-12")]
+        Term::Triple(_) => return None,
+    };
+    let value = literal.value();
+    let datatype = literal.datatype();
+    Some(if datatype == xsd::STRING {
+        KeyValue::String(value)
+    } else if datatype == rdf::LANG_STRING {
+        KeyValue::LangString(value, literal.language()?)
+    } else if datatype == xsd::BOOLEAN {
+        KeyValue::Boolean(value.parse().ok()?)
+    } else if datatype == xsd::INTEGER {
+        KeyValue::Integer(value.parse().ok()?)
+    } else if datatype == xsd::DECIMAL {
+        KeyValue::Decimal(value.parse().ok()?)
+    } else if datatype == xsd::FLOAT {
+        // NaN is not ordered by < and >
+        KeyValue::Float(value.parse().ok().filter(|v: &Float| !v.is_nan())?)
+    } else if datatype == xsd::DOUBLE {
+        KeyValue::Double(value.parse().ok().filter(|v: &Double| !v.is_nan())?)
+    } else if datatype == xsd::DATE_TIME {
+        KeyValue::DateTime(value.parse().ok()?)
+    } else if datatype == xsd::DATE {
+        KeyValue::Date(value.parse().ok()?)
+    } else if datatype == xsd::TIME {
+        KeyValue::Time(value.parse().ok()?)
+    } else {
+        return None;
+    })
+}
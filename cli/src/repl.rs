@@ -0,0 +1,273 @@
+#![allow(clippy::print_stdout)]
+
+use crate::table::write_results_table;
+use crate::{default_sparql_evaluator, rdf_serializer};
+use anyhow::bail;
+use oxigraph::io::RdfFormat;
+use oxigraph::sparql::QueryResults;
+use oxigraph::sparql::results::{QueryResultsFormat, QueryResultsSerializer};
+use oxigraph::store::Store;
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+use std::io::{Write, stdout};
+use std::time::Instant;
+
+const PROMPT: &str = "oxigraph> ";
+const CONTINUATION_PROMPT: &str = "       -> ";
+const HELP: &str = "Queries and updates might span multiple lines and are terminated by a \";\" at the end of a line.
+
+Available commands:
+  \\format [table|json|csv|tsv|xml]  shows or sets the query results format
+  \\timing [on|off]                  shows or sets the display of the evaluation time
+  \\prefixes                         lists the namespaces stored in the store
+  \\help                             shows this help
+  \\quit                             exits";
+
+/// The interactive prompt state
+pub struct Repl {
+    store: Store,
+    read_only: bool,
+    union_default_graph: bool,
+    format: OutputFormat,
+    timing: bool,
+}
+
+#[derive(Clone, Copy)]
+enum OutputFormat {
+    Table,
+    Serialized(QueryResultsFormat),
+}
+
+impl Repl {
+    pub fn new(store: Store, read_only: bool, union_default_graph: bool) -> Self {
+        Self {
+            store,
+            read_only,
+            union_default_graph,
+            format: OutputFormat::Table,
+            timing: false,
+        }
+    }
+
+    /// Reads and executes statements until the end of the input or a `\quit` command
+    pub fn run(mut self) -> anyhow::Result<()> {
+        let mut editor = DefaultEditor::new()?;
+        let mut buffer = String::new();
+        loop {
+            let line = match editor.readline(if buffer.is_empty() {
+                PROMPT
+            } else {
+                CONTINUATION_PROMPT
+            }) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => {
+                    buffer.clear();
+                    continue;
+                }
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(e.into()),
+            };
+            if buffer.is_empty() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                if let Some(command) = line.strip_prefix('\\') {
+                    editor.add_history_entry(line)?;
+                    match self.execute_command(command) {
+                        Ok(true) => continue,
+                        Ok(false) => return Ok(()),
+                        Err(e) => eprintln!("{e:#}"),
+                    }
+                    continue;
+                }
+            } else {
+                buffer.push('\n');
+            }
+            buffer.push_str(&line);
+            if let Some(statement) = complete_statement(&buffer) {
+                editor.add_history_entry(buffer.as_str())?;
+                self.execute_statement(statement);
+                buffer.clear();
+            }
+        }
+        // We execute what remains if the input is not terminated by a ";"
+        if !buffer.trim().is_empty() {
+            self.execute_statement(&buffer);
+        }
+        Ok(())
+    }
+
+    /// Returns `false` if the prompt should be closed
+    fn execute_command(&mut self, command: &str) -> anyhow::Result<bool> {
+        let (name, argument) = command
+            .split_once(char::is_whitespace)
+            .map_or((command, ""), |(name, argument)| (name, argument.trim()));
+        match name {
+            "q" | "quit" | "exit" => return Ok(false),
+            "?" | "help" => println!("{HELP}"),
+            "format" => {
+                if !argument.is_empty() {
+                    self.format = if argument == "table" {
+                        OutputFormat::Table
+                    } else if let Some(format) = QueryResultsFormat::from_extension(argument)
+                        .or_else(|| QueryResultsFormat::from_media_type(argument))
+                    {
+                        OutputFormat::Serialized(format)
+                    } else {
+                        bail!("The results format '{argument}' is unknown")
+                    };
+                }
+                match self.format {
+                    OutputFormat::Table => println!("Results format is table"),
+                    OutputFormat::Serialized(format) => {
+                        println!("Results format is {}", format.name())
+                    }
+                }
+            }
+            "timing" => {
+                self.timing = match argument {
+                    "" => !self.timing,
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("\\timing expects 'on' or 'off', found '{argument}'"),
+                };
+                println!("Timing is {}", if self.timing { "on" } else { "off" });
+            }
+            "prefixes" => {
+                for (prefix, namespace) in self.store.namespaces()? {
+                    println!("PREFIX {prefix}: <{}>", namespace.as_str());
+                }
+            }
+            _ => bail!("Unknown command \\{name}, type \\help to list the available commands"),
+        }
+        Ok(true)
+    }
+
+    fn execute_statement(&self, statement: &str) {
+        let start = Instant::now();
+        if let Err(e) = self.try_execute_statement(statement) {
+            eprintln!("{e:#}");
+        }
+        if self.timing {
+            println!("Time: {:.3} ms", start.elapsed().as_secs_f64() * 1000.);
+        }
+    }
+
+    fn try_execute_statement(&self, statement: &str) -> anyhow::Result<()> {
+        let query_error = match default_sparql_evaluator().parse_query(statement) {
+            Ok(mut query) => {
+                if self.union_default_graph {
+                    query.dataset_mut().set_default_graph_as_union();
+                }
+                return self.write_results(query.on_store(&self.store).execute()?);
+            }
+            Err(e) => e,
+        };
+        let update = match default_sparql_evaluator().parse_update(statement) {
+            Ok(update) => update,
+            Err(update_error) => bail!(
+                "Not a valid SPARQL query: {query_error}\nNot a valid SPARQL update: {update_error}"
+            ),
+        };
+        if self.read_only {
+            bail!("Updates are not allowed on read-only stores");
+        }
+        update.on_store(&self.store).execute()?;
+        println!("Update done");
+        Ok(())
+    }
+
+    fn write_results(&self, results: QueryResults<'_>) -> anyhow::Result<()> {
+        let OutputFormat::Serialized(format) = self.format else {
+            write_results_table(stdout().lock(), results)?.flush()?;
+            return Ok(());
+        };
+        match results {
+            QueryResults::Solutions(solutions) => {
+                let mut serializer = QueryResultsSerializer::from_format(format)
+                    .serialize_solutions_to_writer(
+                        stdout().lock(),
+                        solutions.variables().to_vec(),
+                    )?;
+                for solution in solutions {
+                    serializer.serialize(&solution?)?;
+                }
+                serializer.finish()?.flush()?;
+            }
+            QueryResults::Boolean(result) => {
+                QueryResultsSerializer::from_format(format)
+                    .serialize_boolean_to_writer(stdout().lock(), result)?
+                    .flush()?;
+            }
+            QueryResults::Graph(triples) => {
+                // Query results formats do not support graphs, we use Turtle instead
+                let mut serializer =
+                    rdf_serializer(&self.store, RdfFormat::Turtle)?.for_writer(stdout().lock());
+                for triple in triples {
+                    serializer.serialize_triple(triple?.as_ref())?;
+                }
+                serializer.finish()?.flush()?;
+            }
+            QueryResults::Dataset(quads) => {
+                let mut serializer =
+                    rdf_serializer(&self.store, RdfFormat::TriG)?.for_writer(stdout().lock());
+                for quad in quads {
+                    serializer.serialize_quad(quad?.as_ref())?;
+                }
+                serializer.finish()?.flush()?;
+            }
+        }
+        println!();
+        Ok(())
+    }
+}
+
+/// Returns the statement without its final ";" if the buffer contains a full statement
+///
+/// The ";" must be at the end of a line and outside of any "{}" block
+/// to not be confused with the predicate-object list separator.
+fn complete_statement(buffer: &str) -> Option<&str> {
+    let statement = buffer.trim_end().strip_suffix(';')?;
+    let mut depth = 0_usize;
+    let mut chars = statement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '"' | '\'' => {
+                // We skip the string
+                while let Some(s) = chars.next() {
+                    if s == '\\' {
+                        chars.next();
+                    } else if s == c {
+                        break;
+                    }
+                }
+            }
+            '#' => {
+                // We skip the comment
+                for s in chars.by_ref() {
+                    if s == '\n' {
+                        break;
+                    }
+                }
+            }
+            '<' => {
+                // We skip the IRI if it looks like an IRI and not like a comparison
+                let mut iri = chars.clone();
+                while let Some(s) = iri.next() {
+                    if s == '>' {
+                        chars = iri;
+                        break;
+                    }
+                    if s.is_whitespace() || matches!(s, '{' | '}' | '"' | '<') {
+                        break;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    (depth == 0).then_some(statement)
+}
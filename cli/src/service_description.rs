@@ -1,6 +1,7 @@
 use oxigraph::io::{RdfFormat, RdfSerializer};
 use oxigraph::model::vocab::rdf;
 use oxigraph::model::{BlankNode, NamedNodeRef, TripleRef};
+use oxigraph::sparql::EntailmentRegime;
 use oxigraph::sparql::results::QueryResultsFormat;
 #[cfg(feature = "geosparql")]
 use spargeo::GEOSPARQL_EXTENSION_FUNCTIONS;
@@ -62,6 +63,7 @@ pub fn generate_service_description(
     format: RdfFormat,
     kind: EndpointKind,
     union_default_graph: bool,
+    entailment_regime: EntailmentRegime,
     endpoint_base_url: &str,
 ) -> Vec<u8> {
     let mut graph = Vec::new();
@@ -138,7 +140,7 @@ pub fn generate_service_description(
     graph.push(TripleRef::new(
         &root,
         sd::DEFAULT_ENTAILMENT_REGIME,
-        NamedNodeRef::new_unchecked("http://www.w3.org/ns/entailment/Simple"),
+        NamedNodeRef::new_unchecked(entailment_regime.iri()),
     ));
     #[cfg(feature = "geosparql")]
     for (function_name, _) in GEOSPARQL_EXTENSION_FUNCTIONS {
@@ -0,0 +1,88 @@
+use json_event_parser::{JsonEvent, WriterJsonSerializer};
+use oxigraph::sparql::QueryLogRecord;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Appends the queries slower than a threshold to a file, one JSON object per line
+pub struct SlowQueryLog {
+    file: Mutex<File>,
+    threshold: Duration,
+    max_query_length: Option<usize>,
+}
+
+impl SlowQueryLog {
+    pub fn open(
+        path: &Path,
+        threshold: Duration,
+        max_query_length: Option<usize>,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            file: Mutex::new(OpenOptions::new().create(true).append(true).open(path)?),
+            threshold,
+            max_query_length,
+        })
+    }
+
+    /// Writes the record if the query is slow enough
+    ///
+    /// `query` is the query text as sent by the client, used instead of the serialization in the record.
+    /// Errors are emitted as `tracing` events and not returned to never fail the query evaluation.
+    pub fn log(&self, query: &str, record: &QueryLogRecord) {
+        if record.duration < self.threshold {
+            return;
+        }
+        if let Err(e) = self.write(query, record) {
+            tracing::error!("Error while writing into the slow query log: {e}");
+        }
+    }
+
+    fn write(&self, query: &str, record: &QueryLogRecord) -> io::Result<()> {
+        let (query, truncated) = match self
+            .max_query_length
+            .and_then(|max_length| query.char_indices().nth(max_length))
+        {
+            Some((end, _)) => (&query[..end], true),
+            None => (query, false),
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut serializer = WriterJsonSerializer::new(Vec::new());
+        serializer.serialize_event(JsonEvent::StartObject)?;
+        serializer.serialize_event(JsonEvent::ObjectKey("timestamp".into()))?;
+        serializer.serialize_event(JsonEvent::Number(
+            timestamp.as_secs_f64().to_string().into(),
+        ))?;
+        serializer.serialize_event(JsonEvent::ObjectKey("query".into()))?;
+        serializer.serialize_event(JsonEvent::String(query.into()))?;
+        serializer.serialize_event(JsonEvent::ObjectKey("truncated".into()))?;
+        serializer.serialize_event(JsonEvent::Boolean(truncated))?;
+        serializer.serialize_event(JsonEvent::ObjectKey("durationSeconds".into()))?;
+        serializer.serialize_event(JsonEvent::Number(
+            record.duration.as_secs_f64().to_string().into(),
+        ))?;
+        serializer.serialize_event(JsonEvent::ObjectKey("resultCount".into()))?;
+        serializer.serialize_event(JsonEvent::Number(record.result_count.to_string().into()))?;
+        serializer.serialize_event(JsonEvent::ObjectKey("cancelled".into()))?;
+        serializer.serialize_event(JsonEvent::Boolean(record.cancelled))?;
+        if let Some(max_intermediate_results) = record.max_intermediate_results {
+            serializer.serialize_event(JsonEvent::ObjectKey("maxIntermediateResults".into()))?;
+            serializer.serialize_event(JsonEvent::Number(
+                max_intermediate_results.to_string().into(),
+            ))?;
+        }
+        serializer.serialize_event(JsonEvent::EndObject)?;
+        let mut line = serializer.finish()?;
+        line.push(b'\n');
+        // We write the full line at once to not interleave concurrent writes
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| io::Error::other("The slow query log file lock is poisoned"))?;
+        file.write_all(&line)?;
+        file.flush()
+    }
+}
@@ -0,0 +1,131 @@
+use oxigraph::model::Term;
+use oxigraph::sparql::QueryResults;
+use std::io::Write;
+
+/// Writes the query results as a human-readable text table followed by a row count
+///
+/// Beware, the solutions and triples are all loaded in memory to compute the column widths.
+pub fn write_results_table<W: Write>(
+    mut writer: W,
+    results: QueryResults<'_>,
+) -> anyhow::Result<W> {
+    match results {
+        QueryResults::Solutions(solutions) => {
+            let header = solutions
+                .variables()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            let rows = solutions
+                .map(|solution| {
+                    let solution = solution?;
+                    Ok((0..header.len())
+                        .map(|i| solution.get(i).map(Term::to_string).unwrap_or_default())
+                        .collect())
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write_table(&mut writer, &header, &rows)?;
+            writeln!(
+                writer,
+                "{} {}",
+                rows.len(),
+                if rows.len() == 1 { "row" } else { "rows" }
+            )?;
+        }
+        QueryResults::Boolean(result) => {
+            writeln!(writer, "{result}")?;
+        }
+        QueryResults::Graph(triples) => {
+            let header = ["subject".into(), "predicate".into(), "object".into()];
+            let rows = triples
+                .map(|triple| {
+                    let triple = triple?;
+                    Ok(vec![
+                        triple.subject.to_string(),
+                        triple.predicate.to_string(),
+                        triple.object.to_string(),
+                    ])
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write_table(&mut writer, &header, &rows)?;
+            writeln!(
+                writer,
+                "{} {}",
+                rows.len(),
+                if rows.len() == 1 { "triple" } else { "triples" }
+            )?;
+        }
+        QueryResults::Dataset(quads) => {
+            let header = [
+                "subject".into(),
+                "predicate".into(),
+                "object".into(),
+                "graph".into(),
+            ];
+            let rows = quads
+                .map(|quad| {
+                    let quad = quad?;
+                    Ok(vec![
+                        quad.subject.to_string(),
+                        quad.predicate.to_string(),
+                        quad.object.to_string(),
+                        quad.graph_name.to_string(),
+                    ])
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            write_table(&mut writer, &header, &rows)?;
+            writeln!(
+                writer,
+                "{} {}",
+                rows.len(),
+                if rows.len() == 1 { "quad" } else { "quads" }
+            )?;
+        }
+    }
+    Ok(writer)
+}
+
+/// Writes a text table with a header row
+pub fn write_table(
+    writer: &mut impl Write,
+    header: &[String],
+    rows: &[Vec<String>],
+) -> anyhow::Result<()> {
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([name.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    write_separator(writer, &widths)?;
+    write_row(writer, header, &widths)?;
+    write_separator(writer, &widths)?;
+    for row in rows {
+        write_row(writer, row, &widths)?;
+    }
+    if !rows.is_empty() {
+        write_separator(writer, &widths)?;
+    }
+    Ok(())
+}
+
+fn write_separator(writer: &mut impl Write, widths: &[usize]) -> anyhow::Result<()> {
+    for width in widths {
+        write!(writer, "+{}", "-".repeat(width + 2))?;
+    }
+    writeln!(writer, "+")?;
+    Ok(())
+}
+
+fn write_row(writer: &mut impl Write, row: &[String], widths: &[usize]) -> anyhow::Result<()> {
+    for (cell, width) in row.iter().zip(widths) {
+        write!(writer, "| {cell:width$} ")?;
+    }
+    writeln!(writer, "|")?;
+    Ok(())
+}
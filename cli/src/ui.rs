@@ -0,0 +1,221 @@
+use crate::{
+    HttpError, bad_request, content_type, error, internal_server_error, limited_string_body,
+    the_server_is_read_only, unsupported_media_type, url_query_parameter,
+};
+use json_event_parser::{JsonEvent, WriterJsonSerializer};
+use oxhttp::model::header::CONTENT_TYPE;
+use oxhttp::model::{Body, Request, Response, StatusCode};
+use oxigraph::model::vocab::{rdf, rdfs};
+use oxigraph::model::{GraphNameRef, LiteralRef, NamedNode, NamedNodeRef, QuadRef, Term};
+use oxigraph::store::{Store, Transaction};
+use spargebra::SparqlParser;
+use url::form_urlencoded;
+
+const UI_PAGE: &str = include_str!("../templates/ui.html");
+
+/// The graph in which the queries saved from the web UI are stored
+pub const SAVED_QUERIES_GRAPH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("tag:oxigraph:saved-queries");
+
+/// Serves the web UI on `/ui` and its saved queries on `/ui/saved-queries`
+///
+/// The other requests are forwarded to `on_request`.
+pub fn ui_middleware(
+    store: Store,
+    read_only: bool,
+    max_body_size: u64,
+    on_request: impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static,
+) -> impl Fn(&mut Request<Body>) -> Response<Body> + Send + Sync + 'static {
+    move |request| {
+        let path = request.uri().path();
+        if path != "/ui" && !path.starts_with("/ui/") {
+            return on_request(request);
+        }
+        handle_ui_request(request, &store, read_only, max_body_size)
+            .unwrap_or_else(|(status, message)| error(status, message))
+    }
+}
+
+fn handle_ui_request(
+    request: &mut Request<Body>,
+    store: &Store,
+    read_only: bool,
+    max_body_size: u64,
+) -> Result<Response<Body>, HttpError> {
+    match (request.uri().path(), request.method().as_ref()) {
+        ("/ui", "HEAD") => Response::builder()
+            .header(CONTENT_TYPE, "text/html")
+            .body(Body::empty())
+            .map_err(internal_server_error),
+        ("/ui", "GET") => Response::builder()
+            .header(CONTENT_TYPE, "text/html")
+            .body(UI_PAGE.into())
+            .map_err(internal_server_error),
+        ("/ui/saved-queries", "GET") => Response::builder()
+            .header(CONTENT_TYPE, "application/json")
+            .body(saved_queries_json(store)?.into())
+            .map_err(internal_server_error),
+        ("/ui/saved-queries", "PUT") => {
+            if read_only {
+                return Err(the_server_is_read_only());
+            }
+            let name = saved_query_name(request)?;
+            let content_type =
+                content_type(request).ok_or_else(|| bad_request("No Content-Type given"))?;
+            if content_type != "application/sparql-query" {
+                return Err(unsupported_media_type(&content_type));
+            }
+            let query = limited_string_body(request, max_body_size)?;
+            // We only save valid queries
+            SparqlParser::new()
+                .parse_query(&query)
+                .map_err(bad_request)?;
+            save_query(store, &name, &query)?;
+            Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(Body::empty())
+                .map_err(internal_server_error)
+        }
+        ("/ui/saved-queries", "DELETE") => {
+            if read_only {
+                return Err(the_server_is_read_only());
+            }
+            let name = saved_query_name(request)?;
+            if !delete_query(store, &name)? {
+                return Err((
+                    StatusCode::NOT_FOUND,
+                    format!("There is no saved query named {name}"),
+                ));
+            }
+            Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(Body::empty())
+                .map_err(internal_server_error)
+        }
+        _ => Err((
+            StatusCode::NOT_FOUND,
+            format!(
+                "{} {} is not supported by this server",
+                request.method(),
+                request.uri().path()
+            ),
+        )),
+    }
+}
+
+fn saved_query_name(request: &Request<Body>) -> Result<String, HttpError> {
+    let name = url_query_parameter(request, "name")
+        .ok_or_else(|| bad_request("The name URL parameter is required"))?;
+    if name.is_empty() {
+        return Err(bad_request("Saved query names must not be empty"));
+    }
+    Ok(name.into_owned())
+}
+
+/// The IRI of the saved query, the query might also be described by other IRIs if written using SPARQL
+fn saved_query_iri(name: &str) -> Result<NamedNode, HttpError> {
+    NamedNode::new(format!(
+        "{}:{}",
+        SAVED_QUERIES_GRAPH.as_str(),
+        form_urlencoded::byte_serialize(name.as_bytes()).collect::<String>()
+    ))
+    .map_err(internal_server_error)
+}
+
+/// Returns the saved queries as a JSON array of `{"name": ..., "query": ...}` objects sorted by name
+fn saved_queries_json(store: &Store) -> Result<Vec<u8>, HttpError> {
+    let graph = GraphNameRef::from(SAVED_QUERIES_GRAPH);
+    let mut queries = Vec::new();
+    for label in store.quads_for_pattern(None, Some(rdfs::LABEL), None, Some(graph)) {
+        let label = label.map_err(internal_server_error)?;
+        let Term::Literal(name) = label.object else {
+            continue;
+        };
+        for value in store.quads_for_pattern(
+            Some(label.subject.as_ref()),
+            Some(rdf::VALUE),
+            None,
+            Some(graph),
+        ) {
+            if let Term::Literal(query) = value.map_err(internal_server_error)?.object {
+                queries.push((name.value().to_owned(), query.value().to_owned()));
+            }
+        }
+    }
+    queries.sort();
+    let mut serializer = WriterJsonSerializer::new(Vec::new());
+    serializer
+        .serialize_event(JsonEvent::StartArray)
+        .map_err(internal_server_error)?;
+    for (name, query) in queries {
+        for event in [
+            JsonEvent::StartObject,
+            JsonEvent::ObjectKey("name".into()),
+            JsonEvent::String(name.into()),
+            JsonEvent::ObjectKey("query".into()),
+            JsonEvent::String(query.into()),
+            JsonEvent::EndObject,
+        ] {
+            serializer
+                .serialize_event(event)
+                .map_err(internal_server_error)?;
+        }
+    }
+    serializer
+        .serialize_event(JsonEvent::EndArray)
+        .map_err(internal_server_error)?;
+    serializer.finish().map_err(internal_server_error)
+}
+
+/// Saves the query, replacing the existing queries with the same name
+fn save_query(store: &Store, name: &str, query: &str) -> Result<(), HttpError> {
+    let graph = GraphNameRef::from(SAVED_QUERIES_GRAPH);
+    let subject = saved_query_iri(name)?;
+    let mut transaction = store.start_transaction().map_err(internal_server_error)?;
+    remove_queries_named(&mut transaction, name)?;
+    transaction.insert(QuadRef::new(
+        &subject,
+        rdfs::LABEL,
+        LiteralRef::new_simple_literal(name),
+        graph,
+    ));
+    transaction.insert(QuadRef::new(
+        &subject,
+        rdf::VALUE,
+        LiteralRef::new_simple_literal(query),
+        graph,
+    ));
+    transaction.commit().map_err(internal_server_error)
+}
+
+/// Returns `false` if there is no query with this name
+fn delete_query(store: &Store, name: &str) -> Result<bool, HttpError> {
+    let mut transaction = store.start_transaction().map_err(internal_server_error)?;
+    let found = remove_queries_named(&mut transaction, name)?;
+    transaction.commit().map_err(internal_server_error)?;
+    Ok(found)
+}
+
+fn remove_queries_named(transaction: &mut Transaction<'_>, name: &str) -> Result<bool, HttpError> {
+    let graph = GraphNameRef::from(SAVED_QUERIES_GRAPH);
+    let subjects = transaction
+        .quads_for_pattern(
+            None,
+            Some(rdfs::LABEL),
+            Some(LiteralRef::new_simple_literal(name).into()),
+            Some(graph),
+        )
+        .map(|quad| quad.map(|quad| quad.subject))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(internal_server_error)?;
+    for subject in &subjects {
+        let quads = transaction
+            .quads_for_pattern(Some(subject.as_ref()), None, None, Some(graph))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal_server_error)?;
+        for quad in &quads {
+            transaction.remove(quad);
+        }
+    }
+    Ok(!subjects.is_empty())
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+#![cfg(feature = "axum")]
+#![allow(clippy::panic_in_result_fn)]
+
+use anyhow::{Result, bail, ensure};
+use axum::Router;
+use oxigraph::model::{GraphNameRef, Literal, NamedNodeRef, QuadRef};
+use oxigraph::sparql::{QueryResults, SparqlClient};
+use oxigraph::store::Store;
+use oxigraph_cli::{SparqlEndpoint, SparqlEndpointConfig};
+use tokio::net::TcpListener;
+use tokio::task::spawn_blocking;
+
+/// Serves the endpoint under `/sparql` and returns its URL
+async fn serve(endpoint: SparqlEndpoint) -> Result<String> {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+    let address = listener.local_addr()?;
+    let app = Router::new().nest("/sparql", endpoint.into_axum_router());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    Ok(format!("http://{address}/sparql"))
+}
+
+fn client(url: &str) -> Result<SparqlClient> {
+    Ok(SparqlClient::new(format!("{url}/query"))?.with_update_endpoint(format!("{url}/update"))?)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn query_and_update() -> Result<()> {
+    let url = serve(SparqlEndpoint::new(
+        Store::new()?,
+        SparqlEndpointConfig::new(),
+    ))
+    .await?;
+    spawn_blocking(move || -> Result<()> {
+        let client = client(&url)?;
+        client.update("INSERT DATA { <http://example.com/s> <http://example.com/p> 1 }")?;
+        assert!(matches!(
+            client.query("ASK { ?s ?p 1 }")?,
+            QueryResults::Boolean(true)
+        ));
+        let QueryResults::Graph(triples) = client.query("CONSTRUCT WHERE { ?s ?p ?o }")? else {
+            bail!("triples expected");
+        };
+        assert_eq!(triples.collect::<Result<Vec<_>, _>>()?.len(), 1);
+        Ok(())
+    })
+    .await?
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn read_only() -> Result<()> {
+    let url = serve(SparqlEndpoint::new(
+        Store::new()?,
+        SparqlEndpointConfig::new().with_read_only(),
+    ))
+    .await?;
+    spawn_blocking(move || -> Result<()> {
+        let client = client(&url)?;
+        ensure!(
+            client
+                .update("INSERT DATA { <http://example.com/s> <http://example.com/p> 1 }")
+                .is_err(),
+            "updates should be rejected"
+        );
+        assert!(matches!(
+            client.query("ASK { ?s ?p ?o }")?,
+            QueryResults::Boolean(false)
+        ));
+        Ok(())
+    })
+    .await?
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn body_size_limit() -> Result<()> {
+    let url = serve(SparqlEndpoint::new(
+        Store::new()?,
+        SparqlEndpointConfig::new().with_max_body_size(100),
+    ))
+    .await?;
+    spawn_blocking(move || -> Result<()> {
+        let client = client(&url)?;
+        client.update("INSERT DATA { <http://example.com/s> <http://example.com/p> 1 }")?;
+        ensure!(
+            client
+                .update(&format!(
+                    "INSERT DATA {{ <http://example.com/s> <http://example.com/p> \"{}\" }}",
+                    "a".repeat(100)
+                ))
+                .is_err(),
+            "too large bodies should be rejected"
+        );
+        assert!(matches!(
+            client.query("ASK { ?s ?p 1 }")?,
+            QueryResults::Boolean(true)
+        ));
+        Ok(())
+    })
+    .await?
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn large_response() -> Result<()> {
+    let store = Store::new()?;
+    for i in 0..1000 {
+        store.insert(QuadRef::new(
+            NamedNodeRef::new("http://example.com/s")?,
+            NamedNodeRef::new("http://example.com/p")?,
+            &Literal::from(format!("value number {i} with a long enough label")),
+            GraphNameRef::DefaultGraph,
+        ))?;
+    }
+    let url = serve(SparqlEndpoint::new(store, SparqlEndpointConfig::new())).await?;
+    spawn_blocking(move || -> Result<()> {
+        let QueryResults::Graph(triples) = client(&url)?.query("CONSTRUCT WHERE { ?s ?p ?o }")?
+        else {
+            bail!("triples expected");
+        };
+        assert_eq!(triples.collect::<Result<Vec<_>, _>>()?.len(), 1000);
+        Ok(())
+    })
+    .await?
+}
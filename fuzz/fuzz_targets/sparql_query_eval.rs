@@ -103,6 +103,13 @@ fn query_results_key(results: QueryResults, is_reduced: bool) -> String {
             triples.sort_unstable();
             triples.join("\n")
         }
+        QueryResults::Dataset(iter) => {
+            let mut dataset = iter.filter_map(Result::ok).collect::<Dataset>();
+            dataset.canonicalize(CanonicalizationAlgorithm::Unstable);
+            let mut quads = dataset.iter().map(|q| q.to_string()).collect::<Vec<_>>();
+            quads.sort_unstable();
+            quads.join("\n")
+        }
         QueryResults::Boolean(bool) => if bool { "true" } else { "false" }.into(),
     }
 }
@@ -57,7 +57,8 @@ fn serialize_quads(
     prefixes: Vec<(String, String)>,
     base_iri: Option<String>,
 ) -> Vec<u8> {
-    let mut serializer = TriGSerializer::new();
+    // We keep the quads order to compare the roundtrip output with the input
+    let mut serializer = TriGSerializer::new().grouped(true);
     for (prefix_name, prefix_iri) in prefixes {
         serializer = serializer.with_prefix(prefix_name, prefix_iri).unwrap();
     }
@@ -7,10 +7,12 @@ use js_sys::{IntoIter, Reflect, Uint8Array, try_iter};
 use oxigraph::io::{RdfFormat, RdfParseError, RdfParser, ReaderQuadParser};
 use oxigraph::model::GraphName;
 use oxrdfio::TokioAsyncReaderQuadParser;
+use std::cell::Cell;
 use std::error::Error;
 use std::fmt::{Debug, Formatter};
 use std::io::{Cursor, Read};
 use std::pin::{Pin, pin};
+use std::rc::Rc;
 use std::task::{Context, Poll, ready};
 use std::{fmt, io};
 use tokio::io::{AsyncRead, ReadBuf};
@@ -200,6 +202,42 @@ impl AsyncRead for AsyncBytesInput {
     }
 }
 
+/// Wraps an input to count the number of bytes read from it
+pub struct CountingInput<R> {
+    inner: R,
+    count: Rc<Cell<u64>>,
+}
+
+impl<R> CountingInput<R> {
+    pub fn new(inner: R, count: Rc<Cell<u64>>) -> Self {
+        Self { inner, count }
+    }
+}
+
+impl<R: Read> Read for CountingInput<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.count
+            .set(self.count.get() + u64::try_from(count).unwrap_or(u64::MAX));
+        Ok(count)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for CountingInput<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let filled_before = buf.filled().len();
+        ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        let count = buf.filled().len() - filled_before;
+        self.count
+            .set(self.count.get() + u64::try_from(count).unwrap_or(u64::MAX));
+        Poll::Ready(Ok(()))
+    }
+}
+
 pub fn buffer_from_js_value(value: &JsValue) -> Option<Vec<u8>> {
     if let Some(value) = value.as_string() {
         Some(value.into_bytes())
@@ -263,6 +301,12 @@ impl ParserIteratorResult {
     }
 }
 
+impl From<Option<JsQuad>> for ParserIteratorResult {
+    fn from(quad: Option<JsQuad>) -> Self {
+        Self(quad)
+    }
+}
+
 // Wrap a JsValue in something implementing Error
 #[derive(Debug)]
 struct WrappedJsValue(JsValue);
@@ -282,7 +326,7 @@ unsafe impl Send for WrappedJsValue {}
 #[expect(unsafe_code)]
 unsafe impl Sync for WrappedJsValue {}
 
-fn convert_rdf_parse_error(error: RdfParseError) -> JsValue {
+pub fn convert_rdf_parse_error(error: RdfParseError) -> JsValue {
     match error {
         RdfParseError::Io(error) => convert_io_error(error),
         RdfParseError::Syntax(error) => JsError::from(error).into(),
@@ -6,6 +6,7 @@ mod store;
 mod utils;
 
 #[wasm_bindgen(start)]
-pub fn main() {
+pub fn main() -> Result<(), JsValue> {
     console_error_panic_hook::set_once();
+    store::make_store_iterable()
 }
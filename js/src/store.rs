@@ -1,15 +1,27 @@
-use crate::io::{BytesInput, buffer_from_js_value, convert_base_iri, rdf_format};
+use crate::io::{
+    AsyncBytesInput, BytesInput, CountingInput, ParserIteratorResult, buffer_from_js_value,
+    convert_base_iri, convert_rdf_parse_error, rdf_format,
+};
 use crate::model::*;
+use crate::utils::{make_iterator_iterable, try_async_iter, yield_to_event_loop};
 use crate::{console_warn, format_err};
-use js_sys::{Array, Map, Reflect, try_iter};
+use js_sys::{Array, Function, IntoIter, Map, Object, Promise, Reflect, Symbol, try_iter};
 use oxigraph::io::{RdfParser, RdfSerializer};
 use oxigraph::model::*;
 use oxigraph::sparql::results::{QueryResultsFormat, QueryResultsSerializer};
 use oxigraph::sparql::{QueryResults, SparqlEvaluator};
-use oxigraph::store::Store;
+use oxigraph::store::{QuadIter, SerializerError, Store};
 #[cfg(feature = "geosparql")]
 use spargeo::GEOSPARQL_EXTENSION_FUNCTIONS;
+use std::cell::{Cell, RefCell};
+use std::io::{Cursor, Read, Write};
+use std::rc::Rc;
+use std::{io, mem};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+/// Number of quads loaded or serialized by loadAsync and dumpAsync between two yields to the event loop
+const ASYNC_BATCH_SIZE: usize = 10_000;
 
 // We skip_typescript on specific wasm_bindgen macros and provide custom TypeScript types for parts of this module in order to have narrower types
 // instead of any and improve compatibility with RDF/JS Dataset interfaces (https://rdf.js.org/dataset-spec/).
@@ -20,7 +32,9 @@ const TYPESCRIPT_CUSTOM_SECTION: &str = r###"
 export class Store {
     readonly size: number;
 
-    constructor(quads?: Iterable<Quad>);
+    constructor(quads?: Iterable<Quad> | { match(): Iterable<Quad> });
+
+    [Symbol.iterator](): Iterator<Quad>;
 
     add(quad: Quad): void;
 
@@ -33,8 +47,28 @@ export class Store {
         }
     ): string;
 
+    dumpAsync(
+        options: {
+            format: string;
+            from_graph_name?: BlankNode | DefaultGraph | NamedNode;
+        }
+    ): Promise<string>;
+    dumpAsync(
+        options: {
+            format: string;
+            from_graph_name?: BlankNode | DefaultGraph | NamedNode;
+            on_chunk: (chunk: string) => void;
+        }
+    ): Promise<void>;
+
+    extend(quads: Quad[]): void;
+
     has(quad: Quad): boolean;
 
+    import(
+        source: Iterable<Quad> | { match(): Iterable<Quad> | EventEmitter } | EventEmitter
+    ): Promise<void>;
+
     load(
         input: string | UInt8Array | Iterable<string | UInt8Array>,
         options: {
@@ -47,6 +81,17 @@ export class Store {
         }
     ): void;
 
+    loadAsync(
+        input: string | UInt8Array | Iterable<string | UInt8Array> | AsyncIterable<string | UInt8Array>,
+        options: {
+            base_iri?: NamedNode | string;
+            format: string;
+            to_graph_name?: BlankNode | DefaultGraph | NamedNode;
+            lenient?: boolean;
+            progress?: (bytesProcessed: number, quadsLoaded: number) => void;
+        }
+    ): Promise<void>;
+
     match(subject?: Term | null, predicate?: Term | null, object?: Term | null, graph?: Term | null): Quad[];
 
     query(
@@ -60,12 +105,33 @@ export class Store {
         }
     ): boolean | Map<string, Term>[] | Quad[] | string;
 
+    renameGraph(
+        from: BlankNode | DefaultGraph | NamedNode,
+        to: BlankNode | DefaultGraph | NamedNode,
+        options?: {
+            overwrite?: boolean;
+        }
+    ): boolean;
+
+    setObject(
+        subject: BlankNode | NamedNode,
+        predicate: NamedNode,
+        objects: Iterable<Term>,
+        graph?: BlankNode | DefaultGraph | NamedNode | null
+    ): Term[];
+
     update(
         update: string,
         options?: {
             base_iri?: NamedNode | string;
         }
     ): void;
+
+    values(): IterableIterator<Quad>;
+}
+
+interface EventEmitter {
+    on(event: string, listener: (...args: any[]) => void): unknown;
 }
 "###;
 
@@ -84,15 +150,64 @@ impl JsStore {
             store: Store::new().map_err(JsError::from)?,
         };
         if !quads.is_undefined() && !quads.is_null() {
-            if let Some(quads) = try_iter(quads)? {
-                for quad in quads {
-                    store.add(&quad?)?;
-                }
-            }
+            let Some(quads) = try_dataset_iter(quads)? else {
+                return Err(format_err!(
+                    "The Store constructor argument must be an iterable of quads or a RDF/JS DatasetCore"
+                ));
+            };
+            store.extend(quads.collect::<Result<_, _>>()?)?;
         }
         Ok(store)
     }
 
+    /// Adds the quads of an iterable, a RDF/JS DatasetCore, Source or Stream.
+    ///
+    /// The returned promise is resolved when all quads have been added.
+    pub fn import(&self, source: &JsValue) -> Result<Promise, JsValue> {
+        let mut quads = try_iter(source)?;
+        let mut stream = source.clone();
+        if quads.is_none() {
+            if let Some(matched) = call_match(source)? {
+                quads = try_iter(&matched)?;
+                stream = matched;
+            }
+        }
+        if let Some(quads) = quads {
+            for quad in quads {
+                self.add(&quad?)?;
+            }
+            return Ok(Promise::resolve(&JsValue::UNDEFINED));
+        }
+        let Some(on) = get_function(&stream, "on")? else {
+            return Err(format_err!(
+                "Store.import argument must be an iterable of quads or a RDF/JS DatasetCore, Source or Stream"
+            ));
+        };
+        let store = self.store.clone();
+        Ok(Promise::new(&mut |resolve, reject| {
+            let store = store.clone();
+            let on_error = reject.clone();
+            let on_data = Closure::<dyn FnMut(JsValue)>::new(move |quad: JsValue| {
+                let inserted = FROM_JS.with(|c| c.to_quad(&quad)).and_then(|quad| {
+                    store
+                        .insert(&quad)
+                        .map_err(|e| JsValue::from(JsError::from(e)))
+                });
+                if let Err(error) = inserted {
+                    reject_promise(&on_error, &error);
+                }
+            })
+            .into_js_value();
+            let registered = on
+                .call2(&stream, &JsValue::from_str("data"), &on_data)
+                .and_then(|_| on.call2(&stream, &JsValue::from_str("error"), &reject))
+                .and_then(|_| on.call2(&stream, &JsValue::from_str("end"), &resolve));
+            if let Err(error) = registered {
+                reject_promise(&reject, &error);
+            }
+        }))
+    }
+
     pub fn add(&self, quad: &JsValue) -> Result<(), JsValue> {
         self.store
             .insert(&FROM_JS.with(|c| c.to_quad(quad))?)
@@ -100,6 +215,28 @@ impl JsStore {
         Ok(())
     }
 
+    /// Adds all the quads of an array in a single transaction.
+    ///
+    /// Nothing is added if one of the array elements is not a valid quad.
+    pub fn extend(&self, quads: Box<[JsValue]>) -> Result<(), JsValue> {
+        let quads = FROM_JS.with(|c| {
+            quads
+                .into_iter()
+                .enumerate()
+                .map(|(i, quad)| {
+                    c.to_quad(&quad).map_err(|e| {
+                        format_err!(
+                            "The element {i} of the array is not a valid quad: {}",
+                            error_message(&e)
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        self.store.extend(quads).map_err(JsError::from)?;
+        Ok(())
+    }
+
     pub fn delete(&self, quad: &JsValue) -> Result<(), JsValue> {
         self.store
             .remove(&FROM_JS.with(|c| c.to_quad(quad))?)
@@ -114,6 +251,13 @@ impl JsStore {
             .map_err(JsError::from)?)
     }
 
+    /// Iterates on all the quads of the store
+    pub fn values(&self) -> Result<JsValue, JsValue> {
+        make_iterator_iterable(StoreIterator {
+            iter: self.store.iter(),
+        })
+    }
+
     #[wasm_bindgen(getter=size)]
     pub fn size(&self) -> Result<usize, JsError> {
         Ok(self.store.len()?)
@@ -302,6 +446,27 @@ impl JsStore {
                     results.into()
                 }
             }
+            QueryResults::Dataset(quads) => {
+                if let Some(results_format) = results_format {
+                    let mut serializer = RdfSerializer::from_format(rdf_format(&results_format)?)
+                        .for_writer(Vec::new());
+                    for quad in quads {
+                        serializer
+                            .serialize_quad(&quad.map_err(JsError::from)?)
+                            .map_err(JsError::from)?;
+                    }
+                    JsValue::from_str(
+                        &String::from_utf8(serializer.finish().map_err(JsError::from)?)
+                            .map_err(JsError::from)?,
+                    )
+                } else {
+                    let results = Array::new();
+                    for quad in quads {
+                        results.push(&JsQuad::from(quad.map_err(JsError::from)?).into());
+                    }
+                    results.into()
+                }
+            }
             QueryResults::Boolean(b) => {
                 if let Some(results_format) = results_format {
                     JsValue::from_str(
@@ -321,6 +486,57 @@ impl JsStore {
         })
     }
 
+    #[wasm_bindgen(js_name = renameGraph)]
+    pub fn rename_graph(
+        &self,
+        from: &JsValue,
+        to: &JsValue,
+        options: &JsValue,
+    ) -> Result<bool, JsValue> {
+        let from: GraphName = FROM_JS.with(|c| c.to_term(from))?.try_into()?;
+        let to: GraphName = FROM_JS.with(|c| c.to_term(to))?.try_into()?;
+        let overwrite = !options.is_undefined()
+            && !options.is_null()
+            && Reflect::get(options, &JsValue::from_str("overwrite"))?.is_truthy();
+        Ok(self
+            .store
+            .rename_graph(&from, &to, overwrite)
+            .map_err(JsError::from)?)
+    }
+
+    #[wasm_bindgen(js_name = setObject)]
+    pub fn set_object(
+        &self,
+        subject: &JsValue,
+        predicate: &JsValue,
+        objects: &JsValue,
+        graph_name: &JsValue,
+    ) -> Result<Array, JsValue> {
+        let subject = NamedOrBlankNode::try_from(FROM_JS.with(|c| c.to_term(subject))?)?;
+        let predicate = NamedNode::try_from(FROM_JS.with(|c| c.to_term(predicate))?)?;
+        let Some(objects) = try_iter(objects)? else {
+            return Err(format_err!(
+                "Store.setObject objects argument must be an iterable of terms"
+            ));
+        };
+        let objects = objects
+            .map(|object| Term::try_from(FROM_JS.with(|c| c.to_term(&object?))?))
+            .collect::<Result<Vec<_>, _>>()?;
+        let graph_name =
+            if let Some(graph_name) = FROM_JS.with(|c| c.to_optional_term(graph_name))? {
+                graph_name.try_into()?
+            } else {
+                GraphName::DefaultGraph
+            };
+        Ok(self
+            .store
+            .set_object(&subject, &predicate, objects, &graph_name)
+            .map_err(JsError::from)?
+            .into_iter()
+            .map(|object| JsValue::from(JsTerm::from(object)))
+            .collect())
+    }
+
     pub fn update(&self, update: &str, options: &JsValue) -> Result<(), JsValue> {
         // Parsing options
         let mut base_iri = None;
@@ -482,6 +698,145 @@ impl JsStore {
         .map_err(JsError::from)?;
         Ok(String::from_utf8(buffer).map_err(JsError::from)?)
     }
+
+    /// Loads the data in batches of quads, yielding to the event loop between them.
+    ///
+    /// Each batch is committed on its own: the load is not atomic.
+    #[wasm_bindgen(js_name = loadAsync)]
+    pub fn load_async(&self, data: &JsValue, options: &JsValue) -> Result<Promise, JsValue> {
+        // Parsing options
+        let mut format = None;
+        let mut base_iri = None;
+        let mut to_graph_name = None;
+        let mut lenient = false;
+        let mut progress = None;
+        if !options.is_undefined() && !options.is_null() {
+            if let Some(format_str) =
+                Reflect::get(options, &JsValue::from_str("format"))?.as_string()
+            {
+                format = Some(rdf_format(&format_str)?);
+            }
+            base_iri = convert_base_iri(&Reflect::get(options, &JsValue::from_str("base_iri"))?)?;
+            let to_graph_name_js = Reflect::get(options, &JsValue::from_str("to_graph_name"))?;
+            to_graph_name = FROM_JS.with(|c| c.to_optional_term(&to_graph_name_js))?;
+            lenient = Reflect::get(options, &JsValue::from_str("lenient"))?.is_truthy();
+            progress = optional_function(options, "progress")?;
+        }
+        let format = format
+            .ok_or_else(|| format_err!("The format option should be provided as a second argument of Store.loadAsync like store.loadAsync(my_content, {{format: 'nt'}}"))?;
+
+        let mut parser = RdfParser::from_format(format);
+        if let Some(to_graph_name) = to_graph_name {
+            parser = parser.with_default_graph(GraphName::try_from(to_graph_name)?);
+        }
+        if let Some(base_iri) = base_iri {
+            parser = parser.with_base_iri(base_iri).map_err(JsError::from)?;
+        }
+        if lenient {
+            parser = parser.lenient();
+        }
+        let bytes_read = Rc::new(Cell::new(0));
+        let mut loader = BatchLoader {
+            store: self.store.clone(),
+            progress,
+            batch: Vec::new(),
+            quads_loaded: 0,
+        };
+        let input: Box<dyn Read> = if let Some(buffer) = buffer_from_js_value(data) {
+            Box::new(Cursor::new(buffer))
+        } else if let Some(iterator) = try_iter(data)? {
+            Box::new(BytesInput::from(iterator))
+        } else if let Some(iterator) = try_async_iter(data)? {
+            let mut quads = parser.for_tokio_async_reader(CountingInput::new(
+                AsyncBytesInput::from(iterator),
+                Rc::clone(&bytes_read),
+            ));
+            return Ok(future_to_promise(async move {
+                while let Some(quad) = quads.next().await {
+                    let quad = quad.map_err(convert_rdf_parse_error)?;
+                    loader.push(quad, bytes_read.get()).await?;
+                }
+                loader.finish(bytes_read.get())?;
+                Ok(JsValue::UNDEFINED)
+            }));
+        } else {
+            return Err(format_err!(
+                "The input must be a string, Uint8Array or a sync or async iterator of string or Uint8Array"
+            ));
+        };
+        let quads = parser.for_reader(CountingInput::new(input, Rc::clone(&bytes_read)));
+        Ok(future_to_promise(async move {
+            for quad in quads {
+                let quad = quad.map_err(convert_rdf_parse_error)?;
+                loader.push(quad, bytes_read.get()).await?;
+            }
+            loader.finish(bytes_read.get())?;
+            Ok(JsValue::UNDEFINED)
+        }))
+    }
+
+    /// Serializes the data in chunks, yielding to the event loop between them.
+    ///
+    /// The chunks are given to the `on_chunk` callback if provided and concatenated into the returned string otherwise.
+    #[wasm_bindgen(js_name = dumpAsync)]
+    pub fn dump_async(&self, options: &JsValue) -> Result<Promise, JsValue> {
+        // Serialization options
+        let mut format = None;
+        let mut from_graph_name = None;
+        let mut on_chunk = None;
+        if !options.is_undefined() && !options.is_null() {
+            if let Some(format_str) =
+                Reflect::get(options, &JsValue::from_str("format"))?.as_string()
+            {
+                format = Some(rdf_format(&format_str)?);
+            }
+            let from_graph_name_js = Reflect::get(options, &JsValue::from_str("from_graph_name"))?;
+            from_graph_name = FROM_JS
+                .with(|c| c.to_optional_term(&from_graph_name_js))?
+                .map(GraphName::try_from)
+                .transpose()?;
+            on_chunk = optional_function(options, "on_chunk")?;
+        }
+        let format = format
+            .ok_or_else(|| format_err!("The format option should be provided as a first argument of Store.dumpAsync like store.dumpAsync({{format: 'nt'}}"))?;
+        if from_graph_name.is_none() && !format.supports_datasets() {
+            return Err(JsError::from(SerializerError::DatasetFormatExpected(format)).into());
+        }
+
+        let store = self.store.clone();
+        Ok(future_to_promise(async move {
+            let buffer = SharedBuffer::default();
+            let mut serializer = RdfSerializer::from_format(format).for_writer(buffer.clone());
+            let mut output = String::new();
+            let mut serialized = 0;
+            for quad in store.quads_for_pattern(
+                None,
+                None,
+                None,
+                from_graph_name.as_ref().map(GraphName::as_ref),
+            ) {
+                let quad = quad.map_err(JsError::from)?;
+                if from_graph_name.is_some() {
+                    serializer.serialize_triple(quad.as_ref())
+                } else {
+                    serializer.serialize_quad(&quad)
+                }
+                .map_err(JsError::from)?;
+                serialized += 1;
+                if serialized % ASYNC_BATCH_SIZE == 0 {
+                    buffer.flush_to(on_chunk.as_ref(), &mut output)?;
+                    yield_to_event_loop().await?;
+                }
+            }
+            serializer.finish().map_err(JsError::from)?;
+            buffer.flush_to(on_chunk.as_ref(), &mut output)?;
+            Ok(if on_chunk.is_some() {
+                JsValue::UNDEFINED
+            } else {
+                output.into()
+            })
+        }))
+    }
 }
 
 fn query_results_format(format: &str) -> Result<QueryResultsFormat, JsValue> {
@@ -501,3 +856,157 @@ fn query_results_format(format: &str) -> Result<QueryResultsFormat, JsValue> {
         })
     }
 }
+
+/// Makes `Store` instances iterable by setting `Store.prototype[Symbol.iterator]` to `Store.prototype.values`
+pub fn make_store_iterable() -> Result<(), JsValue> {
+    let store = JsValue::from(JsStore {
+        store: Store::new().map_err(JsError::from)?,
+    });
+    let prototype = Object::get_prototype_of(&store);
+    Reflect::set(
+        &prototype,
+        &Symbol::iterator(),
+        &Reflect::get(&prototype, &JsValue::from_str("values"))?,
+    )?;
+    Ok(())
+}
+
+#[wasm_bindgen(skip_typescript, private)]
+pub struct StoreIterator {
+    iter: QuadIter<'static>,
+}
+
+#[wasm_bindgen]
+impl StoreIterator {
+    pub fn next(&mut self) -> Result<ParserIteratorResult, JsValue> {
+        Ok(self
+            .iter
+            .next()
+            .transpose()
+            .map_err(JsError::from)?
+            .map(JsQuad::from)
+            .into())
+    }
+}
+
+/// Iterates on an iterable of quads or on the result of the `match` method of a RDF/JS DatasetCore
+fn try_dataset_iter(value: &JsValue) -> Result<Option<IntoIter>, JsValue> {
+    if let Some(quads) = try_iter(value)? {
+        return Ok(Some(quads));
+    }
+    let Some(matched) = call_match(value)? else {
+        return Ok(None);
+    };
+    try_iter(&matched)
+}
+
+/// Calls the `match` method of a RDF/JS DatasetCore or Source without any pattern
+fn call_match(value: &JsValue) -> Result<Option<JsValue>, JsValue> {
+    let Some(match_fn) = get_function(value, "match")? else {
+        return Ok(None);
+    };
+    Ok(Some(match_fn.call0(value)?))
+}
+
+fn error_message(error: &JsValue) -> String {
+    if let Some(error) = error.dyn_ref::<js_sys::Error>() {
+        error.message().into()
+    } else if let Some(error) = error.as_string() {
+        error
+    } else {
+        format!("{error:?}")
+    }
+}
+
+fn get_function(value: &JsValue, name: &str) -> Result<Option<Function>, JsValue> {
+    if !value.is_object() {
+        return Ok(None);
+    }
+    Ok(Reflect::get(value, &JsValue::from_str(name))?
+        .dyn_into::<Function>()
+        .ok())
+}
+
+fn reject_promise(reject: &Function, error: &JsValue) {
+    if let Err(error) = reject.call1(&JsValue::NULL, error) {
+        console_warn!("Failed to reject a promise: {:?}", error);
+    }
+}
+
+fn optional_function(options: &JsValue, name: &str) -> Result<Option<Function>, JsValue> {
+    let value = Reflect::get(options, &JsValue::from_str(name))?;
+    if value.is_undefined() || value.is_null() {
+        return Ok(None);
+    }
+    Ok(Some(value.dyn_into::<Function>().map_err(|_| {
+        format_err!("The {} option must be a function", name)
+    })?))
+}
+
+struct BatchLoader {
+    store: Store,
+    progress: Option<Function>,
+    batch: Vec<Quad>,
+    quads_loaded: u64,
+}
+
+impl BatchLoader {
+    async fn push(&mut self, quad: Quad, bytes_processed: u64) -> Result<(), JsValue> {
+        self.batch.push(quad);
+        if self.batch.len() >= ASYNC_BATCH_SIZE {
+            self.flush(bytes_processed)?;
+            yield_to_event_loop().await?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self, bytes_processed: u64) -> Result<(), JsValue> {
+        self.flush(bytes_processed)
+    }
+
+    fn flush(&mut self, bytes_processed: u64) -> Result<(), JsValue> {
+        self.quads_loaded += u64::try_from(self.batch.len()).unwrap_or(u64::MAX);
+        self.store
+            .extend(self.batch.drain(..))
+            .map_err(JsError::from)?;
+        if let Some(progress) = &self.progress {
+            #[expect(clippy::cast_precision_loss)]
+            progress.call2(
+                &JsValue::NULL,
+                &JsValue::from(bytes_processed as f64),
+                &JsValue::from(self.quads_loaded as f64),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A writer whose content can be taken while a serializer still owns it
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn flush_to(&self, on_chunk: Option<&Function>, output: &mut String) -> Result<(), JsValue> {
+        let chunk =
+            String::from_utf8(mem::take(&mut *self.0.borrow_mut())).map_err(JsError::from)?;
+        if let Some(on_chunk) = on_chunk {
+            if !chunk.is_empty() {
+                on_chunk.call1(&JsValue::NULL, &JsValue::from_str(&chunk))?;
+            }
+        } else {
+            output.push_str(&chunk);
+        }
+        Ok(())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
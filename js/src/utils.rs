@@ -1,4 +1,4 @@
-use js_sys::{AsyncIterator, Function, IteratorNext, Reflect, Symbol};
+use js_sys::{AsyncIterator, Function, IteratorNext, Promise, Reflect, Symbol};
 use std::pin::pin;
 use std::task::{Context, Poll, ready};
 use wasm_bindgen::prelude::*;
@@ -23,6 +23,18 @@ macro_rules! console_warn {
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub(crate) fn warn(s: &str);
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout: i32) -> JsValue;
+}
+
+/// Lets the JS event loop process other tasks before resuming the current future
+pub async fn yield_to_event_loop() -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, _| {
+        set_timeout(&resolve, 0);
+    });
+    JsFuture::from(promise).await?;
+    Ok(())
 }
 
 pub fn try_async_iter(val: &JsValue) -> Result<Option<IntoAsyncIter>, JsValue> {
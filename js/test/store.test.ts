@@ -2,6 +2,8 @@ import assert from "node:assert";
 import { webcrypto } from "node:crypto";
 // @ts-expect-error
 import dataModel from "@rdfjs/data-model";
+import { Readable } from "node:stream";
+import { DataFactory as n3DataFactory, Store as N3Store } from "n3";
 import { describe, it, vi } from "vitest";
 import { type Quad, Store, type Term } from "../pkg/oxigraph.js";
 
@@ -25,6 +27,29 @@ describe("Store", () => {
         });
     });
 
+    describe("#extend()", () => {
+        it("all quads of the array should be in the store", () => {
+            const store = new Store();
+            store.extend([dataModel.quad(ex, ex, triple), dataModel.quad(ex, ex, ex, ex2)]);
+            assert.strictEqual(2, store.size);
+            assert(store.has(dataModel.quad(ex, ex, triple)));
+            assert(store.has(dataModel.quad(ex, ex, ex, ex2)));
+        });
+
+        it("should not add anything if an element is not a quad", () => {
+            const store = new Store();
+            assert.throws(
+                () =>
+                    store.extend([
+                        dataModel.quad(ex, ex, ex),
+                        dataModel.namedNode("http://example.com/not-a-quad"),
+                    ]),
+                /element 1/,
+            );
+            assert.strictEqual(0, store.size);
+        });
+    });
+
     describe("#delete()", () => {
         it("a removed quad should not be in the store anymore", () => {
             const store = new Store([dataModel.quad(ex, ex, triple, ex)]);
@@ -170,6 +195,49 @@ describe("Store", () => {
         });
     });
 
+    describe("#renameGraph()", () => {
+        it("should move the quads to the new graph", () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex)]);
+            assert(store.renameGraph(ex, ex2));
+            assert(!store.has(dataModel.quad(ex, ex, ex, ex)));
+            assert(store.has(dataModel.quad(ex, ex, ex, ex2)));
+            assert(!store.renameGraph(ex, ex2));
+        });
+
+        it("should fail if the target graph exists without overwrite", () => {
+            const store = new Store([
+                dataModel.quad(ex, ex, ex, ex),
+                dataModel.quad(ex, ex, ex2, ex2),
+            ]);
+            assert.throws(() => store.renameGraph(ex, ex2));
+            assert(store.renameGraph(ex, ex2, { overwrite: true }));
+            assert.strictEqual(1, store.size);
+            assert(store.has(dataModel.quad(ex, ex, ex, ex2)));
+        });
+    });
+
+    describe("#setObject()", () => {
+        it("should replace the objects of the pair", () => {
+            const store = new Store([
+                dataModel.quad(ex, ex, ex),
+                dataModel.quad(ex, ex, ex, ex2),
+            ]);
+            const removed = store.setObject(ex, ex, [ex2, dataModel.literal("o")]);
+            assert.strictEqual(1, removed.length);
+            assert(ex.equals(removed[0]));
+            assert.strictEqual(3, store.size);
+            assert(store.has(dataModel.quad(ex, ex, ex2)));
+            assert(store.has(dataModel.quad(ex, ex, dataModel.literal("o"))));
+            assert(store.has(dataModel.quad(ex, ex, ex, ex2)));
+        });
+
+        it("should only remove with an empty list", () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex2)]);
+            assert.strictEqual(1, store.setObject(ex, ex, [], ex2).length);
+            assert.strictEqual(0, store.size);
+        });
+    });
+
     describe("#update()", () => {
         it("INSERT DATA", () => {
             const store = new Store();
@@ -291,4 +359,125 @@ describe("Store", () => {
             );
         });
     });
+
+    describe("#loadAsync()", () => {
+        it("load NTriples with progress", async () => {
+            const store = new Store();
+            const progress: [number, number][] = [];
+            await store.loadAsync(
+                "<http://example.com> <http://example.com> <http://example.com> .",
+                {
+                    format: "application/n-triples",
+                    progress: (bytes: number, quads: number) => progress.push([bytes, quads]),
+                },
+            );
+            assert(store.has(dataModel.quad(ex, ex, ex)));
+            assert.deepStrictEqual(progress, [[64, 1]]);
+        });
+
+        it("load many NQuads from an async iterable", async () => {
+            const store = new Store();
+            async function* input() {
+                for (let i = 0; i < 25000; i++) {
+                    yield `<http://example.com/${i}> <http://example.com> <http://example.com> <http://example.com> .\n`;
+                }
+            }
+            let lastQuadsLoaded = 0;
+            await store.loadAsync(input(), {
+                format: "application/n-quads",
+                progress: (_bytes: number, quads: number) => {
+                    lastQuadsLoaded = quads;
+                },
+            });
+            assert.strictEqual(25000, store.size);
+            assert.strictEqual(25000, lastQuadsLoaded);
+        });
+
+        it("load invalid data", async () => {
+            const store = new Store();
+            await assert.rejects(store.loadAsync("<http://example.com>", { format: "nt" }));
+        });
+    });
+
+    describe("#dumpAsync()", () => {
+        it("dump dataset content", async () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex)]);
+            assert.strictEqual(
+                "<http://example.com> <http://example.com> <http://example.com> <http://example.com> .\n",
+                await store.dumpAsync({ format: "application/n-quads" }),
+            );
+        });
+
+        it("dump named graph content with a chunk callback", async () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex)]);
+            const chunks: string[] = [];
+            await store.dumpAsync({
+                format: "application/n-triples",
+                from_graph_name: ex,
+                on_chunk: (chunk: string) => chunks.push(chunk),
+            });
+            assert.strictEqual(
+                "<http://example.com> <http://example.com> <http://example.com> .\n",
+                chunks.join(""),
+            );
+        });
+    });
+
+    describe("RDF/JS interoperability", () => {
+        it("constructor from a N3.js store", () => {
+            const n3Store = new N3Store([dataModel.quad(ex, ex, ex, ex), dataModel.quad(ex, ex, ex2)]);
+            const store = new Store(n3Store);
+            assert.strictEqual(2, store.size);
+            assert(store.has(dataModel.quad(ex, ex, ex, ex)));
+            assert(store.has(dataModel.quad(ex, ex, ex2)));
+        });
+
+        it("import a N3.js store with a quoted triple", async () => {
+            const quotedTriple = n3DataFactory.quad(
+                n3DataFactory.quad(
+                    n3DataFactory.namedNode(ex.value),
+                    n3DataFactory.namedNode(ex.value),
+                    n3DataFactory.namedNode(ex.value),
+                ),
+                n3DataFactory.namedNode(ex.value),
+                n3DataFactory.literal("o"),
+            );
+            const store = new Store();
+            await store.import(new N3Store([quotedTriple]));
+            assert.strictEqual(1, store.size);
+            assert(store.has(quotedTriple));
+        });
+
+        it("import a RDF/JS stream", async () => {
+            const store = new Store();
+            await store.import(Readable.from([dataModel.quad(ex, ex, ex), dataModel.quad(ex, ex, ex2)]));
+            assert.strictEqual(2, store.size);
+            assert(store.has(dataModel.quad(ex, ex, ex2)));
+        });
+
+        it("import a RDF/JS source", async () => {
+            const store = new Store();
+            await store.import({
+                match: () => Readable.from([dataModel.quad(ex, ex, ex, ex)]),
+            });
+            assert(store.has(dataModel.quad(ex, ex, ex, ex)));
+        });
+
+        it("import an invalid RDF/JS stream", async () => {
+            const store = new Store();
+            await assert.rejects(store.import(Readable.from([ex])));
+        });
+
+        it("iterate on the store", () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex)]);
+            assert.deepStrictEqual([dataModel.quad(ex, ex, ex, ex)].map(String), [...store].map(String));
+        });
+
+        it("build a N3.js store from the store", () => {
+            const store = new Store([dataModel.quad(ex, ex, ex, ex), dataModel.quad(ex, ex, ex2)]);
+            const n3Store = new N3Store([...store]);
+            assert.strictEqual(2, n3Store.size);
+            assert(n3Store.has(dataModel.quad(ex, ex, ex2)));
+        });
+    });
 });
@@ -4,6 +4,7 @@ use bzip2::read::MultiBzDecoder;
 use codspeed_criterion_compat::{Criterion, Throughput, criterion_group, criterion_main};
 use oxhttp::model::{Request, Uri};
 use oxigraph::io::{JsonLdProfile, JsonLdProfileSet, RdfFormat, RdfParser, RdfSerializer};
+use oxigraph::model::{GraphName, Literal, NamedNode, NamedNodeRef, Quad, Term};
 use oxigraph::sparql::{QueryResults, SparqlEvaluator};
 use oxigraph::store::Store;
 use spargebra::{Query, Update};
@@ -263,6 +264,11 @@ fn run_operation(store: &Store, operations: &[Operation], with_opts: bool) {
                         t.unwrap();
                     }
                 }
+                QueryResults::Dataset(d) => {
+                    for q in d {
+                        q.unwrap();
+                    }
+                }
             },
             Operation::Update(u) => store.update_opt(u.clone(), evaluator.clone()).unwrap(),
         }
@@ -298,8 +304,352 @@ fn sparql_parsing(c: &mut Criterion) {
     });
 }
 
+fn store_property_paths(c: &mut Criterion) {
+    // A single cycle going through all the nodes
+    const CYCLE_SIZE: usize = 10_000;
+    let store = Store::new().unwrap();
+    let knows = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/knows");
+    let node =
+        |i: usize| NamedNode::new_unchecked(format!("http://example.com/{}", i % CYCLE_SIZE));
+    let mut loader = store.bulk_loader();
+    loader
+        .load_quads(
+            (0..CYCLE_SIZE)
+                .map(|i| Quad::new(node(i), knows, node(i + 1), GraphName::DefaultGraph)),
+        )
+        .unwrap();
+    loader.commit().unwrap();
+
+    let mut group = c.benchmark_group("property paths");
+    group.sample_size(10);
+    for (name, query) in [
+        (
+            "one or more from a bound subject",
+            "SELECT (COUNT(*) AS ?c) WHERE { <http://example.com/0> <http://xmlns.com/foaf/0.1/knows>+ ?o }",
+        ),
+        (
+            "one or more between bound ends",
+            "ASK { <http://example.com/0> <http://xmlns.com/foaf/0.1/knows>+ <http://example.com/5000> }",
+        ),
+        (
+            "first results of one or more with unbound ends",
+            "SELECT * WHERE { ?s <http://xmlns.com/foaf/0.1/knows>+ ?o } LIMIT 100000",
+        ),
+    ] {
+        let query = Query::from_str(query).unwrap();
+        group.bench_function(format!("{name} on a {CYCLE_SIZE} nodes cycle"), |b| {
+            b.iter(|| run_operation(&store, &[Operation::Query(query.clone())], true))
+        });
+    }
+}
+
+fn store_join_ordering(c: &mut Criterion) {
+    // A BSBM-like dataset where some product and review properties are rare
+    const PRODUCT_COUNT: usize = 10_000;
+    let bsbm = |name: &str| {
+        NamedNode::new_unchecked(format!(
+            "http://www4.wiwiss.fu-berlin.de/bizer/bsbm/v01/vocabulary/{name}"
+        ))
+    };
+    let instance = |name: &str, i: usize| {
+        NamedNode::new_unchecked(format!(
+            "http://www4.wiwiss.fu-berlin.de/bizer/bsbm/v01/instances/{name}{i}"
+        ))
+    };
+    let label = NamedNodeRef::new_unchecked("http://www.w3.org/2000/01/rdf-schema#label");
+    let mut quads = Vec::new();
+    let mut add = |s: &NamedNode, p: &NamedNode, o: Term| {
+        quads.push(Quad::new(s.clone(), p.clone(), o, GraphName::DefaultGraph))
+    };
+    for i in 0..100 {
+        let producer = instance("Producer", i);
+        add(
+            &producer,
+            &label.into(),
+            Literal::from(format!("Producer {i}")).into(),
+        );
+        add(
+            &producer,
+            &bsbm("country"),
+            instance("Country", i % 10).into(),
+        );
+    }
+    for i in 0..PRODUCT_COUNT {
+        let product = instance("Product", i);
+        add(
+            &product,
+            &label.into(),
+            Literal::from(format!("Product {i}")).into(),
+        );
+        add(
+            &product,
+            &bsbm("producer"),
+            instance("Producer", i % 100).into(),
+        );
+        for j in 0..5 {
+            add(
+                &product,
+                &bsbm("productFeature"),
+                instance("ProductFeature", (i * 7 + j) % 1000).into(),
+            );
+        }
+        if i % 100 == 0 {
+            add(
+                &product,
+                &bsbm("productPropertyTextual6"),
+                Literal::from(format!("Text {i}")).into(),
+            );
+        }
+        for j in 0..2 {
+            let review = instance("Review", i * 2 + j);
+            add(&review, &bsbm("reviewFor"), product.clone().into());
+            add(
+                &review,
+                &bsbm("reviewer"),
+                instance("Reviewer", i % 500).into(),
+            );
+            if i % 50 == 0 {
+                add(
+                    &review,
+                    &bsbm("rating4"),
+                    Literal::from(u64::try_from(i % 10).unwrap()).into(),
+                );
+            }
+        }
+    }
+    for i in 0..500 {
+        add(
+            &instance("Reviewer", i),
+            &bsbm("country"),
+            instance("Country", i % 10).into(),
+        );
+    }
+    let store_without_statistics = Store::new().unwrap();
+    let store_with_statistics = Store::new().unwrap();
+    for store in [&store_without_statistics, &store_with_statistics] {
+        let mut loader = store.bulk_loader();
+        loader.load_quads(quads.iter().cloned()).unwrap();
+        loader.commit().unwrap();
+    }
+    store_with_statistics.recompute_statistics().unwrap();
+
+    let mut group = c.benchmark_group("join ordering");
+    group.sample_size(10);
+    for (name, query) in [
+        (
+            "products with a rare property",
+            "PREFIX bsbm: <http://www4.wiwiss.fu-berlin.de/bizer/bsbm/v01/vocabulary/>
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            SELECT * WHERE {
+                ?product rdfs:label ?label ; bsbm:producer ?producer ; bsbm:productFeature ?feature .
+                ?producer rdfs:label ?producerLabel .
+                ?product bsbm:productPropertyTextual6 ?text
+            }",
+        ),
+        (
+            "reviews with a rare rating",
+            "PREFIX bsbm: <http://www4.wiwiss.fu-berlin.de/bizer/bsbm/v01/vocabulary/>
+            SELECT * WHERE {
+                ?review bsbm:reviewFor ?product ; bsbm:reviewer ?reviewer .
+                ?reviewer bsbm:country ?country .
+                ?review bsbm:rating4 ?rating
+            }",
+        ),
+    ] {
+        let query = Query::from_str(query).unwrap();
+        for (store, description) in [
+            (&store_without_statistics, "without statistics"),
+            (&store_with_statistics, "with statistics"),
+        ] {
+            group.bench_function(format!("{name} {description}"), |b| {
+                b.iter(|| run_operation(store, &[Operation::Query(query.clone())], true))
+            });
+        }
+    }
+}
+
+fn store_graph_management(c: &mut Criterion) {
+    const GRAPH_SIZE: usize = 100_000;
+    let store = Store::new().unwrap();
+    let source = NamedNodeRef::new_unchecked("http://example.com/source");
+    let target = NamedNodeRef::new_unchecked("http://example.com/target");
+    let p = NamedNodeRef::new_unchecked("http://example.com/p");
+    let mut loader = store.bulk_loader();
+    loader
+        .load_quads((0..GRAPH_SIZE).map(|i| {
+            Quad::new(
+                NamedNode::new_unchecked(format!("http://example.com/{i}")),
+                p,
+                Literal::from(i64::try_from(i).unwrap()),
+                source,
+            )
+        }))
+        .unwrap();
+    loader.commit().unwrap();
+
+    let mut group = c.benchmark_group("graph management");
+    group.throughput(Throughput::Elements(GRAPH_SIZE as u64));
+    group.sample_size(10);
+    group.bench_function(format!("copy a {GRAPH_SIZE} quads graph"), |b| {
+        b.iter(|| store.copy_graph(source, target).unwrap())
+    });
+    let update =
+        Update::from_str("COPY <http://example.com/source> TO <http://example.com/target>")
+            .unwrap();
+    group.bench_function(format!("SPARQL COPY of a {GRAPH_SIZE} quads graph"), |b| {
+        b.iter(|| run_operation(&store, &[Operation::Update(update.clone())], true))
+    });
+    let update = Update::from_str(
+        "INSERT { GRAPH <http://example.com/target> { ?s ?p ?o } } WHERE { GRAPH <http://example.com/source> { ?s ?p ?o . FILTER(true) } }",
+    )
+    .unwrap();
+    group.bench_function(
+        format!("SPARQL INSERT WHERE of a {GRAPH_SIZE} quads graph"),
+        |b| b.iter(|| run_operation(&store, &[Operation::Update(update.clone())], true)),
+    );
+}
+
+fn store_exists(c: &mut Criterion) {
+    // Each subject references a single object but there are millions of objects
+    const SUBJECT_COUNT: usize = 1_000_000;
+    let store = Store::new().unwrap();
+    let node =
+        |kind: &str, i: usize| NamedNode::new_unchecked(format!("http://example.com/{kind}{i}"));
+    let reference = NamedNodeRef::new_unchecked("http://example.com/reference");
+    let value = NamedNodeRef::new_unchecked("http://example.com/value");
+    let selected = NamedNodeRef::new_unchecked("http://example.com/selected");
+    let mut loader = store.bulk_loader();
+    loader
+        .load_quads((0..SUBJECT_COUNT).flat_map(|i| {
+            [
+                Quad::new(
+                    node("s", i),
+                    reference,
+                    node("o", i),
+                    GraphName::DefaultGraph,
+                ),
+                Quad::new(
+                    node("o", i),
+                    value,
+                    Literal::from(i64::try_from(i).unwrap()),
+                    GraphName::DefaultGraph,
+                ),
+                Quad::new(
+                    node("s", i),
+                    selected,
+                    Literal::from(i % 1000 == 0),
+                    GraphName::DefaultGraph,
+                ),
+            ]
+        }))
+        .unwrap();
+    loader.commit().unwrap();
+
+    let mut group = c.benchmark_group("exists");
+    group.sample_size(10);
+    for (name, query) in [
+        (
+            "FILTER EXISTS",
+            "SELECT (COUNT(*) AS ?c) WHERE {
+                ?s <http://example.com/selected> true
+                FILTER EXISTS { ?o <http://example.com/value> ?v . ?s <http://example.com/reference> ?o }
+            }",
+        ),
+        (
+            "FILTER NOT EXISTS",
+            "SELECT (COUNT(*) AS ?c) WHERE {
+                ?s <http://example.com/selected> true
+                FILTER NOT EXISTS { ?o <http://example.com/value> ?v . ?s <http://example.com/reference> ?o }
+            }",
+        ),
+    ] {
+        let query = Query::from_str(query).unwrap();
+        group.bench_function(
+            format!("{name} with a pattern matching {SUBJECT_COUNT} quads when unbound"),
+            |b| b.iter(|| run_operation(&store, &[Operation::Query(query.clone())], true)),
+        );
+    }
+}
+
+fn store_object_range(c: &mut Criterion) {
+    // Measurements with values spread over a large interval
+    const MEASUREMENT_COUNT: i64 = 10_000_000;
+    let path = TempDir::new().unwrap();
+    let store = Store::open(&path).unwrap();
+    let value = NamedNodeRef::new_unchecked("http://example.com/value");
+    let mut loader = store.bulk_loader();
+    loader
+        .load_quads((0..MEASUREMENT_COUNT).map(|i| {
+            Quad::new(
+                NamedNode::new_unchecked(format!("http://example.com/m{i}")),
+                value,
+                Literal::from(i),
+                GraphName::DefaultGraph,
+            )
+        }))
+        .unwrap();
+    loader.commit().unwrap();
+    store.optimize().unwrap();
+
+    let mut group = c.benchmark_group("object range");
+    group.sample_size(10);
+    group.bench_function(
+        format!("filter of the {MEASUREMENT_COUNT} values between 1000 and 2000"),
+        |b| {
+            b.iter(|| {
+                let count = store
+                    .quads_for_pattern(None, Some(value), None, None)
+                    .filter(|q| {
+                        let Term::Literal(o) = &q.as_ref().unwrap().object else {
+                            return false;
+                        };
+                        o.value()
+                            .parse::<i64>()
+                            .is_ok_and(|v| (1000..=2000).contains(&v))
+                    })
+                    .count();
+                assert_eq!(count, 1001);
+            })
+        },
+    );
+    group.bench_function(
+        format!("range scan of the {MEASUREMENT_COUNT} values between 1000 and 2000"),
+        |b| {
+            b.iter(|| {
+                let count = store
+                    .quads_for_pattern_with_object_range(
+                        None,
+                        Some(value),
+                        Literal::from(1000)..=Literal::from(2000),
+                        None,
+                    )
+                    .count();
+                assert_eq!(count, 1001);
+            })
+        },
+    );
+    let query = Query::from_str(
+        "SELECT ?m WHERE { ?m <http://example.com/value> ?v FILTER(?v >= 1000 && ?v <= 2000) }",
+    )
+    .unwrap();
+    group.bench_function(
+        format!("SPARQL range FILTER on {MEASUREMENT_COUNT} values"),
+        |b| b.iter(|| run_operation(&store, &[Operation::Query(query.clone())], true)),
+    );
+}
+
 criterion_group!(parse, parse_bsbm);
-criterion_group!(store, sparql_parsing, store_query_and_update, store_load);
+criterion_group!(
+    store,
+    sparql_parsing,
+    store_query_and_update,
+    store_load,
+    store_property_paths,
+    store_join_ordering,
+    store_exists,
+    store_graph_management,
+    store_object_range
+);
 
 criterion_main!(parse, store);
 
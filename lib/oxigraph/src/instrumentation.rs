@@ -0,0 +1,25 @@
+//! Helpers to emit [`tracing`](https://docs.rs/tracing) spans and events if the `tracing` feature is enabled.
+//!
+//! They expand to nothing if the feature is disabled.
+
+/// Enters an info level span until the end of the current scope
+///
+/// The arguments are the ones of [`tracing::info_span`](https://docs.rs/tracing/latest/tracing/macro.info_span.html).
+/// They are not evaluated if the `tracing` feature is disabled.
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($($arg)+).entered();
+    };
+}
+
+/// Records an error with its sources as an error level event
+macro_rules! trace_error {
+    ($error:expr) => {{
+        let error: &(dyn std::error::Error + 'static) = $error;
+        #[cfg(feature = "tracing")]
+        tracing::error!(error);
+        #[cfg(not(feature = "tracing"))]
+        let _: &dyn std::error::Error = error;
+    }};
+}
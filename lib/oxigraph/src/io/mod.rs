@@ -27,7 +27,9 @@
 //! ```
 
 pub use oxrdfio::{
-    JsonLdProfile, JsonLdProfileSet, LoadedDocument, RdfFormat, RdfParseError, RdfParser,
-    RdfSerializer, RdfSyntaxError, ReaderQuadParser, SliceQuadParser, TextPosition,
-    WriterQuadSerializer,
+    ContentEncoding, CsvRdfParser, DatasetExt, DecodingReader, GraphExt, InvalidIriPolicy,
+    JsonLdProfile, JsonLdProfileSet, LiteralDecision, LoadedDocument, RdfFormat, RdfParseError,
+    RdfParser, RdfSerializer, RdfStarPolicy, RdfStarSerializationPolicy, RdfSyntaxError,
+    ReaderCsvRdfParser, ReaderQuadParser, SerializableStatement, SerializeFromIterError,
+    SliceQuadParser, TextPosition, TurtleSyntaxErrorKind, ValidationPolicy, WriterQuadSerializer,
 };
@@ -5,8 +5,12 @@
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
+#[macro_use]
+mod instrumentation;
 pub mod io;
 pub mod model;
+#[cfg(feature = "shacl")]
+pub mod shacl;
 pub mod sparql;
 mod storage;
 pub mod store;
@@ -0,0 +1,151 @@
+use crate::model::dataset::GraphView;
+use crate::model::{
+    Graph, GraphNameRef, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term,
+    TermRef,
+};
+use crate::store::{StorageError, Store};
+use std::convert::Infallible;
+
+/// The graph being validated.
+pub(super) trait DataGraph {
+    type Error;
+
+    fn objects(
+        &self,
+        subject: NamedOrBlankNodeRef<'_>,
+        predicate: NamedNodeRef<'_>,
+    ) -> Result<Vec<Term>, Self::Error>;
+
+    fn subjects(
+        &self,
+        predicate: NamedNodeRef<'_>,
+        object: TermRef<'_>,
+    ) -> Result<Vec<NamedOrBlankNode>, Self::Error>;
+
+    fn subject_objects(
+        &self,
+        predicate: NamedNodeRef<'_>,
+    ) -> Result<Vec<(NamedOrBlankNode, Term)>, Self::Error>;
+
+    fn predicate_objects(
+        &self,
+        subject: NamedOrBlankNodeRef<'_>,
+    ) -> Result<Vec<(NamedNode, Term)>, Self::Error>;
+}
+
+macro_rules! impl_in_memory_data_graph {
+    ($type:ty) => {
+        impl DataGraph for $type {
+            type Error = Infallible;
+
+            fn objects(
+                &self,
+                subject: NamedOrBlankNodeRef<'_>,
+                predicate: NamedNodeRef<'_>,
+            ) -> Result<Vec<Term>, Infallible> {
+                Ok(self
+                    .objects_for_subject_predicate(subject, predicate)
+                    .map(TermRef::into_owned)
+                    .collect())
+            }
+
+            fn subjects(
+                &self,
+                predicate: NamedNodeRef<'_>,
+                object: TermRef<'_>,
+            ) -> Result<Vec<NamedOrBlankNode>, Infallible> {
+                Ok(self
+                    .subjects_for_predicate_object(predicate, object)
+                    .map(NamedOrBlankNodeRef::into_owned)
+                    .collect())
+            }
+
+            fn subject_objects(
+                &self,
+                predicate: NamedNodeRef<'_>,
+            ) -> Result<Vec<(NamedOrBlankNode, Term)>, Infallible> {
+                Ok(self
+                    .triples_for_predicate(predicate)
+                    .map(|t| (t.subject.into_owned(), t.object.into_owned()))
+                    .collect())
+            }
+
+            fn predicate_objects(
+                &self,
+                subject: NamedOrBlankNodeRef<'_>,
+            ) -> Result<Vec<(NamedNode, Term)>, Infallible> {
+                Ok(self
+                    .triples_for_subject(subject)
+                    .map(|t| (t.predicate.into_owned(), t.object.into_owned()))
+                    .collect())
+            }
+        }
+    };
+}
+
+impl_in_memory_data_graph!(Graph);
+impl_in_memory_data_graph!(GraphView<'_>);
+
+/// The default graph of a [`Store`].
+impl DataGraph for Store {
+    type Error = StorageError;
+
+    fn objects(
+        &self,
+        subject: NamedOrBlankNodeRef<'_>,
+        predicate: NamedNodeRef<'_>,
+    ) -> Result<Vec<Term>, StorageError> {
+        self.quads_for_pattern(
+            Some(subject),
+            Some(predicate),
+            None,
+            Some(GraphNameRef::DefaultGraph),
+        )
+        .map(|q| Ok(q?.object))
+        .collect()
+    }
+
+    fn subjects(
+        &self,
+        predicate: NamedNodeRef<'_>,
+        object: TermRef<'_>,
+    ) -> Result<Vec<NamedOrBlankNode>, StorageError> {
+        self.quads_for_pattern(
+            None,
+            Some(predicate),
+            Some(object),
+            Some(GraphNameRef::DefaultGraph),
+        )
+        .map(|q| Ok(q?.subject))
+        .collect()
+    }
+
+    fn subject_objects(
+        &self,
+        predicate: NamedNodeRef<'_>,
+    ) -> Result<Vec<(NamedOrBlankNode, Term)>, StorageError> {
+        self.quads_for_pattern(
+            None,
+            Some(predicate),
+            None,
+            Some(GraphNameRef::DefaultGraph),
+        )
+        .map(|q| {
+            let q = q?;
+            Ok((q.subject, q.object))
+        })
+        .collect()
+    }
+
+    fn predicate_objects(
+        &self,
+        subject: NamedOrBlankNodeRef<'_>,
+    ) -> Result<Vec<(NamedNode, Term)>, StorageError> {
+        self.quads_for_pattern(Some(subject), None, None, Some(GraphNameRef::DefaultGraph))
+            .map(|q| {
+                let q = q?;
+                Ok((q.predicate, q.object))
+            })
+            .collect()
+    }
+}
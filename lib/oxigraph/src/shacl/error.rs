@@ -0,0 +1,26 @@
+use crate::model::{NamedNodeRef, NamedOrBlankNode, TermRef};
+
+/// An error raised when the shapes graph given to a [`ShaclValidator`](super::ShaclValidator) is not well-formed.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid SHACL shapes graph: {message}")]
+pub struct ShapesGraphError {
+    message: String,
+}
+
+impl ShapesGraphError {
+    pub(super) fn msg(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    pub(super) fn invalid_value(
+        shape: &NamedOrBlankNode,
+        predicate: NamedNodeRef<'_>,
+        value: TermRef<'_>,
+    ) -> Self {
+        Self::msg(format!(
+            "the shape {shape} has an invalid {predicate} value: {value}"
+        ))
+    }
+}
@@ -0,0 +1,136 @@
+//! [SHACL](https://www.w3.org/TR/shacl/) validation of RDF graphs.
+//!
+//! The [SHACL Core](https://www.w3.org/TR/shacl/#core-components) constraint components are supported except value range (`sh:minInclusive`...),
+//! `sh:lessThan`, `sh:lessThanOrEquals` and the qualified value shapes.
+//! SHACL-SPARQL is not supported.
+//!
+//! When validating a [`Dataset`] or a [`Store`], the data graph is their default graph.
+//!
+//! Usage example:
+//! ```
+//! use oxigraph::io::{RdfFormat, RdfParser};
+//! use oxigraph::model::{Graph, NamedNode, Triple};
+//! use oxigraph::shacl::ShaclValidator;
+//! use oxigraph::store::Store;
+//!
+//! let shapes = RdfParser::from_format(RdfFormat::Turtle)
+//!     .for_slice(
+//!         "@prefix sh: <http://www.w3.org/ns/shacl#> .
+//!         @prefix ex: <http://example.com/> .
+//!         ex:PersonShape a sh:NodeShape ;
+//!             sh:targetClass ex:Person ;
+//!             sh:property [ sh:path ex:name ; sh:minCount 1 ] .",
+//!     )
+//!     .map(|q| Ok(Triple::from(q?)))
+//!     .collect::<Result<Graph, oxigraph::io::RdfParseError>>()?;
+//! let validator = ShaclValidator::new(&shapes)?;
+//!
+//! let store = Store::new()?;
+//! store.load_from_slice(
+//!     RdfFormat::Turtle,
+//!     "<http://example.com/alice> a <http://example.com/Person> .",
+//! )?;
+//! let report = validator.validate_store(&store)?;
+//! assert!(!report.conforms());
+//! assert_eq!(
+//!     report.results()[0].focus_node,
+//!     NamedNode::new("http://example.com/alice")?.into()
+//! );
+//! # Result::<_, Box<dyn std::error::Error>>::Ok(())
+//! ```
+
+mod data;
+mod error;
+mod path;
+mod report;
+mod shape;
+mod validation;
+mod vocab;
+
+use crate::model::{Dataset, Graph, GraphNameRef};
+use crate::shacl::data::DataGraph;
+pub use crate::shacl::error::ShapesGraphError;
+pub use crate::shacl::path::ShaclPath;
+pub use crate::shacl::report::{Severity, ValidationReport, ValidationResult};
+use crate::shacl::shape::{Shape, parse_shapes};
+use crate::shacl::validation::Validator;
+use crate::store::{StorageError, Store};
+use std::convert::Infallible;
+use std::fmt;
+
+/// Validates RDF data against a [SHACL](https://www.w3.org/TR/shacl/) shapes graph.
+///
+/// The shapes graph is parsed once on creation so the same validator can be used to validate many data graphs.
+///
+/// ```
+/// use oxigraph::model::*;
+/// use oxigraph::shacl::ShaclValidator;
+///
+/// let ex = NamedNodeRef::new("http://example.com/shape")?;
+/// let mut shapes = Graph::new();
+/// shapes.insert(TripleRef::new(
+///     ex,
+///     NamedNodeRef::new("http://www.w3.org/ns/shacl#targetNode")?,
+///     LiteralRef::new_simple_literal("foo"),
+/// ));
+/// shapes.insert(TripleRef::new(
+///     ex,
+///     NamedNodeRef::new("http://www.w3.org/ns/shacl#datatype")?,
+///     vocab::xsd::INTEGER,
+/// ));
+/// let report = ShaclValidator::new(&shapes)?.validate_graph(&Graph::new());
+/// assert!(!report.conforms());
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub struct ShaclValidator {
+    shapes: Vec<Shape>,
+}
+
+impl ShaclValidator {
+    /// Parses the shapes from the shapes graph.
+    ///
+    /// Returns an error if the shapes graph is not well-formed, for example if a `sh:pattern` is not a valid regular expression.
+    pub fn new(shapes: &Graph) -> Result<Self, ShapesGraphError> {
+        Ok(Self {
+            shapes: parse_shapes(shapes)?,
+        })
+    }
+
+    /// Validates a graph.
+    pub fn validate_graph(&self, data: &Graph) -> ValidationReport {
+        self.validate_infallible(data)
+    }
+
+    /// Validates the default graph of a dataset.
+    pub fn validate(&self, data: &Dataset) -> ValidationReport {
+        self.validate_infallible(&data.graph(GraphNameRef::DefaultGraph))
+    }
+
+    /// Validates the default graph of a store.
+    ///
+    /// Each lookup reads the current state of the store: writes done concurrently to the validation might be partially visible.
+    pub fn validate_store(&self, data: &Store) -> Result<ValidationReport, StorageError> {
+        self.validate_data(data)
+    }
+
+    fn validate_infallible(&self, data: &impl DataGraph<Error = Infallible>) -> ValidationReport {
+        match self.validate_data(data) {
+            Ok(report) => report,
+            Err(e) => match e {},
+        }
+    }
+
+    fn validate_data<D: DataGraph>(&self, data: &D) -> Result<ValidationReport, D::Error> {
+        Ok(ValidationReport::new(
+            Validator::new(&self.shapes, data).validate()?,
+        ))
+    }
+}
+
+impl fmt::Debug for ShaclValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShaclValidator")
+            .field("shapes", &self.shapes.len())
+            .finish()
+    }
+}
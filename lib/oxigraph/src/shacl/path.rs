@@ -0,0 +1,336 @@
+use crate::model::vocab::rdf;
+use crate::model::{
+    BlankNode, Graph, NamedNode, NamedNodeRef, NamedOrBlankNodeRef, Term, TermRef, TripleRef,
+};
+use crate::shacl::data::DataGraph;
+use crate::shacl::error::ShapesGraphError;
+use crate::shacl::shape::parse_list;
+use crate::shacl::vocab as sh;
+use rustc_hash::FxHashSet;
+use std::fmt;
+
+const MAX_PATH_DEPTH: usize = 64;
+
+/// A [SHACL property path](https://www.w3.org/TR/shacl/#property-paths).
+///
+/// Its [`Display`](fmt::Display) implementation uses the SPARQL property path syntax.
+/// ```
+/// use oxigraph::model::NamedNode;
+/// use oxigraph::shacl::ShaclPath;
+///
+/// let path = ShaclPath::Sequence(vec![
+///     ShaclPath::Predicate(NamedNode::new("http://schema.org/address")?),
+///     ShaclPath::Inverse(Box::new(ShaclPath::Predicate(NamedNode::new(
+///         "http://schema.org/location"
+///     )?))),
+/// ]);
+/// assert_eq!(
+///     path.to_string(),
+///     "(<http://schema.org/address> / ^(<http://schema.org/location>))"
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub enum ShaclPath {
+    /// A [predicate path](https://www.w3.org/TR/shacl/#property-path-predicate).
+    Predicate(NamedNode),
+    /// An [inverse path](https://www.w3.org/TR/shacl/#property-path-inverse).
+    Inverse(Box<Self>),
+    /// A [sequence path](https://www.w3.org/TR/shacl/#property-path-sequence).
+    Sequence(Vec<Self>),
+    /// An [alternative path](https://www.w3.org/TR/shacl/#property-path-alternative).
+    Alternative(Vec<Self>),
+    /// A [zero-or-more path](https://www.w3.org/TR/shacl/#property-path-zero-or-more).
+    ZeroOrMore(Box<Self>),
+    /// A [one-or-more path](https://www.w3.org/TR/shacl/#property-path-one-or-more).
+    OneOrMore(Box<Self>),
+    /// A [zero-or-one path](https://www.w3.org/TR/shacl/#property-path-zero-or-one).
+    ZeroOrOne(Box<Self>),
+}
+
+impl ShaclPath {
+    pub(super) fn parse(shapes: &Graph, node: TermRef<'_>) -> Result<Self, ShapesGraphError> {
+        Self::parse_with_depth(shapes, node, 0)
+    }
+
+    fn parse_with_depth(
+        shapes: &Graph,
+        node: TermRef<'_>,
+        depth: usize,
+    ) -> Result<Self, ShapesGraphError> {
+        if depth > MAX_PATH_DEPTH {
+            return Err(ShapesGraphError::msg(format!(
+                "the property path {node} is too deeply nested"
+            )));
+        }
+        let node = match node {
+            TermRef::NamedNode(node) => return Ok(Self::Predicate(node.into_owned())),
+            TermRef::BlankNode(node) => node,
+            TermRef::Literal(_) => return Err(Self::invalid_path(node)),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(_) => return Err(Self::invalid_path(node)),
+        };
+        if shapes
+            .object_for_subject_predicate(node, rdf::FIRST)
+            .is_some()
+        {
+            let elements = Self::parse_list(shapes, node.into(), depth)?;
+            if elements.len() < 2 {
+                return Err(ShapesGraphError::msg(format!(
+                    "the sequence path {node} must have at least two members"
+                )));
+            }
+            return Ok(Self::Sequence(elements));
+        }
+        let mut paths = shapes.triples_for_subject(node).filter_map(|t| {
+            let kind = if t.predicate == sh::INVERSE_PATH {
+                PathKind::Inverse
+            } else if t.predicate == sh::ALTERNATIVE_PATH {
+                PathKind::Alternative
+            } else if t.predicate == sh::ZERO_OR_MORE_PATH {
+                PathKind::ZeroOrMore
+            } else if t.predicate == sh::ONE_OR_MORE_PATH {
+                PathKind::OneOrMore
+            } else if t.predicate == sh::ZERO_OR_ONE_PATH {
+                PathKind::ZeroOrOne
+            } else {
+                return None;
+            };
+            Some((kind, t.object))
+        });
+        let (Some((kind, value)), None) = (paths.next(), paths.next()) else {
+            return Err(Self::invalid_path(node.into()));
+        };
+        Ok(match kind {
+            PathKind::Inverse => {
+                Self::Inverse(Box::new(Self::parse_with_depth(shapes, value, depth + 1)?))
+            }
+            PathKind::Alternative => {
+                let elements = Self::parse_list(shapes, value, depth)?;
+                if elements.len() < 2 {
+                    return Err(ShapesGraphError::msg(format!(
+                        "the alternative path {node} must have at least two members"
+                    )));
+                }
+                Self::Alternative(elements)
+            }
+            PathKind::ZeroOrMore => {
+                Self::ZeroOrMore(Box::new(Self::parse_with_depth(shapes, value, depth + 1)?))
+            }
+            PathKind::OneOrMore => {
+                Self::OneOrMore(Box::new(Self::parse_with_depth(shapes, value, depth + 1)?))
+            }
+            PathKind::ZeroOrOne => {
+                Self::ZeroOrOne(Box::new(Self::parse_with_depth(shapes, value, depth + 1)?))
+            }
+        })
+    }
+
+    fn invalid_path(node: TermRef<'_>) -> ShapesGraphError {
+        ShapesGraphError::msg(format!("{node} is not a valid property path"))
+    }
+
+    fn parse_list(
+        shapes: &Graph,
+        list: TermRef<'_>,
+        depth: usize,
+    ) -> Result<Vec<Self>, ShapesGraphError> {
+        parse_list(shapes, list)?
+            .iter()
+            .map(|element| Self::parse_with_depth(shapes, element.as_ref(), depth + 1))
+            .collect()
+    }
+
+    /// Writes the path in its RDF representation and returns the path root node.
+    pub(super) fn write(&self, graph: &mut Graph) -> Term {
+        match self {
+            Self::Predicate(p) => p.clone().into(),
+            Self::Inverse(p) => Self::write_unary(graph, sh::INVERSE_PATH, p),
+            Self::Sequence(elements) => Self::write_list(graph, elements),
+            Self::Alternative(elements) => {
+                let node = BlankNode::default();
+                let list = Self::write_list(graph, elements);
+                graph.insert(TripleRef::new(&node, sh::ALTERNATIVE_PATH, &list));
+                node.into()
+            }
+            Self::ZeroOrMore(p) => Self::write_unary(graph, sh::ZERO_OR_MORE_PATH, p),
+            Self::OneOrMore(p) => Self::write_unary(graph, sh::ONE_OR_MORE_PATH, p),
+            Self::ZeroOrOne(p) => Self::write_unary(graph, sh::ZERO_OR_ONE_PATH, p),
+        }
+    }
+
+    fn write_unary(graph: &mut Graph, predicate: NamedNodeRef<'_>, path: &Self) -> Term {
+        let node = BlankNode::default();
+        let value = path.write(graph);
+        graph.insert(TripleRef::new(&node, predicate, &value));
+        node.into()
+    }
+
+    fn write_list(graph: &mut Graph, elements: &[Self]) -> Term {
+        let mut list = Term::from(rdf::NIL.into_owned());
+        for element in elements.iter().rev() {
+            let node = BlankNode::default();
+            let value = element.write(graph);
+            graph.insert(TripleRef::new(&node, rdf::FIRST, &value));
+            graph.insert(TripleRef::new(&node, rdf::REST, &list));
+            list = node.into();
+        }
+        list
+    }
+
+    /// Returns the set of nodes reachable from `node` through this path.
+    pub(super) fn evaluate<D: DataGraph>(
+        &self,
+        data: &D,
+        node: &Term,
+    ) -> Result<Vec<Term>, D::Error> {
+        self.evaluate_from(data, vec![node.clone()], false)
+    }
+
+    fn evaluate_from<D: DataGraph>(
+        &self,
+        data: &D,
+        nodes: Vec<Term>,
+        inverse: bool,
+    ) -> Result<Vec<Term>, D::Error> {
+        Ok(match self {
+            Self::Predicate(p) => {
+                let mut output = UniqueTerms::default();
+                for node in &nodes {
+                    if inverse {
+                        for subject in data.subjects(p.as_ref(), node.as_ref())? {
+                            output.insert(subject.into());
+                        }
+                    } else if let Some(subject) = as_subject(node) {
+                        for object in data.objects(subject, p.as_ref())? {
+                            output.insert(object);
+                        }
+                    }
+                }
+                output.terms
+            }
+            Self::Inverse(p) => p.evaluate_from(data, nodes, !inverse)?,
+            Self::Sequence(elements) => {
+                let mut current = nodes;
+                if inverse {
+                    for element in elements.iter().rev() {
+                        current = element.evaluate_from(data, current, inverse)?;
+                    }
+                } else {
+                    for element in elements {
+                        current = element.evaluate_from(data, current, inverse)?;
+                    }
+                }
+                current
+            }
+            Self::Alternative(elements) => {
+                let mut output = UniqueTerms::default();
+                for element in elements {
+                    for term in element.evaluate_from(data, nodes.clone(), inverse)? {
+                        output.insert(term);
+                    }
+                }
+                output.terms
+            }
+            Self::ZeroOrMore(p) => {
+                let mut output = UniqueTerms::default();
+                for node in &nodes {
+                    output.insert(node.clone());
+                }
+                Self::transitive_closure(p, data, nodes, inverse, output)?
+            }
+            Self::OneOrMore(p) => {
+                Self::transitive_closure(p, data, nodes, inverse, UniqueTerms::default())?
+            }
+            Self::ZeroOrOne(p) => {
+                let mut output = UniqueTerms::default();
+                for node in &nodes {
+                    output.insert(node.clone());
+                }
+                for term in p.evaluate_from(data, nodes, inverse)? {
+                    output.insert(term);
+                }
+                output.terms
+            }
+        })
+    }
+
+    fn transitive_closure<D: DataGraph>(
+        path: &Self,
+        data: &D,
+        nodes: Vec<Term>,
+        inverse: bool,
+        mut output: UniqueTerms,
+    ) -> Result<Vec<Term>, D::Error> {
+        let mut frontier = nodes;
+        while !frontier.is_empty() {
+            frontier = path
+                .evaluate_from(data, frontier, inverse)?
+                .into_iter()
+                .filter(|term| output.insert(term.clone()))
+                .collect();
+        }
+        Ok(output.terms)
+    }
+}
+
+impl fmt::Display for ShaclPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Predicate(p) => p.fmt(f),
+            Self::Inverse(p) => write!(f, "^({p})"),
+            Self::Sequence(elements) => fmt_list(f, elements, " / "),
+            Self::Alternative(elements) => fmt_list(f, elements, " | "),
+            Self::ZeroOrMore(p) => write!(f, "({p})*"),
+            Self::OneOrMore(p) => write!(f, "({p})+"),
+            Self::ZeroOrOne(p) => write!(f, "({p})?"),
+        }
+    }
+}
+
+fn fmt_list(f: &mut fmt::Formatter<'_>, elements: &[ShaclPath], separator: &str) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{element}")?;
+    }
+    f.write_str(")")
+}
+
+enum PathKind {
+    Inverse,
+    Alternative,
+    ZeroOrMore,
+    OneOrMore,
+    ZeroOrOne,
+}
+
+/// Terms in insertion order without duplicates.
+#[derive(Default)]
+struct UniqueTerms {
+    terms: Vec<Term>,
+    seen: FxHashSet<Term>,
+}
+
+impl UniqueTerms {
+    fn insert(&mut self, term: Term) -> bool {
+        if self.seen.insert(term.clone()) {
+            self.terms.push(term);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+pub(super) fn as_subject(term: &Term) -> Option<NamedOrBlankNodeRef<'_>> {
+    match term {
+        Term::NamedNode(node) => Some(node.into()),
+        Term::BlankNode(node) => Some(node.into()),
+        Term::Literal(_) => None,
+        #[cfg(feature = "rdf-12")]
+        Term::Triple(_) => None,
+    }
+}
@@ -0,0 +1,173 @@
+use crate::model::vocab::{rdf, xsd};
+use crate::model::{
+    BlankNode, Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, Term, TripleRef,
+};
+use crate::shacl::path::ShaclPath;
+use crate::shacl::vocab as sh;
+use std::fmt;
+
+/// The [severity](https://www.w3.org/TR/shacl/#severity) of a validation result.
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub enum Severity {
+    /// `sh:Info`
+    Info,
+    /// `sh:Warning`
+    Warning,
+    /// `sh:Violation`, the default severity
+    Violation,
+    /// A custom severity
+    Other(NamedNode),
+}
+
+impl Severity {
+    /// The severity IRI.
+    pub fn as_named_node(&self) -> NamedNodeRef<'_> {
+        match self {
+            Self::Info => sh::INFO,
+            Self::Warning => sh::WARNING,
+            Self::Violation => sh::VIOLATION,
+            Self::Other(severity) => severity.as_ref(),
+        }
+    }
+}
+
+impl From<NamedNodeRef<'_>> for Severity {
+    fn from(severity: NamedNodeRef<'_>) -> Self {
+        if severity == sh::INFO {
+            Self::Info
+        } else if severity == sh::WARNING {
+            Self::Warning
+        } else if severity == sh::VIOLATION {
+            Self::Violation
+        } else {
+            Self::Other(severity.into_owned())
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_named_node().fmt(f)
+    }
+}
+
+/// A [SHACL validation result](https://www.w3.org/TR/shacl/#results-validation-result).
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ValidationResult {
+    /// The focus node that has caused the result (`sh:focusNode`).
+    pub focus_node: Term,
+    /// The path of the property shape that has caused the result, if any (`sh:resultPath`).
+    pub result_path: Option<ShaclPath>,
+    /// The value node that has caused the result, if any (`sh:value`).
+    pub value: Option<Term>,
+    /// The shape that the focus node has been validated against (`sh:sourceShape`).
+    pub source_shape: NamedOrBlankNode,
+    /// The constraint component that has caused the result (`sh:sourceConstraintComponent`).
+    pub source_constraint_component: NamedNode,
+    /// The severity of the shape (`sh:resultSeverity`).
+    pub severity: Severity,
+    /// The messages of the shape (`sh:resultMessage`).
+    pub messages: Vec<Literal>,
+}
+
+impl fmt::Display for ValidationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on focus node {}",
+            self.source_constraint_component, self.focus_node
+        )?;
+        if let Some(path) = &self.result_path {
+            write!(f, " with path {path}")?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, " and value {value}")?;
+        }
+        write!(f, " from shape {}", self.source_shape)?;
+        for message in &self.messages {
+            write!(f, ": {}", message.value())?;
+        }
+        Ok(())
+    }
+}
+
+/// A [SHACL validation report](https://www.w3.org/TR/shacl/#validation-report) returned by [`ShaclValidator`](super::ShaclValidator).
+#[derive(Eq, PartialEq, Debug, Clone, Default)]
+pub struct ValidationReport {
+    results: Vec<ValidationResult>,
+}
+
+impl ValidationReport {
+    pub(super) fn new(results: Vec<ValidationResult>) -> Self {
+        Self { results }
+    }
+
+    /// If the data graph conforms to the shapes graph, i.e. there is no validation result whatever its severity (`sh:conforms`).
+    pub fn conforms(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// The validation results (`sh:result`).
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+
+    /// Builds the standard RDF representation of the report, rooted in a blank node of type `sh:ValidationReport`.
+    pub fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new();
+        let report = BlankNode::default();
+        graph.insert(TripleRef::new(&report, rdf::TYPE, sh::VALIDATION_REPORT));
+        graph.insert(TripleRef::new(
+            &report,
+            sh::CONFORMS,
+            &Literal::new_typed_literal(
+                if self.conforms() { "true" } else { "false" },
+                xsd::BOOLEAN,
+            ),
+        ));
+        for result in &self.results {
+            let node = BlankNode::default();
+            graph.insert(TripleRef::new(&report, sh::RESULT, &node));
+            graph.insert(TripleRef::new(&node, rdf::TYPE, sh::VALIDATION_RESULT));
+            graph.insert(TripleRef::new(&node, sh::FOCUS_NODE, &result.focus_node));
+            if let Some(path) = &result.result_path {
+                let path = path.write(&mut graph);
+                graph.insert(TripleRef::new(&node, sh::RESULT_PATH, &path));
+            }
+            if let Some(value) = &result.value {
+                graph.insert(TripleRef::new(&node, sh::VALUE, value));
+            }
+            graph.insert(TripleRef::new(
+                &node,
+                sh::SOURCE_SHAPE,
+                &result.source_shape,
+            ));
+            graph.insert(TripleRef::new(
+                &node,
+                sh::SOURCE_CONSTRAINT_COMPONENT,
+                &result.source_constraint_component,
+            ));
+            graph.insert(TripleRef::new(
+                &node,
+                sh::RESULT_SEVERITY,
+                result.severity.as_named_node(),
+            ));
+            for message in &result.messages {
+                graph.insert(TripleRef::new(&node, sh::RESULT_MESSAGE, message));
+            }
+        }
+        graph
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.conforms() {
+            return f.write_str("The data graph conforms to the shapes graph");
+        }
+        for result in &self.results {
+            writeln!(f, "{result}")?;
+        }
+        Ok(())
+    }
+}
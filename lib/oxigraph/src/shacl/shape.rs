@@ -0,0 +1,485 @@
+use crate::model::vocab::{rdf, rdfs, xsd};
+use crate::model::{
+    Graph, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term, TermRef,
+    TripleRef,
+};
+use crate::shacl::error::ShapesGraphError;
+use crate::shacl::path::ShaclPath;
+use crate::shacl::report::Severity;
+use crate::shacl::vocab as sh;
+use regex::{Regex, RegexBuilder};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+const REGEX_SIZE_LIMIT: usize = 1_000_000;
+
+pub(super) type ShapeId = usize;
+
+pub(super) struct Shape {
+    pub id: NamedOrBlankNode,
+    pub path: Option<ShaclPath>,
+    pub targets: Vec<Target>,
+    pub deactivated: bool,
+    pub severity: Severity,
+    pub messages: Vec<Literal>,
+    pub constraints: Vec<Constraint>,
+}
+
+pub(super) enum Target {
+    Node(Term),
+    Class(Term),
+    SubjectsOf(NamedNode),
+    ObjectsOf(NamedNode),
+}
+
+pub(super) enum Constraint {
+    Class(Term),
+    Datatype(NamedNode),
+    NodeKind(NodeKind),
+    MinCount(usize),
+    MaxCount(usize),
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Regex),
+    LanguageIn(Vec<String>),
+    UniqueLang,
+    In(Vec<Term>),
+    HasValue(Term),
+    Equals(NamedNode),
+    Disjoint(NamedNode),
+    Closed(FxHashSet<NamedNode>),
+    Node(ShapeId),
+    Property(ShapeId),
+    Not(ShapeId),
+    And(Vec<ShapeId>),
+    Or(Vec<ShapeId>),
+    Xone(Vec<ShapeId>),
+}
+
+impl Constraint {
+    pub fn component(&self) -> NamedNodeRef<'static> {
+        match self {
+            Self::Class(_) => sh::CLASS_CONSTRAINT_COMPONENT,
+            Self::Datatype(_) => sh::DATATYPE_CONSTRAINT_COMPONENT,
+            Self::NodeKind(_) => sh::NODE_KIND_CONSTRAINT_COMPONENT,
+            Self::MinCount(_) => sh::MIN_COUNT_CONSTRAINT_COMPONENT,
+            Self::MaxCount(_) => sh::MAX_COUNT_CONSTRAINT_COMPONENT,
+            Self::MinLength(_) => sh::MIN_LENGTH_CONSTRAINT_COMPONENT,
+            Self::MaxLength(_) => sh::MAX_LENGTH_CONSTRAINT_COMPONENT,
+            Self::Pattern(_) => sh::PATTERN_CONSTRAINT_COMPONENT,
+            Self::LanguageIn(_) => sh::LANGUAGE_IN_CONSTRAINT_COMPONENT,
+            Self::UniqueLang => sh::UNIQUE_LANG_CONSTRAINT_COMPONENT,
+            Self::In(_) => sh::IN_CONSTRAINT_COMPONENT,
+            Self::HasValue(_) => sh::HAS_VALUE_CONSTRAINT_COMPONENT,
+            Self::Equals(_) => sh::EQUALS_CONSTRAINT_COMPONENT,
+            Self::Disjoint(_) => sh::DISJOINT_CONSTRAINT_COMPONENT,
+            Self::Closed(_) => sh::CLOSED_CONSTRAINT_COMPONENT,
+            Self::Node(_) => sh::NODE_CONSTRAINT_COMPONENT,
+            Self::Property(_) => sh::PROPERTY_CONSTRAINT_COMPONENT,
+            Self::Not(_) => sh::NOT_CONSTRAINT_COMPONENT,
+            Self::And(_) => sh::AND_CONSTRAINT_COMPONENT,
+            Self::Or(_) => sh::OR_CONSTRAINT_COMPONENT,
+            Self::Xone(_) => sh::XONE_CONSTRAINT_COMPONENT,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum NodeKind {
+    Iri,
+    BlankNode,
+    Literal,
+    BlankNodeOrIri,
+    BlankNodeOrLiteral,
+    IriOrLiteral,
+}
+
+impl NodeKind {
+    pub fn matches(self, term: &Term) -> bool {
+        let (iri, blank_node, literal) = match self {
+            Self::Iri => (true, false, false),
+            Self::BlankNode => (false, true, false),
+            Self::Literal => (false, false, true),
+            Self::BlankNodeOrIri => (true, true, false),
+            Self::BlankNodeOrLiteral => (false, true, true),
+            Self::IriOrLiteral => (true, false, true),
+        };
+        match term {
+            Term::NamedNode(_) => iri,
+            Term::BlankNode(_) => blank_node,
+            Term::Literal(_) => literal,
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(_) => false,
+        }
+    }
+}
+
+/// Parses all the shapes of the shapes graph.
+///
+/// Shapes are allocated before being parsed so that recursive references between shapes are supported.
+pub(super) fn parse_shapes(graph: &Graph) -> Result<Vec<Shape>, ShapesGraphError> {
+    let mut parser = ShapesParser {
+        graph,
+        ids: FxHashMap::default(),
+        to_parse: VecDeque::new(),
+    };
+    for class in [sh::NODE_SHAPE, sh::PROPERTY_SHAPE] {
+        for shape in graph.subjects_for_predicate_object(rdf::TYPE, class) {
+            parser.shape_id(shape.into())?;
+        }
+    }
+    for target in [
+        sh::TARGET_NODE,
+        sh::TARGET_CLASS,
+        sh::TARGET_SUBJECTS_OF,
+        sh::TARGET_OBJECTS_OF,
+    ] {
+        for triple in graph.triples_for_predicate(target) {
+            parser.shape_id(triple.subject.into())?;
+        }
+    }
+    let mut shapes = Vec::with_capacity(parser.ids.len());
+    while let Some(node) = parser.to_parse.pop_front() {
+        shapes.push(parser.parse_shape(&node)?);
+    }
+    Ok(shapes)
+}
+
+struct ShapesParser<'a> {
+    graph: &'a Graph,
+    ids: FxHashMap<NamedOrBlankNode, ShapeId>,
+    to_parse: VecDeque<NamedOrBlankNode>,
+}
+
+impl ShapesParser<'_> {
+    fn shape_id(&mut self, node: TermRef<'_>) -> Result<ShapeId, ShapesGraphError> {
+        let node = match node {
+            TermRef::NamedNode(node) => NamedOrBlankNode::from(node.into_owned()),
+            TermRef::BlankNode(node) => node.into_owned().into(),
+            TermRef::Literal(_) => return Err(invalid_shape(node)),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(_) => return Err(invalid_shape(node)),
+        };
+        if let Some(id) = self.ids.get(&node) {
+            return Ok(*id);
+        }
+        let id = self.ids.len();
+        self.ids.insert(node.clone(), id);
+        self.to_parse.push_back(node);
+        Ok(id)
+    }
+
+    fn shape_ids(&mut self, list: TermRef<'_>) -> Result<Vec<ShapeId>, ShapesGraphError> {
+        parse_list(self.graph, list)?
+            .iter()
+            .map(|shape| self.shape_id(shape.as_ref()))
+            .collect()
+    }
+
+    fn parse_shape(&mut self, node: &NamedOrBlankNode) -> Result<Shape, ShapesGraphError> {
+        let graph = self.graph;
+        let mut shape = Shape {
+            id: node.clone(),
+            path: None,
+            targets: Vec::new(),
+            deactivated: false,
+            severity: Severity::Violation,
+            messages: Vec::new(),
+            constraints: Vec::new(),
+        };
+        if let NamedOrBlankNode::NamedNode(class) = node {
+            if graph.contains(TripleRef::new(class, rdf::TYPE, rdfs::CLASS)) {
+                // Implicit class target
+                shape.targets.push(Target::Class(class.clone().into()));
+            }
+        }
+        let mut pattern = None;
+        let mut flags = None;
+        let mut closed = false;
+        let mut ignored_properties = Vec::new();
+        for triple in graph.triples_for_subject(node) {
+            let (predicate, value) = (triple.predicate, triple.object);
+            if predicate == sh::PATH {
+                if shape.path.is_some() {
+                    return Err(ShapesGraphError::msg(format!(
+                        "the shape {node} has multiple sh:path values"
+                    )));
+                }
+                shape.path = Some(ShaclPath::parse(graph, value)?);
+            } else if predicate == sh::TARGET_NODE {
+                shape.targets.push(Target::Node(value.into_owned()));
+            } else if predicate == sh::TARGET_CLASS {
+                shape.targets.push(Target::Class(value.into_owned()));
+            } else if predicate == sh::TARGET_SUBJECTS_OF {
+                shape
+                    .targets
+                    .push(Target::SubjectsOf(named_node(node, predicate, value)?));
+            } else if predicate == sh::TARGET_OBJECTS_OF {
+                shape
+                    .targets
+                    .push(Target::ObjectsOf(named_node(node, predicate, value)?));
+            } else if predicate == sh::DEACTIVATED {
+                shape.deactivated = boolean(node, predicate, value)?;
+            } else if predicate == sh::SEVERITY {
+                shape.severity = named_node(node, predicate, value)?.as_ref().into();
+            } else if predicate == sh::MESSAGE {
+                if let TermRef::Literal(message) = value {
+                    shape.messages.push(message.into_owned());
+                }
+            } else if predicate == sh::CLASS {
+                shape
+                    .constraints
+                    .push(Constraint::Class(value.into_owned()));
+            } else if predicate == sh::DATATYPE {
+                shape
+                    .constraints
+                    .push(Constraint::Datatype(named_node(node, predicate, value)?));
+            } else if predicate == sh::NODE_KIND {
+                shape
+                    .constraints
+                    .push(Constraint::NodeKind(node_kind(node, value)?));
+            } else if predicate == sh::MIN_COUNT {
+                shape
+                    .constraints
+                    .push(Constraint::MinCount(integer(node, predicate, value)?));
+            } else if predicate == sh::MAX_COUNT {
+                shape
+                    .constraints
+                    .push(Constraint::MaxCount(integer(node, predicate, value)?));
+            } else if predicate == sh::MIN_LENGTH {
+                shape
+                    .constraints
+                    .push(Constraint::MinLength(integer(node, predicate, value)?));
+            } else if predicate == sh::MAX_LENGTH {
+                shape
+                    .constraints
+                    .push(Constraint::MaxLength(integer(node, predicate, value)?));
+            } else if predicate == sh::PATTERN {
+                if pattern.is_some() {
+                    return Err(ShapesGraphError::msg(format!(
+                        "the shape {node} has multiple sh:pattern values"
+                    )));
+                }
+                pattern = Some(string(node, predicate, value)?);
+            } else if predicate == sh::FLAGS {
+                flags = Some(string(node, predicate, value)?);
+            } else if predicate == sh::LANGUAGE_IN {
+                shape.constraints.push(Constraint::LanguageIn(
+                    parse_list(graph, value)?
+                        .iter()
+                        .map(|range| Ok(string(node, predicate, range.as_ref())?.to_owned()))
+                        .collect::<Result<_, ShapesGraphError>>()?,
+                ));
+            } else if predicate == sh::UNIQUE_LANG {
+                if boolean(node, predicate, value)? {
+                    shape.constraints.push(Constraint::UniqueLang);
+                }
+            } else if predicate == sh::IN {
+                shape
+                    .constraints
+                    .push(Constraint::In(parse_list(graph, value)?));
+            } else if predicate == sh::HAS_VALUE {
+                shape
+                    .constraints
+                    .push(Constraint::HasValue(value.into_owned()));
+            } else if predicate == sh::EQUALS {
+                shape
+                    .constraints
+                    .push(Constraint::Equals(named_node(node, predicate, value)?));
+            } else if predicate == sh::DISJOINT {
+                shape
+                    .constraints
+                    .push(Constraint::Disjoint(named_node(node, predicate, value)?));
+            } else if predicate == sh::CLOSED {
+                closed = boolean(node, predicate, value)?;
+            } else if predicate == sh::IGNORED_PROPERTIES {
+                for property in parse_list(graph, value)? {
+                    ignored_properties.push(named_node(node, predicate, property.as_ref())?);
+                }
+            } else if predicate == sh::NODE {
+                let id = self.shape_id(value)?;
+                shape.constraints.push(Constraint::Node(id));
+            } else if predicate == sh::PROPERTY {
+                let id = self.shape_id(value)?;
+                shape.constraints.push(Constraint::Property(id));
+            } else if predicate == sh::NOT {
+                let id = self.shape_id(value)?;
+                shape.constraints.push(Constraint::Not(id));
+            } else if predicate == sh::AND {
+                let ids = self.shape_ids(value)?;
+                shape.constraints.push(Constraint::And(ids));
+            } else if predicate == sh::OR {
+                let ids = self.shape_ids(value)?;
+                shape.constraints.push(Constraint::Or(ids));
+            } else if predicate == sh::XONE {
+                let ids = self.shape_ids(value)?;
+                shape.constraints.push(Constraint::Xone(ids));
+            }
+        }
+        if let Some(pattern) = pattern {
+            shape.constraints.push(Constraint::Pattern(
+                compile_pattern(pattern, flags).ok_or_else(|| {
+                    ShapesGraphError::msg(format!(
+                        "the shape {node} has an invalid sh:pattern: {pattern}"
+                    ))
+                })?,
+            ));
+        }
+        if closed {
+            let mut allowed = ignored_properties.into_iter().collect::<FxHashSet<_>>();
+            for property in graph.objects_for_subject_predicate(node, sh::PROPERTY) {
+                let property = match property {
+                    TermRef::NamedNode(property) => NamedOrBlankNodeRef::from(property),
+                    TermRef::BlankNode(property) => property.into(),
+                    TermRef::Literal(_) => continue,
+                    #[cfg(feature = "rdf-12")]
+                    TermRef::Triple(_) => continue,
+                };
+                if let Some(TermRef::NamedNode(path)) =
+                    graph.object_for_subject_predicate(property, sh::PATH)
+                {
+                    allowed.insert(path.into_owned());
+                }
+            }
+            shape.constraints.push(Constraint::Closed(allowed));
+        }
+        Ok(shape)
+    }
+}
+
+/// Parses an RDF list.
+pub(super) fn parse_list(graph: &Graph, list: TermRef<'_>) -> Result<Vec<Term>, ShapesGraphError> {
+    let mut elements = Vec::new();
+    let mut seen = FxHashSet::default();
+    let mut current = list;
+    loop {
+        let node = match current {
+            TermRef::NamedNode(node) if node == rdf::NIL => return Ok(elements),
+            TermRef::NamedNode(node) => NamedOrBlankNodeRef::from(node),
+            TermRef::BlankNode(node) => node.into(),
+            TermRef::Literal(_) => return Err(invalid_list(list)),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(_) => return Err(invalid_list(list)),
+        };
+        if !seen.insert(node) {
+            return Err(ShapesGraphError::msg(format!("the list {list} is cyclic")));
+        }
+        let (Some(first), Some(rest)) = (
+            graph.object_for_subject_predicate(node, rdf::FIRST),
+            graph.object_for_subject_predicate(node, rdf::REST),
+        ) else {
+            return Err(invalid_list(list));
+        };
+        elements.push(first.into_owned());
+        current = rest;
+    }
+}
+
+fn invalid_shape(node: TermRef<'_>) -> ShapesGraphError {
+    ShapesGraphError::msg(format!("{node} is not a valid shape"))
+}
+
+fn invalid_list(list: TermRef<'_>) -> ShapesGraphError {
+    ShapesGraphError::msg(format!("{list} is not a valid list"))
+}
+
+fn named_node(
+    shape: &NamedOrBlankNode,
+    predicate: NamedNodeRef<'_>,
+    value: TermRef<'_>,
+) -> Result<NamedNode, ShapesGraphError> {
+    if let TermRef::NamedNode(value) = value {
+        Ok(value.into_owned())
+    } else {
+        Err(ShapesGraphError::invalid_value(shape, predicate, value))
+    }
+}
+
+fn string<'a>(
+    shape: &NamedOrBlankNode,
+    predicate: NamedNodeRef<'_>,
+    value: TermRef<'a>,
+) -> Result<&'a str, ShapesGraphError> {
+    if let TermRef::Literal(value) = value {
+        Ok(value.value())
+    } else {
+        Err(ShapesGraphError::invalid_value(shape, predicate, value))
+    }
+}
+
+fn boolean(
+    shape: &NamedOrBlankNode,
+    predicate: NamedNodeRef<'_>,
+    value: TermRef<'_>,
+) -> Result<bool, ShapesGraphError> {
+    if let TermRef::Literal(literal) = value {
+        if literal.datatype() == xsd::BOOLEAN {
+            match literal.value() {
+                "true" | "1" => return Ok(true),
+                "false" | "0" => return Ok(false),
+                _ => (),
+            }
+        }
+    }
+    Err(ShapesGraphError::invalid_value(shape, predicate, value))
+}
+
+fn integer(
+    shape: &NamedOrBlankNode,
+    predicate: NamedNodeRef<'_>,
+    value: TermRef<'_>,
+) -> Result<usize, ShapesGraphError> {
+    if let TermRef::Literal(literal) = value {
+        if literal.datatype() == xsd::INTEGER {
+            if let Ok(value) = literal.value().parse() {
+                return Ok(value);
+            }
+        }
+    }
+    Err(ShapesGraphError::invalid_value(shape, predicate, value))
+}
+
+fn node_kind(shape: &NamedOrBlankNode, value: TermRef<'_>) -> Result<NodeKind, ShapesGraphError> {
+    Ok(match value {
+        TermRef::NamedNode(value) if value == sh::IRI => NodeKind::Iri,
+        TermRef::NamedNode(value) if value == sh::BLANK_NODE => NodeKind::BlankNode,
+        TermRef::NamedNode(value) if value == sh::LITERAL => NodeKind::Literal,
+        TermRef::NamedNode(value) if value == sh::BLANK_NODE_OR_IRI => NodeKind::BlankNodeOrIri,
+        TermRef::NamedNode(value) if value == sh::BLANK_NODE_OR_LITERAL => {
+            NodeKind::BlankNodeOrLiteral
+        }
+        TermRef::NamedNode(value) if value == sh::IRI_OR_LITERAL => NodeKind::IriOrLiteral,
+        _ => return Err(ShapesGraphError::invalid_value(shape, sh::NODE_KIND, value)),
+    })
+}
+
+/// Builds a regular expression following the [XPath `fn:matches` flags](https://www.w3.org/TR/xpath-functions-31/#flags).
+fn compile_pattern(pattern: &str, flags: Option<&str>) -> Option<Regex> {
+    let mut pattern = Cow::Borrowed(pattern);
+    let flags = flags.unwrap_or_default();
+    if flags.contains('q') {
+        pattern = regex::escape(&pattern).into();
+    }
+    let mut regex_builder = RegexBuilder::new(&pattern);
+    regex_builder.size_limit(REGEX_SIZE_LIMIT);
+    for flag in flags.chars() {
+        match flag {
+            's' => {
+                regex_builder.dot_matches_new_line(true);
+            }
+            'm' => {
+                regex_builder.multi_line(true);
+            }
+            'i' => {
+                regex_builder.case_insensitive(true);
+            }
+            'x' => {
+                regex_builder.ignore_whitespace(true);
+            }
+            'q' => (),        // Already supported
+            _ => return None, // invalid option
+        }
+    }
+    regex_builder.build().ok()
+}
@@ -0,0 +1,411 @@
+use crate::model::vocab::{rdf, rdfs, xsd};
+use crate::model::{Literal, NamedNodeRef, Term};
+use crate::shacl::data::DataGraph;
+use crate::shacl::path::{ShaclPath, as_subject};
+use crate::shacl::report::ValidationResult;
+use crate::shacl::shape::{Constraint, Shape, ShapeId, Target};
+use oxsdatatypes::{
+    Base64Binary, Boolean, Date, DateTime, DayTimeDuration, Decimal, Double, Duration, Float, GDay,
+    GMonth, GMonthDay, GYear, GYearMonth, HexBinary, Integer, Time, YearMonthDuration,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::str::FromStr;
+
+pub(super) struct Validator<'a, D: DataGraph> {
+    shapes: &'a [Shape],
+    data: &'a D,
+    /// The (shape, focus node) pairs currently being validated, used to stop on recursive shapes
+    in_progress: FxHashSet<(ShapeId, Term)>,
+}
+
+impl<'a, D: DataGraph> Validator<'a, D> {
+    pub fn new(shapes: &'a [Shape], data: &'a D) -> Self {
+        Self {
+            shapes,
+            data,
+            in_progress: FxHashSet::default(),
+        }
+    }
+
+    pub fn validate(&mut self) -> Result<Vec<ValidationResult>, D::Error> {
+        let mut results = Vec::new();
+        for (id, shape) in self.shapes.iter().enumerate() {
+            if shape.deactivated {
+                continue;
+            }
+            for focus_node in self.focus_nodes(shape)? {
+                self.validate_shape(id, &focus_node, &mut results)?;
+            }
+        }
+        Ok(results)
+    }
+
+    fn focus_nodes(&self, shape: &Shape) -> Result<Vec<Term>, D::Error> {
+        let mut focus_nodes = Vec::new();
+        let mut seen = FxHashSet::default();
+        let mut add = |term: Term| {
+            if seen.insert(term.clone()) {
+                focus_nodes.push(term);
+            }
+        };
+        for target in &shape.targets {
+            match target {
+                Target::Node(node) => add(node.clone()),
+                Target::Class(class) => {
+                    for class in self.subclasses(class)? {
+                        for instance in self.data.subjects(rdf::TYPE, class.as_ref())? {
+                            add(instance.into());
+                        }
+                    }
+                }
+                Target::SubjectsOf(predicate) => {
+                    for (subject, _) in self.data.subject_objects(predicate.as_ref())? {
+                        add(subject.into());
+                    }
+                }
+                Target::ObjectsOf(predicate) => {
+                    for (_, object) in self.data.subject_objects(predicate.as_ref())? {
+                        add(object);
+                    }
+                }
+            }
+        }
+        Ok(focus_nodes)
+    }
+
+    /// The class itself and all its transitive subclasses in the data graph.
+    fn subclasses(&self, class: &Term) -> Result<Vec<Term>, D::Error> {
+        ShaclPath::ZeroOrMore(Box::new(ShaclPath::Inverse(Box::new(
+            ShaclPath::Predicate(rdfs::SUB_CLASS_OF.into_owned()),
+        ))))
+        .evaluate(self.data, class)
+    }
+
+    /// Checks if the node is a [SHACL instance](https://www.w3.org/TR/shacl/#dfn-shacl-instance) of the class.
+    fn is_instance(&self, node: &Term, class: &Term) -> Result<bool, D::Error> {
+        let Some(node) = as_subject(node) else {
+            return Ok(false);
+        };
+        let mut seen = FxHashSet::default();
+        let mut to_check = self.data.objects(node, rdf::TYPE)?;
+        while let Some(current) = to_check.pop() {
+            if current == *class {
+                return Ok(true);
+            }
+            if let Some(subject) = as_subject(&current) {
+                if seen.insert(current.clone()) {
+                    to_check.extend(self.data.objects(subject, rdfs::SUB_CLASS_OF)?);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn validate_shape(
+        &mut self,
+        id: ShapeId,
+        focus_node: &Term,
+        results: &mut Vec<ValidationResult>,
+    ) -> Result<(), D::Error> {
+        let shapes = self.shapes;
+        let shape = &shapes[id];
+        if shape.deactivated || !self.in_progress.insert((id, focus_node.clone())) {
+            // Recursive shapes are not defined by SHACL, we consider that they conform
+            return Ok(());
+        }
+        let value_nodes = if let Some(path) = &shape.path {
+            path.evaluate(self.data, focus_node)
+        } else {
+            Ok(vec![focus_node.clone()])
+        };
+        let result = value_nodes.and_then(|value_nodes| {
+            for constraint in &shape.constraints {
+                self.validate_constraint(shape, constraint, focus_node, &value_nodes, results)?;
+            }
+            Ok(())
+        });
+        self.in_progress.remove(&(id, focus_node.clone()));
+        result
+    }
+
+    fn validate_constraint(
+        &mut self,
+        shape: &Shape,
+        constraint: &Constraint,
+        focus_node: &Term,
+        value_nodes: &[Term],
+        results: &mut Vec<ValidationResult>,
+    ) -> Result<(), D::Error> {
+        let mut add = |value: Option<&Term>| {
+            results.push(new_result(shape, constraint, focus_node, value.cloned()));
+        };
+        match constraint {
+            Constraint::Class(class) => {
+                for value in value_nodes {
+                    if !self.is_instance(value, class)? {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::Datatype(datatype) => {
+                for value in value_nodes {
+                    if !matches!(value, Term::Literal(literal) if literal.datatype() == *datatype && is_well_formed(literal))
+                    {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::NodeKind(node_kind) => {
+                for value in value_nodes {
+                    if !node_kind.matches(value) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::MinCount(min) => {
+                if value_nodes.len() < *min {
+                    add(None);
+                }
+            }
+            Constraint::MaxCount(max) => {
+                if value_nodes.len() > *max {
+                    add(None);
+                }
+            }
+            Constraint::MinLength(min) => {
+                for value in value_nodes {
+                    if string_value(value).is_none_or(|s| s.chars().count() < *min) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::MaxLength(max) => {
+                for value in value_nodes {
+                    if string_value(value).is_none_or(|s| s.chars().count() > *max) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::Pattern(regex) => {
+                for value in value_nodes {
+                    if string_value(value).is_none_or(|s| !regex.is_match(s)) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::LanguageIn(ranges) => {
+                for value in value_nodes {
+                    let matches = if let Term::Literal(literal) = value {
+                        literal.language().is_some_and(|language| {
+                            ranges.iter().any(|range| language_matches(language, range))
+                        })
+                    } else {
+                        false
+                    };
+                    if !matches {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::UniqueLang => {
+                let mut counts = FxHashMap::<&str, usize>::default();
+                let mut languages = Vec::new();
+                for value in value_nodes {
+                    if let Term::Literal(literal) = value {
+                        if let Some(language) = literal.language() {
+                            let count = counts.entry(language).or_default();
+                            *count += 1;
+                            if *count == 2 {
+                                languages.push(language);
+                            }
+                        }
+                    }
+                }
+                for _ in languages {
+                    add(None);
+                }
+            }
+            Constraint::In(allowed) => {
+                for value in value_nodes {
+                    if !allowed.contains(value) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::HasValue(expected) => {
+                if !value_nodes.contains(expected) {
+                    add(None);
+                }
+            }
+            Constraint::Equals(predicate) => {
+                let others = self.objects(focus_node, predicate.as_ref())?;
+                for value in value_nodes {
+                    if !others.contains(value) {
+                        add(Some(value));
+                    }
+                }
+                for other in &others {
+                    if !value_nodes.contains(other) {
+                        add(Some(other));
+                    }
+                }
+            }
+            Constraint::Disjoint(predicate) => {
+                let others = self.objects(focus_node, predicate.as_ref())?;
+                for value in value_nodes {
+                    if others.contains(value) {
+                        add(Some(value));
+                    }
+                }
+            }
+            Constraint::Closed(allowed) => {
+                for value in value_nodes {
+                    let Some(subject) = as_subject(value) else {
+                        continue;
+                    };
+                    for (predicate, object) in self.data.predicate_objects(subject)? {
+                        if !allowed.contains(&predicate) {
+                            let mut result = new_result(shape, constraint, value, Some(object));
+                            result.result_path = Some(ShaclPath::Predicate(predicate));
+                            results.push(result);
+                        }
+                    }
+                }
+            }
+            Constraint::Node(node_shape) => {
+                for value in value_nodes {
+                    if !self.conforms(*node_shape, value)? {
+                        results.push(new_result(
+                            shape,
+                            constraint,
+                            focus_node,
+                            Some(value.clone()),
+                        ));
+                    }
+                }
+            }
+            Constraint::Property(property_shape) => {
+                for value in value_nodes {
+                    self.validate_shape(*property_shape, value, results)?;
+                }
+            }
+            Constraint::Not(negated_shape) => {
+                for value in value_nodes {
+                    if self.conforms(*negated_shape, value)? {
+                        results.push(new_result(
+                            shape,
+                            constraint,
+                            focus_node,
+                            Some(value.clone()),
+                        ));
+                    }
+                }
+            }
+            Constraint::And(shapes) | Constraint::Or(shapes) | Constraint::Xone(shapes) => {
+                for value in value_nodes {
+                    let mut conforming = 0;
+                    for member in shapes {
+                        if self.conforms(*member, value)? {
+                            conforming += 1;
+                        }
+                    }
+                    let valid = match constraint {
+                        Constraint::And(_) => conforming == shapes.len(),
+                        Constraint::Or(_) => conforming > 0,
+                        _ => conforming == 1,
+                    };
+                    if !valid {
+                        results.push(new_result(
+                            shape,
+                            constraint,
+                            focus_node,
+                            Some(value.clone()),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn conforms(&mut self, shape: ShapeId, node: &Term) -> Result<bool, D::Error> {
+        let mut results = Vec::new();
+        self.validate_shape(shape, node, &mut results)?;
+        Ok(results.is_empty())
+    }
+
+    fn objects(&self, node: &Term, predicate: NamedNodeRef<'_>) -> Result<Vec<Term>, D::Error> {
+        if let Some(subject) = as_subject(node) {
+            self.data.objects(subject, predicate)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+}
+
+fn new_result(
+    shape: &Shape,
+    constraint: &Constraint,
+    focus_node: &Term,
+    value: Option<Term>,
+) -> ValidationResult {
+    ValidationResult {
+        focus_node: focus_node.clone(),
+        result_path: shape.path.clone(),
+        value,
+        source_shape: shape.id.clone(),
+        source_constraint_component: constraint.component().into_owned(),
+        severity: shape.severity.clone(),
+        messages: shape.messages.clone(),
+    }
+}
+
+/// The string used by `sh:minLength`, `sh:maxLength` and `sh:pattern`, `None` for blank nodes
+fn string_value(term: &Term) -> Option<&str> {
+    match term {
+        Term::NamedNode(node) => Some(node.as_str()),
+        Term::Literal(literal) => Some(literal.value()),
+        Term::BlankNode(_) => None,
+        #[cfg(feature = "rdf-12")]
+        Term::Triple(_) => None,
+    }
+}
+
+/// [Basic filtering](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1) of language tags
+fn language_matches(language: &str, range: &str) -> bool {
+    if range == "*" {
+        return !language.is_empty();
+    }
+    let (language, range) = (language.as_bytes(), range.as_bytes());
+    language
+        .get(..range.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(range))
+        && language.get(range.len()).is_none_or(|c| *c == b'-')
+}
+
+/// Checks that the literal lexical form is valid for its datatype, if the datatype is supported
+fn is_well_formed(literal: &Literal) -> bool {
+    let value = literal.value();
+    match literal.datatype() {
+        xsd::BOOLEAN => Boolean::from_str(value).is_ok(),
+        xsd::DECIMAL => Decimal::from_str(value).is_ok(),
+        xsd::INTEGER => Integer::from_str(value).is_ok(),
+        xsd::DOUBLE => Double::from_str(value).is_ok(),
+        xsd::FLOAT => Float::from_str(value).is_ok(),
+        xsd::DATE => Date::from_str(value).is_ok(),
+        xsd::DATE_TIME => DateTime::from_str(value).is_ok(),
+        xsd::TIME => Time::from_str(value).is_ok(),
+        xsd::G_YEAR => GYear::from_str(value).is_ok(),
+        xsd::G_YEAR_MONTH => GYearMonth::from_str(value).is_ok(),
+        xsd::G_MONTH => GMonth::from_str(value).is_ok(),
+        xsd::G_MONTH_DAY => GMonthDay::from_str(value).is_ok(),
+        xsd::G_DAY => GDay::from_str(value).is_ok(),
+        xsd::DURATION => Duration::from_str(value).is_ok(),
+        xsd::DAY_TIME_DURATION => DayTimeDuration::from_str(value).is_ok(),
+        xsd::YEAR_MONTH_DURATION => YearMonthDuration::from_str(value).is_ok(),
+        xsd::HEX_BINARY => HexBinary::from_str(value).is_ok(),
+        xsd::BASE_64_BINARY => Base64Binary::from_str(value).is_ok(),
+        rdf::LANG_STRING => literal.language().is_some(),
+        _ => true,
+    }
+}
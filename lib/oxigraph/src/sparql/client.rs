@@ -0,0 +1,275 @@
+use crate::io::{RdfFormat, RdfParser};
+use crate::model::Triple;
+use crate::sparql::UpdateEvaluationError;
+use crate::sparql::http::{Client, check_status, content_type, invalid_input_error};
+use oxhttp::model::header::{ACCEPT, CONTENT_TYPE};
+use oxhttp::model::{Body, Method, Request, Response, StatusCode};
+use oxiri::{Iri, IriParseError};
+use sparesults::{QueryResultsFormat, QueryResultsParser, ReaderQueryResultsParserOutput};
+use spareval::{
+    QueryEvaluationError, QueryResults, QuerySolutionIter, QueryTripleIter, ServiceHandler,
+};
+use spargebra::algebra::GraphPattern;
+use std::error::Error;
+use std::io;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// The media types accepted for query results, the query form is not known in advance
+const QUERY_ACCEPT: &str = "application/sparql-results+json, application/sparql-results+xml;q=0.9, text/tab-separated-values;q=0.8, application/n-triples, text/turtle;q=0.9, application/rdf+xml;q=0.8";
+const SOLUTIONS_ACCEPT: &str = "application/sparql-results+json, application/sparql-results+xml";
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// A client for remote endpoints implementing the [SPARQL 1.1 Protocol](https://www.w3.org/TR/sparql11-protocol/).
+///
+/// Queries and updates are sent with POST requests.
+/// The query results are parsed while they are read and returned with the same types as the local query evaluation.
+///
+/// It is also a [`ServiceHandler`]: it could be given to [`SparqlEvaluator::with_service_handler`](super::SparqlEvaluator::with_service_handler) to evaluate the `SERVICE` calls to its endpoint,
+/// for example to authenticate them.
+/// The default `SERVICE` handler is a client without options.
+///
+/// Usage example:
+/// ```no_run
+/// use oxigraph::sparql::{QueryResults, SparqlClient};
+/// use std::time::Duration;
+///
+/// let client = SparqlClient::new("http://example.com/sparql")?
+///     .with_timeout(Duration::from_secs(10))
+///     .with_header("Authorization", "Bearer my-token")
+///     .with_max_retries(3);
+/// if let QueryResults::Solutions(solutions) = client.query("SELECT * WHERE { ?s ?p ?o }")? {
+///     for solution in solutions {
+///         println!("{:?}", solution?.get("s"));
+///     }
+/// }
+/// client.update("INSERT DATA { <http://example.com/s> <http://example.com/p> 1 }")?;
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Clone)]
+#[must_use]
+pub struct SparqlClient {
+    client: Client,
+    endpoint: String,
+    update_endpoint: Option<String>,
+    timeout: Option<Duration>,
+    redirection_limit: usize,
+    headers: Vec<(String, String)>,
+    max_retries: usize,
+    retry_delay: Duration,
+}
+
+impl SparqlClient {
+    /// Builds a client for the endpoint at the given URL.
+    pub fn new(endpoint: impl Into<String>) -> Result<Self, IriParseError> {
+        Ok(Self::from_client(
+            Client::new(None, 0),
+            Iri::parse(endpoint.into())?.into_inner(),
+        ))
+    }
+
+    pub(crate) fn from_client(client: Client, endpoint: String) -> Self {
+        Self {
+            client,
+            endpoint,
+            update_endpoint: None,
+            timeout: None,
+            redirection_limit: 0,
+            headers: Vec::new(),
+            max_retries: 0,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Sends the updates to a different URL than the queries.
+    ///
+    /// Some endpoints expose the SPARQL update operation at its own URL, like `/update`.
+    pub fn with_update_endpoint(
+        mut self,
+        endpoint: impl Into<String>,
+    ) -> Result<Self, IriParseError> {
+        self.update_endpoint = Some(Iri::parse(endpoint.into())?.into_inner());
+        Ok(self)
+    }
+
+    /// Sets a timeout for each HTTP request.
+    #[inline]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.client = Client::new(self.timeout, self.redirection_limit);
+        self
+    }
+
+    /// Sets an upper bound to the number of HTTP redirections followed per HTTP request.
+    ///
+    /// By default, this value is `0`.
+    #[inline]
+    pub fn with_redirection_limit(mut self, redirection_limit: usize) -> Self {
+        self.redirection_limit = redirection_limit;
+        self.client = Client::new(self.timeout, self.redirection_limit);
+        self
+    }
+
+    /// Adds a header to all the requests, like an `Authorization` header.
+    ///
+    /// The requests fail if the header name or value is not valid.
+    #[inline]
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Retries at most `max_retries` times the requests for which the endpoint answers `503 Service Unavailable`.
+    ///
+    /// The delay before the first retry is set with [`with_retry_delay`](Self::with_retry_delay) and doubled after each retry.
+    /// By default, the requests are not retried.
+    #[inline]
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, one second by default.
+    #[inline]
+    pub fn with_retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Evaluates a query on the endpoint.
+    ///
+    /// The query is not parsed locally, it might use extensions supported by the endpoint.
+    /// The kind of results is chosen from the `Content-Type` of the response.
+    pub fn query(&self, query: &str) -> Result<QueryResults<'static>, QueryEvaluationError> {
+        let (content_type, body) = self
+            .send_query(query, QUERY_ACCEPT)
+            .map_err(service_error)?;
+        if let Some(format) = QueryResultsFormat::from_media_type(&content_type) {
+            return Ok(
+                match QueryResultsParser::from_format(format)
+                    .for_reader(body)
+                    .map_err(service_error)?
+                {
+                    ReaderQueryResultsParserOutput::Solutions(reader) => {
+                        QueryResults::Solutions(QuerySolutionIter::new(
+                            reader.variables().into(),
+                            reader.map(|s| s.map_err(service_error)),
+                        ))
+                    }
+                    ReaderQueryResultsParserOutput::Boolean(value) => QueryResults::Boolean(value),
+                },
+            );
+        }
+        if let Some(format) = RdfFormat::from_media_type(&content_type) {
+            return Ok(QueryResults::Graph(QueryTripleIter::new(
+                RdfParser::from_format(format)
+                    .for_reader(body)
+                    .map(|q| q.map(Triple::from).map_err(service_error)),
+            )));
+        }
+        Err(QueryEvaluationError::Service(
+            self.unsupported_content_type(&content_type),
+        ))
+    }
+
+    /// Executes an update on the endpoint.
+    pub fn update(&self, update: &str) -> Result<(), UpdateEvaluationError> {
+        self.post(
+            self.update_endpoint.as_ref().unwrap_or(&self.endpoint),
+            "application/sparql-update",
+            update,
+            "*/*",
+        )
+        .map_err(|e| UpdateEvaluationError::Service(Box::new(e)))?;
+        Ok(())
+    }
+
+    /// Sends the query and returns the media type and the content of the results
+    fn send_query(&self, query: &str, accept: &'static str) -> io::Result<(String, Body)> {
+        let response = self.post(&self.endpoint, "application/sparql-query", query, accept)?;
+        Ok((
+            content_type(&self.endpoint, &response)?,
+            response.into_body(),
+        ))
+    }
+
+    /// Sends the query or update, retrying while the endpoint is unavailable
+    fn post(
+        &self,
+        url: &str,
+        media_type: &'static str,
+        payload: &str,
+        accept: &'static str,
+    ) -> io::Result<Response<Body>> {
+        let mut retry_delay = self.retry_delay;
+        let mut retries = 0;
+        loop {
+            let mut request = Request::builder()
+                .method(Method::POST)
+                .uri(url)
+                .header(ACCEPT, accept)
+                .header(CONTENT_TYPE, media_type);
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            let request = request
+                .body(payload.as_bytes().to_vec())
+                .map_err(invalid_input_error)?;
+            let response = self.client.send(request)?;
+            if response.status() != StatusCode::SERVICE_UNAVAILABLE || retries >= self.max_retries {
+                return check_status(url, response);
+            }
+            retries += 1;
+            sleep(retry_delay);
+            retry_delay = retry_delay.saturating_mul(2);
+        }
+    }
+
+    fn unsupported_content_type(&self, content_type: &str) -> Box<dyn Error + Send + Sync> {
+        format!(
+            "Unsupported Content-Type returned by {}: {content_type}",
+            self.endpoint
+        )
+        .into()
+    }
+}
+
+impl ServiceHandler for SparqlClient {
+    type Error = QueryEvaluationError;
+
+    fn handle(
+        &self,
+        pattern: &GraphPattern,
+        base_iri: Option<&Iri<String>>,
+    ) -> Result<QuerySolutionIter<'static>, Self::Error> {
+        let query = spargebra::Query::Select {
+            dataset: None,
+            pattern: pattern.clone(),
+            base_iri: base_iri.cloned(),
+        }
+        .to_string();
+        let (content_type, body) = self
+            .send_query(&query, SOLUTIONS_ACCEPT)
+            .map_err(service_error)?;
+        let format = QueryResultsFormat::from_media_type(&content_type).ok_or_else(|| {
+            QueryEvaluationError::Service(self.unsupported_content_type(&content_type))
+        })?;
+        let ReaderQueryResultsParserOutput::Solutions(reader) =
+            QueryResultsParser::from_format(format)
+                .for_reader(body)
+                .map_err(service_error)?
+        else {
+            return Err(QueryEvaluationError::Service(
+                format!("No valid SPARQL solutions returned by {}", self.endpoint).into(),
+            ));
+        };
+        Ok(QuerySolutionIter::new(
+            reader.variables().into(),
+            reader.map(|s| s.map_err(service_error)),
+        ))
+    }
+}
+
+fn service_error(error: impl Error + Send + Sync + 'static) -> QueryEvaluationError {
+    QueryEvaluationError::Service(Box::new(error))
+}
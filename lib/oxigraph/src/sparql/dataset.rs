@@ -1,29 +1,39 @@
+use crate::sparql::GraphFilter;
 #[cfg(feature = "rdf-12")]
 use crate::storage::numeric_encoder::EncodedTriple;
 use crate::storage::numeric_encoder::{
-    Decoder, EncodedTerm, StrHash, StrHashHasher, StrLookup, insert_term,
+    Decoder, EncodedQuad, EncodedTerm, StrHash, StrHashHasher, StrLookup, insert_term,
 };
+use crate::storage::range::EncodedObjectRange;
 use crate::storage::{CorruptionError, StorageError, StorageReader};
-use oxrdf::Term;
+use oxrdf::{GraphNameRef, NamedNode, NamedNodeRef, Term};
 use oxsdatatypes::Boolean;
 #[cfg(feature = "rdf-12")]
 use spareval::ExpressionTriple;
 use spareval::{ExpressionTerm, InternalQuad, QueryableDataset};
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::hash::BuildHasherDefault;
-#[cfg(feature = "rdf-12")]
-use std::sync::Arc;
+use std::ops::Bound;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 pub struct DatasetView<'a> {
     reader: StorageReader<'a>,
     extra: RefCell<HashMap<StrHash, String, BuildHasherDefault<StrHashHasher>>>,
+    visibility: Option<GraphVisibility<'a>>,
 }
 
 impl<'a> DatasetView<'a> {
-    pub fn new(reader: StorageReader<'a>) -> Self {
+    /// The quads in the graphs rejected by `graph_filter` are hidden
+    pub fn new(reader: StorageReader<'a>, graph_filter: Option<GraphFilter>) -> Self {
         Self {
+            visibility: graph_filter.map(|filter| GraphVisibility {
+                filter,
+                reader: reader.clone(),
+                cache: Arc::default(),
+            }),
             reader,
             extra: RefCell::new(HashMap::default()),
         }
@@ -49,6 +59,7 @@ impl<'a> QueryableDataset<'a> for DatasetView<'a> {
         object: Option<&EncodedTerm>,
         graph_name: Option<Option<&EncodedTerm>>,
     ) -> impl Iterator<Item = Result<InternalQuad<EncodedTerm>, StorageError>> + use<'a> {
+        let visibility = self.visibility.clone();
         self.reader
             .quads_for_pattern(
                 subject,
@@ -56,9 +67,19 @@ impl<'a> QueryableDataset<'a> for DatasetView<'a> {
                 object,
                 graph_name.map(|graph_name| graph_name.unwrap_or(&EncodedTerm::DefaultGraph)),
             )
-            .map(|quad| {
-                let quad = quad?;
-                Ok(InternalQuad {
+            .filter_map(move |quad| {
+                let quad = match quad {
+                    Ok(quad) => quad,
+                    Err(e) => return Some(Err(e)),
+                };
+                if let Some(visibility) = &visibility {
+                    match visibility.is_visible(&quad.graph_name) {
+                        Ok(true) => (),
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Some(Ok(InternalQuad {
                     subject: quad.subject,
                     predicate: quad.predicate,
                     object: quad.object,
@@ -67,17 +88,98 @@ impl<'a> QueryableDataset<'a> for DatasetView<'a> {
                     } else {
                         Some(quad.graph_name)
                     },
-                })
+                }))
             })
     }
 
+    fn internal_quads_for_pattern_with_object_range(
+        &self,
+        subject: Option<&EncodedTerm>,
+        predicate: Option<&EncodedTerm>,
+        object_range: (Bound<&EncodedTerm>, Bound<&EncodedTerm>),
+        graph_name: Option<Option<&EncodedTerm>>,
+    ) -> impl Iterator<Item = Result<InternalQuad<EncodedTerm>, StorageError>> + use<'a> {
+        let (object_range, error) =
+            match EncodedObjectRange::from_encoded_bounds(object_range.0, object_range.1, self) {
+                Ok(object_range) => (object_range, None),
+                Err(e) => (None, Some(e)),
+            };
+        let graph_name =
+            graph_name.map(|graph_name| graph_name.unwrap_or(&EncodedTerm::DefaultGraph));
+        let iter = if let Some(object_range) = &object_range {
+            self.reader.quads_for_pattern_with_object_range(
+                subject,
+                predicate,
+                object_range,
+                graph_name,
+            )
+        } else {
+            self.reader
+                .quads_for_pattern(subject, predicate, None, graph_name)
+        };
+        let reader = self.reader.clone();
+        let visibility = self.visibility.clone();
+        error
+            .map(Err)
+            .into_iter()
+            .chain(iter.filter_map(move |quad| {
+                let quad = match quad {
+                    Ok(quad) => quad,
+                    Err(e) => return Some(Err(e)),
+                };
+                if let Some(visibility) = &visibility {
+                    match visibility.is_visible(&quad.graph_name) {
+                        Ok(true) => (),
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                if let Some(object_range) = &object_range {
+                    match object_range.contains(&quad.object, &reader) {
+                        Ok(true) => (),
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Some(Ok(InternalQuad {
+                    subject: quad.subject,
+                    predicate: quad.predicate,
+                    object: quad.object,
+                    graph_name: if quad.graph_name.is_default_graph() {
+                        None
+                    } else {
+                        Some(quad.graph_name)
+                    },
+                }))
+            }))
+    }
+
     fn internal_named_graphs(
         &self,
     ) -> impl Iterator<Item = Result<EncodedTerm, StorageError>> + use<'a> {
-        self.reader.named_graphs()
+        let visibility = self.visibility.clone();
+        self.reader.named_graphs().filter_map(move |graph_name| {
+            let Some(visibility) = &visibility else {
+                return Some(graph_name);
+            };
+            let graph_name = match graph_name {
+                Ok(graph_name) => graph_name,
+                Err(e) => return Some(Err(e)),
+            };
+            match visibility.is_visible(&graph_name) {
+                Ok(true) => Some(Ok(graph_name)),
+                Ok(false) => None,
+                Err(e) => Some(Err(e)),
+            }
+        })
     }
 
     fn contains_internal_graph_name(&self, graph_name: &EncodedTerm) -> Result<bool, StorageError> {
+        if let Some(visibility) = &self.visibility {
+            if !visibility.is_visible(graph_name)? {
+                return Ok(false);
+            }
+        }
         self.reader.contains_named_graph(graph_name)
     }
 
@@ -187,6 +289,40 @@ impl<'a> QueryableDataset<'a> for DatasetView<'a> {
     }
 }
 
+/// Applies the graph filter, the decisions are cached per graph name
+#[derive(Clone)]
+struct GraphVisibility<'a> {
+    filter: GraphFilter,
+    reader: StorageReader<'a>,
+    cache: Arc<Mutex<HashMap<EncodedTerm, bool>>>,
+}
+
+impl GraphVisibility<'_> {
+    fn is_visible(&self, graph_name: &EncodedTerm) -> Result<bool, StorageError> {
+        if graph_name.is_default_graph() {
+            return Ok((self.filter)(GraphNameRef::DefaultGraph));
+        }
+        if let Some(visible) = self
+            .cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(graph_name).copied())
+        {
+            return Ok(visible);
+        }
+        let visible = (self.filter)(
+            self.reader
+                .decode_named_or_blank_node(graph_name)?
+                .as_ref()
+                .into(),
+        );
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(graph_name.clone(), visible);
+        }
+        Ok(visible)
+    }
+}
+
 impl StrLookup for DatasetView<'_> {
     fn get_str(&self, key: &StrHash) -> Result<Option<String>, StorageError> {
         Ok(if let Some(value) = self.extra.borrow().get(key) {
@@ -196,3 +332,257 @@ impl StrLookup for DatasetView<'_> {
         })
     }
 }
+
+/// A read-only view on the union of the snapshots of several stores
+///
+/// The term encoding only depends on the term content so the members can share the same [`EncodedTerm`]s.
+/// The named graphs of the members with a prefix are renamed by prepending the prefix to their IRI.
+#[derive(Clone)]
+pub struct FederatedDatasetView {
+    members: Rc<[FederatedMember]>,
+    extra: Rc<RefCell<HashMap<StrHash, String, BuildHasherDefault<StrHashHasher>>>>,
+    /// Cache of the graph names of the members to the graph names of the federated view
+    graph_names: Rc<RefCell<HashMap<(usize, EncodedTerm), EncodedTerm>>>,
+}
+
+struct FederatedMember {
+    reader: StorageReader<'static>,
+    graph_name_prefix: Option<String>,
+}
+
+impl FederatedDatasetView {
+    pub fn new(
+        members: impl IntoIterator<Item = (StorageReader<'static>, Option<String>)>,
+    ) -> Self {
+        Self {
+            members: members
+                .into_iter()
+                .map(|(reader, graph_name_prefix)| FederatedMember {
+                    reader,
+                    graph_name_prefix,
+                })
+                .collect(),
+            extra: Rc::default(),
+            graph_names: Rc::default(),
+        }
+    }
+
+    fn insert_str(&self, key: &StrHash, value: &str) {
+        if let Entry::Vacant(e) = self.extra.borrow_mut().entry(*key) {
+            if !self
+                .members
+                .iter()
+                .any(|member| matches!(member.reader.contains_str(key), Ok(true)))
+            {
+                e.insert(value.to_owned());
+            }
+        }
+    }
+
+    /// Returns the name in the member `member` of the federated graph `graph_name`, `None` if the member can't contain it
+    fn member_graph_name(
+        &self,
+        member: usize,
+        graph_name: &EncodedTerm,
+    ) -> Result<Option<EncodedTerm>, StorageError> {
+        let Some(prefix) = &self.members[member].graph_name_prefix else {
+            return Ok(Some(graph_name.clone()));
+        };
+        if !matches!(graph_name, EncodedTerm::NamedNode { .. }) {
+            return Ok(Some(graph_name.clone()));
+        }
+        let graph_name = self.decode_named_node(graph_name)?;
+        Ok(graph_name
+            .as_str()
+            .strip_prefix(prefix.as_str())
+            .map(|iri| NamedNodeRef::new_unchecked(iri).into()))
+    }
+
+    /// Returns the name in the federated view of the graph `graph_name` of the member `member`
+    fn federated_graph_name(
+        &self,
+        member: usize,
+        graph_name: EncodedTerm,
+    ) -> Result<EncodedTerm, StorageError> {
+        let Some(prefix) = &self.members[member].graph_name_prefix else {
+            return Ok(graph_name);
+        };
+        if !matches!(graph_name, EncodedTerm::NamedNode { .. }) {
+            return Ok(graph_name);
+        }
+        if let Some(federated) = self.graph_names.borrow().get(&(member, graph_name.clone())) {
+            return Ok(federated.clone());
+        }
+        let iri = self.members[member].reader.decode_named_node(&graph_name)?;
+        let federated = self.internalize_term(
+            NamedNode::new_unchecked(format!("{prefix}{}", iri.as_str())).into(),
+        )?;
+        self.graph_names
+            .borrow_mut()
+            .insert((member, graph_name), federated.clone());
+        Ok(federated)
+    }
+
+    /// Returns if one of the members before `member` contains the quad, the graph name being the federated one
+    fn is_in_previous_members(
+        &self,
+        member: usize,
+        quad: &EncodedQuad,
+    ) -> Result<bool, StorageError> {
+        for previous in 0..member {
+            let Some(graph_name) = self.member_graph_name(previous, &quad.graph_name)? else {
+                continue;
+            };
+            if self.members[previous].reader.contains(&EncodedQuad::new(
+                quad.subject.clone(),
+                quad.predicate.clone(),
+                quad.object.clone(),
+                graph_name,
+            ))? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl QueryableDataset<'static> for FederatedDatasetView {
+    type InternalTerm = EncodedTerm;
+    type Error = StorageError;
+
+    fn internal_quads_for_pattern(
+        &self,
+        subject: Option<&EncodedTerm>,
+        predicate: Option<&EncodedTerm>,
+        object: Option<&EncodedTerm>,
+        graph_name: Option<Option<&EncodedTerm>>,
+    ) -> impl Iterator<Item = Result<InternalQuad<EncodedTerm>, StorageError>> + use<> {
+        let subject = subject.cloned();
+        let predicate = predicate.cloned();
+        let object = object.cloned();
+        let graph_name = graph_name.map(Option::<&EncodedTerm>::cloned);
+        let view = self.clone();
+        (0..self.members.len()).flat_map(move |member| {
+            let member_graph_name = match &graph_name {
+                Some(Some(graph_name)) => match view.member_graph_name(member, graph_name) {
+                    Ok(Some(graph_name)) => Some(graph_name),
+                    Ok(None) => return FederatedQuadIter::Empty,
+                    Err(e) => return FederatedQuadIter::Error(Some(e)),
+                },
+                Some(None) => Some(EncodedTerm::DefaultGraph),
+                None => None,
+            };
+            let iter = view.members[member].reader.quads_for_pattern(
+                subject.as_ref(),
+                predicate.as_ref(),
+                object.as_ref(),
+                member_graph_name.as_ref(),
+            );
+            let view = view.clone();
+            FederatedQuadIter::Quads(Box::new(iter.filter_map(move |quad| {
+                let mut quad = match quad {
+                    Ok(quad) => quad,
+                    Err(e) => return Some(Err(e)),
+                };
+                quad.graph_name = match view.federated_graph_name(member, quad.graph_name) {
+                    Ok(graph_name) => graph_name,
+                    Err(e) => return Some(Err(e)),
+                };
+                match view.is_in_previous_members(member, &quad) {
+                    Ok(false) => (),
+                    Ok(true) => return None,
+                    Err(e) => return Some(Err(e)),
+                }
+                Some(Ok(InternalQuad {
+                    subject: quad.subject,
+                    predicate: quad.predicate,
+                    object: quad.object,
+                    graph_name: if quad.graph_name.is_default_graph() {
+                        None
+                    } else {
+                        Some(quad.graph_name)
+                    },
+                }))
+            })))
+        })
+    }
+
+    fn internal_named_graphs(
+        &self,
+    ) -> impl Iterator<Item = Result<EncodedTerm, StorageError>> + use<> {
+        let mut graph_names = HashSet::new();
+        let mut error = None;
+        for (i, member) in self.members.iter().enumerate() {
+            for graph_name in member.reader.named_graphs() {
+                match graph_name.and_then(|graph_name| self.federated_graph_name(i, graph_name)) {
+                    Ok(graph_name) => {
+                        graph_names.insert(graph_name);
+                    }
+                    Err(e) => {
+                        error = Some(e);
+                        break;
+                    }
+                }
+            }
+        }
+        error
+            .map(Err)
+            .into_iter()
+            .chain(graph_names.into_iter().map(Ok))
+    }
+
+    fn contains_internal_graph_name(&self, graph_name: &EncodedTerm) -> Result<bool, StorageError> {
+        for (i, member) in self.members.iter().enumerate() {
+            if let Some(graph_name) = self.member_graph_name(i, graph_name)? {
+                if member.reader.contains_named_graph(&graph_name)? {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn internalize_term(&self, term: Term) -> Result<EncodedTerm, StorageError> {
+        let encoded = term.as_ref().into();
+        insert_term(term.as_ref(), &encoded, &mut |key, value| {
+            self.insert_str(key, value)
+        });
+        Ok(encoded)
+    }
+
+    fn externalize_term(&self, term: EncodedTerm) -> Result<Term, StorageError> {
+        self.decode_term(&term)
+    }
+}
+
+impl StrLookup for FederatedDatasetView {
+    fn get_str(&self, key: &StrHash) -> Result<Option<String>, StorageError> {
+        if let Some(value) = self.extra.borrow().get(key) {
+            return Ok(Some(value.clone()));
+        }
+        for member in self.members.iter() {
+            if let Some(value) = member.reader.get_str(key)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+}
+
+enum FederatedQuadIter {
+    Quads(Box<dyn Iterator<Item = Result<InternalQuad<EncodedTerm>, StorageError>>>),
+    Error(Option<StorageError>),
+    Empty,
+}
+
+impl Iterator for FederatedQuadIter {
+    type Item = Result<InternalQuad<EncodedTerm>, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Quads(iter) => iter.next(),
+            Self::Error(error) => error.take().map(Err),
+            Self::Empty => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::io::RdfParseError;
-use crate::model::NamedNode;
+use crate::model::{GraphName, NamedNode};
+#[cfg(feature = "http-client")]
+use crate::store::IriLoadError;
 use crate::store::{CorruptionError, StorageError};
 use oxrdf::{Term, Variable};
 use spareval::QueryEvaluationError;
@@ -27,6 +29,9 @@ pub enum UpdateEvaluationError {
     /// Error when `DROP` or `CLEAR` tries to remove a not existing graph
     #[error("The graph {0} does not exist")]
     GraphDoesNotExist(NamedNode),
+    /// Error when the update writes into a graph rejected by [`SparqlEvaluator::with_writable_graph_filter`](crate::sparql::SparqlEvaluator::with_writable_graph_filter)
+    #[error("The graph {0} is not writable")]
+    GraphNotWritable(GraphName),
     /// The variable storing the `SERVICE` name is unbound
     #[error("The variable encoding the service name is unbound")]
     UnboundService,
@@ -54,6 +59,21 @@ impl From<Infallible> for UpdateEvaluationError {
     }
 }
 
+#[cfg(feature = "http-client")]
+impl From<IriLoadError> for UpdateEvaluationError {
+    fn from(error: IriLoadError) -> Self {
+        match error {
+            IriLoadError::Parsing(error) => Self::GraphParsing(error),
+            IriLoadError::Storage(error) => Self::Storage(error),
+            IriLoadError::UnsupportedFormat {
+                content_type: Some(content_type),
+                ..
+            } => Self::UnsupportedContentType(content_type),
+            e => Self::Service(Box::new(e)),
+        }
+    }
+}
+
 impl From<QueryEvaluationError> for UpdateEvaluationError {
     fn from(error: QueryEvaluationError) -> Self {
         match error {
@@ -95,6 +115,9 @@ impl From<UpdateEvaluationError> for io::Error {
                     Err(error) => Self::other(error),
                 }
             }
+            UpdateEvaluationError::GraphNotWritable(_) => {
+                Self::new(io::ErrorKind::PermissionDenied, error)
+            }
             UpdateEvaluationError::GraphAlreadyExists(_)
             | UpdateEvaluationError::GraphDoesNotExist(_)
             | UpdateEvaluationError::UnboundService
@@ -116,3 +139,18 @@ impl From<SparqlSyntaxError> for UpdateEvaluationError {
         Self::Unexpected(Box::new(error))
     }
 }
+
+/// An error returned by [`Store::subscribe_query`](crate::store::Store::subscribe_query).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum QuerySubscriptionError {
+    /// An error during the initial evaluation of the query.
+    #[error(transparent)]
+    Evaluation(#[from] QueryEvaluationError),
+    /// Only `SELECT` queries can be subscribed to.
+    #[error("Only SELECT queries can be subscribed to")]
+    NotSelectQuery,
+    /// The store already has the maximal number of query subscriptions.
+    #[error("The store already has {0} query subscriptions, the maximum allowed")]
+    TooManySubscriptions(usize),
+}
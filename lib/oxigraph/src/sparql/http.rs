@@ -1,92 +1,188 @@
-use oxhttp::model::header::{ACCEPT, CONTENT_TYPE};
-use oxhttp::model::{Body, Method, Request};
+use crate::io::{LoadedDocument, RdfFormat, RdfParser, ReaderQuadParser};
+use crate::model::GraphNameRef;
+use crate::sparql::client::SparqlClient;
+use crate::storage::IriLoadError;
+use oxhttp::model::header::{ACCEPT, CONTENT_TYPE, LOCATION};
+use oxhttp::model::{Body, Request, Response};
 use oxiri::Iri;
 use oxrdf::NamedNode;
-use sparesults::{QueryResultsFormat, QueryResultsParser, ReaderQueryResultsParserOutput};
-use spareval::{DefaultServiceHandler, QueryEvaluationError, QuerySolutionIter};
+use spareval::{DefaultServiceHandler, QueryEvaluationError, QuerySolutionIter, ServiceHandler};
 use spargebra::algebra::GraphPattern;
-use std::io::{Error, ErrorKind, Read, Result};
+use std::io::{Error, ErrorKind, Result};
 use std::sync::Arc;
 use std::time::Duration;
 
+/// The `Accept` header used when dereferencing RDF documents
+const RDF_ACCEPT: &str = "application/n-triples, text/turtle, application/n-quads, application/trig, application/rdf+xml;q=0.9, application/ld+json;q=0.8, text/n3;q=0.7";
+
 #[derive(Clone)]
 pub struct Client {
-    client: Arc<oxhttp::Client>,
+    inner: Arc<oxhttp::Client>,
+    /// Client not following redirections, used to know the final URL of dereferenced documents
+    without_redirection: Arc<oxhttp::Client>,
+    redirection_limit: usize,
 }
 
 impl Client {
     pub fn new(timeout: Option<Duration>, redirection_limit: usize) -> Self {
-        let mut client = oxhttp::Client::new()
-            .with_redirection_limit(redirection_limit)
-            .with_user_agent(concat!("Oxigraph/", env!("CARGO_PKG_VERSION")))
-            .unwrap();
-        if let Some(timeout) = timeout {
-            client = client.with_global_timeout(timeout);
-        }
         Self {
-            client: Arc::new(client),
+            inner: Arc::new(build_client(timeout, redirection_limit)),
+            without_redirection: Arc::new(build_client(timeout, 0)),
+            redirection_limit,
         }
     }
 
-    pub fn get(&self, url: &str, accept: &'static str) -> Result<(String, impl Read)> {
-        let request = Request::builder()
-            .uri(url)
-            .header(ACCEPT, accept)
-            .body(())
-            .map_err(invalid_input_error)?;
-        let response = self.client.request(request)?;
-        let status = response.status();
-        if !status.is_success() {
-            return Err(Error::other(format!(
-                "Error {} returned by {} with payload:\n{}",
-                status,
-                url,
-                response.into_body().to_string()?
-            )));
+    /// Sends a GET request following redirections and returns the final URL, the document format and its body
+    fn dereference(
+        &self,
+        iri: &str,
+    ) -> std::result::Result<(String, RdfFormat, Body), IriLoadError> {
+        let mut url = iri.to_owned();
+        for _ in 0..=self.redirection_limit {
+            let request = Request::builder()
+                .uri(&url)
+                .header(ACCEPT, RDF_ACCEPT)
+                .body(())
+                .map_err(|e| IriLoadError::Network {
+                    iri: url.clone(),
+                    error: invalid_input_error(e),
+                })?;
+            let response = self.without_redirection.request(request).map_err(|error| {
+                IriLoadError::Network {
+                    iri: url.clone(),
+                    error,
+                }
+            })?;
+            let status = response.status();
+            if status.is_redirection() {
+                let location = response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .ok_or_else(|| IriLoadError::HttpStatus {
+                        iri: url.clone(),
+                        status: status.as_u16(),
+                    })?;
+                url = Iri::parse(url.as_str())
+                    .and_then(|base| base.resolve(location))
+                    .map_err(|e| IriLoadError::Network {
+                        iri: url.clone(),
+                        error: invalid_data_error(format!(
+                            "Invalid redirection location {location}: {e}"
+                        )),
+                    })?
+                    .into_inner();
+                continue;
+            }
+            if !status.is_success() {
+                return Err(IriLoadError::HttpStatus {
+                    iri: url,
+                    status: status.as_u16(),
+                });
+            }
+            let content_type = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|content_type| content_type.to_str().ok())
+                .map(ToOwned::to_owned);
+            let Some(format) = content_type
+                .as_deref()
+                .and_then(RdfFormat::from_media_type)
+                .or_else(|| format_from_extension(&url))
+            else {
+                return Err(IriLoadError::UnsupportedFormat {
+                    iri: url,
+                    content_type,
+                });
+            };
+            return Ok((url, format, response.into_body()));
         }
-        let content_type = response
-            .headers()
-            .get(CONTENT_TYPE)
-            .ok_or_else(|| invalid_data_error(format!("No Content-Type returned by {url}")))?
-            .to_str()
-            .map_err(invalid_data_error)?
-            .to_owned();
-        Ok((content_type, response.into_body()))
+        Err(IriLoadError::TooManyRedirections {
+            iri: url,
+            limit: self.redirection_limit,
+        })
     }
 
-    pub fn post(
-        &self,
-        url: &str,
-        payload: Vec<u8>,
-        content_type: &'static str,
-        accept: &'static str,
-    ) -> Result<(String, Body)> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri(url)
-            .header(ACCEPT, accept)
-            .header(CONTENT_TYPE, content_type)
-            .body(payload)
-            .map_err(invalid_input_error)?;
-        let response = self.client.request(request)?;
-        let status = response.status();
-        if !status.is_success() {
-            return Err(Error::other(format!(
-                "Error {} returned by {} with payload:\n{}",
-                status,
-                url,
-                response.into_body().to_string()?
-            )));
-        }
-        let content_type = response
-            .headers()
-            .get(CONTENT_TYPE)
-            .ok_or_else(|| invalid_data_error(format!("No Content-Type returned by {url}")))?
-            .to_str()
-            .map_err(invalid_data_error)?
-            .to_owned();
-        Ok((content_type, response.into_body()))
+    /// Sends the request without checking the response status
+    pub fn send(&self, request: Request<impl Into<Body>>) -> Result<Response<Body>> {
+        self.inner.request(request)
+    }
+}
+
+/// Dereferences the RDF document at the given IRI and returns a parser over its content.
+///
+/// Redirections are followed, the final URL is used as base IRI.
+/// The format is given by the `Content-Type` header or, if it is missing or not an RDF format, by the URL extension.
+/// If `to_graph_name` is set, all the triples are loaded into this graph and named graphs are rejected.
+pub fn load_rdf(
+    client: &Client,
+    iri: &str,
+    to_graph_name: Option<GraphNameRef<'_>>,
+) -> std::result::Result<ReaderQuadParser<Body>, IriLoadError> {
+    let (url, format, body) = client.dereference(iri)?;
+    let mut parser = RdfParser::from_format(format).rename_blank_nodes();
+    if let Some(to_graph_name) = to_graph_name {
+        parser = parser
+            .without_named_graphs()
+            .with_default_graph(to_graph_name);
+    }
+    let parser = parser
+        .with_base_iri(url.as_str())
+        .map_err(|e| IriLoadError::Network {
+            iri: url.clone(),
+            error: invalid_input_error(e),
+        })?;
+    let client = client.clone();
+    Ok(parser.for_reader(body).with_document_loader(move |url| {
+        let (url, format, body) = client.dereference(url)?;
+        Ok(LoadedDocument {
+            content: body.to_vec()?,
+            url,
+            format,
+        })
+    }))
+}
+
+/// Guesses the RDF format from the extension of the last segment of the URL path
+fn format_from_extension(url: &str) -> Option<RdfFormat> {
+    let url = Iri::parse(url).ok()?;
+    let (_, extension) = url.path().rsplit('/').next()?.rsplit_once('.')?;
+    RdfFormat::from_extension(extension)
+}
+
+fn build_client(timeout: Option<Duration>, redirection_limit: usize) -> oxhttp::Client {
+    let mut client = oxhttp::Client::new()
+        .with_redirection_limit(redirection_limit)
+        .with_user_agent(concat!("Oxigraph/", env!("CARGO_PKG_VERSION")))
+        .unwrap();
+    if let Some(timeout) = timeout {
+        client = client.with_global_timeout(timeout);
     }
+    client
+}
+
+/// Fails if the response status is not a success
+pub fn check_status(url: &str, response: Response<Body>) -> Result<Response<Body>> {
+    let status = response.status();
+    if !status.is_success() {
+        return Err(Error::other(format!(
+            "Error {} returned by {} with payload:\n{}",
+            status,
+            url,
+            response.into_body().to_string()?
+        )));
+    }
+    Ok(response)
+}
+
+pub fn content_type(url: &str, response: &Response<Body>) -> Result<String> {
+    Ok(response
+        .headers()
+        .get(CONTENT_TYPE)
+        .ok_or_else(|| invalid_data_error(format!("No Content-Type returned by {url}")))?
+        .to_str()
+        .map_err(invalid_data_error)?
+        .to_owned())
 }
 
 pub struct HttpServiceHandler {
@@ -110,42 +206,8 @@ impl DefaultServiceHandler for HttpServiceHandler {
         pattern: &GraphPattern,
         base_iri: Option<&Iri<String>>,
     ) -> std::result::Result<QuerySolutionIter<'static>, Self::Error> {
-        let (content_type, body) = self
-            .client
-            .post(
-                service_name.as_str(),
-                spargebra::Query::Select {
-                    dataset: None,
-                    pattern: pattern.clone(),
-                    base_iri: base_iri.cloned(),
-                }
-                .to_string()
-                .into_bytes(),
-                "application/sparql-query",
-                "application/sparql-results+json, application/sparql-results+xml",
-            )
-            .map_err(|e| QueryEvaluationError::Service(Box::new(e)))?;
-        let format = QueryResultsFormat::from_media_type(&content_type).ok_or_else(|| {
-            QueryEvaluationError::Service(
-                format!(
-                    "Unsupported Content-Type returned by service {service_name}: {content_type}"
-                )
-                .into(),
-            )
-        })?;
-        let ReaderQueryResultsParserOutput::Solutions(reader) =
-            QueryResultsParser::from_format(format)
-                .for_reader(body)
-                .map_err(|e| QueryEvaluationError::Service(Box::new(e)))?
-        else {
-            return Err(QueryEvaluationError::Service(
-                "No valid SPARQL solutions returned by {service_name}".into(),
-            ));
-        };
-        Ok(QuerySolutionIter::new(
-            reader.variables().into(),
-            Box::new(reader.map(|t| t.map_err(|e| QueryEvaluationError::Service(Box::new(e))))),
-        ))
+        SparqlClient::from_client(self.client.clone(), service_name.as_str().into())
+            .handle(pattern, base_iri)
     }
 }
 
@@ -153,6 +215,6 @@ fn invalid_data_error(error: impl Into<Box<dyn std::error::Error + Send + Sync>>
     Error::new(ErrorKind::InvalidData, error)
 }
 
-fn invalid_input_error(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+pub fn invalid_input_error(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
     Error::new(ErrorKind::InvalidInput, error)
 }
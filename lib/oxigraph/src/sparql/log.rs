@@ -0,0 +1,105 @@
+use oxsdatatypes::DateTime;
+use spareval::{
+    QueryEvaluationError, QueryExplanation, QueryQuadIter, QueryResults, QuerySolutionIter,
+    QueryTripleIter,
+};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Information about a query evaluation given to the query logger set with [`SparqlEvaluator::with_query_logger`](super::SparqlEvaluator::with_query_logger).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct QueryLogRecord {
+    /// The evaluated query serialized in SPARQL.
+    pub query: String,
+    /// The duration of the evaluation, from its start to the end of the results iteration.
+    pub duration: Duration,
+    /// The number of returned solutions or triples (1 for `ASK` queries).
+    pub result_count: usize,
+    /// If the evaluation has been cancelled, for example because of a timeout.
+    pub cancelled: bool,
+    /// The biggest number of results returned by a step of the query plan.
+    ///
+    /// Only set if statistics are computed using [`BoundPreparedSparqlQuery::compute_statistics`](super::BoundPreparedSparqlQuery::compute_statistics).
+    pub max_intermediate_results: Option<usize>,
+}
+
+pub type QueryLogger = Arc<dyn Fn(QueryLogRecord) + Send + Sync>;
+
+/// Tracks a query evaluation and calls the logger when dropped
+struct QueryLogState {
+    logger: QueryLogger,
+    query: String,
+    start: DateTime,
+    explanation: QueryExplanation,
+    result_count: usize,
+    cancelled: bool,
+}
+
+impl QueryLogState {
+    fn observe<T>(&mut self, result: &Result<T, QueryEvaluationError>) {
+        match result {
+            Ok(_) => self.result_count += 1,
+            Err(QueryEvaluationError::Cancelled) => self.cancelled = true,
+            Err(_) => (),
+        }
+    }
+}
+
+impl Drop for QueryLogState {
+    fn drop(&mut self) {
+        let record = QueryLogRecord {
+            query: std::mem::take(&mut self.query),
+            duration: DateTime::now()
+                .checked_sub(self.start)
+                .and_then(|d| Duration::try_from(d).ok())
+                .unwrap_or_default(),
+            result_count: self.result_count,
+            cancelled: self.cancelled,
+            max_intermediate_results: self.explanation.max_intermediate_results(),
+        };
+        // A failing logger must not impact the query evaluation
+        drop(catch_unwind(AssertUnwindSafe(|| (self.logger)(record))));
+    }
+}
+
+/// Wraps the query results to call the logger once they have been fully consumed or dropped
+pub fn log_query_results<'a>(
+    logger: QueryLogger,
+    query: String,
+    start: DateTime,
+    results: Result<QueryResults<'a>, QueryEvaluationError>,
+    explanation: QueryExplanation,
+) -> Result<QueryResults<'a>, QueryEvaluationError> {
+    let mut state = QueryLogState {
+        logger,
+        query,
+        start,
+        explanation,
+        result_count: 0,
+        cancelled: false,
+    };
+    match results {
+        Ok(QueryResults::Solutions(solutions)) => {
+            Ok(QueryResults::Solutions(QuerySolutionIter::new(
+                solutions.variables().into(),
+                solutions.inspect(move |solution| state.observe(solution)),
+            )))
+        }
+        Ok(QueryResults::Boolean(result)) => {
+            state.result_count = 1;
+            Ok(QueryResults::Boolean(result))
+        }
+        Ok(QueryResults::Graph(triples)) => Ok(QueryResults::Graph(QueryTripleIter::new(
+            triples.inspect(move |triple| state.observe(triple)),
+        ))),
+        Ok(QueryResults::Dataset(quads)) => Ok(QueryResults::Dataset(QueryQuadIter::new(
+            quads.inspect(move |quad| state.observe(quad)),
+        ))),
+        Err(error) => {
+            state.cancelled = matches!(error, QueryEvaluationError::Cancelled);
+            Err(error)
+        }
+    }
+}
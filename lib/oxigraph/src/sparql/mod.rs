@@ -3,28 +3,46 @@
 //! The entry point for SPARQL execution is the [`SparqlEvaluator`] type.
 
 mod algebra;
+#[cfg(feature = "http-client")]
+#[cfg_attr(not(feature = "sparql-client"), expect(dead_code))]
+mod client;
 mod dataset;
 mod error;
 #[cfg(feature = "http-client")]
-mod http;
+pub(crate) mod http;
+mod log;
 pub mod results;
+mod subscription;
+#[cfg(feature = "tracing")]
+mod traced_service;
 mod update;
 
-use crate::model::{NamedNode, Term};
+use crate::model::{GraphNameRef, NamedNode, Skolemizer, Term};
 #[expect(deprecated)]
 pub use crate::sparql::algebra::{Query, Update};
-use crate::sparql::dataset::DatasetView;
-pub use crate::sparql::error::UpdateEvaluationError;
+#[cfg(feature = "sparql-client")]
+pub use crate::sparql::client::SparqlClient;
+use crate::sparql::dataset::{DatasetView, FederatedDatasetView};
+pub use crate::sparql::error::{QuerySubscriptionError, UpdateEvaluationError};
 #[cfg(feature = "http-client")]
 use crate::sparql::http::HttpServiceHandler;
+pub use crate::sparql::log::QueryLogRecord;
+use crate::sparql::log::{QueryLogger, log_query_results};
+pub use crate::sparql::subscription::{
+    QuerySolutionsChange, QuerySubscription, QuerySubscriptionOptions,
+};
+#[cfg(feature = "tracing")]
+use crate::sparql::traced_service::{TracedDefaultServiceHandler, TracedServiceHandler};
 pub use crate::sparql::update::{BoundPreparedSparqlUpdate, PreparedSparqlUpdate};
-use crate::store::{Store, Transaction};
+use crate::store::{FederatedStore, Store, Transaction};
 use oxrdf::IriParseError;
 pub use oxrdf::{Variable, VariableNameParseError};
+use oxsdatatypes::DateTime;
 pub use spareval::{
-    AggregateFunctionAccumulator, CancellationToken, DefaultServiceHandler,
-    QueryDatasetSpecification, QueryEvaluationError, QueryExplanation, QueryResults, QuerySolution,
-    QuerySolutionIter, QueryTripleIter, ServiceHandler,
+    AggregateFunctionAccumulator, CancellationToken, CustomDescribeHandler, DefaultServiceHandler,
+    DescribeDataset, DescribeStrategy, QueryDatasetSpecification, QueryEvaluationError,
+    QueryExplanation, QueryQuadIter, QueryResults, QueryResultsSerializationError, QuerySolution,
+    QuerySolutionIter, QuerySolutionSet, QueryTripleIter, ServiceHandler,
 };
 use spareval::{QueryEvaluator, QueryableDataset};
 use spargebra::SparqlParser;
@@ -32,6 +50,7 @@ pub use spargebra::SparqlSyntaxError;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::mem::take;
+use std::sync::Arc;
 #[cfg(feature = "http-client")]
 use std::time::Duration;
 
@@ -44,6 +63,76 @@ pub type EvaluationError = QueryEvaluationError;
 
 pub type QueryDataset = QueryDatasetSpecification;
 
+const DEFAULT_UPDATE_MAX_MEMORY_SIZE_IN_MEGABYTES: usize = 256;
+
+type GraphFilter = Arc<dyn Fn(GraphNameRef<'_>) -> bool + Send + Sync>;
+
+/// The graphs used as the default graph by the queries that do not set their dataset.
+///
+/// See [`Store::set_default_query_dataset`] and [`SparqlEvaluator::with_default_graph_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DefaultGraphMode {
+    /// Only the store default graph.
+    #[default]
+    DefaultGraphOnly,
+    /// The union of all the store graphs.
+    Union,
+}
+
+/// A [SPARQL 1.1 entailment regime](https://www.w3.org/TR/sparql11-entailment/).
+///
+/// This enumeration is non exhaustive. New regimes might be added in the future.
+///
+/// See [`SparqlEvaluator::with_entailment_regime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum EntailmentRegime {
+    /// [Simple entailment](https://www.w3.org/TR/sparql11-entailment/#SimpleEntailment), i.e. the regular SPARQL evaluation.
+    #[default]
+    Simple,
+}
+
+impl EntailmentRegime {
+    /// The regime IRI.
+    ///
+    /// ```
+    /// use oxigraph::sparql::EntailmentRegime;
+    ///
+    /// assert_eq!(
+    ///     EntailmentRegime::Simple.iri(),
+    ///     "http://www.w3.org/ns/entailment/Simple"
+    /// )
+    /// ```
+    #[inline]
+    pub const fn iri(self) -> &'static str {
+        match self {
+            Self::Simple => "http://www.w3.org/ns/entailment/Simple",
+        }
+    }
+
+    /// Looks for a supported regime from its IRI.
+    ///
+    /// ```
+    /// use oxigraph::sparql::EntailmentRegime;
+    ///
+    /// assert_eq!(
+    ///     EntailmentRegime::from_iri("http://www.w3.org/ns/entailment/Simple"),
+    ///     Some(EntailmentRegime::Simple)
+    /// );
+    /// assert_eq!(
+    ///     EntailmentRegime::from_iri("http://www.w3.org/ns/entailment/OWL-Direct"),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_iri(iri: &str) -> Option<Self> {
+        match iri {
+            "http://www.w3.org/ns/entailment/Simple" => Some(Self::Simple),
+            _ => None,
+        }
+    }
+}
+
 /// SPARQL evaluator.
 ///
 /// It supports [SPARQL 1.1 query](https://www.w3.org/TR/sparql11-query/) and [SPARQL 1.1 update](https://www.w3.org/TR/sparql11-update/).
@@ -77,6 +166,12 @@ pub struct SparqlEvaluator {
     with_http_default_service_handler: bool,
     parser: SparqlParser,
     inner: QueryEvaluator,
+    query_logger: Option<QueryLogger>,
+    default_graph_mode: Option<DefaultGraphMode>,
+    update_max_memory_size: usize,
+    skolemizer: Option<Skolemizer>,
+    graph_filter: Option<GraphFilter>,
+    writable_graph_filter: Option<GraphFilter>,
 }
 
 impl SparqlEvaluator {
@@ -149,6 +244,9 @@ impl SparqlEvaluator {
         service_name: impl Into<NamedNode>,
         handler: impl ServiceHandler + 'static,
     ) -> Self {
+        let service_name = service_name.into();
+        #[cfg(feature = "tracing")]
+        let handler = TracedServiceHandler::new(service_name.clone(), handler);
         self.inner = self.inner.with_service_handler(service_name, handler);
         self
     }
@@ -167,6 +265,8 @@ impl SparqlEvaluator {
         {
             self.with_http_default_service_handler = false;
         }
+        #[cfg(feature = "tracing")]
+        let handler = TracedDefaultServiceHandler::new(handler);
         self.inner = self.inner.with_default_service_handler(handler);
         self
     }
@@ -208,6 +308,68 @@ impl SparqlEvaluator {
         self
     }
 
+    /// Sets the maximal amount of memory used to buffer the changes computed by update operations.
+    ///
+    /// The `WHERE` clause of `DELETE`/`INSERT` operations is fully evaluated before the changes are applied.
+    /// If the computed changes do not fit in half of this memory budget, they are written to temporary files in [the temporary directory](std::env::temp_dir).
+    ///
+    /// When an update is executed on a store, outside of a [`Transaction`](crate::store::Transaction),
+    /// its changes are written to the on-disk storage each time they use more than the other half of the budget.
+    /// Such a large update is then not atomic anymore: its changes become visible to the readers while it is running
+    /// and the changes written before an error are kept.
+    ///
+    /// By default, this value is 256MB.
+    ///
+    /// ```
+    /// use oxigraph::sparql::SparqlEvaluator;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// SparqlEvaluator::new()
+    ///     .with_update_max_memory_size_in_megabytes(64)
+    ///     .parse_update("DELETE { ?s ?p ?o } WHERE { ?s ?p ?o }")?
+    ///     .on_store(&store)
+    ///     .execute()?;
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_update_max_memory_size_in_megabytes(mut self, memory_size: usize) -> Self {
+        self.update_max_memory_size = memory_size.saturating_mul(1024 * 1024);
+        self
+    }
+
+    /// Replaces the blank nodes in the query results, i.e. the solution bindings and the `CONSTRUCT` and `DESCRIBE` triples,
+    /// by [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization) of the shape `{base authority}/.well-known/genid/{blank node id}`.
+    ///
+    /// See [`Skolemizer`] for more details.
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// if let QueryResults::Solutions(mut solutions) = SparqlEvaluator::new()
+    ///     .skolemize_blank_nodes(NamedNode::new("http://example.com/")?)
+    ///     .parse_query("SELECT ?b WHERE { BIND(BNODE('foo') AS ?b) }")?
+    ///     .on_store(&Store::new()?)
+    ///     .execute()?
+    /// {
+    ///     let Some(Term::NamedNode(b)) = solutions.next().unwrap()?.get("b").cloned() else {
+    ///         unreachable!()
+    ///     };
+    ///     assert!(
+    ///         b.as_str()
+    ///             .starts_with("http://example.com/.well-known/genid/")
+    ///     );
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn skolemize_blank_nodes(mut self, base: impl Into<NamedNode>) -> Self {
+        self.skolemizer = Some(Skolemizer::new(base.into().as_ref()));
+        self
+    }
+
     /// Adds a custom SPARQL evaluation function.
     ///
     /// Example with a function serializing terms to N-Triples:
@@ -303,6 +465,69 @@ impl SparqlEvaluator {
         self
     }
 
+    /// Allows `GRAPH` blocks in the `CONSTRUCT` query templates.
+    ///
+    /// This is an Oxigraph extension to SPARQL.
+    /// The queries with such blocks return [`QueryResults::Dataset`] quads that can be inserted as is in a [`Store`](crate::store::Store),
+    /// for example to copy data between graphs in a single step.
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// if let QueryResults::Dataset(quads) = SparqlEvaluator::new()
+    ///     .with_construct_quads()
+    ///     .parse_query("CONSTRUCT { GRAPH <http://example.com/copy> { ?s ?p ?o } } WHERE { ?s ?p ?o }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     store.extend(quads.collect::<Result<Vec<_>, _>>()?)?;
+    /// }
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, NamedNodeRef::new("http://example.com/copy")?))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_construct_quads(mut self) -> Self {
+        self.parser = self.parser.with_construct_quads();
+        self
+    }
+
+    /// Sets the way `DESCRIBE` queries build the descriptions of the resources.
+    ///
+    /// By default, the [Concise Bounded Description](DescribeStrategy::ConciseBoundedDescription) is used.
+    /// The descriptions are built from the query default graph:
+    /// use [`QueryDataset::set_default_graph_as_union`] to describe the resources across all the store graphs.
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{DescribeStrategy, QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let bnode = BlankNode::default();
+    /// store.insert(QuadRef::new(ex, ex, &bnode, GraphNameRef::DefaultGraph))?;
+    /// store.insert(QuadRef::new(&bnode, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// if let QueryResults::Graph(triples) = SparqlEvaluator::new()
+    ///     .with_describe_strategy(DescribeStrategy::SymmetricCbd)
+    ///     .parse_query("DESCRIBE <http://example.com>")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     assert_eq!(triples.count(), 2);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_describe_strategy(mut self, strategy: DescribeStrategy) -> Self {
+        self.inner = self.inner.with_describe_strategy(strategy);
+        self
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn without_optimizations(mut self) -> Self {
@@ -348,16 +573,208 @@ impl SparqlEvaluator {
         self
     }
 
+    /// Derives the ids of the blank nodes minted by `CONSTRUCT` templates and the `BNODE()` function from the given seed and a counter.
+    ///
+    /// Running the same query twice on the same data returns the same blank nodes.
+    ///
+    /// <div class="warning">
+    ///
+    /// The returned blank nodes are not globally unique anymore: this must not be used if the outputs of multiple runs are merged together.</div>
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// let run = || -> Result<Vec<Triple>, Box<dyn std::error::Error>> {
+    ///     let QueryResults::Graph(triples) = SparqlEvaluator::new()
+    ///         .with_deterministic_blank_node_ids(42)
+    ///         .parse_query("CONSTRUCT { [] ?p ?o } WHERE { ?s ?p ?o }")?
+    ///         .on_store(&store)
+    ///         .execute()?
+    ///     else {
+    ///         unreachable!()
+    ///     };
+    ///     Ok(triples.collect::<Result<_, _>>()?)
+    /// };
+    /// assert_eq!(run()?, run()?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_deterministic_blank_node_ids(mut self, seed: u128) -> Self {
+        self.inner = self.inner.with_deterministic_blank_node_ids(seed);
+        self
+    }
+
+    /// Calls the given function after each query evaluation with information about it like its duration.
+    ///
+    /// The function is called once the query results have been fully consumed or dropped.
+    /// Panics in the function are caught and ignored to never fail the query evaluation.
+    ///
+    /// ```
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let records = Arc::new(Mutex::new(Vec::new()));
+    /// let logger_records = Arc::clone(&records);
+    /// if let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+    ///     .with_query_logger(move |record| logger_records.lock().unwrap().push(record))
+    ///     .parse_query("SELECT ?v WHERE { VALUES ?v { 1 2 } }")?
+    ///     .on_store(&Store::new()?)
+    ///     .execute()?
+    /// {
+    ///     for _ in solutions {}
+    /// }
+    /// let records = records.lock().unwrap();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].result_count, 2);
+    /// assert!(!records[0].cancelled);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_query_logger(
+        mut self,
+        logger: impl Fn(QueryLogRecord) + Send + Sync + 'static,
+    ) -> Self {
+        self.query_logger = Some(Arc::new(logger));
+        self
+    }
+
+    /// Sets the graphs used as the default graph by the queries that do not set their dataset
+    /// with `FROM` clauses or [`PreparedSparqlQuery::dataset_mut`].
+    ///
+    /// It overrides the store setting defined with [`Store::set_default_query_dataset`].
+    ///
+    /// It also applies to the `WHERE` clauses of the update operations without `USING` or `WITH` clauses.
+    /// The update templates always target the actual default graph
+    /// and `WITH <g>` scopes both the `WHERE` clause and the templates to `<g>`.
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{DefaultGraphMode, QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// if let QueryResults::Boolean(result) = SparqlEvaluator::new()
+    ///     .with_default_graph_mode(DefaultGraphMode::Union)
+    ///     .parse_query("ASK { ?s ?p ?o }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     assert!(result); // The triple in the named graph is found
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_default_graph_mode(mut self, mode: DefaultGraphMode) -> Self {
+        self.default_graph_mode = Some(mode);
+        self
+    }
+
+    /// Sets the [entailment regime](https://www.w3.org/TR/sparql11-entailment/) used to evaluate the queries.
+    ///
+    /// Only [simple entailment](EntailmentRegime::Simple), the default, is currently supported.
+    ///
+    /// ```
+    /// use oxigraph::sparql::{EntailmentRegime, QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// if let QueryResults::Boolean(result) = SparqlEvaluator::new()
+    ///     .with_entailment_regime(EntailmentRegime::Simple)
+    ///     .parse_query("ASK { ?s ?p ?o }")?
+    ///     .on_store(&Store::new()?)
+    ///     .execute()?
+    /// {
+    ///     assert!(!result);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_entailment_regime(self, regime: EntailmentRegime) -> Self {
+        match regime {
+            // Simple entailment is the regular evaluation
+            EntailmentRegime::Simple => self,
+        }
+    }
+
+    /// Hides the graphs for which `filter` returns `false` from the queries and the update `WHERE` clauses.
+    ///
+    /// The quads of these graphs are filtered out when reading the store,
+    /// so they are not visible to basic graph patterns, `GRAPH ?g` enumeration, `FROM` and `FROM NAMED` clauses, `DESCRIBE`, aggregates or `EXISTS`.
+    /// The default graph is given to the filter as [`GraphNameRef::DefaultGraph`].
+    ///
+    /// The filter only applies to queries evaluated with [`PreparedSparqlQuery::on_store`] and [`PreparedSparqlQuery::on_transaction`] and to updates.
+    /// Use [`with_writable_graph_filter`](Self::with_writable_graph_filter) to also protect the graphs from updates.
+    ///
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let public = NamedNodeRef::new("http://example.com/public")?;
+    /// let private = NamedNodeRef::new("http://example.com/private")?;
+    /// store.insert(QuadRef::new(public, public, public, public))?;
+    /// store.insert(QuadRef::new(private, private, private, private))?;
+    ///
+    /// if let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+    ///     .with_graph_filter(move |graph_name| graph_name != private.into())
+    ///     .parse_query("SELECT ?g WHERE { GRAPH ?g { ?s ?p ?o } }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     let graphs = solutions
+    ///         .map(|solution| Ok(solution?.get("g").cloned()))
+    ///         .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    ///     assert_eq!(graphs, [Some(public.into_owned().into())]);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_graph_filter(
+        mut self,
+        filter: impl Fn(GraphNameRef<'_>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.graph_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Makes the updates fail with [`UpdateEvaluationError::GraphNotWritable`] if they write into a graph for which `filter` returns `false`.
+    ///
+    /// The default graph is given to the filter as [`GraphNameRef::DefaultGraph`].
+    /// The graphs hidden by [`with_graph_filter`](Self::with_graph_filter) are not cleared nor dropped by `CLEAR ALL`, `DROP NAMED`...
+    ///
+    /// ```
+    /// use oxigraph::sparql::{SparqlEvaluator, UpdateEvaluationError};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let result = SparqlEvaluator::new()
+    ///     .with_writable_graph_filter(|graph_name| !graph_name.is_default_graph())
+    ///     .parse_update("INSERT DATA { <http://example.com> <http://example.com> <http://example.com> }")?
+    ///     .on_store(&store)
+    ///     .execute();
+    /// assert!(matches!(result, Err(UpdateEvaluationError::GraphNotWritable(_))));
+    /// assert!(store.is_empty()?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_writable_graph_filter(
+        mut self,
+        filter: impl Fn(GraphNameRef<'_>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.writable_graph_filter = Some(Arc::new(filter));
+        self
+    }
+
     #[cfg_attr(not(feature = "http-client"), expect(unused_mut))]
     fn into_evaluator(mut self) -> QueryEvaluator {
         #[cfg(feature = "http-client")]
         if self.with_http_default_service_handler {
-            self.inner = self
-                .inner
-                .with_default_service_handler(HttpServiceHandler::new(
-                    self.http_timeout,
-                    self.http_redirection_limit,
-                ))
+            let handler = HttpServiceHandler::new(self.http_timeout, self.http_redirection_limit);
+            #[cfg(feature = "tracing")]
+            let handler = TracedDefaultServiceHandler::new(handler);
+            self.inner = self.inner.with_default_service_handler(handler);
         }
         self.inner
     }
@@ -388,7 +805,10 @@ impl SparqlEvaluator {
         mut self,
         query: &(impl AsRef<str> + ?Sized),
     ) -> Result<PreparedSparqlQuery, SparqlSyntaxError> {
-        let query = take(&mut self.parser).parse_query(query.as_ref())?;
+        trace_span!("query_parse");
+        let query = take(&mut self.parser)
+            .parse_query(query.as_ref())
+            .inspect_err(|e| trace_error!(e))?;
         Ok(self.for_query(query))
     }
 
@@ -418,11 +838,15 @@ impl SparqlEvaluator {
     /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
     /// ```
     #[expect(deprecated)]
-    pub fn for_query(self, query: impl Into<Query>) -> PreparedSparqlQuery {
+    pub fn for_query(mut self, query: impl Into<Query>) -> PreparedSparqlQuery {
         let query = query.into();
         PreparedSparqlQuery {
             dataset: query.dataset,
             query: query.inner,
+            query_logger: self.query_logger.take(),
+            default_graph_mode: self.default_graph_mode,
+            skolemizer: self.skolemizer.take(),
+            graph_filter: self.graph_filter.take(),
             evaluator: self.into_evaluator(),
             substitutions: HashMap::new(),
         }
@@ -447,7 +871,10 @@ impl SparqlEvaluator {
         mut self,
         query: &(impl AsRef<str> + ?Sized),
     ) -> Result<PreparedSparqlUpdate, SparqlSyntaxError> {
-        let update = take(&mut self.parser).parse_update(query.as_ref())?;
+        trace_span!("update_parse");
+        let update = take(&mut self.parser)
+            .parse_update(query.as_ref())
+            .inspect_err(|e| trace_error!(e))?;
         Ok(self.for_update(update))
     }
 
@@ -469,14 +896,22 @@ impl SparqlEvaluator {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     #[expect(deprecated)]
-    pub fn for_update(self, update: impl Into<Update>) -> PreparedSparqlUpdate {
+    pub fn for_update(mut self, update: impl Into<Update>) -> PreparedSparqlUpdate {
         #[cfg(feature = "http-client")]
         let http_timeout = self.http_timeout;
         #[cfg(feature = "http-client")]
         let http_redirection_limit = self.http_redirection_limit;
+        let default_graph_mode = self.default_graph_mode;
+        let update_max_memory_size = self.update_max_memory_size;
+        let graph_filter = self.graph_filter.take();
+        let writable_graph_filter = self.writable_graph_filter.take();
         PreparedSparqlUpdate::new(
             self.into_evaluator(),
             update.into(),
+            default_graph_mode,
+            update_max_memory_size,
+            graph_filter,
+            writable_graph_filter,
             #[cfg(feature = "http-client")]
             http_timeout,
             #[cfg(feature = "http-client")]
@@ -496,6 +931,12 @@ impl Default for SparqlEvaluator {
             with_http_default_service_handler: true,
             parser: SparqlParser::new(),
             inner: QueryEvaluator::new(),
+            query_logger: None,
+            default_graph_mode: None,
+            update_max_memory_size: DEFAULT_UPDATE_MAX_MEMORY_SIZE_IN_MEGABYTES * 1024 * 1024,
+            skolemizer: None,
+            graph_filter: None,
+            writable_graph_filter: None,
         }
     }
 }
@@ -531,6 +972,10 @@ pub struct PreparedSparqlQuery {
     query: spargebra::Query,
     dataset: QueryDatasetSpecification,
     substitutions: HashMap<Variable, Term>,
+    query_logger: Option<QueryLogger>,
+    default_graph_mode: Option<DefaultGraphMode>,
+    skolemizer: Option<Skolemizer>,
+    graph_filter: Option<GraphFilter>,
 }
 
 impl PreparedSparqlQuery {
@@ -579,33 +1024,66 @@ impl PreparedSparqlQuery {
     }
 
     /// Bind the prepared query to the [`Store`] it should be evaluated on.
-    pub fn on_store(self, store: &Store) -> BoundPreparedSparqlQuery<'static> {
+    ///
+    /// The statistics computed by [`Store::recompute_statistics`] are used to optimize the query
+    /// and the default graph set with [`Store::set_default_query_dataset`] is used if the query does not set its dataset.
+    pub fn on_store(mut self, store: &Store) -> BoundPreparedSparqlQuery<'static> {
+        if let Some(statistics) = store.query_statistics() {
+            self.evaluator = self.evaluator.with_statistics(statistics);
+        }
+        if self.default_graph_mode.is_none() {
+            self.default_graph_mode = Some(store.default_query_dataset());
+        }
         let reader = store.storage().snapshot();
-        let queryable_dataset = DatasetView::new(reader);
+        let queryable_dataset = DatasetView::new(reader, self.graph_filter.take());
         self.on_queryable_dataset(queryable_dataset)
     }
 
     /// Bind the prepared query to the [`Transaction`] it should be evaluated on.
     pub fn on_transaction<'b>(
-        self,
+        mut self,
         transaction: &'b Transaction<'_>,
     ) -> BoundPreparedSparqlQuery<'b> {
         let reader = transaction.inner().reader();
-        let dataset = DatasetView::new(reader);
+        let dataset = DatasetView::new(reader, self.graph_filter.take());
+        self.on_queryable_dataset(dataset)
+    }
+
+    /// Bind the prepared query to the [`FederatedStore`] it should be evaluated on.
+    ///
+    /// A snapshot of each member store is taken now.
+    /// The filter set with [`SparqlEvaluator::with_graph_filter`] is not applied.
+    pub fn on_federated_store(
+        self,
+        store: &FederatedStore,
+    ) -> BoundPreparedSparqlQuery<'static, FederatedDatasetView> {
+        let dataset =
+            FederatedDatasetView::new(store.members().iter().map(|(store, graph_name_prefix)| {
+                (store.storage().snapshot(), graph_name_prefix.clone())
+            }));
         self.on_queryable_dataset(dataset)
     }
 
     /// Bind the prepared query to the [`QueryableDataset`] it should be evaluated on.
+    ///
+    /// The filter set with [`SparqlEvaluator::with_graph_filter`] is not applied, the dataset is used as is.
     pub fn on_queryable_dataset<'a, D: QueryableDataset<'a>>(
-        self,
+        mut self,
         queryable_dataset: D,
     ) -> BoundPreparedSparqlQuery<'a, D> {
+        if self.default_graph_mode == Some(DefaultGraphMode::Union)
+            && self.dataset.is_default_dataset()
+        {
+            self.dataset.set_default_graph_as_union();
+        }
         BoundPreparedSparqlQuery {
             evaluator: self.evaluator,
             query: self.query,
             queryable_dataset,
             substitutions: self.substitutions,
             dataset: self.dataset,
+            query_logger: self.query_logger,
+            skolemizer: self.skolemizer,
             marker: PhantomData,
         }
     }
@@ -640,6 +1118,8 @@ pub struct BoundPreparedSparqlQuery<'a, D: QueryableDataset<'a> = DatasetView<'a
     queryable_dataset: D,
     substitutions: HashMap<Variable, Term>,
     dataset: QueryDatasetSpecification,
+    query_logger: Option<QueryLogger>,
+    skolemizer: Option<Skolemizer>,
     marker: PhantomData<&'a ()>,
 }
 
@@ -677,12 +1157,7 @@ impl<'a, D: QueryableDataset<'a>> BoundPreparedSparqlQuery<'a, D> {
 
     /// Evaluate the query against the given store.
     pub fn execute(self) -> Result<QueryResults<'a>, QueryEvaluationError> {
-        let mut prepared = self.evaluator.prepare(&self.query);
-        for (variable, term) in self.substitutions {
-            prepared = prepared.substitute_variable(variable, term);
-        }
-        *prepared.dataset_mut() = self.dataset;
-        prepared.execute(self.queryable_dataset)
+        self.explain().0
     }
 
     /// Compute statistics during evaluation and fills them in the explanation tree.
@@ -719,11 +1194,70 @@ impl<'a, D: QueryableDataset<'a>> BoundPreparedSparqlQuery<'a, D> {
         Result<QueryResults<'a>, QueryEvaluationError>,
         QueryExplanation,
     ) {
-        let mut prepared = self.evaluator.prepare(&self.query);
+        trace_span!("query");
+        let start = DateTime::now();
+        let mut prepared = {
+            trace_span!("query_plan");
+            self.evaluator.prepare(&self.query)
+        };
         for (variable, term) in self.substitutions {
             prepared = prepared.substitute_variable(variable, term);
         }
         *prepared.dataset_mut() = self.dataset;
-        prepared.explain(self.queryable_dataset)
+        let (mut results, explanation) = {
+            trace_span!("query_execute");
+            prepared.explain(self.queryable_dataset)
+        };
+        if let Err(e) = &results {
+            trace_error!(e);
+        }
+        if let Some(skolemizer) = self.skolemizer {
+            results = results.map(|results| skolemize_query_results(results, skolemizer));
+        }
+        let Some(query_logger) = self.query_logger else {
+            return (results, explanation);
+        };
+        (
+            log_query_results(
+                query_logger,
+                self.query.to_string(),
+                start,
+                results,
+                explanation.clone(),
+            ),
+            explanation,
+        )
+    }
+}
+
+fn skolemize_query_results(results: QueryResults<'_>, skolemizer: Skolemizer) -> QueryResults<'_> {
+    match results {
+        QueryResults::Solutions(solutions) => {
+            let variables: Arc<[Variable]> = solutions.variables().into();
+            QueryResults::Solutions(QuerySolutionIter::new(
+                Arc::clone(&variables),
+                solutions.map(move |solution| {
+                    Ok(QuerySolution::from((
+                        Arc::clone(&variables),
+                        solution?
+                            .values()
+                            .iter()
+                            .map(|value| {
+                                value
+                                    .as_ref()
+                                    .map(|term| skolemizer.skolemize_term(term.as_ref()))
+                            })
+                            .collect::<Vec<_>>(),
+                    )))
+                }),
+            ))
+        }
+        QueryResults::Graph(triples) => QueryResults::Graph(QueryTripleIter::new(
+            triples.map(move |triple| Ok(skolemizer.skolemize_triple(triple?.as_ref()))),
+        )),
+        QueryResults::Dataset(quads) => QueryResults::Dataset(QueryQuadIter::new(
+            quads.map(move |quad| Ok(skolemizer.skolemize_quad(quad?.as_ref()))),
+        )),
+        QueryResults::Boolean(result) => QueryResults::Boolean(result),
     }
 }
@@ -0,0 +1,264 @@
+use crate::model::{Quad, Term, TermRef, Variable};
+use crate::sparql::{
+    PreparedSparqlQuery, QueryEvaluationError, QueryResults, QuerySolution, QuerySubscriptionError,
+};
+use crate::storage::{ChangeFeed, CommittedChanges};
+use crate::store::Store;
+use spargebra::algebra::GraphPattern;
+use spargebra::term::{NamedNodePattern, TermPattern, TriplePattern};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+type SubscriptionCallback =
+    Box<dyn Fn(Result<QuerySolutionsChange, QueryEvaluationError>) + Send + Sync>;
+
+/// Options for [`Store::subscribe_query`].
+///
+/// By default, the query is only re-evaluated after a transaction if it might have changed its results.
+/// This is only checked for queries made of a single basic graph pattern:
+/// they are re-evaluated only if one of the quads inserted or removed by the transaction matches one of their triple patterns.
+/// The other queries are re-evaluated after each transaction.
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct QuerySubscriptionOptions {
+    without_incremental_evaluation: bool,
+}
+
+impl QuerySubscriptionOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Re-evaluates the query after each committed transaction, even if none of the changed quads match the query.
+    #[inline]
+    pub fn without_incremental_evaluation(mut self) -> Self {
+        self.without_incremental_evaluation = true;
+        self
+    }
+}
+
+/// A change in the solutions of a query subscribed with [`Store::subscribe_query`].
+///
+/// Solutions are compared as a multiset: if a solution is returned twice by the query and only once after a transaction, it is in the removed solutions.
+#[derive(Clone)]
+pub struct QuerySolutionsChange {
+    added: Vec<QuerySolution>,
+    removed: Vec<QuerySolution>,
+}
+
+impl QuerySolutionsChange {
+    /// The solutions that are now returned by the query.
+    #[inline]
+    pub fn added(&self) -> &[QuerySolution] {
+        &self.added
+    }
+
+    /// The solutions that are not returned by the query anymore.
+    #[inline]
+    pub fn removed(&self) -> &[QuerySolution] {
+        &self.removed
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// A query subscribed with [`Store::subscribe_query`].
+///
+/// The subscription is cancelled when this handle is dropped or [`unsubscribe`](Self::unsubscribe) is called.
+#[must_use]
+pub struct QuerySubscription {
+    change_feed: Arc<ChangeFeed>,
+    id: u64,
+}
+
+impl QuerySubscription {
+    /// Registers the query to be notified after each commit, evaluates it and calls the callback with its solutions
+    pub(crate) fn start(
+        store: &Store,
+        query: PreparedSparqlQuery,
+        options: QuerySubscriptionOptions,
+        callback: SubscriptionCallback,
+        max_subscriptions: usize,
+    ) -> Result<Self, QuerySubscriptionError> {
+        let spargebra::Query::Select { pattern, .. } = &query.query else {
+            return Err(QuerySubscriptionError::NotSelectQuery);
+        };
+        let patterns = if options.without_incremental_evaluation {
+            None
+        } else {
+            bgp_patterns(pattern)
+        };
+        let state = Arc::new(SubscriptionState {
+            store: store.clone(),
+            query,
+            patterns,
+            solutions: Mutex::default(),
+            callback,
+        });
+        // We hold the lock during the initial evaluation so that the commits done meanwhile wait for it
+        let mut solutions = state
+            .solutions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let change_feed = Arc::clone(store.storage().change_feed());
+        let listener_state = Arc::clone(&state);
+        let id = change_feed
+            .add_listener(
+                move |changes| listener_state.on_commit(changes),
+                max_subscriptions,
+            )
+            .ok_or(QuerySubscriptionError::TooManySubscriptions(
+                max_subscriptions,
+            ))?;
+        // Dropped on error to unregister the listener
+        let subscription = Self { change_feed, id };
+        *solutions = state.evaluate()?;
+        let change = solutions.diff(&SolutionCounts::default());
+        drop(solutions);
+        (state.callback)(Ok(change));
+        Ok(subscription)
+    }
+
+    /// Stops to call the callback after commits.
+    #[inline]
+    pub fn unsubscribe(self) {
+        drop(self);
+    }
+}
+
+impl Drop for QuerySubscription {
+    fn drop(&mut self) {
+        self.change_feed.remove_listener(self.id);
+    }
+}
+
+struct SubscriptionState {
+    store: Store,
+    query: PreparedSparqlQuery,
+    /// The triple patterns of the query if it is a basic graph pattern and the incremental evaluation is enabled
+    patterns: Option<Vec<TriplePattern>>,
+    /// The current solutions of the query
+    solutions: Mutex<SolutionCounts>,
+    callback: SubscriptionCallback,
+}
+
+impl SubscriptionState {
+    fn on_commit(&self, changes: &CommittedChanges) {
+        if let (Some(patterns), Some(quads)) = (&self.patterns, changes.quads()) {
+            if !quads
+                .iter()
+                .any(|quad| patterns.iter().any(|pattern| matches(pattern, quad)))
+            {
+                return;
+            }
+        }
+        let Ok(mut solutions) = self.solutions.lock() else {
+            return;
+        };
+        let change = match self.evaluate() {
+            Ok(new_solutions) => {
+                let change = new_solutions.diff(&solutions);
+                *solutions = new_solutions;
+                change
+            }
+            Err(e) => {
+                drop(solutions);
+                (self.callback)(Err(e));
+                return;
+            }
+        };
+        drop(solutions);
+        if !change.is_empty() {
+            (self.callback)(Ok(change));
+        }
+    }
+
+    fn evaluate(&self) -> Result<SolutionCounts, QueryEvaluationError> {
+        let QueryResults::Solutions(solutions) =
+            self.query.clone().on_store(&self.store).execute()?
+        else {
+            return Err(QueryEvaluationError::Unexpected(
+                "Only SELECT queries can be subscribed to".into(),
+            ));
+        };
+        let mut counts = SolutionCounts {
+            variables: solutions.variables().into(),
+            counts: HashMap::new(),
+        };
+        for solution in solutions {
+            *counts
+                .counts
+                .entry(solution?.values().to_vec())
+                .or_default() += 1;
+        }
+        Ok(counts)
+    }
+}
+
+/// The solutions of a query as a multiset
+#[derive(Default)]
+struct SolutionCounts {
+    variables: Arc<[Variable]>,
+    counts: HashMap<Vec<Option<Term>>, usize>,
+}
+
+impl SolutionCounts {
+    /// The change to go from `previous` to `self`
+    fn diff(&self, previous: &Self) -> QuerySolutionsChange {
+        QuerySolutionsChange {
+            added: self.missing_from(previous),
+            removed: previous.missing_from(self),
+        }
+    }
+
+    /// The solutions of `self` that are not in `other`
+    fn missing_from(&self, other: &Self) -> Vec<QuerySolution> {
+        let mut missing = Vec::new();
+        for (values, count) in &self.counts {
+            let other_count = other.counts.get(values).copied().unwrap_or(0);
+            for _ in other_count..*count {
+                missing.push(QuerySolution::from((
+                    Arc::clone(&self.variables),
+                    values.clone(),
+                )));
+            }
+        }
+        missing
+    }
+}
+
+/// The triple patterns of the query if it is only a basic graph pattern, maybe with a projection or a `GRAPH`
+fn bgp_patterns(pattern: &GraphPattern) -> Option<Vec<TriplePattern>> {
+    match pattern {
+        GraphPattern::Bgp { patterns } => Some(patterns.clone()),
+        GraphPattern::Project { inner, .. }
+        | GraphPattern::Distinct { inner }
+        | GraphPattern::Reduced { inner }
+        | GraphPattern::Graph { inner, .. } => bgp_patterns(inner),
+        _ => None,
+    }
+}
+
+/// If the quad might be matched by the pattern
+///
+/// The graph name is ignored, it might only lead to a useless evaluation.
+fn matches(pattern: &TriplePattern, quad: &Quad) -> bool {
+    term_matches(&pattern.subject, quad.subject.as_ref().into())
+        && match &pattern.predicate {
+            NamedNodePattern::NamedNode(predicate) => *predicate == quad.predicate,
+            NamedNodePattern::Variable(_) => true,
+        }
+        && term_matches(&pattern.object, quad.object.as_ref())
+}
+
+fn term_matches(pattern: &TermPattern, term: TermRef<'_>) -> bool {
+    match pattern {
+        TermPattern::NamedNode(node) => TermRef::from(node.as_ref()) == term,
+        TermPattern::Literal(literal) => TermRef::from(literal.as_ref()) == term,
+        // Variables, blank nodes and triple terms
+        _ => true,
+    }
+}
@@ -0,0 +1,61 @@
+use oxiri::Iri;
+use oxrdf::NamedNode;
+use spareval::{DefaultServiceHandler, QuerySolutionIter, ServiceHandler};
+use spargebra::algebra::GraphPattern;
+
+/// Wraps a [`ServiceHandler`] to evaluate each `SERVICE` call inside of a span
+pub struct TracedServiceHandler<H> {
+    service_name: NamedNode,
+    inner: H,
+}
+
+impl<H> TracedServiceHandler<H> {
+    pub fn new(service_name: NamedNode, inner: H) -> Self {
+        Self {
+            service_name,
+            inner,
+        }
+    }
+}
+
+impl<H: ServiceHandler> ServiceHandler for TracedServiceHandler<H> {
+    type Error = H::Error;
+
+    fn handle(
+        &self,
+        pattern: &GraphPattern,
+        base_iri: Option<&Iri<String>>,
+    ) -> Result<QuerySolutionIter<'static>, Self::Error> {
+        trace_span!("service", service = self.service_name.as_str());
+        self.inner
+            .handle(pattern, base_iri)
+            .inspect_err(|e| trace_error!(e))
+    }
+}
+
+/// Wraps a [`DefaultServiceHandler`] to evaluate each `SERVICE` call inside of a span
+pub struct TracedDefaultServiceHandler<H> {
+    inner: H,
+}
+
+impl<H> TracedDefaultServiceHandler<H> {
+    pub fn new(inner: H) -> Self {
+        Self { inner }
+    }
+}
+
+impl<H: DefaultServiceHandler> DefaultServiceHandler for TracedDefaultServiceHandler<H> {
+    type Error = H::Error;
+
+    fn handle(
+        &self,
+        service_name: &NamedNode,
+        pattern: &GraphPattern,
+        base_iri: Option<&Iri<String>>,
+    ) -> Result<QuerySolutionIter<'static>, Self::Error> {
+        trace_span!("service", service = service_name.as_str());
+        self.inner
+            .handle(service_name, pattern, base_iri)
+            .inspect_err(|e| trace_error!(e))
+    }
+}
@@ -1,29 +1,36 @@
-#[cfg(feature = "http-client")]
-use crate::io::{RdfFormat, RdfParser};
-use crate::model::{GraphName as OxGraphName, GraphNameRef, Quad as OxQuad};
+use crate::io::{RdfFormat, RdfParser, RdfSerializer, ReaderQuadParser, WriterQuadSerializer};
+use crate::model::{
+    GraphName as OxGraphName, GraphNameRef, NamedNodeRef, NamedOrBlankNode as OxNamedOrBlankNode,
+    Quad as OxQuad, QuadRef, TermRef,
+};
 #[expect(deprecated)]
 use crate::sparql::algebra::Update;
 use crate::sparql::dataset::DatasetView;
 use crate::sparql::error::UpdateEvaluationError;
 #[cfg(feature = "http-client")]
-use crate::sparql::http::Client;
+use crate::sparql::http::{Client, load_rdf};
+use crate::sparql::{DefaultGraphMode, GraphFilter};
+use crate::storage::numeric_encoder::{Decoder, EncodedTerm};
 use crate::storage::{Storage, StorageError, StorageReadableTransaction, StorageTransaction};
 use crate::store::{Store, Transaction};
 use oxiri::Iri;
-#[cfg(feature = "http-client")]
-use oxrdfio::LoadedDocument;
+use rand::random;
 use rustc_hash::FxHashMap;
 use spareval::{DeleteInsertQuad, QueryDatasetSpecification, QueryEvaluator};
 use spargebra::GraphUpdateOperation;
 use spargebra::algebra::{GraphPattern, GraphTarget};
 use spargebra::term::{
-    BlankNode, GraphName, GroundQuad, GroundQuadPattern, GroundTerm, NamedNode, NamedOrBlankNode,
-    Quad, QuadPattern, Term,
+    BlankNode, GraphName, GraphNamePattern, GroundQuad, GroundQuadPattern, GroundTerm,
+    GroundTermPattern, NamedNode, NamedNodePattern, NamedOrBlankNode, Quad, QuadPattern, Term,
+    TermPattern, Variable,
 };
 #[cfg(feature = "rdf-12")]
 use spargebra::term::{GroundTriple, Triple};
-#[cfg(feature = "http-client")]
-use std::io::Read;
+use std::env;
+use std::fs::{File, OpenOptions, remove_file};
+use std::io::{self, BufReader, BufWriter, IntoInnerError};
+use std::mem::take;
+use std::path::PathBuf;
 #[cfg(feature = "http-client")]
 use std::time::Duration;
 
@@ -46,6 +53,10 @@ pub struct PreparedSparqlUpdate {
     evaluator: QueryEvaluator,
     update: spargebra::Update,
     using_datasets: Vec<Option<QueryDatasetSpecification>>,
+    default_graph_mode: Option<DefaultGraphMode>,
+    update_max_memory_size: usize,
+    graph_filter: Option<GraphFilter>,
+    writable_graph_filter: Option<GraphFilter>,
     #[cfg(feature = "http-client")]
     http_timeout: Option<Duration>,
     #[cfg(feature = "http-client")]
@@ -57,6 +68,10 @@ impl PreparedSparqlUpdate {
     pub(crate) fn new(
         evaluator: QueryEvaluator,
         update: Update,
+        default_graph_mode: Option<DefaultGraphMode>,
+        update_max_memory_size: usize,
+        graph_filter: Option<GraphFilter>,
+        writable_graph_filter: Option<GraphFilter>,
         #[cfg(feature = "http-client")] http_timeout: Option<Duration>,
         #[cfg(feature = "http-client")] http_redirection_limit: usize,
     ) -> Self {
@@ -64,6 +79,10 @@ impl PreparedSparqlUpdate {
             evaluator,
             update: update.inner,
             using_datasets: update.using_datasets,
+            default_graph_mode,
+            update_max_memory_size,
+            graph_filter,
+            writable_graph_filter,
             #[cfg(feature = "http-client")]
             http_timeout,
             #[cfg(feature = "http-client")]
@@ -96,8 +115,18 @@ impl PreparedSparqlUpdate {
     /// prepared_update.on_store(&Store::new()?).execute()?;
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
-    pub fn on_store(self, store: &Store) -> BoundPreparedSparqlUpdate<'_, '_> {
-        let transaction = if update_requires_read(&self.update) {
+    ///
+    /// The statistics computed by [`Store::recompute_statistics`] are used to optimize the update.
+    pub fn on_store(mut self, store: &Store) -> BoundPreparedSparqlUpdate<'_, '_> {
+        if let Some(statistics) = store.query_statistics() {
+            self.evaluator = self.evaluator.with_statistics(statistics);
+        }
+        self.apply_default_graph_mode();
+        // The graph filters require to read the store to check the cleared graphs
+        let transaction = if self.graph_filter.is_some()
+            || self.writable_graph_filter.is_some()
+            || update_requires_read(&self.update)
+        {
             store
                 .storage()
                 .start_readable_transaction()
@@ -112,6 +141,9 @@ impl PreparedSparqlUpdate {
             evaluator: self.evaluator,
             update: self.update,
             using_datasets: self.using_datasets,
+            update_max_memory_size: self.update_max_memory_size,
+            graph_filter: self.graph_filter,
+            writable_graph_filter: self.writable_graph_filter,
             #[cfg(feature = "http-client")]
             http_timeout: self.http_timeout,
             #[cfg(feature = "http-client")]
@@ -136,13 +168,17 @@ impl PreparedSparqlUpdate {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn on_transaction<'a, 'b: 'a>(
-        self,
+        mut self,
         transaction: &'a mut Transaction<'b>,
     ) -> BoundPreparedSparqlUpdate<'a, 'b> {
+        self.apply_default_graph_mode();
         BoundPreparedSparqlUpdate {
             evaluator: self.evaluator,
             update: self.update,
             using_datasets: self.using_datasets,
+            update_max_memory_size: self.update_max_memory_size,
+            graph_filter: self.graph_filter,
+            writable_graph_filter: self.writable_graph_filter,
             #[cfg(feature = "http-client")]
             http_timeout: self.http_timeout,
             #[cfg(feature = "http-client")]
@@ -150,6 +186,17 @@ impl PreparedSparqlUpdate {
             transaction: Ok(UpdateTransaction::BorrowedReadable(transaction.inner_mut())),
         }
     }
+
+    /// Only the `WHERE` clauses without `USING` or `WITH` are affected, the templates still target the actual default graph
+    fn apply_default_graph_mode(&mut self) {
+        if self.default_graph_mode == Some(DefaultGraphMode::Union) {
+            for using in self.using_datasets.iter_mut().flatten() {
+                if using.is_default_dataset() {
+                    using.set_default_graph_as_union();
+                }
+            }
+        }
+    }
 }
 
 /// A prepared SPARQL query bound to a storage, ready to be executed.
@@ -174,6 +221,9 @@ pub struct BoundPreparedSparqlUpdate<'a, 'b> {
     evaluator: QueryEvaluator,
     update: spargebra::Update,
     using_datasets: Vec<Option<QueryDatasetSpecification>>,
+    update_max_memory_size: usize,
+    graph_filter: Option<GraphFilter>,
+    writable_graph_filter: Option<GraphFilter>,
     #[cfg(feature = "http-client")]
     http_timeout: Option<Duration>,
     #[cfg(feature = "http-client")]
@@ -184,12 +234,21 @@ pub struct BoundPreparedSparqlUpdate<'a, 'b> {
 impl BoundPreparedSparqlUpdate<'_, '_> {
     /// Evaluate the update against the given store.
     pub fn execute(self) -> Result<(), UpdateEvaluationError> {
+        trace_span!("update", operations = self.update.operations.len());
+        self.evaluate().inspect_err(|e| trace_error!(e))
+    }
+
+    fn evaluate(self) -> Result<(), UpdateEvaluationError> {
         match self.transaction? {
             UpdateTransaction::OwnedReadable(mut transaction) => {
                 ReadableUpdateEvaluator {
                     transaction: &mut transaction,
                     base_iri: self.update.base_iri.clone(),
                     query_evaluator: self.evaluator,
+                    max_memory_size: self.update_max_memory_size,
+                    owns_transaction: true,
+                    graph_filter: self.graph_filter,
+                    writable_graph_filter: self.writable_graph_filter,
                     #[cfg(feature = "http-client")]
                     client: Client::new(self.http_timeout, self.http_redirection_limit),
                 }
@@ -201,6 +260,10 @@ impl BoundPreparedSparqlUpdate<'_, '_> {
                 transaction,
                 base_iri: self.update.base_iri.clone(),
                 query_evaluator: self.evaluator,
+                max_memory_size: self.update_max_memory_size,
+                owns_transaction: false,
+                graph_filter: self.graph_filter,
+                writable_graph_filter: self.writable_graph_filter,
                 #[cfg(feature = "http-client")]
                 client: Client::new(self.http_timeout, self.http_redirection_limit),
             }
@@ -211,6 +274,7 @@ impl BoundPreparedSparqlUpdate<'_, '_> {
                     storage_for_initial_read: Some(storage),
                     base_iri: self.update.base_iri.clone(),
                     query_evaluator: self.evaluator,
+                    max_memory_size: self.update_max_memory_size,
                     #[cfg(feature = "http-client")]
                     client: Client::new(self.http_timeout, self.http_redirection_limit),
                 }
@@ -232,6 +296,11 @@ struct ReadableUpdateEvaluator<'a, 'b> {
     transaction: &'a mut StorageReadableTransaction<'b>,
     base_iri: Option<Iri<String>>,
     query_evaluator: QueryEvaluator,
+    max_memory_size: usize,
+    /// If the transaction is only used by this update and its changes might be written before the end of the update
+    owns_transaction: bool,
+    graph_filter: Option<GraphFilter>,
+    writable_graph_filter: Option<GraphFilter>,
     #[cfg(feature = "http-client")]
     client: Client,
 }
@@ -239,11 +308,21 @@ struct ReadableUpdateEvaluator<'a, 'b> {
 impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
     fn eval_all(
         &mut self,
-        updates: &[GraphUpdateOperation],
-        using_datasets: &[Option<QueryDatasetSpecification>],
+        mut updates: &[GraphUpdateOperation],
+        mut using_datasets: &[Option<QueryDatasetSpecification>],
     ) -> Result<(), UpdateEvaluationError> {
-        for (update, using_dataset) in updates.iter().zip(using_datasets) {
-            self.eval(update, using_dataset)?;
+        while let Some((update, using_dataset)) = updates.first().zip(using_datasets.first()) {
+            if let Some((from, to, silent)) =
+                as_graph_move(updates, using_datasets).filter(|_| !self.has_graph_filters())
+            {
+                self.eval_move(from, to, silent)?;
+                updates = &updates[3..];
+                using_datasets = &using_datasets[3..];
+            } else {
+                self.eval(update, using_dataset)?;
+                updates = &updates[1..];
+                using_datasets = &using_datasets[1..];
+            }
         }
         Ok(())
     }
@@ -253,15 +332,21 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
         update: &GraphUpdateOperation,
         using_dataset: &Option<QueryDatasetSpecification>,
     ) -> Result<(), UpdateEvaluationError> {
-        match update {
-            GraphUpdateOperation::InsertData { data } => {
-                self.eval_insert_data(data);
-                Ok(())
+        if let Some((from, to)) = as_graph_addition(update, using_dataset) {
+            // The graph filters require to check each quad
+            if !self.has_graph_filters() {
+                return Ok(self.transaction.add_graph(from, to)?);
             }
-            GraphUpdateOperation::DeleteData { data } => {
-                self.eval_delete_data(data);
-                Ok(())
+        }
+        if let Some(deletion) = as_pattern_deletion(update, using_dataset) {
+            // The graph filters require to check each quad
+            if !self.has_graph_filters() {
+                return self.eval_pattern_deletion(&deletion);
             }
+        }
+        match update {
+            GraphUpdateOperation::InsertData { data } => self.eval_insert_data(data),
+            GraphUpdateOperation::DeleteData { data } => self.eval_delete_data(data),
             GraphUpdateOperation::DeleteInsert {
                 delete,
                 insert,
@@ -292,19 +377,91 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
         }
     }
 
-    fn eval_insert_data(&mut self, data: &[Quad]) {
+    fn has_graph_filters(&self) -> bool {
+        self.graph_filter.is_some() || self.writable_graph_filter.is_some()
+    }
+
+    fn is_visible(&self, graph_name: GraphNameRef<'_>) -> bool {
+        self.graph_filter
+            .as_ref()
+            .is_none_or(|filter| filter(graph_name))
+    }
+
+    fn check_writable(&self, graph_name: GraphNameRef<'_>) -> Result<(), UpdateEvaluationError> {
+        if self
+            .writable_graph_filter
+            .as_ref()
+            .is_none_or(|filter| filter(graph_name))
+        {
+            Ok(())
+        } else {
+            Err(UpdateEvaluationError::GraphNotWritable(
+                graph_name.into_owned(),
+            ))
+        }
+    }
+
+    /// The graphs hidden by the graph filter are considered as not existing
+    fn contains_named_graph(&self, graph_name: NamedNodeRef<'_>) -> Result<bool, StorageError> {
+        Ok(self.is_visible(graph_name.into())
+            && self
+                .transaction
+                .reader()
+                .contains_named_graph(&graph_name.into())?)
+    }
+
+    fn visible_named_graphs(&self) -> Result<Vec<OxNamedOrBlankNode>, StorageError> {
+        let reader = self.transaction.reader();
+        let mut graph_names = Vec::new();
+        for graph_name in reader.named_graphs() {
+            let graph_name = reader.decode_named_or_blank_node(&graph_name?)?;
+            if self.is_visible(graph_name.as_ref().into()) {
+                graph_names.push(graph_name);
+            }
+        }
+        Ok(graph_names)
+    }
+
+    fn eval_move(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+        silent: bool,
+    ) -> Result<(), UpdateEvaluationError> {
+        if let GraphNameRef::NamedNode(from) = from {
+            if !silent
+                && !self
+                    .transaction
+                    .reader()
+                    .contains_named_graph(&from.into())?
+            {
+                return Err(UpdateEvaluationError::GraphDoesNotExist(from.into_owned()));
+            }
+        }
+        Ok(self.transaction.move_graph(from, to)?)
+    }
+
+    fn eval_insert_data(&mut self, data: &[Quad]) -> Result<(), UpdateEvaluationError> {
+        for quad in data {
+            self.check_writable(graph_name_ref(&quad.graph_name))?;
+        }
         let mut bnodes = FxHashMap::default();
         for quad in data {
             let quad = convert_quad(quad, &mut bnodes);
             self.transaction.insert(quad.as_ref());
         }
+        Ok(())
     }
 
-    fn eval_delete_data(&mut self, data: &[GroundQuad]) {
+    fn eval_delete_data(&mut self, data: &[GroundQuad]) -> Result<(), UpdateEvaluationError> {
+        for quad in data {
+            self.check_writable(graph_name_ref(&quad.graph_name))?;
+        }
         for quad in data {
             let quad = convert_ground_quad(quad);
             self.transaction.remove(quad.as_ref());
         }
+        Ok(())
     }
 
     fn eval_delete_insert(
@@ -322,25 +479,77 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
             algebra,
         );
         *prepared.dataset_mut() = using.clone();
-        let mutations = prepared
-            .execute(DatasetView::new(self.transaction.reader()))?
-            .collect::<Result<Vec<_>, _>>()?;
-        for mutation in mutations {
+        // We evaluate the whole WHERE clause before applying the changes to not see them during the evaluation
+        let mut buffer = DeleteInsertBuffer::new(self.max_memory_size / 2);
+        for mutation in prepared.execute(DatasetView::new(
+            self.transaction.reader(),
+            self.graph_filter.clone(),
+        ))? {
+            let mutation = mutation?;
+            let (DeleteInsertQuad::Delete(quad) | DeleteInsertQuad::Insert(quad)) = &mutation;
+            self.check_writable(quad.graph_name.as_ref())?;
+            buffer.push(mutation)?;
+        }
+        let max_pending_size = self.max_pending_size();
+        buffer.apply(|mutation| {
             match mutation {
                 DeleteInsertQuad::Delete(quad) => self.transaction.remove(quad.as_ref()),
                 DeleteInsertQuad::Insert(quad) => self.transaction.insert(quad.as_ref()),
             }
+            self.transaction
+                .write_pending_if_larger_than(max_pending_size)
+        })?;
+        Ok(())
+    }
+
+    /// The changes done so far are written when they use more than half of the memory budget, the other half being for the [`DeleteInsertBuffer`]
+    ///
+    /// It is only done if the update owns its transaction, the changes done in a user transaction are kept until its commit.
+    fn max_pending_size(&self) -> usize {
+        if self.owns_transaction {
+            self.max_memory_size / 2
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Removes the quads matching the pattern without evaluating the `WHERE` clause
+    fn eval_pattern_deletion(
+        &mut self,
+        deletion: &PatternDeletion,
+    ) -> Result<(), UpdateEvaluationError> {
+        let graph_names = if let Some(graph_name) = &deletion.graph_name {
+            vec![graph_name.clone()]
+        } else {
+            self.transaction
+                .reader()
+                .named_graphs()
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        for graph_name in &graph_names {
+            self.transaction.remove_matching(
+                deletion.subject.as_ref(),
+                deletion.predicate.as_ref(),
+                deletion.object.as_ref(),
+                Some(graph_name),
+            )?;
         }
         Ok(())
     }
 
     fn eval_load(&mut self, from: &NamedNode, to: &GraphName) -> Result<(), UpdateEvaluationError> {
+        self.check_writable(graph_name_ref(to))?;
+        let max_pending_size = self.max_pending_size();
         eval_load(
             from,
             to,
             #[cfg(feature = "http-client")]
             &self.client,
-            |q| self.transaction.insert(q.as_ref()),
+            |q| {
+                self.transaction.insert(q.as_ref());
+                self.transaction
+                    .write_pending_if_larger_than(max_pending_size)
+            },
         )
     }
 
@@ -349,11 +558,7 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
         graph_name: &NamedNode,
         silent: bool,
     ) -> Result<(), UpdateEvaluationError> {
-        if self
-            .transaction
-            .reader()
-            .contains_named_graph(&graph_name.as_ref().into())?
-        {
+        if self.contains_named_graph(graph_name.as_ref())? {
             if silent {
                 Ok(())
             } else {
@@ -362,6 +567,7 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
                 ))
             }
         } else {
+            self.check_writable(graph_name.as_ref().into())?;
             self.transaction.insert_named_graph(graph_name.into());
             Ok(())
         }
@@ -374,11 +580,8 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
     ) -> Result<(), UpdateEvaluationError> {
         match graph {
             GraphTarget::NamedNode(graph_name) => {
-                if self
-                    .transaction
-                    .reader()
-                    .contains_named_graph(&graph_name.as_ref().into())?
-                {
+                if self.contains_named_graph(graph_name.as_ref())? {
+                    self.check_writable(graph_name.as_ref().into())?;
                     Ok(self.transaction.clear_graph(graph_name.into())?)
                 } else if silent {
                     Ok(())
@@ -387,9 +590,17 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
                 }
             }
             GraphTarget::DefaultGraph => {
+                self.check_writable(GraphNameRef::DefaultGraph)?;
                 self.transaction.clear_graph(GraphNameRef::DefaultGraph)?;
                 Ok(())
             }
+            GraphTarget::NamedGraphs | GraphTarget::AllGraphs if self.has_graph_filters() => {
+                let graph_names = self.target_graphs(graph)?;
+                for graph_name in &graph_names {
+                    self.transaction.clear_graph(graph_name.as_ref())?;
+                }
+                Ok(())
+            }
             GraphTarget::NamedGraphs => Ok(self.transaction.clear_all_named_graphs()?),
             GraphTarget::AllGraphs => Ok(self.transaction.clear_all_graphs()?),
         }
@@ -402,11 +613,8 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
     ) -> Result<(), UpdateEvaluationError> {
         match graph {
             GraphTarget::NamedNode(graph_name) => {
-                if self
-                    .transaction
-                    .reader()
-                    .contains_named_graph(&graph_name.as_ref().into())?
-                {
+                if self.contains_named_graph(graph_name.as_ref())? {
+                    self.check_writable(graph_name.as_ref().into())?;
                     self.transaction.remove_named_graph(graph_name.into())?;
                     Ok(())
                 } else if silent {
@@ -416,16 +624,66 @@ impl<'a, 'b: 'a> ReadableUpdateEvaluator<'a, 'b> {
                 }
             }
             GraphTarget::DefaultGraph => {
+                self.check_writable(GraphNameRef::DefaultGraph)?;
                 Ok(self.transaction.clear_graph(GraphNameRef::DefaultGraph)?)
             }
+            GraphTarget::NamedGraphs | GraphTarget::AllGraphs if self.has_graph_filters() => {
+                let graph_names = self.target_graphs(graph)?;
+                for graph_name in &graph_names {
+                    match graph_name {
+                        OxGraphName::NamedNode(graph_name) => {
+                            self.transaction.remove_named_graph(graph_name.into())?
+                        }
+                        OxGraphName::BlankNode(graph_name) => {
+                            self.transaction.remove_named_graph(graph_name.into())?
+                        }
+                        OxGraphName::DefaultGraph => {
+                            self.transaction.clear_graph(GraphNameRef::DefaultGraph)?
+                        }
+                    }
+                }
+                Ok(())
+            }
             GraphTarget::NamedGraphs => Ok(self.transaction.remove_all_named_graphs()?),
             GraphTarget::AllGraphs => Ok(self.transaction.clear()?),
         }
     }
+
+    /// The visible graphs targeted by `NAMED` or `ALL`, fails if one of them is not writable
+    fn target_graphs(
+        &self,
+        target: &GraphTarget,
+    ) -> Result<Vec<OxGraphName>, UpdateEvaluationError> {
+        let mut graph_names = self
+            .visible_named_graphs()?
+            .into_iter()
+            .map(OxGraphName::from)
+            .collect::<Vec<_>>();
+        if matches!(target, GraphTarget::AllGraphs) && self.is_visible(GraphNameRef::DefaultGraph) {
+            graph_names.push(OxGraphName::DefaultGraph);
+        }
+        for graph_name in &graph_names {
+            self.check_writable(graph_name.as_ref())?;
+        }
+        Ok(graph_names)
+    }
+}
+
+fn graph_name_ref(graph_name: &GraphName) -> GraphNameRef<'_> {
+    match graph_name {
+        GraphName::NamedNode(graph_name) => graph_name.into(),
+        GraphName::DefaultGraph => GraphNameRef::DefaultGraph,
+    }
 }
 
 fn update_requires_read(update: &spargebra::Update) -> bool {
     for (i, op) in update.operations.iter().enumerate() {
+        if as_graph_addition(op, &None).is_some() {
+            return true; // We copy the graph from the transaction
+        }
+        if as_pattern_deletion(op, &None).is_some() {
+            return true; // We remove the matching quads seen by the transaction
+        }
         match op {
             GraphUpdateOperation::InsertData { .. }
             | GraphUpdateOperation::DeleteData { .. }
@@ -450,6 +708,7 @@ struct WriteOnlyUpdateEvaluator<'a, 'b> {
     storage_for_initial_read: Option<&'b Storage>,
     base_iri: Option<Iri<String>>,
     query_evaluator: QueryEvaluator,
+    max_memory_size: usize,
     #[cfg(feature = "http-client")]
     client: Client,
 }
@@ -546,25 +805,39 @@ impl WriteOnlyUpdateEvaluator<'_, '_> {
             algebra,
         );
         *prepared.dataset_mut() = using.clone();
-        let mutations = prepared
-            .execute(DatasetView::new(storage.snapshot()))?
-            .collect::<Result<Vec<_>, _>>()?;
-        for mutation in mutations {
+        let mut buffer = DeleteInsertBuffer::new(self.max_memory_size / 2);
+        for mutation in prepared.execute(DatasetView::new(storage.snapshot(), None))? {
+            buffer.push(mutation?)?;
+        }
+        let max_pending_size = self.max_pending_size();
+        buffer.apply(|mutation| {
             match mutation {
                 DeleteInsertQuad::Delete(quad) => self.transaction.remove(quad.as_ref()),
                 DeleteInsertQuad::Insert(quad) => self.transaction.insert(quad.as_ref()),
             }
-        }
+            self.transaction
+                .write_pending_if_larger_than(max_pending_size)
+        })?;
         Ok(())
     }
 
+    /// The changes done so far are written when they use more than half of the memory budget, the other half being for the [`DeleteInsertBuffer`]
+    fn max_pending_size(&self) -> usize {
+        self.max_memory_size / 2
+    }
+
     fn eval_load(&mut self, from: &NamedNode, to: &GraphName) -> Result<(), UpdateEvaluationError> {
+        let max_pending_size = self.max_pending_size();
         eval_load(
             from,
             to,
             #[cfg(feature = "http-client")]
             &self.client,
-            |q| self.transaction.insert(q.as_ref()),
+            |q| {
+                self.transaction.insert(q.as_ref());
+                self.transaction
+                    .write_pending_if_larger_than(max_pending_size)
+            },
         )
     }
 
@@ -631,49 +904,418 @@ impl WriteOnlyUpdateEvaluator<'_, '_> {
     }
 }
 
+/// Changes computed by a DELETE/INSERT operation, kept until the WHERE clause evaluation is done.
+///
+/// Following the SPARQL semantics, all deletions are applied before the insertions.
+/// When the buffered quads use more than `max_memory_size` bytes, they are spilled to temporary N-Quads files.
+struct DeleteInsertBuffer {
+    delete: QuadBuffer,
+    insert: QuadBuffer,
+    max_memory_size: usize,
+}
+
+impl DeleteInsertBuffer {
+    fn new(max_memory_size: usize) -> Self {
+        Self {
+            delete: QuadBuffer::default(),
+            insert: QuadBuffer::default(),
+            max_memory_size,
+        }
+    }
+
+    fn push(&mut self, mutation: DeleteInsertQuad) -> Result<(), StorageError> {
+        match mutation {
+            DeleteInsertQuad::Delete(quad) => self.delete.push(quad),
+            DeleteInsertQuad::Insert(quad) => self.insert.push(quad),
+        }
+        // There is no file system in the browser, we keep everything in memory
+        if self.delete.memory_size + self.insert.memory_size > self.max_memory_size
+            && !cfg!(all(target_family = "wasm", target_os = "unknown"))
+        {
+            if self.delete.memory_size >= self.insert.memory_size {
+                self.delete.spill()?;
+            } else {
+                self.insert.spill()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(
+        self,
+        mut f: impl FnMut(DeleteInsertQuad) -> Result<(), StorageError>,
+    ) -> Result<(), StorageError> {
+        self.delete
+            .for_each(|quad| f(DeleteInsertQuad::Delete(quad)))?;
+        self.insert
+            .for_each(|quad| f(DeleteInsertQuad::Insert(quad)))
+    }
+}
+
+#[derive(Default)]
+struct QuadBuffer {
+    quads: Vec<OxQuad>,
+    memory_size: usize,
+    spilled: Option<SpillFile>,
+}
+
+impl QuadBuffer {
+    fn push(&mut self, quad: OxQuad) {
+        self.memory_size += quad_memory_size(quad.as_ref());
+        self.quads.push(quad);
+    }
+
+    /// Moves the in-memory quads to a temporary file
+    fn spill(&mut self) -> Result<(), StorageError> {
+        let spilled = match &mut self.spilled {
+            Some(spilled) => spilled,
+            None => self.spilled.insert(SpillFile::new()?),
+        };
+        for quad in take(&mut self.quads) {
+            spilled.serializer.serialize_quad(&quad)?;
+        }
+        self.memory_size = 0;
+        Ok(())
+    }
+
+    fn for_each(
+        self,
+        mut f: impl FnMut(OxQuad) -> Result<(), StorageError>,
+    ) -> Result<(), StorageError> {
+        if let Some(spilled) = self.spilled {
+            for quad in spilled.read()? {
+                f(quad?)?;
+            }
+        }
+        self.quads.into_iter().try_for_each(f)
+    }
+}
+
+/// A temporary N-Quads file
+struct SpillFile {
+    path: TemporaryPath,
+    serializer: WriterQuadSerializer<BufWriter<File>>,
+}
+
+impl SpillFile {
+    fn new() -> io::Result<Self> {
+        let path = env::temp_dir().join(format!("oxigraph-update-{:x}.nq", random::<u128>()));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self {
+            path: TemporaryPath(path),
+            serializer: RdfSerializer::from_format(RdfFormat::NQuads)
+                .for_writer(BufWriter::new(file)),
+        })
+    }
+
+    fn read(self) -> io::Result<SpillFileReader> {
+        self.serializer
+            .finish()?
+            .into_inner()
+            .map_err(IntoInnerError::into_error)?;
+        let file = File::open(&self.path.0)?;
+        Ok(SpillFileReader {
+            parser: RdfParser::from_format(RdfFormat::NQuads)
+                .lenient()
+                .for_reader(BufReader::new(file)),
+            _path: self.path,
+        })
+    }
+}
+
+struct SpillFileReader {
+    parser: ReaderQuadParser<BufReader<File>>,
+    _path: TemporaryPath,
+}
+
+impl Iterator for SpillFileReader {
+    type Item = io::Result<OxQuad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.parser.next()?.map_err(io::Error::from))
+    }
+}
+
+/// A file path that is removed on drop
+struct TemporaryPath(PathBuf);
+
+impl Drop for TemporaryPath {
+    fn drop(&mut self) {
+        #[expect(unused_must_use)] // It is only a temporary file
+        remove_file(&self.0);
+    }
+}
+
+/// Approximate number of bytes used by the quad in memory
+fn quad_memory_size(quad: QuadRef<'_>) -> usize {
+    size_of::<OxQuad>()
+        + term_memory_size(quad.subject.into())
+        + term_memory_size(quad.predicate.into())
+        + term_memory_size(quad.object)
+        + match quad.graph_name {
+            GraphNameRef::NamedNode(node) => node.as_str().len(),
+            GraphNameRef::BlankNode(node) => node.as_str().len(),
+            GraphNameRef::DefaultGraph => 0,
+        }
+}
+
+fn term_memory_size(term: TermRef<'_>) -> usize {
+    match term {
+        TermRef::NamedNode(node) => node.as_str().len(),
+        TermRef::BlankNode(node) => node.as_str().len(),
+        TermRef::Literal(literal) => {
+            literal.value().len()
+                + literal
+                    .language()
+                    .map_or_else(|| literal.datatype().as_str().len(), str::len)
+        }
+        #[cfg(feature = "rdf-12")]
+        TermRef::Triple(triple) => {
+            size_of::<Triple>()
+                + term_memory_size(triple.subject.as_ref().into())
+                + term_memory_size(triple.predicate.as_ref().into())
+                + term_memory_size(triple.object.as_ref())
+        }
+    }
+}
+
+/// Returns the source and target graphs if the operation inserts a whole graph into an other one.
+///
+/// `ADD`, `COPY` and `MOVE` operations are rewritten by the parser into such `INSERT { GRAPH <to> { ?s ?p ?o } } WHERE { GRAPH <from> { ?s ?p ?o } }` operations
+/// and are evaluated directly by the storage without decoding the quads.
+fn as_graph_addition<'a>(
+    update: &'a GraphUpdateOperation,
+    using_dataset: &Option<QueryDatasetSpecification>,
+) -> Option<(GraphNameRef<'a>, GraphNameRef<'a>)> {
+    let GraphUpdateOperation::DeleteInsert {
+        delete,
+        insert,
+        using: None,
+        pattern,
+    } = update
+    else {
+        return None;
+    };
+    if !delete.is_empty()
+        || using_dataset
+            .as_ref()
+            .is_some_and(|d| *d != QueryDatasetSpecification::new())
+    {
+        return None;
+    }
+    let [insert] = insert.as_slice() else {
+        return None;
+    };
+    let (from, inner) = match pattern.as_ref() {
+        GraphPattern::Graph {
+            name: NamedNodePattern::NamedNode(name),
+            inner,
+        } => (GraphNameRef::from(name.as_ref()), inner.as_ref()),
+        pattern => (GraphNameRef::DefaultGraph, pattern),
+    };
+    let GraphPattern::Bgp { patterns } = inner else {
+        return None;
+    };
+    let [pattern] = patterns.as_slice() else {
+        return None;
+    };
+    let (
+        TermPattern::Variable(subject),
+        NamedNodePattern::Variable(predicate),
+        TermPattern::Variable(object),
+    ) = (&pattern.subject, &pattern.predicate, &pattern.object)
+    else {
+        return None;
+    };
+    if subject == predicate || subject == object || predicate == object {
+        return None;
+    }
+    if insert.subject != pattern.subject
+        || insert.predicate != pattern.predicate
+        || insert.object != pattern.object
+    {
+        return None;
+    }
+    let to = match &insert.graph_name {
+        GraphNamePattern::NamedNode(name) => name.as_ref().into(),
+        GraphNamePattern::DefaultGraph => GraphNameRef::DefaultGraph,
+        GraphNamePattern::Variable(_) => return None,
+    };
+    Some((from, to))
+}
+
+/// The quads removed by a `DELETE WHERE` operation made of a single pattern, `None` matching any term
+struct PatternDeletion {
+    subject: Option<EncodedTerm>,
+    predicate: Option<EncodedTerm>,
+    object: Option<EncodedTerm>,
+    /// `None` for the `GRAPH ?g` patterns, matching all the named graphs but not the default graph
+    graph_name: Option<EncodedTerm>,
+}
+
+/// Recognizes the `DELETE WHERE` operations made of a single triple pattern without filters,
+/// i.e. `DELETE { GRAPH ?g { ?s ex:p ?o } } WHERE { GRAPH ?g { ?s ex:p ?o } }` or the equivalent `DELETE WHERE`.
+///
+/// The variables must be distinct so that any quad matching the pattern is removed.
+fn as_pattern_deletion(
+    update: &GraphUpdateOperation,
+    using_dataset: &Option<QueryDatasetSpecification>,
+) -> Option<PatternDeletion> {
+    let GraphUpdateOperation::DeleteInsert {
+        delete,
+        insert,
+        using: None,
+        pattern,
+    } = update
+    else {
+        return None;
+    };
+    if !insert.is_empty()
+        || using_dataset
+            .as_ref()
+            .is_some_and(|d| *d != QueryDatasetSpecification::new())
+    {
+        return None;
+    }
+    let [template] = delete.as_slice() else {
+        return None;
+    };
+    let mut variables = Vec::new();
+    let (graph_name, inner) = match (pattern.as_ref(), &template.graph_name) {
+        (
+            GraphPattern::Graph {
+                name: NamedNodePattern::NamedNode(name),
+                inner,
+            },
+            GraphNamePattern::NamedNode(template_name),
+        ) if name == template_name => (Some(EncodedTerm::from(name.as_ref())), inner.as_ref()),
+        (
+            GraphPattern::Graph {
+                name: NamedNodePattern::Variable(name),
+                inner,
+            },
+            GraphNamePattern::Variable(template_name),
+        ) if name == template_name => {
+            variables.push(name);
+            (None, inner.as_ref())
+        }
+        (GraphPattern::Graph { .. }, _) => return None,
+        (pattern, GraphNamePattern::DefaultGraph) => {
+            (Some(EncodedTerm::from(GraphNameRef::DefaultGraph)), pattern)
+        }
+        _ => return None,
+    };
+    let GraphPattern::Bgp { patterns } = inner else {
+        return None;
+    };
+    let [pattern] = patterns.as_slice() else {
+        return None;
+    };
+    if pattern.predicate != template.predicate {
+        return None;
+    }
+    let predicate = match &pattern.predicate {
+        NamedNodePattern::NamedNode(predicate) => Some(EncodedTerm::from(predicate.as_ref())),
+        NamedNodePattern::Variable(predicate) => {
+            if variables.contains(&predicate) {
+                return None;
+            }
+            variables.push(predicate);
+            None
+        }
+    };
+    let subject = deleted_term(&pattern.subject, &template.subject, &mut variables)?;
+    let object = deleted_term(&pattern.object, &template.object, &mut variables)?;
+    Some(PatternDeletion {
+        subject,
+        predicate,
+        object,
+        graph_name,
+    })
+}
+
+/// The term to remove, `Some(None)` for a variable
+///
+/// Returns `None` if the template term is not the pattern one or if the variable is already used.
+fn deleted_term<'a>(
+    pattern: &'a TermPattern,
+    template: &GroundTermPattern,
+    variables: &mut Vec<&'a Variable>,
+) -> Option<Option<EncodedTerm>> {
+    match (pattern, template) {
+        (TermPattern::NamedNode(pattern), GroundTermPattern::NamedNode(template))
+            if pattern == template =>
+        {
+            Some(Some(pattern.as_ref().into()))
+        }
+        (TermPattern::Literal(pattern), GroundTermPattern::Literal(template))
+            if pattern == template =>
+        {
+            Some(Some(pattern.as_ref().into()))
+        }
+        (TermPattern::Variable(pattern), GroundTermPattern::Variable(template))
+            if pattern == template && !variables.contains(&pattern) =>
+        {
+            variables.push(pattern);
+            Some(None)
+        }
+        _ => None,
+    }
+}
+
+/// Recognizes the `DROP SILENT to ; ADD from TO to ; DROP from` sequence the `MOVE from TO to` operation is rewritten to
+///
+/// Returns the source graph, the target graph and if the `MOVE` is silent.
+fn as_graph_move<'a>(
+    updates: &'a [GraphUpdateOperation],
+    using_datasets: &[Option<QueryDatasetSpecification>],
+) -> Option<(GraphNameRef<'a>, GraphNameRef<'a>, bool)> {
+    let [
+        GraphUpdateOperation::Drop {
+            graph: drop_to,
+            silent: true,
+        },
+        addition,
+        GraphUpdateOperation::Drop {
+            graph: drop_from,
+            silent,
+        },
+        ..,
+    ] = updates
+    else {
+        return None;
+    };
+    let (from, to) = as_graph_addition(addition, using_datasets.get(1)?)?;
+    (is_graph_target(drop_to, to) && is_graph_target(drop_from, from))
+        .then_some((from, to, *silent))
+}
+
+fn is_graph_target(target: &GraphTarget, graph_name: GraphNameRef<'_>) -> bool {
+    match (target, graph_name) {
+        (GraphTarget::NamedNode(target), GraphNameRef::NamedNode(graph_name)) => {
+            target.as_ref() == graph_name
+        }
+        (GraphTarget::DefaultGraph, GraphNameRef::DefaultGraph) => true,
+        _ => false,
+    }
+}
+
 #[cfg(feature = "http-client")]
 fn eval_load(
     from: &NamedNode,
     to: &GraphName,
     client: &Client,
-    mut insert: impl FnMut(OxQuad),
+    mut insert: impl FnMut(OxQuad) -> Result<(), StorageError>,
 ) -> Result<(), UpdateEvaluationError> {
-    let (content_type, body) = client
-        .get(
-            from.as_str(),
-            "application/n-triples, text/turtle, application/rdf+xml",
-        )
-        .map_err(|e| UpdateEvaluationError::Service(Box::new(e)))?;
-    let format = RdfFormat::from_media_type(&content_type)
-        .ok_or_else(|| UpdateEvaluationError::UnsupportedContentType(content_type))?;
     let to_graph_name = match to {
         GraphName::NamedNode(graph_name) => graph_name.into(),
         GraphName::DefaultGraph => GraphNameRef::DefaultGraph,
     };
-    let client = client.clone();
-    let parser = RdfParser::from_format(format)
-        .rename_blank_nodes()
-        .without_named_graphs()
-        .with_default_graph(to_graph_name)
-        .with_base_iri(from.as_str())
-        .map_err(|e| UpdateEvaluationError::Unexpected(format!("Invalid URL: {from}: {e}").into()))?
-        .for_reader(body)
-        .with_document_loader(move |url| {
-            let (content_type, mut body) = client.get(
-                url,
-                "application/n-triples, text/turtle, application/rdf+xml, application/ld+json",
-            )?;
-            let mut content = Vec::new();
-            body.read_to_end(&mut content)?;
-            Ok(LoadedDocument {
-                url: url.into(),
-                content,
-                format: RdfFormat::from_media_type(&content_type)
-                    .ok_or_else(|| UpdateEvaluationError::UnsupportedContentType(content_type))?,
-            })
-        });
-    for q in parser {
-        insert(q?);
+    for q in load_rdf(client, from.as_str(), Some(to_graph_name))? {
+        insert(q?)?;
     }
     Ok(())
 }
@@ -682,7 +1324,7 @@ fn eval_load(
 fn eval_load(
     _from: &NamedNode,
     _to: &GraphName,
-    _insert: impl FnMut(OxQuad),
+    _insert: impl FnMut(OxQuad) -> Result<(), StorageError>,
 ) -> Result<(), UpdateEvaluationError> {
     Err(UpdateEvaluationError::Unexpected(
         "HTTP client is not available. Enable the feature 'http-client'".into(),
@@ -762,3 +1404,51 @@ fn convert_ground_triple(triple: &GroundTriple) -> Triple {
         },
     }
 }
+
+#[cfg(test)]
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+mod tests {
+    use super::*;
+    use crate::model::{Literal, NamedNode as OxNamedNode};
+
+    fn quad(i: usize) -> OxQuad {
+        let s = OxNamedNode::new_unchecked(format!("http://example.com/{i}"));
+        OxQuad::new(
+            s.clone(),
+            s,
+            Literal::from(i64::try_from(i).unwrap()),
+            OxGraphName::DefaultGraph,
+        )
+    }
+
+    #[test]
+    #[expect(clippy::panic_in_result_fn)]
+    fn test_delete_insert_buffer_spill() -> Result<(), StorageError> {
+        let mut buffer = DeleteInsertBuffer::new(1024);
+        for i in 0..100 {
+            buffer.push(DeleteInsertQuad::Insert(quad(i + 100)))?;
+            buffer.push(DeleteInsertQuad::Delete(quad(i)))?;
+        }
+        let spill_files = [&buffer.delete, &buffer.insert]
+            .map(|b| b.spilled.as_ref().map(|spilled| spilled.path.0.clone()));
+        for spill_file in &spill_files {
+            assert!(spill_file.as_ref().is_some_and(|path| path.exists()));
+        }
+        let mut changes = Vec::new();
+        buffer.apply(|change| {
+            changes.push(change);
+            Ok(())
+        })?;
+        assert_eq!(
+            changes,
+            (0..100)
+                .map(|i| DeleteInsertQuad::Delete(quad(i)))
+                .chain((0..100).map(|i| DeleteInsertQuad::Insert(quad(i + 100))))
+                .collect::<Vec<_>>()
+        );
+        for spill_file in spill_files.into_iter().flatten() {
+            assert!(!spill_file.exists());
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use crate::storage::error::{CorruptionError, StorageError};
 #[cfg(feature = "rdf-12")]
 use crate::storage::numeric_encoder::EncodedTriple;
 use crate::storage::numeric_encoder::{EncodedQuad, EncodedTerm, StrHash};
+use crate::storage::range::ValueInterval;
 use crate::storage::small_string::SmallString;
 use oxsdatatypes::*;
 use std::io::Read;
@@ -622,6 +623,103 @@ pub fn encode_term_quad(
     vec
 }
 
+/// Returns the intervals of encoded terms that contain the literals of the value interval, each as an included start and an excluded end
+///
+/// The numeric values are written in big endian: the two's complement encoding puts the negative values after the positive ones
+/// and the IEEE 754 encoding puts the negative values after the positive ones in reverse order.
+pub fn encode_value_interval(interval: &ValueInterval) -> Vec<(Vec<u8>, Vec<u8>)> {
+    match interval {
+        ValueInterval::String => vec![(
+            vec![TYPE_SMALL_STRING_LITERAL],
+            vec![TYPE_BIG_STRING_LITERAL + 1],
+        )],
+        ValueInterval::Float(start, end) => {
+            encode_ieee_754_interval(TYPE_FLOAT_LITERAL, (*start).into(), (*end).into(), |v| {
+                #[expect(clippy::cast_possible_truncation)]
+                Float::from(v as f32).to_be_bytes().to_vec()
+            })
+        }
+        ValueInterval::Double(start, end) => {
+            encode_ieee_754_interval(TYPE_DOUBLE_LITERAL, (*start).into(), (*end).into(), |v| {
+                Double::from(v).to_be_bytes().to_vec()
+            })
+        }
+        ValueInterval::Integer(start, end) => encode_twos_complement_interval(
+            TYPE_INTEGER_LITERAL,
+            &start.to_be_bytes(),
+            &end.to_be_bytes(),
+        ),
+        ValueInterval::Decimal(start, end) => encode_twos_complement_interval(
+            TYPE_DECIMAL_LITERAL,
+            &start.to_be_bytes(),
+            &end.to_be_bytes(),
+        ),
+        // The timestamp is written before the timezone offset
+        ValueInterval::DateTime(start, end) => encode_twos_complement_interval(
+            TYPE_DATE_TIME_LITERAL,
+            &start.to_be_bytes(),
+            &end.to_be_bytes(),
+        ),
+    }
+}
+
+fn encode_twos_complement_interval(
+    type_byte: u8,
+    start: &[u8],
+    end: &[u8],
+) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let is_negative = |value: &[u8]| value.first().is_some_and(|b| b & 0x80 != 0);
+    if is_negative(start) == is_negative(end) {
+        vec![encode_interval(type_byte, start, end)]
+    } else {
+        vec![
+            encode_interval(type_byte, start, &vec![u8::MAX; end.len()]),
+            encode_interval(type_byte, &vec![0; start.len()], end),
+        ]
+    }
+}
+
+fn encode_ieee_754_interval(
+    type_byte: u8,
+    start: f64,
+    end: f64,
+    to_be_bytes: impl Fn(f64) -> Vec<u8>,
+) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut intervals = Vec::new();
+    if start <= 0. {
+        // The negative values, including -0, are in reverse order
+        intervals.push(encode_interval(
+            type_byte,
+            &to_be_bytes(if end < 0. { end } else { -0. }),
+            &to_be_bytes(if start < 0. { start } else { -0. }),
+        ));
+    }
+    if end >= 0. {
+        intervals.push(encode_interval(
+            type_byte,
+            &to_be_bytes(if start > 0. { start } else { 0. }),
+            &to_be_bytes(if end > 0. { end } else { 0. }),
+        ));
+    }
+    intervals
+}
+
+/// The keys starting with the type byte followed by a value between `start` and `end`
+fn encode_interval(type_byte: u8, start: &[u8], end: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut start_key = vec![type_byte];
+    start_key.extend_from_slice(start);
+    let mut end_key = vec![type_byte];
+    end_key.extend_from_slice(end);
+    // We build the smallest key after all the keys starting with the end value
+    while end_key.last() == Some(&u8::MAX) {
+        end_key.pop();
+    }
+    if let Some(last) = end_key.last_mut() {
+        *last += 1;
+    }
+    (start_key, end_key)
+}
+
 pub fn write_term(sink: &mut Vec<u8>, term: &EncodedTerm) {
     match term {
         EncodedTerm::DefaultGraph => (),
@@ -978,4 +1076,124 @@ mod tests {
             assert_eq!(encoded, buffer.as_slice().read_term().unwrap());
         }
     }
+
+    #[test]
+    fn test_encode_value_interval() {
+        use crate::model::vocab::xsd;
+        use crate::model::*;
+        use crate::storage::range::EncodedObjectRange;
+        use std::ops::Bound;
+
+        let values = [
+            Literal::from(i64::MIN),
+            Literal::from(-1_000_000_i64),
+            Literal::from(-2),
+            Literal::from(-1),
+            Literal::from(0),
+            Literal::from(1),
+            Literal::from(2),
+            Literal::from(3),
+            Literal::from(1_000_000_i64),
+            Literal::from(i64::MAX),
+            Literal::new_typed_literal("-1.5", xsd::DECIMAL),
+            Literal::new_typed_literal("-0.1", xsd::DECIMAL),
+            Literal::new_typed_literal("0.0", xsd::DECIMAL),
+            Literal::new_typed_literal("0.1", xsd::DECIMAL),
+            Literal::new_typed_literal("2.5", xsd::DECIMAL),
+            Literal::from(f64::NEG_INFINITY),
+            Literal::from(-2.5),
+            Literal::from(-0.1),
+            Literal::from(-0.),
+            Literal::from(0.),
+            Literal::from(0.1),
+            Literal::from(2.),
+            Literal::from(2.5),
+            Literal::from(f64::INFINITY),
+            Literal::from(f64::NAN),
+            Literal::from(-2.5_f32),
+            Literal::from(-0.1_f32),
+            Literal::from(0_f32),
+            Literal::from(0.1_f32),
+            Literal::from(1e-40_f32),
+            Literal::from(2.5_f32),
+            Literal::from(3e38_f32),
+            Literal::new_typed_literal("2020-01-01T00:00:00Z", xsd::DATE_TIME),
+            Literal::new_typed_literal("2020-01-01T10:00:00", xsd::DATE_TIME),
+            Literal::new_typed_literal("2020-01-01T23:00:00-10:00", xsd::DATE_TIME),
+            Literal::new_typed_literal("2020-01-03T00:00:00+14:00", xsd::DATE_TIME),
+            Literal::new_typed_literal("1960-01-01T00:00:00Z", xsd::DATE_TIME),
+            Literal::from("a"),
+            Literal::from("a-very-long-string-that-is-not-inlined"),
+        ];
+        let bounds = [
+            Bound::Unbounded,
+            Bound::Included(Literal::from(-1)),
+            Bound::Excluded(Literal::from(0)),
+            Bound::Included(Literal::from(2)),
+            Bound::Included(Literal::new_typed_literal("-0.1", xsd::DECIMAL)),
+            Bound::Excluded(Literal::new_typed_literal("0.1", xsd::DECIMAL)),
+            Bound::Included(Literal::from(0.1)),
+            Bound::Excluded(Literal::from(-0.)),
+            Bound::Included(Literal::from(2.5_f32)),
+            Bound::Included(Literal::from(1e-41_f32)),
+            Bound::Included(Literal::from(f64::INFINITY)),
+            Bound::Included(Literal::new_typed_literal(
+                "2020-01-01T10:00:00Z",
+                xsd::DATE_TIME,
+            )),
+            Bound::Excluded(Literal::new_typed_literal(
+                "2020-01-02T00:00:00",
+                xsd::DATE_TIME,
+            )),
+            Bound::Included(Literal::from("a")),
+        ];
+        let store = MemoryStrStore::default();
+        for value in &values {
+            let encoded = value.as_ref().into();
+            store.insert_term(value.as_ref().into(), &encoded);
+        }
+        for start in &bounds {
+            for end in &bounds {
+                let Some(range) = EncodedObjectRange::from_literal_bounds(
+                    start.as_ref().map(Literal::as_ref),
+                    end.as_ref().map(Literal::as_ref),
+                ) else {
+                    continue;
+                };
+                let intervals = range
+                    .value_intervals()
+                    .iter()
+                    .flat_map(encode_value_interval)
+                    .collect::<Vec<_>>();
+                for value in &values {
+                    let encoded = value.as_ref().into();
+                    if range.contains(&encoded, &store).unwrap() {
+                        let key = encode_term(&encoded);
+                        assert!(
+                            intervals
+                                .iter()
+                                .any(|(start, end)| *start <= key && key < *end),
+                            "{value} is in {start:?}..{end:?} but not in the scanned intervals"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_exact_integer_interval() {
+        let intervals =
+            encode_value_interval(&ValueInterval::Integer(Integer::from(-1), Integer::from(1)));
+        for (value, expected) in [(-2, false), (-1, true), (0, true), (1, true), (2, false)] {
+            let key = encode_term(&EncodedTerm::IntegerLiteral(Integer::from(value)));
+            assert_eq!(
+                intervals
+                    .iter()
+                    .any(|(start, end)| *start <= key && key < *end),
+                expected,
+                "{value}"
+            );
+        }
+    }
 }
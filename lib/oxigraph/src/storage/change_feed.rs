@@ -0,0 +1,127 @@
+use oxrdf::{Quad, QuadRef};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Above this number of changed quads a transaction only reports that the store changed
+const MAX_RECORDED_QUADS: usize = 10_000;
+
+/// The changes done by a committed transaction
+pub struct CommittedChanges {
+    quads: Option<Vec<Quad>>,
+}
+
+impl CommittedChanges {
+    /// The inserted and removed quads
+    ///
+    /// `None` if they are not all known, e.g. because a graph has been cleared or the transaction was too big.
+    pub fn quads(&self) -> Option<&[Quad]> {
+        self.quads.as_deref()
+    }
+}
+
+type CommitListener = Arc<dyn Fn(&CommittedChanges) + Send + Sync>;
+
+/// Calls listeners after each committed transaction
+#[derive(Default)]
+pub struct ChangeFeed {
+    listeners: RwLock<Vec<(u64, CommitListener)>>,
+    next_id: AtomicU64,
+}
+
+impl ChangeFeed {
+    /// Adds a listener and returns its id
+    ///
+    /// Returns `None` if there are already `max_listeners` listeners.
+    pub fn add_listener(
+        &self,
+        listener: impl Fn(&CommittedChanges) + Send + Sync + 'static,
+        max_listeners: usize,
+    ) -> Option<u64> {
+        let mut listeners = self.listeners.write().ok()?;
+        if listeners.len() >= max_listeners {
+            return None;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        listeners.push((id, Arc::new(listener)));
+        Some(id)
+    }
+
+    pub fn remove_listener(&self, id: u64) {
+        if let Ok(mut listeners) = self.listeners.write() {
+            listeners.retain(|(listener_id, _)| *listener_id != id);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.listeners.read().map_or(0, |listeners| listeners.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn notify(&self, changes: &CommittedChanges) {
+        // We release the lock before calling the listeners so that they are able to remove themselves
+        let listeners = match self.listeners.read() {
+            Ok(listeners) if !listeners.is_empty() => listeners
+                .iter()
+                .map(|(_, listener)| Arc::clone(listener))
+                .collect::<Vec<_>>(),
+            _ => return,
+        };
+        for listener in listeners {
+            listener(changes);
+        }
+    }
+}
+
+/// The quads changed by a transaction, to give to the [`ChangeFeed`] on commit
+pub struct ChangeLog<'a> {
+    feed: &'a ChangeFeed,
+    /// `None` if the changes are not known
+    quads: Option<Vec<Quad>>,
+    /// The number of quad insertions and removals, including the ones that have no effect
+    #[cfg(feature = "tracing")]
+    quad_changes: usize,
+}
+
+impl<'a> ChangeLog<'a> {
+    /// The changes are only recorded if there are already listeners
+    ///
+    /// The listeners added during the transaction still get notified but without the changed quads.
+    pub fn new(feed: &'a ChangeFeed) -> Self {
+        Self {
+            feed,
+            quads: (!feed.is_empty()).then(Vec::new),
+            #[cfg(feature = "tracing")]
+            quad_changes: 0,
+        }
+    }
+
+    pub fn record(&mut self, quad: QuadRef<'_>) {
+        #[cfg(feature = "tracing")]
+        {
+            self.quad_changes += 1;
+        }
+        if let Some(quads) = &mut self.quads {
+            if quads.len() < MAX_RECORDED_QUADS {
+                quads.push(quad.into_owned());
+            } else {
+                self.quads = None;
+            }
+        }
+    }
+
+    pub fn record_unknown(&mut self) {
+        self.quads = None;
+    }
+
+    #[cfg(feature = "tracing")]
+    pub fn quad_changes(&self) -> usize {
+        self.quad_changes
+    }
+
+    pub fn notify(self) {
+        self.feed.notify(&CommittedChanges { quads: self.quads });
+    }
+}
@@ -1,7 +1,9 @@
 use crate::io::{RdfFormat, RdfParseError};
+use crate::model::{GraphName, ListError};
 use oxiri::IriParseError;
 use std::error::Error;
 use std::io;
+use std::path::PathBuf;
 
 /// An error related to storage operations (reads, writes...).
 #[derive(Debug, thiserror::Error)]
@@ -89,6 +91,18 @@ pub enum LoaderError {
         #[source]
         error: IriParseError,
     },
+    /// Checkpointed loading is only supported with line-based formats.
+    #[error("Checkpointed loading only supports N-Triples and N-Quads, {0} found")]
+    UnsupportedCheckpointFormat(RdfFormat),
+    /// An error raised while reading or writing the checkpoint file.
+    #[error("Error with the checkpoint file {}: {error}", path.display())]
+    Checkpoint {
+        /// The checkpoint file path.
+        path: PathBuf,
+        /// The I/O error.
+        #[source]
+        error: io::Error,
+    },
 }
 
 impl From<LoaderError> for io::Error {
@@ -97,9 +111,139 @@ impl From<LoaderError> for io::Error {
         match error {
             LoaderError::Storage(error) => error.into(),
             LoaderError::Parsing(error) => error.into(),
-            LoaderError::InvalidBaseIri { .. } => {
+            LoaderError::InvalidBaseIri { .. } | LoaderError::UnsupportedCheckpointFormat(_) => {
                 Self::new(io::ErrorKind::InvalidInput, error.to_string())
             }
+            LoaderError::Checkpoint { error: e, .. } => e,
+        }
+    }
+}
+
+/// An error raised while loading the document at an IRI into a [`Store`](crate::store::Store) with [`Store::load_from_iri`](crate::store::Store::load_from_iri).
+#[cfg(feature = "http-client")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum IriLoadError {
+    /// The HTTP request failed (connection error, timeout...).
+    #[error("Error while fetching {iri}: {error}")]
+    Network {
+        /// The requested URL.
+        iri: String,
+        /// The I/O error.
+        #[source]
+        error: io::Error,
+    },
+    /// The server returned a status that is neither a success nor a redirection.
+    #[error("Error {status} returned by {iri}")]
+    HttpStatus {
+        /// The requested URL.
+        iri: String,
+        /// The HTTP status code.
+        status: u16,
+    },
+    /// More redirections than allowed have been followed.
+    #[error("Too many redirections while fetching {iri}, the limit is {limit}")]
+    TooManyRedirections {
+        /// The last requested URL.
+        iri: String,
+        /// The redirection limit.
+        limit: usize,
+    },
+    /// The format of the returned document is neither given by its `Content-Type` nor by its URL extension.
+    #[error("Not able to find a supported RDF format for {iri} with content type {}", content_type.as_deref().unwrap_or("(none)"))]
+    UnsupportedFormat {
+        /// The URL of the document.
+        iri: String,
+        /// The `Content-Type` returned by the server.
+        content_type: Option<String>,
+    },
+    /// An error raised while parsing the document.
+    #[error(transparent)]
+    Parsing(#[from] RdfParseError),
+    /// An error raised during the insertion in the store.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+#[cfg(feature = "http-client")]
+impl From<IriLoadError> for io::Error {
+    #[inline]
+    fn from(error: IriLoadError) -> Self {
+        match error {
+            IriLoadError::Network { error: e, .. } => e,
+            IriLoadError::Storage(error) => error.into(),
+            IriLoadError::Parsing(error) => error.into(),
+            IriLoadError::HttpStatus { .. } | IriLoadError::TooManyRedirections { .. } => {
+                Self::other(error)
+            }
+            IriLoadError::UnsupportedFormat { .. } => Self::new(io::ErrorKind::InvalidData, error),
+        }
+    }
+}
+
+/// An error raised while renaming a graph of a [`Store`](crate::store::Store).
+#[derive(Debug, thiserror::Error)]
+pub enum RenameGraphError {
+    /// An error raised during the renaming in the store.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    /// The target graph already exists and overwriting it has not been allowed.
+    #[error("The graph {0} already exists")]
+    TargetGraphAlreadyExists(GraphName),
+}
+
+impl From<RenameGraphError> for io::Error {
+    #[inline]
+    fn from(error: RenameGraphError) -> Self {
+        match error {
+            RenameGraphError::Storage(error) => error.into(),
+            RenameGraphError::TargetGraphAlreadyExists(_) => {
+                Self::new(io::ErrorKind::AlreadyExists, error.to_string())
+            }
+        }
+    }
+}
+
+/// An error raised while registering a namespace in a [`Store`](crate::store::Store).
+#[derive(Debug, thiserror::Error)]
+pub enum SetNamespaceError {
+    /// An error raised during the write in the store.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    /// The prefix is not a valid [Turtle `PN_PREFIX`](https://www.w3.org/TR/turtle/#grammar-production-PN_PREFIX).
+    #[error("{0:?} is not a valid prefix name")]
+    InvalidPrefix(String),
+}
+
+impl From<SetNamespaceError> for io::Error {
+    #[inline]
+    fn from(error: SetNamespaceError) -> Self {
+        match error {
+            SetNamespaceError::Storage(error) => error.into(),
+            SetNamespaceError::InvalidPrefix(_) => {
+                Self::new(io::ErrorKind::InvalidInput, error.to_string())
+            }
+        }
+    }
+}
+
+/// An error raised while reading an [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) from a [`Store`](crate::store::Store).
+#[derive(Debug, thiserror::Error)]
+pub enum ReadListError {
+    /// An error raised during the lookup in the store.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    /// The list is malformed.
+    #[error(transparent)]
+    List(#[from] ListError),
+}
+
+impl From<ReadListError> for io::Error {
+    #[inline]
+    fn from(error: ReadListError) -> Self {
+        match error {
+            ReadListError::Storage(error) => error.into(),
+            ReadListError::List(error) => Self::new(io::ErrorKind::InvalidData, error),
         }
     }
 }
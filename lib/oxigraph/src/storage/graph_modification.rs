@@ -0,0 +1,120 @@
+use crate::model::GraphNameRef;
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+use crate::storage::error::{CorruptionError, StorageError};
+use crate::storage::numeric_encoder::EncodedTerm;
+use oxsdatatypes::DateTime;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The last modification of a graph.
+///
+/// See [`Store::graph_modification`](crate::store::Store::graph_modification).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphModification {
+    /// A counter increased by each committed transaction that modifies the store.
+    ///
+    /// It changes each time the graph content changes, making it usable as a strong HTTP `ETag`.
+    pub counter: u64,
+    /// The time at which the transaction modifying the graph has been committed.
+    pub timestamp: DateTime,
+}
+
+impl GraphModification {
+    pub(crate) fn new(counter: u64) -> Self {
+        Self {
+            counter,
+            timestamp: DateTime::now(),
+        }
+    }
+
+    /// The modification with the greatest counter
+    pub(crate) fn latest(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(if a.counter >= b.counter { a } else { b }),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub(crate) fn encode(&self) -> [u8; 26] {
+        let mut buffer = [0; 26];
+        buffer[..8].copy_from_slice(&self.counter.to_be_bytes());
+        buffer[8..].copy_from_slice(&self.timestamp.to_be_bytes());
+        buffer
+    }
+
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub(crate) fn decode(buffer: &[u8]) -> Result<Self, StorageError> {
+        let (Ok(counter), Ok(timestamp)) = (
+            buffer.get(..8).unwrap_or_default().try_into(),
+            buffer.get(8..).unwrap_or_default().try_into(),
+        ) else {
+            return Err(CorruptionError::msg("Invalid graph modification encoding").into());
+        };
+        Ok(Self {
+            counter: u64::from_be_bytes(counter),
+            timestamp: DateTime::from_be_bytes(timestamp),
+        })
+    }
+}
+
+/// The graphs written by a transaction
+#[derive(Default)]
+pub struct ModifiedGraphs {
+    pub graphs: FxHashSet<EncodedTerm>,
+    /// If all the named graphs, including the ones not in `graphs`, are modified
+    pub all_named_graphs: bool,
+}
+
+impl ModifiedGraphs {
+    pub fn insert(&mut self, graph_name: GraphNameRef<'_>) {
+        if graph_name.is_default_graph() || !self.all_named_graphs {
+            self.graphs.insert(graph_name.into());
+        }
+    }
+
+    pub fn insert_encoded(&mut self, graph_name: &EncodedTerm) {
+        if graph_name.is_default_graph() || !self.all_named_graphs {
+            self.graphs.insert(graph_name.clone());
+        }
+    }
+
+    pub fn insert_all_named_graphs(&mut self) {
+        self.all_named_graphs = true;
+        self.graphs.retain(EncodedTerm::is_default_graph);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.graphs.is_empty() && !self.all_named_graphs
+    }
+}
+
+/// The last modifications of the graphs of an in-memory storage
+#[derive(Default)]
+pub struct GraphModifications {
+    graphs: FxHashMap<EncodedTerm, GraphModification>,
+    all_named_graphs: Option<GraphModification>,
+}
+
+impl GraphModifications {
+    pub fn get(&self, graph_name: &EncodedTerm) -> Option<GraphModification> {
+        let modification = self.graphs.get(graph_name).copied();
+        if graph_name.is_default_graph() {
+            modification
+        } else {
+            GraphModification::latest(modification, self.all_named_graphs)
+        }
+    }
+
+    pub fn record(&mut self, graphs: &ModifiedGraphs, modification: GraphModification) {
+        if graphs.all_named_graphs {
+            self.all_named_graphs = Some(modification);
+            // The older specific modifications are now useless
+            self.graphs
+                .retain(|graph_name, _| graph_name.is_default_graph());
+        }
+        for graph_name in &graphs.graphs {
+            self.graphs.insert(graph_name.clone(), modification);
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! Recording of the changes done by transactions into a history graph.
+//!
+//! Each change is stored as an RDF reification in the history graph:
+//! ```turtle
+//! _:e a oxh:Addition ; # or oxh:Removal
+//!     rdf:subject ex:s ; rdf:predicate ex:p ; rdf:object ex:o ;
+//!     oxh:graph ex:g ; # absent for the default graph
+//!     prov:generatedAtTime "2024-01-01T00:00:00Z"^^xsd:dateTime ;
+//!     oxh:transaction 12 .
+//! ```
+//! Bulk loads are only recorded as a single `oxh:BulkLoad` event without the list of loaded quads.
+
+use crate::model::vocab::{prov, rdf};
+use crate::model::{
+    BlankNode, Graph, GraphName, GraphNameRef, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode,
+    Quad, QuadRef, Term, TermRef, Triple,
+};
+use crate::storage::numeric_encoder::{Decoder, EncodedTerm};
+use crate::storage::{CorruptionError, StorageError, StorageReader};
+use oxsdatatypes::DateTime;
+use rustc_hash::FxHashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const ADDITION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/history#Addition");
+const REMOVAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/history#Removal");
+const BULK_LOAD: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/history#BulkLoad");
+const GRAPH: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://oxigraph.org/history#graph");
+const TRANSACTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/history#transaction");
+
+/// Configuration of the change history recorded by a [`Store`](crate::store::Store).
+///
+/// See [`Store::enable_history`](crate::store::Store::enable_history).
+#[derive(Debug, Clone)]
+pub struct HistoryConfig {
+    graph: NamedNode,
+}
+
+impl HistoryConfig {
+    /// Records the history into the named graph `graph`.
+    ///
+    /// Changes done to this graph are not recorded.
+    pub fn new(graph: impl Into<NamedNode>) -> Self {
+        Self {
+            graph: graph.into(),
+        }
+    }
+
+    /// The graph in which the history is recorded.
+    pub fn graph(&self) -> NamedNodeRef<'_> {
+        self.graph.as_ref()
+    }
+}
+
+/// The kind of a [`HistoryEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistoryEventKind {
+    /// The quad has been inserted.
+    Addition,
+    /// The quad has been removed.
+    Removal,
+}
+
+/// A change of a quad recorded in the store history.
+///
+/// See [`Store::history_for_quad`](crate::store::Store::history_for_quad).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEvent {
+    /// If the quad has been added or removed.
+    pub kind: HistoryEventKind,
+    /// The time at which the transaction has been committed.
+    pub timestamp: DateTime,
+    /// The identifier of the transaction, increasing with each recorded transaction.
+    pub transaction: u64,
+}
+
+/// Shared history state of a storage
+pub struct HistoryRecorder {
+    config: HistoryConfig,
+    next_transaction: AtomicU64,
+}
+
+impl HistoryRecorder {
+    /// Builds the recorder, continuing the transaction numbering already present in the history graph
+    pub fn new(config: HistoryConfig, reader: &StorageReader<'_>) -> Result<Self, StorageError> {
+        let mut next_transaction = 0;
+        for quad in reader.quads_for_pattern(
+            None,
+            Some(&TRANSACTION.into()),
+            None,
+            Some(&config.graph().into()),
+        ) {
+            let transaction = parse_transaction(reader.decode_term(&quad?.object)?.as_ref())?;
+            next_transaction = next_transaction.max(transaction + 1);
+        }
+        Ok(Self {
+            config,
+            next_transaction: AtomicU64::new(next_transaction),
+        })
+    }
+
+    pub fn graph(&self) -> NamedNodeRef<'_> {
+        self.config.graph()
+    }
+
+    /// The quads recording a bulk load
+    pub fn bulk_load_quads(&self) -> Vec<Quad> {
+        let event = BlankNode::default();
+        let graph = self.config.graph.clone();
+        vec![
+            Quad::new(event.clone(), rdf::TYPE, BULK_LOAD, graph.clone()),
+            Quad::new(
+                event.clone(),
+                prov::GENERATED_AT_TIME,
+                Literal::from(DateTime::now()),
+                graph.clone(),
+            ),
+            Quad::new(
+                event.clone(),
+                TRANSACTION,
+                Literal::from(self.next_transaction.fetch_add(1, Ordering::Relaxed)),
+                graph,
+            ),
+        ]
+    }
+}
+
+/// The graphs affected by a clear operation
+#[derive(Clone, Copy)]
+pub enum ClearedGraphs<'a> {
+    Graph(GraphNameRef<'a>),
+    NamedGraphs,
+    AllGraphs,
+}
+
+impl ClearedGraphs<'_> {
+    fn contains(self, graph_name: GraphNameRef<'_>) -> bool {
+        match self {
+            Self::Graph(g) => g == graph_name,
+            Self::NamedGraphs => !graph_name.is_default_graph(),
+            Self::AllGraphs => true,
+        }
+    }
+}
+
+/// The changes done by a transaction, written into the history graph on commit
+pub struct HistoryLog {
+    recorder: Arc<HistoryRecorder>,
+    /// The last change of each quad
+    changes: FxHashMap<Quad, HistoryEventKind>,
+    /// An error raised by an operation not able to return it, returned on commit
+    error: Option<StorageError>,
+}
+
+impl HistoryLog {
+    pub fn new(recorder: Arc<HistoryRecorder>) -> Self {
+        Self {
+            recorder,
+            changes: FxHashMap::default(),
+            error: None,
+        }
+    }
+
+    /// Keeps the error to return it on commit
+    pub fn defer_error(&mut self, result: Result<(), StorageError>) {
+        if let Err(error) = result {
+            self.error.get_or_insert(error);
+        }
+    }
+
+    pub fn record(&mut self, quad: QuadRef<'_>, kind: HistoryEventKind) {
+        if quad.graph_name != GraphNameRef::NamedNode(self.recorder.graph()) {
+            self.changes.insert(quad.into_owned(), kind);
+        }
+    }
+
+    /// Records the insertion or the removal of a quad only if it changes the store content
+    ///
+    /// `reader` tells if the quad is in the store when it has not been changed yet by the transaction.
+    pub fn record_if_changed(
+        &mut self,
+        quad: QuadRef<'_>,
+        kind: HistoryEventKind,
+        reader: &StorageReader<'_>,
+    ) -> Result<(), StorageError> {
+        if quad.graph_name == GraphNameRef::NamedNode(self.recorder.graph()) {
+            return Ok(());
+        }
+        let quad = quad.into_owned();
+        let is_present = match self.changes.get(&quad) {
+            Some(HistoryEventKind::Addition) => true,
+            Some(HistoryEventKind::Removal) => false,
+            None => reader.contains(&quad.as_ref().into())?,
+        };
+        if is_present != (kind == HistoryEventKind::Addition) {
+            self.changes.insert(quad, kind);
+        }
+        Ok(())
+    }
+
+    /// Records the removal of all the quads in the cleared graphs
+    ///
+    /// `reader` might not see the quads added earlier in the transaction, so they are also marked as removed.
+    pub fn record_clear(
+        &mut self,
+        reader: &StorageReader<'_>,
+        graphs: ClearedGraphs<'_>,
+    ) -> Result<(), StorageError> {
+        let graph_name = match graphs {
+            ClearedGraphs::Graph(graph_name) => Some(EncodedTerm::from(graph_name)),
+            ClearedGraphs::NamedGraphs | ClearedGraphs::AllGraphs => None,
+        };
+        for quad in reader.quads_for_pattern(None, None, None, graph_name.as_ref()) {
+            let quad = reader.decode_quad(&quad?)?;
+            if graphs.contains(quad.graph_name.as_ref()) {
+                self.record(quad.as_ref(), HistoryEventKind::Removal);
+            }
+        }
+        for (quad, kind) in &mut self.changes {
+            if graphs.contains(quad.graph_name.as_ref()) {
+                *kind = HistoryEventKind::Removal;
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the addition into `to` of all the quads of `from`
+    pub fn record_add_graph(
+        &mut self,
+        reader: &StorageReader<'_>,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+    ) -> Result<(), StorageError> {
+        for quad in reader.quads_for_pattern(None, None, None, Some(&from.into())) {
+            let quad = reader.decode_quad(&quad?)?;
+            self.record(
+                QuadRef::new(&quad.subject, &quad.predicate, &quad.object, to),
+                HistoryEventKind::Addition,
+            );
+        }
+        Ok(())
+    }
+
+    /// The quads to write in the history graph, all in the same recorded transaction
+    pub fn into_quads(self) -> Result<Vec<Quad>, StorageError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.changes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let graph = self.recorder.config.graph.clone();
+        let timestamp = Literal::from(DateTime::now());
+        let transaction = Literal::from(
+            self.recorder
+                .next_transaction
+                .fetch_add(1, Ordering::Relaxed),
+        );
+        let mut quads = Vec::with_capacity(self.changes.len() * 7);
+        for (quad, kind) in self.changes {
+            let event = BlankNode::default();
+            let kind = match kind {
+                HistoryEventKind::Addition => ADDITION,
+                HistoryEventKind::Removal => REMOVAL,
+            };
+            quads.push(Quad::new(event.clone(), rdf::TYPE, kind, graph.clone()));
+            quads.push(Quad::new(
+                event.clone(),
+                rdf::SUBJECT,
+                quad.subject,
+                graph.clone(),
+            ));
+            quads.push(Quad::new(
+                event.clone(),
+                rdf::PREDICATE,
+                quad.predicate,
+                graph.clone(),
+            ));
+            quads.push(Quad::new(
+                event.clone(),
+                rdf::OBJECT,
+                quad.object,
+                graph.clone(),
+            ));
+            match quad.graph_name {
+                GraphName::NamedNode(g) => {
+                    quads.push(Quad::new(event.clone(), GRAPH, g, graph.clone()));
+                }
+                GraphName::BlankNode(g) => {
+                    quads.push(Quad::new(event.clone(), GRAPH, g, graph.clone()));
+                }
+                GraphName::DefaultGraph => (),
+            }
+            quads.push(Quad::new(
+                event.clone(),
+                prov::GENERATED_AT_TIME,
+                timestamp.clone(),
+                graph.clone(),
+            ));
+            quads.push(Quad::new(
+                event,
+                TRANSACTION,
+                transaction.clone(),
+                graph.clone(),
+            ));
+        }
+        Ok(quads)
+    }
+}
+
+/// Returns all the recorded changes of the given quad, sorted by transaction
+pub fn history_for_quad(
+    reader: &StorageReader<'_>,
+    history_graph: NamedNodeRef<'_>,
+    quad: QuadRef<'_>,
+) -> Result<Vec<HistoryEvent>, StorageError> {
+    let mut events = Vec::new();
+    for candidate in reader.quads_for_pattern(
+        None,
+        Some(&rdf::SUBJECT.into()),
+        Some(&TermRef::from(quad.subject).into()),
+        Some(&history_graph.into()),
+    ) {
+        let event = candidate?.subject;
+        if let Some((change, event)) = read_change(reader, history_graph, &event)? {
+            if change.as_ref() == quad {
+                events.push(event);
+            }
+        }
+    }
+    events.sort_by_key(|event| event.transaction);
+    Ok(events)
+}
+
+/// Rebuilds the content of the graph at the given time by replaying the recorded changes
+pub fn graph_at(
+    reader: &StorageReader<'_>,
+    history_graph: NamedNodeRef<'_>,
+    graph_name: GraphNameRef<'_>,
+    timestamp: &DateTime,
+) -> Result<Graph, StorageError> {
+    let candidates = match graph_name {
+        GraphNameRef::NamedNode(g) => vec![(GRAPH, TermRef::from(g))],
+        GraphNameRef::BlankNode(g) => vec![(GRAPH, TermRef::from(g))],
+        GraphNameRef::DefaultGraph => {
+            vec![(rdf::TYPE, ADDITION.into()), (rdf::TYPE, REMOVAL.into())]
+        }
+    };
+    let mut changes = Vec::new();
+    for (predicate, object) in candidates {
+        for candidate in reader.quads_for_pattern(
+            None,
+            Some(&predicate.into()),
+            Some(&object.into()),
+            Some(&history_graph.into()),
+        ) {
+            let event = candidate?.subject;
+            if let Some((change, event)) = read_change(reader, history_graph, &event)? {
+                if change.graph_name.as_ref() == graph_name && event.timestamp <= *timestamp {
+                    changes.push((event, Triple::from(change)));
+                }
+            }
+        }
+    }
+    changes.sort_by_key(|(event, _)| event.transaction);
+    let mut graph = Graph::new();
+    for (event, triple) in changes {
+        match event.kind {
+            HistoryEventKind::Addition => graph.insert(&triple),
+            HistoryEventKind::Removal => graph.remove(&triple),
+        };
+    }
+    Ok(graph)
+}
+
+/// Reads a change event, returns `None` if it is not an addition or a removal
+fn read_change(
+    reader: &StorageReader<'_>,
+    history_graph: NamedNodeRef<'_>,
+    event: &EncodedTerm,
+) -> Result<Option<(Quad, HistoryEvent)>, StorageError> {
+    let mut kind = None;
+    let mut subject = None;
+    let mut predicate = None;
+    let mut object = None;
+    let mut graph_name = GraphName::DefaultGraph;
+    let mut timestamp = None;
+    let mut transaction = None;
+    for quad in reader.quads_for_pattern(Some(event), None, None, Some(&history_graph.into())) {
+        let quad = reader.decode_quad(&quad?)?;
+        match quad.predicate.as_ref() {
+            rdf::TYPE if quad.object == ADDITION.into() => {
+                kind = Some(HistoryEventKind::Addition);
+            }
+            rdf::TYPE if quad.object == REMOVAL.into() => {
+                kind = Some(HistoryEventKind::Removal);
+            }
+            rdf::TYPE => return Ok(None),
+            rdf::SUBJECT => {
+                subject = Some(NamedOrBlankNode::try_from(quad.object).map_err(|_| {
+                    CorruptionError::msg(
+                        "The subject of a history event must be an IRI or a blank node",
+                    )
+                })?);
+            }
+            rdf::PREDICATE => {
+                predicate = Some(NamedNode::try_from(quad.object).map_err(|_| {
+                    CorruptionError::msg("The predicate of a history event must be an IRI")
+                })?);
+            }
+            rdf::OBJECT => object = Some(quad.object),
+            GRAPH => {
+                graph_name = NamedOrBlankNode::try_from(quad.object)
+                    .map_err(|_| {
+                        CorruptionError::msg(
+                            "The graph of a history event must be an IRI or a blank node",
+                        )
+                    })?
+                    .into();
+            }
+            prov::GENERATED_AT_TIME => {
+                let Term::Literal(literal) = &quad.object else {
+                    return Err(CorruptionError::msg(
+                        "The timestamp of a history event must be a literal",
+                    )
+                    .into());
+                };
+                timestamp = Some(DateTime::from_str(literal.value()).map_err(|e| {
+                    CorruptionError::msg(format!("Invalid history event timestamp: {e}"))
+                })?);
+            }
+            TRANSACTION => transaction = Some(parse_transaction(quad.object.as_ref())?),
+            _ => (),
+        }
+    }
+    let (
+        Some(kind),
+        Some(subject),
+        Some(predicate),
+        Some(object),
+        Some(timestamp),
+        Some(transaction),
+    ) = (kind, subject, predicate, object, timestamp, transaction)
+    else {
+        return Err(CorruptionError::msg("A history event is incomplete").into());
+    };
+    Ok(Some((
+        Quad::new(subject, predicate, object, graph_name),
+        HistoryEvent {
+            kind,
+            timestamp,
+            transaction,
+        },
+    )))
+}
+
+fn parse_transaction(term: TermRef<'_>) -> Result<u64, StorageError> {
+    if let TermRef::Literal(literal) = term {
+        if let Ok(transaction) = literal.value().parse() {
+            return Ok(transaction);
+        }
+    }
+    Err(CorruptionError::msg(format!("Invalid history transaction identifier {term}")).into())
+}
@@ -1,6 +1,7 @@
 use crate::model::{GraphNameRef, NamedOrBlankNodeRef, QuadRef, TermRef};
 use crate::storage::CorruptionError;
 pub use crate::storage::error::StorageError;
+use crate::storage::graph_modification::{GraphModification, GraphModifications, ModifiedGraphs};
 use crate::storage::numeric_encoder::{
     Decoder, EncodedQuad, EncodedTerm, StrHash, StrHashHasher, StrLookup, insert_term,
 };
@@ -10,6 +11,7 @@ use dashmap::{DashMap, DashSet};
 use oxrdf::Quad;
 use rustc_hash::FxHasher;
 use std::borrow::Borrow;
+use std::collections::BTreeMap;
 use std::hash::{BuildHasherDefault, Hash, Hasher};
 use std::marker::PhantomData;
 use std::mem::{take, transmute};
@@ -26,6 +28,8 @@ pub struct MemoryStorage {
     id2str: Arc<DashMap<StrHash, String, BuildHasherDefault<StrHashHasher>>>,
     version_counter: Arc<AtomicUsize>,
     transaction_counter: Arc<Mutex<usize>>,
+    namespaces: Arc<RwLock<BTreeMap<String, String>>>,
+    graph_modifications: Arc<RwLock<GraphModifications>>,
 }
 
 struct Content {
@@ -57,9 +61,40 @@ impl MemoryStorage {
             id2str: Arc::new(DashMap::default()),
             version_counter: Arc::new(AtomicUsize::new(0)),
             transaction_counter: Arc::new(Mutex::new(usize::MAX >> 1)),
+            namespaces: Arc::default(),
+            graph_modifications: Arc::default(),
         }
     }
 
+    pub fn namespaces(&self) -> Vec<(String, String)> {
+        self.namespaces
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+            .collect()
+    }
+
+    pub fn set_namespace(&self, prefix: &str, namespace: &str) {
+        self.namespaces
+            .write()
+            .unwrap()
+            .insert(prefix.into(), namespace.into());
+    }
+
+    pub fn remove_namespace(&self, prefix: &str) {
+        self.namespaces.write().unwrap().remove(prefix);
+    }
+
+    pub fn graph_modification(&self, graph_name: &EncodedTerm) -> Option<GraphModification> {
+        self.graph_modifications.read().unwrap().get(graph_name)
+    }
+
+    /// Id of the last committed transaction, it changes each time the content is modified
+    pub fn version(&self) -> usize {
+        self.version_counter.load(Ordering::Acquire)
+    }
+
     pub fn snapshot(&self) -> MemoryStorageReader<'static> {
         MemoryStorageReader {
             storage: self.clone(),
@@ -433,28 +468,37 @@ impl MemoryStorageTransaction<'_> {
 
     pub fn insert(&mut self, quad: QuadRef<'_>) {
         let encoded: EncodedQuad = quad.into();
+        match quad.graph_name {
+            GraphNameRef::NamedNode(graph_name) => {
+                self.insert_encoded_named_graph(graph_name.into(), encoded.graph_name.clone());
+            }
+            GraphNameRef::BlankNode(graph_name) => {
+                self.insert_encoded_named_graph(graph_name.into(), encoded.graph_name.clone());
+            }
+            GraphNameRef::DefaultGraph => (),
+        }
+        if self.insert_encoded(&encoded) {
+            self.insert_term(quad.subject.into(), &encoded.subject);
+            self.insert_term(quad.predicate.into(), &encoded.predicate);
+            self.insert_term(quad.object, &encoded.object);
+        }
+    }
+
+    /// Inserts an encoded quad whose graph name is already inserted
+    ///
+    /// Returns `true` if the quad has never been in the storage before and its terms might have to be inserted.
+    fn insert_encoded(&mut self, encoded: &EncodedQuad) -> bool {
         if let Some(node) = self
             .storage
             .content
             .quad_set
-            .get(&encoded)
+            .get(encoded)
             .map(|node| Arc::clone(&node))
         {
-            let added = node.range.lock().unwrap().add(self.transaction_id);
-            if added {
+            if node.range.lock().unwrap().add(self.transaction_id) {
                 self.log.push(LogEntry::QuadNode(node));
-                if !quad.graph_name.is_default_graph()
-                    && self
-                        .storage
-                        .content
-                        .graphs
-                        .get_mut(&encoded.graph_name)
-                        .unwrap()
-                        .add(self.transaction_id)
-                {
-                    self.log.push(LogEntry::Graph(encoded.graph_name.clone()));
-                }
             }
+            false
         } else {
             let node = Arc::new(QuadListNode {
                 quad: encoded.clone(),
@@ -519,21 +563,37 @@ impl MemoryStorageTransaction<'_> {
                     *count += 1;
                 })
                 .or_insert_with(|| (Arc::downgrade(&node), 1));
+            self.log.push(LogEntry::QuadNode(node));
+            true
+        }
+    }
 
-            self.insert_term(quad.subject.into(), &encoded.subject);
-            self.insert_term(quad.predicate.into(), &encoded.predicate);
-            self.insert_term(quad.object, &encoded.object);
-
-            match quad.graph_name {
-                GraphNameRef::NamedNode(graph_name) => {
-                    self.insert_encoded_named_graph(graph_name.into(), encoded.graph_name.clone());
-                }
-                GraphNameRef::BlankNode(graph_name) => {
-                    self.insert_encoded_named_graph(graph_name.into(), encoded.graph_name.clone());
-                }
-                GraphNameRef::DefaultGraph => (),
+    /// Inserts all the quads of the graph `from` into the graph `to`
+    ///
+    /// The terms are already in the storage so only the graph name has to be inserted.
+    pub fn add_graph(&mut self, from: GraphNameRef<'_>, to: GraphNameRef<'_>) {
+        let encoded_to: EncodedTerm = to.into();
+        let quads = self
+            .reader()
+            .quads_for_pattern(None, None, None, Some(&from.into()))
+            .collect::<Vec<_>>();
+        if quads.is_empty() {
+            return;
+        }
+        match to {
+            GraphNameRef::NamedNode(graph_name) => {
+                self.insert_encoded_named_graph(graph_name.into(), encoded_to.clone());
             }
-            self.log.push(LogEntry::QuadNode(node));
+            GraphNameRef::BlankNode(graph_name) => {
+                self.insert_encoded_named_graph(graph_name.into(), encoded_to.clone());
+            }
+            GraphNameRef::DefaultGraph => (),
+        }
+        for quad in quads {
+            self.insert_encoded(&EncodedQuad {
+                graph_name: encoded_to.clone(),
+                ..quad
+            });
         }
     }
 
@@ -581,7 +641,7 @@ impl MemoryStorageTransaction<'_> {
         self.remove_encoded(&quad.into())
     }
 
-    fn remove_encoded(&mut self, quad: &EncodedQuad) {
+    pub fn remove_encoded(&mut self, quad: &EncodedQuad) {
         let Some(node) = self
             .storage
             .content
@@ -669,7 +729,7 @@ impl MemoryStorageTransaction<'_> {
         self.do_remove_graphs();
     }
 
-    pub fn commit(mut self) {
+    pub fn commit(mut self, modified_graphs: &ModifiedGraphs) {
         let new_version_id = self.snapshot_id + 1;
         for operation in take(&mut self.log) {
             match operation {
@@ -691,6 +751,13 @@ impl MemoryStorageTransaction<'_> {
         self.storage
             .version_counter
             .store(new_version_id, Ordering::Release);
+        if !modified_graphs.is_empty() {
+            // Recorded after the new content is visible so that readers never see a modification before its content
+            self.storage.graph_modifications.write().unwrap().record(
+                modified_graphs,
+                GraphModification::new(new_version_id.try_into().unwrap_or(u64::MAX)),
+            );
+        }
         self.committed = true;
     }
 }
@@ -824,8 +891,8 @@ impl MemoryStorageBulkLoader<'_> {
         }
     }
 
-    pub fn commit(self) {
-        self.transaction.commit();
+    pub fn commit(self, modified_graphs: &ModifiedGraphs) {
+        self.transaction.commit(modified_graphs);
     }
 }
 
@@ -1107,7 +1174,7 @@ mod tests {
         let snapshot = storage.snapshot();
         let mut transaction = storage.start_transaction();
         transaction.insert_named_graph(example.into());
-        transaction.commit();
+        transaction.commit(&ModifiedGraphs::default());
         assert!(!snapshot.contains_named_graph(&encoded_example));
         assert!(storage.snapshot().contains_named_graph(&encoded_example));
         storage.snapshot().validate()?;
@@ -1117,7 +1184,7 @@ mod tests {
         let mut transaction = storage.start_transaction();
         transaction.insert(default_quad);
         transaction.insert(named_graph_quad);
-        transaction.commit();
+        transaction.commit(&ModifiedGraphs::default());
         assert!(!snapshot.contains(&encoded_default_quad));
         assert!(!snapshot.contains(&encoded_named_graph_quad));
         assert!(storage.snapshot().contains(&encoded_default_quad));
@@ -1129,7 +1196,7 @@ mod tests {
         let mut transaction = storage.start_transaction();
         transaction.remove(default_quad);
         transaction.remove_named_graph(example.into());
-        transaction.commit();
+        transaction.commit(&ModifiedGraphs::default());
         assert!(snapshot.contains(&encoded_default_quad));
         assert!(snapshot.contains(&encoded_named_graph_quad));
         assert!(snapshot.contains_named_graph(&encoded_example));
@@ -1162,10 +1229,10 @@ mod tests {
         ]);
         let mut transaction = storage.start_transaction();
         transaction.insert_named_graph(example2.into());
-        transaction.commit();
+        transaction.commit(&ModifiedGraphs::default());
         let mut transaction = storage.start_transaction();
         transaction.clear();
-        transaction.commit();
+        transaction.commit(&ModifiedGraphs::default());
         assert!(!storage.snapshot().contains(&encoded_default_quad));
         assert!(!storage.snapshot().contains(&encoded_named_graph_quad));
         assert!(!storage.snapshot().contains_named_graph(&encoded_example));
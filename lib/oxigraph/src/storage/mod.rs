@@ -1,39 +1,97 @@
 use crate::model::{GraphNameRef, NamedOrBlankNodeRef, QuadRef};
-pub use crate::storage::error::{CorruptionError, LoaderError, SerializerError, StorageError};
+use crate::storage::change_feed::ChangeLog;
+pub use crate::storage::change_feed::{ChangeFeed, CommittedChanges};
+#[cfg(feature = "http-client")]
+pub use crate::storage::error::IriLoadError;
+pub use crate::storage::error::{
+    CorruptionError, LoaderError, ReadListError, RenameGraphError, SerializerError,
+    SetNamespaceError, StorageError,
+};
+pub use crate::storage::graph_modification::GraphModification;
+use crate::storage::graph_modification::ModifiedGraphs;
+#[cfg(feature = "history")]
+use crate::storage::history::{ClearedGraphs, HistoryLog, HistoryRecorder};
+#[cfg(feature = "history")]
+pub use crate::storage::history::{HistoryConfig, HistoryEvent, HistoryEventKind};
 use crate::storage::memory::{
     MemoryDecodingGraphIterator, MemoryStorage, MemoryStorageBulkLoader, MemoryStorageReader,
     MemoryStorageTransaction, QuadIterator,
 };
+#[cfg(feature = "history")]
+use crate::storage::numeric_encoder::Decoder;
 use crate::storage::numeric_encoder::{EncodedQuad, EncodedTerm, StrHash, StrLookup};
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+pub use crate::storage::options::{StorageCompression, StoreOptions};
+pub use crate::storage::provenance::{LoadProvenance, ProvenanceTracker};
+use crate::storage::range::EncodedObjectRange;
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 use crate::storage::rocksdb::{
-    RocksDbChainedDecodingQuadIterator, RocksDbDecodingGraphIterator, RocksDbStorage,
-    RocksDbStorageBulkLoader, RocksDbStorageReadableTransaction, RocksDbStorageReader,
-    RocksDbStorageTransaction,
+    RocksDbChainedDecodingQuadIterator, RocksDbDecodingGraphIterator,
+    RocksDbRangesDecodingQuadIterator, RocksDbStorage, RocksDbStorageBulkLoader,
+    RocksDbStorageReadableTransaction, RocksDbStorageReader, RocksDbStorageTransaction,
 };
 use oxrdf::Quad;
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 use std::path::Path;
+use std::sync::Arc;
+#[cfg(feature = "history")]
+use std::sync::RwLock;
 #[cfg(not(target_family = "wasm"))]
 use std::{io, thread};
 
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 mod binary_encoder;
+mod change_feed;
 mod error;
+mod graph_modification;
+#[cfg(feature = "history")]
+pub mod history;
 mod memory;
 pub mod numeric_encoder;
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+mod options;
+mod provenance;
+pub mod range;
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 mod rocksdb;
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 mod rocksdb_wrapper;
 pub mod small_string;
+mod statistics;
+
+pub use crate::storage::statistics::{PredicateStatistics, QueryStatistics};
 
 pub const DEFAULT_BULK_LOAD_BATCH_SIZE: usize = 1_000_000;
+/// The number of quads read before being removed by [`StorageReadableTransaction::remove_matching`]
+const REMOVE_BATCH_SIZE: usize = 100_000;
+
+/// How much the [bulk loader](crate::store::BulkLoader) waits for the loaded data to be on disk.
+///
+/// It is set with [`BulkLoader::with_durability`](crate::store::BulkLoader::with_durability).
+/// Only the writes done by the bulk loader are affected, the other store writes keep their usual durability.
+/// It has no effect on in-memory stores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Durability {
+    /// The write-ahead log is synced to disk on each commit done by the loader.
+    ///
+    /// When a commit returns, the loaded data is guaranteed to survive a crash of the system.
+    #[default]
+    Full,
+    /// The write-ahead log is not synced by the loader.
+    ///
+    /// The loaded quads are still saved in files synced to disk when they are inserted into the store,
+    /// but a system crash shortly after a commit might lose the last changes to the store metadata (graph modification history...).
+    /// A crash of the process only is not an issue.
+    Relaxed,
+}
 
 /// Low level storage primitives
 #[derive(Clone)]
 pub struct Storage {
     kind: StorageKind,
+    change_feed: Arc<ChangeFeed>,
+    #[cfg(feature = "history")]
+    history: Arc<RwLock<Option<Arc<HistoryRecorder>>>>,
 }
 
 #[derive(Clone)]
@@ -48,13 +106,19 @@ impl Storage {
     pub fn new() -> Result<Self, StorageError> {
         Ok(Self {
             kind: StorageKind::Memory(MemoryStorage::new()),
+            change_feed: Arc::default(),
+            #[cfg(feature = "history")]
+            history: Arc::default(),
         })
     }
 
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-    pub fn open(path: &Path) -> Result<Self, StorageError> {
+    pub fn open(path: &Path, options: StoreOptions) -> Result<Self, StorageError> {
         Ok(Self {
-            kind: StorageKind::RocksDb(RocksDbStorage::open(path)?),
+            kind: StorageKind::RocksDb(RocksDbStorage::open(path, options)?),
+            change_feed: Arc::default(),
+            #[cfg(feature = "history")]
+            history: Arc::default(),
         })
     }
 
@@ -62,6 +126,9 @@ impl Storage {
     pub fn open_read_only(path: &Path) -> Result<Self, StorageError> {
         Ok(Self {
             kind: StorageKind::RocksDb(RocksDbStorage::open_read_only(path)?),
+            change_feed: Arc::default(),
+            #[cfg(feature = "history")]
+            history: Arc::default(),
         })
     }
 
@@ -90,6 +157,15 @@ impl Storage {
                     StorageTransactionKind::Memory(storage.start_transaction())
                 }
             },
+            modified_graphs: ModifiedGraphs::default(),
+            changes: ChangeLog::new(&self.change_feed),
+            #[cfg(feature = "history")]
+            history: self
+                .history_recorder()
+                .map(|recorder| (HistoryLog::new(recorder), self.snapshot())),
+
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!("transaction"),
         })
     }
 
@@ -110,27 +186,110 @@ impl Storage {
                     StorageReadableTransactionKind::Memory(storage.start_transaction())
                 }
             },
+            modified_graphs: ModifiedGraphs::default(),
+            changes: ChangeLog::new(&self.change_feed),
+            #[cfg(feature = "history")]
+            history: self.history_recorder().map(HistoryLog::new),
+
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!("transaction"),
         })
     }
 
+    /// Starts to record the changes done by the transactions into the history graph
+    #[cfg(feature = "history")]
+    pub fn enable_history(&self, config: HistoryConfig) -> Result<(), StorageError> {
+        let recorder = HistoryRecorder::new(config, &self.snapshot())?;
+        if let Ok(mut history) = self.history.write() {
+            *history = Some(Arc::new(recorder));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "history")]
+    pub fn disable_history(&self) {
+        if let Ok(mut history) = self.history.write() {
+            *history = None;
+        }
+    }
+
+    #[cfg(feature = "history")]
+    pub fn history_recorder(&self) -> Option<Arc<HistoryRecorder>> {
+        self.history.read().ok()?.clone()
+    }
+
+    /// The listeners called after each commit
+    pub fn change_feed(&self) -> &Arc<ChangeFeed> {
+        &self.change_feed
+    }
+
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     pub fn flush(&self) -> Result<(), StorageError> {
+        trace_span!("flush");
         match &self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageKind::RocksDb(storage) => storage.flush(),
+            StorageKind::RocksDb(storage) => storage.flush().inspect_err(|e| trace_error!(e)),
             StorageKind::Memory(_) => Ok(()),
         }
     }
 
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     pub fn compact(&self) -> Result<(), StorageError> {
+        trace_span!("compact");
         match &self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageKind::RocksDb(storage) => storage.compact(),
+            StorageKind::RocksDb(storage) => storage.compact().inspect_err(|e| trace_error!(e)),
             StorageKind::Memory(_) => Ok(()),
         }
     }
 
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn full_compact(&self) -> Result<(), StorageError> {
+        trace_span!("full_compact");
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => {
+                storage.full_compact().inspect_err(|e| trace_error!(e))
+            }
+            StorageKind::Memory(_) => Ok(()),
+        }
+    }
+
+    /// Drops from the disk the entries deleted by clearing the store
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn reclaim_cleared_space(&self) -> Result<(), StorageError> {
+        trace_span!("reclaim_cleared_space");
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage
+                .reclaim_cleared_space()
+                .inspect_err(|e| trace_error!(e)),
+            StorageKind::Memory(_) => Ok(()),
+        }
+    }
+
+    /// Size on disk of the quads and graph names
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn quad_data_size(&self) -> u64 {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.quad_data_size(),
+            StorageKind::Memory(_) => 0,
+        }
+    }
+
+    /// The estimated size of the live data and the size of the storage directory
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn disk_usage(&self) -> Result<(u64, u64), StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => {
+                Ok((storage.live_data_size(), storage.directory_size()?))
+            }
+            StorageKind::Memory(_) => Ok((0, 0)),
+        }
+    }
+
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     pub fn backup(&self, target_directory: &Path) -> Result<(), StorageError> {
         match &self.kind {
@@ -142,24 +301,185 @@ impl Storage {
         }
     }
 
+    /// The registered namespaces, sorted by prefix
+    ///
+    /// Namespaces are not versioned: they are not part of snapshots and transactions.
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn namespaces(&self) -> Result<Vec<(String, String)>, StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.namespaces(),
+            StorageKind::Memory(storage) => Ok(storage.namespaces()),
+        }
+    }
+
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn set_namespace(&self, prefix: &str, namespace: &str) -> Result<(), StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.set_namespace(prefix, namespace),
+            StorageKind::Memory(storage) => {
+                storage.set_namespace(prefix, namespace);
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn remove_namespace(&self, prefix: &str) -> Result<(), StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.remove_namespace(prefix),
+            StorageKind::Memory(storage) => {
+                storage.remove_namespace(prefix);
+                Ok(())
+            }
+        }
+    }
+
+    /// The statistics saved by [`set_query_statistics`](Self::set_query_statistics)
+    ///
+    /// They are only persisted by on-disk storages.
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn query_statistics(&self) -> Result<Option<QueryStatistics>, StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.query_statistics(),
+            StorageKind::Memory(_) => Ok(None),
+        }
+    }
+
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps, unused_variables)
+    )]
+    pub fn set_query_statistics(&self, statistics: &QueryStatistics) -> Result<(), StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.set_query_statistics(statistics),
+            StorageKind::Memory(_) => Ok(()),
+        }
+    }
+
+    /// If the union of all graphs should be the default graph of queries
+    ///
+    /// It is only persisted by on-disk storages.
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn default_graph_as_union(&self) -> Result<bool, StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.default_graph_as_union(),
+            StorageKind::Memory(_) => Ok(false),
+        }
+    }
+
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps, unused_variables)
+    )]
+    pub fn set_default_graph_as_union(&self, value: bool) -> Result<(), StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.set_default_graph_as_union(value),
+            StorageKind::Memory(_) => Ok(()),
+        }
+    }
+
+    /// The last modification of a graph, `None` if it has not been modified since the storage creation
+    ///
+    /// Named graphs are considered modified by the operations on all named graphs like [`StorageTransaction::clear`].
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn graph_modification(
+        &self,
+        graph_name: &EncodedTerm,
+    ) -> Result<Option<GraphModification>, StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.graph_modification(graph_name),
+            StorageKind::Memory(storage) => Ok(storage.graph_modification(graph_name)),
+        }
+    }
+
+    /// A number that changes each time the storage content is modified
+    pub fn version(&self) -> u64 {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.version(),
+            StorageKind::Memory(storage) => storage.version().try_into().unwrap_or(u64::MAX),
+        }
+    }
+
+    /// The size on disk of each RocksDB column family, empty for in-memory storages
+    pub fn column_family_sizes(&self) -> Vec<(&'static str, u64)> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => storage.column_family_sizes(),
+            StorageKind::Memory(_) => Vec::new(),
+        }
+    }
+
+    /// The estimated number of quads in each graph, computed without a full scan
+    ///
+    /// Returns `None` if the storage does not support estimations.
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn approximate_graph_sizes(
+        &self,
+    ) -> Result<Option<Vec<(EncodedTerm, usize)>>, StorageError> {
+        match &self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageKind::RocksDb(storage) => Ok(Some(storage.approximate_graph_sizes()?)),
+            StorageKind::Memory(_) => Ok(None),
+        }
+    }
+
     pub fn bulk_loader(&self) -> StorageBulkLoader<'_> {
+        // The loaded quads are not recorded, they might be numerous
+        let mut changes = ChangeLog::new(&self.change_feed);
+        changes.record_unknown();
         match &self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageKind::RocksDb(storage) => StorageBulkLoader {
                 kind: StorageBulkLoaderKind::RocksDb(storage.bulk_loader()),
+                modified_graphs: ModifiedGraphs::default(),
+                changes,
             },
             StorageKind::Memory(storage) => StorageBulkLoader {
                 kind: StorageBulkLoaderKind::Memory(storage.bulk_loader()),
+                modified_graphs: ModifiedGraphs::default(),
+                changes,
             },
         }
     }
 }
 
+#[derive(Clone)]
 #[must_use]
 pub struct StorageReader<'a> {
     kind: StorageReaderKind<'a>,
 }
 
+#[derive(Clone)]
 enum StorageReaderKind<'a> {
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     RocksDb(RocksDbStorageReader<'a>),
@@ -215,6 +535,42 @@ impl<'a> StorageReader<'a> {
         }
     }
 
+    /// Returns the quads matching the pattern whose object might be in the given range.
+    ///
+    /// On disk, the object index is scanned in value order, the in-memory storage has no such index and scans all the quads matching the pattern.
+    /// The returned quads still have to be filtered using [`EncodedObjectRange::contains`].
+    #[cfg_attr(
+        not(all(not(target_family = "wasm"), feature = "rocksdb")),
+        expect(unused_variables)
+    )]
+    pub fn quads_for_pattern_with_object_range(
+        &self,
+        subject: Option<&EncodedTerm>,
+        predicate: Option<&EncodedTerm>,
+        object_range: &EncodedObjectRange,
+        graph_name: Option<&EncodedTerm>,
+    ) -> DecodingQuadIterator<'a> {
+        if subject.is_some() {
+            // The subject indexes are more selective
+            return self.quads_for_pattern(subject, predicate, None, graph_name);
+        }
+        DecodingQuadIterator {
+            kind: match &self.kind {
+                #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+                StorageReaderKind::RocksDb(reader) => DecodingQuadIteratorKind::RocksDbRanges(
+                    reader.quads_for_pattern_with_object_values(
+                        predicate,
+                        &object_range.value_intervals(),
+                        graph_name,
+                    ),
+                ),
+                StorageReaderKind::Memory(reader) => DecodingQuadIteratorKind::Memory(
+                    reader.quads_for_pattern(None, predicate, None, graph_name),
+                ),
+            },
+        }
+    }
+
     pub fn named_graphs(&self) -> DecodingGraphIterator<'a> {
         DecodingGraphIterator {
             kind: match &self.kind {
@@ -263,6 +619,8 @@ pub struct DecodingQuadIterator<'a> {
 enum DecodingQuadIteratorKind<'a> {
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     RocksDb(RocksDbChainedDecodingQuadIterator<'a>),
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    RocksDbRanges(RocksDbRangesDecodingQuadIterator<'a>),
     Memory(QuadIterator<'a>),
 }
 
@@ -273,6 +631,8 @@ impl Iterator for DecodingQuadIterator<'_> {
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             DecodingQuadIteratorKind::RocksDb(iter) => iter.next(),
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            DecodingQuadIteratorKind::RocksDbRanges(iter) => iter.next(),
             DecodingQuadIteratorKind::Memory(iter) => iter.next().map(Ok),
         }
     }
@@ -314,6 +674,16 @@ impl StrLookup for StorageReader<'_> {
 #[must_use]
 pub struct StorageTransaction<'a> {
     kind: StorageTransactionKind<'a>,
+    /// The graphs to mark as modified on commit
+    modified_graphs: ModifiedGraphs,
+    /// The quads to give to the change feed on commit
+    changes: ChangeLog<'a>,
+    /// The changes log and a snapshot of the store before the transaction
+    #[cfg(feature = "history")]
+    history: Option<(HistoryLog, StorageReader<'static>)>,
+    /// Lasts from the start of the transaction to its commit or drop
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
 enum StorageTransactionKind<'a> {
@@ -323,11 +693,21 @@ enum StorageTransactionKind<'a> {
 }
 
 #[cfg_attr(
-    not(all(not(target_family = "wasm"), feature = "rocksdb")),
+    not(any(
+        all(not(target_family = "wasm"), feature = "rocksdb"),
+        feature = "history"
+    )),
     expect(clippy::unnecessary_wraps)
 )]
 impl StorageTransaction<'_> {
     pub fn insert(&mut self, quad: QuadRef<'_>) {
+        self.modified_graphs.insert(quad.graph_name);
+        self.changes.record(quad);
+        #[cfg(feature = "history")]
+        if let Some((log, snapshot)) = &mut self.history {
+            let result = log.record_if_changed(quad, HistoryEventKind::Addition, snapshot);
+            log.defer_error(result);
+        }
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.insert(quad),
@@ -338,6 +718,7 @@ impl StorageTransaction<'_> {
     }
 
     pub fn insert_named_graph(&mut self, graph_name: NamedOrBlankNodeRef<'_>) {
+        self.modified_graphs.insert(graph_name.into());
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => {
@@ -350,6 +731,13 @@ impl StorageTransaction<'_> {
     }
 
     pub fn remove(&mut self, quad: QuadRef<'_>) {
+        self.modified_graphs.insert(quad.graph_name);
+        self.changes.record(quad);
+        #[cfg(feature = "history")]
+        if let Some((log, snapshot)) = &mut self.history {
+            let result = log.record_if_changed(quad, HistoryEventKind::Removal, snapshot);
+            log.defer_error(result);
+        }
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.remove(quad),
@@ -358,6 +746,10 @@ impl StorageTransaction<'_> {
     }
 
     pub fn clear_default_graph(&mut self) {
+        self.modified_graphs.insert(GraphNameRef::DefaultGraph);
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::Graph(GraphNameRef::DefaultGraph));
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.clear_default_graph(),
@@ -368,6 +760,10 @@ impl StorageTransaction<'_> {
     }
 
     pub fn clear_all_named_graphs(&mut self) {
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::NamedGraphs);
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.clear_all_named_graphs(),
@@ -376,6 +772,11 @@ impl StorageTransaction<'_> {
     }
 
     pub fn clear_all_graphs(&mut self) {
+        self.modified_graphs.insert(GraphNameRef::DefaultGraph);
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::AllGraphs);
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.clear_all_graphs(),
@@ -384,6 +785,10 @@ impl StorageTransaction<'_> {
     }
 
     pub fn remove_all_named_graphs(&mut self) {
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::NamedGraphs);
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.remove_all_named_graphs(),
@@ -392,6 +797,11 @@ impl StorageTransaction<'_> {
     }
 
     pub fn clear(&mut self) {
+        self.modified_graphs.insert(GraphNameRef::DefaultGraph);
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::AllGraphs);
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageTransactionKind::RocksDb(transaction) => transaction.clear(),
@@ -399,21 +809,83 @@ impl StorageTransaction<'_> {
         }
     }
 
-    pub fn commit(self) -> Result<(), StorageError> {
+    #[cfg(feature = "history")]
+    fn record_clear(&mut self, graphs: ClearedGraphs<'_>) {
+        if let Some((log, snapshot)) = &mut self.history {
+            let result = log.record_clear(snapshot, graphs);
+            log.defer_error(result);
+        }
+    }
+
+    /// Writes the changes done so far if they use more than `max_pending_size` bytes of memory
+    ///
+    /// The transaction is not atomic anymore after such a write: the written changes are visible to the readers and are kept even if the transaction is not committed.
+    /// The change feed and the history are still only updated on commit.
+    #[cfg_attr(
+        any(target_family = "wasm", not(feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps, unused_variables)
+    )]
+    pub fn write_pending_if_larger_than(
+        &mut self,
+        max_pending_size: usize,
+    ) -> Result<(), StorageError> {
+        match &mut self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageTransactionKind::RocksDb(transaction) => {
+                if transaction.pending_size() > max_pending_size {
+                    trace_span!("write_pending");
+                    transaction
+                        .write_pending(&self.modified_graphs)
+                        .inspect_err(|e| trace_error!(e))?;
+                }
+                Ok(())
+            }
+            StorageTransactionKind::Memory(_) => Ok(()), // The changes are directly written into the storage
+        }
+    }
+
+    #[cfg_attr(not(feature = "history"), expect(unused_mut))]
+    pub fn commit(mut self) -> Result<(), StorageError> {
+        #[cfg(feature = "tracing")]
+        let _span = self.span.clone().entered();
+        #[cfg(feature = "history")]
+        if let Some((log, _)) = self.history.take() {
+            for quad in log.into_quads()? {
+                self.insert(quad.as_ref());
+            }
+        }
+        trace_span!(
+            "commit",
+            quad_changes = self.changes.quad_changes(),
+            graphs = self.modified_graphs.graphs.len(),
+            all_named_graphs = self.modified_graphs.all_named_graphs
+        );
         match self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageTransactionKind::RocksDb(transaction) => transaction.commit(),
+            StorageTransactionKind::RocksDb(transaction) => transaction
+                .commit(&self.modified_graphs)
+                .inspect_err(|e| trace_error!(e))?,
             StorageTransactionKind::Memory(transaction) => {
-                transaction.commit();
-                Ok(())
+                transaction.commit(&self.modified_graphs);
             }
         }
+        self.changes.notify();
+        Ok(())
     }
 }
 
 #[must_use]
 pub struct StorageReadableTransaction<'a> {
     kind: StorageReadableTransactionKind<'a>,
+    /// The graphs to mark as modified on commit
+    modified_graphs: ModifiedGraphs,
+    /// The quads to give to the change feed on commit
+    changes: ChangeLog<'a>,
+    #[cfg(feature = "history")]
+    history: Option<HistoryLog>,
+    /// Lasts from the start of the transaction to its commit or drop
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
 enum StorageReadableTransactionKind<'a> {
@@ -422,26 +894,39 @@ enum StorageReadableTransactionKind<'a> {
     Memory(MemoryStorageTransaction<'a>),
 }
 
+impl StorageReadableTransactionKind<'_> {
+    fn reader(&self) -> StorageReader<'_> {
+        StorageReader {
+            kind: match self {
+                #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+                Self::RocksDb(transaction) => StorageReaderKind::RocksDb(transaction.reader()),
+                Self::Memory(transaction) => StorageReaderKind::Memory(transaction.reader()),
+            },
+        }
+    }
+}
+
 #[cfg_attr(
-    not(all(not(target_family = "wasm"), feature = "rocksdb")),
+    not(any(
+        all(not(target_family = "wasm"), feature = "rocksdb"),
+        feature = "history"
+    )),
     expect(clippy::unnecessary_wraps)
 )]
 impl StorageReadableTransaction<'_> {
     pub fn reader(&self) -> StorageReader<'_> {
-        StorageReader {
-            kind: match &self.kind {
-                #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-                StorageReadableTransactionKind::RocksDb(transaction) => {
-                    StorageReaderKind::RocksDb(transaction.reader())
-                }
-                StorageReadableTransactionKind::Memory(transaction) => {
-                    StorageReaderKind::Memory(transaction.reader())
-                }
-            },
-        }
+        self.kind.reader()
     }
 
     pub fn insert(&mut self, quad: QuadRef<'_>) {
+        self.modified_graphs.insert(quad.graph_name);
+        self.changes.record(quad);
+        #[cfg(feature = "history")]
+        if let Some(log) = &mut self.history {
+            let result =
+                log.record_if_changed(quad, HistoryEventKind::Addition, &self.kind.reader());
+            log.defer_error(result);
+        }
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => transaction.insert(quad),
@@ -452,6 +937,7 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn insert_named_graph(&mut self, graph_name: NamedOrBlankNodeRef<'_>) {
+        self.modified_graphs.insert(graph_name.into());
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => {
@@ -464,6 +950,14 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn remove(&mut self, quad: QuadRef<'_>) {
+        self.modified_graphs.insert(quad.graph_name);
+        self.changes.record(quad);
+        #[cfg(feature = "history")]
+        if let Some(log) = &mut self.history {
+            let result =
+                log.record_if_changed(quad, HistoryEventKind::Removal, &self.kind.reader());
+            log.defer_error(result);
+        }
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => transaction.remove(quad),
@@ -472,6 +966,11 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn clear_graph(&mut self, graph_name: GraphNameRef<'_>) -> Result<(), StorageError> {
+        trace_span!("clear_graph", graph = %graph_name);
+        self.modified_graphs.insert(graph_name);
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::Graph(graph_name))?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => {
@@ -484,7 +983,164 @@ impl StorageReadableTransaction<'_> {
         }
     }
 
+    /// Removes all the quads matching the pattern and returns the number of removed quads
+    ///
+    /// The matching quads are read and removed by batches, so they are never all in memory at the same time.
+    pub fn remove_matching(
+        &mut self,
+        subject: Option<&EncodedTerm>,
+        predicate: Option<&EncodedTerm>,
+        object: Option<&EncodedTerm>,
+        graph_name: Option<&EncodedTerm>,
+    ) -> Result<u64, StorageError> {
+        trace_span!("remove_matching");
+        self.changes.record_unknown();
+        let mut count = 0;
+        loop {
+            let reader = self.kind.reader();
+            let quads = reader
+                .quads_for_pattern(subject, predicate, object, graph_name)
+                .take(REMOVE_BATCH_SIZE)
+                .collect::<Result<Vec<_>, _>>()?;
+            #[cfg(feature = "history")]
+            if let Some(log) = &mut self.history {
+                for quad in &quads {
+                    log.record(
+                        reader.decode_quad(quad)?.as_ref(),
+                        HistoryEventKind::Removal,
+                    );
+                }
+            }
+            for quad in &quads {
+                self.modified_graphs.insert_encoded(&quad.graph_name);
+                match &mut self.kind {
+                    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+                    StorageReadableTransactionKind::RocksDb(transaction) => {
+                        transaction.remove_encoded(quad)
+                    }
+                    StorageReadableTransactionKind::Memory(transaction) => {
+                        transaction.remove_encoded(quad)
+                    }
+                }
+            }
+            count += quads.len() as u64;
+            if quads.len() < REMOVE_BATCH_SIZE {
+                return Ok(count);
+            }
+        }
+    }
+
+    /// Inserts all the quads of the graph `from` into the graph `to` without decoding them
+    pub fn add_graph(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+    ) -> Result<(), StorageError> {
+        trace_span!("add_graph", from = %from, to = %to);
+        if from == to {
+            return Ok(());
+        }
+        self.modified_graphs.insert(to);
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        if let Some(log) = &mut self.history {
+            log.record_add_graph(&self.kind.reader(), from, to)?;
+        }
+        match &mut self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageReadableTransactionKind::RocksDb(transaction) => transaction.add_graph(from, to),
+            StorageReadableTransactionKind::Memory(transaction) => {
+                transaction.add_graph(from, to);
+                Ok(())
+            }
+        }
+    }
+
+    /// Replaces the content of the graph `to` by the one of the graph `from`
+    pub fn copy_graph(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+    ) -> Result<(), StorageError> {
+        if from == to {
+            return Ok(());
+        }
+        self.drop_graph(to)?;
+        self.add_graph(from, to)
+    }
+
+    /// Moves the content of the graph `from` into the graph `to`, removing the previous content of `to` and the graph `from`
+    pub fn move_graph(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+    ) -> Result<(), StorageError> {
+        if from == to {
+            return Ok(());
+        }
+        self.copy_graph(from, to)?;
+        self.drop_graph(from)
+    }
+
+    /// Moves the content of the graph `from` into the graph `to` and removes the graph `from`
+    ///
+    /// Fails if `to` already exists and `overwrite` is not set.
+    /// Returns `false` and does nothing if `from` does not exist.
+    /// The graph name is part of every index key so all the quads are rewritten.
+    pub fn rename_graph(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+        overwrite: bool,
+    ) -> Result<bool, RenameGraphError> {
+        trace_span!("rename_graph", from = %from, to = %to);
+        if !self.contains_graph(from)? {
+            return Ok(false);
+        }
+        if from == to {
+            return Ok(true);
+        }
+        if !overwrite && self.contains_graph(to)? {
+            return Err(RenameGraphError::TargetGraphAlreadyExists(to.into_owned()));
+        }
+        self.move_graph(from, to)?;
+        // Empty named graphs are not created by the copy
+        match to {
+            GraphNameRef::NamedNode(to) => self.insert_named_graph(to.into()),
+            GraphNameRef::BlankNode(to) => self.insert_named_graph(to.into()),
+            GraphNameRef::DefaultGraph => (),
+        }
+        Ok(true)
+    }
+
+    /// If the graph is a named graph of the store or the non-empty default graph
+    fn contains_graph(&self, graph_name: GraphNameRef<'_>) -> Result<bool, StorageError> {
+        let reader = self.reader();
+        let encoded_graph_name = graph_name.into();
+        if graph_name.is_default_graph() {
+            Ok(reader
+                .quads_for_pattern(None, None, None, Some(&encoded_graph_name))
+                .next()
+                .transpose()?
+                .is_some())
+        } else {
+            reader.contains_named_graph(&encoded_graph_name)
+        }
+    }
+
+    fn drop_graph(&mut self, graph_name: GraphNameRef<'_>) -> Result<(), StorageError> {
+        match graph_name {
+            GraphNameRef::NamedNode(graph_name) => self.remove_named_graph(graph_name.into()),
+            GraphNameRef::BlankNode(graph_name) => self.remove_named_graph(graph_name.into()),
+            GraphNameRef::DefaultGraph => self.clear_graph(GraphNameRef::DefaultGraph),
+        }
+    }
+
     pub fn clear_all_named_graphs(&mut self) -> Result<(), StorageError> {
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::NamedGraphs)?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => {
@@ -498,6 +1154,11 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn clear_all_graphs(&mut self) -> Result<(), StorageError> {
+        self.modified_graphs.insert(GraphNameRef::DefaultGraph);
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::AllGraphs)?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => transaction.clear_all_graphs(),
@@ -512,6 +1173,11 @@ impl StorageReadableTransaction<'_> {
         &mut self,
         graph_name: NamedOrBlankNodeRef<'_>,
     ) -> Result<(), StorageError> {
+        trace_span!("remove_named_graph", graph = %graph_name);
+        self.modified_graphs.insert(graph_name.into());
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::Graph(graph_name.into()))?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => {
@@ -525,6 +1191,10 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn remove_all_named_graphs(&mut self) -> Result<(), StorageError> {
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::NamedGraphs)?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => {
@@ -538,6 +1208,11 @@ impl StorageReadableTransaction<'_> {
     }
 
     pub fn clear(&mut self) -> Result<(), StorageError> {
+        self.modified_graphs.insert(GraphNameRef::DefaultGraph);
+        self.modified_graphs.insert_all_named_graphs();
+        self.changes.record_unknown();
+        #[cfg(feature = "history")]
+        self.record_clear(ClearedGraphs::AllGraphs)?;
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageReadableTransactionKind::RocksDb(transaction) => transaction.clear(),
@@ -548,21 +1223,78 @@ impl StorageReadableTransaction<'_> {
         }
     }
 
-    pub fn commit(self) -> Result<(), StorageError> {
+    #[cfg(feature = "history")]
+    fn record_clear(&mut self, graphs: ClearedGraphs<'_>) -> Result<(), StorageError> {
+        if let Some(log) = &mut self.history {
+            log.record_clear(&self.kind.reader(), graphs)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the changes done so far if they use more than `max_pending_size` bytes of memory
+    ///
+    /// The transaction is not atomic anymore after such a write: the written changes are visible to the readers and are kept even if the transaction is not committed.
+    /// The change feed and the history are still only updated on commit.
+    #[cfg_attr(
+        any(target_family = "wasm", not(feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps, unused_variables)
+    )]
+    pub fn write_pending_if_larger_than(
+        &mut self,
+        max_pending_size: usize,
+    ) -> Result<(), StorageError> {
+        match &mut self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageReadableTransactionKind::RocksDb(transaction) => {
+                if transaction.pending_size() > max_pending_size {
+                    trace_span!("write_pending");
+                    transaction
+                        .write_pending(&self.modified_graphs)
+                        .inspect_err(|e| trace_error!(e))?;
+                }
+                Ok(())
+            }
+            StorageReadableTransactionKind::Memory(_) => Ok(()), // The changes are directly written into the storage
+        }
+    }
+
+    #[cfg_attr(not(feature = "history"), expect(unused_mut))]
+    pub fn commit(mut self) -> Result<(), StorageError> {
+        #[cfg(feature = "tracing")]
+        let _span = self.span.clone().entered();
+        #[cfg(feature = "history")]
+        if let Some(log) = self.history.take() {
+            for quad in log.into_quads()? {
+                self.insert(quad.as_ref());
+            }
+        }
+        trace_span!(
+            "commit",
+            quad_changes = self.changes.quad_changes(),
+            graphs = self.modified_graphs.graphs.len(),
+            all_named_graphs = self.modified_graphs.all_named_graphs
+        );
         match self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageReadableTransactionKind::RocksDb(transaction) => transaction.commit(),
+            StorageReadableTransactionKind::RocksDb(transaction) => transaction
+                .commit(&self.modified_graphs)
+                .inspect_err(|e| trace_error!(e))?,
             StorageReadableTransactionKind::Memory(transaction) => {
-                transaction.commit();
-                Ok(())
+                transaction.commit(&self.modified_graphs);
             }
         }
+        self.changes.notify();
+        Ok(())
     }
 }
 
 #[must_use]
 pub struct StorageBulkLoader<'a> {
     kind: StorageBulkLoaderKind<'a>,
+    /// The graphs to mark as modified on commit
+    modified_graphs: ModifiedGraphs,
+    /// Notifies the change feed on commit
+    changes: ChangeLog<'a>,
 }
 
 enum StorageBulkLoaderKind<'a> {
@@ -577,9 +1309,13 @@ impl StorageBulkLoader<'_> {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageBulkLoaderKind::RocksDb(loader) => Self {
                 kind: StorageBulkLoaderKind::RocksDb(loader.on_progress(callback)),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
             },
             StorageBulkLoaderKind::Memory(loader) => Self {
                 kind: StorageBulkLoaderKind::Memory(loader.on_progress(callback)),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
             },
         }
     }
@@ -589,9 +1325,49 @@ impl StorageBulkLoader<'_> {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
             StorageBulkLoaderKind::RocksDb(loader) => Self {
                 kind: StorageBulkLoaderKind::RocksDb(loader.without_atomicity()),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
+            },
+            StorageBulkLoaderKind::Memory(loader) => Self {
+                kind: StorageBulkLoaderKind::Memory(loader),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
+            },
+        }
+    }
+
+    pub fn atomic(self) -> Self {
+        match self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageBulkLoaderKind::RocksDb(loader) => Self {
+                kind: StorageBulkLoaderKind::RocksDb(loader.atomic()),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
             },
             StorageBulkLoaderKind::Memory(loader) => Self {
                 kind: StorageBulkLoaderKind::Memory(loader),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
+            },
+        }
+    }
+
+    #[cfg_attr(
+        any(target_family = "wasm", not(feature = "rocksdb")),
+        expect(unused_variables)
+    )]
+    pub fn with_durability(self, durability: Durability) -> Self {
+        match self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageBulkLoaderKind::RocksDb(loader) => Self {
+                kind: StorageBulkLoaderKind::RocksDb(loader.with_durability(durability)),
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
+            },
+            StorageBulkLoaderKind::Memory(loader) => Self {
+                kind: StorageBulkLoaderKind::Memory(loader), // Nothing is persisted anyway
+                modified_graphs: self.modified_graphs,
+                changes: self.changes,
             },
         }
     }
@@ -605,9 +1381,15 @@ impl StorageBulkLoader<'_> {
         quads: Vec<Quad>,
         max_num_threads: usize,
     ) -> Result<(), StorageError> {
+        trace_span!("load_batch", quads = quads.len());
+        for quad in &quads {
+            self.modified_graphs.insert(quad.graph_name.as_ref());
+        }
         match &mut self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageBulkLoaderKind::RocksDb(loader) => loader.load_batch(quads, max_num_threads),
+            StorageBulkLoaderKind::RocksDb(loader) => loader
+                .load_batch(quads, max_num_threads)
+                .inspect_err(|e| trace_error!(e)),
             StorageBulkLoaderKind::Memory(loader) => {
                 loader.load_batch(quads);
                 Ok(())
@@ -615,19 +1397,39 @@ impl StorageBulkLoader<'_> {
         }
     }
 
+    /// Makes sure all the loaded batches are saved into the storage if it is not atomic
+    #[cfg_attr(
+        any(target_family = "wasm", not(feature = "rocksdb")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn flush(&mut self) -> Result<(), StorageError> {
+        match &mut self.kind {
+            #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+            StorageBulkLoaderKind::RocksDb(loader) => loader.flush(),
+            StorageBulkLoaderKind::Memory(_) => Ok(()), // Nothing is persisted anyway
+        }
+    }
+
     #[cfg_attr(
         any(target_family = "wasm", not(feature = "rocksdb")),
         expect(clippy::unnecessary_wraps)
     )]
     pub fn commit(self) -> Result<(), StorageError> {
+        trace_span!(
+            "bulk_load_commit",
+            graphs = self.modified_graphs.graphs.len()
+        );
         match self.kind {
             #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-            StorageBulkLoaderKind::RocksDb(loader) => loader.commit(),
+            StorageBulkLoaderKind::RocksDb(loader) => loader
+                .commit(&self.modified_graphs)
+                .inspect_err(|e| trace_error!(e))?,
             StorageBulkLoaderKind::Memory(loader) => {
-                loader.commit();
-                Ok(())
+                loader.commit(&self.modified_graphs);
             }
         }
+        self.changes.notify();
+        Ok(())
     }
 }
 
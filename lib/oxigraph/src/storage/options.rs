@@ -0,0 +1,107 @@
+/// Tuning options for the on-disk storage, given to [`Store::open_with_options`](crate::store::Store::open_with_options).
+///
+/// The options are not persisted: they apply to the store as long as it is open.
+/// It is safe to open an existing store with different options.
+/// A new compression algorithm is only used for the data written afterward and for the data rewritten by compactions,
+/// [`Store::compact`](crate::store::Store::compact) rewrites everything.
+///
+/// The unset options keep their default values.
+///
+/// ```
+/// use oxigraph::store::{StorageCompression, StoreOptions};
+///
+/// let options = StoreOptions::new()
+///     .with_compression(StorageCompression::Zstd)
+///     .with_zstd_dictionary_size(16 * 1024)
+///     .with_block_cache_size(512 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct StoreOptions {
+    pub(crate) compression_per_level: Option<Vec<StorageCompression>>,
+    pub(crate) zstd_dictionary_size: Option<u32>,
+    pub(crate) block_cache_size: Option<usize>,
+    pub(crate) bloom_filter_bits_per_key: Option<f64>,
+    pub(crate) write_buffer_size: Option<usize>,
+    pub(crate) max_background_jobs: Option<usize>,
+}
+
+impl StoreOptions {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the same compression algorithm for all the LSM tree levels.
+    ///
+    /// By default, the two first levels are not compressed and the other ones use LZ4.
+    #[inline]
+    pub fn with_compression(self, compression: StorageCompression) -> Self {
+        self.with_compression_per_level(vec![compression])
+    }
+
+    /// Sets the compression algorithm of each LSM tree level, starting with level 0.
+    ///
+    /// The last algorithm is used for the levels after the end of the list.
+    #[inline]
+    pub fn with_compression_per_level(mut self, compression: Vec<StorageCompression>) -> Self {
+        self.compression_per_level = Some(compression);
+        self
+    }
+
+    /// Trains a dictionary of at most `size` bytes when compressing a file with Zstandard.
+    ///
+    /// It improves the compression ratio of small blocks, 16KB is a common value.
+    #[inline]
+    pub fn with_zstd_dictionary_size(mut self, size: u32) -> Self {
+        self.zstd_dictionary_size = Some(size);
+        self
+    }
+
+    /// Sets the size in bytes of the cache of uncompressed blocks shared by all column families.
+    #[inline]
+    pub fn with_block_cache_size(mut self, size: usize) -> Self {
+        self.block_cache_size = Some(size);
+        self
+    }
+
+    /// Adds a Bloom filter with the given number of bits per key to the files, allowing to skip files when looking up keys.
+    #[inline]
+    pub fn with_bloom_filter_bits_per_key(mut self, bits: f64) -> Self {
+        self.bloom_filter_bits_per_key = Some(bits);
+        self
+    }
+
+    /// Sets the size in bytes of the in-memory buffer of each column family, written to disk when full.
+    #[inline]
+    pub fn with_write_buffer_size(mut self, size: usize) -> Self {
+        self.write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the maximal number of concurrent background flushes and compactions.
+    ///
+    /// By default, it is the number of available CPUs.
+    #[inline]
+    pub fn with_max_background_jobs(mut self, jobs: usize) -> Self {
+        self.max_background_jobs = Some(jobs);
+        self
+    }
+}
+
+/// A compression algorithm for the on-disk storage.
+///
+/// See [`StoreOptions::with_compression`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageCompression {
+    /// No compression.
+    None,
+    /// [LZ4](https://lz4.org/), fast but with a lower compression ratio.
+    Lz4,
+    /// [Zstandard](https://facebook.github.io/zstd/), slower but with a higher compression ratio.
+    ///
+    /// RocksDB must be built with Zstandard support, e.g. by linking to a system RocksDB with the `rocksdb-pkg-config` feature.
+    /// Opening the store fails otherwise.
+    Zstd,
+}
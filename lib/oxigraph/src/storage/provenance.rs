@@ -0,0 +1,217 @@
+//! Recording of the provenance of load operations into a metadata graph using [PROV-O](https://www.w3.org/TR/prov-o/).
+//!
+//! Each load operation is described as:
+//! ```turtle
+//! <activity> a prov:Activity ;
+//!     prov:startedAtTime "2024-01-01T00:00:00Z"^^xsd:dateTime ;
+//!     prov:endedAtTime "2024-01-01T00:00:10Z"^^xsd:dateTime ;
+//!     prov:used <http://example.com/dump.nt> ; # if a source is set
+//!     prov:wasAssociatedWith [ a prov:Agent ; rdfs:label "alice" ; oxp:clientAddress "127.0.0.1" ] ; # if an agent is set
+//!     oxp:quadCount 12 .
+//! ex:g a prov:Entity ; prov:wasGeneratedBy <activity> . # for each loaded named graph
+//! ```
+
+use crate::model::vocab::{prov, rdf, rdfs};
+use crate::model::{
+    BlankNode, GraphNameRef, IriParseError, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode,
+    Quad, QuadRef, Term,
+};
+use oxiri::Iri;
+use oxsdatatypes::DateTime;
+use std::collections::HashSet;
+
+const QUAD_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/provenance#quadCount");
+const CLIENT_ADDRESS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/provenance#clientAddress");
+const DEFAULT_GRAPH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://oxigraph.org/provenance");
+
+/// Configuration of the [PROV-O](https://www.w3.org/TR/prov-o/) provenance recorded by a load operation.
+///
+/// After a successful load, a `prov:Activity` is written into the metadata graph with its start and end times,
+/// the source it `prov:used`, the agent it `prov:wasAssociatedWith` and the number of loaded quads (`<http://oxigraph.org/provenance#quadCount>`).
+/// Each loaded named graph is described as a `prov:Entity` that `prov:wasGeneratedBy` the activity.
+///
+/// See [`BulkLoader::with_provenance`](crate::store::BulkLoader::with_provenance) and [`Store::load_from_reader_with_provenance`](crate::store::Store::load_from_reader_with_provenance).
+#[derive(Debug, Clone)]
+pub struct LoadProvenance {
+    activity_iri_base: String,
+    graph: NamedNode,
+    source: Option<Term>,
+    agent: Option<String>,
+    client_address: Option<String>,
+}
+
+impl LoadProvenance {
+    /// Builds a configuration naming the activities with IRIs made of `activity_iri_base` followed by a random identifier.
+    ///
+    /// The provenance is written by default into the `<http://oxigraph.org/provenance>` named graph.
+    pub fn new(activity_iri_base: impl Into<String>) -> Result<Self, IriParseError> {
+        Ok(Self {
+            activity_iri_base: Iri::parse(activity_iri_base.into())?.into_inner(),
+            graph: DEFAULT_GRAPH.into_owned(),
+            source: None,
+            agent: None,
+            client_address: None,
+        })
+    }
+
+    /// Sets the named graph into which the provenance is written.
+    #[must_use]
+    pub fn with_graph(mut self, graph: impl Into<NamedNode>) -> Self {
+        self.graph = graph.into();
+        self
+    }
+
+    /// Sets the loaded source, usually its IRI or a literal with a file name.
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<Term>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the name of the agent (e.g. the user) that triggered the load.
+    #[must_use]
+    pub fn with_agent(mut self, name: impl Into<String>) -> Self {
+        self.agent = Some(name.into());
+        self
+    }
+
+    /// Sets the network address of the client that triggered the load.
+    #[must_use]
+    pub fn with_client_address(mut self, address: impl Into<String>) -> Self {
+        self.client_address = Some(address.into());
+        self
+    }
+
+    /// The graph in which the provenance is written.
+    pub fn graph(&self) -> NamedNodeRef<'_> {
+        self.graph.as_ref()
+    }
+}
+
+/// Collects the loaded graphs and the number of loaded quads during a load operation
+pub struct ProvenanceTracker {
+    config: LoadProvenance,
+    started_at: DateTime,
+    graphs: HashSet<NamedOrBlankNode>,
+    last_graph: Option<NamedOrBlankNode>,
+    quad_count: u64,
+}
+
+impl ProvenanceTracker {
+    pub fn new(config: LoadProvenance) -> Self {
+        Self {
+            config,
+            started_at: DateTime::now(),
+            graphs: HashSet::new(),
+            last_graph: None,
+            quad_count: 0,
+        }
+    }
+
+    pub fn record(&mut self, quad: QuadRef<'_>) {
+        self.quad_count += 1;
+        let graph = match quad.graph_name {
+            GraphNameRef::NamedNode(graph) => graph.into(),
+            GraphNameRef::BlankNode(graph) => graph.into(),
+            GraphNameRef::DefaultGraph => return,
+        };
+        // Quads are usually grouped by graph, we avoid allocating a new graph name for each of them
+        if self
+            .last_graph
+            .as_ref()
+            .is_none_or(|last_graph| last_graph.as_ref() != graph)
+        {
+            let graph = graph.into_owned();
+            self.graphs.insert(graph.clone());
+            self.last_graph = Some(graph);
+        }
+    }
+
+    /// The quads describing the load operation, ended now
+    pub fn quads(&self) -> Vec<Quad> {
+        let graph = &self.config.graph;
+        let activity = NamedNode::new_unchecked(format!(
+            "{}{:x}",
+            self.config.activity_iri_base,
+            rand::random::<u128>()
+        ));
+        let mut quads = vec![
+            Quad::new(activity.clone(), rdf::TYPE, prov::ACTIVITY, graph.clone()),
+            Quad::new(
+                activity.clone(),
+                prov::STARTED_AT_TIME,
+                Literal::from(self.started_at),
+                graph.clone(),
+            ),
+            Quad::new(
+                activity.clone(),
+                prov::ENDED_AT_TIME,
+                Literal::from(DateTime::now()),
+                graph.clone(),
+            ),
+            Quad::new(
+                activity.clone(),
+                QUAD_COUNT,
+                Literal::from(self.quad_count),
+                graph.clone(),
+            ),
+        ];
+        if let Some(source) = &self.config.source {
+            quads.push(Quad::new(
+                activity.clone(),
+                prov::USED,
+                source.clone(),
+                graph.clone(),
+            ));
+        }
+        if self.config.agent.is_some() || self.config.client_address.is_some() {
+            let agent = BlankNode::default();
+            quads.push(Quad::new(
+                activity.clone(),
+                prov::WAS_ASSOCIATED_WITH,
+                agent.clone(),
+                graph.clone(),
+            ));
+            quads.push(Quad::new(
+                agent.clone(),
+                rdf::TYPE,
+                prov::AGENT,
+                graph.clone(),
+            ));
+            if let Some(name) = &self.config.agent {
+                quads.push(Quad::new(
+                    agent.clone(),
+                    rdfs::LABEL,
+                    Literal::from(name.as_str()),
+                    graph.clone(),
+                ));
+            }
+            if let Some(address) = &self.config.client_address {
+                quads.push(Quad::new(
+                    agent,
+                    CLIENT_ADDRESS,
+                    Literal::from(address.as_str()),
+                    graph.clone(),
+                ));
+            }
+        }
+        for loaded_graph in &self.graphs {
+            quads.push(Quad::new(
+                loaded_graph.clone(),
+                rdf::TYPE,
+                prov::ENTITY,
+                graph.clone(),
+            ));
+            quads.push(Quad::new(
+                loaded_graph.clone(),
+                prov::WAS_GENERATED_BY,
+                activity.clone(),
+                graph.clone(),
+            ));
+        }
+        quads
+    }
+}
@@ -0,0 +1,535 @@
+use crate::model::LiteralRef;
+use crate::storage::error::StorageError;
+use crate::storage::numeric_encoder::{EncodedTerm, StrLookup, get_required_str};
+use oxsdatatypes::{DateTime, Decimal, Double, Float, Integer};
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::ops::Bound;
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+pub use value_intervals::ValueInterval;
+
+/// The kinds of literal values that can be used as bounds of an [`EncodedObjectRange`]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum LiteralValueKind {
+    /// `xsd:string` literals
+    String,
+    /// `xsd:integer`, `xsd:decimal`, `xsd:float` and `xsd:double` literals
+    Numeric,
+    /// `xsd:dateTime` literals
+    DateTime,
+}
+
+/// A range of literal values the object of a quad must be in.
+///
+/// Values are compared using the SPARQL operator mapping:
+/// numbers are compared after numeric type promotion, strings using their code points and `xsd:dateTime` using the XML Schema ordering.
+#[derive(Clone, Debug)]
+pub struct EncodedObjectRange {
+    kind: LiteralValueKind,
+    start: Bound<LiteralValue>,
+    end: Bound<LiteralValue>,
+}
+
+impl EncodedObjectRange {
+    /// Builds a new range from encoded bounds.
+    ///
+    /// Returns `None` if the range is unbounded or if its bounds are not string, numeric or `xsd:dateTime` literals of the same kind.
+    pub fn from_encoded_bounds(
+        start: Bound<&EncodedTerm>,
+        end: Bound<&EncodedTerm>,
+        lookup: &impl StrLookup,
+    ) -> Result<Option<Self>, StorageError> {
+        let Some(start) = map_bound(start, |t| LiteralValue::from_encoded(t, lookup))? else {
+            return Ok(None);
+        };
+        let Some(end) = map_bound(end, |t| LiteralValue::from_encoded(t, lookup))? else {
+            return Ok(None);
+        };
+        Ok(Self::new(start, end))
+    }
+
+    /// Builds a new range from literal bounds.
+    ///
+    /// Returns `None` if the range is unbounded or if its bounds are not string, numeric or `xsd:dateTime` literals of the same kind.
+    pub fn from_literal_bounds(
+        start: Bound<LiteralRef<'_>>,
+        end: Bound<LiteralRef<'_>>,
+    ) -> Option<Self> {
+        let Ok(Some(start)) = map_bound(start, |l| {
+            Ok::<_, Infallible>(LiteralValue::from_literal(l))
+        }) else {
+            return None;
+        };
+        let Ok(Some(end)) = map_bound(end, |l| Ok::<_, Infallible>(LiteralValue::from_literal(l)))
+        else {
+            return None;
+        };
+        Self::new(start, end)
+    }
+
+    fn new(start: Bound<LiteralValue>, end: Bound<LiteralValue>) -> Option<Self> {
+        let kind = match (bound_value(&start), bound_value(&end)) {
+            (Some(start), Some(end)) => (start.kind() == end.kind()).then_some(start.kind())?,
+            (Some(value), None) | (None, Some(value)) => value.kind(),
+            (None, None) => return None,
+        };
+        Some(Self { kind, start, end })
+    }
+
+    pub fn kind(&self) -> LiteralValueKind {
+        self.kind
+    }
+
+    /// Checks if the given term is in the range.
+    pub fn contains(
+        &self,
+        term: &EncodedTerm,
+        lookup: &impl StrLookup,
+    ) -> Result<bool, StorageError> {
+        let Some(value) = LiteralValue::from_encoded(term, lookup)? else {
+            return Ok(false);
+        };
+        if value.kind() != self.kind {
+            return Ok(false);
+        }
+        let after_start = match &self.start {
+            Bound::Included(start) => value.partial_cmp(start).is_some_and(Ordering::is_ge),
+            Bound::Excluded(start) => value.partial_cmp(start).is_some_and(Ordering::is_gt),
+            Bound::Unbounded => true,
+        };
+        Ok(after_start
+            && match &self.end {
+                Bound::Included(end) => value.partial_cmp(end).is_some_and(Ordering::is_le),
+                Bound::Excluded(end) => value.partial_cmp(end).is_some_and(Ordering::is_lt),
+                Bound::Unbounded => true,
+            })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum LiteralValue {
+    String(String),
+    Float(Float),
+    Double(Double),
+    Integer(Integer),
+    Decimal(Decimal),
+    DateTime(DateTime),
+}
+
+impl LiteralValue {
+    fn from_encoded(
+        term: &EncodedTerm,
+        lookup: &impl StrLookup,
+    ) -> Result<Option<Self>, StorageError> {
+        Ok(Some(match term {
+            EncodedTerm::SmallStringLiteral(value) => Self::String(value.as_str().into()),
+            EncodedTerm::BigStringLiteral { value_id } => {
+                Self::String(get_required_str(lookup, value_id)?)
+            }
+            EncodedTerm::FloatLiteral(value) => Self::Float(*value),
+            EncodedTerm::DoubleLiteral(value) => Self::Double(*value),
+            EncodedTerm::IntegerLiteral(value) => Self::Integer(*value),
+            EncodedTerm::DecimalLiteral(value) => Self::Decimal(*value),
+            EncodedTerm::DateTimeLiteral(value) => Self::DateTime(*value),
+            _ => return Ok(None),
+        }))
+    }
+
+    fn from_literal(literal: LiteralRef<'_>) -> Option<Self> {
+        match EncodedTerm::from(literal) {
+            EncodedTerm::SmallStringLiteral(_) | EncodedTerm::BigStringLiteral { .. } => {
+                Some(Self::String(literal.value().into()))
+            }
+            EncodedTerm::FloatLiteral(value) => Some(Self::Float(value)),
+            EncodedTerm::DoubleLiteral(value) => Some(Self::Double(value)),
+            EncodedTerm::IntegerLiteral(value) => Some(Self::Integer(value)),
+            EncodedTerm::DecimalLiteral(value) => Some(Self::Decimal(value)),
+            EncodedTerm::DateTimeLiteral(value) => Some(Self::DateTime(value)),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> LiteralValueKind {
+        match self {
+            Self::String(_) => LiteralValueKind::String,
+            Self::Float(_) | Self::Double(_) | Self::Integer(_) | Self::Decimal(_) => {
+                LiteralValueKind::Numeric
+            }
+            Self::DateTime(_) => LiteralValueKind::DateTime,
+        }
+    }
+}
+
+impl PartialOrd for LiteralValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self {
+            Self::String(a) => {
+                if let Self::String(b) = other {
+                    a.partial_cmp(b)
+                } else {
+                    None
+                }
+            }
+            Self::Float(a) => match other {
+                Self::Float(b) => a.partial_cmp(b),
+                Self::Double(b) => Double::from(*a).partial_cmp(b),
+                Self::Integer(b) => a.partial_cmp(&Float::from(*b)),
+                Self::Decimal(b) => a.partial_cmp(&(*b).into()),
+                _ => None,
+            },
+            Self::Double(a) => match other {
+                Self::Float(b) => a.partial_cmp(&(*b).into()),
+                Self::Double(b) => a.partial_cmp(b),
+                Self::Integer(b) => a.partial_cmp(&Double::from(*b)),
+                Self::Decimal(b) => a.partial_cmp(&(*b).into()),
+                _ => None,
+            },
+            Self::Integer(a) => match other {
+                Self::Float(b) => Float::from(*a).partial_cmp(b),
+                Self::Double(b) => Double::from(*a).partial_cmp(b),
+                Self::Integer(b) => a.partial_cmp(b),
+                Self::Decimal(b) => Decimal::from(*a).partial_cmp(b),
+                _ => None,
+            },
+            Self::Decimal(a) => match other {
+                Self::Float(b) => Float::from(*a).partial_cmp(b),
+                Self::Double(b) => Double::from(*a).partial_cmp(b),
+                Self::Integer(b) => a.partial_cmp(&Decimal::from(*b)),
+                Self::Decimal(b) => a.partial_cmp(b),
+                _ => None,
+            },
+            Self::DateTime(a) => {
+                if let Self::DateTime(b) = other {
+                    a.partial_cmp(b)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn bound_value<T>(bound: &Bound<T>) -> Option<&T> {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => Some(value),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Maps the bound value, returns `None` if the mapping fails
+fn map_bound<T, U, E>(
+    bound: Bound<T>,
+    map: impl FnOnce(T) -> Result<Option<U>, E>,
+) -> Result<Option<Bound<U>>, E> {
+    Ok(Some(match bound {
+        Bound::Included(value) => {
+            let Some(value) = map(value)? else {
+                return Ok(None);
+            };
+            Bound::Included(value)
+        }
+        Bound::Excluded(value) => {
+            let Some(value) = map(value)? else {
+                return Ok(None);
+            };
+            Bound::Excluded(value)
+        }
+        Bound::Unbounded => Bound::Unbounded,
+    }))
+}
+
+/// The lookups by value intervals are only supported by the on-disk storage
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+mod value_intervals {
+    use super::*;
+
+    /// An interval of stored literal values of the same type, both bounds being included
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum ValueInterval {
+        /// All the `xsd:string` literals, the strings are not stored in value order
+        String,
+        Float(Float, Float),
+        Double(Double, Double),
+        Integer(Integer, Integer),
+        Decimal(Decimal, Decimal),
+        /// Timestamps in seconds of `xsd:dateTime` literals, the timezone being ignored
+        DateTime(Decimal, Decimal),
+    }
+
+    const MAX_TIMEZONE_OFFSET_IN_SECONDS: i64 = 14 * 3600;
+
+    impl EncodedObjectRange {
+        /// Returns the intervals of stored values that contain all the literals of the range.
+        ///
+        /// The bounds are rounded outward when converted to another numeric type
+        /// so the intervals might contain literals outside of the range that have to be filtered out using [`contains`](Self::contains).
+        pub fn value_intervals(&self) -> Vec<ValueInterval> {
+            match self.kind {
+                LiteralValueKind::String => vec![ValueInterval::String],
+                LiteralValueKind::Numeric => {
+                    let (Some(start), Some(end)) = (
+                        map_numeric_bound(&self.start, f64::NEG_INFINITY),
+                        map_numeric_bound(&self.end, f64::INFINITY),
+                    ) else {
+                        // NaN is not comparable
+                        return Vec::new();
+                    };
+                    let mut intervals = Vec::new();
+                    let integer_start = match &self.start {
+                        Bound::Included(LiteralValue::Integer(value))
+                        | Bound::Excluded(LiteralValue::Integer(value)) => *value,
+                        Bound::Included(LiteralValue::Decimal(value))
+                        | Bound::Excluded(LiteralValue::Decimal(value)) => {
+                            decimal_to_integer(value.checked_floor().unwrap_or(Decimal::MIN))
+                        }
+                        _ => float_to_integer(widen_down(start).floor()),
+                    };
+                    let integer_end = match &self.end {
+                        Bound::Included(LiteralValue::Integer(value))
+                        | Bound::Excluded(LiteralValue::Integer(value)) => *value,
+                        Bound::Included(LiteralValue::Decimal(value))
+                        | Bound::Excluded(LiteralValue::Decimal(value)) => {
+                            decimal_to_integer(value.checked_ceil().unwrap_or(Decimal::MAX))
+                        }
+                        _ => float_to_integer(widen_up(end).ceil()),
+                    };
+                    if integer_start <= integer_end {
+                        intervals.push(ValueInterval::Integer(integer_start, integer_end));
+                    }
+                    let decimal_start = match &self.start {
+                        Bound::Included(LiteralValue::Integer(value))
+                        | Bound::Excluded(LiteralValue::Integer(value)) => Decimal::from(*value),
+                        Bound::Included(LiteralValue::Decimal(value))
+                        | Bound::Excluded(LiteralValue::Decimal(value)) => *value,
+                        _ => float_to_decimal(widen_down(start)),
+                    };
+                    let decimal_end = match &self.end {
+                        Bound::Included(LiteralValue::Integer(value))
+                        | Bound::Excluded(LiteralValue::Integer(value)) => Decimal::from(*value),
+                        Bound::Included(LiteralValue::Decimal(value))
+                        | Bound::Excluded(LiteralValue::Decimal(value)) => *value,
+                        _ => float_to_decimal(widen_up(end)),
+                    };
+                    if decimal_start <= decimal_end {
+                        intervals.push(ValueInterval::Decimal(decimal_start, decimal_end));
+                    }
+                    let (double_start, double_end) = (widen_down(start), widen_up(end));
+                    if double_start <= double_end {
+                        intervals.push(ValueInterval::Double(
+                            double_start.into(),
+                            double_end.into(),
+                        ));
+                    }
+                    let (float_start, float_end) = (
+                        double_to_float_down(double_start),
+                        double_to_float_up(double_end),
+                    );
+                    if float_start <= float_end {
+                        intervals.push(ValueInterval::Float(float_start.into(), float_end.into()));
+                    }
+                    intervals
+                }
+                LiteralValueKind::DateTime => {
+                    // The values without timezone are compared with a margin of 14 hours
+                    let start = match &self.start {
+                        Bound::Included(LiteralValue::DateTime(value))
+                        | Bound::Excluded(LiteralValue::DateTime(value)) => {
+                            date_time_timestamp(*value)
+                                .checked_sub(MAX_TIMEZONE_OFFSET_IN_SECONDS)
+                                .unwrap_or(Decimal::MIN)
+                        }
+                        _ => Decimal::MIN,
+                    };
+                    let end = match &self.end {
+                        Bound::Included(LiteralValue::DateTime(value))
+                        | Bound::Excluded(LiteralValue::DateTime(value)) => {
+                            date_time_timestamp(*value)
+                                .checked_add(MAX_TIMEZONE_OFFSET_IN_SECONDS)
+                                .unwrap_or(Decimal::MAX)
+                        }
+                        _ => Decimal::MAX,
+                    };
+                    if start <= end {
+                        vec![ValueInterval::DateTime(start, end)]
+                    } else {
+                        Vec::new()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the numeric bound as a double or `default` if unbounded, `None` if it is NaN
+    fn map_numeric_bound(bound: &Bound<LiteralValue>, default: f64) -> Option<f64> {
+        let value = match bound_value(bound) {
+            Some(LiteralValue::Float(value)) => f64::from(Double::from(*value)),
+            Some(LiteralValue::Double(value)) => f64::from(*value),
+            Some(LiteralValue::Integer(value)) => f64::from(Double::from(*value)),
+            Some(LiteralValue::Decimal(value)) => f64::from(Double::from(*value)),
+            _ => default,
+        };
+        (!value.is_nan()).then_some(value)
+    }
+
+    /// Decreases the value to make sure it stays a lower bound after numeric type conversions
+    fn widen_down(value: f64) -> f64 {
+        if value.is_finite() {
+            value - value.abs() * 1e-6 - f64::MIN_POSITIVE
+        } else {
+            value
+        }
+    }
+
+    /// Increases the value to make sure it stays an upper bound after numeric type conversions
+    fn widen_up(value: f64) -> f64 {
+        -widen_down(-value)
+    }
+
+    /// Returns the greatest float lower or equal to the double
+    #[expect(clippy::cast_possible_truncation)]
+    fn double_to_float_down(value: f64) -> f32 {
+        let float = value as f32;
+        if f64::from(float) > value {
+            float.next_down()
+        } else {
+            float
+        }
+    }
+
+    /// Returns the lowest float greater or equal to the double
+    fn double_to_float_up(value: f64) -> f32 {
+        -double_to_float_down(-value)
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn float_to_integer(value: f64) -> Integer {
+        // The conversion saturates
+        Integer::from(value as i64)
+    }
+
+    fn decimal_to_integer(value: Decimal) -> Integer {
+        Integer::try_from(value).unwrap_or(if value.is_negative() {
+            Integer::MIN
+        } else {
+            Integer::MAX
+        })
+    }
+
+    fn float_to_decimal(value: f64) -> Decimal {
+        Decimal::try_from(Double::from(value)).unwrap_or(if value < 0. {
+            Decimal::MIN
+        } else {
+            Decimal::MAX
+        })
+    }
+
+    fn date_time_timestamp(value: DateTime) -> Decimal {
+        let mut timestamp = [0; 16];
+        timestamp.copy_from_slice(&value.to_be_bytes()[..16]);
+        Decimal::from_be_bytes(timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Literal;
+    use crate::storage::numeric_encoder::StrHash;
+
+    struct NoLookup;
+
+    impl StrLookup for NoLookup {
+        fn get_str(&self, _: &StrHash) -> Result<Option<String>, StorageError> {
+            Ok(None)
+        }
+    }
+
+    fn contains(range: &EncodedObjectRange, term: &EncodedTerm) -> bool {
+        range.contains(term, &NoLookup).unwrap()
+    }
+
+    #[test]
+    fn test_numeric_range() {
+        let range = EncodedObjectRange::from_encoded_bounds(
+            Bound::Included(&EncodedTerm::IntegerLiteral(Integer::from(10))),
+            Bound::Excluded(&EncodedTerm::DoubleLiteral(Double::from(20.))),
+            &NoLookup,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(range.kind(), LiteralValueKind::Numeric);
+        assert!(contains(
+            &range,
+            &EncodedTerm::IntegerLiteral(Integer::from(10))
+        ));
+        assert!(contains(
+            &range,
+            &EncodedTerm::DecimalLiteral(Decimal::from(15))
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::FloatLiteral(Float::from(20.))
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::IntegerLiteral(Integer::from(-15))
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::SmallStringLiteral("15".try_into().unwrap())
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::SmallTypedLiteral {
+                value: "15".try_into().unwrap(),
+                datatype_id: StrHash::new("http://example.com/number")
+            }
+        ));
+    }
+
+    #[test]
+    fn test_string_range() {
+        let range = EncodedObjectRange::from_literal_bounds(
+            Bound::Excluded(LiteralRef::new_simple_literal("a very long string value")),
+            Bound::Unbounded,
+        )
+        .unwrap();
+        assert_eq!(range.kind(), LiteralValueKind::String);
+        assert!(contains(
+            &range,
+            &EncodedTerm::SmallStringLiteral("b".try_into().unwrap())
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::SmallStringLiteral("a".try_into().unwrap())
+        ));
+        assert!(!contains(
+            &range,
+            &EncodedTerm::SmallSmallLangStringLiteral {
+                value: "b".try_into().unwrap(),
+                language: "en".try_into().unwrap()
+            }
+        ));
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert!(
+            EncodedObjectRange::from_literal_bounds(Bound::Unbounded, Bound::Unbounded).is_none()
+        );
+        assert!(
+            EncodedObjectRange::from_literal_bounds(
+                Bound::Included(Literal::from(10).as_ref()),
+                Bound::Included(Literal::from(true).as_ref()),
+            )
+            .is_none()
+        );
+        assert!(
+            EncodedObjectRange::from_literal_bounds(
+                Bound::Included(Literal::from(10).as_ref()),
+                Bound::Included(LiteralRef::new_simple_literal("a")),
+            )
+            .is_none()
+        );
+    }
+}
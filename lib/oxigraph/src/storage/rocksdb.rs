@@ -5,29 +5,35 @@ use crate::model::{BlankNode, GraphName, Term, Triple};
 use crate::model::{GraphNameRef, NamedOrBlankNodeRef, Quad, QuadRef, TermRef};
 use crate::storage::binary_encoder::{
     QuadEncoding, TYPE_STAR_TRIPLE, WRITTEN_TERM_MAX_SIZE, decode_term, encode_term,
-    encode_term_pair, encode_term_quad, encode_term_triple, write_gosp_quad, write_gpos_quad,
-    write_gspo_quad, write_osp_quad, write_ospg_quad, write_pos_quad, write_posg_quad,
-    write_spo_quad, write_spog_quad, write_term,
+    encode_term_pair, encode_term_quad, encode_term_triple, encode_value_interval, write_gosp_quad,
+    write_gpos_quad, write_gspo_quad, write_osp_quad, write_ospg_quad, write_pos_quad,
+    write_posg_quad, write_spo_quad, write_spog_quad, write_term,
 };
 pub use crate::storage::error::{CorruptionError, StorageError};
+use crate::storage::graph_modification::{GraphModification, ModifiedGraphs};
 use crate::storage::numeric_encoder::{
     Decoder, EncodedQuad, EncodedTerm, StrHash, StrHashHasher, StrLookup, insert_term,
 };
+use crate::storage::options::StoreOptions;
+use crate::storage::range::ValueInterval;
 use crate::storage::rocksdb_wrapper::{
     ColumnFamily, ColumnFamilyDefinition, Db, Iter, ReadableTransaction, Reader, Transaction,
 };
-use crate::storage::{DEFAULT_BULK_LOAD_BATCH_SIZE, map_thread_result};
+use crate::storage::{
+    DEFAULT_BULK_LOAD_BATCH_SIZE, Durability, QueryStatistics, map_thread_result,
+};
 use rustc_hash::{FxBuildHasher, FxHashSet};
 #[cfg(feature = "rdf-12")]
 use siphasher::sip128::{Hasher128, SipHasher24};
 use spareval::CancellationToken;
 use std::collections::{HashMap, VecDeque};
-use std::fs::remove_file;
+use std::fs::{read_dir, remove_file};
 use std::hash::BuildHasherDefault;
 #[cfg(feature = "rdf-12")]
 use std::hash::Hash;
 use std::mem::take;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 use std::{io, thread};
@@ -46,6 +52,11 @@ const DPOS_CF: &str = "dpos";
 const DOSP_CF: &str = "dosp";
 const GRAPHS_CF: &str = "graphs";
 const DEFAULT_CF: &str = "default";
+const NAMESPACE_KEY_PREFIX: &[u8] = b"namespace:";
+const QUERY_STATISTICS_KEY: &[u8] = b"query_statistics";
+const DEFAULT_GRAPH_AS_UNION_KEY: &[u8] = b"default_graph_as_union";
+const GRAPH_MODIFICATION_KEY_PREFIX: &[u8] = b"graph_modification:";
+const NAMED_GRAPHS_MODIFICATION_KEY: &[u8] = b"named_graphs_modification";
 
 /// Low level storage primitives
 #[derive(Clone)]
@@ -63,11 +74,13 @@ pub struct RocksDbStorage {
     dpos_cf: ColumnFamily,
     dosp_cf: ColumnFamily,
     graphs_cf: ColumnFamily,
+    /// The counter of the last graph modification
+    graph_modification_counter: Arc<AtomicU64>,
 }
 
 impl RocksDbStorage {
-    pub fn open(path: &Path) -> Result<Self, StorageError> {
-        Self::setup(Db::open_read_write(path, Self::column_families())?)
+    pub fn open(path: &Path, options: StoreOptions) -> Result<Self, StorageError> {
+        Self::setup(Db::open_read_write(path, Self::column_families(), options)?)
     }
 
     pub fn open_read_only(path: &Path) -> Result<Self, StorageError> {
@@ -159,9 +172,12 @@ impl RocksDbStorage {
             dpos_cf: db.column_family(DPOS_CF)?,
             dosp_cf: db.column_family(DOSP_CF)?,
             graphs_cf: db.column_family(GRAPHS_CF)?,
+            graph_modification_counter: Arc::default(),
             db,
         };
         this.migrate()?;
+        this.graph_modification_counter
+            .store(this.last_graph_modification_counter()?, Ordering::Release);
         Ok(this)
     }
 
@@ -365,10 +381,254 @@ impl RocksDbStorage {
         self.db.compact(&self.id2str_cf)
     }
 
+    pub fn full_compact(&self) -> Result<(), StorageError> {
+        self.db.full_compact(&self.default_cf)?;
+        for cf in self.quad_column_families() {
+            self.db.full_compact(cf)?;
+        }
+        self.db.full_compact(&self.id2str_cf)
+    }
+
+    /// Compacts the column families emptied by [`clear`](RocksDbStorageTransaction::clear) to drop their deleted entries
+    pub fn reclaim_cleared_space(&self) -> Result<(), StorageError> {
+        for cf in self.quad_column_families() {
+            self.db.full_compact(cf)?;
+        }
+        Ok(())
+    }
+
+    /// The column families storing the quads and the graph names, emptied when clearing the store
+    fn quad_column_families(&self) -> [&ColumnFamily; 10] {
+        [
+            &self.gspo_cf,
+            &self.gpos_cf,
+            &self.gosp_cf,
+            &self.spog_cf,
+            &self.posg_cf,
+            &self.ospg_cf,
+            &self.dspo_cf,
+            &self.dpos_cf,
+            &self.dosp_cf,
+            &self.graphs_cf,
+        ]
+    }
+
+    /// Size on disk of the quads and graph names
+    pub fn quad_data_size(&self) -> u64 {
+        self.quad_column_families()
+            .into_iter()
+            .map(|cf| {
+                self.db
+                    .int_property(cf, c"rocksdb.total-sst-files-size")
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+
+    /// Estimated size on disk of the data that is neither deleted nor overwritten
+    pub fn live_data_size(&self) -> u64 {
+        [&self.default_cf, &self.id2str_cf]
+            .into_iter()
+            .chain(self.quad_column_families())
+            .map(|cf| {
+                self.db
+                    .int_property(cf, c"rocksdb.estimate-live-data-size")
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+
+    /// Size of all the files in the database directory, including the write-ahead log and the logs
+    pub fn directory_size(&self) -> Result<u64, StorageError> {
+        Ok(directory_size(self.db.path())?)
+    }
+
     pub fn backup(&self, target_directory: &Path) -> Result<(), StorageError> {
         self.db.backup(target_directory)
     }
 
+    pub fn namespaces(&self) -> Result<Vec<(String, String)>, StorageError> {
+        let reader = self.db.snapshot();
+        let mut iter = reader.scan_prefix(&self.default_cf, NAMESPACE_KEY_PREFIX);
+        let mut namespaces = Vec::new();
+        while let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+            namespaces.push((
+                String::from_utf8(key[NAMESPACE_KEY_PREFIX.len()..].to_vec())
+                    .map_err(CorruptionError::new)?,
+                String::from_utf8(value.to_vec()).map_err(CorruptionError::new)?,
+            ));
+            iter.next();
+        }
+        iter.status()?;
+        Ok(namespaces)
+    }
+
+    pub fn set_namespace(&self, prefix: &str, namespace: &str) -> Result<(), StorageError> {
+        self.db.insert(
+            &self.default_cf,
+            &namespace_key(prefix),
+            namespace.as_bytes(),
+        )
+    }
+
+    pub fn remove_namespace(&self, prefix: &str) -> Result<(), StorageError> {
+        self.db.remove(&self.default_cf, &namespace_key(prefix))
+    }
+
+    pub fn query_statistics(&self) -> Result<Option<QueryStatistics>, StorageError> {
+        let Some(value) = self.db.get(&self.default_cf, QUERY_STATISTICS_KEY)? else {
+            return Ok(None);
+        };
+        QueryStatistics::decode(&value)
+    }
+
+    pub fn default_graph_as_union(&self) -> Result<bool, StorageError> {
+        Ok(self
+            .db
+            .get(&self.default_cf, DEFAULT_GRAPH_AS_UNION_KEY)?
+            .is_some_and(|value| value.as_ref() == [1]))
+    }
+
+    pub fn set_default_graph_as_union(&self, value: bool) -> Result<(), StorageError> {
+        self.db.insert(
+            &self.default_cf,
+            DEFAULT_GRAPH_AS_UNION_KEY,
+            &[u8::from(value)],
+        )
+    }
+
+    pub fn set_query_statistics(&self, statistics: &QueryStatistics) -> Result<(), StorageError> {
+        self.db
+            .insert(&self.default_cf, QUERY_STATISTICS_KEY, &statistics.encode())
+    }
+
+    pub fn graph_modification(
+        &self,
+        graph_name: &EncodedTerm,
+    ) -> Result<Option<GraphModification>, StorageError> {
+        let modification = self
+            .db
+            .get(&self.default_cf, &graph_modification_key(graph_name))?
+            .map(|value| GraphModification::decode(&value))
+            .transpose()?;
+        if graph_name.is_default_graph() {
+            return Ok(modification);
+        }
+        let named_graphs_modification = self
+            .db
+            .get(&self.default_cf, NAMED_GRAPHS_MODIFICATION_KEY)?
+            .map(|value| GraphModification::decode(&value))
+            .transpose()?;
+        Ok(GraphModification::latest(
+            modification,
+            named_graphs_modification,
+        ))
+    }
+
+    /// The greatest saved modification counter, used to never reuse a counter after a restart
+    fn last_graph_modification_counter(&self) -> Result<u64, StorageError> {
+        let mut counter = match self
+            .db
+            .get(&self.default_cf, NAMED_GRAPHS_MODIFICATION_KEY)?
+        {
+            Some(value) => GraphModification::decode(&value)?.counter,
+            None => 0,
+        };
+        let reader = self.db.snapshot();
+        let mut iter = reader.scan_prefix(&self.default_cf, GRAPH_MODIFICATION_KEY_PREFIX);
+        while let Some(value) = iter.value() {
+            counter = counter.max(GraphModification::decode(value)?.counter);
+            iter.next();
+        }
+        iter.status()?;
+        Ok(counter)
+    }
+
+    /// Allocates a new modification if some graphs have been modified
+    fn new_graph_modification(
+        &self,
+        modified_graphs: &ModifiedGraphs,
+    ) -> Option<GraphModification> {
+        (!modified_graphs.is_empty()).then(|| {
+            GraphModification::new(
+                self.graph_modification_counter
+                    .fetch_add(1, Ordering::AcqRel)
+                    .saturating_add(1),
+            )
+        })
+    }
+
+    /// Sequence number of the last write, it changes each time the database is modified
+    pub fn version(&self) -> u64 {
+        self.db.latest_sequence_number()
+    }
+
+    /// Size on disk of each column family
+    pub fn column_family_sizes(&self) -> Vec<(&'static str, u64)> {
+        [
+            (DEFAULT_CF, &self.default_cf),
+            (ID2STR_CF, &self.id2str_cf),
+            (SPOG_CF, &self.spog_cf),
+            (POSG_CF, &self.posg_cf),
+            (OSPG_CF, &self.ospg_cf),
+            (GSPO_CF, &self.gspo_cf),
+            (GPOS_CF, &self.gpos_cf),
+            (GOSP_CF, &self.gosp_cf),
+            (DSPO_CF, &self.dspo_cf),
+            (DPOS_CF, &self.dpos_cf),
+            (DOSP_CF, &self.dosp_cf),
+            (GRAPHS_CF, &self.graphs_cf),
+        ]
+        .into_iter()
+        .map(|(name, cf)| {
+            (
+                name,
+                self.db
+                    .int_property(cf, c"rocksdb.total-sst-files-size")
+                    .unwrap_or(0),
+            )
+        })
+        .collect()
+    }
+
+    /// Estimates the number of quads in each graph from the RocksDB statistics without scanning the quads
+    pub fn approximate_graph_sizes(&self) -> Result<Vec<(EncodedTerm, usize)>, StorageError> {
+        let estimate_num_keys = |cf| {
+            self.db
+                .int_property(cf, c"rocksdb.estimate-num-keys")
+                .unwrap_or(0)
+        };
+        let mut sizes = vec![(
+            EncodedTerm::DefaultGraph,
+            usize::try_from(estimate_num_keys(&self.dspo_cf)).unwrap_or(usize::MAX),
+        )];
+        let named_graphs_keys = estimate_num_keys(&self.gspo_cf);
+        let named_graphs_size = self.db.approximate_size(&self.gspo_cf, &[], &[u8::MAX])?;
+        let reader = self.snapshot();
+        for graph_name in reader.named_graphs() {
+            let graph_name = graph_name?;
+            let start = encode_term(&graph_name);
+            let count = if named_graphs_size == 0 {
+                // Everything is still in the memtables, we count the quads
+                reader
+                    .gspo_quads(&start)
+                    .try_fold(0, |count, quad| quad.map(|_| count + 1))?
+            } else {
+                // We assume that the quads of all graphs have the same average size on disk
+                let mut end = start.clone();
+                end.push(u8::MAX);
+                let graph_size = self.db.approximate_size(&self.gspo_cf, &start, &end)?;
+                usize::try_from(
+                    u128::from(graph_size) * u128::from(named_graphs_keys)
+                        / u128::from(named_graphs_size),
+                )
+                .unwrap_or(usize::MAX)
+            };
+            sizes.push((graph_name, count));
+        }
+        Ok(sizes)
+    }
+
     pub fn bulk_loader(&self) -> RocksDbStorageBulkLoader<'_> {
         RocksDbStorageBulkLoader {
             storage: self,
@@ -379,10 +639,12 @@ impl RocksDbStorage {
             done_and_displayed_counter: 0,
             cancellation_token: CancellationToken::new(),
             atomic: true,
+            durability: Durability::default(),
         }
     }
 }
 
+#[derive(Clone)]
 #[must_use]
 pub struct RocksDbStorageReader<'a> {
     reader: Reader<'a>,
@@ -473,6 +735,96 @@ impl<'a> RocksDbStorageReader<'a> {
         }
     }
 
+    /// Returns the quads matching the pattern whose object is a literal with a value in one of the given intervals.
+    ///
+    /// The POS or OSP indexes are ordered by object value for each literal type, the lookup only scans the keys of the intervals.
+    pub fn quads_for_pattern_with_object_values(
+        &self,
+        predicate: Option<&EncodedTerm>,
+        object_values: &[ValueInterval],
+        graph_name: Option<&EncodedTerm>,
+    ) -> RocksDbRangesDecodingQuadIterator<'a> {
+        let mut iters = Vec::new();
+        match predicate {
+            Some(predicate) => match graph_name {
+                Some(graph_name) => {
+                    if graph_name.is_default_graph() {
+                        self.inner_quads_in_value_intervals(
+                            &self.storage.dpos_cf,
+                            &encode_term(predicate),
+                            object_values,
+                            QuadEncoding::Dpos,
+                            &mut iters,
+                        )
+                    } else {
+                        self.inner_quads_in_value_intervals(
+                            &self.storage.gpos_cf,
+                            &encode_term_pair(graph_name, predicate),
+                            object_values,
+                            QuadEncoding::Gpos,
+                            &mut iters,
+                        )
+                    }
+                }
+                None => {
+                    self.inner_quads_in_value_intervals(
+                        &self.storage.dpos_cf,
+                        &encode_term(predicate),
+                        object_values,
+                        QuadEncoding::Dpos,
+                        &mut iters,
+                    );
+                    self.inner_quads_in_value_intervals(
+                        &self.storage.posg_cf,
+                        &encode_term(predicate),
+                        object_values,
+                        QuadEncoding::Posg,
+                        &mut iters,
+                    )
+                }
+            },
+            None => match graph_name {
+                Some(graph_name) => {
+                    if graph_name.is_default_graph() {
+                        self.inner_quads_in_value_intervals(
+                            &self.storage.dosp_cf,
+                            &[],
+                            object_values,
+                            QuadEncoding::Dosp,
+                            &mut iters,
+                        )
+                    } else {
+                        self.inner_quads_in_value_intervals(
+                            &self.storage.gosp_cf,
+                            &encode_term(graph_name),
+                            object_values,
+                            QuadEncoding::Gosp,
+                            &mut iters,
+                        )
+                    }
+                }
+                None => {
+                    self.inner_quads_in_value_intervals(
+                        &self.storage.dosp_cf,
+                        &[],
+                        object_values,
+                        QuadEncoding::Dosp,
+                        &mut iters,
+                    );
+                    self.inner_quads_in_value_intervals(
+                        &self.storage.ospg_cf,
+                        &[],
+                        object_values,
+                        QuadEncoding::Ospg,
+                        &mut iters,
+                    )
+                }
+            },
+        }
+        iters.reverse(); // They are consumed from the end
+        RocksDbRangesDecodingQuadIterator { iters }
+    }
+
     pub fn quads(&self) -> RocksDbChainedDecodingQuadIterator<'a> {
         RocksDbChainedDecodingQuadIterator::pair(self.dspo_quads(&[]), self.gspo_quads(&[]))
     }
@@ -702,6 +1054,31 @@ impl<'a> RocksDbStorageReader<'a> {
         }
     }
 
+    /// Adds an iterator for each interval of keys made of the prefix followed by an object with a value in the intervals
+    fn inner_quads_in_value_intervals(
+        &self,
+        column_family: &ColumnFamily,
+        prefix: &[u8],
+        object_values: &[ValueInterval],
+        encoding: QuadEncoding,
+        iters: &mut Vec<RocksDbDecodingQuadIterator<'a>>,
+    ) {
+        for interval in object_values {
+            for (start, end) in encode_value_interval(interval) {
+                let mut start_key = prefix.to_vec();
+                start_key.extend_from_slice(&start);
+                let mut end_key = prefix.to_vec();
+                end_key.extend_from_slice(&end);
+                iters.push(RocksDbDecodingQuadIterator {
+                    iter: self
+                        .reader
+                        .scan_range(column_family, &start_key, Some(end_key)),
+                    encoding,
+                });
+            }
+        }
+    }
+
     pub fn contains_str(&self, key: &StrHash) -> Result<bool, StorageError> {
         self.storage
             .db
@@ -859,6 +1236,26 @@ impl Iterator for RocksDbChainedDecodingQuadIterator<'_> {
     }
 }
 
+/// Iterates over several intervals of keys one after the other
+#[must_use]
+pub struct RocksDbRangesDecodingQuadIterator<'a> {
+    /// The remaining iterators, in reverse order
+    iters: Vec<RocksDbDecodingQuadIterator<'a>>,
+}
+
+impl Iterator for RocksDbRangesDecodingQuadIterator<'_> {
+    type Item = Result<EncodedQuad, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.iters.last_mut()?.next() {
+                return Some(result);
+            }
+            self.iters.pop();
+        }
+    }
+}
+
 struct RocksDbDecodingQuadIterator<'a> {
     iter: Iter<'a>,
     encoding: QuadEncoding,
@@ -1092,9 +1489,30 @@ impl RocksDbStorageTransaction<'_> {
         // TODO: clear id2str?
     }
 
-    pub fn commit(self) -> Result<(), StorageError> {
+    /// Size in bytes of the changes that are not written yet
+    pub fn pending_size(&self) -> usize {
+        self.transaction.pending_size()
+    }
+
+    /// Writes the changes done so far, they are visible to the readers even if the transaction is not committed
+    pub fn write_pending(&mut self, modified_graphs: &ModifiedGraphs) -> Result<(), StorageError> {
+        self.insert_graph_modification(modified_graphs);
+        self.transaction.write_pending()
+    }
+
+    pub fn commit(mut self, modified_graphs: &ModifiedGraphs) -> Result<(), StorageError> {
+        self.insert_graph_modification(modified_graphs);
         self.transaction.commit()
     }
+
+    fn insert_graph_modification(&mut self, modified_graphs: &ModifiedGraphs) {
+        if let Some(modification) = self.storage.new_graph_modification(modified_graphs) {
+            for (key, value) in graph_modification_entries(modified_graphs, modification) {
+                self.transaction
+                    .insert(&self.storage.default_cf, &key, &value);
+            }
+        }
+    }
 }
 
 #[must_use]
@@ -1114,67 +1532,93 @@ impl RocksDbStorageReadableTransaction<'_> {
 
     pub fn insert(&mut self, quad: QuadRef<'_>) {
         let encoded = quad.into();
+        self.insert_encoded(&encoded);
+        self.insert_term(quad.subject.into(), &encoded.subject);
+        self.insert_term(quad.predicate.into(), &encoded.predicate);
+        self.insert_term(quad.object, &encoded.object);
+        self.insert_graph_name(quad.graph_name, &encoded.graph_name)
+    }
+
+    /// Writes the index entries of the quad without its terms
+    fn insert_encoded(&mut self, quad: &EncodedQuad) {
         self.buffer.clear();
         if quad.graph_name.is_default_graph() {
-            write_spo_quad(&mut self.buffer, &encoded);
+            write_spo_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.dspo_cf, &self.buffer);
 
             self.buffer.clear();
-            write_pos_quad(&mut self.buffer, &encoded);
+            write_pos_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.dpos_cf, &self.buffer);
 
             self.buffer.clear();
-            write_osp_quad(&mut self.buffer, &encoded);
+            write_osp_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.dosp_cf, &self.buffer);
-
-            self.insert_term(quad.subject.into(), &encoded.subject);
-            self.insert_term(quad.predicate.into(), &encoded.predicate);
-            self.insert_term(quad.object, &encoded.object)
         } else {
-            write_spog_quad(&mut self.buffer, &encoded);
+            write_spog_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.spog_cf, &self.buffer);
 
             self.buffer.clear();
-            write_posg_quad(&mut self.buffer, &encoded);
+            write_posg_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.posg_cf, &self.buffer);
 
             self.buffer.clear();
-            write_ospg_quad(&mut self.buffer, &encoded);
+            write_ospg_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.ospg_cf, &self.buffer);
 
             self.buffer.clear();
-            write_gspo_quad(&mut self.buffer, &encoded);
+            write_gspo_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.gspo_cf, &self.buffer);
 
             self.buffer.clear();
-            write_gpos_quad(&mut self.buffer, &encoded);
+            write_gpos_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.gpos_cf, &self.buffer);
 
             self.buffer.clear();
-            write_gosp_quad(&mut self.buffer, &encoded);
+            write_gosp_quad(&mut self.buffer, quad);
             self.transaction
                 .insert_empty(&self.storage.gosp_cf, &self.buffer);
 
-            self.insert_term(quad.subject.into(), &encoded.subject);
-            self.insert_term(quad.predicate.into(), &encoded.predicate);
-            self.insert_term(quad.object, &encoded.object);
-
             self.buffer.clear();
-            write_term(&mut self.buffer, &encoded.graph_name);
+            write_term(&mut self.buffer, &quad.graph_name);
             self.transaction
                 .insert_empty(&self.storage.graphs_cf, &self.buffer);
-            self.insert_graph_name(quad.graph_name, &encoded.graph_name)
         }
     }
 
+    /// Inserts all the quads of the graph `from` into the graph `to`
+    ///
+    /// The subject, predicate and object terms are already stored so only the index entries and the graph name are written.
+    pub fn add_graph(
+        &mut self,
+        from: GraphNameRef<'_>,
+        to: GraphNameRef<'_>,
+    ) -> Result<(), StorageError> {
+        let encoded_to: EncodedTerm = to.into();
+        let quads = self
+            .reader()
+            .quads_for_graph(&from.into())
+            .collect::<Result<Vec<_>, _>>()?;
+        if quads.is_empty() {
+            return Ok(());
+        }
+        for quad in quads {
+            self.insert_encoded(&EncodedQuad {
+                graph_name: encoded_to.clone(),
+                ..quad
+            });
+        }
+        self.insert_graph_name(to, &encoded_to);
+        Ok(())
+    }
+
     pub fn insert_named_graph(&mut self, graph_name: NamedOrBlankNodeRef<'_>) {
         let encoded_graph_name = graph_name.into();
 
@@ -1209,7 +1653,7 @@ impl RocksDbStorageReadableTransaction<'_> {
         self.remove_encoded(&quad.into())
     }
 
-    fn remove_encoded(&mut self, quad: &EncodedQuad) {
+    pub fn remove_encoded(&mut self, quad: &EncodedQuad) {
         self.buffer.clear();
         if quad.graph_name.is_default_graph() {
             write_spo_quad(&mut self.buffer, quad);
@@ -1326,9 +1770,30 @@ impl RocksDbStorageReadableTransaction<'_> {
         self.clear_graph(GraphNameRef::DefaultGraph)
     }
 
-    pub fn commit(self) -> Result<(), StorageError> {
+    /// Size in bytes of the changes that are not written yet
+    pub fn pending_size(&self) -> usize {
+        self.transaction.pending_size()
+    }
+
+    /// Writes the changes done so far, they are visible to the readers even if the transaction is not committed
+    pub fn write_pending(&mut self, modified_graphs: &ModifiedGraphs) -> Result<(), StorageError> {
+        self.insert_graph_modification(modified_graphs);
+        self.transaction.write_pending()
+    }
+
+    pub fn commit(mut self, modified_graphs: &ModifiedGraphs) -> Result<(), StorageError> {
+        self.insert_graph_modification(modified_graphs);
         self.transaction.commit()
     }
+
+    fn insert_graph_modification(&mut self, modified_graphs: &ModifiedGraphs) {
+        if let Some(modification) = self.storage.new_graph_modification(modified_graphs) {
+            for (key, value) in graph_modification_entries(modified_graphs, modification) {
+                self.transaction
+                    .insert(&self.storage.default_cf, &key, &value);
+            }
+        }
+    }
 }
 
 #[must_use]
@@ -1341,6 +1806,7 @@ pub struct RocksDbStorageBulkLoader<'a> {
     done_and_displayed_counter: u64,
     cancellation_token: CancellationToken,
     atomic: bool,
+    durability: Durability,
 }
 
 impl Drop for RocksDbStorageBulkLoader<'_> {
@@ -1373,6 +1839,16 @@ impl RocksDbStorageBulkLoader<'_> {
         self
     }
 
+    pub fn atomic(mut self) -> Self {
+        self.atomic = true;
+        self
+    }
+
+    pub fn with_durability(mut self, durability: Durability) -> Self {
+        self.durability = durability;
+        self
+    }
+
     pub fn load_batch(
         &mut self,
         batch: Vec<Quad>,
@@ -1393,7 +1869,13 @@ impl RocksDbStorageBulkLoader<'_> {
         let storage = self.storage.clone();
         let counter = Arc::clone(&self.done_counter);
         let cancellation_token = self.cancellation_token.clone();
+        #[cfg(feature = "tracing")]
+        let parent_span = tracing::Span::current();
         self.threads.push_back(thread::spawn(move || {
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::info_span!(parent: &parent_span, "write_batch_files", quads = batch.len())
+                    .entered();
             let mut sst_files = Vec::new();
             match FileBulkLoader::new(&storage, batch.len(), cancellation_token).load(
                 batch,
@@ -1441,7 +1923,8 @@ impl RocksDbStorageBulkLoader<'_> {
         Ok(())
     }
 
-    pub fn commit(mut self) -> Result<(), StorageError> {
+    /// Waits for the pending batches and saves them into the database
+    pub fn flush(&mut self) -> Result<(), StorageError> {
         while let Some(thread) = self.threads.pop_front() {
             self.sst_files
                 .extend(map_thread_result(thread.join()).map_err(StorageError::Io)??);
@@ -1449,6 +1932,22 @@ impl RocksDbStorageBulkLoader<'_> {
         }
         self.do_commit()
     }
+
+    pub fn commit(mut self, modified_graphs: &ModifiedGraphs) -> Result<(), StorageError> {
+        self.flush()?;
+        if let Some(modification) = self.storage.new_graph_modification(modified_graphs) {
+            for (key, value) in graph_modification_entries(modified_graphs, modification) {
+                self.storage
+                    .db
+                    .insert(&self.storage.default_cf, &key, &value)?;
+            }
+        }
+        if self.durability == Durability::Full {
+            // The ingested files are already synced by RocksDB, only the metadata writes are left
+            self.storage.db.sync_wal()?;
+        }
+        Ok(())
+    }
 }
 
 struct FileBulkLoader<'a> {
@@ -1685,6 +2184,56 @@ impl<'a> FileBulkLoader<'a> {
     }
 }
 
+fn graph_modification_key(graph_name: &EncodedTerm) -> Vec<u8> {
+    let mut key = Vec::with_capacity(GRAPH_MODIFICATION_KEY_PREFIX.len() + WRITTEN_TERM_MAX_SIZE);
+    key.extend_from_slice(GRAPH_MODIFICATION_KEY_PREFIX);
+    write_term(&mut key, graph_name);
+    key
+}
+
+/// The metadata entries to write to save a modification
+fn graph_modification_entries(
+    modified_graphs: &ModifiedGraphs,
+    modification: GraphModification,
+) -> Vec<(Vec<u8>, [u8; 26])> {
+    let value = modification.encode();
+    let mut entries = modified_graphs
+        .graphs
+        .iter()
+        .map(|graph_name| (graph_modification_key(graph_name), value))
+        .collect::<Vec<_>>();
+    if modified_graphs.all_named_graphs {
+        entries.push((NAMED_GRAPHS_MODIFICATION_KEY.to_vec(), value));
+    }
+    entries
+}
+
+fn namespace_key(prefix: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(NAMESPACE_KEY_PREFIX.len() + prefix.len());
+    key.extend_from_slice(NAMESPACE_KEY_PREFIX);
+    key.extend_from_slice(prefix.as_bytes());
+    key
+}
+
+fn directory_size(path: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in read_dir(path)? {
+        let entry = entry?;
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            // The file has been removed by a compaction meanwhile
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        size += if metadata.is_dir() {
+            directory_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1713,7 +2262,7 @@ mod tests {
         let encoded_named_graph_quad = EncodedQuad::from(named_graph_quad);
 
         let path = TempDir::new()?;
-        let storage = RocksDbStorage::open(path.as_ref())?;
+        let storage = RocksDbStorage::open(path.as_ref(), StoreOptions::default())?;
 
         // We start with a graph
         let snapshot = storage.snapshot();
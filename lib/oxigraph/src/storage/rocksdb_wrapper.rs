@@ -9,6 +9,7 @@
 )]
 
 use crate::storage::error::{CorruptionError, StorageError};
+use crate::storage::options::{StorageCompression, StoreOptions};
 use oxrocksdb_sys::*;
 use rand::random;
 use std::borrow::Borrow;
@@ -16,12 +17,15 @@ use std::borrow::Borrow;
 use std::cmp::min;
 use std::collections::HashMap;
 use std::error::Error;
-use std::ffi::CString;
+use std::ffi::{CStr, CString, c_int};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 use std::thread::available_parallelism;
-use std::{fmt, io, ptr, slice};
+use std::{fmt, fs, io, ptr, slice};
+
+/// The prefix of the SST files written by the bulk loader before their ingestion
+const BULK_LOAD_FILE_PREFIX: &str = "bulk-";
 
 macro_rules! ffi_result {
     ( $($function:ident)::*( $arg1:expr $(, $arg:expr)* $(,)? ) ) => {{
@@ -102,6 +106,7 @@ struct RoDbHandler {
     column_family_names: Vec<&'static str>,
     cf_handles: Vec<*mut rocksdb_column_family_handle_t>,
     cf_options: Vec<*mut rocksdb_options_t>,
+    path: PathBuf,
 }
 
 unsafe impl Send for RoDbHandler {}
@@ -128,13 +133,48 @@ impl Db {
     pub fn open_read_write(
         path: &Path,
         column_families: Vec<ColumnFamilyDefinition>,
+        store_options: StoreOptions,
     ) -> Result<Self, StorageError> {
+        let StoreOptions {
+            compression_per_level,
+            zstd_dictionary_size,
+            block_cache_size,
+            bloom_filter_bits_per_key,
+            write_buffer_size,
+            max_background_jobs,
+        } = store_options;
         let c_path = path_to_cstring(path)?;
         unsafe {
             let options = Self::db_options()?;
             rocksdb_options_set_create_if_missing(options, 1);
             rocksdb_options_set_create_missing_column_families(options, 1);
             rocksdb_options_set_compression(options, rocksdb_lz4_compression.try_into().unwrap());
+            if let Some(compression_per_level) = compression_per_level {
+                let levels = compression_per_level
+                    .into_iter()
+                    .map(compression_type)
+                    .collect::<Vec<_>>();
+                rocksdb_options_set_compression_per_level(options, levels.as_ptr(), levels.len());
+            }
+            if let Some(dictionary_size) = zstd_dictionary_size {
+                let dictionary_size = c_int::try_from(dictionary_size).unwrap_or(c_int::MAX);
+                // Default window bits, level and strategy
+                rocksdb_options_set_compression_options(options, -14, 32767, 0, dictionary_size);
+                // Zstandard recommends to train on 100 times the dictionary size
+                rocksdb_options_set_compression_options_zstd_max_train_bytes(
+                    options,
+                    dictionary_size.saturating_mul(100),
+                );
+            }
+            if let Some(write_buffer_size) = write_buffer_size {
+                rocksdb_options_set_write_buffer_size(options, write_buffer_size);
+            }
+            if let Some(max_background_jobs) = max_background_jobs {
+                rocksdb_options_set_max_background_jobs(
+                    options,
+                    c_int::try_from(max_background_jobs).unwrap_or(c_int::MAX),
+                );
+            }
             let block_based_table_options = rocksdb_block_based_options_create();
             assert!(
                 !block_based_table_options.is_null(),
@@ -145,6 +185,23 @@ impl Db {
                 block_based_table_options,
                 16,
             );
+            if let Some(block_cache_size) = block_cache_size {
+                let block_cache = rocksdb_cache_create_lru(block_cache_size);
+                assert!(
+                    !block_cache.is_null(),
+                    "rocksdb_cache_create_lru returned null"
+                );
+                rocksdb_block_based_options_set_block_cache(block_based_table_options, block_cache);
+                // The table options keep their own reference to the cache
+                rocksdb_cache_destroy(block_cache);
+            }
+            if let Some(bits_per_key) = bloom_filter_bits_per_key {
+                // The table options take the ownership of the filter policy
+                rocksdb_block_based_options_set_filter_policy(
+                    block_based_table_options,
+                    rocksdb_filterpolicy_create_bloom(bits_per_key),
+                );
+            }
             rocksdb_options_set_block_based_table_factory(options, block_based_table_options);
             #[cfg(feature = "rocksdb-debug")]
             {
@@ -224,7 +281,7 @@ impl Db {
                 "rocksdb_compactoptions_create returned null"
             );
 
-            Ok(Self {
+            let db = Self {
                 inner: DbKind::ReadWrite(Arc::new(RwDbHandler {
                     db,
                     options,
@@ -240,7 +297,10 @@ impl Db {
                     cf_options,
                     path: path.into(),
                 })),
-            })
+            };
+            // The database lock is now held, the files of interrupted bulk loads can't be used anymore
+            remove_bulk_load_files(path)?;
+            Ok(db)
         }
     }
 
@@ -301,6 +361,7 @@ impl Db {
                     column_family_names,
                     cf_handles,
                     cf_options,
+                    path: path.into(),
                 })),
             })
         }
@@ -538,6 +599,24 @@ impl Db {
         Ok(())
     }
 
+    pub fn remove(&self, column_family: &ColumnFamily, key: &[u8]) -> Result<(), StorageError> {
+        let DbKind::ReadWrite(db) = &self.inner else {
+            return Err(StorageError::Other(
+                "Removals are only possible on read-write instances".into(),
+            ));
+        };
+        unsafe {
+            ffi_result!(rocksdb_delete_cf(
+                db.db,
+                db.write_options,
+                column_family.0,
+                key.as_ptr().cast(),
+                key.len(),
+            ))
+        }?;
+        Ok(())
+    }
+
     pub fn flush(&self) -> Result<(), StorageError> {
         let DbKind::ReadWrite(db) = &self.inner else {
             return Err(StorageError::Other(
@@ -555,6 +634,17 @@ impl Db {
         Ok(())
     }
 
+    /// Writes the write-ahead log to disk and syncs it
+    pub fn sync_wal(&self) -> Result<(), StorageError> {
+        let DbKind::ReadWrite(db) = &self.inner else {
+            return Err(StorageError::Other(
+                "WAL sync is only possible on read-write instances".into(),
+            ));
+        };
+        unsafe { ffi_result!(rocksdb_flush_wal(db.db, 1)) }?;
+        Ok(())
+    }
+
     pub fn compact(&self, column_family: &ColumnFamily) -> Result<(), StorageError> {
         let DbKind::ReadWrite(db) = &self.inner else {
             return Err(StorageError::Other(
@@ -575,13 +665,44 @@ impl Db {
         Ok(())
     }
 
+    /// Compacts the full column family, rewriting the files of the last level too
+    pub fn full_compact(&self, column_family: &ColumnFamily) -> Result<(), StorageError> {
+        let DbKind::ReadWrite(db) = &self.inner else {
+            return Err(StorageError::Other(
+                "Compact are only possible on read-write instances".into(),
+            ));
+        };
+        unsafe {
+            let compaction_options = rocksdb_compactoptions_create();
+            assert!(
+                !compaction_options.is_null(),
+                "rocksdb_compactoptions_create returned null"
+            );
+            // kForceOptimized: the files created by the compaction of the previous levels are not compacted again
+            rocksdb_compactoptions_set_bottommost_level_compaction(compaction_options, 3);
+            rocksdb_compact_range_cf_opt(
+                db.db.cast(),
+                column_family.0,
+                compaction_options,
+                ptr::null(),
+                0,
+                ptr::null(),
+                0,
+            );
+            rocksdb_compactoptions_destroy(compaction_options);
+        }
+        Ok(())
+    }
+
     pub fn new_sst_file(&self) -> Result<SstFileWriter, StorageError> {
         let DbKind::ReadWrite(db) = &self.inner else {
             return Err(StorageError::Other(
                 "SST creation is only possible on read-write instances".into(),
             ));
         };
-        let path = db.path.join(format!("bulk-{}.sst", random::<u128>()));
+        let path = db
+            .path
+            .join(format!("{BULK_LOAD_FILE_PREFIX}{}.sst", random::<u128>()));
         unsafe {
             let writer = rocksdb_sstfilewriter_create(db.env_options, db.options);
             ffi_result!(rocksdb_sstfilewriter_open(
@@ -641,10 +762,7 @@ impl Db {
     pub fn backup(&self, target_directory: &Path) -> Result<(), StorageError> {
         let path = path_to_cstring(target_directory)?;
         unsafe {
-            let checkpoint = ffi_result!(rocksdb_checkpoint_object_create(match &self.inner {
-                DbKind::ReadOnly(db) => db.db,
-                DbKind::ReadWrite(db) => db.db,
-            }))?;
+            let checkpoint = ffi_result!(rocksdb_checkpoint_object_create(self.raw_db()))?;
             assert!(
                 !checkpoint.is_null(),
                 "rocksdb_checkpoint_object_create returned null"
@@ -655,6 +773,71 @@ impl Db {
         }?;
         Ok(())
     }
+
+    /// Returns the value of an integer property like `rocksdb.estimate-num-keys` for the given column family
+    ///
+    /// Returns `None` if the property does not exist.
+    pub fn int_property(&self, column_family: &ColumnFamily, name: &CStr) -> Option<u64> {
+        let mut value = 0;
+        let status = unsafe {
+            rocksdb_property_int_cf(
+                self.raw_db(),
+                column_family.0,
+                name.as_ptr(),
+                &raw mut value,
+            )
+        };
+        (status == 0).then_some(value)
+    }
+
+    /// Returns the approximate size on disk of the keys between `start` (included) and `end` (excluded)
+    ///
+    /// Keys only stored in memtables are not taken into account.
+    pub fn approximate_size(
+        &self,
+        column_family: &ColumnFamily,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<u64, StorageError> {
+        let start_key = start.as_ptr().cast();
+        let start_len = start.len();
+        let end_key = end.as_ptr().cast();
+        let end_len = end.len();
+        let mut size = 0;
+        unsafe {
+            ffi_result!(rocksdb_approximate_sizes_cf(
+                self.raw_db(),
+                column_family.0,
+                1,
+                &raw const start_key,
+                &raw const start_len,
+                &raw const end_key,
+                &raw const end_len,
+                &raw mut size
+            ))
+        }?;
+        Ok(size)
+    }
+
+    /// Returns the sequence number of the most recent write
+    pub fn latest_sequence_number(&self) -> u64 {
+        unsafe { rocksdb_get_latest_sequence_number(self.raw_db()) }
+    }
+
+    /// The directory of the database
+    pub fn path(&self) -> &Path {
+        match &self.inner {
+            DbKind::ReadOnly(db) => &db.path,
+            DbKind::ReadWrite(db) => &db.path,
+        }
+    }
+
+    fn raw_db(&self) -> *mut rocksdb_t {
+        match &self.inner {
+            DbKind::ReadOnly(db) => db.db,
+            DbKind::ReadWrite(db) => db.db,
+        }
+    }
 }
 
 // It is fine to not keep a lifetime: there is no way to use this type without the database being still in scope.
@@ -790,7 +973,16 @@ impl<'a> Reader<'a> {
             }
             found.then_some(bound)
         };
+        self.scan_range(column_family, prefix, upper_bound)
+    }
 
+    /// Iterates over the keys between `start` (included) and `upper_bound` (excluded)
+    pub fn scan_range(
+        &self,
+        column_family: &ColumnFamily,
+        start: &[u8],
+        upper_bound: Option<Vec<u8>>,
+    ) -> Iter<'a> {
         unsafe {
             let options = oxrocksdb_readoptions_create_copy(self.options);
             assert!(
@@ -821,10 +1013,10 @@ impl<'a> Reader<'a> {
                 }
             };
             assert!(!iter.is_null(), "rocksdb_create_iterator returned null");
-            if prefix.is_empty() {
+            if start.is_empty() {
                 rocksdb_iter_seek_to_first(iter);
             } else {
-                rocksdb_iter_seek(iter, prefix.as_ptr().cast(), prefix.len());
+                rocksdb_iter_seek(iter, start.as_ptr().cast(), start.len());
             }
             let is_currently_valid = rocksdb_iter_valid(iter) != 0;
             Iter {
@@ -911,6 +1103,24 @@ impl Transaction {
         }
     }
 
+    /// Size in bytes of the changes that are not written yet
+    pub fn pending_size(&self) -> usize {
+        let mut size = 0;
+        unsafe {
+            rocksdb_writebatch_data(self.batch, &mut size);
+        }
+        size
+    }
+
+    /// Writes the changes done so far and starts again with an empty batch
+    pub fn write_pending(&mut self) -> Result<(), StorageError> {
+        unsafe {
+            ffi_result!(rocksdb_write(self.db.db, self.db.write_options, self.batch))?;
+            rocksdb_writebatch_clear(self.batch);
+        }
+        Ok(())
+    }
+
     pub fn commit(self) -> Result<(), StorageError> {
         unsafe {
             ffi_result!(rocksdb_write(self.db.db, self.db.write_options, self.batch))?;
@@ -978,6 +1188,33 @@ impl ReadableTransaction<'_> {
         }
     }
 
+    /// Size in bytes of the changes that are not written yet
+    pub fn pending_size(&self) -> usize {
+        let mut size = 0;
+        unsafe {
+            rocksdb_writebatch_wi_data(self.batch, &mut size);
+        }
+        size
+    }
+
+    /// Writes the changes done so far and starts again with an empty batch
+    ///
+    /// The reads are then done on a new snapshot that contains the written changes.
+    pub fn write_pending(&mut self) -> Result<(), StorageError> {
+        unsafe {
+            ffi_result!(rocksdb_write_writebatch_wi(
+                self.db.db,
+                self.db.write_options,
+                self.batch
+            ))?;
+            rocksdb_writebatch_wi_clear(self.batch);
+            rocksdb_release_snapshot(self.db.db, self.snapshot);
+            self.snapshot = rocksdb_create_snapshot(self.db.db);
+            rocksdb_readoptions_set_snapshot(self.read_options, self.snapshot);
+        }
+        Ok(())
+    }
+
     pub fn commit(self) -> Result<(), StorageError> {
         unsafe {
             ffi_result!(rocksdb_write_writebatch_wi(
@@ -1120,6 +1357,18 @@ impl Iter<'_> {
             None
         }
     }
+
+    pub fn value(&self) -> Option<&[u8]> {
+        if self.is_valid() {
+            unsafe {
+                let mut len = 0;
+                let val = rocksdb_iter_value(self.inner, &raw mut len);
+                Some(slice::from_raw_parts(val.cast(), len))
+            }
+        } else {
+            None
+        }
+    }
 }
 
 pub struct SstFileWriter {
@@ -1217,6 +1466,16 @@ struct UnsafeEnv(*mut rocksdb_env_t);
 unsafe impl Send for UnsafeEnv {}
 unsafe impl Sync for UnsafeEnv {}
 
+fn compression_type(compression: StorageCompression) -> c_int {
+    match compression {
+        StorageCompression::None => rocksdb_no_compression,
+        StorageCompression::Lz4 => rocksdb_lz4_compression,
+        StorageCompression::Zstd => rocksdb_zstd_compression,
+    }
+    .try_into()
+    .unwrap()
+}
+
 fn path_to_cstring(path: &Path) -> Result<CString, StorageError> {
     Ok(CString::new(path.to_str().ok_or_else(|| {
         io::Error::new(
@@ -1232,6 +1491,21 @@ fn path_to_cstring(path: &Path) -> Result<CString, StorageError> {
     })?)
 }
 
+/// Removes the SST files left by bulk loads interrupted before their ingestion, for example by a crash
+fn remove_bulk_load_files(path: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let is_bulk_load_file = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(BULK_LOAD_FILE_PREFIX) && name.ends_with(".sst"));
+        if is_bulk_load_file {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(unix)]
 fn available_file_descriptors() -> io::Result<Option<libc::rlim_t>> {
     let mut rlimit = libc::rlimit {
@@ -0,0 +1,115 @@
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+use crate::storage::binary_encoder::{TermReader, write_term};
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+use crate::storage::error::{CorruptionError, StorageError};
+use crate::storage::numeric_encoder::EncodedTerm;
+use oxrdf::NamedNodeRef;
+use spareval::StatisticsProvider;
+use std::collections::HashMap;
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+use std::io::Read;
+use std::sync::Arc;
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+const ENCODING_VERSION: u8 = 1;
+
+/// Cardinalities of the stored data used by the SPARQL optimizer to order joins
+#[derive(Clone, Default)]
+pub struct QueryStatistics {
+    pub triple_count: usize,
+    pub predicates: Arc<HashMap<EncodedTerm, PredicateStatistics>>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct PredicateStatistics {
+    pub triple_count: usize,
+    pub distinct_subjects: usize,
+    pub distinct_objects: usize,
+}
+
+impl QueryStatistics {
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = vec![ENCODING_VERSION];
+        buffer.extend_from_slice(&to_u64(self.triple_count).to_be_bytes());
+        for (predicate, statistics) in self.predicates.iter() {
+            write_term(&mut buffer, predicate);
+            for value in [
+                statistics.triple_count,
+                statistics.distinct_subjects,
+                statistics.distinct_objects,
+            ] {
+                buffer.extend_from_slice(&to_u64(value).to_be_bytes());
+            }
+        }
+        buffer
+    }
+
+    /// Returns `None` if the statistics have been written with an other encoding version
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn decode(mut buffer: &[u8]) -> Result<Option<Self>, StorageError> {
+        let Some((version, rest)) = buffer.split_first() else {
+            return Err(CorruptionError::msg("Empty query statistics").into());
+        };
+        if *version != ENCODING_VERSION {
+            return Ok(None);
+        }
+        buffer = rest;
+        let triple_count = read_usize(&mut buffer)?;
+        let mut predicates = HashMap::new();
+        while !buffer.is_empty() {
+            let predicate = buffer.read_term()?;
+            predicates.insert(
+                predicate,
+                PredicateStatistics {
+                    triple_count: read_usize(&mut buffer)?,
+                    distinct_subjects: read_usize(&mut buffer)?,
+                    distinct_objects: read_usize(&mut buffer)?,
+                },
+            );
+        }
+        Ok(Some(Self {
+            triple_count,
+            predicates: Arc::new(predicates),
+        }))
+    }
+}
+
+impl StatisticsProvider for QueryStatistics {
+    fn triple_count(&self) -> usize {
+        self.triple_count
+    }
+
+    fn predicate_triple_count(&self, predicate: NamedNodeRef<'_>) -> Option<usize> {
+        // The predicates not known when computing the statistics are considered as absent
+        Some(
+            self.predicates
+                .get(&predicate.into())
+                .map_or(0, |p| p.triple_count),
+        )
+    }
+
+    fn predicate_distinct_subject_count(&self, predicate: NamedNodeRef<'_>) -> Option<usize> {
+        self.predicates
+            .get(&predicate.into())
+            .map(|p| p.distinct_subjects)
+    }
+
+    fn predicate_distinct_object_count(&self, predicate: NamedNodeRef<'_>) -> Option<usize> {
+        self.predicates
+            .get(&predicate.into())
+            .map(|p| p.distinct_objects)
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn to_u64(value: usize) -> u64 {
+    value.try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn read_usize(buffer: &mut &[u8]) -> Result<usize, StorageError> {
+    let mut value = [0; 8];
+    buffer.read_exact(&mut value)?;
+    Ok(u64::from_be_bytes(value).try_into().unwrap_or(usize::MAX))
+}
@@ -31,37 +31,67 @@
 //! ```
 use crate::io::{RdfParseError, RdfParser, RdfSerializer};
 use crate::model::*;
+#[cfg(feature = "http-client")]
+use crate::sparql::http::{Client, load_rdf};
 #[expect(deprecated)]
 use crate::sparql::{
-    Query, QueryEvaluationError, QueryExplanation, QueryResults, SparqlEvaluator, Update,
-    UpdateEvaluationError,
+    DefaultGraphMode, PreparedSparqlQuery, Query, QueryEvaluationError, QueryExplanation,
+    QueryResults, QuerySolutionsChange, QuerySubscription, QuerySubscriptionError,
+    QuerySubscriptionOptions, SparqlEvaluator, Update, UpdateEvaluationError,
 };
+pub use crate::storage::GraphModification;
+#[cfg(feature = "http-client")]
+pub use crate::storage::IriLoadError;
 #[cfg(not(target_family = "wasm"))]
 use crate::storage::map_thread_result;
-use crate::storage::numeric_encoder::{Decoder, EncodedQuad, EncodedTerm};
-pub use crate::storage::{CorruptionError, LoaderError, SerializerError, StorageError};
+use crate::storage::numeric_encoder::{Decoder, EncodedQuad, EncodedTerm, StrHash, insert_term};
+use crate::storage::range::EncodedObjectRange;
+pub use crate::storage::{
+    CorruptionError, Durability, LoadProvenance, LoaderError, ReadListError, RenameGraphError,
+    SerializerError, SetNamespaceError, StorageError,
+};
 use crate::storage::{
-    DEFAULT_BULK_LOAD_BATCH_SIZE, DecodingGraphIterator, DecodingQuadIterator, Storage,
-    StorageBulkLoader, StorageReadableTransaction, StorageReader,
+    DEFAULT_BULK_LOAD_BATCH_SIZE, DecodingGraphIterator, DecodingQuadIterator, PredicateStatistics,
+    ProvenanceTracker, QueryStatistics, Storage, StorageBulkLoader, StorageReadableTransaction,
+    StorageReader,
 };
+#[cfg(feature = "history")]
+pub use crate::storage::{HistoryConfig, HistoryEvent, HistoryEventKind};
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+pub use crate::storage::{StorageCompression, StoreOptions};
+use oxiri::Iri;
+#[cfg(feature = "history")]
+use oxsdatatypes::DateTime;
 #[cfg(not(target_family = "wasm"))]
 use std::cmp::max;
+use std::cmp::min;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 #[cfg(not(target_family = "wasm"))]
-use std::fs::File;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+#[cfg(not(target_family = "wasm"))]
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
 use std::io::{Read, Write};
 use std::mem::swap;
 #[cfg(not(target_family = "wasm"))]
 use std::num::NonZero;
+use std::ops::RangeBounds;
 #[cfg(not(target_family = "wasm"))]
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 #[cfg(not(target_family = "wasm"))]
 use std::sync::mpsc;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 #[cfg(not(target_family = "wasm"))]
 use std::thread;
 #[cfg(not(target_family = "wasm"))]
 use std::thread::available_parallelism;
+#[cfg(all(target_family = "wasm", feature = "http-client"))]
+use std::time::Duration;
+#[cfg(not(target_family = "wasm"))]
+use std::time::{Duration, Instant};
 
 /// An on-disk [RDF dataset](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-dataset).
 /// Allows querying and updating it using SPARQL.
@@ -101,14 +131,17 @@ use std::thread::available_parallelism;
 #[derive(Clone)]
 pub struct Store {
     storage: Storage,
+    stats_cache: Arc<Mutex<Option<(u64, StoreStats)>>>,
+    query_statistics: Arc<RwLock<Option<QueryStatistics>>>,
+    default_graph_mode: Arc<RwLock<DefaultGraphMode>>,
+    max_query_subscriptions: Arc<AtomicUsize>,
+    set_object_lock: Arc<Mutex<()>>,
 }
 
 impl Store {
     /// New in-memory [`Store`] without RocksDB.
     pub fn new() -> Result<Self, StorageError> {
-        Ok(Self {
-            storage: Storage::new()?,
-        })
+        Self::from_storage(Storage::new()?)
     }
 
     /// Opens a read-write [`Store`] and creates it if it does not exist yet.
@@ -118,9 +151,18 @@ impl Store {
     /// use [`Store::open_read_only`].
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     pub fn open(path: impl AsRef<Path>) -> Result<Self, StorageError> {
-        Ok(Self {
-            storage: Storage::open(path.as_ref())?,
-        })
+        Self::open_with_options(path, StoreOptions::new())
+    }
+
+    /// Opens a read-write [`Store`] with some storage tuning options and creates it if it does not exist yet.
+    ///
+    /// See [`StoreOptions`] for the available options.
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn open_with_options(
+        path: impl AsRef<Path>,
+        options: StoreOptions,
+    ) -> Result<Self, StorageError> {
+        Self::from_storage(Storage::open(path.as_ref(), options)?)
     }
 
     /// Opens a read-only [`Store`] from disk.
@@ -128,8 +170,22 @@ impl Store {
     /// Opening as read-only while having an other process writing the database is undefined behavior.
     #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
     pub fn open_read_only(path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        Self::from_storage(Storage::open_read_only(path.as_ref())?)
+    }
+
+    fn from_storage(storage: Storage) -> Result<Self, StorageError> {
+        let default_graph_mode = if storage.default_graph_as_union()? {
+            DefaultGraphMode::Union
+        } else {
+            DefaultGraphMode::DefaultGraphOnly
+        };
         Ok(Self {
-            storage: Storage::open_read_only(path.as_ref())?,
+            query_statistics: Arc::new(RwLock::new(storage.query_statistics()?)),
+            default_graph_mode: Arc::new(RwLock::new(default_graph_mode)),
+            storage,
+            stats_cache: Arc::default(),
+            max_query_subscriptions: Arc::new(AtomicUsize::new(DEFAULT_MAX_QUERY_SUBSCRIPTIONS)),
+            set_object_lock: Arc::default(),
         })
     }
 
@@ -369,13 +425,168 @@ impl Store {
     ) -> QuadIter<'static> {
         let reader = self.storage.snapshot();
         QuadIter {
-            iter: reader.quads_for_pattern(
+            iter: Some(reader.quads_for_pattern(
                 subject.map(EncodedTerm::from).as_ref(),
                 predicate.map(EncodedTerm::from).as_ref(),
                 object.map(EncodedTerm::from).as_ref(),
                 graph_name.map(EncodedTerm::from).as_ref(),
-            ),
+            )),
+            reader,
+            object_range: None,
+        }
+    }
+
+    /// Retrieves quads with a filter on the subject, predicate and graph name and whose object is in a range of literal values
+    ///
+    /// The bounds must be `xsd:string`, numeric (`xsd:integer`, `xsd:decimal`, `xsd:float` or `xsd:double`) or `xsd:dateTime` literals of the same kind.
+    /// Values are compared like the SPARQL `<` and `>` operators do.
+    /// If the bounds are not supported, no quad is returned.
+    ///
+    /// On-disk stores read the object indexes in value order for numeric and `xsd:dateTime` ranges when no subject is given.
+    /// The other lookups read all the quads matching the subject, predicate and graph name and filter them on their object value.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    ///
+    /// // insertion
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// for i in 0..10 {
+    ///     store.insert(QuadRef::new(&ex, &ex, &Literal::from(i), GraphNameRef::DefaultGraph))?;
+    /// }
+    ///
+    /// // quad filter by object value
+    /// let results = store
+    ///     .quads_for_pattern_with_object_range(
+    ///         None,
+    ///         Some((&ex).into()),
+    ///         Literal::from(2)..Literal::from(4.),
+    ///         None,
+    ///     )
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(results.len(), 2);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn quads_for_pattern_with_object_range(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object_range: impl RangeBounds<Literal>,
+        graph_name: Option<GraphNameRef<'_>>,
+    ) -> QuadIter<'static> {
+        let reader = self.storage.snapshot();
+        let Some(object_range) = EncodedObjectRange::from_literal_bounds(
+            object_range.start_bound().map(Literal::as_ref),
+            object_range.end_bound().map(Literal::as_ref),
+        ) else {
+            return QuadIter {
+                iter: None,
+                reader,
+                object_range: None,
+            };
+        };
+        QuadIter {
+            iter: Some(reader.quads_for_pattern_with_object_range(
+                subject.map(EncodedTerm::from).as_ref(),
+                predicate.map(EncodedTerm::from).as_ref(),
+                &object_range,
+                graph_name.map(EncodedTerm::from).as_ref(),
+            )),
             reader,
+            object_range: Some(object_range),
+        }
+    }
+
+    /// Returns the [`TermId`] used by the store to encode the given term.
+    ///
+    /// Returns `None` if the term is not known by the store.
+    /// Note that small terms like numbers or short strings are encoded inline and are always known by the store.
+    ///
+    /// See [`quads_for_pattern_ids`](Self::quads_for_pattern_ids) to filter the store content using ids.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// assert_eq!(store.term_id(ex)?, None);
+    ///
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// let id = store.term_id(ex)?.unwrap();
+    /// assert_eq!(store.id_term(&id)?, Some(ex.into_owned().into()));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn term_id<'a>(
+        &self,
+        term: impl Into<TermRef<'a>>,
+    ) -> Result<Option<TermId>, StorageError> {
+        let term = term.into();
+        let encoded = EncodedTerm::from(term);
+        let mut str_ids = Vec::new();
+        insert_term(term, &encoded, &mut |id: &StrHash, _: &str| {
+            str_ids.push(*id)
+        });
+        let reader = self.storage.snapshot();
+        for id in &str_ids {
+            if !reader.contains_str(id)? {
+                return Ok(None);
+            }
+        }
+        Ok(Some(TermId(encoded)))
+    }
+
+    /// Returns the term encoded by the given [`TermId`].
+    ///
+    /// Returns `None` for [`TermId::DEFAULT_GRAPH`].
+    /// The id must have been built by this store, an error might be returned otherwise.
+    pub fn id_term(&self, id: &TermId) -> Result<Option<Term>, StorageError> {
+        if id.is_default_graph() {
+            return Ok(None);
+        }
+        self.storage.snapshot().decode_term(&id.0).map(Some)
+    }
+
+    /// Retrieves quads with a filter on each quad component, the components being given as [`TermId`].
+    ///
+    /// Returns quads made of [`TermId`] without decoding the terms strings.
+    /// It is much faster than [`quads_for_pattern`](Self::quads_for_pattern) when the terms are only compared with each other.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::{Store, TermId};
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    ///
+    /// let id = store.term_id(ex)?.unwrap();
+    /// let quads = store
+    ///     .quads_for_pattern_ids(Some(&id), None, None, Some(&TermId::DEFAULT_GRAPH))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(quads.len(), 1);
+    /// assert_eq!(quads[0].object, id);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn quads_for_pattern_ids(
+        &self,
+        subject: Option<&TermId>,
+        predicate: Option<&TermId>,
+        object: Option<&TermId>,
+        graph_name: Option<&TermId>,
+    ) -> IdQuadIter<'static> {
+        IdQuadIter {
+            iter: self.storage.snapshot().quads_for_pattern(
+                subject.map(|id| &id.0),
+                predicate.map(|id| &id.0),
+                object.map(|id| &id.0),
+                graph_name.map(|id| &id.0),
+            ),
         }
     }
 
@@ -402,6 +613,81 @@ impl Store {
         self.quads_for_pattern(None, None, None, None)
     }
 
+    /// Reads the items of the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` in the given graph.
+    ///
+    /// The list must be well-formed: each node must have exactly one `rdf:first` and one `rdf:rest` value,
+    /// and the `rdf:rest` values must lead to `rdf:nil` without cycle.
+    /// See [`Graph::read_list`] for more details.
+    ///
+    /// The SPARQL property path `?list rdf:rest*/rdf:first ?item` also returns the items but not their order.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// store.load_from_slice(
+    ///     RdfFormat::Turtle,
+    ///     "<http://example.com/s> <http://example.com/p> (1 2 3) .",
+    /// )?;
+    ///
+    /// if let QueryResults::Solutions(mut solutions) = SparqlEvaluator::new()
+    ///     .parse_query("SELECT ?list WHERE { <http://example.com/s> <http://example.com/p> ?list }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     let Some(Term::BlankNode(list)) = solutions.next().unwrap()?.get("list").cloned() else {
+    ///         panic!("the list should be a blank node");
+    ///     };
+    ///     assert_eq!(
+    ///         store.read_list(&list, GraphNameRef::DefaultGraph)?,
+    ///         [Literal::from(1).into(), Literal::from(2).into(), Literal::from(3).into()]
+    ///     );
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn read_list<'a, 'b>(
+        &self,
+        head: impl Into<NamedOrBlankNodeRef<'a>>,
+        graph_name: impl Into<GraphNameRef<'b>>,
+    ) -> Result<Vec<Term>, ReadListError> {
+        let head = head.into();
+        let graph_name = EncodedTerm::from(graph_name.into());
+        let reader = self.storage.snapshot();
+        // We copy the triples of the list nodes into a graph and let it validate the list
+        let mut list = Graph::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![head.into_owned()];
+        while let Some(node) = to_visit.pop() {
+            if node.as_ref() == NamedOrBlankNodeRef::from(vocab::rdf::NIL)
+                || !visited.insert(node.clone())
+            {
+                continue;
+            }
+            let subject = EncodedTerm::from(node.as_ref());
+            for predicate in [vocab::rdf::FIRST, vocab::rdf::REST] {
+                for quad in reader.quads_for_pattern(
+                    Some(&subject),
+                    Some(&predicate.into()),
+                    None,
+                    Some(&graph_name),
+                ) {
+                    let quad = reader.decode_quad(&quad?)?;
+                    if predicate == vocab::rdf::REST {
+                        if let Ok(rest) = NamedOrBlankNode::try_from(quad.object.clone()) {
+                            to_visit.push(rest);
+                        }
+                    }
+                    list.insert(TripleRef::from(quad.as_ref()));
+                }
+            }
+        }
+        Ok(list.read_list(head)?)
+    }
+
     /// Checks if this store contains a given quad.
     ///
     /// Usage example:
@@ -471,6 +757,8 @@ impl Store {
     /// (e.g., a SPARQL query) or a read/write operation (e.g., a SPARQL update).
     /// Transactional operations are also atomic.
     ///
+    /// Reads done through the transaction, including SPARQL queries, also see the changes done earlier in the same transaction.
+    ///
     /// Note that the transaction keeps the complete set of changes into memory, do not use them to load
     /// tens of millions of triples.
     ///
@@ -655,6 +943,210 @@ impl Store {
         Ok(())
     }
 
+    /// Loads an RDF file into the store and registers the prefixes it declares as store [namespaces](Self::namespaces).
+    ///
+    /// It behaves like [`load_from_reader`](Store::load_from_reader).
+    /// The namespaces are registered after the data has been committed, replacing the previous ones with the same prefix.
+    /// If the same prefix is declared multiple times in the file, the last declaration wins.
+    /// Prefixes that are not valid [Turtle `PN_PREFIX`](https://www.w3.org/TR/turtle/#grammar-production-PN_PREFIX) (e.g. some RDF/XML ones) are ignored.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::NamedNode;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// store.load_from_reader_with_namespaces(
+    ///     RdfFormat::Turtle,
+    ///     b"@prefix ex: <http://example.com/> . ex:s ex:p ex:o .".as_slice(),
+    /// )?;
+    /// assert_eq!(store.len()?, 1);
+    /// assert_eq!(
+    ///     store.namespaces()?.collect::<Vec<_>>(),
+    ///     [("ex".to_owned(), NamedNode::new("http://example.com/")?)]
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn load_from_reader_with_namespaces(
+        &self,
+        parser: impl Into<RdfParser>,
+        reader: impl Read,
+    ) -> Result<(), LoaderError> {
+        let mut transaction = self.storage.start_transaction()?;
+        let mut parser = parser.into().rename_blank_nodes().for_reader(reader);
+        for quad in &mut parser {
+            transaction.insert(quad?.as_ref());
+        }
+        transaction.commit()?;
+        self.register_prefixes(parser.prefixes())?;
+        Ok(())
+    }
+
+    /// Loads an RDF file into the store and registers the prefixes it declares as store [namespaces](Self::namespaces).
+    ///
+    /// It behaves like [`load_from_slice`](Store::load_from_slice).
+    /// See [`load_from_reader_with_namespaces`](Store::load_from_reader_with_namespaces) for the namespace registration details.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::NamedNode;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// store.load_from_slice_with_namespaces(
+    ///     RdfFormat::Turtle,
+    ///     "@prefix ex: <http://example.com/> . ex:s ex:p ex:o .",
+    /// )?;
+    /// assert_eq!(
+    ///     store.namespaces()?.collect::<Vec<_>>(),
+    ///     [("ex".to_owned(), NamedNode::new("http://example.com/")?)]
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn load_from_slice_with_namespaces(
+        &self,
+        parser: impl Into<RdfParser>,
+        slice: &(impl AsRef<[u8]> + ?Sized),
+    ) -> Result<(), LoaderError> {
+        let mut transaction = self.storage.start_transaction()?;
+        let mut parser = parser.into().rename_blank_nodes().for_slice(slice.as_ref());
+        for quad in &mut parser {
+            transaction.insert(quad.map_err(RdfParseError::Syntax)?.as_ref());
+        }
+        transaction.commit()?;
+        self.register_prefixes(parser.prefixes())?;
+        Ok(())
+    }
+
+    /// Registers the valid prefixes as namespaces
+    fn register_prefixes<'b>(
+        &self,
+        prefixes: impl IntoIterator<Item = (&'b str, &'b str)>,
+    ) -> Result<(), StorageError> {
+        for (prefix, namespace) in prefixes {
+            if is_valid_prefix_name(prefix) {
+                self.storage.set_namespace(prefix, namespace)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads an RDF file into the store and records its [PROV-O](https://www.w3.org/TR/prov-o/) provenance.
+    ///
+    /// It behaves like [`load_from_reader`](Store::load_from_reader) and also writes into the provenance graph the description of the load activity set up by [`LoadProvenance`].
+    /// The data and its provenance are written atomically.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::{RdfFormat, RdfParser};
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::{LoadProvenance, Store};
+    ///
+    /// let store = Store::new()?;
+    /// let file = "<http://example.com> <http://example.com> <http://example.com> .";
+    /// store.load_from_reader_with_provenance(
+    ///     RdfParser::from_format(RdfFormat::NTriples)
+    ///         .with_default_graph(NamedNodeRef::new("http://example.com/g")?),
+    ///     file.as_bytes(),
+    ///     LoadProvenance::new("http://example.com/load/")?
+    ///         .with_graph(NamedNode::new("http://example.com/provenance")?)
+    ///         .with_source(NamedNode::new("http://example.com/file.nt")?),
+    /// )?;
+    ///
+    /// let activity = store
+    ///     .quads_for_pattern(None, Some(vocab::prov::USED), None, None)
+    ///     .next()
+    ///     .unwrap()?
+    ///     .subject;
+    /// assert!(store.contains(QuadRef::new(
+    ///     NamedNodeRef::new("http://example.com/g")?,
+    ///     vocab::prov::WAS_GENERATED_BY,
+    ///     activity.as_ref(),
+    ///     NamedNodeRef::new("http://example.com/provenance")?
+    /// ))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn load_from_reader_with_provenance(
+        &self,
+        parser: impl Into<RdfParser>,
+        reader: impl Read,
+        provenance: LoadProvenance,
+    ) -> Result<(), LoaderError> {
+        let mut provenance = ProvenanceTracker::new(provenance);
+        let mut transaction = self.storage.start_transaction()?;
+        for quad in parser.into().rename_blank_nodes().for_reader(reader) {
+            let quad = quad?;
+            provenance.record(quad.as_ref());
+            transaction.insert(quad.as_ref());
+        }
+        for quad in provenance.quads() {
+            transaction.insert(quad.as_ref());
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Dereferences an IRI and loads the returned RDF document into the store.
+    ///
+    /// The document is fetched with an HTTP `GET` request asking for an RDF format, following up to 10 redirections.
+    /// Its format is given by the response `Content-Type` or, if it is not an RDF format, by the URL extension.
+    /// The final URL after redirections is used as base IRI.
+    ///
+    /// If `to_graph_name` is set, the triples are loaded into this graph and named graphs are rejected.
+    /// Otherwise, the graphs of the document are kept.
+    ///
+    /// This function is atomic. Use [`iri_loader`](Store::iri_loader) to configure the HTTP client.
+    ///
+    /// Usage example:
+    /// ```no_run
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ontology = NamedNodeRef::new("http://www.w3.org/2002/07/owl")?;
+    /// store.load_from_iri(ontology, Some(ontology.into()))?;
+    /// assert!(!store.is_empty()?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[cfg(feature = "http-client")]
+    pub fn load_from_iri<'a>(
+        &self,
+        iri: impl Into<NamedNodeRef<'a>>,
+        to_graph_name: Option<GraphNameRef<'_>>,
+    ) -> Result<(), IriLoadError> {
+        self.iri_loader().load(iri, to_graph_name)
+    }
+
+    /// Returns a configurable loader of documents from their IRIs.
+    ///
+    /// See [`load_from_iri`](Store::load_from_iri) for the default behavior.
+    ///
+    /// Usage example:
+    /// ```no_run
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    /// use std::time::Duration;
+    ///
+    /// let store = Store::new()?;
+    /// store
+    ///     .iri_loader()
+    ///     .with_http_timeout(Duration::from_secs(10))
+    ///     .with_http_redirection_limit(2)
+    ///     .load(NamedNodeRef::new("http://www.w3.org/2002/07/owl")?, None)?;
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[cfg(feature = "http-client")]
+    pub fn iri_loader(&self) -> IriLoader<'_> {
+        IriLoader {
+            store: self,
+            timeout: None,
+            redirection_limit: 10,
+            provenance: None,
+        }
+    }
+
     /// Adds a quad to this store.
     ///
     /// Usage example:
@@ -695,7 +1187,13 @@ impl Store {
         Ok(())
     }
 
-    /// Removes a quad from this store.
+    /// Atomically adds a set of quads to this store and reports which of them were already present.
+    ///
+    /// Quads repeated in the input are reported as already present after their first occurrence.
+    ///
+    /// <div class="warning">
+    ///
+    /// This operation uses a memory heavy transaction internally, use the [`bulk_loader`](Store::bulk_loader) if you plan to add ten of millions of triples.</div>
     ///
     /// Usage example:
     /// ```
@@ -704,22 +1202,158 @@ impl Store {
     ///
     /// let ex = NamedNodeRef::new("http://example.com")?;
     /// let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    /// let other = QuadRef::new(ex, ex, ex, ex);
     ///
     /// let store = Store::new()?;
     /// store.insert(quad)?;
-    /// store.remove(quad)?;
-    ///
-    /// assert!(!store.contains(quad)?);
+    /// let report = store.extend_with_report([quad.into_owned(), other.into_owned()])?;
+    /// assert_eq!(report.inserted, 1);
+    /// assert_eq!(report.already_present, 1);
+    /// assert_eq!(report.duplicates, [0]);
     /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
     /// ```
-    pub fn remove<'a>(&self, quad: impl Into<QuadRef<'a>>) -> Result<(), StorageError> {
-        let mut transaction = self.storage.start_transaction()?;
-        transaction.remove(quad.into());
+    pub fn extend_with_report(
+        &self,
+        quads: impl IntoIterator<Item = impl Into<Quad>>,
+    ) -> Result<ExtendReport, StorageError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        let mut report = ExtendReport::default();
+        for quad in quads {
+            report.insert(&mut transaction, quad.into().as_ref())?;
+        }
         transaction.commit()?;
-        Ok(())
+        Ok(report)
     }
 
-    /// Dumps the store into a file.
+    /// Removes a quad from this store.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    ///
+    /// let store = Store::new()?;
+    /// store.insert(quad)?;
+    /// store.remove(quad)?;
+    ///
+    /// assert!(!store.contains(quad)?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn remove<'a>(&self, quad: impl Into<QuadRef<'a>>) -> Result<(), StorageError> {
+        let mut transaction = self.storage.start_transaction()?;
+        transaction.remove(quad.into());
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Removes all the quads matching a pattern from this store and returns the number of removed quads.
+    ///
+    /// `None` matches any term, e.g. `store.remove_matching(None, Some(p), None, None)` removes all the quads with the predicate `p` in all graphs.
+    /// The matching quads are removed in a single transaction without being all loaded in memory.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let name = NamedNodeRef::new("http://schema.org/name")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, name, LiteralRef::new_simple_literal("foo"), ex))?;
+    /// store.insert(QuadRef::new(
+    ///     ex,
+    ///     name,
+    ///     LiteralRef::new_simple_literal("bar"),
+    ///     GraphNameRef::DefaultGraph,
+    /// ))?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    ///
+    /// assert_eq!(store.remove_matching(None, Some(name), None, None)?, 2);
+    /// assert_eq!(store.len()?, 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn remove_matching(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+        graph_name: Option<GraphNameRef<'_>>,
+    ) -> Result<u64, StorageError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        let count = transaction.remove_matching(
+            subject.map(EncodedTerm::from).as_ref(),
+            predicate.map(EncodedTerm::from).as_ref(),
+            object.map(EncodedTerm::from).as_ref(),
+            graph_name.map(EncodedTerm::from).as_ref(),
+        )?;
+        transaction.commit()?;
+        Ok(count)
+    }
+
+    /// Replaces all the objects of the `subject` `predicate` pair in the graph `graph_name` by `objects`
+    /// and returns the previous objects that are not in `objects` anymore.
+    ///
+    /// The read, the removals and the insertions are done in a single transaction
+    /// and concurrent calls to this method on the same store are serialized:
+    /// if two callers set different objects for the same pair, the store ends up with the objects of one of them and not a mix of both.
+    /// Other writes are not serialized with this method.
+    /// An empty `objects` removes all the values of the pair.
+    ///
+    /// Use [`Transaction::set_object`] to set the objects of multiple pairs at once.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let name = NamedNodeRef::new("http://schema.org/name")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(
+    ///     ex,
+    ///     name,
+    ///     LiteralRef::new_simple_literal("foo"),
+    ///     GraphNameRef::DefaultGraph,
+    /// ))?;
+    ///
+    /// let removed = store.set_object(
+    ///     ex,
+    ///     name,
+    ///     [Literal::new_simple_literal("bar")],
+    ///     GraphNameRef::DefaultGraph,
+    /// )?;
+    /// assert_eq!(removed, [Literal::new_simple_literal("foo").into()]);
+    /// assert!(store.contains(QuadRef::new(
+    ///     ex,
+    ///     name,
+    ///     LiteralRef::new_simple_literal("bar"),
+    ///     GraphNameRef::DefaultGraph
+    /// ))?);
+    /// assert_eq!(store.len()?, 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn set_object<'a>(
+        &self,
+        subject: impl Into<NamedOrBlankNodeRef<'a>>,
+        predicate: impl Into<NamedNodeRef<'a>>,
+        objects: impl IntoIterator<Item = impl Into<Term>>,
+        graph_name: impl Into<GraphNameRef<'a>>,
+    ) -> Result<Vec<Term>, StorageError> {
+        // The on-disk transactions read from a snapshot so we serialize the calls ourselves
+        let _lock = self
+            .set_object_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut transaction = self.start_transaction()?;
+        let removed = transaction.set_object(subject, predicate, objects, graph_name)?;
+        transaction.commit()?;
+        Ok(removed)
+    }
+
+    /// Dumps the store into a file.
     ///
     /// ```
     /// use oxigraph::io::RdfFormat;
@@ -828,6 +1462,60 @@ impl Store {
         self.storage.snapshot().contains_named_graph(&graph_name)
     }
 
+    /// Returns the last modification of a graph or `None` if the graph has not been modified since the store creation.
+    ///
+    /// The modification counter is increased on commit by every transaction or bulk load writing into the graph,
+    /// making it usable to detect changes without reading the graph content, for example as an HTTP `ETag`.
+    /// Operations on all named graphs like [`clear`](Self::clear) are considered as modifying every named graph.
+    ///
+    /// Modifications are persisted with the store content but are only tracked since this feature exists:
+    /// graphs not written to since then have no modification.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let store = Store::new()?;
+    /// assert_eq!(store.graph_modification(ex)?, None);
+    ///
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// let first = store.graph_modification(ex)?.unwrap();
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// let second = store.graph_modification(ex)?.unwrap();
+    /// assert!(second.counter > first.counter);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn graph_modification<'a>(
+        &self,
+        graph_name: impl Into<GraphNameRef<'a>>,
+    ) -> Result<Option<GraphModification>, StorageError> {
+        self.storage
+            .graph_modification(&EncodedTerm::from(graph_name.into()))
+    }
+
+    /// Returns a number that changes each time the store content is modified.
+    ///
+    /// It allows to cheaply detect that the store has been written to since a previous call, for example to invalidate a cache.
+    /// It is not guaranteed to increase and might also change on writes that do not modify the quads, like [`recompute_statistics`](Self::recompute_statistics).
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let store = Store::new()?;
+    /// let version = store.version();
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// assert_ne!(store.version(), version);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn version(&self) -> u64 {
+        self.storage.version()
+    }
+
     /// Inserts a graph into this store.
     ///
     /// Usage example:
@@ -917,7 +1605,143 @@ impl Store {
         Ok(())
     }
 
-    /// Clears the store.
+    /// Inserts all the quads of the graph `from` into the graph `to`, like the SPARQL [`ADD`](https://www.w3.org/TR/sparql11-update/#add) operation.
+    ///
+    /// The quads are copied inside of the storage without being decoded and the operation is atomic.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{GraphNameRef, NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// store.add_graph_into(ex, GraphNameRef::DefaultGraph)?;
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, ex))?);
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn add_graph_into<'a, 'b>(
+        &self,
+        from: impl Into<GraphNameRef<'a>>,
+        to: impl Into<GraphNameRef<'b>>,
+    ) -> Result<(), StorageError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        transaction.add_graph(from.into(), to.into())?;
+        transaction.commit()
+    }
+
+    /// Replaces the content of the graph `to` by the quads of the graph `from`, like the SPARQL [`COPY`](https://www.w3.org/TR/sparql11-update/#copy) operation.
+    ///
+    /// The quads are copied inside of the storage without being decoded and the operation is atomic.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com/ex")?;
+    /// let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    /// let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, g1))?;
+    /// store.insert(QuadRef::new(ex, ex, g2, g2))?;
+    /// store.copy_graph(g1, g2)?;
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, g1))?);
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, g2))?);
+    /// assert!(!store.contains(QuadRef::new(ex, ex, g2, g2))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn copy_graph<'a, 'b>(
+        &self,
+        from: impl Into<GraphNameRef<'a>>,
+        to: impl Into<GraphNameRef<'b>>,
+    ) -> Result<(), StorageError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        transaction.copy_graph(from.into(), to.into())?;
+        transaction.commit()
+    }
+
+    /// Replaces the content of the graph `to` by the quads of the graph `from` and removes the graph `from`, like the SPARQL [`MOVE`](https://www.w3.org/TR/sparql11-update/#move) operation.
+    ///
+    /// The quads are copied inside of the storage without being decoded and the operation is atomic.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com/ex")?;
+    /// let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    /// let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, g1))?;
+    /// store.move_graph(g1, g2)?;
+    /// assert_eq!(
+    ///     store.quads_for_pattern(None, None, None, None).collect::<Result<Vec<_>, _>>()?,
+    ///     [QuadRef::new(ex, ex, ex, g2).into_owned()]
+    /// );
+    /// assert_eq!(store.named_graphs().count(), 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn move_graph<'a, 'b>(
+        &self,
+        from: impl Into<GraphNameRef<'a>>,
+        to: impl Into<GraphNameRef<'b>>,
+    ) -> Result<(), StorageError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        transaction.move_graph(from.into(), to.into())?;
+        transaction.commit()
+    }
+
+    /// Renames the graph `from` to `to`.
+    ///
+    /// Unlike [`move_graph`](Self::move_graph), it fails with [`RenameGraphError::TargetGraphAlreadyExists`] if the graph `to` already exists,
+    /// except if `overwrite` is set, in which case the previous content of `to` is removed.
+    /// The default graph exists if it is not empty.
+    ///
+    /// Returns `false` and does nothing if the graph `from` does not exist.
+    ///
+    /// The operation is atomic.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::{NamedNodeRef, QuadRef};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com/ex")?;
+    /// let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    /// let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, g1))?;
+    /// assert!(store.rename_graph(g1, g2, false)?);
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, g2))?);
+    /// assert!(!store.contains_named_graph(g1)?);
+    ///
+    /// store.insert_named_graph(g1)?;
+    /// assert!(store.rename_graph(g2, g1, false).is_err());
+    /// assert!(store.rename_graph(g2, g1, true)?);
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, g1))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn rename_graph<'a, 'b>(
+        &self,
+        from: impl Into<GraphNameRef<'a>>,
+        to: impl Into<GraphNameRef<'b>>,
+        overwrite: bool,
+    ) -> Result<bool, RenameGraphError> {
+        let mut transaction = self.storage.start_readable_transaction()?;
+        let renamed = transaction.rename_graph(from.into(), to.into(), overwrite)?;
+        transaction.commit()?;
+        Ok(renamed)
+    }
+
+    /// Clears the store and returns the number of removed quads.
+    ///
+    /// On disk, the quads are removed with range deletions and the affected column families are then compacted to reclaim their space.
+    /// Use [`clear_with_background_reclaim`](Self::clear_with_background_reclaim) to not wait for the compaction.
+    /// The terms are kept on disk.
     ///
     /// Usage example:
     /// ```
@@ -930,14 +1754,127 @@ impl Store {
     /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
     /// assert_eq!(2, store.len()?);
     ///
-    /// store.clear()?;
+    /// let report = store.clear()?;
+    /// assert_eq!(report.quads_removed, 2);
     /// assert!(store.is_empty()?);
     /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
     /// ```
-    pub fn clear(&self) -> Result<(), StorageError> {
+    pub fn clear(&self) -> Result<ClearReport, StorageError> {
+        let report = self.clear_without_reclaim()?;
+        #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+        self.storage.reclaim_cleared_space()?;
+        Ok(report)
+    }
+
+    /// Clears the store like [`clear`](Self::clear) but compacts the disk storage in a background thread.
+    ///
+    /// The returned [`ReclaimHandle`] allows to wait for the end of the compaction.
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn clear_with_background_reclaim(&self) -> Result<ReclaimHandle, StorageError> {
+        let report = self.clear_without_reclaim()?;
+        let storage = self.storage.clone();
+        let thread = thread::Builder::new()
+            .name("Oxigraph space reclaim".into())
+            .spawn(move || storage.reclaim_cleared_space())?;
+        Ok(ReclaimHandle { report, thread })
+    }
+
+    fn clear_without_reclaim(&self) -> Result<ClearReport, StorageError> {
+        // Quads inserted meanwhile by other threads are removed but not counted
+        let quads_removed = self.storage.snapshot().len()?;
+        #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+        let bytes_to_reclaim = self.storage.quad_data_size();
+        #[cfg(not(all(not(target_family = "wasm"), feature = "rocksdb")))]
+        let bytes_to_reclaim = 0;
         let mut transaction = self.storage.start_transaction()?;
         transaction.clear();
-        transaction.commit()
+        transaction.commit()?;
+        Ok(ClearReport {
+            quads_removed,
+            bytes_to_reclaim,
+        })
+    }
+
+    /// Starts recording the quads added and removed by each transaction into the history graph set in `config`.
+    ///
+    /// Each change is recorded in the same transaction as the change itself, as a reified statement
+    /// with its commit time (`prov:generatedAtTime`) and a transaction identifier.
+    /// A [bulk load](Self::bulk_loader) is only recorded as a single event without the loaded quads.
+    /// Only the changes done after this call are recorded and the recording must be enabled again each time the store is opened.
+    ///
+    /// <div class="warning">This roughly multiplies the written data volume by seven.</div>
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::{HistoryConfig, HistoryEventKind, Store};
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    /// let store = Store::new()?;
+    /// store.enable_history(HistoryConfig::new(NamedNode::new("http://example.com/history")?))?;
+    /// store.insert(quad)?;
+    /// let addition = &store.history_for_quad(quad)?[0];
+    /// assert_eq!(addition.kind, HistoryEventKind::Addition);
+    /// assert!(store.graph_at(GraphNameRef::DefaultGraph, addition.timestamp)?.contains(quad));
+    ///
+    /// store.remove(quad)?;
+    /// let removal = &store.history_for_quad(quad)?[1];
+    /// assert_eq!(removal.kind, HistoryEventKind::Removal);
+    /// assert!(store.graph_at(GraphNameRef::DefaultGraph, removal.timestamp)?.is_empty());
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[cfg(feature = "history")]
+    pub fn enable_history(&self, config: HistoryConfig) -> Result<(), StorageError> {
+        self.storage.enable_history(config)
+    }
+
+    /// Stops recording the history started with [`enable_history`](Self::enable_history).
+    ///
+    /// The already recorded history is kept.
+    #[cfg(feature = "history")]
+    pub fn disable_history(&self) {
+        self.storage.disable_history()
+    }
+
+    /// Returns the recorded additions and removals of the given quad, ordered by transaction.
+    ///
+    /// Returns an empty list if the history is not [enabled](Self::enable_history).
+    #[cfg(feature = "history")]
+    pub fn history_for_quad<'a>(
+        &self,
+        quad: impl Into<QuadRef<'a>>,
+    ) -> Result<Vec<HistoryEvent>, StorageError> {
+        let Some(recorder) = self.storage.history_recorder() else {
+            return Ok(Vec::new());
+        };
+        crate::storage::history::history_for_quad(
+            &self.storage.snapshot(),
+            recorder.graph(),
+            quad.into(),
+        )
+    }
+
+    /// Rebuilds the content of a graph at the given time by replaying its recorded history.
+    ///
+    /// Only the changes recorded since the history is [enabled](Self::enable_history) are replayed:
+    /// the quads already present before and the ones added by bulk loads are not part of the result.
+    /// Returns an empty graph if the history is not enabled.
+    #[cfg(feature = "history")]
+    pub fn graph_at<'a>(
+        &self,
+        graph_name: impl Into<GraphNameRef<'a>>,
+        timestamp: DateTime,
+    ) -> Result<Graph, StorageError> {
+        let Some(recorder) = self.storage.history_recorder() else {
+            return Ok(Graph::new());
+        };
+        crate::storage::history::graph_at(
+            &self.storage.snapshot(),
+            recorder.graph(),
+            graph_name.into(),
+            &timestamp,
+        )
     }
 
     /// Flushes all buffers and ensures that all writes are saved on disk.
@@ -948,97 +1885,1145 @@ impl Store {
         self.storage.flush()
     }
 
-    /// Optimizes the database for future workload.
-    ///
-    /// Useful to call after a batch upload or another similar operation.
-    ///
-    /// <div class="warning">Can take hours on huge databases.</div>
-    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-    pub fn optimize(&self) -> Result<(), StorageError> {
-        self.storage.compact()
+    /// Optimizes the database for future workload.
+    ///
+    /// Useful to call after a batch upload or another similar operation.
+    ///
+    /// <div class="warning">Can take hours on huge databases.</div>
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn optimize(&self) -> Result<(), StorageError> {
+        self.storage.compact()?;
+        self.recompute_statistics()
+    }
+
+    /// Rewrites all the data on disk with a full compaction and returns the size of the store before and after it.
+    ///
+    /// The rewritten data uses the current [`StoreOptions`], e.g. a newly set compression algorithm.
+    /// The in-memory buffers are flushed first so that they are taken into account in both sizes.
+    ///
+    /// <div class="warning">Can take hours on huge databases.</div>
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn compact(&self) -> Result<CompactionReport, StorageError> {
+        self.storage.flush()?;
+        let size_before = self.disk_size();
+        self.storage.full_compact()?;
+        Ok(CompactionReport {
+            size_before,
+            size_after: self.disk_size(),
+        })
+    }
+
+    /// Returns the estimated size of the live data on disk and the size of the store directory.
+    ///
+    /// The difference is made of deleted or overwritten entries not compacted yet, of the write-ahead log and of the logs.
+    /// If it is large, [`compact`](Self::compact) reclaims the space of the deleted entries.
+    /// The in-memory buffers are not counted.
+    ///
+    /// Both sizes are `0` for in-memory stores.
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn disk_usage(&self) -> Result<DiskUsage, StorageError> {
+        let (live_data_size, total_size) = self.storage.disk_usage()?;
+        Ok(DiskUsage {
+            live_data_size,
+            total_size,
+        })
+    }
+
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    fn disk_size(&self) -> u64 {
+        self.storage
+            .column_family_sizes()
+            .into_iter()
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    /// Computes the statistics used by the SPARQL query optimizer to pick the order in which triple patterns are joined.
+    ///
+    /// They contain the number of triples, and for each predicate the number of triples and of distinct subjects and objects.
+    /// Until they are computed, the optimizer relies on heuristics based on which terms of the triple patterns are bound.
+    /// They are not updated when the store is modified: call this method again after big changes.
+    /// On-disk stores persist them so that they are available when the store is opened again.
+    ///
+    /// They are also recomputed by [`optimize`](Self::optimize).
+    ///
+    /// <div class="warning">This requires a full scan of the store.</div>
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// store.recompute_statistics()?;
+    /// if let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+    ///     .parse_query("SELECT * WHERE { ?s <http://example.com> ?o . ?o <http://example.com/p> ?o2 }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     assert_eq!(solutions.count(), 0);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn recompute_statistics(&self) -> Result<(), StorageError> {
+        let reader = self.storage.snapshot();
+        let mut triple_count = 0;
+        let mut predicates = HashMap::<EncodedTerm, PredicateCounter>::new();
+        for quad in reader.quads_for_pattern(None, None, None, None) {
+            let quad = quad?;
+            triple_count += 1;
+            let counter = predicates.entry(quad.predicate).or_default();
+            counter.triple_count += 1;
+            counter.subjects.add(&quad.subject);
+            counter.objects.add(&quad.object);
+        }
+        let statistics = QueryStatistics {
+            triple_count,
+            predicates: Arc::new(
+                predicates
+                    .into_iter()
+                    .map(|(predicate, counter)| {
+                        (
+                            predicate,
+                            PredicateStatistics {
+                                triple_count: counter.triple_count,
+                                // The estimates might be a bit bigger than the triple count
+                                distinct_subjects: min(
+                                    counter.subjects.estimate(),
+                                    counter.triple_count,
+                                ),
+                                distinct_objects: min(
+                                    counter.objects.estimate(),
+                                    counter.triple_count,
+                                ),
+                            },
+                        )
+                    })
+                    .collect(),
+            ),
+        };
+        self.storage.set_query_statistics(&statistics)?;
+        if let Ok(mut cache) = self.query_statistics.write() {
+            *cache = Some(statistics);
+        }
+        Ok(())
+    }
+
+    /// Creates database backup into the `target_directory`.
+    ///
+    /// After its creation, the backup is usable using [`Store::open`]
+    /// like a regular Oxigraph database and operates independently from the original database.
+    ///
+    /// <div class="warning">
+    ///
+    /// Backups are only possible for on-disk databases created using [`Store::open`].</div>
+    /// Temporary in-memory databases created using [`Store::new`] are not compatible with RocksDB backup system.
+    ///
+    /// <div class="warning">An error is raised if the `target_directory` already exists.</div>
+    ///
+    /// If the target directory is in the same file system as the current database,
+    /// the database content will not be fully copied
+    /// but hard links will be used to point to the original database immutable snapshots.
+    /// This allows cheap regular backups.
+    ///
+    /// If you want to move your data to another RDF storage system, you should have a look at the [`Store::dump_to_writer`] function instead.
+    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+    pub fn backup(&self, target_directory: impl AsRef<Path>) -> Result<(), StorageError> {
+        self.storage.backup(target_directory.as_ref())
+    }
+
+    /// Returns the namespaces registered in the store, sorted by prefix.
+    ///
+    /// Namespaces are a persisted mapping from prefixes to IRIs that might be used when serializing RDF.
+    /// They are not part of the RDF dataset: transactions, SPARQL updates and [`clear`](Self::clear) do not change them.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::NamedNode;
+    /// use oxigraph::store::Store;
+    ///
+    /// let schema = NamedNode::new("http://schema.org/")?;
+    /// let store = Store::new()?;
+    /// store.set_namespace("schema", &schema)?;
+    /// assert_eq!(
+    ///     store.namespaces()?.collect::<Vec<_>>(),
+    ///     [("schema".to_owned(), schema)]
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn namespaces(
+        &self,
+    ) -> Result<impl Iterator<Item = (String, NamedNode)> + use<>, StorageError> {
+        Ok(self
+            .storage
+            .namespaces()?
+            .into_iter()
+            .map(|(prefix, namespace)| (prefix, NamedNode::new_unchecked(namespace))))
+    }
+
+    /// Registers a namespace in the store, replacing the previous one with the same prefix if it exists.
+    ///
+    /// The prefix must be a valid [Turtle `PN_PREFIX`](https://www.w3.org/TR/turtle/#grammar-production-PN_PREFIX) or the empty string,
+    /// otherwise [`SetNamespaceError::InvalidPrefix`] is returned.
+    ///
+    /// See [`namespaces`](Self::namespaces) for more details.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::NamedNodeRef;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// store.set_namespace("ex", NamedNodeRef::new("http://example.com/")?)?;
+    /// assert_eq!(store.namespaces()?.count(), 1);
+    /// assert!(
+    ///     store
+    ///         .set_namespace("not a prefix", NamedNodeRef::new("http://example.com/")?)
+    ///         .is_err()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn set_namespace<'a>(
+        &self,
+        prefix: &str,
+        namespace: impl Into<NamedNodeRef<'a>>,
+    ) -> Result<(), SetNamespaceError> {
+        if !is_valid_prefix_name(prefix) {
+            return Err(SetNamespaceError::InvalidPrefix(prefix.into()));
+        }
+        Ok(self
+            .storage
+            .set_namespace(prefix, namespace.into().as_str())?)
+    }
+
+    /// Removes the namespace with the given prefix from the store if it exists.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::NamedNodeRef;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// store.set_namespace("ex", NamedNodeRef::new("http://example.com/")?)?;
+    /// store.remove_namespace("ex")?;
+    /// assert_eq!(store.namespaces()?.count(), 0);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn remove_namespace(&self, prefix: &str) -> Result<(), StorageError> {
+        self.storage.remove_namespace(prefix)
+    }
+
+    /// Creates a bulk loader allowing to load at a lot of data quickly into the store.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    ///
+    /// // quads file insertion
+    /// let file =
+    ///     "<http://example.com> <http://example.com> <http://example.com> <http://example.com> .";
+    /// let mut loader = store.bulk_loader();
+    /// loader.load_from_slice(RdfFormat::NQuads, file)?;
+    /// loader.commit()?;
+    ///
+    /// // we inspect the store contents
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, ex))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn bulk_loader(&self) -> BulkLoader<'_> {
+        BulkLoader {
+            storage: self.storage.bulk_loader(),
+            namespace_storage: &self.storage,
+            namespaces: None,
+            num_threads: None,
+            max_memory_size: None,
+            batch_size: None,
+            on_parse_error: None,
+            graph_mapper: None,
+            provenance: None,
+            #[cfg(not(target_family = "wasm"))]
+            checkpoint: None,
+            #[cfg(not(target_family = "wasm"))]
+            checkpoint_interval: (
+                DEFAULT_BULK_LOAD_CHECKPOINT_QUADS,
+                DEFAULT_BULK_LOAD_CHECKPOINT_DURATION,
+            ),
+        }
+    }
+
+    /// Returns statistics about the store content: number of quads per graph, number of distinct terms...
+    ///
+    /// If `approximate` is `true` and the store is backed by RocksDB, the counts are estimated from the RocksDB metadata
+    /// without scanning the data and the distinct term counts are not computed.
+    /// Otherwise, a full scan of the store is done, and its result is cached until the next store modification.
+    /// The distinct term counts are estimated using [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog)
+    /// and might be a few percent off on big stores.
+    ///
+    /// <div class="warning">Exact statistics require a full scan.</div>
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// let stats = store.stats(false)?;
+    /// assert_eq!(stats.quads, 2);
+    /// assert_eq!(stats.named_graphs, 1);
+    /// assert_eq!(
+    ///     stats.graph_quads,
+    ///     vec![(GraphName::DefaultGraph, 1), (ex.into_owned().into(), 1)]
+    /// );
+    /// assert_eq!(stats.distinct_subjects, Some(1));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn stats(&self, approximate: bool) -> Result<StoreStats, StorageError> {
+        if approximate {
+            if let Some(graph_sizes) = self.storage.approximate_graph_sizes()? {
+                let reader = self.storage.snapshot();
+                let graph_quads = graph_sizes
+                    .into_iter()
+                    .map(|(graph_name, count)| {
+                        Ok((decode_graph_name(&reader, &graph_name)?, count))
+                    })
+                    .collect::<Result<Vec<_>, StorageError>>()?;
+                return Ok(StoreStats::new(
+                    graph_quads,
+                    [None, None, None],
+                    StoreStats::column_family_sizes(&self.storage),
+                ));
+            }
+        }
+
+        // We read the version before the snapshot to never cache stale statistics with a newer version
+        let version = self.storage.version();
+        if let Ok(cache) = self.stats_cache.lock() {
+            if let Some((cached_version, stats)) = &*cache {
+                if *cached_version == version {
+                    let mut stats = stats.clone();
+                    stats.column_family_sizes = StoreStats::column_family_sizes(&self.storage);
+                    return Ok(stats);
+                }
+            }
+        }
+
+        let reader = self.storage.snapshot();
+        let mut graph_sizes = HashMap::<EncodedTerm, usize>::new();
+        let mut subjects = DistinctCounter::default();
+        let mut predicates = DistinctCounter::default();
+        let mut objects = DistinctCounter::default();
+        for quad in reader.quads_for_pattern(None, None, None, None) {
+            let quad = quad?;
+            subjects.add(&quad.subject);
+            predicates.add(&quad.predicate);
+            objects.add(&quad.object);
+            *graph_sizes.entry(quad.graph_name).or_default() += 1;
+        }
+        let mut graph_quads = vec![(
+            GraphName::DefaultGraph,
+            graph_sizes
+                .get(&EncodedTerm::DefaultGraph)
+                .copied()
+                .unwrap_or(0),
+        )];
+        for graph_name in reader.named_graphs() {
+            let graph_name = graph_name?;
+            let count = graph_sizes.get(&graph_name).copied().unwrap_or(0);
+            graph_quads.push((decode_graph_name(&reader, &graph_name)?, count));
+        }
+        let stats = StoreStats::new(
+            graph_quads,
+            [
+                Some(subjects.estimate()),
+                Some(predicates.estimate()),
+                Some(objects.estimate()),
+            ],
+            StoreStats::column_family_sizes(&self.storage),
+        );
+        if let Ok(mut cache) = self.stats_cache.lock() {
+            *cache = Some((version, stats.clone()));
+        }
+        Ok(stats)
+    }
+
+    /// Scans the store and returns the terms that would not pass a strict validation:
+    /// IRIs that are not valid according to [RFC 3987](https://www.ietf.org/rfc/rfc3987.html),
+    /// invalid [BCP 47](https://tools.ietf.org/html/bcp47) language tags
+    /// and literals whose lexical form is not valid for their datatype.
+    ///
+    /// Such terms might have been inserted using [lenient parsing](RdfParser::lenient) or unchecked constructors.
+    /// They can be fixed on dump using [`RdfSerializer::with_invalid_iri_handling`](crate::io::RdfSerializer::with_invalid_iri_handling).
+    ///
+    /// <div class="warning">This function does a full scan.</div>
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::{RdfFormat, RdfParser};
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::{InvalidTermKind, Store};
+    ///
+    /// let store = Store::new()?;
+    /// store.load_from_slice(
+    ///     RdfParser::from_format(RdfFormat::NTriples).lenient(),
+    ///     "<http://example.com/a b> <http://example.com/p> \"foo\"^^<http://www.w3.org/2001/XMLSchema#integer> .",
+    /// )?;
+    /// let report = store.validate_terms()?;
+    /// assert_eq!(report.iris, 1);
+    /// assert_eq!(report.literals, 1);
+    /// assert!(report.terms.contains(&(
+    ///     NamedNode::new_unchecked("http://example.com/a b").into(),
+    ///     InvalidTermKind::Iri
+    /// )));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn validate_terms(&self) -> Result<TermValidationReport, StorageError> {
+        let reader = self.storage.snapshot();
+        let mut seen = HashSet::new();
+        let mut report = TermValidationReport::default();
+        for quad in reader.quads_for_pattern(None, None, None, None) {
+            let quad = quad?;
+            for term in [quad.subject, quad.predicate, quad.object, quad.graph_name] {
+                if term.is_default_graph() || !seen.insert(term.clone()) {
+                    continue;
+                }
+                report.check(reader.decode_term(&term)?);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Normalizes all the IRIs of the store according to the given policy.
+    ///
+    /// It rewrites the quads containing an IRI that is not normalized, including in literal datatypes and triple terms,
+    /// and renames the named graphs.
+    /// The quads that become equal to an already existing quad are merged with it.
+    /// See [`IriNormalizationPolicy`] for the available normalizations.
+    ///
+    /// <div class="warning">This function does a full scan and uses a memory heavy transaction internally.</div>
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.org/b")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    /// let denormalized = NamedNodeRef::new("HTTP://Example.org/a/../b")?;
+    /// store.insert(QuadRef::new(denormalized, ex, ex, GraphNameRef::DefaultGraph))?;
+    ///
+    /// let report = store.normalize_iris(IriNormalizationPolicy::Full)?;
+    /// assert_eq!(report.terms, 1);
+    /// assert_eq!(report.quads, 1);
+    /// assert_eq!(report.merged_quads, 1);
+    /// assert_eq!(store.len()?, 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn normalize_iris(
+        &self,
+        policy: IriNormalizationPolicy,
+    ) -> Result<IriNormalizationReport, StorageError> {
+        let reader = self.storage.snapshot();
+        let mut report = IriNormalizationReport::default();
+        // For each term, if it changes when normalized
+        let mut changing_terms = HashMap::new();
+        let mut quads = Vec::new();
+        for quad in reader.quads_for_pattern(None, None, None, None) {
+            let quad = quad?;
+            let mut changes = false;
+            for term in [
+                &quad.subject,
+                &quad.predicate,
+                &quad.object,
+                &quad.graph_name,
+            ] {
+                if term.is_default_graph() {
+                    continue;
+                }
+                if let Some(term_changes) = changing_terms.get(term) {
+                    changes |= *term_changes;
+                    continue;
+                }
+                let decoded = reader.decode_term(term)?;
+                let term_changes = policy.normalize_term(decoded.as_ref()) != decoded;
+                if term_changes {
+                    report.terms += 1;
+                }
+                changing_terms.insert(term.clone(), term_changes);
+                changes |= term_changes;
+            }
+            if changes {
+                quads.push(reader.decode_quad(&quad)?);
+            }
+        }
+        let mut graph_names = Vec::new();
+        for encoded_graph_name in reader.named_graphs() {
+            let encoded_graph_name = encoded_graph_name?;
+            if let NamedOrBlankNode::NamedNode(graph_name) =
+                reader.decode_named_or_blank_node(&encoded_graph_name)?
+            {
+                let normalized = graph_name.normalized(policy);
+                if normalized != graph_name {
+                    if !changing_terms.contains_key(&encoded_graph_name) {
+                        // Empty graph
+                        report.terms += 1;
+                    }
+                    graph_names.push((graph_name, normalized));
+                }
+            }
+        }
+
+        let mut transaction = self.start_transaction()?;
+        for quad in &quads {
+            transaction.remove(quad);
+        }
+        for quad in quads {
+            let normalized = policy.normalize_quad(quad.as_ref());
+            report.quads += 1;
+            if transaction.contains(&normalized)? {
+                report.merged_quads += 1;
+            } else {
+                transaction.insert(&normalized);
+            }
+        }
+        for (graph_name, normalized) in graph_names {
+            transaction.insert_named_graph(&normalized);
+            transaction.remove_named_graph(&graph_name)?;
+        }
+        transaction.commit()?;
+        Ok(report)
+    }
+
+    /// Sets the graphs used as the default graph by the SPARQL queries that do not set their dataset
+    /// with `FROM` clauses or [`PreparedSparqlQuery::dataset_mut`](crate::sparql::PreparedSparqlQuery::dataset_mut).
+    ///
+    /// The setting is persisted by on-disk stores.
+    /// It is overridden by [`SparqlEvaluator::with_default_graph_mode`]
+    /// and it is not used by updates and by queries evaluated inside of [transactions](Self::start_transaction).
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{DefaultGraphMode, QueryResults, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// store.set_default_query_dataset(DefaultGraphMode::Union)?;
+    /// assert_eq!(store.default_query_dataset(), DefaultGraphMode::Union);
+    /// if let QueryResults::Boolean(result) = SparqlEvaluator::new()
+    ///     .parse_query("ASK { ?s ?p ?o }")?
+    ///     .on_store(&store)
+    ///     .execute()?
+    /// {
+    ///     assert!(result); // The triple in the named graph is found
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn set_default_query_dataset(&self, mode: DefaultGraphMode) -> Result<(), StorageError> {
+        self.storage
+            .set_default_graph_as_union(mode == DefaultGraphMode::Union)?;
+        if let Ok(mut default_graph_mode) = self.default_graph_mode.write() {
+            *default_graph_mode = mode;
+        }
+        Ok(())
+    }
+
+    /// Returns the graphs used as the default graph by the SPARQL queries that do not set their dataset.
+    ///
+    /// See [`set_default_query_dataset`](Self::set_default_query_dataset).
+    pub fn default_query_dataset(&self) -> DefaultGraphMode {
+        self.default_graph_mode
+            .read()
+            .map(|mode| *mode)
+            .unwrap_or_default()
+    }
+
+    /// Subscribes to the changes of the solutions of a SPARQL `SELECT` query.
+    ///
+    /// The query is evaluated once and `callback` is called with all its solutions as added solutions.
+    /// Then, after each committed transaction, the query is evaluated again
+    /// and `callback` is called with the added and removed solutions if there are some.
+    /// See [`QuerySubscriptionOptions`] for how the useless evaluations are skipped.
+    ///
+    /// The callback is called by the thread committing the transaction, after the commit.
+    /// It gets an error if the evaluation of the query failed.
+    ///
+    /// The subscription lasts until the returned [`QuerySubscription`] is dropped.
+    /// At most [`set_max_query_subscriptions`](Self::set_max_query_subscriptions) queries might be subscribed at the same time, 64 by default.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::sparql::{QuerySubscriptionOptions, SparqlEvaluator};
+    /// use oxigraph::store::Store;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let store = Store::new()?;
+    /// let added = Arc::new(Mutex::new(Vec::new()));
+    /// let subscription = store.subscribe_query(
+    ///     SparqlEvaluator::new().parse_query("SELECT ?s WHERE { ?s a <http://example.com/Person> }")?,
+    ///     QuerySubscriptionOptions::new(),
+    ///     {
+    ///         let added = Arc::clone(&added);
+    ///         move |change| {
+    ///             if let Ok(change) = change {
+    ///                 added.lock().unwrap().extend(change.added().iter().cloned());
+    ///             }
+    ///         }
+    ///     },
+    /// )?;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com/alice")?;
+    /// store.insert(QuadRef::new(
+    ///     ex,
+    ///     vocab::rdf::TYPE,
+    ///     NamedNodeRef::new("http://example.com/Person")?,
+    ///     GraphNameRef::DefaultGraph,
+    /// ))?;
+    /// assert_eq!(added.lock().unwrap()[0].get("s"), Some(&ex.into()));
+    /// subscription.unsubscribe();
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn subscribe_query(
+        &self,
+        query: PreparedSparqlQuery,
+        options: QuerySubscriptionOptions,
+        callback: impl Fn(Result<QuerySolutionsChange, QueryEvaluationError>) + Send + Sync + 'static,
+    ) -> Result<QuerySubscription, QuerySubscriptionError> {
+        QuerySubscription::start(
+            self,
+            query,
+            options,
+            Box::new(callback),
+            self.max_query_subscriptions.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Sets the maximal number of queries subscribed at the same time with [`subscribe_query`](Self::subscribe_query).
+    ///
+    /// The existing subscriptions are kept even if there are more of them.
+    pub fn set_max_query_subscriptions(&self, max_subscriptions: usize) {
+        self.max_query_subscriptions
+            .store(max_subscriptions, Ordering::Relaxed);
+    }
+
+    /// Validate that all the store invariants held in the data
+    #[doc(hidden)]
+    pub fn validate(&self) -> Result<(), StorageError> {
+        self.storage.snapshot().validate()
+    }
+
+    pub(super) fn storage(&self) -> &Storage {
+        &self.storage
+    }
+
+    /// The statistics computed by [`recompute_statistics`](Self::recompute_statistics) if any
+    pub(super) fn query_statistics(&self) -> Option<QueryStatistics> {
+        self.query_statistics.read().ok()?.clone()
+    }
+}
+
+const DEFAULT_MAX_QUERY_SUBSCRIPTIONS: usize = 64;
+
+impl fmt::Display for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for t in self {
+            writeln!(f, "{} .", t.map_err(|_| fmt::Error)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for &Store {
+    type IntoIter = QuadIter<'static>;
+    type Item = Result<Quad, StorageError>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A read-only union of several [`Store`]s that can be queried with SPARQL without copying their content.
+///
+/// Each member store might be mapped under a graph name prefix:
+/// its named graphs are then exposed with the prefix prepended to their IRI (blank node graph names are kept as is).
+/// The default graph of the federation is the union of the members default graphs.
+/// The quads contained in several members are only returned once.
+///
+/// A snapshot of each member is taken at the beginning of the query evaluation:
+/// the query does not see the changes done to the members during its evaluation.
+/// Writes are not supported: the member stores must be modified directly.
+///
+/// Usage example joining two stores:
+/// ```
+/// use oxigraph::model::*;
+/// use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+/// use oxigraph::store::{FederatedStore, Store};
+///
+/// let ex = NamedNodeRef::new("http://example.com/")?;
+/// let name = NamedNodeRef::new("http://schema.org/name")?;
+/// let age = NamedNodeRef::new("http://schema.org/age")?;
+/// let graph = NamedNodeRef::new("http://example.com/g")?;
+///
+/// let names = Store::new()?;
+/// names.insert(QuadRef::new(ex, name, LiteralRef::from("Alice"), graph))?;
+/// let ages = Store::new()?;
+/// ages.insert(QuadRef::new(ex, age, &Literal::from(42), graph))?;
+///
+/// let federation = FederatedStore::new()
+///     .with_store(names)
+///     .with_store_under_prefix(ages, "http://ages.example.com/")?;
+/// if let QueryResults::Solutions(mut solutions) = federation.query_opt(
+///     "SELECT ?name ?age ?g WHERE { GRAPH <http://example.com/g> { ?s <http://schema.org/name> ?name } GRAPH ?g { ?s <http://schema.org/age> ?age } }",
+///     SparqlEvaluator::new(),
+/// )? {
+///     let solution = solutions.next().unwrap()?;
+///     assert_eq!(solution.get("name"), Some(&Literal::from("Alice").into()));
+///     assert_eq!(solution.get("age"), Some(&Literal::from(42).into()));
+///     assert_eq!(
+///         solution.get("g"),
+///         Some(&NamedNode::new("http://ages.example.com/http://example.com/g")?.into())
+///     );
+/// }
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Clone, Default)]
+pub struct FederatedStore {
+    members: Vec<(Store, Option<String>)>,
+}
+
+impl FederatedStore {
+    /// Builds a new federation without any member.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a member store whose graph names are kept as is.
+    #[inline]
+    #[must_use]
+    pub fn with_store(mut self, store: Store) -> Self {
+        self.members.push((store, None));
+        self
+    }
+
+    /// Adds a member store whose named graphs are exposed with `graph_name_prefix` prepended to their IRI.
+    ///
+    /// For example, with the prefix `http://example.com/source/`, the graph `<http://example.com/g>`
+    /// of the store is exposed as `<http://example.com/source/http://example.com/g>`.
+    #[inline]
+    pub fn with_store_under_prefix(
+        mut self,
+        store: Store,
+        graph_name_prefix: impl Into<String>,
+    ) -> Result<Self, IriParseError> {
+        self.members.push((
+            store,
+            Some(Iri::parse(graph_name_prefix.into())?.into_inner()),
+        ));
+        Ok(self)
+    }
+
+    /// Parses and executes a [SPARQL 1.1 query](https://www.w3.org/TR/sparql11-query/) with some options against the union of the member stores.
+    ///
+    /// It is a shortcut for [`SparqlEvaluator::parse_query`] followed by [`PreparedSparqlQuery::on_federated_store`].
+    pub fn query_opt(
+        &self,
+        query: &(impl AsRef<str> + ?Sized),
+        options: SparqlEvaluator,
+    ) -> Result<QueryResults<'static>, QueryEvaluationError> {
+        options
+            .parse_query(query)?
+            .on_federated_store(self)
+            .execute()
+    }
+
+    pub(crate) fn members(&self) -> &[(Store, Option<String>)] {
+        &self.members
+    }
+}
+
+/// The sizes on disk before and after [`Store::compact`].
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CompactionReport {
+    /// The size on disk in bytes before the compaction.
+    pub size_before: u64,
+    /// The size on disk in bytes after the compaction.
+    pub size_after: u64,
+}
+
+/// The result of [`Store::clear`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ClearReport {
+    /// The number of removed quads.
+    pub quads_removed: usize,
+    /// The estimated size on disk in bytes of the removed quads, reclaimed by the compaction that follows the clearing.
+    ///
+    /// The in-memory buffers are not counted and it is always `0` for in-memory stores.
+    pub bytes_to_reclaim: u64,
+}
+
+/// A compaction reclaiming the disk space after [`Store::clear_with_background_reclaim`].
+///
+/// The compaction goes on if the handle is dropped.
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+pub struct ReclaimHandle {
+    report: ClearReport,
+    thread: thread::JoinHandle<Result<(), StorageError>>,
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+impl ReclaimHandle {
+    /// The result of the clearing.
+    #[inline]
+    pub fn report(&self) -> &ClearReport {
+        &self.report
+    }
+
+    /// Checks if the compaction is done.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Waits for the end of the compaction.
+    pub fn wait(self) -> Result<(), StorageError> {
+        map_thread_result(self.thread.join())?
+    }
+}
+
+/// The disk usage of a [`Store`] returned by [`Store::disk_usage`].
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiskUsage {
+    /// The estimated size in bytes of the data that is neither deleted nor overwritten.
+    pub live_data_size: u64,
+    /// The size in bytes of all the files in the store directory.
+    pub total_size: u64,
+}
+
+/// Statistics about the content of a [`Store`] returned by [`Store::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StoreStats {
+    /// The number of quads in the store.
+    pub quads: usize,
+    /// The number of named graphs in the store.
+    pub named_graphs: usize,
+    /// The number of quads in each graph, starting with the default graph and then sorted by graph name.
+    pub graph_quads: Vec<(GraphName, usize)>,
+    /// The number of distinct subjects, `None` if approximate statistics have been requested.
+    pub distinct_subjects: Option<usize>,
+    /// The number of distinct predicates, `None` if approximate statistics have been requested.
+    pub distinct_predicates: Option<usize>,
+    /// The number of distinct objects, `None` if approximate statistics have been requested.
+    pub distinct_objects: Option<usize>,
+    /// The size on disk in bytes of each RocksDB column family, empty for in-memory stores.
+    pub column_family_sizes: Vec<(String, u64)>,
+}
+
+impl StoreStats {
+    fn new(
+        mut graph_quads: Vec<(GraphName, usize)>,
+        [distinct_subjects, distinct_predicates, distinct_objects]: [Option<usize>; 3],
+        column_family_sizes: Vec<(String, u64)>,
+    ) -> Self {
+        // The storage order is based on hashes, we sort by graph name to get a stable output
+        graph_quads.sort_by_cached_key(|(graph_name, _)| {
+            (!graph_name.is_default_graph(), graph_name.to_string())
+        });
+        Self {
+            quads: graph_quads.iter().map(|(_, count)| count).sum(),
+            named_graphs: graph_quads
+                .iter()
+                .filter(|(graph_name, _)| !graph_name.is_default_graph())
+                .count(),
+            graph_quads,
+            distinct_subjects,
+            distinct_predicates,
+            distinct_objects,
+            column_family_sizes,
+        }
+    }
+
+    fn column_family_sizes(storage: &Storage) -> Vec<(String, u64)> {
+        storage
+            .column_family_sizes()
+            .into_iter()
+            .map(|(name, size)| (name.into(), size))
+            .collect()
+    }
+}
+
+/// Outcome of [`Store::validate_terms`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TermValidationReport {
+    /// The distinct invalid terms with the reason why they are invalid.
+    pub terms: Vec<(Term, InvalidTermKind)>,
+    /// The number of distinct terms that are or contain an invalid IRI.
+    pub iris: usize,
+    /// The number of distinct literals with an invalid language tag.
+    pub language_tags: usize,
+    /// The number of distinct literals whose lexical form is not valid for their datatype.
+    pub literals: usize,
+}
+
+impl TermValidationReport {
+    fn check(&mut self, term: Term) {
+        let Some(kind) = invalid_term_kind(term.as_ref()) else {
+            return;
+        };
+        match kind {
+            InvalidTermKind::Iri => self.iris += 1,
+            InvalidTermKind::LanguageTag => self.language_tags += 1,
+            InvalidTermKind::Literal => self.literals += 1,
+        }
+        self.terms.push((term, kind));
+    }
+
+    /// Returns `true` if no invalid term has been found.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+/// Outcome of [`Store::normalize_iris`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IriNormalizationReport {
+    /// The number of distinct terms that have been changed by the normalization.
+    pub terms: usize,
+    /// The number of quads that have been rewritten.
+    pub quads: usize,
+    /// The number of rewritten quads that were merged with an already existing quad.
+    pub merged_quads: usize,
+}
+
+/// Why a term has been reported by [`Store::validate_terms`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidTermKind {
+    /// The term is or contains an IRI that is not valid according to [RFC 3987](https://www.ietf.org/rfc/rfc3987.html).
+    Iri,
+    /// The literal language tag is not valid according to [BCP 47](https://tools.ietf.org/html/bcp47).
+    LanguageTag,
+    /// The literal lexical form is not valid for its datatype.
+    Literal,
+}
+
+fn invalid_term_kind(term: TermRef<'_>) -> Option<InvalidTermKind> {
+    match term {
+        TermRef::NamedNode(node) => invalid_iri_kind(node),
+        TermRef::BlankNode(_) => None,
+        TermRef::Literal(literal) => {
+            if let Some(language) = literal.language() {
+                Literal::new_language_tagged_literal(literal.value(), language)
+                    .is_err()
+                    .then_some(InvalidTermKind::LanguageTag)
+            } else if let Some(kind) = invalid_iri_kind(literal.datatype()) {
+                Some(kind)
+            } else {
+                literal
+                    .parsed_value()
+                    .is_err()
+                    .then_some(InvalidTermKind::Literal)
+            }
+        }
+        #[cfg(feature = "rdf-12")]
+        TermRef::Triple(triple) => invalid_term_kind(triple.subject.as_ref().into())
+            .or_else(|| invalid_iri_kind(triple.predicate.as_ref()))
+            .or_else(|| invalid_term_kind(triple.object.as_ref())),
+    }
+}
+
+fn invalid_iri_kind(node: NamedNodeRef<'_>) -> Option<InvalidTermKind> {
+    NamedNodeRef::new(node.as_str())
+        .is_err()
+        .then_some(InvalidTermKind::Iri)
+}
+
+/// Outcome of [`Store::extend_with_report`] and [`Transaction::extend_with_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExtendReport {
+    /// The number of quads that were not in the store before.
+    pub inserted: usize,
+    /// The number of quads that were already in the store.
+    pub already_present: usize,
+    /// The positions in the input of the quads that were already in the store, in increasing order.
+    pub duplicates: Vec<usize>,
+}
+
+impl ExtendReport {
+    fn insert(
+        &mut self,
+        transaction: &mut StorageReadableTransaction<'_>,
+        quad: QuadRef<'_>,
+    ) -> Result<(), StorageError> {
+        if transaction.reader().contains(&quad.into())? {
+            self.duplicates.push(self.inserted + self.already_present);
+            self.already_present += 1;
+        } else {
+            transaction.insert(quad);
+            self.inserted += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if at least one quad was not already in the store.
+    #[inline]
+    pub fn has_changes(&self) -> bool {
+        self.inserted > 0
     }
+}
 
-    /// Creates database backup into the `target_directory`.
-    ///
-    /// After its creation, the backup is usable using [`Store::open`]
-    /// like a regular Oxigraph database and operates independently from the original database.
-    ///
-    /// <div class="warning">
-    ///
-    /// Backups are only possible for on-disk databases created using [`Store::open`].</div>
-    /// Temporary in-memory databases created using [`Store::new`] are not compatible with RocksDB backup system.
-    ///
-    /// <div class="warning">An error is raised if the `target_directory` already exists.</div>
-    ///
-    /// If the target directory is in the same file system as the current database,
-    /// the database content will not be fully copied
-    /// but hard links will be used to point to the original database immutable snapshots.
-    /// This allows cheap regular backups.
-    ///
-    /// If you want to move your data to another RDF storage system, you should have a look at the [`Store::dump_to_writer`] function instead.
-    #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-    pub fn backup(&self, target_directory: impl AsRef<Path>) -> Result<(), StorageError> {
-        self.storage.backup(target_directory.as_ref())
-    }
+/// Checks that the prefix matches the Turtle `PN_PREFIX` production or is empty
+fn is_valid_prefix_name(prefix: &str) -> bool {
+    // [167s] PN_PREFIX ::= PN_CHARS_BASE ((PN_CHARS | '.')* PN_CHARS)?
+    let mut chars = prefix.chars();
+    let Some(first) = chars.next() else {
+        return true;
+    };
+    is_pn_chars_base(first) && !prefix.ends_with('.') && chars.all(|c| c == '.' || is_pn_chars(c))
+}
 
-    /// Creates a bulk loader allowing to load at a lot of data quickly into the store.
-    ///
-    /// Usage example:
-    /// ```
-    /// use oxigraph::io::RdfFormat;
-    /// use oxigraph::model::*;
-    /// use oxigraph::store::Store;
-    ///
-    /// let store = Store::new()?;
-    ///
-    /// // quads file insertion
-    /// let file =
-    ///     "<http://example.com> <http://example.com> <http://example.com> <http://example.com> .";
-    /// let mut loader = store.bulk_loader();
-    /// loader.load_from_slice(RdfFormat::NQuads, file)?;
-    /// loader.commit()?;
-    ///
-    /// // we inspect the store contents
-    /// let ex = NamedNodeRef::new("http://example.com")?;
-    /// assert!(store.contains(QuadRef::new(ex, ex, ex, ex))?);
-    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
-    /// ```
-    pub fn bulk_loader(&self) -> BulkLoader<'_> {
-        BulkLoader {
-            storage: self.storage.bulk_loader(),
-            num_threads: None,
-            max_memory_size: None,
-            on_parse_error: None,
+// [157s] PN_CHARS_BASE ::= [A-Z] | [a-z] | [#x00C0-#x00D6] | [#x00D8-#x00F6] | [#x00F8-#x02FF] | [#x0370-#x037D] | [#x037F-#x1FFF] | [#x200C-#x200D] | [#x2070-#x218F] | [#x2C00-#x2FEF] | [#x3001-#xD7FF] | [#xF900-#xFDCF] | [#xFDF0-#xFFFD] | [#x10000-#xEFFFF]
+fn is_pn_chars_base(c: char) -> bool {
+    matches!(c,
+        'A'..='Z'
+        | 'a'..='z'
+        | '\u{00C0}'..='\u{00D6}'
+        | '\u{00D8}'..='\u{00F6}'
+        | '\u{00F8}'..='\u{02FF}'
+        | '\u{0370}'..='\u{037D}'
+        | '\u{037F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}')
+}
+
+// [160s] PN_CHARS ::= PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
+fn is_pn_chars(c: char) -> bool {
+    is_pn_chars_base(c)
+        || matches!(c,
+            '_' | '-' | '0'..='9' | '\u{00B7}' | '\u{0300}'..='\u{036F}' | '\u{203F}'..='\u{2040}')
+}
+
+fn decode_graph_name(
+    reader: &StorageReader<'_>,
+    graph_name: &EncodedTerm,
+) -> Result<GraphName, StorageError> {
+    Ok(if graph_name.is_default_graph() {
+        GraphName::DefaultGraph
+    } else {
+        reader.decode_named_or_blank_node(graph_name)?.into()
+    })
+}
+
+/// Estimates the number of distinct elements using [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog).
+///
+/// It uses 2^`index_bits` registers, the default 2^12 registers leading to a standard error around 1.6%.
+struct DistinctCounter {
+    index_bits: u32,
+    registers: Vec<u8>,
+}
+
+impl DistinctCounter {
+    fn new(index_bits: u32) -> Self {
+        Self {
+            index_bits,
+            registers: vec![0; 1 << index_bits],
         }
     }
 
-    /// Validate that all the store invariants held in the data
-    #[doc(hidden)]
-    pub fn validate(&self) -> Result<(), StorageError> {
-        self.storage.snapshot().validate()
+    fn add(&mut self, value: &impl Hash) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = usize::try_from(hash >> (64 - self.index_bits)).unwrap_or(0);
+        // The rank is the position of the first 1 bit in the remaining bits
+        let rank = (hash << self.index_bits)
+            .leading_zeros()
+            .min(64 - self.index_bits)
+            + 1;
+        let rank = u8::try_from(rank).unwrap_or(u8::MAX);
+        if let Some(register) = self.registers.get_mut(index) {
+            *register = (*register).max(rank);
+        }
     }
 
-    pub(super) fn storage(&self) -> &Storage {
-        &self.storage
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the estimate is positive and far below usize::MAX"
+    )]
+    fn estimate(&self) -> usize {
+        let count = f64::from(1_u32 << self.index_bits);
+        let mut sum = 0.;
+        let mut zeros = 0_u32;
+        for register in &self.registers {
+            sum += 2_f64.powi(-i32::from(*register));
+            if *register == 0 {
+                zeros += 1;
+            }
+        }
+        let alpha = 0.7213 / (1. + 1.079 / count);
+        let estimate = alpha * count * count / sum;
+        if estimate <= 2.5 * count && zeros > 0 {
+            // Linear counting is more precise for small cardinalities
+            (count * (count / f64::from(zeros)).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
     }
 }
 
-impl fmt::Display for Store {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for t in self {
-            writeln!(f, "{} .", t.map_err(|_| fmt::Error)?)?;
-        }
-        Ok(())
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        Self::new(12)
     }
 }
 
-impl IntoIterator for &Store {
-    type IntoIter = QuadIter<'static>;
-    type Item = Result<Quad, StorageError>;
+/// Counts used to build [`PredicateStatistics`]
+///
+/// There might be a lot of predicates so we use smaller distinct counters with a standard error around 6.5%.
+struct PredicateCounter {
+    triple_count: usize,
+    subjects: DistinctCounter,
+    objects: DistinctCounter,
+}
 
-    #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+impl Default for PredicateCounter {
+    fn default() -> Self {
+        Self {
+            triple_count: 0,
+            subjects: DistinctCounter::new(8),
+            objects: DistinctCounter::new(8),
+        }
     }
 }
 
@@ -1148,6 +3133,8 @@ impl<'a> Transaction<'a> {
 
     /// Retrieves quads with a filter on each quad component.
     ///
+    /// The quads inserted and removed earlier in the transaction are taken into account.
+    ///
     /// Usage example:
     /// ```
     /// use oxigraph::model::*;
@@ -1182,13 +3169,14 @@ impl<'a> Transaction<'a> {
     ) -> QuadIter<'_> {
         let reader = self.inner.reader();
         QuadIter {
-            iter: reader.quads_for_pattern(
+            iter: Some(reader.quads_for_pattern(
                 subject.map(EncodedTerm::from).as_ref(),
                 predicate.map(EncodedTerm::from).as_ref(),
                 object.map(EncodedTerm::from).as_ref(),
                 graph_name.map(EncodedTerm::from).as_ref(),
-            ),
+            )),
             reader,
+            object_range: None,
         }
     }
 
@@ -1197,7 +3185,24 @@ impl<'a> Transaction<'a> {
         self.quads_for_pattern(None, None, None, None)
     }
 
-    /// Checks if this store contains a given quad.
+    /// Checks if this store contains a given quad, taking into account the changes done earlier in the transaction.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new_unchecked("http://example.com");
+    /// let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    ///
+    /// let store = Store::new()?;
+    /// store.insert(quad)?;
+    /// let mut transaction = store.start_transaction()?;
+    /// transaction.remove(quad);
+    /// assert!(!transaction.contains(quad)?);
+    /// assert!(store.contains(quad)?); // Not committed yet
+    /// # Result::<_,oxigraph::store::StorageError>::Ok(())
+    /// ```
     pub fn contains<'b>(&self, quad: impl Into<QuadRef<'b>>) -> Result<bool, StorageError> {
         let quad = EncodedQuad::from(quad.into());
         self.inner.reader().contains(&quad)
@@ -1309,6 +3314,28 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Loads an RDF file into the store and records its [PROV-O](https://www.w3.org/TR/prov-o/) provenance.
+    ///
+    /// The provenance is written in the same transaction as the loaded data.
+    /// See [`Store::load_from_reader_with_provenance`] for the details.
+    pub fn load_from_reader_with_provenance(
+        &mut self,
+        parser: impl Into<RdfParser>,
+        reader: impl Read,
+        provenance: LoadProvenance,
+    ) -> Result<(), LoaderError> {
+        let mut provenance = ProvenanceTracker::new(provenance);
+        for quad in parser.into().rename_blank_nodes().for_reader(reader) {
+            let quad = quad?;
+            provenance.record(quad.as_ref());
+            self.insert(quad.as_ref());
+        }
+        for quad in provenance.quads() {
+            self.insert(quad.as_ref());
+        }
+        Ok(())
+    }
+
     /// Loads an RDF file into the store.
     ///
     /// This function is atomic, quite slow and memory hungry. To get much better performances, you might want to use the [`bulk_loader`](Store::bulk_loader).
@@ -1401,6 +3428,37 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Adds a set of quads to this store and reports which of them were already present.
+    ///
+    /// Quads already added earlier in this transaction or repeated in the input are reported as already present.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new_unchecked("http://example.com");
+    /// let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    ///
+    /// let store = Store::new()?;
+    /// let mut transaction = store.start_transaction()?;
+    /// let report = transaction.extend_with_report([quad, quad])?;
+    /// transaction.commit()?;
+    /// assert_eq!(report.inserted, 1);
+    /// assert_eq!(report.duplicates, [1]);
+    /// # Result::<_,oxigraph::store::StorageError>::Ok(())
+    /// ```
+    pub fn extend_with_report<'b>(
+        &mut self,
+        quads: impl IntoIterator<Item = impl Into<QuadRef<'b>>>,
+    ) -> Result<ExtendReport, StorageError> {
+        let mut report = ExtendReport::default();
+        for quad in quads {
+            report.insert(&mut self.inner, quad.into())?;
+        }
+        Ok(report)
+    }
+
     /// Removes a quad from this store.
     ///
     /// Usage example:
@@ -1422,6 +3480,93 @@ impl<'a> Transaction<'a> {
         self.inner.remove(quad.into())
     }
 
+    /// Removes all the quads matching a pattern from this store and returns the number of removed quads.
+    ///
+    /// `None` matches any term.
+    /// The quads inserted earlier in the transaction are also removed if they match.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new_unchecked("http://example.com");
+    /// let store = Store::new()?;
+    /// let mut transaction = store.start_transaction()?;
+    /// transaction.insert(QuadRef::new(ex, ex, ex, ex));
+    /// transaction.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph));
+    /// assert_eq!(transaction.remove_matching(Some(ex.into()), None, None, None)?, 2);
+    /// transaction.commit()?;
+    /// assert!(store.is_empty()?);
+    /// # Result::<_,oxigraph::store::StorageError>::Ok(())
+    /// ```
+    pub fn remove_matching(
+        &mut self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+        graph_name: Option<GraphNameRef<'_>>,
+    ) -> Result<u64, StorageError> {
+        self.inner.remove_matching(
+            subject.map(EncodedTerm::from).as_ref(),
+            predicate.map(EncodedTerm::from).as_ref(),
+            object.map(EncodedTerm::from).as_ref(),
+            graph_name.map(EncodedTerm::from).as_ref(),
+        )
+    }
+
+    /// Replaces all the objects of the `subject` `predicate` pair in the graph `graph_name` by `objects`
+    /// and returns the previous objects that are not in `objects` anymore.
+    ///
+    /// An empty `objects` removes all the values of the pair.
+    /// Unlike [`Store::set_object`], calls done in concurrent transactions are not serialized.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let ex = NamedNodeRef::new_unchecked("http://example.com");
+    /// let store = Store::new()?;
+    /// store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    /// let mut transaction = store.start_transaction()?;
+    /// let removed = transaction.set_object(ex, ex, [] as [Term; 0], ex)?;
+    /// transaction.commit()?;
+    /// assert_eq!(removed, [ex.into_owned().into()]);
+    /// assert!(store.is_empty()?);
+    /// # Result::<_, oxigraph::store::StorageError>::Ok(())
+    /// ```
+    pub fn set_object<'b>(
+        &mut self,
+        subject: impl Into<NamedOrBlankNodeRef<'b>>,
+        predicate: impl Into<NamedNodeRef<'b>>,
+        objects: impl IntoIterator<Item = impl Into<Term>>,
+        graph_name: impl Into<GraphNameRef<'b>>,
+    ) -> Result<Vec<Term>, StorageError> {
+        let subject = subject.into();
+        let predicate = predicate.into();
+        let graph_name = graph_name.into();
+        let objects = objects.into_iter().map(Into::into).collect::<Vec<_>>();
+        let previous = self
+            .quads_for_pattern(Some(subject), Some(predicate), None, Some(graph_name))
+            .map(|quad| Ok(quad?.object))
+            .collect::<Result<Vec<_>, StorageError>>()?;
+        let removed = previous
+            .iter()
+            .filter(|object| !objects.contains(object))
+            .cloned()
+            .collect::<Vec<_>>();
+        for object in &removed {
+            self.remove(QuadRef::new(subject, predicate, object, graph_name));
+        }
+        for object in &objects {
+            if !previous.contains(object) {
+                self.insert(QuadRef::new(subject, predicate, object, graph_name));
+            }
+        }
+        Ok(removed)
+    }
+
     /// Returns all the named graphs in the store.
     pub fn named_graphs(&self) -> GraphNameIter<'_> {
         let reader = self.inner.reader();
@@ -1513,6 +3658,51 @@ impl<'a> Transaction<'a> {
         self.inner.remove_named_graph(graph_name.into())
     }
 
+    /// Inserts all the quads of the graph `from` into the graph `to`, like the SPARQL [`ADD`](https://www.w3.org/TR/sparql11-update/#add) operation.
+    ///
+    /// See [`Store::add_graph_into`].
+    pub fn add_graph_into<'b, 'c>(
+        &mut self,
+        from: impl Into<GraphNameRef<'b>>,
+        to: impl Into<GraphNameRef<'c>>,
+    ) -> Result<(), StorageError> {
+        self.inner.add_graph(from.into(), to.into())
+    }
+
+    /// Replaces the content of the graph `to` by the quads of the graph `from`, like the SPARQL [`COPY`](https://www.w3.org/TR/sparql11-update/#copy) operation.
+    ///
+    /// See [`Store::copy_graph`].
+    pub fn copy_graph<'b, 'c>(
+        &mut self,
+        from: impl Into<GraphNameRef<'b>>,
+        to: impl Into<GraphNameRef<'c>>,
+    ) -> Result<(), StorageError> {
+        self.inner.copy_graph(from.into(), to.into())
+    }
+
+    /// Replaces the content of the graph `to` by the quads of the graph `from` and removes the graph `from`, like the SPARQL [`MOVE`](https://www.w3.org/TR/sparql11-update/#move) operation.
+    ///
+    /// See [`Store::move_graph`].
+    pub fn move_graph<'b, 'c>(
+        &mut self,
+        from: impl Into<GraphNameRef<'b>>,
+        to: impl Into<GraphNameRef<'c>>,
+    ) -> Result<(), StorageError> {
+        self.inner.move_graph(from.into(), to.into())
+    }
+
+    /// Renames the graph `from` to `to`, failing if `to` already exists and `overwrite` is not set.
+    ///
+    /// See [`Store::rename_graph`].
+    pub fn rename_graph<'b, 'c>(
+        &mut self,
+        from: impl Into<GraphNameRef<'b>>,
+        to: impl Into<GraphNameRef<'c>>,
+        overwrite: bool,
+    ) -> Result<bool, RenameGraphError> {
+        self.inner.rename_graph(from.into(), to.into(), overwrite)
+    }
+
     /// Clears the store.
     ///
     /// Usage example:
@@ -1571,44 +3761,178 @@ impl<'a> IntoIterator for &'a Transaction<'_> {
     }
 }
 
-/// An iterator returning the quads contained in a [`Store`].
+/// An iterator returning the quads contained in a [`Store`].
+#[must_use]
+pub struct QuadIter<'a> {
+    /// `None` if no quad can match
+    iter: Option<DecodingQuadIterator<'a>>,
+    reader: StorageReader<'a>,
+    object_range: Option<EncodedObjectRange>,
+}
+
+impl Iterator for QuadIter<'_> {
+    type Item = Result<Quad, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let quad = match self.iter.as_mut()?.next()? {
+                Ok(quad) => quad,
+                Err(error) => return Some(Err(error)),
+            };
+            if let Some(object_range) = &self.object_range {
+                match object_range.contains(&quad.object, &self.reader) {
+                    Ok(true) => (),
+                    Ok(false) => continue,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+            return Some(self.reader.decode_quad(&quad));
+        }
+    }
+}
+
+/// An iterator returning the graph names contained in a [`Store`].
+#[must_use]
+pub struct GraphNameIter<'a> {
+    iter: DecodingGraphIterator<'a>,
+    reader: StorageReader<'a>,
+}
+
+impl Iterator for GraphNameIter<'_> {
+    type Item = Result<NamedOrBlankNode, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.iter
+                .next()?
+                .and_then(|graph_name| self.reader.decode_named_or_blank_node(&graph_name)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An opaque identifier of an RDF term or of the default graph in a [`Store`].
+///
+/// Ids are returned by [`Store::term_id`] and [`Store::quads_for_pattern_ids`] and are converted back to terms with [`Store::id_term`].
+/// Comparing and hashing them is cheap and does not require to read the terms strings.
+///
+/// <div class="warning">Ids are only valid for the data of the store that built them.
+/// They should not be persisted: they are not stable across storage encoding changes like Oxigraph upgrades or operations rewriting the string dictionary.</div>
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct TermId(EncodedTerm);
+
+impl TermId {
+    /// The id of the default graph.
+    pub const DEFAULT_GRAPH: Self = Self(EncodedTerm::DefaultGraph);
+
+    /// Checks if this id is the one of the default graph.
+    #[inline]
+    pub fn is_default_graph(&self) -> bool {
+        self.0.is_default_graph()
+    }
+}
+
+/// A quad whose components are [`TermId`], returned by [`Store::quads_for_pattern_ids`].
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct IdQuad {
+    pub subject: TermId,
+    pub predicate: TermId,
+    pub object: TermId,
+    /// [`TermId::DEFAULT_GRAPH`] if the quad is in the default graph.
+    pub graph_name: TermId,
+}
+
+/// An iterator returning the quads contained in a [`Store`] as [`IdQuad`].
 #[must_use]
-pub struct QuadIter<'a> {
+pub struct IdQuadIter<'a> {
     iter: DecodingQuadIterator<'a>,
-    reader: StorageReader<'a>,
 }
 
-impl Iterator for QuadIter<'_> {
-    type Item = Result<Quad, StorageError>;
+impl Iterator for IdQuadIter<'_> {
+    type Item = Result<IdQuad, StorageError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(match self.iter.next()? {
-            Ok(quad) => self.reader.decode_quad(&quad),
-            Err(error) => Err(error),
-        })
+        Some(self.iter.next()?.map(|quad| IdQuad {
+            subject: TermId(quad.subject),
+            predicate: TermId(quad.predicate),
+            object: TermId(quad.object),
+            graph_name: TermId(quad.graph_name),
+        }))
     }
 }
 
-/// An iterator returning the graph names contained in a [`Store`].
+/// A loader of RDF documents dereferenced from their IRIs.
+///
+/// It is created with [`Store::iri_loader`].
+#[cfg(feature = "http-client")]
 #[must_use]
-pub struct GraphNameIter<'a> {
-    iter: DecodingGraphIterator<'a>,
-    reader: StorageReader<'a>,
+pub struct IriLoader<'a> {
+    store: &'a Store,
+    timeout: Option<Duration>,
+    redirection_limit: usize,
+    provenance: Option<LoadProvenance>,
 }
 
-impl Iterator for GraphNameIter<'_> {
-    type Item = Result<NamedOrBlankNode, StorageError>;
+#[cfg(feature = "http-client")]
+impl IriLoader<'_> {
+    /// Sets a timeout for the HTTP requests.
+    ///
+    /// By default, there is no timeout.
+    pub fn with_http_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        Some(
-            self.iter
-                .next()?
-                .and_then(|graph_name| self.reader.decode_named_or_blank_node(&graph_name)),
-        )
+    /// Sets an upper bound to the number of HTTP redirections followed.
+    ///
+    /// By default, this value is `10`.
+    pub fn with_http_redirection_limit(mut self, redirection_limit: usize) -> Self {
+        self.redirection_limit = redirection_limit;
+        self
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+    /// Records the [PROV-O](https://www.w3.org/TR/prov-o/) provenance of each loaded document.
+    ///
+    /// The dereferenced IRI is recorded as the activity source.
+    /// See [`LoadProvenance`] for the recorded description.
+    pub fn with_provenance(mut self, provenance: LoadProvenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Dereferences the IRI and atomically loads the returned RDF document into the store.
+    ///
+    /// See [`Store::load_from_iri`] for the details.
+    pub fn load<'a>(
+        &self,
+        iri: impl Into<NamedNodeRef<'a>>,
+        to_graph_name: Option<GraphNameRef<'_>>,
+    ) -> Result<(), IriLoadError> {
+        let iri = iri.into();
+        let client = Client::new(self.timeout, self.redirection_limit);
+        let quads = load_rdf(&client, iri.as_str(), to_graph_name)?;
+        let mut provenance = self
+            .provenance
+            .clone()
+            .map(|provenance| ProvenanceTracker::new(provenance.with_source(iri.into_owned())));
+        let mut transaction = self.store.storage.start_transaction()?;
+        for quad in quads {
+            let quad = quad?;
+            if let Some(provenance) = &mut provenance {
+                provenance.record(quad.as_ref());
+            }
+            transaction.insert(quad.as_ref());
+        }
+        if let Some(provenance) = provenance {
+            for quad in provenance.quads() {
+                transaction.insert(quad.as_ref());
+            }
+        }
+        transaction.commit()?;
+        Ok(())
     }
 }
 
@@ -1620,6 +3944,12 @@ impl Iterator for GraphNameIter<'_> {
 /// is around 2GB per thread and 2 threads.
 /// These targets are considered per loaded file.
 ///
+/// By default, the loading is [atomic](Self::atomic): the loaded data is only written into the store by [`commit`](Self::commit)
+/// and an interrupted loading leaves nothing in the store.
+/// [`without_atomicity`](Self::without_atomicity) writes the data [batch](Self::with_batch_size) per batch during the loading instead:
+/// it uses less disk space and the already written batches are kept if the loading is interrupted.
+/// The time spent waiting for the disk is configured with [`with_durability`](Self::with_durability).
+///
 /// Usage example a dataset:
 /// ```
 /// use oxigraph::io::RdfFormat;
@@ -1643,9 +3973,19 @@ impl Iterator for GraphNameIter<'_> {
 #[must_use]
 pub struct BulkLoader<'a> {
     storage: StorageBulkLoader<'a>,
+    namespace_storage: &'a Storage,
+    /// The prefixes read from the loaded files, `None` if they should not be registered
+    namespaces: Option<Vec<(String, String)>>,
     num_threads: Option<usize>,
     max_memory_size: Option<usize>,
+    batch_size: Option<usize>,
     on_parse_error: Option<Arc<dyn Fn(RdfParseError) -> Result<(), RdfParseError> + Send + Sync>>,
+    graph_mapper: Option<Arc<dyn Fn(GraphNameRef<'_>) -> GraphName + Send + Sync>>,
+    provenance: Option<ProvenanceTracker>,
+    #[cfg(not(target_family = "wasm"))]
+    checkpoint: Option<PathBuf>,
+    #[cfg(not(target_family = "wasm"))]
+    checkpoint_interval: (u64, Duration),
 }
 
 impl BulkLoader<'_> {
@@ -1687,16 +4027,59 @@ impl BulkLoader<'_> {
     }
 
     fn target_batch_size(&self) -> usize {
-        if let Some(max_memory_size) = self.max_memory_size {
+        if let Some(batch_size) = self.batch_size {
+            batch_size
+        } else if let Some(max_memory_size) = self.max_memory_size {
             max_memory_size * 1000 / self.target_num_threads()
         } else {
             DEFAULT_BULK_LOAD_BATCH_SIZE
         }
     }
 
+    /// Sets the number of quads in each batch written by the loader.
+    ///
+    /// Without atomicity, each batch is an internal commit:
+    /// if the loading is interrupted, the batches already written are kept in the store and the others are lost.
+    /// Smaller batches lose less data on interruption, but are slower to write and to compact.
+    /// With the RocksDB storage, each batch is written into its own set of files that are then inserted into the database.
+    ///
+    /// It overrides the batch size computed from [`with_max_memory_size_in_megabytes`](Self::with_max_memory_size_in_megabytes).
+    /// By default, batches of 1 million quads are used.
+    pub fn with_batch_size(mut self, quads: usize) -> Self {
+        self.batch_size = Some(quads.max(1));
+        self
+    }
+
+    /// Sets how much the loader waits for the loaded data to be on disk, see [`Durability`].
+    ///
+    /// By default, [`Durability::Full`] is used.
+    pub fn with_durability(mut self, durability: Durability) -> Self {
+        self.storage = self.storage.with_durability(durability);
+        self
+    }
+
+    /// Makes the loading atomic: nothing is visible in the store before [`commit`](Self::commit) is called,
+    /// and nothing is left in the store if the loader is dropped without commit or if the process crashes before the end of the commit.
+    ///
+    /// With the RocksDB storage, the batches are staged into files outside of the database
+    /// that are all inserted into the database in a single atomic operation by [`commit`](Self::commit).
+    /// The staged files of a crashed loading are removed when the store is opened again.
+    /// It requires enough disk space to store the full loaded data twice until the commit ends.
+    ///
+    /// It is the default behavior, this function undoes [`without_atomicity`](Self::without_atomicity) and [`with_checkpoint`](Self::with_checkpoint).
+    pub fn atomic(mut self) -> Self {
+        self.storage = self.storage.atomic();
+        #[cfg(not(target_family = "wasm"))]
+        {
+            self.checkpoint = None;
+        }
+        self
+    }
+
     /// Allow the bulk loader to save also data to the database during the bulk loading instead of only when [`commit`](Self::commit) is called.
     ///
     /// When used with the RocksDB storage, it allows the storage to compact the data while the loading continues.
+    /// If the loading is interrupted, the batches already saved are kept in the store, see [`with_batch_size`](Self::with_batch_size).
     pub fn without_atomicity(mut self) -> Self {
         self.storage = self.storage.without_atomicity();
         self
@@ -1708,6 +4091,35 @@ impl BulkLoader<'_> {
         self
     }
 
+    /// Registers the prefixes declared in the loaded files as store [namespaces](Store::namespaces) when [`commit`](Self::commit) is called.
+    ///
+    /// If the same prefix is declared multiple times, the last declaration wins.
+    /// Prefixes that are not valid [Turtle `PN_PREFIX`](https://www.w3.org/TR/turtle/#grammar-production-PN_PREFIX) are ignored.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::NamedNode;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let mut loader = store.bulk_loader().register_namespaces();
+    /// loader.load_from_slice(
+    ///     RdfFormat::Turtle,
+    ///     "@prefix ex: <http://example.com/> . ex:s ex:p ex:o .",
+    /// )?;
+    /// loader.commit()?;
+    /// assert_eq!(
+    ///     store.namespaces()?.collect::<Vec<_>>(),
+    ///     [("ex".to_owned(), NamedNode::new("http://example.com/")?)]
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn register_namespaces(mut self) -> Self {
+        self.namespaces = Some(Vec::new());
+        self
+    }
+
     /// Adds a `callback` catching all parse errors and choosing if the parsing should continue
     /// by returning `Ok` or fail by returning `Err`.
     ///
@@ -1720,6 +4132,154 @@ impl BulkLoader<'_> {
         self
     }
 
+    /// Sets a function rewriting the graph name of each loaded quad before its insertion.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let mut loader = store.bulk_loader().with_graph_mapper(|graph_name| match graph_name {
+    ///     GraphNameRef::NamedNode(g) => match g.as_str().strip_prefix("http://old/g/") {
+    ///         Some(suffix) => NamedNode::new_unchecked(format!("http://new/g/{suffix}")).into(),
+    ///         None => g.into(),
+    ///     },
+    ///     g => g.into_owned(),
+    /// });
+    /// loader.load_from_slice(
+    ///     RdfFormat::TriG,
+    ///     "GRAPH <http://old/g/x> { <http://example.com> <http://example.com> <http://example.com> }",
+    /// )?;
+    /// loader.commit()?;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, NamedNodeRef::new("http://new/g/x")?))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_graph_mapper(
+        mut self,
+        mapper: impl Fn(GraphNameRef<'_>) -> GraphName + Send + Sync + 'static,
+    ) -> Self {
+        self.graph_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Periodically records the loading progress into the file at `path`
+    /// so that an interrupted load can be restarted with [`resume_from_checkpoint`](Self::resume_from_checkpoint).
+    ///
+    /// At each checkpoint, the quads loaded so far are written into the store
+    /// and the position reached in the input is atomically saved into the checkpoint file.
+    /// This implies [`without_atomicity`](Self::without_atomicity).
+    /// Checkpoints are written by [`load_from_reader`](Self::load_from_reader), [`load_from_slice`](Self::load_from_slice)
+    /// and [`resume_from_checkpoint`](Self::resume_from_checkpoint) but not by the parallel loading functions.
+    ///
+    /// Only N-Triples and N-Quads are supported because they are the only formats that can be parsed starting from the middle of a file.
+    /// Blank nodes are not renamed randomly but prefixed with a random string stored in the checkpoint file,
+    /// so that they keep the same identifiers when the loading is resumed.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::store::Store;
+    /// use std::io::Cursor;
+    ///
+    /// let checkpoint = std::env::temp_dir().join("oxigraph-bulk-loader-checkpoint-doctest");
+    /// # let _ = std::fs::remove_file(&checkpoint);
+    /// let store = Store::new()?;
+    /// let mut loader = store.bulk_loader().with_checkpoint(&checkpoint);
+    /// // If the process is killed, calling this again will continue from the last checkpoint
+    /// loader.resume_from_checkpoint(
+    ///     RdfFormat::NTriples,
+    ///     Cursor::new("<http://example.com> <http://example.com> <http://example.com> .\n"),
+    /// )?;
+    /// loader.commit()?;
+    /// assert_eq!(store.len()?, 1);
+    /// # std::fs::remove_file(&checkpoint)?;
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_checkpoint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint = Some(path.into());
+        self.without_atomicity()
+    }
+
+    /// Sets how often checkpoints are written when [`with_checkpoint`](Self::with_checkpoint) is used:
+    /// after `quads` loaded quads or `duration` since the previous checkpoint, whichever comes first.
+    ///
+    /// By default, a checkpoint is written every 10 million quads or every minute.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_checkpoint_interval(mut self, quads: u64, duration: Duration) -> Self {
+        self.checkpoint_interval = (quads, duration);
+        self
+    }
+
+    /// Loads all quads into the given graph, whatever the graph they are in inside of the loaded data.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::Store;
+    ///
+    /// let store = Store::new()?;
+    /// let g = NamedNode::new("http://example.com/g")?;
+    /// let mut loader = store.bulk_loader().with_target_graph(g.clone());
+    /// loader.load_from_slice(
+    ///     RdfFormat::NQuads,
+    ///     "<http://example.com> <http://example.com> <http://example.com> <http://example.com/other> .",
+    /// )?;
+    /// loader.commit()?;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// assert!(store.contains(QuadRef::new(ex, ex, ex, &g))?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_target_graph(self, graph_name: impl Into<GraphName>) -> Self {
+        let graph_name = graph_name.into();
+        self.with_graph_mapper(move |_| graph_name.clone())
+    }
+
+    /// Records the [PROV-O](https://www.w3.org/TR/prov-o/) provenance of the loading when it is [committed](Self::commit).
+    ///
+    /// The activity starts when this method is called and ends on commit.
+    /// All the quads loaded by this loader are counted and all the named graphs they are written to are described as generated by the activity.
+    /// See [`LoadProvenance`] for the recorded description.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxigraph::io::RdfFormat;
+    /// use oxigraph::model::*;
+    /// use oxigraph::store::{LoadProvenance, Store};
+    ///
+    /// let store = Store::new()?;
+    /// let mut loader = store.bulk_loader().with_provenance(
+    ///     LoadProvenance::new("http://example.com/load/")?.with_source(Literal::from("dump.nq")),
+    /// );
+    /// loader.load_from_slice(
+    ///     RdfFormat::NQuads,
+    ///     "<http://example.com> <http://example.com> <http://example.com> <http://example.com/g> .",
+    /// )?;
+    /// loader.commit()?;
+    ///
+    /// let provenance = NamedNodeRef::new("http://oxigraph.org/provenance")?;
+    /// assert!(store.contains_named_graph(provenance)?);
+    /// assert_eq!(
+    ///     store
+    ///         .quads_for_pattern(None, Some(vocab::prov::WAS_GENERATED_BY), None, Some(provenance.into()))
+    ///         .next()
+    ///         .unwrap()?
+    ///         .subject,
+    ///     NamedNode::new("http://example.com/g")?.into()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn with_provenance(mut self, provenance: LoadProvenance) -> Self {
+        self.provenance = Some(ProvenanceTracker::new(provenance));
+        self
+    }
+
     /// Loads a file using the bulk loader.
     ///
     /// This function is optimized for large dataset loading speed. For small files, [`Store::load_from_reader`] might be more convenient.
@@ -1768,27 +4328,131 @@ impl BulkLoader<'_> {
         parser: impl Into<RdfParser>,
         reader: impl Read,
     ) -> Result<(), LoaderError> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(path) = self.checkpoint.clone() {
+            return self.load_with_checkpoints(&parser.into(), reader, &path, Checkpoint::new());
+        }
+        let on_parse_error = self.on_parse_error.as_ref().map(Arc::clone);
+        let mut parser = parser.into().rename_blank_nodes().for_reader(reader);
+        self.load_ok_quads::<_, LoaderError>((&mut parser).filter_map(|r| match r {
+            Ok(q) => Some(Ok(q)),
+            Err(e) => {
+                if let Some(callback) = &on_parse_error {
+                    if let Err(e) = callback(e) {
+                        Some(Err(e))
+                    } else {
+                        None
+                    }
+                } else {
+                    Some(Err(e))
+                }
+            }
+        }))?;
+        self.register_prefixes(parser.prefixes());
+        Ok(())
+    }
+
+    /// Loads a file using the bulk loader, starting from the position saved in the checkpoint file set with [`with_checkpoint`](Self::with_checkpoint).
+    ///
+    /// If the checkpoint file does not exist, the loading starts from the beginning of the input.
+    /// The `reader` must read the same input as the interrupted loading.
+    /// Quads loaded after the last checkpoint of the interrupted loading are inserted again, which is harmless.
+    ///
+    /// If no checkpoint file is set, this behaves like [`load_from_reader`](Self::load_from_reader).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn resume_from_checkpoint(
+        &mut self,
+        parser: impl Into<RdfParser>,
+        mut reader: impl Read + Seek,
+    ) -> Result<(), LoaderError> {
+        let Some(path) = self.checkpoint.clone() else {
+            return self.load_from_reader(parser, reader);
+        };
+        let checkpoint = Checkpoint::read(&path)
+            .map_err(|error| LoaderError::Checkpoint {
+                path: path.clone(),
+                error,
+            })?
+            .unwrap_or_else(Checkpoint::new);
+        reader
+            .seek(SeekFrom::Start(checkpoint.offset))
+            .map_err(RdfParseError::from)?;
+        self.load_with_checkpoints(&parser.into(), reader, &path, checkpoint)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn load_with_checkpoints(
+        &mut self,
+        parser: &RdfParser,
+        reader: impl Read,
+        path: &Path,
+        mut checkpoint: Checkpoint,
+    ) -> Result<(), LoaderError> {
+        let format = parser.format();
+        if !matches!(
+            format,
+            crate::io::RdfFormat::NTriples | crate::io::RdfFormat::NQuads
+        ) {
+            return Err(LoaderError::UnsupportedCheckpointFormat(format));
+        }
         let on_parse_error = self.on_parse_error.as_ref().map(Arc::clone);
-        self.load_ok_quads(
-            parser
-                .into()
-                .rename_blank_nodes()
-                .for_reader(reader)
-                .filter_map(|r| match r {
-                    Ok(q) => Some(Ok(q)),
+        let target_batch_size = self.target_batch_size();
+        let (checkpoint_quads, checkpoint_duration) = self.checkpoint_interval;
+        let mut reader = BufReader::new(reader);
+        let mut chunk = Vec::new();
+        let mut quads_since_checkpoint = 0;
+        let mut last_checkpoint = Instant::now();
+        loop {
+            // We read full lines to never cut a quad in the middle
+            chunk.clear();
+            for _ in 0..target_batch_size {
+                if reader
+                    .read_until(b'\n', &mut chunk)
+                    .map_err(RdfParseError::from)?
+                    == 0
+                {
+                    break;
+                }
+            }
+            if chunk.is_empty() {
+                break;
+            }
+            let mut quads = Vec::new();
+            for quad in parser.clone().for_slice(&chunk) {
+                match quad {
+                    Ok(quad) => quads.push(checkpoint.rename_blank_nodes(quad)),
                     Err(e) => {
                         if let Some(callback) = &on_parse_error {
-                            if let Err(e) = callback(e) {
-                                Some(Err(e))
-                            } else {
-                                None
-                            }
+                            callback(e.into())?;
                         } else {
-                            Some(Err(e))
+                            return Err(RdfParseError::from(e).into());
                         }
                     }
-                }),
-        )
+                }
+            }
+            quads_since_checkpoint += u64::try_from(quads.len()).unwrap_or(u64::MAX);
+            self.load_quads(quads)?;
+            checkpoint.offset += u64::try_from(chunk.len()).unwrap_or(u64::MAX);
+            if quads_since_checkpoint >= checkpoint_quads
+                || last_checkpoint.elapsed() >= checkpoint_duration
+            {
+                self.save_checkpoint(path, &checkpoint)?;
+                quads_since_checkpoint = 0;
+                last_checkpoint = Instant::now();
+            }
+        }
+        self.save_checkpoint(path, &checkpoint)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn save_checkpoint(&mut self, path: &Path, checkpoint: &Checkpoint) -> Result<(), LoaderError> {
+        self.storage.flush()?;
+        checkpoint
+            .write(path)
+            .map_err(|error| LoaderError::Checkpoint {
+                path: path.into(),
+                error,
+            })
     }
 
     /// Loads serialized RDF in a slice using the bulk loader.
@@ -1839,27 +4503,33 @@ impl BulkLoader<'_> {
         parser: impl Into<RdfParser>,
         slice: &(impl AsRef<[u8]> + ?Sized),
     ) -> Result<(), LoaderError> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(path) = self.checkpoint.clone() {
+            return self.load_with_checkpoints(
+                &parser.into(),
+                slice.as_ref(),
+                &path,
+                Checkpoint::new(),
+            );
+        }
         let on_parse_error = self.on_parse_error.as_ref().map(Arc::clone);
-        self.load_ok_quads(
-            parser
-                .into()
-                .rename_blank_nodes()
-                .for_slice(slice)
-                .filter_map(|r| match r {
-                    Ok(q) => Some(Ok(q)),
-                    Err(e) => {
-                        if let Some(callback) = &on_parse_error {
-                            if let Err(e) = callback(e.into()) {
-                                Some(Err(e))
-                            } else {
-                                None
-                            }
-                        } else {
-                            Some(Err(e.into()))
-                        }
+        let mut parser = parser.into().rename_blank_nodes().for_slice(slice);
+        self.load_ok_quads::<_, LoaderError>((&mut parser).filter_map(|r| match r {
+            Ok(q) => Some(Ok(q)),
+            Err(e) => {
+                if let Some(callback) = &on_parse_error {
+                    if let Err(e) = callback(e.into()) {
+                        Some(Err(e))
+                    } else {
+                        None
                     }
-                }),
-        )
+                } else {
+                    Some(Err(e.into()))
+                }
+            }
+        }))?;
+        self.register_prefixes(parser.prefixes());
+        Ok(())
     }
 
     /// Loads RDF file using the bulk loader.
@@ -1913,14 +4583,14 @@ impl BulkLoader<'_> {
         parser: impl Into<RdfParser>,
         path: impl AsRef<Path>,
     ) -> Result<(), LoaderError> {
+        let parser = parser.into();
         let target_num_threads = self.target_num_threads() / 2;
-        if target_num_threads < 2 {
+        if target_num_threads < 2 || !self.can_load_in_parallel(&parser) {
             return self.load_from_reader(parser, File::open(path).map_err(RdfParseError::from)?);
         }
         let target_batch_size = self.target_batch_size();
         let on_parse_error = self.on_parse_error.as_ref().map(Arc::clone);
         let parsers = parser
-            .into()
             .rename_blank_nodes()
             .split_file_for_parallel_parsing(path, target_num_threads)
             .map_err(RdfParseError::Io)?;
@@ -1964,7 +4634,7 @@ impl BulkLoader<'_> {
                 .collect::<Vec<_>>();
             drop(sender);
             while let Ok(batch) = receiver.recv() {
-                self.storage.load_batch(batch, target_num_threads)?;
+                self.save_batch(batch, target_num_threads)?;
             }
             for thread in threads {
                 map_thread_result(thread.join()).map_err(StorageError::from)??;
@@ -2024,14 +4694,14 @@ impl BulkLoader<'_> {
         parser: impl Into<RdfParser>,
         slice: &(impl AsRef<[u8]> + ?Sized),
     ) -> Result<(), LoaderError> {
+        let parser = parser.into();
         let target_num_threads = self.target_num_threads() / 2;
-        if target_num_threads < 2 {
+        if target_num_threads < 2 || !self.can_load_in_parallel(&parser) {
             return self.load_from_slice(parser, slice);
         }
         let target_batch_size = self.target_batch_size();
         let on_parse_error = self.on_parse_error.as_ref().map(Arc::clone);
         let parsers = parser
-            .into()
             .rename_blank_nodes()
             .split_slice_for_parallel_parsing(slice, target_num_threads);
         thread::scope(|scope| {
@@ -2074,7 +4744,7 @@ impl BulkLoader<'_> {
                 .collect::<Vec<_>>();
             drop(sender);
             while let Ok(batch) = receiver.recv() {
-                self.storage.load_batch(batch, target_num_threads)?;
+                self.save_batch(batch, target_num_threads)?;
             }
             for thread in threads {
                 map_thread_result(thread.join()).map_err(StorageError::from)??;
@@ -2108,18 +4778,184 @@ impl BulkLoader<'_> {
             if batch.len() >= target_batch_size {
                 let mut batch_to_save = Vec::with_capacity(target_batch_size);
                 swap(&mut batch, &mut batch_to_save);
-                self.storage.load_batch(batch_to_save, target_num_threads)?;
+                self.save_batch(batch_to_save, target_num_threads)?;
             }
         }
         if !batch.is_empty() {
-            self.storage.load_batch(batch, target_num_threads)?;
+            self.save_batch(batch, target_num_threads)?;
         }
         Ok(())
     }
 
     /// Saves all the quads loaded using the bulk loader into the store.
     pub fn commit(self) -> Result<(), StorageError> {
-        self.storage.commit()
+        self.storage.commit()?;
+        for (prefix, namespace) in self.namespaces.into_iter().flatten() {
+            if is_valid_prefix_name(&prefix) {
+                self.namespace_storage.set_namespace(&prefix, &namespace)?;
+            }
+        }
+        if let Some(provenance) = &self.provenance {
+            let mut transaction = self.namespace_storage.start_transaction()?;
+            for quad in provenance.quads() {
+                transaction.insert(quad.as_ref());
+            }
+            transaction.commit()?;
+        }
+        #[cfg(feature = "history")]
+        if let Some(recorder) = self.namespace_storage.history_recorder() {
+            let mut transaction = self.namespace_storage.start_transaction()?;
+            for quad in recorder.bulk_load_quads() {
+                transaction.insert(quad.as_ref());
+            }
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    fn save_batch(
+        &mut self,
+        mut batch: Vec<Quad>,
+        target_num_threads: usize,
+    ) -> Result<(), StorageError> {
+        if let Some(graph_mapper) = &self.graph_mapper {
+            for quad in &mut batch {
+                quad.graph_name = graph_mapper(quad.graph_name.as_ref());
+            }
+        }
+        if let Some(provenance) = &mut self.provenance {
+            for quad in &batch {
+                provenance.record(quad.as_ref());
+            }
+        }
+        self.storage.load_batch(batch, target_num_threads)
+    }
+
+    fn register_prefixes<'b>(&mut self, prefixes: impl IntoIterator<Item = (&'b str, &'b str)>) {
+        if let Some(namespaces) = &mut self.namespaces {
+            namespaces.extend(
+                prefixes
+                    .into_iter()
+                    .map(|(prefix, namespace)| (prefix.into(), namespace.into())),
+            );
+        }
+    }
+
+    /// Only N-Triples and N-Quads are split between threads and they do not declare prefixes
+    #[cfg(not(target_family = "wasm"))]
+    fn can_load_in_parallel(&self, parser: &RdfParser) -> bool {
+        self.namespaces.is_none()
+            || matches!(
+                parser.format(),
+                crate::io::RdfFormat::NTriples | crate::io::RdfFormat::NQuads
+            )
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+const DEFAULT_BULK_LOAD_CHECKPOINT_QUADS: u64 = 10_000_000;
+#[cfg(not(target_family = "wasm"))]
+const DEFAULT_BULK_LOAD_CHECKPOINT_DURATION: Duration = Duration::from_secs(60);
+
+/// The state saved in a [`BulkLoader`] checkpoint file
+#[cfg(not(target_family = "wasm"))]
+struct Checkpoint {
+    /// The number of input bytes already loaded
+    offset: u64,
+    /// The prefix added to the input blank node identifiers
+    blank_node_prefix: String,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Checkpoint {
+    fn new() -> Self {
+        Self {
+            offset: 0,
+            blank_node_prefix: format!("{:x}", rand::random::<u128>()),
+        }
+    }
+
+    fn read(path: &Path) -> io::Result<Option<Self>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let mut offset = None;
+        let mut blank_node_prefix = None;
+        for line in content.lines() {
+            match line.split_once(' ') {
+                Some(("offset", value)) => offset = value.parse().ok(),
+                Some(("blank_node_prefix", value))
+                    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric()) =>
+                {
+                    blank_node_prefix = Some(value.to_owned())
+                }
+                _ => (),
+            }
+        }
+        let (Some(offset), Some(blank_node_prefix)) = (offset, blank_node_prefix) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid bulk loader checkpoint file",
+            ));
+        };
+        Ok(Some(Self {
+            offset,
+            blank_node_prefix,
+        }))
+    }
+
+    /// Writes the checkpoint into a temporary file and then renames it to never leave a partially written checkpoint
+    fn write(&self, path: &Path) -> io::Result<()> {
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        let mut file = File::create(&temp_path)?;
+        write!(
+            file,
+            "offset {}\nblank_node_prefix {}\n",
+            self.offset, self.blank_node_prefix
+        )?;
+        file.sync_all()?;
+        fs::rename(temp_path, path)
+    }
+
+    fn rename_blank_nodes(&self, quad: Quad) -> Quad {
+        Quad {
+            subject: self.rename_subject(quad.subject),
+            predicate: quad.predicate,
+            object: self.rename_term(quad.object),
+            graph_name: match quad.graph_name {
+                GraphName::BlankNode(node) => self.rename_blank_node(&node).into(),
+                graph_name => graph_name,
+            },
+        }
+    }
+
+    fn rename_subject(&self, subject: NamedOrBlankNode) -> NamedOrBlankNode {
+        match subject {
+            NamedOrBlankNode::BlankNode(node) => self.rename_blank_node(&node).into(),
+            NamedOrBlankNode::NamedNode(node) => node.into(),
+        }
+    }
+
+    fn rename_term(&self, term: Term) -> Term {
+        match term {
+            Term::BlankNode(node) => self.rename_blank_node(&node).into(),
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(triple) => Triple {
+                subject: self.rename_subject(triple.subject),
+                predicate: triple.predicate,
+                object: self.rename_term(triple.object),
+            }
+            .into(),
+            Term::NamedNode(_) | Term::Literal(_) => term,
+        }
+    }
+
+    fn rename_blank_node(&self, node: &BlankNode) -> BlankNode {
+        BlankNode::new_unchecked(format!("{}{}", self.blank_node_prefix, node.as_str()))
     }
 }
 
@@ -2351,4 +5187,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn stats() -> Result<(), StorageError> {
+        let ex = NamedNodeRef::new_unchecked("http://example.com/");
+        let g1 = NamedNodeRef::new_unchecked("http://example.com/g1");
+        let g2 = NamedNodeRef::new_unchecked("http://example.com/g2");
+        let store = Store::new()?;
+        store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+        store.insert(QuadRef::new(ex, ex, g1, g1))?;
+        store.insert(QuadRef::new(g1, ex, g2, g1))?;
+        store.insert_named_graph(g2)?;
+
+        let stats = store.stats(false)?;
+        assert_eq!(stats.quads, 3);
+        assert_eq!(stats.named_graphs, 2);
+        assert_eq!(
+            stats.graph_quads,
+            vec![
+                (GraphName::DefaultGraph, 1),
+                (g1.into_owned().into(), 2),
+                (g2.into_owned().into(), 0)
+            ]
+        );
+        assert_eq!(stats.distinct_subjects, Some(2));
+        assert_eq!(stats.distinct_predicates, Some(1));
+        assert_eq!(stats.distinct_objects, Some(3));
+        assert!(stats.column_family_sizes.is_empty());
+        // Approximate statistics are not supported in memory, we get the exact ones
+        assert_eq!(store.stats(true)?, stats);
+
+        // The cache is invalidated by modifications
+        store.insert(QuadRef::new(g2, ex, ex, g2))?;
+        let stats = store.stats(false)?;
+        assert_eq!(stats.quads, 4);
+        assert_eq!(stats.distinct_subjects, Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn distinct_counter() {
+        for size in [0, 10, 1_000, 100_000] {
+            let mut counter = DistinctCounter::default();
+            for i in 0..size {
+                counter.add(&i);
+                counter.add(&i);
+            }
+            let estimate = counter.estimate();
+            assert!(
+                estimate.abs_diff(size) <= size / 20,
+                "{estimate} is not close to {size}"
+            );
+        }
+    }
 }
@@ -1,11 +1,43 @@
 #![cfg(test)]
 #![allow(clippy::panic_in_result_fn)]
 
-use oxigraph::io::RdfFormat;
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+use oxhttp::Server;
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+use oxhttp::model::header::{CONTENT_TYPE, LOCATION};
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+use oxhttp::model::{Body, Response, StatusCode};
+#[cfg(feature = "rdf-12")]
+use oxigraph::io::RdfStarSerializationPolicy;
+use oxigraph::io::{
+    CsvRdfParser, InvalidIriPolicy, LiteralDecision, RdfFormat, RdfParseError, RdfParser,
+    RdfSerializer, RdfStarPolicy, TurtleSyntaxErrorKind, ValidationPolicy,
+};
 use oxigraph::model::vocab::{rdf, xsd};
 use oxigraph::model::*;
-use oxigraph::store::Store;
+use oxigraph::sparql::{
+    CancellationToken, DefaultGraphMode, QueryEvaluationError, QueryResults,
+    QuerySubscriptionError, QuerySubscriptionOptions, SparqlEvaluator, UpdateEvaluationError,
+};
+#[cfg(feature = "tracing")]
+use oxigraph::sparql::{QuerySolutionIter, ServiceHandler};
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+use oxigraph::store::IriLoadError;
+use oxigraph::store::{
+    Durability, FederatedStore, LoadProvenance, LoaderError, ReadListError, RenameGraphError,
+    SerializerError, SetNamespaceError, StorageError, Store, TermId,
+};
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+use oxigraph::store::{StorageCompression, StoreOptions};
+#[cfg(feature = "tracing")]
+use oxiri::Iri;
+use oxsdatatypes::{DateTime, Decimal};
+#[cfg(feature = "tracing")]
+use spargebra::algebra::GraphPattern;
+use std::collections::HashSet;
 use std::error::Error;
+#[cfg(feature = "tracing")]
+use std::fmt::{self, Debug, Display, Write as _};
 #[cfg(all(target_os = "linux", feature = "rocksdb"))]
 use std::fs::remove_dir_all;
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
@@ -17,18 +49,17 @@ use std::fs::{File, create_dir_all, read_dir, remove_dir};
     feature = "rocksdb"
 ))]
 use std::fs::{read, write};
-#[cfg(all(
-    target_os = "linux",
-    target_pointer_width = "64",
-    target_endian = "little",
-    feature = "rocksdb"
-))]
 use std::io;
+#[cfg(not(target_family = "wasm"))]
+use std::io::Cursor;
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 use std::io::Write;
 use std::iter::empty;
 #[cfg(all(target_os = "linux", feature = "rocksdb"))]
 use std::iter::once;
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::ops::Bound;
 #[cfg(all(
     target_os = "linux",
     target_pointer_width = "64",
@@ -36,8 +67,21 @@ use std::iter::once;
     feature = "rocksdb"
 ))]
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 use tempfile::TempDir;
+#[cfg(feature = "tracing")]
+use tracing::Level;
+#[cfg(feature = "tracing")]
+use tracing::field::{Field, Visit};
+#[cfg(feature = "tracing")]
+use tracing::span::{Attributes, Id};
+#[cfg(feature = "tracing")]
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+#[cfg(feature = "tracing")]
+use tracing_subscriber::util::SubscriberInitExt;
 
 #[expect(clippy::non_ascii_literal)]
 const DATA: &str = r#"
@@ -230,6 +274,154 @@ fn test_bulk_load_rollback() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_bulk_load_atomic_interrupted() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    check_bulk_load_atomic_interrupted(&store, "memory")
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[test]
+fn test_bulk_load_atomic_interrupted_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    let before_files = read_dir(&dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    check_bulk_load_atomic_interrupted(&store, "disk")?;
+    let after_files = read_dir(&dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(before_files, after_files);
+    Ok(())
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn check_bulk_load_atomic_interrupted(store: &Store, kind: &str) -> Result<(), Box<dyn Error>> {
+    let checkpoint =
+        std::env::temp_dir().join(format!("oxigraph-test-bulk-load-atomic-checkpoint-{kind}"));
+    drop(std::fs::remove_file(&checkpoint));
+    let mut loader = store
+        .bulk_loader()
+        .with_checkpoint(&checkpoint)
+        .with_batch_size(10)
+        .atomic();
+    loader.load_quads(numbered_quads(100))?;
+    drop(loader);
+    assert!(store.is_empty()?);
+    assert!(!checkpoint.exists());
+    store.validate()?;
+    Ok(())
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[test]
+fn test_bulk_load_interrupted_without_atomicity() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    let mut loader = store
+        .bulk_loader()
+        .with_num_threads(1)
+        .with_batch_size(10)
+        .without_atomicity();
+    loader.load_quads(numbered_quads(100))?;
+    drop(loader);
+    // With a single thread, each batch is saved before the next one is written, the last one is lost
+    assert_eq!(store.len()?, 90);
+    for quad in numbered_quads(90) {
+        assert!(store.contains(&quad)?);
+    }
+    store.validate()?;
+    Ok(())
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[test]
+fn test_bulk_load_files_of_crashed_load_are_removed() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    drop(Store::open(&dir)?);
+    // A bulk loading crashed before inserting its files into the database
+    let leftover = dir.path().join("bulk-42.sst");
+    File::create(&leftover)?.write_all(b"not a valid SST file")?;
+    let store = Store::open(&dir)?;
+    assert!(!leftover.exists());
+    assert!(store.is_empty()?);
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_bulk_load_durability() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    check_bulk_load_durability(&store)
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+#[test]
+fn test_bulk_load_durability_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_bulk_load_durability(&Store::open(&dir)?)?;
+    let store = Store::open(&dir)?;
+    assert_eq!(store.len()?, 20);
+    store.validate()?;
+    Ok(())
+}
+
+fn check_bulk_load_durability(store: &Store) -> Result<(), Box<dyn Error>> {
+    for (durability, quads) in [(Durability::Full, 0..10), (Durability::Relaxed, 10..20)] {
+        let mut loader = store
+            .bulk_loader()
+            .with_batch_size(3)
+            .with_durability(durability);
+        loader.load_quads(numbered_quads(20).skip(quads.start).take(quads.len()))?;
+        loader.commit()?;
+    }
+    assert_eq!(store.len()?, 20);
+    store.validate()?;
+    Ok(())
+}
+
+fn numbered_quads(count: usize) -> impl Iterator<Item = Quad> {
+    let p = NamedNodeRef::new_unchecked("http://example.com/p");
+    (0..count).map(move |i| {
+        Quad::new(
+            NamedNode::new_unchecked(format!("http://example.com/s{i}")),
+            p,
+            Literal::from(i64::try_from(i).unwrap_or(i64::MAX)),
+            GraphNameRef::DefaultGraph,
+        )
+    })
+}
+
+#[test]
+fn test_bulk_load_csv() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let mut loader = store.bulk_loader();
+    loader.load_ok_quads::<_, LoaderError>(
+        CsvRdfParser::new()
+            .with_base_iri("http://example.com/people.csv")?
+            .with_datatype_inference()
+            .for_reader(b"name,age\nFoo,12\nBar,\n".as_slice()),
+    )?;
+    loader.commit()?;
+    assert_eq!(store.len()?, 3);
+    let age = NamedNodeRef::new("http://example.com/people.csv#age")?;
+    assert!(
+        store
+            .quads_for_pattern(
+                None,
+                Some(age),
+                Some(Literal::from(12).as_ref().into()),
+                None
+            )
+            .next()
+            .is_some()
+    );
+    store.validate()?;
+    Ok(())
+}
+
 #[test]
 fn test_load_dataset() -> Result<(), Box<dyn Error>> {
     let store = Store::new()?;
@@ -259,6 +451,339 @@ fn test_bulk_load_dataset() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_load_with_literal_validation() -> Result<(), Box<dyn Error>> {
+    let data = "<http://example.com/s> <http://example.com/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n<http://example.com/s> <http://example.com/p> \"abc\"^^<http://www.w3.org/2001/XMLSchema#integer> .";
+
+    let store = Store::new()?;
+    let Err(LoaderError::Parsing(RdfParseError::Syntax(error))) = store.load_from_slice(
+        RdfParser::from_format(RdfFormat::NTriples)
+            .with_literal_validation(ValidationPolicy::Reject),
+        data,
+    ) else {
+        unreachable!("the invalid literal should be rejected")
+    };
+    assert!(matches!(error.location(), Some(location) if location.start.line == 1));
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let warnings2 = Arc::clone(&warnings);
+    let mut loader = store.bulk_loader();
+    loader.load_from_slice(
+        RdfParser::from_format(RdfFormat::NTriples).with_literal_validation(
+            ValidationPolicy::Warn(Arc::new(move |error| {
+                warnings2.lock().unwrap().push(error.value().to_owned())
+            })),
+        ),
+        data,
+    )?;
+    loader.commit()?;
+    assert_eq!(*warnings.lock().map_err(|e| e.to_string())?, ["abc"]);
+    assert_eq!(store.len()?, 2);
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_load_syntax_error_kind() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let Err(LoaderError::Parsing(RdfParseError::Syntax(error))) = store.load_from_slice(
+        RdfFormat::Turtle,
+        "<http://example.com/s> <http://example.com/p> ex:o .",
+    ) else {
+        unreachable!("the undefined prefix should be rejected")
+    };
+    assert!(matches!(
+        error.turtle_kind(),
+        Some(TurtleSyntaxErrorKind::UndefinedPrefix { prefix }) if prefix == "ex"
+    ));
+    assert!(store.is_empty()?);
+    Ok(())
+}
+
+#[test]
+fn test_load_with_literal_policy() -> Result<(), Box<dyn Error>> {
+    let data = "<http://example.com/s> <http://example.com/p> \"a\"@EN-US .\n<http://example.com/s> <http://example.com/p> \"b\"@en-a .";
+    let parser = RdfParser::from_format(RdfFormat::Turtle)
+        .with_literal_policy(|literal| LiteralDecision::check_language_tag(literal, true));
+
+    let store = Store::new()?;
+    let Err(LoaderError::Parsing(RdfParseError::Syntax(error))) =
+        store.load_from_slice(parser.clone(), data)
+    else {
+        unreachable!("the invalid language tag should be rejected")
+    };
+    assert!(matches!(error.location(), Some(location) if location.start.line == 1));
+    assert!(store.is_empty()?);
+
+    let mut loader = store.bulk_loader().on_parse_error(|_| Ok(()));
+    loader.load_from_slice(parser.lenient(), data)?;
+    loader.commit()?;
+    assert_eq!(
+        store
+            .iter()
+            .map(|q| q.map(|q| q.object))
+            .collect::<Result<Vec<_>, _>>()?,
+        [Term::from(Literal::new_language_tagged_literal_unchecked(
+            "a", "en-us"
+        ))]
+    );
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_load_with_rdf_star_policies() -> Result<(), Box<dyn Error>> {
+    let data = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<< <http://example.com/s> <http://example.com/p> <http://example.com/o> >> <http://example.com/source> <http://example.com/doc> .";
+
+    let store = Store::new()?;
+    let Err(LoaderError::Parsing(RdfParseError::Syntax(error))) = store.load_from_slice(
+        RdfParser::from_format(RdfFormat::Turtle).with_rdf_star(RdfStarPolicy::Reject),
+        data,
+    ) else {
+        unreachable!("the reified triple should be rejected")
+    };
+    assert!(matches!(error.location(), Some(location) if location.start.line == 1));
+    assert!(store.is_empty()?);
+
+    #[cfg(feature = "rdf-12")]
+    {
+        let stripped = Arc::new(Mutex::new(Vec::new()));
+        let stripped2 = Arc::clone(&stripped);
+        let mut loader = store.bulk_loader();
+        loader.load_from_slice(
+            RdfParser::from_format(RdfFormat::Turtle).with_rdf_star(RdfStarPolicy::Strip(
+                Arc::new(move |quad| stripped2.lock().unwrap().push(quad.clone())),
+            )),
+            data,
+        )?;
+        loader.commit()?;
+        assert_eq!(stripped.lock().map_err(|e| e.to_string())?.len(), 1);
+        assert_eq!(store.len()?, 2);
+        assert!(
+            !store
+                .iter()
+                .any(|q| q.is_ok_and(|q| q.predicate == rdf::REIFIES))
+        );
+
+        store.clear()?;
+        store.load_from_slice(
+            RdfParser::from_format(RdfFormat::Turtle).with_rdf_star(RdfStarPolicy::Accept),
+            data,
+        )?;
+        assert_eq!(store.len()?, 3);
+    }
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rdf-12")]
+fn test_dump_with_rdf_star_policies() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_slice(
+        RdfFormat::Turtle,
+        "<http://example.com/s> <http://example.com/p> <http://example.com/o> ~ <http://example.com/r> {| <http://example.com/source> <http://example.com/doc> |} .",
+    )?;
+
+    let as_is = store.dump_to_writer(
+        RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_rdf_star(RdfStarSerializationPolicy::WriteAsIs),
+        Vec::new(),
+    )?;
+    assert!(String::from_utf8(as_is)?.contains("<<("));
+
+    let error = store
+        .dump_to_writer(
+            RdfSerializer::from_format(RdfFormat::NQuads)
+                .with_rdf_star(RdfStarSerializationPolicy::Error),
+            Vec::new(),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(&error, SerializerError::Io(error) if error.kind() == io::ErrorKind::InvalidInput)
+    );
+
+    let reified = store.dump_graph_to_writer(
+        GraphNameRef::DefaultGraph,
+        RdfSerializer::from_format(RdfFormat::RdfXml)
+            .with_rdf_star(RdfStarSerializationPolicy::Reify),
+        Vec::new(),
+    )?;
+    let reloaded = Store::new()?;
+    reloaded.load_from_slice(RdfFormat::RdfXml, &reified)?;
+    let r = NamedNodeRef::new_unchecked("http://example.com/r");
+    for (predicate, object) in [
+        (rdf::TYPE, TermRef::from(rdf::STATEMENT)),
+        (
+            rdf::SUBJECT,
+            NamedNodeRef::new_unchecked("http://example.com/s").into(),
+        ),
+        (
+            rdf::PREDICATE,
+            NamedNodeRef::new_unchecked("http://example.com/p").into(),
+        ),
+        (
+            rdf::OBJECT,
+            NamedNodeRef::new_unchecked("http://example.com/o").into(),
+        ),
+    ] {
+        assert!(reloaded.contains(QuadRef::new(
+            r,
+            predicate,
+            object,
+            GraphNameRef::DefaultGraph
+        ))?);
+    }
+    assert_eq!(reloaded.len()?, 6);
+    Ok(())
+}
+
+#[test]
+fn test_load_dataset_with_graph_mapper() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let target = NamedNodeRef::new_unchecked("http://example.com/target");
+    store.load_from_reader(
+        RdfParser::from_format(RdfFormat::TriG).with_graph_mapper(move |graph_name| {
+            if graph_name.is_default_graph() {
+                target.into()
+            } else {
+                graph_name.into_owned()
+            }
+        }),
+        GRAPH_DATA.as_bytes(),
+    )?;
+    store.load_from_reader(
+        RdfParser::from_format(RdfFormat::Turtle).with_graph_mapper(move |graph_name| {
+            if graph_name.is_default_graph() {
+                target.into()
+            } else {
+                graph_name.into_owned()
+            }
+        }),
+        DATA.as_bytes(),
+    )?;
+    for q in quads(target) {
+        assert!(store.contains(q)?);
+    }
+    for q in quads(NamedNodeRef::new_unchecked(
+        "http://www.wikidata.org/wiki/Special:EntityData/Q90",
+    )) {
+        assert!(store.contains(q)?);
+    }
+    assert_eq!(store.len()?, 2 * NUMBER_OF_TRIPLES);
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_bulk_load_dataset_with_target_graph() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let target = NamedNodeRef::new_unchecked("http://example.com/target");
+    let mut loader = store.bulk_loader().with_target_graph(target);
+    loader.load_from_slice(RdfFormat::TriG, GRAPH_DATA.as_bytes())?;
+    loader.load_from_slice(RdfFormat::Turtle, DATA.as_bytes())?;
+    loader.commit()?;
+    for q in quads(target) {
+        assert!(store.contains(q)?);
+    }
+    assert_eq!(store.len()?, NUMBER_OF_TRIPLES);
+    assert_eq!(
+        store.named_graphs().collect::<Result<Vec<_>, _>>()?,
+        [NamedOrBlankNode::from(target.into_owned())]
+    );
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_bulk_load_dataset_with_blank_node_graph_mapper() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let mut loader = store
+        .bulk_loader()
+        .with_graph_mapper(|graph_name| match graph_name {
+            GraphNameRef::BlankNode(_) => GraphName::DefaultGraph,
+            GraphNameRef::DefaultGraph => NamedNode::new_unchecked("http://example.com/g").into(),
+            GraphNameRef::NamedNode(named_node) => named_node.into_owned().into(),
+        });
+    loader.load_from_slice(
+        RdfFormat::TriG,
+        "_:g { <http://example.com/s> <http://example.com/p> _:g } <http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+    )?;
+    loader.commit()?;
+    let s = NamedNodeRef::new("http://example.com/s")?;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    let o = NamedNodeRef::new("http://example.com/o")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    assert_eq!(store.len()?, 2);
+    assert!(store.contains(QuadRef::new(s, p, o, g))?);
+    assert_eq!(
+        store
+            .quads_for_pattern(
+                Some(s.into()),
+                Some(p),
+                None,
+                Some(GraphNameRef::DefaultGraph)
+            )
+            .count(),
+        1
+    );
+    assert_eq!(store.named_graphs().count(), 1);
+    store.validate()?;
+    Ok(())
+}
+
+#[test]
+fn test_collect_owned_solutions() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_reader(RdfFormat::Turtle, DATA.as_bytes())?;
+    let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+        .parse_query("SELECT ?s ?p ?o WHERE { ?s ?p ?o }")?
+        .on_store(&store)
+        .execute()?
+    else {
+        unreachable!()
+    };
+    let solutions = solutions.collect_owned()?;
+    drop(store);
+    let count = std::thread::spawn(move || solutions.column("o").flatten().count())
+        .join()
+        .map_err(|_| "the counting thread panicked")?;
+    assert_eq!(count, NUMBER_OF_TRIPLES);
+    Ok(())
+}
+
+#[test]
+fn test_load_with_deterministic_blank_node_ids() -> Result<(), Box<dyn Error>> {
+    let data = "@prefix ex: <http://example.com/> .\n[] ex:p [ ex:q _:a ] .\n_:a ex:r ( 1 2 ) .";
+    let dump = || -> Result<Vec<u8>, Box<dyn Error>> {
+        let store = Store::new()?;
+        store.load_from_reader(
+            RdfParser::from_format(RdfFormat::Turtle).with_deterministic_blank_node_ids(42),
+            data.as_bytes(),
+        )?;
+        // We use a different seed to not reuse the parser blank node ids
+        let QueryResults::Graph(triples) = SparqlEvaluator::new()
+            .with_deterministic_blank_node_ids(1 << 64)
+            .parse_query("CONSTRUCT { ?s ?p [ <http://example.com/v> ?o ] } WHERE { ?s ?p ?o }")?
+            .on_store(&store)
+            .execute()?
+        else {
+            unreachable!()
+        };
+        for triple in triples.collect::<Result<Vec<_>, _>>()? {
+            store.insert(&triple.in_graph(GraphName::DefaultGraph))?;
+        }
+        let dump = store.dump_graph_to_writer(
+            GraphNameRef::DefaultGraph,
+            RdfFormat::Turtle,
+            Vec::new(),
+        )?;
+        Ok(dump)
+    };
+    assert_eq!(dump()?, dump()?);
+    Ok(())
+}
+
 #[test]
 fn test_load_graph_generates_new_blank_nodes() -> Result<(), Box<dyn Error>> {
     let store = Store::new()?;
@@ -346,68 +871,2476 @@ fn test_snapshot_isolation_iterator_on_disk() -> Result<(), Box<dyn Error>> {
 }
 
 #[test]
-fn test_bulk_load_on_existing_delete_overrides_the_delete() -> Result<(), Box<dyn Error>> {
-    let quad = QuadRef::new(
-        NamedNodeRef::new_unchecked("http://example.com/s"),
-        NamedNodeRef::new_unchecked("http://example.com/p"),
-        NamedNodeRef::new_unchecked("http://example.com/o"),
-        NamedNodeRef::new_unchecked("http://www.wikidata.org/wiki/Special:EntityData/Q90"),
-    );
-    let store = Store::new()?;
-    store.remove(quad)?;
-    let mut loader = store.bulk_loader();
-    loader.load_quads([quad.into_owned()])?;
-    loader.commit()?;
-    assert_eq!(store.len()?, 1);
-    Ok(())
+fn test_transaction_read_your_writes() -> Result<(), Box<dyn Error>> {
+    check_transaction_read_your_writes(&Store::new()?)
 }
 
 #[test]
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-fn test_bulk_load_on_existing_delete_overrides_the_delete_on_disk() -> Result<(), Box<dyn Error>> {
-    let quad = QuadRef::new(
-        NamedNodeRef::new_unchecked("http://example.com/s"),
-        NamedNodeRef::new_unchecked("http://example.com/p"),
-        NamedNodeRef::new_unchecked("http://example.com/o"),
-        NamedNodeRef::new_unchecked("http://www.wikidata.org/wiki/Special:EntityData/Q90"),
-    );
+fn test_transaction_read_your_writes_on_disk() -> Result<(), Box<dyn Error>> {
     let dir = TempDir::new()?;
-    let store = Store::open(&dir)?;
-    store.remove(quad)?;
-    let mut loader = store.bulk_loader();
-    loader.load_quads([quad.into_owned()])?;
-    loader.commit()?;
-    assert_eq!(store.len()?, 1);
-    Ok(())
+    check_transaction_read_your_writes(&Store::open(&dir)?)
 }
 
-#[test]
-#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
-fn test_open_bad_dir() -> Result<(), Box<dyn Error>> {
-    let dir = TempDir::new()?;
-    create_dir_all(&dir)?;
-    {
-        File::create(dir.as_ref().join("CURRENT"))?.write_all(b"foo")?;
-    }
-    assert!(Store::open(&dir).is_err());
+fn check_transaction_read_your_writes(store: &Store) -> Result<(), Box<dyn Error>> {
+    let s = NamedNodeRef::new("http://example.com/s")?;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let o = |i: u32| Literal::from(i);
+    let quad = |i: u32, graph_name: GraphNameRef<'_>| Quad::new(s, p, o(i), graph_name);
+    let count_objects = |transaction: &oxigraph::store::Transaction<'_>,
+                         graph_name: GraphNameRef<'_>|
+     -> Result<usize, StorageError> {
+        Ok(transaction
+            .quads_for_pattern(Some(s.into()), Some(p), None, Some(graph_name))
+            .collect::<Result<Vec<_>, _>>()?
+            .len())
+    };
+    store.insert(&quad(1, GraphNameRef::DefaultGraph))?;
+    store.insert(&quad(2, GraphNameRef::DefaultGraph))?;
+    store.insert(&quad(1, g.into()))?;
+
+    let mut transaction = store.start_transaction()?;
+    // Insertion of a new quad
+    transaction.insert(&quad(3, GraphNameRef::DefaultGraph));
+    assert!(transaction.contains(&quad(3, GraphNameRef::DefaultGraph))?);
+    assert_eq!(count_objects(&transaction, GraphNameRef::DefaultGraph)?, 3);
+    assert_eq!(transaction.len()?, 4);
+    // Removal of a committed quad
+    transaction.remove(&quad(1, GraphNameRef::DefaultGraph));
+    assert!(!transaction.contains(&quad(1, GraphNameRef::DefaultGraph))?);
+    assert!(transaction.contains(&quad(1, g.into()))?);
+    assert_eq!(
+        transaction
+            .quads_for_pattern(None, None, Some(o(1).as_ref().into()), None)
+            .collect::<Result<Vec<_>, _>>()?,
+        vec![quad(1, g.into())]
+    );
+    assert_eq!(transaction.len()?, 3);
+    // Removal of a quad inserted in the same transaction
+    transaction.insert(&quad(4, g.into()));
+    assert_eq!(count_objects(&transaction, g.into())?, 2);
+    transaction.remove(&quad(4, g.into()));
+    assert_eq!(count_objects(&transaction, g.into())?, 1);
+    assert!(!transaction.contains(&quad(4, g.into()))?);
+    // Insertion back of a removed quad
+    transaction.insert(&quad(1, GraphNameRef::DefaultGraph));
+    transaction.remove(&quad(2, GraphNameRef::DefaultGraph));
+    let objects = transaction
+        .quads_for_pattern(None, None, None, Some(GraphNameRef::DefaultGraph))
+        .map(|q| Ok(q?.object))
+        .collect::<Result<HashSet<_>, StorageError>>()?;
+    assert_eq!(objects, HashSet::from([o(1).into(), o(3).into()]));
+    assert_eq!(transaction.len()?, 3);
+    // SPARQL queries see the same state
+    let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+        .parse_query(
+            "SELECT ?o WHERE { <http://example.com/s> <http://example.com/p> ?o } ORDER BY ?o",
+        )?
+        .on_transaction(&transaction)
+        .execute()?
+    else {
+        return Err("Solutions expected".into());
+    };
+    let objects = solutions
+        .map(|solution| Ok(solution?.get("o").cloned()))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    assert_eq!(objects, vec![Some(o(1).into()), Some(o(3).into())]);
+    assert!(matches!(
+        SparqlEvaluator::new()
+            .parse_query("ASK { GRAPH <http://example.com/g> { ?s ?p 4 } }")?
+            .on_transaction(&transaction)
+            .execute()?,
+        QueryResults::Boolean(false)
+    ));
+
+    // The store is not changed before the commit
+    assert!(!store.contains(&quad(3, GraphNameRef::DefaultGraph))?);
+    assert!(store.contains(&quad(2, GraphNameRef::DefaultGraph))?);
+    transaction.commit()?;
+    assert!(store.contains(&quad(1, GraphNameRef::DefaultGraph))?);
+    assert!(!store.contains(&quad(2, GraphNameRef::DefaultGraph))?);
+    assert!(store.contains(&quad(3, GraphNameRef::DefaultGraph))?);
+    assert!(!store.contains(&quad(4, g.into()))?);
+    assert_eq!(store.len()?, 3);
+    store.validate()?;
     Ok(())
 }
 
 #[test]
-#[cfg(all(target_os = "linux", feature = "rocksdb"))]
-fn test_bad_stt_open() -> Result<(), Box<dyn Error>> {
-    let dir = TempDir::new()?;
-    let store = Store::open(&dir)?;
-    remove_dir_all(&dir)?;
-    let mut loader = store.bulk_loader();
-    loader.load_quads(once(Quad::new(
-        NamedNode::new_unchecked("http://example.com/s"),
-        NamedNode::new_unchecked("http://example.com/p"),
-        NamedNode::new_unchecked("http://example.com/o"),
-        GraphName::DefaultGraph,
-    )))?;
-    loader.commit().unwrap_err();
-    Ok(())
+fn test_quads_for_pattern_with_object_range() -> Result<(), Box<dyn Error>> {
+    check_quads_for_pattern_with_object_range(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_quads_for_pattern_with_object_range_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_quads_for_pattern_with_object_range(&Store::open(&dir)?)
+}
+
+fn check_quads_for_pattern_with_object_range(store: &Store) -> Result<(), Box<dyn Error>> {
+    let s = NamedNodeRef::new("http://example.com/s")?;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let objects = [
+        Literal::from(-5),
+        Literal::from(10),
+        Literal::new_typed_literal("12", xsd::INT),
+        Literal::from(Decimal::from(15)),
+        Literal::from(16.),
+        Literal::from(20_f32),
+        Literal::new_simple_literal("15"),
+        Literal::new_simple_literal("a very long string that is not inlined"),
+        Literal::new_language_tagged_literal_unchecked("b", "en"),
+        Literal::from(DateTime::from_str("2020-01-01T00:00:00Z")?),
+    ];
+    for object in &objects {
+        store.insert(QuadRef::new(s, p, object, GraphNameRef::DefaultGraph))?;
+        store.insert(QuadRef::new(s, s, object, g))?;
+    }
+
+    let objects_in_range = |predicate: Option<NamedNodeRef<'_>>,
+                            range: (Bound<Literal>, Bound<Literal>),
+                            graph_name: Option<GraphNameRef<'_>>|
+     -> Result<Vec<Term>, Box<dyn Error>> {
+        let mut objects = store
+            .quads_for_pattern_with_object_range(None, predicate, range, graph_name)
+            .map(|q| Ok(q?.object))
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        objects.sort_by_key(ToString::to_string);
+        Ok(objects)
+    };
+
+    let numbers = vec![
+        Term::from(Literal::from(10)),
+        Literal::from(12).into(),
+        Literal::from(Decimal::from(15)).into(),
+        Literal::from(16.).into(),
+    ];
+    let range = (
+        Bound::Included(Literal::from(10)),
+        Bound::Excluded(Literal::from(20)),
+    );
+    assert_eq!(objects_in_range(Some(p), range.clone(), None)?, numbers);
+    assert_eq!(
+        objects_in_range(Some(p), range.clone(), Some(GraphNameRef::DefaultGraph))?,
+        numbers
+    );
+    assert_eq!(
+        objects_in_range(None, range.clone(), Some(g.into()))?,
+        numbers
+    );
+    assert_eq!(objects_in_range(None, range.clone(), None)?.len(), 8);
+    assert_eq!(
+        objects_in_range(
+            Some(p),
+            (Bound::Unbounded, Bound::Included(Literal::from(0))),
+            None
+        )?,
+        vec![Term::from(Literal::from(-5))]
+    );
+    assert_eq!(
+        objects_in_range(
+            Some(p),
+            (
+                Bound::Excluded(Literal::new_simple_literal("15")),
+                Bound::Unbounded
+            ),
+            None
+        )?,
+        vec![Term::from(Literal::new_simple_literal(
+            "a very long string that is not inlined"
+        ))]
+    );
+    assert_eq!(
+        objects_in_range(
+            Some(p),
+            (
+                Bound::Included(Literal::from(DateTime::from_str("2019-01-01T00:00:00Z")?)),
+                Bound::Unbounded
+            ),
+            None
+        )?
+        .len(),
+        1
+    );
+    assert!(
+        objects_in_range(
+            Some(p),
+            (
+                Bound::Included(Literal::from(10)),
+                Bound::Included(Literal::new_simple_literal("20"))
+            ),
+            None
+        )?
+        .is_empty()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extend_with_report() -> Result<(), Box<dyn Error>> {
+    check_extend_with_report(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_extend_with_report_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_extend_with_report(&Store::open(&dir)?)
+}
+
+fn check_extend_with_report(store: &Store) -> Result<(), Box<dyn Error>> {
+    let default_quads = quads(GraphNameRef::DefaultGraph);
+    let report = store.extend_with_report(default_quads.iter().map(|q| q.into_owned()))?;
+    assert_eq!(report.inserted, default_quads.len());
+    assert_eq!(report.already_present, 0);
+    assert!(report.duplicates.is_empty());
+    assert!(report.has_changes());
+
+    let report = store.extend_with_report(default_quads.iter().map(|q| q.into_owned()))?;
+    assert_eq!(report.inserted, 0);
+    assert_eq!(report.already_present, default_quads.len());
+    assert_eq!(
+        report.duplicates,
+        (0..default_quads.len()).collect::<Vec<_>>()
+    );
+    assert!(!report.has_changes());
+
+    let graph = NamedNodeRef::new_unchecked("http://example.com/g");
+    let named_quads = quads(graph);
+    let mut transaction = store.start_transaction()?;
+    transaction.insert(named_quads[1]);
+    let report = transaction.extend_with_report([
+        default_quads[0],
+        named_quads[0],
+        named_quads[1],
+        named_quads[0],
+    ])?;
+    transaction.commit()?;
+    assert_eq!(report.inserted, 1);
+    assert_eq!(report.already_present, 3);
+    assert_eq!(report.duplicates, [0, 2, 3]);
+    assert_eq!(store.len()?, default_quads.len() + 2);
+    Ok(())
+}
+
+#[test]
+fn test_term_ids() -> Result<(), Box<dyn Error>> {
+    check_term_ids(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_term_ids_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_term_ids(&Store::open(&dir)?)
+}
+
+fn check_term_ids(store: &Store) -> Result<(), Box<dyn Error>> {
+    let s = NamedNodeRef::new("http://example.com/s")?;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let long = LiteralRef::new_simple_literal("a string that is too long to be inlined");
+    assert_eq!(store.term_id(s)?, None);
+    assert_eq!(store.term_id(long)?, None);
+
+    store.insert(QuadRef::new(s, p, long, GraphNameRef::DefaultGraph))?;
+    store.insert(QuadRef::new(s, p, &Literal::from(1), g))?;
+    let s_id = store.term_id(s)?.unwrap();
+    let p_id = store.term_id(p)?.unwrap();
+    let g_id = store.term_id(g)?.unwrap();
+    let long_id = store.term_id(long)?.unwrap();
+    assert_eq!(store.id_term(&s_id)?, Some(s.into_owned().into()));
+    assert_eq!(store.id_term(&long_id)?, Some(long.into_owned().into()));
+    assert_eq!(store.id_term(&TermId::DEFAULT_GRAPH)?, None);
+    // Inlined terms are always known
+    let other = store.term_id(&Literal::from(2))?.unwrap();
+    assert_eq!(store.id_term(&other)?, Some(Literal::from(2).into()));
+
+    let quads = store
+        .quads_for_pattern_ids(Some(&s_id), Some(&p_id), None, None)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(quads.len(), 2);
+    assert!(
+        quads
+            .iter()
+            .all(|q| q.subject == s_id && q.predicate == p_id)
+    );
+    let quads = store
+        .quads_for_pattern_ids(None, None, None, Some(&TermId::DEFAULT_GRAPH))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(quads.len(), 1);
+    assert_eq!(quads[0].object, long_id);
+    assert!(quads[0].graph_name.is_default_graph());
+    let quads = store
+        .quads_for_pattern_ids(None, None, Some(&other), None)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(quads.is_empty());
+    let quads = store
+        .quads_for_pattern_ids(None, None, None, Some(&g_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(quads.len(), 1);
+    assert_eq!(
+        store.id_term(&quads[0].object)?,
+        Some(Literal::from(1).into())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_validate_terms_and_dump_invalid_iris() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_slice(
+        RdfParser::from_format(RdfFormat::NQuads).lenient(),
+        "<http://example.com/a b> <http://example.com/p> \"foo\"@abcdefghijk .\n\
+         <http://example.com/s> <http://example.com/p> \"bar\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n\
+         <http://example.com/s> <http://example.com/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> <http://example.com/a b> .",
+    )?;
+    let report = store.validate_terms()?;
+    assert_eq!(
+        (report.iris, report.language_tags, report.literals),
+        (1, 1, 1)
+    );
+    assert!(!report.is_valid());
+
+    let error = store
+        .dump_to_writer(
+            RdfSerializer::from_format(RdfFormat::NQuads)
+                .with_invalid_iri_handling(InvalidIriPolicy::Error),
+            Vec::new(),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(&error, SerializerError::Io(error) if error.kind() == io::ErrorKind::InvalidInput)
+    );
+    let skipped = Arc::new(AtomicUsize::new(0));
+    let skipped_in_callback = Arc::clone(&skipped);
+    let dump = store.dump_to_writer(
+        RdfSerializer::from_format(RdfFormat::NQuads).with_invalid_iri_handling(
+            InvalidIriPolicy::Skip(Arc::new(move |_, _| {
+                skipped_in_callback.fetch_add(1, Ordering::Relaxed);
+            })),
+        ),
+        Vec::new(),
+    )?;
+    assert_eq!(skipped.load(Ordering::Relaxed), 2);
+    assert_eq!(
+        RdfParser::from_format(RdfFormat::NQuads)
+            .for_slice(&dump)
+            .count(),
+        1
+    );
+    let dump = store.dump_to_writer(
+        RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_invalid_iri_handling(InvalidIriPolicy::PercentEncode),
+        Vec::new(),
+    )?;
+    let fixed = Store::new()?;
+    fixed.load_from_slice(RdfParser::from_format(RdfFormat::NQuads).lenient(), &dump)?;
+    assert!(fixed.contains(QuadRef::new(
+        NamedNodeRef::new("http://example.com/s")?,
+        NamedNodeRef::new("http://example.com/p")?,
+        &Literal::from(1),
+        NamedNodeRef::new("http://example.com/a%20b")?,
+    ))?);
+    assert_eq!(fixed.validate_terms()?.iris, 0);
+    Ok(())
+}
+
+#[test]
+fn test_graph_modification() -> Result<(), Box<dyn Error>> {
+    check_graph_modification(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_graph_modification_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let ex = NamedNodeRef::new("http://example.com/g")?;
+    let before_reopening = {
+        let store = Store::open(&dir)?;
+        check_graph_modification(&store)?;
+        store.graph_modification(ex)?
+    };
+    let store = Store::open(&dir)?;
+    assert_eq!(store.graph_modification(ex)?, before_reopening);
+    store.insert(QuadRef::new(ex, ex, ex, ex))?;
+    assert!(store.graph_modification(ex)?.unwrap().counter > before_reopening.unwrap().counter);
+    Ok(())
+}
+
+fn check_graph_modification(store: &Store) -> Result<(), Box<dyn Error>> {
+    let g1 = NamedNodeRef::new("http://example.com/g")?;
+    let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    assert_eq!(store.graph_modification(g1)?, None);
+
+    store.insert(QuadRef::new(g1, g1, g1, g1))?;
+    let first = store.graph_modification(g1)?.unwrap();
+    assert_eq!(store.graph_modification(g2)?, None);
+    assert_eq!(store.graph_modification(GraphNameRef::DefaultGraph)?, None);
+
+    // Rolled back transactions do not modify graphs
+    let mut transaction = store.start_transaction()?;
+    transaction.insert(QuadRef::new(g1, g1, g2, g1));
+    drop(transaction);
+    assert_eq!(store.graph_modification(g1)?, Some(first));
+
+    store.insert(QuadRef::new(g2, g2, g2, g2))?;
+    assert_eq!(store.graph_modification(g1)?, Some(first));
+    let second = store.graph_modification(g2)?.unwrap();
+    assert!(second.counter > first.counter);
+
+    store.update(
+        "INSERT DATA { <http://example.com/s> <http://example.com/p> <http://example.com/o> }",
+    )?;
+    assert!(
+        store
+            .graph_modification(GraphNameRef::DefaultGraph)?
+            .unwrap()
+            .counter
+            > second.counter
+    );
+    assert_eq!(store.graph_modification(g1)?, Some(first));
+
+    // Clearing all named graphs modifies all of them
+    store.update("CLEAR NAMED")?;
+    let cleared = store.graph_modification(g1)?.unwrap();
+    assert!(cleared.counter > second.counter);
+    assert_eq!(store.graph_modification(g2)?, Some(cleared));
+    Ok(())
+}
+
+#[test]
+fn test_graph_management() -> Result<(), Box<dyn Error>> {
+    check_graph_management(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_graph_management_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_graph_management(&Store::open(&dir)?)
+}
+
+fn check_graph_management(store: &Store) -> Result<(), Box<dyn Error>> {
+    let g1 = NamedNodeRef::new_unchecked("http://example.com/g1");
+    let g2 = NamedNodeRef::new_unchecked("http://example.com/g2");
+    let g3 = NamedNodeRef::new_unchecked("http://example.com/g3");
+    let ex = NamedNodeRef::new_unchecked("http://example.com/ex");
+    store.extend(quads(g1).into_iter().map(QuadRef::into_owned))?;
+    store.insert(QuadRef::new(ex, ex, ex, g2))?;
+
+    store.add_graph_into(g1, g2)?;
+    assert_eq!(store.len()?, 2 * NUMBER_OF_TRIPLES + 1);
+    assert!(store.contains(QuadRef::new(ex, ex, ex, g2))?);
+
+    store.copy_graph(g1, g2)?;
+    assert_eq!(store.len()?, 2 * NUMBER_OF_TRIPLES);
+    assert!(!store.contains(QuadRef::new(ex, ex, ex, g2))?);
+    for quad in quads(g2) {
+        assert!(store.contains(quad)?);
+    }
+
+    store.move_graph(g2, GraphNameRef::DefaultGraph)?;
+    assert_eq!(store.len()?, 2 * NUMBER_OF_TRIPLES);
+    assert!(!store.contains_named_graph(g2)?);
+    for quad in quads(GraphNameRef::DefaultGraph) {
+        assert!(store.contains(quad)?);
+    }
+
+    // Through SPARQL, including in a transaction that previously modified the source graph
+    SparqlEvaluator::new()
+        .parse_update(
+            "INSERT DATA { GRAPH <http://example.com/g1> { <http://example.com/ex> <http://example.com/ex> <http://example.com/ex> } } ;
+            COPY <http://example.com/g1> TO <http://example.com/g3> ;
+            ADD DEFAULT TO <http://example.com/g3> ;
+            MOVE SILENT <http://example.com/g1> TO DEFAULT",
+        )?
+        .on_store(store)
+        .execute()?;
+    assert!(!store.contains_named_graph(g1)?);
+    assert!(store.contains(QuadRef::new(ex, ex, ex, g3))?);
+    assert!(store.contains(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?);
+    assert_eq!(store.len()?, 2 * NUMBER_OF_TRIPLES + 2);
+    for quad in quads(g3) {
+        assert!(store.contains(quad)?);
+    }
+    assert!(
+        SparqlEvaluator::new()
+            .parse_update("MOVE <http://example.com/g1> TO <http://example.com/g2>")?
+            .on_store(store)
+            .execute()
+            .is_err()
+    );
+
+    assert!(!store.rename_graph(g1, g2, false)?);
+    assert!(matches!(
+        store.rename_graph(g3, GraphNameRef::DefaultGraph, false),
+        Err(RenameGraphError::TargetGraphAlreadyExists(_))
+    ));
+    assert!(store.rename_graph(g3, g2, false)?);
+    assert!(!store.contains_named_graph(g3)?);
+    assert!(store.contains(QuadRef::new(ex, ex, ex, g2))?);
+    assert!(store.rename_graph(GraphNameRef::DefaultGraph, g2, true)?);
+    assert_eq!(store.len()?, NUMBER_OF_TRIPLES + 1);
+    assert!(store.contains(QuadRef::new(ex, ex, ex, g2))?);
+    for quad in quads(g2) {
+        assert!(store.contains(quad)?);
+    }
+    store.insert_named_graph(g1)?;
+    assert!(store.rename_graph(g1, g3, false)?);
+    assert!(!store.contains_named_graph(g1)?);
+    assert!(store.contains_named_graph(g3)?);
+    Ok(())
+}
+
+#[test]
+fn test_read_list() -> Result<(), Box<dyn Error>> {
+    check_read_list(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_read_list_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_read_list(&Store::open(&dir)?)
+}
+
+fn check_read_list(store: &Store) -> Result<(), Box<dyn Error>> {
+    let g = NamedNodeRef::new_unchecked("http://example.com/g");
+    let ex = NamedNodeRef::new_unchecked("http://example.com/ex");
+    let mut graph = Graph::new();
+    let items = [Term::from(ex), Literal::from(1).into(), rdf::NIL.into()];
+    let head = graph.insert_list(&items);
+    store.extend(graph.iter().map(|t| t.in_graph(g).into_owned()))?;
+
+    assert_eq!(store.read_list(&head, g)?, items);
+    assert!(store.read_list(rdf::NIL, g)?.is_empty());
+    assert!(matches!(
+        store.read_list(&head, GraphNameRef::DefaultGraph),
+        Err(ReadListError::List(ListError::MissingFirst(_)))
+    ));
+
+    // A cycle back to the head
+    let last = graph
+        .subjects_for_predicate_object(rdf::REST, rdf::NIL)
+        .next()
+        .ok_or("no last node")?
+        .into_owned();
+    store.remove(QuadRef::new(&last, rdf::REST, rdf::NIL, g))?;
+    store.insert(QuadRef::new(&last, rdf::REST, &head, g))?;
+    assert!(matches!(
+        store.read_list(&head, g),
+        Err(ReadListError::List(ListError::Cycle(_)))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_clear_report() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.extend(
+        quads(GraphNameRef::DefaultGraph)
+            .into_iter()
+            .map(QuadRef::into_owned),
+    )?;
+    let report = store.clear()?;
+    assert_eq!(report.quads_removed, NUMBER_OF_TRIPLES);
+    assert_eq!(report.bytes_to_reclaim, 0);
+    assert!(store.is_empty()?);
+    Ok(())
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn load_inlined_quads(store: &Store, count: u64) -> Result<(), StorageError> {
+    // Blank nodes and integers are inlined in the quads: nearly all the data is removed by clearing the store
+    let p = NamedNodeRef::new_unchecked("http://example.com/p");
+    let g = NamedNodeRef::new_unchecked("http://example.com/g");
+    let mut loader = store.bulk_loader();
+    loader.load_quads((0..count).map(|i| {
+        Quad::new(
+            BlankNode::new_from_unique_id(i.into()),
+            p,
+            Literal::from(i),
+            g,
+        )
+    }))?;
+    loader.commit()
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_clear_reclaims_disk_space() -> Result<(), Box<dyn Error>> {
+    const COUNT: u64 = 2_000_000;
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    load_inlined_quads(&store, COUNT)?;
+    store.flush()?;
+    let usage_before = store.disk_usage()?;
+    assert!(usage_before.total_size > 100 * 1024 * 1024);
+    assert!(usage_before.live_data_size > 0);
+
+    let report = store.clear()?;
+    assert_eq!(report.quads_removed, usize::try_from(COUNT)?);
+    assert!(report.bytes_to_reclaim > usage_before.total_size / 2);
+    assert!(store.is_empty()?);
+    let usage_after = store.disk_usage()?;
+    assert!(usage_after.total_size < usage_before.total_size / 10);
+    assert!(usage_after.live_data_size < usage_before.live_data_size / 10);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_clear_with_background_reclaim() -> Result<(), Box<dyn Error>> {
+    const COUNT: u64 = 100_000;
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    load_inlined_quads(&store, COUNT)?;
+    store.flush()?;
+    let usage_before = store.disk_usage()?;
+
+    let reclaim = store.clear_with_background_reclaim()?;
+    assert_eq!(reclaim.report().quads_removed, usize::try_from(COUNT)?);
+    assert!(store.is_empty()?);
+    reclaim.wait()?;
+    assert!(store.disk_usage()?.total_size < usage_before.total_size);
+    Ok(())
+}
+
+#[test]
+fn test_update_with_spilled_changes() -> Result<(), Box<dyn Error>> {
+    check_update_with_spilled_changes(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_update_with_spilled_changes_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_update_with_spilled_changes(&Store::open(&dir)?)
+}
+
+fn check_update_with_spilled_changes(store: &Store) -> Result<(), Box<dyn Error>> {
+    const COUNT: i64 = 20_000;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    store.extend((0..COUNT).map(|i| {
+        Quad::new(
+            NamedNode::new_unchecked(format!("http://example.com/{i}")),
+            p,
+            Literal::from(i),
+            GraphName::DefaultGraph,
+        )
+    }))?;
+    let evaluator = SparqlEvaluator::new().with_update_max_memory_size_in_megabytes(1);
+
+    // All deletions are applied before the insertions, even if they do not fit in memory
+    evaluator
+        .clone()
+        .parse_update(
+            "DELETE { ?s ?p ?o } INSERT { ?s ?p ?n } WHERE { ?s ?p ?o BIND(?o + 1 AS ?n) }",
+        )?
+        .on_store(store)
+        .execute()?;
+    assert_eq!(store.len()?, usize::try_from(COUNT)?);
+    for i in [1, COUNT / 2, COUNT] {
+        assert!(store.contains(QuadRef::new(
+            NamedNodeRef::new(&format!("http://example.com/{}", i - 1))?,
+            p,
+            &Literal::from(i),
+            GraphNameRef::DefaultGraph,
+        ))?);
+    }
+
+    // Same inside a transaction that has already done changes, with fresh blank nodes
+    evaluator
+        .parse_update(
+            "INSERT DATA { <http://example.com/s> <http://example.com/p> 0 } ;
+            DELETE { ?s ?p ?o } INSERT { ?s <http://example.com/q> [] } WHERE { ?s ?p ?o }",
+        )?
+        .on_store(store)
+        .execute()?;
+    assert_eq!(store.len()?, usize::try_from(COUNT + 1)?);
+    let objects = store
+        .iter()
+        .map(|quad| Ok(quad?.object))
+        .collect::<Result<HashSet<_>, StorageError>>()?;
+    assert_eq!(objects.len(), usize::try_from(COUNT + 1)?);
+    assert!(objects.iter().all(Term::is_blank_node));
+    Ok(())
+}
+
+#[test]
+fn test_remove_matching() -> Result<(), Box<dyn Error>> {
+    check_remove_matching(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_remove_matching_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_remove_matching(&Store::open(&dir)?)
+}
+
+fn check_remove_matching(store: &Store) -> Result<(), Box<dyn Error>> {
+    // More quads than the number of quads removed at once
+    const COUNT: i64 = 150_000;
+    let p = NamedNodeRef::new("http://example.com/p")?;
+    let q = NamedNodeRef::new("http://example.com/q")?;
+    let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    store.extend((0..COUNT).map(|i| {
+        Quad::new(
+            NamedNode::new_unchecked(format!("http://example.com/{i}")),
+            p,
+            Literal::from(i),
+            if i % 2 == 0 { g1 } else { g2 },
+        )
+    }))?;
+    store.insert(QuadRef::new(p, p, p, GraphNameRef::DefaultGraph))?;
+    store.insert(QuadRef::new(p, q, p, g1))?;
+
+    assert_eq!(
+        store.remove_matching(None, Some(p), None, Some(g1.into()))?,
+        75_000
+    );
+    assert_eq!(store.len()?, 75_002);
+    assert_eq!(store.remove_matching(None, Some(p), None, None)?, 75_001);
+    assert_eq!(store.len()?, 1);
+    assert_eq!(store.remove_matching(None, Some(p), None, None)?, 0);
+
+    // The quads written earlier in the transaction are visible
+    let mut transaction = store.start_transaction()?;
+    transaction.insert(QuadRef::new(p, q, q, g2));
+    transaction.insert(QuadRef::new(q, q, q, g2));
+    assert_eq!(
+        transaction.remove_matching(Some(p.into()), None, None, None)?,
+        2
+    );
+    transaction.commit()?;
+    assert_eq!(
+        store.iter().collect::<Result<Vec<_>, _>>()?,
+        vec![QuadRef::new(q, q, q, g2).into_owned()]
+    );
+
+    // DELETE WHERE with a single pattern
+    store.insert(QuadRef::new(p, q, p, GraphNameRef::DefaultGraph))?;
+    store.insert(QuadRef::new(p, q, p, g1))?;
+    SparqlEvaluator::new()
+        .parse_update("DELETE WHERE { GRAPH ?g { ?s <http://example.com/q> ?o } }")?
+        .on_store(store)
+        .execute()?;
+    assert_eq!(
+        store.iter().collect::<Result<Vec<_>, _>>()?,
+        vec![QuadRef::new(p, q, p, GraphNameRef::DefaultGraph).into_owned()]
+    );
+    SparqlEvaluator::new()
+        .parse_update("DELETE WHERE { ?s ?p ?s }")?
+        .on_store(store)
+        .execute()?;
+    assert!(store.is_empty()?);
+    Ok(())
+}
+
+#[test]
+fn test_set_object() -> Result<(), Box<dyn Error>> {
+    check_set_object(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_set_object_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_set_object(&Store::open(&dir)?)
+}
+
+fn check_set_object(store: &Store) -> Result<(), Box<dyn Error>> {
+    let ex = NamedNodeRef::new("http://example.com/ex")?;
+    let name = NamedNodeRef::new("http://example.com/name")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let foo = Term::from(Literal::new_simple_literal("foo"));
+    let bar = Term::from(Literal::new_simple_literal("bar"));
+    let baz = Term::from(Literal::new_simple_literal("baz"));
+    store.insert(QuadRef::new(ex, name, &foo, GraphNameRef::DefaultGraph))?;
+    store.insert(QuadRef::new(ex, name, &bar, GraphNameRef::DefaultGraph))?;
+    store.insert(QuadRef::new(ex, name, &foo, g))?;
+
+    // Only the objects that are not kept are reported
+    assert_eq!(
+        store.set_object(
+            ex,
+            name,
+            [bar.clone(), baz.clone()],
+            GraphNameRef::DefaultGraph
+        )?,
+        std::slice::from_ref(&foo)
+    );
+    let objects = |graph_name: GraphNameRef<'_>| {
+        store
+            .quads_for_pattern(Some(ex.into()), Some(name), None, Some(graph_name))
+            .map(|quad| Ok(quad?.object))
+            .collect::<Result<HashSet<_>, StorageError>>()
+    };
+    assert_eq!(
+        objects(GraphNameRef::DefaultGraph)?,
+        HashSet::from([bar.clone(), baz.clone()])
+    );
+
+    // Only the target graph is modified
+    assert_eq!(
+        store.set_object(ex, name, [bar.clone()], g)?,
+        std::slice::from_ref(&foo)
+    );
+    assert_eq!(objects(g.into())?, HashSet::from([bar.clone()]));
+    assert_eq!(
+        objects(GraphNameRef::DefaultGraph)?,
+        HashSet::from([bar.clone(), baz.clone()])
+    );
+
+    // An empty list of objects is a removal
+    let no_objects: [Term; 0] = [];
+    assert_eq!(
+        store.set_object(ex, name, no_objects.clone(), g)?,
+        std::slice::from_ref(&bar)
+    );
+    assert!(objects(g.into())?.is_empty());
+    assert!(store.set_object(ex, name, no_objects, g)?.is_empty());
+
+    // In a transaction, the quads written earlier are visible
+    let mut transaction = store.start_transaction()?;
+    transaction.insert(QuadRef::new(ex, name, &foo, g));
+    assert_eq!(transaction.set_object(ex, name, [baz.clone()], g)?, [foo]);
+    transaction.commit()?;
+    assert_eq!(objects(g.into())?, HashSet::from([baz]));
+
+    // Concurrent callers never leave a mix of their values
+    std::thread::scope(|scope| {
+        for i in 0..4 {
+            scope.spawn(move || {
+                for j in 0..100 {
+                    store
+                        .set_object(
+                            ex,
+                            name,
+                            [Literal::from(i), Literal::from(i * 1000 + j)],
+                            GraphNameRef::DefaultGraph,
+                        )
+                        .unwrap();
+                }
+            });
+        }
+    });
+    let objects = store
+        .quads_for_pattern(
+            Some(ex.into()),
+            Some(name),
+            None,
+            Some(GraphNameRef::DefaultGraph),
+        )
+        .map(|quad| match quad?.object {
+            Term::Literal(literal) => Ok(literal.value().parse::<i64>()?),
+            object => Err(format!("{object} is not a literal").into()),
+        })
+        .collect::<Result<HashSet<_>, Box<dyn Error>>>()?;
+    let winner = objects.iter().copied().min().ok_or("no object")?;
+    assert_eq!(objects, HashSet::from([winner, winner * 1000 + 99]));
+    Ok(())
+}
+
+#[test]
+fn test_namespaces() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    check_namespaces(&store)?;
+    assert_eq!(
+        store.namespaces()?.collect::<Vec<_>>(),
+        [("schema".to_owned(), NamedNode::new("http://schema.org/")?)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_namespaces_on_disk() -> Result<(), Box<dyn Error>> {
+    let store_dir = TempDir::new()?;
+    let backup_dir = TempDir::new()?;
+    remove_dir(&backup_dir)?;
+    {
+        let store = Store::open(&store_dir)?;
+        check_namespaces(&store)?;
+        store.backup(&backup_dir)?;
+    }
+    let expected = [("schema".to_owned(), NamedNode::new("http://schema.org/")?)];
+    assert_eq!(
+        Store::open(&store_dir)?.namespaces()?.collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        Store::open_read_only(&backup_dir)?
+            .namespaces()?
+            .collect::<Vec<_>>(),
+        expected
+    );
+    Ok(())
+}
+
+fn check_namespaces(store: &Store) -> Result<(), Box<dyn Error>> {
+    assert_eq!(store.namespaces()?.count(), 0);
+    store.set_namespace("ex", NamedNodeRef::new("http://example.com/")?)?;
+    store.set_namespace("schema", NamedNodeRef::new("http://example.com/schema/")?)?;
+    store.remove_namespace("ex")?;
+    store.remove_namespace("foo")?;
+
+    // Prefixes declared in the loaded data are only registered on demand
+    let mut loader = store.bulk_loader();
+    loader.load_from_slice(RdfFormat::Turtle, DATA)?;
+    loader.commit()?;
+    assert_eq!(
+        store.namespaces()?.collect::<Vec<_>>(),
+        [(
+            "schema".to_owned(),
+            NamedNode::new("http://example.com/schema/")?
+        )]
+    );
+    let mut loader = store.bulk_loader().register_namespaces();
+    loader.load_from_slice(RdfFormat::Turtle, DATA)?;
+    loader.commit()?;
+    assert_eq!(
+        store.namespaces()?.collect::<Vec<_>>(),
+        [
+            ("schema".to_owned(), NamedNode::new("http://schema.org/")?),
+            (
+                "wd".to_owned(),
+                NamedNode::new("http://www.wikidata.org/entity/")?
+            ),
+            (
+                "xsd".to_owned(),
+                NamedNode::new("http://www.w3.org/2001/XMLSchema#")?
+            ),
+        ]
+    );
+
+    // Namespaces are not part of the dataset
+    store.clear()?;
+    store.remove_namespace("wd")?;
+    store.remove_namespace("xsd")?;
+    Ok(())
+}
+
+#[test]
+fn test_load_with_namespaces() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_slice(RdfFormat::Turtle, DATA)?;
+    assert_eq!(store.namespaces()?.count(), 0);
+    store.load_from_reader_with_namespaces(RdfFormat::Turtle, DATA.as_bytes())?;
+    assert_eq!(store.len()?, NUMBER_OF_TRIPLES);
+    assert_eq!(
+        store
+            .namespaces()?
+            .map(|(prefix, _)| prefix)
+            .collect::<Vec<_>>(),
+        ["schema", "wd", "xsd"]
+    );
+    store.load_from_slice_with_namespaces(
+        RdfFormat::TriG,
+        "@prefix : <http://example.com/> . @prefix schema: <http://example.com/schema/> . :s :p :o .",
+    )?;
+    assert_eq!(
+        store.namespaces()?.collect::<Vec<_>>(),
+        [
+            (String::new(), NamedNode::new("http://example.com/")?),
+            (
+                "schema".to_owned(),
+                NamedNode::new("http://example.com/schema/")?
+            ),
+            (
+                "wd".to_owned(),
+                NamedNode::new("http://www.wikidata.org/entity/")?
+            ),
+            (
+                "xsd".to_owned(),
+                NamedNode::new("http://www.w3.org/2001/XMLSchema#")?
+            ),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_invalid_namespace() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let namespace = NamedNodeRef::new("http://example.com/")?;
+    for prefix in ["ex-1", "ex.a", "\u{e9}", ""] {
+        store.set_namespace(prefix, namespace)?;
+    }
+    for prefix in ["1ex", "_ex", "-ex", "ex.", "e x", "ex:"] {
+        assert!(
+            matches!(
+                store.set_namespace(prefix, namespace),
+                Err(SetNamespaceError::InvalidPrefix(p)) if p == prefix
+            ),
+            "{prefix} should be rejected"
+        );
+    }
+    assert_eq!(store.namespaces()?.count(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_query_statistics() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    check_query_statistics(&store)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_query_statistics_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_query_statistics(&Store::open(&dir)?)?;
+    // The statistics are persisted
+    assert_eq!(
+        explain_statistics_query(&Store::open(&dir)?)?,
+        ["<http://example.com/rare>", "<http://example.com/common>"]
+    );
+    Ok(())
+}
+
+fn check_query_statistics(store: &Store) -> Result<(), Box<dyn Error>> {
+    let common = NamedNodeRef::new("http://example.com/common")?;
+    let rare = NamedNodeRef::new("http://example.com/rare")?;
+    for i in 0..100 {
+        let s = NamedNode::new(format!("http://example.com/s{i}"))?;
+        store.insert(QuadRef::new(
+            &s,
+            common,
+            &Literal::from(i),
+            GraphNameRef::DefaultGraph,
+        ))?;
+        if i % 50 == 0 {
+            store.insert(QuadRef::new(&s, rare, &s, GraphNameRef::DefaultGraph))?;
+        }
+    }
+
+    // Without statistics the patterns have the same estimated size and the query order is kept
+    assert_eq!(
+        explain_statistics_query(store)?,
+        ["<http://example.com/common>", "<http://example.com/rare>"]
+    );
+    store.recompute_statistics()?;
+    assert_eq!(
+        explain_statistics_query(store)?,
+        ["<http://example.com/rare>", "<http://example.com/common>"]
+    );
+    Ok(())
+}
+
+/// Returns the predicates of the query triple patterns in evaluation order
+fn explain_statistics_query(store: &Store) -> Result<Vec<&'static str>, Box<dyn Error>> {
+    let (results, explanation) = SparqlEvaluator::new()
+        .parse_query("SELECT * WHERE { ?s <http://example.com/common> ?o . ?s <http://example.com/rare> ?s }")?
+        .on_store(store)
+        .explain();
+    let QueryResults::Solutions(solutions) = results? else {
+        return Err("Solutions expected".into());
+    };
+    assert_eq!(solutions.count(), 2);
+    let plan = format!("{explanation:?}");
+    let mut predicates = ["<http://example.com/common>", "<http://example.com/rare>"];
+    predicates.sort_by_key(|p| plan.find(p));
+    Ok(predicates.into())
+}
+
+#[test]
+fn test_default_query_dataset() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    check_default_query_dataset(&store)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_default_query_dataset_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_default_query_dataset(&Store::open(&dir)?)?;
+    // The setting is persisted
+    let store = Store::open(&dir)?;
+    assert_eq!(store.default_query_dataset(), DefaultGraphMode::Union);
+    assert!(ask(&store, "ASK { ?s ?p ?o }", None)?);
+    Ok(())
+}
+
+fn check_default_query_dataset(store: &Store) -> Result<(), Box<dyn Error>> {
+    let ex = NamedNodeRef::new("http://example.com/g")?;
+    store.insert(QuadRef::new(ex, ex, ex, ex))?;
+
+    assert_eq!(
+        store.default_query_dataset(),
+        DefaultGraphMode::DefaultGraphOnly
+    );
+    assert!(!ask(store, "ASK { ?s ?p ?o }", None)?);
+    assert!(ask(
+        store,
+        "ASK { ?s ?p ?o }",
+        Some(DefaultGraphMode::Union)
+    )?);
+
+    store.set_default_query_dataset(DefaultGraphMode::Union)?;
+    assert_eq!(store.default_query_dataset(), DefaultGraphMode::Union);
+    assert!(ask(store, "ASK { ?s ?p ?o }", None)?);
+    // The evaluator setting wins over the store default
+    assert!(!ask(
+        store,
+        "ASK { ?s ?p ?o }",
+        Some(DefaultGraphMode::DefaultGraphOnly)
+    )?);
+    // The query dataset wins over both
+    assert!(!ask(
+        store,
+        "ASK FROM <http://example.com/other> { ?s ?p ?o }",
+        None
+    )?);
+    assert!(!ask(
+        store,
+        "ASK FROM <http://example.com/other> { ?s ?p ?o }",
+        Some(DefaultGraphMode::Union)
+    )?);
+    assert!(ask(
+        store,
+        "ASK FROM <http://example.com/g> { ?s ?p ?o }",
+        None
+    )?);
+    // Transactions do not use the store default
+    let transaction = store.start_transaction()?;
+    let results = SparqlEvaluator::new()
+        .parse_query("ASK { ?s ?p ?o }")?
+        .on_transaction(&transaction)
+        .execute()?;
+    assert!(matches!(results, QueryResults::Boolean(false)));
+    Ok(())
+}
+
+fn ask(store: &Store, query: &str, mode: Option<DefaultGraphMode>) -> Result<bool, Box<dyn Error>> {
+    let mut evaluator = SparqlEvaluator::new();
+    if let Some(mode) = mode {
+        evaluator = evaluator.with_default_graph_mode(mode);
+    }
+    let QueryResults::Boolean(result) = evaluator.parse_query(query)?.on_store(store).execute()?
+    else {
+        return Err("Boolean expected".into());
+    };
+    Ok(result)
+}
+
+#[test]
+fn test_graph_filter() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let ex = NamedNodeRef::new("http://example.com/s")?;
+    let public = NamedNodeRef::new("http://example.com/public")?;
+    let private = NamedNodeRef::new("http://example.com/private")?;
+    let secret = LiteralRef::new_simple_literal("secret");
+    store.insert(QuadRef::new(ex, ex, ex, public))?;
+    store.insert(QuadRef::new(ex, ex, secret, private))?;
+    let evaluator = SparqlEvaluator::new()
+        .with_graph_filter(move |graph_name| graph_name != private.into())
+        .with_default_graph_mode(DefaultGraphMode::Union);
+
+    for (query, expected) in [
+        ("ASK { GRAPH ?g { ?s ?p ?o } }", true),
+        ("ASK { GRAPH <http://example.com/private> {} }", false),
+        ("ASK { GRAPH ?g { ?s ?p 'secret' } }", false),
+        ("ASK { ?s ?p 'secret' }", false),
+        ("ASK FROM <http://example.com/private> { ?s ?p ?o }", false),
+        (
+            "ASK FROM NAMED <http://example.com/private> { GRAPH ?g { ?s ?p ?o } }",
+            false,
+        ),
+        (
+            "ASK { FILTER EXISTS { GRAPH ?g { ?s ?p 'secret' } } }",
+            false,
+        ),
+        (
+            "ASK { { SELECT (COUNT(*) AS ?c) { GRAPH ?g { ?s ?p ?o } } } FILTER(?c = 1) }",
+            true,
+        ),
+        (
+            "ASK { { SELECT (COUNT(DISTINCT ?g) AS ?c) { GRAPH ?g {} } } FILTER(?c = 1) }",
+            true,
+        ),
+    ] {
+        let QueryResults::Boolean(result) = evaluator
+            .clone()
+            .parse_query(query)?
+            .on_store(&store)
+            .execute()?
+        else {
+            return Err("Boolean expected".into());
+        };
+        assert_eq!(result, expected, "{query}");
+    }
+    let QueryResults::Graph(triples) = evaluator
+        .clone()
+        .parse_query("DESCRIBE <http://example.com/s>")?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Graph expected".into());
+    };
+    assert_eq!(
+        triples.collect::<Result<Vec<_>, _>>()?,
+        [Triple::new(ex, ex, ex)]
+    );
+
+    // The hidden graphs are not seen by the updates
+    evaluator
+        .clone()
+        .parse_update("DELETE WHERE { GRAPH ?g { ?s ?p ?o } }")?
+        .on_store(&store)
+        .execute()?;
+    evaluator
+        .parse_update("DROP ALL")?
+        .on_store(&store)
+        .execute()?;
+    assert_eq!(
+        store.iter().collect::<Result<Vec<_>, _>>()?,
+        [Quad::new(ex, ex, secret, private)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_writable_graph_filter() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let ex = NamedNodeRef::new("http://example.com/s")?;
+    let public = NamedNodeRef::new("http://example.com/public")?;
+    let private = NamedNodeRef::new("http://example.com/private")?;
+    store.insert(QuadRef::new(ex, ex, ex, private))?;
+    let evaluator = SparqlEvaluator::new()
+        .with_writable_graph_filter(move |graph_name| graph_name == public.into());
+
+    for update in [
+        "INSERT DATA { GRAPH <http://example.com/private> { <http://example.com/s> <http://example.com/s> 1 } }",
+        "INSERT DATA { <http://example.com/s> <http://example.com/s> 1 }",
+        "DELETE DATA { GRAPH <http://example.com/private> { <http://example.com/s> <http://example.com/s> <http://example.com/s> } }",
+        "DELETE WHERE { GRAPH ?g { ?s ?p ?o } }",
+        "INSERT { GRAPH <http://example.com/private> { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }",
+        "ADD <http://example.com/private> TO DEFAULT",
+        "MOVE <http://example.com/private> TO <http://example.com/public>",
+        "CLEAR GRAPH <http://example.com/private>",
+        "CLEAR ALL",
+        "DROP NAMED",
+        "CREATE GRAPH <http://example.com/other>",
+    ] {
+        let result = evaluator
+            .clone()
+            .parse_update(update)?
+            .on_store(&store)
+            .execute();
+        assert!(
+            matches!(result, Err(UpdateEvaluationError::GraphNotWritable(_))),
+            "{update}: {result:?}"
+        );
+    }
+    assert_eq!(
+        store.iter().collect::<Result<Vec<_>, _>>()?,
+        [Quad::new(ex, ex, ex, private)]
+    );
+
+    evaluator
+        .clone()
+        .parse_update("ADD <http://example.com/private> TO <http://example.com/public>")?
+        .on_store(&store)
+        .execute()?;
+    evaluator
+        .parse_update("CLEAR GRAPH <http://example.com/public>")?
+        .on_store(&store)
+        .execute()?;
+    assert_eq!(
+        store.iter().collect::<Result<Vec<_>, _>>()?,
+        [Quad::new(ex, ex, ex, private)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_normalize_iris() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_slice(
+        RdfFormat::NQuads,
+        r#"<http://example.com/a> <http://example.com/p> <http://example.com/b> .
+<HTTP://EXAMPLE.com/x/../a> <http://example.com/p> <http://example.com/%62> .
+<http://example.com/a> <http://example.com/p> <http://example.com/b?Q=%7e#F> <HTTP://Example.com/g> .
+<http://example.com/a> <http://example.com/p> "1"^^<HTTP://www.w3.org/2001/XMLSchema#integer> .
+"#,
+    )?;
+    let mut transaction = store.start_transaction()?;
+    transaction.insert_named_graph(NamedNodeRef::new("http://example.com/./empty")?);
+    transaction.commit()?;
+
+    let report = store.normalize_iris(IriNormalizationPolicy::Full)?;
+    assert_eq!(report.terms, 5);
+    assert_eq!(report.quads, 3);
+    assert_eq!(report.merged_quads, 1);
+    let mut quads = store
+        .iter()
+        .map(|q| Ok(q?.to_string()))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    quads.sort();
+    assert_eq!(
+        quads,
+        [
+            "<http://example.com/a> <http://example.com/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+            "<http://example.com/a> <http://example.com/p> <http://example.com/b>",
+            "<http://example.com/a> <http://example.com/p> <http://example.com/b?Q=%7e#F> <http://example.com/g>",
+        ]
+    );
+    let mut graph_names = store
+        .named_graphs()
+        .map(|g| Ok(g?.to_string()))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    graph_names.sort();
+    assert_eq!(
+        graph_names,
+        ["<http://example.com/empty>", "<http://example.com/g>"]
+    );
+
+    // Already normalized
+    assert_eq!(
+        store.normalize_iris(IriNormalizationPolicy::Full)?,
+        store.normalize_iris(IriNormalizationPolicy::Case)?
+    );
+    assert_eq!(store.normalize_iris(IriNormalizationPolicy::Full)?.quads, 0);
+    Ok(())
+}
+
+#[test]
+fn test_federated_store() -> Result<(), Box<dyn Error>> {
+    let ex = NamedNodeRef::new("http://example.com/s")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let first = Store::new()?;
+    first.insert(QuadRef::new(
+        ex,
+        ex,
+        &Literal::from(1),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    first.insert(QuadRef::new(ex, ex, &Literal::from(2), g))?;
+    let second = Store::new()?;
+    second.insert(QuadRef::new(
+        ex,
+        ex,
+        &Literal::from(1),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    second.insert(QuadRef::new(
+        ex,
+        ex,
+        &Literal::from(3),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    second.insert(QuadRef::new(ex, ex, &Literal::from(4), g))?;
+    let federation = FederatedStore::new()
+        .with_store(first.clone())
+        .with_store_under_prefix(second.clone(), "http://example.com/second/")?;
+
+    for (query, expected) in [
+        ("SELECT (COUNT(*) AS ?c) { ?s ?p ?o }", 2),
+        ("SELECT (COUNT(*) AS ?c) { GRAPH ?g { ?s ?p ?o } }", 2),
+        ("SELECT (COUNT(DISTINCT ?g) AS ?c) { GRAPH ?g {} }", 2),
+        (
+            "SELECT (COUNT(*) AS ?c) { GRAPH <http://example.com/g> { ?s ?p ?o } }",
+            1,
+        ),
+        (
+            "SELECT (COUNT(*) AS ?c) { GRAPH <http://example.com/second/http://example.com/g> { ?s ?p 4 } }",
+            1,
+        ),
+        (
+            "SELECT (COUNT(*) AS ?c) { GRAPH <http://example.com/other> { ?s ?p ?o } }",
+            0,
+        ),
+        ("SELECT (SUM(?o) AS ?c) { ?s ?p ?o }", 4),
+        ("SELECT (SUM(?o) AS ?c) { GRAPH ?g { ?s ?p ?o } }", 6),
+    ] {
+        let QueryResults::Solutions(mut solutions) =
+            federation.query_opt(query, SparqlEvaluator::new())?
+        else {
+            return Err("Solutions expected".into());
+        };
+        assert_eq!(
+            solutions.next().ok_or("No solution")??.get("c"),
+            Some(&Literal::from(expected).into()),
+            "{query}"
+        );
+    }
+
+    // The snapshots are taken when the query is bound
+    let prepared = SparqlEvaluator::new()
+        .parse_query("ASK { ?s ?p 5 }")?
+        .on_federated_store(&federation);
+    second.insert(QuadRef::new(
+        ex,
+        ex,
+        &Literal::from(5),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    assert!(matches!(prepared.execute()?, QueryResults::Boolean(false)));
+    assert!(matches!(
+        federation.query_opt("ASK { ?s ?p 5 }", SparqlEvaluator::new())?,
+        QueryResults::Boolean(true)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_property_paths_in_graph_with_union_default_graph() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.set_default_query_dataset(DefaultGraphMode::Union)?;
+    let ex = |name: &str| NamedNode::new_unchecked(format!("http://example.com/{name}"));
+    // The a -> b -> c -> d path only exists across the graphs
+    store.insert(QuadRef::new(&ex("a"), &ex("p"), &ex("b"), &ex("g1")))?;
+    store.insert(QuadRef::new(&ex("b"), &ex("p"), &ex("c"), &ex("g2")))?;
+    store.insert(QuadRef::new(
+        &ex("c"),
+        &ex("p"),
+        &ex("d"),
+        GraphNameRef::DefaultGraph,
+    ))?;
+
+    for (query, expected) in [
+        (
+            "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p+ ?o } }",
+            &[["a", "b", "g1"], ["b", "c", "g2"]][..],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p* ?o } }",
+            &[
+                ["a", "a", "g1"],
+                ["a", "b", "g1"],
+                ["b", "b", "g1"],
+                ["b", "b", "g2"],
+                ["b", "c", "g2"],
+                ["c", "c", "g2"],
+            ],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p/ex:p ?o } }",
+            &[],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) GRAPH ?g { ?s ex:p+ ?o } }",
+            &[["a", "b", "g1"]],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:c AS ?o) GRAPH ?g { ?s ex:p+ ?o } }",
+            &[["b", "c", "g2"]],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) BIND(ex:c AS ?o) GRAPH ?g { ?s ex:p+ ?o } }",
+            &[],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:b AS ?s) BIND(ex:b AS ?o) GRAPH ?g { ?s ex:p? ?o } }",
+            &[["b", "b", "g1"], ["b", "b", "g2"]],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:d AS ?s) BIND(ex:d AS ?o) GRAPH ?g { ?s ex:p* ?o } }",
+            &[],
+        ),
+        (
+            "SELECT ?s ?o ?g WHERE { VALUES ?g { ex:g1 ex:g2 } BIND(ex:c AS ?s) GRAPH ?g { ?s ex:p* ?o } }",
+            &[["c", "c", "g2"]],
+        ),
+        // The default graph is the union of all graphs, including the store default graph
+        (
+            "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) ?s ex:p+ ?o }",
+            &[["a", "b", ""], ["a", "c", ""], ["a", "d", ""]],
+        ),
+    ] {
+        let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+            .parse_query(&format!("PREFIX ex: <http://example.com/> {query}"))?
+            .on_store(&store)
+            .execute()?
+        else {
+            return Err("Solutions expected".into());
+        };
+        let mut solutions = solutions
+            .map(|solution| {
+                let solution = solution?;
+                Ok(["s", "o", "g"].map(|v| match solution.get(v) {
+                    Some(Term::NamedNode(n)) => n
+                        .as_str()
+                        .trim_start_matches("http://example.com/")
+                        .to_owned(),
+                    _ => String::new(),
+                }))
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        solutions.sort();
+        assert_eq!(solutions, expected, "{query}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_update_with_union_default_graph() -> Result<(), Box<dyn Error>> {
+    let ex = |name: &str| NamedNode::new_unchecked(format!("http://example.com/{name}"));
+    for (update, expected) in [
+        // WITH scopes both the WHERE clause and the templates
+        (
+            "WITH ex:g1 DELETE { ?s ?p ?o } WHERE { ?s ?p ?o }",
+            &[["a", "p", "1", ""], ["a", "p", "3", "g2"]][..],
+        ),
+        (
+            "WITH ex:g1 INSERT { ?s ex:q ?o } WHERE { ?s ex:p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "2", "g1"],
+            ],
+        ),
+        (
+            "WITH ex:g1 INSERT { GRAPH ex:g2 { ?s ex:q ?o } } WHERE { ?s ex:p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "2", "g2"],
+            ],
+        ),
+        // The WHERE clause reads the union but the templates target the actual default graph
+        (
+            "DELETE { ?s ?p ?o } WHERE { ?s ?p ?o }",
+            &[["a", "p", "2", "g1"], ["a", "p", "3", "g2"]],
+        ),
+        (
+            "DELETE WHERE { ?s ?p ?o }",
+            &[["a", "p", "2", "g1"], ["a", "p", "3", "g2"]],
+        ),
+        (
+            "INSERT { ?s ex:q ?o } WHERE { ?s ex:p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "1", ""],
+                ["a", "q", "2", ""],
+                ["a", "q", "3", ""],
+            ],
+        ),
+        // USING and USING NAMED override the WHERE clause dataset
+        (
+            "INSERT { ?s ex:q ?o } USING ex:g2 WHERE { ?s ex:p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "3", ""],
+            ],
+        ),
+        (
+            "INSERT { ?s ex:q ?o } USING NAMED ex:g2 WHERE { GRAPH ?g { ?s ex:p ?o } }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "3", ""],
+            ],
+        ),
+        (
+            "WITH ex:g1 INSERT { ?s ex:q ?o } USING ex:g2 WHERE { ?s ex:p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+                ["a", "q", "3", "g1"],
+            ],
+        ),
+        (
+            "WITH ex:g1 DELETE { ?s ?p ?o } USING ex:g2 WHERE { ?s ?p ?o }",
+            &[
+                ["a", "p", "1", ""],
+                ["a", "p", "2", "g1"],
+                ["a", "p", "3", "g2"],
+            ],
+        ),
+    ] {
+        let store = Store::new()?;
+        store.insert(QuadRef::new(
+            &ex("a"),
+            &ex("p"),
+            &ex("1"),
+            GraphNameRef::DefaultGraph,
+        ))?;
+        store.insert(QuadRef::new(&ex("a"), &ex("p"), &ex("2"), &ex("g1")))?;
+        store.insert(QuadRef::new(&ex("a"), &ex("p"), &ex("3"), &ex("g2")))?;
+        SparqlEvaluator::new()
+            .with_default_graph_mode(DefaultGraphMode::Union)
+            .parse_update(&format!("PREFIX ex: <http://example.com/> {update}"))?
+            .on_store(&store)
+            .execute()?;
+        let name = |term: TermRef<'_>| match term {
+            TermRef::NamedNode(n) => n
+                .as_str()
+                .trim_start_matches("http://example.com/")
+                .to_owned(),
+            _ => String::new(),
+        };
+        let mut quads = store
+            .iter()
+            .map(|quad| {
+                let quad = quad?;
+                Ok([
+                    name(quad.subject.as_ref().into()),
+                    name(quad.predicate.as_ref().into()),
+                    name(quad.object.as_ref()),
+                    match &quad.graph_name {
+                        GraphName::NamedNode(n) => name(n.as_ref().into()),
+                        _ => String::new(),
+                    },
+                ])
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+        quads.sort();
+        assert_eq!(quads, expected, "{update}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_nan_identity() -> Result<(), Box<dyn Error>> {
+    // 0e0 / 0e0 computes a NaN with a different bit pattern than the parsed "NaN"
+    let store = Store::new()?;
+    for (query, expected) in [
+        (
+            "SELECT DISTINCT ?v WHERE { VALUES ?x { \"NaN\"^^xsd:double \"-0\"^^xsd:double 0e0 } BIND(?x / 0e0 AS ?v) }",
+            &["\"NaN\"^^xsd:double"][..],
+        ),
+        (
+            "SELECT DISTINCT ?v WHERE { VALUES ?x { \"NaN\"^^xsd:double \"-0\"^^xsd:double 0e0 } VALUES ?c { false true } BIND(IF(?c, 0e0 / 0e0, ?x) AS ?v) }",
+            &[
+                "\"-0\"^^xsd:double",
+                "\"0\"^^xsd:double",
+                "\"NaN\"^^xsd:double",
+            ],
+        ),
+        (
+            "SELECT (CONCAT(STR(?v), \" \", STR(COUNT(*))) AS ?c) WHERE { VALUES ?x { \"NaN\"^^xsd:double \"-0\"^^xsd:double 0e0 1e0 } BIND(?x / 0e0 AS ?v) } GROUP BY ?v",
+            &["\"INF 1\"", "\"NaN 3\""],
+        ),
+    ] {
+        let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+            .parse_query(&format!(
+                "PREFIX xsd: <http://www.w3.org/2001/XMLSchema#> {query}"
+            ))?
+            .on_store(&store)
+            .execute()?
+        else {
+            return Err("Solutions expected".into());
+        };
+        let mut solutions = solutions
+            .map(|solution| {
+                Ok(solution?
+                    .values()
+                    .iter()
+                    .flatten()
+                    .map(|t| t.to_string().replace(xsd::DOUBLE.as_str(), "xsd:double"))
+                    .collect::<String>()
+                    .replace("<xsd:double>", "xsd:double"))
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        solutions.sort();
+        assert_eq!(solutions, expected, "{query}");
+    }
+
+    // The store also considers all NaN values as the same term
+    let ex = NamedNodeRef::new_unchecked("http://example.com");
+    store.insert(QuadRef::new(
+        ex,
+        ex,
+        LiteralRef::new_typed_literal("NaN", xsd::DOUBLE),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    SparqlEvaluator::new()
+        .parse_update("INSERT { <http://example.com> <http://example.com> ?v } WHERE { BIND(0e0 / 0e0 AS ?v) }")?
+        .on_store(&store)
+        .execute()?;
+    assert_eq!(store.len()?, 1);
+    Ok(())
+}
+
+#[test]
+fn test_bulk_load_on_existing_delete_overrides_the_delete() -> Result<(), Box<dyn Error>> {
+    let quad = QuadRef::new(
+        NamedNodeRef::new_unchecked("http://example.com/s"),
+        NamedNodeRef::new_unchecked("http://example.com/p"),
+        NamedNodeRef::new_unchecked("http://example.com/o"),
+        NamedNodeRef::new_unchecked("http://www.wikidata.org/wiki/Special:EntityData/Q90"),
+    );
+    let store = Store::new()?;
+    store.remove(quad)?;
+    let mut loader = store.bulk_loader();
+    loader.load_quads([quad.into_owned()])?;
+    loader.commit()?;
+    assert_eq!(store.len()?, 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_bulk_load_resume_from_checkpoint() -> Result<(), Box<dyn Error>> {
+    let data = "<http://example.com/s> <http://example.com/p> _:b1 .\n\
+        <http://example.com/s> <http://example.com/p> _:b2 .\n\
+        _:b2 <http://example.com/p> <http://example.com/o> .\n";
+    let checkpoint = std::env::temp_dir().join("oxigraph-test-bulk-load-checkpoint");
+    drop(std::fs::remove_file(&checkpoint));
+
+    let full_store = Store::new()?;
+    let mut loader = full_store
+        .bulk_loader()
+        .with_checkpoint(&checkpoint)
+        .with_checkpoint_interval(1, std::time::Duration::ZERO);
+    loader.resume_from_checkpoint(RdfFormat::NTriples, Cursor::new(data))?;
+    loader.commit()?;
+    assert_eq!(full_store.len()?, 3);
+    let saved_checkpoint = std::fs::read_to_string(&checkpoint)?;
+    let full_offset = format!("offset {}\n", data.len());
+    assert!(saved_checkpoint.starts_with(&full_offset));
+
+    // We simulate a loading interrupted after the first line
+    let first_line_end = data.find('\n').ok_or("no line")? + 1;
+    std::fs::write(
+        &checkpoint,
+        saved_checkpoint.replace(&full_offset, &format!("offset {first_line_end}\n")),
+    )?;
+    let resumed_store = Store::new()?;
+    let mut loader = resumed_store.bulk_loader().with_checkpoint(&checkpoint);
+    loader.resume_from_checkpoint(RdfFormat::NTriples, Cursor::new(data))?;
+    loader.commit()?;
+    assert_eq!(resumed_store.len()?, 2);
+    for quad in &resumed_store {
+        // Blank nodes get the same identifiers as during the first loading
+        assert!(full_store.contains(&quad?)?);
+    }
+    std::fs::remove_file(&checkpoint)?;
+    Ok(())
+}
+
+#[test]
+fn test_bulk_load_checkpoint_requires_line_based_format() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let mut loader = store
+        .bulk_loader()
+        .with_checkpoint(std::env::temp_dir().join("oxigraph-test-bulk-load-checkpoint-turtle"));
+    assert!(matches!(
+        loader.load_from_slice(
+            RdfFormat::Turtle,
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> ."
+        ),
+        Err(LoaderError::UnsupportedCheckpointFormat(RdfFormat::Turtle))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_load_provenance() -> Result<(), Box<dyn Error>> {
+    let provenance = NamedNodeRef::new("http://example.com/provenance")?;
+    let store = Store::new()?;
+
+    let mut loader = store.bulk_loader().with_provenance(
+        LoadProvenance::new("http://example.com/load/")?
+            .with_graph(provenance)
+            .with_source(Literal::from("dump.nq")),
+    );
+    loader.load_from_slice(
+        RdfFormat::NQuads,
+        "<http://example.com/s> <http://example.com/p> \"1\" <http://example.com/g1> .\n\
+         <http://example.com/s> <http://example.com/p> \"2\" <http://example.com/g2> .\n\
+         <http://example.com/s> <http://example.com/p> \"3\" <http://example.com/g1> .\n\
+         <http://example.com/s> <http://example.com/p> \"4\" .",
+    )?;
+    loader.commit()?;
+
+    store.load_from_reader_with_provenance(
+        RdfParser::from_format(RdfFormat::NTriples)
+            .with_default_graph(NamedNodeRef::new("http://example.com/g3")?),
+        b"<http://example.com/s> <http://example.com/p> \"5\" .".as_slice(),
+        LoadProvenance::new("http://example.com/load/")?
+            .with_graph(provenance)
+            .with_agent("alice")
+            .with_client_address("127.0.0.1"),
+    )?;
+
+    let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+        .parse_query(
+            "PREFIX prov: <http://www.w3.org/ns/prov#>
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            SELECT ?g ?count ?used ?agent ?address WHERE { GRAPH <http://example.com/provenance> {
+                ?activity a prov:Activity ;
+                    prov:startedAtTime ?start ;
+                    prov:endedAtTime ?end ;
+                    <http://oxigraph.org/provenance#quadCount> ?count .
+                ?g a prov:Entity ; prov:wasGeneratedBy ?activity .
+                OPTIONAL { ?activity prov:used ?used }
+                OPTIONAL { ?activity prov:wasAssociatedWith [ a prov:Agent ; rdfs:label ?agent ; <http://oxigraph.org/provenance#clientAddress> ?address ] }
+                FILTER(STRSTARTS(STR(?activity), \"http://example.com/load/\") && ?start <= ?end)
+            } } ORDER BY ?g",
+        )?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Solutions expected".into());
+    };
+    let solutions = solutions
+        .map(|s| {
+            let s = s?;
+            Ok((
+                s.get("g").map(Term::to_string),
+                s.get("count").map(Term::to_string),
+                s.get("used").map(Term::to_string),
+                s.get("agent").map(Term::to_string),
+                s.get("address").map(Term::to_string),
+            ))
+        })
+        .collect::<Result<Vec<_>, QueryEvaluationError>>()?;
+    let some = |s: &str| Some(s.to_owned());
+    assert_eq!(
+        solutions,
+        [
+            (
+                some("<http://example.com/g1>"),
+                some("\"4\"^^<http://www.w3.org/2001/XMLSchema#integer>"),
+                some("\"dump.nq\""),
+                None,
+                None
+            ),
+            (
+                some("<http://example.com/g2>"),
+                some("\"4\"^^<http://www.w3.org/2001/XMLSchema#integer>"),
+                some("\"dump.nq\""),
+                None,
+                None
+            ),
+            (
+                some("<http://example.com/g3>"),
+                some("\"1\"^^<http://www.w3.org/2001/XMLSchema#integer>"),
+                None,
+                some("\"alice\""),
+                some("\"127.0.0.1\"")
+            ),
+        ]
+    );
+    let error = LoadProvenance::new("not an IRI").unwrap_err();
+    assert_eq!(error.to_string(), "Invalid IRI code point ' '");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "history")]
+fn test_history() -> Result<(), Box<dyn Error>> {
+    use oxigraph::store::{HistoryConfig, HistoryEventKind};
+
+    let ex = NamedNodeRef::new("http://example.com/s")?;
+    let g = NamedNodeRef::new("http://example.com/g")?;
+    let history = NamedNodeRef::new("http://example.com/history")?;
+    let in_default_graph = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    let in_g = QuadRef::new(ex, ex, ex, g);
+    let store = Store::new()?;
+    store.insert(in_default_graph)?; // Not recorded
+    store.enable_history(HistoryConfig::new(history))?;
+
+    let mut transaction = store.start_transaction()?;
+    transaction.remove(in_default_graph);
+    transaction.insert(in_g);
+    transaction.commit()?;
+    SparqlEvaluator::new()
+        .parse_update("CLEAR GRAPH <http://example.com/g>")?
+        .on_store(&store)
+        .execute()?;
+    let mut loader = store.bulk_loader();
+    loader.load_quads([in_default_graph.into_owned()])?;
+    loader.commit()?;
+
+    let events = store.history_for_quad(in_g)?;
+    assert_eq!(
+        events.iter().map(|e| e.kind).collect::<Vec<_>>(),
+        [HistoryEventKind::Addition, HistoryEventKind::Removal]
+    );
+    assert!(events[0].transaction < events[1].transaction);
+    let removal = &store.history_for_quad(in_default_graph)?[0];
+    assert_eq!(removal.kind, HistoryEventKind::Removal);
+    assert_eq!(removal.transaction, events[0].transaction);
+    assert!(store.graph_at(g, events[0].timestamp)?.contains(in_g));
+    assert!(store.graph_at(g, events[1].timestamp)?.is_empty());
+    assert!(
+        store
+            .graph_at(GraphNameRef::DefaultGraph, events[1].timestamp)?
+            .is_empty()
+    );
+    let QueryResults::Boolean(has_bulk_load) = SparqlEvaluator::new()
+        .parse_query(
+            "ASK { GRAPH <http://example.com/history> { ?e a <http://oxigraph.org/history#BulkLoad> } }",
+        )?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Boolean expected".into());
+    };
+    assert!(has_bulk_load);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "history")]
+fn test_history_ignores_unchanged_quads() -> Result<(), Box<dyn Error>> {
+    use oxigraph::store::{HistoryConfig, HistoryEventKind};
+
+    let ex = NamedNodeRef::new("http://example.com/s")?;
+    let quad = QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph);
+    let store = Store::new()?;
+    store.enable_history(HistoryConfig::new(NamedNodeRef::new(
+        "http://example.com/history",
+    )?))?;
+    store.remove(quad)?; // Not in the store
+    store.insert(quad)?;
+    store.insert(quad)?; // Already in the store
+    let mut transaction = store.start_transaction()?;
+    transaction.insert(quad); // Already in the store
+    transaction.remove(quad);
+    transaction.remove(quad); // Already removed
+    transaction.commit()?;
+    assert_eq!(
+        store
+            .history_for_quad(quad)?
+            .iter()
+            .map(|e| e.kind)
+            .collect::<Vec<_>>(),
+        [HistoryEventKind::Addition, HistoryEventKind::Removal]
+    );
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::panic)]
+fn test_query_logger() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    let ex = NamedNodeRef::new("http://example.com")?;
+    store.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph))?;
+    let records = Arc::new(Mutex::new(Vec::new()));
+
+    // Statistics
+    let logger_records = Arc::clone(&records);
+    let QueryResults::Solutions(solutions) = SparqlEvaluator::new()
+        .with_query_logger(move |record| {
+            if let Ok(mut records) = logger_records.lock() {
+                records.push(record);
+            }
+        })
+        .parse_query("SELECT ?v WHERE { VALUES ?v { 1 2 3 } FILTER(?v > 1) }")?
+        .on_store(&store)
+        .compute_statistics()
+        .execute()?
+    else {
+        return Err("Solutions expected".into());
+    };
+    assert_eq!(solutions.count(), 2);
+    {
+        let records = records.lock().map_err(|e| e.to_string())?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].result_count, 2);
+        assert_eq!(records[0].max_intermediate_results, Some(3));
+        assert!(!records[0].cancelled);
+    }
+
+    // Cancellation
+    let logger_records = Arc::clone(&records);
+    let cancellation_token = CancellationToken::new();
+    let QueryResults::Graph(triples) = SparqlEvaluator::new()
+        .with_query_logger(move |record| {
+            if let Ok(mut records) = logger_records.lock() {
+                records.push(record);
+            }
+        })
+        .with_cancellation_token(cancellation_token.clone())
+        .parse_query("CONSTRUCT WHERE { ?s ?p ?o }")?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Triples expected".into());
+    };
+    cancellation_token.cancel();
+    if triples.collect::<Result<Vec<_>, _>>().is_ok() {
+        return Err("The evaluation should have been cancelled".into());
+    }
+    {
+        let records = records.lock().map_err(|e| e.to_string())?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].result_count, 0);
+        assert_eq!(records[1].max_intermediate_results, None);
+        assert!(records[1].cancelled);
+    }
+
+    // A failing logger does not fail the query
+    let results = SparqlEvaluator::new()
+        .with_query_logger(|_| panic!("logger failure"))
+        .parse_query("ASK { }")?
+        .on_store(&store)
+        .execute()?;
+    assert!(matches!(results, QueryResults::Boolean(true)));
+    Ok(())
+}
+
+#[test]
+fn test_construct_quads() -> Result<(), Box<dyn Error>> {
+    let store = Store::new()?;
+    store.load_from_slice(
+        RdfFormat::TriG,
+        r#"@prefix ex: <http://example.com/> .
+        ex:source {
+            ex:a ex:status "reviewed" ; ex:name "A" .
+            ex:b ex:status "draft" ; ex:name "B" .
+        }
+        ex:other {
+            ex:c ex:status "reviewed" ; ex:name "C" .
+        }"#,
+    )?;
+
+    // The GRAPH blocks in CONSTRUCT templates are an extension
+    assert!(
+        SparqlEvaluator::new()
+            .parse_query("CONSTRUCT { GRAPH ?g { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }")
+            .is_err()
+    );
+
+    // We copy the reviewed resources of each graph to a graph of reviewed resources
+    let QueryResults::Dataset(quads) = SparqlEvaluator::new()
+        .with_construct_quads()
+        .parse_query(
+            r#"PREFIX ex: <http://example.com/>
+            CONSTRUCT {
+                GRAPH ex:reviewed { ?s ?p ?o }
+                ?s ex:copiedFrom ?g
+            } WHERE {
+                GRAPH ?g { ?s ex:status "reviewed" ; ?p ?o }
+            }"#,
+        )?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Quads expected".into());
+    };
+    let quads = quads.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(quads.len(), 6);
+    store.extend(quads)?;
+    let reviewed = NamedNodeRef::new("http://example.com/reviewed")?;
+    assert_eq!(
+        store
+            .quads_for_pattern(None, None, None, Some(reviewed.into()))
+            .count(),
+        4
+    );
+    assert!(store.contains(QuadRef::new(
+        NamedNodeRef::new("http://example.com/c")?,
+        NamedNodeRef::new("http://example.com/name")?,
+        LiteralRef::new_simple_literal("C"),
+        reviewed,
+    ))?);
+    assert!(store.contains(QuadRef::new(
+        NamedNodeRef::new("http://example.com/a")?,
+        NamedNodeRef::new("http://example.com/copiedFrom")?,
+        NamedNodeRef::new("http://example.com/source")?,
+        GraphNameRef::DefaultGraph,
+    ))?);
+
+    // Each quad goes back to its own graph, the quads whose graph name would not be an IRI are skipped
+    let QueryResults::Dataset(quads) = SparqlEvaluator::new()
+        .with_construct_quads()
+        .parse_query(
+            "CONSTRUCT { GRAPH ?g { ?s ?p ?o } GRAPH ?o { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }",
+        )?
+        .on_store(&store)
+        .execute()?
+    else {
+        return Err("Quads expected".into());
+    };
+    let quads = quads.collect::<Result<HashSet<_>, _>>()?;
+    assert_eq!(
+        quads,
+        store
+            .iter()
+            .filter(|q| q.as_ref().is_ok_and(|q| !q.graph_name.is_default_graph()))
+            .collect::<Result<HashSet<_>, _>>()?
+    );
+
+    // Templates without GRAPH blocks still return triples
+    assert!(matches!(
+        SparqlEvaluator::new()
+            .with_construct_quads()
+            .parse_query("CONSTRUCT { ?s ?p ?o } WHERE { ?s ?p ?o }")?
+            .on_store(&store)
+            .execute()?,
+        QueryResults::Graph(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_query_subscription() -> Result<(), Box<dyn Error>> {
+    check_query_subscription(&Store::new()?)
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_query_subscription_on_disk() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    check_query_subscription(&Store::open(&dir)?)
+}
+
+fn check_query_subscription(store: &Store) -> Result<(), Box<dyn Error>> {
+    let person = NamedNodeRef::new("http://example.com/Person")?;
+    let name = NamedNodeRef::new("http://example.com/name")?;
+    let alice = NamedNodeRef::new("http://example.com/alice")?;
+    let bob = NamedNodeRef::new("http://example.com/bob")?;
+    store.insert(QuadRef::new(
+        alice,
+        rdf::TYPE,
+        person,
+        GraphNameRef::DefaultGraph,
+    ))?;
+
+    // We record the changes of ?s and the number of evaluations
+    let subscribe = |options| {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let evaluations = Arc::new(Mutex::new(0));
+        let callback_changes = Arc::clone(&changes);
+        let logger_evaluations = Arc::clone(&evaluations);
+        let subscription = store.subscribe_query(
+            SparqlEvaluator::new()
+                .with_query_logger(move |_| {
+                    if let Ok(mut evaluations) = logger_evaluations.lock() {
+                        *evaluations += 1;
+                    }
+                })
+                .parse_query("SELECT ?s WHERE { ?s a <http://example.com/Person> }")?,
+            options,
+            move |change| {
+                if let (Ok(change), Ok(mut changes)) = (change, callback_changes.lock()) {
+                    let values = |solutions: &[oxigraph::sparql::QuerySolution]| {
+                        solutions
+                            .iter()
+                            .filter_map(|solution| solution.get("s").cloned())
+                            .collect::<Vec<_>>()
+                    };
+                    changes.push((values(change.added()), values(change.removed())));
+                }
+            },
+        )?;
+        Ok::<_, Box<dyn Error>>((subscription, changes, evaluations))
+    };
+    let (incremental, incremental_changes, incremental_evaluations) =
+        subscribe(QuerySubscriptionOptions::new())?;
+    let (naive, naive_changes, naive_evaluations) =
+        subscribe(QuerySubscriptionOptions::new().without_incremental_evaluation())?;
+    let take_changes = |changes: &Mutex<Vec<(Vec<Term>, Vec<Term>)>>| {
+        changes
+            .lock()
+            .map(|mut changes| changes.drain(..).collect::<Vec<_>>())
+            .map_err(|e| e.to_string())
+    };
+    let evaluation_count = |evaluations: &Mutex<usize>| {
+        evaluations
+            .lock()
+            .map(|evaluations| *evaluations)
+            .map_err(|e| e.to_string())
+    };
+
+    // Initial evaluation
+    assert_eq!(
+        take_changes(&incremental_changes)?,
+        vec![(vec![alice.into()], vec![])]
+    );
+    assert_eq!(
+        take_changes(&naive_changes)?,
+        vec![(vec![alice.into()], vec![])]
+    );
+
+    // A change not matching the pattern does not trigger the incremental evaluation
+    store.insert(QuadRef::new(
+        bob,
+        name,
+        LiteralRef::new_simple_literal("Bob"),
+        GraphNameRef::DefaultGraph,
+    ))?;
+    assert_eq!(evaluation_count(&incremental_evaluations)?, 1);
+    assert_eq!(evaluation_count(&naive_evaluations)?, 2);
+    assert!(take_changes(&incremental_changes)?.is_empty());
+    assert!(take_changes(&naive_changes)?.is_empty());
+
+    // Additions and removals
+    store.insert(QuadRef::new(
+        bob,
+        rdf::TYPE,
+        person,
+        GraphNameRef::DefaultGraph,
+    ))?;
+    assert_eq!(
+        take_changes(&incremental_changes)?,
+        vec![(vec![bob.into()], vec![])]
+    );
+    store.remove(QuadRef::new(
+        alice,
+        rdf::TYPE,
+        person,
+        GraphNameRef::DefaultGraph,
+    ))?;
+    assert_eq!(
+        take_changes(&incremental_changes)?,
+        vec![(vec![], vec![alice.into()])]
+    );
+    assert_eq!(evaluation_count(&incremental_evaluations)?, 3);
+
+    // Clearing a graph does not give the changed quads
+    store.clear_graph(GraphNameRef::DefaultGraph)?;
+    assert_eq!(
+        take_changes(&incremental_changes)?,
+        vec![(vec![], vec![bob.into()])]
+    );
+    assert_eq!(
+        take_changes(&naive_changes)?,
+        vec![
+            (vec![bob.into()], vec![]),
+            (vec![], vec![alice.into()]),
+            (vec![], vec![bob.into()])
+        ]
+    );
+
+    // Only SELECT queries are supported
+    assert!(matches!(
+        store.subscribe_query(
+            SparqlEvaluator::new().parse_query("ASK { ?s ?p ?o }")?,
+            QuerySubscriptionOptions::new(),
+            |_| (),
+        ),
+        Err(QuerySubscriptionError::NotSelectQuery)
+    ));
+
+    // Maximal number of subscriptions
+    store.set_max_query_subscriptions(2);
+    assert!(matches!(
+        store.subscribe_query(
+            SparqlEvaluator::new().parse_query("SELECT * WHERE { ?s ?p ?o }")?,
+            QuerySubscriptionOptions::new(),
+            |_| (),
+        ),
+        Err(QuerySubscriptionError::TooManySubscriptions(2))
+    ));
+    naive.unsubscribe();
+    let (_other, _, _) = subscribe(QuerySubscriptionOptions::new())?;
+
+    // No callback after unsubscription
+    incremental.unsubscribe();
+    store.insert(QuadRef::new(
+        alice,
+        rdf::TYPE,
+        person,
+        GraphNameRef::DefaultGraph,
+    ))?;
+    assert!(take_changes(&incremental_changes)?.is_empty());
+    assert!(take_changes(&naive_changes)?.is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn test_tracing_spans() -> Result<(), Box<dyn Error>> {
+    let layer = RecordingLayer::default();
+    let _guard = tracing_subscriber::registry()
+        .with(layer.clone())
+        .set_default();
+
+    let ex = NamedNodeRef::new("http://example.com")?;
+    let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    let store = Store::new()?;
+    store.insert(QuadRef::new(ex, ex, ex, g1))?;
+    store
+        .bulk_loader()
+        .load_quads([Quad::new(ex, ex, ex, GraphName::DefaultGraph)])?;
+    store.rename_graph(g1, g2, false)?;
+    SparqlEvaluator::new()
+        .parse_query("SELECT * WHERE { ?s ?p ?o }")?
+        .on_store(&store)
+        .execute()?;
+    let result = SparqlEvaluator::new()
+        .with_service_handler(ex, FailingServiceHandler)
+        .parse_update("INSERT { ?s ?p ?o } WHERE { SERVICE <http://example.com> { ?s ?p ?o } }")?
+        .on_store(&store)
+        .execute();
+    assert!(result.is_err());
+
+    let spans = layer.spans.lock().map_err(|e| e.to_string())?.clone();
+    for expected in [
+        "transaction",
+        "commit quad_changes=1 graphs=1 all_named_graphs=false",
+        "load_batch quads=1",
+        "rename_graph from=<http://example.com/g1> to=<http://example.com/g2>",
+        "query_parse",
+        "query",
+        "query_plan",
+        "query_execute",
+        "update_parse",
+        "update operations=1",
+        "service service=http://example.com",
+    ] {
+        assert!(
+            spans.iter().any(|span| span == expected),
+            "{expected} not found in {spans:?}"
+        );
+    }
+    let errors = layer.errors.lock().map_err(|e| e.to_string())?.clone();
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|error| error.contains("service failure"))
+            .count(),
+        2,
+        "{errors:?}"
+    );
+    Ok(())
+}
+
+/// Records the names and fields of the created spans and the error events
+#[cfg(feature = "tracing")]
+#[derive(Default, Clone)]
+struct RecordingLayer {
+    spans: Arc<Mutex<Vec<String>>>,
+    errors: Arc<Mutex<Vec<String>>>,
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing::Subscriber> Layer<S> for RecordingLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+        let mut recorder = FieldRecorder(attrs.metadata().name().to_owned());
+        attrs.record(&mut recorder);
+        self.spans.lock().unwrap().push(recorder.0);
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+        if *event.metadata().level() == Level::ERROR {
+            let mut recorder = FieldRecorder(String::new());
+            event.record(&mut recorder);
+            self.errors.lock().unwrap().push(recorder.0);
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+struct FieldRecorder(String);
+
+#[cfg(feature = "tracing")]
+impl Visit for FieldRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        // `%` fields are given wrapped in a value whose Debug implementation is Display
+        write!(self.0, " {}={}", field.name(), DisplayedField(value)).unwrap();
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        write!(self.0, " {}={value}", field.name()).unwrap();
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        write!(self.0, " {}={value}", field.name()).unwrap();
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        write!(self.0, " {}={value}", field.name()).unwrap();
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        write!(self.0, " {}={value}", field.name()).unwrap();
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn Error + 'static)) {
+        write!(self.0, " {}={value}", field.name()).unwrap();
+        let mut source = value.source();
+        while let Some(error) = source {
+            write!(self.0, ": {error}").unwrap();
+            source = error.source();
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+struct DisplayedField<'a>(&'a dyn Debug);
+
+#[cfg(feature = "tracing")]
+impl Display for DisplayedField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "tracing")]
+struct FailingServiceHandler;
+
+#[cfg(feature = "tracing")]
+impl ServiceHandler for FailingServiceHandler {
+    type Error = io::Error;
+
+    fn handle(
+        &self,
+        _: &GraphPattern,
+        _: Option<&Iri<String>>,
+    ) -> Result<QuerySolutionIter<'static>, io::Error> {
+        Err(io::Error::other("service failure"))
+    }
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_bulk_load_on_existing_delete_overrides_the_delete_on_disk() -> Result<(), Box<dyn Error>> {
+    let quad = QuadRef::new(
+        NamedNodeRef::new_unchecked("http://example.com/s"),
+        NamedNodeRef::new_unchecked("http://example.com/p"),
+        NamedNodeRef::new_unchecked("http://example.com/o"),
+        NamedNodeRef::new_unchecked("http://www.wikidata.org/wiki/Special:EntityData/Q90"),
+    );
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    store.remove(quad)?;
+    let mut loader = store.bulk_loader();
+    loader.load_quads([quad.into_owned()])?;
+    loader.commit()?;
+    assert_eq!(store.len()?, 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_open_bad_dir() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    create_dir_all(&dir)?;
+    {
+        File::create(dir.as_ref().join("CURRENT"))?.write_all(b"foo")?;
+    }
+    assert!(Store::open(&dir).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "rocksdb"))]
+fn test_bad_stt_open() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let store = Store::open(&dir)?;
+    remove_dir_all(&dir)?;
+    let mut loader = store.bulk_loader();
+    loader.load_quads(once(Quad::new(
+        NamedNode::new_unchecked("http://example.com/s"),
+        NamedNode::new_unchecked("http://example.com/p"),
+        NamedNode::new_unchecked("http://example.com/o"),
+        GraphName::DefaultGraph,
+    )))?;
+    loader.commit().unwrap_err();
+    Ok(())
 }
 
 #[test]
@@ -443,6 +3376,50 @@ fn test_backup() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
+fn test_open_with_options_and_compact() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let quads = (0..1000)
+        .map(|i| {
+            Quad::new(
+                NamedNode::new(format!("http://example.com/s{i}"))?,
+                NamedNode::new("http://example.com/p")?,
+                Literal::from(i),
+                GraphName::DefaultGraph,
+            )
+        })
+        .collect::<Result<Vec<_>, IriParseError>>()?;
+    {
+        let store = Store::open(&dir)?;
+        store.extend(quads.clone())?;
+    }
+
+    // The existing data is rewritten with the new options
+    let store = Store::open_with_options(
+        &dir,
+        StoreOptions::new()
+            .with_compression_per_level(vec![StorageCompression::None, StorageCompression::Lz4])
+            .with_block_cache_size(8 * 1024 * 1024)
+            .with_bloom_filter_bits_per_key(10.)
+            .with_write_buffer_size(4 * 1024 * 1024)
+            .with_max_background_jobs(2),
+    )?;
+    let report = store.compact()?;
+    assert!(report.size_before > 0);
+    assert!(report.size_after > 0);
+    store.validate()?;
+    assert_eq!(store.len()?, quads.len());
+    drop(store);
+
+    // The store is still readable with the default options
+    let store = Store::open(&dir)?;
+    for quad in &quads {
+        assert!(store.contains(quad)?);
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(all(not(target_family = "wasm"), feature = "rocksdb"))]
 fn test_bad_backup() -> Result<(), Box<dyn Error>> {
@@ -639,3 +3616,64 @@ impl Drop for DirSaver {
         }
     }
 }
+
+#[test]
+#[cfg(all(not(target_family = "wasm"), feature = "http-client"))]
+fn test_load_from_iri() -> Result<(), Box<dyn Error>> {
+    let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?
+        .local_addr()?
+        .port();
+    let _server = Server::new(|request| {
+        let response = Response::builder();
+        match request.uri().path() {
+            "/redirect" => response
+                .status(StatusCode::FOUND)
+                .header(LOCATION, "/data/file.ttl")
+                .body(Body::empty()),
+            // The format is guessed from the extension
+            "/data/file.ttl" => response
+                .header(CONTENT_TYPE, "text/plain")
+                .body("<s> <p> <o> .".into()),
+            "/invalid.nt" => response
+                .header(CONTENT_TYPE, "application/n-triples")
+                .body("<s> <p> .".into()),
+            _ => response.status(StatusCode::NOT_FOUND).body(Body::empty()),
+        }
+        .unwrap()
+    })
+    .bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+    .spawn()?;
+    let base = format!("http://localhost:{port}");
+
+    let store = Store::new()?;
+    let graph = NamedNode::new("http://example.com/g")?;
+    store.load_from_iri(
+        NamedNodeRef::new(&format!("{base}/redirect"))?,
+        Some(graph.as_ref().into()),
+    )?;
+    // The final URL is the base IRI
+    let data = |name: &str| NamedNode::new(format!("{base}/data/{name}"));
+    assert!(store.contains(QuadRef::new(&data("s")?, &data("p")?, &data("o")?, &graph))?);
+
+    assert!(matches!(
+        store
+            .iri_loader()
+            .with_http_redirection_limit(0)
+            .load(NamedNodeRef::new(&format!("{base}/redirect"))?, None),
+        Err(IriLoadError::TooManyRedirections { limit: 0, .. })
+    ));
+    assert!(matches!(
+        store.load_from_iri(NamedNodeRef::new(&format!("{base}/missing"))?, None),
+        Err(IriLoadError::HttpStatus { status: 404, .. })
+    ));
+    assert!(matches!(
+        store.load_from_iri(NamedNodeRef::new(&format!("{base}/invalid.nt"))?, None),
+        Err(IriLoadError::Parsing(_))
+    ));
+    assert!(matches!(
+        store.load_from_iri(NamedNodeRef::new("http://localhost:1/")?, None),
+        Err(IriLoadError::Network { .. })
+    ));
+    assert_eq!(store.len()?, 1);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+#![cfg(all(target_os = "linux", feature = "rocksdb"))]
+#![allow(clippy::panic_in_result_fn)]
+
+use oxigraph::model::*;
+use oxigraph::sparql::SparqlEvaluator;
+use oxigraph::store::{Store, StoreOptions};
+use std::error::Error;
+use std::fs;
+use tempfile::TempDir;
+
+const NUMBER_OF_QUADS: u64 = 1_000_000;
+const UPDATE_MAX_MEMORY_SIZE_IN_MEGABYTES: usize = 16;
+const MAX_MEMORY_GROWTH_IN_MEGABYTES: u64 = 128;
+
+/// Deletes 1M quads with `DELETE/WHERE` and checks that the process resident memory never grows by more than 128MB.
+///
+/// Keeping the whole update in a single RocksDB write batch would take a few hundreds of MB.
+/// The RocksDB caches are kept small so that they do not hide the memory used by the update.
+#[test]
+fn test_delete_insert_with_memory_ceiling() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let store = Store::open_with_options(
+        dir.path(),
+        StoreOptions::new()
+            .with_write_buffer_size(4 * 1024 * 1024)
+            .with_block_cache_size(8 * 1024 * 1024),
+    )?;
+    let predicate = NamedNode::new("http://example.com/p")?;
+    store
+        .bulk_loader()
+        .load_quads((0..NUMBER_OF_QUADS).map(|i| {
+            Quad::new(
+                NamedNode::new_unchecked(format!("http://example.com/{i}")),
+                predicate.clone(),
+                Literal::from(i),
+                GraphName::DefaultGraph,
+            )
+        }))?;
+    assert_eq!(store.len()?, usize::try_from(NUMBER_OF_QUADS)?);
+
+    // Resets the peak resident memory to the current resident memory
+    fs::write("/proc/self/clear_refs", "5")?;
+    let baseline = resident_memory_in_kilobytes("VmRSS")?;
+    SparqlEvaluator::new()
+        .with_update_max_memory_size_in_megabytes(UPDATE_MAX_MEMORY_SIZE_IN_MEGABYTES)
+        .parse_update("DELETE { ?s ?p ?o } WHERE { ?s ?p ?o FILTER(isLiteral(?o)) }")?
+        .on_store(&store)
+        .execute()?;
+    let growth = resident_memory_in_kilobytes("VmHWM")?.saturating_sub(baseline) / 1024;
+    assert!(
+        growth < MAX_MEMORY_GROWTH_IN_MEGABYTES,
+        "The update made the process resident memory grow by {growth}MB"
+    );
+    assert!(store.is_empty()?);
+    Ok(())
+}
+
+/// Reads a memory usage field of `/proc/self/status`
+fn resident_memory_in_kilobytes(field: &str) -> Result<u64, Box<dyn Error>> {
+    let status = fs::read_to_string("/proc/self/status")?;
+    let value = status
+        .lines()
+        .find_map(|line| line.strip_prefix(field)?.strip_prefix(':'))
+        .ok_or_else(|| format!("{field} is not in /proc/self/status"))?;
+    Ok(value.trim().trim_end_matches("kB").trim().parse()?)
+}
@@ -0,0 +1,96 @@
+use codspeed_criterion_compat::{Criterion, criterion_group, criterion_main};
+use oxrdf::{Graph, GraphNameRef, Literal, NamedNode, TripleRef};
+
+fn graph_triples_matching(c: &mut Criterion) {
+    let subjects = (0..1000)
+        .map(|i| NamedNode::new_unchecked(format!("http://example.com/s{i}")))
+        .collect::<Vec<_>>();
+    let predicates = (0..10)
+        .map(|i| NamedNode::new_unchecked(format!("http://example.com/p{i}")))
+        .collect::<Vec<_>>();
+    let objects = (0..10).map(Literal::from).collect::<Vec<_>>();
+    let mut graph = Graph::new();
+    for s in &subjects {
+        for p in &predicates {
+            for o in &objects {
+                graph.insert(TripleRef::new(s, p, o));
+            }
+        }
+    }
+    let subject = subjects[500].as_ref();
+    let predicate = predicates[5].as_ref();
+    let object = objects[5].as_ref();
+
+    let mut group = c.benchmark_group("graph triples matching");
+    group.bench_function("subject with filter", |b| {
+        b.iter(|| graph.iter().filter(|t| t.subject == subject.into()).count())
+    });
+    group.bench_function("subject with index", |b| {
+        b.iter(|| {
+            graph
+                .triples_matching(Some(subject.into()), None, None)
+                .count()
+        })
+    });
+    group.bench_function("subject and predicate with filter", |b| {
+        b.iter(|| {
+            graph
+                .iter()
+                .filter(|t| t.subject == subject.into() && t.predicate == predicate)
+                .count()
+        })
+    });
+    group.bench_function("subject and predicate with index", |b| {
+        b.iter(|| {
+            graph
+                .triples_matching(Some(subject.into()), Some(predicate), None)
+                .count()
+        })
+    });
+    group.bench_function("predicate and object with filter", |b| {
+        b.iter(|| {
+            graph
+                .iter()
+                .filter(|t| t.predicate == predicate && t.object == object.into())
+                .count()
+        })
+    });
+    group.bench_function("predicate and object with index", |b| {
+        b.iter(|| {
+            graph
+                .triples_matching(None, Some(predicate), Some(object.into()))
+                .count()
+        })
+    });
+    group.finish();
+
+    let dataset = graph
+        .iter()
+        .map(|t| t.in_graph(GraphNameRef::DefaultGraph))
+        .collect::<oxrdf::Dataset>();
+    let mut group = c.benchmark_group("dataset quads matching");
+    group.bench_function("object and graph name with filter", |b| {
+        b.iter(|| {
+            dataset
+                .iter()
+                .filter(|q| q.object == object.into() && q.graph_name.is_default_graph())
+                .count()
+        })
+    });
+    group.bench_function("object and graph name with index", |b| {
+        b.iter(|| {
+            dataset
+                .quads_matching(
+                    None,
+                    None,
+                    Some(object.into()),
+                    Some(GraphNameRef::DefaultGraph),
+                )
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(dataset, graph_triples_matching);
+criterion_main!(dataset);
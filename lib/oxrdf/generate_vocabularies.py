@@ -0,0 +1,168 @@
+"""Generates the vocabulary modules in src/vocab/ from the published vocabularies.
+
+Usage: `pip install pyoxigraph` then `python generate_vocabularies.py` from this directory.
+"""
+
+import re
+import subprocess
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Dict, List, Optional
+from urllib.request import Request, urlopen
+
+from pyoxigraph import Literal, NamedNode, RdfFormat, parse
+
+LABEL_PREDICATES = [
+    NamedNode("http://www.w3.org/2000/01/rdf-schema#label"),
+    NamedNode("http://www.w3.org/2004/02/skos/core#prefLabel"),
+]
+OUTPUT_DIR = Path(__file__).parent / "src" / "vocab"
+
+
+@dataclass
+class Vocabulary:
+    module: str
+    title: str
+    documentation: str
+    namespace: str
+    source: str
+    format: RdfFormat
+    # Explicit constant names for the terms whose generated names would collide
+    renamed: Dict[str, str] = field(default_factory=dict)
+
+
+VOCABULARIES = [
+    Vocabulary(
+        "dcterms",
+        "DCMI Metadata Terms",
+        "https://www.dublincore.org/specifications/dublin-core/dcmi-terms/",
+        "http://purl.org/dc/terms/",
+        "https://www.dublincore.org/specifications/dublin-core/dcmi-terms/dublin_core_terms.ttl",
+        RdfFormat.TURTLE,
+    ),
+    Vocabulary(
+        "foaf",
+        "FOAF",
+        "http://xmlns.com/foaf/spec/",
+        "http://xmlns.com/foaf/0.1/",
+        "http://xmlns.com/foaf/spec/index.rdf",
+        RdfFormat.RDF_XML,
+        # Archaic spelling of foaf:familyName
+        {"family_name": "FAMILY_NAME_ARCHAIC"},
+    ),
+    Vocabulary(
+        "geosparql",
+        "GeoSPARQL",
+        "https://opengeospatial.github.io/ogc-geosparql/",
+        "http://www.opengis.net/ont/geosparql#",
+        "https://opengeospatial.github.io/ogc-geosparql/geosparql11/geo.ttl",
+        RdfFormat.TURTLE,
+    ),
+    Vocabulary(
+        "owl",
+        "OWL 2",
+        "https://www.w3.org/TR/owl2-overview/",
+        "http://www.w3.org/2002/07/owl#",
+        "https://www.w3.org/2002/07/owl.ttl",
+        RdfFormat.TURTLE,
+    ),
+    Vocabulary(
+        "prov",
+        "PROV-O",
+        "https://www.w3.org/TR/prov-o/",
+        "http://www.w3.org/ns/prov#",
+        "https://www.w3.org/ns/prov-o.ttl",
+        RdfFormat.TURTLE,
+    ),
+    Vocabulary(
+        "shacl",
+        "SHACL",
+        "https://www.w3.org/TR/shacl/",
+        "http://www.w3.org/ns/shacl#",
+        "https://www.w3.org/ns/shacl.ttl",
+        RdfFormat.TURTLE,
+    ),
+    Vocabulary(
+        "skos",
+        "SKOS",
+        "https://www.w3.org/TR/skos-reference/",
+        "http://www.w3.org/2004/02/skos/core#",
+        "https://www.w3.org/2009/08/skos-reference/skos.rdf",
+        RdfFormat.RDF_XML,
+    ),
+]
+
+
+def fetch_terms(vocabulary: Vocabulary) -> Dict[str, Optional[str]]:
+    """The local names of the terms described in the vocabulary source with their English label"""
+    request = Request(vocabulary.source, headers={"Accept": vocabulary.format.media_type})
+    with urlopen(request) as response:
+        triples = list(parse(response.read(), vocabulary.format, base_iri=vocabulary.source))
+    terms: Dict[str, Optional[str]] = {}
+    for triple in triples:
+        subject = triple.subject
+        if isinstance(subject, NamedNode) and subject.value.startswith(vocabulary.namespace):
+            terms.setdefault(subject.value[len(vocabulary.namespace) :], None)
+    for label_predicate in reversed(LABEL_PREDICATES):
+        for triple in triples:
+            if (
+                triple.predicate == label_predicate
+                and isinstance(triple.subject, NamedNode)
+                and isinstance(triple.object, Literal)
+                and triple.object.language in (None, "en")
+                and triple.subject.value.startswith(vocabulary.namespace)
+            ):
+                terms[triple.subject.value[len(vocabulary.namespace) :]] = triple.object.value
+    terms.pop("", None)  # The ontology itself
+    return terms
+
+
+def constant_name(local_name: str) -> str:
+    """base64Binary -> BASE_64_BINARY, NCName -> NC_NAME, AndConstraintComponent-and -> AND_CONSTRAINT_COMPONENT_AND"""
+    words = re.findall(r"[A-Z]+(?=[A-Z][a-z])|[A-Z]?[a-z]+|[A-Z]+|[0-9]+", local_name)
+    return "_".join(words).upper()
+
+
+def constant_names(vocabulary: Vocabulary, local_names: List[str]) -> Dict[str, str]:
+    """Properties get a _PROPERTY suffix if they would collide with a class, e.g. prov:entity and prov:Entity"""
+    names = {
+        local_name: vocabulary.renamed.get(local_name, constant_name(local_name))
+        for local_name in local_names
+    }
+    classes = {name for local_name, name in names.items() if local_name[0].isupper()}
+    for local_name, name in names.items():
+        if local_name[0].islower() and name in classes and local_name not in vocabulary.renamed:
+            names[local_name] = f"{name}_PROPERTY"
+    duplicates = {name for name in names.values() if list(names.values()).count(name) > 1}
+    if duplicates:
+        raise ValueError(f"Colliding constant names in {vocabulary.module}: {duplicates}")
+    return names
+
+
+def write_module(vocabulary: Vocabulary, terms: Dict[str, Optional[str]]) -> Path:
+    names = constant_names(vocabulary, list(terms))
+    lines = [
+        "// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.",
+        f"//! [{vocabulary.title}]({vocabulary.documentation}) vocabulary.",
+        "use crate::named_node::NamedNodeRef;",
+        "",
+    ]
+    for local_name, name in sorted(names.items(), key=lambda e: e[1]):
+        label = terms[local_name] or local_name
+        lines.append(f"/// {' '.join(label.split())}")
+        lines.append(
+            f'pub const {name}: NamedNodeRef<\'_> = NamedNodeRef::new_unchecked("{vocabulary.namespace}{local_name}");'
+        )
+    lines.append("")
+    lines.append("#[cfg(test)]")
+    lines.append("pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[")
+    lines.extend(f"{name}," for name in sorted(names.values()))
+    lines.append("];")
+    path = OUTPUT_DIR / f"{vocabulary.module}.rs"
+    path.write_text("\n".join(lines) + "\n")
+    return path
+
+
+if __name__ == "__main__":
+    paths = [write_module(vocabulary, fetch_terms(vocabulary)) for vocabulary in VOCABULARIES]
+    subprocess.run(["rustfmt", "--edition", "2024", *paths], check=True)
@@ -0,0 +1,648 @@
+//! [Memory efficient in-memory implementation](CompactGraph) of [RDF graphs](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-graph).
+//!
+//! Usage example:
+//! ```
+//! use oxrdf::*;
+//!
+//! let mut graph = CompactGraph::default();
+//!
+//! // insertion
+//! let ex = NamedNodeRef::new("http://example.com")?;
+//! let triple = TripleRef::new(ex, ex, ex);
+//! graph.insert(triple);
+//!
+//! // simple filter
+//! let results: Vec<_> = graph.triples_for_subject(ex).collect();
+//! assert_eq!(vec![triple], results);
+//!
+//! // Print
+//! assert_eq!(
+//!     graph.to_string(),
+//!     "<http://example.com> <http://example.com> <http://example.com> .\n"
+//! );
+//! # Result::<_, Box<dyn std::error::Error>>::Ok(())
+//! ```
+//!
+//! See also [`Graph`] that provides more features like canonicalization.
+
+use crate::interning::IdentityHasherBuilder;
+use crate::vocab::xsd;
+use crate::*;
+use std::collections::btree_set::Range;
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::hash::BuildHasher;
+use std::ops::RangeInclusive;
+
+/// A memory efficient in-memory [RDF graph](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-graph).
+///
+/// Each distinct term is stored once in an arena and the triples are stored as three 32 bits term identifiers
+/// in three indexes (subject-predicate-object, predicate-object-subject and object-subject-predicate).
+/// Iteration returns [`TripleRef`] views borrowing from the arena.
+///
+/// On data with repeated IRIs and datatypes like the BSBM benchmark datasets,
+/// it allocates around 130 bytes per triple where [`Graph`] allocates around 1050.
+/// It can contain at most 2<sup>32</sup> distinct terms.
+///
+/// <div class="warning">The terms that are not used anymore after a removal are kept in the arena
+/// until [`shrink_to_fit`](Self::shrink_to_fit) is called.</div>
+///
+/// Usage example:
+/// ```
+/// use oxrdf::*;
+///
+/// let mut graph = CompactGraph::default();
+///
+/// // insertion
+/// let ex = NamedNodeRef::new("http://example.com")?;
+/// let triple = TripleRef::new(ex, ex, ex);
+/// graph.insert(triple);
+///
+/// // simple filter
+/// let results: Vec<_> = graph.triples_for_subject(ex).collect();
+/// assert_eq!(vec![triple], results);
+///
+/// // removal
+/// graph.remove(triple);
+/// graph.shrink_to_fit();
+/// assert!(graph.is_empty());
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CompactGraph {
+    arena: TermArena,
+    spo: BTreeSet<(TermId, TermId, TermId)>,
+    pos: BTreeSet<(TermId, TermId, TermId)>,
+    osp: BTreeSet<(TermId, TermId, TermId)>,
+}
+
+impl CompactGraph {
+    /// Creates a new graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns all the triples contained by the graph.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            arena: &self.arena,
+            inner: self.spo.iter(),
+        }
+    }
+
+    pub fn triples_for_subject<'a, 'b>(
+        &'a self,
+        subject: impl Into<NamedOrBlankNodeRef<'b>>,
+    ) -> TriplesMatching<'a> {
+        self.triples_matching(Some(subject.into()), None, None)
+    }
+
+    pub fn triples_for_predicate<'a, 'b>(
+        &'a self,
+        predicate: impl Into<NamedNodeRef<'b>>,
+    ) -> TriplesMatching<'a> {
+        self.triples_matching(None, Some(predicate.into()), None)
+    }
+
+    pub fn triples_for_object<'a, 'b>(
+        &'a self,
+        object: impl Into<TermRef<'b>>,
+    ) -> TriplesMatching<'a> {
+        self.triples_matching(None, None, Some(object.into()))
+    }
+
+    /// Retrieves the triples matching a pattern, `None` matching any term.
+    ///
+    /// The pattern is looked up in the index starting with its bound components, only the matching triples are visited.
+    ///
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = CompactGraph::default();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let other = NamedNodeRef::new("http://example.com/other")?;
+    /// graph.insert(TripleRef::new(ex, ex, ex));
+    /// graph.insert(TripleRef::new(ex, other, other));
+    ///
+    /// let results: Vec<_> = graph
+    ///     .triples_matching(None, Some(other), Some(other.into()))
+    ///     .collect();
+    /// assert_eq!(vec![TripleRef::new(ex, other, other)], results);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn triples_matching(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+    ) -> TriplesMatching<'_> {
+        let lookup = |term: Option<TermRef<'_>>| match term {
+            Some(term) => self.arena.get(term).map(Some),
+            None => Some(None),
+        };
+        let (Some(subject), Some(predicate), Some(object)) = (
+            lookup(subject.map(Into::into)),
+            lookup(predicate.map(Into::into)),
+            lookup(object),
+        ) else {
+            // A term is not in the graph
+            return TriplesMatching {
+                arena: &self.arena,
+                inner: None,
+                order: IndexOrder::Spo,
+            };
+        };
+        let (index, order, key) = match (subject, predicate, object) {
+            (Some(s), p, None) | (Some(s), p @ Some(_), Some(_)) => {
+                (&self.spo, IndexOrder::Spo, [Some(s), p, object])
+            }
+            (Some(s), None, Some(o)) => (&self.osp, IndexOrder::Osp, [Some(o), Some(s), None]),
+            (None, Some(p), o) => (&self.pos, IndexOrder::Pos, [Some(p), o, None]),
+            (None, None, Some(o)) => (&self.osp, IndexOrder::Osp, [Some(o), None, None]),
+            (None, None, None) => (&self.spo, IndexOrder::Spo, [None, None, None]),
+        };
+        TriplesMatching {
+            arena: &self.arena,
+            inner: Some(index.range(key_range(key))),
+            order,
+        }
+    }
+
+    /// Checks if the graph contains the given triple.
+    pub fn contains<'a>(&self, triple: impl Into<TripleRef<'a>>) -> bool {
+        let triple = triple.into();
+        let (Some(subject), Some(predicate), Some(object)) = (
+            self.arena.get(triple.subject.into()),
+            self.arena.get(triple.predicate.into()),
+            self.arena.get(triple.object),
+        ) else {
+            return false;
+        };
+        self.spo.contains(&(subject, predicate, object))
+    }
+
+    /// Returns the number of triples in this graph.
+    pub fn len(&self) -> usize {
+        self.spo.len()
+    }
+
+    /// Checks if this graph contains a triple.
+    pub fn is_empty(&self) -> bool {
+        self.spo.is_empty()
+    }
+
+    /// Adds a triple to the graph.
+    ///
+    /// Panics if the graph already contains 2<sup>32</sup> distinct terms.
+    pub fn insert<'a>(&mut self, triple: impl Into<TripleRef<'a>>) -> bool {
+        let triple = triple.into();
+        let subject = self.arena.get_or_insert(triple.subject.into());
+        let predicate = self.arena.get_or_insert(triple.predicate.into());
+        let object = self.arena.get_or_insert(triple.object);
+        if !self.spo.insert((subject, predicate, object)) {
+            return false;
+        }
+        self.pos.insert((predicate, object, subject));
+        self.osp.insert((object, subject, predicate));
+        true
+    }
+
+    /// Removes a concrete triple from the graph.
+    ///
+    /// The triple terms are kept in memory until [`shrink_to_fit`](Self::shrink_to_fit) is called.
+    pub fn remove<'a>(&mut self, triple: impl Into<TripleRef<'a>>) -> bool {
+        let triple = triple.into();
+        let (Some(subject), Some(predicate), Some(object)) = (
+            self.arena.get(triple.subject.into()),
+            self.arena.get(triple.predicate.into()),
+            self.arena.get(triple.object),
+        ) else {
+            return false;
+        };
+        if !self.spo.remove(&(subject, predicate, object)) {
+            return false;
+        }
+        self.pos.remove(&(predicate, object, subject));
+        self.osp.remove(&(object, subject, predicate));
+        true
+    }
+
+    /// Clears the graph.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Frees the memory used by the terms that are not used by any triple anymore.
+    ///
+    /// The graph is rebuilt so it temporarily uses twice the memory.
+    ///
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = CompactGraph::default();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let other = NamedNodeRef::new("http://example.com/other")?;
+    /// graph.insert(TripleRef::new(ex, ex, ex));
+    /// graph.insert(TripleRef::new(ex, ex, other));
+    /// graph.remove(TripleRef::new(ex, ex, other));
+    /// graph.shrink_to_fit();
+    /// assert_eq!(graph.len(), 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let mut graph = Self::new();
+        graph.extend(self.iter());
+        graph.arena.shrink_to_fit();
+        *self = graph;
+    }
+}
+
+impl PartialEq for CompactGraph {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|t| other.contains(t))
+    }
+}
+
+impl Eq for CompactGraph {}
+
+impl<'a> IntoIterator for &'a CompactGraph {
+    type Item = TripleRef<'a>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Triple> for CompactGraph {
+    fn from_iter<I: IntoIterator<Item = Triple>>(iter: I) -> Self {
+        let mut g = Self::new();
+        g.extend(iter);
+        g
+    }
+}
+
+impl<'a, T: Into<TripleRef<'a>>> FromIterator<T> for CompactGraph {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut g = Self::new();
+        g.extend(iter);
+        g
+    }
+}
+
+impl Extend<Triple> for CompactGraph {
+    fn extend<I: IntoIterator<Item = Triple>>(&mut self, iter: I) {
+        for t in iter {
+            self.insert(&t);
+        }
+    }
+}
+
+impl<'a, T: Into<TripleRef<'a>>> Extend<T> for CompactGraph {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for t in iter {
+            self.insert(t);
+        }
+    }
+}
+
+impl From<&Graph> for CompactGraph {
+    fn from(graph: &Graph) -> Self {
+        graph.iter().collect()
+    }
+}
+
+impl From<&CompactGraph> for Graph {
+    fn from(graph: &CompactGraph) -> Self {
+        graph.iter().collect()
+    }
+}
+
+impl fmt::Display for CompactGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for t in self {
+            writeln!(f, "{t} .")?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator returned by [`CompactGraph::iter`].
+pub struct Iter<'a> {
+    arena: &'a TermArena,
+    inner: std::collections::btree_set::Iter<'a, (TermId, TermId, TermId)>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = TripleRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (s, p, o) = self.inner.next()?;
+        Some(self.arena.decode_triple(*s, *p, *o))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+/// Iterator returned by [`CompactGraph::triples_matching`].
+pub struct TriplesMatching<'a> {
+    arena: &'a TermArena,
+    inner: Option<Range<'a, (TermId, TermId, TermId)>>,
+    order: IndexOrder,
+}
+
+impl<'a> Iterator for TriplesMatching<'a> {
+    type Item = TripleRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, b, c) = *self.inner.as_mut()?.next()?;
+        Some(match self.order {
+            IndexOrder::Spo => self.arena.decode_triple(a, b, c),
+            IndexOrder::Pos => self.arena.decode_triple(c, a, b),
+            IndexOrder::Osp => self.arena.decode_triple(b, c, a),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IndexOrder {
+    Spo,
+    Pos,
+    Osp,
+}
+
+/// The range of the index keys starting with the given components, `None` matching any term
+fn key_range(key: [Option<TermId>; 3]) -> RangeInclusive<(TermId, TermId, TermId)> {
+    let [a, b, c] = key.map(|id| id.map_or((TermId::MIN, TermId::MAX), |id| (id, id)));
+    (a.0, b.0, c.0)..=(a.1, b.1, c.1)
+}
+
+type TermId = u32;
+
+/// Stores each distinct term once, the strings are concatenated in a single buffer
+#[derive(Debug, Default, Clone)]
+struct TermArena {
+    hasher: RandomState,
+    /// Identifiers for the term hashes, on collision the next free hash is used
+    id_for_hash: HashMap<u64, TermId, IdentityHasherBuilder>,
+    terms: Vec<ArenaTerm>,
+    strings: String,
+}
+
+#[derive(Debug, Clone)]
+enum ArenaTerm {
+    NamedNode(Span),
+    BlankNode(Span),
+    SimpleLiteral(Span),
+    LanguageTaggedLiteral {
+        value: Span,
+        language: Span,
+    },
+    #[cfg(feature = "rdf-12")]
+    DirectionalLanguageTaggedLiteral {
+        value: Span,
+        language: Span,
+        direction: BaseDirection,
+    },
+    TypedLiteral {
+        value: Span,
+        datatype: TermId,
+    },
+    #[cfg(feature = "rdf-12")]
+    Triple(Box<Triple>),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+impl TermArena {
+    fn get(&self, term: TermRef<'_>) -> Option<TermId> {
+        let mut hash = self.hasher.hash_one(term);
+        loop {
+            let id = *self.id_for_hash.get(&hash)?;
+            if self.decode(id) == term {
+                return Some(id);
+            }
+            hash = hash.wrapping_add(1);
+        }
+    }
+
+    fn get_or_insert(&mut self, term: TermRef<'_>) -> TermId {
+        let mut hash = self.hasher.hash_one(term);
+        while let Some(id) = self.id_for_hash.get(&hash) {
+            if self.decode(*id) == term {
+                return *id;
+            }
+            hash = hash.wrapping_add(1);
+        }
+        let entry = match term {
+            TermRef::NamedNode(node) => ArenaTerm::NamedNode(self.push_str(node.as_str())),
+            TermRef::BlankNode(node) => ArenaTerm::BlankNode(self.push_str(node.as_str())),
+            TermRef::Literal(literal) => {
+                if let Some(language) = literal.language() {
+                    let value = self.push_str(literal.value());
+                    let language = self.push_str(language);
+                    #[cfg(feature = "rdf-12")]
+                    if let Some(direction) = literal.direction() {
+                        return self.push_term(
+                            hash,
+                            ArenaTerm::DirectionalLanguageTaggedLiteral {
+                                value,
+                                language,
+                                direction,
+                            },
+                        );
+                    }
+                    ArenaTerm::LanguageTaggedLiteral { value, language }
+                } else if literal.datatype() == xsd::STRING {
+                    ArenaTerm::SimpleLiteral(self.push_str(literal.value()))
+                } else {
+                    let datatype = self.get_or_insert(literal.datatype().into());
+                    ArenaTerm::TypedLiteral {
+                        value: self.push_str(literal.value()),
+                        datatype,
+                    }
+                }
+            }
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(triple) => ArenaTerm::Triple(Box::new(triple.clone())),
+        };
+        self.push_term(hash, entry)
+    }
+
+    #[expect(clippy::expect_used)]
+    fn push_term(&mut self, mut hash: u64, term: ArenaTerm) -> TermId {
+        // The literal datatype insertion might have taken the slot
+        while self.id_for_hash.contains_key(&hash) {
+            hash = hash.wrapping_add(1);
+        }
+        let id = TermId::try_from(self.terms.len())
+            .expect("A compact graph can contain at most 2^32 distinct terms");
+        self.terms.push(term);
+        self.id_for_hash.insert(hash, id);
+        id
+    }
+
+    fn push_str(&mut self, value: &str) -> Span {
+        let start = self.strings.len();
+        self.strings.push_str(value);
+        Span {
+            start,
+            end: self.strings.len(),
+        }
+    }
+
+    fn str(&self, span: Span) -> &str {
+        &self.strings[span.start..span.end]
+    }
+
+    fn decode(&self, id: TermId) -> TermRef<'_> {
+        match &self.terms[id as usize] {
+            ArenaTerm::NamedNode(iri) => NamedNodeRef::new_unchecked(self.str(*iri)).into(),
+            ArenaTerm::BlankNode(id) => BlankNodeRef::new_unchecked(self.str(*id)).into(),
+            ArenaTerm::SimpleLiteral(value) => {
+                LiteralRef::new_simple_literal(self.str(*value)).into()
+            }
+            ArenaTerm::LanguageTaggedLiteral { value, language } => {
+                LiteralRef::new_language_tagged_literal_unchecked(
+                    self.str(*value),
+                    self.str(*language),
+                )
+                .into()
+            }
+            #[cfg(feature = "rdf-12")]
+            ArenaTerm::DirectionalLanguageTaggedLiteral {
+                value,
+                language,
+                direction,
+            } => LiteralRef::new_directional_language_tagged_literal_unchecked(
+                self.str(*value),
+                self.str(*language),
+                *direction,
+            )
+            .into(),
+            ArenaTerm::TypedLiteral { value, datatype } => {
+                let TermRef::NamedNode(datatype) = self.decode(*datatype) else {
+                    unreachable!("Literal datatypes are always IRIs")
+                };
+                LiteralRef::new_typed_literal(self.str(*value), datatype).into()
+            }
+            #[cfg(feature = "rdf-12")]
+            ArenaTerm::Triple(triple) => TermRef::Triple(triple),
+        }
+    }
+
+    fn decode_triple(&self, subject: TermId, predicate: TermId, object: TermId) -> TripleRef<'_> {
+        let subject: NamedOrBlankNodeRef<'_> = match self.decode(subject) {
+            TermRef::NamedNode(subject) => subject.into(),
+            TermRef::BlankNode(subject) => subject.into(),
+            TermRef::Literal(_) => unreachable!("Triple subjects are never literals"),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(_) => unreachable!("Triple subjects are never triples"),
+        };
+        let TermRef::NamedNode(predicate) = self.decode(predicate) else {
+            unreachable!("Triple predicates are always IRIs")
+        };
+        TripleRef::new(subject, predicate, self.decode(object))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.id_for_hash.shrink_to_fit();
+        self.terms.shrink_to_fit();
+        self.strings.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_triples() -> Vec<Triple> {
+        let ex = |name: &str| NamedNode::new_unchecked(format!("http://example.com/{name}"));
+        vec![
+            Triple::new(ex("s"), ex("p"), ex("o")),
+            Triple::new(ex("s"), ex("p"), Literal::from(1)),
+            Triple::new(ex("s"), ex("q"), Literal::new_simple_literal("foo")),
+            Triple::new(
+                ex("s"),
+                ex("q"),
+                Literal::new_language_tagged_literal_unchecked("foo", "en"),
+            ),
+            Triple::new(BlankNode::default(), ex("p"), ex("s")),
+            Triple::new(BlankNode::new_unchecked("b"), ex("p"), BlankNode::default()),
+        ]
+    }
+
+    #[test]
+    fn test_same_content_as_graph() {
+        let triples = example_triples();
+        let graph = triples.iter().collect::<Graph>();
+        let compact = triples.iter().collect::<CompactGraph>();
+        assert_eq!(compact.len(), triples.len());
+        assert_eq!(Graph::from(&compact), graph);
+        assert_eq!(CompactGraph::from(&graph), compact);
+        for t in &triples {
+            assert!(compact.contains(t));
+        }
+    }
+
+    #[test]
+    fn test_triples_matching() {
+        let triples = example_triples();
+        let graph = triples.iter().collect::<Graph>();
+        let compact = triples.iter().collect::<CompactGraph>();
+        let unknown = NamedNodeRef::new_unchecked("http://example.com/unknown");
+        let mut subjects = triples
+            .iter()
+            .map(|t| Some(t.subject.as_ref()))
+            .collect::<Vec<_>>();
+        subjects.extend([None, Some(unknown.into())]);
+        let mut predicates = triples
+            .iter()
+            .map(|t| Some(t.predicate.as_ref()))
+            .collect::<Vec<_>>();
+        predicates.extend([None, Some(unknown)]);
+        let mut objects = triples
+            .iter()
+            .map(|t| Some(t.object.as_ref()))
+            .collect::<Vec<_>>();
+        objects.extend([None, Some(unknown.into())]);
+        for s in &subjects {
+            for p in &predicates {
+                for o in &objects {
+                    let mut expected = graph.triples_matching(*s, *p, *o).collect::<Vec<_>>();
+                    let mut actual = compact.triples_matching(*s, *p, *o).collect::<Vec<_>>();
+                    expected.sort_by_key(ToString::to_string);
+                    actual.sort_by_key(ToString::to_string);
+                    assert_eq!(expected, actual, "{s:?} {p:?} {o:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_remove_and_shrink() {
+        let triples = example_triples();
+        let mut compact = triples.iter().collect::<CompactGraph>();
+        assert!(compact.remove(&triples[0]));
+        assert!(!compact.remove(&triples[0]));
+        assert!(!compact.contains(&triples[0]));
+        assert_eq!(compact.triples_for_object(&triples[0].object).count(), 0);
+        compact.shrink_to_fit();
+        assert_eq!(compact.len(), triples.len() - 1);
+        assert_eq!(compact.arena.get(triples[0].object.as_ref()), None);
+        for t in &triples[1..] {
+            assert!(compact.contains(t));
+        }
+        assert!(compact.insert(&triples[0]));
+        assert_eq!(compact, triples.iter().collect::<CompactGraph>());
+    }
+}
@@ -30,9 +30,13 @@
 //! See also [`Graph`] if you only care about plain triples.
 
 use crate::interning::*;
+use crate::isomorphism::are_isomorphic;
+use crate::list::list_nodes;
+use crate::vocab::rdf;
 use crate::*;
 #[cfg(feature = "rdfc-10")]
 use sha2::{Digest, Sha256, Sha384};
+use std::collections::btree_set::Range;
 use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
@@ -347,28 +351,98 @@ impl Dataset {
         object: Option<TermRef<'a>>,
         graph_name: Option<GraphNameRef<'a>>,
     ) -> impl Iterator<Item = QuadRef<'a>> + 'a {
-        let iter: Box<dyn Iterator<Item = _>> = if let Some(subject) = subject {
-            Box::new(self.quads_for_subject(subject).filter(move |q| {
-                predicate.as_ref().is_none_or(|p| *p == q.predicate)
-                    && object.as_ref().is_none_or(|o| *o == q.object)
-                    && graph_name.as_ref().is_none_or(|g| *g == q.graph_name)
-            }))
-        } else if let Some(object) = object {
-            Box::new(self.quads_for_object(object).filter(move |q| {
-                predicate.as_ref().is_none_or(|p| *p == q.predicate)
-                    && graph_name.as_ref().is_none_or(|g| *g == q.graph_name)
-            }))
-        } else if let Some(predicate) = predicate {
-            Box::new(
-                self.quads_for_predicate(predicate)
-                    .filter(move |q| graph_name.as_ref().is_none_or(|g| *g == q.graph_name)),
-            )
-        } else if let Some(graph_name) = graph_name {
-            Box::new(self.quads_for_graph_name(graph_name))
-        } else {
-            Box::new(self.iter())
-        };
-        iter
+        self.quads_matching(subject, predicate, object, graph_name)
+    }
+
+    /// Retrieves the quads matching a pattern, `None` matching any term.
+    ///
+    /// The pattern is looked up in the index starting with its bound components, only the matching quads are visited.
+    ///
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let mut dataset = Dataset::default();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let other = NamedNodeRef::new("http://example.com/other")?;
+    /// dataset.insert(QuadRef::new(ex, ex, ex, ex));
+    /// dataset.insert(QuadRef::new(ex, other, ex, GraphNameRef::DefaultGraph));
+    ///
+    /// let results: Vec<_> = dataset
+    ///     .quads_matching(Some(ex.into()), None, Some(ex.into()), Some(ex.into()))
+    ///     .collect();
+    /// assert_eq!(vec![QuadRef::new(ex, ex, ex, ex)], results);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn quads_matching(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+        graph_name: Option<GraphNameRef<'_>>,
+    ) -> QuadsMatching<'_> {
+        QuadsMatching {
+            dataset: self,
+            inner: self.interned_quads_matching(
+                subject.map(|s| {
+                    self.encoded_named_or_blank_node(s)
+                        .unwrap_or_else(InternedNamedOrBlankNode::impossible)
+                }),
+                predicate.map(|p| {
+                    self.encoded_named_node(p)
+                        .unwrap_or_else(InternedNamedNode::impossible)
+                }),
+                object.map(|o| {
+                    self.encoded_term(o)
+                        .unwrap_or_else(InternedTerm::impossible)
+                }),
+                graph_name.map(|g| {
+                    self.encoded_graph_name(g)
+                        .unwrap_or_else(InternedGraphName::impossible)
+                }),
+            ),
+        }
+    }
+
+    /// Picks the index having the bound components as prefix
+    fn interned_quads_matching(
+        &self,
+        subject: Option<InternedNamedOrBlankNode>,
+        predicate: Option<InternedNamedNode>,
+        object: Option<InternedTerm>,
+        graph_name: Option<InternedGraphName>,
+    ) -> QuadsMatchingInner<'_> {
+        match (subject, predicate, object, graph_name) {
+            (None, None, None, None) => {
+                QuadsMatchingInner::Spog(index_range(&self.spog, None, None, None, None))
+            }
+            (Some(s), p, None, None) => {
+                QuadsMatchingInner::Spog(index_range(&self.spog, Some(s), p, None, None))
+            }
+            (Some(s), Some(p), Some(o), g) => {
+                QuadsMatchingInner::Spog(index_range(&self.spog, Some(s), Some(p), Some(o), g))
+            }
+            (Some(s), None, Some(o), None) => {
+                QuadsMatchingInner::Ospg(index_range(&self.ospg, Some(o), Some(s), None, None))
+            }
+            (None, Some(p), o, None) => {
+                QuadsMatchingInner::Posg(index_range(&self.posg, Some(p), o, None, None))
+            }
+            (None, None, Some(o), None) => {
+                QuadsMatchingInner::Ospg(index_range(&self.ospg, Some(o), None, None, None))
+            }
+            (s, None, None, Some(g)) => {
+                QuadsMatchingInner::Gspo(index_range(&self.gspo, Some(g), s, None, None))
+            }
+            (Some(s), Some(p), None, Some(g)) => {
+                QuadsMatchingInner::Gspo(index_range(&self.gspo, Some(g), Some(s), Some(p), None))
+            }
+            (None, Some(p), o, Some(g)) => {
+                QuadsMatchingInner::Gpos(index_range(&self.gpos, Some(g), Some(p), o, None))
+            }
+            (s, None, Some(o), Some(g)) => {
+                QuadsMatchingInner::Gosp(index_range(&self.gosp, Some(g), Some(o), s, None))
+            }
+        }
     }
 
     /// Checks if the dataset contains the given quad
@@ -451,6 +525,39 @@ impl Dataset {
         self.ospg.clear();
     }
 
+    /// Reads the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` in the given graph.
+    ///
+    /// See [`Graph::read_list`].
+    pub fn read_list<'a, 'b>(
+        &self,
+        head: impl Into<NamedOrBlankNodeRef<'a>>,
+        graph_name: impl Into<GraphNameRef<'b>>,
+    ) -> Result<Vec<Term>, ListError> {
+        self.graph(graph_name).read_list(head)
+    }
+
+    /// Inserts the items as a new [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) in the given graph and returns its head.
+    ///
+    /// See [`Graph::insert_list`].
+    pub fn insert_list<'a, 'b>(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<TermRef<'a>>>,
+        graph_name: impl Into<GraphNameRef<'b>>,
+    ) -> NamedOrBlankNode {
+        self.graph_mut(graph_name).insert_list(items)
+    }
+
+    /// Removes the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` from the given graph and returns its items.
+    ///
+    /// See [`Graph::remove_list`].
+    pub fn remove_list<'a, 'b>(
+        &mut self,
+        head: impl Into<NamedOrBlankNodeRef<'a>>,
+        graph_name: impl Into<GraphNameRef<'b>>,
+    ) -> Result<Vec<Term>, ListError> {
+        self.graph_mut(graph_name).remove_list(head)
+    }
+
     fn encode_quad(
         &mut self,
         quad: QuadRef<'_>,
@@ -599,6 +706,166 @@ impl Dataset {
             .collect()
     }
 
+    /// Returns the hash of the [canonical N-Quads](https://www.w3.org/TR/rdf-canon/#canonical-quads) serialization of the dataset
+    /// after its canonicalization with the [RDF Dataset Canonicalization](https://www.w3.org/TR/rdf-canon/) algorithm.
+    ///
+    /// The same hash algorithm is used for the canonicalization and for the hash of the serialization.
+    /// Isomorphic datasets, i.e. datasets only differing by their blank node ids, have the same hash.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::dataset::CanonicalizationHashAlgorithm;
+    /// use oxrdf::*;
+    ///
+    /// let iri = NamedNodeRef::new("http://example.com")?;
+    ///
+    /// let mut dataset1 = Dataset::new();
+    /// dataset1.insert(QuadRef::new(iri, iri, BlankNodeRef::new("a")?, iri));
+    ///
+    /// let mut dataset2 = Dataset::new();
+    /// dataset2.insert(QuadRef::new(iri, iri, BlankNodeRef::new("b")?, iri));
+    ///
+    /// assert_ne!(dataset1, dataset2);
+    /// assert_eq!(
+    ///     dataset1.canonical_hash(CanonicalizationHashAlgorithm::Sha256),
+    ///     dataset2.canonical_hash(CanonicalizationHashAlgorithm::Sha256)
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    ///
+    /// <div class="warning">
+    ///     This implementation's worst-case complexity is exponential with respect to the number of blank nodes in the input dataset.
+    ///     See [the RDFC specification section about it](https://www.w3.org/TR/rdf-canon/#dataset-poisoning).
+    /// </div>
+    #[cfg(feature = "rdfc-10")]
+    pub fn canonical_hash(&self, hash_algorithm: CanonicalizationHashAlgorithm) -> Vec<u8> {
+        let mut dataset = self.clone();
+        dataset.canonicalize(CanonicalizationAlgorithm::Rdfc10 { hash_algorithm });
+        let mut lines = dataset
+            .iter()
+            .map(|quad| format!("{quad} .\n"))
+            .collect::<Vec<_>>();
+        lines.sort_unstable();
+        match hash_algorithm {
+            CanonicalizationHashAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                for line in &lines {
+                    hasher.update(line);
+                }
+                hasher.finalize().to_vec()
+            }
+            CanonicalizationHashAlgorithm::Sha384 => {
+                let mut hasher = Sha384::new();
+                for line in &lines {
+                    hasher.update(line);
+                }
+                hasher.finalize().to_vec()
+            }
+        }
+    }
+
+    /// Replaces all blank nodes, including the ones used as graph names, by [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization)
+    /// of the shape `{base authority}/.well-known/genid/{blank node id}`.
+    ///
+    /// See [`Skolemizer`] for more details and [`de_skolemize`](Self::de_skolemize) for the inverse operation.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let iri = NamedNodeRef::new("http://example.com/p")?;
+    /// let mut dataset = Dataset::new();
+    /// dataset.insert(QuadRef::new(
+    ///     BlankNodeRef::new("b")?,
+    ///     iri,
+    ///     iri,
+    ///     BlankNodeRef::new("g")?,
+    /// ));
+    /// let original = dataset.clone();
+    ///
+    /// dataset.skolemize(&NamedNode::new("http://example.com/")?);
+    /// assert!(dataset.contains(QuadRef::new(
+    ///     NamedNodeRef::new("http://example.com/.well-known/genid/b")?,
+    ///     iri,
+    ///     iri,
+    ///     NamedNodeRef::new("http://example.com/.well-known/genid/g")?,
+    /// )));
+    ///
+    /// dataset.de_skolemize();
+    /// assert!(dataset.is_isomorphic_to(&original));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn skolemize<'a>(&mut self, base: impl Into<NamedNodeRef<'a>>) {
+        let skolemizer = Skolemizer::new(base.into());
+        let new_quads = self
+            .iter()
+            .map(|quad| skolemizer.skolemize_quad(quad))
+            .collect::<Vec<_>>();
+        self.clear();
+        for quad in &new_quads {
+            self.insert(quad);
+        }
+    }
+
+    /// Replaces all [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization) of the shape `.../.well-known/genid/{id}`
+    /// in subject, object and graph name positions by blank nodes.
+    ///
+    /// It is the inverse of [`skolemize`](Self::skolemize). See [`Skolemizer::de_skolemize_named_node`] for more details.
+    pub fn de_skolemize(&mut self) {
+        let new_quads = self
+            .iter()
+            .map(Skolemizer::de_skolemize_quad)
+            .collect::<Vec<_>>();
+        self.clear();
+        for quad in &new_quads {
+            self.insert(quad);
+        }
+    }
+
+    /// Checks if this dataset is [isomorphic](https://www.w3.org/TR/rdf11-concepts/#dfn-dataset-isomorphism) to another one.
+    ///
+    /// Blank nodes might appear in subject, object and graph name positions
+    /// like in the datasets the N3 parser builds from formulas.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let iri = NamedNodeRef::new("http://example.com")?;
+    ///
+    /// let mut dataset1 = Dataset::new();
+    /// let bnode1 = BlankNode::default();
+    /// let g1 = BlankNode::default();
+    /// dataset1.insert(QuadRef::new(iri, iri, &bnode1, &g1));
+    /// dataset1.insert(QuadRef::new(&bnode1, iri, iri, &g1));
+    ///
+    /// let mut dataset2 = Dataset::new();
+    /// let bnode2 = BlankNode::default();
+    /// let g2 = BlankNode::default();
+    /// dataset2.insert(QuadRef::new(iri, iri, &bnode2, &g2));
+    /// dataset2.insert(QuadRef::new(&bnode2, iri, iri, &g2));
+    ///
+    /// assert_ne!(dataset1, dataset2);
+    /// assert!(dataset1.is_isomorphic_to(&dataset2));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    ///
+    /// <div class="warning">This implementation worst-case complexity is exponential with respect to the number of blank nodes.
+    /// Use [`is_isomorphic_to_within`](Self::is_isomorphic_to_within) to bound the work done.</div>
+    pub fn is_isomorphic_to(&self, other: &Self) -> bool {
+        are_isomorphic(self, other, None).unwrap_or(false)
+    }
+
+    /// Checks if this dataset is [isomorphic](https://www.w3.org/TR/rdf11-concepts/#dfn-dataset-isomorphism) to another one
+    /// trying at most `max_steps` blank node assignments.
+    ///
+    /// Returns `None` if the limit has been reached before getting an answer.
+    ///
+    /// See also [`is_isomorphic_to`](Self::is_isomorphic_to).
+    pub fn is_isomorphic_to_within(&self, other: &Self, max_steps: usize) -> Option<bool> {
+        are_isomorphic(self, other, Some(max_steps))
+    }
+
     fn canonicalize_interned_blank_nodes(
         &self,
         algorithm: CanonicalizationAlgorithm,
@@ -1612,29 +1879,34 @@ impl<'a> GraphView<'a> {
         predicate: Option<NamedNodeRef<'a>>,
         object: Option<TermRef<'a>>,
     ) -> impl Iterator<Item = TripleRef<'a>> + 'a {
-        let iter: Box<dyn Iterator<Item = _>> = match (subject, predicate, object) {
-            (Some(subject), Some(predicate), Some(object)) => {
-                let triple = TripleRef::new(subject, predicate, object);
-                Box::new(self.contains(triple).then_some(triple).into_iter())
-            }
-            (Some(subject), Some(predicate), None) => Box::new(
-                self.objects_for_subject_predicate(subject, predicate)
-                    .map(move |object| TripleRef::new(subject, predicate, object)),
-            ),
-            (Some(subject), None, Some(object)) => Box::new(
-                self.predicates_for_subject_object(subject, object)
-                    .map(move |predicate| TripleRef::new(subject, predicate, object)),
-            ),
-            (Some(subject), None, None) => Box::new(self.triples_for_subject(subject)),
-            (None, Some(predicate), Some(object)) => Box::new(
-                self.subjects_for_predicate_object(predicate, object)
-                    .map(move |subject| TripleRef::new(subject, predicate, object)),
+        self.triples_matching(subject, predicate, object)
+    }
+
+    /// Retrieves the triples matching a pattern, `None` matching any term.
+    ///
+    /// See [`Graph::triples_matching`].
+    pub fn triples_matching(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+    ) -> TriplesMatching<'a> {
+        let ds = self.dataset;
+        TriplesMatching {
+            dataset: ds,
+            inner: ds.interned_quads_matching(
+                subject.map(|s| {
+                    ds.encoded_named_or_blank_node(s)
+                        .unwrap_or_else(InternedNamedOrBlankNode::impossible)
+                }),
+                predicate.map(|p| {
+                    ds.encoded_named_node(p)
+                        .unwrap_or_else(InternedNamedNode::impossible)
+                }),
+                object.map(|o| ds.encoded_term(o).unwrap_or_else(InternedTerm::impossible)),
+                Some(self.graph_name),
             ),
-            (None, Some(predicate), None) => Box::new(self.triples_for_predicate(predicate)),
-            (None, None, Some(object)) => Box::new(self.triples_for_object(object)),
-            (None, None, None) => Box::new(self.iter()),
-        };
-        iter
+        }
     }
 
     /// Checks if the graph contains the given triple.
@@ -1661,6 +1933,19 @@ impl<'a> GraphView<'a> {
         self.iter().next().is_none()
     }
 
+    /// Reads the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head`.
+    ///
+    /// See [`Graph::read_list`].
+    pub fn read_list<'b>(
+        &self,
+        head: impl Into<NamedOrBlankNodeRef<'b>>,
+    ) -> Result<Vec<Term>, ListError> {
+        Ok(list_nodes(self, head.into())?
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect())
+    }
+
     fn encoded_triple(&self, triple: TripleRef<'_>) -> Option<InternedTriple> {
         Some(InternedTriple {
             subject: self.dataset.encoded_named_or_blank_node(triple.subject)?,
@@ -1866,6 +2151,59 @@ impl<'a> GraphViewMut<'a> {
     pub fn is_empty(&self) -> bool {
         self.read().is_empty()
     }
+
+    /// Reads the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head`.
+    ///
+    /// See [`Graph::read_list`].
+    pub fn read_list<'b>(
+        &self,
+        head: impl Into<NamedOrBlankNodeRef<'b>>,
+    ) -> Result<Vec<Term>, ListError> {
+        self.read().read_list(head)
+    }
+
+    /// Inserts the items as a new [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) and returns its head.
+    ///
+    /// See [`Graph::insert_list`].
+    pub fn insert_list<'b>(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<TermRef<'b>>>,
+    ) -> NamedOrBlankNode {
+        let items = items.into_iter().map(Into::into).collect::<Vec<_>>();
+        let nodes = items
+            .iter()
+            .map(|_| BlankNode::default())
+            .collect::<Vec<_>>();
+        for (i, (node, item)) in nodes.iter().zip(items).enumerate() {
+            self.insert(TripleRef::new(node, rdf::FIRST, item));
+            let rest = nodes
+                .get(i + 1)
+                .map_or_else(|| rdf::NIL.into(), TermRef::from);
+            self.insert(TripleRef::new(node, rdf::REST, rest));
+        }
+        nodes
+            .into_iter()
+            .next()
+            .map_or_else(|| rdf::NIL.into(), Into::into)
+    }
+
+    /// Removes the `rdf:first` and `rdf:rest` triples of the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` and returns its items.
+    ///
+    /// See [`Graph::remove_list`].
+    pub fn remove_list<'b>(
+        &mut self,
+        head: impl Into<NamedOrBlankNodeRef<'b>>,
+    ) -> Result<Vec<Term>, ListError> {
+        let nodes = list_nodes(&self.read(), head.into())?;
+        for (i, (node, item)) in nodes.iter().enumerate() {
+            self.remove(TripleRef::new(node, rdf::FIRST, item));
+            let rest: TermRef<'_> = nodes
+                .get(i + 1)
+                .map_or_else(|| rdf::NIL.into(), |(next, _)| next.into());
+            self.remove(TripleRef::new(node, rdf::REST, rest));
+        }
+        Ok(nodes.into_iter().map(|(_, item)| item).collect())
+    }
 }
 
 impl Extend<Triple> for GraphViewMut<'_> {
@@ -1924,12 +2262,18 @@ impl<'a> Iterator for Iter<'a> {
             .next()
             .map(|(s, p, o, g)| self.dataset.decode_spog((s, p, o, g)))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl ExactSizeIterator for Iter<'_> {}
+
 /// Iterator returned by [`GraphView::iter`].
 pub struct GraphViewIter<'a> {
     dataset: &'a Dataset,
-    inner: std::collections::btree_set::Range<
+    inner: Range<
         'a,
         (
             InternedGraphName,
@@ -1950,6 +2294,185 @@ impl<'a> Iterator for GraphViewIter<'a> {
     }
 }
 
+/// Iterator returned by [`Dataset::quads_matching`].
+pub struct QuadsMatching<'a> {
+    dataset: &'a Dataset,
+    inner: QuadsMatchingInner<'a>,
+}
+
+impl<'a> Iterator for QuadsMatching<'a> {
+    type Item = QuadRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|q| self.dataset.decode_spog(q))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator returned by [`GraphView::triples_matching`] and [`Graph::triples_matching`].
+pub struct TriplesMatching<'a> {
+    dataset: &'a Dataset,
+    inner: QuadsMatchingInner<'a>,
+}
+
+impl<'a> Iterator for TriplesMatching<'a> {
+    type Item = TripleRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(s, p, o, _)| self.dataset.decode_spo((s, p, o)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A range of one of the dataset indexes
+enum QuadsMatchingInner<'a> {
+    Spog(
+        Range<
+            'a,
+            (
+                InternedNamedOrBlankNode,
+                InternedNamedNode,
+                InternedTerm,
+                InternedGraphName,
+            ),
+        >,
+    ),
+    Posg(
+        Range<
+            'a,
+            (
+                InternedNamedNode,
+                InternedTerm,
+                InternedNamedOrBlankNode,
+                InternedGraphName,
+            ),
+        >,
+    ),
+    Ospg(
+        Range<
+            'a,
+            (
+                InternedTerm,
+                InternedNamedOrBlankNode,
+                InternedNamedNode,
+                InternedGraphName,
+            ),
+        >,
+    ),
+    Gspo(
+        Range<
+            'a,
+            (
+                InternedGraphName,
+                InternedNamedOrBlankNode,
+                InternedNamedNode,
+                InternedTerm,
+            ),
+        >,
+    ),
+    Gpos(
+        Range<
+            'a,
+            (
+                InternedGraphName,
+                InternedNamedNode,
+                InternedTerm,
+                InternedNamedOrBlankNode,
+            ),
+        >,
+    ),
+    Gosp(
+        Range<
+            'a,
+            (
+                InternedGraphName,
+                InternedTerm,
+                InternedNamedOrBlankNode,
+                InternedNamedNode,
+            ),
+        >,
+    ),
+}
+
+impl<'a> QuadsMatchingInner<'a> {
+    fn next(
+        &mut self,
+    ) -> Option<(
+        &'a InternedNamedOrBlankNode,
+        &'a InternedNamedNode,
+        &'a InternedTerm,
+        &'a InternedGraphName,
+    )> {
+        match self {
+            Self::Spog(iter) => iter.next().map(|(s, p, o, g)| (s, p, o, g)),
+            Self::Posg(iter) => iter.next().map(|(p, o, s, g)| (s, p, o, g)),
+            Self::Ospg(iter) => iter.next().map(|(o, s, p, g)| (s, p, o, g)),
+            Self::Gspo(iter) => iter.next().map(|(g, s, p, o)| (s, p, o, g)),
+            Self::Gpos(iter) => iter.next().map(|(g, p, o, s)| (s, p, o, g)),
+            Self::Gosp(iter) => iter.next().map(|(g, o, s, p)| (s, p, o, g)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Spog(iter) => iter.size_hint(),
+            Self::Posg(iter) => iter.size_hint(),
+            Self::Ospg(iter) => iter.size_hint(),
+            Self::Gspo(iter) => iter.size_hint(),
+            Self::Gpos(iter) => iter.size_hint(),
+            Self::Gosp(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// The range of the index containing the quads starting with the given components
+///
+/// The components after the first `None` one are ignored.
+fn index_range<A: IndexComponent, B: IndexComponent, C: IndexComponent, D: IndexComponent>(
+    index: &BTreeSet<(A, B, C, D)>,
+    a: Option<A>,
+    b: Option<B>,
+    c: Option<C>,
+    d: Option<D>,
+) -> Range<'_, (A, B, C, D)> {
+    let Some(a) = a else {
+        return index.range::<(A, B, C, D), _>(..);
+    };
+    let Some(b) = b else {
+        return index.range(
+            (a.clone(), B::min_value(), C::min_value(), D::min_value())
+                ..(
+                    a.successor(),
+                    B::min_value(),
+                    C::min_value(),
+                    D::min_value(),
+                ),
+        );
+    };
+    let Some(c) = c else {
+        return index.range(
+            (a.clone(), b.clone(), C::min_value(), D::min_value())
+                ..(a, b.successor(), C::min_value(), D::min_value()),
+        );
+    };
+    let Some(d) = d else {
+        return index.range(
+            (a.clone(), b.clone(), c.clone(), D::min_value())
+                ..(a, b, c.successor(), D::min_value()),
+        );
+    };
+    let quad = (a, b, c, d);
+    index.range(quad.clone()..=quad)
+}
+
 type QuadsPerBlankNode<'a> = HashMap<
     InternedBlankNode,
     Vec<&'a (
@@ -2041,6 +2564,34 @@ fn generate_permutations<T: Copy>(items: impl IntoIterator<Item = T>) -> Vec<Vec
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_skolemization_round_trip() {
+        let p = NamedNode::new_unchecked("http://example.com/#p");
+        let b1 = BlankNode::default();
+        let b2 = BlankNode::default();
+        let g = BlankNode::default();
+        let mut dataset = Dataset::new();
+        dataset.insert(QuadRef::new(&b1, &p, &b2, &g));
+        dataset.insert(QuadRef::new(&b2, &p, &p, GraphNameRef::DefaultGraph));
+        #[cfg(feature = "rdf-12")]
+        dataset.insert(QuadRef::new(
+            &p,
+            &p,
+            &Term::from(Triple::new(b1.clone(), p.clone(), b2.clone())),
+            &g,
+        ));
+        let original = dataset.clone();
+
+        dataset.skolemize(&NamedNode::new_unchecked("http://example.com/base"));
+        assert!(dataset.iter().all(|quad| {
+            !quad.subject.is_blank_node()
+                && !quad.object.is_blank_node()
+                && !quad.graph_name.is_blank_node()
+        }));
+        dataset.de_skolemize();
+        assert!(dataset.is_isomorphic_to(&original));
+    }
+
     #[test]
     fn test_canon() {
         let p = NamedNode::new_unchecked("http://example.com/#p");
@@ -2073,4 +2624,77 @@ mod tests {
         expected.insert(QuadRef::new(&c14n3, &p, &c14n0, GraphNameRef::DefaultGraph));
         assert_eq!(dataset, expected);
     }
+
+    #[test]
+    fn test_quads_matching() {
+        let a = NamedNode::new_unchecked("http://example.com/a");
+        let b = NamedNode::new_unchecked("http://example.com/b");
+        let c = BlankNode::default();
+        let unknown = NamedNode::new_unchecked("http://example.com/unknown");
+        let mut dataset = Dataset::new();
+        for s in [NamedOrBlankNodeRef::from(&a), (&c).into()] {
+            for p in [&a, &b] {
+                for o in [
+                    TermRef::from(&a),
+                    (&c).into(),
+                    LiteralRef::new_simple_literal("a").into(),
+                ] {
+                    for g in [GraphNameRef::DefaultGraph, (&b).into(), (&c).into()] {
+                        dataset.insert(QuadRef::new(s, p, o, g));
+                    }
+                }
+            }
+        }
+
+        let subjects = [
+            None,
+            Some((&a).into()),
+            Some((&c).into()),
+            Some((&unknown).into()),
+        ];
+        let predicates = [None, Some((&b).into()), Some((&unknown).into())];
+        let objects = [
+            None,
+            Some((&c).into()),
+            Some(LiteralRef::new_simple_literal("a").into()),
+        ];
+        let graph_names = [None, Some(GraphNameRef::DefaultGraph), Some((&c).into())];
+        for subject in subjects {
+            for predicate in predicates {
+                for object in objects {
+                    for graph_name in graph_names {
+                        let expected = dataset
+                            .iter()
+                            .filter(|q| {
+                                subject.is_none_or(|s| s == q.subject)
+                                    && predicate.is_none_or(|p| p == q.predicate)
+                                    && object.is_none_or(|o| o == q.object)
+                                    && graph_name.is_none_or(|g| g == q.graph_name)
+                            })
+                            .collect::<Vec<_>>();
+                        let mut actual = dataset
+                            .quads_matching(subject, predicate, object, graph_name)
+                            .collect::<Vec<_>>();
+                        actual.sort_by_key(|q| dataset.encoded_quad(*q));
+                        assert_eq!(
+                            actual, expected,
+                            "{subject:?} {predicate:?} {object:?} {graph_name:?}"
+                        );
+                        if let Some(graph_name) = graph_name {
+                            let expected = expected
+                                .into_iter()
+                                .map(TripleRef::from)
+                                .collect::<Vec<_>>();
+                            let mut actual = dataset
+                                .graph(graph_name)
+                                .triples_matching(subject, predicate, object)
+                                .collect::<Vec<_>>();
+                            actual.sort_by_key(|t| dataset.encoded_quad(t.in_graph(graph_name)));
+                            assert_eq!(actual, expected);
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
@@ -25,8 +25,11 @@
 //!
 //! See also [`Dataset`] if you want to get support of multiple RDF graphs at the same time.
 
+use crate::dataset::Iter as DatasetIter;
 use crate::dataset::*;
-pub use crate::dataset::{CanonicalizationAlgorithm, CanonicalizationHashAlgorithm};
+pub use crate::dataset::{
+    CanonicalizationAlgorithm, CanonicalizationHashAlgorithm, TriplesMatching,
+};
 use crate::*;
 use std::fmt;
 
@@ -74,8 +77,9 @@ impl Graph {
 
     /// Returns all the triples contained by the graph.
     pub fn iter(&self) -> Iter<'_> {
+        // All the dataset quads are in the default graph
         Iter {
-            inner: self.graph().iter(),
+            inner: self.dataset.iter(),
         }
     }
 
@@ -154,6 +158,34 @@ impl Graph {
             .triples_for_interned_object(self.dataset.encoded_term(object))
     }
 
+    /// Retrieves the triples matching a pattern, `None` matching any term.
+    ///
+    /// The pattern is looked up in the index starting with its bound components, only the matching triples are visited.
+    ///
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = Graph::default();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let other = NamedNodeRef::new("http://example.com/other")?;
+    /// graph.insert(TripleRef::new(ex, ex, ex));
+    /// graph.insert(TripleRef::new(ex, other, other));
+    ///
+    /// let results: Vec<_> = graph
+    ///     .triples_matching(None, Some(other), Some(other.into()))
+    ///     .collect();
+    /// assert_eq!(vec![TripleRef::new(ex, other, other)], results);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn triples_matching(
+        &self,
+        subject: Option<NamedOrBlankNodeRef<'_>>,
+        predicate: Option<NamedNodeRef<'_>>,
+        object: Option<TermRef<'_>>,
+    ) -> TriplesMatching<'_> {
+        self.graph().triples_matching(subject, predicate, object)
+    }
+
     /// Checks if the graph contains the given triple.
     pub fn contains<'a>(&self, triple: impl Into<TripleRef<'a>>) -> bool {
         self.graph().contains(triple)
@@ -184,6 +216,82 @@ impl Graph {
         self.dataset.clear()
     }
 
+    /// Reads the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` by following its `rdf:first` and `rdf:rest` triples.
+    ///
+    /// `rdf:nil` is the empty list.
+    /// Returns an error if the list is malformed:
+    /// if a node has no or multiple `rdf:first` or `rdf:rest` values, if the list is cyclic or if it does not end with `rdf:nil`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::vocab::rdf;
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = Graph::new();
+    /// let head = graph.insert_list(&[Literal::from(1), Literal::from(2)]);
+    /// assert_eq!(
+    ///     graph.read_list(&head)?,
+    ///     [Term::from(Literal::from(1)), Term::from(Literal::from(2))]
+    /// );
+    ///
+    /// // A list node without rdf:rest
+    /// let node = BlankNode::default();
+    /// graph.insert(TripleRef::new(&node, rdf::FIRST, rdf::NIL));
+    /// assert!(graph.read_list(&node).is_err());
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn read_list<'a>(
+        &self,
+        head: impl Into<NamedOrBlankNodeRef<'a>>,
+    ) -> Result<Vec<Term>, ListError> {
+        self.graph().read_list(head)
+    }
+
+    /// Inserts the items as a new [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) made of fresh blank nodes and returns its head.
+    ///
+    /// Returns `rdf:nil` if there are no items.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::vocab::rdf;
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = Graph::new();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let head = graph.insert_list([ex]);
+    /// assert!(graph.contains(TripleRef::new(&head, rdf::FIRST, ex)));
+    /// assert!(graph.contains(TripleRef::new(&head, rdf::REST, rdf::NIL)));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn insert_list<'a>(
+        &mut self,
+        items: impl IntoIterator<Item = impl Into<TermRef<'a>>>,
+    ) -> NamedOrBlankNode {
+        self.graph_mut().insert_list(items)
+    }
+
+    /// Removes the `rdf:first` and `rdf:rest` triples of the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) starting at `head` and returns its items.
+    ///
+    /// Nothing is removed if the list is malformed, see [`read_list`](Self::read_list).
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let mut graph = Graph::new();
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let head = graph.insert_list([ex]);
+    /// assert_eq!(graph.remove_list(&head)?, [Term::from(ex)]);
+    /// assert!(graph.is_empty());
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn remove_list<'a>(
+        &mut self,
+        head: impl Into<NamedOrBlankNodeRef<'a>>,
+    ) -> Result<Vec<Term>, ListError> {
+        self.graph_mut().remove_list(head)
+    }
+
     /// Canonicalizes the dataset by renaming blank nodes.
     ///
     /// Usage example ([Graph isomorphism](https://www.w3.org/TR/rdf11-concepts/#dfn-graph-isomorphism)):
@@ -217,6 +325,44 @@ impl Graph {
     pub fn canonicalize(&mut self, algorithm: CanonicalizationAlgorithm) {
         self.dataset.canonicalize(algorithm)
     }
+
+    /// Checks if this graph is [isomorphic](https://www.w3.org/TR/rdf11-concepts/#dfn-graph-isomorphism) to another one.
+    ///
+    /// Usage example:
+    /// ```
+    /// use oxrdf::*;
+    ///
+    /// let iri = NamedNodeRef::new("http://example.com")?;
+    ///
+    /// let mut graph1 = Graph::new();
+    /// let bnode1 = BlankNode::default();
+    /// graph1.insert(TripleRef::new(iri, iri, &bnode1));
+    /// graph1.insert(TripleRef::new(&bnode1, iri, iri));
+    ///
+    /// let mut graph2 = Graph::new();
+    /// let bnode2 = BlankNode::default();
+    /// graph2.insert(TripleRef::new(iri, iri, &bnode2));
+    /// graph2.insert(TripleRef::new(&bnode2, iri, iri));
+    ///
+    /// assert_ne!(graph1, graph2);
+    /// assert!(graph1.is_isomorphic_to(&graph2));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    ///
+    /// <div class="warning">This implementation worst-case complexity is exponential with respect to the number of blank nodes.
+    /// Use [`is_isomorphic_to_within`](Self::is_isomorphic_to_within) to bound the work done.</div>
+    pub fn is_isomorphic_to(&self, other: &Self) -> bool {
+        self.dataset.is_isomorphic_to(&other.dataset)
+    }
+
+    /// Checks if this graph is [isomorphic](https://www.w3.org/TR/rdf11-concepts/#dfn-graph-isomorphism) to another one
+    /// trying at most `max_steps` blank node assignments.
+    ///
+    /// Returns `None` if the limit has been reached before getting an answer.
+    pub fn is_isomorphic_to_within(&self, other: &Self, max_steps: usize) -> Option<bool> {
+        self.dataset
+            .is_isomorphic_to_within(&other.dataset, max_steps)
+    }
 }
 
 impl PartialEq for Graph {
@@ -272,13 +418,19 @@ impl fmt::Display for Graph {
 
 /// Iterator returned by [`Graph::iter`].
 pub struct Iter<'a> {
-    inner: GraphViewIter<'a>,
+    inner: DatasetIter<'a>,
 }
 
 impl<'a> Iterator for Iter<'a> {
     type Item = TripleRef<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
+        self.inner.next().map(Into::into)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
 }
+
+impl ExactSizeIterator for Iter<'_> {}
@@ -537,8 +537,57 @@ impl InternedTriple {
     }
 }
 
-#[derive(Default, Clone)]
-struct IdentityHasherBuilder;
+/// An interned term that can be a component of the dataset indexes
+pub trait IndexComponent: Ord + Clone {
+    /// The smallest possible value
+    fn min_value() -> Self;
+
+    /// The smallest value greater than this one
+    fn successor(&self) -> Self;
+}
+
+impl IndexComponent for InternedNamedNode {
+    fn min_value() -> Self {
+        Self::first()
+    }
+
+    fn successor(&self) -> Self {
+        Self::next(*self)
+    }
+}
+
+impl IndexComponent for InternedNamedOrBlankNode {
+    fn min_value() -> Self {
+        Self::first()
+    }
+
+    fn successor(&self) -> Self {
+        Self::next(self)
+    }
+}
+
+impl IndexComponent for InternedTerm {
+    fn min_value() -> Self {
+        Self::first()
+    }
+
+    fn successor(&self) -> Self {
+        Self::next(self)
+    }
+}
+
+impl IndexComponent for InternedGraphName {
+    fn min_value() -> Self {
+        Self::first()
+    }
+
+    fn successor(&self) -> Self {
+        Self::next(self)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct IdentityHasherBuilder;
 
 impl BuildHasher for IdentityHasherBuilder {
     type Hasher = IdentityHasher;
@@ -549,7 +598,7 @@ impl BuildHasher for IdentityHasherBuilder {
 }
 
 #[derive(Default)]
-struct IdentityHasher {
+pub struct IdentityHasher {
     value: u64,
 }
 
@@ -0,0 +1,318 @@
+use crate::{
+    GraphName, GraphNameRef, Literal, LiteralRef, NamedNode, NamedNodeRef, NamedOrBlankNode,
+    NamedOrBlankNodeRef, Quad, QuadRef, Term, TermRef,
+};
+#[cfg(feature = "rdf-12")]
+use crate::{Triple, TripleRef};
+use oxiri::Iri;
+
+/// How aggressively IRIs are normalized following [RFC 3986 syntax-based normalization](https://www.rfc-editor.org/rfc/rfc3986#section-6.2.2).
+///
+/// Each policy also applies the normalizations of the previous ones.
+/// The query and the fragment of the IRIs are never modified and IRIs that are not valid are returned as is.
+///
+/// ```
+/// use oxrdf::{IriNormalizationPolicy, NamedNode};
+///
+/// let iri = NamedNode::new("HTTP://Example.ORG/a/../%7e%c3?Q=%7e#F")?;
+/// assert_eq!(
+///     iri.normalized(IriNormalizationPolicy::Case).as_str(),
+///     "http://example.org/a/../%7E%C3?Q=%7e#F"
+/// );
+/// assert_eq!(
+///     iri.normalized(IriNormalizationPolicy::PercentEncoding).as_str(),
+///     "http://example.org/a/../~%C3?Q=%7e#F"
+/// );
+/// assert_eq!(
+///     iri.normalized(IriNormalizationPolicy::Full).as_str(),
+///     "http://example.org/~%C3?Q=%7e#F"
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IriNormalizationPolicy {
+    /// Lowercases the scheme and the host and uppercases the hexadecimal digits of the percent-encoded octets.
+    Case,
+    /// Also decodes the percent-encoded unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_` and `~`).
+    PercentEncoding,
+    /// Also removes the `.` and `..` segments of the paths starting with `/`.
+    Full,
+}
+
+impl IriNormalizationPolicy {
+    /// Normalizes an IRI.
+    pub fn normalize_named_node(self, node: NamedNodeRef<'_>) -> NamedNode {
+        let Ok(iri) = Iri::parse(node.as_str()) else {
+            return node.into_owned();
+        };
+        let mut output = String::with_capacity(node.as_str().len());
+        output.push_str(&iri.scheme().to_ascii_lowercase());
+        output.push(':');
+        if let Some(authority) = iri.authority() {
+            output.push_str("//");
+            let host_and_port = if let Some((user_info, host_and_port)) = authority.rsplit_once('@')
+            {
+                self.push_percent_encoded(user_info, false, &mut output);
+                output.push('@');
+                host_and_port
+            } else {
+                authority
+            };
+            let port_start = if host_and_port.starts_with('[') {
+                host_and_port
+                    .find(']')
+                    .map_or(host_and_port.len(), |i| i + 1)
+            } else {
+                host_and_port.rfind(':').unwrap_or(host_and_port.len())
+            };
+            let (host, port) = host_and_port.split_at(port_start);
+            self.push_percent_encoded(host, true, &mut output);
+            output.push_str(port);
+        }
+        if self == Self::Full && iri.path().starts_with('/') {
+            let mut path = String::with_capacity(iri.path().len());
+            self.push_percent_encoded(iri.path(), false, &mut path);
+            remove_dot_segments(&path, &mut output);
+        } else {
+            self.push_percent_encoded(iri.path(), false, &mut output);
+        }
+        if let Some(query) = iri.query() {
+            output.push('?');
+            output.push_str(query);
+        }
+        if let Some(fragment) = iri.fragment() {
+            output.push('#');
+            output.push_str(fragment);
+        }
+        NamedNode::new_unchecked(output)
+    }
+
+    /// Normalizes the IRIs in a term, including the literal datatypes and the IRIs in triple terms.
+    pub fn normalize_term(self, term: TermRef<'_>) -> Term {
+        match term {
+            TermRef::NamedNode(node) => self.normalize_named_node(node).into(),
+            TermRef::BlankNode(node) => node.into_owned().into(),
+            TermRef::Literal(literal) => self.normalize_literal(literal).into(),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(triple) => self.normalize_triple(triple.as_ref()).into(),
+        }
+    }
+
+    /// Normalizes the IRIs in a triple.
+    #[cfg(feature = "rdf-12")]
+    pub fn normalize_triple(self, triple: TripleRef<'_>) -> Triple {
+        Triple::new(
+            self.normalize_subject(triple.subject),
+            self.normalize_named_node(triple.predicate),
+            self.normalize_term(triple.object),
+        )
+    }
+
+    /// Normalizes the IRIs in a quad, including its graph name.
+    pub fn normalize_quad(self, quad: QuadRef<'_>) -> Quad {
+        Quad::new(
+            self.normalize_subject(quad.subject),
+            self.normalize_named_node(quad.predicate),
+            self.normalize_term(quad.object),
+            match quad.graph_name {
+                GraphNameRef::NamedNode(node) => self.normalize_named_node(node).into(),
+                GraphNameRef::BlankNode(node) => node.into_owned().into(),
+                GraphNameRef::DefaultGraph => GraphName::DefaultGraph,
+            },
+        )
+    }
+
+    fn normalize_subject(self, subject: NamedOrBlankNodeRef<'_>) -> NamedOrBlankNode {
+        match subject {
+            NamedOrBlankNodeRef::NamedNode(node) => self.normalize_named_node(node).into(),
+            NamedOrBlankNodeRef::BlankNode(node) => node.into_owned().into(),
+        }
+    }
+
+    fn normalize_literal(self, literal: LiteralRef<'_>) -> Literal {
+        if literal.language().is_some() {
+            return literal.into_owned();
+        }
+        let datatype = self.normalize_named_node(literal.datatype());
+        if datatype == literal.datatype() {
+            literal.into_owned()
+        } else {
+            Literal::new_typed_literal(literal.value(), datatype)
+        }
+    }
+
+    /// Writes `input` with normalized percent-encodings, `lowercase` lowercases the other ASCII letters
+    fn push_percent_encoded(self, input: &str, lowercase: bool, output: &mut String) {
+        let mut chars = input.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(if lowercase { c.to_ascii_lowercase() } else { c });
+                continue;
+            }
+            // The IRI is valid so % is always followed by two hexadecimal digits
+            let (Some(high), Some(low)) = (chars.next(), chars.next()) else {
+                output.push(c);
+                continue;
+            };
+            let decoded = high
+                .to_digit(16)
+                .zip(low.to_digit(16))
+                .and_then(|(high, low)| u8::try_from(high * 16 + low).ok());
+            if let Some(decoded) = decoded.filter(|decoded| {
+                self != Self::Case
+                    && (decoded.is_ascii_alphanumeric()
+                        || matches!(decoded, b'-' | b'.' | b'_' | b'~'))
+            }) {
+                let decoded = char::from(decoded);
+                output.push(if lowercase {
+                    decoded.to_ascii_lowercase()
+                } else {
+                    decoded
+                });
+            } else {
+                output.push('%');
+                output.push(high.to_ascii_uppercase());
+                output.push(low.to_ascii_uppercase());
+            }
+        }
+    }
+}
+
+/// [RFC 3986 remove_dot_segments](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4) algorithm
+fn remove_dot_segments(mut input: &str, output: &mut String) {
+    let start = output.len();
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../") {
+            input = rest;
+        } else if let Some(rest) = input.strip_prefix("./") {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input == "/.." { "/" } else { &input[3..] };
+            let last_segment_start = output[start..].rfind('/').map_or(start, |i| start + i);
+            output.truncate(last_segment_start);
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let segment_end = input[1..].find('/').map_or(input.len(), |i| i + 1);
+            output.push_str(&input[..segment_end]);
+            input = &input[segment_end..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(iri: &str, policy: IriNormalizationPolicy) -> String {
+        policy
+            .normalize_named_node(NamedNodeRef::new_unchecked(iri))
+            .into_string()
+    }
+
+    #[test]
+    fn case_normalization() {
+        for (input, expected) in [
+            ("HTTP://EXAMPLE.com/A", "http://example.com/A"),
+            ("http://User@Example.com:80/", "http://User@example.com:80/"),
+            ("http://[::ABCD]:80/", "http://[::abcd]:80/"),
+            ("http://ex%c3%a9.com/%c3%a9", "http://ex%C3%A9.com/%C3%A9"),
+            ("http://example.com/%7e", "http://example.com/%7E"),
+            ("URN:Foo:Bar", "urn:Foo:Bar"),
+        ] {
+            assert_eq!(normalize(input, IriNormalizationPolicy::Case), expected);
+        }
+    }
+
+    #[test]
+    fn percent_encoding_normalization() {
+        for (input, expected) in [
+            (
+                "http://example.com/%7e%41%2F%20",
+                "http://example.com/~A%2F%20",
+            ),
+            ("http://%45xample.com/", "http://example.com/"),
+            ("http://example.com/a/%2E%2E/b", "http://example.com/a/../b"),
+        ] {
+            assert_eq!(
+                normalize(input, IriNormalizationPolicy::PercentEncoding),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn dot_segments_removal() {
+        for (input, expected) in [
+            ("http://example.com/a/../b", "http://example.com/b"),
+            ("http://example.com/a/./b/.", "http://example.com/a/b/"),
+            ("http://example.com/a/b/..", "http://example.com/a/"),
+            ("http://example.com/../../a", "http://example.com/a"),
+            ("http://example.com/a/%2E%2E/b", "http://example.com/b"),
+            ("http://example.com/a/..b/c", "http://example.com/a/..b/c"),
+            ("file:/a/../b", "file:/b"),
+            // Not hierarchical
+            ("urn:a/../b", "urn:a/../b"),
+        ] {
+            assert_eq!(normalize(input, IriNormalizationPolicy::Full), expected);
+        }
+    }
+
+    #[test]
+    fn query_and_fragment_are_preserved() {
+        for policy in [
+            IriNormalizationPolicy::Case,
+            IriNormalizationPolicy::PercentEncoding,
+            IriNormalizationPolicy::Full,
+        ] {
+            for iri in [
+                "http://example.com/a?Q=%7e&x=/../y#F%7e/../",
+                "http://example.com?HTTP://EXAMPLE.COM/./",
+                "http://example.com#%2e%2E",
+                "http://example.com/",
+                "http://example.com",
+                "mailto:Foo@Example.com",
+            ] {
+                let normalized = normalize(iri, policy);
+                assert_eq!(normalized, iri, "{policy:?}");
+                NamedNode::new(normalized).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_iris_are_kept() {
+        assert_eq!(
+            normalize("HTTP://Example.com/a b", IriNormalizationPolicy::Full),
+            "HTTP://Example.com/a b"
+        );
+    }
+
+    #[test]
+    fn literal_datatype_normalization() {
+        let policy = IriNormalizationPolicy::Case;
+        assert_eq!(
+            policy.normalize_term(
+                LiteralRef::new_typed_literal(
+                    "1",
+                    NamedNodeRef::new_unchecked("HTTP://www.w3.org/2001/XMLSchema#integer")
+                )
+                .into()
+            ),
+            Literal::from(1).into()
+        );
+        assert_eq!(
+            policy.normalize_term(
+                LiteralRef::new_language_tagged_literal_unchecked("a", "en").into()
+            ),
+            LiteralRef::new_language_tagged_literal_unchecked("a", "en")
+                .into_owned()
+                .into()
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Blank node bijection search used by [`Dataset::is_isomorphic_to`].
+//!
+//! Blank nodes are first partitioned with an iterative color refinement ("hash refinement"):
+//! each blank node color is rehashed with the colors of the quads it appears in until the partition is stable.
+//! A backtracking search then maps each blank node of the first dataset to a blank node with the same color in the second one,
+//! checking after each assignment that the quads whose blank nodes are all mapped exist in the second dataset.
+
+use crate::{
+    BlankNodeRef, Dataset, GraphNameRef, LiteralRef, NamedNodeRef, NamedOrBlankNodeRef, QuadRef,
+    TermRef,
+};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Checks if the two datasets are isomorphic.
+///
+/// Returns `None` if more than `max_steps` candidate blank node assignments have been tried.
+pub fn are_isomorphic(left: &Dataset, right: &Dataset, max_steps: Option<usize>) -> Option<bool> {
+    if left.len() != right.len() {
+        return Some(false);
+    }
+    let left = TokenizedDataset::new(left);
+    let right = TokenizedDataset::new(right);
+    if left.ground_quads != right.ground_quads
+        || left.quads.len() != right.quads.len()
+        || left.quads_per_blank_node.len() != right.quads_per_blank_node.len()
+    {
+        return Some(false);
+    }
+    let (left_colors, right_colors) = refine_colors(&left, &right);
+    let mut candidates_per_color = HashMap::<_, Vec<_>>::new();
+    for (node, color) in right_colors.into_iter().enumerate() {
+        candidates_per_color.entry(color).or_default().push(node);
+    }
+    let mut left_color_counts = HashMap::<_, usize>::new();
+    for color in &left_colors {
+        *left_color_counts.entry(*color).or_default() += 1;
+    }
+    if left_color_counts.len() != candidates_per_color.len()
+        || left_color_counts.iter().any(|(color, count)| {
+            candidates_per_color
+                .get(color)
+                .is_none_or(|candidates| candidates.len() != *count)
+        })
+    {
+        // The two datasets do not have the same number of blank nodes of each color
+        return Some(false);
+    }
+    let candidates = left_colors
+        .iter()
+        .map(|color| {
+            candidates_per_color
+                .get(color)
+                .map_or(&[][..], Vec::as_slice)
+        })
+        .collect::<Vec<_>>();
+    search_bijection(&left, &right, &candidates, max_steps)
+}
+
+/// A quad component where blank nodes are replaced by their index.
+///
+/// Quoted triples are flattened in prefix order after a [`Token::Triple`] marker.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Token<'a> {
+    BlankNode(usize),
+    NamedNode(NamedNodeRef<'a>),
+    Literal(LiteralRef<'a>),
+    DefaultGraph,
+    #[cfg(feature = "rdf-12")]
+    Triple,
+}
+
+struct TokenizedDataset<'a> {
+    /// The quads without blank nodes
+    ground_quads: HashSet<QuadRef<'a>>,
+    /// The quads with blank nodes
+    quads: Vec<Vec<Token<'a>>>,
+    quad_set: HashSet<Vec<Token<'a>>>,
+    /// For each blank node, the indices in `quads` of the quads it appears in
+    quads_per_blank_node: Vec<Vec<usize>>,
+}
+
+impl<'a> TokenizedDataset<'a> {
+    fn new(dataset: &'a Dataset) -> Self {
+        let mut result = Self {
+            ground_quads: HashSet::new(),
+            quads: Vec::new(),
+            quad_set: HashSet::new(),
+            quads_per_blank_node: Vec::new(),
+        };
+        let mut blank_nodes = HashMap::new();
+        for quad in dataset {
+            let mut tokens = Vec::with_capacity(4);
+            let mut tokenizer = Tokenizer {
+                tokens: &mut tokens,
+                blank_nodes: &mut blank_nodes,
+                has_blank_node: false,
+            };
+            tokenizer.push_subject(quad.subject);
+            tokenizer.tokens.push(Token::NamedNode(quad.predicate));
+            tokenizer.push_object(quad.object);
+            tokenizer.push_graph_name(quad.graph_name);
+            if !tokenizer.has_blank_node {
+                result.ground_quads.insert(quad);
+                continue;
+            }
+            result
+                .quads_per_blank_node
+                .resize_with(blank_nodes.len(), Vec::new);
+            let quad_id = result.quads.len();
+            for token in &tokens {
+                if let Token::BlankNode(node) = token {
+                    let node_quads = &mut result.quads_per_blank_node[*node];
+                    if node_quads.last() != Some(&quad_id) {
+                        node_quads.push(quad_id);
+                    }
+                }
+            }
+            result.quad_set.insert(tokens.clone());
+            result.quads.push(tokens);
+        }
+        result
+    }
+
+    /// Computes the next color of each blank node from the colors of its neighbors.
+    fn refine(&self, colors: &[u64]) -> Vec<u64> {
+        self.quads_per_blank_node
+            .iter()
+            .enumerate()
+            .map(|(node, quads)| {
+                let mut quad_hashes = quads
+                    .iter()
+                    .map(|quad| {
+                        let mut hasher = DefaultHasher::new();
+                        for token in &self.quads[*quad] {
+                            match token {
+                                Token::BlankNode(other) if *other == node => {
+                                    0_u8.hash(&mut hasher);
+                                }
+                                Token::BlankNode(other) => {
+                                    1_u8.hash(&mut hasher);
+                                    colors[*other].hash(&mut hasher);
+                                }
+                                _ => {
+                                    2_u8.hash(&mut hasher);
+                                    token.hash(&mut hasher);
+                                }
+                            }
+                        }
+                        hasher.finish()
+                    })
+                    .collect::<Vec<_>>();
+                quad_hashes.sort_unstable();
+                let mut hasher = DefaultHasher::new();
+                colors[node].hash(&mut hasher);
+                quad_hashes.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    /// Checks that all the quads of `node` whose blank nodes are all mapped exist in `other`.
+    fn is_consistent(&self, node: usize, mapping: &[Option<usize>], other: &Self) -> bool {
+        self.quads_per_blank_node[node].iter().all(|quad| {
+            let mut mapped = Vec::with_capacity(self.quads[*quad].len());
+            for token in &self.quads[*quad] {
+                mapped.push(if let Token::BlankNode(node) = token {
+                    let Some(target) = mapping[*node] else {
+                        return true; // Not fully mapped yet
+                    };
+                    Token::BlankNode(target)
+                } else {
+                    *token
+                });
+            }
+            other.quad_set.contains(&mapped)
+        })
+    }
+
+    /// Orders the blank nodes to map the ones with the fewest candidates first
+    /// while staying connected to the already ordered ones, so that inconsistencies are found early.
+    fn search_order(&self, candidates: &[&[usize]]) -> Vec<usize> {
+        let len = self.quads_per_blank_node.len();
+        let mut is_ordered = vec![false; len];
+        let mut order = Vec::with_capacity(len);
+        let mut frontier = BinaryHeap::<Reverse<(usize, usize)>>::new();
+        while order.len() < len {
+            let node = loop {
+                match frontier.pop() {
+                    Some(Reverse((_, node))) if !is_ordered[node] => break Some(node),
+                    Some(_) => (),
+                    None => {
+                        break (0..len)
+                            .filter(|node| !is_ordered[*node])
+                            .min_by_key(|node| candidates[*node].len());
+                    }
+                }
+            };
+            let Some(node) = node else {
+                break;
+            };
+            is_ordered[node] = true;
+            order.push(node);
+            for quad in &self.quads_per_blank_node[node] {
+                for token in &self.quads[*quad] {
+                    if let Token::BlankNode(neighbor) = token {
+                        if !is_ordered[*neighbor] {
+                            frontier.push(Reverse((candidates[*neighbor].len(), *neighbor)));
+                        }
+                    }
+                }
+            }
+        }
+        order
+    }
+}
+
+struct Tokenizer<'a, 'b> {
+    tokens: &'b mut Vec<Token<'a>>,
+    blank_nodes: &'b mut HashMap<BlankNodeRef<'a>, usize>,
+    has_blank_node: bool,
+}
+
+impl<'a> Tokenizer<'a, '_> {
+    fn push_blank_node(&mut self, node: BlankNodeRef<'a>) {
+        let len = self.blank_nodes.len();
+        let id = *self.blank_nodes.entry(node).or_insert(len);
+        self.tokens.push(Token::BlankNode(id));
+        self.has_blank_node = true;
+    }
+
+    fn push_subject(&mut self, subject: NamedOrBlankNodeRef<'a>) {
+        match subject {
+            NamedOrBlankNodeRef::NamedNode(node) => self.tokens.push(Token::NamedNode(node)),
+            NamedOrBlankNodeRef::BlankNode(node) => self.push_blank_node(node),
+        }
+    }
+
+    fn push_object(&mut self, object: TermRef<'a>) {
+        match object {
+            TermRef::NamedNode(node) => self.tokens.push(Token::NamedNode(node)),
+            TermRef::BlankNode(node) => self.push_blank_node(node),
+            TermRef::Literal(literal) => self.tokens.push(Token::Literal(literal)),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(triple) => {
+                self.tokens.push(Token::Triple);
+                self.push_subject(triple.subject.as_ref());
+                self.tokens
+                    .push(Token::NamedNode(triple.predicate.as_ref()));
+                self.push_object(triple.object.as_ref());
+            }
+        }
+    }
+
+    fn push_graph_name(&mut self, graph_name: GraphNameRef<'a>) {
+        match graph_name {
+            GraphNameRef::NamedNode(node) => self.tokens.push(Token::NamedNode(node)),
+            GraphNameRef::BlankNode(node) => self.push_blank_node(node),
+            GraphNameRef::DefaultGraph => self.tokens.push(Token::DefaultGraph),
+        }
+    }
+}
+
+/// Refines the colors of both datasets in lockstep until neither partition gets finer.
+fn refine_colors(
+    left: &TokenizedDataset<'_>,
+    right: &TokenizedDataset<'_>,
+) -> (Vec<u64>, Vec<u64>) {
+    let mut left_colors = vec![0; left.quads_per_blank_node.len()];
+    let mut right_colors = vec![0; right.quads_per_blank_node.len()];
+    let mut class_counts = (1, 1);
+    loop {
+        let new_left_colors = left.refine(&left_colors);
+        let new_right_colors = right.refine(&right_colors);
+        let new_class_counts = (
+            new_left_colors.iter().collect::<HashSet<_>>().len(),
+            new_right_colors.iter().collect::<HashSet<_>>().len(),
+        );
+        left_colors = new_left_colors;
+        right_colors = new_right_colors;
+        if new_class_counts == class_counts {
+            return (left_colors, right_colors);
+        }
+        class_counts = new_class_counts;
+    }
+}
+
+fn search_bijection(
+    left: &TokenizedDataset<'_>,
+    right: &TokenizedDataset<'_>,
+    candidates: &[&[usize]],
+    max_steps: Option<usize>,
+) -> Option<bool> {
+    let order = left.search_order(candidates);
+    let mut mapping = vec![None; order.len()];
+    let mut is_used = vec![false; order.len()];
+    // For each depth, the position in the candidate list of the next candidate to try
+    let mut cursors = vec![0; order.len()];
+    let mut steps = 0;
+    let mut depth = 0;
+    while depth < order.len() {
+        let node = order[depth];
+        if let Some(previous) = mapping[node].take() {
+            is_used[previous] = false;
+        }
+        let mut is_found = false;
+        while let Some(candidate) = candidates[node].get(cursors[depth]).copied() {
+            cursors[depth] += 1;
+            if is_used[candidate] {
+                continue;
+            }
+            steps += 1;
+            if max_steps.is_some_and(|max_steps| steps > max_steps) {
+                return None;
+            }
+            mapping[node] = Some(candidate);
+            if left.is_consistent(node, &mapping, right) {
+                is_used[candidate] = true;
+                is_found = true;
+                break;
+            }
+            mapping[node] = None;
+        }
+        if is_found {
+            depth += 1;
+            if let Some(cursor) = cursors.get_mut(depth) {
+                *cursor = 0;
+            }
+        } else if depth == 0 {
+            return Some(false);
+        } else {
+            depth -= 1;
+        }
+    }
+    Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlankNode, NamedNode, Quad};
+
+    fn ex(name: &str) -> NamedNode {
+        NamedNode::new_unchecked(format!("http://example.com/{name}"))
+    }
+
+    fn dataset(quads: &[(&str, &str, &str, &str)]) -> Dataset {
+        // "_:" prefix for blank nodes, "" for the default graph, any other string is an IRI
+        fn subject(value: &str) -> NamedOrBlankNodeRef<'_> {
+            value.strip_prefix("_:").map_or_else(
+                || NamedNodeRef::new_unchecked(value).into(),
+                |id| BlankNodeRef::new_unchecked(id).into(),
+            )
+        }
+        quads
+            .iter()
+            .map(|(s, p, o, g)| {
+                Quad::new(
+                    subject(s),
+                    NamedNodeRef::new_unchecked(p),
+                    subject(o),
+                    if g.is_empty() {
+                        GraphNameRef::DefaultGraph
+                    } else {
+                        subject(g).into()
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ground_datasets() {
+        let a = dataset(&[("s", "p", "o", ""), ("s", "p", "o", "g")]);
+        assert_eq!(are_isomorphic(&a, &a.clone(), None), Some(true));
+        let b = dataset(&[("s", "p", "o", ""), ("s", "p", "o", "h")]);
+        assert_eq!(are_isomorphic(&a, &b, None), Some(false));
+    }
+
+    #[test]
+    fn test_blank_node_graph_names() {
+        let a = dataset(&[
+            ("_:a", "p", "o", "_:g1"),
+            ("_:g1", "p", "_:g2", ""),
+            ("_:b", "p", "o", "_:g2"),
+            ("_:b", "q", "_:a", "_:g2"),
+        ]);
+        let b = dataset(&[
+            ("_:x", "p", "o", "_:h1"),
+            ("_:h1", "p", "_:h2", ""),
+            ("_:y", "p", "o", "_:h2"),
+            ("_:y", "q", "_:x", "_:h2"),
+        ]);
+        assert_eq!(are_isomorphic(&a, &b, None), Some(true));
+
+        // The same quads but with the two graph names swapped in the default graph link
+        let c = dataset(&[
+            ("_:x", "p", "o", "_:h1"),
+            ("_:h2", "p", "_:h1", ""),
+            ("_:y", "p", "o", "_:h2"),
+            ("_:y", "q", "_:x", "_:h2"),
+        ]);
+        assert_eq!(are_isomorphic(&a, &c, None), Some(false));
+    }
+
+    #[test]
+    fn test_blank_node_merged() {
+        // Same shape except that two blank nodes are the same node
+        let a = dataset(&[("_:a", "p", "o", ""), ("_:b", "p", "o", "_:g")]);
+        let b = dataset(&[("_:a", "p", "o", ""), ("_:a", "p", "o", "_:g")]);
+        assert_eq!(are_isomorphic(&a, &b, None), Some(false));
+    }
+
+    #[test]
+    fn test_regular_graphs() {
+        // A 6-cycle and two 3-cycles are not distinguished by color refinement
+        let cycle = |nodes: &[&'static str]| {
+            (0..nodes.len())
+                .map(|i| (nodes[i], "p", nodes[(i + 1) % nodes.len()], ""))
+                .collect::<Vec<_>>()
+        };
+        let six = dataset(&cycle(&["_:a", "_:b", "_:c", "_:d", "_:e", "_:f"]));
+        let mut two_triangles = cycle(&["_:a", "_:b", "_:c"]);
+        two_triangles.extend(cycle(&["_:d", "_:e", "_:f"]));
+        let two_triangles = dataset(&two_triangles);
+        assert_eq!(are_isomorphic(&six, &two_triangles, None), Some(false));
+        let six_relabeled = dataset(&cycle(&["_:f", "_:a", "_:e", "_:b", "_:d", "_:c"]));
+        assert_eq!(are_isomorphic(&six, &six_relabeled, None), Some(true));
+    }
+
+    #[test]
+    fn test_max_steps() {
+        // Two 60-node cycles versus two 30-node cycles need a lot of backtracking
+        let mut a = Dataset::new();
+        let mut b = Dataset::new();
+        let nodes = (0..60).map(|_| BlankNode::default()).collect::<Vec<_>>();
+        let p = ex("p");
+        for i in 0..60 {
+            a.insert(QuadRef::new(
+                &nodes[i],
+                &p,
+                &nodes[(i + 1) % 60],
+                GraphNameRef::DefaultGraph,
+            ));
+            b.insert(QuadRef::new(
+                &nodes[i],
+                &p,
+                &nodes[i / 30 * 30 + (i + 1) % 30],
+                GraphNameRef::DefaultGraph,
+            ));
+        }
+        assert_eq!(are_isomorphic(&a, &b, Some(10)), None);
+        assert_eq!(are_isomorphic(&a, &a.clone(), Some(10_000)), Some(true));
+    }
+}
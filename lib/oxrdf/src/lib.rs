@@ -5,28 +5,43 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
 mod blank_node;
+pub mod compact_graph;
 pub mod dataset;
 pub mod graph;
 mod interning;
+mod iri_normalization;
+mod isomorphism;
+mod list;
 mod literal;
 mod named_node;
 mod parser;
+#[cfg(feature = "signing")]
+pub mod signing;
+mod skolemization;
 mod triple;
+#[cfg(feature = "oxsdatatypes")]
+mod typed_value;
 mod variable;
 pub mod vocab;
 
 pub use crate::blank_node::{BlankNode, BlankNodeIdParseError, BlankNodeRef};
+pub use crate::compact_graph::CompactGraph;
 pub use crate::dataset::Dataset;
 pub use crate::graph::Graph;
+pub use crate::iri_normalization::IriNormalizationPolicy;
+pub use crate::list::ListError;
 #[cfg(feature = "rdf-12")]
 pub use crate::literal::BaseDirection;
 pub use crate::literal::{Literal, LiteralRef};
 pub use crate::named_node::{NamedNode, NamedNodeRef};
 pub use crate::parser::TermParseError;
+pub use crate::skolemization::Skolemizer;
 pub use crate::triple::{
     GraphName, GraphNameRef, NamedOrBlankNode, NamedOrBlankNodeRef, Quad, QuadRef, Term, TermRef,
     Triple, TripleRef, TryFromTermError,
 };
+#[cfg(feature = "oxsdatatypes")]
+pub use crate::typed_value::{DatatypeError, TypedValue};
 pub use crate::variable::{Variable, VariableNameParseError, VariableRef};
 pub use oxilangtag::LanguageTagParseError;
 pub use oxiri::IriParseError;
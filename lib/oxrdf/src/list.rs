@@ -0,0 +1,195 @@
+use crate::dataset::GraphView;
+use crate::vocab::rdf;
+use crate::{NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Term, TermRef};
+use std::collections::HashSet;
+
+/// An error returned when reading a malformed [RDF collection](https://www.w3.org/TR/rdf11-mt/#rdf-collections),
+/// i.e. a list encoded with `rdf:first` and `rdf:rest`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
+pub enum ListError {
+    /// A list node has no `rdf:first` value.
+    #[error("The list node {0} has no rdf:first value")]
+    MissingFirst(NamedOrBlankNode),
+    /// A list node has no `rdf:rest` value.
+    #[error("The list node {0} has no rdf:rest value")]
+    MissingRest(NamedOrBlankNode),
+    /// A list node has multiple `rdf:first` or `rdf:rest` values.
+    #[error("The list node {0} has multiple rdf:first or rdf:rest values")]
+    Branching(NamedOrBlankNode),
+    /// The list goes back to one of its previous nodes.
+    #[error("The list node {0} is visited twice, the list is cyclic")]
+    Cycle(NamedOrBlankNode),
+    /// A `rdf:rest` value is not a named or blank node.
+    #[error("The rdf:rest value {0} is not a named or blank node")]
+    InvalidRest(Term),
+}
+
+/// Returns the nodes of the list starting at `head` with their `rdf:first` value
+///
+/// `rdf:nil` is the empty list.
+pub(crate) fn list_nodes(
+    graph: &GraphView<'_>,
+    head: NamedOrBlankNodeRef<'_>,
+) -> Result<Vec<(NamedOrBlankNode, Term)>, ListError> {
+    let mut nodes = Vec::new();
+    let mut visited = HashSet::new();
+    let mut node = head.into_owned();
+    while node.as_ref() != NamedOrBlankNodeRef::from(rdf::NIL) {
+        if !visited.insert(node.clone()) {
+            return Err(ListError::Cycle(node));
+        }
+        let Some(first) = single_object(graph, &node, rdf::FIRST)? else {
+            return Err(ListError::MissingFirst(node));
+        };
+        let Some(rest) = single_object(graph, &node, rdf::REST)? else {
+            return Err(ListError::MissingRest(node));
+        };
+        let rest = NamedOrBlankNode::try_from(rest.into_owned())
+            .map_err(|e| ListError::InvalidRest(e.into_term()))?;
+        nodes.push((node, first.into_owned()));
+        node = rest;
+    }
+    Ok(nodes)
+}
+
+/// The only value of the predicate for the list node
+fn single_object<'a>(
+    graph: &GraphView<'a>,
+    node: &NamedOrBlankNode,
+    predicate: NamedNodeRef<'_>,
+) -> Result<Option<TermRef<'a>>, ListError> {
+    let mut objects = graph.objects_for_subject_predicate(node, predicate);
+    let object = objects.next();
+    if objects.next().is_some() {
+        return Err(ListError::Branching(node.clone()));
+    }
+    Ok(object)
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use crate::{BlankNode, Dataset, Graph, GraphNameRef, Literal, NamedNodeRef, TripleRef};
+
+    #[test]
+    fn test_list_round_trip() -> Result<(), ListError> {
+        let ex = NamedNodeRef::new_unchecked("http://example.com");
+        let items = [
+            Term::from(ex),
+            Term::from(Literal::from(1)),
+            Term::from(BlankNode::default()),
+        ];
+        let mut graph = Graph::new();
+        graph.insert(TripleRef::new(ex, ex, ex));
+        let head = graph.insert_list(&items);
+        assert_eq!(graph.len(), 7);
+        assert_eq!(graph.read_list(&head)?, items);
+        assert_eq!(graph.remove_list(&head)?, items);
+        assert_eq!(graph.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_list() -> Result<(), ListError> {
+        let mut graph = Graph::new();
+        let head = graph.insert_list(Vec::<TermRef<'_>>::new());
+        assert_eq!(head, NamedOrBlankNode::from(rdf::NIL));
+        assert!(graph.is_empty());
+        assert!(graph.read_list(rdf::NIL)?.is_empty());
+        assert!(graph.remove_list(rdf::NIL)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dataset_list() -> Result<(), ListError> {
+        let g = NamedNodeRef::new_unchecked("http://example.com/g");
+        let mut dataset = Dataset::new();
+        let head = dataset.insert_list([Literal::from(1).as_ref()], g);
+        assert!(matches!(
+            dataset.read_list(&head, GraphNameRef::DefaultGraph),
+            Err(ListError::MissingFirst(_))
+        ));
+        assert_eq!(dataset.read_list(&head, g)?, [Literal::from(1).into()]);
+        assert_eq!(dataset.remove_list(&head, g)?, [Literal::from(1).into()]);
+        assert!(dataset.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_lists() {
+        let ex = NamedNodeRef::new_unchecked("http://example.com");
+        let n1 = BlankNode::default();
+        let n2 = BlankNode::default();
+
+        // Missing rdf:first
+        let graph = Graph::from_iter([TripleRef::new(&n1, rdf::REST, rdf::NIL)]);
+        assert!(
+            matches!(graph.read_list(&n1), Err(ListError::MissingFirst(n)) if n == n1.clone().into())
+        );
+
+        // Missing rdf:rest
+        let graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::REST, &n2),
+            TripleRef::new(&n2, rdf::FIRST, ex),
+        ]);
+        assert!(
+            matches!(graph.read_list(&n1), Err(ListError::MissingRest(n)) if n == n2.clone().into())
+        );
+
+        // Multiple rdf:first
+        let graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::FIRST, rdf::NIL),
+            TripleRef::new(&n1, rdf::REST, rdf::NIL),
+        ]);
+        assert!(matches!(graph.read_list(&n1), Err(ListError::Branching(_))));
+
+        // Multiple rdf:rest
+        let graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::REST, rdf::NIL),
+            TripleRef::new(&n1, rdf::REST, &n2),
+            TripleRef::new(&n2, rdf::FIRST, ex),
+            TripleRef::new(&n2, rdf::REST, rdf::NIL),
+        ]);
+        assert!(matches!(graph.read_list(&n1), Err(ListError::Branching(_))));
+
+        // Cycle
+        let mut graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::REST, &n2),
+            TripleRef::new(&n2, rdf::FIRST, ex),
+            TripleRef::new(&n2, rdf::REST, &n1),
+        ]);
+        assert!(matches!(graph.read_list(&n1), Err(ListError::Cycle(n)) if n == n1.clone().into()));
+        // Nothing is removed from a malformed list
+        assert!(matches!(graph.remove_list(&n1), Err(ListError::Cycle(_))));
+        assert_eq!(graph.len(), 4);
+
+        // Self loop
+        let graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::REST, &n1),
+        ]);
+        assert!(matches!(graph.read_list(&n1), Err(ListError::Cycle(_))));
+
+        // Literal rdf:rest
+        let literal = Literal::from(1);
+        let graph = Graph::from_iter([
+            TripleRef::new(&n1, rdf::FIRST, ex),
+            TripleRef::new(&n1, rdf::REST, &literal),
+        ]);
+        assert!(
+            matches!(graph.read_list(&n1), Err(ListError::InvalidRest(t)) if t == literal.clone().into())
+        );
+
+        // Not a list at all
+        assert!(matches!(
+            Graph::new().read_list(ex),
+            Err(ListError::MissingFirst(_))
+        ));
+    }
+}
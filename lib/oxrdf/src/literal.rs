@@ -1,4 +1,6 @@
 use crate::named_node::{NamedNode, NamedNodeRef};
+#[cfg(feature = "oxsdatatypes")]
+use crate::typed_value::{DatatypeError, TypedValue, parse_literal};
 use crate::vocab::{rdf, xsd};
 use oxilangtag::{LanguageTag, LanguageTagParseError};
 #[cfg(feature = "oxsdatatypes")]
@@ -177,6 +179,32 @@ impl Literal {
         self.as_ref().datatype()
     }
 
+    /// Parses the literal [lexical form](https://www.w3.org/TR/rdf11-concepts/#dfn-lexical-form) according to its [datatype](https://www.w3.org/TR/rdf11-concepts/#dfn-datatype-iri).
+    ///
+    /// Returns an error if the datatype is recognized but the lexical form is not valid for it
+    /// and [`TypedValue::Other`] if the datatype is not recognized.
+    ///
+    /// ```
+    /// use oxrdf::vocab::xsd;
+    /// use oxrdf::{Literal, TypedValue};
+    ///
+    /// assert!(matches!(
+    ///     Literal::new_typed_literal("true", xsd::BOOLEAN).parsed_value()?,
+    ///     TypedValue::Boolean(value) if bool::from(value)
+    /// ));
+    /// assert!(
+    ///     Literal::new_typed_literal("abc", xsd::INTEGER)
+    ///         .parsed_value()
+    ///         .is_err()
+    /// );
+    /// # Result::<_, oxrdf::DatatypeError>::Ok(())
+    /// ```
+    #[cfg(feature = "oxsdatatypes")]
+    #[inline]
+    pub fn parsed_value(&self) -> Result<TypedValue<'_>, DatatypeError> {
+        self.as_ref().parsed_value()
+    }
+
     /// Checks if this literal could be seen as an RDF 1.0 [plain literal](https://www.w3.org/TR/2004/REC-rdf-concepts-20040210/#dfn-plain-literal).
     ///
     /// It returns true if the literal is a [language-tagged string](https://www.w3.org/TR/rdf11-concepts/#dfn-language-tagged-string)
@@ -519,6 +547,22 @@ impl From<DayTimeDuration> for Literal {
     }
 }
 
+#[cfg(feature = "oxsdatatypes")]
+impl From<HexBinary> for Literal {
+    #[inline]
+    fn from(value: HexBinary) -> Self {
+        Self::new_typed_literal(value.to_string(), xsd::HEX_BINARY)
+    }
+}
+
+#[cfg(feature = "oxsdatatypes")]
+impl From<Base64Binary> for Literal {
+    #[inline]
+    fn from(value: Base64Binary) -> Self {
+        Self::new_typed_literal(value.to_string(), xsd::BASE_64_BINARY)
+    }
+}
+
 /// A borrowed RDF [literal](https://www.w3.org/TR/rdf11-concepts/#dfn-literal).
 ///
 /// The default string formatter is returning an N-Triples, Turtle, and SPARQL compatible representation:
@@ -662,6 +706,15 @@ impl<'a> LiteralRef<'a> {
         }
     }
 
+    /// Parses the literal [lexical form](https://www.w3.org/TR/rdf11-concepts/#dfn-lexical-form) according to its [datatype](https://www.w3.org/TR/rdf11-concepts/#dfn-datatype-iri).
+    ///
+    /// See [`Literal::parsed_value()`].
+    #[cfg(feature = "oxsdatatypes")]
+    #[inline]
+    pub fn parsed_value(self) -> Result<TypedValue<'a>, DatatypeError> {
+        parse_literal(self)
+    }
+
     /// Checks if this literal could be seen as an RDF 1.0 [plain literal](https://www.w3.org/TR/2004/REC-rdf-concepts-20040210/#dfn-plain-literal).
     ///
     /// It returns true if the literal is a [language-tagged string](https://www.w3.org/TR/rdf11-concepts/#dfn-language-tagged-string)
@@ -804,7 +857,7 @@ pub fn print_quoted_str(string: &str, f: &mut impl Write) -> fmt::Result {
 
 /// A [directional language-tagged string](https://www.w3.org/TR/rdf12-concepts/#dfn-dir-lang-string) [base-direction](https://www.w3.org/TR/rdf12-concepts/#dfn-base-direction)
 #[cfg(feature = "rdf-12")]
-#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BaseDirection {
     /// the initial text direction is set to left-to-right
@@ -1,3 +1,4 @@
+use crate::IriNormalizationPolicy;
 use oxiri::{Iri, IriParseError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
@@ -56,6 +57,23 @@ impl NamedNode {
     pub fn as_ref(&self) -> NamedNodeRef<'_> {
         NamedNodeRef::new_unchecked(&self.iri)
     }
+
+    /// Returns the IRI normalized according to the given policy.
+    ///
+    /// ```
+    /// use oxrdf::{IriNormalizationPolicy, NamedNode};
+    ///
+    /// assert_eq!(
+    ///     NamedNode::new("HTTP://Example.org/a/../b")?.normalized(IriNormalizationPolicy::Full),
+    ///     NamedNode::new("http://example.org/b")?
+    /// );
+    /// # Result::<_,oxrdf::IriParseError>::Ok(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn normalized(&self, policy: IriNormalizationPolicy) -> Self {
+        self.as_ref().normalized(policy)
+    }
 }
 
 impl fmt::Display for NamedNode {
@@ -140,6 +158,15 @@ impl<'a> NamedNodeRef<'a> {
     pub fn into_owned(self) -> NamedNode {
         NamedNode::new_unchecked(self.iri)
     }
+
+    /// Returns the IRI normalized according to the given policy.
+    ///
+    /// See [`IriNormalizationPolicy`] for the available normalizations.
+    #[inline]
+    #[must_use]
+    pub fn normalized(self, policy: IriNormalizationPolicy) -> NamedNode {
+        policy.normalize_named_node(self)
+    }
 }
 
 impl fmt::Display for NamedNodeRef<'_> {
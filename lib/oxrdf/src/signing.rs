@@ -0,0 +1,518 @@
+//! Signature and verification of [RDF datasets](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-dataset)
+//! based on their [canonical hash](Dataset::canonical_hash).
+//!
+//! The signed message is the canonical hash of the dataset, so that the signature does not depend
+//! on the serialization or on the blank node ids of the dataset.
+//! The quads in the [`SIGNATURE_GRAPH`] are ignored when computing the hash,
+//! allowing to [embed](DatasetSignature::embed_into) the signature into the dataset it signs.
+//!
+//! Signature algorithms are pluggable using the [`Signer`] and [`Verifier`] traits.
+//! [Ed25519](https://www.rfc-editor.org/rfc/rfc8032) is provided by [`Ed25519Signer`] and [`Ed25519Verifier`].
+//!
+//! Usage example:
+//! ```
+//! use oxrdf::dataset::CanonicalizationHashAlgorithm;
+//! use oxrdf::signing::{Ed25519Signer, sign_embedded, verify_embedded};
+//! use oxrdf::*;
+//!
+//! let ex = NamedNodeRef::new("http://example.com")?;
+//! let mut dataset = Dataset::new();
+//! dataset.insert(QuadRef::new(ex, ex, ex, GraphNameRef::DefaultGraph));
+//!
+//! let signer = Ed25519Signer::from_seed(&[1; 32])?;
+//! sign_embedded(&mut dataset, &signer, CanonicalizationHashAlgorithm::Sha256)?;
+//! verify_embedded(&dataset, &signer.verifier())?;
+//!
+//! // Any change to the dataset invalidates the signature
+//! dataset.insert(QuadRef::new(ex, ex, LiteralRef::new_simple_literal("foo"), GraphNameRef::DefaultGraph));
+//! assert!(verify_embedded(&dataset, &signer.verifier()).is_err());
+//! # Result::<_, Box<dyn std::error::Error>>::Ok(())
+//! ```
+use crate::dataset::CanonicalizationHashAlgorithm;
+use crate::vocab::{rdf, xsd};
+use crate::{BlankNode, Dataset, LiteralRef, NamedNode, NamedNodeRef, QuadRef, TermRef};
+use ring::signature::{ED25519, Ed25519KeyPair, KeyPair, UnparsedPublicKey};
+use std::error::Error;
+
+/// The graph in which [`DatasetSignature::embed_into`] writes the signature.
+///
+/// Its content is ignored when computing the signed hash of a dataset.
+pub const SIGNATURE_GRAPH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://oxigraph.org/ns/signature#graph");
+/// The class of the signature descriptions written by [`DatasetSignature::embed_into`].
+pub const SIGNATURE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://oxigraph.org/ns/signature#Signature");
+/// Relates a signature description to the IRI of its [signature algorithm](Signer::algorithm).
+pub const ALGORITHM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://oxigraph.org/ns/signature#algorithm");
+/// Relates a signature description to the IRI of the hash algorithm used to compute the canonical hash.
+pub const HASH_ALGORITHM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://oxigraph.org/ns/signature#hashAlgorithm");
+/// Relates a signature description to the signature bytes encoded as a `xsd:hexBinary` literal.
+pub const SIGNATURE_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("https://oxigraph.org/ns/signature#signatureValue");
+
+/// The [Ed25519 algorithm IRI](https://www.rfc-editor.org/rfc/rfc9231#section-2.3.5).
+pub const ED25519_ALGORITHM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2021/04/xmldsig-more#eddsa-ed25519");
+const SHA256_ALGORITHM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2001/04/xmlenc#sha256");
+const SHA384_ALGORITHM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2001/04/xmldsig-more#sha384");
+/// DER prefix of a SubjectPublicKeyInfo structure containing an Ed25519 public key.
+const ED25519_SPKI_PREFIX: &[u8] = &[
+    0x30, 0x2A, 0x30, 0x05, 0x06, 0x03, 0x2B, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+/// A signature algorithm with its private key.
+pub trait Signer {
+    /// The IRI identifying the signature algorithm.
+    fn algorithm(&self) -> NamedNodeRef<'_>;
+
+    /// Signs the given message.
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, SignatureError>;
+}
+
+/// A signature algorithm with its public key.
+pub trait Verifier {
+    /// The IRI identifying the signature algorithm.
+    fn algorithm(&self) -> NamedNodeRef<'_>;
+
+    /// Checks that `signature` is a valid signature of `message`.
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), SignatureError>;
+}
+
+/// A [`Signer`] for [Ed25519](https://www.rfc-editor.org/rfc/rfc8032).
+///
+/// ```
+/// use oxrdf::signing::{Ed25519Signer, Signer, Verifier};
+///
+/// let signer = Ed25519Signer::from_seed(&[1; 32])?;
+/// let signature = signer.sign(b"foo")?;
+/// signer.verifier().verify(b"foo", &signature)?;
+/// assert!(signer.verifier().verify(b"bar", &signature).is_err());
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Debug)]
+pub struct Ed25519Signer {
+    key_pair: Ed25519KeyPair,
+}
+
+impl Ed25519Signer {
+    /// Builds a signer from a private key encoded as a DER PKCS#8 document (v1 or v2),
+    /// e.g. the output of `openssl genpkey -algorithm ed25519 -outform DER`.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, SignatureError> {
+        Ok(Self {
+            key_pair: Ed25519KeyPair::from_pkcs8_maybe_unchecked(pkcs8)
+                .map_err(|e| SignatureError::InvalidKey(e.to_string()))?,
+        })
+    }
+
+    /// Builds a signer from the 32 bytes seed of a private key.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, SignatureError> {
+        Ok(Self {
+            key_pair: Ed25519KeyPair::from_seed_unchecked(seed)
+                .map_err(|e| SignatureError::InvalidKey(e.to_string()))?,
+        })
+    }
+
+    /// The 32 bytes public key.
+    pub fn public_key(&self) -> &[u8] {
+        self.key_pair.public_key().as_ref()
+    }
+
+    /// Builds the verifier for the signatures produced by this signer.
+    pub fn verifier(&self) -> Ed25519Verifier {
+        Ed25519Verifier {
+            public_key: self.public_key().to_vec(),
+        }
+    }
+}
+
+impl Signer for Ed25519Signer {
+    fn algorithm(&self) -> NamedNodeRef<'_> {
+        ED25519_ALGORITHM
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, SignatureError> {
+        Ok(self.key_pair.sign(message).as_ref().to_vec())
+    }
+}
+
+/// A [`Verifier`] for [Ed25519](https://www.rfc-editor.org/rfc/rfc8032).
+///
+/// See [`Ed25519Signer`] for an example.
+#[derive(Debug, Clone)]
+pub struct Ed25519Verifier {
+    public_key: Vec<u8>,
+}
+
+impl Ed25519Verifier {
+    /// Builds a verifier from a 32 bytes public key or from a DER SubjectPublicKeyInfo structure,
+    /// e.g. the output of `openssl pkey -pubout -outform DER`.
+    pub fn from_public_key(public_key: &[u8]) -> Result<Self, SignatureError> {
+        let public_key = public_key
+            .strip_prefix(ED25519_SPKI_PREFIX)
+            .unwrap_or(public_key);
+        if public_key.len() != 32 {
+            return Err(SignatureError::InvalidKey(format!(
+                "Ed25519 public keys must be 32 bytes long, found {} bytes",
+                public_key.len()
+            )));
+        }
+        Ok(Self {
+            public_key: public_key.to_vec(),
+        })
+    }
+}
+
+impl Verifier for Ed25519Verifier {
+    fn algorithm(&self) -> NamedNodeRef<'_> {
+        ED25519_ALGORITHM
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), SignatureError> {
+        UnparsedPublicKey::new(&ED25519, &self.public_key)
+            .verify(message, signature)
+            .map_err(|_| SignatureError::Mismatch)
+    }
+}
+
+/// The signature of a dataset.
+///
+/// It is built using [`sign`] and checked using [`verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetSignature {
+    /// The IRI of the [signature algorithm](Signer::algorithm).
+    pub algorithm: NamedNode,
+    /// The hash algorithm used to compute the [canonical hash](Dataset::canonical_hash) of the dataset.
+    pub hash_algorithm: CanonicalizationHashAlgorithm,
+    /// The signature bytes.
+    pub value: Vec<u8>,
+}
+
+impl DatasetSignature {
+    /// Writes the signature into the [`SIGNATURE_GRAPH`] of `dataset`, replacing any existing content of this graph.
+    ///
+    /// The signature is described by a blank node:
+    /// ```turtle
+    /// GRAPH <https://oxigraph.org/ns/signature#graph> {
+    ///     [] a sig:Signature ;
+    ///         sig:algorithm <http://www.w3.org/2021/04/xmldsig-more#eddsa-ed25519> ;
+    ///         sig:hashAlgorithm <http://www.w3.org/2001/04/xmlenc#sha256> ;
+    ///         sig:signatureValue "..."^^xsd:hexBinary .
+    /// }
+    /// ```
+    /// with the `sig:` prefix bound to `https://oxigraph.org/ns/signature#`.
+    pub fn embed_into(&self, dataset: &mut Dataset) {
+        remove_signature_graph(dataset);
+        let node = BlankNode::default();
+        let value = hex::encode(&self.value);
+        for (predicate, object) in [
+            (rdf::TYPE, TermRef::from(SIGNATURE)),
+            (ALGORITHM, self.algorithm.as_ref().into()),
+            (
+                HASH_ALGORITHM,
+                hash_algorithm_iri(self.hash_algorithm).into(),
+            ),
+            (
+                SIGNATURE_VALUE,
+                LiteralRef::new_typed_literal(&value, xsd::HEX_BINARY).into(),
+            ),
+        ] {
+            dataset.insert(QuadRef::new(&node, predicate, object, SIGNATURE_GRAPH));
+        }
+    }
+
+    /// Reads the signature written by [`embed_into`](Self::embed_into) from the [`SIGNATURE_GRAPH`] of `dataset`.
+    pub fn extract_from(dataset: &Dataset) -> Result<Self, SignatureError> {
+        let graph = dataset.graph(SIGNATURE_GRAPH);
+        let mut nodes = graph.subjects_for_predicate_object(rdf::TYPE, SIGNATURE);
+        let node = nodes.next().ok_or(SignatureError::Missing)?;
+        if nodes.next().is_some() {
+            return Err(SignatureError::InvalidDescription(
+                "multiple signatures are present".into(),
+            ));
+        }
+        let object = |predicate: NamedNodeRef<'_>| {
+            let mut objects = graph.objects_for_subject_predicate(node, predicate);
+            let object = objects.next().ok_or_else(|| {
+                SignatureError::InvalidDescription(format!("no {predicate} value"))
+            })?;
+            if objects.next().is_some() {
+                return Err(SignatureError::InvalidDescription(format!(
+                    "multiple {predicate} values"
+                )));
+            }
+            Ok(object)
+        };
+        let TermRef::NamedNode(algorithm) = object(ALGORITHM)? else {
+            return Err(SignatureError::InvalidDescription(format!(
+                "the {ALGORITHM} value must be an IRI"
+            )));
+        };
+        let hash_algorithm = match object(HASH_ALGORITHM)? {
+            TermRef::NamedNode(iri) if iri == SHA256_ALGORITHM => {
+                CanonicalizationHashAlgorithm::Sha256
+            }
+            TermRef::NamedNode(iri) if iri == SHA384_ALGORITHM => {
+                CanonicalizationHashAlgorithm::Sha384
+            }
+            hash_algorithm => {
+                return Err(SignatureError::InvalidDescription(format!(
+                    "unsupported hash algorithm {hash_algorithm}"
+                )));
+            }
+        };
+        let value = match object(SIGNATURE_VALUE)? {
+            TermRef::Literal(literal) if literal.datatype() == xsd::HEX_BINARY => {
+                hex::decode(literal.value()).map_err(|e| {
+                    SignatureError::InvalidDescription(format!(
+                        "invalid {SIGNATURE_VALUE} value: {e}"
+                    ))
+                })?
+            }
+            _ => {
+                return Err(SignatureError::InvalidDescription(format!(
+                    "the {SIGNATURE_VALUE} value must be a xsd:hexBinary literal"
+                )));
+            }
+        };
+        Ok(Self {
+            algorithm: algorithm.into_owned(),
+            hash_algorithm,
+            value,
+        })
+    }
+}
+
+/// Signs the [canonical hash](Dataset::canonical_hash) of `dataset`, ignoring the content of the [`SIGNATURE_GRAPH`].
+pub fn sign(
+    dataset: &Dataset,
+    signer: &(impl Signer + ?Sized),
+    hash_algorithm: CanonicalizationHashAlgorithm,
+) -> Result<DatasetSignature, SignatureError> {
+    Ok(DatasetSignature {
+        algorithm: signer.algorithm().into_owned(),
+        hash_algorithm,
+        value: signer.sign(&signed_hash(dataset, hash_algorithm))?,
+    })
+}
+
+/// Checks that `signature` is a valid signature of `dataset`, ignoring the content of the [`SIGNATURE_GRAPH`].
+pub fn verify(
+    dataset: &Dataset,
+    signature: &DatasetSignature,
+    verifier: &(impl Verifier + ?Sized),
+) -> Result<(), SignatureError> {
+    if signature.algorithm != verifier.algorithm() {
+        return Err(SignatureError::UnexpectedAlgorithm {
+            expected: verifier.algorithm().into_owned(),
+            actual: signature.algorithm.clone(),
+        });
+    }
+    verifier.verify(
+        &signed_hash(dataset, signature.hash_algorithm),
+        &signature.value,
+    )
+}
+
+/// Signs `dataset` using [`sign`] and [embeds](DatasetSignature::embed_into) the signature into it.
+pub fn sign_embedded(
+    dataset: &mut Dataset,
+    signer: &(impl Signer + ?Sized),
+    hash_algorithm: CanonicalizationHashAlgorithm,
+) -> Result<(), SignatureError> {
+    sign(dataset, signer, hash_algorithm)?.embed_into(dataset);
+    Ok(())
+}
+
+/// [Extracts](DatasetSignature::extract_from) the signature embedded into `dataset` and [verifies](verify) it.
+pub fn verify_embedded(
+    dataset: &Dataset,
+    verifier: &(impl Verifier + ?Sized),
+) -> Result<(), SignatureError> {
+    verify(dataset, &DatasetSignature::extract_from(dataset)?, verifier)
+}
+
+fn signed_hash(dataset: &Dataset, hash_algorithm: CanonicalizationHashAlgorithm) -> Vec<u8> {
+    if dataset.graph(SIGNATURE_GRAPH).is_empty() {
+        return dataset.canonical_hash(hash_algorithm);
+    }
+    let mut dataset = dataset.clone();
+    remove_signature_graph(&mut dataset);
+    dataset.canonical_hash(hash_algorithm)
+}
+
+fn remove_signature_graph(dataset: &mut Dataset) {
+    let quads = dataset
+        .quads_for_graph_name(SIGNATURE_GRAPH)
+        .map(QuadRef::into_owned)
+        .collect::<Vec<_>>();
+    for quad in &quads {
+        dataset.remove(quad);
+    }
+}
+
+fn hash_algorithm_iri(hash_algorithm: CanonicalizationHashAlgorithm) -> NamedNodeRef<'static> {
+    match hash_algorithm {
+        CanonicalizationHashAlgorithm::Sha256 => SHA256_ALGORITHM,
+        CanonicalizationHashAlgorithm::Sha384 => SHA384_ALGORITHM,
+    }
+}
+
+/// An error raised while signing or verifying a dataset.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SignatureError {
+    /// The signature does not match the dataset.
+    #[error("The signature does not match the dataset")]
+    Mismatch,
+    /// The signature has not been produced by the algorithm of the verifier.
+    #[error("The signature uses the algorithm {actual} but {expected} is expected")]
+    UnexpectedAlgorithm {
+        expected: NamedNode,
+        actual: NamedNode,
+    },
+    /// The key is invalid.
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+    /// No signature is embedded into the dataset.
+    #[error("The dataset does not contain a signature in the graph {SIGNATURE_GRAPH}")]
+    Missing,
+    /// The signature embedded into the dataset is malformed.
+    #[error("Invalid signature description: {0}")]
+    InvalidDescription(String),
+    /// An error raised by a custom [`Signer`] or [`Verifier`].
+    #[error(transparent)]
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GraphNameRef, Literal};
+
+    fn example_dataset(bnode: &BlankNode) -> Dataset {
+        let ex = NamedNodeRef::new_unchecked("http://example.com/p");
+        let mut dataset = Dataset::new();
+        dataset.insert(QuadRef::new(
+            bnode,
+            ex,
+            LiteralRef::new_simple_literal("foo"),
+            GraphNameRef::DefaultGraph,
+        ));
+        dataset.insert(QuadRef::new(ex, ex, bnode, ex));
+        dataset
+    }
+
+    #[test]
+    fn test_signature_is_invariant_to_blank_node_relabeling() {
+        let signer = Ed25519Signer::from_seed(&[7; 32]).unwrap();
+        for hash_algorithm in [
+            CanonicalizationHashAlgorithm::Sha256,
+            CanonicalizationHashAlgorithm::Sha384,
+        ] {
+            let dataset = example_dataset(&BlankNode::new_unchecked("a"));
+            let relabeled = example_dataset(&BlankNode::new_unchecked("b"));
+            assert_ne!(dataset, relabeled);
+            assert_eq!(
+                dataset.canonical_hash(hash_algorithm),
+                relabeled.canonical_hash(hash_algorithm)
+            );
+            let signature = sign(&dataset, &signer, hash_algorithm).unwrap();
+            verify(&relabeled, &signature, &signer.verifier()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_signature_detects_mutations() {
+        let signer = Ed25519Signer::from_seed(&[7; 32]).unwrap();
+        let bnode = BlankNode::new_unchecked("a");
+        let dataset = example_dataset(&bnode);
+        let signature = sign(&dataset, &signer, CanonicalizationHashAlgorithm::Sha256).unwrap();
+
+        let mut changed_literal = Dataset::new();
+        for quad in &dataset {
+            let mut quad = quad.into_owned();
+            if quad.object == Literal::new_simple_literal("foo").into() {
+                quad.object = Literal::new_simple_literal("fop").into();
+            }
+            changed_literal.insert(&quad);
+        }
+        assert!(matches!(
+            verify(&changed_literal, &signature, &signer.verifier()),
+            Err(SignatureError::Mismatch)
+        ));
+
+        let mut removed_quad = dataset.clone();
+        removed_quad.remove(QuadRef::new(
+            NamedNodeRef::new_unchecked("http://example.com/p"),
+            NamedNodeRef::new_unchecked("http://example.com/p"),
+            &bnode,
+            NamedNodeRef::new_unchecked("http://example.com/p"),
+        ));
+        assert!(matches!(
+            verify(&removed_quad, &signature, &signer.verifier()),
+            Err(SignatureError::Mismatch)
+        ));
+
+        let other_signer = Ed25519Signer::from_seed(&[8; 32]).unwrap();
+        assert!(matches!(
+            verify(&dataset, &signature, &other_signer.verifier()),
+            Err(SignatureError::Mismatch)
+        ));
+    }
+
+    #[test]
+    fn test_embedded_signature_round_trip() {
+        let signer = Ed25519Signer::from_seed(&[7; 32]).unwrap();
+        let mut dataset = example_dataset(&BlankNode::default());
+        sign_embedded(&mut dataset, &signer, CanonicalizationHashAlgorithm::Sha384).unwrap();
+        assert_eq!(dataset.len(), 6);
+        verify_embedded(&dataset, &signer.verifier()).unwrap();
+        let signature = DatasetSignature::extract_from(&dataset).unwrap();
+        assert_eq!(signature.algorithm, ED25519_ALGORITHM);
+        assert_eq!(
+            signature.hash_algorithm,
+            CanonicalizationHashAlgorithm::Sha384
+        );
+
+        // Signing again replaces the previous signature
+        sign_embedded(&mut dataset, &signer, CanonicalizationHashAlgorithm::Sha256).unwrap();
+        assert_eq!(dataset.len(), 6);
+        verify_embedded(&dataset, &signer.verifier()).unwrap();
+
+        dataset.insert(QuadRef::new(
+            NamedNodeRef::new_unchecked("http://example.com/s"),
+            NamedNodeRef::new_unchecked("http://example.com/p"),
+            NamedNodeRef::new_unchecked("http://example.com/o"),
+            GraphNameRef::DefaultGraph,
+        ));
+        assert!(matches!(
+            verify_embedded(&dataset, &signer.verifier()),
+            Err(SignatureError::Mismatch)
+        ));
+
+        assert!(matches!(
+            verify_embedded(&example_dataset(&BlankNode::default()), &signer.verifier()),
+            Err(SignatureError::Missing)
+        ));
+    }
+
+    #[test]
+    fn test_verifier_from_public_key() {
+        let signer = Ed25519Signer::from_seed(&[7; 32]).unwrap();
+        let signature = signer.sign(b"foo").unwrap();
+        Ed25519Verifier::from_public_key(signer.public_key())
+            .unwrap()
+            .verify(b"foo", &signature)
+            .unwrap();
+        let spki = [ED25519_SPKI_PREFIX, signer.public_key()].concat();
+        Ed25519Verifier::from_public_key(&spki)
+            .unwrap()
+            .verify(b"foo", &signature)
+            .unwrap();
+        Ed25519Verifier::from_public_key(b"foo").unwrap_err();
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    BlankNode, BlankNodeRef, GraphName, GraphNameRef, NamedNode, NamedNodeRef, NamedOrBlankNode,
+    NamedOrBlankNodeRef, Quad, QuadRef, Term, TermRef, Triple, TripleRef,
+};
+use oxiri::Iri;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const GENID_PATH: &str = "/.well-known/genid/";
+
+/// Replaces blank nodes with [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization) and back.
+///
+/// The Skolem IRIs are built from the scheme and the authority of a base IRI
+/// and the blank node id, e.g. `_:b1` becomes `<http://example.com/.well-known/genid/b1>` with the `http://example.com/foo` base IRI.
+///
+/// De-skolemization recognizes all IRIs whose path starts with `/.well-known/genid/` whatever their authority.
+///
+/// ```
+/// use oxrdf::{BlankNode, NamedNodeRef, Skolemizer};
+///
+/// let skolemizer = Skolemizer::new(NamedNodeRef::new("http://example.com/foo")?);
+/// let bnode = BlankNode::new("b1")?;
+/// let iri = skolemizer.skolemize_blank_node(bnode.as_ref());
+/// assert_eq!(iri.as_str(), "http://example.com/.well-known/genid/b1");
+/// assert_eq!(Skolemizer::de_skolemize_named_node(iri.as_ref()), Some(bnode));
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Skolemizer {
+    prefix: String,
+}
+
+impl Skolemizer {
+    /// Builds a skolemizer minting IRIs with the same scheme and authority as `base`.
+    pub fn new(base: NamedNodeRef<'_>) -> Self {
+        let prefix = Iri::parse(base.as_str())
+            .and_then(|base| base.resolve(GENID_PATH))
+            .map_or_else(
+                |_| format!("{}{GENID_PATH}", base.as_str().trim_end_matches('/')),
+                Iri::into_inner,
+            );
+        Self { prefix }
+    }
+
+    /// Returns the Skolem IRI of a blank node.
+    pub fn skolemize_blank_node(&self, node: BlankNodeRef<'_>) -> NamedNode {
+        NamedNode::new_unchecked(format!("{}{}", self.prefix, node.as_str()))
+    }
+
+    /// Replaces the blank nodes in a term, including in the triple terms, by Skolem IRIs.
+    pub fn skolemize_term(&self, term: TermRef<'_>) -> Term {
+        match term {
+            TermRef::NamedNode(node) => node.into_owned().into(),
+            TermRef::BlankNode(node) => self.skolemize_blank_node(node).into(),
+            TermRef::Literal(literal) => literal.into_owned().into(),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(triple) => self.skolemize_triple(triple.as_ref()).into(),
+        }
+    }
+
+    /// Replaces the blank nodes in a triple by Skolem IRIs.
+    pub fn skolemize_triple(&self, triple: TripleRef<'_>) -> Triple {
+        Triple::new(
+            self.skolemize_subject(triple.subject),
+            triple.predicate,
+            self.skolemize_term(triple.object),
+        )
+    }
+
+    /// Replaces the blank nodes in a quad, including its graph name, by Skolem IRIs.
+    pub fn skolemize_quad(&self, quad: QuadRef<'_>) -> Quad {
+        Quad::new(
+            self.skolemize_subject(quad.subject),
+            quad.predicate,
+            self.skolemize_term(quad.object),
+            match quad.graph_name {
+                GraphNameRef::NamedNode(node) => node.into_owned().into(),
+                GraphNameRef::BlankNode(node) => self.skolemize_blank_node(node).into(),
+                GraphNameRef::DefaultGraph => GraphName::DefaultGraph,
+            },
+        )
+    }
+
+    fn skolemize_subject(&self, subject: NamedOrBlankNodeRef<'_>) -> NamedNode {
+        match subject {
+            NamedOrBlankNodeRef::NamedNode(node) => node.into_owned(),
+            NamedOrBlankNodeRef::BlankNode(node) => self.skolemize_blank_node(node),
+        }
+    }
+
+    /// Returns the blank node identified by a Skolem IRI or `None` if the IRI is not a Skolem IRI.
+    ///
+    /// The blank node id is the end of the IRI if it is a valid blank node id,
+    /// or is derived from the IRI hash otherwise.
+    pub fn de_skolemize_named_node(node: NamedNodeRef<'_>) -> Option<BlankNode> {
+        let iri = node.as_str();
+        if !iri.contains(GENID_PATH) {
+            return None; // Fast path
+        }
+        let parsed = Iri::parse(iri).ok()?;
+        if parsed.query().is_some() || parsed.fragment().is_some() {
+            return None;
+        }
+        let id = parsed.path().strip_prefix(GENID_PATH)?;
+        if id.is_empty() {
+            return None;
+        }
+        Some(BlankNode::new(id).unwrap_or_else(|_| {
+            let mut hasher = DefaultHasher::new();
+            iri.hash(&mut hasher);
+            BlankNode::new_from_unique_id(hasher.finish().into())
+        }))
+    }
+
+    /// Replaces the Skolem IRIs in a term, including in the triple terms, by blank nodes.
+    ///
+    /// Predicates are kept as is because blank nodes are not allowed there.
+    pub fn de_skolemize_term(term: TermRef<'_>) -> Term {
+        match term {
+            TermRef::NamedNode(node) => Self::de_skolemize_named_node(node)
+                .map_or_else(|| node.into_owned().into(), Into::into),
+            TermRef::BlankNode(node) => node.into_owned().into(),
+            TermRef::Literal(literal) => literal.into_owned().into(),
+            #[cfg(feature = "rdf-12")]
+            TermRef::Triple(triple) => Self::de_skolemize_triple(triple.as_ref()).into(),
+        }
+    }
+
+    /// Replaces the Skolem IRIs in a triple subject and object by blank nodes.
+    pub fn de_skolemize_triple(triple: TripleRef<'_>) -> Triple {
+        Triple::new(
+            Self::de_skolemize_subject(triple.subject),
+            triple.predicate,
+            Self::de_skolemize_term(triple.object),
+        )
+    }
+
+    /// Replaces the Skolem IRIs in a quad subject, object and graph name by blank nodes.
+    pub fn de_skolemize_quad(quad: QuadRef<'_>) -> Quad {
+        Quad::new(
+            Self::de_skolemize_subject(quad.subject),
+            quad.predicate,
+            Self::de_skolemize_term(quad.object),
+            match quad.graph_name {
+                GraphNameRef::NamedNode(node) => Self::de_skolemize_named_node(node)
+                    .map_or_else(|| node.into_owned().into(), Into::into),
+                GraphNameRef::BlankNode(node) => node.into_owned().into(),
+                GraphNameRef::DefaultGraph => GraphName::DefaultGraph,
+            },
+        )
+    }
+
+    fn de_skolemize_subject(subject: NamedOrBlankNodeRef<'_>) -> NamedOrBlankNode {
+        match subject {
+            NamedOrBlankNodeRef::NamedNode(node) => Self::de_skolemize_named_node(node)
+                .map_or_else(|| node.into_owned().into(), Into::into),
+            NamedOrBlankNodeRef::BlankNode(node) => node.into_owned().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skolem_iri_uses_base_authority() {
+        let skolemizer = Skolemizer::new(NamedNodeRef::new_unchecked(
+            "http://example.com/foo/bar?q#f",
+        ));
+        assert_eq!(
+            skolemizer
+                .skolemize_blank_node(BlankNodeRef::new_unchecked("b"))
+                .as_str(),
+            "http://example.com/.well-known/genid/b"
+        );
+    }
+
+    #[test]
+    fn de_skolemize_ignores_other_iris() {
+        for iri in [
+            "http://example.com/foo",
+            "http://example.com/.well-known/genid/",
+            "http://example.com/foo/.well-known/genid/b",
+            "http://example.com/.well-known/genid/b?q",
+        ] {
+            assert_eq!(
+                Skolemizer::de_skolemize_named_node(NamedNodeRef::new_unchecked(iri)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn de_skolemize_invalid_blank_node_id() {
+        let iri = NamedNodeRef::new_unchecked("http://example.com/.well-known/genid/a%20b");
+        let bnode = Skolemizer::de_skolemize_named_node(iri).unwrap();
+        assert_eq!(Skolemizer::de_skolemize_named_node(iri), Some(bnode));
+    }
+}
@@ -0,0 +1,370 @@
+use crate::LiteralRef;
+use crate::named_node::{NamedNode, NamedNodeRef};
+use crate::vocab::{rdf, xsd};
+use oxsdatatypes::*;
+use std::str::FromStr;
+
+/// The value of a [`Literal`] parsed according to its datatype.
+///
+/// It is returned by [`Literal::parsed_value()`].
+///
+/// ```
+/// use oxrdf::vocab::xsd;
+/// use oxrdf::{Literal, TypedValue};
+///
+/// assert!(matches!(
+///     Literal::new_typed_literal("12", xsd::INTEGER).parsed_value()?,
+///     TypedValue::Integer(value) if i64::from(value) == 12
+/// ));
+/// assert!(
+///     Literal::new_typed_literal("abc", xsd::INTEGER)
+///         .parsed_value()
+///         .is_err()
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub enum TypedValue<'a> {
+    /// A [xsd:integer](https://www.w3.org/TR/xmlschema11-2/#integer) or one of its derived datatypes like [xsd:int](https://www.w3.org/TR/xmlschema11-2/#int).
+    Integer(Integer),
+    /// A [xsd:decimal](https://www.w3.org/TR/xmlschema11-2/#decimal).
+    Decimal(Decimal),
+    /// A [xsd:float](https://www.w3.org/TR/xmlschema11-2/#float).
+    Float(Float),
+    /// A [xsd:double](https://www.w3.org/TR/xmlschema11-2/#double).
+    Double(Double),
+    /// A [xsd:boolean](https://www.w3.org/TR/xmlschema11-2/#boolean).
+    Boolean(Boolean),
+    /// A [xsd:dateTime](https://www.w3.org/TR/xmlschema11-2/#dateTime) or a [xsd:dateTimeStamp](https://www.w3.org/TR/xmlschema11-2/#dateTimeStamp).
+    DateTime(DateTime),
+    /// A [xsd:date](https://www.w3.org/TR/xmlschema11-2/#date).
+    Date(Date),
+    /// A [xsd:time](https://www.w3.org/TR/xmlschema11-2/#time).
+    Time(Time),
+    /// A [xsd:duration](https://www.w3.org/TR/xmlschema11-2/#duration), [xsd:yearMonthDuration](https://www.w3.org/TR/xmlschema11-2/#yearMonthDuration) or [xsd:dayTimeDuration](https://www.w3.org/TR/xmlschema11-2/#dayTimeDuration).
+    Duration(Duration),
+    /// A [xsd:hexBinary](https://www.w3.org/TR/xmlschema11-2/#hexBinary).
+    HexBinary(HexBinary),
+    /// A [xsd:base64Binary](https://www.w3.org/TR/xmlschema11-2/#base64Binary).
+    Base64Binary(Base64Binary),
+    /// A [simple literal](https://www.w3.org/TR/rdf11-concepts/#dfn-simple-literal) i.e. a [xsd:string](https://www.w3.org/TR/xmlschema11-2/#string).
+    String(&'a str),
+    /// A [language-tagged string](https://www.w3.org/TR/rdf11-concepts/#dfn-language-tagged-string).
+    LangString { value: &'a str, language: &'a str },
+    /// A literal with a datatype that is not recognized by this implementation.
+    ///
+    /// Its lexical form is not validated.
+    Other(LiteralRef<'a>),
+}
+
+pub(crate) fn parse_literal(literal: LiteralRef<'_>) -> Result<TypedValue<'_>, DatatypeError> {
+    let value = literal.value();
+    let datatype = literal.datatype();
+    let invalid = || DatatypeError {
+        value: value.into(),
+        datatype: datatype.into_owned(),
+    };
+    Ok(match datatype {
+        xsd::STRING => TypedValue::String(value),
+        rdf::LANG_STRING => TypedValue::LangString {
+            value,
+            language: literal.language().ok_or_else(invalid)?,
+        },
+        xsd::BOOLEAN => TypedValue::Boolean(value.parse().map_err(|_| invalid())?),
+        xsd::INTEGER => TypedValue::Integer(value.parse().map_err(|_| invalid())?),
+        xsd::DECIMAL => TypedValue::Decimal(value.parse().map_err(|_| invalid())?),
+        xsd::FLOAT if is_float_lexical_form(value) => {
+            TypedValue::Float(value.parse().map_err(|_| invalid())?)
+        }
+        xsd::DOUBLE if is_float_lexical_form(value) => {
+            TypedValue::Double(value.parse().map_err(|_| invalid())?)
+        }
+        xsd::FLOAT | xsd::DOUBLE => return Err(invalid()),
+        xsd::DATE_TIME => TypedValue::DateTime(value.parse().map_err(|_| invalid())?),
+        xsd::DATE_TIME_STAMP => {
+            let value = DateTime::from_str(value).map_err(|_| invalid())?;
+            if value.timezone_offset().is_none() {
+                return Err(invalid());
+            }
+            TypedValue::DateTime(value)
+        }
+        xsd::DATE => TypedValue::Date(value.parse().map_err(|_| invalid())?),
+        xsd::TIME => TypedValue::Time(value.parse().map_err(|_| invalid())?),
+        xsd::DURATION => TypedValue::Duration(value.parse().map_err(|_| invalid())?),
+        xsd::YEAR_MONTH_DURATION => TypedValue::Duration(
+            YearMonthDuration::from_str(value)
+                .map_err(|_| invalid())?
+                .into(),
+        ),
+        xsd::DAY_TIME_DURATION => TypedValue::Duration(
+            DayTimeDuration::from_str(value)
+                .map_err(|_| invalid())?
+                .into(),
+        ),
+        xsd::HEX_BINARY => TypedValue::HexBinary(value.parse().map_err(|_| invalid())?),
+        xsd::BASE_64_BINARY => TypedValue::Base64Binary(value.parse().map_err(|_| invalid())?),
+        _ => {
+            let Some((min, max)) = integer_bounds(datatype) else {
+                return Ok(TypedValue::Other(literal));
+            };
+            let value = Integer::from_str(value).map_err(|_| invalid())?;
+            if min.is_some_and(|min| i64::from(value) < min)
+                || max.is_some_and(|max| i64::from(value) > max)
+            {
+                return Err(invalid());
+            }
+            TypedValue::Integer(value)
+        }
+    })
+}
+
+/// The Rust float parser also accepts forms like `inf` or `infinity` that are not allowed by XSD
+fn is_float_lexical_form(value: &str) -> bool {
+    matches!(value, "INF" | "+INF" | "-INF" | "NaN")
+        || value
+            .bytes()
+            .all(|c| c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E'))
+}
+
+/// The bounds of the datatypes derived from [xsd:integer](https://www.w3.org/TR/xmlschema11-2/#integer)
+fn integer_bounds(datatype: NamedNodeRef<'_>) -> Option<(Option<i64>, Option<i64>)> {
+    Some(match datatype {
+        xsd::LONG => (None, None),
+        xsd::INT => (Some(i32::MIN.into()), Some(i32::MAX.into())),
+        xsd::SHORT => (Some(i16::MIN.into()), Some(i16::MAX.into())),
+        xsd::BYTE => (Some(i8::MIN.into()), Some(i8::MAX.into())),
+        xsd::UNSIGNED_INT => (Some(0), Some(u32::MAX.into())),
+        xsd::UNSIGNED_SHORT => (Some(0), Some(u16::MAX.into())),
+        xsd::UNSIGNED_BYTE => (Some(0), Some(u8::MAX.into())),
+        xsd::UNSIGNED_LONG | xsd::NON_NEGATIVE_INTEGER => (Some(0), None),
+        xsd::POSITIVE_INTEGER => (Some(1), None),
+        xsd::NON_POSITIVE_INTEGER => (None, Some(0)),
+        xsd::NEGATIVE_INTEGER => (None, Some(-1)),
+        _ => return None,
+    })
+}
+
+/// An error raised when the lexical form of a literal is not valid for its datatype.
+///
+/// ```
+/// use oxrdf::Literal;
+/// use oxrdf::vocab::xsd;
+///
+/// let error = Literal::new_typed_literal("abc", xsd::INTEGER)
+///     .parsed_value()
+///     .unwrap_err();
+/// assert_eq!(error.value(), "abc");
+/// assert_eq!(error.datatype(), xsd::INTEGER);
+/// ```
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{value:?} is not a valid lexical form for the datatype {datatype}")]
+pub struct DatatypeError {
+    value: String,
+    datatype: NamedNode,
+}
+
+impl DatatypeError {
+    /// The invalid lexical form.
+    #[inline]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The datatype the lexical form is not valid for.
+    #[inline]
+    pub fn datatype(&self) -> NamedNodeRef<'_> {
+        self.datatype.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+
+    fn check(datatype: NamedNodeRef<'_>, valid: &[&str], invalid: &[&str]) {
+        for value in valid {
+            let literal = LiteralRef::new_typed_literal(value, datatype);
+            assert!(literal.parsed_value().is_ok(), "{literal} should be valid");
+        }
+        for value in invalid {
+            let literal = LiteralRef::new_typed_literal(value, datatype);
+            let Err(error) = literal.parsed_value() else {
+                unreachable!("{literal} should be invalid")
+            };
+            assert_eq!(error.value(), *value);
+            assert_eq!(error.datatype(), datatype);
+        }
+    }
+
+    #[test]
+    fn boolean() {
+        check(
+            xsd::BOOLEAN,
+            &["true", "false", "1", "0"],
+            &["", "yes", "TRUE"],
+        );
+        assert_eq!(
+            LiteralRef::new_typed_literal("1", xsd::BOOLEAN)
+                .parsed_value()
+                .unwrap(),
+            TypedValue::Boolean(true.into())
+        );
+    }
+
+    #[test]
+    fn integer() {
+        check(
+            xsd::INTEGER,
+            &["0", "-12", "+12", "0012"],
+            &["", "abc", "1.0", "1e3", "99999999999999999999999"],
+        );
+        assert_eq!(
+            LiteralRef::new_typed_literal("-12", xsd::INTEGER)
+                .parsed_value()
+                .unwrap(),
+            TypedValue::Integer((-12).into())
+        );
+    }
+
+    #[test]
+    fn derived_integers() {
+        check(xsd::BYTE, &["-128", "127"], &["-129", "128", "a"]);
+        check(xsd::SHORT, &["-32768", "32767"], &["-32769", "32768"]);
+        check(xsd::INT, &["-2147483648", "2147483647"], &["2147483648"]);
+        check(
+            xsd::LONG,
+            &["-9223372036854775808"],
+            &["9223372036854775808"],
+        );
+        check(xsd::UNSIGNED_BYTE, &["0", "255"], &["-1", "256"]);
+        check(xsd::UNSIGNED_SHORT, &["65535"], &["65536"]);
+        check(xsd::UNSIGNED_INT, &["4294967295"], &["4294967296"]);
+        check(xsd::UNSIGNED_LONG, &["0"], &["-1"]);
+        check(xsd::POSITIVE_INTEGER, &["1"], &["0"]);
+        check(xsd::NON_NEGATIVE_INTEGER, &["0"], &["-1"]);
+        check(xsd::NEGATIVE_INTEGER, &["-1"], &["0"]);
+        check(xsd::NON_POSITIVE_INTEGER, &["0"], &["1"]);
+    }
+
+    #[test]
+    fn decimal() {
+        check(
+            xsd::DECIMAL,
+            &["1", "-1.5", ".5", "1.", "+0.0"],
+            &["", ".", "1e3", "abc", "1.2.3"],
+        );
+    }
+
+    #[test]
+    fn float_and_double() {
+        for datatype in [xsd::FLOAT, xsd::DOUBLE] {
+            check(
+                datatype,
+                &["1", "-1.5", "1e3", "1.5E-3", "INF", "-INF", "NaN"],
+                &["", "abc", "inf", "1e", "1.2.3"],
+            );
+        }
+    }
+
+    #[test]
+    fn date_time() {
+        check(
+            xsd::DATE_TIME,
+            &[
+                "2020-01-01T00:00:00",
+                "2020-01-01T12:30:00.5Z",
+                "2020-01-01T00:00:00+01:00",
+            ],
+            &[
+                "2020-01-01",
+                "2020-13-01T00:00:00",
+                "2020-01-01T25:00:00",
+                "abc",
+            ],
+        );
+        check(
+            xsd::DATE_TIME_STAMP,
+            &["2020-01-01T00:00:00Z"],
+            &["2020-01-01T00:00:00"],
+        );
+    }
+
+    #[test]
+    fn date() {
+        check(
+            xsd::DATE,
+            &["2020-01-01", "2020-02-29Z", "-0044-03-15"],
+            &["2020-02-30", "2020-1-1", "2020-01-01T00:00:00"],
+        );
+    }
+
+    #[test]
+    fn time() {
+        check(
+            xsd::TIME,
+            &["00:00:00", "23:59:59.999", "12:00:00-05:00"],
+            &["25:00:00", "12:00", "abc"],
+        );
+    }
+
+    #[test]
+    fn durations() {
+        check(
+            xsd::DURATION,
+            &["P1Y2M3DT4H5M6S", "-P1D", "PT0.5S"],
+            &["P", "1Y", "PT", "P1S"],
+        );
+        check(xsd::YEAR_MONTH_DURATION, &["P1Y2M"], &["P1D"]);
+        check(xsd::DAY_TIME_DURATION, &["P1DT2H"], &["P1Y"]);
+    }
+
+    #[test]
+    fn binaries() {
+        check(
+            xsd::HEX_BINARY,
+            &["", "0fb7", "0FB7"],
+            &["0", "0G", "0F B7"],
+        );
+        check(
+            xsd::BASE_64_BINARY,
+            &["", "D7c=", "Zm9v YmFy"],
+            &["D7c", "D7d=", "Zm9v\nYmFy", "Zm9v  YmFy"],
+        );
+        assert_eq!(
+            LiteralRef::new_typed_literal("0fb7", xsd::HEX_BINARY)
+                .parsed_value()
+                .unwrap(),
+            TypedValue::HexBinary(vec![0x0F, 0xB7].into())
+        );
+        assert_eq!(
+            LiteralRef::new_typed_literal("D7c=", xsd::BASE_64_BINARY)
+                .parsed_value()
+                .unwrap(),
+            TypedValue::Base64Binary(vec![0x0F, 0xB7].into())
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            Literal::new_simple_literal("abc").parsed_value().unwrap(),
+            TypedValue::String("abc")
+        );
+        assert_eq!(
+            Literal::new_language_tagged_literal_unchecked("abc", "en")
+                .parsed_value()
+                .unwrap(),
+            TypedValue::LangString {
+                value: "abc",
+                language: "en"
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_datatype() {
+        let literal = LiteralRef::new_typed_literal("abc", xsd::G_YEAR);
+        assert_eq!(literal.parsed_value().unwrap(), TypedValue::Other(literal));
+    }
+}
@@ -0,0 +1,376 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [DCMI Metadata Terms](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// Abstract
+pub const ABSTRACT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/abstract");
+/// Access Rights
+pub const ACCESS_RIGHTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/accessRights");
+/// Accrual Method
+pub const ACCRUAL_METHOD: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/accrualMethod");
+/// Accrual Periodicity
+pub const ACCRUAL_PERIODICITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/accrualPeriodicity");
+/// Accrual Policy
+pub const ACCRUAL_POLICY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/accrualPolicy");
+/// Agent
+pub const AGENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Agent");
+/// Agent Class
+pub const AGENT_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/AgentClass");
+/// Alternative Title
+pub const ALTERNATIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/alternative");
+/// Audience
+pub const AUDIENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/audience");
+/// Date Available
+pub const AVAILABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/available");
+/// Bibliographic Citation
+pub const BIBLIOGRAPHIC_CITATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/bibliographicCitation");
+/// Bibliographic Resource
+pub const BIBLIOGRAPHIC_RESOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/BibliographicResource");
+/// DCMI Box
+pub const BOX: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Box");
+/// Conforms To
+pub const CONFORMS_TO: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/conformsTo");
+/// Contributor
+pub const CONTRIBUTOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/contributor");
+/// Coverage
+pub const COVERAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/coverage");
+/// Date Created
+pub const CREATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/created");
+/// Creator
+pub const CREATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/creator");
+/// Date
+pub const DATE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/date");
+/// Date Accepted
+pub const DATE_ACCEPTED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/dateAccepted");
+/// Date Copyrighted
+pub const DATE_COPYRIGHTED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/dateCopyrighted");
+/// Date Submitted
+pub const DATE_SUBMITTED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/dateSubmitted");
+/// DCMI Type Vocabulary
+pub const DCMI_TYPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/DCMIType");
+/// DDC
+pub const DDC: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/DDC");
+/// Description
+pub const DESCRIPTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/description");
+/// Audience Education Level
+pub const EDUCATION_LEVEL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/educationLevel");
+/// Extent
+pub const EXTENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/extent");
+/// File Format
+pub const FILE_FORMAT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/FileFormat");
+/// Format
+pub const FORMAT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/format");
+/// Frequency
+pub const FREQUENCY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Frequency");
+/// Has Format
+pub const HAS_FORMAT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/hasFormat");
+/// Has Part
+pub const HAS_PART: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/hasPart");
+/// Has Version
+pub const HAS_VERSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/hasVersion");
+/// Identifier
+pub const IDENTIFIER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/identifier");
+/// IMT
+pub const IMT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/IMT");
+/// Instructional Method
+pub const INSTRUCTIONAL_METHOD: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/instructionalMethod");
+/// ISO 3166
+pub const ISO_3166: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/ISO3166");
+/// ISO 639-2
+pub const ISO_639_2: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/ISO639-2");
+/// ISO 639-3
+pub const ISO_639_3: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/ISO639-3");
+/// Date Issued
+pub const ISSUED: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/issued");
+/// Is Format Of
+pub const IS_FORMAT_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isFormatOf");
+/// Is Part Of
+pub const IS_PART_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isPartOf");
+/// Is Referenced By
+pub const IS_REFERENCED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isReferencedBy");
+/// Is Replaced By
+pub const IS_REPLACED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isReplacedBy");
+/// Is Required By
+pub const IS_REQUIRED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isRequiredBy");
+/// Is Version Of
+pub const IS_VERSION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/isVersionOf");
+/// Jurisdiction
+pub const JURISDICTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Jurisdiction");
+/// Language
+pub const LANGUAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/language");
+/// LCC
+pub const LCC: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/LCC");
+/// LCSH
+pub const LCSH: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/LCSH");
+/// License
+pub const LICENSE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/license");
+/// License Document
+pub const LICENSE_DOCUMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/LicenseDocument");
+/// Linguistic System
+pub const LINGUISTIC_SYSTEM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/LinguisticSystem");
+/// Location
+pub const LOCATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Location");
+/// Location, Period, or Jurisdiction
+pub const LOCATION_PERIOD_OR_JURISDICTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/LocationPeriodOrJurisdiction");
+/// Mediator
+pub const MEDIATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/mediator");
+/// Media Type
+pub const MEDIA_TYPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/MediaType");
+/// Media Type or Extent
+pub const MEDIA_TYPE_OR_EXTENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/MediaTypeOrExtent");
+/// Medium
+pub const MEDIUM: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/medium");
+/// MeSH
+pub const MESH: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/MESH");
+/// Method of Accrual
+pub const METHOD_OF_ACCRUAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/MethodOfAccrual");
+/// Method of Instruction
+pub const METHOD_OF_INSTRUCTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/MethodOfInstruction");
+/// Date Modified
+pub const MODIFIED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/modified");
+/// NLM
+pub const NLM: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/NLM");
+/// DCMI Period
+pub const PERIOD: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Period");
+/// Period of Time
+pub const PERIOD_OF_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/PeriodOfTime");
+/// Physical Medium
+pub const PHYSICAL_MEDIUM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/PhysicalMedium");
+/// Physical Resource
+pub const PHYSICAL_RESOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/PhysicalResource");
+/// DCMI Point
+pub const POINT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Point");
+/// Policy
+pub const POLICY: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Policy");
+/// Provenance
+pub const PROVENANCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/provenance");
+/// Provenance Statement
+pub const PROVENANCE_STATEMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/ProvenanceStatement");
+/// Publisher
+pub const PUBLISHER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/publisher");
+/// References
+pub const REFERENCES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/references");
+/// Relation
+pub const RELATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/relation");
+/// Replaces
+pub const REPLACES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/replaces");
+/// Requires
+pub const REQUIRES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/requires");
+/// RFC 1766
+pub const RFC_1766: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/RFC1766");
+/// RFC 3066
+pub const RFC_3066: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/RFC3066");
+/// RFC 4646
+pub const RFC_4646: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/RFC4646");
+/// RFC 5646
+pub const RFC_5646: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/RFC5646");
+/// Rights
+pub const RIGHTS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/rights");
+/// Rights Holder
+pub const RIGHTS_HOLDER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/rightsHolder");
+/// Rights Statement
+pub const RIGHTS_STATEMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/RightsStatement");
+/// Size or Duration
+pub const SIZE_OR_DURATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/SizeOrDuration");
+/// Source
+pub const SOURCE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/source");
+/// Spatial Coverage
+pub const SPATIAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/spatial");
+/// Standard
+pub const STANDARD: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/Standard");
+/// Subject
+pub const SUBJECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/subject");
+/// Table Of Contents
+pub const TABLE_OF_CONTENTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/tableOfContents");
+/// Temporal Coverage
+pub const TEMPORAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/temporal");
+/// TGN
+pub const TGN: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/TGN");
+/// Title
+pub const TITLE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/title");
+/// Type
+pub const TYPE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/type");
+/// UDC
+pub const UDC: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/UDC");
+/// URI
+pub const URI: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/URI");
+/// Date Valid
+pub const VALID: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://purl.org/dc/terms/valid");
+/// W3C-DTF
+pub const W_3_CDTF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/W3CDTF");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ABSTRACT,
+    ACCESS_RIGHTS,
+    ACCRUAL_METHOD,
+    ACCRUAL_PERIODICITY,
+    ACCRUAL_POLICY,
+    AGENT,
+    AGENT_CLASS,
+    ALTERNATIVE,
+    AUDIENCE,
+    AVAILABLE,
+    BIBLIOGRAPHIC_CITATION,
+    BIBLIOGRAPHIC_RESOURCE,
+    BOX,
+    CONFORMS_TO,
+    CONTRIBUTOR,
+    COVERAGE,
+    CREATED,
+    CREATOR,
+    DATE,
+    DATE_ACCEPTED,
+    DATE_COPYRIGHTED,
+    DATE_SUBMITTED,
+    DCMI_TYPE,
+    DDC,
+    DESCRIPTION,
+    EDUCATION_LEVEL,
+    EXTENT,
+    FILE_FORMAT,
+    FORMAT,
+    FREQUENCY,
+    HAS_FORMAT,
+    HAS_PART,
+    HAS_VERSION,
+    IDENTIFIER,
+    IMT,
+    INSTRUCTIONAL_METHOD,
+    ISO_3166,
+    ISO_639_2,
+    ISO_639_3,
+    ISSUED,
+    IS_FORMAT_OF,
+    IS_PART_OF,
+    IS_REFERENCED_BY,
+    IS_REPLACED_BY,
+    IS_REQUIRED_BY,
+    IS_VERSION_OF,
+    JURISDICTION,
+    LANGUAGE,
+    LCC,
+    LCSH,
+    LICENSE,
+    LICENSE_DOCUMENT,
+    LINGUISTIC_SYSTEM,
+    LOCATION,
+    LOCATION_PERIOD_OR_JURISDICTION,
+    MEDIATOR,
+    MEDIA_TYPE,
+    MEDIA_TYPE_OR_EXTENT,
+    MEDIUM,
+    MESH,
+    METHOD_OF_ACCRUAL,
+    METHOD_OF_INSTRUCTION,
+    MODIFIED,
+    NLM,
+    PERIOD,
+    PERIOD_OF_TIME,
+    PHYSICAL_MEDIUM,
+    PHYSICAL_RESOURCE,
+    POINT,
+    POLICY,
+    PROVENANCE,
+    PROVENANCE_STATEMENT,
+    PUBLISHER,
+    REFERENCES,
+    RELATION,
+    REPLACES,
+    REQUIRES,
+    RFC_1766,
+    RFC_3066,
+    RFC_4646,
+    RFC_5646,
+    RIGHTS,
+    RIGHTS_HOLDER,
+    RIGHTS_STATEMENT,
+    SIZE_OR_DURATION,
+    SOURCE,
+    SPATIAL,
+    STANDARD,
+    SUBJECT,
+    TABLE_OF_CONTENTS,
+    TEMPORAL,
+    TGN,
+    TITLE,
+    TYPE,
+    UDC,
+    URI,
+    VALID,
+    W_3_CDTF,
+];
@@ -0,0 +1,288 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [FOAF](http://xmlns.com/foaf/spec/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// account
+pub const ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/account");
+/// account name
+pub const ACCOUNT_NAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/accountName");
+/// account service homepage
+pub const ACCOUNT_SERVICE_HOMEPAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/accountServiceHomepage");
+/// age
+pub const AGE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/age");
+/// Agent
+pub const AGENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Agent");
+/// AIM chat ID
+pub const AIM_CHAT_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/aimChatID");
+/// based near
+pub const BASED_NEAR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/based_near");
+/// birthday
+pub const BIRTHDAY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/birthday");
+/// current project
+pub const CURRENT_PROJECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/currentProject");
+/// depiction
+pub const DEPICTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/depiction");
+/// depicts
+pub const DEPICTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/depicts");
+/// DNA checksum
+pub const DNA_CHECKSUM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/dnaChecksum");
+/// Document
+pub const DOCUMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Document");
+/// familyName
+pub const FAMILY_NAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/familyName");
+/// family_name
+pub const FAMILY_NAME_ARCHAIC: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/family_name");
+/// firstName
+pub const FIRST_NAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/firstName");
+/// focus
+pub const FOCUS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/focus");
+/// funded by
+pub const FUNDED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/fundedBy");
+/// geekcode
+pub const GEEKCODE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/geekcode");
+/// gender
+pub const GENDER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/gender");
+/// Given name
+pub const GIVENNAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/givenname");
+/// Given name
+pub const GIVEN_NAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/givenName");
+/// Group
+pub const GROUP: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Group");
+/// account
+pub const HOLDS_ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/holdsAccount");
+/// homepage
+pub const HOMEPAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/homepage");
+/// ICQ chat ID
+pub const ICQ_CHAT_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/icqChatID");
+/// Image
+pub const IMAGE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Image");
+/// image
+pub const IMG: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/img");
+/// interest
+pub const INTEREST: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/interest");
+/// is primary topic of
+pub const IS_PRIMARY_TOPIC_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/isPrimaryTopicOf");
+/// jabber ID
+pub const JABBER_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/jabberID");
+/// knows
+pub const KNOWS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/knows");
+/// Label Property
+pub const LABEL_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/LabelProperty");
+/// lastName
+pub const LAST_NAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/lastName");
+/// logo
+pub const LOGO: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/logo");
+/// made
+pub const MADE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/made");
+/// maker
+pub const MAKER: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/maker");
+/// personal mailbox
+pub const MBOX: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/mbox");
+/// sha1sum of a personal mailbox URI name
+pub const MBOX_SHA_1_SUM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/mbox_sha1sum");
+/// member
+pub const MEMBER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/member");
+/// membershipClass
+pub const MEMBERSHIP_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/membershipClass");
+/// MSN chat ID
+pub const MSN_CHAT_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/msnChatID");
+/// myersBriggs
+pub const MYERS_BRIGGS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/myersBriggs");
+/// name
+pub const NAME: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/name");
+/// nickname
+pub const NICK: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/nick");
+/// Online Account
+pub const ONLINE_ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/OnlineAccount");
+/// Online Chat Account
+pub const ONLINE_CHAT_ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/OnlineChatAccount");
+/// Online E-commerce Account
+pub const ONLINE_ECOMMERCE_ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/OnlineEcommerceAccount");
+/// Online Gaming Account
+pub const ONLINE_GAMING_ACCOUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/OnlineGamingAccount");
+/// openid
+pub const OPENID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/openid");
+/// Organization
+pub const ORGANIZATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Organization");
+/// page
+pub const PAGE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/page");
+/// past project
+pub const PAST_PROJECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/pastProject");
+/// Person
+pub const PERSON: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Person");
+/// PersonalProfileDocument
+pub const PERSONAL_PROFILE_DOCUMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/PersonalProfileDocument");
+/// phone
+pub const PHONE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/phone");
+/// plan
+pub const PLAN: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/plan");
+/// primary topic
+pub const PRIMARY_TOPIC: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/primaryTopic");
+/// Project
+pub const PROJECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/Project");
+/// publications
+pub const PUBLICATIONS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/publications");
+/// schoolHomepage
+pub const SCHOOL_HOMEPAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/schoolHomepage");
+/// sha1sum (hex)
+pub const SHA_1: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/sha1");
+/// Skype ID
+pub const SKYPE_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/skypeID");
+/// status
+pub const STATUS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/status");
+/// Surname
+pub const SURNAME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/surname");
+/// theme
+pub const THEME: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/theme");
+/// thumbnail
+pub const THUMBNAIL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/thumbnail");
+/// tipjar
+pub const TIPJAR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/tipjar");
+/// title
+pub const TITLE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/title");
+/// topic
+pub const TOPIC: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/topic");
+/// topic_interest
+pub const TOPIC_INTEREST: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/topic_interest");
+/// weblog
+pub const WEBLOG: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/weblog");
+/// workplace homepage
+pub const WORKPLACE_HOMEPAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/workplaceHomepage");
+/// work info homepage
+pub const WORK_INFO_HOMEPAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/workInfoHomepage");
+/// Yahoo chat ID
+pub const YAHOO_CHAT_ID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://xmlns.com/foaf/0.1/yahooChatID");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ACCOUNT,
+    ACCOUNT_NAME,
+    ACCOUNT_SERVICE_HOMEPAGE,
+    AGE,
+    AGENT,
+    AIM_CHAT_ID,
+    BASED_NEAR,
+    BIRTHDAY,
+    CURRENT_PROJECT,
+    DEPICTION,
+    DEPICTS,
+    DNA_CHECKSUM,
+    DOCUMENT,
+    FAMILY_NAME,
+    FAMILY_NAME_ARCHAIC,
+    FIRST_NAME,
+    FOCUS,
+    FUNDED_BY,
+    GEEKCODE,
+    GENDER,
+    GIVENNAME,
+    GIVEN_NAME,
+    GROUP,
+    HOLDS_ACCOUNT,
+    HOMEPAGE,
+    ICQ_CHAT_ID,
+    IMAGE,
+    IMG,
+    INTEREST,
+    IS_PRIMARY_TOPIC_OF,
+    JABBER_ID,
+    KNOWS,
+    LABEL_PROPERTY,
+    LAST_NAME,
+    LOGO,
+    MADE,
+    MAKER,
+    MBOX,
+    MBOX_SHA_1_SUM,
+    MEMBER,
+    MEMBERSHIP_CLASS,
+    MSN_CHAT_ID,
+    MYERS_BRIGGS,
+    NAME,
+    NICK,
+    ONLINE_ACCOUNT,
+    ONLINE_CHAT_ACCOUNT,
+    ONLINE_ECOMMERCE_ACCOUNT,
+    ONLINE_GAMING_ACCOUNT,
+    OPENID,
+    ORGANIZATION,
+    PAGE,
+    PAST_PROJECT,
+    PERSON,
+    PERSONAL_PROFILE_DOCUMENT,
+    PHONE,
+    PLAN,
+    PRIMARY_TOPIC,
+    PROJECT,
+    PUBLICATIONS,
+    SCHOOL_HOMEPAGE,
+    SHA_1,
+    SKYPE_ID,
+    STATUS,
+    SURNAME,
+    THEME,
+    THUMBNAIL,
+    TIPJAR,
+    TITLE,
+    TOPIC,
+    TOPIC_INTEREST,
+    WEBLOG,
+    WORKPLACE_HOMEPAGE,
+    WORK_INFO_HOMEPAGE,
+    YAHOO_CHAT_ID,
+];
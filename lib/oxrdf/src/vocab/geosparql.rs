@@ -0,0 +1,264 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [GeoSPARQL](https://opengeospatial.github.io/ogc-geosparql/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// as DGGS
+pub const AS_DGGS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#asDGGS");
+/// as GeoJSON
+pub const AS_GEO_JSON: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#asGeoJSON");
+/// as GML
+pub const AS_GML: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#asGML");
+/// as KML
+pub const AS_KML: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#asKML");
+/// as WKT
+pub const AS_WKT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#asWKT");
+/// coordinate dimension
+pub const COORDINATE_DIMENSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#coordinateDimension");
+/// DGGS Literal
+pub const DGGS_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#dggsLiteral");
+/// dimension
+pub const DIMENSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#dimension");
+/// contains
+pub const EH_CONTAINS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehContains");
+/// covered by
+pub const EH_COVERED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehCoveredBy");
+/// covers
+pub const EH_COVERS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehCovers");
+/// disjoint
+pub const EH_DISJOINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehDisjoint");
+/// equals
+pub const EH_EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehEquals");
+/// inside
+pub const EH_INSIDE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehInside");
+/// meet
+pub const EH_MEET: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehMeet");
+/// overlap
+pub const EH_OVERLAP: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#ehOverlap");
+/// Feature
+pub const FEATURE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#Feature");
+/// Feature Collection
+pub const FEATURE_COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#FeatureCollection");
+/// Geometry
+pub const GEOMETRY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#Geometry");
+/// Geometry Collection
+pub const GEOMETRY_COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#GeometryCollection");
+/// GeoJSON Literal
+pub const GEO_JSON_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#geoJSONLiteral");
+/// GML Literal
+pub const GML_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#gmlLiteral");
+/// has area
+pub const HAS_AREA: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasArea");
+/// has bounding box
+pub const HAS_BOUNDING_BOX: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasBoundingBox");
+/// has centroid
+pub const HAS_CENTROID: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasCentroid");
+/// has default geometry
+pub const HAS_DEFAULT_GEOMETRY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasDefaultGeometry");
+/// has geometry
+pub const HAS_GEOMETRY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasGeometry");
+/// has length
+pub const HAS_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasLength");
+/// has area in square meters
+pub const HAS_METRIC_AREA: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricArea");
+/// has length in meters
+pub const HAS_METRIC_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricLength");
+/// has perimeter length in meters
+pub const HAS_METRIC_PERIMETER_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricPerimeterLength");
+/// has metric size
+pub const HAS_METRIC_SIZE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricSize");
+/// has spatial accuracy in meters
+pub const HAS_METRIC_SPATIAL_ACCURACY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricSpatialAccuracy");
+/// has spatial resolution in meters
+pub const HAS_METRIC_SPATIAL_RESOLUTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricSpatialResolution");
+/// has volume in cubic meters
+pub const HAS_METRIC_VOLUME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasMetricVolume");
+/// has perimeter length
+pub const HAS_PERIMETER_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasPerimeterLength");
+/// has serialization
+pub const HAS_SERIALIZATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasSerialization");
+/// has size
+pub const HAS_SIZE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasSize");
+/// has spatial accuracy
+pub const HAS_SPATIAL_ACCURACY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasSpatialAccuracy");
+/// has spatial resolution
+pub const HAS_SPATIAL_RESOLUTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasSpatialResolution");
+/// has volume
+pub const HAS_VOLUME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#hasVolume");
+/// is empty
+pub const IS_EMPTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#isEmpty");
+/// is simple
+pub const IS_SIMPLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#isSimple");
+/// KML Literal
+pub const KML_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#kmlLiteral");
+/// disconnected
+pub const RCC_8_DC: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8dc");
+/// externally connected
+pub const RCC_8_EC: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8ec");
+/// equals
+pub const RCC_8_EQ: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8eq");
+/// non-tangential proper part
+pub const RCC_8_NTPP: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8ntpp");
+/// non-tangential proper part inverse
+pub const RCC_8_NTPPI: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8ntppi");
+/// partially overlapping
+pub const RCC_8_PO: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8po");
+/// tangential proper part
+pub const RCC_8_TPP: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8tpp");
+/// tangential proper part inverse
+pub const RCC_8_TPPI: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#rcc8tppi");
+/// contains
+pub const SF_CONTAINS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfContains");
+/// crosses
+pub const SF_CROSSES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfCrosses");
+/// disjoint
+pub const SF_DISJOINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfDisjoint");
+/// equals
+pub const SF_EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfEquals");
+/// intersects
+pub const SF_INTERSECTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfIntersects");
+/// overlaps
+pub const SF_OVERLAPS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfOverlaps");
+/// touches
+pub const SF_TOUCHES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfTouches");
+/// within
+pub const SF_WITHIN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#sfWithin");
+/// spatial dimension
+pub const SPATIAL_DIMENSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#spatialDimension");
+/// Spatial Object
+pub const SPATIAL_OBJECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#SpatialObject");
+/// Spatial Object Collection
+pub const SPATIAL_OBJECT_COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#SpatialObjectCollection");
+/// Well-known Text Literal
+pub const WKT_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#wktLiteral");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    AS_DGGS,
+    AS_GEO_JSON,
+    AS_GML,
+    AS_KML,
+    AS_WKT,
+    COORDINATE_DIMENSION,
+    DGGS_LITERAL,
+    DIMENSION,
+    EH_CONTAINS,
+    EH_COVERED_BY,
+    EH_COVERS,
+    EH_DISJOINT,
+    EH_EQUALS,
+    EH_INSIDE,
+    EH_MEET,
+    EH_OVERLAP,
+    FEATURE,
+    FEATURE_COLLECTION,
+    GEOMETRY,
+    GEOMETRY_COLLECTION,
+    GEO_JSON_LITERAL,
+    GML_LITERAL,
+    HAS_AREA,
+    HAS_BOUNDING_BOX,
+    HAS_CENTROID,
+    HAS_DEFAULT_GEOMETRY,
+    HAS_GEOMETRY,
+    HAS_LENGTH,
+    HAS_METRIC_AREA,
+    HAS_METRIC_LENGTH,
+    HAS_METRIC_PERIMETER_LENGTH,
+    HAS_METRIC_SIZE,
+    HAS_METRIC_SPATIAL_ACCURACY,
+    HAS_METRIC_SPATIAL_RESOLUTION,
+    HAS_METRIC_VOLUME,
+    HAS_PERIMETER_LENGTH,
+    HAS_SERIALIZATION,
+    HAS_SIZE,
+    HAS_SPATIAL_ACCURACY,
+    HAS_SPATIAL_RESOLUTION,
+    HAS_VOLUME,
+    IS_EMPTY,
+    IS_SIMPLE,
+    KML_LITERAL,
+    RCC_8_DC,
+    RCC_8_EC,
+    RCC_8_EQ,
+    RCC_8_NTPP,
+    RCC_8_NTPPI,
+    RCC_8_PO,
+    RCC_8_TPP,
+    RCC_8_TPPI,
+    SF_CONTAINS,
+    SF_CROSSES,
+    SF_DISJOINT,
+    SF_EQUALS,
+    SF_INTERSECTS,
+    SF_OVERLAPS,
+    SF_TOUCHES,
+    SF_WITHIN,
+    SPATIAL_DIMENSION,
+    SPATIAL_OBJECT,
+    SPATIAL_OBJECT_COLLECTION,
+    WKT_LITERAL,
+];
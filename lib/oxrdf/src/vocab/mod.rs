@@ -1,4 +1,26 @@
 //! Provides ready to use [`NamedNodeRef`](super::NamedNodeRef)s for basic RDF vocabularies.
+//!
+//! The [`rdf`], [`rdfs`] and [`xsd`] vocabularies are always available.
+//! The complete lists of terms of other common vocabularies are available behind features:
+//! `vocab-dcterms`, `vocab-foaf`, `vocab-geosparql`, `vocab-owl`, `vocab-prov`, `vocab-shacl` and `vocab-skos`.
+//! Their modules are generated from the published vocabularies by `generate_vocabularies.py`.
+//!
+//! Without their features, the [`prov`] and [`geosparql`] modules only contain a few terms.
+
+#[cfg(feature = "vocab-dcterms")]
+pub mod dcterms;
+#[cfg(feature = "vocab-foaf")]
+pub mod foaf;
+#[cfg(feature = "vocab-geosparql")]
+pub mod geosparql;
+#[cfg(feature = "vocab-owl")]
+pub mod owl;
+#[cfg(feature = "vocab-prov")]
+pub mod prov;
+#[cfg(feature = "vocab-shacl")]
+pub mod shacl;
+#[cfg(feature = "vocab-skos")]
+pub mod skos;
 
 pub mod rdf {
     //! [RDF](https://www.w3.org/TR/rdf11-concepts/) vocabulary.
@@ -243,11 +265,124 @@ pub mod xsd {
         NamedNodeRef::new_unchecked("http://www.w3.org/2001/XMLSchema#yearMonthDuration");
 }
 
+#[cfg(not(feature = "vocab-prov"))]
+pub mod prov {
+    //! [PROV-O](https://www.w3.org/TR/prov-o/) provenance vocabulary.
+    //!
+    //! Only a few terms are available, enable the `vocab-prov` feature to get all of them.
+    use crate::named_node::NamedNodeRef;
+
+    /// Something that occurs over a period of time and acts upon or with entities.
+    pub const ACTIVITY: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Activity");
+    /// Something that bears some form of responsibility for an activity taking place, for the existence of an entity, or for another agent's activity.
+    pub const AGENT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Agent");
+    /// The time at which an activity ended.
+    pub const ENDED_AT_TIME: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#endedAtTime");
+    /// A physical, digital, conceptual, or other kind of thing with some fixed aspects.
+    pub const ENTITY: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Entity");
+    /// The time at which an entity was completely created and is available for use.
+    pub const GENERATED_AT_TIME: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#generatedAtTime");
+    /// The time at which an activity started.
+    pub const STARTED_AT_TIME: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#startedAtTime");
+    /// An entity used by an activity.
+    pub const USED: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#used");
+    /// An agent that had some role in an activity.
+    pub const WAS_ASSOCIATED_WITH: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasAssociatedWith");
+    /// An agent to which an entity is ascribed.
+    pub const WAS_ATTRIBUTED_TO: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasAttributedTo");
+    /// An entity from which the subject entity has been derived.
+    pub const WAS_DERIVED_FROM: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasDerivedFrom");
+    /// The activity that generated the subject entity.
+    pub const WAS_GENERATED_BY: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasGeneratedBy");
+}
+
+#[cfg(not(feature = "vocab-geosparql"))]
 pub mod geosparql {
     //! [GeoSpatial](https://opengeospatial.github.io/ogc-geosparql/) vocabulary.
+    //!
+    //! Only a few terms are available, enable the `vocab-geosparql` feature to get all of them.
     use crate::named_node::NamedNodeRef;
 
     /// Geospatial datatype like `"Point({longitude} {latitude})"^^geo:wktLiteral`
     pub const WKT_LITERAL: NamedNodeRef<'_> =
         NamedNodeRef::new_unchecked("http://www.opengis.net/ont/geosparql#wktLiteral");
 }
+
+#[cfg(test)]
+#[cfg_attr(
+    not(any(
+        feature = "vocab-dcterms",
+        feature = "vocab-foaf",
+        feature = "vocab-geosparql",
+        feature = "vocab-owl",
+        feature = "vocab-prov",
+        feature = "vocab-shacl",
+        feature = "vocab-skos"
+    )),
+    expect(dead_code)
+)]
+mod tests {
+    use crate::{NamedNode, NamedNodeRef};
+    use std::collections::HashSet;
+
+    fn check_terms(terms: &[NamedNodeRef<'_>]) {
+        let mut seen = HashSet::new();
+        for term in terms {
+            NamedNode::new(term.as_str()).unwrap();
+            assert!(seen.insert(*term), "{term} is defined twice");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-dcterms")]
+    fn dcterms_terms() {
+        check_terms(super::dcterms::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-foaf")]
+    fn foaf_terms() {
+        check_terms(super::foaf::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-geosparql")]
+    fn geosparql_terms() {
+        check_terms(super::geosparql::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-owl")]
+    fn owl_terms() {
+        check_terms(super::owl::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-prov")]
+    fn prov_terms() {
+        check_terms(super::prov::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-shacl")]
+    fn shacl_terms() {
+        check_terms(super::shacl::ALL_TERMS);
+    }
+
+    #[test]
+    #[cfg(feature = "vocab-skos")]
+    fn skos_terms() {
+        check_terms(super::skos::ALL_TERMS);
+    }
+}
@@ -0,0 +1,316 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [OWL 2](https://www.w3.org/TR/owl2-overview/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// AllDifferent
+pub const ALL_DIFFERENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#AllDifferent");
+/// AllDisjointClasses
+pub const ALL_DISJOINT_CLASSES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#AllDisjointClasses");
+/// AllDisjointProperties
+pub const ALL_DISJOINT_PROPERTIES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#AllDisjointProperties");
+/// allValuesFrom
+pub const ALL_VALUES_FROM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#allValuesFrom");
+/// annotatedProperty
+pub const ANNOTATED_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#annotatedProperty");
+/// annotatedSource
+pub const ANNOTATED_SOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#annotatedSource");
+/// annotatedTarget
+pub const ANNOTATED_TARGET: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#annotatedTarget");
+/// Annotation
+pub const ANNOTATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Annotation");
+/// AnnotationProperty
+pub const ANNOTATION_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#AnnotationProperty");
+/// assertionProperty
+pub const ASSERTION_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#assertionProperty");
+/// AsymmetricProperty
+pub const ASYMMETRIC_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#AsymmetricProperty");
+/// Axiom
+pub const AXIOM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Axiom");
+/// backwardCompatibleWith
+pub const BACKWARD_COMPATIBLE_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#backwardCompatibleWith");
+/// bottomDataProperty
+pub const BOTTOM_DATA_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#bottomDataProperty");
+/// bottomObjectProperty
+pub const BOTTOM_OBJECT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#bottomObjectProperty");
+/// cardinality
+pub const CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#cardinality");
+/// Class
+pub const CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Class");
+/// complementOf
+pub const COMPLEMENT_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#complementOf");
+/// datatypeComplementOf
+pub const DATATYPE_COMPLEMENT_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#datatypeComplementOf");
+/// DatatypeProperty
+pub const DATATYPE_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#DatatypeProperty");
+/// DataRange
+pub const DATA_RANGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#DataRange");
+/// deprecated
+pub const DEPRECATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#deprecated");
+/// DeprecatedClass
+pub const DEPRECATED_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#DeprecatedClass");
+/// DeprecatedProperty
+pub const DEPRECATED_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#DeprecatedProperty");
+/// differentFrom
+pub const DIFFERENT_FROM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#differentFrom");
+/// disjointUnionOf
+pub const DISJOINT_UNION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#disjointUnionOf");
+/// disjointWith
+pub const DISJOINT_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#disjointWith");
+/// distinctMembers
+pub const DISTINCT_MEMBERS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#distinctMembers");
+/// equivalentClass
+pub const EQUIVALENT_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#equivalentClass");
+/// equivalentProperty
+pub const EQUIVALENT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#equivalentProperty");
+/// FunctionalProperty
+pub const FUNCTIONAL_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#FunctionalProperty");
+/// hasKey
+pub const HAS_KEY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#hasKey");
+/// hasSelf
+pub const HAS_SELF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#hasSelf");
+/// hasValue
+pub const HAS_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#hasValue");
+/// imports
+pub const IMPORTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#imports");
+/// incompatibleWith
+pub const INCOMPATIBLE_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#incompatibleWith");
+/// intersectionOf
+pub const INTERSECTION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#intersectionOf");
+/// InverseFunctionalProperty
+pub const INVERSE_FUNCTIONAL_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#InverseFunctionalProperty");
+/// inverseOf
+pub const INVERSE_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#inverseOf");
+/// IrreflexiveProperty
+pub const IRREFLEXIVE_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#IrreflexiveProperty");
+/// maxCardinality
+pub const MAX_CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#maxCardinality");
+/// maxQualifiedCardinality
+pub const MAX_QUALIFIED_CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#maxQualifiedCardinality");
+/// members
+pub const MEMBERS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#members");
+/// minCardinality
+pub const MIN_CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#minCardinality");
+/// minQualifiedCardinality
+pub const MIN_QUALIFIED_CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#minQualifiedCardinality");
+/// NamedIndividual
+pub const NAMED_INDIVIDUAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#NamedIndividual");
+/// NegativePropertyAssertion
+pub const NEGATIVE_PROPERTY_ASSERTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#NegativePropertyAssertion");
+/// Nothing
+pub const NOTHING: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Nothing");
+/// ObjectProperty
+pub const OBJECT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#ObjectProperty");
+/// oneOf
+pub const ONE_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#oneOf");
+/// Ontology
+pub const ONTOLOGY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Ontology");
+/// OntologyProperty
+pub const ONTOLOGY_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#OntologyProperty");
+/// onClass
+pub const ON_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#onClass");
+/// onDatatype
+pub const ON_DATATYPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#onDatatype");
+/// onDataRange
+pub const ON_DATA_RANGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#onDataRange");
+/// onProperties
+pub const ON_PROPERTIES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#onProperties");
+/// onProperty
+pub const ON_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#onProperty");
+/// priorVersion
+pub const PRIOR_VERSION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#priorVersion");
+/// propertyChainAxiom
+pub const PROPERTY_CHAIN_AXIOM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#propertyChainAxiom");
+/// propertyDisjointWith
+pub const PROPERTY_DISJOINT_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#propertyDisjointWith");
+/// qualifiedCardinality
+pub const QUALIFIED_CARDINALITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#qualifiedCardinality");
+/// ReflexiveProperty
+pub const REFLEXIVE_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#ReflexiveProperty");
+/// Restriction
+pub const RESTRICTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Restriction");
+/// sameAs
+pub const SAME_AS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#sameAs");
+/// someValuesFrom
+pub const SOME_VALUES_FROM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#someValuesFrom");
+/// sourceIndividual
+pub const SOURCE_INDIVIDUAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#sourceIndividual");
+/// SymmetricProperty
+pub const SYMMETRIC_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#SymmetricProperty");
+/// targetIndividual
+pub const TARGET_INDIVIDUAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#targetIndividual");
+/// targetValue
+pub const TARGET_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#targetValue");
+/// Thing
+pub const THING: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#Thing");
+/// topDataProperty
+pub const TOP_DATA_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#topDataProperty");
+/// topObjectProperty
+pub const TOP_OBJECT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#topObjectProperty");
+/// TransitiveProperty
+pub const TRANSITIVE_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#TransitiveProperty");
+/// unionOf
+pub const UNION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#unionOf");
+/// versionInfo
+pub const VERSION_INFO: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#versionInfo");
+/// versionIRI
+pub const VERSION_IRI: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#versionIRI");
+/// withRestrictions
+pub const WITH_RESTRICTIONS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2002/07/owl#withRestrictions");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ALL_DIFFERENT,
+    ALL_DISJOINT_CLASSES,
+    ALL_DISJOINT_PROPERTIES,
+    ALL_VALUES_FROM,
+    ANNOTATED_PROPERTY,
+    ANNOTATED_SOURCE,
+    ANNOTATED_TARGET,
+    ANNOTATION,
+    ANNOTATION_PROPERTY,
+    ASSERTION_PROPERTY,
+    ASYMMETRIC_PROPERTY,
+    AXIOM,
+    BACKWARD_COMPATIBLE_WITH,
+    BOTTOM_DATA_PROPERTY,
+    BOTTOM_OBJECT_PROPERTY,
+    CARDINALITY,
+    CLASS,
+    COMPLEMENT_OF,
+    DATATYPE_COMPLEMENT_OF,
+    DATATYPE_PROPERTY,
+    DATA_RANGE,
+    DEPRECATED,
+    DEPRECATED_CLASS,
+    DEPRECATED_PROPERTY,
+    DIFFERENT_FROM,
+    DISJOINT_UNION_OF,
+    DISJOINT_WITH,
+    DISTINCT_MEMBERS,
+    EQUIVALENT_CLASS,
+    EQUIVALENT_PROPERTY,
+    FUNCTIONAL_PROPERTY,
+    HAS_KEY,
+    HAS_SELF,
+    HAS_VALUE,
+    IMPORTS,
+    INCOMPATIBLE_WITH,
+    INTERSECTION_OF,
+    INVERSE_FUNCTIONAL_PROPERTY,
+    INVERSE_OF,
+    IRREFLEXIVE_PROPERTY,
+    MAX_CARDINALITY,
+    MAX_QUALIFIED_CARDINALITY,
+    MEMBERS,
+    MIN_CARDINALITY,
+    MIN_QUALIFIED_CARDINALITY,
+    NAMED_INDIVIDUAL,
+    NEGATIVE_PROPERTY_ASSERTION,
+    NOTHING,
+    OBJECT_PROPERTY,
+    ONE_OF,
+    ONTOLOGY,
+    ONTOLOGY_PROPERTY,
+    ON_CLASS,
+    ON_DATATYPE,
+    ON_DATA_RANGE,
+    ON_PROPERTIES,
+    ON_PROPERTY,
+    PRIOR_VERSION,
+    PROPERTY_CHAIN_AXIOM,
+    PROPERTY_DISJOINT_WITH,
+    QUALIFIED_CARDINALITY,
+    REFLEXIVE_PROPERTY,
+    RESTRICTION,
+    SAME_AS,
+    SOME_VALUES_FROM,
+    SOURCE_INDIVIDUAL,
+    SYMMETRIC_PROPERTY,
+    TARGET_INDIVIDUAL,
+    TARGET_VALUE,
+    THING,
+    TOP_DATA_PROPERTY,
+    TOP_OBJECT_PROPERTY,
+    TRANSITIVE_PROPERTY,
+    UNION_OF,
+    VERSION_INFO,
+    VERSION_IRI,
+    WITH_RESTRICTIONS,
+];
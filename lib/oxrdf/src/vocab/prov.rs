@@ -0,0 +1,372 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [PROV-O](https://www.w3.org/TR/prov-o/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// actedOnBehalfOf
+pub const ACTED_ON_BEHALF_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#actedOnBehalfOf");
+/// Activity
+pub const ACTIVITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Activity");
+/// ActivityInfluence
+pub const ACTIVITY_INFLUENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#ActivityInfluence");
+/// activity
+pub const ACTIVITY_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#activity");
+/// Agent
+pub const AGENT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Agent");
+/// AgentInfluence
+pub const AGENT_INFLUENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#AgentInfluence");
+/// agent
+pub const AGENT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#agent");
+/// alternateOf
+pub const ALTERNATE_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#alternateOf");
+/// Association
+pub const ASSOCIATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Association");
+/// Attribution
+pub const ATTRIBUTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Attribution");
+/// atLocation
+pub const AT_LOCATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#atLocation");
+/// atTime
+pub const AT_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#atTime");
+/// Bundle
+pub const BUNDLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Bundle");
+/// category
+pub const CATEGORY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#category");
+/// Collection
+pub const COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Collection");
+/// Communication
+pub const COMMUNICATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Communication");
+/// component
+pub const COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#component");
+/// constraints
+pub const CONSTRAINTS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#constraints");
+/// definition
+pub const DEFINITION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#definition");
+/// Delegation
+pub const DELEGATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Delegation");
+/// Derivation
+pub const DERIVATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Derivation");
+/// dm
+pub const DM: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#dm");
+/// editorialNote
+pub const EDITORIAL_NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#editorialNote");
+/// editorsDefinition
+pub const EDITORS_DEFINITION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#editorsDefinition");
+/// EmptyCollection
+pub const EMPTY_COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#EmptyCollection");
+/// End
+pub const END: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#End");
+/// endedAtTime
+pub const ENDED_AT_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#endedAtTime");
+/// Entity
+pub const ENTITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Entity");
+/// EntityInfluence
+pub const ENTITY_INFLUENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#EntityInfluence");
+/// entity
+pub const ENTITY_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#entity");
+/// generated
+pub const GENERATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#generated");
+/// generatedAtTime
+pub const GENERATED_AT_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#generatedAtTime");
+/// Generation
+pub const GENERATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Generation");
+/// hadActivity
+pub const HAD_ACTIVITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadActivity");
+/// hadGeneration
+pub const HAD_GENERATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadGeneration");
+/// hadMember
+pub const HAD_MEMBER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadMember");
+/// hadPlan
+pub const HAD_PLAN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadPlan");
+/// hadPrimarySource
+pub const HAD_PRIMARY_SOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadPrimarySource");
+/// hadRole
+pub const HAD_ROLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadRole");
+/// hadUsage
+pub const HAD_USAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#hadUsage");
+/// Influence
+pub const INFLUENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Influence");
+/// influenced
+pub const INFLUENCED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#influenced");
+/// influencer
+pub const INFLUENCER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#influencer");
+/// InstantaneousEvent
+pub const INSTANTANEOUS_EVENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#InstantaneousEvent");
+/// invalidated
+pub const INVALIDATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#invalidated");
+/// invalidatedAtTime
+pub const INVALIDATED_AT_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#invalidatedAtTime");
+/// Invalidation
+pub const INVALIDATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Invalidation");
+/// inverse
+pub const INVERSE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#inverse");
+/// Location
+pub const LOCATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Location");
+/// n
+pub const N: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#n");
+/// order
+pub const ORDER: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#order");
+/// Organization
+pub const ORGANIZATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Organization");
+/// Person
+pub const PERSON: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Person");
+/// Plan
+pub const PLAN: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Plan");
+/// PrimarySource
+pub const PRIMARY_SOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#PrimarySource");
+/// qualifiedAssociation
+pub const QUALIFIED_ASSOCIATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedAssociation");
+/// qualifiedAttribution
+pub const QUALIFIED_ATTRIBUTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedAttribution");
+/// qualifiedCommunication
+pub const QUALIFIED_COMMUNICATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedCommunication");
+/// qualifiedDelegation
+pub const QUALIFIED_DELEGATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedDelegation");
+/// qualifiedDerivation
+pub const QUALIFIED_DERIVATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedDerivation");
+/// qualifiedEnd
+pub const QUALIFIED_END: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedEnd");
+/// qualifiedForm
+pub const QUALIFIED_FORM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedForm");
+/// qualifiedGeneration
+pub const QUALIFIED_GENERATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedGeneration");
+/// qualifiedInfluence
+pub const QUALIFIED_INFLUENCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedInfluence");
+/// qualifiedInvalidation
+pub const QUALIFIED_INVALIDATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedInvalidation");
+/// qualifiedPrimarySource
+pub const QUALIFIED_PRIMARY_SOURCE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedPrimarySource");
+/// qualifiedQuotation
+pub const QUALIFIED_QUOTATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedQuotation");
+/// qualifiedRevision
+pub const QUALIFIED_REVISION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedRevision");
+/// qualifiedStart
+pub const QUALIFIED_START: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedStart");
+/// qualifiedUsage
+pub const QUALIFIED_USAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#qualifiedUsage");
+/// Quotation
+pub const QUOTATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Quotation");
+/// Revision
+pub const REVISION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Revision");
+/// Role
+pub const ROLE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Role");
+/// sharesDefinitionWith
+pub const SHARES_DEFINITION_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#sharesDefinitionWith");
+/// SoftwareAgent
+pub const SOFTWARE_AGENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#SoftwareAgent");
+/// specializationOf
+pub const SPECIALIZATION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#specializationOf");
+/// Start
+pub const START: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Start");
+/// startedAtTime
+pub const STARTED_AT_TIME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#startedAtTime");
+/// todo
+pub const TODO: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#todo");
+/// unqualifiedForm
+pub const UNQUALIFIED_FORM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#unqualifiedForm");
+/// Usage
+pub const USAGE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#Usage");
+/// used
+pub const USED: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#used");
+/// value
+pub const VALUE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#value");
+/// wasAssociatedWith
+pub const WAS_ASSOCIATED_WITH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasAssociatedWith");
+/// wasAttributedTo
+pub const WAS_ATTRIBUTED_TO: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasAttributedTo");
+/// wasDerivedFrom
+pub const WAS_DERIVED_FROM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasDerivedFrom");
+/// wasEndedBy
+pub const WAS_ENDED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasEndedBy");
+/// wasGeneratedBy
+pub const WAS_GENERATED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasGeneratedBy");
+/// wasInfluencedBy
+pub const WAS_INFLUENCED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasInfluencedBy");
+/// wasInformedBy
+pub const WAS_INFORMED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasInformedBy");
+/// wasInvalidatedBy
+pub const WAS_INVALIDATED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasInvalidatedBy");
+/// wasQuotedFrom
+pub const WAS_QUOTED_FROM: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasQuotedFrom");
+/// wasRevisionOf
+pub const WAS_REVISION_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasRevisionOf");
+/// wasStartedBy
+pub const WAS_STARTED_BY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/prov#wasStartedBy");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ACTED_ON_BEHALF_OF,
+    ACTIVITY,
+    ACTIVITY_INFLUENCE,
+    ACTIVITY_PROPERTY,
+    AGENT,
+    AGENT_INFLUENCE,
+    AGENT_PROPERTY,
+    ALTERNATE_OF,
+    ASSOCIATION,
+    ATTRIBUTION,
+    AT_LOCATION,
+    AT_TIME,
+    BUNDLE,
+    CATEGORY,
+    COLLECTION,
+    COMMUNICATION,
+    COMPONENT,
+    CONSTRAINTS,
+    DEFINITION,
+    DELEGATION,
+    DERIVATION,
+    DM,
+    EDITORIAL_NOTE,
+    EDITORS_DEFINITION,
+    EMPTY_COLLECTION,
+    END,
+    ENDED_AT_TIME,
+    ENTITY,
+    ENTITY_INFLUENCE,
+    ENTITY_PROPERTY,
+    GENERATED,
+    GENERATED_AT_TIME,
+    GENERATION,
+    HAD_ACTIVITY,
+    HAD_GENERATION,
+    HAD_MEMBER,
+    HAD_PLAN,
+    HAD_PRIMARY_SOURCE,
+    HAD_ROLE,
+    HAD_USAGE,
+    INFLUENCE,
+    INFLUENCED,
+    INFLUENCER,
+    INSTANTANEOUS_EVENT,
+    INVALIDATED,
+    INVALIDATED_AT_TIME,
+    INVALIDATION,
+    INVERSE,
+    LOCATION,
+    N,
+    ORDER,
+    ORGANIZATION,
+    PERSON,
+    PLAN,
+    PRIMARY_SOURCE,
+    QUALIFIED_ASSOCIATION,
+    QUALIFIED_ATTRIBUTION,
+    QUALIFIED_COMMUNICATION,
+    QUALIFIED_DELEGATION,
+    QUALIFIED_DERIVATION,
+    QUALIFIED_END,
+    QUALIFIED_FORM,
+    QUALIFIED_GENERATION,
+    QUALIFIED_INFLUENCE,
+    QUALIFIED_INVALIDATION,
+    QUALIFIED_PRIMARY_SOURCE,
+    QUALIFIED_QUOTATION,
+    QUALIFIED_REVISION,
+    QUALIFIED_START,
+    QUALIFIED_USAGE,
+    QUOTATION,
+    REVISION,
+    ROLE,
+    SHARES_DEFINITION_WITH,
+    SOFTWARE_AGENT,
+    SPECIALIZATION_OF,
+    START,
+    STARTED_AT_TIME,
+    TODO,
+    UNQUALIFIED_FORM,
+    USAGE,
+    USED,
+    VALUE,
+    WAS_ASSOCIATED_WITH,
+    WAS_ATTRIBUTED_TO,
+    WAS_DERIVED_FROM,
+    WAS_ENDED_BY,
+    WAS_GENERATED_BY,
+    WAS_INFLUENCED_BY,
+    WAS_INFORMED_BY,
+    WAS_INVALIDATED_BY,
+    WAS_QUOTED_FROM,
+    WAS_REVISION_OF,
+    WAS_STARTED_BY,
+];
@@ -0,0 +1,740 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [SHACL](https://www.w3.org/TR/shacl/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// Abstract result
+pub const ABSTRACT_RESULT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#AbstractResult");
+/// alternative path
+pub const ALTERNATIVE_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#alternativePath");
+/// and
+pub const AND: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#and");
+/// And constraint component
+pub const AND_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#AndConstraintComponent");
+/// AndConstraintComponent-and
+pub const AND_CONSTRAINT_COMPONENT_AND: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#AndConstraintComponent-and");
+/// ask
+pub const ASK: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ask");
+/// Blank node
+pub const BLANK_NODE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#BlankNode");
+/// Blank node or IRI
+pub const BLANK_NODE_OR_IRI: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#BlankNodeOrIRI");
+/// Blank node or literal
+pub const BLANK_NODE_OR_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#BlankNodeOrLiteral");
+/// class
+pub const CLASS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#class");
+/// Class constraint component
+pub const CLASS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ClassConstraintComponent");
+/// ClassConstraintComponent-class
+pub const CLASS_CONSTRAINT_COMPONENT_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ClassConstraintComponent-class");
+/// closed
+pub const CLOSED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#closed");
+/// Closed constraint component
+pub const CLOSED_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ClosedConstraintComponent");
+/// ClosedConstraintComponent-closed
+pub const CLOSED_CONSTRAINT_COMPONENT_CLOSED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ClosedConstraintComponent-closed");
+/// ClosedConstraintComponent-ignoredProperties
+pub const CLOSED_CONSTRAINT_COMPONENT_IGNORED_PROPERTIES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#ClosedConstraintComponent-ignoredProperties",
+    );
+/// conforms
+pub const CONFORMS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#conforms");
+/// Constraint component
+pub const CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ConstraintComponent");
+/// construct
+pub const CONSTRUCT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#construct");
+/// datatype
+pub const DATATYPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#datatype");
+/// Datatype constraint component
+pub const DATATYPE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#DatatypeConstraintComponent");
+/// DatatypeConstraintComponent-datatype
+pub const DATATYPE_CONSTRAINT_COMPONENT_DATATYPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#DatatypeConstraintComponent-datatype");
+/// deactivated
+pub const DEACTIVATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#deactivated");
+/// declare
+pub const DECLARE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#declare");
+/// default value
+pub const DEFAULT_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#defaultValue");
+/// description
+pub const DESCRIPTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#description");
+/// detail
+pub const DETAIL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#detail");
+/// disjoint
+pub const DISJOINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#disjoint");
+/// Disjoint constraint component
+pub const DISJOINT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#DisjointConstraintComponent");
+/// DisjointConstraintComponent-disjoint
+pub const DISJOINT_CONSTRAINT_COMPONENT_DISJOINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#DisjointConstraintComponent-disjoint");
+/// entailment
+pub const ENTAILMENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#entailment");
+/// equals
+pub const EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#equals");
+/// Equals constraint component
+pub const EQUALS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#EqualsConstraintComponent");
+/// EqualsConstraintComponent-equals
+pub const EQUALS_CONSTRAINT_COMPONENT_EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#EqualsConstraintComponent-equals");
+/// flags
+pub const FLAGS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#flags");
+/// focus node
+pub const FOCUS_NODE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#focusNode");
+/// group
+pub const GROUP: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#group");
+/// has value
+pub const HAS_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#hasValue");
+/// Has value constraint component
+pub const HAS_VALUE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#HasValueConstraintComponent");
+/// HasValueConstraintComponent-hasValue
+pub const HAS_VALUE_CONSTRAINT_COMPONENT_HAS_VALUE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#HasValueConstraintComponent-hasValue");
+/// ignored properties
+pub const IGNORED_PROPERTIES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ignoredProperties");
+/// in
+pub const IN: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#in");
+/// Info
+pub const INFO: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Info");
+/// inverse path
+pub const INVERSE_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#inversePath");
+/// In constraint component
+pub const IN_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#InConstraintComponent");
+/// InConstraintComponent-in
+pub const IN_CONSTRAINT_COMPONENT_IN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#InConstraintComponent-in");
+/// IRI
+pub const IRI: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#IRI");
+/// IRI or literal
+pub const IRI_OR_LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#IRIOrLiteral");
+/// label template
+pub const LABEL_TEMPLATE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#labelTemplate");
+/// language in
+pub const LANGUAGE_IN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#languageIn");
+/// Language in constraint component
+pub const LANGUAGE_IN_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#LanguageInConstraintComponent");
+/// LanguageInConstraintComponent-languageIn
+pub const LANGUAGE_IN_CONSTRAINT_COMPONENT_LANGUAGE_IN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#LanguageInConstraintComponent-languageIn",
+    );
+/// less than
+pub const LESS_THAN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#lessThan");
+/// Less than constraint component
+pub const LESS_THAN_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#LessThanConstraintComponent");
+/// LessThanConstraintComponent-lessThan
+pub const LESS_THAN_CONSTRAINT_COMPONENT_LESS_THAN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#LessThanConstraintComponent-lessThan");
+/// less than or equals
+pub const LESS_THAN_OR_EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#lessThanOrEquals");
+/// Less than or equals constraint component
+pub const LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#LessThanOrEqualsConstraintComponent");
+/// LessThanOrEqualsConstraintComponent-lessThanOrEquals
+pub const LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT_LESS_THAN_OR_EQUALS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#LessThanOrEqualsConstraintComponent-lessThanOrEquals",
+    );
+/// Literal
+pub const LITERAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Literal");
+/// max count
+pub const MAX_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#maxCount");
+/// Max count constraint component
+pub const MAX_COUNT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MaxCountConstraintComponent");
+/// MaxCountConstraintComponent-maxCount
+pub const MAX_COUNT_CONSTRAINT_COMPONENT_MAX_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MaxCountConstraintComponent-maxCount");
+/// max exclusive
+pub const MAX_EXCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#maxExclusive");
+/// Max exclusive constraint component
+pub const MAX_EXCLUSIVE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MaxExclusiveConstraintComponent");
+/// MaxExclusiveConstraintComponent-maxExclusive
+pub const MAX_EXCLUSIVE_CONSTRAINT_COMPONENT_MAX_EXCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MaxExclusiveConstraintComponent-maxExclusive",
+    );
+/// max inclusive
+pub const MAX_INCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#maxInclusive");
+/// Max inclusive constraint component
+pub const MAX_INCLUSIVE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MaxInclusiveConstraintComponent");
+/// MaxInclusiveConstraintComponent-maxInclusive
+pub const MAX_INCLUSIVE_CONSTRAINT_COMPONENT_MAX_INCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MaxInclusiveConstraintComponent-maxInclusive",
+    );
+/// max length
+pub const MAX_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#maxLength");
+/// Max length constraint component
+pub const MAX_LENGTH_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MaxLengthConstraintComponent");
+/// MaxLengthConstraintComponent-maxLength
+pub const MAX_LENGTH_CONSTRAINT_COMPONENT_MAX_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MaxLengthConstraintComponent-maxLength",
+    );
+/// message
+pub const MESSAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#message");
+/// min count
+pub const MIN_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#minCount");
+/// Min count constraint component
+pub const MIN_COUNT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MinCountConstraintComponent");
+/// MinCountConstraintComponent-minCount
+pub const MIN_COUNT_CONSTRAINT_COMPONENT_MIN_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MinCountConstraintComponent-minCount");
+/// min exclusive
+pub const MIN_EXCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#minExclusive");
+/// Min exclusive constraint component
+pub const MIN_EXCLUSIVE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MinExclusiveConstraintComponent");
+/// MinExclusiveConstraintComponent-minExclusive
+pub const MIN_EXCLUSIVE_CONSTRAINT_COMPONENT_MIN_EXCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MinExclusiveConstraintComponent-minExclusive",
+    );
+/// min inclusive
+pub const MIN_INCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#minInclusive");
+/// Min inclusive constraint component
+pub const MIN_INCLUSIVE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MinInclusiveConstraintComponent");
+/// MinInclusiveConstraintComponent-minInclusive
+pub const MIN_INCLUSIVE_CONSTRAINT_COMPONENT_MIN_INCLUSIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MinInclusiveConstraintComponent-minInclusive",
+    );
+/// min length
+pub const MIN_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#minLength");
+/// Min length constraint component
+pub const MIN_LENGTH_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#MinLengthConstraintComponent");
+/// MinLengthConstraintComponent-minLength
+pub const MIN_LENGTH_CONSTRAINT_COMPONENT_MIN_LENGTH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#MinLengthConstraintComponent-minLength",
+    );
+/// name
+pub const NAME: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#name");
+/// namespace
+pub const NAMESPACE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#namespace");
+/// node
+pub const NODE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#node");
+/// Node constraint component
+pub const NODE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeConstraintComponent");
+/// NodeConstraintComponent-node
+pub const NODE_CONSTRAINT_COMPONENT_NODE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeConstraintComponent-node");
+/// Node kind
+pub const NODE_KIND: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeKind");
+/// Node kind constraint component
+pub const NODE_KIND_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeKindConstraintComponent");
+/// NodeKindConstraintComponent-nodeKind
+pub const NODE_KIND_CONSTRAINT_COMPONENT_NODE_KIND: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeKindConstraintComponent-nodeKind");
+/// node kind
+pub const NODE_KIND_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#nodeKind");
+/// Node shape
+pub const NODE_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NodeShape");
+/// shape validator
+pub const NODE_VALIDATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#nodeValidator");
+/// not
+pub const NOT: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#not");
+/// Not constraint component
+pub const NOT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NotConstraintComponent");
+/// NotConstraintComponent-not
+pub const NOT_CONSTRAINT_COMPONENT_NOT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#NotConstraintComponent-not");
+/// one or more path
+pub const ONE_OR_MORE_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#oneOrMorePath");
+/// optional
+pub const OPTIONAL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#optional");
+/// or
+pub const OR: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#or");
+/// order
+pub const ORDER: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#order");
+/// Or constraint component
+pub const OR_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#OrConstraintComponent");
+/// OrConstraintComponent-or
+pub const OR_CONSTRAINT_COMPONENT_OR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#OrConstraintComponent-or");
+/// Parameter
+pub const PARAMETER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Parameter");
+/// Parameterizable
+pub const PARAMETERIZABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Parameterizable");
+/// parameter
+pub const PARAMETER_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#parameter");
+/// path
+pub const PATH: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#path");
+/// pattern
+pub const PATTERN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#pattern");
+/// Pattern constraint component
+pub const PATTERN_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PatternConstraintComponent");
+/// PatternConstraintComponent-flags
+pub const PATTERN_CONSTRAINT_COMPONENT_FLAGS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PatternConstraintComponent-flags");
+/// PatternConstraintComponent-pattern
+pub const PATTERN_CONSTRAINT_COMPONENT_PATTERN: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PatternConstraintComponent-pattern");
+/// prefix
+pub const PREFIX: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#prefix");
+/// prefixes
+pub const PREFIXES: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#prefixes");
+/// Prefix declaration
+pub const PREFIX_DECLARATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PrefixDeclaration");
+/// property
+pub const PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#property");
+/// Property constraint component
+pub const PROPERTY_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PropertyConstraintComponent");
+/// PropertyConstraintComponent-property
+pub const PROPERTY_CONSTRAINT_COMPONENT_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PropertyConstraintComponent-property");
+/// Property group
+pub const PROPERTY_GROUP: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PropertyGroup");
+/// Property shape
+pub const PROPERTY_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#PropertyShape");
+/// property validator
+pub const PROPERTY_VALIDATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#propertyValidator");
+/// qualified maximum count
+pub const QUALIFIED_MAX_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#qualifiedMaxCount");
+/// Qualified max count constraint component
+pub const QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#QualifiedMaxCountConstraintComponent");
+/// QualifiedMaxCountConstraintComponent-qualifiedMaxCount
+pub const QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_MAX_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#QualifiedMaxCountConstraintComponent-qualifiedMaxCount",
+    );
+/// QualifiedMaxCountConstraintComponent-qualifiedValueShape
+pub const QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#QualifiedMaxCountConstraintComponent-qualifiedValueShape",
+    );
+/// QualifiedMaxCountConstraintComponent-qualifiedValueShapesDisjoint
+pub const QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPES_DISJOINT: NamedNodeRef<
+    '_,
+> = NamedNodeRef::new_unchecked(
+    "http://www.w3.org/ns/shacl#QualifiedMaxCountConstraintComponent-qualifiedValueShapesDisjoint",
+);
+/// qualified minimum count
+pub const QUALIFIED_MIN_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#qualifiedMinCount");
+/// Qualified min count constraint component
+pub const QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#QualifiedMinCountConstraintComponent");
+/// QualifiedMinCountConstraintComponent-qualifiedMinCount
+pub const QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_MIN_COUNT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#QualifiedMinCountConstraintComponent-qualifiedMinCount",
+    );
+/// QualifiedMinCountConstraintComponent-qualifiedValueShape
+pub const QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#QualifiedMinCountConstraintComponent-qualifiedValueShape",
+    );
+/// QualifiedMinCountConstraintComponent-qualifiedValueShapesDisjoint
+pub const QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPES_DISJOINT: NamedNodeRef<
+    '_,
+> = NamedNodeRef::new_unchecked(
+    "http://www.w3.org/ns/shacl#QualifiedMinCountConstraintComponent-qualifiedValueShapesDisjoint",
+);
+/// qualified value shape
+pub const QUALIFIED_VALUE_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#qualifiedValueShape");
+/// qualified value shapes disjoint
+pub const QUALIFIED_VALUE_SHAPES_DISJOINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#qualifiedValueShapesDisjoint");
+/// result
+pub const RESULT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#result");
+/// result message
+pub const RESULT_MESSAGE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#resultMessage");
+/// result path
+pub const RESULT_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#resultPath");
+/// result severity
+pub const RESULT_SEVERITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#resultSeverity");
+/// select
+pub const SELECT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#select");
+/// Severity
+pub const SEVERITY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Severity");
+/// severity
+pub const SEVERITY_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#severity");
+/// Shape
+pub const SHAPE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Shape");
+/// shapes graph
+pub const SHAPES_GRAPH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#shapesGraph");
+/// shapes graph well-formed
+pub const SHAPES_GRAPH_WELL_FORMED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#shapesGraphWellFormed");
+/// source constraint
+pub const SOURCE_CONSTRAINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#sourceConstraint");
+/// source constraint component
+pub const SOURCE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#sourceConstraintComponent");
+/// source shape
+pub const SOURCE_SHAPE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#sourceShape");
+/// constraint (in SPARQL)
+pub const SPARQL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#sparql");
+/// SPARQL ASK executable
+pub const SPARQL_ASK_EXECUTABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLAskExecutable");
+/// SPARQL ASK validator
+pub const SPARQL_ASK_VALIDATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLAskValidator");
+/// SPARQL constraint
+pub const SPARQL_CONSTRAINT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLConstraint");
+/// SPARQL constraint component
+pub const SPARQL_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLConstraintComponent");
+/// SPARQLConstraintComponent-sparql
+pub const SPARQL_CONSTRAINT_COMPONENT_SPARQL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLConstraintComponent-sparql");
+/// SPARQL CONSTRUCT executable
+pub const SPARQL_CONSTRUCT_EXECUTABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLConstructExecutable");
+/// SPARQL executable
+pub const SPARQL_EXECUTABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLExecutable");
+/// SPARQL SELECT executable
+pub const SPARQL_SELECT_EXECUTABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLSelectExecutable");
+/// SPARQL SELECT validator
+pub const SPARQL_SELECT_VALIDATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLSelectValidator");
+/// SPARQL UPDATE executable
+pub const SPARQL_UPDATE_EXECUTABLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#SPARQLUpdateExecutable");
+/// suggested shapes graph
+pub const SUGGESTED_SHAPES_GRAPH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#suggestedShapesGraph");
+/// target class
+pub const TARGET_CLASS: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#targetClass");
+/// target node
+pub const TARGET_NODE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#targetNode");
+/// target objects of
+pub const TARGET_OBJECTS_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#targetObjectsOf");
+/// target subjects of
+pub const TARGET_SUBJECTS_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#targetSubjectsOf");
+/// this
+pub const THIS: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#this");
+/// unique languages
+pub const UNIQUE_LANG: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#uniqueLang");
+/// Unique lang constraint component
+pub const UNIQUE_LANG_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#UniqueLangConstraintComponent");
+/// UniqueLangConstraintComponent-uniqueLang
+pub const UNIQUE_LANG_CONSTRAINT_COMPONENT_UNIQUE_LANG: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked(
+        "http://www.w3.org/ns/shacl#UniqueLangConstraintComponent-uniqueLang",
+    );
+/// update
+pub const UPDATE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#update");
+/// Validation report
+pub const VALIDATION_REPORT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ValidationReport");
+/// Validation result
+pub const VALIDATION_RESULT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ValidationResult");
+/// Validator
+pub const VALIDATOR: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Validator");
+/// validator
+pub const VALIDATOR_PROPERTY: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#validator");
+/// value
+pub const VALUE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#value");
+/// Violation
+pub const VIOLATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Violation");
+/// Warning
+pub const WARNING: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#Warning");
+/// exactly one
+pub const XONE: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#xone");
+/// Xone constraint component
+pub const XONE_CONSTRAINT_COMPONENT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#XoneConstraintComponent");
+/// XoneConstraintComponent-xone
+pub const XONE_CONSTRAINT_COMPONENT_XONE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#XoneConstraintComponent-xone");
+/// zero or more path
+pub const ZERO_OR_MORE_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#zeroOrMorePath");
+/// zero or one path
+pub const ZERO_OR_ONE_PATH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#zeroOrOnePath");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ABSTRACT_RESULT,
+    ALTERNATIVE_PATH,
+    AND,
+    AND_CONSTRAINT_COMPONENT,
+    AND_CONSTRAINT_COMPONENT_AND,
+    ASK,
+    BLANK_NODE,
+    BLANK_NODE_OR_IRI,
+    BLANK_NODE_OR_LITERAL,
+    CLASS,
+    CLASS_CONSTRAINT_COMPONENT,
+    CLASS_CONSTRAINT_COMPONENT_CLASS,
+    CLOSED,
+    CLOSED_CONSTRAINT_COMPONENT,
+    CLOSED_CONSTRAINT_COMPONENT_CLOSED,
+    CLOSED_CONSTRAINT_COMPONENT_IGNORED_PROPERTIES,
+    CONFORMS,
+    CONSTRAINT_COMPONENT,
+    CONSTRUCT,
+    DATATYPE,
+    DATATYPE_CONSTRAINT_COMPONENT,
+    DATATYPE_CONSTRAINT_COMPONENT_DATATYPE,
+    DEACTIVATED,
+    DECLARE,
+    DEFAULT_VALUE,
+    DESCRIPTION,
+    DETAIL,
+    DISJOINT,
+    DISJOINT_CONSTRAINT_COMPONENT,
+    DISJOINT_CONSTRAINT_COMPONENT_DISJOINT,
+    ENTAILMENT,
+    EQUALS,
+    EQUALS_CONSTRAINT_COMPONENT,
+    EQUALS_CONSTRAINT_COMPONENT_EQUALS,
+    FLAGS,
+    FOCUS_NODE,
+    GROUP,
+    HAS_VALUE,
+    HAS_VALUE_CONSTRAINT_COMPONENT,
+    HAS_VALUE_CONSTRAINT_COMPONENT_HAS_VALUE,
+    IGNORED_PROPERTIES,
+    IN,
+    INFO,
+    INVERSE_PATH,
+    IN_CONSTRAINT_COMPONENT,
+    IN_CONSTRAINT_COMPONENT_IN,
+    IRI,
+    IRI_OR_LITERAL,
+    LABEL_TEMPLATE,
+    LANGUAGE_IN,
+    LANGUAGE_IN_CONSTRAINT_COMPONENT,
+    LANGUAGE_IN_CONSTRAINT_COMPONENT_LANGUAGE_IN,
+    LESS_THAN,
+    LESS_THAN_CONSTRAINT_COMPONENT,
+    LESS_THAN_CONSTRAINT_COMPONENT_LESS_THAN,
+    LESS_THAN_OR_EQUALS,
+    LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT,
+    LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT_LESS_THAN_OR_EQUALS,
+    LITERAL,
+    MAX_COUNT,
+    MAX_COUNT_CONSTRAINT_COMPONENT,
+    MAX_COUNT_CONSTRAINT_COMPONENT_MAX_COUNT,
+    MAX_EXCLUSIVE,
+    MAX_EXCLUSIVE_CONSTRAINT_COMPONENT,
+    MAX_EXCLUSIVE_CONSTRAINT_COMPONENT_MAX_EXCLUSIVE,
+    MAX_INCLUSIVE,
+    MAX_INCLUSIVE_CONSTRAINT_COMPONENT,
+    MAX_INCLUSIVE_CONSTRAINT_COMPONENT_MAX_INCLUSIVE,
+    MAX_LENGTH,
+    MAX_LENGTH_CONSTRAINT_COMPONENT,
+    MAX_LENGTH_CONSTRAINT_COMPONENT_MAX_LENGTH,
+    MESSAGE,
+    MIN_COUNT,
+    MIN_COUNT_CONSTRAINT_COMPONENT,
+    MIN_COUNT_CONSTRAINT_COMPONENT_MIN_COUNT,
+    MIN_EXCLUSIVE,
+    MIN_EXCLUSIVE_CONSTRAINT_COMPONENT,
+    MIN_EXCLUSIVE_CONSTRAINT_COMPONENT_MIN_EXCLUSIVE,
+    MIN_INCLUSIVE,
+    MIN_INCLUSIVE_CONSTRAINT_COMPONENT,
+    MIN_INCLUSIVE_CONSTRAINT_COMPONENT_MIN_INCLUSIVE,
+    MIN_LENGTH,
+    MIN_LENGTH_CONSTRAINT_COMPONENT,
+    MIN_LENGTH_CONSTRAINT_COMPONENT_MIN_LENGTH,
+    NAME,
+    NAMESPACE,
+    NODE,
+    NODE_CONSTRAINT_COMPONENT,
+    NODE_CONSTRAINT_COMPONENT_NODE,
+    NODE_KIND,
+    NODE_KIND_CONSTRAINT_COMPONENT,
+    NODE_KIND_CONSTRAINT_COMPONENT_NODE_KIND,
+    NODE_KIND_PROPERTY,
+    NODE_SHAPE,
+    NODE_VALIDATOR,
+    NOT,
+    NOT_CONSTRAINT_COMPONENT,
+    NOT_CONSTRAINT_COMPONENT_NOT,
+    ONE_OR_MORE_PATH,
+    OPTIONAL,
+    OR,
+    ORDER,
+    OR_CONSTRAINT_COMPONENT,
+    OR_CONSTRAINT_COMPONENT_OR,
+    PARAMETER,
+    PARAMETERIZABLE,
+    PARAMETER_PROPERTY,
+    PATH,
+    PATTERN,
+    PATTERN_CONSTRAINT_COMPONENT,
+    PATTERN_CONSTRAINT_COMPONENT_FLAGS,
+    PATTERN_CONSTRAINT_COMPONENT_PATTERN,
+    PREFIX,
+    PREFIXES,
+    PREFIX_DECLARATION,
+    PROPERTY,
+    PROPERTY_CONSTRAINT_COMPONENT,
+    PROPERTY_CONSTRAINT_COMPONENT_PROPERTY,
+    PROPERTY_GROUP,
+    PROPERTY_SHAPE,
+    PROPERTY_VALIDATOR,
+    QUALIFIED_MAX_COUNT,
+    QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT,
+    QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_MAX_COUNT,
+    QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPE,
+    QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPES_DISJOINT,
+    QUALIFIED_MIN_COUNT,
+    QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT,
+    QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_MIN_COUNT,
+    QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPE,
+    QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT_QUALIFIED_VALUE_SHAPES_DISJOINT,
+    QUALIFIED_VALUE_SHAPE,
+    QUALIFIED_VALUE_SHAPES_DISJOINT,
+    RESULT,
+    RESULT_MESSAGE,
+    RESULT_PATH,
+    RESULT_SEVERITY,
+    SELECT,
+    SEVERITY,
+    SEVERITY_PROPERTY,
+    SHAPE,
+    SHAPES_GRAPH,
+    SHAPES_GRAPH_WELL_FORMED,
+    SOURCE_CONSTRAINT,
+    SOURCE_CONSTRAINT_COMPONENT,
+    SOURCE_SHAPE,
+    SPARQL,
+    SPARQL_ASK_EXECUTABLE,
+    SPARQL_ASK_VALIDATOR,
+    SPARQL_CONSTRAINT,
+    SPARQL_CONSTRAINT_COMPONENT,
+    SPARQL_CONSTRAINT_COMPONENT_SPARQL,
+    SPARQL_CONSTRUCT_EXECUTABLE,
+    SPARQL_EXECUTABLE,
+    SPARQL_SELECT_EXECUTABLE,
+    SPARQL_SELECT_VALIDATOR,
+    SPARQL_UPDATE_EXECUTABLE,
+    SUGGESTED_SHAPES_GRAPH,
+    TARGET_CLASS,
+    TARGET_NODE,
+    TARGET_OBJECTS_OF,
+    TARGET_SUBJECTS_OF,
+    THIS,
+    UNIQUE_LANG,
+    UNIQUE_LANG_CONSTRAINT_COMPONENT,
+    UNIQUE_LANG_CONSTRAINT_COMPONENT_UNIQUE_LANG,
+    UPDATE,
+    VALIDATION_REPORT,
+    VALIDATION_RESULT,
+    VALIDATOR,
+    VALIDATOR_PROPERTY,
+    VALUE,
+    VIOLATION,
+    WARNING,
+    XONE,
+    XONE_CONSTRAINT_COMPONENT,
+    XONE_CONSTRAINT_COMPONENT_XONE,
+    ZERO_OR_MORE_PATH,
+    ZERO_OR_ONE_PATH,
+];
@@ -0,0 +1,136 @@
+// This file is generated by lib/oxrdf/generate_vocabularies.py, do not edit it by hand.
+//! [SKOS](https://www.w3.org/TR/skos-reference/) vocabulary.
+use crate::named_node::NamedNodeRef;
+
+/// alternative label
+pub const ALT_LABEL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#altLabel");
+/// has broader
+pub const BROADER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#broader");
+/// has broader transitive
+pub const BROADER_TRANSITIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#broaderTransitive");
+/// has broader match
+pub const BROAD_MATCH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#broadMatch");
+/// change note
+pub const CHANGE_NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#changeNote");
+/// has close match
+pub const CLOSE_MATCH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#closeMatch");
+/// Collection
+pub const COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#Collection");
+/// Concept
+pub const CONCEPT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#Concept");
+/// Concept Scheme
+pub const CONCEPT_SCHEME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#ConceptScheme");
+/// definition
+pub const DEFINITION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#definition");
+/// editorial note
+pub const EDITORIAL_NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#editorialNote");
+/// has exact match
+pub const EXACT_MATCH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#exactMatch");
+/// example
+pub const EXAMPLE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#example");
+/// has top concept
+pub const HAS_TOP_CONCEPT: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#hasTopConcept");
+/// hidden label
+pub const HIDDEN_LABEL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#hiddenLabel");
+/// history note
+pub const HISTORY_NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#historyNote");
+/// is in scheme
+pub const IN_SCHEME: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#inScheme");
+/// is in mapping relation with
+pub const MAPPING_RELATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#mappingRelation");
+/// has member
+pub const MEMBER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#member");
+/// has member list
+pub const MEMBER_LIST: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#memberList");
+/// has narrower
+pub const NARROWER: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#narrower");
+/// has narrower transitive
+pub const NARROWER_TRANSITIVE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#narrowerTransitive");
+/// has narrower match
+pub const NARROW_MATCH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#narrowMatch");
+/// notation
+pub const NOTATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#notation");
+/// note
+pub const NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#note");
+/// Ordered Collection
+pub const ORDERED_COLLECTION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#OrderedCollection");
+/// preferred label
+pub const PREF_LABEL: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#prefLabel");
+/// has related
+pub const RELATED: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#related");
+/// has related match
+pub const RELATED_MATCH: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#relatedMatch");
+/// scope note
+pub const SCOPE_NOTE: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#scopeNote");
+/// is in semantic relation with
+pub const SEMANTIC_RELATION: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#semanticRelation");
+/// is top concept in scheme
+pub const TOP_CONCEPT_OF: NamedNodeRef<'_> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/2004/02/skos/core#topConceptOf");
+
+#[cfg(test)]
+pub(super) const ALL_TERMS: &[NamedNodeRef<'_>] = &[
+    ALT_LABEL,
+    BROADER,
+    BROADER_TRANSITIVE,
+    BROAD_MATCH,
+    CHANGE_NOTE,
+    CLOSE_MATCH,
+    COLLECTION,
+    CONCEPT,
+    CONCEPT_SCHEME,
+    DEFINITION,
+    EDITORIAL_NOTE,
+    EXACT_MATCH,
+    EXAMPLE,
+    HAS_TOP_CONCEPT,
+    HIDDEN_LABEL,
+    HISTORY_NOTE,
+    IN_SCHEME,
+    MAPPING_RELATION,
+    MEMBER,
+    MEMBER_LIST,
+    NARROWER,
+    NARROWER_TRANSITIVE,
+    NARROW_MATCH,
+    NOTATION,
+    NOTE,
+    ORDERED_COLLECTION,
+    PREF_LABEL,
+    RELATED,
+    RELATED_MATCH,
+    SCOPE_NOTE,
+    SEMANTIC_RELATION,
+    TOP_CONCEPT_OF,
+];
@@ -0,0 +1,506 @@
+//! A converter from [CSV](https://www.rfc-editor.org/rfc/rfc4180) files to RDF
+//! implementing the [minimal mode](https://www.w3.org/TR/csv2rdf/#dfn-minimal-mode) of the W3C [Generating RDF from Tabular Data on the Web](https://www.w3.org/TR/csv2rdf/) recommendation.
+
+use crate::error::{RdfParseError, RdfSyntaxError, TextPosition};
+use oxrdf::vocab::xsd;
+use oxrdf::{BlankNode, GraphName, IriParseError, Literal, NamedNode, Quad};
+use oxsdatatypes::{Date, DateTime, Double, Integer};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// A parser converting [CSV](https://www.rfc-editor.org/rfc/rfc4180) files to RDF
+/// following the [minimal mode](https://www.w3.org/TR/csv2rdf/#dfn-minimal-mode) of the W3C [CSV to RDF](https://www.w3.org/TR/csv2rdf/) conversion.
+///
+/// The first row of the file is the header giving the column names.
+/// Each other row is converted to a fresh blank node with one triple per non-empty cell.
+/// The property of a column is either set with [`with_column_iri`](Self::with_column_iri)
+/// or built from the percent-encoded column name used as the fragment of the base IRI like `<http://example.com/data.csv#name>`.
+///
+/// The cells are returned as `xsd:string` literals, unless [`with_datatype_inference`](Self::with_datatype_inference) is used.
+///
+/// Records are separated by LF or CRLF line jumps.
+/// Cells might be quoted using `"` to contain delimiters, quotes escaped by doubling them and line jumps.
+/// A leading UTF-8 byte order mark is ignored and empty lines are skipped.
+///
+/// ```
+/// use oxrdf::{Literal, NamedNodeRef};
+/// use oxrdfio::CsvRdfParser;
+///
+/// let file = "name;age\nFoo;12\n\"Bar; Baz\";\n";
+///
+/// let quads = CsvRdfParser::new()
+///     .with_base_iri("http://example.com/people.csv")?
+///     .with_delimiter(b';')
+///     .with_datatype_inference()
+///     .for_reader(file.as_bytes())
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let name = NamedNodeRef::new("http://example.com/people.csv#name")?;
+/// let age = NamedNodeRef::new("http://example.com/people.csv#age")?;
+/// assert_eq!(quads.len(), 3);
+/// assert_eq!(quads[0].predicate, name);
+/// assert_eq!(quads[0].object, Literal::from("Foo").into());
+/// assert_eq!(quads[1].predicate, age);
+/// assert_eq!(quads[1].object, Literal::from(12).into());
+/// assert_eq!(quads[2].object, Literal::from("Bar; Baz").into());
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Clone)]
+#[must_use]
+pub struct CsvRdfParser {
+    base_iri: Option<NamedNode>,
+    delimiter: u8,
+    column_iris: HashMap<String, NamedNode>,
+    infer_datatypes: bool,
+    default_graph: GraphName,
+}
+
+impl Default for CsvRdfParser {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            base_iri: None,
+            delimiter: b',',
+            column_iris: HashMap::new(),
+            infer_datatypes: false,
+            default_graph: GraphName::DefaultGraph,
+        }
+    }
+}
+
+impl CsvRdfParser {
+    /// Builds a parser for comma separated files.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the IRI of the CSV file, used to build the column properties.
+    #[inline]
+    pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Result<Self, IriParseError> {
+        self.base_iri = Some(NamedNode::new(base_iri)?);
+        Ok(self)
+    }
+
+    /// Sets the cell delimiter (`,` by default).
+    ///
+    /// It must be an ASCII character other than `"`, CR and LF, for example `;` or `\t`.
+    #[inline]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the property used for the cells of the column with the given name in the header.
+    ///
+    /// ```
+    /// use oxrdf::NamedNode;
+    /// use oxrdfio::CsvRdfParser;
+    ///
+    /// let quads = CsvRdfParser::new()
+    ///     .with_column_iri("name", NamedNode::new("http://schema.org/name")?)
+    ///     .for_reader(b"name\nFoo\n".as_slice())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(quads[0].predicate.as_str(), "http://schema.org/name");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_column_iri(mut self, column: impl Into<String>, iri: impl Into<NamedNode>) -> Self {
+        self.column_iris.insert(column.into(), iri.into());
+        self
+    }
+
+    /// Returns the cells looking like integers, doubles, booleans, dates or date times
+    /// as `xsd:integer`, `xsd:double`, `xsd:boolean`, `xsd:date` or `xsd:dateTime` literals instead of `xsd:string` literals.
+    #[inline]
+    pub fn with_datatype_inference(mut self) -> Self {
+        self.infer_datatypes = true;
+        self
+    }
+
+    /// Sets the graph in which the triples are returned (the default graph by default).
+    #[inline]
+    pub fn with_default_graph(mut self, default_graph: impl Into<GraphName>) -> Self {
+        self.default_graph = default_graph.into();
+        self
+    }
+
+    /// Parses a CSV file from a [`Read`] implementation.
+    ///
+    /// The returned iterator goes on after a row is invalid, allowing to skip it.
+    pub fn for_reader<R: Read>(self, reader: R) -> ReaderCsvRdfParser<R> {
+        ReaderCsvRdfParser {
+            reader: BufReader::new(reader),
+            parser: self,
+            properties: None,
+            buffer: Vec::new(),
+            line: 0,
+            offset: 0,
+            results: VecDeque::new(),
+            is_ending: false,
+        }
+    }
+
+    fn property(&self, index: usize, column: &str) -> Result<NamedNode, &'static str> {
+        if let Some(iri) = self.column_iris.get(column) {
+            return Ok(iri.clone());
+        }
+        let base_iri = self
+            .base_iri
+            .as_ref()
+            .ok_or("A base IRI is required to build the properties of the columns without IRI")?;
+        let base_iri = base_iri
+            .as_str()
+            .split_once('#')
+            .map_or(base_iri.as_str(), |(base, _)| base);
+        let mut iri = format!("{base_iri}#");
+        if column.is_empty() {
+            // Name given to the columns without title
+            iri.push_str("_col.");
+            iri.push_str(&(index + 1).to_string());
+        }
+        for byte in column.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                iri.push(char::from(byte));
+            } else {
+                iri.push('%');
+                for digit in [byte >> 4, byte & 0xF] {
+                    iri.push(char::from(HEX_DIGITS[usize::from(digit)]));
+                }
+            }
+        }
+        Ok(NamedNode::new_unchecked(iri))
+    }
+
+    fn literal(&self, value: String) -> Literal {
+        if self.infer_datatypes {
+            let datatype = if Integer::from_str(&value).is_ok() {
+                Some(xsd::INTEGER)
+            } else if Double::from_str(&value).is_ok() && value.bytes().any(|b| b.is_ascii_digit())
+            {
+                Some(xsd::DOUBLE)
+            } else if matches!(value.as_str(), "true" | "false") {
+                Some(xsd::BOOLEAN)
+            } else if Date::from_str(&value).is_ok() {
+                Some(xsd::DATE)
+            } else if DateTime::from_str(&value).is_ok() {
+                Some(xsd::DATE_TIME)
+            } else {
+                None
+            };
+            if let Some(datatype) = datatype {
+                return Literal::new_typed_literal(value, datatype);
+            }
+        }
+        Literal::new_simple_literal(value)
+    }
+}
+
+/// Parses a CSV file from a [`Read`] implementation.
+///
+/// Can be built using [`CsvRdfParser::for_reader`].
+#[must_use]
+pub struct ReaderCsvRdfParser<R: Read> {
+    reader: BufReader<R>,
+    parser: CsvRdfParser,
+    /// The properties of the columns, `None` if the header is not read yet
+    properties: Option<Vec<NamedNode>>,
+    buffer: Vec<u8>,
+    line: u64,
+    offset: u64,
+    results: VecDeque<Quad>,
+    is_ending: bool,
+}
+
+impl<R: Read> Iterator for ReaderCsvRdfParser<R> {
+    type Item = Result<Quad, RdfParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(quad) = self.results.pop_front() {
+                return Some(Ok(quad));
+            }
+            if self.is_ending {
+                return None;
+            }
+            let (record, position) = match self.read_record() {
+                Ok(Some(record)) => record,
+                Ok(None) => {
+                    self.is_ending = true;
+                    return None;
+                }
+                Err(e) => {
+                    if matches!(e, RdfParseError::Io(_)) {
+                        self.is_ending = true;
+                    }
+                    return Some(Err(e));
+                }
+            };
+            if let Err(e) = self.convert_record(record, position) {
+                return Some(Err(e.into()));
+            }
+        }
+    }
+}
+
+impl<R: Read> ReaderCsvRdfParser<R> {
+    /// Reads the cells of the next non-empty record and its position
+    fn read_record(&mut self) -> Result<Option<(Vec<String>, TextPosition)>, RdfParseError> {
+        let delimiter = self.parser.delimiter;
+        if !delimiter.is_ascii() || matches!(delimiter, b'"' | b'\r' | b'\n') {
+            self.is_ending = true;
+            return Err(RdfSyntaxError::msg(
+                "The CSV delimiter must be an ASCII character other than '\"', CR and LF",
+            )
+            .into());
+        }
+        let mut start = TextPosition {
+            line: self.line,
+            column: 0,
+            offset: self.offset,
+        };
+        let mut cells = Vec::new();
+        let mut cell = Vec::new();
+        let mut is_quoted = false;
+        let mut in_quotes = false;
+        loop {
+            self.buffer.clear();
+            if self.reader.read_until(b'\n', &mut self.buffer)? == 0 {
+                // End of file
+                if in_quotes {
+                    self.is_ending = true;
+                    return Err(RdfSyntaxError::csv("Unterminated quoted cell", start).into());
+                }
+                if cells.is_empty() && cell.is_empty() && !is_quoted {
+                    return Ok(None);
+                }
+                cells.push(Self::decode_cell(cell, start)?);
+                return Ok(Some((cells, start)));
+            }
+            let mut line = self.buffer.as_slice();
+            if self.offset == 0 {
+                line = line.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(line);
+            }
+            self.offset += u64::try_from(self.buffer.len()).unwrap_or(u64::MAX);
+            self.line += 1;
+            if !in_quotes && cells.is_empty() && matches!(line, b"\n" | b"\r\n") {
+                // Empty lines are skipped
+                start.line = self.line;
+                start.offset = self.offset;
+                continue;
+            }
+            let mut bytes = line.iter().copied().peekable();
+            while let Some(byte) = bytes.next() {
+                if in_quotes {
+                    if byte == b'"' {
+                        if bytes.peek() == Some(&b'"') {
+                            bytes.next();
+                            cell.push(b'"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        cell.push(byte);
+                    }
+                } else if byte == delimiter {
+                    cells.push(Self::decode_cell(std::mem::take(&mut cell), start)?);
+                    is_quoted = false;
+                } else if byte == b'\n' || (byte == b'\r' && bytes.peek() == Some(&b'\n')) {
+                    break;
+                } else if is_quoted {
+                    return Err(RdfSyntaxError::csv(
+                        "Only a delimiter or a line jump is allowed after a quoted cell",
+                        start,
+                    )
+                    .into());
+                } else if byte == b'"' && cell.is_empty() {
+                    is_quoted = true;
+                    in_quotes = true;
+                } else {
+                    cell.push(byte);
+                }
+            }
+            if !in_quotes {
+                cells.push(Self::decode_cell(cell, start)?);
+                return Ok(Some((cells, start)));
+            }
+        }
+    }
+
+    fn decode_cell(cell: Vec<u8>, position: TextPosition) -> Result<String, RdfSyntaxError> {
+        String::from_utf8(cell).map_err(|_| RdfSyntaxError::csv("Invalid UTF-8 in cell", position))
+    }
+
+    fn convert_record(
+        &mut self,
+        record: Vec<String>,
+        position: TextPosition,
+    ) -> Result<(), RdfSyntaxError> {
+        let Some(properties) = &self.properties else {
+            let properties = record
+                .iter()
+                .enumerate()
+                .map(|(i, column)| self.parser.property(i, column))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    self.is_ending = true;
+                    RdfSyntaxError::csv(e, position)
+                })?;
+            self.properties = Some(properties);
+            return Ok(());
+        };
+        if record.len() != properties.len() {
+            return Err(RdfSyntaxError::csv(
+                format!(
+                    "The row has {} cells but the header has {} columns",
+                    record.len(),
+                    properties.len()
+                ),
+                position,
+            ));
+        }
+        let subject = BlankNode::default();
+        for (value, property) in record.into_iter().zip(properties) {
+            if !value.is_empty() {
+                self.results.push_back(Quad::new(
+                    subject.clone(),
+                    property.clone(),
+                    self.parser.literal(value),
+                    self.parser.default_graph.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use oxrdf::{NamedOrBlankNode, Term};
+    use std::error::Error;
+
+    fn parse(parser: CsvRdfParser, file: &[u8]) -> Result<Vec<Quad>, RdfParseError> {
+        parser.for_reader(file).collect()
+    }
+
+    fn objects(quads: &[Quad]) -> Vec<String> {
+        quads.iter().map(|q| q.object.to_string()).collect()
+    }
+
+    #[test]
+    fn test_quoting() -> Result<(), Box<dyn Error>> {
+        let quads = parse(
+            CsvRdfParser::new().with_base_iri("http://example.com/data.csv")?,
+            b"\xEF\xBB\xBFa,b\r\n\"x,y\",\"say \"\"hi\"\"\"\r\n\r\n\"multi\nline\",\"\"\n,plain",
+        )?;
+        assert_eq!(
+            objects(&quads),
+            [
+                "\"x,y\"",
+                "\"say \\\"hi\\\"\"",
+                "\"multi\\nline\"",
+                "\"plain\""
+            ]
+        );
+        assert_eq!(quads[0].predicate.as_str(), "http://example.com/data.csv#a");
+        assert_eq!(quads[1].predicate.as_str(), "http://example.com/data.csv#b");
+        // One subject per row
+        assert_eq!(quads[0].subject, quads[1].subject);
+        assert_ne!(quads[1].subject, quads[2].subject);
+        assert!(matches!(quads[0].subject, NamedOrBlankNode::BlankNode(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_properties() -> Result<(), Box<dyn Error>> {
+        let quads = parse(
+            CsvRdfParser::new()
+                .with_base_iri("http://example.com/ns#ignored")?
+                .with_column_iri("id", NamedNode::new("http://schema.org/identifier")?)
+                .with_default_graph(NamedNode::new("http://example.com/g")?),
+            "id,first name,,\u{e9}\n1,2,3,4".as_bytes(),
+        )?;
+        let properties = quads
+            .iter()
+            .map(|q| q.predicate.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            properties,
+            [
+                "http://schema.org/identifier",
+                "http://example.com/ns#first%20name",
+                "http://example.com/ns#_col.3",
+                "http://example.com/ns#%C3%A9"
+            ]
+        );
+        assert_eq!(quads[0].graph_name.to_string(), "<http://example.com/g>");
+        // The properties are required
+        parse(CsvRdfParser::new(), b"a\nb").unwrap_err();
+        Ok(())
+    }
+
+    #[test]
+    fn test_datatype_inference() -> Result<(), Box<dyn Error>> {
+        let file = b"v\n12\n-1.5e3\n.5\ntrue\n2024-01-31\n2024-01-31T12:00:00Z\nINF\n1 2\nFalse";
+        let quads = parse(
+            CsvRdfParser::new()
+                .with_base_iri("http://example.com/")?
+                .with_datatype_inference(),
+            file,
+        )?;
+        assert_eq!(
+            objects(&quads),
+            [
+                "\"12\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+                "\"-1.5e3\"^^<http://www.w3.org/2001/XMLSchema#double>",
+                "\".5\"^^<http://www.w3.org/2001/XMLSchema#double>",
+                "\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>",
+                "\"2024-01-31\"^^<http://www.w3.org/2001/XMLSchema#date>",
+                "\"2024-01-31T12:00:00Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime>",
+                "\"INF\"",
+                "\"1 2\"",
+                "\"False\""
+            ]
+        );
+        let quads = parse(
+            CsvRdfParser::new().with_base_iri("http://example.com/")?,
+            file,
+        )?;
+        assert!(
+            quads
+                .iter()
+                .all(|q| matches!(&q.object, Term::Literal(l) if l.datatype() == xsd::STRING))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() -> Result<(), Box<dyn Error>> {
+        let parser = CsvRdfParser::new().with_base_iri("http://example.com/")?;
+        // Invalid rows are skipped
+        let results = parser
+            .clone()
+            .for_reader(b"a,b\n\n1,2,3\n4,5\n\"6\"7,8\n9,\"10".as_slice())
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 5);
+        let RdfParseError::Syntax(error) = results[0].as_ref().unwrap_err() else {
+            unreachable!()
+        };
+        assert_eq!(
+            error.to_string(),
+            "Parser error at line 3: The row has 3 cells but the header has 2 columns"
+        );
+        assert_eq!(error.location().map(|l| l.start.offset), Some(5));
+        assert_eq!(
+            results.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            [false, true, true, false, false]
+        );
+        parse(parser.clone(), b"a\n\xFF").unwrap_err();
+        parse(parser.with_delimiter(b'"'), b"a").unwrap_err();
+        Ok(())
+    }
+}
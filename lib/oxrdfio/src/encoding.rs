@@ -0,0 +1,488 @@
+//! Transparent decompression of RDF documents.
+
+#[cfg(feature = "bzip2")]
+use bzip2::read::MultiBzDecoder;
+#[cfg(feature = "gzip")]
+use flate2::read::MultiGzDecoder;
+#[cfg(feature = "xz")]
+use lzma_rust2::XzReader;
+#[cfg(feature = "zstd")]
+use ruzstd::decoding::errors::{FrameDecoderError, ReadFrameHeaderError};
+#[cfg(feature = "zstd")]
+use ruzstd::decoding::{BlockDecodingStrategy, FrameDecoder};
+use std::fmt;
+#[cfg(feature = "zstd")]
+use std::io::BufReader;
+use std::io::{self, BufRead, Read};
+
+/// Content encodings (i.e. compression formats) of RDF documents.
+///
+/// Each compression format is only available if the matching cargo feature is enabled (`gzip`, `bzip2`, `xz` or `zstd`).
+///
+/// This enumeration is non exhaustive. New encodings might be added in the future.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum ContentEncoding {
+    /// No compression
+    Identity,
+    /// [Gzip](https://www.rfc-editor.org/rfc/rfc1952)
+    #[cfg(feature = "gzip")]
+    Gzip,
+    /// [Bzip2](https://sourceware.org/bzip2/)
+    #[cfg(feature = "bzip2")]
+    Bzip2,
+    /// [XZ](https://tukaani.org/xz/format.html)
+    #[cfg(feature = "xz")]
+    Xz,
+    /// [Zstandard](https://www.rfc-editor.org/rfc/rfc8878)
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl ContentEncoding {
+    /// The encoding name as used in the HTTP `Content-Encoding` header.
+    ///
+    /// ```
+    /// use oxrdfio::ContentEncoding;
+    ///
+    /// assert_eq!(ContentEncoding::Identity.name(), "identity")
+    /// ```
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Identity => "identity",
+            #[cfg(feature = "gzip")]
+            Self::Gzip => "gzip",
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2 => "bzip2",
+            #[cfg(feature = "xz")]
+            Self::Xz => "xz",
+            #[cfg(feature = "zstd")]
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// The usual file extension of the encoding, if any.
+    ///
+    /// ```
+    /// use oxrdfio::ContentEncoding;
+    ///
+    /// assert_eq!(ContentEncoding::Identity.file_extension(), None)
+    /// ```
+    #[inline]
+    pub const fn file_extension(self) -> Option<&'static str> {
+        match self {
+            Self::Identity => None,
+            #[cfg(feature = "gzip")]
+            Self::Gzip => Some("gz"),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2 => Some("bz2"),
+            #[cfg(feature = "xz")]
+            Self::Xz => Some("xz"),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Some("zst"),
+        }
+    }
+
+    /// Looks for a supported encoding from an HTTP `Content-Encoding` header value.
+    ///
+    /// ```
+    /// use oxrdfio::ContentEncoding;
+    ///
+    /// assert_eq!(
+    ///     ContentEncoding::from_name("identity"),
+    ///     Some(ContentEncoding::Identity)
+    /// );
+    /// assert_eq!(ContentEncoding::from_name("foo"), None);
+    /// ```
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        const NAMES: &[(&str, ContentEncoding)] = &[
+            ("identity", ContentEncoding::Identity),
+            #[cfg(feature = "gzip")]
+            ("gzip", ContentEncoding::Gzip),
+            #[cfg(feature = "gzip")]
+            ("x-gzip", ContentEncoding::Gzip),
+            #[cfg(feature = "bzip2")]
+            ("bzip2", ContentEncoding::Bzip2),
+            #[cfg(feature = "xz")]
+            ("xz", ContentEncoding::Xz),
+            #[cfg(feature = "zstd")]
+            ("zstd", ContentEncoding::Zstd),
+        ];
+        let name = name.trim();
+        NAMES
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+            .map(|(_, encoding)| *encoding)
+    }
+
+    /// Looks for a supported compression format from a file extension.
+    ///
+    /// ```
+    /// use oxrdfio::ContentEncoding;
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     ContentEncoding::from_extension("gz"),
+    ///     Some(ContentEncoding::Gzip)
+    /// );
+    /// assert_eq!(ContentEncoding::from_extension("nt"), None);
+    /// ```
+    #[inline]
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        const EXTENSIONS: &[(&str, ContentEncoding)] = &[
+            #[cfg(feature = "gzip")]
+            ("gz", ContentEncoding::Gzip),
+            #[cfg(feature = "gzip")]
+            ("gzip", ContentEncoding::Gzip),
+            #[cfg(feature = "bzip2")]
+            ("bz2", ContentEncoding::Bzip2),
+            #[cfg(feature = "xz")]
+            ("xz", ContentEncoding::Xz),
+            #[cfg(feature = "zstd")]
+            ("zst", ContentEncoding::Zstd),
+        ];
+        EXTENSIONS
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(extension))
+            .map(|(_, encoding)| *encoding)
+    }
+
+    /// Guesses the compression format from the first bytes of a document.
+    ///
+    /// Returns [`ContentEncoding::Identity`] if no supported compression format magic number is found.
+    ///
+    /// ```
+    /// use oxrdfio::ContentEncoding;
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     ContentEncoding::from_magic_bytes(b"\x1f\x8b\x08\x00"),
+    ///     ContentEncoding::Gzip
+    /// );
+    /// assert_eq!(
+    ///     ContentEncoding::from_magic_bytes(b"<http://example.com/s>"),
+    ///     ContentEncoding::Identity
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_magic_bytes(bytes: &[u8]) -> Self {
+        const MAGIC_NUMBERS: &[(&[u8], ContentEncoding)] = &[
+            #[cfg(feature = "gzip")]
+            (b"\x1f\x8b", ContentEncoding::Gzip),
+            #[cfg(feature = "bzip2")]
+            (b"BZh", ContentEncoding::Bzip2),
+            #[cfg(feature = "xz")]
+            (b"\xfd7zXZ\x00", ContentEncoding::Xz),
+            #[cfg(feature = "zstd")]
+            (b"\x28\xb5\x2f\xfd", ContentEncoding::Zstd),
+        ];
+        #[cfg(feature = "zstd")]
+        if let [0x50..=0x5F, 0x2A, 0x4D, 0x18, ..] = bytes {
+            return Self::Zstd; // Zstandard skippable frame
+        }
+        MAGIC_NUMBERS
+            .iter()
+            .find(|(magic, _)| bytes.starts_with(magic))
+            .map_or(Self::Identity, |(_, encoding)| *encoding)
+    }
+}
+
+impl fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A [`Read`] implementation decompressing on the fly a compressed document.
+///
+/// Decompression is streaming: only a small buffer of the input is kept in memory.
+///
+/// Can be built using [`DecodingReader::new`] if the encoding is known or [`DecodingReader::detect`] to guess it from the magic number.
+///
+/// ```
+/// use oxrdfio::{ContentEncoding, DecodingReader};
+/// use std::io::Read;
+///
+/// let mut reader = DecodingReader::detect(b"<http://example.com/s> <http://example.com/p> <http://example.com/o> .".as_slice())?;
+/// assert_eq!(reader.encoding(), ContentEncoding::Identity);
+/// let mut content = String::new();
+/// reader.read_to_string(&mut content)?;
+/// # std::io::Result::Ok(())
+/// ```
+#[must_use]
+pub struct DecodingReader<R: Read> {
+    inner: DecodingReaderKind<R>,
+}
+
+enum DecodingReaderKind<R: Read> {
+    Identity(R),
+    #[cfg(feature = "gzip")]
+    Gzip(MultiGzDecoder<R>),
+    #[cfg(feature = "bzip2")]
+    Bzip2(MultiBzDecoder<R>),
+    #[cfg(feature = "xz")]
+    Xz(Box<XzReader<R>>),
+    #[cfg(feature = "zstd")]
+    Zstd(Box<ZstdReader<R>>),
+}
+
+impl<R: Read> DecodingReader<R> {
+    /// Decompresses the given reader content using the given encoding.
+    pub fn new(reader: R, encoding: ContentEncoding) -> Self {
+        Self {
+            inner: match encoding {
+                ContentEncoding::Identity => DecodingReaderKind::Identity(reader),
+                #[cfg(feature = "gzip")]
+                ContentEncoding::Gzip => DecodingReaderKind::Gzip(MultiGzDecoder::new(reader)),
+                #[cfg(feature = "bzip2")]
+                ContentEncoding::Bzip2 => DecodingReaderKind::Bzip2(MultiBzDecoder::new(reader)),
+                #[cfg(feature = "xz")]
+                ContentEncoding::Xz => {
+                    DecodingReaderKind::Xz(Box::new(XzReader::new(reader, true)))
+                }
+                #[cfg(feature = "zstd")]
+                ContentEncoding::Zstd => {
+                    DecodingReaderKind::Zstd(Box::new(ZstdReader::new(reader)))
+                }
+            },
+        }
+    }
+
+    /// The encoding used to decompress the content.
+    pub fn encoding(&self) -> ContentEncoding {
+        match &self.inner {
+            DecodingReaderKind::Identity(_) => ContentEncoding::Identity,
+            #[cfg(feature = "gzip")]
+            DecodingReaderKind::Gzip(_) => ContentEncoding::Gzip,
+            #[cfg(feature = "bzip2")]
+            DecodingReaderKind::Bzip2(_) => ContentEncoding::Bzip2,
+            #[cfg(feature = "xz")]
+            DecodingReaderKind::Xz(_) => ContentEncoding::Xz,
+            #[cfg(feature = "zstd")]
+            DecodingReaderKind::Zstd(_) => ContentEncoding::Zstd,
+        }
+    }
+}
+
+impl<R: BufRead> DecodingReader<R> {
+    /// Guesses the encoding from the first bytes of the reader content using [`ContentEncoding::from_magic_bytes`].
+    ///
+    /// No byte is consumed from the reader before decompression starts.
+    pub fn detect(mut reader: R) -> io::Result<Self> {
+        let encoding = ContentEncoding::from_magic_bytes(reader.fill_buf()?);
+        Ok(Self::new(reader, encoding))
+    }
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.inner {
+            DecodingReaderKind::Identity(r) => r.read(buf),
+            #[cfg(feature = "gzip")]
+            DecodingReaderKind::Gzip(r) => r.read(buf),
+            #[cfg(feature = "bzip2")]
+            DecodingReaderKind::Bzip2(r) => r.read(buf),
+            #[cfg(feature = "xz")]
+            DecodingReaderKind::Xz(r) => r.read(buf),
+            #[cfg(feature = "zstd")]
+            DecodingReaderKind::Zstd(r) => r.read(buf),
+        }
+    }
+}
+
+/// Zstandard decoder reading the frame headers lazily so that [`DecodingReader::new`] does not do any IO.
+///
+/// Like with the gzip and bzip2 decoders, all the concatenated frames are decoded
+/// and the [skippable frames](https://www.rfc-editor.org/rfc/rfc8878#section-3.1.2) (e.g. the ones written by `pzstd`) are ignored.
+#[cfg(feature = "zstd")]
+struct ZstdReader<R: Read> {
+    reader: BufReader<R>,
+    decoder: FrameDecoder,
+    /// A frame header has been read and the frame content is not fully decoded yet
+    in_frame: bool,
+    /// At least one frame has been read
+    started: bool,
+}
+
+#[cfg(feature = "zstd")]
+impl<R: Read> ZstdReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            decoder: FrameDecoder::new(),
+            in_frame: false,
+            started: false,
+        }
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<R: Read> Read for ZstdReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            if !self.in_frame {
+                if self.started && self.reader.fill_buf()?.is_empty() {
+                    return Ok(0); // No more frame
+                }
+                match self.decoder.init(&mut self.reader) {
+                    Ok(()) => self.in_frame = true,
+                    Err(FrameDecoderError::ReadFrameHeaderError(
+                        ReadFrameHeaderError::SkipFrame { length, .. },
+                    )) => {
+                        let length = u64::from(length);
+                        if io::copy(&mut (&mut self.reader).take(length), &mut io::sink())? < length
+                        {
+                            return Err(io::Error::new(
+                                io::ErrorKind::UnexpectedEof,
+                                "Truncated Zstandard skippable frame",
+                            ));
+                        }
+                    }
+                    Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+                }
+                self.started = true;
+                continue;
+            }
+            while self.decoder.can_collect() < buf.len() && !self.decoder.is_finished() {
+                let additional_bytes_needed = buf.len() - self.decoder.can_collect();
+                self.decoder
+                    .decode_blocks(
+                        &mut self.reader,
+                        BlockDecodingStrategy::UptoBytes(additional_bytes_needed),
+                    )
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            }
+            let read = self.decoder.read(buf)?;
+            if read > 0 {
+                return Ok(read);
+            }
+            // The current frame is fully decoded, we move to the next one
+            self.in_frame = false;
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+
+    const NTRIPLES: &str =
+        "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n";
+
+    fn decode(data: &[u8]) -> io::Result<(ContentEncoding, String)> {
+        let mut reader = DecodingReader::detect(data)?;
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+        Ok((reader.encoding(), content))
+    }
+
+    #[test]
+    fn test_identity() -> io::Result<()> {
+        assert_eq!(
+            decode(NTRIPLES.as_bytes())?,
+            (ContentEncoding::Identity, NTRIPLES.into())
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip() -> io::Result<()> {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(NTRIPLES.as_bytes())?;
+        // Concatenated members are all decoded
+        let mut data = encoder.finish()?;
+        data.extend_from_within(..);
+        assert_eq!(
+            decode(&data)?,
+            (ContentEncoding::Gzip, format!("{NTRIPLES}{NTRIPLES}"))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "bzip2")]
+    #[test]
+    fn test_bzip2() -> io::Result<()> {
+        use bzip2::Compression;
+        use bzip2::write::BzEncoder;
+        use std::io::Write;
+
+        let mut encoder = BzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(NTRIPLES.as_bytes())?;
+        assert_eq!(
+            decode(&encoder.finish()?)?,
+            (ContentEncoding::Bzip2, NTRIPLES.into())
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "xz")]
+    #[test]
+    fn test_xz() -> io::Result<()> {
+        use lzma_rust2::{XzOptions, XzWriter};
+        use std::io::Write;
+
+        let mut encoder = XzWriter::new(Vec::new(), XzOptions::with_preset(6))?;
+        encoder.write_all(NTRIPLES.as_bytes())?;
+        assert_eq!(
+            decode(&encoder.finish()?)?,
+            (ContentEncoding::Xz, NTRIPLES.into())
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd() -> io::Result<()> {
+        use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+        let data = compress_to_vec(NTRIPLES.as_bytes(), CompressionLevel::Fastest);
+        assert_eq!(decode(&data)?, (ContentEncoding::Zstd, NTRIPLES.into()));
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_multiple_frames() -> io::Result<()> {
+        use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+        // Concatenated frames are all decoded and skippable frames are ignored like `pzstd` output
+        let frame = compress_to_vec(NTRIPLES.as_bytes(), CompressionLevel::Fastest);
+        let mut data = b"\x50\x2a\x4d\x18\x04\x00\x00\x00skip".to_vec();
+        data.extend_from_slice(&frame);
+        data.extend_from_slice(&frame);
+        assert_eq!(
+            decode(&data)?,
+            (ContentEncoding::Zstd, format!("{NTRIPLES}{NTRIPLES}"))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_truncated_zstd() {
+        use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+        let mut data = compress_to_vec(NTRIPLES.as_bytes(), CompressionLevel::Fastest);
+        data.extend_from_slice(b"\x28\xb5");
+        let mut reader = DecodingReader::new(data.as_slice(), ContentEncoding::Zstd);
+        reader.read_to_end(&mut Vec::new()).unwrap_err();
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_invalid_zstd() {
+        let mut reader = DecodingReader::new(NTRIPLES.as_bytes(), ContentEncoding::Zstd);
+        reader.read_to_end(&mut Vec::new()).unwrap_err();
+    }
+}
@@ -52,6 +52,37 @@ impl From<RdfParseError> for io::Error {
     }
 }
 
+/// Error returned by [`RdfSerializer::serialize_from_iter`](crate::RdfSerializer::serialize_from_iter).
+#[derive(Debug, thiserror::Error)]
+pub enum SerializeFromIterError<E> {
+    /// The statement iterator returned an error.
+    #[error("Error while reading the statement {index}: {error}")]
+    Input {
+        /// The index of the failed statement.
+        index: usize,
+        #[source]
+        error: E,
+    },
+    /// I/O error while writing the statement.
+    #[error("Error while writing the statement {index}: {error}")]
+    Io {
+        /// The index of the failed statement.
+        index: usize,
+        #[source]
+        error: io::Error,
+    },
+}
+
+impl<E> SerializeFromIterError<E> {
+    /// The index of the failed statement.
+    #[inline]
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Input { index, .. } | Self::Io { index, .. } => *index,
+        }
+    }
+}
+
 /// An error in the syntax of the parsed file.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -66,8 +97,22 @@ enum SyntaxErrorKind {
     Turtle(#[from] oxttl::TurtleSyntaxError),
     #[error(transparent)]
     RdfXml(#[from] oxrdfxml::RdfXmlSyntaxError),
+    #[error(transparent)]
+    InvalidLiteral(#[from] oxrdf::DatatypeError),
+    #[error("Invalid base IRI: {0}")]
+    InvalidBaseIri(#[from] oxrdf::IriParseError),
     #[error("{0}")]
     Msg(&'static str),
+    #[error("{0}")]
+    RejectedLiteral(String),
+    #[error("Parser error at line {}: {message}", .location.line + 1)]
+    Csv {
+        message: String,
+        location: TextPosition,
+    },
+    #[cfg(feature = "hdt")]
+    #[error("{0}")]
+    Hdt(String),
 }
 
 impl RdfSyntaxError {
@@ -103,13 +148,61 @@ impl RdfSyntaxError {
                     },
                 )
             }
-            SyntaxErrorKind::RdfXml(_) | SyntaxErrorKind::Msg(_) => None,
+            SyntaxErrorKind::RdfXml(_)
+            | SyntaxErrorKind::InvalidLiteral(_)
+            | SyntaxErrorKind::InvalidBaseIri(_)
+            | SyntaxErrorKind::Msg(_)
+            | SyntaxErrorKind::RejectedLiteral(_) => None,
+            SyntaxErrorKind::Csv { location, .. } => Some(*location..*location),
+            #[cfg(feature = "hdt")]
+            SyntaxErrorKind::Hdt(_) => None,
+        }
+    }
+
+    /// The kind of the error if it has been raised by the N-Triples, N-Quads, Turtle, TriG or N3 parsers.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParseError, RdfParser, TurtleSyntaxErrorKind};
+    ///
+    /// let Some(Err(RdfParseError::Syntax(error))) = RdfParser::from_format(RdfFormat::Turtle)
+    ///     .for_reader("schema:Person a schema:Thing .".as_bytes())
+    ///     .next()
+    /// else {
+    ///     unreachable!()
+    /// };
+    /// assert!(matches!(
+    ///     error.turtle_kind(),
+    ///     Some(TurtleSyntaxErrorKind::UndefinedPrefix { prefix }) if prefix == "schema"
+    /// ));
+    /// ```
+    #[inline]
+    pub fn turtle_kind(&self) -> Option<&oxttl::TurtleSyntaxErrorKind> {
+        if let SyntaxErrorKind::Turtle(e) = &self.0 {
+            Some(e.kind())
+        } else {
+            None
         }
     }
 
     pub(crate) fn msg(msg: &'static str) -> Self {
         Self(SyntaxErrorKind::Msg(msg))
     }
+
+    pub(crate) fn rejected_literal(message: String) -> Self {
+        Self(SyntaxErrorKind::RejectedLiteral(message))
+    }
+
+    pub(crate) fn csv(message: impl Into<String>, location: TextPosition) -> Self {
+        Self(SyntaxErrorKind::Csv {
+            message: message.into(),
+            location,
+        })
+    }
+
+    #[cfg(feature = "hdt")]
+    pub(crate) fn hdt(msg: impl Into<String>) -> Self {
+        Self(SyntaxErrorKind::Hdt(msg.into()))
+    }
 }
 
 impl From<oxjsonld::JsonLdSyntaxError> for RdfSyntaxError {
@@ -133,6 +226,20 @@ impl From<oxrdfxml::RdfXmlSyntaxError> for RdfSyntaxError {
     }
 }
 
+impl From<oxrdf::IriParseError> for RdfSyntaxError {
+    #[inline]
+    fn from(error: oxrdf::IriParseError) -> Self {
+        Self(SyntaxErrorKind::InvalidBaseIri(error))
+    }
+}
+
+impl From<oxrdf::DatatypeError> for RdfSyntaxError {
+    #[inline]
+    fn from(error: oxrdf::DatatypeError) -> Self {
+        Self(SyntaxErrorKind::InvalidLiteral(error))
+    }
+}
+
 impl From<RdfSyntaxError> for io::Error {
     #[inline]
     fn from(error: RdfSyntaxError) -> Self {
@@ -140,7 +247,17 @@ impl From<RdfSyntaxError> for io::Error {
             SyntaxErrorKind::JsonLd(error) => error.into(),
             SyntaxErrorKind::Turtle(error) => error.into(),
             SyntaxErrorKind::RdfXml(error) => error.into(),
+            SyntaxErrorKind::InvalidLiteral(error) => Self::new(io::ErrorKind::InvalidData, error),
             SyntaxErrorKind::Msg(msg) => Self::new(io::ErrorKind::InvalidData, msg),
+            SyntaxErrorKind::RejectedLiteral(msg) => Self::new(io::ErrorKind::InvalidData, msg),
+            kind @ SyntaxErrorKind::InvalidBaseIri(_) => {
+                Self::new(io::ErrorKind::InvalidInput, kind.to_string())
+            }
+            kind @ SyntaxErrorKind::Csv { .. } => {
+                Self::new(io::ErrorKind::InvalidData, kind.to_string())
+            }
+            #[cfg(feature = "hdt")]
+            SyntaxErrorKind::Hdt(msg) => Self::new(io::ErrorKind::InvalidData, msg),
         }
     }
 }
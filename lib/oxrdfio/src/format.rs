@@ -21,6 +21,11 @@ pub enum RdfFormat {
     Turtle,
     /// [JSON-LD](https://www.w3.org/TR/json-ld/)
     JsonLd { profile: JsonLdProfileSet },
+    /// [HDT](https://www.rdfhdt.org/hdt-binary-format/)
+    ///
+    /// Only parsing is supported.
+    #[cfg(feature = "hdt")]
+    Hdt,
 }
 
 impl RdfFormat {
@@ -44,6 +49,8 @@ impl RdfFormat {
             Self::RdfXml => "http://www.w3.org/ns/formats/RDF_XML",
             Self::TriG => "http://www.w3.org/ns/formats/TriG",
             Self::Turtle => "http://www.w3.org/ns/formats/Turtle",
+            #[cfg(feature = "hdt")]
+            Self::Hdt => "http://purl.org/HDT/hdt#HDTv1",
         }
     }
 
@@ -71,6 +78,8 @@ impl RdfFormat {
             Self::RdfXml => "application/rdf+xml",
             Self::TriG => "application/trig",
             Self::Turtle => "text/turtle",
+            #[cfg(feature = "hdt")]
+            Self::Hdt => "application/vnd.hdt",
         }
     }
 
@@ -91,6 +100,63 @@ impl RdfFormat {
             Self::RdfXml => "rdf",
             Self::TriG => "trig",
             Self::Turtle => "ttl",
+            #[cfg(feature = "hdt")]
+            Self::Hdt => "hdt",
+        }
+    }
+
+    /// All the known media types of the format, starting with the canonical one returned by [`media_type`](Self::media_type).
+    ///
+    /// They are all recognized by [`from_media_type`](Self::from_media_type).
+    ///
+    /// ```
+    /// use oxrdfio::RdfFormat;
+    ///
+    /// assert_eq!(
+    ///     RdfFormat::Turtle.media_types(),
+    ///     ["text/turtle", "application/x-turtle"]
+    /// )
+    /// ```
+    #[inline]
+    pub const fn media_types(self) -> &'static [&'static str] {
+        match self {
+            Self::JsonLd { .. } => &[
+                "application/ld+json",
+                "application/activity+json",
+                "application/json",
+            ],
+            Self::N3 => &["text/n3"],
+            Self::NQuads => &["application/n-quads", "text/x-nquads"],
+            Self::NTriples => &["application/n-triples", "text/x-ntriples", "text/plain"],
+            Self::RdfXml => &["application/rdf+xml", "application/xml", "text/xml"],
+            Self::TriG => &["application/trig", "application/x-trig"],
+            Self::Turtle => &["text/turtle", "application/x-turtle"],
+            #[cfg(feature = "hdt")]
+            Self::Hdt => &["application/vnd.hdt"],
+        }
+    }
+
+    /// All the known file extensions of the format, starting with the canonical one returned by [`file_extension`](Self::file_extension).
+    ///
+    /// They are all recognized by [`from_extension`](Self::from_extension).
+    ///
+    /// ```
+    /// use oxrdfio::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::RdfXml.file_extensions(), ["rdf", "xml"])
+    /// ```
+    #[inline]
+    pub const fn file_extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::JsonLd { .. } => &["jsonld", "json"],
+            Self::N3 => &["n3"],
+            Self::NQuads => &["nq"],
+            Self::NTriples => &["nt", "txt"],
+            Self::RdfXml => &["rdf", "xml"],
+            Self::TriG => &["trig"],
+            Self::Turtle => &["ttl"],
+            #[cfg(feature = "hdt")]
+            Self::Hdt => &["hdt"],
         }
     }
 
@@ -118,6 +184,8 @@ impl RdfFormat {
             Self::RdfXml => "RDF/XML",
             Self::TriG => "TriG",
             Self::Turtle => "Turtle",
+            #[cfg(feature = "hdt")]
+            Self::Hdt => "HDT",
         }
     }
 
@@ -230,6 +298,10 @@ impl RdfFormat {
                 .collect::<Option<Vec<_>>>()?
         };
 
+        #[cfg(feature = "hdt")]
+        if r#type.eq_ignore_ascii_case("application") && subtype.eq_ignore_ascii_case("vnd.hdt") {
+            return Some(Self::Hdt);
+        }
         for (candidate_subtype, mut candidate_id) in MEDIA_SUBTYPES {
             if candidate_subtype.eq_ignore_ascii_case(subtype) {
                 // We have a look at parameters
@@ -264,47 +336,52 @@ impl RdfFormat {
 
     /// Looks for a known format from an extension.
     ///
-    /// It supports some aliases.
+    /// It supports all the extensions returned by [`file_extensions`](Self::file_extensions)
+    /// and compound extensions ending with a compression format like `ttl.gz`.
     ///
     /// Example:
     /// ```
     /// use oxrdfio::RdfFormat;
     ///
-    /// assert_eq!(RdfFormat::from_extension("nt"), Some(RdfFormat::NTriples))
+    /// assert_eq!(RdfFormat::from_extension("nt"), Some(RdfFormat::NTriples));
+    /// assert_eq!(RdfFormat::from_extension("ttl.gz"), Some(RdfFormat::Turtle));
     /// ```
     #[inline]
     pub fn from_extension(extension: &str) -> Option<Self> {
-        const EXTENSIONS: [(&str, RdfFormat); 10] = [
-            (
-                "json",
-                RdfFormat::JsonLd {
-                    profile: JsonLdProfileSet::empty(),
-                },
-            ),
-            (
-                "jsonld",
-                RdfFormat::JsonLd {
-                    profile: JsonLdProfileSet::empty(),
-                },
-            ),
-            ("n3", RdfFormat::N3),
-            ("nq", RdfFormat::NQuads),
-            ("nt", RdfFormat::NTriples),
-            ("rdf", RdfFormat::RdfXml),
-            ("trig", RdfFormat::TriG),
-            ("ttl", RdfFormat::Turtle),
-            ("txt", RdfFormat::NTriples),
-            ("xml", RdfFormat::RdfXml),
-        ];
-        for (candidate_extension, candidate_id) in EXTENSIONS {
-            if candidate_extension.eq_ignore_ascii_case(extension) {
-                return Some(candidate_id);
-            }
+        if let Some((extension, compression)) = extension.rsplit_once('.') {
+            return COMPRESSION_EXTENSIONS
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(compression))
+                .then(|| Self::from_extension(extension))
+                .flatten();
         }
-        None
+        Self::KNOWN.iter().copied().find(|candidate_id| {
+            candidate_id
+                .file_extensions()
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(extension))
+        })
     }
+
+    /// All formats, JSON-LD being given without profile.
+    const KNOWN: &[Self] = &[
+        Self::JsonLd {
+            profile: JsonLdProfileSet::empty(),
+        },
+        Self::N3,
+        Self::NQuads,
+        Self::NTriples,
+        Self::RdfXml,
+        Self::TriG,
+        Self::Turtle,
+        #[cfg(feature = "hdt")]
+        Self::Hdt,
+    ];
 }
 
+/// File extensions of the compression formats that might be appended to a RDF format extension
+const COMPRESSION_EXTENSIONS: [&str; 5] = ["br", "bz2", "gz", "xz", "zst"];
+
 impl fmt::Display for RdfFormat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.name())
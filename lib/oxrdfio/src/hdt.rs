@@ -0,0 +1,1076 @@
+//! A read-only parser for the [HDT](https://www.rdfhdt.org/hdt-binary-format/) binary format.
+//!
+//! It supports the sections written by [hdt-cpp](https://github.com/rdfhdt/hdt-cpp):
+//! the four sections dictionary encoded with plain front coding and the bitmap and list triples encodings.
+
+use crate::error::{RdfParseError, RdfSyntaxError};
+use oxrdf::{BlankNode, Literal, NamedNode, NamedOrBlankNode, Term, Triple};
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+#[cfg(feature = "async-tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const GLOBAL_TYPE: u8 = 1;
+const HEADER_TYPE: u8 = 2;
+const DICTIONARY_TYPE: u8 = 3;
+const TRIPLES_TYPE: u8 = 4;
+const HDT_FORMAT: &str = "<http://purl.org/HDT/hdt#HDTv1>";
+const HEADER_FORMAT: &str = "ntriples";
+const DICTIONARY_FOUR_FORMAT: &str = "<http://purl.org/HDT/hdt#dictionaryFour>";
+const TRIPLES_BITMAP_FORMAT: &str = "<http://purl.org/HDT/hdt#triplesBitmap>";
+const TRIPLES_LIST_FORMAT: &str = "<http://purl.org/HDT/hdt#triplesList>";
+const PLAIN_BITMAP_TYPE: u8 = 1;
+const LOG_SEQUENCE_TYPE: u8 = 1;
+const PFC_SECTION_TYPE: u8 = 2;
+/// Number of bytes read at once when loading the triples section parts.
+const WINDOW_SIZE: u64 = 64 * 1024;
+
+/// A parser for HDT files.
+///
+/// The dictionary is loaded in memory and the triples are then read lazily.
+#[derive(Default, Clone)]
+pub struct HdtParser {
+    lenient: bool,
+}
+
+impl HdtParser {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips the checksums and the term validation.
+    #[inline]
+    pub fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+
+    /// HDT requires random access to the file so the reader content is first fully loaded in memory.
+    pub fn for_reader<R: Read>(self, reader: R) -> ReaderHdtParser<R> {
+        ReaderHdtParser {
+            inner: ReaderHdtParserKind::Unbuffered {
+                reader: Some(reader),
+                lenient: self.lenient,
+            },
+        }
+    }
+
+    pub fn for_seekable_reader<R: Read + Seek>(self, reader: R) -> ReaderHdtParser<R> {
+        ReaderHdtParser {
+            inner: ReaderHdtParserKind::Seekable(HdtDecoder::new(reader, self.lenient)),
+        }
+    }
+
+    #[cfg(feature = "async-tokio")]
+    pub fn for_tokio_async_reader<R: AsyncRead + Unpin>(
+        self,
+        reader: R,
+    ) -> TokioAsyncReaderHdtParser<R> {
+        TokioAsyncReaderHdtParser {
+            reader: Some(reader),
+            lenient: self.lenient,
+            decoder: None,
+        }
+    }
+
+    pub fn for_slice(self, slice: &[u8]) -> SliceHdtParser<'_> {
+        SliceHdtParser {
+            decoder: HdtDecoder::new(Cursor::new(slice), self.lenient),
+        }
+    }
+}
+
+/// Parses a HDT file from a [`Read`] implementation.
+pub struct ReaderHdtParser<R> {
+    inner: ReaderHdtParserKind<R>,
+}
+
+enum ReaderHdtParserKind<R> {
+    Unbuffered { reader: Option<R>, lenient: bool },
+    Buffered(HdtDecoder<Cursor<Vec<u8>>>),
+    Seekable(HdtDecoder<R>),
+}
+
+impl<R: Read> Iterator for ReaderHdtParser<R> {
+    type Item = Result<Triple, RdfParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match &mut self.inner {
+                ReaderHdtParserKind::Unbuffered {
+                    reader,
+                    lenient: is_lenient,
+                } => {
+                    let lenient = *is_lenient;
+                    let mut reader = reader.take()?;
+                    let mut buffer = Vec::new();
+                    if let Err(e) = reader.read_to_end(&mut buffer) {
+                        return Some(Err(e.into()));
+                    }
+                    self.inner = ReaderHdtParserKind::Buffered(HdtDecoder::new(
+                        Cursor::new(buffer),
+                        lenient,
+                    ));
+                }
+                ReaderHdtParserKind::Buffered(decoder) => return decoder.next(),
+                ReaderHdtParserKind::Seekable(decoder) => return decoder.next(),
+            }
+        }
+    }
+}
+
+/// Parses a HDT file from a Tokio [`AsyncRead`] implementation.
+///
+/// The file is fully loaded in memory before being decoded.
+#[cfg(feature = "async-tokio")]
+pub struct TokioAsyncReaderHdtParser<R: AsyncRead + Unpin> {
+    reader: Option<R>,
+    lenient: bool,
+    decoder: Option<HdtDecoder<Cursor<Vec<u8>>>>,
+}
+
+#[cfg(feature = "async-tokio")]
+impl<R: AsyncRead + Unpin> TokioAsyncReaderHdtParser<R> {
+    pub async fn next(&mut self) -> Option<Result<Triple, RdfParseError>> {
+        if let Some(mut reader) = self.reader.take() {
+            let mut buffer = Vec::new();
+            if let Err(e) = reader.read_to_end(&mut buffer).await {
+                return Some(Err(e.into()));
+            }
+            self.decoder = Some(HdtDecoder::new(Cursor::new(buffer), self.lenient));
+        }
+        self.decoder.as_mut()?.next()
+    }
+}
+
+/// Parses a HDT file from a byte slice.
+pub struct SliceHdtParser<'a> {
+    decoder: HdtDecoder<Cursor<&'a [u8]>>,
+}
+
+impl Iterator for SliceHdtParser<'_> {
+    type Item = Result<Triple, RdfSyntaxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.decoder.next()?.map_err(|e| match e {
+            RdfParseError::Syntax(e) => e,
+            RdfParseError::Io(e) => RdfSyntaxError::hdt(e.to_string()),
+        }))
+    }
+}
+
+/// Erases the [`Seek`] bound of a reader, allowing to store it in types that only require [`Read`].
+struct SeekableReader<R> {
+    inner: R,
+    seek: fn(&mut R, SeekFrom) -> io::Result<u64>,
+}
+
+impl<R: Read> Read for SeekableReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R> Seek for SeekableReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        (self.seek)(&mut self.inner, pos)
+    }
+}
+
+struct HdtDecoder<R> {
+    reader: SeekableReader<R>,
+    lenient: bool,
+    content: Option<Box<HdtContent>>,
+    is_ended: bool,
+}
+
+impl<R: Read + Seek> HdtDecoder<R> {
+    fn new(reader: R, lenient: bool) -> Self {
+        Self {
+            reader: SeekableReader {
+                inner: reader,
+                seek: <R as Seek>::seek,
+            },
+            lenient,
+            content: None,
+            is_ended: false,
+        }
+    }
+}
+
+impl<R: Read> HdtDecoder<R> {
+    fn next(&mut self) -> Option<Result<Triple, RdfParseError>> {
+        if self.is_ended {
+            return None;
+        }
+        match self.read_next() {
+            Ok(Some(triple)) => Some(Ok(triple)),
+            Ok(None) => {
+                self.is_ended = true;
+                None
+            }
+            Err(e) => {
+                self.is_ended = true;
+                Some(Err(match e {
+                    RdfParseError::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                        RdfSyntaxError::hdt("Unexpected end of the HDT file").into()
+                    }
+                    RdfParseError::Io(e) => RdfParseError::Io(e),
+                    RdfParseError::Syntax(e) => RdfParseError::Syntax(e),
+                }))
+            }
+        }
+    }
+
+    fn read_next(&mut self) -> Result<Option<Triple>, RdfParseError> {
+        if self.content.is_none() {
+            self.content = Some(Box::new(HdtContent::read(&mut self.reader, self.lenient)?));
+        }
+        let Some(content) = &mut self.content else {
+            return Ok(None);
+        };
+        content.next_triple(&mut self.reader, self.lenient)
+    }
+}
+
+/// The decoded dictionary and the state of the iteration on the triples.
+struct HdtContent {
+    dictionary: Dictionary,
+    triples: TriplesReader,
+    last_subject: Option<(u64, NamedOrBlankNode)>,
+    last_predicate: Option<(u64, NamedNode)>,
+}
+
+impl HdtContent {
+    fn read(reader: &mut (impl Read + Seek), lenient: bool) -> Result<Self, RdfParseError> {
+        ControlInformation::read(reader, lenient)?.check(GLOBAL_TYPE, HDT_FORMAT)?;
+
+        // We skip the header, it is only metadata about the file
+        let header = ControlInformation::read(reader, lenient)?;
+        header.check(HEADER_TYPE, HEADER_FORMAT)?;
+        let header_length = i64::try_from(header.integer_property("length")?)
+            .map_err(|_| RdfSyntaxError::hdt("The HDT header is too long"))?;
+        reader.seek(SeekFrom::Current(header_length))?;
+
+        let dictionary = ControlInformation::read(reader, lenient)?;
+        dictionary.check(DICTIONARY_TYPE, DICTIONARY_FOUR_FORMAT)?;
+        let objects_after_subjects = match dictionary.property("mapping") {
+            None | Some("1") => false,
+            Some("0") => true,
+            Some(mapping) => {
+                return Err(RdfSyntaxError::hdt(format!(
+                    "Unsupported HDT dictionary mapping {mapping}"
+                ))
+                .into());
+            }
+        };
+        let dictionary = Dictionary {
+            shared: PfcSection::read(reader, lenient)?,
+            subjects: PfcSection::read(reader, lenient)?,
+            predicates: PfcSection::read(reader, lenient)?,
+            objects: PfcSection::read(reader, lenient)?,
+            objects_after_subjects,
+        };
+
+        let triples = ControlInformation::read(reader, lenient)?;
+        if triples.kind != TRIPLES_TYPE {
+            return Err(RdfSyntaxError::hdt("The HDT triples section is missing").into());
+        }
+        let triples = match triples.format.as_str() {
+            TRIPLES_BITMAP_FORMAT => {
+                let order = triples.integer_property("order")?;
+                let order = TripleOrder::from_id(order).ok_or_else(|| {
+                    RdfSyntaxError::hdt(format!("Unsupported HDT triple component order {order}"))
+                })?;
+                TriplesReader::read_bitmap(reader, order, lenient)?
+            }
+            TRIPLES_LIST_FORMAT => {
+                TriplesReader::read_list(reader, triples.integer_property("numTriples")?)?
+            }
+            format => {
+                return Err(RdfSyntaxError::hdt(format!(
+                    "Unsupported HDT triples format {format}"
+                ))
+                .into());
+            }
+        };
+        Ok(Self {
+            dictionary,
+            triples,
+            last_subject: None,
+            last_predicate: None,
+        })
+    }
+
+    fn next_triple(
+        &mut self,
+        reader: &mut (impl Read + Seek),
+        lenient: bool,
+    ) -> Result<Option<Triple>, RdfParseError> {
+        let Some((subject_id, predicate_id, object_id)) = self.triples.next_ids(reader)? else {
+            return Ok(None);
+        };
+        // Consecutive triples usually share their subject or predicate
+        let subject = match &self.last_subject {
+            Some((id, subject)) if *id == subject_id => subject.clone(),
+            _ => {
+                let subject = self.dictionary.subject(subject_id, lenient)?;
+                self.last_subject = Some((subject_id, subject.clone()));
+                subject
+            }
+        };
+        let predicate = match &self.last_predicate {
+            Some((id, predicate)) if *id == predicate_id => predicate.clone(),
+            _ => {
+                let predicate = self.dictionary.predicate(predicate_id, lenient)?;
+                self.last_predicate = Some((predicate_id, predicate.clone()));
+                predicate
+            }
+        };
+        let object = self.dictionary.object(object_id, lenient)?;
+        Ok(Some(Triple::new(subject, predicate, object)))
+    }
+}
+
+/// The "control information" preamble of each HDT section.
+struct ControlInformation {
+    kind: u8,
+    format: String,
+    properties: Vec<(String, String)>,
+}
+
+impl ControlInformation {
+    fn read(reader: &mut impl Read, lenient: bool) -> Result<Self, RdfParseError> {
+        let mut recorder = RecordingReader::new(reader);
+        let mut magic = [0; 4];
+        recorder.read_exact(&mut magic)?;
+        if magic != *b"$HDT" {
+            return Err(RdfSyntaxError::hdt(
+                "Invalid HDT file: the section control information must start with $HDT",
+            )
+            .into());
+        }
+        let kind = read_u8(&mut recorder)?;
+        let format = read_nul_terminated_string(&mut recorder)?;
+        let properties = read_nul_terminated_string(&mut recorder)?
+            .split(';')
+            .filter(|property| !property.is_empty())
+            .map(|property| {
+                let (key, value) = property.split_once('=').ok_or_else(|| {
+                    RdfSyntaxError::hdt(format!(
+                        "Invalid HDT control information property {property}"
+                    ))
+                })?;
+                Ok((key.into(), value.into()))
+            })
+            .collect::<Result<_, RdfSyntaxError>>()?;
+        let expected_checksum = crc16(&recorder.buffer);
+        let mut checksum = [0; 2];
+        reader.read_exact(&mut checksum)?;
+        if !lenient && u16::from_le_bytes(checksum) != expected_checksum {
+            return Err(RdfSyntaxError::hdt("Invalid HDT control information checksum").into());
+        }
+        Ok(Self {
+            kind,
+            format,
+            properties,
+        })
+    }
+
+    fn check(&self, kind: u8, format: &str) -> Result<(), RdfSyntaxError> {
+        if self.kind != kind {
+            return Err(RdfSyntaxError::hdt(format!(
+                "Unexpected HDT section of type {}, expecting type {kind}",
+                self.kind
+            )));
+        }
+        if self.format != format {
+            return Err(RdfSyntaxError::hdt(format!(
+                "Unsupported HDT section format {}, only {format} is supported",
+                self.format
+            )));
+        }
+        Ok(())
+    }
+
+    fn property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find_map(|(k, v)| (k == key).then_some(v.as_str()))
+    }
+
+    fn integer_property(&self, key: &str) -> Result<u64, RdfSyntaxError> {
+        self.property(key)
+            .ok_or_else(|| RdfSyntaxError::hdt(format!("The HDT property {key} is missing")))?
+            .parse()
+            .map_err(|_| RdfSyntaxError::hdt(format!("The HDT property {key} must be an integer")))
+    }
+}
+
+/// A "four sections" dictionary: the shared subjects and objects, the other subjects, the predicates and the other objects.
+struct Dictionary {
+    shared: PfcSection,
+    subjects: PfcSection,
+    predicates: PfcSection,
+    objects: PfcSection,
+    objects_after_subjects: bool,
+}
+
+impl Dictionary {
+    fn subject(&self, id: u64, lenient: bool) -> Result<NamedOrBlankNode, RdfSyntaxError> {
+        match parse_term(self.shared_or(&self.subjects, 0, id)?, lenient)? {
+            Term::NamedNode(node) => Ok(node.into()),
+            Term::BlankNode(node) => Ok(node.into()),
+            Term::Literal(_) => Err(RdfSyntaxError::hdt(
+                "Literals are not allowed in regular RDF subjects",
+            )),
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(_) => Err(RdfSyntaxError::hdt(
+                "Triple terms are not allowed in regular RDF subjects",
+            )),
+        }
+    }
+
+    fn predicate(&self, id: u64, lenient: bool) -> Result<NamedNode, RdfSyntaxError> {
+        let index = id
+            .checked_sub(1)
+            .ok_or_else(|| RdfSyntaxError::hdt("Invalid HDT predicate identifier 0"))?;
+        parse_iri(self.predicates.get(index)?, lenient)
+    }
+
+    fn object(&self, id: u64, lenient: bool) -> Result<Term, RdfSyntaxError> {
+        let skipped = if self.objects_after_subjects {
+            self.subjects.len
+        } else {
+            0
+        };
+        parse_term(self.shared_or(&self.objects, skipped, id)?, lenient)
+    }
+
+    /// Identifiers are first allocated to the shared section and then to the given section.
+    fn shared_or(
+        &self,
+        section: &PfcSection,
+        skipped: u64,
+        id: u64,
+    ) -> Result<String, RdfSyntaxError> {
+        let index = id
+            .checked_sub(1)
+            .ok_or_else(|| RdfSyntaxError::hdt("Invalid HDT term identifier 0"))?;
+        if index < self.shared.len {
+            return self.shared.get(index);
+        }
+        section.get(
+            index
+                .checked_sub(self.shared.len + skipped)
+                .ok_or_else(|| RdfSyntaxError::hdt(format!("Invalid HDT term identifier {id}")))?,
+        )
+    }
+}
+
+/// A dictionary section encoded with plain front coding.
+///
+/// The strings are grouped into blocks: the first string of each block is written in full
+/// and the following ones only write their suffix after the prefix shared with the previous string.
+struct PfcSection {
+    len: u64,
+    block_size: u64,
+    blocks: InMemorySequence,
+    text: Vec<u8>,
+}
+
+impl PfcSection {
+    fn read(reader: &mut impl Read, lenient: bool) -> Result<Self, RdfParseError> {
+        let mut recorder = RecordingReader::new(reader);
+        let kind = read_u8(&mut recorder)?;
+        if kind != PFC_SECTION_TYPE {
+            return Err(RdfSyntaxError::hdt(format!(
+                "Unsupported HDT dictionary section type {kind}, only plain front coding is supported"
+            ))
+            .into());
+        }
+        let len = read_vbyte(&mut recorder)?;
+        let text_len = read_vbyte(&mut recorder)?;
+        let block_size = read_vbyte(&mut recorder)?;
+        let header = recorder.buffer;
+        check_crc8(reader, &header, lenient)?;
+        if block_size == 0 && len > 0 {
+            return Err(RdfSyntaxError::hdt("Invalid HDT dictionary block size 0").into());
+        }
+        let blocks = InMemorySequence::read(reader, lenient)?;
+        let text = read_data(reader, text_len, lenient)?;
+        Ok(Self {
+            len,
+            block_size,
+            blocks,
+            text,
+        })
+    }
+
+    fn get(&self, index: u64) -> Result<String, RdfSyntaxError> {
+        if index >= self.len {
+            return Err(RdfSyntaxError::hdt(format!(
+                "Invalid HDT dictionary index {index}"
+            )));
+        }
+        let invalid = || RdfSyntaxError::hdt("Invalid HDT dictionary section content");
+        let block_start = self
+            .blocks
+            .get(index / self.block_size)
+            .ok_or_else(invalid)?;
+        let mut text = usize::try_from(block_start)
+            .ok()
+            .and_then(|start| self.text.get(start..))
+            .ok_or_else(invalid)?;
+        let (first, rest) = split_nul_terminated(text).ok_or_else(invalid)?;
+        let mut value = first.to_vec();
+        text = rest;
+        for _ in 0..index % self.block_size {
+            let shared_len = read_vbyte(&mut text).map_err(|_| invalid())?;
+            let (suffix, rest) = split_nul_terminated(text).ok_or_else(invalid)?;
+            if shared_len > value.len() as u64 {
+                return Err(invalid());
+            }
+            value.truncate(usize::try_from(shared_len).map_err(|_| invalid())?);
+            value.extend_from_slice(suffix);
+            text = rest;
+        }
+        String::from_utf8(value)
+            .map_err(|_| RdfSyntaxError::hdt("Invalid UTF-8 in the HDT dictionary"))
+    }
+}
+
+/// The [order](https://www.rdfhdt.org/hdt-binary-format/#triples) in which the triple components are stored.
+#[derive(Clone, Copy)]
+enum TripleOrder {
+    Spo,
+    Sop,
+    Pso,
+    Pos,
+    Osp,
+    Ops,
+}
+
+impl TripleOrder {
+    fn from_id(id: u64) -> Option<Self> {
+        Some(match id {
+            1 => Self::Spo,
+            2 => Self::Sop,
+            3 => Self::Pso,
+            4 => Self::Pos,
+            5 => Self::Osp,
+            6 => Self::Ops,
+            _ => return None,
+        })
+    }
+
+    /// Maps the stored (x, y, z) ids to (subject, predicate, object) ids.
+    fn to_spo(self, x: u64, y: u64, z: u64) -> (u64, u64, u64) {
+        match self {
+            Self::Spo => (x, y, z),
+            Self::Sop => (x, z, y),
+            Self::Pso => (y, x, z),
+            Self::Pos => (z, x, y),
+            Self::Osp => (y, z, x),
+            Self::Ops => (z, y, x),
+        }
+    }
+}
+
+#[expect(clippy::large_enum_variant)]
+enum TriplesReader {
+    /// The y and z components of the triples are stored in two sequences,
+    /// with two bitmaps marking the last y of each x and the last z of each (x, y).
+    Bitmap {
+        order: TripleOrder,
+        bitmap_y: StreamedSequence,
+        bitmap_z: StreamedSequence,
+        array_y: StreamedSequence,
+        array_z: StreamedSequence,
+        x: u64,
+        position_y: u64,
+        position_z: u64,
+    },
+    /// A plain list of (subject, predicate, object) ids
+    List {
+        ids: StreamedSequence,
+        position: u64,
+    },
+}
+
+impl TriplesReader {
+    fn read_bitmap(
+        reader: &mut (impl Read + Seek),
+        order: TripleOrder,
+        lenient: bool,
+    ) -> Result<Self, RdfParseError> {
+        let bitmap_y = StreamedSequence::read_bitmap(reader, lenient)?;
+        let bitmap_z = StreamedSequence::read_bitmap(reader, lenient)?;
+        let array_y = StreamedSequence::read_log_sequence(reader, lenient)?;
+        let array_z = StreamedSequence::read_log_sequence(reader, lenient)?;
+        if bitmap_y.len != array_y.len || bitmap_z.len != array_z.len {
+            return Err(RdfSyntaxError::hdt(
+                "The HDT triples bitmaps and sequences must have the same length",
+            )
+            .into());
+        }
+        Ok(Self::Bitmap {
+            order,
+            bitmap_y,
+            bitmap_z,
+            array_y,
+            array_z,
+            x: 1,
+            position_y: 0,
+            position_z: 0,
+        })
+    }
+
+    fn read_list(reader: &mut (impl Read + Seek), len: u64) -> Result<Self, RdfParseError> {
+        let len = len
+            .checked_mul(3)
+            .ok_or_else(|| RdfSyntaxError::hdt("Too many HDT triples"))?;
+        Ok(Self::List {
+            ids: StreamedSequence::new(reader.stream_position()?, 64, len)?,
+            position: 0,
+        })
+    }
+
+    fn next_ids(
+        &mut self,
+        reader: &mut (impl Read + Seek),
+    ) -> Result<Option<(u64, u64, u64)>, RdfParseError> {
+        match self {
+            Self::Bitmap {
+                order,
+                bitmap_y,
+                bitmap_z,
+                array_y,
+                array_z,
+                x,
+                position_y,
+                position_z,
+            } => {
+                if *position_z >= array_z.len {
+                    return Ok(None);
+                }
+                if *position_y >= array_y.len {
+                    return Err(RdfSyntaxError::hdt("Invalid HDT triples bitmap").into());
+                }
+                let y = array_y.get(reader, *position_y)?;
+                let z = array_z.get(reader, *position_z)?;
+                let ids = order.to_spo(*x, y, z);
+                if bitmap_z.get(reader, *position_z)? == 1 {
+                    if bitmap_y.get(reader, *position_y)? == 1 {
+                        *x += 1;
+                    }
+                    *position_y += 1;
+                }
+                *position_z += 1;
+                Ok(Some(ids))
+            }
+            Self::List { ids, position } => {
+                if *position >= ids.len {
+                    return Ok(None);
+                }
+                let triple = (
+                    ids.get(reader, *position)?,
+                    ids.get(reader, *position + 1)?,
+                    ids.get(reader, *position + 2)?,
+                );
+                *position += 3;
+                Ok(Some(triple))
+            }
+        }
+    }
+}
+
+/// A sequence of fixed width integers loaded in memory.
+struct InMemorySequence {
+    bits: u8,
+    len: u64,
+    data: Vec<u8>,
+}
+
+impl InMemorySequence {
+    fn read(reader: &mut impl Read, lenient: bool) -> Result<Self, RdfParseError> {
+        let (bits, len) = read_log_sequence_header(reader, lenient)?;
+        let data = read_data(reader, data_len(bits, len)?, lenient)?;
+        Ok(Self { bits, len, data })
+    }
+
+    fn get(&self, index: u64) -> Option<u64> {
+        if index >= self.len {
+            return None;
+        }
+        read_bits(&self.data, index * u64::from(self.bits), self.bits)
+    }
+}
+
+/// A sequence of fixed width integers read lazily from the file, one window at a time.
+///
+/// Its checksum is not validated because the sequence is never fully loaded.
+struct StreamedSequence {
+    bits: u8,
+    len: u64,
+    offset: u64,
+    data_len: u64,
+    window: Vec<u8>,
+    window_start: u64,
+}
+
+impl StreamedSequence {
+    fn new(offset: u64, bits: u8, len: u64) -> Result<Self, RdfSyntaxError> {
+        Ok(Self {
+            bits,
+            len,
+            offset,
+            data_len: data_len(bits, len)?,
+            window: Vec::new(),
+            window_start: 0,
+        })
+    }
+
+    fn read_bitmap(reader: &mut (impl Read + Seek), lenient: bool) -> Result<Self, RdfParseError> {
+        let mut recorder = RecordingReader::new(reader);
+        let kind = read_u8(&mut recorder)?;
+        if kind != PLAIN_BITMAP_TYPE {
+            return Err(RdfSyntaxError::hdt(format!("Unsupported HDT bitmap type {kind}")).into());
+        }
+        let len = read_vbyte(&mut recorder)?;
+        let header = recorder.buffer;
+        check_crc8(reader, &header, lenient)?;
+        Self::skip_data(reader, 1, len)
+    }
+
+    fn read_log_sequence(
+        reader: &mut (impl Read + Seek),
+        lenient: bool,
+    ) -> Result<Self, RdfParseError> {
+        let (bits, len) = read_log_sequence_header(reader, lenient)?;
+        Self::skip_data(reader, bits, len)
+    }
+
+    /// Records the position of the sequence data and moves after it and its checksum.
+    fn skip_data(
+        reader: &mut (impl Read + Seek),
+        bits: u8,
+        len: u64,
+    ) -> Result<Self, RdfParseError> {
+        let sequence = Self::new(reader.stream_position()?, bits, len)?;
+        let skipped = i64::try_from(sequence.data_len + 4)
+            .map_err(|_| RdfSyntaxError::hdt("The HDT triples section is too long"))?;
+        reader.seek(SeekFrom::Current(skipped))?;
+        Ok(sequence)
+    }
+
+    fn get(&mut self, reader: &mut (impl Read + Seek), index: u64) -> Result<u64, RdfParseError> {
+        let invalid = || RdfSyntaxError::hdt("Invalid HDT triples section");
+        if index >= self.len {
+            return Err(invalid().into());
+        }
+        let bits = u64::from(self.bits);
+        let start_bit = index * bits;
+        let start = start_bit / 8;
+        let end = (start_bit + bits).div_ceil(8);
+        if start < self.window_start || end > self.window_start + self.window.len() as u64 {
+            let window_len =
+                usize::try_from(self.data_len.min(start + WINDOW_SIZE).max(end) - start)
+                    .map_err(|_| invalid())?;
+            self.window.resize(window_len, 0);
+            reader.seek(SeekFrom::Start(self.offset + start))?;
+            reader.read_exact(&mut self.window)?;
+            self.window_start = start;
+        }
+        read_bits(&self.window, start_bit - self.window_start * 8, self.bits)
+            .ok_or_else(|| invalid().into())
+    }
+}
+
+/// Reads the header of a `LogSequence2` and returns the number of bits per entry and the number of entries.
+fn read_log_sequence_header(
+    reader: &mut impl Read,
+    lenient: bool,
+) -> Result<(u8, u64), RdfParseError> {
+    let mut recorder = RecordingReader::new(reader);
+    let kind = read_u8(&mut recorder)?;
+    if kind != LOG_SEQUENCE_TYPE {
+        return Err(RdfSyntaxError::hdt(format!(
+            "Unsupported HDT integer sequence type {kind}, only LogSequence2 is supported"
+        ))
+        .into());
+    }
+    let bits = read_u8(&mut recorder)?;
+    if bits > 64 {
+        return Err(RdfSyntaxError::hdt(format!(
+            "Invalid HDT integer sequence with {bits} bits per entry"
+        ))
+        .into());
+    }
+    let len = read_vbyte(&mut recorder)?;
+    let header = recorder.buffer;
+    check_crc8(reader, &header, lenient)?;
+    Ok((bits, len))
+}
+
+/// Number of bytes used to store `len` entries of `bits` bits.
+fn data_len(bits: u8, len: u64) -> Result<u64, RdfSyntaxError> {
+    Ok(u64::from(bits)
+        .checked_mul(len)
+        .ok_or_else(|| RdfSyntaxError::hdt("Too long HDT integer sequence"))?
+        .div_ceil(8))
+}
+
+/// Reads the `bits` bits integer starting at the given bit of `data`, bits being ordered from the least significant.
+fn read_bits(data: &[u8], start_bit: u64, bits: u8) -> Option<u64> {
+    let first_byte = usize::try_from(start_bit / 8).ok()?;
+    let shift = start_bit % 8;
+    let byte_count = usize::try_from((shift + u64::from(bits)).div_ceil(8)).ok()?;
+    let mut value = 0_u128;
+    for (i, byte) in data
+        .get(first_byte..first_byte + byte_count)?
+        .iter()
+        .enumerate()
+    {
+        value |= u128::from(*byte) << (8 * i);
+    }
+    value >>= shift;
+    if bits < 64 {
+        value &= (1 << bits) - 1;
+    }
+    u64::try_from(value & u128::from(u64::MAX)).ok()
+}
+
+/// Reads `len` bytes followed by their CRC32-C checksum.
+fn read_data(reader: &mut impl Read, len: u64, lenient: bool) -> Result<Vec<u8>, RdfParseError> {
+    // We do not trust the length to preallocate the buffer
+    let mut data = Vec::new();
+    if reader.take(len).read_to_end(&mut data)? as u64 != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    let mut checksum = [0; 4];
+    reader.read_exact(&mut checksum)?;
+    if !lenient && u32::from_le_bytes(checksum) != crc32c(&data) {
+        return Err(RdfSyntaxError::hdt("Invalid HDT data checksum").into());
+    }
+    Ok(data)
+}
+
+fn check_crc8(reader: &mut impl Read, data: &[u8], lenient: bool) -> Result<(), RdfParseError> {
+    let checksum = read_u8(reader)?;
+    if !lenient && checksum != crc8(data) {
+        return Err(RdfSyntaxError::hdt("Invalid HDT header checksum").into());
+    }
+    Ok(())
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut byte = [0];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+/// Reads a variable length integer: 7 bits per byte starting from the least significant, the last byte having its most significant bit set.
+fn read_vbyte(reader: &mut impl Read) -> Result<u64, RdfParseError> {
+    let mut value = 0_u64;
+    for shift in (0..64).step_by(7) {
+        let byte = read_u8(reader)?;
+        value |= u64::from(byte & 0x7F)
+            .checked_shl(shift)
+            .filter(|v| v >> shift == u64::from(byte & 0x7F))
+            .ok_or_else(|| RdfSyntaxError::hdt("Too large HDT variable length integer"))?;
+        if byte & 0x80 != 0 {
+            return Ok(value);
+        }
+    }
+    Err(RdfSyntaxError::hdt("Too large HDT variable length integer").into())
+}
+
+fn read_nul_terminated_string(reader: &mut impl Read) -> Result<String, RdfParseError> {
+    let mut value = Vec::new();
+    loop {
+        match read_u8(reader)? {
+            0 => break,
+            byte => value.push(byte),
+        }
+    }
+    Ok(String::from_utf8(value)
+        .map_err(|_| RdfSyntaxError::hdt("Invalid UTF-8 in HDT control information"))?)
+}
+
+fn split_nul_terminated(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let end = data.iter().position(|b| *b == 0)?;
+    Some((&data[..end], &data[end + 1..]))
+}
+
+/// Parses a term as written in the dictionary: `"value"@lang` or `"value"^^<datatype>` for literals, `_:id` for blank nodes and the raw IRI for named nodes.
+fn parse_term(value: String, lenient: bool) -> Result<Term, RdfSyntaxError> {
+    if let Some(literal) = value.strip_prefix('"') {
+        let (lexical_form, suffix) = literal
+            .rsplit_once('"')
+            .ok_or_else(|| RdfSyntaxError::hdt(format!("Invalid HDT literal {value}")))?;
+        return Ok(if suffix.is_empty() {
+            Literal::new_simple_literal(lexical_form)
+        } else if let Some(language) = suffix.strip_prefix('@') {
+            if lenient {
+                Literal::new_language_tagged_literal_unchecked(lexical_form, language)
+            } else {
+                Literal::new_language_tagged_literal(lexical_form, language).map_err(|e| {
+                    RdfSyntaxError::hdt(format!("Invalid HDT language tag {language}: {e}"))
+                })?
+            }
+        } else if let Some(datatype) = suffix
+            .strip_prefix("^^<")
+            .and_then(|datatype| datatype.strip_suffix('>'))
+        {
+            Literal::new_typed_literal(lexical_form, parse_iri(datatype.into(), lenient)?)
+        } else {
+            return Err(RdfSyntaxError::hdt(format!("Invalid HDT literal {value}")));
+        }
+        .into());
+    }
+    if let Some(id) = value.strip_prefix("_:") {
+        return Ok(if lenient {
+            BlankNode::new_unchecked(id)
+        } else {
+            BlankNode::new(id).map_err(|e| {
+                RdfSyntaxError::hdt(format!("Invalid HDT blank node identifier {id}: {e}"))
+            })?
+        }
+        .into());
+    }
+    Ok(parse_iri(value, lenient)?.into())
+}
+
+fn parse_iri(value: String, lenient: bool) -> Result<NamedNode, RdfSyntaxError> {
+    if lenient {
+        return Ok(NamedNode::new_unchecked(value));
+    }
+    NamedNode::new(value).map_err(|e| RdfSyntaxError::hdt(format!("Invalid HDT IRI: {e}")))
+}
+
+/// Wraps a reader to keep the read bytes in order to compute their checksum.
+struct RecordingReader<'a, R> {
+    inner: &'a mut R,
+    buffer: Vec<u8>,
+}
+
+impl<'a, R> RecordingReader<'a, R> {
+    fn new(inner: &'a mut R) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl<R: Read> Read for RecordingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.buffer.extend_from_slice(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// CRC-8-CCITT as used by hdt-cpp for the section headers
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0_u8;
+    for byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ 0x07
+            };
+        }
+    }
+    crc
+}
+
+/// CRC-16-ANSI as used by hdt-cpp for the control information
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0_u16;
+    for byte in data {
+        crc ^= u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0xA001
+            };
+        }
+    }
+    crc
+}
+
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        #[expect(clippy::cast_possible_truncation)]
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0x82F6_3B78
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32C (Castagnoli) as used by hdt-cpp for the section data
+fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, byte| {
+        CRC32C_TABLE[usize::from(crc.to_le_bytes()[0] ^ byte)] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streamed_sequence_windows() -> Result<(), RdfParseError> {
+        // Large enough to require multiple windows
+        let bits = 17;
+        let values = (0..100_000)
+            .map(|i| (i * 7919) % (1 << bits))
+            .collect::<Vec<u64>>();
+        let mut data = vec![0; 212_500];
+        for (i, value) in values.iter().enumerate() {
+            for bit in 0..usize::from(bits) {
+                if value >> bit & 1 == 1 {
+                    let position = i * usize::from(bits) + bit;
+                    data[position / 8] |= 1 << (position % 8);
+                }
+            }
+        }
+        let mut reader = Cursor::new(data);
+        let mut sequence = StreamedSequence::new(0, bits, 100_000)?;
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(sequence.get(&mut reader, i as u64)?, *value);
+        }
+        for i in [99_999, 0, 50_000, 3] {
+            assert_eq!(sequence.get(&mut reader, i as u64)?, values[i]);
+        }
+        sequence.get(&mut reader, 100_000).unwrap_err();
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_vbyte() -> Result<(), RdfParseError> {
+        assert_eq!(read_vbyte(&mut [0x80].as_slice())?, 0);
+        assert_eq!(read_vbyte(&mut [0xFF].as_slice())?, 127);
+        assert_eq!(read_vbyte(&mut [0x00, 0x81].as_slice())?, 128);
+        read_vbyte(&mut [0x7F; 11].as_slice()).unwrap_err();
+        Ok(())
+    }
+}
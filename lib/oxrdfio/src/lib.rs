@@ -4,19 +4,34 @@
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
+mod csv;
 mod document;
+mod encoding;
 mod error;
 mod format;
+#[cfg(feature = "hdt")]
+mod hdt;
+mod model;
 mod parser;
 mod serializer;
+mod skolemization;
 
+pub use csv::{CsvRdfParser, ReaderCsvRdfParser};
 pub use document::LoadedDocument;
-pub use error::{RdfParseError, RdfSyntaxError, TextPosition};
+pub use encoding::{ContentEncoding, DecodingReader};
+pub use error::{RdfParseError, RdfSyntaxError, SerializeFromIterError, TextPosition};
 pub use format::RdfFormat;
+pub use model::{DatasetExt, GraphExt};
 pub use oxjsonld::{JsonLdProfile, JsonLdProfileSet};
+pub use oxttl::TurtleSyntaxErrorKind;
 #[cfg(feature = "async-tokio")]
 pub use parser::TokioAsyncReaderQuadParser;
-pub use parser::{RdfParser, ReaderQuadParser, SliceQuadParser};
+pub use parser::{
+    LiteralDecision, RdfParser, RdfStarPolicy, ReaderQuadParser, SliceQuadParser, ValidationPolicy,
+};
 #[cfg(feature = "async-tokio")]
 pub use serializer::TokioAsyncWriterQuadSerializer;
-pub use serializer::{RdfSerializer, WriterQuadSerializer};
+pub use serializer::{
+    InvalidIriPolicy, RdfSerializer, RdfStarSerializationPolicy, SerializableStatement,
+    WriterQuadSerializer,
+};
@@ -0,0 +1,311 @@
+use crate::{RdfFormat, RdfParseError, RdfParser, RdfSerializer, RdfSyntaxError};
+use oxrdf::{Dataset, Graph, Triple};
+use std::io::{self, Read, Write};
+
+/// Parsing and serialization helpers for [`Graph`].
+///
+/// They wire up [`RdfParser`] and [`RdfSerializer`] with their default settings.
+/// Use them directly to get more control on the parsing and serialization.
+///
+/// ```
+/// use oxrdf::Graph;
+/// use oxrdfio::{GraphExt, RdfFormat};
+///
+/// let graph = Graph::from_read(
+///     "<s> <p> <o> .".as_bytes(),
+///     RdfFormat::Turtle,
+///     Some("http://example.com/"),
+///     false,
+/// )?;
+/// assert_eq!(
+///     graph.serialize_to_string(RdfFormat::NTriples)?,
+///     "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n"
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub trait GraphExt: Sized {
+    /// Parses a graph from a [`Read`] implementation.
+    ///
+    /// If `merge_named_graphs` is `false`, the parsing fails if the file contains a named graph.
+    /// If it is `true`, the triples of all the graphs are merged together.
+    ///
+    /// ```
+    /// use oxrdf::Graph;
+    /// use oxrdfio::{GraphExt, RdfFormat};
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://example.com/g> .";
+    ///
+    /// assert!(Graph::from_read(file.as_bytes(), RdfFormat::NQuads, None, false).is_err());
+    /// let graph = Graph::from_read(file.as_bytes(), RdfFormat::NQuads, None, true)?;
+    /// assert_eq!(graph.len(), 1);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    fn from_read(
+        reader: impl Read,
+        format: RdfFormat,
+        base_iri: Option<&str>,
+        merge_named_graphs: bool,
+    ) -> Result<Self, RdfParseError>;
+
+    /// Serializes the graph to a [`Write`] implementation.
+    ///
+    /// It fails if `format` does not support the graph content, like HDT that can't be serialized.
+    fn write_to<W: Write>(&self, writer: W, format: RdfFormat) -> io::Result<W>;
+
+    /// Serializes the graph to a [`String`].
+    ///
+    /// It fails if the format is not a text format.
+    fn serialize_to_string(&self, format: RdfFormat) -> io::Result<String>;
+}
+
+impl GraphExt for Graph {
+    fn from_read(
+        reader: impl Read,
+        format: RdfFormat,
+        base_iri: Option<&str>,
+        merge_named_graphs: bool,
+    ) -> Result<Self, RdfParseError> {
+        let mut parser = parser(format, base_iri)?;
+        if !merge_named_graphs {
+            parser = parser.without_named_graphs();
+        }
+        parser
+            .for_reader(reader)
+            .map(|quad| Ok(Triple::from(quad?)))
+            .collect()
+    }
+
+    fn write_to<W: Write>(&self, writer: W, format: RdfFormat) -> io::Result<W> {
+        let mut serializer = RdfSerializer::try_from_format(format)?.for_writer(writer);
+        for triple in self {
+            serializer.serialize_triple(triple)?;
+        }
+        serializer.finish()
+    }
+
+    fn serialize_to_string(&self, format: RdfFormat) -> io::Result<String> {
+        into_string(self.write_to(Vec::new(), format)?)
+    }
+}
+
+/// Parsing and serialization helpers for [`Dataset`].
+///
+/// They wire up [`RdfParser`] and [`RdfSerializer`] with their default settings.
+/// Use them directly to get more control on the parsing and serialization.
+///
+/// ```
+/// use oxrdf::Dataset;
+/// use oxrdfio::{DatasetExt, RdfFormat};
+///
+/// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://example.com/g> .\n";
+/// let dataset = Dataset::from_read(file.as_bytes(), RdfFormat::NQuads, None)?;
+/// assert_eq!(dataset.serialize_to_string(RdfFormat::NQuads)?, file);
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub trait DatasetExt: Sized {
+    /// Parses a dataset from a [`Read`] implementation.
+    ///
+    /// The triples of the graph formats are added to the default graph.
+    fn from_read(
+        reader: impl Read,
+        format: RdfFormat,
+        base_iri: Option<&str>,
+    ) -> Result<Self, RdfParseError>;
+
+    /// Serializes the dataset to a [`Write`] implementation.
+    ///
+    /// It fails if the dataset contains named graphs and `format` only supports graphs.
+    fn write_to<W: Write>(&self, writer: W, format: RdfFormat) -> io::Result<W>;
+
+    /// Serializes the dataset to a [`String`].
+    ///
+    /// It fails if the format is not a text format.
+    fn serialize_to_string(&self, format: RdfFormat) -> io::Result<String>;
+}
+
+impl DatasetExt for Dataset {
+    fn from_read(
+        reader: impl Read,
+        format: RdfFormat,
+        base_iri: Option<&str>,
+    ) -> Result<Self, RdfParseError> {
+        parser(format, base_iri)?.for_reader(reader).collect()
+    }
+
+    fn write_to<W: Write>(&self, writer: W, format: RdfFormat) -> io::Result<W> {
+        let mut serializer = RdfSerializer::try_from_format(format)?.for_writer(writer);
+        for quad in self {
+            serializer.serialize_quad(quad)?;
+        }
+        serializer.finish()
+    }
+
+    fn serialize_to_string(&self, format: RdfFormat) -> io::Result<String> {
+        into_string(self.write_to(Vec::new(), format)?)
+    }
+}
+
+fn parser(format: RdfFormat, base_iri: Option<&str>) -> Result<RdfParser, RdfParseError> {
+    let parser = RdfParser::from_format(format);
+    Ok(if let Some(base_iri) = base_iri {
+        parser
+            .with_base_iri(base_iri)
+            .map_err(RdfSyntaxError::from)?
+    } else {
+        parser
+    })
+}
+
+fn into_string(buffer: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(buffer).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use oxjsonld::JsonLdProfileSet;
+    use oxrdf::{GraphNameRef, NamedNodeRef, QuadRef, TripleRef};
+
+    const S: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://example.com/s");
+    const P: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://example.com/p");
+    const O: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://example.com/o");
+    const G: NamedNodeRef<'_> = NamedNodeRef::new_unchecked("http://example.com/g");
+
+    #[test]
+    fn graph_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let mut graph = Graph::new();
+        graph.insert(TripleRef::new(S, P, O));
+        for format in [
+            RdfFormat::NTriples,
+            RdfFormat::Turtle,
+            RdfFormat::RdfXml,
+            RdfFormat::N3,
+            RdfFormat::JsonLd {
+                profile: JsonLdProfileSet::empty(),
+            },
+        ] {
+            let serialized = graph.serialize_to_string(format)?;
+            assert_eq!(
+                Graph::from_read(serialized.as_bytes(), format, None, false)?,
+                graph,
+                "{format}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn graph_from_dataset_format() -> Result<(), RdfParseError> {
+        let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://example.com/g> .";
+        assert!(matches!(
+            Graph::from_read(file.as_bytes(), RdfFormat::NQuads, None, false),
+            Err(RdfParseError::Syntax(_))
+        ));
+        let graph = Graph::from_read(file.as_bytes(), RdfFormat::NQuads, None, true)?;
+        assert_eq!(graph.len(), 1);
+        assert!(graph.contains(TripleRef::new(S, P, O)));
+        // The dataset formats without named graphs are fine
+        let graph = Graph::from_read(
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .".as_bytes(),
+            RdfFormat::TriG,
+            None,
+            false,
+        )?;
+        assert_eq!(graph.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn graph_base_iri() -> Result<(), RdfParseError> {
+        let graph = Graph::from_read(
+            "<s> <p> <o> .".as_bytes(),
+            RdfFormat::Turtle,
+            Some("http://example.com/"),
+            false,
+        )?;
+        assert!(graph.contains(TripleRef::new(S, P, O)));
+        assert!(matches!(
+            Graph::from_read(
+                "<s> <p> <o> .".as_bytes(),
+                RdfFormat::Turtle,
+                Some("not an iri"),
+                false,
+            ),
+            Err(RdfParseError::Syntax(e)) if e.location().is_none()
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn graph_invalid_syntax() {
+        assert!(matches!(
+            Graph::from_read(
+                "<http://example.com/s> <http://example.com/p> .".as_bytes(),
+                RdfFormat::NTriples,
+                None,
+                false,
+            ),
+            Err(RdfParseError::Syntax(e)) if e.location().is_some()
+        ));
+    }
+
+    #[test]
+    fn graph_io_error() {
+        struct FailingRead;
+
+        impl Read for FailingRead {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failure"))
+            }
+        }
+
+        assert!(matches!(
+            Graph::from_read(FailingRead, RdfFormat::NTriples, None, false),
+            Err(RdfParseError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn dataset_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let mut dataset = Dataset::new();
+        dataset.insert(QuadRef::new(S, P, O, GraphNameRef::DefaultGraph));
+        dataset.insert(QuadRef::new(S, P, O, G));
+        for format in [
+            RdfFormat::NQuads,
+            RdfFormat::TriG,
+            RdfFormat::JsonLd {
+                profile: JsonLdProfileSet::empty(),
+            },
+        ] {
+            let serialized = dataset.serialize_to_string(format)?;
+            assert_eq!(
+                Dataset::from_read(serialized.as_bytes(), format, None)?,
+                dataset,
+                "{format}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dataset_from_graph_format() -> Result<(), RdfParseError> {
+        let dataset = Dataset::from_read(
+            "<s> <p> <o> .".as_bytes(),
+            RdfFormat::Turtle,
+            Some("http://example.com/"),
+        )?;
+        assert!(dataset.contains(QuadRef::new(S, P, O, GraphNameRef::DefaultGraph)));
+        Ok(())
+    }
+
+    #[test]
+    fn dataset_to_graph_format() {
+        let mut dataset = Dataset::new();
+        dataset.insert(QuadRef::new(S, P, O, G));
+        let error = dataset
+            .serialize_to_string(RdfFormat::NTriples)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -2,14 +2,22 @@
 
 pub use crate::error::RdfParseError;
 use crate::format::RdfFormat;
-use crate::{LoadedDocument, RdfSyntaxError};
+#[cfg(all(feature = "hdt", feature = "async-tokio"))]
+use crate::hdt::TokioAsyncReaderHdtParser;
+#[cfg(feature = "hdt")]
+use crate::hdt::{HdtParser, ReaderHdtParser, SliceHdtParser};
+use crate::skolemization::Skolemization;
+use crate::{ContentEncoding, DecodingReader, LoadedDocument, RdfSyntaxError};
 #[cfg(feature = "async-tokio")]
 use oxjsonld::TokioAsyncReaderJsonLdParser;
 use oxjsonld::{
     JsonLdParser, JsonLdPrefixesIter, JsonLdProfileSet, JsonLdRemoteDocument, ReaderJsonLdParser,
     SliceJsonLdParser,
 };
-use oxrdf::{BlankNode, GraphName, IriParseError, NamedOrBlankNode, Quad, Term, Triple};
+use oxrdf::{
+    BlankNode, DatatypeError, GraphName, GraphNameRef, IriNormalizationPolicy, IriParseError,
+    Literal, LiteralRef, NamedNode, NamedOrBlankNode, Quad, Skolemizer, Term, Triple,
+};
 #[cfg(feature = "async-tokio")]
 use oxrdfxml::TokioAsyncReaderRdfXmlParser;
 use oxrdfxml::{RdfXmlParser, RdfXmlPrefixesIter, ReaderRdfXmlParser, SliceRdfXmlParser};
@@ -32,9 +40,10 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io;
-use std::io::{Read, Take};
+use std::io::{Read, Seek, Take};
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::path::Path;
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::AsyncRead;
 
@@ -48,6 +57,7 @@ use tokio::io::AsyncRead;
 /// * [RDF/XML](https://www.w3.org/TR/rdf-syntax-grammar/) ([`RdfFormat::RdfXml`])
 /// * [TriG](https://www.w3.org/TR/trig/) ([`RdfFormat::TriG`])
 /// * [Turtle](https://www.w3.org/TR/turtle/) ([`RdfFormat::Turtle`])
+/// * [HDT](https://www.rdfhdt.org/hdt-binary-format/) ([`RdfFormat::Hdt`]) if the `hdt` feature is enabled
 ///
 /// Note the useful options:
 /// - [`with_base_iri`](Self::with_base_iri) to resolve the relative IRIs.
@@ -75,6 +85,121 @@ pub struct RdfParser {
     default_graph: GraphName,
     without_named_graphs: bool,
     rename_blank_nodes: bool,
+    blank_node_id_seed: Option<u128>,
+    graph_mapper: Option<GraphMapper>,
+    literal_validation: ValidationPolicy,
+    literal_policy: Option<LiteralPolicy>,
+    rdf_star: RdfStarPolicy,
+    iri_normalization: Option<IriNormalizationPolicy>,
+    skolemization: Option<Skolemization>,
+}
+
+type GraphMapper = Arc<dyn Fn(GraphNameRef<'_>) -> GraphName + Send + Sync>;
+type LiteralPolicy = Arc<dyn Fn(LiteralRef<'_>) -> LiteralDecision + Send + Sync>;
+
+/// What to do with the literals whose lexical form is not valid for their datatype, like `"abc"^^xsd:integer`.
+///
+/// Only the XSD datatypes recognized by [`Literal::parsed_value`](oxrdf::Literal::parsed_value) are checked.
+///
+/// It is set using [`RdfParser::with_literal_validation`].
+#[derive(Clone, Default)]
+pub enum ValidationPolicy {
+    /// Returns a syntax error, located on the literal if the format parser provides locations.
+    Reject,
+    /// Calls the given callback and returns the literal as is.
+    Warn(Arc<dyn Fn(&DatatypeError) + Send + Sync>),
+    /// Does not validate literals (the default).
+    #[default]
+    Ignore,
+}
+
+/// What to do with a parsed literal according to the policy set using [`RdfParser::with_literal_policy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiteralDecision {
+    /// Returns the literal as is.
+    Keep,
+    /// Replaces the literal with the given one.
+    Rewrite(Literal),
+    /// Returns a syntax error with the given message, located on the literal if the format parser provides locations.
+    Reject(String),
+}
+
+impl LiteralDecision {
+    /// Checks that the language tag of `literal`, if any, is well-formed according to [BCP47](https://tools.ietf.org/html/bcp47).
+    ///
+    /// Literals with an invalid language tag are rejected.
+    /// If `normalize_case` is set, the valid language tags are rewritten to lowercase, the case used by this implementation.
+    /// It is useful with [`RdfParser::lenient`] that does not validate language tags.
+    ///
+    /// ```
+    /// use oxrdf::Literal;
+    /// use oxrdfio::LiteralDecision;
+    ///
+    /// assert_eq!(
+    ///     LiteralDecision::check_language_tag(Literal::new_language_tagged_literal_unchecked("foo", "en-US").as_ref(), true),
+    ///     LiteralDecision::Rewrite(Literal::new_language_tagged_literal("foo", "en-us")?)
+    /// );
+    /// assert!(matches!(
+    ///     LiteralDecision::check_language_tag(Literal::new_language_tagged_literal_unchecked("foo", "en-a").as_ref(), true),
+    ///     LiteralDecision::Reject(_)
+    /// ));
+    /// assert_eq!(
+    ///     LiteralDecision::check_language_tag(Literal::from(1).as_ref(), true),
+    ///     LiteralDecision::Keep
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn check_language_tag(literal: LiteralRef<'_>, normalize_case: bool) -> Self {
+        let Some(language) = literal.language() else {
+            return Self::Keep;
+        };
+        #[cfg(feature = "rdf-12")]
+        let normalized = if let Some(direction) = literal.direction() {
+            Literal::new_directional_language_tagged_literal(literal.value(), language, direction)
+        } else {
+            Literal::new_language_tagged_literal(literal.value(), language)
+        };
+        #[cfg(not(feature = "rdf-12"))]
+        let normalized = Literal::new_language_tagged_literal(literal.value(), language);
+        match normalized {
+            Ok(normalized) => {
+                if normalize_case && normalized.language() != Some(language) {
+                    Self::Rewrite(normalized)
+                } else {
+                    Self::Keep
+                }
+            }
+            Err(e) => Self::Reject(format!(
+                "The language tag '{language}' is not a valid BCP47 language tag: {e}"
+            )),
+        }
+    }
+
+    fn apply(self, literal: Literal) -> Result<Literal, String> {
+        match self {
+            Self::Keep => Ok(literal),
+            Self::Rewrite(literal) => Ok(literal),
+            Self::Reject(message) => Err(message),
+        }
+    }
+}
+
+/// What to do with the RDF 1.2 triple terms (RDF-star quoted triples) like `<<( ex:s ex:p ex:o )>>`.
+///
+/// Triple terms are only parsed if the `rdf-12` feature is enabled.
+/// Reified triples `<< ex:s ex:p ex:o >>` and annotations `{| |}` are considered as triple terms
+/// because they are parsed into a `rdf:reifies` triple whose object is a triple term.
+///
+/// It is set using [`RdfParser::with_rdf_star`].
+#[derive(Clone, Default)]
+pub enum RdfStarPolicy {
+    /// Returns the quads with triple terms as is (the default).
+    #[default]
+    Accept,
+    /// Returns a syntax error, located on the opening `<<` if the format parser provides locations.
+    Reject,
+    /// Does not return the quads with a triple term and calls the given callback with each of them.
+    Strip(Arc<dyn Fn(&Quad) + Send + Sync>),
 }
 
 #[derive(Clone)]
@@ -86,6 +211,8 @@ enum RdfParserKind {
     RdfXml(RdfXmlParser),
     TriG(TriGParser),
     Turtle(TurtleParser),
+    #[cfg(feature = "hdt")]
+    Hdt(HdtParser),
 }
 
 impl RdfParser {
@@ -103,10 +230,19 @@ impl RdfParser {
                 RdfFormat::RdfXml => RdfParserKind::RdfXml(RdfXmlParser::new()),
                 RdfFormat::TriG => RdfParserKind::TriG(TriGParser::new()),
                 RdfFormat::Turtle => RdfParserKind::Turtle(TurtleParser::new()),
+                #[cfg(feature = "hdt")]
+                RdfFormat::Hdt => RdfParserKind::Hdt(HdtParser::new()),
             },
             default_graph: GraphName::DefaultGraph,
             without_named_graphs: false,
             rename_blank_nodes: false,
+            blank_node_id_seed: None,
+            graph_mapper: None,
+            literal_validation: ValidationPolicy::Ignore,
+            literal_policy: None,
+            rdf_star: RdfStarPolicy::Accept,
+            iri_normalization: None,
+            skolemization: None,
         }
     }
 
@@ -129,6 +265,8 @@ impl RdfParser {
             RdfParserKind::RdfXml(_) => RdfFormat::RdfXml,
             RdfParserKind::TriG(_) => RdfFormat::TriG,
             RdfParserKind::Turtle(_) => RdfFormat::Turtle,
+            #[cfg(feature = "hdt")]
+            RdfParserKind::Hdt(_) => RdfFormat::Hdt,
         }
     }
 
@@ -158,6 +296,8 @@ impl RdfParser {
             RdfParserKind::RdfXml(p) => RdfParserKind::RdfXml(p.with_base_iri(base_iri)?),
             RdfParserKind::TriG(p) => RdfParserKind::TriG(p.with_base_iri(base_iri)?),
             RdfParserKind::Turtle(p) => RdfParserKind::Turtle(p.with_base_iri(base_iri)?),
+            #[cfg(feature = "hdt")]
+            RdfParserKind::Hdt(p) => RdfParserKind::Hdt(p),
         };
         Ok(self)
     }
@@ -203,6 +343,95 @@ impl RdfParser {
         self
     }
 
+    /// Sets a function rewriting the graph name of each returned quad.
+    ///
+    /// It is applied after [`with_default_graph`](Self::with_default_graph) and [`rename_blank_nodes`](Self::rename_blank_nodes).
+    ///
+    /// ```
+    /// use oxrdf::{GraphNameRef, NamedNode};
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://old.example.com/g> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NQuads)
+    ///     .with_graph_mapper(|graph_name| match graph_name {
+    ///         GraphNameRef::NamedNode(g) => {
+    ///             NamedNode::new_unchecked(g.as_str().replace("old.", "new.")).into()
+    ///         }
+    ///         g => g.into_owned(),
+    ///     })
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(quads[0].graph_name.to_string(), "<http://new.example.com/g>");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_graph_mapper(
+        mut self,
+        mapper: impl Fn(GraphNameRef<'_>) -> GraphName + Send + Sync + 'static,
+    ) -> Self {
+        self.graph_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Replaces the blank nodes by [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization)
+    /// of the shape `{base authority}/.well-known/genid/{blank node id}`.
+    ///
+    /// It is applied after all the other transformations like [`rename_blank_nodes`](Self::rename_blank_nodes)
+    /// and overrides [`de_skolemize_blank_nodes`](Self::de_skolemize_blank_nodes).
+    /// See [`Skolemizer`] for more details.
+    ///
+    /// ```
+    /// use oxrdf::NamedNode;
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "_:a <http://example.com/p> <http://example.com/o> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .skolemize_blank_nodes(NamedNode::new("http://example.com/")?)
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(
+    ///     quads[0].subject.to_string(),
+    ///     "<http://example.com/.well-known/genid/a>"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn skolemize_blank_nodes(mut self, base: impl Into<NamedNode>) -> Self {
+        self.skolemization = Some(Skolemization::Skolemize(Skolemizer::new(
+            base.into().as_ref(),
+        )));
+        self
+    }
+
+    /// Replaces the [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization)
+    /// of the shape `.../.well-known/genid/{id}` by blank nodes.
+    ///
+    /// It is the inverse of [`skolemize_blank_nodes`](Self::skolemize_blank_nodes) and overrides it.
+    /// See [`Skolemizer::de_skolemize_named_node`] for more details.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "<http://example.com/.well-known/genid/a> <http://example.com/p> <http://example.com/o> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .de_skolemize_blank_nodes()
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(quads[0].subject.to_string(), "_:a");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn de_skolemize_blank_nodes(mut self) -> Self {
+        self.skolemization = Some(Skolemization::DeSkolemize);
+        self
+    }
+
     /// Renames the blank nodes ids from the ones set in the serialization to random ids.
     ///
     /// This allows to avoid id conflicts when merging graphs together.
@@ -229,6 +458,227 @@ impl RdfParser {
         self
     }
 
+    /// Renames the blank nodes ids from the ones set in the serialization to ids derived from the given seed and a counter.
+    ///
+    /// Blank nodes get ids in the order of their first appearance in the file,
+    /// so parsing the same file twice with the same seed returns the exact same quads.
+    /// This is useful to build reproducible pipelines.
+    ///
+    /// <div class="warning">
+    ///
+    /// This sacrifices the global uniqueness of the generated ids: two parsers with the same seed will generate the same ids for different blank nodes.
+    /// It must not be used when the outputs of multiple runs or multiple files are merged together, use [`rename_blank_nodes`](Self::rename_blank_nodes) for that.</div>
+    ///
+    /// It also disables [`split_slice_for_parallel_parsing`](Self::split_slice_for_parallel_parsing) and [`split_file_for_parallel_parsing`](Self::split_file_for_parallel_parsing): they return a single parser.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "[] <http://example.com/p> [ <http://example.com/p> _:a ] .";
+    ///
+    /// let result1 = RdfParser::from_format(RdfFormat::Turtle)
+    ///     .with_deterministic_blank_node_ids(42)
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// let result2 = RdfParser::from_format(RdfFormat::Turtle)
+    ///     .with_deterministic_blank_node_ids(42)
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(result1, result2);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_deterministic_blank_node_ids(mut self, seed: u128) -> Self {
+        self.blank_node_id_seed = Some(seed);
+        self
+    }
+
+    /// Checks the lexical form of the literals with a recognized XSD datatype like `xsd:integer` or `xsd:date`.
+    ///
+    /// With [`ValidationPolicy::Reject`] the N-Triples, N-Quads, Turtle and TriG parsers report the error
+    /// at the location of the literal datatype.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser, ValidationPolicy};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "abc"^^<http://www.w3.org/2001/XMLSchema#integer> ."#;
+    ///
+    /// let error = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .with_literal_validation(ValidationPolicy::Reject)
+    ///     .for_reader(file.as_bytes())
+    ///     .next()
+    ///     .unwrap()
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("\"abc\""));
+    ///
+    /// let warnings = Arc::new(Mutex::new(Vec::new()));
+    /// let warnings2 = Arc::clone(&warnings);
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .with_literal_validation(ValidationPolicy::Warn(Arc::new(move |error| {
+    ///         warnings2.lock().unwrap().push(error.to_string())
+    ///     })))
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(quads.len(), 1);
+    /// assert_eq!(warnings.lock().unwrap().len(), 1);
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_literal_validation(mut self, policy: ValidationPolicy) -> Self {
+        self.literal_validation = policy;
+        if matches!(self.literal_validation, ValidationPolicy::Reject) {
+            // These parsers validate the literals themselves to report the error location
+            self.inner = match self.inner {
+                RdfParserKind::NQuads(p) => {
+                    self.literal_validation = ValidationPolicy::Ignore;
+                    RdfParserKind::NQuads(p.with_literal_validation())
+                }
+                RdfParserKind::NTriples(p) => {
+                    self.literal_validation = ValidationPolicy::Ignore;
+                    RdfParserKind::NTriples(p.with_literal_validation())
+                }
+                RdfParserKind::TriG(p) => {
+                    self.literal_validation = ValidationPolicy::Ignore;
+                    RdfParserKind::TriG(p.with_literal_validation())
+                }
+                RdfParserKind::Turtle(p) => {
+                    self.literal_validation = ValidationPolicy::Ignore;
+                    RdfParserKind::Turtle(p.with_literal_validation())
+                }
+                inner => inner,
+            };
+        }
+        self
+    }
+
+    /// Calls `policy` on each parsed literal, including the ones in triple terms, to keep, rewrite or reject it.
+    ///
+    /// It is called before the checks enabled by [`with_literal_validation`](Self::with_literal_validation).
+    /// With [`LiteralDecision::Reject`] the N-Triples, N-Quads, Turtle and TriG parsers report the error
+    /// at the location of the end of the literal and skip the rest of the statement.
+    ///
+    /// ```
+    /// use oxrdf::Literal;
+    /// use oxrdfio::{LiteralDecision, RdfFormat, RdfParser};
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "a"^^<http://example.com/unknown> .
+    /// <http://example.com/s> <http://example.com/p> "b"@en-a ."#;
+    ///
+    /// let mut quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .lenient()
+    ///     .with_literal_policy(|literal| {
+    ///         if literal.language().is_none()
+    ///             && !literal.datatype().as_str().starts_with("http://www.w3.org/2001/XMLSchema#")
+    ///         {
+    ///             // Unknown datatypes become xsd:string
+    ///             return LiteralDecision::Rewrite(Literal::new_simple_literal(literal.value()));
+    ///         }
+    ///         LiteralDecision::check_language_tag(literal, true)
+    ///     })
+    ///     .for_reader(file.as_bytes());
+    /// assert_eq!(quads.next().unwrap()?.object, Literal::new_simple_literal("a").into());
+    /// let error = quads.next().unwrap().unwrap_err();
+    /// assert!(error.to_string().contains("is not a valid BCP47 language tag"));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_literal_policy(
+        mut self,
+        policy: impl Fn(LiteralRef<'_>) -> LiteralDecision + Send + Sync + 'static,
+    ) -> Self {
+        let policy: LiteralPolicy = Arc::new(policy);
+        let mapper = {
+            let policy = Arc::clone(&policy);
+            move |literal: Literal| policy(literal.as_ref()).apply(literal)
+        };
+        // These parsers apply the policy themselves to report the error location
+        self.inner = match self.inner {
+            RdfParserKind::NQuads(p) => RdfParserKind::NQuads(p.with_literal_mapper(mapper)),
+            RdfParserKind::NTriples(p) => RdfParserKind::NTriples(p.with_literal_mapper(mapper)),
+            RdfParserKind::TriG(p) => RdfParserKind::TriG(p.with_literal_mapper(mapper)),
+            RdfParserKind::Turtle(p) => RdfParserKind::Turtle(p.with_literal_mapper(mapper)),
+            inner => {
+                self.literal_policy = Some(policy);
+                inner
+            }
+        };
+        self
+    }
+
+    /// Sets what to do with the RDF 1.2 triple terms (RDF-star quoted triples) for consumers that do not support them.
+    ///
+    /// With [`RdfStarPolicy::Reject`] the N-Triples, N-Quads, Turtle and TriG parsers report the error
+    /// at the location of the opening `<<`.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser, RdfStarPolicy};
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .
+    /// <http://example.com/s> <http://example.com/p> <<( <http://example.com/a> <http://example.com/b> <http://example.com/c> )>> .";
+    ///
+    /// let mut quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .with_rdf_star(RdfStarPolicy::Reject)
+    ///     .for_reader(file.as_bytes());
+    /// assert!(quads.next().unwrap().is_ok());
+    /// let error = quads.next().unwrap().unwrap_err();
+    /// assert!(error.to_string().contains("Triple terms are not allowed"));
+    /// ```
+    #[inline]
+    pub fn with_rdf_star(mut self, policy: RdfStarPolicy) -> Self {
+        self.rdf_star = policy;
+        if matches!(self.rdf_star, RdfStarPolicy::Reject) {
+            // These parsers reject the triple terms themselves to report the error location
+            self.inner = match self.inner {
+                RdfParserKind::NQuads(p) => {
+                    self.rdf_star = RdfStarPolicy::Accept;
+                    RdfParserKind::NQuads(p.without_triple_terms())
+                }
+                RdfParserKind::NTriples(p) => {
+                    self.rdf_star = RdfStarPolicy::Accept;
+                    RdfParserKind::NTriples(p.without_triple_terms())
+                }
+                RdfParserKind::TriG(p) => {
+                    self.rdf_star = RdfStarPolicy::Accept;
+                    RdfParserKind::TriG(p.without_triple_terms())
+                }
+                RdfParserKind::Turtle(p) => {
+                    self.rdf_star = RdfStarPolicy::Accept;
+                    RdfParserKind::Turtle(p.without_triple_terms())
+                }
+                inner => inner,
+            };
+        }
+        self
+    }
+
+    /// Normalizes the IRIs of the parsed quads according to the given policy.
+    ///
+    /// It allows to match the same resources written with different IRIs like `HTTP://Example.org/a/../b` and `http://example.org/b`.
+    /// The query and the fragment of the IRIs are kept as is.
+    /// See [`IriNormalizationPolicy`] for the available normalizations.
+    ///
+    /// ```
+    /// use oxrdf::IriNormalizationPolicy;
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "<HTTP://Example.org/a/../b> <http://example.org/p> <http://example.org/%7Eo?Q#F> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .with_iri_normalization(IriNormalizationPolicy::Full)
+    ///     .for_reader(file.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(quads[0].subject.to_string(), "<http://example.org/b>");
+    /// assert_eq!(quads[0].object.to_string(), "<http://example.org/~o?Q#F>");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_iri_normalization(mut self, policy: IriNormalizationPolicy) -> Self {
+        self.iri_normalization = Some(policy);
+        self
+    }
+
     /// Assumes the file is valid to make parsing faster.
     ///
     /// It will skip some validations.
@@ -244,6 +694,8 @@ impl RdfParser {
             RdfParserKind::RdfXml(p) => RdfParserKind::RdfXml(p.lenient()),
             RdfParserKind::TriG(p) => RdfParserKind::TriG(p.lenient()),
             RdfParserKind::Turtle(p) => RdfParserKind::Turtle(p.lenient()),
+            #[cfg(feature = "hdt")]
+            RdfParserKind::Hdt(p) => RdfParserKind::Hdt(p.lenient()),
         };
         self
     }
@@ -254,10 +706,52 @@ impl RdfParser {
         self.lenient()
     }
 
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered in memory.
+    /// There is no limit by default.
+    ///
+    /// The limit is only enforced by the N-Triples, N-Quads, Turtle, TriG and N3 parsers.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> \"a literal longer than the limit\" .";
+    ///
+    /// let mut quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .with_max_term_length(22)
+    ///     .for_reader(file.as_bytes());
+    /// assert!(quads.next().unwrap().is_err());
+    /// ```
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.inner = match self.inner {
+            RdfParserKind::N3(p) => RdfParserKind::N3(p.with_max_term_length(max_term_length)),
+            RdfParserKind::NTriples(p) => {
+                RdfParserKind::NTriples(p.with_max_term_length(max_term_length))
+            }
+            RdfParserKind::NQuads(p) => {
+                RdfParserKind::NQuads(p.with_max_term_length(max_term_length))
+            }
+            RdfParserKind::TriG(p) => RdfParserKind::TriG(p.with_max_term_length(max_term_length)),
+            RdfParserKind::Turtle(p) => {
+                RdfParserKind::Turtle(p.with_max_term_length(max_term_length))
+            }
+            inner @ (RdfParserKind::JsonLd(..) | RdfParserKind::RdfXml(_)) => inner,
+            #[cfg(feature = "hdt")]
+            inner @ RdfParserKind::Hdt(_) => inner,
+        };
+        self
+    }
+
     /// Parses from a [`Read`] implementation and returns an iterator of quads.
     ///
     /// Reads are buffered.
     ///
+    /// HDT files are fully loaded in memory before being decoded.
+    /// Use [`for_seekable_reader`](Self::for_seekable_reader) to avoid that.
+    ///
     /// ```
     /// use oxrdfio::{RdfFormat, RdfParser};
     ///
@@ -281,15 +775,91 @@ impl RdfParser {
                 RdfParserKind::RdfXml(p) => ReaderQuadParserKind::RdfXml(p.for_reader(reader)),
                 RdfParserKind::TriG(p) => ReaderQuadParserKind::TriG(p.for_reader(reader)),
                 RdfParserKind::Turtle(p) => ReaderQuadParserKind::Turtle(p.for_reader(reader)),
+                #[cfg(feature = "hdt")]
+                RdfParserKind::Hdt(p) => ReaderQuadParserKind::Hdt(p.for_reader(reader)),
             },
             mapper: QuadMapper {
                 default_graph: self.default_graph,
                 without_named_graphs: self.without_named_graphs,
-                blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                blank_node_map: (self.rename_blank_nodes || self.blank_node_id_seed.is_some())
+                    .then(HashMap::new),
+                next_blank_node_id: self.blank_node_id_seed,
+                graph_mapper: self.graph_mapper.clone(),
+                literal_validation: self.literal_validation.clone(),
+                literal_policy: self.literal_policy.clone(),
+                rdf_star: self.rdf_star.clone(),
+                iri_normalization: self.iri_normalization,
+                skolemization: self.skolemization.clone(),
             },
         }
     }
 
+    /// Parses from a [`Read`] and [`Seek`] implementation like a [`File`] and returns an iterator of quads.
+    ///
+    /// It behaves like [`for_reader`](Self::for_reader) except for HDT:
+    /// only its dictionary is loaded in memory, the triples being read from the file when iterating.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfParser};
+    /// use std::io::Cursor;
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .for_seekable_reader(Cursor::new(file))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(quads.len(), 1);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn for_seekable_reader<R: Read + Seek>(self, reader: R) -> ReaderQuadParser<R> {
+        match self.inner {
+            #[cfg(feature = "hdt")]
+            RdfParserKind::Hdt(p) => ReaderQuadParser {
+                inner: ReaderQuadParserKind::Hdt(p.for_seekable_reader(reader)),
+                mapper: QuadMapper {
+                    default_graph: self.default_graph,
+                    without_named_graphs: self.without_named_graphs,
+                    blank_node_map: (self.rename_blank_nodes || self.blank_node_id_seed.is_some())
+                        .then(HashMap::new),
+                    next_blank_node_id: self.blank_node_id_seed,
+                    graph_mapper: self.graph_mapper.clone(),
+                    literal_validation: self.literal_validation.clone(),
+                    literal_policy: self.literal_policy.clone(),
+                    rdf_star: self.rdf_star.clone(),
+                    iri_normalization: self.iri_normalization,
+                    skolemization: self.skolemization.clone(),
+                },
+            },
+            _ => self.for_reader(reader),
+        }
+    }
+
+    /// Parses from a compressed [`Read`] implementation and returns an iterator of quads.
+    ///
+    /// Decompression is done on the fly using [`DecodingReader`].
+    /// Use [`DecodingReader::detect`] and [`for_reader`](Self::for_reader) to guess the compression format from the document content.
+    ///
+    /// ```
+    /// use oxrdfio::{ContentEncoding, RdfFormat, RdfParser};
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .";
+    ///
+    /// let quads = RdfParser::from_format(RdfFormat::NTriples)
+    ///     .for_reader_with_encoding(file.as_bytes(), ContentEncoding::Identity)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(quads.len(), 1);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn for_reader_with_encoding<R: Read>(
+        self,
+        reader: R,
+        encoding: ContentEncoding,
+    ) -> ReaderQuadParser<DecodingReader<R>> {
+        self.for_reader(DecodingReader::new(reader, encoding))
+    }
+
     /// Parses from a Tokio [`AsyncRead`] implementation and returns an async iterator of quads.
     ///
     /// Reads are buffered.
@@ -337,11 +907,23 @@ impl RdfParser {
                 RdfParserKind::Turtle(p) => {
                     TokioAsyncReaderQuadParserKind::Turtle(p.for_tokio_async_reader(reader))
                 }
+                #[cfg(feature = "hdt")]
+                RdfParserKind::Hdt(p) => {
+                    TokioAsyncReaderQuadParserKind::Hdt(p.for_tokio_async_reader(reader))
+                }
             },
             mapper: QuadMapper {
                 default_graph: self.default_graph,
                 without_named_graphs: self.without_named_graphs,
-                blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                blank_node_map: (self.rename_blank_nodes || self.blank_node_id_seed.is_some())
+                    .then(HashMap::new),
+                next_blank_node_id: self.blank_node_id_seed,
+                graph_mapper: self.graph_mapper.clone(),
+                literal_validation: self.literal_validation.clone(),
+                literal_policy: self.literal_policy.clone(),
+                rdf_star: self.rdf_star.clone(),
+                iri_normalization: self.iri_normalization,
+                skolemization: self.skolemization.clone(),
             },
         }
     }
@@ -371,11 +953,21 @@ impl RdfParser {
                 RdfParserKind::RdfXml(p) => SliceQuadParserKind::RdfXml(p.for_slice(slice)),
                 RdfParserKind::TriG(p) => SliceQuadParserKind::TriG(p.for_slice(slice)),
                 RdfParserKind::Turtle(p) => SliceQuadParserKind::Turtle(p.for_slice(slice)),
+                #[cfg(feature = "hdt")]
+                RdfParserKind::Hdt(p) => SliceQuadParserKind::Hdt(p.for_slice(slice.as_ref())),
             },
             mapper: QuadMapper {
                 default_graph: self.default_graph,
                 without_named_graphs: self.without_named_graphs,
-                blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                blank_node_map: (self.rename_blank_nodes || self.blank_node_id_seed.is_some())
+                    .then(HashMap::new),
+                next_blank_node_id: self.blank_node_id_seed,
+                graph_mapper: self.graph_mapper.clone(),
+                literal_validation: self.literal_validation.clone(),
+                literal_policy: self.literal_policy.clone(),
+                rdf_star: self.rdf_star.clone(),
+                iri_normalization: self.iri_normalization,
+                skolemization: self.skolemization.clone(),
             },
         }
     }
@@ -406,6 +998,10 @@ impl RdfParser {
         slice: &(impl AsRef<[u8]> + ?Sized),
         target_parallelism: usize,
     ) -> Vec<SliceQuadParser<'_>> {
+        if self.blank_node_id_seed.is_some() {
+            // The blank node counter must be shared by the whole document
+            return vec![self.for_slice(slice)];
+        }
         match self.inner {
             RdfParserKind::NTriples(p) => p
                 .split_slice_for_parallel_parsing(slice, target_parallelism)
@@ -415,7 +1011,16 @@ impl RdfParser {
                     mapper: QuadMapper {
                         default_graph: self.default_graph.clone(),
                         without_named_graphs: self.without_named_graphs,
-                        blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                        blank_node_map: (self.rename_blank_nodes
+                            || self.blank_node_id_seed.is_some())
+                        .then(HashMap::new),
+                        next_blank_node_id: self.blank_node_id_seed,
+                        graph_mapper: self.graph_mapper.clone(),
+                        literal_validation: self.literal_validation.clone(),
+                        literal_policy: self.literal_policy.clone(),
+                        rdf_star: self.rdf_star.clone(),
+                        iri_normalization: self.iri_normalization,
+                        skolemization: self.skolemization.clone(),
                     },
                 })
                 .collect(),
@@ -427,7 +1032,16 @@ impl RdfParser {
                     mapper: QuadMapper {
                         default_graph: self.default_graph.clone(),
                         without_named_graphs: self.without_named_graphs,
-                        blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                        blank_node_map: (self.rename_blank_nodes
+                            || self.blank_node_id_seed.is_some())
+                        .then(HashMap::new),
+                        next_blank_node_id: self.blank_node_id_seed,
+                        graph_mapper: self.graph_mapper.clone(),
+                        literal_validation: self.literal_validation.clone(),
+                        literal_policy: self.literal_policy.clone(),
+                        rdf_star: self.rdf_star.clone(),
+                        iri_normalization: self.iri_normalization,
+                        skolemization: self.skolemization.clone(),
                     },
                 })
                 .collect(),
@@ -460,6 +1074,10 @@ impl RdfParser {
         path: impl AsRef<Path>,
         target_parallelism: usize,
     ) -> io::Result<Vec<ReaderQuadParser<Take<File>>>> {
+        if self.blank_node_id_seed.is_some() {
+            // The blank node counter must be shared by the whole document
+            return Ok(vec![self.for_reader(File::open(path)?.take(u64::MAX))]);
+        }
         Ok(match self.inner {
             RdfParserKind::NTriples(p) => p
                 .split_file_for_parallel_parsing(path, target_parallelism)?
@@ -469,7 +1087,16 @@ impl RdfParser {
                     mapper: QuadMapper {
                         default_graph: self.default_graph.clone(),
                         without_named_graphs: self.without_named_graphs,
-                        blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                        blank_node_map: (self.rename_blank_nodes
+                            || self.blank_node_id_seed.is_some())
+                        .then(HashMap::new),
+                        next_blank_node_id: self.blank_node_id_seed,
+                        graph_mapper: self.graph_mapper.clone(),
+                        literal_validation: self.literal_validation.clone(),
+                        literal_policy: self.literal_policy.clone(),
+                        rdf_star: self.rdf_star.clone(),
+                        iri_normalization: self.iri_normalization,
+                        skolemization: self.skolemization.clone(),
                     },
                 })
                 .collect(),
@@ -481,7 +1108,16 @@ impl RdfParser {
                     mapper: QuadMapper {
                         default_graph: self.default_graph.clone(),
                         without_named_graphs: self.without_named_graphs,
-                        blank_node_map: self.rename_blank_nodes.then(HashMap::new),
+                        blank_node_map: (self.rename_blank_nodes
+                            || self.blank_node_id_seed.is_some())
+                        .then(HashMap::new),
+                        next_blank_node_id: self.blank_node_id_seed,
+                        graph_mapper: self.graph_mapper.clone(),
+                        literal_validation: self.literal_validation.clone(),
+                        literal_policy: self.literal_policy.clone(),
+                        rdf_star: self.rdf_star.clone(),
+                        iri_normalization: self.iri_normalization,
+                        skolemization: self.skolemization.clone(),
                     },
                 })
                 .collect(),
@@ -529,42 +1165,66 @@ enum ReaderQuadParserKind<R: Read> {
     RdfXml(ReaderRdfXmlParser<R>),
     TriG(ReaderTriGParser<R>),
     Turtle(ReaderTurtleParser<R>),
+    #[cfg(feature = "hdt")]
+    Hdt(ReaderHdtParser<R>),
 }
 
 impl<R: Read> Iterator for ReaderQuadParser<R> {
     type Item = Result<Quad, RdfParseError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(match &mut self.inner {
-            ReaderQuadParserKind::JsonLd(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::N3(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_n3_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::NQuads(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::NTriples(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::RdfXml(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::TriG(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            ReaderQuadParserKind::Turtle(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-        })
+        loop {
+            let result = match &mut self.inner {
+                ReaderQuadParserKind::JsonLd(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::N3(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_n3_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::NQuads(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::NTriples(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::RdfXml(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::TriG(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                ReaderQuadParserKind::Turtle(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                #[cfg(feature = "hdt")]
+                ReaderQuadParserKind::Hdt(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e),
+                },
+            };
+            let quad = match result
+                .and_then(|quad| self.mapper.check_triple_terms(quad).map_err(Into::into))
+            {
+                Ok(Some(quad)) => quad,
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            };
+            return Some(
+                self.mapper
+                    .apply_literal_policy(quad)
+                    .and_then(|quad| self.mapper.validate_literals(quad))
+                    .map_err(Into::into)
+                    .map(|quad| self.mapper.normalize_iris(quad))
+                    .map(|quad| self.mapper.skolemize(quad)),
+            );
+        }
     }
 }
 
@@ -607,6 +1267,8 @@ impl<R: Read> ReaderQuadParser<R> {
                 ReaderQuadParserKind::NQuads(_) | ReaderQuadParserKind::NTriples(_) => {
                     PrefixesIterKind::None
                 }
+                #[cfg(feature = "hdt")]
+                ReaderQuadParserKind::Hdt(_) => PrefixesIterKind::None,
             },
         }
     }
@@ -638,6 +1300,8 @@ impl<R: Read> ReaderQuadParser<R> {
             ReaderQuadParserKind::Turtle(p) => p.base_iri(),
             ReaderQuadParserKind::RdfXml(p) => p.base_iri(),
             ReaderQuadParserKind::NQuads(_) | ReaderQuadParserKind::NTriples(_) => None,
+            #[cfg(feature = "hdt")]
+            ReaderQuadParserKind::Hdt(_) => None,
         }
     }
 
@@ -748,41 +1412,65 @@ enum TokioAsyncReaderQuadParserKind<R: AsyncRead + Unpin> {
     RdfXml(TokioAsyncReaderRdfXmlParser<R>),
     TriG(TokioAsyncReaderTriGParser<R>),
     Turtle(TokioAsyncReaderTurtleParser<R>),
+    #[cfg(feature = "hdt")]
+    Hdt(TokioAsyncReaderHdtParser<R>),
 }
 
 #[cfg(feature = "async-tokio")]
 impl<R: AsyncRead + Unpin> TokioAsyncReaderQuadParser<R> {
     pub async fn next(&mut self) -> Option<Result<Quad, RdfParseError>> {
-        Some(match &mut self.inner {
-            TokioAsyncReaderQuadParserKind::JsonLd(parser) => match parser.next().await? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::N3(parser) => match parser.next().await? {
-                Ok(quad) => self.mapper.map_n3_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::NQuads(parser) => match parser.next().await? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::NTriples(parser) => match parser.next().await? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::RdfXml(parser) => match parser.next().await? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::TriG(parser) => match parser.next().await? {
-                Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
-                Err(e) => Err(e.into()),
-            },
-            TokioAsyncReaderQuadParserKind::Turtle(parser) => match parser.next().await? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-        })
+        loop {
+            let result = match &mut self.inner {
+                TokioAsyncReaderQuadParserKind::JsonLd(parser) => match parser.next().await? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::N3(parser) => match parser.next().await? {
+                    Ok(quad) => self.mapper.map_n3_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::NQuads(parser) => match parser.next().await? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::NTriples(parser) => match parser.next().await? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::RdfXml(parser) => match parser.next().await? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::TriG(parser) => match parser.next().await? {
+                    Ok(quad) => self.mapper.map_quad(quad).map_err(Into::into),
+                    Err(e) => Err(e.into()),
+                },
+                TokioAsyncReaderQuadParserKind::Turtle(parser) => match parser.next().await? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                #[cfg(feature = "hdt")]
+                TokioAsyncReaderQuadParserKind::Hdt(parser) => match parser.next().await? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e),
+                },
+            };
+            let quad = match result
+                .and_then(|quad| self.mapper.check_triple_terms(quad).map_err(Into::into))
+            {
+                Ok(Some(quad)) => quad,
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            };
+            return Some(
+                self.mapper
+                    .apply_literal_policy(quad)
+                    .and_then(|quad| self.mapper.validate_literals(quad))
+                    .map_err(Into::into)
+                    .map(|quad| self.mapper.normalize_iris(quad))
+                    .map(|quad| self.mapper.skolemize(quad)),
+            );
+        }
     }
 
     /// The list of IRI prefixes considered at the current step of the parsing.
@@ -826,6 +1514,8 @@ impl<R: AsyncRead + Unpin> TokioAsyncReaderQuadParser<R> {
                 TokioAsyncReaderQuadParserKind::RdfXml(p) => PrefixesIterKind::RdfXml(p.prefixes()),
                 TokioAsyncReaderQuadParserKind::NQuads(_)
                 | TokioAsyncReaderQuadParserKind::NTriples(_) => PrefixesIterKind::None,
+                #[cfg(feature = "hdt")]
+                TokioAsyncReaderQuadParserKind::Hdt(_) => PrefixesIterKind::None,
             },
         }
     }
@@ -863,6 +1553,8 @@ impl<R: AsyncRead + Unpin> TokioAsyncReaderQuadParser<R> {
             TokioAsyncReaderQuadParserKind::RdfXml(p) => p.base_iri(),
             TokioAsyncReaderQuadParserKind::NQuads(_)
             | TokioAsyncReaderQuadParserKind::NTriples(_) => None,
+            #[cfg(feature = "hdt")]
+            TokioAsyncReaderQuadParserKind::Hdt(_) => None,
         }
     }
 }
@@ -898,42 +1590,63 @@ enum SliceQuadParserKind<'a> {
     RdfXml(SliceRdfXmlParser<'a>),
     TriG(SliceTriGParser<'a>),
     Turtle(SliceTurtleParser<'a>),
+    #[cfg(feature = "hdt")]
+    Hdt(SliceHdtParser<'a>),
 }
 
 impl Iterator for SliceQuadParser<'_> {
     type Item = Result<Quad, RdfSyntaxError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(match &mut self.inner {
-            SliceQuadParserKind::JsonLd(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::N3(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_n3_quad(quad),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::NQuads(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::NTriples(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::RdfXml(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::TriG(parser) => match parser.next()? {
-                Ok(quad) => self.mapper.map_quad(quad),
-                Err(e) => Err(e.into()),
-            },
-            SliceQuadParserKind::Turtle(parser) => match parser.next()? {
-                Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
-                Err(e) => Err(e.into()),
-            },
-        })
+        loop {
+            let result = match &mut self.inner {
+                SliceQuadParserKind::JsonLd(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::N3(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_n3_quad(quad),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::NQuads(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::NTriples(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::RdfXml(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::TriG(parser) => match parser.next()? {
+                    Ok(quad) => self.mapper.map_quad(quad),
+                    Err(e) => Err(e.into()),
+                },
+                SliceQuadParserKind::Turtle(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e.into()),
+                },
+                #[cfg(feature = "hdt")]
+                SliceQuadParserKind::Hdt(parser) => match parser.next()? {
+                    Ok(triple) => Ok(self.mapper.map_triple_to_quad(triple)),
+                    Err(e) => Err(e),
+                },
+            };
+            let quad = match result.and_then(|quad| self.mapper.check_triple_terms(quad)) {
+                Ok(Some(quad)) => quad,
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            };
+            return Some(
+                self.mapper
+                    .apply_literal_policy(quad)
+                    .and_then(|quad| self.mapper.validate_literals(quad))
+                    .map(|quad| self.mapper.normalize_iris(quad))
+                    .map(|quad| self.mapper.skolemize(quad)),
+            );
+        }
     }
 }
 
@@ -1045,6 +1758,8 @@ impl SliceQuadParser<'_> {
                 SliceQuadParserKind::NQuads(_) | SliceQuadParserKind::NTriples(_) => {
                     PrefixesIterKind::None
                 }
+                #[cfg(feature = "hdt")]
+                SliceQuadParserKind::Hdt(_) => PrefixesIterKind::None,
             },
         }
     }
@@ -1076,6 +1791,8 @@ impl SliceQuadParser<'_> {
             SliceQuadParserKind::Turtle(p) => p.base_iri(),
             SliceQuadParserKind::RdfXml(p) => p.base_iri(),
             SliceQuadParserKind::NQuads(_) | SliceQuadParserKind::NTriples(_) => None,
+            #[cfg(feature = "hdt")]
+            SliceQuadParserKind::Hdt(_) => None,
         }
     }
 }
@@ -1128,6 +1845,14 @@ struct QuadMapper {
     default_graph: GraphName,
     without_named_graphs: bool,
     blank_node_map: Option<HashMap<BlankNode, BlankNode>>,
+    next_blank_node_id: Option<u128>,
+    graph_mapper: Option<GraphMapper>,
+    literal_validation: ValidationPolicy,
+    literal_policy: Option<LiteralPolicy>,
+    #[cfg_attr(not(feature = "rdf-12"), expect(dead_code))]
+    rdf_star: RdfStarPolicy,
+    iri_normalization: Option<IriNormalizationPolicy>,
+    skolemization: Option<Skolemization>,
 }
 
 impl QuadMapper {
@@ -1135,7 +1860,15 @@ impl QuadMapper {
         if let Some(blank_node_map) = &mut self.blank_node_map {
             blank_node_map
                 .entry(node)
-                .or_insert_with(BlankNode::default)
+                .or_insert_with(|| {
+                    if let Some(next_id) = &mut self.next_blank_node_id {
+                        let id = *next_id;
+                        *next_id = next_id.wrapping_add(1);
+                        BlankNode::new_from_unique_id(id)
+                    } else {
+                        BlankNode::default()
+                    }
+                })
                 .clone()
         } else {
             node
@@ -1168,7 +1901,7 @@ impl QuadMapper {
     }
 
     fn map_graph_name(&mut self, graph_name: GraphName) -> Result<GraphName, RdfSyntaxError> {
-        match graph_name {
+        let graph_name = match graph_name {
             GraphName::NamedNode(node) => {
                 if self.without_named_graphs {
                     Err(RdfSyntaxError::msg("Named graphs are not allowed"))
@@ -1184,6 +1917,15 @@ impl QuadMapper {
                 }
             }
             GraphName::DefaultGraph => Ok(self.default_graph.clone()),
+        }?;
+        Ok(self.apply_graph_mapper(graph_name))
+    }
+
+    fn apply_graph_mapper(&self, graph_name: GraphName) -> GraphName {
+        if let Some(graph_mapper) = &self.graph_mapper {
+            graph_mapper(graph_name.as_ref())
+        } else {
+            graph_name
         }
     }
 
@@ -1197,7 +1939,100 @@ impl QuadMapper {
     }
 
     fn map_triple_to_quad(&mut self, triple: Triple) -> Quad {
-        self.map_triple(triple).in_graph(self.default_graph.clone())
+        let graph_name = self.apply_graph_mapper(self.default_graph.clone());
+        self.map_triple(triple).in_graph(graph_name)
+    }
+
+    /// Returns `None` if the quad must be skipped
+    #[cfg_attr(
+        not(feature = "rdf-12"),
+        expect(clippy::unused_self, clippy::unnecessary_wraps)
+    )]
+    fn check_triple_terms(&self, quad: Quad) -> Result<Option<Quad>, RdfSyntaxError> {
+        #[cfg(feature = "rdf-12")]
+        if let Term::Triple(_) = &quad.object {
+            match &self.rdf_star {
+                RdfStarPolicy::Accept => (),
+                RdfStarPolicy::Reject => {
+                    return Err(RdfSyntaxError::msg("Triple terms are not allowed"));
+                }
+                RdfStarPolicy::Strip(callback) => {
+                    callback(&quad);
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(quad))
+    }
+
+    fn apply_literal_policy(&self, quad: Quad) -> Result<Quad, RdfSyntaxError> {
+        let Some(policy) = &self.literal_policy else {
+            return Ok(quad);
+        };
+        Ok(Quad {
+            object: Self::apply_literal_policy_to_term(policy, quad.object)?,
+            ..quad
+        })
+    }
+
+    fn apply_literal_policy_to_term(
+        policy: &LiteralPolicy,
+        term: Term,
+    ) -> Result<Term, RdfSyntaxError> {
+        match term {
+            Term::Literal(literal) => Ok(policy(literal.as_ref())
+                .apply(literal)
+                .map_err(RdfSyntaxError::rejected_literal)?
+                .into()),
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(triple) => Ok(Triple {
+                object: Self::apply_literal_policy_to_term(policy, triple.object)?,
+                ..*triple
+            }
+            .into()),
+            Term::NamedNode(_) | Term::BlankNode(_) => Ok(term),
+        }
+    }
+
+    fn validate_literals(&self, quad: Quad) -> Result<Quad, RdfSyntaxError> {
+        if !matches!(self.literal_validation, ValidationPolicy::Ignore) {
+            self.validate_literals_in_term(&quad.object)?;
+        }
+        Ok(quad)
+    }
+
+    fn normalize_iris(&self, quad: Quad) -> Quad {
+        if let Some(policy) = self.iri_normalization {
+            policy.normalize_quad(quad.as_ref())
+        } else {
+            quad
+        }
+    }
+
+    fn skolemize(&self, quad: Quad) -> Quad {
+        if let Some(skolemization) = &self.skolemization {
+            skolemization.apply(quad.as_ref())
+        } else {
+            quad
+        }
+    }
+
+    fn validate_literals_in_term(&self, term: &Term) -> Result<(), RdfSyntaxError> {
+        match term {
+            Term::Literal(literal) => {
+                if let Err(error) = literal.parsed_value() {
+                    match &self.literal_validation {
+                        ValidationPolicy::Reject => return Err(error.into()),
+                        ValidationPolicy::Warn(callback) => callback(&error),
+                        ValidationPolicy::Ignore => (),
+                    }
+                }
+                Ok(())
+            }
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(triple) => self.validate_literals_in_term(&triple.object),
+            Term::NamedNode(_) | Term::BlankNode(_) => Ok(()),
+        }
     }
 
     fn map_n3_quad(&mut self, quad: N3Quad) -> Result<Quad, RdfSyntaxError> {
@@ -1246,3 +2081,253 @@ impl QuadMapper {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_validation_reports_location() {
+        let file = "@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n<http://example.com/s> <http://example.com/p> 1, \"2020-02-30\"^^xsd:date .";
+        let mut parser = RdfParser::from_format(RdfFormat::Turtle)
+            .with_literal_validation(ValidationPolicy::Reject)
+            .for_slice(file);
+        parser.next().unwrap().unwrap();
+        let error = parser.next().unwrap().unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.start.line, location.start.column), (1, 63));
+    }
+
+    #[test]
+    fn literal_validation_without_location() {
+        let file = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:ex="http://example.com/">
+  <rdf:Description rdf:about="http://example.com/s">
+    <ex:p rdf:datatype="http://www.w3.org/2001/XMLSchema#boolean">yes</ex:p>
+  </rdf:Description>
+</rdf:RDF>"#;
+        let error = RdfParser::from_format(RdfFormat::RdfXml)
+            .with_literal_validation(ValidationPolicy::Reject)
+            .for_slice(file)
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(error.location().is_none());
+        assert!(
+            RdfParser::from_format(RdfFormat::RdfXml)
+                .for_slice(file)
+                .all(|quad| quad.is_ok())
+        );
+    }
+
+    fn unknown_datatypes_as_strings(literal: LiteralRef<'_>) -> LiteralDecision {
+        if literal.language().is_none()
+            && !literal
+                .datatype()
+                .as_str()
+                .starts_with("http://www.w3.org/2001/XMLSchema#")
+        {
+            LiteralDecision::Rewrite(Literal::new_simple_literal(literal.value()))
+        } else {
+            LiteralDecision::Keep
+        }
+    }
+
+    #[test]
+    fn literal_policy_rewrites_literals() {
+        let files = [
+            (
+                RdfFormat::Turtle,
+                "@prefix ex: <http://example.com/> . ex:s ex:p \"a\"^^ex:t, 1 .",
+            ),
+            (
+                RdfFormat::RdfXml,
+                r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:ex="http://example.com/">
+  <rdf:Description rdf:about="http://example.com/s">
+    <ex:p rdf:datatype="http://example.com/t">a</ex:p>
+    <ex:p rdf:datatype="http://www.w3.org/2001/XMLSchema#integer">1</ex:p>
+  </rdf:Description>
+</rdf:RDF>"#,
+            ),
+        ];
+        for (format, file) in files {
+            let objects = RdfParser::from_format(format)
+                .with_literal_policy(unknown_datatypes_as_strings)
+                .for_slice(file)
+                .map(|quad| quad.map(|quad| quad.object))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(
+                objects,
+                [
+                    Literal::new_simple_literal("a").into(),
+                    Literal::from(1).into()
+                ],
+                "{format}"
+            );
+        }
+    }
+
+    #[test]
+    fn literal_policy_reject_reports_location() {
+        let file = "<http://example.com/s> <http://example.com/p> \"a\"@en-us <http://example.com/g> .\n<http://example.com/s> <http://example.com/p> \"b\"@en-a <http://example.com/g> .\n<http://example.com/s> <http://example.com/p> \"c\"@EN-GB .";
+        let mut parser = RdfParser::from_format(RdfFormat::NQuads)
+            .lenient()
+            .with_literal_policy(|literal| LiteralDecision::check_language_tag(literal, true))
+            .for_slice(file);
+        assert_eq!(
+            parser.next().unwrap().unwrap().object,
+            Literal::new_language_tagged_literal_unchecked("a", "en-us").into()
+        );
+        let error = parser.next().unwrap().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("'en-a' is not a valid BCP47 language tag"),
+            "{error}"
+        );
+        let location = error.location().unwrap();
+        assert_eq!((location.start.line, location.start.column), (1, 49));
+        // The rest of the statement is skipped
+        assert_eq!(
+            parser.next().unwrap().unwrap().object,
+            Literal::new_language_tagged_literal_unchecked("c", "en-gb").into()
+        );
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn literal_policy_reject_without_location() {
+        let file = r#"{"@id": "http://example.com/s", "http://example.com/p": {"@value": "a", "@language": "en-a"}}"#;
+        let error = RdfParser::from_format(RdfFormat::JsonLd {
+            profile: JsonLdProfileSet::empty(),
+        })
+        .lenient()
+        .with_literal_policy(|literal| LiteralDecision::check_language_tag(literal, false))
+        .for_slice(file)
+        .next()
+        .unwrap()
+        .unwrap_err();
+        assert!(error.location().is_none());
+        assert!(
+            error
+                .to_string()
+                .contains("'en-a' is not a valid BCP47 language tag"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn without_named_graphs_rejects_named_graphs_in_all_dataset_formats() {
+        let files = [
+            (
+                RdfFormat::NQuads,
+                "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://example.com/g> .",
+            ),
+            (
+                RdfFormat::TriG,
+                "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n_:g { <http://example.com/s> <http://example.com/p> <http://example.com/o> }",
+            ),
+            (
+                RdfFormat::N3,
+                "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n{ <http://example.com/s> <http://example.com/p> <http://example.com/o> } <http://example.com/p> <http://example.com/o> .",
+            ),
+            (
+                RdfFormat::JsonLd {
+                    profile: JsonLdProfileSet::empty(),
+                },
+                r#"[{"@id": "http://example.com/s", "http://example.com/p": {"@id": "http://example.com/o"}}, {"@id": "http://example.com/g", "@graph": {"@id": "http://example.com/s", "http://example.com/p": {"@id": "http://example.com/o"}}}]"#,
+            ),
+        ];
+        for (format, file) in files {
+            let parser = RdfParser::from_format(format).without_named_graphs();
+            for results in [
+                parser
+                    .clone()
+                    .for_slice(file)
+                    .map(|q| q.is_ok())
+                    .collect::<Vec<_>>(),
+                parser
+                    .for_reader(file.as_bytes())
+                    .map(|q| q.is_ok())
+                    .collect::<Vec<_>>(),
+            ] {
+                assert_eq!(
+                    results.first(),
+                    Some(&true),
+                    "{format} default graph triple should be allowed"
+                );
+                assert!(
+                    results.contains(&false),
+                    "{format} named graph should be rejected"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rdf_star_reject_reports_location() {
+        let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n<http://example.com/s> <http://example.com/p> <http://example.com/o> {| <http://example.com/q> <http://example.com/r> |} .";
+        let mut parser = RdfParser::from_format(RdfFormat::Turtle)
+            .with_rdf_star(RdfStarPolicy::Reject)
+            .for_slice(file);
+        parser.next().unwrap().unwrap();
+        parser.next().unwrap().unwrap();
+        let error = parser.next().unwrap().unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.start.line, location.start.column), (1, 69));
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn rdf_star_reject_without_location() {
+        let file = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:ex="http://example.com/">
+  <rdf:Description rdf:about="http://example.com/s">
+    <ex:p rdf:resource="http://example.com/o" rdf:annotation="http://example.com/r"/>
+  </rdf:Description>
+</rdf:RDF>"#;
+        let error = RdfParser::from_format(RdfFormat::RdfXml)
+            .with_rdf_star(RdfStarPolicy::Reject)
+            .for_slice(file)
+            .find_map(Result::err)
+            .unwrap();
+        assert!(error.location().is_none());
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn rdf_star_accept_and_strip() {
+        use std::sync::Mutex;
+
+        let file = "<< <http://example.com/s> <http://example.com/p> <http://example.com/o> >> <http://example.com/q> <http://example.com/r> .";
+        let accepted = RdfParser::from_format(RdfFormat::Turtle)
+            .for_slice(file)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(accepted.len(), 2);
+
+        let stripped = Arc::new(Mutex::new(Vec::new()));
+        let stripped_in_callback = Arc::clone(&stripped);
+        let parser = RdfParser::from_format(RdfFormat::Turtle).with_rdf_star(RdfStarPolicy::Strip(
+            Arc::new(move |quad| {
+                stripped_in_callback.lock().unwrap().push(quad.clone());
+            }),
+        ));
+        for quads in [
+            parser
+                .clone()
+                .for_slice(file)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            parser
+                .for_reader(file.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+        ] {
+            assert_eq!(quads.len(), 1);
+            assert_eq!(quads[0].predicate.as_str(), "http://example.com/q");
+        }
+        let stripped = stripped.lock().unwrap();
+        assert_eq!(stripped.len(), 2);
+        assert!(matches!(stripped[0].object, Term::Triple(_)));
+    }
+}
@@ -1,10 +1,20 @@
 //! Utilities to write RDF graphs and datasets.
 
+use crate::error::SerializeFromIterError;
 use crate::format::RdfFormat;
+use crate::skolemization::Skolemization;
 #[cfg(feature = "async-tokio")]
 use oxjsonld::TokioAsyncWriterJsonLdSerializer;
 use oxjsonld::{JsonLdProfile, JsonLdSerializer, WriterJsonLdSerializer};
-use oxrdf::{GraphNameRef, IriParseError, QuadRef, TripleRef};
+#[cfg(feature = "rdf-12")]
+use oxrdf::BlankNode;
+#[cfg(feature = "rdf-12")]
+use oxrdf::vocab::rdf;
+use oxrdf::{
+    GraphName, GraphNameRef, IriParseError, Literal, LiteralRef, NamedNode, NamedNodeRef,
+    NamedOrBlankNode, NamedOrBlankNodeRef, Quad, QuadRef, Skolemizer, Term, TermRef, Triple,
+    TripleRef,
+};
 #[cfg(feature = "async-tokio")]
 use oxrdfxml::TokioAsyncWriterRdfXmlSerializer;
 use oxrdfxml::{RdfXmlSerializer, WriterRdfXmlSerializer};
@@ -20,7 +30,9 @@ use oxttl::trig::{TriGSerializer, WriterTriGSerializer};
 #[cfg(feature = "async-tokio")]
 use oxttl::turtle::TokioAsyncWriterTurtleSerializer;
 use oxttl::turtle::{TurtleSerializer, WriterTurtleSerializer};
+use std::convert::Infallible;
 use std::io::{self, Write};
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::AsyncWrite;
 
@@ -53,6 +65,48 @@ use tokio::io::AsyncWrite;
 #[derive(Clone)]
 pub struct RdfSerializer {
     inner: RdfSerializerKind,
+    invalid_iri_handling: Option<InvalidIriPolicy>,
+    rdf_star: RdfStarSerializationPolicy,
+    skolemization: Option<Skolemization>,
+}
+
+/// What to do with the quads containing an IRI that is not valid according to [RFC 3987](https://www.ietf.org/rfc/rfc3987.html), like `<http://example.com/a b>`.
+///
+/// Such IRIs might have been produced by a parser set to be [lenient](crate::RdfParser::lenient).
+///
+/// It is set using [`RdfSerializer::with_invalid_iri_handling`].
+#[derive(Clone)]
+pub enum InvalidIriPolicy {
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput).
+    Error,
+    /// Does not write the quad and calls the given callback with it.
+    Skip(Arc<dyn Fn(QuadRef<'_>, &IriParseError) + Send + Sync>),
+    /// Percent-encodes the characters that are not allowed in IRIs, like spaces.
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if the IRI is still not valid after that, e.g. if it is relative.
+    PercentEncode,
+}
+
+/// What to do with the RDF 1.2 triple terms (RDF-star quoted triples) like `<<( ex:s ex:p ex:o )>>`.
+///
+/// Triple terms only exist if the `rdf-12` feature is enabled.
+///
+/// It is set using [`RdfSerializer::with_rdf_star`].
+#[derive(Clone, Copy, Default)]
+pub enum RdfStarSerializationPolicy {
+    /// Writes the triple terms as is (the default).
+    ///
+    /// Formats without a syntax for triple terms like RDF/XML return an error.
+    #[default]
+    WriteAsIs,
+    /// Replaces each triple term by a blank node described using the
+    /// [RDF 1.1 reification vocabulary](https://www.w3.org/TR/rdf11-mt/#reification)
+    /// (`rdf:Statement`, `rdf:subject`, `rdf:predicate` and `rdf:object`) for consumers that do not support triple terms.
+    ///
+    /// The subject of a `rdf:reifies` triple is described directly instead of introducing a new blank node.
+    Reify,
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput).
+    Error,
 }
 
 #[derive(Clone)]
@@ -63,10 +117,15 @@ enum RdfSerializerKind {
     RdfXml(RdfXmlSerializer),
     TriG(TriGSerializer),
     Turtle(TurtleSerializer),
+    #[cfg(feature = "hdt")]
+    Hdt,
 }
 
 impl RdfSerializer {
     /// Builds a serializer for the given format
+    ///
+    /// Serialization to HDT is not supported: the returned serializer fails when writing.
+    /// Use [`try_from_format`](Self::try_from_format) to get an error when building the serializer instead.
     #[inline]
     pub fn from_format(format: RdfFormat) -> Self {
         Self {
@@ -79,8 +138,36 @@ impl RdfSerializer {
                 RdfFormat::Turtle | RdfFormat::N3 => {
                     RdfSerializerKind::Turtle(TurtleSerializer::new())
                 }
+                #[cfg(feature = "hdt")]
+                RdfFormat::Hdt => RdfSerializerKind::Hdt,
             },
+            invalid_iri_handling: None,
+            rdf_star: RdfStarSerializationPolicy::WriteAsIs,
+            skolemization: None,
+        }
+    }
+
+    /// Builds a serializer for the given format
+    ///
+    /// Returns an error of kind [`Unsupported`](io::ErrorKind::Unsupported) if the format does not support serialization like HDT.
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfSerializer};
+    ///
+    /// assert_eq!(
+    ///     RdfSerializer::try_from_format(RdfFormat::Turtle)?.format(),
+    ///     RdfFormat::Turtle
+    /// );
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[inline]
+    #[cfg_attr(not(feature = "hdt"), expect(clippy::unnecessary_wraps))]
+    pub fn try_from_format(format: RdfFormat) -> io::Result<Self> {
+        #[cfg(feature = "hdt")]
+        if format == RdfFormat::Hdt {
+            return Err(hdt_serialization_error());
         }
+        Ok(Self::from_format(format))
     }
 
     /// The format the serializer serializes to.
@@ -103,6 +190,8 @@ impl RdfSerializer {
             RdfSerializerKind::RdfXml(_) => RdfFormat::RdfXml,
             RdfSerializerKind::TriG(_) => RdfFormat::TriG,
             RdfSerializerKind::Turtle(_) => RdfFormat::Turtle,
+            #[cfg(feature = "hdt")]
+            RdfSerializerKind::Hdt => RdfFormat::Hdt,
         }
     }
 
@@ -146,6 +235,8 @@ impl RdfSerializer {
             RdfSerializerKind::Turtle(s) => {
                 RdfSerializerKind::Turtle(s.with_prefix(prefix_name, prefix_iri)?)
             }
+            #[cfg(feature = "hdt")]
+            RdfSerializerKind::Hdt => RdfSerializerKind::Hdt,
         };
         Ok(self)
     }
@@ -181,10 +272,120 @@ impl RdfSerializer {
             RdfSerializerKind::RdfXml(s) => RdfSerializerKind::RdfXml(s.with_base_iri(base_iri)?),
             RdfSerializerKind::TriG(s) => RdfSerializerKind::TriG(s.with_base_iri(base_iri)?),
             RdfSerializerKind::Turtle(s) => RdfSerializerKind::Turtle(s.with_base_iri(base_iri)?),
+            #[cfg(feature = "hdt")]
+            RdfSerializerKind::Hdt => RdfSerializerKind::Hdt,
         };
         Ok(self)
     }
 
+    /// Validates the IRIs before writing them and sets what to do with the invalid ones.
+    ///
+    /// By default, IRIs are written as is without validation, which might produce files other parsers reject.
+    ///
+    /// ```
+    /// use oxrdf::{NamedNode, Triple};
+    /// use oxrdfio::{InvalidIriPolicy, RdfFormat, RdfSerializer};
+    ///
+    /// let mut serializer = RdfSerializer::from_format(RdfFormat::NTriples)
+    ///     .with_invalid_iri_handling(InvalidIriPolicy::PercentEncode)
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_triple(&Triple::new(
+    ///     NamedNode::new_unchecked("http://example.com/a b"),
+    ///     NamedNode::new("http://example.com/p")?,
+    ///     NamedNode::new("http://example.com/o")?,
+    /// ))?;
+    /// assert_eq!(
+    ///     serializer.finish()?,
+    ///     b"<http://example.com/a%20b> <http://example.com/p> <http://example.com/o> .\n"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_invalid_iri_handling(mut self, policy: InvalidIriPolicy) -> Self {
+        self.invalid_iri_handling = Some(policy);
+        self
+    }
+
+    /// Sets what to do with the RDF 1.2 triple terms (RDF-star quoted triples) for consumers that do not support them.
+    ///
+    /// ```
+    /// # #[cfg(feature = "rdf-12")]
+    /// # {
+    /// use oxrdf::{BlankNode, NamedNode, Triple};
+    /// use oxrdfio::{RdfFormat, RdfSerializer, RdfStarSerializationPolicy};
+    ///
+    /// let mut serializer = RdfSerializer::from_format(RdfFormat::NTriples)
+    ///     .with_rdf_star(RdfStarSerializationPolicy::Reify)
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_triple(&Triple::new(
+    ///     BlankNode::new("r")?,
+    ///     NamedNode::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#reifies")?,
+    ///     Triple::new(
+    ///         NamedNode::new("http://example.com/s")?,
+    ///         NamedNode::new("http://example.com/p")?,
+    ///         NamedNode::new("http://example.com/o")?,
+    ///     ),
+    /// ))?;
+    /// assert_eq!(
+    ///     serializer.finish()?,
+    ///     b"_:r <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/1999/02/22-rdf-syntax-ns#Statement> .
+    /// _:r <http://www.w3.org/1999/02/22-rdf-syntax-ns#subject> <http://example.com/s> .
+    /// _:r <http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate> <http://example.com/p> .
+    /// _:r <http://www.w3.org/1999/02/22-rdf-syntax-ns#object> <http://example.com/o> .
+    /// "
+    /// );
+    /// # }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_rdf_star(mut self, policy: RdfStarSerializationPolicy) -> Self {
+        self.rdf_star = policy;
+        self
+    }
+
+    /// Replaces the blank nodes by [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization)
+    /// of the shape `{base authority}/.well-known/genid/{blank node id}` before writing them.
+    ///
+    /// It overrides [`de_skolemize_blank_nodes`](Self::de_skolemize_blank_nodes).
+    /// See [`Skolemizer`] for more details.
+    ///
+    /// ```
+    /// use oxrdf::{BlankNode, NamedNode, Triple};
+    /// use oxrdfio::{RdfFormat, RdfSerializer};
+    ///
+    /// let mut serializer = RdfSerializer::from_format(RdfFormat::NTriples)
+    ///     .skolemize_blank_nodes(NamedNode::new("http://example.com/")?)
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_triple(&Triple::new(
+    ///     BlankNode::new("a")?,
+    ///     NamedNode::new("http://example.com/p")?,
+    ///     NamedNode::new("http://example.com/o")?,
+    /// ))?;
+    /// assert_eq!(
+    ///     serializer.finish()?,
+    ///     b"<http://example.com/.well-known/genid/a> <http://example.com/p> <http://example.com/o> .\n"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn skolemize_blank_nodes(mut self, base: impl Into<NamedNode>) -> Self {
+        self.skolemization = Some(Skolemization::Skolemize(Skolemizer::new(
+            base.into().as_ref(),
+        )));
+        self
+    }
+
+    /// Replaces the [Skolem IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization)
+    /// of the shape `.../.well-known/genid/{id}` by blank nodes before writing them.
+    ///
+    /// It is the inverse of [`skolemize_blank_nodes`](Self::skolemize_blank_nodes) and overrides it.
+    /// See [`Skolemizer::de_skolemize_named_node`] for more details.
+    #[inline]
+    pub fn de_skolemize_blank_nodes(mut self) -> Self {
+        self.skolemization = Some(Skolemization::DeSkolemize);
+        self
+    }
+
     /// Serializes to a [`Write`] implementation.
     ///
     /// <div class="warning">
@@ -228,10 +429,72 @@ impl RdfSerializer {
                 RdfSerializerKind::Turtle(s) => {
                     WriterQuadSerializerKind::Turtle(s.for_writer(writer))
                 }
+                #[cfg(feature = "hdt")]
+                RdfSerializerKind::Hdt => WriterQuadSerializerKind::Hdt,
             },
+            invalid_iri_handling: self.invalid_iri_handling,
+            rdf_star: self.rdf_star,
+            skolemization: self.skolemization,
         }
     }
 
+    /// Serializes all the statements returned by an iterator to a [`Write`] implementation
+    /// and returns the number of serialized statements.
+    ///
+    /// The statements are streamed to the writer without being buffered in memory.
+    /// They might be quads, triples or results of them like the ones returned by a store iterator.
+    /// Consecutive statements sharing the same subject are grouped in the formats supporting it like Turtle.
+    ///
+    /// If the iterator returns an error or the writer fails, the error is returned with the index of the failed statement.
+    /// Failures of [`finish`](WriterQuadSerializer::finish) are reported with the number of statements as index.
+    ///
+    /// <div class="warning">
+    ///
+    /// This writer does unbuffered writes. You might want to use [`BufWriter`](io::BufWriter) to avoid that.</div>
+    ///
+    /// ```
+    /// use oxrdfio::{RdfFormat, RdfSerializer};
+    /// use oxrdf::{NamedNodeRef, TripleRef};
+    ///
+    /// let s = NamedNodeRef::new("http://example.com/s")?;
+    /// let p = NamedNodeRef::new("http://example.com/p")?;
+    /// let mut output = Vec::new();
+    /// let count = RdfSerializer::from_format(RdfFormat::Turtle)
+    ///     .with_prefix("ex", "http://example.com/")?
+    ///     .serialize_from_iter(&mut output, [TripleRef::new(s, p, s), TripleRef::new(s, p, p)])?;
+    /// assert_eq!(count, 2);
+    /// assert_eq!(output, b"@prefix ex: <http://example.com/> .\nex:s ex:p ex:s , ex:p .\n");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn serialize_from_iter<W: Write, S: SerializableStatement>(
+        self,
+        writer: W,
+        statements: impl IntoIterator<Item = S>,
+    ) -> Result<usize, SerializeFromIterError<S::Error>> {
+        let mut serializer = self.for_writer(writer);
+        let mut count = 0;
+        for statement in statements {
+            statement
+                .with_quad(|quad| serializer.serialize_quad(quad))
+                .map_err(|error| SerializeFromIterError::Input {
+                    index: count,
+                    error,
+                })?
+                .map_err(|error| SerializeFromIterError::Io {
+                    index: count,
+                    error,
+                })?;
+            count += 1;
+        }
+        serializer
+            .finish()
+            .map_err(|error| SerializeFromIterError::Io {
+                index: count,
+                error,
+            })?;
+        Ok(count)
+    }
+
     /// Serializes to a Tokio [`AsyncWrite`] implementation.
     ///
     /// <div class="warning">
@@ -284,7 +547,12 @@ impl RdfSerializer {
                 RdfSerializerKind::Turtle(s) => {
                     TokioAsyncWriterQuadSerializerKind::Turtle(s.for_tokio_async_writer(writer))
                 }
+                #[cfg(feature = "hdt")]
+                RdfSerializerKind::Hdt => TokioAsyncWriterQuadSerializerKind::Hdt,
             },
+            invalid_iri_handling: self.invalid_iri_handling,
+            rdf_star: self.rdf_star,
+            skolemization: self.skolemization,
         }
     }
 }
@@ -295,6 +563,77 @@ impl From<RdfFormat> for RdfSerializer {
     }
 }
 
+/// A statement that can be serialized by [`RdfSerializer::serialize_from_iter`].
+///
+/// It is implemented by quads, triples and results of them.
+/// Triples are serialized in the default graph.
+pub trait SerializableStatement {
+    /// The error returned instead of the statement.
+    type Error;
+
+    /// Calls `f` with the statement as a quad.
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Self::Error>;
+}
+
+impl SerializableStatement for QuadRef<'_> {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self))
+    }
+}
+
+impl SerializableStatement for &Quad {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self.as_ref()))
+    }
+}
+
+impl SerializableStatement for Quad {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self.as_ref()))
+    }
+}
+
+impl SerializableStatement for TripleRef<'_> {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self.in_graph(GraphNameRef::DefaultGraph)))
+    }
+}
+
+impl SerializableStatement for &Triple {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self.as_ref().in_graph(GraphNameRef::DefaultGraph)))
+    }
+}
+
+impl SerializableStatement for Triple {
+    type Error = Infallible;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, Infallible> {
+        Ok(f(self.as_ref().in_graph(GraphNameRef::DefaultGraph)))
+    }
+}
+
+impl<T: SerializableStatement<Error = Infallible>, E> SerializableStatement for Result<T, E> {
+    type Error = E;
+
+    fn with_quad<R>(self, f: impl FnOnce(QuadRef<'_>) -> R) -> Result<R, E> {
+        match self?.with_quad(f) {
+            Ok(result) => Ok(result),
+            Err(error) => match error {},
+        }
+    }
+}
+
 /// Serializes quads or triples to a [`Write`] implementation.
 ///
 /// Can be built using [`RdfSerializer::for_writer`].
@@ -324,6 +663,10 @@ impl From<RdfFormat> for RdfSerializer {
 #[must_use]
 pub struct WriterQuadSerializer<W: Write> {
     inner: WriterQuadSerializerKind<W>,
+    invalid_iri_handling: Option<InvalidIriPolicy>,
+    #[cfg_attr(not(feature = "rdf-12"), expect(dead_code))]
+    rdf_star: RdfStarSerializationPolicy,
+    skolemization: Option<Skolemization>,
 }
 
 enum WriterQuadSerializerKind<W: Write> {
@@ -333,11 +676,52 @@ enum WriterQuadSerializerKind<W: Write> {
     RdfXml(WriterRdfXmlSerializer<W>),
     TriG(WriterTriGSerializer<W>),
     Turtle(WriterTurtleSerializer<W>),
+    #[cfg(feature = "hdt")]
+    Hdt,
 }
 
 impl<W: Write> WriterQuadSerializer<W> {
     /// Serializes a [`QuadRef`]
     pub fn serialize_quad<'a>(&mut self, quad: impl Into<QuadRef<'a>>) -> io::Result<()> {
+        let quad = quad.into();
+        #[cfg(feature = "rdf-12")]
+        if let TermRef::Triple(_) = quad.object {
+            match self.rdf_star {
+                RdfStarSerializationPolicy::WriteAsIs => (),
+                RdfStarSerializationPolicy::Reify => {
+                    for quad in reify_triple_terms(quad) {
+                        self.write_quad(quad.as_ref())?;
+                    }
+                    return Ok(());
+                }
+                RdfStarSerializationPolicy::Error => return Err(triple_term_error(quad)),
+            }
+        }
+        self.write_quad(quad)
+    }
+
+    /// Serializes a [`TripleRef`]
+    pub fn serialize_triple<'a>(&mut self, triple: impl Into<TripleRef<'a>>) -> io::Result<()> {
+        self.serialize_quad(triple.into().in_graph(GraphNameRef::DefaultGraph))
+    }
+
+    fn write_quad(&mut self, quad: QuadRef<'_>) -> io::Result<()> {
+        let skolemized;
+        let quad = if let Some(skolemization) = &self.skolemization {
+            skolemized = skolemization.apply(quad);
+            skolemized.as_ref()
+        } else {
+            quad
+        };
+        let fixed;
+        let quad = match check_iris(quad, self.invalid_iri_handling.as_ref())? {
+            CheckedQuad::Valid(quad) => quad,
+            CheckedQuad::Fixed(quad) => {
+                fixed = quad;
+                fixed.as_ref()
+            }
+            CheckedQuad::Skipped => return Ok(()),
+        };
         match &mut self.inner {
             WriterQuadSerializerKind::JsonLd(serializer) => serializer.serialize_quad(quad),
             WriterQuadSerializerKind::NQuads(serializer) => serializer.serialize_quad(quad),
@@ -351,14 +735,11 @@ impl<W: Write> WriterQuadSerializer<W> {
             WriterQuadSerializerKind::Turtle(serializer) => {
                 serializer.serialize_triple(to_triple(quad)?)
             }
+            #[cfg(feature = "hdt")]
+            WriterQuadSerializerKind::Hdt => Err(hdt_serialization_error()),
         }
     }
 
-    /// Serializes a [`TripleRef`]
-    pub fn serialize_triple<'a>(&mut self, triple: impl Into<TripleRef<'a>>) -> io::Result<()> {
-        self.serialize_quad(triple.into().in_graph(GraphNameRef::DefaultGraph))
-    }
-
     /// Writes the last bytes of the file
     ///
     /// Note that this function does not flush the writer. You need to do that if you are using a [`BufWriter`](io::BufWriter).
@@ -370,6 +751,8 @@ impl<W: Write> WriterQuadSerializer<W> {
             WriterQuadSerializerKind::RdfXml(serializer) => serializer.finish()?,
             WriterQuadSerializerKind::TriG(serializer) => serializer.finish()?,
             WriterQuadSerializerKind::Turtle(serializer) => serializer.finish()?,
+            #[cfg(feature = "hdt")]
+            WriterQuadSerializerKind::Hdt => return Err(hdt_serialization_error()),
         })
     }
 }
@@ -407,6 +790,10 @@ impl<W: Write> WriterQuadSerializer<W> {
 #[cfg(feature = "async-tokio")]
 pub struct TokioAsyncWriterQuadSerializer<W: AsyncWrite + Unpin> {
     inner: TokioAsyncWriterQuadSerializerKind<W>,
+    invalid_iri_handling: Option<InvalidIriPolicy>,
+    #[cfg_attr(not(feature = "rdf-12"), expect(dead_code))]
+    rdf_star: RdfStarSerializationPolicy,
+    skolemization: Option<Skolemization>,
 }
 
 #[cfg(feature = "async-tokio")]
@@ -417,12 +804,57 @@ enum TokioAsyncWriterQuadSerializerKind<W: AsyncWrite + Unpin> {
     RdfXml(TokioAsyncWriterRdfXmlSerializer<W>),
     TriG(TokioAsyncWriterTriGSerializer<W>),
     Turtle(TokioAsyncWriterTurtleSerializer<W>),
+    #[cfg(feature = "hdt")]
+    Hdt,
 }
 
 #[cfg(feature = "async-tokio")]
 impl<W: AsyncWrite + Unpin> TokioAsyncWriterQuadSerializer<W> {
     /// Serializes a [`QuadRef`]
     pub async fn serialize_quad<'a>(&mut self, quad: impl Into<QuadRef<'a>>) -> io::Result<()> {
+        let quad = quad.into();
+        #[cfg(feature = "rdf-12")]
+        if let TermRef::Triple(_) = quad.object {
+            match self.rdf_star {
+                RdfStarSerializationPolicy::WriteAsIs => (),
+                RdfStarSerializationPolicy::Reify => {
+                    for quad in reify_triple_terms(quad) {
+                        self.write_quad(quad.as_ref()).await?;
+                    }
+                    return Ok(());
+                }
+                RdfStarSerializationPolicy::Error => return Err(triple_term_error(quad)),
+            }
+        }
+        self.write_quad(quad).await
+    }
+
+    /// Serializes a [`TripleRef`]
+    pub async fn serialize_triple<'a>(
+        &mut self,
+        triple: impl Into<TripleRef<'a>>,
+    ) -> io::Result<()> {
+        self.serialize_quad(triple.into().in_graph(GraphNameRef::DefaultGraph))
+            .await
+    }
+
+    async fn write_quad(&mut self, quad: QuadRef<'_>) -> io::Result<()> {
+        let skolemized;
+        let quad = if let Some(skolemization) = &self.skolemization {
+            skolemized = skolemization.apply(quad);
+            skolemized.as_ref()
+        } else {
+            quad
+        };
+        let fixed;
+        let quad = match check_iris(quad, self.invalid_iri_handling.as_ref())? {
+            CheckedQuad::Valid(quad) => quad,
+            CheckedQuad::Fixed(quad) => {
+                fixed = quad;
+                fixed.as_ref()
+            }
+            CheckedQuad::Skipped => return Ok(()),
+        };
         match &mut self.inner {
             TokioAsyncWriterQuadSerializerKind::JsonLd(serializer) => {
                 serializer.serialize_quad(quad).await
@@ -442,18 +874,11 @@ impl<W: AsyncWrite + Unpin> TokioAsyncWriterQuadSerializer<W> {
             TokioAsyncWriterQuadSerializerKind::Turtle(serializer) => {
                 serializer.serialize_triple(to_triple(quad)?).await
             }
+            #[cfg(feature = "hdt")]
+            TokioAsyncWriterQuadSerializerKind::Hdt => Err(hdt_serialization_error()),
         }
     }
 
-    /// Serializes a [`TripleRef`]
-    pub async fn serialize_triple<'a>(
-        &mut self,
-        triple: impl Into<TripleRef<'a>>,
-    ) -> io::Result<()> {
-        self.serialize_quad(triple.into().in_graph(GraphNameRef::DefaultGraph))
-            .await
-    }
-
     /// Writes the last bytes of the file
     ///
     /// Note that this function does not flush the writer. You need to do that if you are using a [`BufWriter`](io::BufWriter).
@@ -465,6 +890,8 @@ impl<W: AsyncWrite + Unpin> TokioAsyncWriterQuadSerializer<W> {
             TokioAsyncWriterQuadSerializerKind::RdfXml(serializer) => serializer.finish().await?,
             TokioAsyncWriterQuadSerializerKind::TriG(serializer) => serializer.finish().await?,
             TokioAsyncWriterQuadSerializerKind::Turtle(serializer) => serializer.finish().await?,
+            #[cfg(feature = "hdt")]
+            TokioAsyncWriterQuadSerializerKind::Hdt => return Err(hdt_serialization_error()),
         })
     }
 }
@@ -480,3 +907,489 @@ fn to_triple<'a>(quad: impl Into<QuadRef<'a>>) -> io::Result<TripleRef<'a>> {
         ))
     }
 }
+
+#[cfg(feature = "rdf-12")]
+fn triple_term_error(quad: QuadRef<'_>) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Triple terms are not allowed, found in {quad}"),
+    )
+}
+
+/// Rewrites the triple terms using the RDF 1.1 reification vocabulary
+#[cfg(feature = "rdf-12")]
+fn reify_triple_terms(quad: QuadRef<'_>) -> Vec<Quad> {
+    let mut output = Vec::new();
+    let graph_name = quad.graph_name.into_owned();
+    match quad.object {
+        TermRef::Triple(triple) if quad.predicate == rdf::REIFIES => {
+            add_reification(quad.subject.into_owned(), triple, &graph_name, &mut output);
+        }
+        object => {
+            let object = reify_term(object, &graph_name, &mut output);
+            output.push(Quad::new(
+                quad.subject.into_owned(),
+                quad.predicate.into_owned(),
+                object,
+                graph_name,
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(feature = "rdf-12")]
+fn reify_term(term: TermRef<'_>, graph_name: &GraphName, output: &mut Vec<Quad>) -> Term {
+    if let TermRef::Triple(triple) = term {
+        let reifier = BlankNode::default();
+        add_reification(reifier.clone().into(), triple, graph_name, output);
+        reifier.into()
+    } else {
+        term.into_owned()
+    }
+}
+
+#[cfg(feature = "rdf-12")]
+fn add_reification(
+    reifier: NamedOrBlankNode,
+    triple: &Triple,
+    graph_name: &GraphName,
+    output: &mut Vec<Quad>,
+) {
+    output.push(Quad::new(
+        reifier.clone(),
+        rdf::TYPE,
+        rdf::STATEMENT,
+        graph_name.clone(),
+    ));
+    output.push(Quad::new(
+        reifier.clone(),
+        rdf::SUBJECT,
+        triple.subject.clone(),
+        graph_name.clone(),
+    ));
+    output.push(Quad::new(
+        reifier.clone(),
+        rdf::PREDICATE,
+        triple.predicate.clone(),
+        graph_name.clone(),
+    ));
+    let object = reify_term(triple.object.as_ref(), graph_name, output);
+    output.push(Quad::new(reifier, rdf::OBJECT, object, graph_name.clone()));
+}
+
+enum CheckedQuad<'a> {
+    Valid(QuadRef<'a>),
+    Fixed(Quad),
+    Skipped,
+}
+
+fn check_iris<'a>(
+    quad: QuadRef<'a>,
+    policy: Option<&InvalidIriPolicy>,
+) -> io::Result<CheckedQuad<'a>> {
+    let Some(policy) = policy else {
+        return Ok(CheckedQuad::Valid(quad));
+    };
+    let Some((iri, error)) = find_invalid_iri(quad) else {
+        return Ok(CheckedQuad::Valid(quad));
+    };
+    match policy {
+        InvalidIriPolicy::Error => Err(invalid_iri_error(iri, &error)),
+        InvalidIriPolicy::Skip(callback) => {
+            callback(quad, &error);
+            Ok(CheckedQuad::Skipped)
+        }
+        InvalidIriPolicy::PercentEncode => {
+            let fixed = Quad::new(
+                percent_encode_named_or_blank_node(quad.subject),
+                percent_encode_named_node(quad.predicate),
+                percent_encode_term(quad.object),
+                match quad.graph_name {
+                    GraphNameRef::NamedNode(node) => percent_encode_named_node(node).into(),
+                    GraphNameRef::BlankNode(node) => node.into_owned().into(),
+                    GraphNameRef::DefaultGraph => GraphName::DefaultGraph,
+                },
+            );
+            if let Some((iri, error)) = find_invalid_iri(fixed.as_ref()) {
+                return Err(invalid_iri_error(iri, &error));
+            }
+            Ok(CheckedQuad::Fixed(fixed))
+        }
+    }
+}
+
+fn find_invalid_iri(quad: QuadRef<'_>) -> Option<(NamedNodeRef<'_>, IriParseError)> {
+    if let NamedOrBlankNodeRef::NamedNode(node) = quad.subject {
+        if let Some(error) = validate_iri(node) {
+            return Some((node, error));
+        }
+    }
+    if let Some(error) = validate_iri(quad.predicate) {
+        return Some((quad.predicate, error));
+    }
+    if let Some(invalid) = find_invalid_iri_in_term(quad.object) {
+        return Some(invalid);
+    }
+    if let GraphNameRef::NamedNode(node) = quad.graph_name {
+        if let Some(error) = validate_iri(node) {
+            return Some((node, error));
+        }
+    }
+    None
+}
+
+fn find_invalid_iri_in_term(term: TermRef<'_>) -> Option<(NamedNodeRef<'_>, IriParseError)> {
+    match term {
+        TermRef::NamedNode(node) => validate_iri(node).map(|error| (node, error)),
+        TermRef::BlankNode(_) => None,
+        TermRef::Literal(literal) => {
+            let datatype = literal.datatype();
+            validate_iri(datatype).map(|error| (datatype, error))
+        }
+        #[cfg(feature = "rdf-12")]
+        TermRef::Triple(triple) => {
+            find_invalid_iri(triple.as_ref().in_graph(GraphNameRef::DefaultGraph))
+        }
+    }
+}
+
+fn validate_iri(node: NamedNodeRef<'_>) -> Option<IriParseError> {
+    NamedNodeRef::new(node.as_str()).err()
+}
+
+fn invalid_iri_error(iri: NamedNodeRef<'_>, error: &IriParseError) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Invalid IRI <{}>: {error}", iri.as_str()),
+    )
+}
+
+fn percent_encode_named_or_blank_node(node: NamedOrBlankNodeRef<'_>) -> NamedOrBlankNode {
+    match node {
+        NamedOrBlankNodeRef::NamedNode(node) => percent_encode_named_node(node).into(),
+        NamedOrBlankNodeRef::BlankNode(node) => node.into_owned().into(),
+    }
+}
+
+fn percent_encode_term(term: TermRef<'_>) -> Term {
+    match term {
+        TermRef::NamedNode(node) => percent_encode_named_node(node).into(),
+        TermRef::BlankNode(node) => node.into_owned().into(),
+        TermRef::Literal(literal) => percent_encode_literal(literal).into(),
+        #[cfg(feature = "rdf-12")]
+        TermRef::Triple(triple) => Triple::new(
+            percent_encode_named_or_blank_node(triple.subject.as_ref()),
+            percent_encode_named_node(triple.predicate.as_ref()),
+            percent_encode_term(triple.object.as_ref()),
+        )
+        .into(),
+    }
+}
+
+fn percent_encode_literal(literal: LiteralRef<'_>) -> Literal {
+    if literal.language().is_some() || validate_iri(literal.datatype()).is_none() {
+        return literal.into_owned();
+    }
+    Literal::new_typed_literal(
+        literal.value(),
+        percent_encode_named_node(literal.datatype()),
+    )
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Percent-encodes the characters that are never allowed in IRIs
+fn percent_encode_named_node(node: NamedNodeRef<'_>) -> NamedNode {
+    let iri = node.as_str();
+    let mut encoded = String::with_capacity(iri.len());
+    for (i, c) in iri.char_indices() {
+        let must_encode = match c {
+            '%' => !iri
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit())),
+            '"' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}' => true,
+            _ => c <= ' ' || ('\u{7F}'..='\u{9F}').contains(&c),
+        };
+        if must_encode {
+            for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                encoded.push('%');
+                encoded.push(char::from(HEX_DIGITS[usize::from(b >> 4)]));
+                encoded.push(char::from(HEX_DIGITS[usize::from(b & 0xF)]));
+            }
+        } else {
+            encoded.push(c);
+        }
+    }
+    NamedNode::new_unchecked(encoded)
+}
+
+#[cfg(feature = "hdt")]
+fn hdt_serialization_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Serialization to HDT is not supported",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn quad_with_invalid_iri() -> Quad {
+        Quad::new(
+            NamedNode::new_unchecked("http://example.com/a b"),
+            NamedNode::new_unchecked("http://example.com/p"),
+            Literal::new_typed_literal("1", NamedNode::new_unchecked("http://example.com/{t}")),
+            GraphName::DefaultGraph,
+        )
+    }
+
+    #[test]
+    fn invalid_iris_are_written_as_is_by_default() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads).for_writer(Vec::new());
+        serializer.serialize_quad(&quad_with_invalid_iri()).unwrap();
+        assert_eq!(
+            serializer.finish().unwrap(),
+            b"<http://example.com/a b> <http://example.com/p> \"1\"^^<http://example.com/{t}> .\n"
+        );
+    }
+
+    #[test]
+    fn invalid_iris_error() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_invalid_iri_handling(InvalidIriPolicy::Error)
+            .for_writer(Vec::new());
+        let error = serializer
+            .serialize_quad(&quad_with_invalid_iri())
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("<http://example.com/a b>"));
+    }
+
+    #[test]
+    fn invalid_iris_skip() {
+        let skipped = Arc::new(Mutex::new(Vec::new()));
+        let skipped_in_callback = Arc::clone(&skipped);
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_invalid_iri_handling(InvalidIriPolicy::Skip(Arc::new(move |quad, _| {
+                skipped_in_callback.lock().unwrap().push(quad.into_owned());
+            })))
+            .for_writer(Vec::new());
+        serializer.serialize_quad(&quad_with_invalid_iri()).unwrap();
+        assert_eq!(serializer.finish().unwrap(), b"");
+        assert_eq!(*skipped.lock().unwrap(), [quad_with_invalid_iri()]);
+    }
+
+    #[test]
+    fn invalid_iris_percent_encode() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_invalid_iri_handling(InvalidIriPolicy::PercentEncode)
+            .for_writer(Vec::new());
+        serializer.serialize_quad(&quad_with_invalid_iri()).unwrap();
+        serializer
+            .serialize_quad(QuadRef::new(
+                NamedNodeRef::new_unchecked("http://example.com/100%"),
+                NamedNodeRef::new_unchecked("http://example.com/%C3%A9"),
+                NamedNodeRef::new_unchecked("http://example.com/\u{9}"),
+                GraphNameRef::DefaultGraph,
+            ))
+            .unwrap();
+        assert_eq!(
+            serializer.finish().unwrap(),
+            b"<http://example.com/a%20b> <http://example.com/p> \"1\"^^<http://example.com/%7Bt%7D> .\n<http://example.com/100%25> <http://example.com/%C3%A9> <http://example.com/%09> .\n"
+        );
+    }
+
+    #[test]
+    fn relative_iris_can_not_be_percent_encoded() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_invalid_iri_handling(InvalidIriPolicy::PercentEncode)
+            .for_writer(Vec::new());
+        let error = serializer
+            .serialize_quad(QuadRef::new(
+                NamedNodeRef::new_unchecked("a b"),
+                NamedNodeRef::new_unchecked("http://example.com/p"),
+                NamedNodeRef::new_unchecked("http://example.com/o"),
+                GraphNameRef::DefaultGraph,
+            ))
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "rdf-12")]
+    fn quad_with_triple_term() -> Quad {
+        Quad::new(
+            NamedNode::new_unchecked("http://example.com/s"),
+            NamedNode::new_unchecked("http://example.com/p"),
+            Triple::new(
+                NamedNode::new_unchecked("http://example.com/a"),
+                NamedNode::new_unchecked("http://example.com/b"),
+                Triple::new(
+                    NamedNode::new_unchecked("http://example.com/c"),
+                    NamedNode::new_unchecked("http://example.com/d"),
+                    NamedNode::new_unchecked("http://example.com/e"),
+                ),
+            ),
+            NamedNode::new_unchecked("http://example.com/g"),
+        )
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn triple_terms_are_written_as_is_by_default() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads).for_writer(Vec::new());
+        serializer.serialize_quad(&quad_with_triple_term()).unwrap();
+        assert_eq!(
+            serializer.finish().unwrap(),
+            b"<http://example.com/s> <http://example.com/p> <<( <http://example.com/a> <http://example.com/b> <<( <http://example.com/c> <http://example.com/d> <http://example.com/e> )>> )>> <http://example.com/g> .\n"
+        );
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn triple_terms_error() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_rdf_star(RdfStarSerializationPolicy::Error)
+            .for_writer(Vec::new());
+        let error = serializer
+            .serialize_quad(&quad_with_triple_term())
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn triple_terms_reify() {
+        let mut serializer = RdfSerializer::from_format(RdfFormat::NQuads)
+            .with_rdf_star(RdfStarSerializationPolicy::Reify)
+            .for_writer(Vec::new());
+        serializer.serialize_quad(&quad_with_triple_term()).unwrap();
+        let output = serializer.finish().unwrap();
+        let quads = crate::RdfParser::from_format(RdfFormat::NQuads)
+            .for_slice(&output)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(quads.len(), 9);
+        assert!(quads.iter().all(|q| !matches!(q.object, Term::Triple(_))
+            && q.graph_name.to_string() == "<http://example.com/g>"));
+        let statements = quads
+            .iter()
+            .filter(|q| q.predicate == rdf::TYPE && q.object == rdf::STATEMENT.into())
+            .count();
+        assert_eq!(statements, 2);
+        let last = quads.last().unwrap();
+        assert_eq!(last.subject.to_string(), "<http://example.com/s>");
+        assert!(matches!(last.object, Term::BlankNode(_)));
+    }
+
+    fn example_quads() -> Vec<Quad> {
+        let s1 = NamedNode::new_unchecked("http://example.com/s1");
+        let s2 = NamedNode::new_unchecked("http://example.com/s2");
+        let p = NamedNode::new_unchecked("http://example.com/p");
+        let g = NamedNode::new_unchecked("http://example.com/g");
+        vec![
+            Quad::new(s1.clone(), p.clone(), s2.clone(), g.clone()),
+            Quad::new(s1.clone(), p.clone(), Literal::from(1), g.clone()),
+            Quad::new(s2.clone(), p.clone(), s1.clone(), g),
+            Quad::new(s1, p, s2, GraphName::DefaultGraph),
+        ]
+    }
+
+    #[test]
+    fn serialize_from_iter_groups_subjects() {
+        let mut output = Vec::new();
+        let count = RdfSerializer::from_format(RdfFormat::TriG)
+            .with_prefix("ex", "http://example.com/")
+            .unwrap()
+            .serialize_from_iter(&mut output, &example_quads())
+            .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "@prefix ex: <http://example.com/> .\nex:g {\n\tex:s1 ex:p ex:s2 , 1 .\n\tex:s2 ex:p ex:s1 .\n}\nex:s1 ex:p ex:s2 .\n"
+        );
+    }
+
+    #[test]
+    fn serialize_from_iter_all_formats() {
+        let quads = example_quads();
+        for format in [
+            RdfFormat::JsonLd {
+                profile: JsonLdProfile::Streaming.into(),
+            },
+            RdfFormat::N3,
+            RdfFormat::NQuads,
+            RdfFormat::NTriples,
+            RdfFormat::RdfXml,
+            RdfFormat::TriG,
+            RdfFormat::Turtle,
+        ] {
+            let mut output = Vec::new();
+            let triples = quads
+                .iter()
+                .map(|quad| TripleRef::from(quad.as_ref()))
+                .collect::<Vec<_>>();
+            let count = if format.supports_datasets() {
+                RdfSerializer::from_format(format).serialize_from_iter(&mut output, &quads)
+            } else {
+                RdfSerializer::from_format(format).serialize_from_iter(&mut output, triples)
+            }
+            .unwrap();
+            assert_eq!(count, 4);
+            let parsed = crate::RdfParser::from_format(format)
+                .for_slice(&output)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(parsed.len(), 4, "{format}");
+        }
+    }
+
+    #[test]
+    fn serialize_from_iter_propagates_input_errors() {
+        let mut output = Vec::new();
+        let error = RdfSerializer::from_format(RdfFormat::NQuads)
+            .serialize_from_iter(
+                &mut output,
+                example_quads()
+                    .into_iter()
+                    .map(Ok)
+                    .take(2)
+                    .chain([Err(io::Error::other("store failure"))]),
+            )
+            .unwrap_err();
+        assert_eq!(error.index(), 2);
+        assert!(matches!(error, SerializeFromIterError::Input { .. }));
+        assert_eq!(
+            error.to_string(),
+            "Error while reading the statement 2: store failure"
+        );
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn serialize_from_iter_reports_write_errors() {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk full"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let error = RdfSerializer::from_format(RdfFormat::NQuads)
+            .serialize_from_iter(FailingWriter, example_quads())
+            .unwrap_err();
+        assert_eq!(error.index(), 0);
+        assert!(matches!(error, SerializeFromIterError::Io { .. }));
+        assert_eq!(
+            error.to_string(),
+            "Error while writing the statement 0: disk full"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use oxrdf::{Quad, QuadRef, Skolemizer};
+
+/// Conversion between blank nodes and Skolem IRIs done on the fly by parsers and serializers
+#[derive(Clone)]
+pub enum Skolemization {
+    Skolemize(Skolemizer),
+    DeSkolemize,
+}
+
+impl Skolemization {
+    pub fn apply(&self, quad: QuadRef<'_>) -> Quad {
+        match self {
+            Self::Skolemize(skolemizer) => skolemizer.skolemize_quad(quad),
+            Self::DeSkolemize => Skolemizer::de_skolemize_quad(quad),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+#![cfg(feature = "hdt")]
+#![allow(clippy::panic_in_result_fn)]
+
+use oxrdf::Quad;
+use oxrdfio::{RdfFormat, RdfParseError, RdfParser, RdfSerializer};
+use std::error::Error;
+use std::fs::{File, read};
+use std::io;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("hdt")
+        .join(name)
+}
+
+fn expected_quads(name: &str) -> Result<Vec<Quad>, Box<dyn Error>> {
+    let mut quads = RdfParser::from_format(RdfFormat::NTriples)
+        .for_reader(File::open(fixture(name))?)
+        .collect::<Result<Vec<_>, _>>()?;
+    quads.sort_by_key(ToString::to_string);
+    Ok(quads)
+}
+
+fn sorted(mut quads: Vec<Quad>) -> Vec<Quad> {
+    quads.sort_by_key(ToString::to_string);
+    quads
+}
+
+#[test]
+fn test_hdt_bitmap_triples() -> Result<(), Box<dyn Error>> {
+    let expected = expected_quads("bitmap.nt")?;
+    let from_reader = RdfParser::from_format(RdfFormat::Hdt)
+        .for_reader(File::open(fixture("bitmap.hdt"))?)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(sorted(from_reader), expected);
+    let from_seekable_reader = RdfParser::from_format(RdfFormat::Hdt)
+        .for_seekable_reader(File::open(fixture("bitmap.hdt"))?)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(sorted(from_seekable_reader), expected);
+    let file = read(fixture("bitmap.hdt"))?;
+    let from_slice = RdfParser::from_format(RdfFormat::Hdt)
+        .for_slice(&file)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(sorted(from_slice), expected);
+    Ok(())
+}
+
+#[test]
+fn test_hdt_list_triples() -> Result<(), Box<dyn Error>> {
+    let quads = RdfParser::from_format(RdfFormat::Hdt)
+        .for_seekable_reader(File::open(fixture("list.hdt"))?)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(sorted(quads), expected_quads("list.nt")?);
+    Ok(())
+}
+
+#[test]
+fn test_hdt_bad_checksum() -> Result<(), Box<dyn Error>> {
+    let mut file = read(fixture("bitmap.hdt"))?;
+    let position = file
+        .windows(8)
+        .position(|w| w == b"zero\"@en")
+        .ok_or("zero not found")?;
+    file[position] = b'h';
+    let error = RdfParser::from_format(RdfFormat::Hdt)
+        .for_reader(file.as_slice())
+        .collect::<Result<Vec<_>, _>>()
+        .err()
+        .ok_or("The checksum should be invalid")?;
+    assert!(matches!(error, RdfParseError::Syntax(_)));
+    assert_eq!(error.to_string(), "Invalid HDT data checksum");
+    assert!(
+        RdfParser::from_format(RdfFormat::Hdt)
+            .lenient()
+            .for_reader(file.as_slice())
+            .any(|q| q.is_ok_and(|q| q.object.to_string() == "\"hero\"@en"))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_hdt_truncated() -> Result<(), Box<dyn Error>> {
+    let file = read(fixture("bitmap.hdt"))?;
+    for len in [0, 10, file.len() / 2, file.len() - 5] {
+        let result = RdfParser::from_format(RdfFormat::Hdt)
+            .for_slice(&file[..len])
+            .collect::<Result<Vec<_>, _>>();
+        assert!(result.is_err(), "Truncation to {len} bytes is not detected");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hdt_format() {
+    assert_eq!(RdfFormat::from_extension("hdt"), Some(RdfFormat::Hdt));
+    assert_eq!(
+        RdfFormat::from_media_type("application/vnd.hdt"),
+        Some(RdfFormat::Hdt)
+    );
+    assert_eq!(RdfFormat::Hdt.file_extension(), "hdt");
+    assert!(!RdfFormat::Hdt.supports_datasets());
+}
+
+#[test]
+fn test_hdt_serialization_is_not_supported() {
+    assert_eq!(
+        RdfSerializer::try_from_format(RdfFormat::Hdt)
+            .err()
+            .map(|e| e.kind()),
+        Some(io::ErrorKind::Unsupported)
+    );
+    let serializer = RdfSerializer::from_format(RdfFormat::Hdt);
+    assert_eq!(serializer.format(), RdfFormat::Hdt);
+    assert_eq!(
+        serializer
+            .for_writer(Vec::new())
+            .finish()
+            .err()
+            .map(|e| e.kind()),
+        Some(io::ErrorKind::Unsupported)
+    );
+}
+
+#[cfg(feature = "async-tokio")]
+#[tokio::test]
+async fn test_hdt_tokio_async_reader() -> Result<(), Box<dyn Error>> {
+    let file = read(fixture("list.hdt"))?;
+    let mut parser = RdfParser::from_format(RdfFormat::Hdt).for_tokio_async_reader(file.as_slice());
+    let mut quads = Vec::new();
+    while let Some(quad) = parser.next().await {
+        quads.push(quad?);
+    }
+    assert_eq!(sorted(quads), expected_quads("list.nt")?);
+    Ok(())
+}
@@ -0,0 +1,8 @@
+#!/usr/bin/env bash
+# Writes the HDT test files from their N-Triples equivalent using the rdf2hdt tool of hdt-cpp
+# (https://github.com/rdfhdt/hdt-cpp).
+# Usage: bash generate.sh
+set -euo pipefail
+cd "$(dirname "$0")"
+rdf2hdt -f ntriples bitmap.nt bitmap.hdt
+rdf2hdt -f ntriples -o "triples.type=<http://purl.org/HDT/hdt#triplesList>" list.nt list.hdt
@@ -12,7 +12,9 @@ mod utils;
 pub use error::{RdfXmlParseError, RdfXmlSyntaxError};
 #[cfg(feature = "async-tokio")]
 pub use parser::TokioAsyncReaderRdfXmlParser;
-pub use parser::{RdfXmlParser, RdfXmlPrefixesIter, ReaderRdfXmlParser, SliceRdfXmlParser};
+pub use parser::{
+    LowLevelRdfXmlParser, RdfXmlParser, RdfXmlPrefixesIter, ReaderRdfXmlParser, SliceRdfXmlParser,
+};
 #[cfg(feature = "async-tokio")]
 pub use serializer::TokioAsyncWriterRdfXmlSerializer;
 pub use serializer::{RdfXmlSerializer, WriterRdfXmlSerializer};
@@ -10,10 +10,10 @@ use quick_xml::escape::{resolve_xml_entity, unescape_with};
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::*;
 use quick_xml::name::{LocalName, Namespace, PrefixDeclaration, PrefixIter, ResolveResult};
-use quick_xml::{Decoder, Error, NsReader, Writer};
+use quick_xml::{Decoder, Error, NsReader};
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
-use std::io::{BufReader, Read};
+use std::io::{self, BufRead, BufReader, Read};
 use std::str;
 #[cfg(feature = "async-tokio")]
 use tokio::io::{AsyncRead, BufReader as AsyncBufReader};
@@ -205,6 +205,56 @@ impl RdfXmlParser {
         }
     }
 
+    /// Allows to parse a RDF/XML file by using a low-level API.
+    ///
+    /// The bytes are pushed into the parser that only keeps the not yet parsed XML events in memory.
+    ///
+    /// Count the number of people:
+    /// ```
+    /// use oxrdf::NamedNodeRef;
+    /// use oxrdf::vocab::rdf;
+    /// use oxrdfxml::RdfXmlParser;
+    ///
+    /// let file: [&[u8]; 5] = [
+    ///     br#"<?xml version="1.0"?><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" "#,
+    ///     br#"xmlns:schema="http://schema.org/"><rdf:Description rdf:about="http://example.com/foo">"#,
+    ///     br#"<rdf:type rdf:resource="http://schema.org/Person" /><schema:name>Fo"#,
+    ///     br#"o</schema:name></rdf:Description><schema:Person rdf:about="http://exa"#,
+    ///     br#"mple.com/bar" schema:name="Bar" /></rdf:RDF>"#,
+    /// ];
+    ///
+    /// let schema_person = NamedNodeRef::new("http://schema.org/Person")?;
+    /// let mut count = 0;
+    /// let mut parser = RdfXmlParser::new().low_level();
+    /// let mut file_chunks = file.iter();
+    /// while !parser.is_end() {
+    ///     // We feed more data to the parser
+    ///     if let Some(chunk) = file_chunks.next() {
+    ///         parser.extend_from_slice(chunk);
+    ///     } else {
+    ///         parser.end(); // It's finished
+    ///     }
+    ///     // We read as many triples from the parser as possible
+    ///     while let Some(triple) = parser.parse_next() {
+    ///         let triple = triple?;
+    ///         if triple.predicate == rdf::TYPE && triple.object == schema_person.into() {
+    ///             count += 1;
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn low_level(self) -> LowLevelRdfXmlParser {
+        LowLevelRdfXmlParser {
+            results: Vec::new(),
+            parser: self.into_internal(PushBuffer::default()),
+            reader_buffer: Vec::new(),
+            is_ending: false,
+            has_pending_end: false,
+        }
+    }
+
     fn into_internal<T>(self, reader: T) -> InternalRdfXmlParser<T> {
         let mut reader = NsReader::from_reader(reader);
         reader.config_mut().expand_empty_elements = true;
@@ -627,6 +677,371 @@ impl SliceRdfXmlParser<'_> {
     }
 }
 
+/// Parses a RDF/XML file by using a low-level API.
+///
+/// Can be built using [`RdfXmlParser::low_level`].
+///
+/// Count the number of people:
+/// ```
+/// use oxrdf::NamedNodeRef;
+/// use oxrdf::vocab::rdf;
+/// use oxrdfxml::RdfXmlParser;
+///
+/// let file: [&[u8]; 5] = [
+///     br#"<?xml version="1.0"?><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" "#,
+///     br#"xmlns:schema="http://schema.org/"><rdf:Description rdf:about="http://example.com/foo">"#,
+///     br#"<rdf:type rdf:resource="http://schema.org/Person" /><schema:name>Fo"#,
+///     br#"o</schema:name></rdf:Description><schema:Person rdf:about="http://exa"#,
+///     br#"mple.com/bar" schema:name="Bar" /></rdf:RDF>"#,
+/// ];
+///
+/// let schema_person = NamedNodeRef::new("http://schema.org/Person")?;
+/// let mut count = 0;
+/// let mut parser = RdfXmlParser::new().low_level();
+/// let mut file_chunks = file.iter();
+/// while !parser.is_end() {
+///     // We feed more data to the parser
+///     if let Some(chunk) = file_chunks.next() {
+///         parser.extend_from_slice(chunk);
+///     } else {
+///         parser.end(); // It's finished
+///     }
+///     // We read as many triples from the parser as possible
+///     while let Some(triple) = parser.parse_next() {
+///         let triple = triple?;
+///         if triple.predicate == rdf::TYPE && triple.object == schema_person.into() {
+///             count += 1;
+///         }
+///     }
+/// }
+/// assert_eq!(2, count);
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[must_use]
+pub struct LowLevelRdfXmlParser {
+    results: Vec<Triple>,
+    parser: InternalRdfXmlParser<PushBuffer>,
+    reader_buffer: Vec<u8>,
+    is_ending: bool,
+    /// The last event was the start of an empty element, its end is emitted without reading more data
+    has_pending_end: bool,
+}
+
+impl LowLevelRdfXmlParser {
+    /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
+    pub fn extend_from_slice(&mut self, other: &[u8]) {
+        self.parser.reader.get_mut().extend_from_slice(other)
+    }
+
+    /// Tell the parser that the file is finished.
+    ///
+    /// This triggers the parsing of the final bytes and might lead [`parse_next`](Self::parse_next) to return some extra values.
+    pub fn end(&mut self) {
+        self.parser.reader.get_mut().end();
+        self.is_ending = true;
+    }
+
+    /// Returns if the parsing is finished i.e. [`end`](Self::end) has been called and [`parse_next`](Self::parse_next) is always going to return `None`.
+    pub fn is_end(&self) -> bool {
+        self.parser.is_end && self.results.is_empty()
+    }
+
+    /// Attempt to parse a new triple from the already provided data.
+    ///
+    /// Returns [`None`] if the parsing is finished or more data is required.
+    /// If it is the case more data should be fed using [`extend_from_slice`](Self::extend_from_slice).
+    pub fn parse_next(&mut self) -> Option<Result<Triple, RdfXmlSyntaxError>> {
+        loop {
+            if let Some(triple) = self.results.pop() {
+                return Some(Ok(triple));
+            } else if self.parser.is_end {
+                return None;
+            }
+            if !self.is_ending
+                && !self.has_pending_end
+                && !self.parser.reader.get_ref().has_complete_markup()
+            {
+                return None; // We need more data
+            }
+            if let Err(RdfXmlParseError::Syntax(e)) = self.parse_step() {
+                // I/O errors can't happen
+                return Some(Err(e));
+            }
+        }
+    }
+
+    /// The list of IRI prefixes considered at the current step of the parsing.
+    ///
+    /// This method returns (prefix name, prefix value) tuples.
+    /// It is empty at the beginning of the parsing and gets updated when prefixes are encountered.
+    /// It should be full at the end of the parsing (but if a prefix is overridden, only the latest version will be returned).
+    ///
+    /// ```
+    /// use oxrdfxml::RdfXmlParser;
+    ///
+    /// let file = r#"<?xml version="1.0"?>
+    /// <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:schema="http://schema.org/">
+    ///  <rdf:Description rdf:about="http://example.com/foo">
+    ///    <rdf:type rdf:resource="http://schema.org/Person" />
+    ///    <schema:name>Foo</schema:name>
+    ///  </rdf:Description>
+    ///  <schema:Person rdf:about="http://example.com/bar" schema:name="Bar" />
+    /// </rdf:RDF>"#;
+    ///
+    /// let mut parser = RdfXmlParser::new().low_level();
+    /// parser.extend_from_slice(file.as_bytes());
+    /// assert_eq!(parser.prefixes().collect::<Vec<_>>(), []); // No prefix at the beginning
+    ///
+    /// parser.parse_next().unwrap()?; // We read the first triple
+    /// assert_eq!(
+    ///     parser.prefixes().collect::<Vec<_>>(),
+    ///     [
+    ///         ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ///         ("schema", "http://schema.org/")
+    ///     ]
+    /// ); // There are now prefixes
+    /// //
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn prefixes(&self) -> RdfXmlPrefixesIter<'_> {
+        RdfXmlPrefixesIter {
+            inner: self.parser.reader.prefixes(),
+            decoder: self.parser.reader.decoder(),
+            lenient: self.parser.lenient,
+        }
+    }
+
+    /// The base IRI considered at the current step of the parsing.
+    ///
+    /// ```
+    /// use oxrdfxml::RdfXmlParser;
+    ///
+    /// let file = r#"<?xml version="1.0"?>
+    /// <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xml:base="http://example.com/">
+    ///  <rdf:Description rdf:about="foo">
+    ///    <rdf:type rdf:resource="http://schema.org/Person" />
+    ///  </rdf:Description>
+    /// </rdf:RDF>"#;
+    ///
+    /// let mut parser = RdfXmlParser::new().low_level();
+    /// parser.extend_from_slice(file.as_bytes());
+    /// assert!(parser.base_iri().is_none()); // No base at the beginning because none has been given to the parser.
+    ///
+    /// parser.parse_next().unwrap()?; // We read the first triple
+    /// assert_eq!(parser.base_iri(), Some("http://example.com/")); // There is now a base IRI.
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn base_iri(&self) -> Option<&str> {
+        Some(self.parser.current_base_iri()?.as_str())
+    }
+
+    /// The current byte position in the input data.
+    pub fn buffer_position(&self) -> u64 {
+        self.parser.reader.buffer_position()
+    }
+
+    fn parse_step(&mut self) -> Result<(), RdfXmlParseError> {
+        self.reader_buffer.clear();
+        let event = self
+            .parser
+            .reader
+            .read_event_into(&mut self.reader_buffer)?;
+        // With expand_empty_elements, <a/> is returned as a start event followed by an end event
+        self.has_pending_end = matches!(event, Event::Start(_))
+            && self.parser.reader.get_ref().consumed().ends_with(b"/>");
+        self.parser.parse_event(event, &mut self.results)
+    }
+}
+
+/// Input buffer of [`LowLevelRdfXmlParser`].
+///
+/// It only exposes to the XML reader the bytes up to the end of the last complete markup
+/// so that the reader never reaches the end of the input in the middle of an event.
+/// The detection of the end of the markups mirrors the one done by quick-xml.
+#[derive(Default)]
+struct PushBuffer {
+    data: Vec<u8>,
+    /// Position of the first byte not consumed by the XML reader
+    position: usize,
+    /// End of the bytes exposed to the XML reader
+    available_end: usize,
+    /// End of the bytes already scanned
+    scanned_end: usize,
+    state: PushBufferState,
+}
+
+#[derive(Default, Clone, Copy)]
+enum PushBufferState {
+    #[default]
+    Text,
+    /// Just after `<`
+    MarkupStart {
+        start: usize,
+    },
+    /// Just after `<!`
+    BangStart {
+        start: usize,
+    },
+    Element {
+        quote: Option<u8>,
+    },
+    ProcessingInstruction {
+        start: usize,
+    },
+    Comment {
+        start: usize,
+    },
+    CData {
+        start: usize,
+    },
+    DocType {
+        balance: usize,
+    },
+}
+
+impl PushBuffer {
+    fn extend_from_slice(&mut self, other: &[u8]) {
+        // We drop the consumed bytes if they are the majority of the buffer to keep the memory bounded
+        if self.position > 0 && self.position >= self.data.len() / 2 {
+            self.data.drain(..self.position);
+            self.available_end -= self.position;
+            self.scanned_end -= self.position;
+            self.state = match self.state {
+                PushBufferState::MarkupStart { start } => PushBufferState::MarkupStart {
+                    start: start - self.position,
+                },
+                PushBufferState::BangStart { start } => PushBufferState::BangStart {
+                    start: start - self.position,
+                },
+                PushBufferState::ProcessingInstruction { start } => {
+                    PushBufferState::ProcessingInstruction {
+                        start: start - self.position,
+                    }
+                }
+                PushBufferState::Comment { start } => PushBufferState::Comment {
+                    start: start - self.position,
+                },
+                PushBufferState::CData { start } => PushBufferState::CData {
+                    start: start - self.position,
+                },
+                state => state,
+            };
+            self.position = 0;
+        }
+        self.data.extend_from_slice(other);
+        self.scan();
+    }
+
+    fn end(&mut self) {
+        self.available_end = self.data.len();
+        self.scanned_end = self.data.len();
+    }
+
+    fn has_complete_markup(&self) -> bool {
+        self.position < self.available_end
+    }
+
+    /// The bytes consumed by the XML reader and still in the buffer
+    fn consumed(&self) -> &[u8] {
+        &self.data[..self.position]
+    }
+
+    fn scan(&mut self) {
+        for i in self.scanned_end..self.data.len() {
+            let c = self.data[i];
+            self.state = match self.state {
+                PushBufferState::Text => {
+                    if c == b'<' {
+                        PushBufferState::MarkupStart { start: i }
+                    } else {
+                        PushBufferState::Text
+                    }
+                }
+                PushBufferState::MarkupStart { start } => match c {
+                    b'!' => PushBufferState::BangStart { start },
+                    b'?' => PushBufferState::ProcessingInstruction { start },
+                    _ => self.element_byte(i, None),
+                },
+                PushBufferState::BangStart { start } => match c {
+                    b'[' => PushBufferState::CData { start },
+                    b'-' => PushBufferState::Comment { start },
+                    b'D' | b'd' => PushBufferState::DocType { balance: 0 },
+                    // The reader is going to fail on this byte
+                    _ => self.markup_end(i),
+                },
+                PushBufferState::Element { quote } => self.element_byte(i, quote),
+                PushBufferState::ProcessingInstruction { start } => {
+                    if c == b'>' && i >= start + 2 && self.data[i - 1] == b'?' {
+                        self.markup_end(i)
+                    } else {
+                        self.state
+                    }
+                }
+                PushBufferState::Comment { start } => {
+                    if c == b'>' && i > start + 5 && self.data[..i].ends_with(b"--") {
+                        self.markup_end(i)
+                    } else {
+                        self.state
+                    }
+                }
+                PushBufferState::CData { start } => {
+                    if c == b'>' && i >= start + 4 && self.data[..i].ends_with(b"]]") {
+                        self.markup_end(i)
+                    } else {
+                        self.state
+                    }
+                }
+                PushBufferState::DocType { balance } => match c {
+                    b'<' => PushBufferState::DocType {
+                        balance: balance + 1,
+                    },
+                    b'>' => {
+                        if let Some(balance) = balance.checked_sub(1) {
+                            PushBufferState::DocType { balance }
+                        } else {
+                            self.markup_end(i)
+                        }
+                    }
+                    _ => self.state,
+                },
+            };
+        }
+        self.scanned_end = self.data.len();
+    }
+
+    fn element_byte(&mut self, i: usize, quote: Option<u8>) -> PushBufferState {
+        match (quote, self.data[i]) {
+            (None, b'>') => self.markup_end(i),
+            (None, c @ (b'\'' | b'"')) => PushBufferState::Element { quote: Some(c) },
+            (Some(q), c) if q == c => PushBufferState::Element { quote: None },
+            _ => PushBufferState::Element { quote },
+        }
+    }
+
+    fn markup_end(&mut self, i: usize) -> PushBufferState {
+        self.available_end = i + 1;
+        PushBufferState::Text
+    }
+}
+
+impl Read for PushBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.fill_buf()?.read(buf)?;
+        self.consume(read);
+        Ok(read)
+    }
+}
+
+impl BufRead for PushBuffer {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self.data[self.position..self.available_end])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount;
+    }
+}
+
 /// Iterator on the file prefixes.
 ///
 /// See [`ReaderRdfXmlParser::prefixes`].
@@ -788,7 +1203,7 @@ enum RdfXmlState {
         #[cfg(feature = "rdf-12")]
         base_direction: Option<BaseDirection>,
         subject: NamedOrBlankNode,
-        writer: Writer<Vec<u8>>,
+        writer: ExclusiveCanonicalXmlWriter,
         id_attr: Option<NamedNode>,
         #[cfg(feature = "rdf-12")]
         annotation_attr: Option<NamedNode>,
@@ -838,7 +1253,8 @@ impl<R> InternalRdfXmlParser<R> {
             .into()),
             Event::Text(event) => self.parse_text_event(&event),
             Event::CData(event) => self.parse_text_event(&event.escape()?),
-            Event::Comment(_) | Event::PI(_) => Ok(()),
+            Event::PI(event) => self.parse_pi_event(&event),
+            Event::Comment(_) => Ok(()),
             Event::Decl(decl) => {
                 if let Some(encoding) = decl.encoding() {
                     if !is_utf8(&encoding?) {
@@ -920,33 +1336,62 @@ impl<R> InternalRdfXmlParser<R> {
         }
 
         // Literal case
-        if let Some(RdfXmlState::ParseTypeLiteralPropertyElt { writer, .. }) = self.state.last_mut()
-        {
-            let mut clean_event = BytesStart::new(
-                self.reader
-                    .decoder()
-                    .decode(event.name().as_ref())?
-                    .to_string(),
-            );
-            for attr in event.attributes() {
-                clean_event.push_attribute(attr.map_err(Error::InvalidAttr)?);
-            }
-            if self.in_literal_depth == 0 {
-                for (prefix, namespace) in self.reader.prefixes() {
-                    match prefix {
-                        PrefixDeclaration::Default => {
-                            clean_event.push_attribute(("xmlns".as_bytes(), namespace.into_inner()))
-                        }
-                        PrefixDeclaration::Named(name) => {
-                            let mut attr = Vec::with_capacity(6 + name.len());
-                            attr.extend_from_slice(b"xmlns:");
-                            attr.extend_from_slice(name);
-                            clean_event.push_attribute((attr.as_slice(), namespace.into_inner()))
-                        }
+        if matches!(
+            self.state.last(),
+            Some(RdfXmlState::ParseTypeLiteralPropertyElt { .. })
+        ) {
+            let name = self
+                .reader
+                .decoder()
+                .decode(event.name().as_ref())?
+                .to_string();
+            let (namespace, _) = self.reader.resolve_element(event.name());
+            let mut namespaces = vec![(
+                event.name().prefix().map_or(Ok(String::new()), |prefix| {
+                    self.reader
+                        .decoder()
+                        .decode(prefix.as_ref())
+                        .map(Cow::into_owned)
+                })?,
+                self.literal_namespace(namespace)?,
+            )];
+            let mut attributes = Vec::new();
+            for attribute in event.attributes() {
+                let attribute = attribute.map_err(Error::InvalidAttr)?;
+                if attribute.key.as_namespace_binding().is_some() {
+                    continue; // Namespace declarations are only output if visibly utilized
+                }
+                let (namespace, local_name) = self.reader.resolve_attribute(attribute.key);
+                let namespace = self.literal_namespace(namespace)?;
+                if let Some(prefix) = attribute.key.prefix() {
+                    if namespace != "http://www.w3.org/XML/1998/namespace" {
+                        namespaces.push((
+                            self.reader.decoder().decode(prefix.as_ref())?.into_owned(),
+                            namespace.clone(),
+                        ));
                     }
                 }
+                attributes.push(CanonicalXmlAttribute {
+                    namespace,
+                    local_name: self
+                        .reader
+                        .decoder()
+                        .decode(local_name.as_ref())?
+                        .into_owned(),
+                    name: self
+                        .reader
+                        .decoder()
+                        .decode(attribute.key.as_ref())?
+                        .into_owned(),
+                    value: self.convert_attribute(&attribute)?.into_owned(),
+                });
             }
-            writer.write_event(Event::Start(clean_event))?;
+            let Some(RdfXmlState::ParseTypeLiteralPropertyElt { writer, .. }) =
+                self.state.last_mut()
+            else {
+                unreachable!()
+            };
+            writer.start_element(&name, namespaces, attributes);
             self.in_literal_depth += 1;
             return Ok(());
         }
@@ -1354,7 +1799,7 @@ impl<R> InternalRdfXmlParser<R> {
                         #[cfg(feature = "rdf-12")]
                         base_direction,
                         subject,
-                        writer: Writer::new(Vec::default()),
+                        writer: ExclusiveCanonicalXmlWriter::default(),
                         id_attr,
                         #[cfg(feature = "rdf-12")]
                         annotation_attr,
@@ -1419,7 +1864,7 @@ impl<R> InternalRdfXmlParser<R> {
                         #[cfg(feature = "rdf-12")]
                         base_direction,
                         subject,
-                        writer: Writer::new(Vec::default()),
+                        writer: ExclusiveCanonicalXmlWriter::default(),
                         id_attr,
                         #[cfg(feature = "rdf-12")]
                         annotation_attr,
@@ -1448,9 +1893,7 @@ impl<R> InternalRdfXmlParser<R> {
             else {
                 unreachable!()
             };
-            writer.write_event(Event::End(BytesEnd::new(
-                self.reader.decoder().decode(event.name().as_ref())?,
-            )))?;
+            writer.end_element(&self.reader.decoder().decode(event.name().as_ref())?);
             self.in_literal_depth -= 1;
             return Ok(());
         }
@@ -1480,7 +1923,7 @@ impl<R> InternalRdfXmlParser<R> {
                 }
             }
             Some(RdfXmlState::ParseTypeLiteralPropertyElt { writer, .. }) => {
-                writer.write_event(Event::Text(BytesText::new(&text)))?;
+                writer.text(&text);
                 Ok(())
             }
             _ => {
@@ -1493,6 +1936,31 @@ impl<R> InternalRdfXmlParser<R> {
         }
     }
 
+    fn parse_pi_event(&mut self, event: &BytesPI<'_>) -> Result<(), RdfXmlParseError> {
+        if let Some(RdfXmlState::ParseTypeLiteralPropertyElt { writer, .. }) = self.state.last_mut()
+        {
+            let decoder = self.reader.decoder();
+            writer.processing_instruction(
+                &decoder.decode(event.target())?,
+                decoder.decode(event.content())?.trim_start(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the namespace of a name inside of a XML literal, the empty string if there is none
+    fn literal_namespace(&self, namespace: ResolveResult<'_>) -> Result<String, RdfXmlParseError> {
+        match namespace {
+            ResolveResult::Bound(ns) => Ok(self.reader.decoder().decode(ns.as_ref())?.into_owned()),
+            ResolveResult::Unbound => Ok(String::new()),
+            ResolveResult::Unknown(v) => Err(RdfXmlSyntaxError::msg(format!(
+                "Unknown prefix {}:",
+                self.reader.decoder().decode(&v)?
+            ))
+            .into()),
+        }
+    }
+
     fn resolve_ns_name(
         &self,
         namespace: ResolveResult<'_>,
@@ -1723,23 +2191,10 @@ impl<R> InternalRdfXmlParser<R> {
                 ..
             } => {
                 if emit {
-                    let object = writer.into_inner();
-                    if object.is_empty() {
-                        return Err(RdfXmlSyntaxError::msg(format!(
-                            "No value found for rdf:XMLLiteral value of property {iri}"
-                        )));
-                    }
                     let triple = Triple::new(
                         subject,
                         iri,
-                        Literal::new_typed_literal(
-                            str::from_utf8(&object).map_err(|_| {
-                                RdfXmlSyntaxError::msg(
-                                    "The XML literal is not in valid UTF-8".to_owned(),
-                                )
-                            })?,
-                            rdf::XML_LITERAL,
-                        ),
+                        Literal::new_typed_literal(writer.finish(), rdf::XML_LITERAL),
                     );
                     self.reify_and_annotation(
                         &triple,
@@ -2076,6 +2531,123 @@ impl<R> InternalRdfXmlParser<R> {
     }
 }
 
+/// Serializes the content of `rdf:parseType="Literal"` elements following [Exclusive XML Canonicalization](https://www.w3.org/TR/xml-exc-c14n/).
+///
+/// Comments are omitted.
+#[derive(Default)]
+struct ExclusiveCanonicalXmlWriter {
+    buffer: String,
+    /// The namespace declarations rendered on each currently open element
+    rendered_namespaces: Vec<Vec<(String, String)>>,
+}
+
+struct CanonicalXmlAttribute {
+    namespace: String,
+    local_name: String,
+    name: String,
+    value: String,
+}
+
+impl ExclusiveCanonicalXmlWriter {
+    /// Writes an element start tag
+    ///
+    /// `namespaces` are the (prefix, namespace) pairs visibly utilized by the element and its attributes.
+    fn start_element(
+        &mut self,
+        name: &str,
+        mut namespaces: Vec<(String, String)>,
+        mut attributes: Vec<CanonicalXmlAttribute>,
+    ) {
+        namespaces.retain(|(prefix, namespace)| {
+            prefix != "xml" && self.rendered_namespace(prefix) != Some(namespace)
+        });
+        namespaces.sort();
+        namespaces.dedup();
+        attributes
+            .sort_by(|a, b| (&a.namespace, &a.local_name).cmp(&(&b.namespace, &b.local_name)));
+        self.buffer.push('<');
+        self.buffer.push_str(name);
+        for (prefix, namespace) in &namespaces {
+            if prefix.is_empty() {
+                self.buffer.push_str(" xmlns=\"");
+            } else {
+                self.buffer.push_str(" xmlns:");
+                self.buffer.push_str(prefix);
+                self.buffer.push_str("=\"");
+            }
+            escape_attribute_value(namespace, &mut self.buffer);
+            self.buffer.push('"');
+        }
+        for attribute in &attributes {
+            self.buffer.push(' ');
+            self.buffer.push_str(&attribute.name);
+            self.buffer.push_str("=\"");
+            escape_attribute_value(&attribute.value, &mut self.buffer);
+            self.buffer.push('"');
+        }
+        self.buffer.push('>');
+        self.rendered_namespaces.push(namespaces);
+    }
+
+    fn end_element(&mut self, name: &str) {
+        self.rendered_namespaces.pop();
+        self.buffer.push_str("</");
+        self.buffer.push_str(name);
+        self.buffer.push('>');
+    }
+
+    fn text(&mut self, text: &str) {
+        for c in text.chars() {
+            match c {
+                '&' => self.buffer.push_str("&amp;"),
+                '<' => self.buffer.push_str("&lt;"),
+                '>' => self.buffer.push_str("&gt;"),
+                '\r' => self.buffer.push_str("&#xD;"),
+                _ => self.buffer.push(c),
+            }
+        }
+    }
+
+    fn processing_instruction(&mut self, target: &str, content: &str) {
+        self.buffer.push_str("<?");
+        self.buffer.push_str(target);
+        if !content.is_empty() {
+            self.buffer.push(' ');
+            self.buffer.push_str(content);
+        }
+        self.buffer.push_str("?>");
+    }
+
+    fn finish(self) -> String {
+        self.buffer
+    }
+
+    /// The namespace bound to the prefix by the closest output ancestor
+    fn rendered_namespace(&self, prefix: &str) -> Option<&str> {
+        self.rendered_namespaces
+            .iter()
+            .rev()
+            .flatten()
+            .find(|(p, _)| p == prefix)
+            .map(|(_, namespace)| namespace.as_str())
+            .or_else(|| prefix.is_empty().then_some(""))
+    }
+}
+
+fn escape_attribute_value(value: &str, output: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '"' => output.push_str("&quot;"),
+            '\t' => output.push_str("&#x9;"),
+            '\n' => output.push_str("&#xA;"),
+            '\r' => output.push_str("&#xD;"),
+            _ => output.push(c),
+        }
+    }
+}
+
 fn is_object_defined(object: &Option<NodeOrText>) -> bool {
     match object {
         Some(NodeOrText::Node(_)) => true,
@@ -2129,3 +2701,147 @@ impl RdfVersion {
         }
     }
 }
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+    use std::fmt::Write;
+
+    fn parse_xml_literal(content: &str) -> Result<Literal, Box<dyn Error>> {
+        let file = format!(
+            "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:ex=\"http://example.com/\" xmlns:foo=\"http://example.com/foo\"><rdf:Description rdf:about=\"http://example.com/s\"><ex:p rdf:parseType=\"Literal\">{content}</ex:p></rdf:Description></rdf:RDF>"
+        );
+        let triples = RdfXmlParser::new()
+            .for_slice(&file)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(triples.len(), 1);
+        let Term::Literal(literal) = triples.into_iter().next().unwrap().object else {
+            return Err("The object should be a literal".into());
+        };
+        assert_eq!(literal.datatype(), rdf::XML_LITERAL);
+        Ok(literal)
+    }
+
+    #[test]
+    fn test_xml_literal_exclusive_canonicalization() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            parse_xml_literal(
+                "<ex:a  foo:b='1\"&amp;' a=\"2\" xmlns:bar=\"http://example.com/bar\"><!-- comment --><b xmlns=\"http://example.com/b\"><c/></b><?pi  data ?>x &gt; y</ex:a>"
+            )?
+            .value(),
+            "<ex:a xmlns:ex=\"http://example.com/\" xmlns:foo=\"http://example.com/foo\" a=\"2\" foo:b=\"1&quot;&amp;\"><b xmlns=\"http://example.com/b\"><c></c></b><?pi data ?>x &gt; y</ex:a>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_xml_literal_default_namespace_undeclaration() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            parse_xml_literal("<a xmlns=\"http://example.com/a\"><b xmlns=\"\"><c/></b></a>")?
+                .value(),
+            "<a xmlns=\"http://example.com/a\"><b xmlns=\"\"><c></c></b></a>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_xml_literal() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse_xml_literal("")?.value(), "");
+        Ok(())
+    }
+
+    const LOW_LEVEL_FILE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<!DOCTYPE rdf:RDF [
+  <!ENTITY ex "http://example.com/">
+  <!ENTITY gt "&#62;">
+]>
+<?pi data > with ? ?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:ex="&ex;">
+  <!-- comment with > and - -->
+  <rdf:Description rdf:about="&ex;s" ex:a='"a>b"'>
+    <ex:b><![CDATA[x]>y]]></ex:b>
+    <ex:c rdf:resource="&ex;o"/>
+    <ex:d rdf:parseType="Literal"><ex:e a=">">f</ex:e><ex:g/></ex:d>
+    <ex:h><rdf:Description rdf:nodeID="m"><ex:i>&gt;</ex:i></rdf:Description></ex:h>
+  </rdf:Description>
+  <ex:T rdf:nodeID="n" />
+</rdf:RDF>
+"#;
+
+    fn parse_low_level<'a>(
+        chunks: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<Vec<Triple>, RdfXmlSyntaxError> {
+        let mut parser = RdfXmlParser::new().low_level();
+        let mut chunks = chunks.into_iter();
+        let mut triples = Vec::new();
+        while !parser.is_end() {
+            if let Some(chunk) = chunks.next() {
+                parser.extend_from_slice(chunk);
+            } else {
+                parser.end();
+            }
+            while let Some(triple) = parser.parse_next() {
+                triples.push(triple?);
+            }
+        }
+        Ok(triples)
+    }
+
+    #[test]
+    fn test_low_level_chunks() -> Result<(), Box<dyn Error>> {
+        let expected = RdfXmlParser::new()
+            .for_slice(LOW_LEVEL_FILE)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(expected.len(), 7);
+        let file = LOW_LEVEL_FILE.as_bytes();
+        for i in 0..=file.len() {
+            let (start, end) = file.split_at(i);
+            assert_eq!(parse_low_level([start, end])?, expected, "split at {i}");
+        }
+        assert_eq!(parse_low_level(file.chunks(1))?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_low_level_bounded_buffer() -> Result<(), Box<dyn Error>> {
+        let mut file = String::from(
+            "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:ex=\"http://example.com/\">",
+        );
+        for i in 0..10_000 {
+            write!(
+                file,
+                "<rdf:Description rdf:about=\"http://example.com/{i}\"><ex:p>{i}</ex:p></rdf:Description>"
+            )?;
+        }
+        file.push_str("</rdf:RDF>");
+        let mut parser = RdfXmlParser::new().low_level();
+        let mut count = 0;
+        for chunk in file.as_bytes().chunks(100) {
+            parser.extend_from_slice(chunk);
+            while let Some(triple) = parser.parse_next() {
+                triple?;
+                count += 1;
+            }
+            assert!(parser.parser.reader.get_ref().data.len() < 500);
+        }
+        parser.end();
+        assert!(parser.parse_next().is_none());
+        assert!(parser.is_end());
+        assert_eq!(count, 10_000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_low_level_truncated() {
+        let mut parser = RdfXmlParser::new().low_level();
+        parser.extend_from_slice(
+            b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"><rdf:Description",
+        );
+        assert!(parser.parse_next().is_none());
+        assert!(!parser.is_end());
+        parser.end();
+        assert!(matches!(parser.parse_next(), Some(Err(_))));
+    }
+}
@@ -3,11 +3,15 @@ use oxiri::{Iri, IriParseError};
 #[cfg(feature = "rdf-12")]
 use oxrdf::BaseDirection;
 use oxrdf::vocab::{rdf, xsd};
-use oxrdf::{NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, TermRef, TripleRef};
-use quick_xml::Writer;
+use oxrdf::{
+    BlankNodeRef, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, TermRef, Triple, TripleRef,
+};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::name::ResolveResult;
+use quick_xml::{NsReader, Writer};
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io;
 use std::io::Write;
 #[cfg(feature = "async-tokio")]
@@ -44,6 +48,7 @@ use tokio::io::AsyncWrite;
 pub struct RdfXmlSerializer {
     prefixes: BTreeMap<String, String>,
     base_iri: Option<Iri<String>>,
+    pretty: bool,
 }
 
 impl RdfXmlSerializer {
@@ -53,6 +58,7 @@ impl RdfXmlSerializer {
         Self {
             prefixes: BTreeMap::new(),
             base_iri: None,
+            pretty: false,
         }
     }
 
@@ -101,6 +107,51 @@ impl RdfXmlSerializer {
         Ok(self)
     }
 
+    /// Writes a more readable "striped" RDF/XML.
+    ///
+    /// All the triples are buffered in memory until `finish` is called. Then each subject is written in a single node element:
+    /// * named after one of its `rdf:type`s if this type can be written as an XML qualified name, `rdf:Description` is used else,
+    /// * blank nodes used exactly once as object and not part of a cycle are nested inside of the property element referring to them.
+    ///
+    /// ```
+    /// use oxrdf::vocab::rdf;
+    /// use oxrdf::{BlankNode, LiteralRef, NamedNodeRef, TripleRef};
+    /// use oxrdfxml::RdfXmlSerializer;
+    ///
+    /// let bob = BlankNode::default();
+    /// let person = NamedNodeRef::new("http://xmlns.com/foaf/0.1/Person")?;
+    /// let mut serializer = RdfXmlSerializer::new()
+    ///     .with_prefix("foaf", "http://xmlns.com/foaf/0.1/")?
+    ///     .pretty()
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_triple(TripleRef::new(
+    ///     NamedNodeRef::new("http://example.com/alice")?,
+    ///     NamedNodeRef::new("http://xmlns.com/foaf/0.1/knows")?,
+    ///     &bob,
+    /// ))?;
+    /// serializer.serialize_triple(TripleRef::new(
+    ///     NamedNodeRef::new("http://example.com/alice")?,
+    ///     rdf::TYPE,
+    ///     person,
+    /// ))?;
+    /// serializer.serialize_triple(TripleRef::new(&bob, rdf::TYPE, person))?;
+    /// serializer.serialize_triple(TripleRef::new(
+    ///     &bob,
+    ///     NamedNodeRef::new("http://xmlns.com/foaf/0.1/name")?,
+    ///     LiteralRef::new_simple_literal("Bob"),
+    /// ))?;
+    /// assert_eq!(
+    ///     b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:its=\"http://www.w3.org/2005/11/its\" xmlns:foaf=\"http://xmlns.com/foaf/0.1/\">\n\t<foaf:Person rdf:about=\"http://example.com/alice\">\n\t\t<foaf:knows>\n\t\t\t<foaf:Person>\n\t\t\t\t<foaf:name>Bob</foaf:name>\n\t\t\t</foaf:Person>\n\t\t</foaf:knows>\n\t</foaf:Person>\n</rdf:RDF>",
+    ///     serializer.finish()?.as_slice()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn pretty(mut self) -> Self {
+        self.pretty = true;
+        self
+    }
+
     /// Serializes a RDF/XML file to a [`Write`] implementation.
     ///
     /// This writer does unbuffered writes.
@@ -193,9 +244,11 @@ impl RdfXmlSerializer {
         InnerRdfXmlWriter {
             current_subject: None,
             current_resource_tag: None,
+            current_resource_has_default_namespace: false,
             custom_default_prefix,
             prefixes_by_iri: prefixes,
             base_iri: self.base_iri,
+            buffered_triples: self.pretty.then(Vec::new),
         }
     }
 }
@@ -243,7 +296,7 @@ impl<W: Write> WriterRdfXmlSerializer<W> {
     /// Ends the write process and returns the underlying [`Write`].
     pub fn finish(mut self) -> io::Result<W> {
         let mut buffer = Vec::new();
-        self.inner.finish(&mut buffer);
+        self.inner.finish(&mut buffer)?;
         self.flush_buffer(&mut buffer)?;
         Ok(self.writer.into_inner())
     }
@@ -304,7 +357,7 @@ impl<W: AsyncWrite + Unpin> TokioAsyncWriterRdfXmlSerializer<W> {
     /// Ends the write process and returns the underlying [`Write`].
     pub async fn finish(mut self) -> io::Result<W> {
         let mut buffer = Vec::new();
-        self.inner.finish(&mut buffer);
+        self.inner.finish(&mut buffer)?;
         self.flush_buffer(&mut buffer).await?;
         Ok(self.writer.into_inner())
     }
@@ -335,9 +388,11 @@ const RESERVED_SYNTAX_TERMS: [&str; 9] = [
 pub struct InnerRdfXmlWriter {
     current_subject: Option<NamedOrBlankNode>,
     current_resource_tag: Option<String>,
+    current_resource_has_default_namespace: bool,
     custom_default_prefix: bool,
     prefixes_by_iri: BTreeMap<String, String>,
     base_iri: Option<Iri<String>>,
+    buffered_triples: Option<Vec<Triple>>,
 }
 
 impl InnerRdfXmlWriter {
@@ -346,11 +401,18 @@ impl InnerRdfXmlWriter {
         t: impl Into<TripleRef<'a>>,
         output: &mut Vec<Event<'a>>,
     ) -> io::Result<()> {
+        let triple = t.into();
+        if let Some(buffered_triples) = &mut self.buffered_triples {
+            // We fail early on invalid predicates
+            validate_predicate(triple)?;
+            buffered_triples.push(triple.into_owned());
+            return Ok(());
+        }
+
         if self.current_subject.is_none() {
             self.write_start(output);
         }
 
-        let triple = t.into();
         // We open a new rdf:Description if useful
         if self.current_subject.as_ref().map(NamedOrBlankNode::as_ref) != Some(triple.subject) {
             if self.current_subject.is_some() {
@@ -362,57 +424,46 @@ impl InnerRdfXmlWriter {
             }
             self.current_subject = Some(triple.subject.into_owned());
 
-            let (mut description_start, with_type_tag) = if triple.predicate == rdf::TYPE {
+            let type_element = if triple.predicate == rdf::TYPE {
                 if let TermRef::NamedNode(t) = triple.object {
-                    if RESERVED_SYNTAX_TERMS.contains(&t.as_str()) {
-                        (BytesStart::new("rdf:Description"), false)
-                    } else {
-                        let (prop_qname, prop_xmlns) = self.uri_to_qname_and_xmlns(t);
-                        let mut description_start = BytesStart::new(prop_qname.clone());
-                        if let Some(prop_xmlns) = prop_xmlns {
-                            description_start.push_attribute(prop_xmlns);
-                        }
-                        self.current_resource_tag = Some(prop_qname.into_owned());
-                        (description_start, true)
-                    }
+                    self.type_to_qname_and_xmlns(t)
                 } else {
-                    (BytesStart::new("rdf:Description"), false)
+                    None
                 }
             } else {
-                (BytesStart::new("rdf:Description"), false)
+                None
             };
-            description_start.push_attribute(match triple.subject {
-                NamedOrBlankNodeRef::NamedNode(node) => {
-                    ("rdf:about", relative_iri(node.as_str(), &self.base_iri))
-                }
-                NamedOrBlankNodeRef::BlankNode(node) => ("rdf:nodeID", node.as_str().into()),
-            });
+            let with_type_tag = type_element.is_some();
+            let (mut description_start, has_default_namespace) =
+                node_element_start(type_element, self.custom_default_prefix);
+            if with_type_tag {
+                self.current_resource_tag =
+                    Some(String::from_utf8_lossy(description_start.name().as_ref()).into_owned());
+            }
+            self.current_resource_has_default_namespace = has_default_namespace;
+            description_start.push_attribute(self.subject_attribute(triple.subject));
             output.push(Event::Start(description_start));
             if with_type_tag {
                 return Ok(()); // No need for a value
             }
         }
-        self.write_predicate_object(triple.predicate, triple.object, output)
+        self.write_predicate_object(
+            triple.predicate,
+            triple.object,
+            self.current_resource_has_default_namespace,
+            output,
+        )
     }
 
     fn write_predicate_object<'a>(
-        &mut self,
+        &self,
         predicate: NamedNodeRef<'a>,
         object: TermRef<'a>,
+        parent_has_default_namespace: bool,
         output: &mut Vec<Event<'a>>,
     ) -> io::Result<()> {
-        if RESERVED_SYNTAX_TERMS.contains(&predicate.as_str()) {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "RDF/XML reserved syntax term is not allowed as a predicate",
-            ));
-        }
-
-        let (prop_qname, prop_xmlns) = self.uri_to_qname_and_xmlns(predicate);
-        let mut property_open = BytesStart::new(prop_qname.clone());
-        if let Some(prop_xmlns) = prop_xmlns {
-            property_open.push_attribute(prop_xmlns);
-        }
+        let (prop_qname, mut property_open, has_default_namespace) =
+            self.property_element_start(predicate, parent_has_default_namespace)?;
         #[allow(
             unreachable_patterns,
             clippy::match_wildcard_for_single_variants,
@@ -428,6 +479,15 @@ impl InnerRdfXmlWriter {
                 property_open.push_attribute(("rdf:nodeID", node.as_str()));
                 output.push(Event::Empty(property_open));
             }
+            TermRef::Literal(literal)
+                if literal.datatype() == rdf::XML_LITERAL
+                    && is_xml_literal_content(literal.value(), has_default_namespace) =>
+            {
+                property_open.push_attribute(("rdf:parseType", "Literal"));
+                output.push(Event::Start(property_open));
+                output.push(Event::Text(BytesText::from_escaped(literal.value())));
+                output.push(Event::End(BytesEnd::new(prop_qname)));
+            }
             TermRef::Literal(literal) => {
                 if let Some(language) = literal.language() {
                     property_open.push_attribute(("xml:lang", language));
@@ -459,16 +519,12 @@ impl InnerRdfXmlWriter {
                 property_open.push_attribute(("rdf:parseType", "Triple"));
                 output.push(Event::Start(property_open));
                 let mut subject_start = BytesStart::new("rdf:Description");
-                subject_start.push_attribute(match triple.subject.as_ref() {
-                    NamedOrBlankNodeRef::NamedNode(node) => {
-                        ("rdf:about", relative_iri(node.as_str(), &self.base_iri))
-                    }
-                    NamedOrBlankNodeRef::BlankNode(node) => ("rdf:nodeID", node.as_str().into()),
-                });
+                subject_start.push_attribute(self.subject_attribute(triple.subject.as_ref()));
                 output.push(Event::Start(subject_start));
                 self.write_predicate_object(
                     triple.predicate.as_ref(),
                     triple.object.as_ref(),
+                    has_default_namespace,
                     output,
                 )?;
                 output.push(Event::End(BytesEnd::new("rdf:Description")));
@@ -498,8 +554,13 @@ impl InnerRdfXmlWriter {
         output.push(Event::Start(rdf_open))
     }
 
-    fn finish(&mut self, output: &mut Vec<Event<'static>>) {
-        if self.current_subject.is_some() {
+    fn finish(&mut self, output: &mut Vec<Event<'static>>) -> io::Result<()> {
+        if let Some(triples) = self.buffered_triples.take() {
+            self.write_start(output);
+            let mut events = Vec::new();
+            self.write_pretty(&triples, &mut events)?;
+            output.extend(events.into_iter().map(Event::into_owned));
+        } else if self.current_subject.is_some() {
             output.push(Event::End(
                 self.current_resource_tag
                     .take()
@@ -509,6 +570,162 @@ impl InnerRdfXmlWriter {
             self.write_start(output);
         }
         output.push(Event::End(BytesEnd::new("rdf:RDF")));
+        Ok(())
+    }
+
+    fn write_pretty<'a>(
+        &self,
+        triples: &'a [Triple],
+        output: &mut Vec<Event<'a>>,
+    ) -> io::Result<()> {
+        let layout = PrettyLayout::new(triples);
+        for subject in &layout.subjects {
+            if let NamedOrBlankNodeRef::BlankNode(subject) = subject {
+                if layout.nested.contains(subject) {
+                    continue; // Written inside of its parent
+                }
+            }
+            // We use an explicit stack to not overflow on long nested chains like RDF lists
+            let mut stack = Vec::new();
+            stack.extend(self.open_pretty_node_element(
+                *subject,
+                false,
+                self.custom_default_prefix,
+                None,
+                &layout,
+                output,
+            ));
+            while let Some(element) = stack.last_mut() {
+                let has_default_namespace = element.has_default_namespace;
+                if let Some((predicate, object)) = element.next_property() {
+                    if let TermRef::BlankNode(object) = object {
+                        if layout.nested.contains(&object) {
+                            let (prop_qname, property_open, has_default_namespace) =
+                                self.property_element_start(predicate, has_default_namespace)?;
+                            output.push(Event::Start(property_open));
+                            stack.extend(self.open_pretty_node_element(
+                                object.into(),
+                                true,
+                                has_default_namespace,
+                                Some(prop_qname),
+                                &layout,
+                                output,
+                            ));
+                            continue;
+                        }
+                    }
+                    self.write_predicate_object(predicate, object, has_default_namespace, output)?;
+                } else if let Some(element) = stack.pop() {
+                    output.push(Event::End(BytesEnd::new(element.tag)));
+                    if let Some(property_tag) = element.property_tag {
+                        output.push(Event::End(BytesEnd::new(property_tag)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens the node element of a subject in pretty mode
+    ///
+    /// Returns `None` if the element has already been closed because it has no property to write.
+    fn open_pretty_node_element<'a, 'b>(
+        &self,
+        node: NamedOrBlankNodeRef<'a>,
+        is_nested: bool,
+        parent_has_default_namespace: bool,
+        property_tag: Option<Cow<'a, str>>,
+        layout: &'b PrettyLayout<'a>,
+        output: &mut Vec<Event<'a>>,
+    ) -> Option<PrettyNodeElement<'a, 'b>> {
+        let properties = layout
+            .descriptions
+            .get(&node)
+            .map_or(&[][..], Vec::as_slice);
+        let type_element = properties
+            .iter()
+            .enumerate()
+            .find_map(|(i, (predicate, object))| {
+                if *predicate != rdf::TYPE {
+                    return None;
+                }
+                let TermRef::NamedNode(t) = object else {
+                    return None;
+                };
+                Some((i, self.type_to_qname_and_xmlns(*t)?))
+            });
+        let (type_position, type_element) = type_element.unzip();
+        let (mut node_open, has_default_namespace) =
+            node_element_start(type_element, parent_has_default_namespace);
+        if !is_nested {
+            node_open.push_attribute(self.subject_attribute(node));
+        }
+        let tag = String::from_utf8_lossy(node_open.name().as_ref()).into_owned();
+        let element = PrettyNodeElement {
+            tag,
+            property_tag,
+            properties,
+            position: 0,
+            type_position,
+            has_default_namespace,
+        };
+        if properties.len() == usize::from(type_position.is_some()) {
+            output.push(Event::Empty(node_open));
+            if let Some(property_tag) = element.property_tag {
+                output.push(Event::End(BytesEnd::new(property_tag)));
+            }
+            return None;
+        }
+        output.push(Event::Start(node_open));
+        Some(element)
+    }
+
+    /// Builds a property element start tag
+    ///
+    /// Also returns the element qualified name and if a default namespace is in scope inside of the element.
+    fn property_element_start<'a>(
+        &self,
+        predicate: NamedNodeRef<'a>,
+        parent_has_default_namespace: bool,
+    ) -> io::Result<(Cow<'a, str>, BytesStart<'a>, bool)> {
+        if RESERVED_SYNTAX_TERMS.contains(&predicate.as_str()) {
+            return Err(reserved_predicate_error());
+        }
+        let (prop_qname, prop_xmlns) = self.uri_to_qname_and_xmlns(predicate);
+        let mut property_open = BytesStart::new(prop_qname.clone());
+        let has_default_namespace =
+            parent_has_default_namespace || prop_xmlns.is_some_and(|(key, _)| key == "xmlns");
+        if let Some(prop_xmlns) = prop_xmlns {
+            property_open.push_attribute(prop_xmlns);
+        }
+        Ok((prop_qname, property_open, has_default_namespace))
+    }
+
+    fn subject_attribute<'a>(
+        &self,
+        subject: NamedOrBlankNodeRef<'a>,
+    ) -> (&'static str, Cow<'a, str>) {
+        match subject {
+            NamedOrBlankNodeRef::NamedNode(node) => {
+                ("rdf:about", relative_iri(node.as_str(), &self.base_iri))
+            }
+            NamedOrBlankNodeRef::BlankNode(node) => ("rdf:nodeID", node.as_str().into()),
+        }
+    }
+
+    /// Returns the qualified name to use for a typed node element if the type IRI can be written as one
+    fn type_to_qname_and_xmlns<'a>(
+        &self,
+        t: NamedNodeRef<'a>,
+    ) -> Option<(Cow<'a, str>, Option<(&'a str, &'a str)>)> {
+        if RESERVED_SYNTAX_TERMS.contains(&t.as_str()) {
+            return None;
+        }
+        let (prefix, local_name) = split_iri(t.as_str());
+        if local_name.is_empty() || prefix == "http://www.w3.org/2000/xmlns/" {
+            return None;
+        }
+        Some(self.uri_to_qname_and_xmlns(t))
     }
 
     fn uri_to_qname_and_xmlns<'a>(
@@ -539,6 +756,175 @@ impl InnerRdfXmlWriter {
     }
 }
 
+/// The structure of the "striped" RDF/XML written in pretty mode
+struct PrettyLayout<'a> {
+    /// The subjects in the order of their first appearance
+    subjects: Vec<NamedOrBlankNodeRef<'a>>,
+    descriptions: HashMap<NamedOrBlankNodeRef<'a>, Vec<(NamedNodeRef<'a>, TermRef<'a>)>>,
+    /// The blank nodes written inside of the property element referring to them
+    nested: HashSet<BlankNodeRef<'a>>,
+}
+
+impl<'a> PrettyLayout<'a> {
+    fn new(triples: &'a [Triple]) -> Self {
+        let mut subjects = Vec::new();
+        let mut descriptions = HashMap::<_, Vec<_>>::new();
+        // The subject using each blank node object if it is its only use
+        let mut parents = HashMap::<BlankNodeRef<'a>, Option<NamedOrBlankNodeRef<'a>>>::new();
+        for triple in triples {
+            let triple = triple.as_ref();
+            descriptions
+                .entry(triple.subject)
+                .or_insert_with(|| {
+                    subjects.push(triple.subject);
+                    Vec::new()
+                })
+                .push((triple.predicate, triple.object));
+            match triple.object {
+                TermRef::BlankNode(object) => match parents.entry(object) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(Some(triple.subject));
+                    }
+                    Entry::Occupied(mut entry) => {
+                        entry.insert(None);
+                    }
+                },
+                #[cfg(feature = "rdf-12")]
+                TermRef::Triple(triple) => {
+                    add_triple_term_blank_nodes(triple.as_ref(), &mut parents)
+                }
+                _ => (),
+            }
+        }
+        let parents = parents
+            .into_iter()
+            .filter_map(|(node, parent)| Some((node, parent?)))
+            .collect::<HashMap<_, _>>();
+
+        // We follow the chains of parents to find the cycles, their blank nodes must keep their identifiers
+        let mut nested = HashSet::new();
+        let mut in_cycle = HashSet::new();
+        for &start in parents.keys() {
+            if nested.contains(&start) || in_cycle.contains(&start) {
+                continue;
+            }
+            let mut path = vec![start];
+            let mut path_positions = HashMap::from([(start, 0)]);
+            let mut current = start;
+            loop {
+                let NamedOrBlankNodeRef::BlankNode(parent) = parents[&current] else {
+                    nested.extend(path);
+                    break;
+                };
+                if !parents.contains_key(&parent)
+                    || nested.contains(&parent)
+                    || in_cycle.contains(&parent)
+                {
+                    // The parent is written somewhere else
+                    nested.extend(path);
+                    break;
+                }
+                if let Some(position) = path_positions.get(&parent) {
+                    in_cycle.extend(path.drain(*position..));
+                    nested.extend(path);
+                    break;
+                }
+                path_positions.insert(parent, path.len());
+                path.push(parent);
+                current = parent;
+            }
+        }
+        Self {
+            subjects,
+            descriptions,
+            nested,
+        }
+    }
+}
+
+/// Blank nodes in triple terms must keep their identifiers
+#[cfg(feature = "rdf-12")]
+fn add_triple_term_blank_nodes<'a>(
+    triple: TripleRef<'a>,
+    parents: &mut HashMap<BlankNodeRef<'a>, Option<NamedOrBlankNodeRef<'a>>>,
+) {
+    if let NamedOrBlankNodeRef::BlankNode(subject) = triple.subject {
+        parents.insert(subject, None);
+    }
+    match triple.object {
+        TermRef::BlankNode(object) => {
+            parents.insert(object, None);
+        }
+        TermRef::Triple(triple) => add_triple_term_blank_nodes(triple.as_ref(), parents),
+        TermRef::NamedNode(_) | TermRef::Literal(_) => (),
+    }
+}
+
+/// A node element opened in pretty mode
+struct PrettyNodeElement<'a, 'b> {
+    tag: String,
+    /// The enclosing property element of a nested node element
+    property_tag: Option<Cow<'a, str>>,
+    properties: &'b [(NamedNodeRef<'a>, TermRef<'a>)],
+    position: usize,
+    /// The position of the type used as element name
+    type_position: Option<usize>,
+    has_default_namespace: bool,
+}
+
+impl<'a> PrettyNodeElement<'a, '_> {
+    fn next_property(&mut self) -> Option<(NamedNodeRef<'a>, TermRef<'a>)> {
+        loop {
+            let position = self.position;
+            let property = *self.properties.get(position)?;
+            self.position += 1;
+            if Some(position) != self.type_position {
+                return Some(property);
+            }
+        }
+    }
+}
+
+/// Builds a node element start tag, typed if a type qualified name is given
+///
+/// Also returns if a default namespace is in scope inside of the element.
+fn node_element_start<'a>(
+    type_element: Option<(Cow<'a, str>, Option<(&'a str, &'a str)>)>,
+    parent_has_default_namespace: bool,
+) -> (BytesStart<'a>, bool) {
+    let Some((type_qname, type_xmlns)) = type_element else {
+        return (
+            BytesStart::new("rdf:Description"),
+            parent_has_default_namespace,
+        );
+    };
+    let has_default_namespace =
+        parent_has_default_namespace || type_xmlns.is_some_and(|(key, _)| key == "xmlns");
+    let mut node_open = BytesStart::new(type_qname);
+    if let Some(type_xmlns) = type_xmlns {
+        node_open.push_attribute(type_xmlns);
+    }
+    (node_open, has_default_namespace)
+}
+
+fn validate_predicate(triple: TripleRef<'_>) -> io::Result<()> {
+    if RESERVED_SYNTAX_TERMS.contains(&triple.predicate.as_str()) {
+        return Err(reserved_predicate_error());
+    }
+    #[cfg(feature = "rdf-12")]
+    if let TermRef::Triple(triple) = triple.object {
+        validate_predicate(triple.as_ref())?;
+    }
+    Ok(())
+}
+
+fn reserved_predicate_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "RDF/XML reserved syntax term is not allowed as a predicate",
+    )
+}
+
 #[cfg(feature = "async-tokio")]
 fn map_err(error: quick_xml::Error) -> io::Error {
     if let quick_xml::Error::Io(error) = error {
@@ -548,6 +934,68 @@ fn map_err(error: quick_xml::Error) -> io::Error {
     }
 }
 
+/// Checks that the value is a well-formed XML fragment that can be written as is inside of a `rdf:parseType="Literal"` element
+///
+/// All the used prefixes must be declared inside of the fragment.
+/// If a default namespace is declared in the output document, unprefixed element names must also declare their namespace.
+fn is_xml_literal_content(value: &str, has_default_namespace: bool) -> bool {
+    let mut reader = NsReader::from_str(value);
+    let mut depth = 0_usize;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => {
+                if !is_xml_literal_element_start(&reader, &start, has_default_namespace) {
+                    return false;
+                }
+                depth += 1;
+            }
+            Ok(Event::Empty(start)) => {
+                if !is_xml_literal_element_start(&reader, &start, has_default_namespace) {
+                    return false;
+                }
+            }
+            Ok(Event::End(_)) => {
+                let Some(new_depth) = depth.checked_sub(1) else {
+                    return false;
+                };
+                depth = new_depth;
+            }
+            Ok(Event::Text(text)) => {
+                if text.unescape().is_err() {
+                    return false;
+                }
+            }
+            Ok(Event::CData(_) | Event::Comment(_) | Event::PI(_)) => (),
+            Ok(Event::Decl(_) | Event::DocType(_)) | Err(_) => return false,
+            Ok(Event::Eof) => return depth == 0,
+        }
+    }
+}
+
+fn is_xml_literal_element_start(
+    reader: &NsReader<&[u8]>,
+    start: &BytesStart<'_>,
+    has_default_namespace: bool,
+) -> bool {
+    match reader.resolve_element(start.name()).0 {
+        ResolveResult::Bound(_) => (),
+        ResolveResult::Unbound => {
+            if has_default_namespace {
+                return false;
+            }
+        }
+        ResolveResult::Unknown(_) => return false,
+    }
+    start.attributes().all(|attribute| {
+        attribute.is_ok_and(|attribute| {
+            !matches!(
+                reader.resolve_attribute(attribute.key).0,
+                ResolveResult::Unknown(_)
+            )
+        })
+    })
+}
+
 fn split_iri(iri: &str) -> (&str, &str) {
     if let Some(position_base) = iri.rfind(|c| !is_name_char(c) || c == ':') {
         if let Some(position_add) = iri[position_base..].find(|c| is_name_start_char(c) && c != ':')
@@ -577,6 +1025,9 @@ fn relative_iri<'a>(iri: &'a str, base_iri: &Option<Iri<String>>) -> Cow<'a, str
 #[expect(clippy::panic_in_result_fn)]
 mod tests {
     use super::*;
+    use crate::RdfXmlParser;
+    use oxrdf::dataset::CanonicalizationAlgorithm;
+    use oxrdf::{BlankNode, Graph, LiteralRef};
     use std::error::Error;
 
     #[test]
@@ -625,4 +1076,180 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_xml_literal() -> Result<(), Box<dyn Error>> {
+        let mut serializer = RdfXmlSerializer::new()
+            .with_prefix("ex", "http://example.com/")?
+            .for_writer(Vec::new());
+        serializer.serialize_triple(TripleRef::new(
+            NamedNodeRef::new("http://example.com/s")?,
+            NamedNodeRef::new("http://example.com/p")?,
+            LiteralRef::new_typed_literal(
+                "<ex:a xmlns:ex=\"http://example.com/\">b &amp; c</ex:a>",
+                rdf::XML_LITERAL,
+            ),
+        ))?;
+        serializer.serialize_triple(TripleRef::new(
+            NamedNodeRef::new("http://example.com/s")?,
+            NamedNodeRef::new("http://example.com/p")?,
+            LiteralRef::new_typed_literal("<a>", rdf::XML_LITERAL),
+        ))?;
+        let output = serializer.finish()?;
+        assert_eq!(
+            String::from_utf8_lossy(&output),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rdf:RDF xmlns:ex=\"http://example.com/\" xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:its=\"http://www.w3.org/2005/11/its\">\n\t<rdf:Description rdf:about=\"http://example.com/s\">\n\t\t<ex:p rdf:parseType=\"Literal\"><ex:a xmlns:ex=\"http://example.com/\">b &amp; c</ex:a></ex:p>\n\t\t<ex:p rdf:datatype=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral\">&lt;a&gt;</ex:p>\n\t</rdf:Description>\n</rdf:RDF>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_xml_literal_unbound_default_namespace() {
+        assert!(is_xml_literal_content("<a>b</a> c", false));
+        assert!(!is_xml_literal_content("<a>b</a> c", true));
+        assert!(is_xml_literal_content(
+            "<a xmlns=\"http://example.com/\">b</a>",
+            true
+        ));
+        assert!(!is_xml_literal_content("<ex:a>b</ex:a>", false));
+        assert!(!is_xml_literal_content("<a>b</c>", false));
+        assert!(!is_xml_literal_content("</a>", false));
+    }
+    #[test]
+    fn test_pretty_fallback_and_cycle() -> Result<(), Box<dyn Error>> {
+        let a = BlankNodeRef::new("a")?;
+        let b = BlankNodeRef::new("b")?;
+        let p = NamedNodeRef::new("http://example.com/p")?;
+        let mut serializer = RdfXmlSerializer::new()
+            .with_prefix("ex", "http://example.com/")?
+            .pretty()
+            .for_writer(Vec::new());
+        serializer.serialize_triple(TripleRef::new(
+            NamedNodeRef::new("http://example.com/s")?,
+            rdf::TYPE,
+            NamedNodeRef::new("http://example.com/")?,
+        ))?;
+        serializer.serialize_triple(TripleRef::new(a, p, b))?;
+        serializer.serialize_triple(TripleRef::new(b, p, a))?;
+        let output = serializer.finish()?;
+        assert_eq!(
+            String::from_utf8_lossy(&output),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rdf:RDF xmlns:ex=\"http://example.com/\" xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:its=\"http://www.w3.org/2005/11/its\">\n\t<rdf:Description rdf:about=\"http://example.com/s\">\n\t\t<rdf:type rdf:resource=\"http://example.com/\"/>\n\t</rdf:Description>\n\t<rdf:Description rdf:nodeID=\"a\">\n\t\t<ex:p rdf:nodeID=\"b\"/>\n\t</rdf:Description>\n\t<rdf:Description rdf:nodeID=\"b\">\n\t\t<ex:p rdf:nodeID=\"a\"/>\n\t</rdf:Description>\n</rdf:RDF>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_reserved_predicate() -> Result<(), Box<dyn Error>> {
+        let mut serializer = RdfXmlSerializer::new().pretty().for_writer(Vec::new());
+        serializer
+            .serialize_triple(TripleRef::new(
+                NamedNodeRef::new("http://example.com/s")?,
+                NamedNodeRef::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#li")?,
+                NamedNodeRef::new("http://example.com/o")?,
+            ))
+            .unwrap_err();
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_round_trip() -> Result<(), Box<dyn Error>> {
+        let ex = |name: &str| NamedNodeRef::new_unchecked(name).into_owned();
+        let s = ex("http://example.com/s");
+        let p = ex("http://example.com/p");
+        let class = ex("http://example.com/Class");
+        let nested = BlankNode::default();
+        let nested_nested = BlankNode::default();
+        let shared = BlankNode::default();
+        let cycle_1 = BlankNode::default();
+        let cycle_2 = BlankNode::default();
+        let self_loop = BlankNode::default();
+        let mut graph = Graph::new();
+        graph.insert(TripleRef::new(&s, rdf::TYPE, &class));
+        graph.insert(TripleRef::new(&s, rdf::TYPE, &ex("http://example.com/")));
+        graph.insert(TripleRef::new(&s, &p, &nested));
+        graph.insert(TripleRef::new(&s, &p, &ex("http://example.com/o")));
+        graph.insert(TripleRef::new(
+            &nested,
+            rdf::TYPE,
+            &ex("http://example.com/123"),
+        ));
+        graph.insert(TripleRef::new(&nested, &p, &nested_nested));
+        graph.insert(TripleRef::new(
+            &nested,
+            &ex("http://example.org/q"),
+            LiteralRef::new_language_tagged_literal_unchecked("foo", "en"),
+        ));
+        graph.insert(TripleRef::new(
+            &nested_nested,
+            &p,
+            LiteralRef::new_typed_literal("<a>b</a>", rdf::XML_LITERAL),
+        ));
+        graph.insert(TripleRef::new(&s, &p, &shared));
+        graph.insert(TripleRef::new(&nested_nested, &p, &shared));
+        graph.insert(TripleRef::new(&shared, rdf::TYPE, &class));
+        graph.insert(TripleRef::new(&s, &p, &cycle_1));
+        graph.insert(TripleRef::new(&cycle_1, &p, &cycle_2));
+        graph.insert(TripleRef::new(&cycle_2, &p, &cycle_1));
+        graph.insert(TripleRef::new(&self_loop, &p, &self_loop));
+        graph.insert(TripleRef::new(&self_loop, &p, &BlankNode::default()));
+        // A long RDF list
+        let mut list = NamedOrBlankNode::from(s.clone());
+        for i in 0..1_000 {
+            let next = BlankNode::default();
+            graph.insert(TripleRef::new(
+                &list,
+                if i == 0 { p.as_ref() } else { rdf::REST },
+                &next,
+            ));
+            graph.insert(TripleRef::new(
+                &next,
+                rdf::FIRST,
+                LiteralRef::new_typed_literal(&i.to_string(), xsd::INTEGER),
+            ));
+            list = next.into();
+        }
+        graph.insert(TripleRef::new(&list, rdf::REST, rdf::NIL));
+
+        let mut serializer = RdfXmlSerializer::new()
+            .with_prefix("ex", "http://example.com/")?
+            .pretty()
+            .for_writer(Vec::new());
+        for triple in &graph {
+            serializer.serialize_triple(triple)?;
+        }
+        let output = serializer.finish()?;
+        let mut parsed = RdfXmlParser::new()
+            .for_slice(&output)
+            .collect::<Result<Graph, _>>()?;
+        parsed.canonicalize(CanonicalizationAlgorithm::Unstable);
+        graph.canonicalize(CanonicalizationAlgorithm::Unstable);
+        assert_eq!(parsed, graph);
+        Ok(())
+    }
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn test_pretty_triple_term_round_trip() -> Result<(), Box<dyn Error>> {
+        use oxrdf::Term;
+
+        let s = NamedNodeRef::new("http://example.com/s")?;
+        let p = NamedNodeRef::new("http://example.com/p")?;
+        let b = BlankNode::default();
+        let mut graph = Graph::new();
+        graph.insert(TripleRef::new(s, p, &b));
+        let triple_term = Term::from(Triple::new(b.clone(), p, b.clone()));
+        graph.insert(TripleRef::new(s, p, &triple_term));
+        let mut serializer = RdfXmlSerializer::new().pretty().for_writer(Vec::new());
+        for triple in &graph {
+            serializer.serialize_triple(triple)?;
+        }
+        let output = serializer.finish()?;
+        let mut parsed = RdfXmlParser::new()
+            .for_slice(&output)
+            .collect::<Result<Graph, _>>()?;
+        parsed.canonicalize(CanonicalizationAlgorithm::Unstable);
+        graph.canonicalize(CanonicalizationAlgorithm::Unstable);
+        assert_eq!(parsed, graph);
+        Ok(())
+    }
 }
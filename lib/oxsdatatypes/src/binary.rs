@@ -0,0 +1,327 @@
+use std::fmt;
+use std::fmt::Write;
+use std::str::FromStr;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const BASE64_DIGITS: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// [XML Schema `hexBinary` datatype](https://www.w3.org/TR/xmlschema11-2/#hexBinary)
+///
+/// Uses internally a [`Vec<u8>`] of the octets.
+/// The equality is the octets equality.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct HexBinary {
+    value: Vec<u8>,
+}
+
+impl HexBinary {
+    /// The octets of the value.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Checks if the two values are [identical](https://www.w3.org/TR/xmlschema11-2/#identity).
+    #[inline]
+    #[must_use]
+    pub fn is_identical_with(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl From<Vec<u8>> for HexBinary {
+    #[inline]
+    fn from(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&[u8]> for HexBinary {
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        value.to_vec().into()
+    }
+}
+
+impl From<Base64Binary> for HexBinary {
+    #[inline]
+    fn from(value: Base64Binary) -> Self {
+        value.value.into()
+    }
+}
+
+impl From<HexBinary> for Vec<u8> {
+    #[inline]
+    fn from(value: HexBinary) -> Self {
+        value.value
+    }
+}
+
+impl FromStr for HexBinary {
+    type Err = ParseBinaryError;
+
+    /// Parses hexBinary lexical mapping
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // ([0-9a-fA-F]{2})*
+        let input = input.as_bytes();
+        if !input.len().is_multiple_of(2) {
+            return Err(PARSE_UNEXPECTED_END);
+        }
+        let mut value = Vec::with_capacity(input.len() / 2);
+        for pair in input.chunks_exact(2) {
+            let [high, low] = pair else {
+                return Err(PARSE_UNEXPECTED_END);
+            };
+            value.push((hex_digit_value(*high)? << 4) | hex_digit_value(*low)?);
+        }
+        Ok(value.into())
+    }
+}
+
+impl fmt::Display for HexBinary {
+    /// Formats the value following its canonical representation, i.e. with upper case digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.value {
+            f.write_char(HEX_DIGITS[usize::from(byte >> 4)].into())?;
+            f.write_char(HEX_DIGITS[usize::from(byte & 0xF)].into())?;
+        }
+        Ok(())
+    }
+}
+
+/// [XML Schema `base64Binary` datatype](https://www.w3.org/TR/xmlschema11-2/#base64Binary)
+///
+/// Uses internally a [`Vec<u8>`] of the octets.
+/// The equality is the octets equality.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Base64Binary {
+    value: Vec<u8>,
+}
+
+impl Base64Binary {
+    /// The octets of the value.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Checks if the two values are [identical](https://www.w3.org/TR/xmlschema11-2/#identity).
+    #[inline]
+    #[must_use]
+    pub fn is_identical_with(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl From<Vec<u8>> for Base64Binary {
+    #[inline]
+    fn from(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&[u8]> for Base64Binary {
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        value.to_vec().into()
+    }
+}
+
+impl From<HexBinary> for Base64Binary {
+    #[inline]
+    fn from(value: HexBinary) -> Self {
+        value.value.into()
+    }
+}
+
+impl From<Base64Binary> for Vec<u8> {
+    #[inline]
+    fn from(value: Base64Binary) -> Self {
+        value.value
+    }
+}
+
+impl FromStr for Base64Binary {
+    type Err = ParseBinaryError;
+
+    /// Parses base64Binary lexical mapping
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // Characters of the RFC 2045 alphabet, ending with at most two '=' paddings, each one might be followed by a single space
+        let mut digits = Vec::with_capacity(input.len());
+        let mut padding = 0;
+        let mut after_space = true;
+        for c in input.bytes() {
+            if c == b' ' {
+                if after_space {
+                    return Err(PARSE_UNEXPECTED_CHAR);
+                }
+                after_space = true;
+                continue;
+            }
+            after_space = false;
+            if c == b'=' {
+                padding += 1;
+            } else if padding > 0 {
+                return Err(PARSE_INVALID_PADDING);
+            } else {
+                digits.push(base64_digit_value(c)?);
+            }
+        }
+        if !(digits.len() + padding).is_multiple_of(4) {
+            return Err(PARSE_UNEXPECTED_END);
+        }
+        let expected_padding = match digits.len() % 4 {
+            0 => 0,
+            2 => 2,
+            3 => 1,
+            _ => return Err(PARSE_INVALID_PADDING),
+        };
+        if padding != expected_padding {
+            return Err(PARSE_INVALID_PADDING);
+        }
+        let mut value = Vec::with_capacity(digits.len() * 3 / 4);
+        for chunk in digits.chunks(4) {
+            let bits = chunk.iter().enumerate().fold(0_u32, |bits, (i, digit)| {
+                bits | (u32::from(*digit) << (18 - 6 * i))
+            });
+            let bytes = bits.to_be_bytes();
+            let len = chunk.len() - 1;
+            // The bits after the last encoded octet must be zeros
+            if bytes[len + 1..].iter().any(|b| *b != 0) {
+                return Err(PARSE_INVALID_PADDING);
+            }
+            value.extend_from_slice(&bytes[1..=len]);
+        }
+        Ok(value.into())
+    }
+}
+
+impl fmt::Display for Base64Binary {
+    /// Formats the value following its canonical representation, i.e. without spaces.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.value.chunks(3) {
+            let mut bytes = [0; 3];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            let digits = [
+                bytes[0] >> 2,
+                ((bytes[0] & 0x3) << 4) | (bytes[1] >> 4),
+                ((bytes[1] & 0xF) << 2) | (bytes[2] >> 6),
+                bytes[2] & 0x3F,
+            ];
+            for (i, digit) in digits.into_iter().enumerate() {
+                f.write_char(if i <= chunk.len() {
+                    BASE64_DIGITS[usize::from(digit)].into()
+                } else {
+                    '='
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn hex_digit_value(c: u8) -> Result<u8, ParseBinaryError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(PARSE_UNEXPECTED_CHAR),
+    }
+}
+
+fn base64_digit_value(c: u8) -> Result<u8, ParseBinaryError> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(PARSE_UNEXPECTED_CHAR),
+    }
+}
+
+/// An error when parsing a [`HexBinary`] or a [`Base64Binary`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ParseBinaryError(#[from] BinaryParseErrorKind);
+
+#[derive(Debug, Clone, thiserror::Error)]
+enum BinaryParseErrorKind {
+    #[error("Unexpected character")]
+    UnexpectedChar,
+    #[error("Unexpected end of string")]
+    UnexpectedEnd,
+    #[error("Invalid padding")]
+    InvalidPadding,
+}
+
+const PARSE_UNEXPECTED_CHAR: ParseBinaryError =
+    ParseBinaryError(BinaryParseErrorKind::UnexpectedChar);
+const PARSE_UNEXPECTED_END: ParseBinaryError =
+    ParseBinaryError(BinaryParseErrorKind::UnexpectedEnd);
+const PARSE_INVALID_PADDING: ParseBinaryError =
+    ParseBinaryError(BinaryParseErrorKind::InvalidPadding);
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_binary_from_str() -> Result<(), ParseBinaryError> {
+        assert_eq!(HexBinary::from_str("")?.to_string(), "");
+        assert_eq!(HexBinary::from_str("0fb7")?.to_string(), "0FB7");
+        assert_eq!(HexBinary::from_str("0FB7")?.as_bytes(), [0x0F, 0xB7]);
+        assert_eq!(HexBinary::from_str("0fb7")?, HexBinary::from_str("0FB7")?);
+        HexBinary::from_str("0").unwrap_err();
+        HexBinary::from_str("0g").unwrap_err();
+        HexBinary::from_str("0F B7").unwrap_err();
+        Ok(())
+    }
+
+    #[test]
+    fn base64_binary_from_str() -> Result<(), ParseBinaryError> {
+        assert_eq!(Base64Binary::from_str("")?.to_string(), "");
+        assert_eq!(Base64Binary::from_str("D7c=")?.as_bytes(), [0x0F, 0xB7]);
+        assert_eq!(Base64Binary::from_str("Zm9vYmFy")?.as_bytes(), b"foobar");
+        assert_eq!(Base64Binary::from_str("Zm9vYg==")?.as_bytes(), b"foob");
+        assert_eq!(Base64Binary::from_str("Zm9vYmE=")?.as_bytes(), b"fooba");
+        assert_eq!(Base64Binary::from_str("Zm9v YmFy")?.to_string(), "Zm9vYmFy");
+        assert_eq!(Base64Binary::from_str("Zm9vYg= =")?.to_string(), "Zm9vYg==");
+        Base64Binary::from_str("Zm9").unwrap_err();
+        Base64Binary::from_str("Zm9vY===").unwrap_err();
+        Base64Binary::from_str("Zm9vYh==").unwrap_err(); // Non zero trailing bits
+        Base64Binary::from_str("Zm=9").unwrap_err();
+        Base64Binary::from_str("Zm9v\nYmFy").unwrap_err();
+        Base64Binary::from_str(" Zm9v").unwrap_err();
+        Base64Binary::from_str("Zm  9v").unwrap_err();
+        Base64Binary::from_str("Zm9_").unwrap_err();
+        Ok(())
+    }
+
+    #[test]
+    fn base64_binary_to_string() {
+        for value in ["", "f", "fo", "foo", "foob", "fooba", "foobar"] {
+            let binary = Base64Binary::from(value.as_bytes());
+            assert_eq!(Base64Binary::from_str(&binary.to_string()).unwrap(), binary);
+        }
+        assert_eq!(Base64Binary::from(b"f".as_slice()).to_string(), "Zg==");
+        assert_eq!(Base64Binary::from(b"fo".as_slice()).to_string(), "Zm8=");
+    }
+
+    #[test]
+    fn conversions() -> Result<(), ParseBinaryError> {
+        assert_eq!(
+            HexBinary::from(Base64Binary::from_str("D7c=")?),
+            HexBinary::from_str("0FB7")?
+        );
+        assert_eq!(
+            Base64Binary::from(HexBinary::from_str("0fb7")?).to_string(),
+            "D7c="
+        );
+        Ok(())
+    }
+}
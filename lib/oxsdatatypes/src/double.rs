@@ -35,10 +35,17 @@ impl Double {
         }
     }
 
+    /// Returns the IEEE 754 big-endian encoding of the value.
+    ///
+    /// All the NaN values are encoded the same way so that this encoding is consistent with [`is_identical_with`](Self::is_identical_with).
     #[inline]
     #[must_use]
     pub fn to_be_bytes(self) -> [u8; 8] {
-        self.value.to_be_bytes()
+        if self.value.is_nan() {
+            f64::NAN.to_be_bytes()
+        } else {
+            self.value.to_be_bytes()
+        }
     }
 
     /// [fn:abs](https://www.w3.org/TR/xpath-functions-31/#func-abs)
@@ -82,10 +89,12 @@ impl Double {
     }
 
     /// Checks if the two values are [identical](https://www.w3.org/TR/xmlschema11-2/#identity).
+    ///
+    /// Unlike equality, NaN is identical to itself and `-0` is not identical to `0`.
     #[inline]
     #[must_use]
     pub fn is_identical_with(self, other: Self) -> bool {
-        self.value.to_bits() == other.value.to_bits()
+        self.to_be_bytes() == other.to_be_bytes()
     }
 }
 
@@ -293,9 +302,25 @@ mod tests {
     fn is_identical_with() {
         assert!(Double::from(0.).is_identical_with(Double::from(0.)));
         assert!(Double::NAN.is_identical_with(Double::NAN));
+        assert!(Double::NAN.is_identical_with(-Double::NAN));
+        assert!((Double::from(0.) / Double::from(0.)).is_identical_with(Double::NAN));
+        assert!(!Double::NAN.is_identical_with(Double::INFINITY));
         assert!(!Double::from(-0.).is_identical_with(Double::from(0.)));
     }
 
+    #[test]
+    fn to_be_bytes() {
+        assert_eq!((-Double::NAN).to_be_bytes(), Double::NAN.to_be_bytes());
+        assert_eq!(
+            Double::from_be_bytes(Double::from(-0.).to_be_bytes()).to_string(),
+            "-0"
+        );
+        assert_ne!(
+            Double::from(-0.).to_be_bytes(),
+            Double::from(0.).to_be_bytes()
+        );
+    }
+
     #[test]
     fn from_str() -> Result<(), ParseFloatError> {
         assert_eq!(Double::from_str("NaN")?.to_string(), "NaN");
@@ -4,6 +4,7 @@
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
+mod binary;
 mod boolean;
 mod date_time;
 mod decimal;
@@ -12,6 +13,7 @@ mod duration;
 mod float;
 mod integer;
 
+pub use self::binary::{Base64Binary, HexBinary, ParseBinaryError};
 pub use self::boolean::Boolean;
 pub use self::date_time::{
     Date, DateTime, DateTimeOverflowError, GDay, GMonth, GMonthDay, GYear, GYearMonth,
@@ -1,9 +1,11 @@
 #![allow(clippy::range_plus_one)]
 
-use crate::toolkit::{TokenRecognizer, TokenRecognizerError};
+use crate::toolkit::{
+    RuleRecognizerError, TokenRecognizer, TokenRecognizerError, TurtleSyntaxErrorKind,
+};
 use memchr::{memchr, memchr2};
 use oxilangtag::LanguageTag;
-use oxiri::Iri;
+use oxiri::{Iri, IriParseError};
 #[cfg(feature = "rdf-12")]
 use oxrdf::BaseDirection;
 use oxrdf::NamedNode;
@@ -240,17 +242,19 @@ impl N3Lexer {
                 if self.lenient {
                     base_iri.resolve_unchecked(&iri)
                 } else {
-                    base_iri
-                        .resolve(&iri)
-                        .map_err(|e| (position, e.to_string()))?
+                    match base_iri.resolve(&iri) {
+                        Ok(iri) => iri,
+                        Err(e) => return Err(invalid_iri_error(position, iri, e)),
+                    }
                 }
                 .into_inner()
             } else if self.lenient {
                 iri
             } else {
-                Iri::parse(iri)
-                    .map_err(|e| (position, e.to_string()))?
-                    .into_inner()
+                if let Err(e) = Iri::parse(iri.as_str()) {
+                    return Err(invalid_iri_error(position, iri, e));
+                }
+                iri
             },
         ))
     }
@@ -642,7 +646,14 @@ impl N3Lexer {
                 lang_tag
             } else {
                 LanguageTag::parse(lang_tag)
-                    .map_err(|e| (position.clone(), e.to_string()))?
+                    .map_err(|e| {
+                        TokenRecognizerError::from((position.clone(), e.to_string())).with_kind(
+                            TurtleSyntaxErrorKind::InvalidLanguageTag {
+                                value: lang_tag.into(),
+                                source: e,
+                            },
+                        )
+                    })?
                     .into_inner()
             },
             #[cfg(feature = "rdf-12")]
@@ -1140,23 +1151,48 @@ pub fn resolve_local_name(
     local: &str,
     might_be_invalid_iri: bool,
     prefixes: &HashMap<String, Iri<String>>,
-) -> Result<NamedNode, String> {
+) -> Result<NamedNode, RuleRecognizerError> {
     if let Some(start) = prefixes.get(prefix) {
         let iri = format!("{start}{local}");
         if might_be_invalid_iri || start.path().is_empty() {
             // We validate again. We always validate if the local part might be the IRI authority.
             if let Err(e) = Iri::parse(iri.as_str()) {
-                return Err(format!(
+                let message = format!(
                     "The prefixed name {prefix}:{local} builds IRI {iri} that is invalid: {e}"
+                );
+                return Err(RuleRecognizerError::new(
+                    TurtleSyntaxErrorKind::InvalidIri {
+                        value: iri,
+                        source: e,
+                    },
+                    message,
                 ));
             }
         }
         Ok(NamedNode::new_unchecked(iri))
     } else {
-        Err(format!("The prefix {prefix}: has not been declared"))
+        Err(RuleRecognizerError::new(
+            TurtleSyntaxErrorKind::UndefinedPrefix {
+                prefix: prefix.into(),
+            },
+            format!("The prefix {prefix}: has not been declared"),
+        ))
     }
 }
 
+fn invalid_iri_error(
+    position: Range<usize>,
+    value: String,
+    error: IriParseError,
+) -> TokenRecognizerError {
+    TokenRecognizerError::from((position, error.to_string())).with_kind(
+        TurtleSyntaxErrorKind::InvalidIri {
+            value,
+            source: error,
+        },
+    )
+}
+
 fn str_from_utf8(data: &[u8], range: Range<usize>) -> Result<&str, TokenRecognizerError> {
     str::from_utf8(data).map_err(|e| {
         (
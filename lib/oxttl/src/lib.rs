@@ -18,11 +18,62 @@ pub mod turtle;
 pub use crate::n3::N3Parser;
 pub use crate::nquads::{NQuadsParser, NQuadsSerializer};
 pub use crate::ntriples::{NTriplesParser, NTriplesSerializer};
-pub use crate::toolkit::{TextPosition, TurtleParseError, TurtleSyntaxError};
+use crate::toolkit::RuleRecognizerError;
+pub use crate::toolkit::{
+    LowLevelParserState, TextPosition, TurtleParseError, TurtleSyntaxError, TurtleSyntaxErrorKind,
+};
 pub use crate::trig::{TriGParser, TriGSerializer};
 pub use crate::turtle::{TurtleParser, TurtleSerializer};
+#[cfg(feature = "rdf-12")]
+use oxrdf::vocab::rdf::DIR_LANG_STRING;
+use oxrdf::vocab::rdf::LANG_STRING;
+use oxrdf::{DatatypeError, Literal};
+use std::sync::Arc;
 
-pub(crate) const MIN_BUFFER_SIZE: usize = 4096;
-pub(crate) const MAX_BUFFER_SIZE: usize = 4096 * 4096;
 #[expect(clippy::decimal_literal_representation)]
 pub(crate) const MIN_PARALLEL_CHUNK_SIZE: usize = 16384;
+
+/// Rewrites or rejects a parsed literal, see `with_literal_mapper` on the parsers
+pub(crate) type LiteralMapper = Arc<dyn Fn(Literal) -> Result<Literal, String> + Send + Sync>;
+
+fn map_literal(
+    literal_mapper: Option<&LiteralMapper>,
+    literal: Literal,
+) -> Result<Literal, RuleRecognizerError> {
+    let Some(literal_mapper) = literal_mapper else {
+        return Ok(literal);
+    };
+    literal_mapper(literal.clone()).map_err(|message| {
+        RuleRecognizerError::new(TurtleSyntaxErrorKind::InvalidLiteral { literal }, message)
+    })
+}
+
+/// Checks that a literal written with an explicit datatype does not use the datatypes of the language-tagged strings
+fn check_typed_literal(literal: &Literal, errors: &mut Vec<RuleRecognizerError>) {
+    if literal.datatype() == LANG_STRING {
+        errors.push(RuleRecognizerError::new(
+            TurtleSyntaxErrorKind::InvalidLiteral {
+                literal: literal.clone(),
+            },
+            "The datatype of a literal without a language tag must not be rdf:langString",
+        ));
+    }
+    #[cfg(feature = "rdf-12")]
+    if literal.datatype() == DIR_LANG_STRING {
+        errors.push(RuleRecognizerError::new(
+            TurtleSyntaxErrorKind::InvalidLiteral {
+                literal: literal.clone(),
+            },
+            "The datatype of a literal without a base direction must not be rdf:dirLangString",
+        ));
+    }
+}
+
+fn invalid_literal_error(literal: &Literal, error: &DatatypeError) -> RuleRecognizerError {
+    RuleRecognizerError::new(
+        TurtleSyntaxErrorKind::InvalidLiteral {
+            literal: literal.clone(),
+        },
+        error.to_string(),
+    )
+}
@@ -1,11 +1,12 @@
 //! Shared parser implementation for N-Triples and N-Quads.
 
 use crate::lexer::{N3Lexer, N3LexerMode, N3LexerOptions, N3Token};
-use crate::toolkit::{Lexer, Parser, RuleRecognizer, RuleRecognizerError, TokenOrLineJump};
-use crate::{MAX_BUFFER_SIZE, MIN_BUFFER_SIZE};
+use crate::toolkit::{
+    Lexer, LexerLimits, Parser, RuleRecognizer, RuleRecognizerError, TokenOrLineJump,
+};
+use crate::{LiteralMapper, check_typed_literal, invalid_literal_error, map_literal};
 #[cfg(feature = "rdf-12")]
 use oxrdf::Triple;
-use oxrdf::vocab::rdf;
 use oxrdf::{BlankNode, GraphName, Literal, NamedNode, NamedOrBlankNode, Quad, Term};
 
 pub struct NQuadsRecognizer {
@@ -14,6 +15,9 @@ pub struct NQuadsRecognizer {
     predicates: Vec<NamedNode>,
     objects: Vec<Term>,
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
 }
 
 pub struct NQuadsRecognizerContext {
@@ -71,7 +75,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                             results,
                             errors,
                             token,
-                            "line jumps are not allowed inside of quoted triples",
+                            RuleRecognizerError::unclosed_construct(
+                                "quoted triple",
+                                "line jumps are not allowed inside of quoted triples",
+                            ),
                         )
                     };
                 };
@@ -91,7 +98,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         TokenOrLineJump::Token(token),
-                        "The subject of a triple must be an IRI or a blank node",
+                        RuleRecognizerError::unexpected_token(
+                            "an IRI or a blank node",
+                            "The subject of a triple must be an IRI or a blank node",
+                        ),
                     ),
                 }
             }
@@ -102,7 +112,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         token,
-                        "line jumps are not allowed in the middle of triples",
+                        RuleRecognizerError::unclosed_construct(
+                            "statement",
+                            "line jumps are not allowed in the middle of triples",
+                        ),
                     );
                 };
                 match token {
@@ -116,7 +129,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         TokenOrLineJump::Token(token),
-                        "The predicate of a triple must be an IRI",
+                        RuleRecognizerError::unexpected_token(
+                            "an IRI",
+                            "The predicate of a triple must be an IRI",
+                        ),
                     ),
                 }
             }
@@ -127,7 +143,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         token,
-                        "line jumps are not allowed in the middle of triples",
+                        RuleRecognizerError::unclosed_construct(
+                            "statement",
+                            "line jumps are not allowed in the middle of triples",
+                        ),
                     );
                 };
                 match token {
@@ -148,6 +167,16 @@ impl RuleRecognizer for NQuadsRecognizer {
                             .push(NQuadsState::ExpectLiteralAnnotationOrGraphNameOrDot { value });
                         self
                     }
+                    N3Token::Punctuation("<<(") if self.reject_triple_terms => self.error(
+                        context,
+                        results,
+                        errors,
+                        TokenOrLineJump::Token(token),
+                        RuleRecognizerError::unexpected_token(
+                            "a term that is not a triple term",
+                            "Triple terms are not allowed",
+                        ),
+                    ),
                     #[cfg(feature = "rdf-12")]
                     N3Token::Punctuation("<<(") => {
                         self.stack.push(NQuadsState::AfterQuotedTriple);
@@ -159,7 +188,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         TokenOrLineJump::Token(token),
-                        "The object of a triple must be an IRI, a blank node or a literal",
+                        RuleRecognizerError::unexpected_token(
+                            "an IRI, a blank node or a literal",
+                            "The object of a triple must be an IRI, a blank node or a literal",
+                        ),
                     ),
                 }
             }
@@ -169,49 +201,63 @@ impl RuleRecognizer for NQuadsRecognizer {
                     language,
                     direction,
                 }) => {
-                    self.objects.push(
-                        if let Some(direction) = direction {
-                            Literal::new_directional_language_tagged_literal_unchecked(
-                                value,
-                                language.to_ascii_lowercase(),
-                                direction,
-                            )
-                        } else {
-                            Literal::new_language_tagged_literal_unchecked(
-                                value,
-                                language.to_ascii_lowercase(),
-                            )
-                        }
-                        .into(),
-                    );
-                    self.stack
-                        .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
-                    self
-                }
-                #[cfg(not(feature = "rdf-12"))]
-                TokenOrLineJump::Token(N3Token::LangTag { language }) => {
-                    self.objects.push(
+                    let literal = if let Some(direction) = direction {
+                        Literal::new_directional_language_tagged_literal_unchecked(
+                            value,
+                            language.to_ascii_lowercase(),
+                            direction,
+                        )
+                    } else {
                         Literal::new_language_tagged_literal_unchecked(
                             value,
                             language.to_ascii_lowercase(),
                         )
-                        .into(),
-                    );
-                    self.stack
-                        .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
-                    self
+                    };
+                    match self.map_literal(literal) {
+                        Ok(literal) => {
+                            self.objects.push(literal.into());
+                            self.stack
+                                .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
+                            self
+                        }
+                        Err(e) => {
+                            errors.push(e);
+                            self.error_recovery_state()
+                        }
+                    }
+                }
+                #[cfg(not(feature = "rdf-12"))]
+                TokenOrLineJump::Token(N3Token::LangTag { language }) => {
+                    match self.map_literal(Literal::new_language_tagged_literal_unchecked(
+                        value,
+                        language.to_ascii_lowercase(),
+                    )) {
+                        Ok(literal) => {
+                            self.objects.push(literal.into());
+                            self.stack
+                                .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
+                            self
+                        }
+                        Err(e) => {
+                            errors.push(e);
+                            self.error_recovery_state()
+                        }
+                    }
                 }
                 TokenOrLineJump::Token(N3Token::Punctuation("^^")) => {
                     self.stack
                         .push(NQuadsState::ExpectLiteralDatatype { value });
                     self
                 }
-                _ => {
-                    self.objects.push(Literal::new_simple_literal(value).into());
-                    self.stack
-                        .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
-                    self.recognize_next(token, context, results, errors)
-                }
+                _ => match self.map_literal(Literal::new_simple_literal(value)) {
+                    Ok(literal) => {
+                        self.objects.push(literal.into());
+                        self.stack
+                            .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
+                        self.recognize_next(token, context, results, errors)
+                    }
+                    Err(e) => self.error(context, results, errors, token, e),
+                },
             },
             NQuadsState::ExpectLiteralDatatype { value } => {
                 let TokenOrLineJump::Token(token) = token else {
@@ -220,21 +266,32 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         token,
-                        "line jumps are not allowed in the middle of triples",
+                        RuleRecognizerError::unclosed_construct(
+                            "statement",
+                            "line jumps are not allowed in the middle of triples",
+                        ),
                     );
                 };
                 match token {
                     N3Token::IriRef(d) => {
-                        if !self.lenient && d == rdf::LANG_STRING.as_str() {
-                            errors.push("The datatype of a literal without a language tag must not be rdf:langString".into());
+                        let literal =
+                            Literal::new_typed_literal(value, NamedNode::new_unchecked(d));
+                        if !self.lenient {
+                            check_typed_literal(&literal, errors);
                         }
-                        #[cfg(feature = "rdf-12")]
-                        if !self.lenient && d == rdf::DIR_LANG_STRING.as_str() {
-                            errors.push("The datatype of a literal without a base direction must not be rdf:dirLangString".into());
+                        let literal = match self.map_literal(literal) {
+                            Ok(literal) => literal,
+                            Err(e) => {
+                                errors.push(e);
+                                return self.error_recovery_state();
+                            }
+                        };
+                        if self.validate_literals {
+                            if let Err(e) = literal.parsed_value() {
+                                errors.push(invalid_literal_error(&literal, &e));
+                            }
                         }
-                        self.objects.push(
-                            Literal::new_typed_literal(value, NamedNode::new_unchecked(d)).into(),
-                        );
+                        self.objects.push(literal.into());
                         self.stack
                             .push(NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple);
                         self
@@ -244,7 +301,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         TokenOrLineJump::Token(token),
-                        "A literal datatype must be an IRI",
+                        RuleRecognizerError::unexpected_token(
+                            "an IRI",
+                            "A literal datatype must be an IRI",
+                        ),
                     ),
                 }
             }
@@ -277,7 +337,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                         results,
                         errors,
                         token,
-                        "Expecting the end of a quoted triple ')>>'",
+                        RuleRecognizerError::unexpected_token(
+                            "')>>'",
+                            "Expecting the end of a quoted triple ')>>'",
+                        ),
                     )
                 }
             }
@@ -289,7 +352,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                             results,
                             errors,
                             token,
-                            "Quads must be followed by a dot",
+                            RuleRecognizerError::unexpected_token(
+                                "'.'",
+                                "Quads must be followed by a dot",
+                            ),
                         )
                         .recognize_next(TokenOrLineJump::LineJump, context, results, errors);
                 };
@@ -297,7 +363,10 @@ impl RuleRecognizer for NQuadsRecognizer {
                     self.stack.push(NQuadsState::ExpectLineJump);
                     self
                 } else {
-                    errors.push("Quads must be followed by a dot".into());
+                    errors.push(RuleRecognizerError::unexpected_token(
+                        "'.'",
+                        "Quads must be followed by a dot",
+                    ));
                     self.recognize_next(TokenOrLineJump::Token(token), context, results, errors)
                 }
             }
@@ -305,12 +374,12 @@ impl RuleRecognizer for NQuadsRecognizer {
                 let TokenOrLineJump::Token(token) = token else {
                     return self;
                 };
-                errors.push(
+                errors.push(RuleRecognizerError::unexpected_token(
+                    "a line jump",
                     format!(
                         "Only a single triple or quad can be written in a line, found {token:?}"
-                    )
-                    .into(),
-                );
+                    ),
+                ));
                 self.recognize_next(TokenOrLineJump::Token(token), context, results, errors)
             }
             #[cfg(feature = "rdf-12")]
@@ -342,17 +411,34 @@ impl RuleRecognizer for NQuadsRecognizer {
     ) {
         match &*self.stack {
             [NQuadsState::ExpectSubject | NQuadsState::ExpectLineJump] | [] => (),
-            [NQuadsState::ExpectDot] => errors.push("Triples must be followed by a dot".into()),
+            [NQuadsState::ExpectDot] => errors.push(RuleRecognizerError::unclosed_construct(
+                "statement",
+                "Triples must be followed by a dot",
+            )),
             [NQuadsState::ExpectPossibleGraphOrEndOfQuotedTriple] => {
                 self.emit_quad(results, GraphName::DefaultGraph);
-                errors.push("Triples must be followed by a dot".into())
+                errors.push(RuleRecognizerError::unclosed_construct(
+                    "statement",
+                    "Triples must be followed by a dot",
+                ))
             }
             [NQuadsState::ExpectLiteralAnnotationOrGraphNameOrDot { value }] => {
-                self.objects.push(Literal::new_simple_literal(value).into());
-                self.emit_quad(results, GraphName::DefaultGraph);
-                errors.push("Triples must be followed by a dot".into())
+                match self.map_literal(Literal::new_simple_literal(value)) {
+                    Ok(literal) => {
+                        self.objects.push(literal.into());
+                        self.emit_quad(results, GraphName::DefaultGraph);
+                    }
+                    Err(e) => errors.push(e),
+                }
+                errors.push(RuleRecognizerError::unclosed_construct(
+                    "statement",
+                    "Triples must be followed by a dot",
+                ))
             }
-            _ => errors.push("Unexpected end".into()), // TODO
+            _ => errors.push(RuleRecognizerError::unclosed_construct(
+                "statement",
+                "Unexpected end",
+            )), // TODO
         }
     }
 
@@ -362,19 +448,23 @@ impl RuleRecognizer for NQuadsRecognizer {
 }
 
 impl NQuadsRecognizer {
+    #[expect(clippy::fn_params_excessive_bools)]
     pub fn new_parser<B>(
         data: B,
         is_ending: bool,
         with_graph_name: bool,
         lenient: bool,
+        validate_literals: bool,
+        reject_triple_terms: bool,
+        literal_mapper: Option<LiteralMapper>,
+        limits: LexerLimits,
     ) -> Parser<B, Self> {
         Parser::new(
             Lexer::new(
                 N3Lexer::new(N3LexerMode::NTriples, lenient),
                 data,
                 is_ending,
-                MIN_BUFFER_SIZE,
-                MAX_BUFFER_SIZE,
+                limits,
                 Some(b"#"),
             ),
             Self {
@@ -383,6 +473,9 @@ impl NQuadsRecognizer {
                 predicates: Vec::new(),
                 objects: Vec::new(),
                 lenient,
+                validate_literals,
+                reject_triple_terms,
+                literal_mapper,
             },
             NQuadsRecognizerContext {
                 with_graph_name,
@@ -408,6 +501,10 @@ impl NQuadsRecognizer {
         }
     }
 
+    fn map_literal(&self, literal: Literal) -> Result<Literal, RuleRecognizerError> {
+        map_literal(self.literal_mapper.as_ref(), literal)
+    }
+
     fn emit_quad(&mut self, results: &mut Vec<Quad>, graph_name: GraphName) {
         results.push(Quad {
             subject: self.subjects.pop().unwrap(),
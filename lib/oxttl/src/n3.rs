@@ -1,17 +1,19 @@
 //! A [N3](https://w3c.github.io/N3/spec/) streaming parser implemented by [`N3Parser`].
 
+use crate::TurtleParseError;
 use crate::lexer::{N3Lexer, N3LexerMode, N3LexerOptions, N3Token, resolve_local_name};
 #[cfg(feature = "async-tokio")]
 use crate::toolkit::TokioAsyncReaderIterator;
+#[cfg(feature = "rdf-12")]
+use crate::toolkit::TurtleSyntaxErrorKind;
 use crate::toolkit::{
-    Lexer, Parser, ReaderIterator, RuleRecognizer, RuleRecognizerError, SliceIterator,
-    TokenOrLineJump, TurtleSyntaxError,
+    Lexer, LexerLimits, LowLevelParserState, Parser, ReaderIterator, RuleRecognizer,
+    RuleRecognizerError, SliceIterator, TokenOrLineJump, TurtleSyntaxError,
 };
-use crate::{MAX_BUFFER_SIZE, MIN_BUFFER_SIZE, TurtleParseError};
 use oxiri::{Iri, IriParseError};
 #[cfg(feature = "rdf-12")]
 use oxrdf::Triple;
-use oxrdf::vocab::{rdf, xsd};
+use oxrdf::vocab::{rdf, rdfs, xsd};
 use oxrdf::{
     BlankNode, GraphName, Literal, NamedNode, NamedNodeRef, NamedOrBlankNode, Quad, Term, Variable,
 };
@@ -22,6 +24,8 @@ use std::io::Read;
 #[cfg(feature = "async-tokio")]
 use tokio::io::AsyncRead;
 
+const LOG_FOR_ALL: &str = "http://www.w3.org/2000/10/swap/log#forAll";
+
 /// A N3 term i.e. a RDF `Term` or a `Variable`.
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
 pub enum N3Term {
@@ -166,6 +170,44 @@ impl From<Quad> for N3Quad {
     }
 }
 
+/// How the [N3 quick variables](https://w3c.github.io/N3/spec/#quickvars) like `?x` are returned by [`N3Parser`].
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash, Default)]
+pub enum QuickVarMode {
+    /// Quick variables are returned as [`N3Term::Variable`].
+    #[default]
+    Variable,
+    /// Quick variables are replaced by fresh IRIs universally quantified with [`log:forAll`](http://www.w3.org/2000/10/swap/log#forAll).
+    ///
+    /// Following the [cwm](https://www.w3.org/2000/10/swap/doc/cwm) convention, a quick variable is quantified in the parent of the formula it is used in
+    /// and the same variable name is mapped to the same IRI everywhere in this parent formula, e.g. in the two sides of a rule.
+    /// The quantification is declared by a `<formula> log:forAll <variable>` quad inside of the parent formula itself.
+    /// The document itself is identified by a fresh IRI when the variables are used in top-level triples or formulas.
+    ///
+    /// The fresh IRIs have the form `urn:uuid:<random UUID>#<variable name>`.
+    Quantified,
+}
+
+/// How the triples inside of [N3 formulas](https://w3c.github.io/N3/spec/#formulae) like `{ :a :b :c }` are returned by [`N3Parser`].
+///
+/// Each formula is identified by a fresh blank node, used in place of the formula in the triples around it.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash, Default)]
+pub enum FormulaEncoding {
+    /// The triples of the formula are returned with the formula blank node as graph name.
+    #[default]
+    GraphName,
+    /// The triples of the formula are returned in the default graph as triple terms,
+    /// each one being the object of a `<formula> rdf:reifies <<( subject predicate object )>>` triple.
+    ///
+    /// Triples with a variable or with a literal as subject are not valid triple terms and are reported as errors.
+    /// [`QuickVarMode::Quantified`] replaces quick variables by IRIs to avoid it.
+    #[cfg(feature = "rdf-12")]
+    RdfStarQuoted,
+    /// The triples of the formula are returned in the default graph as [RDF reifications](https://www.w3.org/TR/rdf-schema/#ch_reificationvocab),
+    /// i.e. a fresh `rdf:Statement` blank node with `rdf:subject`, `rdf:predicate` and `rdf:object` triples for each of them,
+    /// each statement being the object of a `<formula> rdfs:member <statement>` triple.
+    Reification,
+}
+
 /// A [N3](https://w3c.github.io/N3/spec/) streaming parser.
 ///
 /// Count the number of people:
@@ -197,6 +239,9 @@ impl From<Quad> for N3Quad {
 #[must_use]
 pub struct N3Parser {
     lenient: bool,
+    limits: LexerLimits,
+    quick_var_mode: QuickVarMode,
+    formula_encoding: FormulaEncoding,
     base: Option<Iri<String>>,
     prefixes: HashMap<String, Iri<String>>,
 }
@@ -225,6 +270,96 @@ impl N3Parser {
         self.lenient()
     }
 
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered.
+    /// There is no limit by default.
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.limits.max_token_length = Some(max_term_length);
+        self
+    }
+
+    /// Sets how many bytes are read at once from the input (4KiB by default)
+    /// and the maximal size of the parser buffer (16MiB by default).
+    ///
+    /// Parsing from a reader fails if the buffer would need to grow beyond its maximal size
+    /// and the low-level parser stops buffering new bytes when it is full.
+    #[inline]
+    pub fn with_buffer_sizes(mut self, min_buffer_size: usize, max_buffer_size: usize) -> Self {
+        self.limits.min_buffer_size = min_buffer_size;
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+
+    /// Sets how the quick variables like `?x` are returned (as [`N3Term::Variable`] by default).
+    ///
+    /// ```
+    /// use oxrdf::NamedNode;
+    /// use oxttl::n3::{N3Parser, N3Term, QuickVarMode};
+    ///
+    /// let file = "@prefix : <http://example.com/> . { ?x a :Person } => { ?x a :Mortal } .";
+    ///
+    /// let for_all = N3Term::NamedNode(NamedNode::new(
+    ///     "http://www.w3.org/2000/10/swap/log#forAll",
+    /// )?);
+    /// let quads = N3Parser::new()
+    ///     .with_quick_variable_handling(QuickVarMode::Quantified)
+    ///     .for_slice(file)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// // ?x is quantified once in the document and used in both formulas
+    /// let quantifications = quads
+    ///     .iter()
+    ///     .filter(|q| q.predicate == for_all)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(quantifications.len(), 1);
+    /// assert_eq!(quads[1].subject, quantifications[0].object);
+    /// assert_eq!(quads[2].subject, quantifications[0].object);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_quick_variable_handling(mut self, mode: QuickVarMode) -> Self {
+        self.quick_var_mode = mode;
+        self
+    }
+
+    /// Sets how the triples inside of formulas are returned (with the formula as graph name by default).
+    ///
+    /// It allows consumers only supporting triples to keep the formulas content.
+    ///
+    /// ```
+    /// use oxrdf::NamedNode;
+    /// use oxrdf::vocab::{rdf, rdfs};
+    /// use oxttl::n3::{FormulaEncoding, N3Parser, N3Term};
+    ///
+    /// let file = "@prefix : <http://example.com/> . { :a :b :c } :says :d .";
+    ///
+    /// let quads = N3Parser::new()
+    ///     .with_formula_encoding(FormulaEncoding::Reification)
+    ///     .for_slice(file)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(quads.len(), 6);
+    /// assert!(quads.iter().all(|q| q.graph_name.is_default_graph()));
+    /// // The formula is linked to a rdf:Statement
+    /// let member = quads
+    ///     .iter()
+    ///     .find(|q| q.predicate == N3Term::from(rdfs::MEMBER))
+    ///     .unwrap();
+    /// assert!(quads.iter().any(|q| q.subject == member.object
+    ///     && q.predicate == N3Term::from(rdf::TYPE)
+    ///     && q.object == N3Term::from(rdf::STATEMENT)));
+    /// // and is the subject of the top-level triple
+    /// let d = N3Term::NamedNode(NamedNode::new("http://example.com/d")?);
+    /// assert!(quads.iter().any(|q| q.subject == member.subject && q.object == d));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_formula_encoding(mut self, encoding: FormulaEncoding) -> Self {
+        self.formula_encoding = encoding;
+        self
+    }
+
     #[inline]
     pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Result<Self, IriParseError> {
         self.base = Some(Iri::parse(base_iri.into())?);
@@ -346,8 +481,17 @@ impl N3Parser {
     /// ```
     pub fn for_slice(self, slice: &(impl AsRef<[u8]> + ?Sized)) -> SliceN3Parser<'_> {
         SliceN3Parser {
-            inner: N3Recognizer::new_parser(slice.as_ref(), true, false, self.base, self.prefixes)
-                .into_iter(),
+            inner: N3Recognizer::new_parser(
+                slice.as_ref(),
+                true,
+                false,
+                self.limits,
+                self.quick_var_mode,
+                self.formula_encoding,
+                self.base,
+                self.prefixes,
+            )
+            .into_iter(),
         }
     }
 
@@ -396,6 +540,9 @@ impl N3Parser {
                 Vec::new(),
                 false,
                 self.lenient,
+                self.limits,
+                self.quick_var_mode,
+                self.formula_encoding,
                 self.base,
                 self.prefixes,
             ),
@@ -766,7 +913,12 @@ pub struct LowLevelN3Parser {
 
 impl LowLevelN3Parser {
     /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    ///
+    /// Returns the number of bytes of `other` that have been buffered.
+    /// It is lower than the length of `other` if the buffer has reached its [maximal size](Self::max_buffer_size):
+    /// the remaining bytes should be provided again after having called [`parse_next`](Self::parse_next).
+    /// If no byte is buffered while the parser [needs input](LowLevelParserState::NeedsInput), a term is longer than the maximal buffer size and the parsing cannot go on.
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.parser.extend_from_slice(other)
     }
 
@@ -790,6 +942,54 @@ impl LowLevelN3Parser {
         self.parser.parse_next()
     }
 
+    /// What the parser is waiting for.
+    ///
+    /// It allows to drive the parser from an event loop with back-pressure:
+    /// ```
+    /// use oxttl::{LowLevelParserState, N3Parser};
+    ///
+    /// let mut file = b"<http://example.com/s> <http://example.com/p> <http://example.com/o1> .
+    /// <http://example.com/s> <http://example.com/p> <http://example.com/o2> .".as_slice();
+    /// let mut parser = N3Parser::new().with_buffer_sizes(32, 64).low_level();
+    /// let mut count = 0;
+    /// loop {
+    ///     match parser.state() {
+    ///         LowLevelParserState::NeedsInput => {
+    ///             if file.is_empty() {
+    ///                 parser.end();
+    ///             } else {
+    ///                 // The parser only buffers the bytes it has room for
+    ///                 let buffered = parser.extend_from_slice(file);
+    ///                 file = &file[buffered..];
+    ///             }
+    ///         }
+    ///         LowLevelParserState::HasPendingOutput => {
+    ///             if let Some(triple) = parser.parse_next() {
+    ///                 triple?;
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///         LowLevelParserState::Finished => break,
+    ///     }
+    ///     assert!(parser.buffered_len() <= parser.max_buffer_size());
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn state(&self) -> LowLevelParserState {
+        self.parser.state()
+    }
+
+    /// The number of bytes provided with [`extend_from_slice`](Self::extend_from_slice) that have not been parsed yet.
+    pub fn buffered_len(&self) -> usize {
+        self.parser.buffered_len()
+    }
+
+    /// The maximal number of bytes the parser buffers, set with `with_buffer_sizes`.
+    pub fn max_buffer_size(&self) -> usize {
+        self.parser.max_buffer_size()
+    }
+
     /// The list of IRI prefixes considered at the current step of the parsing.
     ///
     /// This method returns (prefix name, prefix value) tuples.
@@ -862,6 +1062,47 @@ struct N3Recognizer {
     terms: Vec<N3Term>,
     predicates: Vec<Predicate>,
     contexts: Vec<BlankNode>,
+    /// For each formula in `contexts`, the directive scope to restore when leaving it if it has been changed inside of it
+    formula_scopes: Vec<Option<N3DirectiveScope>>,
+    quick_var_mode: QuickVarMode,
+    formula_encoding: FormulaEncoding,
+    /// The quick variables quantified in the document and then in each formula in `contexts`
+    variable_scopes: Vec<N3VariableScope>,
+}
+
+/// Base IRI and prefixes declared by directives
+struct N3DirectiveScope {
+    base_iri: Option<Iri<String>>,
+    prefixes: HashMap<String, Iri<String>>,
+}
+
+/// Quick variables quantified in a formula when using [`QuickVarMode::Quantified`]
+struct N3VariableScope {
+    /// The random UUID used to build the variable IRIs
+    id: String,
+    variables: HashMap<String, NamedNode>,
+}
+
+impl N3VariableScope {
+    fn new() -> Self {
+        let id = BlankNode::default()
+            .as_ref()
+            .unique_id()
+            .unwrap_or_default();
+        // We set the version 4 and the RFC 4122 variant bits
+        let id = (id & !(0xF << 76) & !(0x3 << 62)) | (0x4 << 76) | (0x2 << 62);
+        Self {
+            id: format!(
+                "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+                id >> 96,
+                (id >> 80) & 0xFFFF,
+                (id >> 64) & 0xFFFF,
+                (id >> 48) & 0xFFFF,
+                id & 0xFFFF_FFFF_FFFF
+            ),
+            variables: HashMap::new(),
+        }
+    }
 }
 
 struct N3RecognizerContext {
@@ -878,6 +1119,7 @@ impl RuleRecognizer for N3Recognizer {
         self.stack.clear();
         self.terms.clear();
         self.predicates.clear();
+        // The formula scopes are restored on the next token
         self.contexts.clear();
         self
     }
@@ -892,6 +1134,8 @@ impl RuleRecognizer for N3Recognizer {
         let TokenOrLineJump::Token(token) = token else {
             return self;
         };
+        self.restore_formula_scopes(context);
+        self.variable_scopes.truncate(self.contexts.len() + 1);
         while let Some(rule) = self.stack.pop() {
             match rule {
                 // [1]  n3Doc            ::=  ( ( n3Statement ".") | sparqlDirective) *
@@ -941,13 +1185,14 @@ impl RuleRecognizer for N3Recognizer {
                     if token == N3Token::Punctuation(".") {
                         return self;
                     }
-                    errors.push("A dot is expected at the end of N3 statements".into());
+                    errors.push(RuleRecognizerError::unexpected_token("'.'", "A dot is expected at the end of N3 statements"));
                 }
                 N3State::BaseExpectIri => return if let N3Token::IriRef(iri) = token {
+                    self.save_formula_scope(context);
                     context.lexer_options.base_iri = Some(Iri::parse_unchecked(iri));
                     self
                 } else {
-                    self.error(errors, "The BASE keyword should be followed by an IRI")
+                    self.error(errors, RuleRecognizerError::unexpected_token("an IRI", "The BASE keyword should be followed by an IRI"))
                 },
                 N3State::PrefixExpectPrefix => return match token {
                     N3Token::PrefixedName { prefix, local, .. } if local.is_empty() => {
@@ -955,14 +1200,15 @@ impl RuleRecognizer for N3Recognizer {
                         self
                     }
                     _ => {
-                        self.error(errors, "The PREFIX keyword should be followed by a prefix like 'ex:'")
+                        self.error(errors, RuleRecognizerError::unexpected_token("a prefix like 'ex:'", "The PREFIX keyword should be followed by a prefix like 'ex:'"))
                     }
                 },
                 N3State::PrefixExpectIri { name } => return if let N3Token::IriRef(iri) = token {
+                    self.save_formula_scope(context);
                     context.prefixes.insert(name, Iri::parse_unchecked(iri));
                     self
                 } else {
-                    self.error(errors, "The PREFIX declaration should be followed by a prefix and its value as an IRI")
+                    self.error(errors, RuleRecognizerError::unexpected_token("an IRI", "The PREFIX declaration should be followed by a prefix and its value as an IRI"))
                 },
                 // [9]  triples  ::=  subject predicateObjectList?
                 N3State::Triples => {
@@ -1005,7 +1251,7 @@ impl RuleRecognizer for N3Recognizer {
                 N3State::ObjectsListEnd => {
                     let object = self.terms.pop().unwrap();
                     let subject = self.terms.last().unwrap().clone();
-                    results.push(match self.predicates.last().unwrap().clone() {
+                    self.emit(match self.predicates.last().unwrap().clone() {
                         Predicate::Regular(predicate) => self.quad(
                             subject,
                             predicate,
@@ -1016,7 +1262,7 @@ impl RuleRecognizer for N3Recognizer {
                             predicate,
                             subject,
                         )
-                    });
+                    }, results, errors);
                     if token == N3Token::Punctuation(",") {
                         self.stack.push(N3State::ObjectsListEnd);
                         self.stack.push(N3State::Path);
@@ -1074,7 +1320,7 @@ impl RuleRecognizer for N3Recognizer {
                         self
                     }
                     _ => {
-                        self.error(errors, "The keyword 'is' should be followed by a predicate then by the keyword 'of'")
+                        self.error(errors, RuleRecognizerError::unexpected_token("the keyword 'of'", "The keyword 'is' should be followed by a predicate then by the keyword 'of'"))
                     }
                 },
                 // [13]  subject     ::=  expression
@@ -1102,7 +1348,7 @@ impl RuleRecognizer for N3Recognizer {
                     let predicate = self.terms.pop().unwrap();
                     let previous = self.terms.pop().unwrap();
                     let current = BlankNode::default();
-                    results.push(if is_inverse { self.quad(current.clone(), predicate, previous) } else { self.quad(previous, predicate, current.clone()) });
+                    self.emit(if is_inverse { self.quad(current.clone(), predicate, previous) } else { self.quad(previous, predicate, current.clone()) }, results, errors);
                     self.terms.push(current.into());
                     self.stack.push(N3State::PathFollowUp);
                 }
@@ -1136,7 +1382,11 @@ impl RuleRecognizer for N3Recognizer {
                             self
                         }
                         N3Token::Variable(name) => {
-                            self.terms.push(Variable::new_unchecked(name).into());
+                            let term = match self.quick_var_mode {
+                                QuickVarMode::Variable => Variable::new_unchecked(name).into(),
+                                QuickVarMode::Quantified => self.quantified_variable(name.into(), results, errors).into(),
+                            };
+                            self.terms.push(term);
                             self
                         }
                         N3Token::Punctuation("[") => {
@@ -1173,11 +1423,12 @@ impl RuleRecognizer for N3Recognizer {
                         }
                         N3Token::Punctuation("{") => {
                             self.contexts.push(BlankNode::default());
+                            self.formula_scopes.push(None);
                             self.stack.push(N3State::FormulaContent);
                             self
                         }
                         _ =>
-                            self.error(errors, "TOKEN is not a valid RDF value")
+                            self.error(errors, RuleRecognizerError::unexpected_token("an RDF value", "TOKEN is not a valid RDF value"))
                     }
                 }
                 N3State::PropertyListMiddle => match token {
@@ -1198,7 +1449,7 @@ impl RuleRecognizer for N3Recognizer {
                 N3State::PropertyListEnd => if token == N3Token::Punctuation("]") {
                     return self;
                 } else {
-                    errors.push("blank node property lists should end with a ']'".into());
+                    errors.push(RuleRecognizerError::unexpected_token("']'", "blank node property lists should end with a ']'"));
                 }
                 N3State::IriPropertyList => return match token {
                     N3Token::IriRef(id) => {
@@ -1219,7 +1470,7 @@ impl RuleRecognizer for N3Recognizer {
                         }
                     }
                     _ => {
-                        self.error(errors, "The '[ id' construction should be followed by an IRI")
+                        self.error(errors, RuleRecognizerError::unexpected_token("an IRI", "The '[ id' construction should be followed by an IRI"))
                     }
                 },
                 N3State::CollectionBeginning => if let N3Token::Punctuation(")") = token {
@@ -1235,25 +1486,25 @@ impl RuleRecognizer for N3Recognizer {
                 N3State::CollectionPossibleEnd => {
                     let value = self.terms.pop().unwrap();
                     let old = self.terms.pop().unwrap();
-                    results.push(self.quad(
+                    self.emit(self.quad(
                         old.clone(),
                         rdf::FIRST,
                         value,
-                    ));
+                    ), results, errors);
                     if let N3Token::Punctuation(")") = token {
-                        results.push(self.quad(
+                        self.emit(self.quad(
                             old,
                             rdf::REST,
                             rdf::NIL,
-                        ));
+                        ), results, errors);
                         return self;
                     }
                     let new = BlankNode::default();
-                    results.push(self.quad(
+                    self.emit(self.quad(
                         old,
                         rdf::REST,
                         new.clone(),
-                    ));
+                    ), results, errors);
                     self.terms.push(new.into());
                     self.stack.push(N3State::CollectionPossibleEnd);
                     self.stack.push(N3State::Path);
@@ -1262,8 +1513,9 @@ impl RuleRecognizer for N3Recognizer {
                     match token {
                         N3Token::LangTag { language, #[cfg(feature = "rdf-12")]direction } => {
                             #[cfg(feature = "rdf-12")]
-                            if direction.is_some() {
-                                return self.error(errors, "rdf:dirLangString is not supported in N3");
+                            if let Some(direction) = direction {
+                                let literal = Literal::new_directional_language_tagged_literal_unchecked(value, language.to_ascii_lowercase(), direction);
+                                return self.error(errors, RuleRecognizerError::new(TurtleSyntaxErrorKind::InvalidLiteral { literal }, "rdf:dirLangString is not supported in N3"));
                             }
                             self.terms.push(Literal::new_language_tagged_literal_unchecked(value, language.to_ascii_lowercase()).into());
                             return self;
@@ -1293,7 +1545,7 @@ impl RuleRecognizer for N3Recognizer {
                             }
                         }
                         _ => {
-                            errors.push("Expecting a datatype IRI after '^^, found TOKEN".into());
+                            errors.push(RuleRecognizerError::unexpected_token("a datatype IRI", "Expecting a datatype IRI after '^^, found TOKEN"));
                             self.stack.clear();
                         }
                     }
@@ -1303,6 +1555,7 @@ impl RuleRecognizer for N3Recognizer {
                     match token {
                         N3Token::Punctuation("}") => {
                             self.terms.push(self.contexts.pop().unwrap().into());
+                            self.restore_formula_scopes(context);
                             return self;
                         }
                         N3Token::PlainKeyword(k)if k.eq_ignore_ascii_case("base") => {
@@ -1343,6 +1596,7 @@ impl RuleRecognizer for N3Recognizer {
                     match token {
                         N3Token::Punctuation("}") => {
                             self.terms.push(self.contexts.pop().unwrap().into());
+                            self.restore_formula_scopes(context);
                             return self;
                         }
                         N3Token::Punctuation(".") => {
@@ -1350,7 +1604,7 @@ impl RuleRecognizer for N3Recognizer {
                             return self;
                         }
                         _ => {
-                            errors.push("A dot is expected at the end of N3 statements".into());
+                            errors.push(RuleRecognizerError::unexpected_token("'.'", "A dot is expected at the end of N3 statements"));
                             self.stack.push(N3State::FormulaContent);
                         }
                     }
@@ -1367,14 +1621,19 @@ impl RuleRecognizer for N3Recognizer {
     }
 
     fn recognize_end(
-        self,
-        _state: &mut N3RecognizerContext,
+        mut self,
+        context: &mut N3RecognizerContext,
         _results: &mut Vec<Self::Output>,
         errors: &mut Vec<RuleRecognizerError>,
     ) {
+        self.contexts.clear();
+        self.restore_formula_scopes(context);
         match &*self.stack {
             [] | [N3State::N3Doc] => (),
-            _ => errors.push("Unexpected end".into()), // TODO
+            _ => errors.push(RuleRecognizerError::unclosed_construct(
+                "statement",
+                "Unexpected end",
+            )), // TODO
         }
     }
 
@@ -1388,6 +1647,9 @@ impl N3Recognizer {
         data: B,
         is_ending: bool,
         unchecked: bool,
+        limits: LexerLimits,
+        quick_var_mode: QuickVarMode,
+        formula_encoding: FormulaEncoding,
         base_iri: Option<Iri<String>>,
         prefixes: HashMap<String, Iri<String>>,
     ) -> Parser<B, Self> {
@@ -1396,8 +1658,7 @@ impl N3Recognizer {
                 N3Lexer::new(N3LexerMode::N3, unchecked),
                 data,
                 is_ending,
-                MIN_BUFFER_SIZE,
-                MAX_BUFFER_SIZE,
+                limits,
                 Some(b"#"),
             ),
             Self {
@@ -1405,6 +1666,10 @@ impl N3Recognizer {
                 terms: Vec::new(),
                 predicates: Vec::new(),
                 contexts: Vec::new(),
+                formula_scopes: Vec::new(),
+                quick_var_mode,
+                formula_encoding,
+                variable_scopes: Vec::new(),
             },
             N3RecognizerContext {
                 lexer_options: N3LexerOptions { base_iri },
@@ -1424,6 +1689,117 @@ impl N3Recognizer {
         self
     }
 
+    /// Saves the current directive scope before it is changed inside of a formula
+    fn save_formula_scope(&mut self, context: &N3RecognizerContext) {
+        if let Some(scope @ None) = self.formula_scopes.last_mut() {
+            *scope = Some(N3DirectiveScope {
+                base_iri: context.lexer_options.base_iri.clone(),
+                prefixes: context.prefixes.clone(),
+            });
+        }
+    }
+
+    /// Restores the directive scopes of the formulas that have been left
+    fn restore_formula_scopes(&mut self, context: &mut N3RecognizerContext) {
+        while self.formula_scopes.len() > self.contexts.len() {
+            if let Some(scope) = self.formula_scopes.pop().flatten() {
+                context.lexer_options.base_iri = scope.base_iri;
+                context.prefixes = scope.prefixes;
+            }
+        }
+    }
+
+    /// Returns the IRI of a quick variable, quantifying it in the parent formula if it is its first use there
+    fn quantified_variable(
+        &mut self,
+        name: String,
+        results: &mut Vec<N3Quad>,
+        errors: &mut Vec<RuleRecognizerError>,
+    ) -> NamedNode {
+        let depth = self.contexts.len().saturating_sub(1);
+        while self.variable_scopes.len() <= depth {
+            self.variable_scopes.push(N3VariableScope::new());
+        }
+        let scope = &mut self.variable_scopes[depth];
+        if let Some(variable) = scope.variables.get(&name) {
+            return variable.clone();
+        }
+        let variable = NamedNode::new_unchecked(format!("urn:uuid:{}#{name}", scope.id));
+        let (formula, graph_name) = if depth == 0 {
+            (
+                NamedNode::new_unchecked(format!("urn:uuid:{}", scope.id)).into(),
+                GraphName::DefaultGraph,
+            )
+        } else {
+            let formula = self.contexts[depth - 1].clone();
+            (formula.clone().into(), formula.into())
+        };
+        scope.variables.insert(name, variable.clone());
+        self.emit(
+            N3Quad {
+                subject: formula,
+                predicate: NamedNode::new_unchecked(LOG_FOR_ALL).into(),
+                object: variable.clone().into(),
+                graph_name,
+            },
+            results,
+            errors,
+        );
+        variable
+    }
+
+    /// Outputs the quad, encoding it following [`FormulaEncoding`] if it is inside of a formula
+    #[cfg_attr(not(feature = "rdf-12"), expect(unused_variables, clippy::ptr_arg))]
+    fn emit(&self, quad: N3Quad, results: &mut Vec<N3Quad>, errors: &mut Vec<RuleRecognizerError>) {
+        let GraphName::BlankNode(formula) = &quad.graph_name else {
+            results.push(quad);
+            return;
+        };
+        match self.formula_encoding {
+            FormulaEncoding::GraphName => results.push(quad),
+            #[cfg(feature = "rdf-12")]
+            FormulaEncoding::RdfStarQuoted => {
+                let Some(triple) = triple_term(&quad) else {
+                    errors.push(
+                        format!(
+                            "The formula triple {} {} {} can't be encoded as a triple term",
+                            quad.subject, quad.predicate, quad.object
+                        )
+                        .into(),
+                    );
+                    return;
+                };
+                results.push(N3Quad {
+                    subject: formula.clone().into(),
+                    predicate: rdf::REIFIES.into(),
+                    object: triple.into(),
+                    graph_name: GraphName::DefaultGraph,
+                });
+            }
+            FormulaEncoding::Reification => {
+                let statement = BlankNode::default();
+                for (subject, predicate, object) in [
+                    (
+                        formula.clone().into(),
+                        rdfs::MEMBER,
+                        statement.clone().into(),
+                    ),
+                    (statement.clone().into(), rdf::TYPE, rdf::STATEMENT.into()),
+                    (statement.clone().into(), rdf::SUBJECT, quad.subject),
+                    (statement.clone().into(), rdf::PREDICATE, quad.predicate),
+                    (statement.into(), rdf::OBJECT, quad.object),
+                ] {
+                    results.push(N3Quad {
+                        subject,
+                        predicate: predicate.into(),
+                        object,
+                        graph_name: GraphName::DefaultGraph,
+                    });
+                }
+            }
+        }
+    }
+
     fn quad(
         &self,
         subject: impl Into<N3Term>,
@@ -1442,6 +1818,27 @@ impl N3Recognizer {
     }
 }
 
+/// Returns `None` if the quad contains a variable or a literal subject
+#[cfg(feature = "rdf-12")]
+fn triple_term(quad: &N3Quad) -> Option<Triple> {
+    let subject = match &quad.subject {
+        N3Term::NamedNode(n) => NamedOrBlankNode::from(n.clone()),
+        N3Term::BlankNode(n) => n.clone().into(),
+        _ => return None,
+    };
+    let N3Term::NamedNode(predicate) = &quad.predicate else {
+        return None;
+    };
+    let object = match &quad.object {
+        N3Term::NamedNode(n) => Term::from(n.clone()),
+        N3Term::BlankNode(n) => n.clone().into(),
+        N3Term::Literal(l) => l.clone().into(),
+        N3Term::Triple(t) => Term::Triple(t.clone()),
+        N3Term::Variable(_) => return None,
+    };
+    Some(Triple::new(subject, predicate.clone(), object))
+}
+
 #[derive(Debug)]
 enum N3State {
     N3Doc,
@@ -1497,3 +1894,327 @@ impl<'a> Iterator for N3PrefixesIter<'a> {
         self.inner.size_hint()
     }
 }
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use crate::{TurtleParser, TurtleSyntaxErrorKind};
+    use oxrdf::{Dataset, GraphNameRef, QuadRef};
+    use std::error::Error;
+
+    #[test]
+    fn error_kinds() {
+        let error = N3Parser::new()
+            .for_slice("ex:s ex:p ex:o .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UndefinedPrefix { prefix } if prefix == "ex"
+        ));
+
+        let error = N3Parser::new()
+            .for_slice("<http://example.com/s> <http://example.com/p> \"a\"^^\"b\" .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnexpectedToken { found, expected } if found == "\"b\"" && expected == "a datatype IRI"
+        ));
+    }
+
+    #[test]
+    fn prefixes_at_the_current_parsing_step() -> Result<(), Box<dyn Error>> {
+        let file = "@prefix ex: <http://example.com/> .\nex:s ex:p ex:o .\n@prefix ex: <http://example.org/> .\n@base <http://example.net/> .\nex:s ex:p <o> .";
+        let mut parser = N3Parser::new().for_slice(file);
+        assert_eq!(parser.prefixes().count(), 0);
+        parser.next().unwrap()?;
+        assert_eq!(
+            parser.prefixes().collect::<Vec<_>>(),
+            [("ex", "http://example.com/")]
+        );
+        assert_eq!(parser.base_iri(), None);
+        parser.next().unwrap()?;
+        assert_eq!(
+            parser.prefixes().collect::<Vec<_>>(),
+            [("ex", "http://example.org/")]
+        );
+        assert_eq!(parser.base_iri(), Some("http://example.net/"));
+
+        let mut parser = N3Parser::new().low_level();
+        parser.extend_from_slice(file.as_bytes());
+        parser.end();
+        parser.parse_next().unwrap()?;
+        assert_eq!(
+            parser.prefixes().collect::<Vec<_>>(),
+            [("ex", "http://example.com/")]
+        );
+        Ok(())
+    }
+
+    fn parse(file: &str) -> Result<Dataset, Box<dyn Error>> {
+        parse_with(N3Parser::new(), file)
+    }
+
+    fn parse_with(parser: N3Parser, file: &str) -> Result<Dataset, Box<dyn Error>> {
+        let mut dataset = Dataset::new();
+        for quad in parser.for_slice(file) {
+            let quad = quad?;
+            let subject = match quad.subject {
+                N3Term::NamedNode(n) => NamedOrBlankNode::from(n),
+                N3Term::BlankNode(n) => n.into(),
+                _ => return Err("Unexpected subject".into()),
+            };
+            let N3Term::NamedNode(predicate) = quad.predicate else {
+                return Err("Unexpected predicate".into());
+            };
+            let object = match quad.object {
+                N3Term::NamedNode(n) => Term::from(n),
+                N3Term::BlankNode(n) => n.into(),
+                N3Term::Literal(l) => l.into(),
+                #[cfg(feature = "rdf-12")]
+                N3Term::Triple(t) => Term::Triple(t),
+                N3Term::Variable(_) => return Err("Unexpected object".into()),
+            };
+            dataset.insert(QuadRef::new(
+                &subject,
+                &predicate,
+                &object,
+                &quad.graph_name,
+            ));
+        }
+        Ok(dataset)
+    }
+
+    #[test]
+    fn test_formulas_isomorphism() -> Result<(), Box<dyn Error>> {
+        let rule = parse(
+            "@prefix : <http://example.com/> .
+            { _:x :p :o . _:x :q [ :r 1 ] } => { _:x :s { :a :b _:y } } .",
+        )?;
+        let same_rule = parse(
+            "@prefix : <http://example.com/> .
+            { _:a :q _:b . _:a :p :o . _:b :r 1 } => { _:a :s { :a :b [] } } .",
+        )?;
+        assert_ne!(rule, same_rule);
+        assert!(rule.is_isomorphic_to(&same_rule));
+
+        // The two formulas are swapped
+        let swapped_rule = parse(
+            "@prefix : <http://example.com/> .
+            { _:x :s { :a :b _:y } } => { _:x :p :o . _:x :q [ :r 1 ] } .",
+        )?;
+        assert!(!rule.is_isomorphic_to(&swapped_rule));
+
+        // Two different blank nodes instead of one
+        let split_rule = parse(
+            "@prefix : <http://example.com/> .
+            { _:x :p :o . _:z :q [ :r 1 ] } => { _:x :s { :a :b _:y } } .",
+        )?;
+        assert!(!rule.is_isomorphic_to(&split_rule));
+
+        // A different literal deep inside a formula
+        let other_literal_rule = parse(
+            "@prefix : <http://example.com/> .
+            { _:x :p :o . _:x :q [ :r 2 ] } => { _:x :s { :a :b _:y } } .",
+        )?;
+        assert!(!rule.is_isomorphic_to(&other_literal_rule));
+        Ok(())
+    }
+
+    fn parse_turtle(file: &str) -> Result<Dataset, Box<dyn Error>> {
+        let mut dataset = Dataset::new();
+        for triple in TurtleParser::new().for_slice(file) {
+            dataset.insert(triple?.as_ref().in_graph(GraphNameRef::DefaultGraph));
+        }
+        Ok(dataset)
+    }
+
+    const NESTED_FORMULAS: &str = "@prefix : <http://example.com/> .
+        { { :c :d :e } :b :a } => { :f :g { :h :i :j } } .";
+
+    #[test]
+    fn test_formulas_as_reifications() -> Result<(), Box<dyn Error>> {
+        let dataset = parse_with(
+            N3Parser::new().with_formula_encoding(FormulaEncoding::Reification),
+            NESTED_FORMULAS,
+        )?;
+        let expected = parse_turtle(
+                "@prefix : <http://example.com/> .
+                @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+                @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+                _:f1 <http://www.w3.org/2000/10/swap/log#implies> _:f2 .
+                _:f1 rdfs:member [ a rdf:Statement ; rdf:subject _:g1 ; rdf:predicate :b ; rdf:object :a ] .
+                _:g1 rdfs:member [ a rdf:Statement ; rdf:subject :c ; rdf:predicate :d ; rdf:object :e ] .
+                _:f2 rdfs:member [ a rdf:Statement ; rdf:subject :f ; rdf:predicate :g ; rdf:object _:g2 ] .
+                _:g2 rdfs:member [ a rdf:Statement ; rdf:subject :h ; rdf:predicate :i ; rdf:object :j ] .",
+        )?;
+        assert!(
+            dataset.is_isomorphic_to(&expected),
+            "{dataset} is not isomorphic to {expected}"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "rdf-12")]
+    #[test]
+    fn test_formulas_as_triple_terms() -> Result<(), Box<dyn Error>> {
+        let dataset = parse_with(
+            N3Parser::new().with_formula_encoding(FormulaEncoding::RdfStarQuoted),
+            NESTED_FORMULAS,
+        )?;
+        let expected = parse_turtle(
+            "@prefix : <http://example.com/> .
+                @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+                _:f1 <http://www.w3.org/2000/10/swap/log#implies> _:f2 .
+                _:f1 rdf:reifies <<( _:g1 :b :a )>> .
+                _:g1 rdf:reifies <<( :c :d :e )>> .
+                _:f2 rdf:reifies <<( :f :g _:g2 )>> .
+                _:g2 rdf:reifies <<( :h :i :j )>> .",
+        )?;
+        assert!(
+            dataset.is_isomorphic_to(&expected),
+            "{dataset} is not isomorphic to {expected}"
+        );
+
+        // Variables are not allowed in triple terms
+        assert!(
+            N3Parser::new()
+                .with_formula_encoding(FormulaEncoding::RdfStarQuoted)
+                .for_slice("{ ?x a ?y } => { ?x a ?y } .")
+                .any(|q| q.is_err())
+        );
+        Ok(())
+    }
+
+    fn parse_quantified(file: &str) -> Result<Vec<N3Quad>, Box<dyn Error>> {
+        Ok(N3Parser::new()
+            .with_quick_variable_handling(QuickVarMode::Quantified)
+            .for_slice(file)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn quantifications(quads: &[N3Quad]) -> Vec<&N3Quad> {
+        let for_all = N3Term::NamedNode(NamedNode::new_unchecked(LOG_FOR_ALL));
+        quads.iter().filter(|q| q.predicate == for_all).collect()
+    }
+
+    #[test]
+    fn test_quick_variables_as_variables() -> Result<(), Box<dyn Error>> {
+        let quads = N3Parser::new()
+            .for_slice("{ ?x <http://example.com/p> ?y } => { ?x <http://example.com/q> ?y } .")
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(quads.len(), 3);
+        assert_eq!(quads[0].subject, Variable::new_unchecked("x").into());
+        assert_eq!(quads[1].object, Variable::new_unchecked("y").into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_quick_variables_in_sibling_formulas() -> Result<(), Box<dyn Error>> {
+        let quads = parse_quantified(
+            "@prefix : <http://example.com/> .
+            { ?x :p ?y } => { ?x :q ?y . ?z :r ?x } .",
+        )?;
+        let quantifications = quantifications(&quads);
+        // ?x, ?y and ?z are quantified once in the document
+        assert_eq!(quantifications.len(), 3);
+        let document = &quantifications[0].subject;
+        let N3Term::NamedNode(document_iri) = document else {
+            return Err("The document should be identified by an IRI".into());
+        };
+        assert!(document_iri.as_str().starts_with("urn:uuid:"));
+        for quantification in &quantifications {
+            assert_eq!(&quantification.subject, document);
+            assert_eq!(quantification.graph_name, GraphName::DefaultGraph);
+        }
+        let x = &quantifications[0].object;
+        let y = &quantifications[1].object;
+        assert_eq!(
+            *x,
+            N3Term::NamedNode(NamedNode::new(format!("{}#x", document_iri.as_str()))?)
+        );
+        let rule = quads
+            .iter()
+            .find(|q| {
+                q.graph_name.is_default_graph() && q.predicate != quantifications[0].predicate
+            })
+            .ok_or("No rule triple")?;
+        let (N3Term::BlankNode(premise), N3Term::BlankNode(conclusion)) =
+            (&rule.subject, &rule.object)
+        else {
+            return Err("The rule should link two formulas".into());
+        };
+        let in_premise = quads
+            .iter()
+            .filter(|q| q.graph_name == premise.clone().into())
+            .collect::<Vec<_>>();
+        let in_conclusion = quads
+            .iter()
+            .filter(|q| q.graph_name == conclusion.clone().into())
+            .collect::<Vec<_>>();
+        assert_eq!(in_premise.len(), 1);
+        assert_eq!(in_conclusion.len(), 2);
+        assert_eq!(&in_premise[0].subject, x);
+        assert_eq!(&in_premise[0].object, y);
+        assert_eq!(&in_conclusion[0].subject, x);
+        assert_eq!(&in_conclusion[0].object, y);
+        assert_eq!(&in_conclusion[1].object, x);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quick_variables_in_nested_formulas() -> Result<(), Box<dyn Error>> {
+        let quads = parse_quantified(
+            "@prefix : <http://example.com/> .
+            :a :says { { ?x :p :o } => { ?x :q :o } } .
+            :b :says { { ?x :p :o } => { ?x :q :o } } .
+            ?x :r :o .",
+        )?;
+        let quantifications = quantifications(&quads);
+        assert_eq!(quantifications.len(), 3);
+        // The variables used inside of the rules are quantified in the formula containing the rule
+        for quantification in &quantifications[..2] {
+            let N3Term::BlankNode(formula) = &quantification.subject else {
+                return Err("The quantification should be on a formula".into());
+            };
+            assert_eq!(quantification.graph_name, formula.clone().into());
+            assert!(
+                quads
+                    .iter()
+                    .any(|q| q.object == quantification.subject && q.graph_name.is_default_graph())
+            );
+            assert_eq!(
+                quads
+                    .iter()
+                    .filter(|q| q.subject == quantification.object)
+                    .count(),
+                2
+            );
+        }
+        // The two sibling formulas and the document have their own ?x
+        assert_ne!(quantifications[0].subject, quantifications[1].subject);
+        assert_ne!(quantifications[0].object, quantifications[1].object);
+        assert_ne!(quantifications[0].object, quantifications[2].object);
+        assert_ne!(quantifications[1].object, quantifications[2].object);
+        assert_eq!(quantifications[2].graph_name, GraphName::DefaultGraph);
+        assert_eq!(
+            quads.last().ok_or("No quad")?.subject,
+            quantifications[2].object
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_quick_variables_after_formula_end() -> Result<(), Box<dyn Error>> {
+        // Leaving a formula should not forget the variables quantified in the document
+        let quads = parse_quantified(
+            "@prefix : <http://example.com/> .
+            { ?x :p :o } => { ?y :q :o } .
+            { ?x :p :o } => { ?y :q :o } .",
+        )?;
+        assert_eq!(quantifications(&quads).len(), 2);
+        Ok(())
+    }
+}
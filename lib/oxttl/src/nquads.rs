@@ -1,16 +1,20 @@
 //! A [N-Quads](https://www.w3.org/TR/n-quads/) streaming parser implemented by [`NQuadsParser`]
 //! and a serializer implemented by [`NQuadsSerializer`].
 
-use crate::MIN_PARALLEL_CHUNK_SIZE;
 use crate::chunker::{get_ntriples_file_chunks, get_ntriples_slice_chunks};
 use crate::line_formats::NQuadsRecognizer;
 #[cfg(feature = "async-tokio")]
 use crate::toolkit::TokioAsyncReaderIterator;
-use crate::toolkit::{Parser, ReaderIterator, SliceIterator, TurtleParseError, TurtleSyntaxError};
-use oxrdf::{Quad, QuadRef};
+use crate::toolkit::{
+    LexerLimits, LowLevelParserState, Parser, ReaderIterator, SliceIterator, TurtleParseError,
+    TurtleSyntaxError,
+};
+use crate::{LiteralMapper, MIN_PARALLEL_CHUNK_SIZE};
+use oxrdf::{Literal, Quad, QuadRef};
 use std::fs::File;
 use std::io::{self, Read, Seek, SeekFrom, Take, Write};
 use std::path::Path;
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 
@@ -41,6 +45,10 @@ use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 #[must_use]
 pub struct NQuadsParser {
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
+    limits: LexerLimits,
 }
 
 impl NQuadsParser {
@@ -67,6 +75,103 @@ impl NQuadsParser {
         self.lenient()
     }
 
+    /// Checks that the lexical forms of the literals with a recognized XSD datatype like `xsd:integer` are valid.
+    ///
+    /// An invalid literal like `"abc"^^xsd:integer` is reported as a syntax error located on its datatype.
+    /// See [`Literal::parsed_value`](oxrdf::Literal::parsed_value) for the recognized datatypes.
+    ///
+    /// ```
+    /// use oxttl::NQuadsParser;
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "abc"^^<http://www.w3.org/2001/XMLSchema#integer> ."#;
+    ///
+    /// let mut triples = NQuadsParser::new().with_literal_validation().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 53);
+    /// ```
+    #[inline]
+    pub fn with_literal_validation(mut self) -> Self {
+        self.validate_literals = true;
+        self
+    }
+
+    /// Rejects the RDF 1.2 triple terms `<<( )>>`.
+    ///
+    /// They are reported as a syntax error located on their first token.
+    /// It allows to make sure the output is plain RDF 1.1 even if the `rdf-12` feature is enabled.
+    ///
+    /// ```
+    /// use oxttl::NQuadsParser;
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <<( <http://example.com/a> <http://example.com/b> <http://example.com/c> )>> .";
+    ///
+    /// let mut triples = NQuadsParser::new().without_triple_terms().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 46);
+    /// ```
+    #[inline]
+    pub fn without_triple_terms(mut self) -> Self {
+        self.reject_triple_terms = true;
+        self
+    }
+
+    /// Calls `mapper` on each parsed literal, including the ones in triple terms, before returning the triple.
+    ///
+    /// The literal is replaced by the returned one.
+    /// An error message is reported as a syntax error located on the literal last token
+    /// (the literal itself, its language tag or its datatype) or, for literals without language tag and datatype, on the token following them.
+    /// The rest of the statement is skipped like after any other syntax error.
+    /// [`with_literal_validation`](Self::with_literal_validation) checks the literals returned by `mapper`.
+    ///
+    /// ```
+    /// use oxttl::NQuadsParser;
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "chat"@fr <http://example.com/g> ."#;
+    ///
+    /// let mut quads = NQuadsParser::new()
+    ///     .with_literal_mapper(|literal| {
+    ///         if literal.language().is_none_or(|l| l == "en") {
+    ///             Ok(literal)
+    ///         } else {
+    ///             Err("Only English literals are allowed".into())
+    ///         }
+    ///     })
+    ///     .for_slice(file);
+    /// let error = quads.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 52);
+    /// ```
+    #[inline]
+    pub fn with_literal_mapper(
+        mut self,
+        mapper: impl Fn(Literal) -> Result<Literal, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.literal_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered.
+    /// There is no limit by default.
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.limits.max_token_length = Some(max_term_length);
+        self
+    }
+
+    /// Sets how many bytes are read at once from the input (4KiB by default)
+    /// and the maximal size of the parser buffer (16MiB by default).
+    ///
+    /// Parsing from a reader fails if the buffer would need to grow beyond its maximal size
+    /// and the low-level parser stops buffering new bytes when it is full.
+    #[inline]
+    pub fn with_buffer_sizes(mut self, min_buffer_size: usize, max_buffer_size: usize) -> Self {
+        self.limits.min_buffer_size = min_buffer_size;
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+
     /// Parses a N-Quads file from a [`Read`] implementation.
     ///
     /// Count the number of people:
@@ -158,8 +263,17 @@ impl NQuadsParser {
     /// ```
     pub fn for_slice(self, slice: &(impl AsRef<[u8]> + ?Sized)) -> SliceNQuadsParser<'_> {
         SliceNQuadsParser {
-            inner: NQuadsRecognizer::new_parser(slice.as_ref(), true, true, self.lenient)
-                .into_iter(),
+            inner: NQuadsRecognizer::new_parser(
+                slice.as_ref(),
+                true,
+                true,
+                self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
+            )
+            .into_iter(),
         }
     }
 
@@ -305,7 +419,16 @@ impl NQuadsParser {
     /// ```
     pub fn low_level(self) -> LowLevelNQuadsParser {
         LowLevelNQuadsParser {
-            parser: NQuadsRecognizer::new_parser(Vec::new(), false, true, self.lenient),
+            parser: NQuadsRecognizer::new_parser(
+                Vec::new(),
+                false,
+                true,
+                self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
+            ),
         }
     }
 }
@@ -473,7 +596,12 @@ pub struct LowLevelNQuadsParser {
 
 impl LowLevelNQuadsParser {
     /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    ///
+    /// Returns the number of bytes of `other` that have been buffered.
+    /// It is lower than the length of `other` if the buffer has reached its [maximal size](Self::max_buffer_size):
+    /// the remaining bytes should be provided again after having called [`parse_next`](Self::parse_next).
+    /// If no byte is buffered while the parser [needs input](LowLevelParserState::NeedsInput), a term is longer than the maximal buffer size and the parsing cannot go on.
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.parser.extend_from_slice(other)
     }
 
@@ -496,6 +624,54 @@ impl LowLevelNQuadsParser {
     pub fn parse_next(&mut self) -> Option<Result<Quad, TurtleSyntaxError>> {
         self.parser.parse_next()
     }
+
+    /// What the parser is waiting for.
+    ///
+    /// It allows to drive the parser from an event loop with back-pressure:
+    /// ```
+    /// use oxttl::{LowLevelParserState, NQuadsParser};
+    ///
+    /// let mut file = b"<http://example.com/s> <http://example.com/p> <http://example.com/o1> .
+    /// <http://example.com/s> <http://example.com/p> <http://example.com/o2> .".as_slice();
+    /// let mut parser = NQuadsParser::new().with_buffer_sizes(32, 64).low_level();
+    /// let mut count = 0;
+    /// loop {
+    ///     match parser.state() {
+    ///         LowLevelParserState::NeedsInput => {
+    ///             if file.is_empty() {
+    ///                 parser.end();
+    ///             } else {
+    ///                 // The parser only buffers the bytes it has room for
+    ///                 let buffered = parser.extend_from_slice(file);
+    ///                 file = &file[buffered..];
+    ///             }
+    ///         }
+    ///         LowLevelParserState::HasPendingOutput => {
+    ///             if let Some(quad) = parser.parse_next() {
+    ///                 quad?;
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///         LowLevelParserState::Finished => break,
+    ///     }
+    ///     assert!(parser.buffered_len() <= parser.max_buffer_size());
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn state(&self) -> LowLevelParserState {
+        self.parser.state()
+    }
+
+    /// The number of bytes provided with [`extend_from_slice`](Self::extend_from_slice) that have not been parsed yet.
+    pub fn buffered_len(&self) -> usize {
+        self.parser.buffered_len()
+    }
+
+    /// The maximal number of bytes the parser buffers, set with `with_buffer_sizes`.
+    pub fn max_buffer_size(&self) -> usize {
+        self.parser.max_buffer_size()
+    }
 }
 
 /// A [N-Quads](https://www.w3.org/TR/n-quads/) serializer.
@@ -1,16 +1,22 @@
 //! A [N-Triples](https://www.w3.org/TR/n-triples/) streaming parser implemented by [`NTriplesParser`]
 //! and a serializer implemented by [`NTriplesSerializer`].
 
-use crate::MIN_PARALLEL_CHUNK_SIZE;
 use crate::chunker::{get_ntriples_file_chunks, get_ntriples_slice_chunks};
 use crate::line_formats::NQuadsRecognizer;
 #[cfg(feature = "async-tokio")]
 use crate::toolkit::TokioAsyncReaderIterator;
-use crate::toolkit::{Parser, ReaderIterator, SliceIterator, TurtleParseError, TurtleSyntaxError};
-use oxrdf::{Triple, TripleRef};
+use crate::toolkit::{
+    LexerLimits, LineSpan, LineSplitter, LowLevelParserState, Parser, ReaderIterator,
+    SliceIterator, TextPosition, TurtleParseError, TurtleSyntaxError, TurtleSyntaxErrorKind,
+};
+use crate::{LiteralMapper, MIN_PARALLEL_CHUNK_SIZE};
+use oxrdf::{Literal, Quad, Triple, TripleRef};
 use std::fs::File;
 use std::io::{self, Read, Seek, SeekFrom, Take, Write};
+use std::ops::Range;
 use std::path::Path;
+use std::str;
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 
@@ -41,6 +47,10 @@ use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 #[must_use]
 pub struct NTriplesParser {
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
+    limits: LexerLimits,
 }
 
 impl NTriplesParser {
@@ -67,6 +77,103 @@ impl NTriplesParser {
         self.lenient()
     }
 
+    /// Checks that the lexical forms of the literals with a recognized XSD datatype like `xsd:integer` are valid.
+    ///
+    /// An invalid literal like `"abc"^^xsd:integer` is reported as a syntax error located on its datatype.
+    /// See [`Literal::parsed_value`](oxrdf::Literal::parsed_value) for the recognized datatypes.
+    ///
+    /// ```
+    /// use oxttl::NTriplesParser;
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "abc"^^<http://www.w3.org/2001/XMLSchema#integer> ."#;
+    ///
+    /// let mut triples = NTriplesParser::new().with_literal_validation().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 53);
+    /// ```
+    #[inline]
+    pub fn with_literal_validation(mut self) -> Self {
+        self.validate_literals = true;
+        self
+    }
+
+    /// Rejects the RDF 1.2 triple terms `<<( )>>`.
+    ///
+    /// They are reported as a syntax error located on their first token.
+    /// It allows to make sure the output is plain RDF 1.1 even if the `rdf-12` feature is enabled.
+    ///
+    /// ```
+    /// use oxttl::NTriplesParser;
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <<( <http://example.com/a> <http://example.com/b> <http://example.com/c> )>> .";
+    ///
+    /// let mut triples = NTriplesParser::new().without_triple_terms().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 46);
+    /// ```
+    #[inline]
+    pub fn without_triple_terms(mut self) -> Self {
+        self.reject_triple_terms = true;
+        self
+    }
+
+    /// Calls `mapper` on each parsed literal, including the ones in triple terms, before returning the triple.
+    ///
+    /// The literal is replaced by the returned one.
+    /// An error message is reported as a syntax error located on the literal last token
+    /// (the literal itself, its language tag or its datatype) or, for literals without language tag and datatype, on the token following them.
+    /// The rest of the statement is skipped like after any other syntax error.
+    /// [`with_literal_validation`](Self::with_literal_validation) checks the literals returned by `mapper`.
+    ///
+    /// ```
+    /// use oxttl::NTriplesParser;
+    ///
+    /// let file = r#"<http://example.com/s> <http://example.com/p> "1"^^<http://example.com/int> ."#;
+    ///
+    /// let mut triples = NTriplesParser::new()
+    ///     .with_literal_mapper(|literal| {
+    ///         if literal.datatype().as_str().starts_with("http://www.w3.org/2001/XMLSchema#") {
+    ///             Ok(literal)
+    ///         } else {
+    ///             Err(format!("Unsupported datatype {}", literal.datatype()))
+    ///         }
+    ///     })
+    ///     .for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 51);
+    /// ```
+    #[inline]
+    pub fn with_literal_mapper(
+        mut self,
+        mapper: impl Fn(Literal) -> Result<Literal, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.literal_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered.
+    /// There is no limit by default.
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.limits.max_token_length = Some(max_term_length);
+        self
+    }
+
+    /// Sets how many bytes are read at once from the input (4KiB by default)
+    /// and the maximal size of the parser buffer (16MiB by default).
+    ///
+    /// Parsing from a reader fails if the buffer would need to grow beyond its maximal size
+    /// and the low-level parser stops buffering new bytes when it is full.
+    #[inline]
+    pub fn with_buffer_sizes(mut self, min_buffer_size: usize, max_buffer_size: usize) -> Self {
+        self.limits.min_buffer_size = min_buffer_size;
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+
     /// Parses a N-Triples file from a [`Read`] implementation.
     ///
     /// Count the number of people:
@@ -158,8 +265,17 @@ impl NTriplesParser {
     /// ```
     pub fn for_slice(self, slice: &(impl AsRef<[u8]> + ?Sized)) -> SliceNTriplesParser<'_> {
         SliceNTriplesParser {
-            inner: NQuadsRecognizer::new_parser(slice.as_ref(), true, false, self.lenient)
-                .into_iter(),
+            inner: NQuadsRecognizer::new_parser(
+                slice.as_ref(),
+                true,
+                false,
+                self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
+            )
+            .into_iter(),
         }
     }
 
@@ -305,7 +421,61 @@ impl NTriplesParser {
     /// ```
     pub fn low_level(self) -> LowLevelNTriplesParser {
         LowLevelNTriplesParser {
-            parser: NQuadsRecognizer::new_parser(Vec::new(), false, false, self.lenient),
+            parser: NQuadsRecognizer::new_parser(
+                Vec::new(),
+                false,
+                false,
+                self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
+            ),
+        }
+    }
+
+    /// Parses a N-Triples file from a [`Read`] implementation, line by line, without losing any information.
+    ///
+    /// Comments and blank lines are returned alongside the triples
+    /// and the exact bytes of each line are available using [`ReaderNTriplesLosslessParser::original_bytes`].
+    /// It allows rewriting some triples of a file while keeping all the other lines untouched.
+    ///
+    /// Replace the name of `foo`:
+    /// ```
+    /// use oxrdf::{Literal, Quad, TripleRef};
+    /// use oxttl::ntriples::Line;
+    /// use oxttl::{NTriplesParser, NTriplesSerializer};
+    ///
+    /// let file = "# People\n<http://example.com/foo> <http://schema.org/name> \"Foo\" .\n\n<http://example.com/bar>  <http://schema.org/name>  \"Bar\" .\n";
+    ///
+    /// let mut output = Vec::new();
+    /// let mut serializer = NTriplesSerializer::new().low_level();
+    /// let mut parser = NTriplesParser::new().for_reader_lossless(file.as_bytes());
+    /// while let Some(line) = parser.next() {
+    ///     if let Line::Quad { quad, .. } = line? {
+    ///         if quad.object == Literal::from("Foo").into() {
+    ///             let quad = Quad { object: Literal::from("Foo Foo").into(), ..quad };
+    ///             serializer.serialize_triple(TripleRef::from(quad.as_ref()), &mut output)?;
+    ///             continue;
+    ///         }
+    ///     }
+    ///     output.extend_from_slice(parser.original_bytes());
+    /// }
+    /// assert_eq!(
+    ///     output,
+    ///     b"# People\n<http://example.com/foo> <http://schema.org/name> \"Foo Foo\" .\n\n<http://example.com/bar>  <http://schema.org/name>  \"Bar\" .\n"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn for_reader_lossless<R: Read>(self, reader: R) -> ReaderNTriplesLosslessParser<R> {
+        ReaderNTriplesLosslessParser {
+            reader,
+            lines: LineSplitter::new(Vec::new(), false, self.limits),
+            lenient: self.lenient,
+            validate_literals: self.validate_literals,
+            reject_triple_terms: self.reject_triple_terms,
+            literal_mapper: self.literal_mapper.clone(),
+            limits: self.limits,
         }
     }
 }
@@ -473,7 +643,12 @@ pub struct LowLevelNTriplesParser {
 
 impl LowLevelNTriplesParser {
     /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    ///
+    /// Returns the number of bytes of `other` that have been buffered.
+    /// It is lower than the length of `other` if the buffer has reached its [maximal size](Self::max_buffer_size):
+    /// the remaining bytes should be provided again after having called [`parse_next`](Self::parse_next).
+    /// If no byte is buffered while the parser [needs input](LowLevelParserState::NeedsInput), a term is longer than the maximal buffer size and the parsing cannot go on.
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.parser.extend_from_slice(other)
     }
 
@@ -496,6 +671,185 @@ impl LowLevelNTriplesParser {
     pub fn parse_next(&mut self) -> Option<Result<Triple, TurtleSyntaxError>> {
         Some(self.parser.parse_next()?.map(Into::into))
     }
+
+    /// What the parser is waiting for.
+    ///
+    /// It allows to drive the parser from an event loop with back-pressure:
+    /// ```
+    /// use oxttl::{LowLevelParserState, NTriplesParser};
+    ///
+    /// let mut file = b"<http://example.com/s> <http://example.com/p> <http://example.com/o1> .
+    /// <http://example.com/s> <http://example.com/p> <http://example.com/o2> .".as_slice();
+    /// let mut parser = NTriplesParser::new().with_buffer_sizes(32, 64).low_level();
+    /// let mut count = 0;
+    /// loop {
+    ///     match parser.state() {
+    ///         LowLevelParserState::NeedsInput => {
+    ///             if file.is_empty() {
+    ///                 parser.end();
+    ///             } else {
+    ///                 // The parser only buffers the bytes it has room for
+    ///                 let buffered = parser.extend_from_slice(file);
+    ///                 file = &file[buffered..];
+    ///             }
+    ///         }
+    ///         LowLevelParserState::HasPendingOutput => {
+    ///             if let Some(triple) = parser.parse_next() {
+    ///                 triple?;
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///         LowLevelParserState::Finished => break,
+    ///     }
+    ///     assert!(parser.buffered_len() <= parser.max_buffer_size());
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn state(&self) -> LowLevelParserState {
+        self.parser.state()
+    }
+
+    /// The number of bytes provided with [`extend_from_slice`](Self::extend_from_slice) that have not been parsed yet.
+    pub fn buffered_len(&self) -> usize {
+        self.parser.buffered_len()
+    }
+
+    /// The maximal number of bytes the parser buffers, set with `with_buffer_sizes`.
+    pub fn max_buffer_size(&self) -> usize {
+        self.parser.max_buffer_size()
+    }
+}
+
+/// A line of a N-Triples file returned by [`ReaderNTriplesLosslessParser`].
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Line {
+    /// A line containing a triple, possibly followed by a comment.
+    ///
+    /// The triple is returned as a [`Quad`] in the default graph.
+    Quad {
+        quad: Quad,
+        /// The byte range of the line in the file, including its line terminator
+        original_bytes_span: Range<u64>,
+    },
+    /// A line containing only a comment, with the comment text after `#` and without the line terminator.
+    Comment(String),
+    /// A line containing only spaces and tabulations.
+    Blank,
+}
+
+/// Parses a N-Triples file from a [`Read`] implementation line by line, keeping comments and blank lines.
+///
+/// Can be built using [`NTriplesParser::for_reader_lossless`].
+///
+/// Writing back the [`original_bytes`](Self::original_bytes) of each line reproduces the input exactly:
+/// ```
+/// use oxttl::NTriplesParser;
+///
+/// let file = "<http://example.com/s>\t<http://example.com/p> <http://example.com/o>.  # a comment\r\n   \n#another comment";
+///
+/// let mut output = Vec::new();
+/// let mut parser = NTriplesParser::new().for_reader_lossless(file.as_bytes());
+/// while let Some(line) = parser.next() {
+///     line?;
+///     output.extend_from_slice(parser.original_bytes());
+/// }
+/// assert_eq!(output, file.as_bytes());
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[must_use]
+pub struct ReaderNTriplesLosslessParser<R: Read> {
+    reader: R,
+    lines: LineSplitter<Vec<u8>>,
+    lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
+    limits: LexerLimits,
+}
+
+impl<R: Read> ReaderNTriplesLosslessParser<R> {
+    /// The exact bytes of the last line returned by [`next`](Iterator::next), including its line terminator.
+    ///
+    /// It is also available if the line has not been parsed successfully.
+    pub fn original_bytes(&self) -> &[u8] {
+        self.lines.last_line()
+    }
+
+    fn parse_line(&self, span: LineSpan) -> Option<Result<Line, TurtleSyntaxError>> {
+        let bytes = self.lines.last_line();
+        let content = bytes
+            .strip_suffix(b"\r\n")
+            .or_else(|| bytes.strip_suffix(b"\n"))
+            .or_else(|| bytes.strip_suffix(b"\r"))
+            .unwrap_or(bytes);
+        let start = content
+            .iter()
+            .position(|b| !matches!(b, b' ' | b'\t'))
+            .unwrap_or(content.len());
+        Some(match content.get(start) {
+            None => Ok(Line::Blank),
+            Some(b'#') => match str::from_utf8(&content[start + 1..]) {
+                Ok(comment) => Ok(Line::Comment(comment.into())),
+                Err(e) => {
+                    let position = TextPosition {
+                        line: span.line,
+                        column: u64::try_from(start + 1).unwrap(),
+                        offset: span.span.start
+                            + u64::try_from(start + 1 + e.valid_up_to()).unwrap(),
+                    };
+                    Err(TurtleSyntaxError::new(
+                        position..position,
+                        TurtleSyntaxErrorKind::InvalidToken,
+                        format!("Invalid UTF-8 in comment: {e}"),
+                    ))
+                }
+            },
+            Some(_) => {
+                // The recognizer returns either a triple or an error on a non-empty line
+                let result = NQuadsRecognizer::new_parser(
+                    bytes,
+                    true,
+                    false,
+                    self.lenient,
+                    self.validate_literals,
+                    self.reject_triple_terms,
+                    self.literal_mapper.clone(),
+                    self.limits,
+                )
+                .into_iter()
+                .next()?;
+                match result {
+                    Ok(quad) => Ok(Line::Quad {
+                        quad,
+                        original_bytes_span: span.span,
+                    }),
+                    Err(e) => Err(e.relative_to_line(span.line, span.span.start)),
+                }
+            }
+        })
+    }
+}
+
+impl<R: Read> Iterator for ReaderNTriplesLosslessParser<R> {
+    type Item = Result<Line, TurtleParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(span) = self.lines.next_line() {
+                if let Some(result) = self.parse_line(span) {
+                    return Some(result.map_err(TurtleParseError::Syntax));
+                }
+                continue;
+            }
+            if self.lines.is_end() {
+                return None;
+            }
+            if let Err(e) = self.lines.extend_from_reader(&mut self.reader) {
+                return Some(Err(e.into()));
+            }
+        }
+    }
 }
 
 /// A [canonical](https://www.w3.org/TR/n-triples/#canonical-ntriples) [N-Triples](https://www.w3.org/TR/n-triples/) serializer.
@@ -743,7 +1097,8 @@ impl LowLevelNTriplesSerializer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use oxrdf::{Literal, NamedNode};
+    use crate::NQuadsParser;
+    use oxrdf::{GraphName, Literal, NamedNode};
 
     #[test]
     fn lenient_parsing() {
@@ -761,4 +1116,193 @@ mod tests {
             )]
         )
     }
+
+    #[test]
+    fn max_term_length() {
+        // All the IRIs are 22 bytes long including their delimiters
+        let file = "<http://example.com/s> <http://example.com/p> <http://example.com/o> .";
+        let parser = NTriplesParser::new().with_max_term_length(22);
+        assert_eq!(parser.clone().for_slice(file).count(), 1);
+        assert_eq!(
+            parser
+                .for_reader(file.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let parser = NTriplesParser::new().with_max_term_length(21);
+        let error = parser.clone().for_slice(file).next().unwrap().unwrap_err();
+        assert_eq!(error.location().start.column, 0);
+        assert_eq!(error.location().end.column, 22);
+        parser
+            .for_reader(file.as_bytes())
+            .next()
+            .unwrap()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        let file = "# header\n\n<http://example.com/s>   <http://example.com/p>\t\"\\u0041\" .# trailing\r\n \t\r<http://example.com/s> <http://example.com/p> _:b1.\r  # indented comment\n<http://example.com/s> <http://example.com/p> <http://example.com/o> .";
+        let mut parser = NTriplesParser::new()
+            .with_buffer_sizes(8, 1024)
+            .for_reader_lossless(file.as_bytes());
+        let mut lines = Vec::new();
+        let mut output = Vec::new();
+        while let Some(line) = parser.next() {
+            lines.push(line.unwrap());
+            output.extend_from_slice(parser.original_bytes());
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), file);
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], Line::Comment(" header".into()));
+        assert_eq!(lines[1], Line::Blank);
+        assert_eq!(
+            lines[2],
+            Line::Quad {
+                quad: Quad::new(
+                    NamedNode::new_unchecked("http://example.com/s"),
+                    NamedNode::new_unchecked("http://example.com/p"),
+                    Literal::from("A"),
+                    GraphName::DefaultGraph
+                ),
+                original_bytes_span: 10..80
+            }
+        );
+        assert_eq!(lines[3], Line::Blank);
+        assert!(matches!(lines[4], Line::Quad { .. }));
+        assert_eq!(lines[5], Line::Comment(" indented comment".into()));
+        assert!(matches!(lines[6], Line::Quad { .. }));
+    }
+
+    #[test]
+    fn lossless_error_location() {
+        let file = "# comment\n<http://example.com/s> <http://example.com/p> .\n<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n";
+        let mut parser = NTriplesParser::new().for_reader_lossless(file.as_bytes());
+        assert!(matches!(parser.next(), Some(Ok(Line::Comment(_)))));
+        let error = parser.next().unwrap().unwrap_err();
+        assert!(
+            matches!(&error, TurtleParseError::Syntax(e) if e.location().start == TextPosition { line: 1, column: 46, offset: 56 }),
+            "{error}"
+        );
+        assert_eq!(
+            parser.original_bytes(),
+            b"<http://example.com/s> <http://example.com/p> .\n"
+        );
+        assert!(matches!(parser.next(), Some(Ok(Line::Quad { .. }))));
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn max_term_length_without_buffering() {
+        let mut parser = NTriplesParser::new().with_max_term_length(22).low_level();
+        parser.extend_from_slice(b"<http://example.com/s> <http://example.com/p> \"");
+        parser.extend_from_slice(&[b'a'; 100]);
+        // The literal is not finished but is already too long
+        let error = parser.parse_next().unwrap().unwrap_err();
+        assert_eq!(error.location().start.column, 46);
+        assert_eq!(error.location().end.column, 147);
+    }
+
+    #[test]
+    fn low_level_state_and_back_pressure() {
+        let file =
+            b"<http://example.com/s> <http://example.com/p> <http://example.com/o> .\n".repeat(3);
+        let mut parser = NTriplesParser::new().with_buffer_sizes(16, 100).low_level();
+        assert_eq!(parser.state(), LowLevelParserState::NeedsInput);
+        assert_eq!(parser.extend_from_slice(&file), 100);
+        assert_eq!(parser.buffered_len(), 100);
+        assert_eq!(parser.extend_from_slice(&file[100..]), 0);
+        assert_eq!(parser.state(), LowLevelParserState::HasPendingOutput);
+        parser.parse_next().unwrap().unwrap();
+        assert!(parser.parse_next().is_none());
+        assert_eq!(parser.state(), LowLevelParserState::NeedsInput);
+        // The parsed bytes are released
+        let buffered = parser.extend_from_slice(&file[100..]);
+        assert!(buffered > 0);
+        assert!(parser.buffered_len() <= parser.max_buffer_size());
+        assert_eq!(parser.state(), LowLevelParserState::HasPendingOutput);
+        parser.parse_next().unwrap().unwrap();
+        assert_eq!(
+            parser.extend_from_slice(&file[100 + buffered..]),
+            file.len() - 100 - buffered
+        );
+        parser.end();
+        assert_eq!(parser.state(), LowLevelParserState::HasPendingOutput);
+        parser.parse_next().unwrap().unwrap();
+        assert!(parser.parse_next().is_none());
+        assert_eq!(parser.state(), LowLevelParserState::Finished);
+    }
+
+    #[test]
+    fn byte_order_mark_and_line_endings_streamed_byte_by_byte() {
+        let file = b"\xEF\xBB\xBF<http://example.com/s> <http://example.com/p> <http://example.com/o> .\r<http://example.com/s> <http://example.com/p> \"o\" .\r\n<http://example.com/s> <http://example.com/p> <o> .\n";
+        let mut parser = NTriplesParser::new().low_level();
+        let mut results = Vec::new();
+        for byte in file {
+            parser.extend_from_slice(&[*byte]);
+            while let Some(result) = parser.parse_next() {
+                results.push(result);
+            }
+        }
+        parser.end();
+        while let Some(result) = parser.parse_next() {
+            results.push(result);
+        }
+        assert_eq!(results.len(), 3);
+        results[0].as_ref().unwrap();
+        results[1].as_ref().unwrap();
+        let error = results[2].as_ref().unwrap_err();
+        // The byte order mark is counted in the offset but not in the columns
+        assert_eq!(
+            error.location().start,
+            TextPosition {
+                line: 2,
+                column: 46,
+                offset: 173
+            }
+        );
+    }
+
+    #[test]
+    fn error_kinds() {
+        let error = NTriplesParser::new()
+            .for_slice("_:s \"p\" <http://example.com/o> .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnexpectedToken { found, expected } if found == "\"p\"" && expected == "an IRI"
+        ));
+        assert_eq!(error.message(), "The predicate of a triple must be an IRI");
+
+        let error = NTriplesParser::new()
+            .for_slice("<http://example.com/s> <http://example.com/p> <http://example.com/o>")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnclosedConstruct { what } if what == "statement"
+        ));
+
+        let error = NQuadsParser::new()
+            .for_slice("<http://example.com/s> <http://example.com/p> <http://example.com/o> <http://example.com/g> <http://example.com/h> .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnexpectedToken { expected, .. } if expected == "'.'"
+        ));
+
+        let error = NQuadsParser::new()
+            .for_slice("<http://example.com/s> <http://example.com/p> <o> <http://example.com/g> .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::InvalidIri { value, .. } if value == "o"
+        ));
+    }
 }
@@ -1,8 +1,10 @@
 //! Shared parser implementation for Turtle and TriG.
 
 use crate::lexer::{N3Lexer, N3LexerMode, N3LexerOptions, N3Token, resolve_local_name};
-use crate::toolkit::{Lexer, Parser, RuleRecognizer, RuleRecognizerError, TokenOrLineJump};
-use crate::{MAX_BUFFER_SIZE, MIN_BUFFER_SIZE};
+use crate::toolkit::{
+    Lexer, LexerLimits, Parser, RuleRecognizer, RuleRecognizerError, TokenOrLineJump,
+};
+use crate::{LiteralMapper, check_typed_literal, invalid_literal_error, map_literal};
 use oxiri::Iri;
 #[cfg(feature = "rdf-12")]
 use oxrdf::Triple;
@@ -20,6 +22,9 @@ pub struct TriGRecognizer {
     #[cfg(feature = "rdf-12")]
     cur_reifier: Vec<NamedOrBlankNode>,
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
 }
 
 #[expect(clippy::partial_pub_fields)]
@@ -61,6 +66,18 @@ impl RuleRecognizer for TriGRecognizer {
         let TokenOrLineJump::Token(token) = token else {
             return self;
         };
+        if self.reject_triple_terms
+            && matches!(token, N3Token::Punctuation("<<" | "<<(" | "~" | "{|"))
+        {
+            // Reified triples, reifiers and annotations all introduce triple terms
+            return self.error(
+                errors,
+                RuleRecognizerError::unexpected_token(
+                    "a term that is not a triple term",
+                    "Triple terms are not allowed",
+                ),
+            );
+        }
         if let Some(rule) = self.stack.pop() {
             match rule {
                 // [1] 	trigDoc 	::= 	(directive | block)*
@@ -148,7 +165,10 @@ impl RuleRecognizer for TriGRecognizer {
                     if token == N3Token::Punctuation(".") {
                         self
                     } else {
-                        errors.push("A dot is expected at the end of statements".into());
+                        errors.push(RuleRecognizerError::unexpected_token(
+                            "'.'",
+                            "A dot is expected at the end of statements",
+                        ));
                         self.recognize_next(TokenOrLineJump::Token(token), context, results, errors)
                     }
                 }
@@ -157,7 +177,13 @@ impl RuleRecognizer for TriGRecognizer {
                         context.lexer_options.base_iri = Some(Iri::parse_unchecked(iri));
                         self
                     } else {
-                        self.error(errors, "The BASE keyword should be followed by an IRI")
+                        self.error(
+                            errors,
+                            RuleRecognizerError::unexpected_token(
+                                "an IRI",
+                                "The BASE keyword should be followed by an IRI",
+                            ),
+                        )
                     }
                 }
                 TriGState::PrefixExpectPrefix => match token {
@@ -169,7 +195,10 @@ impl RuleRecognizer for TriGRecognizer {
                     }
                     _ => self.error(
                         errors,
-                        "The PREFIX keyword should be followed by a prefix like 'ex:'",
+                        RuleRecognizerError::unexpected_token(
+                            "a prefix like 'ex:'",
+                            "The PREFIX keyword should be followed by a prefix like 'ex:'",
+                        ),
                     ),
                 },
                 TriGState::PrefixExpectIri { name } => {
@@ -177,7 +206,7 @@ impl RuleRecognizer for TriGRecognizer {
                         context.prefixes.insert(name, Iri::parse_unchecked(iri));
                         self
                     } else {
-                        self.error(errors, "The PREFIX declaration should be followed by a prefix and its value as an IRI")
+                        self.error(errors, RuleRecognizerError::unexpected_token("an IRI", "The PREFIX declaration should be followed by a prefix and its value as an IRI"))
                     }
                 }
                 #[cfg(feature = "rdf-12")]
@@ -185,7 +214,7 @@ impl RuleRecognizer for TriGRecognizer {
                     if let N3Token::String(_) = token {
                         self
                     } else {
-                        self.error(errors, "The VERSION keyword should be followed by a single quoted string like \"1.2\"")
+                        self.error(errors, RuleRecognizerError::unexpected_token("a single quoted string", "The VERSION keyword should be followed by a single quoted string like \"1.2\""))
                     }
                 }
                 // [3] 	triplesOrGraph 	::= 	(labelOrSubject (wrappedGraph | (predicateObjectList '.'))) | (reifiedTriple predicateObjectList? '.')
@@ -248,7 +277,13 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::ReifiedTripleSubject { is_reified: true });
                         self
                     }
-                    _ => self.error(errors, "TOKEN is not a valid subject or graph name"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "a subject or a graph name",
+                            "TOKEN is not a valid subject or graph name",
+                        ),
+                    ),
                 },
                 TriGState::WrappedGraphOrPredicateObjectList { term } => {
                     if token == N3Token::Punctuation("{") && context.with_graph_name {
@@ -282,7 +317,10 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::SubjectBlankNodePropertyListAfter);
                         self
                     } else {
-                        errors.push("blank node property lists should end with a ']'".into());
+                        errors.push(RuleRecognizerError::unexpected_token(
+                            "']'",
+                            "blank node property lists should end with a ']'",
+                        ));
                         self.stack
                             .push(TriGState::SubjectBlankNodePropertyListAfter);
                         self.recognize_next(TokenOrLineJump::Token(token), context, results, errors)
@@ -339,7 +377,7 @@ impl RuleRecognizer for TriGRecognizer {
                         self.stack.push(TriGState::Triples);
                         self
                     } else {
-                        self.error(errors, "The GRAPH keyword should be followed by a graph name and a value in '{'")
+                        self.error(errors, RuleRecognizerError::unexpected_token("'{'", "The GRAPH keyword should be followed by a graph name and a value in '{'"))
                     }
                 }
                 TriGState::WrappedGraphPossibleEnd => {
@@ -352,9 +390,10 @@ impl RuleRecognizer for TriGRecognizer {
                             self
                         }
                         _ => {
-                            errors.push(
-                                "A '}' or a '.' is expected at the end of a graph block".into(),
-                            );
+                            errors.push(RuleRecognizerError::unexpected_token(
+                                "'}' or '.'",
+                                "A '}' or a '.' is expected at the end of a graph block",
+                            ));
                             self.recognize_next(
                                 TokenOrLineJump::Token(token),
                                 context,
@@ -422,7 +461,13 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::ReifiedTripleSubject { is_reified: true });
                         self
                     }
-                    _ => self.error(errors, "TOKEN is not a valid RDF subject"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "a subject",
+                            "TOKEN is not a valid RDF subject",
+                        ),
+                    ),
                 },
                 TriGState::TriplesBlankNodePropertyListCurrent => {
                     if token == N3Token::Punctuation("]") {
@@ -464,14 +509,20 @@ impl RuleRecognizer for TriGRecognizer {
                         self.stack.push(TriGState::GraphNameAnonEnd);
                         self
                     }
-                    _ => self.error(errors, "TOKEN is not a valid graph name"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "a graph name",
+                            "TOKEN is not a valid graph name",
+                        ),
+                    ),
                 },
                 TriGState::GraphNameAnonEnd => {
                     if token == N3Token::Punctuation("]") {
                         self.cur_graph = BlankNode::default().into();
                         self
                     } else {
-                        self.error(errors, "Anonymous blank node with a property list are not allowed as graph name")
+                        self.error(errors, RuleRecognizerError::unexpected_token("a graph name", "Anonymous blank node with a property list are not allowed as graph name"))
                     }
                 }
                 // [17] 	predicateObjectList 	::= 	verb objectList (';' (verb objectList)?)*
@@ -592,8 +643,19 @@ impl RuleRecognizer for TriGRecognizer {
                     if token == N3Token::Punctuation("|}") {
                         self
                     } else {
-                        self.error(errors, "Annotations should end with '|}'")
-                            .recognize_next(TokenOrLineJump::Token(token), context, results, errors)
+                        self.error(
+                            errors,
+                            RuleRecognizerError::unexpected_token(
+                                "'|}'",
+                                "Annotations should end with '|}'",
+                            ),
+                        )
+                        .recognize_next(
+                            TokenOrLineJump::Token(token),
+                            context,
+                            results,
+                            errors,
+                        )
                     }
                 }
                 // [19] 	verb 	::= 	predicate | 'a'
@@ -623,7 +685,13 @@ impl RuleRecognizer for TriGRecognizer {
                         }
                         Err(e) => self.error(errors, e),
                     },
-                    _ => self.error(errors, "TOKEN is not a valid predicate"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "a predicate",
+                            "TOKEN is not a valid predicate",
+                        ),
+                    ),
                 },
                 // [22] object 	::= 	iri | BlankNode | collection | blankNodePropertyList | literal | tripleTerm | reifiedTriple
                 // [23] literal 	::= 	RDFLiteral | NumericLiteral | BooleanLiteral
@@ -678,36 +746,41 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::LiteralPossibleSuffix { value, emit: true });
                         self
                     }
-                    N3Token::Integer(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::INTEGER).into());
-                        self.emit_quad(results);
-                        self
-                    }
-                    N3Token::Decimal(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::DECIMAL).into());
-                        self.emit_quad(results);
-                        self
-                    }
-                    N3Token::Double(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::DOUBLE).into());
-                        self.emit_quad(results);
-                        self
-                    }
-                    N3Token::PlainKeyword("true") => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal("true", xsd::BOOLEAN).into());
-                        self.emit_quad(results);
-                        self
-                    }
-                    N3Token::PlainKeyword("false") => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal("false", xsd::BOOLEAN).into());
-                        self.emit_quad(results);
-                        self
-                    }
+                    N3Token::Integer(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::INTEGER),
+                        false,
+                        true,
+                        results,
+                        errors,
+                    ),
+                    N3Token::Decimal(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::DECIMAL),
+                        false,
+                        true,
+                        results,
+                        errors,
+                    ),
+                    N3Token::Double(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::DOUBLE),
+                        false,
+                        true,
+                        results,
+                        errors,
+                    ),
+                    N3Token::PlainKeyword("true") => self.push_literal(
+                        Literal::new_typed_literal("true", xsd::BOOLEAN),
+                        false,
+                        true,
+                        results,
+                        errors,
+                    ),
+                    N3Token::PlainKeyword("false") => self.push_literal(
+                        Literal::new_typed_literal("false", xsd::BOOLEAN),
+                        false,
+                        true,
+                        results,
+                        errors,
+                    ),
                     #[cfg(feature = "rdf-12")]
                     N3Token::Punctuation("<<") => {
                         self.stack
@@ -730,7 +803,13 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::ReifiedTripleSubject { is_reified: false });
                         self
                     }
-                    _ => self.error(errors, "TOKEN is not a valid RDF object"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "an object",
+                            "TOKEN is not a valid RDF object",
+                        ),
+                    ),
                 },
                 TriGState::ObjectBlankNodePropertyListCurrent => {
                     if token == N3Token::Punctuation("]") {
@@ -750,7 +829,13 @@ impl RuleRecognizer for TriGRecognizer {
                         self.emit_quad(results);
                         self
                     } else {
-                        self.error(errors, "blank node property lists should end with a ']'")
+                        self.error(
+                            errors,
+                            RuleRecognizerError::unexpected_token(
+                                "']'",
+                                "blank node property lists should end with a ']'",
+                            ),
+                        )
                     }
                 }
                 TriGState::ObjectCollectionBeginning => {
@@ -796,72 +881,54 @@ impl RuleRecognizer for TriGRecognizer {
                         language,
                         direction,
                     } => {
-                        self.cur_object.push(
-                            if let Some(direction) = direction {
-                                Literal::new_directional_language_tagged_literal_unchecked(
-                                    value,
-                                    language.to_ascii_lowercase(),
-                                    direction,
-                                )
-                            } else {
-                                Literal::new_language_tagged_literal_unchecked(
-                                    value,
-                                    language.to_ascii_lowercase(),
-                                )
-                            }
-                            .into(),
-                        );
-                        if emit {
-                            self.emit_quad(results);
-                        }
-                        self
-                    }
-                    #[cfg(not(feature = "rdf-12"))]
-                    N3Token::LangTag { language } => {
-                        self.cur_object.push(
+                        let literal = if let Some(direction) = direction {
+                            Literal::new_directional_language_tagged_literal_unchecked(
+                                value,
+                                language.to_ascii_lowercase(),
+                                direction,
+                            )
+                        } else {
                             Literal::new_language_tagged_literal_unchecked(
                                 value,
                                 language.to_ascii_lowercase(),
                             )
-                            .into(),
-                        );
-                        if emit {
-                            self.emit_quad(results);
-                        }
-                        self
+                        };
+                        self.push_literal(literal, false, emit, results, errors)
                     }
+                    #[cfg(not(feature = "rdf-12"))]
+                    N3Token::LangTag { language } => self.push_literal(
+                        Literal::new_language_tagged_literal_unchecked(
+                            value,
+                            language.to_ascii_lowercase(),
+                        ),
+                        false,
+                        emit,
+                        results,
+                        errors,
+                    ),
                     N3Token::Punctuation("^^") => {
                         self.stack
                             .push(TriGState::LiteralExpectDatatype { value, emit });
                         self
                     }
-                    _ => {
-                        self.cur_object
-                            .push(Literal::new_simple_literal(value).into());
-                        if emit {
-                            self.emit_quad(results);
-                        }
-                        self.recognize_next(TokenOrLineJump::Token(token), context, results, errors)
-                    }
+                    _ => self
+                        .push_literal(
+                            Literal::new_simple_literal(value),
+                            false,
+                            emit,
+                            results,
+                            errors,
+                        )
+                        .recognize_next(TokenOrLineJump::Token(token), context, results, errors),
                 },
                 TriGState::LiteralExpectDatatype { value, emit } => match token {
-                    N3Token::IriRef(datatype) => {
-                        if !self.lenient && datatype == rdf::LANG_STRING.as_str() {
-                            errors.push("The datatype of a literal without a language tag must not be rdf:langString".into());
-                        }
-                        #[cfg(feature = "rdf-12")]
-                        if !self.lenient && datatype == rdf::DIR_LANG_STRING.as_str() {
-                            errors.push("The datatype of a literal without a base direction must not be rdf:dirLangString".into());
-                        }
-                        self.cur_object.push(
-                            Literal::new_typed_literal(value, NamedNode::new_unchecked(datatype))
-                                .into(),
-                        );
-                        if emit {
-                            self.emit_quad(results);
-                        }
-                        self
-                    }
+                    N3Token::IriRef(datatype) => self.push_literal(
+                        Literal::new_typed_literal(value, NamedNode::new_unchecked(datatype)),
+                        true,
+                        emit,
+                        results,
+                        errors,
+                    ),
                     N3Token::PrefixedName {
                         prefix,
                         local,
@@ -872,25 +939,23 @@ impl RuleRecognizer for TriGRecognizer {
                         might_be_invalid_iri,
                         &context.prefixes,
                     ) {
-                        Ok(t) => {
-                            if !self.lenient && t == rdf::LANG_STRING {
-                                errors.push("The datatype of a literal without a language tag must not be rdf:langString".into());
-                            }
-                            #[cfg(feature = "rdf-12")]
-                            if !self.lenient && t == rdf::DIR_LANG_STRING {
-                                errors.push("The datatype of a literal without a base direction must not be rdf:dirLangString".into());
-                            }
-                            self.cur_object
-                                .push(Literal::new_typed_literal(value, t).into());
-                            if emit {
-                                self.emit_quad(results);
-                            }
-                            self
-                        }
+                        Ok(t) => self.push_literal(
+                            Literal::new_typed_literal(value, t),
+                            true,
+                            emit,
+                            results,
+                            errors,
+                        ),
                         Err(e) => self.error(errors, e),
                     },
                     _ => self
-                        .error(errors, "Expecting a datatype IRI after ^^, found TOKEN")
+                        .error(
+                            errors,
+                            RuleRecognizerError::unexpected_token(
+                                "a datatype IRI",
+                                "Expecting a datatype IRI after ^^, found TOKEN",
+                            ),
+                        )
                         .recognize_next(TokenOrLineJump::Token(token), context, results, errors),
                 },
                 // [29] reifiedTriple 	::= 	'<<' rtSubject verb rtObject reifier? '>>'
@@ -902,7 +967,10 @@ impl RuleRecognizer for TriGRecognizer {
                     } else {
                         self.error(
                             errors,
-                            "Expecting '>>' to close a reified triple, found TOKEN",
+                            RuleRecognizerError::unexpected_token(
+                                "'>>'",
+                                "Expecting '>>' to close a reified triple, found TOKEN",
+                            ),
                         )
                         .recognize_next(
                             TokenOrLineJump::Token(token),
@@ -923,7 +991,10 @@ impl RuleRecognizer for TriGRecognizer {
                     } else {
                         self.error(
                             errors,
-                            "Expecting '>>' to close a reified triple, found TOKEN",
+                            RuleRecognizerError::unexpected_token(
+                                "'>>'",
+                                "Expecting '>>' to close a reified triple, found TOKEN",
+                            ),
                         )
                         .recognize_next(
                             TokenOrLineJump::Token(token),
@@ -949,7 +1020,10 @@ impl RuleRecognizer for TriGRecognizer {
                     } else {
                         self.error(
                             errors,
-                            "Expecting ')>>' to close a triple term, found TOKEN",
+                            RuleRecognizerError::unexpected_token(
+                                "')>>'",
+                                "Expecting ')>>' to close a triple term, found TOKEN",
+                            ),
                         )
                         .recognize_next(
                             TokenOrLineJump::Token(token),
@@ -1095,7 +1169,10 @@ impl RuleRecognizer for TriGRecognizer {
                     }
                     _ => self.error(
                         errors,
-                        "TOKEN is not a valid RDF quoted triple subject: TOKEN",
+                        RuleRecognizerError::unexpected_token(
+                            "a quoted triple subject",
+                            "TOKEN is not a valid RDF quoted triple subject: TOKEN",
+                        ),
                     ),
                 },
                 // [31] 	rtObject 	::= 	iri | BlankNode | literal | tripleTerm | reifiedTriple
@@ -1136,31 +1213,41 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::LiteralPossibleSuffix { value, emit: false });
                         self
                     }
-                    N3Token::Integer(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::INTEGER).into());
-                        self
-                    }
-                    N3Token::Decimal(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::DECIMAL).into());
-                        self
-                    }
-                    N3Token::Double(v) => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal(v, xsd::DOUBLE).into());
-                        self
-                    }
-                    N3Token::PlainKeyword("true") => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal("true", xsd::BOOLEAN).into());
-                        self
-                    }
-                    N3Token::PlainKeyword("false") => {
-                        self.cur_object
-                            .push(Literal::new_typed_literal("false", xsd::BOOLEAN).into());
-                        self
-                    }
+                    N3Token::Integer(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::INTEGER),
+                        false,
+                        false,
+                        results,
+                        errors,
+                    ),
+                    N3Token::Decimal(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::DECIMAL),
+                        false,
+                        false,
+                        results,
+                        errors,
+                    ),
+                    N3Token::Double(v) => self.push_literal(
+                        Literal::new_typed_literal(v, xsd::DOUBLE),
+                        false,
+                        false,
+                        results,
+                        errors,
+                    ),
+                    N3Token::PlainKeyword("true") => self.push_literal(
+                        Literal::new_typed_literal("true", xsd::BOOLEAN),
+                        false,
+                        false,
+                        results,
+                        errors,
+                    ),
+                    N3Token::PlainKeyword("false") => self.push_literal(
+                        Literal::new_typed_literal("false", xsd::BOOLEAN),
+                        false,
+                        false,
+                        results,
+                        errors,
+                    ),
                     N3Token::Punctuation("<<(") => {
                         self.stack.push(TriGState::TripleTermEnd { emit: false });
                         self.stack
@@ -1181,14 +1268,20 @@ impl RuleRecognizer for TriGRecognizer {
                             .push(TriGState::ReifiedTripleSubject { is_reified: true });
                         self
                     }
-                    _ => self.error(errors, "TOKEN is not a valid RDF quoted triple object"),
+                    _ => self.error(
+                        errors,
+                        RuleRecognizerError::unexpected_token(
+                            "a quoted triple object",
+                            "TOKEN is not a valid RDF quoted triple object",
+                        ),
+                    ),
                 },
                 #[cfg(feature = "rdf-12")]
                 TriGState::QuotedAnonEnd => {
                     if token == N3Token::Punctuation("]") {
                         self
                     } else {
-                        self.error(errors, "Anonymous blank node with a property list are not allowed in quoted triples")
+                        self.error(errors, RuleRecognizerError::unexpected_token("a quoted triple term", "Anonymous blank node with a property list are not allowed in quoted triples"))
                     }
                 }
             }
@@ -1223,12 +1316,22 @@ impl RuleRecognizer for TriGRecognizer {
                 );
             }
             [.., TriGState::LiteralPossibleSuffix { value, emit: true }] => {
-                self.cur_object
-                    .push(Literal::new_simple_literal(value).into());
-                self.emit_quad(results);
-                errors.push("Triples should be followed by a dot".into())
+                match self.map_literal(Literal::new_simple_literal(value)) {
+                    Ok(literal) => {
+                        self.cur_object.push(literal.into());
+                        self.emit_quad(results);
+                    }
+                    Err(e) => errors.push(e),
+                }
+                errors.push(RuleRecognizerError::unclosed_construct(
+                    "statement",
+                    "Triples should be followed by a dot",
+                ))
             }
-            _ => errors.push("Unexpected end".into()), // TODO
+            _ => errors.push(RuleRecognizerError::unclosed_construct(
+                "statement",
+                "Unexpected end",
+            )), // TODO
         }
     }
 
@@ -1238,11 +1341,16 @@ impl RuleRecognizer for TriGRecognizer {
 }
 
 impl TriGRecognizer {
+    #[expect(clippy::fn_params_excessive_bools)]
     pub fn new_parser<B>(
         data: B,
         is_ending: bool,
         with_graph_name: bool,
         lenient: bool,
+        validate_literals: bool,
+        reject_triple_terms: bool,
+        literal_mapper: Option<LiteralMapper>,
+        limits: LexerLimits,
         base_iri: Option<Iri<String>>,
         prefixes: HashMap<String, Iri<String>>,
     ) -> Parser<B, Self> {
@@ -1251,8 +1359,7 @@ impl TriGRecognizer {
                 N3Lexer::new(N3LexerMode::Turtle, lenient),
                 data,
                 is_ending,
-                MIN_BUFFER_SIZE,
-                MAX_BUFFER_SIZE,
+                limits,
                 Some(b"#"),
             ),
             Self {
@@ -1264,6 +1371,9 @@ impl TriGRecognizer {
                 #[cfg(feature = "rdf-12")]
                 cur_reifier: Vec::new(),
                 lenient,
+                validate_literals,
+                reject_triple_terms,
+                literal_mapper,
             },
             TriGRecognizerContext {
                 with_graph_name,
@@ -1273,6 +1383,41 @@ impl TriGRecognizer {
         )
     }
 
+    fn map_literal(&self, literal: Literal) -> Result<Literal, RuleRecognizerError> {
+        map_literal(self.literal_mapper.as_ref(), literal)
+    }
+
+    /// Pushes a literal object after applying the literal mapper and emits the quad if `emit` is set
+    ///
+    /// If `validate` is set, the literal is checked against its explicit datatype.
+    #[must_use]
+    fn push_literal(
+        mut self,
+        literal: Literal,
+        validate: bool,
+        emit: bool,
+        results: &mut Vec<Quad>,
+        errors: &mut Vec<RuleRecognizerError>,
+    ) -> Self {
+        if validate && !self.lenient {
+            check_typed_literal(&literal, errors);
+        }
+        let literal = match self.map_literal(literal) {
+            Ok(literal) => literal,
+            Err(e) => return self.error(errors, e),
+        };
+        if validate && self.validate_literals {
+            if let Err(e) = literal.parsed_value() {
+                errors.push(invalid_literal_error(&literal, &e));
+            }
+        }
+        self.cur_object.push(literal.into());
+        if emit {
+            self.emit_quad(results);
+        }
+        self
+    }
+
     #[must_use]
     fn error(
         mut self,
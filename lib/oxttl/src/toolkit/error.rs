@@ -1,3 +1,6 @@
+use oxilangtag::LanguageTagParseError;
+use oxiri::IriParseError;
+use oxrdf::Literal;
 use std::ops::Range;
 use std::{fmt, io};
 
@@ -11,27 +14,62 @@ pub struct TextPosition {
 
 /// An error in the syntax of the parsed file.
 ///
-/// It is composed of a message and a byte range in the input.
+/// It is composed of a [kind](TurtleSyntaxErrorKind), a message and a byte range in the input.
 #[derive(Debug, thiserror::Error)]
 pub struct TurtleSyntaxError {
     location: Range<TextPosition>,
+    kind: Box<TurtleSyntaxErrorKind>,
     message: String,
 }
 
 impl TurtleSyntaxError {
-    pub(crate) fn new(location: Range<TextPosition>, message: impl Into<String>) -> Self {
+    pub(crate) fn new(
+        location: Range<TextPosition>,
+        kind: TurtleSyntaxErrorKind,
+        message: impl Into<String>,
+    ) -> Self {
         Self {
             location,
+            kind: Box::new(kind),
             message: message.into(),
         }
     }
 
+    /// Moves the error location from a line parsed on its own to the position of this line in the whole file.
+    pub(crate) fn relative_to_line(mut self, line: u64, line_start_offset: u64) -> Self {
+        for position in [&mut self.location.start, &mut self.location.end] {
+            position.line += line;
+            position.offset += line_start_offset;
+        }
+        self
+    }
+
     /// The location of the error inside of the file.
     #[inline]
     pub fn location(&self) -> Range<TextPosition> {
         self.location.clone()
     }
 
+    /// The kind of error, allowing to react to it without parsing the message.
+    ///
+    /// ```
+    /// use oxttl::{TurtleParser, TurtleSyntaxErrorKind};
+    ///
+    /// let error = TurtleParser::new()
+    ///     .for_slice("schema:Person a schema:Thing .")
+    ///     .next()
+    ///     .unwrap()
+    ///     .unwrap_err();
+    /// assert!(matches!(
+    ///     error.kind(),
+    ///     TurtleSyntaxErrorKind::UndefinedPrefix { prefix } if prefix == "schema"
+    /// ));
+    /// ```
+    #[inline]
+    pub fn kind(&self) -> &TurtleSyntaxErrorKind {
+        &self.kind
+    }
+
     /// The error message.
     #[inline]
     pub fn message(&self) -> &str {
@@ -73,6 +111,41 @@ impl fmt::Display for TurtleSyntaxError {
     }
 }
 
+/// The kind of a [`TurtleSyntaxError`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TurtleSyntaxErrorKind {
+    /// A prefixed name uses a prefix that has not been declared.
+    UndefinedPrefix { prefix: String },
+    /// An IRI is not valid.
+    InvalidIri {
+        value: String,
+        source: IriParseError,
+    },
+    /// A language tag is not a valid BCP47 language tag.
+    InvalidLanguageTag {
+        value: String,
+        source: LanguageTagParseError,
+    },
+    /// A literal is not valid, for example because its lexical form does not match its datatype or because it has been rejected by the literal mapper.
+    InvalidLiteral { literal: Literal },
+    /// A token is not allowed at this position.
+    UnexpectedToken {
+        /// The source of the unexpected token.
+        found: String,
+        /// A description of what was expected instead.
+        expected: String,
+    },
+    /// The file ends before the end of a construct (statement, term, blank node property list...).
+    UnclosedConstruct { what: String },
+    /// A term is longer than the maximal allowed length.
+    TermTooLong { max_length: usize },
+    /// A token is not well-formed (invalid escape sequence, unexpected character...).
+    InvalidToken,
+    /// Any other error.
+    Other,
+}
+
 impl From<TurtleSyntaxError> for io::Error {
     #[inline]
     fn from(error: TurtleSyntaxError) -> Self {
@@ -1,4 +1,4 @@
-use crate::toolkit::error::{TextPosition, TurtleSyntaxError};
+use crate::toolkit::error::{TextPosition, TurtleSyntaxError, TurtleSyntaxErrorKind};
 use memchr::{memchr2, memchr2_iter};
 use std::borrow::Cow;
 use std::cmp::min;
@@ -30,13 +30,24 @@ pub enum TokenOrLineJump<T> {
 
 pub struct TokenRecognizerError {
     pub location: Range<usize>,
+    pub kind: TurtleSyntaxErrorKind,
     pub message: String,
 }
 
+impl TokenRecognizerError {
+    /// Sets the error kind, [`TurtleSyntaxErrorKind::InvalidToken`] by default
+    #[must_use]
+    pub fn with_kind(mut self, kind: TurtleSyntaxErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
 impl<S: Into<String>> From<(Range<usize>, S)> for TokenRecognizerError {
     fn from((location, message): (Range<usize>, S)) -> Self {
         Self {
             location,
+            kind: TurtleSyntaxErrorKind::InvalidToken,
             message: message.into(),
         }
     }
@@ -54,14 +65,39 @@ impl<S: Into<String>> From<(usize, S)> for TokenRecognizerError {
     }
 }
 
+/// Number of bytes after the end of a token a recognizer might need to read to find the token end
+const MAX_TOKEN_LOOKAHEAD: usize = 2;
+
+const UTF8_BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// Bounds on the memory used by a [`Lexer`]
+#[derive(Clone, Copy, Debug)]
+pub struct LexerLimits {
+    /// Number of bytes to read at once from the input
+    pub min_buffer_size: usize,
+    /// Maximal size of the buffer, reading more data fails
+    pub max_buffer_size: usize,
+    /// Maximal number of bytes of a single token, longer tokens are syntax errors
+    pub max_token_length: Option<usize>,
+}
+
+impl Default for LexerLimits {
+    fn default() -> Self {
+        Self {
+            min_buffer_size: 4096,
+            max_buffer_size: 4096 * 4096,
+            max_token_length: None,
+        }
+    }
+}
+
 pub struct Lexer<B, R: TokenRecognizer> {
     parser: R,
     data: B,
     position: Position,
     previous_position: Position, // Lexer position before the last emitted token
     is_ending: bool,
-    min_buffer_size: usize,
-    max_buffer_size: usize,
+    limits: LexerLimits,
     line_comment_start: Option<&'static [u8]>,
 }
 
@@ -78,8 +114,7 @@ impl<B, R: TokenRecognizer> Lexer<B, R> {
         parser: R,
         data: B,
         is_ending: bool,
-        min_buffer_size: usize,
-        max_buffer_size: usize,
+        limits: LexerLimits,
         line_comment_start: Option<&'static [u8]>,
     ) -> Self {
         Self {
@@ -98,17 +133,38 @@ impl<B, R: TokenRecognizer> Lexer<B, R> {
                 global_line: 0,
             },
             is_ending,
-            min_buffer_size,
-            max_buffer_size,
+            limits,
             line_comment_start,
         }
     }
 }
 
 impl<R: TokenRecognizer> Lexer<Vec<u8>, R> {
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    /// Adds as many bytes of `other` as the maximal buffer size allows and returns their number
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.shrink_data();
-        self.data.extend_from_slice(other);
+        if self.data.len() + other.len() > self.limits.max_buffer_size
+            && self.position.buffer_offset > 0
+        {
+            // We really need space, let's forget about error quality
+            self.shrink_data_by(self.position.buffer_offset);
+        }
+        let len = min(
+            other.len(),
+            self.limits.max_buffer_size.saturating_sub(self.data.len()),
+        );
+        self.data.extend_from_slice(&other[..len]);
+        len
+    }
+
+    /// Number of bytes buffered but not consumed yet
+    pub fn buffered_len(&self) -> usize {
+        self.data.len() - self.position.buffer_offset
+    }
+
+    #[inline]
+    pub fn max_buffer_size(&self) -> usize {
+        self.limits.max_buffer_size
     }
 
     #[inline]
@@ -118,16 +174,19 @@ impl<R: TokenRecognizer> Lexer<Vec<u8>, R> {
 
     pub fn extend_from_reader(&mut self, reader: &mut impl Read) -> io::Result<()> {
         self.shrink_data();
-        if self.data.len() == self.max_buffer_size {
+        if self.data.len() == self.limits.max_buffer_size {
             return Err(io::Error::new(
                 io::ErrorKind::OutOfMemory,
                 format!(
                     "Reached the buffer maximal size of {}",
-                    self.max_buffer_size
+                    self.limits.max_buffer_size
                 ),
             ));
         }
-        let min_end = min(self.data.len() + self.min_buffer_size, self.max_buffer_size);
+        let min_end = min(
+            self.data.len() + self.limits.min_buffer_size,
+            self.limits.max_buffer_size,
+        );
         let new_start = self.data.len();
         self.data.resize(min_end, 0);
         if self.data.len() < self.data.capacity() {
@@ -146,16 +205,19 @@ impl<R: TokenRecognizer> Lexer<Vec<u8>, R> {
         reader: &mut (impl AsyncRead + Unpin),
     ) -> io::Result<()> {
         self.shrink_data();
-        if self.data.len() == self.max_buffer_size {
+        if self.data.len() == self.limits.max_buffer_size {
             return Err(io::Error::new(
                 io::ErrorKind::OutOfMemory,
                 format!(
                     "Reached the buffer maximal size of {}",
-                    self.max_buffer_size
+                    self.limits.max_buffer_size
                 ),
             ));
         }
-        let min_end = min(self.data.len() + self.min_buffer_size, self.max_buffer_size);
+        let min_end = min(
+            self.data.len() + self.limits.min_buffer_size,
+            self.limits.max_buffer_size,
+        );
         let new_start = self.data.len();
         self.data.resize(min_end, 0);
         if self.data.len() < self.data.capacity() {
@@ -172,7 +234,7 @@ impl<R: TokenRecognizer> Lexer<Vec<u8>, R> {
         if self.position.line_start_buffer_offset > 0 {
             self.shrink_data_by(self.position.line_start_buffer_offset);
         }
-        if self.position.buffer_offset > self.max_buffer_size / 2 {
+        if self.position.buffer_offset > self.limits.max_buffer_size / 2 {
             // We really need to shrink, let's forget about error quality
             self.shrink_data_by(self.position.buffer_offset);
         }
@@ -195,6 +257,9 @@ impl<B: Deref<Target = [u8]>, R: TokenRecognizer> Lexer<B, R> {
         &mut self,
         options: &R::Options,
     ) -> Option<Result<TokenOrLineJump<R::Token<'_>>, TurtleSyntaxError>> {
+        if self.position.global_offset == 0 {
+            self.skip_byte_order_mark()?;
+        }
         if self.skip_whitespaces_and_comments()? {
             self.previous_position = self.position;
             return Some(Ok(TokenOrLineJump::LineJump));
@@ -223,10 +288,26 @@ impl<B: Deref<Target = [u8]>, R: TokenRecognizer> Lexer<B, R> {
                     self.position.global_line += new_line_jumps;
                     let error = TurtleSyntaxError::new(
                         self.last_token_location(),
+                        TurtleSyntaxErrorKind::UnclosedConstruct {
+                            what: "token".into(),
+                        },
                         "Unexpected end of file",
                     );
                     Some(Err(error))
                 }
+            } else if self
+                .limits
+                .max_token_length
+                .is_some_and(|max_token_length| {
+                    self.data.len() - self.position.buffer_offset
+                        > max_token_length.saturating_add(MAX_TOKEN_LOOKAHEAD)
+                })
+            {
+                // The token is already too long, we fail early instead of buffering it
+                let token_length = self.data.len() - self.position.buffer_offset;
+                let error = self.token_too_long_error(token_length);
+                Self::consume(&mut self.position, &self.data, token_length);
+                Some(Err(error))
             } else {
                 None
             };
@@ -240,24 +321,48 @@ impl<B: Deref<Target = [u8]>, R: TokenRecognizer> Lexer<B, R> {
             "The lexer tried to consumed {consumed} bytes but only {} bytes are readable",
             self.data.len() - self.position.buffer_offset
         );
-        let (new_line_jumps, new_line_start) =
-            Self::find_number_of_line_jumps_and_start_of_last_line(
-                &self.data[self.position.buffer_offset..self.position.buffer_offset + consumed],
-            );
-        if new_line_jumps > 0 {
-            self.position.line_start_buffer_offset = self.position.buffer_offset + new_line_start;
+        if self
+            .limits
+            .max_token_length
+            .is_some_and(|max_token_length| consumed > max_token_length)
+        {
+            let error = self.token_too_long_error(consumed);
+            Self::consume(&mut self.position, &self.data, consumed);
+            return Some(Err(error));
         }
-        self.position.buffer_offset += consumed;
-        self.position.global_offset += u64::try_from(consumed).unwrap();
-        self.position.global_line += new_line_jumps;
+        Self::consume(&mut self.position, &self.data, consumed);
         Some(result.map(TokenOrLineJump::Token).map_err(|e| {
             TurtleSyntaxError::new(
                 self.location_from_buffer_offset_range(e.location),
+                e.kind,
                 e.message,
             )
         }))
     }
 
+    fn consume(position: &mut Position, data: &[u8], length: usize) {
+        let (new_line_jumps, new_line_start) =
+            Self::find_number_of_line_jumps_and_start_of_last_line(
+                &data[position.buffer_offset..position.buffer_offset + length],
+            );
+        if new_line_jumps > 0 {
+            position.line_start_buffer_offset = position.buffer_offset + new_line_start;
+        }
+        position.buffer_offset += length;
+        position.global_offset += u64::try_from(length).unwrap();
+        position.global_line += new_line_jumps;
+    }
+
+    /// Builds the error for a too long token starting at the current position
+    fn token_too_long_error(&self, token_length: usize) -> TurtleSyntaxError {
+        let max_length = self.limits.max_token_length.unwrap_or_default();
+        TurtleSyntaxError::new(
+            self.location_from_buffer_offset_range(0..token_length),
+            TurtleSyntaxErrorKind::TermTooLong { max_length },
+            format!("The term is longer than the maximal allowed length of {max_length} bytes"),
+        )
+    }
+
     pub fn location_from_buffer_offset_range(
         &self,
         offset_range: Range<usize>,
@@ -319,6 +424,19 @@ impl<B: Deref<Target = [u8]>, R: TokenRecognizer> Lexer<B, R> {
         self.is_ending && self.data.len() == self.position.buffer_offset
     }
 
+    /// Skips the UTF-8 byte order mark at the beginning of the file, it is not counted as a column
+    fn skip_byte_order_mark(&mut self) -> Option<()> {
+        let buf = &self.data[self.position.buffer_offset..];
+        if buf.starts_with(UTF8_BYTE_ORDER_MARK) {
+            self.position.buffer_offset += UTF8_BYTE_ORDER_MARK.len();
+            self.position.line_start_buffer_offset = self.position.buffer_offset;
+            self.position.global_offset += u64::try_from(UTF8_BYTE_ORDER_MARK.len()).unwrap();
+        } else if !self.is_ending && UTF8_BYTE_ORDER_MARK.starts_with(buf) {
+            return None; // We need more data
+        }
+        Some(())
+    }
+
     fn skip_whitespaces_and_comments(&mut self) -> Option<bool> {
         if self.skip_whitespaces()? {
             return Some(true);
@@ -401,19 +519,16 @@ impl<B: Deref<Target = [u8]>, R: TokenRecognizer> Lexer<B, R> {
     fn find_number_of_line_jumps_and_start_of_last_line(bytes: &[u8]) -> (u64, usize) {
         let mut num_of_jumps = 0;
         let mut last_jump_pos = 0;
-        let mut previous_cr = 0;
+        let mut previous_cr = None;
         for pos in memchr2_iter(b'\r', b'\n', bytes) {
             if bytes[pos] == b'\r' {
-                previous_cr = pos;
+                previous_cr = Some(pos);
+                num_of_jumps += 1;
+            } else if previous_cr.is_none_or(|previous_cr| previous_cr + 1 < pos) {
+                // We count \r\n as a single line jump
                 num_of_jumps += 1;
-                last_jump_pos = pos + 1;
-            } else {
-                if previous_cr < pos - 1 {
-                    // We count \r\n as a single line jump
-                    num_of_jumps += 1;
-                }
-                last_jump_pos = pos + 1;
             }
+            last_jump_pos = pos + 1;
         }
         (num_of_jumps, last_jump_pos)
     }
@@ -0,0 +1,119 @@
+use crate::toolkit::lexer::LexerLimits;
+use memchr::memchr2;
+use std::cmp::min;
+use std::io::{self, Read};
+use std::ops::{Deref, Range};
+
+/// Splits an input into lines, keeping track of their byte span in the whole input.
+///
+/// A line ends after `\n`, `\r\n` or a lone `\r` and includes its line terminator.
+pub struct LineSplitter<B> {
+    data: B,
+    /// Start in `data` of the next line
+    position: usize,
+    /// The last line returned by [`next_line`](Self::next_line) in `data`
+    last_line: Range<usize>,
+    /// Offset in the whole input of the start of `data`
+    data_global_offset: u64,
+    /// Number of lines already returned
+    line_count: u64,
+    is_ending: bool,
+    limits: LexerLimits,
+}
+
+/// A line returned by [`LineSplitter::next_line`].
+pub struct LineSpan {
+    /// The byte range of the line in the whole input, including its line terminator
+    pub span: Range<u64>,
+    /// The line number, starting from 0
+    pub line: u64,
+}
+
+impl<B> LineSplitter<B> {
+    pub fn new(data: B, is_ending: bool, limits: LexerLimits) -> Self {
+        Self {
+            data,
+            position: 0,
+            last_line: 0..0,
+            data_global_offset: 0,
+            line_count: 0,
+            is_ending,
+            limits,
+        }
+    }
+}
+
+impl LineSplitter<Vec<u8>> {
+    pub fn extend_from_reader(&mut self, reader: &mut impl Read) -> io::Result<()> {
+        self.shrink_data();
+        if self.data.len() == self.limits.max_buffer_size {
+            return Err(io::Error::new(
+                io::ErrorKind::OutOfMemory,
+                format!(
+                    "Reached the buffer maximal size of {}",
+                    self.limits.max_buffer_size
+                ),
+            ));
+        }
+        let min_end = min(
+            self.data.len() + self.limits.min_buffer_size,
+            self.limits.max_buffer_size,
+        );
+        let new_start = self.data.len();
+        self.data.resize(min_end, 0);
+        if self.data.len() < self.data.capacity() {
+            // We keep extending to have as much space as available without reallocation
+            self.data.resize(self.data.capacity(), 0);
+        }
+        let read = reader.read(&mut self.data[new_start..])?;
+        self.data.truncate(new_start + read);
+        self.is_ending = read == 0;
+        Ok(())
+    }
+
+    fn shrink_data(&mut self) {
+        if self.position > 0 {
+            self.data.copy_within(self.position.., 0);
+            self.data.truncate(self.data.len() - self.position);
+            self.data_global_offset += u64::try_from(self.position).unwrap();
+            self.position = 0;
+            self.last_line = 0..0;
+        }
+    }
+}
+
+impl<B: Deref<Target = [u8]>> LineSplitter<B> {
+    /// Returns the next line or `None` if more data is required or the input is finished.
+    pub fn next_line(&mut self) -> Option<LineSpan> {
+        let buf = &self.data[self.position..];
+        let len = match memchr2(b'\r', b'\n', buf) {
+            Some(end) if buf[end] == b'\n' => end + 1,
+            Some(end) => match buf.get(end + 1) {
+                Some(b'\n') => end + 2,
+                Some(_) => end + 1,
+                None if self.is_ending => end + 1,
+                None => return None, // We need to know if the next byte is a \n
+            },
+            None if self.is_ending && !buf.is_empty() => buf.len(),
+            None => return None,
+        };
+        let start = self.data_global_offset + u64::try_from(self.position).unwrap();
+        self.last_line = self.position..self.position + len;
+        self.position += len;
+        let line = self.line_count;
+        self.line_count += 1;
+        Some(LineSpan {
+            span: start..start + u64::try_from(len).unwrap(),
+            line,
+        })
+    }
+
+    /// The bytes of the last line returned by [`next_line`](Self::next_line).
+    pub fn last_line(&self) -> &[u8] {
+        &self.data[self.last_line.clone()]
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.is_ending && self.position == self.data.len()
+    }
+}
@@ -4,12 +4,14 @@
 
 mod error;
 mod lexer;
+mod lines;
 mod parser;
 
-pub use self::error::{TextPosition, TurtleParseError, TurtleSyntaxError};
-pub use self::lexer::{Lexer, TokenOrLineJump, TokenRecognizer, TokenRecognizerError};
+pub use self::error::{TextPosition, TurtleParseError, TurtleSyntaxError, TurtleSyntaxErrorKind};
+pub use self::lexer::{Lexer, LexerLimits, TokenOrLineJump, TokenRecognizer, TokenRecognizerError};
+pub use self::lines::{LineSpan, LineSplitter};
 #[cfg(feature = "async-tokio")]
 pub use self::parser::TokioAsyncReaderIterator;
 pub use self::parser::{
-    Parser, ReaderIterator, RuleRecognizer, RuleRecognizerError, SliceIterator,
+    LowLevelParserState, Parser, ReaderIterator, RuleRecognizer, RuleRecognizerError, SliceIterator,
 };
@@ -1,4 +1,4 @@
-use crate::toolkit::error::{TurtleParseError, TurtleSyntaxError};
+use crate::toolkit::error::{TurtleParseError, TurtleSyntaxError, TurtleSyntaxErrorKind};
 use crate::toolkit::lexer::{Lexer, TokenOrLineJump, TokenRecognizer};
 use std::io::Read;
 use std::ops::Deref;
@@ -32,16 +32,57 @@ pub trait RuleRecognizer: Sized {
     ) -> &<Self::TokenRecognizer as TokenRecognizer>::Options;
 }
 
+/// An error raised by a [`RuleRecognizer`]
+///
+/// `TOKEN` in the message is replaced by the source of the last read token.
 pub struct RuleRecognizerError {
+    pub kind: TurtleSyntaxErrorKind,
     pub message: String,
 }
 
-impl<S: Into<String>> From<S> for RuleRecognizerError {
-    fn from(message: S) -> Self {
+impl RuleRecognizerError {
+    pub fn new(kind: TurtleSyntaxErrorKind, message: impl Into<String>) -> Self {
         Self {
+            kind,
             message: message.into(),
         }
     }
+
+    /// The last read token is not allowed here, `expected` describes what would have been allowed
+    pub fn unexpected_token(expected: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(
+            TurtleSyntaxErrorKind::UnexpectedToken {
+                found: String::new(),
+                expected: expected.into(),
+            },
+            message,
+        )
+    }
+
+    /// The file ends inside of `what`
+    pub fn unclosed_construct(what: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(
+            TurtleSyntaxErrorKind::UnclosedConstruct { what: what.into() },
+            message,
+        )
+    }
+}
+
+impl<S: Into<String>> From<S> for RuleRecognizerError {
+    fn from(message: S) -> Self {
+        Self::new(TurtleSyntaxErrorKind::Other, message)
+    }
+}
+
+/// What a low-level parser is waiting for, returned by its `state` method.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum LowLevelParserState {
+    /// All the buffered bytes have been parsed, more bytes should be provided with `extend_from_slice` or the end of the file signaled with `end`.
+    NeedsInput,
+    /// `parse_next` should be called, it might return new results from the bytes already provided.
+    HasPendingOutput,
+    /// The parsing is finished, `parse_next` is always going to return `None`.
+    Finished,
 }
 
 #[expect(clippy::partial_pub_fields)]
@@ -51,6 +92,8 @@ pub struct Parser<B, RR: RuleRecognizer> {
     pub context: RR::Context,
     results: Vec<RR::Output>,
     errors: Vec<RuleRecognizerError>,
+    /// The last call to `parse_next` returned `None` because the lexer needs more data
+    needs_input: bool,
 }
 
 impl<B, RR: RuleRecognizer> Parser<B, RR> {
@@ -61,6 +104,7 @@ impl<B, RR: RuleRecognizer> Parser<B, RR> {
             context,
             results: vec![],
             errors: vec![],
+            needs_input: true,
         }
     }
 }
@@ -74,11 +118,15 @@ impl<B: Deref<Target = [u8]>, RR: RuleRecognizer> Parser<B, RR> {
     pub fn parse_next(&mut self) -> Option<Result<RR::Output, TurtleSyntaxError>> {
         loop {
             if let Some(error) = self.errors.pop() {
+                let token = self.lexer.last_token_source();
+                let mut kind = error.kind;
+                if let TurtleSyntaxErrorKind::UnexpectedToken { found, .. } = &mut kind {
+                    *found = token.to_string();
+                }
                 return Some(Err(TurtleSyntaxError::new(
                     self.lexer.last_token_location(),
-                    error
-                        .message
-                        .replace("TOKEN", &self.lexer.last_token_source()),
+                    kind,
+                    error.message.replace("TOKEN", &token),
                 )));
             }
             if let Some(result) = self.results.pop() {
@@ -110,6 +158,7 @@ impl<B: Deref<Target = [u8]>, RR: RuleRecognizer> Parser<B, RR> {
                     &mut self.errors,
                 )
             } else {
+                self.needs_input = true;
                 return None;
             }
         }
@@ -119,11 +168,36 @@ impl<B: Deref<Target = [u8]>, RR: RuleRecognizer> Parser<B, RR> {
 impl<RR: RuleRecognizer> Parser<Vec<u8>, RR> {
     #[inline]
     pub fn end(&mut self) {
-        self.lexer.end()
+        self.lexer.end();
+        self.needs_input = false;
+    }
+
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
+        let len = self.lexer.extend_from_slice(other);
+        if len > 0 {
+            self.needs_input = false;
+        }
+        len
+    }
+
+    pub fn state(&self) -> LowLevelParserState {
+        if self.is_end() {
+            LowLevelParserState::Finished
+        } else if self.needs_input {
+            LowLevelParserState::NeedsInput
+        } else {
+            LowLevelParserState::HasPendingOutput
+        }
+    }
+
+    #[inline]
+    pub fn buffered_len(&self) -> usize {
+        self.lexer.buffered_len()
     }
 
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
-        self.lexer.extend_from_slice(other)
+    #[inline]
+    pub fn max_buffer_size(&self) -> usize {
+        self.lexer.max_buffer_size()
     }
 
     pub fn for_reader<R: Read>(self, reader: R) -> ReaderIterator<R, RR> {
@@ -1,22 +1,28 @@
 //! A [TriG](https://www.w3.org/TR/trig/) streaming parser implemented by [`TriGParser`]
 //! and a serializer implemented by [`TriGSerializer`].
 
+use crate::LiteralMapper;
 use crate::lexer::N3Lexer;
 use crate::terse::TriGRecognizer;
 #[cfg(feature = "async-tokio")]
 use crate::toolkit::TokioAsyncReaderIterator;
-use crate::toolkit::{Parser, ReaderIterator, SliceIterator, TurtleParseError, TurtleSyntaxError};
+use crate::toolkit::{
+    LexerLimits, LowLevelParserState, Parser, ReaderIterator, SliceIterator, TurtleParseError,
+    TurtleSyntaxError,
+};
 use oxiri::{Iri, IriParseError};
 use oxrdf::vocab::{rdf, xsd};
 use oxrdf::{
-    GraphName, GraphNameRef, LiteralRef, NamedNode, NamedNodeRef, NamedOrBlankNode, Quad, QuadRef,
-    TermRef,
+    GraphName, GraphNameRef, Literal, LiteralRef, NamedNode, NamedNodeRef, NamedOrBlankNode, Quad,
+    QuadRef, TermRef, TypedValue,
 };
 use std::borrow::Cow;
 use std::collections::hash_map::Iter;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::io::{self, Read, Write};
+use std::mem::take;
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 
@@ -50,6 +56,10 @@ use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 #[must_use]
 pub struct TriGParser {
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
+    limits: LexerLimits,
     base: Option<Iri<String>>,
     prefixes: HashMap<String, Iri<String>>,
 }
@@ -78,6 +88,105 @@ impl TriGParser {
         self.lenient()
     }
 
+    /// Checks that the lexical forms of the literals with a recognized XSD datatype like `xsd:integer` are valid.
+    ///
+    /// An invalid literal like `"abc"^^xsd:integer` is reported as a syntax error located on its datatype.
+    /// See [`Literal::parsed_value`](oxrdf::Literal::parsed_value) for the recognized datatypes.
+    ///
+    /// ```
+    /// use oxttl::TriGParser;
+    ///
+    /// let file = r#"@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+    /// <http://example.com/s> <http://example.com/p> "abc"^^xsd:integer ."#;
+    ///
+    /// let mut triples = TriGParser::new().with_literal_validation().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 53);
+    /// ```
+    #[inline]
+    pub fn with_literal_validation(mut self) -> Self {
+        self.validate_literals = true;
+        self
+    }
+
+    /// Rejects the RDF 1.2 triple terms `<<( )>>`, reified triples `<< >>`, reifiers `~` and annotations `{| |}`.
+    ///
+    /// They are reported as a syntax error located on their first token.
+    /// It allows to make sure the output is plain RDF 1.1 even if the `rdf-12` feature is enabled.
+    ///
+    /// ```
+    /// use oxttl::TriGParser;
+    ///
+    /// let file = "<< <http://example.com/a> <http://example.com/b> <http://example.com/c> >> <http://example.com/p> <http://example.com/o> .";
+    ///
+    /// let mut triples = TriGParser::new().without_triple_terms().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 0);
+    /// ```
+    #[inline]
+    pub fn without_triple_terms(mut self) -> Self {
+        self.reject_triple_terms = true;
+        self
+    }
+
+    /// Calls `mapper` on each parsed literal, including the ones in triple terms, before returning the triple.
+    ///
+    /// The literal is replaced by the returned one.
+    /// An error message is reported as a syntax error located on the literal last token
+    /// (the literal itself, its language tag or its datatype) or, for literals without language tag and datatype, on the token following them.
+    /// The rest of the statement is skipped like after any other syntax error.
+    /// [`with_literal_validation`](Self::with_literal_validation) checks the literals returned by `mapper`.
+    ///
+    /// ```
+    /// use oxttl::TriGParser;
+    ///
+    /// let file = "<http://example.com/g> { <http://example.com/s> <http://example.com/p> 1, -1 . }";
+    ///
+    /// let mut quads = TriGParser::new()
+    ///     .with_literal_mapper(|literal| {
+    ///         if literal.value().starts_with('-') {
+    ///             Err("Negative numbers are not allowed".into())
+    ///         } else {
+    ///             Ok(literal)
+    ///         }
+    ///     })
+    ///     .for_slice(file);
+    /// assert!(quads.next().unwrap().is_ok());
+    /// let error = quads.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 74);
+    /// ```
+    #[inline]
+    pub fn with_literal_mapper(
+        mut self,
+        mapper: impl Fn(Literal) -> Result<Literal, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.literal_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered.
+    /// There is no limit by default.
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.limits.max_token_length = Some(max_term_length);
+        self
+    }
+
+    /// Sets how many bytes are read at once from the input (4KiB by default)
+    /// and the maximal size of the parser buffer (16MiB by default).
+    ///
+    /// Parsing from a reader fails if the buffer would need to grow beyond its maximal size
+    /// and the low-level parser stops buffering new bytes when it is full.
+    #[inline]
+    pub fn with_buffer_sizes(mut self, min_buffer_size: usize, max_buffer_size: usize) -> Self {
+        self.limits.min_buffer_size = min_buffer_size;
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+
     #[inline]
     pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Result<Self, IriParseError> {
         self.base = Some(Iri::parse(base_iri.into())?);
@@ -200,6 +309,10 @@ impl TriGParser {
                 true,
                 true,
                 self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
                 self.base,
                 self.prefixes,
             )
@@ -252,6 +365,10 @@ impl TriGParser {
                 false,
                 true,
                 self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
                 self.base,
                 self.prefixes,
             ),
@@ -618,7 +735,12 @@ pub struct LowLevelTriGParser {
 
 impl LowLevelTriGParser {
     /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    ///
+    /// Returns the number of bytes of `other` that have been buffered.
+    /// It is lower than the length of `other` if the buffer has reached its [maximal size](Self::max_buffer_size):
+    /// the remaining bytes should be provided again after having called [`parse_next`](Self::parse_next).
+    /// If no byte is buffered while the parser [needs input](LowLevelParserState::NeedsInput), a term is longer than the maximal buffer size and the parsing cannot go on.
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.parser.extend_from_slice(other)
     }
 
@@ -642,6 +764,54 @@ impl LowLevelTriGParser {
         self.parser.parse_next()
     }
 
+    /// What the parser is waiting for.
+    ///
+    /// It allows to drive the parser from an event loop with back-pressure:
+    /// ```
+    /// use oxttl::{LowLevelParserState, TriGParser};
+    ///
+    /// let mut file = b"<http://example.com/s> <http://example.com/p> <http://example.com/o1> .
+    /// <http://example.com/s> <http://example.com/p> <http://example.com/o2> .".as_slice();
+    /// let mut parser = TriGParser::new().with_buffer_sizes(32, 64).low_level();
+    /// let mut count = 0;
+    /// loop {
+    ///     match parser.state() {
+    ///         LowLevelParserState::NeedsInput => {
+    ///             if file.is_empty() {
+    ///                 parser.end();
+    ///             } else {
+    ///                 // The parser only buffers the bytes it has room for
+    ///                 let buffered = parser.extend_from_slice(file);
+    ///                 file = &file[buffered..];
+    ///             }
+    ///         }
+    ///         LowLevelParserState::HasPendingOutput => {
+    ///             if let Some(triple) = parser.parse_next() {
+    ///                 triple?;
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///         LowLevelParserState::Finished => break,
+    ///     }
+    ///     assert!(parser.buffered_len() <= parser.max_buffer_size());
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn state(&self) -> LowLevelParserState {
+        self.parser.state()
+    }
+
+    /// The number of bytes provided with [`extend_from_slice`](Self::extend_from_slice) that have not been parsed yet.
+    pub fn buffered_len(&self) -> usize {
+        self.parser.buffered_len()
+    }
+
+    /// The maximal number of bytes the parser buffers, set with `with_buffer_sizes`.
+    pub fn max_buffer_size(&self) -> usize {
+        self.parser.max_buffer_size()
+    }
+
     /// The list of IRI prefixes considered at the current step of the parsing.
     ///
     /// This method returns (prefix name, prefix value) tuples.
@@ -727,6 +897,13 @@ impl<'a> Iterator for TriGPrefixesIter<'a> {
 
 /// A [TriG](https://www.w3.org/TR/trig/) serializer.
 ///
+/// The quads are grouped by graph name so that each graph is written in a single `graph-name { ... }` block.
+/// The triples of the default graph are written outside of any block.
+/// The quads of the graph currently being written are streamed,
+/// the other ones are buffered until the end of the serialization in a bounded amount of memory
+/// (when the bound is reached, the least recently extended graph is written, possibly splitting a graph into several blocks).
+/// Use [`TriGSerializer::grouped`] if the input is already grouped by graph name to disable buffering.
+///
 /// ```
 /// use oxrdf::{NamedNodeRef, QuadRef};
 /// use oxrdf::vocab::rdf;
@@ -752,6 +929,8 @@ impl<'a> Iterator for TriGPrefixesIter<'a> {
 pub struct TriGSerializer {
     base_iri: Option<Iri<String>>,
     prefixes: BTreeMap<String, String>,
+    canonicalize_literals: bool,
+    grouped: bool,
 }
 
 impl TriGSerializer {
@@ -761,6 +940,8 @@ impl TriGSerializer {
         Self {
             base_iri: None,
             prefixes: BTreeMap::new(),
+            canonicalize_literals: false,
+            grouped: false,
         }
     }
 
@@ -806,6 +987,95 @@ impl TriGSerializer {
         Ok(self)
     }
 
+    /// Writes the valid `xsd:boolean`, `xsd:integer`, `xsd:decimal` and `xsd:double` literals in their canonical form with the Turtle native syntax.
+    ///
+    /// By default, these literals are written with the native syntax only if their lexical form is allowed by the Turtle grammar,
+    /// so that parsing the file back returns exactly the same literals.
+    /// With this option, the lexical form might change (e.g. `"01"^^xsd:integer` is written `1`) but the literal value is kept.
+    /// The literals with an invalid lexical form and the infinite and NaN doubles are written as usual.
+    ///
+    /// ```
+    /// use oxrdf::vocab::xsd;
+    /// use oxrdf::{LiteralRef, NamedNodeRef, QuadRef};
+    /// use oxttl::TriGSerializer;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let mut serializer = TriGSerializer::new()
+    ///     .canonicalize_literals()
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_quad(QuadRef::new(
+    ///     ex,
+    ///     ex,
+    ///     LiteralRef::new_typed_literal("01", xsd::INTEGER),
+    ///     ex,
+    /// ))?;
+    /// serializer.serialize_quad(QuadRef::new(
+    ///     ex,
+    ///     ex,
+    ///     LiteralRef::new_typed_literal("100", xsd::DOUBLE),
+    ///     ex,
+    /// ))?;
+    /// assert_eq!(
+    ///     b"<http://example.com> {\n\t<http://example.com> <http://example.com> 1 , 1.0E2 .\n}\n",
+    ///     serializer.finish()?.as_slice()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn canonicalize_literals(mut self) -> Self {
+        self.canonicalize_literals = true;
+        self
+    }
+
+    /// Sets if the input quads are already grouped by graph name.
+    ///
+    /// If `true`, the quads are written as soon as they are given, without any buffering,
+    /// and a new block is started each time the graph name changes.
+    /// By default, the quads are buffered to write each graph in a single block.
+    ///
+    /// ```
+    /// use oxrdf::{NamedNodeRef, QuadRef};
+    /// use oxttl::TriGSerializer;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com/")?;
+    /// let g1 = NamedNodeRef::new("http://example.com/g1")?;
+    /// let g2 = NamedNodeRef::new("http://example.com/g2")?;
+    /// let quads = [
+    ///     QuadRef::new(ex, ex, ex, g1),
+    ///     QuadRef::new(ex, ex, ex, g2),
+    ///     QuadRef::new(ex, ex, g1, g1),
+    /// ];
+    ///
+    /// let mut serializer = TriGSerializer::new()
+    ///     .with_prefix("ex", "http://example.com/")?
+    ///     .for_writer(Vec::new());
+    /// for quad in quads {
+    ///     serializer.serialize_quad(quad)?;
+    /// }
+    /// assert_eq!(
+    ///     b"@prefix ex: <http://example.com/> .\nex:g1 {\n\tex: ex: ex: , ex:g1 .\n}\nex:g2 {\n\tex: ex: ex: .\n}\n",
+    ///     serializer.finish()?.as_slice()
+    /// );
+    ///
+    /// let mut serializer = TriGSerializer::new()
+    ///     .with_prefix("ex", "http://example.com/")?
+    ///     .grouped(true)
+    ///     .for_writer(Vec::new());
+    /// for quad in quads {
+    ///     serializer.serialize_quad(quad)?;
+    /// }
+    /// assert_eq!(
+    ///     b"@prefix ex: <http://example.com/> .\nex:g1 {\n\tex: ex: ex: .\n}\nex:g2 {\n\tex: ex: ex: .\n}\nex:g1 {\n\tex: ex: ex:g1 .\n}\n",
+    ///     serializer.finish()?.as_slice()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn grouped(mut self, grouped: bool) -> Self {
+        self.grouped = grouped;
+        self
+    }
+
     /// Writes a TriG file to a [`Write`] implementation.
     ///
     /// ```
@@ -908,9 +1178,13 @@ impl TriGSerializer {
         LowLevelTriGSerializer {
             prefixes,
             base_iri: self.base_iri,
+            canonicalize_literals: self.canonicalize_literals,
+            grouped: self.grouped,
             prelude_written: false,
             current_graph_name: GraphName::DefaultGraph,
             current_subject_predicate: None,
+            pending_graphs: Vec::new(),
+            pending_quads_count: 0,
         }
     }
 }
@@ -1046,11 +1320,21 @@ impl<W: AsyncWrite + Unpin> TokioAsyncWriterTriGSerializer<W> {
 pub struct LowLevelTriGSerializer {
     prefixes: Vec<(String, String)>,
     base_iri: Option<Iri<String>>,
+    canonicalize_literals: bool,
+    grouped: bool,
     prelude_written: bool,
     current_graph_name: GraphName,
     current_subject_predicate: Option<(NamedOrBlankNode, NamedNode)>,
+    /// Quads of the graphs other than the current one, from the least to the most recently extended graph
+    pending_graphs: Vec<(GraphName, Vec<Quad>)>,
+    pending_quads_count: usize,
 }
 
+/// Maximal number of graphs buffered by [`LowLevelTriGSerializer`]
+const MAX_PENDING_GRAPHS: usize = 64;
+/// Maximal number of quads buffered by [`LowLevelTriGSerializer`]
+const MAX_PENDING_QUADS: usize = 100_000;
+
 impl LowLevelTriGSerializer {
     /// Writes an extra quad.
     pub fn serialize_quad<'a>(
@@ -1072,6 +1356,40 @@ impl LowLevelTriGSerializer {
             }
         }
         let q = q.into();
+        if self.grouped
+            || self.current_subject_predicate.is_none()
+            || q.graph_name == self.current_graph_name.as_ref()
+        {
+            return self.write_quad(q, &mut writer);
+        }
+        // We buffer the quad until its graph is written
+        if let Some(position) = self
+            .pending_graphs
+            .iter()
+            .position(|(graph_name, _)| graph_name.as_ref() == q.graph_name)
+        {
+            let mut pending_graph = self.pending_graphs.remove(position);
+            pending_graph.1.push(q.into_owned());
+            self.pending_graphs.push(pending_graph);
+        } else {
+            self.pending_graphs
+                .push((q.graph_name.into_owned(), vec![q.into_owned()]));
+        }
+        self.pending_quads_count += 1;
+        if self.pending_graphs.len() > MAX_PENDING_GRAPHS
+            || self.pending_quads_count > MAX_PENDING_QUADS
+        {
+            // We write the least recently extended graph, it becomes the current one
+            let (_, quads) = self.pending_graphs.remove(0);
+            self.pending_quads_count -= quads.len();
+            for quad in &quads {
+                self.write_quad(quad.as_ref(), &mut writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_quad(&mut self, q: QuadRef<'_>, mut writer: impl Write) -> io::Result<()> {
         if q.graph_name == self.current_graph_name.as_ref() {
             if let Some((current_subject, current_predicate)) =
                 self.current_subject_predicate.take()
@@ -1168,11 +1486,18 @@ impl LowLevelTriGSerializer {
             term: term.into(),
             prefixes: &self.prefixes,
             base_iri: &self.base_iri,
+            canonicalize_literals: self.canonicalize_literals,
         }
     }
 
     /// Finishes to write the file.
     pub fn finish(&mut self, mut writer: impl Write) -> io::Result<()> {
+        for (_, quads) in take(&mut self.pending_graphs) {
+            for quad in &quads {
+                self.write_quad(quad.as_ref(), &mut writer)?;
+            }
+        }
+        self.pending_quads_count = 0;
         if self.current_subject_predicate.is_some() {
             writeln!(writer, " .")?;
         }
@@ -1198,6 +1523,7 @@ impl fmt::Display for TurtlePredicate<'_> {
                 term: self.named_node.into(),
                 prefixes: self.prefixes,
                 base_iri: self.base_iri,
+                canonicalize_literals: false,
             }
             .fmt(f)
         }
@@ -1208,6 +1534,7 @@ struct TurtleTerm<'a> {
     term: TermRef<'a>,
     prefixes: &'a Vec<(String, String)>,
     base_iri: &'a Option<Iri<String>>,
+    canonicalize_literals: bool,
 }
 
 impl fmt::Display for TurtleTerm<'_> {
@@ -1243,6 +1570,12 @@ impl fmt::Display for TurtleTerm<'_> {
                 };
                 if is_plain {
                     write!(f, "{v}")
+                } else if let Some(canonical) = self
+                    .canonicalize_literals
+                    .then(|| canonical_turtle_literal(v))
+                    .flatten()
+                {
+                    f.write_str(&canonical)
                 } else {
                     let inline = match v.datatype() {
                         xsd::BOOLEAN => is_turtle_boolean(value),
@@ -1262,6 +1595,7 @@ impl fmt::Display for TurtleTerm<'_> {
                                 term: v.datatype().into(),
                                 prefixes: self.prefixes,
                                 base_iri: self.base_iri,
+                                canonicalize_literals: self.canonicalize_literals,
                             }
                         )
                     }
@@ -1276,16 +1610,19 @@ impl fmt::Display for TurtleTerm<'_> {
                         term: t.subject.as_ref().into(),
                         prefixes: self.prefixes,
                         base_iri: self.base_iri,
+                        canonicalize_literals: self.canonicalize_literals,
                     },
                     TurtleTerm {
                         term: t.predicate.as_ref().into(),
                         prefixes: self.prefixes,
                         base_iri: self.base_iri,
+                        canonicalize_literals: self.canonicalize_literals,
                     },
                     TurtleTerm {
                         term: t.object.as_ref(),
                         prefixes: self.prefixes,
                         base_iri: self.base_iri,
+                        canonicalize_literals: self.canonicalize_literals,
                     }
                 )
             }
@@ -1302,6 +1639,39 @@ fn relative_iri<'a>(iri: &'a str, base_iri: &Option<Iri<String>>) -> Cow<'a, str
     iri.into()
 }
 
+/// The canonical form of the literal value written with the Turtle native syntax
+///
+/// Returns `None` if the literal is not a valid boolean, integer, decimal or double or if its value has no native syntax.
+fn canonical_turtle_literal(literal: LiteralRef<'_>) -> Option<String> {
+    match (literal.datatype(), literal.parsed_value().ok()?) {
+        (xsd::BOOLEAN, TypedValue::Boolean(value)) => Some(value.to_string()),
+        (xsd::INTEGER, TypedValue::Integer(value)) => Some(value.to_string()),
+        (xsd::DECIMAL, TypedValue::Decimal(value)) => {
+            // The canonical form of integral decimals has no '.' but the Turtle decimal syntax requires it
+            let mut value = value.to_string();
+            if !value.contains('.') {
+                value.push_str(".0");
+            }
+            Some(value)
+        }
+        (xsd::DOUBLE, TypedValue::Double(value)) => {
+            let value = f64::from(value);
+            if !value.is_finite() {
+                return None;
+            }
+            // The canonical mantissa always has a '.'
+            let mut value = format!("{value:E}");
+            if let Some(exponent_start) = value.find('E') {
+                if !value[..exponent_start].contains('.') {
+                    value.insert_str(exponent_start, ".0");
+                }
+            }
+            Some(value)
+        }
+        _ => None,
+    }
+}
+
 fn is_turtle_boolean(value: &str) -> bool {
     matches!(value, "true" | "false")
 }
@@ -1430,6 +1800,7 @@ fn can_be_escaped_in_local_name(c: char) -> bool {
 #[expect(clippy::panic_in_result_fn)]
 mod tests {
     use super::*;
+    use crate::TurtleSyntaxErrorKind;
     use oxrdf::BlankNodeRef;
 
     #[test]
@@ -1494,4 +1865,97 @@ mod tests {
         );
         Ok(())
     }
+
+    fn interleaved_quads(graph_count: usize, quads_per_graph: usize) -> Vec<Quad> {
+        let mut quads = Vec::new();
+        for i in 0..quads_per_graph {
+            for g in 0..graph_count {
+                let graph_name = if g == 0 {
+                    GraphName::DefaultGraph
+                } else {
+                    NamedNode::new_unchecked(format!("http://example.com/g{g}")).into()
+                };
+                quads.push(Quad::new(
+                    NamedNode::new_unchecked(format!("http://example.com/s{}", i % 3)),
+                    NamedNode::new_unchecked("http://example.com/p"),
+                    NamedNode::new_unchecked(format!("http://example.com/o{i}")),
+                    graph_name,
+                ));
+            }
+        }
+        quads
+    }
+
+    fn round_trip(quads: &[Quad], serializer: TriGSerializer) -> io::Result<String> {
+        let mut writer = serializer.for_writer(Vec::new());
+        for quad in quads {
+            writer.serialize_quad(quad)?;
+        }
+        let output = writer.finish()?;
+        let mut parsed = TriGParser::new()
+            .for_slice(&output)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(io::Error::other)?;
+        parsed.sort_unstable_by_key(ToString::to_string);
+        let mut expected = quads.to_vec();
+        expected.sort_unstable_by_key(ToString::to_string);
+        assert_eq!(parsed, expected);
+        String::from_utf8(output).map_err(io::Error::other)
+    }
+
+    #[test]
+    fn test_write_groups_graphs() -> io::Result<()> {
+        let quads = interleaved_quads(20, 10);
+        let output = round_trip(&quads, TriGSerializer::new())?;
+        for g in 1..20 {
+            assert_eq!(
+                output
+                    .matches(&format!("<http://example.com/g{g}> {{"))
+                    .count(),
+                1
+            );
+        }
+        // The default graph triples are not in a block
+        assert!(output.starts_with(
+            "<http://example.com/s0> <http://example.com/p> <http://example.com/o0> .\n"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_groups_many_graphs() -> io::Result<()> {
+        round_trip(&interleaved_quads(500, 5), TriGSerializer::new())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_grouped() -> io::Result<()> {
+        let quads = interleaved_quads(20, 10);
+        let output = round_trip(&quads, TriGSerializer::new().grouped(true))?;
+        assert_eq!(output.matches("<http://example.com/g1> {").count(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn error_kinds() {
+        let error = TriGParser::new()
+            .for_slice("GRAPH <http://example.com/g> <http://example.com/s> <http://example.com/p> <http://example.com/o> .")
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnexpectedToken { expected, .. } if expected == "'{'"
+        ));
+
+        let error = TriGParser::new()
+            .for_slice(
+                "<http://example.com/g> { <http://example.com/s> <http://example.com/p> ex:o }",
+            )
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UndefinedPrefix { prefix } if prefix == "ex"
+        ));
+    }
 }
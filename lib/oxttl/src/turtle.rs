@@ -1,20 +1,24 @@
 //! A [Turtle](https://www.w3.org/TR/turtle/) streaming parser implemented by [`TurtleParser`]
 //! and a serializer implemented by [`TurtleSerializer`].
 
-use crate::MIN_PARALLEL_CHUNK_SIZE;
 use crate::chunker::get_turtle_slice_chunks;
 use crate::terse::TriGRecognizer;
 #[cfg(feature = "async-tokio")]
 use crate::toolkit::TokioAsyncReaderIterator;
-use crate::toolkit::{Parser, ReaderIterator, SliceIterator, TurtleParseError, TurtleSyntaxError};
+use crate::toolkit::{
+    LexerLimits, LowLevelParserState, Parser, ReaderIterator, SliceIterator, TurtleParseError,
+    TurtleSyntaxError,
+};
 #[cfg(feature = "async-tokio")]
 use crate::trig::TokioAsyncWriterTriGSerializer;
 use crate::trig::{LowLevelTriGSerializer, TriGSerializer, WriterTriGSerializer};
+use crate::{LiteralMapper, MIN_PARALLEL_CHUNK_SIZE};
 use oxiri::{Iri, IriParseError};
-use oxrdf::{GraphNameRef, Triple, TripleRef};
+use oxrdf::{GraphNameRef, Literal, Triple, TripleRef};
 use std::collections::HashMap;
 use std::collections::hash_map::Iter;
 use std::io::{self, Read, Write};
+use std::sync::Arc;
 #[cfg(feature = "async-tokio")]
 use tokio::io::{AsyncRead, AsyncWrite};
 
@@ -48,6 +52,10 @@ use tokio::io::{AsyncRead, AsyncWrite};
 #[must_use]
 pub struct TurtleParser {
     lenient: bool,
+    validate_literals: bool,
+    reject_triple_terms: bool,
+    literal_mapper: Option<LiteralMapper>,
+    limits: LexerLimits,
     base: Option<Iri<String>>,
     prefixes: HashMap<String, Iri<String>>,
 }
@@ -76,6 +84,106 @@ impl TurtleParser {
         self.lenient()
     }
 
+    /// Checks that the lexical forms of the literals with a recognized XSD datatype like `xsd:integer` are valid.
+    ///
+    /// An invalid literal like `"abc"^^xsd:integer` is reported as a syntax error located on its datatype.
+    /// See [`Literal::parsed_value`](oxrdf::Literal::parsed_value) for the recognized datatypes.
+    ///
+    /// ```
+    /// use oxttl::TurtleParser;
+    ///
+    /// let file = r#"@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+    /// <http://example.com/s> <http://example.com/p> "abc"^^xsd:integer ."#;
+    ///
+    /// let mut triples = TurtleParser::new().with_literal_validation().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 53);
+    /// ```
+    #[inline]
+    pub fn with_literal_validation(mut self) -> Self {
+        self.validate_literals = true;
+        self
+    }
+
+    /// Rejects the RDF 1.2 triple terms `<<( )>>`, reified triples `<< >>`, reifiers `~` and annotations `{| |}`.
+    ///
+    /// They are reported as a syntax error located on their first token.
+    /// It allows to make sure the output is plain RDF 1.1 even if the `rdf-12` feature is enabled.
+    ///
+    /// ```
+    /// use oxttl::TurtleParser;
+    ///
+    /// let file = "<http://example.com/s> <http://example.com/p> <<( <http://example.com/a> <http://example.com/b> <http://example.com/c> )>> .";
+    ///
+    /// let mut triples = TurtleParser::new().without_triple_terms().for_slice(file);
+    /// let error = triples.next().unwrap().unwrap_err();
+    /// assert_eq!(error.location().start.column, 46);
+    /// ```
+    #[inline]
+    pub fn without_triple_terms(mut self) -> Self {
+        self.reject_triple_terms = true;
+        self
+    }
+
+    /// Calls `mapper` on each parsed literal, including the ones in triple terms, before returning the triple.
+    ///
+    /// The literal is replaced by the returned one.
+    /// An error message is reported as a syntax error located on the literal last token
+    /// (the literal itself, its language tag or its datatype) or, for literals without language tag and datatype, on the token following them.
+    /// The rest of the statement is skipped like after any other syntax error.
+    /// [`with_literal_validation`](Self::with_literal_validation) checks the literals returned by `mapper`.
+    ///
+    /// ```
+    /// use oxrdf::Literal;
+    /// use oxttl::TurtleParser;
+    ///
+    /// let file = "@prefix ex: <http://example.com/> .\nex:s ex:p \"1\"^^ex:int .";
+    ///
+    /// let triples = TurtleParser::new()
+    ///     .with_literal_mapper(|literal| {
+    ///         Ok(if literal.datatype().as_str().starts_with("http://example.com/") {
+    ///             Literal::new_simple_literal(literal.value())
+    ///         } else {
+    ///             literal
+    ///         })
+    ///     })
+    ///     .for_slice(file)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(triples[0].object, Literal::new_simple_literal("1").into());
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_literal_mapper(
+        mut self,
+        mapper: impl Fn(Literal) -> Result<Literal, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.literal_mapper = Some(Arc::new(mapper));
+        self
+    }
+
+    /// Limits the size in bytes of a single term as written in the file,
+    /// e.g. an IRI including its `<` and `>` delimiters or a string literal including its quotes.
+    ///
+    /// A longer term is reported as a syntax error as soon as the limit is exceeded instead of being fully buffered.
+    /// There is no limit by default.
+    #[inline]
+    pub fn with_max_term_length(mut self, max_term_length: usize) -> Self {
+        self.limits.max_token_length = Some(max_term_length);
+        self
+    }
+
+    /// Sets how many bytes are read at once from the input (4KiB by default)
+    /// and the maximal size of the parser buffer (16MiB by default).
+    ///
+    /// Parsing from a reader fails if the buffer would need to grow beyond its maximal size
+    /// and the low-level parser stops buffering new bytes when it is full.
+    #[inline]
+    pub fn with_buffer_sizes(mut self, min_buffer_size: usize, max_buffer_size: usize) -> Self {
+        self.limits.min_buffer_size = min_buffer_size;
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+
     #[inline]
     pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Result<Self, IriParseError> {
         self.base = Some(Iri::parse(base_iri.into())?);
@@ -198,6 +306,10 @@ impl TurtleParser {
                 true,
                 false,
                 self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
                 self.base,
                 self.prefixes,
             )
@@ -252,7 +364,7 @@ impl TurtleParser {
         let n_chunks = (slice.len() / MIN_PARALLEL_CHUNK_SIZE).clamp(1, target_parallelism);
 
         if n_chunks > 1 {
-            // Prefixes must be determined before chunks, since determining chunks relies on parser with prefixes determined.
+            // Prefixes and base IRI must be determined before chunks, since determining chunks relies on parser with prefixes determined.
             let mut from_slice_parser = self.clone().for_slice(slice);
             // We don't care about errors: they will be raised when parsing the first chunk anyway
             from_slice_parser.next();
@@ -260,6 +372,9 @@ impl TurtleParser {
                 // Already know this is a valid IRI
                 self = self.with_prefix(p, iri).unwrap();
             }
+            if let Some(base_iri) = from_slice_parser.base_iri() {
+                self.base = Some(Iri::parse_unchecked(base_iri.into()));
+            }
         }
 
         get_turtle_slice_chunks(slice, n_chunks, &self)
@@ -313,6 +428,10 @@ impl TurtleParser {
                 false,
                 false,
                 self.lenient,
+                self.validate_literals,
+                self.reject_triple_terms,
+                self.literal_mapper.clone(),
+                self.limits,
                 self.base,
                 self.prefixes,
             ),
@@ -679,7 +798,12 @@ pub struct LowLevelTurtleParser {
 
 impl LowLevelTurtleParser {
     /// Adds some extra bytes to the parser. Should be called when [`parse_next`](Self::parse_next) returns [`None`] and there is still unread data.
-    pub fn extend_from_slice(&mut self, other: &[u8]) {
+    ///
+    /// Returns the number of bytes of `other` that have been buffered.
+    /// It is lower than the length of `other` if the buffer has reached its [maximal size](Self::max_buffer_size):
+    /// the remaining bytes should be provided again after having called [`parse_next`](Self::parse_next).
+    /// If no byte is buffered while the parser [needs input](LowLevelParserState::NeedsInput), a term is longer than the maximal buffer size and the parsing cannot go on.
+    pub fn extend_from_slice(&mut self, other: &[u8]) -> usize {
         self.parser.extend_from_slice(other)
     }
 
@@ -703,6 +827,54 @@ impl LowLevelTurtleParser {
         Some(self.parser.parse_next()?.map(Into::into))
     }
 
+    /// What the parser is waiting for.
+    ///
+    /// It allows to drive the parser from an event loop with back-pressure:
+    /// ```
+    /// use oxttl::{LowLevelParserState, TurtleParser};
+    ///
+    /// let mut file = b"<http://example.com/s> <http://example.com/p> <http://example.com/o1> .
+    /// <http://example.com/s> <http://example.com/p> <http://example.com/o2> .".as_slice();
+    /// let mut parser = TurtleParser::new().with_buffer_sizes(32, 64).low_level();
+    /// let mut count = 0;
+    /// loop {
+    ///     match parser.state() {
+    ///         LowLevelParserState::NeedsInput => {
+    ///             if file.is_empty() {
+    ///                 parser.end();
+    ///             } else {
+    ///                 // The parser only buffers the bytes it has room for
+    ///                 let buffered = parser.extend_from_slice(file);
+    ///                 file = &file[buffered..];
+    ///             }
+    ///         }
+    ///         LowLevelParserState::HasPendingOutput => {
+    ///             if let Some(triple) = parser.parse_next() {
+    ///                 triple?;
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///         LowLevelParserState::Finished => break,
+    ///     }
+    ///     assert!(parser.buffered_len() <= parser.max_buffer_size());
+    /// }
+    /// assert_eq!(2, count);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn state(&self) -> LowLevelParserState {
+        self.parser.state()
+    }
+
+    /// The number of bytes provided with [`extend_from_slice`](Self::extend_from_slice) that have not been parsed yet.
+    pub fn buffered_len(&self) -> usize {
+        self.parser.buffered_len()
+    }
+
+    /// The maximal number of bytes the parser buffers, set with `with_buffer_sizes`.
+    pub fn max_buffer_size(&self) -> usize {
+        self.parser.max_buffer_size()
+    }
+
     /// The list of IRI prefixes considered at the current step of the parsing.
     ///
     /// This method returns (prefix name, prefix value) tuples.
@@ -858,6 +1030,44 @@ impl TurtleSerializer {
         Ok(self)
     }
 
+    /// Writes the valid `xsd:boolean`, `xsd:integer`, `xsd:decimal` and `xsd:double` literals in their canonical form with the Turtle native syntax.
+    ///
+    /// By default, these literals are written with the native syntax only if their lexical form is allowed by the Turtle grammar,
+    /// so that parsing the file back returns exactly the same literals.
+    /// With this option, the lexical form might change (e.g. `"01"^^xsd:integer` is written `1`) but the literal value is kept.
+    ///
+    /// ```
+    /// use oxrdf::vocab::xsd;
+    /// use oxrdf::{LiteralRef, NamedNodeRef, TripleRef};
+    /// use oxttl::TurtleSerializer;
+    ///
+    /// let ex = NamedNodeRef::new("http://example.com")?;
+    /// let mut serializer = TurtleSerializer::new()
+    ///     .with_prefix("xsd", "http://www.w3.org/2001/XMLSchema#")?
+    ///     .canonicalize_literals()
+    ///     .for_writer(Vec::new());
+    /// serializer.serialize_triple(TripleRef::new(
+    ///     ex,
+    ///     ex,
+    ///     LiteralRef::new_typed_literal("1.50", xsd::DECIMAL),
+    /// ))?;
+    /// serializer.serialize_triple(TripleRef::new(
+    ///     ex,
+    ///     ex,
+    ///     LiteralRef::new_typed_literal("INF", xsd::DOUBLE),
+    /// ))?;
+    /// assert_eq!(
+    ///     b"@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n<http://example.com> <http://example.com> 1.5 , \"INF\"^^xsd:double .\n",
+    ///     serializer.finish()?.as_slice()
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn canonicalize_literals(mut self) -> Self {
+        self.inner = self.inner.canonicalize_literals();
+        self
+    }
+
     /// Writes a Turtle file to a [`Write`] implementation.
     ///
     /// ```
@@ -1097,7 +1307,9 @@ impl LowLevelTurtleSerializer {
 #[expect(clippy::panic_in_result_fn)]
 mod tests {
     use super::*;
-    use oxrdf::{BlankNodeRef, LiteralRef, NamedNodeRef};
+    use crate::TurtleSyntaxErrorKind;
+    use oxrdf::vocab::xsd;
+    use oxrdf::{BlankNodeRef, LiteralRef, NamedNodeRef, Term, TypedValue};
 
     #[test]
     fn test_write() -> io::Result<()> {
@@ -1128,4 +1340,251 @@ mod tests {
         );
         Ok(())
     }
+
+    fn numeric_literals_round_trip(
+        serializer: TurtleSerializer,
+    ) -> io::Result<Vec<LiteralRef<'static>>> {
+        let literals = [
+            LiteralRef::new_typed_literal("true", xsd::BOOLEAN),
+            LiteralRef::new_typed_literal("0", xsd::BOOLEAN),
+            LiteralRef::new_typed_literal("42", xsd::INTEGER),
+            LiteralRef::new_typed_literal("+042", xsd::INTEGER),
+            LiteralRef::new_typed_literal("1.50", xsd::DECIMAL),
+            LiteralRef::new_typed_literal("1", xsd::DECIMAL),
+            LiteralRef::new_typed_literal("-.5", xsd::DECIMAL),
+            LiteralRef::new_typed_literal("1e3", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("0.5", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("-0", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("INF", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("-INF", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("NaN", xsd::DOUBLE),
+            LiteralRef::new_typed_literal("NaN", xsd::FLOAT),
+            LiteralRef::new_typed_literal("foo", xsd::INTEGER),
+            LiteralRef::new_typed_literal("5", xsd::INT),
+        ];
+        let s = NamedNodeRef::new_unchecked("http://example.com/s");
+        let p = NamedNodeRef::new_unchecked("http://example.com/p");
+        let mut serializer = serializer
+            .with_prefix("xsd", "http://www.w3.org/2001/XMLSchema#")
+            .map_err(io::Error::other)?
+            .for_writer(Vec::new());
+        for literal in literals {
+            serializer.serialize_triple(TripleRef::new(s, p, literal))?;
+        }
+        let serialization = serializer.finish()?;
+        let parsed = TurtleParser::new()
+            .for_slice(&serialization)
+            .map(|triple| match triple.map_err(io::Error::other)?.object {
+                Term::Literal(literal) => Ok(literal),
+                _ => Err(io::Error::other("a literal was expected")),
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(parsed.len(), literals.len());
+        for (original, parsed) in literals.iter().zip(&parsed) {
+            assert_eq!(original.datatype(), parsed.datatype());
+            match (original.parsed_value(), parsed.parsed_value()) {
+                // NaN is not equal to itself
+                (Ok(TypedValue::Double(original)), Ok(TypedValue::Double(parsed))) => {
+                    assert!(original.is_identical_with(parsed))
+                }
+                (Ok(TypedValue::Float(original)), Ok(TypedValue::Float(parsed))) => {
+                    assert!(original.is_identical_with(parsed))
+                }
+                (original, parsed) => assert_eq!(original.ok(), parsed.ok()),
+            }
+        }
+        Ok(parsed
+            .into_iter()
+            .zip(literals)
+            .filter_map(|(parsed, original)| (parsed.as_ref() != original).then_some(original))
+            .collect())
+    }
+
+    #[test]
+    fn test_numeric_literals_strict_round_trip() -> io::Result<()> {
+        // All the literals are kept as is
+        assert!(numeric_literals_round_trip(TurtleSerializer::new())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_literals_canonicalizing_round_trip() -> io::Result<()> {
+        let s = NamedNodeRef::new_unchecked("http://example.com/s");
+        let p = NamedNodeRef::new_unchecked("http://example.com/p");
+        let mut serializer = TurtleSerializer::new()
+            .canonicalize_literals()
+            .for_writer(Vec::new());
+        for (value, datatype) in [
+            ("0", xsd::BOOLEAN),
+            ("+042", xsd::INTEGER),
+            ("1", xsd::DECIMAL),
+            ("-.50", xsd::DECIMAL),
+            ("1e3", xsd::DOUBLE),
+            ("-0", xsd::DOUBLE),
+            ("NaN", xsd::DOUBLE),
+            ("-INF", xsd::DOUBLE),
+        ] {
+            serializer.serialize_triple(TripleRef::new(
+                s,
+                p,
+                LiteralRef::new_typed_literal(value, datatype),
+            ))?;
+        }
+        assert_eq!(
+            String::from_utf8(serializer.finish()?).map_err(io::Error::other)?,
+            "<http://example.com/s> <http://example.com/p> false , 42 , 1.0 , -0.5 , 1.0E3 , -0.0E0 , \"NaN\"^^<http://www.w3.org/2001/XMLSchema#double> , \"-INF\"^^<http://www.w3.org/2001/XMLSchema#double> .\n"
+        );
+
+        // Only the lexical forms of the valid literals with a native syntax change, their values are kept
+        let changed = numeric_literals_round_trip(TurtleSerializer::new().canonicalize_literals())?;
+        assert_eq!(
+            changed,
+            [
+                LiteralRef::new_typed_literal("0", xsd::BOOLEAN),
+                LiteralRef::new_typed_literal("+042", xsd::INTEGER),
+                LiteralRef::new_typed_literal("1.50", xsd::DECIMAL),
+                LiteralRef::new_typed_literal("1", xsd::DECIMAL),
+                LiteralRef::new_typed_literal("-.5", xsd::DECIMAL),
+                LiteralRef::new_typed_literal("1e3", xsd::DOUBLE),
+                LiteralRef::new_typed_literal("0.5", xsd::DOUBLE),
+                LiteralRef::new_typed_literal("-0", xsd::DOUBLE),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_slice_for_parallel_parsing_with_base_iri() -> Result<(), TurtleSyntaxError> {
+        let file = format!(
+            "@base <http://example.com/> .\n{}",
+            (0..2000)
+                .map(|i| format!("<s{i}> <p> <o> .\n"))
+                .collect::<String>()
+        );
+        let parsers = TurtleParser::new().split_slice_for_parallel_parsing(&file, 4);
+        assert!(parsers.len() > 1);
+        let mut count = 0;
+        for parser in parsers {
+            for triple in parser {
+                assert_eq!(
+                    triple?.predicate,
+                    NamedNodeRef::new_unchecked("http://example.com/p")
+                );
+                count += 1;
+            }
+        }
+        assert_eq!(count, 2000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_mapper_rejection_skips_statement() {
+        let file = "@prefix ex: <http://example.com/> .\nex:s ex:p \"a\", \"b\", \"c\" .\nex:s ex:p \"d\"@en, \"b\"^^ex:t .\nex:s ex:p \"e\" .";
+        let mut triples = TurtleParser::new()
+            .with_literal_mapper(|literal| {
+                if literal.value() == "b" {
+                    Err("b is not allowed".into())
+                } else {
+                    Ok(literal)
+                }
+            })
+            .for_slice(file);
+        assert_eq!(
+            triples.next().unwrap().unwrap().object,
+            Term::from(Literal::new_simple_literal("a"))
+        );
+        // Simple literals are only built when the next token is read
+        let error = triples.next().unwrap().unwrap_err();
+        assert_eq!(error.message(), "b is not allowed");
+        assert_eq!(
+            (error.location().start.line, error.location().start.column),
+            (1, 18)
+        );
+        assert_eq!(
+            triples.next().unwrap().unwrap().object,
+            Term::from(Literal::new_language_tagged_literal_unchecked("d", "en"))
+        );
+        let error = triples.next().unwrap().unwrap_err();
+        assert_eq!(
+            (error.location().start.line, error.location().start.column),
+            (2, 23)
+        );
+        assert_eq!(
+            triples.next().unwrap().unwrap().object,
+            Term::from(Literal::new_simple_literal("e"))
+        );
+        assert!(triples.next().is_none());
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        fn first_error(parser: TurtleParser, file: &str) -> TurtleSyntaxError {
+            parser.for_slice(file).find_map(Result::err).unwrap()
+        }
+
+        let error = first_error(TurtleParser::new(), "ex:s ex:p ex:o .");
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UndefinedPrefix { prefix } if prefix == "ex"
+        ));
+        assert_eq!(error.message(), "The prefix ex: has not been declared");
+
+        let error = first_error(
+            TurtleParser::new(),
+            "<s> <http://example.com/p> <http://example.com/o> .",
+        );
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::InvalidIri { value, .. } if value == "s"
+        ));
+
+        let error = first_error(
+            TurtleParser::new(),
+            "<http://example.com/s> <http://example.com/p> \"a\"@en-a .",
+        );
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::InvalidLanguageTag { value, .. } if value == "en-a"
+        ));
+
+        let error = first_error(
+            TurtleParser::new().with_literal_validation(),
+            "<http://example.com/s> <http://example.com/p> \"a\"^^<http://www.w3.org/2001/XMLSchema#integer> .",
+        );
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::InvalidLiteral { literal } if literal.value() == "a" && literal.datatype() == xsd::INTEGER
+        ));
+
+        let error = first_error(TurtleParser::new(), "<http://example.com/s> . ");
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnexpectedToken { found, expected } if found == "." && expected == "a predicate"
+        ));
+        assert_eq!(error.message(), ". is not a valid predicate");
+
+        let error = first_error(
+            TurtleParser::new(),
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o>",
+        );
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::UnclosedConstruct { what } if what == "statement"
+        ));
+
+        let error = first_error(
+            TurtleParser::new(),
+            "<http://example.com/s> <http://example.com/p> \"\\q\" .",
+        );
+        assert!(matches!(error.kind(), TurtleSyntaxErrorKind::InvalidToken));
+
+        let error = first_error(
+            TurtleParser::new().with_max_term_length(10),
+            "<http://example.com/s> <http://example.com/p> <http://example.com/o> .",
+        );
+        assert!(matches!(
+            error.kind(),
+            TurtleSyntaxErrorKind::TermTooLong { max_length: 10 }
+        ));
+    }
 }
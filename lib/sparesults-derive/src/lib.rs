@@ -0,0 +1,111 @@
+#![doc = include_str!("../README.md")]
+#![doc(test(attr(deny(warnings))))]
+#![doc(html_favicon_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields, LitStr, Path, parse_macro_input, parse_quote};
+
+/// Implements `TryFrom<&QuerySolution>` for a struct with named fields.
+///
+/// Each field is filled with the value of the variable with the same name using `QuerySolution::require`,
+/// so all field types must implement `FromSolutionValue`.
+/// Use an [`Option`] field type for variables that might be unbound.
+///
+/// The `#[sparesults(variable = "name")]` field attribute allows to read the field from a variable with a different name
+/// and the `#[sparesults(crate = "path")]` container attribute allows to use the macro through a re-export of `sparesults`,
+/// e.g. `#[sparesults(crate = "oxigraph::sparql::results")]`.
+///
+/// ```
+/// use oxrdf::{Literal, NamedNode, Variable};
+/// use sparesults::{FromQuerySolution, QuerySolution};
+///
+/// #[derive(FromQuerySolution)]
+/// struct Person {
+///     id: NamedNode,
+///     name: String,
+///     #[sparesults(variable = "years")]
+///     age: Option<i64>,
+/// }
+///
+/// let solution = QuerySolution::from((
+///     vec![Variable::new("id")?, Variable::new("name")?, Variable::new("years")?],
+///     vec![Some(NamedNode::new("http://example.com/alice")?.into()), Some(Literal::from("Alice").into()), None],
+/// ));
+/// let person = Person::try_from(&solution)?;
+/// assert_eq!(person.id.as_str(), "http://example.com/alice");
+/// assert_eq!(person.name, "Alice");
+/// assert_eq!(person.age, None);
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[proc_macro_derive(FromQuerySolution, attributes(sparesults))]
+pub fn derive_from_query_solution(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_query_solution(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_query_solution(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut krate: Path = parse_quote!(::sparesults);
+    for attr in &input.attrs {
+        if attr.path().is_ident("sparesults") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported sparesults container attribute"))
+                }
+            })?;
+        }
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FromQuerySolution can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FromQuerySolution can only be derived for structs with named fields",
+        ));
+    };
+    let mut field_values = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let mut variable = ident.unraw().to_string();
+        for attr in &field.attrs {
+            if attr.path().is_ident("sparesults") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("variable") {
+                        variable = meta.value()?.parse::<LitStr>()?.value();
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported sparesults field attribute"))
+                    }
+                })?;
+            }
+        }
+        field_values.push(quote! { #ident: solution.require(#variable)? });
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<&#krate::QuerySolution> for #name #ty_generics #where_clause {
+            type Error = #krate::solution::SolutionValueError;
+
+            fn try_from(solution: &#krate::QuerySolution) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(#field_values,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use oxrdf::vocab::xsd;
+use oxrdf::{Literal, NamedNode, Term, Variable};
+use sparesults::{FromQuerySolution, QuerySolution};
+
+#[derive(FromQuerySolution, Debug, PartialEq)]
+struct Person {
+    id: NamedNode,
+    name: String,
+    age: Option<i64>,
+    #[sparesults(variable = "isActive")]
+    active: bool,
+    r#type: Term,
+}
+
+#[derive(FromQuerySolution, Debug, PartialEq)]
+#[sparesults(crate = "sparesults")]
+struct Measure<T: sparesults::FromSolutionValue> {
+    value: T,
+}
+
+fn solution(age: Option<Term>, active: Term) -> QuerySolution {
+    QuerySolution::from((
+        vec![
+            Variable::new_unchecked("id"),
+            Variable::new_unchecked("name"),
+            Variable::new_unchecked("age"),
+            Variable::new_unchecked("isActive"),
+            Variable::new_unchecked("type"),
+        ],
+        vec![
+            Some(NamedNode::new_unchecked("http://example.com/alice").into()),
+            Some(Literal::new_language_tagged_literal_unchecked("Alice", "en").into()),
+            age,
+            Some(active),
+            Some(NamedNode::new_unchecked("http://example.com/Person").into()),
+        ],
+    ))
+}
+
+#[test]
+fn derive_from_query_solution() {
+    let person = Person::try_from(&solution(
+        Some(Literal::new_typed_literal("42", xsd::INT).into()),
+        Literal::from(true).into(),
+    ))
+    .unwrap();
+    assert_eq!(
+        person,
+        Person {
+            id: NamedNode::new_unchecked("http://example.com/alice"),
+            name: "Alice".into(),
+            age: Some(42),
+            active: true,
+            r#type: NamedNode::new_unchecked("http://example.com/Person").into(),
+        }
+    );
+}
+
+#[test]
+fn derive_from_query_solution_with_unbound_optional_variable() {
+    let person = Person::try_from(&solution(None, Literal::from(false).into())).unwrap();
+    assert_eq!(person.age, None);
+    assert!(!person.active);
+}
+
+#[test]
+fn derive_from_query_solution_with_missing_variable() {
+    let error = Measure::<f64>::try_from(&solution(None, Literal::from(true).into())).unwrap_err();
+    assert_eq!(error.variable().as_str(), "value");
+    assert_eq!(error.value(), None);
+    assert_eq!(
+        error.to_string(),
+        "The variable ?value is not bound, expected a numeric literal"
+    );
+}
+
+#[test]
+fn derive_from_query_solution_with_wrong_datatype() {
+    let error = Person::try_from(&solution(
+        Some(Literal::from(1.5).into()),
+        Literal::from(true).into(),
+    ))
+    .unwrap_err();
+    assert_eq!(error.variable().as_str(), "age");
+    assert_eq!(
+        error.to_string(),
+        "The variable ?age is bound to \"1.5\"^^<http://www.w3.org/2001/XMLSchema#double> that is not an xsd:integer"
+    );
+
+    let error = Person::try_from(&solution(None, Literal::from("yes").into())).unwrap_err();
+    assert_eq!(error.variable().as_str(), "isActive");
+    assert_eq!(error.value(), Some(&Literal::from("yes").into()));
+}
@@ -0,0 +1,377 @@
+//! Conversion of [`QuerySolution`]s into [Apache Arrow](https://arrow.apache.org/) record batches.
+
+use crate::solution::QuerySolution;
+use arrow_array::builder::{NullBufferBuilder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchReader, StructArray};
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, SchemaRef};
+use oxrdf::{Term, Variable};
+use std::error::Error;
+use std::sync::Arc;
+
+/// Converts query solutions into Arrow [`RecordBatch`]es of at most a given number of rows.
+///
+/// There is one column per variable.
+/// Each RDF term is encoded as a struct with the following fields:
+/// * `type`: the kind of term, one of `named_node`, `blank_node`, `literal` or `triple`.
+/// * `value`: the IRI of named nodes, the identifier of blank nodes, the lexical form of literals and the N-Triples serialization of triples.
+/// * `datatype`: the datatype IRI of literals, null otherwise.
+/// * `language`: the language tag of language-tagged string literals, null otherwise.
+/// * `direction`: the base direction (`ltr` or `rtl`) of directional language-tagged string literals, null otherwise (only with the `sparql-12` feature).
+///
+/// Unbound values are nulls.
+///
+/// Only the rows of a single batch are kept in memory at the same time.
+///
+/// ```
+/// use arrow_array::{Array, StructArray};
+/// use oxrdf::{Literal, NamedNode, Variable};
+/// use sparesults::QuerySolution;
+/// use sparesults::arrow::ArrowRecordBatchIter;
+/// use std::convert::Infallible;
+/// use std::sync::Arc;
+///
+/// let variables: Arc<[Variable]> = [Variable::new("s")?, Variable::new("o")?].into();
+/// let solutions = [
+///     vec![
+///         Some(NamedNode::new("http://example.com")?.into()),
+///         Some(Literal::from(1).into()),
+///     ],
+///     vec![None, Some(Literal::new_language_tagged_literal("foo", "en")?.into())],
+/// ]
+/// .map(|values| Ok::<_, Infallible>(QuerySolution::from((Arc::clone(&variables), values))));
+/// let batches =
+///     ArrowRecordBatchIter::new(&variables, solutions, 1024).collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(batches.len(), 1);
+/// assert_eq!(batches[0].num_rows(), 2);
+/// let s = batches[0]
+///     .column_by_name("s")
+///     .and_then(|c| c.as_any().downcast_ref::<StructArray>())
+///     .ok_or("s column not found")?;
+/// assert!(s.is_null(1)); // ?s is unbound in the second solution
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub struct ArrowRecordBatchIter<I> {
+    schema: SchemaRef,
+    solutions: I,
+    batch_size: usize,
+}
+
+impl<E: Error + Send + Sync + 'static, I: Iterator<Item = Result<QuerySolution, E>>>
+    ArrowRecordBatchIter<I>
+{
+    /// Builds a new iterator returning batches of at most `batch_size` rows (at least 1).
+    ///
+    /// `variables` must be the variables of the solutions, in the same order.
+    pub fn new(
+        variables: &[Variable],
+        solutions: impl IntoIterator<IntoIter = I>,
+        batch_size: usize,
+    ) -> Self {
+        Self {
+            schema: Arc::new(Schema::new(
+                variables
+                    .iter()
+                    .map(|v| Field::new(v.as_str(), DataType::Struct(term_fields()), true))
+                    .collect::<Vec<_>>(),
+            )),
+            solutions: solutions.into_iter(),
+            batch_size: batch_size.max(1),
+        }
+    }
+}
+
+impl<I> RecordBatchReader for ArrowRecordBatchIter<I>
+where
+    Self: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    #[inline]
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, I: Iterator<Item = Result<QuerySolution, E>>> Iterator
+    for ArrowRecordBatchIter<I>
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut columns = self
+            .schema
+            .fields()
+            .iter()
+            .map(|_| TermColumnBuilder::new(self.batch_size))
+            .collect::<Vec<_>>();
+        let mut len = 0;
+        while len < self.batch_size {
+            let Some(solution) = self.solutions.next() else {
+                break;
+            };
+            let solution = match solution {
+                Ok(solution) => solution,
+                Err(e) => return Some(Err(ArrowError::ExternalError(Box::new(e)))),
+            };
+            for (i, column) in columns.iter_mut().enumerate() {
+                column.append(solution.get(i));
+            }
+            len += 1;
+        }
+        if len == 0 {
+            return None;
+        }
+        Some(
+            columns
+                .into_iter()
+                .map(|c| -> Result<ArrayRef, ArrowError> { Ok(Arc::new(c.finish()?)) })
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|columns| RecordBatch::try_new(Arc::clone(&self.schema), columns)),
+        )
+    }
+}
+
+fn term_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("type", DataType::Utf8, false),
+        Field::new("value", DataType::Utf8, false),
+        Field::new("datatype", DataType::Utf8, true),
+        Field::new("language", DataType::Utf8, true),
+        #[cfg(feature = "sparql-12")]
+        Field::new("direction", DataType::Utf8, true),
+    ])
+}
+
+struct TermColumnBuilder {
+    types: StringBuilder,
+    values: StringBuilder,
+    datatypes: StringBuilder,
+    languages: StringBuilder,
+    #[cfg(feature = "sparql-12")]
+    directions: StringBuilder,
+    nulls: NullBufferBuilder,
+}
+
+impl TermColumnBuilder {
+    fn new(capacity: usize) -> Self {
+        Self {
+            types: StringBuilder::with_capacity(capacity, capacity * 8),
+            values: StringBuilder::with_capacity(capacity, capacity * 32),
+            datatypes: StringBuilder::with_capacity(capacity, capacity * 32),
+            languages: StringBuilder::new(),
+            #[cfg(feature = "sparql-12")]
+            directions: StringBuilder::new(),
+            nulls: NullBufferBuilder::new(capacity),
+        }
+    }
+
+    fn append(&mut self, term: Option<&Term>) {
+        let Some(term) = term else {
+            // Non-nullable fields still need a value
+            self.types.append_value("");
+            self.values.append_value("");
+            self.datatypes.append_null();
+            self.languages.append_null();
+            #[cfg(feature = "sparql-12")]
+            self.directions.append_null();
+            self.nulls.append_null();
+            return;
+        };
+        match term {
+            Term::NamedNode(node) => {
+                self.types.append_value("named_node");
+                self.values.append_value(node.as_str());
+                self.datatypes.append_null();
+                self.languages.append_null();
+                #[cfg(feature = "sparql-12")]
+                self.directions.append_null();
+            }
+            Term::BlankNode(node) => {
+                self.types.append_value("blank_node");
+                self.values.append_value(node.as_str());
+                self.datatypes.append_null();
+                self.languages.append_null();
+                #[cfg(feature = "sparql-12")]
+                self.directions.append_null();
+            }
+            Term::Literal(literal) => {
+                self.types.append_value("literal");
+                self.values.append_value(literal.value());
+                self.datatypes.append_value(literal.datatype().as_str());
+                self.languages.append_option(literal.language());
+                #[cfg(feature = "sparql-12")]
+                self.directions
+                    .append_option(literal.direction().map(|d| d.to_string()));
+            }
+            #[cfg(feature = "sparql-12")]
+            Term::Triple(_) => {
+                self.types.append_value("triple");
+                self.values.append_value(term.to_string());
+                self.datatypes.append_null();
+                self.languages.append_null();
+                self.directions.append_null();
+            }
+        }
+        self.nulls.append_non_null();
+    }
+
+    fn finish(mut self) -> Result<StructArray, ArrowError> {
+        StructArray::try_new(
+            term_fields(),
+            vec![
+                Arc::new(self.types.finish()),
+                Arc::new(self.values.finish()),
+                Arc::new(self.datatypes.finish()),
+                Arc::new(self.languages.finish()),
+                #[cfg(feature = "sparql-12")]
+                Arc::new(self.directions.finish()),
+            ],
+            self.nulls.finish(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use arrow_array::{Array, StringArray};
+    #[cfg(feature = "sparql-12")]
+    use oxrdf::{BaseDirection, Triple};
+    use oxrdf::{BlankNode, Literal, NamedNode};
+    use std::convert::Infallible;
+
+    fn to_batches(
+        values: Vec<Option<Term>>,
+        batch_size: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        let variables: Arc<[Variable]> = [Variable::new_unchecked("a")].into();
+        let solutions = values.into_iter().map(|value| {
+            Ok::<_, Infallible>(QuerySolution::from((Arc::clone(&variables), vec![value])))
+        });
+        ArrowRecordBatchIter::new(&variables, solutions, batch_size).collect()
+    }
+
+    fn field<'a>(column: &'a StructArray, name: &str, row: usize) -> Option<&'a str> {
+        let array = column
+            .column_by_name(name)?
+            .as_any()
+            .downcast_ref::<StringArray>()?;
+        array.is_valid(row).then(|| array.value(row))
+    }
+
+    /// Returns the type, value, datatype and language of the term in the given row
+    fn row(column: &StructArray, row: usize) -> [Option<&str>; 4] {
+        ["type", "value", "datatype", "language"].map(|name| field(column, name, row))
+    }
+
+    #[test]
+    fn test_term_kinds() -> Result<(), ArrowError> {
+        #[cfg_attr(not(feature = "sparql-12"), expect(unused_mut))]
+        let mut terms = vec![
+            Some(NamedNode::new_unchecked("http://example.com/s").into()),
+            Some(BlankNode::new_unchecked("b").into()),
+            Some(Literal::new_simple_literal("foo").into()),
+            Some(Literal::from(1).into()),
+            Some(Literal::new_language_tagged_literal_unchecked("bar", "en").into()),
+            None,
+        ];
+        #[cfg(feature = "sparql-12")]
+        terms.extend([
+            Some(
+                Literal::new_directional_language_tagged_literal_unchecked(
+                    "baz",
+                    "ar",
+                    BaseDirection::Rtl,
+                )
+                .into(),
+            ),
+            Some(
+                Triple::new(
+                    NamedNode::new_unchecked("http://example.com/s"),
+                    NamedNode::new_unchecked("http://example.com/p"),
+                    Literal::new_simple_literal("o"),
+                )
+                .into(),
+            ),
+        ]);
+        let len = terms.len();
+        let batches = to_batches(terms, 100)?;
+        assert_eq!(batches.len(), 1);
+        let column = batches[0]
+            .column_by_name("a")
+            .and_then(|c| c.as_any().downcast_ref::<StructArray>())
+            .ok_or_else(|| ArrowError::SchemaError("?a column not found".into()))?;
+        assert_eq!(column.len(), len);
+        assert_eq!(
+            row(column, 0),
+            [Some("named_node"), Some("http://example.com/s"), None, None]
+        );
+        assert_eq!(row(column, 1), [Some("blank_node"), Some("b"), None, None]);
+        assert_eq!(
+            row(column, 2),
+            [
+                Some("literal"),
+                Some("foo"),
+                Some("http://www.w3.org/2001/XMLSchema#string"),
+                None
+            ]
+        );
+        assert_eq!(
+            row(column, 3),
+            [
+                Some("literal"),
+                Some("1"),
+                Some("http://www.w3.org/2001/XMLSchema#integer"),
+                None
+            ]
+        );
+        assert_eq!(
+            row(column, 4),
+            [
+                Some("literal"),
+                Some("bar"),
+                Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString"),
+                Some("en")
+            ]
+        );
+        assert!(column.is_valid(4));
+        assert!(column.is_null(5));
+        #[cfg(feature = "sparql-12")]
+        {
+            assert_eq!(
+                row(column, 6),
+                [
+                    Some("literal"),
+                    Some("baz"),
+                    Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#dirLangString"),
+                    Some("ar")
+                ]
+            );
+            assert_eq!(field(column, "direction", 6), Some("rtl"));
+            assert_eq!(field(column, "direction", 4), None);
+            assert_eq!(
+                row(column, 7),
+                [
+                    Some("triple"),
+                    Some("<<( <http://example.com/s> <http://example.com/p> \"o\" )>>"),
+                    None,
+                    None
+                ]
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_size() -> Result<(), ArrowError> {
+        let batches = to_batches((0..5).map(|i| Some(Literal::from(i).into())).collect(), 2)?;
+        assert_eq!(
+            batches
+                .iter()
+                .map(RecordBatch::num_rows)
+                .collect::<Vec<_>>(),
+            [2, 2, 1]
+        );
+        assert!(to_batches(Vec::new(), 2)?.is_empty());
+        Ok(())
+    }
+}
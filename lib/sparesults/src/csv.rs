@@ -11,7 +11,34 @@ use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 const MAX_BUFFER_SIZE: usize = 4096 * 4096;
 
-pub fn write_boolean_csv_result<W: Write>(mut writer: W, value: bool) -> io::Result<W> {
+const UTF8_BOM: &str = "\u{feff}";
+
+/// Options of the CSV serializer
+#[derive(Clone, Copy)]
+pub struct CsvSerializerOptions {
+    /// Starts the output with a UTF-8 byte order mark
+    pub bom: bool,
+    /// Ends rows with CRLF like RFC 4180 mandates, LF is used otherwise
+    pub crlf: bool,
+}
+
+impl Default for CsvSerializerOptions {
+    fn default() -> Self {
+        Self {
+            bom: false,
+            crlf: true,
+        }
+    }
+}
+
+pub fn write_boolean_csv_result<W: Write>(
+    mut writer: W,
+    value: bool,
+    options: CsvSerializerOptions,
+) -> io::Result<W> {
+    if options.bom {
+        writer.write_all(UTF8_BOM.as_bytes())?;
+    }
     writer.write_all(if value { b"true" } else { b"false" })?;
     Ok(writer)
 }
@@ -20,7 +47,11 @@ pub fn write_boolean_csv_result<W: Write>(mut writer: W, value: bool) -> io::Res
 pub async fn tokio_async_write_boolean_csv_result<W: AsyncWrite + Unpin>(
     mut writer: W,
     value: bool,
+    options: CsvSerializerOptions,
 ) -> io::Result<W> {
+    if options.bom {
+        writer.write_all(UTF8_BOM.as_bytes()).await?;
+    }
     writer
         .write_all(if value { b"true" } else { b"false" })
         .await?;
@@ -34,9 +65,13 @@ pub struct WriterCsvSolutionsSerializer<W: Write> {
 }
 
 impl<W: Write> WriterCsvSolutionsSerializer<W> {
-    pub fn start(mut writer: W, variables: Vec<Variable>) -> io::Result<Self> {
+    pub fn start(
+        mut writer: W,
+        variables: Vec<Variable>,
+        options: CsvSerializerOptions,
+    ) -> io::Result<Self> {
         let mut buffer = String::new();
-        let inner = InnerCsvSolutionsSerializer::start(&mut buffer, variables);
+        let inner = InnerCsvSolutionsSerializer::start(&mut buffer, variables, options);
         writer.write_all(buffer.as_bytes())?;
         buffer.clear();
         Ok(Self {
@@ -70,9 +105,13 @@ pub struct TokioAsyncWriterCsvSolutionsSerializer<W: AsyncWrite + Unpin> {
 
 #[cfg(feature = "async-tokio")]
 impl<W: AsyncWrite + Unpin> TokioAsyncWriterCsvSolutionsSerializer<W> {
-    pub async fn start(mut writer: W, variables: Vec<Variable>) -> io::Result<Self> {
+    pub async fn start(
+        mut writer: W,
+        variables: Vec<Variable>,
+        options: CsvSerializerOptions,
+    ) -> io::Result<Self> {
         let mut buffer = String::new();
-        let inner = InnerCsvSolutionsSerializer::start(&mut buffer, variables);
+        let inner = InnerCsvSolutionsSerializer::start(&mut buffer, variables, options);
         writer.write_all(buffer.as_bytes()).await?;
         buffer.clear();
         Ok(Self {
@@ -99,10 +138,15 @@ impl<W: AsyncWrite + Unpin> TokioAsyncWriterCsvSolutionsSerializer<W> {
 
 struct InnerCsvSolutionsSerializer {
     variables: Vec<Variable>,
+    line_ending: &'static str,
 }
 
 impl InnerCsvSolutionsSerializer {
-    fn start(output: &mut String, variables: Vec<Variable>) -> Self {
+    fn start(output: &mut String, variables: Vec<Variable>, options: CsvSerializerOptions) -> Self {
+        let line_ending = if options.crlf { "\r\n" } else { "\n" };
+        if options.bom {
+            output.push_str(UTF8_BOM);
+        }
         let mut start_vars = true;
         for variable in &variables {
             if start_vars {
@@ -112,8 +156,11 @@ impl InnerCsvSolutionsSerializer {
             }
             output.push_str(variable.as_str());
         }
-        output.push_str("\r\n");
-        Self { variables }
+        output.push_str(line_ending);
+        Self {
+            variables,
+            line_ending,
+        }
     }
 
     fn write<'a>(
@@ -128,6 +175,7 @@ impl InnerCsvSolutionsSerializer {
             }
         }
         let mut start_binding = true;
+        let mut field = String::new();
         for value in values {
             if start_binding {
                 start_binding = false;
@@ -135,10 +183,13 @@ impl InnerCsvSolutionsSerializer {
                 output.push(',');
             }
             if let Some(value) = value {
-                write_csv_term(output, value);
+                // The field is escaped as a whole, IRIs and triple terms might also contain separators
+                write_csv_term(&mut field, value);
+                write_escaped_csv_string(output, &field);
+                field.clear();
             }
         }
-        output.push_str("\r\n");
+        output.push_str(self.line_ending);
     }
 }
 
@@ -149,7 +200,7 @@ fn write_csv_term<'a>(output: &mut String, term: impl Into<TermRef<'a>>) {
             output.push_str("_:");
             output.push_str(bnode.as_str())
         }
-        TermRef::Literal(literal) => write_escaped_csv_string(output, literal.value()),
+        TermRef::Literal(literal) => output.push_str(literal.value()),
         #[cfg(feature = "sparql-12")]
         TermRef::Triple(triple) => {
             write_csv_term(output, &triple.subject);
@@ -161,6 +212,7 @@ fn write_csv_term<'a>(output: &mut String, term: impl Into<TermRef<'a>>) {
     }
 }
 
+/// Quotes the field if it contains a comma, a double quote, CR or LF following RFC 4180
 fn write_escaped_csv_string(output: &mut String, s: &str) {
     if s.bytes().any(|c| matches!(c, b'"' | b',' | b'\n' | b'\r')) {
         output.push('"');
@@ -886,7 +938,11 @@ mod tests {
     fn test_csv_serialization() {
         let (variables, solutions) = build_example();
         let mut buffer = String::new();
-        let serializer = InnerCsvSolutionsSerializer::start(&mut buffer, variables.clone());
+        let serializer = InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            variables.clone(),
+            CsvSerializerOptions::default(),
+        );
         for solution in solutions {
             serializer.write(
                 &mut buffer,
@@ -905,6 +961,94 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_csv_serialization_quoting() {
+        let variables = vec![Variable::new_unchecked("a"), Variable::new_unchecked("b")];
+        let mut buffer = String::new();
+        let serializer = InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            variables.clone(),
+            CsvSerializerOptions::default(),
+        );
+        for (a, b) in [
+            (
+                Term::from(NamedNode::new_unchecked("http://example.com/a,b")),
+                Term::from(Literal::new_simple_literal("\"quoted\", then\r\nnew line")),
+            ),
+            (
+                Literal::new_simple_literal("\"").into(),
+                Literal::new_simple_literal("Gr\u{fc}\u{df}e, \u{4e16}\u{754c}").into(),
+            ),
+            (
+                Literal::new_simple_literal("").into(),
+                Literal::new_simple_literal(" \t;").into(),
+            ),
+        ] {
+            serializer.write(
+                &mut buffer,
+                [
+                    (variables[0].as_ref(), a.as_ref()),
+                    (variables[1].as_ref(), b.as_ref()),
+                ],
+            );
+        }
+        assert_eq!(
+            buffer,
+            "a,b\r\n\"http://example.com/a,b\",\"\"\"quoted\"\", then\r\nnew line\"\r\n\"\"\"\",\"Gr\u{fc}\u{df}e, \u{4e16}\u{754c}\"\r\n, \t;\r\n"
+        );
+    }
+
+    #[cfg(feature = "sparql-12")]
+    #[test]
+    fn test_csv_triple_term_serialization() {
+        let variable = Variable::new_unchecked("t");
+        let triple = Term::from(Triple::new(
+            NamedNode::new_unchecked("http://example.com/s"),
+            NamedNode::new_unchecked("http://example.com/p"),
+            Literal::new_simple_literal("a \"b\", c"),
+        ));
+        let mut buffer = String::new();
+        let serializer = InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            vec![variable.clone()],
+            CsvSerializerOptions::default(),
+        );
+        serializer.write(&mut buffer, [(variable.as_ref(), triple.as_ref())]);
+        assert_eq!(
+            buffer,
+            "t\r\n\"http://example.com/s http://example.com/p a \"\"b\"\", c\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_serialization_options() {
+        let variable = Variable::new_unchecked("a");
+        let value = Term::from(Literal::new_simple_literal("\u{e9}\n\u{e8}"));
+        let mut buffer = String::new();
+        let serializer = InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            vec![variable.clone()],
+            CsvSerializerOptions {
+                bom: true,
+                crlf: false,
+            },
+        );
+        serializer.write(&mut buffer, [(variable.as_ref(), value.as_ref())]);
+        assert_eq!(buffer, "\u{feff}a\n\"\u{e9}\n\u{e8}\"\n");
+        assert_eq!(
+            write_boolean_csv_result(
+                Vec::new(),
+                true,
+                CsvSerializerOptions {
+                    bom: true,
+                    crlf: true,
+                },
+            )
+            .unwrap(),
+            b"\xEF\xBB\xBFtrue"
+        );
+    }
+
     #[test]
     fn test_tsv_roundtrip() -> Result<(), Box<dyn Error>> {
         let (variables, solutions) = build_example();
@@ -977,7 +1121,11 @@ mod tests {
     #[test]
     fn test_no_columns_csv_serialization() {
         let mut buffer = String::new();
-        let serializer = InnerCsvSolutionsSerializer::start(&mut buffer, Vec::new());
+        let serializer = InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            Vec::new(),
+            CsvSerializerOptions::default(),
+        );
         serializer.write(&mut buffer, []);
         assert_eq!(buffer, "\r\n\r\n");
     }
@@ -1009,7 +1157,11 @@ mod tests {
     #[test]
     fn test_no_results_csv_serialization() {
         let mut buffer = String::new();
-        InnerCsvSolutionsSerializer::start(&mut buffer, vec![Variable::new_unchecked("a")]);
+        InnerCsvSolutionsSerializer::start(
+            &mut buffer,
+            vec![Variable::new_unchecked("a")],
+            CsvSerializerOptions::default(),
+        );
         assert_eq!(buffer, "a\r\n");
     }
 
@@ -72,6 +72,51 @@ impl QueryResultsFormat {
         }
     }
 
+    /// All the known media types of the format, starting with the canonical one returned by [`media_type`](Self::media_type).
+    ///
+    /// They are all recognized by [`from_media_type`](Self::from_media_type).
+    ///
+    /// ```
+    /// use sparesults::QueryResultsFormat;
+    ///
+    /// assert_eq!(
+    ///     QueryResultsFormat::Xml.media_types(),
+    ///     ["application/sparql-results+xml", "application/xml", "text/xml"]
+    /// )
+    /// ```
+    #[inline]
+    pub const fn media_types(self) -> &'static [&'static str] {
+        match self {
+            Self::Xml => &[
+                "application/sparql-results+xml",
+                "application/xml",
+                "text/xml",
+            ],
+            Self::Json => &["application/sparql-results+json", "application/json"],
+            Self::Csv => &["text/csv; charset=utf-8", "text/plain"],
+            Self::Tsv => &["text/tab-separated-values; charset=utf-8", "text/tsv"],
+        }
+    }
+
+    /// All the known file extensions of the format, starting with the canonical one returned by [`file_extension`](Self::file_extension).
+    ///
+    /// They are all recognized by [`from_extension`](Self::from_extension).
+    ///
+    /// ```
+    /// use sparesults::QueryResultsFormat;
+    ///
+    /// assert_eq!(QueryResultsFormat::Json.file_extensions(), ["srj", "json"])
+    /// ```
+    #[inline]
+    pub const fn file_extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Xml => &["srx", "xml"],
+            Self::Json => &["srj", "json"],
+            Self::Csv => &["csv", "txt"],
+            Self::Tsv => &["tsv"],
+        }
+    }
+
     /// The format name.
     ///
     /// ```
@@ -138,7 +183,8 @@ impl QueryResultsFormat {
 
     /// Looks for a known format from an extension.
     ///
-    /// It supports some aliases.
+    /// It supports all the extensions returned by [`file_extensions`](Self::file_extensions)
+    /// and compound extensions ending with a compression format like `srj.gz`.
     ///
     /// Example:
     /// ```
@@ -147,30 +193,59 @@ impl QueryResultsFormat {
     /// assert_eq!(
     ///     QueryResultsFormat::from_extension("json"),
     ///     Some(QueryResultsFormat::Json)
-    /// )
+    /// );
+    /// assert_eq!(
+    ///     QueryResultsFormat::from_extension("srj.gz"),
+    ///     Some(QueryResultsFormat::Json)
+    /// );
     /// ```
     #[inline]
     pub fn from_extension(extension: &str) -> Option<Self> {
-        const EXTENSIONS: [(&str, QueryResultsFormat); 7] = [
-            ("csv", QueryResultsFormat::Csv),
-            ("json", QueryResultsFormat::Json),
-            ("srj", QueryResultsFormat::Json),
-            ("srx", QueryResultsFormat::Xml),
-            ("tsv", QueryResultsFormat::Tsv),
-            ("txt", QueryResultsFormat::Csv),
-            ("xml", QueryResultsFormat::Xml),
-        ];
-        for (candidate_extension, candidate_id) in EXTENSIONS {
-            if candidate_extension.eq_ignore_ascii_case(extension) {
-                return Some(candidate_id);
-            }
+        if let Some((extension, compression)) = extension.rsplit_once('.') {
+            return COMPRESSION_EXTENSIONS
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(compression))
+                .then(|| Self::from_extension(extension))
+                .flatten();
         }
-        None
+        Self::KNOWN.iter().copied().find(|candidate_id| {
+            candidate_id
+                .file_extensions()
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(extension))
+        })
     }
+
+    const KNOWN: &[Self] = &[Self::Xml, Self::Json, Self::Csv, Self::Tsv];
 }
 
+/// File extensions of the compression formats that might be appended to a format extension
+const COMPRESSION_EXTENSIONS: [&str; 5] = ["br", "bz2", "gz", "xz", "zst"];
+
 impl fmt::Display for QueryResultsFormat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_types_and_file_extensions_are_recognized() {
+        for &format in QueryResultsFormat::KNOWN {
+            assert_eq!(format.media_types()[0], format.media_type());
+            for media_type in format.media_types() {
+                assert_eq!(
+                    QueryResultsFormat::from_media_type(media_type),
+                    Some(format)
+                );
+            }
+            assert_eq!(format.file_extensions()[0], format.file_extension());
+            for extension in format.file_extensions() {
+                assert_eq!(QueryResultsFormat::from_extension(extension), Some(format));
+            }
+        }
+    }
+}
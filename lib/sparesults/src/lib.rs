@@ -4,6 +4,8 @@
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
 mod csv;
 mod error;
 mod format;
@@ -26,4 +28,6 @@ pub use crate::parser::{
 #[cfg(feature = "async-tokio")]
 pub use crate::serializer::TokioAsyncWriterSolutionsSerializer;
 pub use crate::serializer::{QueryResultsSerializer, WriterSolutionsSerializer};
-pub use crate::solution::QuerySolution;
+pub use crate::solution::{FromSolutionValue, QuerySolution, SolutionValueError};
+#[cfg(feature = "derive")]
+pub use sparesults_derive::FromQuerySolution;
@@ -1,11 +1,12 @@
+use crate::csv::{
+    CsvSerializerOptions, WriterCsvSolutionsSerializer, WriterTsvSolutionsSerializer,
+    write_boolean_csv_result,
+};
 #[cfg(feature = "async-tokio")]
 use crate::csv::{
     TokioAsyncWriterCsvSolutionsSerializer, TokioAsyncWriterTsvSolutionsSerializer,
     tokio_async_write_boolean_csv_result,
 };
-use crate::csv::{
-    WriterCsvSolutionsSerializer, WriterTsvSolutionsSerializer, write_boolean_csv_result,
-};
 use crate::format::QueryResultsFormat;
 #[cfg(feature = "async-tokio")]
 use crate::json::{TokioAsyncWriterJsonSolutionsSerializer, tokio_async_write_boolean_json_result};
@@ -51,13 +52,62 @@ use tokio::io::AsyncWrite;
 #[derive(Clone)]
 pub struct QueryResultsSerializer {
     format: QueryResultsFormat,
+    csv_options: CsvSerializerOptions,
 }
 
 impl QueryResultsSerializer {
     /// Builds a serializer for the given format.
     #[inline]
     pub fn from_format(format: QueryResultsFormat) -> Self {
-        Self { format }
+        Self {
+            format,
+            csv_options: CsvSerializerOptions::default(),
+        }
+    }
+
+    /// Starts the CSV output with a UTF-8 [byte order mark](https://en.wikipedia.org/wiki/Byte_order_mark).
+    ///
+    /// It allows spreadsheet software like Microsoft Excel to detect that the file is encoded in UTF-8.
+    /// This option has no effect on the other formats.
+    ///
+    /// ```
+    /// use sparesults::{QueryResultsFormat, QueryResultsSerializer};
+    ///
+    /// let mut buffer = Vec::new();
+    /// QueryResultsSerializer::from_format(QueryResultsFormat::Csv)
+    ///     .with_csv_bom()
+    ///     .serialize_boolean_to_writer(&mut buffer, true)?;
+    /// assert_eq!(buffer, b"\xEF\xBB\xBFtrue");
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_csv_bom(mut self) -> Self {
+        self.csv_options.bom = true;
+        self
+    }
+
+    /// Ends the CSV rows with LF instead of the CRLF mandated by [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+    ///
+    /// This option has no effect on the other formats.
+    ///
+    /// ```
+    /// use oxrdf::{LiteralRef, Variable, VariableRef};
+    /// use sparesults::{QueryResultsFormat, QueryResultsSerializer};
+    /// use std::iter::once;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut serializer = QueryResultsSerializer::from_format(QueryResultsFormat::Csv)
+    ///     .with_csv_lf_line_endings()
+    ///     .serialize_solutions_to_writer(&mut buffer, vec![Variable::new("foo")?])?;
+    /// serializer.serialize(once((VariableRef::new("foo")?, LiteralRef::from("a, \"b\""))))?;
+    /// serializer.finish()?;
+    /// assert_eq!(buffer, b"foo\n\"a, \"\"b\"\"\"\n");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_csv_lf_line_endings(mut self) -> Self {
+        self.csv_options.crlf = false;
+        self
     }
 
     /// Write a boolean query result (from an `ASK` query)  into the given [`Write`] implementation.
@@ -76,8 +126,9 @@ impl QueryResultsSerializer {
         match self.format {
             QueryResultsFormat::Xml => write_boolean_xml_result(writer, value),
             QueryResultsFormat::Json => write_boolean_json_result(writer, value),
-            QueryResultsFormat::Csv | QueryResultsFormat::Tsv => {
-                write_boolean_csv_result(writer, value)
+            QueryResultsFormat::Csv => write_boolean_csv_result(writer, value, self.csv_options),
+            QueryResultsFormat::Tsv => {
+                write_boolean_csv_result(writer, value, CsvSerializerOptions::default())
             }
         }
     }
@@ -108,8 +159,12 @@ impl QueryResultsSerializer {
         match self.format {
             QueryResultsFormat::Xml => tokio_async_write_boolean_xml_result(writer, value).await,
             QueryResultsFormat::Json => tokio_async_write_boolean_json_result(writer, value).await,
-            QueryResultsFormat::Csv | QueryResultsFormat::Tsv => {
-                tokio_async_write_boolean_csv_result(writer, value).await
+            QueryResultsFormat::Csv => {
+                tokio_async_write_boolean_csv_result(writer, value, self.csv_options).await
+            }
+            QueryResultsFormat::Tsv => {
+                tokio_async_write_boolean_csv_result(writer, value, CsvSerializerOptions::default())
+                    .await
             }
         }
     }
@@ -152,7 +207,7 @@ impl QueryResultsSerializer {
                     WriterJsonSolutionsSerializer::start(writer, &variables)?,
                 ),
                 QueryResultsFormat::Csv => WriterSolutionsSerializerKind::Csv(
-                    WriterCsvSolutionsSerializer::start(writer, variables)?,
+                    WriterCsvSolutionsSerializer::start(writer, variables, self.csv_options)?,
                 ),
                 QueryResultsFormat::Tsv => WriterSolutionsSerializerKind::Tsv(
                     WriterTsvSolutionsSerializer::start(writer, variables)?,
@@ -203,7 +258,12 @@ impl QueryResultsSerializer {
                     TokioAsyncWriterJsonSolutionsSerializer::start(writer, &variables).await?,
                 ),
                 QueryResultsFormat::Csv => TokioAsyncWriterSolutionsSerializerKind::Csv(
-                    TokioAsyncWriterCsvSolutionsSerializer::start(writer, variables).await?,
+                    TokioAsyncWriterCsvSolutionsSerializer::start(
+                        writer,
+                        variables,
+                        self.csv_options,
+                    )
+                    .await?,
                 ),
                 QueryResultsFormat::Tsv => TokioAsyncWriterSolutionsSerializerKind::Tsv(
                     TokioAsyncWriterTsvSolutionsSerializer::start(writer, variables).await?,
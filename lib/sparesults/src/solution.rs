@@ -1,6 +1,11 @@
 //! Definition of [`QuerySolution`] structure and associated utility constructions.
 
-use oxrdf::{Term, Variable, VariableRef};
+use oxrdf::dataset::GraphView;
+use oxrdf::{
+    ListError, Literal, NamedNode, NamedOrBlankNodeRef, Term, TypedValue, Variable, VariableRef,
+};
+use oxsdatatypes::{DateTime, Double};
+use std::error::Error;
 use std::fmt;
 use std::iter::Zip;
 use std::ops::Index;
@@ -19,6 +24,7 @@ use std::sync::Arc;
 /// assert_eq!(solution.get(1), None); // Get the value of the second column if it exists (here no).
 /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
 /// ```
+#[derive(Clone)]
 pub struct QuerySolution {
     variables: Arc<[Variable]>,
     values: Vec<Option<Term>>,
@@ -41,6 +47,236 @@ impl QuerySolution {
         self.values.get(index.index(self)?).and_then(Option::as_ref)
     }
 
+    /// Reads the [RDF list](https://www.w3.org/TR/rdf11-mt/#rdf-collections) whose head is the value for a given position in the tuple or a given variable name.
+    ///
+    /// The list is read from the given graph, usually from the same dataset as the one the query has been evaluated on.
+    /// Returns `None` if the value is not set or is not a named or blank node.
+    /// See [`GraphView::read_list`] for the possible errors.
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphNameRef, Literal, Term, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let mut dataset = Dataset::new();
+    /// let head = dataset.insert_list([Literal::from(1).as_ref()], GraphNameRef::DefaultGraph);
+    /// let solution = QuerySolution::from((vec![Variable::new("list")?], vec![Some(head.into())]));
+    /// assert_eq!(
+    ///     solution.get_list("list", &dataset.graph(GraphNameRef::DefaultGraph))?,
+    ///     Some(vec![Term::from(Literal::from(1))])
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn get_list(
+        &self,
+        index: impl VariableSolutionIndex,
+        graph: &GraphView<'_>,
+    ) -> Result<Option<Vec<Term>>, ListError> {
+        let head = match self.get(index) {
+            Some(Term::NamedNode(head)) => NamedOrBlankNodeRef::from(head),
+            Some(Term::BlankNode(head)) => NamedOrBlankNodeRef::from(head),
+            _ => return Ok(None),
+        };
+        graph.read_list(head).map(Some)
+    }
+
+    /// Returns the value of a string literal, i.e. a literal with the [xsd:string](https://www.w3.org/TR/xmlschema11-2/#string) datatype or a [language-tagged string](https://www.w3.org/TR/rdf11-concepts/#dfn-language-tagged-string).
+    ///
+    /// Returns `None` if the value is not set or is not a string literal.
+    ///
+    /// ```
+    /// use oxrdf::{Literal, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((
+    ///     vec![Variable::new("name")?, Variable::new("age")?],
+    ///     vec![Some(Literal::new_language_tagged_literal("Alice", "en")?.into()), Some(Literal::from(42).into())],
+    /// ));
+    /// assert_eq!(solution.get_str("name"), Some("Alice"));
+    /// assert_eq!(solution.get_str("age"), None);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_str(&self, index: impl VariableSolutionIndex) -> Option<&str> {
+        term_to_str(self.get(index))
+    }
+
+    /// Returns the value of a literal with the [xsd:integer](https://www.w3.org/TR/xmlschema11-2/#integer) datatype or one of its derived datatypes like [xsd:int](https://www.w3.org/TR/xmlschema11-2/#int).
+    ///
+    /// Returns `None` if the value is not set, is not an integer or is not a valid integer.
+    ///
+    /// ```
+    /// use oxrdf::{Literal, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((
+    ///     vec![Variable::new("age")?, Variable::new("name")?],
+    ///     vec![Some(Literal::from(42).into()), Some(Literal::from("Alice").into())],
+    /// ));
+    /// assert_eq!(solution.get_i64("age"), Some(42));
+    /// assert_eq!(solution.get_i64("name"), None);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_i64(&self, index: impl VariableSolutionIndex) -> Option<i64> {
+        i64::from_solution_value(self.get(index))
+    }
+
+    /// Returns the value of a numeric literal, i.e. a literal with the [xsd:double](https://www.w3.org/TR/xmlschema11-2/#double), [xsd:float](https://www.w3.org/TR/xmlschema11-2/#float), [xsd:decimal](https://www.w3.org/TR/xmlschema11-2/#decimal) or [xsd:integer](https://www.w3.org/TR/xmlschema11-2/#integer) datatype.
+    ///
+    /// Returns `None` if the value is not set or is not a valid numeric literal.
+    ///
+    /// ```
+    /// use oxrdf::{Literal, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((vec![Variable::new("size")?], vec![Some(Literal::from(1.5).into())]));
+    /// assert_eq!(solution.get_f64("size"), Some(1.5));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_f64(&self, index: impl VariableSolutionIndex) -> Option<f64> {
+        f64::from_solution_value(self.get(index))
+    }
+
+    /// Returns the value of a literal with the [xsd:boolean](https://www.w3.org/TR/xmlschema11-2/#boolean) datatype.
+    ///
+    /// Returns `None` if the value is not set or is not a valid boolean.
+    ///
+    /// ```
+    /// use oxrdf::{Literal, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((vec![Variable::new("active")?], vec![Some(Literal::from(true).into())]));
+    /// assert_eq!(solution.get_bool("active"), Some(true));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_bool(&self, index: impl VariableSolutionIndex) -> Option<bool> {
+        bool::from_solution_value(self.get(index))
+    }
+
+    /// Returns the value if it is an IRI.
+    ///
+    /// ```
+    /// use oxrdf::{NamedNode, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// let solution = QuerySolution::from((vec![Variable::new("s")?], vec![Some(ex.clone().into())]));
+    /// assert_eq!(solution.get_named_node("s"), Some(&ex));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_named_node(&self, index: impl VariableSolutionIndex) -> Option<&NamedNode> {
+        if let Some(Term::NamedNode(node)) = self.get(index) {
+            Some(node)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a literal with the [xsd:dateTime](https://www.w3.org/TR/xmlschema11-2/#dateTime) or [xsd:dateTimeStamp](https://www.w3.org/TR/xmlschema11-2/#dateTimeStamp) datatype.
+    ///
+    /// Returns `None` if the value is not set or is not a valid date time.
+    ///
+    /// ```
+    /// use oxrdf::vocab::xsd;
+    /// use oxrdf::{Literal, Variable};
+    /// use oxsdatatypes::DateTime;
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((
+    ///     vec![Variable::new("date")?],
+    ///     vec![Some(Literal::new_typed_literal("2020-01-01T00:00:00Z", xsd::DATE_TIME).into())],
+    /// ));
+    /// assert_eq!(solution.get_datetime("date"), Some("2020-01-01T00:00:00Z".parse::<DateTime>()?));
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn get_datetime(&self, index: impl VariableSolutionIndex) -> Option<DateTime> {
+        DateTime::from_solution_value(self.get(index))
+    }
+
+    /// Converts the value of the given variable using [`FromSolutionValue`].
+    ///
+    /// The variable name is given without the leading `?`.
+    /// Unlike the `get_*` methods, it returns an error naming the variable and its actual value if the variable is not bound or the conversion fails.
+    /// Use an [`Option`] target type to allow the variable to be unbound.
+    ///
+    /// ```
+    /// use oxrdf::{Literal, Variable};
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((
+    ///     vec![Variable::new("age")?, Variable::new("name")?],
+    ///     vec![Some(Literal::from(42).into()), None],
+    /// ));
+    /// assert_eq!(solution.require::<i64>("age")?, 42);
+    /// assert_eq!(solution.require::<Option<String>>("name")?, None);
+    /// assert_eq!(
+    ///     solution.require::<String>("age").unwrap_err().to_string(),
+    ///     "The variable ?age is bound to \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> that is not a string literal"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn require<T: FromSolutionValue>(&self, variable: &str) -> Result<T, SolutionValueError> {
+        let value = self.get(variable);
+        T::from_solution_value(value)
+            .ok_or_else(|| SolutionValueError::new(variable, value, T::EXPECTED))
+    }
+
+    /// Same as [`get_str`](Self::get_str) but returns an error if the variable is not bound to a string literal.
+    ///
+    /// ```
+    /// use oxrdf::Variable;
+    /// use sparesults::QuerySolution;
+    ///
+    /// let solution = QuerySolution::from((vec![Variable::new("name")?], vec![None]));
+    /// assert_eq!(
+    ///     solution.require_str("name").unwrap_err().to_string(),
+    ///     "The variable ?name is not bound, expected a string literal"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn require_str(&self, variable: &str) -> Result<&str, SolutionValueError> {
+        let value = self.get(variable);
+        term_to_str(value).ok_or_else(|| SolutionValueError::new(variable, value, String::EXPECTED))
+    }
+
+    /// Same as [`get_i64`](Self::get_i64) but returns an error if the variable is not bound to an integer.
+    #[inline]
+    pub fn require_i64(&self, variable: &str) -> Result<i64, SolutionValueError> {
+        self.require(variable)
+    }
+
+    /// Same as [`get_f64`](Self::get_f64) but returns an error if the variable is not bound to a numeric literal.
+    #[inline]
+    pub fn require_f64(&self, variable: &str) -> Result<f64, SolutionValueError> {
+        self.require(variable)
+    }
+
+    /// Same as [`get_bool`](Self::get_bool) but returns an error if the variable is not bound to a boolean.
+    #[inline]
+    pub fn require_bool(&self, variable: &str) -> Result<bool, SolutionValueError> {
+        self.require(variable)
+    }
+
+    /// Same as [`get_named_node`](Self::get_named_node) but returns an error if the variable is not bound to an IRI.
+    #[inline]
+    pub fn require_named_node(&self, variable: &str) -> Result<&NamedNode, SolutionValueError> {
+        self.get_named_node(variable).ok_or_else(|| {
+            SolutionValueError::new(variable, self.get(variable), NamedNode::EXPECTED)
+        })
+    }
+
+    /// Same as [`get_datetime`](Self::get_datetime) but returns an error if the variable is not bound to a date time.
+    #[inline]
+    pub fn require_datetime(&self, variable: &str) -> Result<DateTime, SolutionValueError> {
+        self.require(variable)
+    }
+
     /// The number of variables which could be bound.
     ///
     /// It is also the number of columns in the solutions table.
@@ -322,3 +558,323 @@ impl VariableSolutionIndex for Variable {
         self.as_ref().index(solution)
     }
 }
+
+/// A type that can be built from the value bound to a variable in a [`QuerySolution`].
+///
+/// It is used by [`QuerySolution::require`] and by the `FromQuerySolution` derive macro available behind the `derive` feature.
+///
+/// ```
+/// use oxrdf::{Literal, Term};
+/// use sparesults::solution::FromSolutionValue;
+///
+/// let value = Term::from(Literal::from(42));
+/// assert_eq!(i64::from_solution_value(Some(&value)), Some(42));
+/// assert_eq!(bool::from_solution_value(Some(&value)), None);
+/// assert_eq!(Option::<i64>::from_solution_value(None), Some(None));
+/// ```
+pub trait FromSolutionValue: Sized {
+    /// A description of the expected values used in error messages, e.g. "an xsd:integer".
+    const EXPECTED: &'static str;
+
+    /// Builds the value from the term bound to the variable, `None` if the variable is not bound.
+    ///
+    /// Returns `None` if the conversion is not possible.
+    fn from_solution_value(value: Option<&Term>) -> Option<Self>;
+}
+
+impl FromSolutionValue for Term {
+    const EXPECTED: &'static str = "an RDF term";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        value.cloned()
+    }
+}
+
+impl FromSolutionValue for NamedNode {
+    const EXPECTED: &'static str = "an IRI";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        if let Some(Term::NamedNode(node)) = value {
+            Some(node.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromSolutionValue for Literal {
+    const EXPECTED: &'static str = "a literal";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        if let Some(Term::Literal(literal)) = value {
+            Some(literal.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromSolutionValue for String {
+    const EXPECTED: &'static str = "a string literal";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        term_to_str(value).map(Into::into)
+    }
+}
+
+impl FromSolutionValue for i64 {
+    const EXPECTED: &'static str = "an xsd:integer";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        if let Some(TypedValue::Integer(value)) = parse_term(value) {
+            Some(value.into())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromSolutionValue for f64 {
+    const EXPECTED: &'static str = "a numeric literal";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        Some(match parse_term(value)? {
+            TypedValue::Double(value) => value.into(),
+            TypedValue::Float(value) => value.into(),
+            TypedValue::Decimal(value) => Double::from(value).into(),
+            TypedValue::Integer(value) => Double::from(value).into(),
+            _ => return None,
+        })
+    }
+}
+
+impl FromSolutionValue for bool {
+    const EXPECTED: &'static str = "an xsd:boolean";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        if let Some(TypedValue::Boolean(value)) = parse_term(value) {
+            Some(value.into())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromSolutionValue for DateTime {
+    const EXPECTED: &'static str = "an xsd:dateTime";
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        if let Some(TypedValue::DateTime(value)) = parse_term(value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: FromSolutionValue> FromSolutionValue for Option<T> {
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    #[inline]
+    fn from_solution_value(value: Option<&Term>) -> Option<Self> {
+        match value {
+            Some(value) => T::from_solution_value(Some(value)).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+fn parse_term(value: Option<&Term>) -> Option<TypedValue<'_>> {
+    if let Some(Term::Literal(literal)) = value {
+        literal.parsed_value().ok()
+    } else {
+        None
+    }
+}
+
+fn term_to_str(value: Option<&Term>) -> Option<&str> {
+    match parse_term(value)? {
+        TypedValue::String(value) | TypedValue::LangString { value, .. } => Some(value),
+        _ => None,
+    }
+}
+
+/// An error returned when the value of a [`QuerySolution`] variable can't be converted to the requested type.
+///
+/// ```
+/// use oxrdf::{Literal, Variable};
+/// use sparesults::QuerySolution;
+///
+/// let solution = QuerySolution::from((vec![Variable::new("age")?], vec![Some(Literal::from("old").into())]));
+/// let error = solution.require_i64("age").unwrap_err();
+/// assert_eq!(error.variable().as_str(), "age");
+/// assert_eq!(error.value(), Some(&Literal::from("old").into()));
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SolutionValueError {
+    variable: Variable,
+    value: Option<Term>,
+    expected: &'static str,
+}
+
+impl SolutionValueError {
+    fn new(variable: &str, value: Option<&Term>, expected: &'static str) -> Self {
+        Self {
+            variable: Variable::new_unchecked(variable),
+            value: value.cloned(),
+            expected,
+        }
+    }
+
+    /// The variable whose value can't be converted.
+    #[inline]
+    pub fn variable(&self) -> VariableRef<'_> {
+        self.variable.as_ref()
+    }
+
+    /// The value bound to the variable, `None` if the variable is not bound.
+    #[inline]
+    pub fn value(&self) -> Option<&Term> {
+        self.value.as_ref()
+    }
+}
+
+impl fmt::Display for SolutionValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(value) = &self.value {
+            write!(
+                f,
+                "The variable {} is bound to {value} that is not {}",
+                self.variable, self.expected
+            )
+        } else {
+            write!(
+                f,
+                "The variable {} is not bound, expected {}",
+                self.variable, self.expected
+            )
+        }
+    }
+}
+
+impl Error for SolutionValueError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxrdf::vocab::xsd;
+
+    fn solution() -> QuerySolution {
+        QuerySolution::from((
+            vec![
+                Variable::new_unchecked("string"),
+                Variable::new_unchecked("integer"),
+                Variable::new_unchecked("short"),
+                Variable::new_unchecked("decimal"),
+                Variable::new_unchecked("boolean"),
+                Variable::new_unchecked("iri"),
+                Variable::new_unchecked("date"),
+                Variable::new_unchecked("invalid"),
+                Variable::new_unchecked("unbound"),
+            ],
+            vec![
+                Some(Literal::new_simple_literal("foo").into()),
+                Some(Literal::from(12).into()),
+                Some(Literal::new_typed_literal("3", xsd::SHORT).into()),
+                Some(Literal::new_typed_literal("1.5", xsd::DECIMAL).into()),
+                Some(Literal::from(true).into()),
+                Some(NamedNode::new_unchecked("http://example.com/").into()),
+                Some(Literal::new_typed_literal("2020-01-01T00:00:00Z", xsd::DATE_TIME).into()),
+                Some(Literal::new_typed_literal("foo", xsd::INTEGER).into()),
+                None,
+            ],
+        ))
+    }
+
+    #[test]
+    fn typed_getters() {
+        let solution = solution();
+        assert_eq!(solution.get_str("string"), Some("foo"));
+        assert_eq!(solution.get_i64("integer"), Some(12));
+        assert_eq!(solution.get_i64("short"), Some(3));
+        assert_eq!(solution.get_f64("decimal"), Some(1.5));
+        assert_eq!(solution.get_f64("integer"), Some(12.));
+        assert_eq!(solution.get_bool("boolean"), Some(true));
+        assert_eq!(
+            solution.get_named_node("iri").map(NamedNode::as_str),
+            Some("http://example.com/")
+        );
+        assert_eq!(
+            solution.get_datetime("date").map(|d| d.to_string()),
+            Some("2020-01-01T00:00:00Z".into())
+        );
+        assert_eq!(solution.get_i64(1), Some(12));
+    }
+
+    #[test]
+    fn typed_getters_with_wrong_datatypes() {
+        let solution = solution();
+        assert_eq!(solution.get_str("integer"), None);
+        assert_eq!(solution.get_str("iri"), None);
+        assert_eq!(solution.get_i64("decimal"), None);
+        assert_eq!(solution.get_i64("invalid"), None);
+        assert_eq!(solution.get_f64("string"), None);
+        assert_eq!(solution.get_bool("integer"), None);
+        assert_eq!(solution.get_named_node("string"), None);
+        assert_eq!(solution.get_datetime("string"), None);
+    }
+
+    #[test]
+    fn typed_getters_with_missing_variables() {
+        let solution = solution();
+        assert_eq!(solution.get_str("unbound"), None);
+        assert_eq!(solution.get_i64("unknown"), None);
+        assert_eq!(solution.get_named_node(42), None);
+    }
+
+    #[test]
+    fn require_errors() {
+        let solution = solution();
+        assert_eq!(solution.require_str("string").ok(), Some("foo"));
+        assert_eq!(solution.require::<Option<i64>>("unbound").ok(), Some(None));
+        assert_eq!(
+            solution.require_i64("unbound").unwrap_err().to_string(),
+            "The variable ?unbound is not bound, expected an xsd:integer"
+        );
+        assert_eq!(
+            solution
+                .require_named_node("unknown")
+                .unwrap_err()
+                .to_string(),
+            "The variable ?unknown is not bound, expected an IRI"
+        );
+        let error = solution.require_bool("string").unwrap_err();
+        assert_eq!(error.variable().as_str(), "string");
+        assert_eq!(
+            error.value(),
+            Some(&Literal::new_simple_literal("foo").into())
+        );
+        assert_eq!(
+            error.to_string(),
+            "The variable ?string is bound to \"foo\" that is not an xsd:boolean"
+        );
+        assert_eq!(
+            solution
+                .require::<Option<i64>>("invalid")
+                .unwrap_err()
+                .to_string(),
+            "The variable ?invalid is bound to \"foo\"^^<http://www.w3.org/2001/XMLSchema#integer> that is not an xsd:integer"
+        );
+        assert_eq!(solution.require_f64("date").ok(), None);
+        assert_eq!(solution.require_datetime("iri").ok(), None);
+    }
+}
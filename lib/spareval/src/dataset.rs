@@ -6,7 +6,7 @@ use oxrdf::{
 };
 #[cfg(feature = "sparql-12")]
 use oxrdf::{NamedOrBlankNode, Triple};
-use oxsdatatypes::{Boolean, DateTime, Decimal, Double, Float, Integer};
+use oxsdatatypes::{Base64Binary, Boolean, DateTime, Decimal, Double, Float, HexBinary, Integer};
 #[cfg(feature = "sep-0002")]
 use oxsdatatypes::{Date, DayTimeDuration, Duration, Time, YearMonthDuration};
 #[cfg(feature = "calendar-ext")]
@@ -17,6 +17,7 @@ use std::error::Error;
 use std::hash::{Hash, Hasher};
 use std::iter::empty;
 use std::mem::discriminant;
+use std::ops::Bound;
 
 /// A [RDF dataset](https://www.w3.org/TR/sparql11-query/#rdfDataset) that can be queried using SPARQL
 pub trait QueryableDataset<'a>: Sized + 'a {
@@ -41,6 +42,25 @@ pub trait QueryableDataset<'a>: Sized + 'a {
         graph_name: Option<Option<&Self::InternalTerm>>,
     ) -> impl Iterator<Item = Result<InternalQuad<Self::InternalTerm>, Self::Error>> + use<'a, Self>;
 
+    /// Fetches quads according to a pattern, the object being in a given range of literal values
+    ///
+    /// The bounds are literals compared with the SPARQL `<` and `<=` operators.
+    /// The range is only a hint: quads with an object outside of the range might be returned and are filtered out by the caller.
+    /// It allows datasets with ordered indexes to avoid a full scan.
+    /// By default, the range is ignored.
+    ///
+    /// For `graph_name`, `Some(None)` encodes the default graph, `Some(Some(_))` a named graph and `None` any named graph (but not the default one!)
+    fn internal_quads_for_pattern_with_object_range(
+        &self,
+        subject: Option<&Self::InternalTerm>,
+        predicate: Option<&Self::InternalTerm>,
+        _object_range: (Bound<&Self::InternalTerm>, Bound<&Self::InternalTerm>),
+        graph_name: Option<Option<&Self::InternalTerm>>,
+    ) -> impl Iterator<Item = Result<InternalQuad<Self::InternalTerm>, Self::Error>> + use<'a, Self>
+    {
+        self.internal_quads_for_pattern(subject, predicate, None, graph_name)
+    }
+
     /// Fetches the list of dataset named graphs
     fn internal_named_graphs(
         &self,
@@ -283,6 +303,8 @@ pub enum ExpressionTerm {
     YearMonthDurationLiteral(YearMonthDuration),
     #[cfg(feature = "sep-0002")]
     DayTimeDurationLiteral(DayTimeDuration),
+    HexBinaryLiteral(HexBinary),
+    Base64BinaryLiteral(Base64Binary),
     OtherTypedLiteral {
         value: String,
         datatype: NamedNode,
@@ -335,6 +357,8 @@ impl PartialEq for ExpressionTerm {
                 (Self::YearMonthDurationLiteral(l), Self::YearMonthDurationLiteral(r)) => l == r,
                 #[cfg(feature = "sep-0002")]
                 (Self::DayTimeDurationLiteral(l), Self::DayTimeDurationLiteral(r)) => l == r,
+                (Self::HexBinaryLiteral(l), Self::HexBinaryLiteral(r)) => l == r,
+                (Self::Base64BinaryLiteral(l), Self::Base64BinaryLiteral(r)) => l == r,
                 (
                     Self::OtherTypedLiteral {
                         value: lv,
@@ -395,6 +419,8 @@ impl Hash for ExpressionTerm {
             ExpressionTerm::YearMonthDurationLiteral(v) => v.hash(state),
             #[cfg(feature = "sep-0002")]
             ExpressionTerm::DayTimeDurationLiteral(v) => v.hash(state),
+            ExpressionTerm::HexBinaryLiteral(v) => v.hash(state),
+            ExpressionTerm::Base64BinaryLiteral(v) => v.hash(state),
             ExpressionTerm::OtherTypedLiteral { value, datatype } => (value, datatype).hash(state),
             #[cfg(feature = "sparql-12")]
             ExpressionTerm::Triple(v) => v.hash(state),
@@ -493,6 +519,8 @@ impl From<ExpressionTerm> for Term {
             ExpressionTerm::YearMonthDurationLiteral(value) => Literal::from(value).into(),
             #[cfg(feature = "sep-0002")]
             ExpressionTerm::DayTimeDurationLiteral(value) => Literal::from(value).into(),
+            ExpressionTerm::HexBinaryLiteral(value) => Literal::from(value).into(),
+            ExpressionTerm::Base64BinaryLiteral(value) => Literal::from(value).into(),
             ExpressionTerm::OtherTypedLiteral { value, datatype } => {
                 Literal::new_typed_literal(value, datatype).into()
             }
@@ -597,6 +625,12 @@ fn parse_typed_literal(value: &str, datatype: &str) -> Option<ExpressionTerm> {
         "http://www.w3.org/2001/XMLSchema#dayTimeDuration" => {
             ExpressionTerm::DayTimeDurationLiteral(value.parse().ok()?)
         }
+        "http://www.w3.org/2001/XMLSchema#hexBinary" => {
+            ExpressionTerm::HexBinaryLiteral(value.parse().ok()?)
+        }
+        "http://www.w3.org/2001/XMLSchema#base64Binary" => {
+            ExpressionTerm::Base64BinaryLiteral(value.parse().ok()?)
+        }
         _ => return None,
     })
 }
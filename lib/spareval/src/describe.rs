@@ -0,0 +1,255 @@
+use crate::QueryEvaluationError;
+use oxrdf::{NamedNode, Term, Triple};
+use std::fmt;
+use std::sync::Arc;
+
+/// The way the `DESCRIBE` queries build the descriptions of the resources.
+///
+/// Descriptions are always computed from the query default graph.
+/// By default it is the dataset default graph but it might be changed using `FROM` clauses or
+/// [`QueryDatasetSpecification::set_default_graph_as_union`](super::QueryDatasetSpecification::set_default_graph_as_union)
+/// to describe the resources across all the graphs.
+///
+/// Should be given to [`QueryEvaluator::with_describe_strategy`](super::QueryEvaluator::with_describe_strategy).
+///
+/// Example with a custom strategy only returning the `rdf:type` of the resources:
+/// ```
+/// use oxrdf::vocab::rdf;
+/// use oxrdf::{Dataset, GraphName, NamedNode, Quad, Triple};
+/// use spareval::{DescribeStrategy, QueryEvaluator, QueryResults};
+/// use spargebra::SparqlParser;
+///
+/// let ex = NamedNode::new("http://example.com/ex")?;
+/// let dataset = Dataset::from_iter([
+///     Quad::new(ex.clone(), rdf::TYPE, ex.clone(), GraphName::DefaultGraph),
+///     Quad::new(ex.clone(), ex.clone(), ex.clone(), GraphName::DefaultGraph),
+/// ]);
+/// let evaluator =
+///     QueryEvaluator::new().with_describe_strategy(DescribeStrategy::custom(|resources, dataset| {
+///         let mut triples = Vec::new();
+///         for resource in resources {
+///             for triple in dataset.triples_for_pattern(Some(resource), Some(&rdf::TYPE.into()), None) {
+///                 triples.push(triple?);
+///             }
+///         }
+///         Ok(triples)
+///     }));
+/// let query = SparqlParser::new().parse_query("DESCRIBE <http://example.com/ex>")?;
+/// if let QueryResults::Graph(triples) = evaluator.prepare(&query).execute(&dataset)? {
+///     assert_eq!(
+///         triples.collect::<Result<Vec<_>, _>>()?,
+///         [Triple::new(ex.clone(), rdf::TYPE, ex)]
+///     );
+/// }
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Clone, Default)]
+pub enum DescribeStrategy {
+    /// The [Concise Bounded Description](https://www.w3.org/submissions/CBD/) of the resources.
+    ///
+    /// It contains all the triples whose subject is a described resource and recursively the descriptions of the blank nodes in object position.
+    /// Reifications are not included.
+    #[default]
+    ConciseBoundedDescription,
+    /// The [Symmetric Concise Bounded Description](https://www.w3.org/submissions/CBD/#alternatives) of the resources.
+    ///
+    /// It is the [Concise Bounded Description](Self::ConciseBoundedDescription) extended with all the triples whose object is a described resource
+    /// and recursively with the triples whose object is a blank node in subject position of these inbound triples.
+    SymmetricCbd,
+    /// A custom handler called with the resources to describe and a read access to the query default graph.
+    ///
+    /// The resources are deduplicated and given in the order they are returned by the `DESCRIBE` pattern.
+    Custom(Arc<CustomDescribeHandler>),
+}
+
+/// A function building the description of the given resources
+pub type CustomDescribeHandler = dyn Fn(&[Term], &DescribeDataset<'_>) -> Result<Vec<Triple>, QueryEvaluationError>
+    + Send
+    + Sync;
+
+impl DescribeStrategy {
+    /// Builds a [`Custom`](Self::Custom) strategy from a function.
+    #[inline]
+    pub fn custom(
+        handler: impl Fn(&[Term], &DescribeDataset<'_>) -> Result<Vec<Triple>, QueryEvaluationError>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        Self::Custom(Arc::new(handler))
+    }
+}
+
+impl fmt::Debug for DescribeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConciseBoundedDescription => f.write_str("ConciseBoundedDescription"),
+            Self::SymmetricCbd => f.write_str("SymmetricCbd"),
+            Self::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+/// Read access to the query default graph given to [`DescribeStrategy::Custom`] handlers.
+pub struct DescribeDataset<'b> {
+    inner: &'b dyn TriplesForPattern,
+}
+
+impl<'b> DescribeDataset<'b> {
+    pub(crate) fn new(inner: &'b dyn TriplesForPattern) -> Self {
+        Self { inner }
+    }
+
+    /// Retrieves the triples of the query default graph matching the given pattern.
+    #[inline]
+    pub fn triples_for_pattern(
+        &self,
+        subject: Option<&Term>,
+        predicate: Option<&NamedNode>,
+        object: Option<&Term>,
+    ) -> Box<dyn Iterator<Item = Result<Triple, QueryEvaluationError>> + 'b> {
+        self.inner.triples_for_pattern(subject, predicate, object)
+    }
+}
+
+pub(crate) trait TriplesForPattern {
+    fn triples_for_pattern<'b>(
+        &'b self,
+        subject: Option<&Term>,
+        predicate: Option<&NamedNode>,
+        object: Option<&Term>,
+    ) -> Box<dyn Iterator<Item = Result<Triple, QueryEvaluationError>> + 'b>;
+}
+
+#[cfg(test)]
+#[expect(clippy::panic_in_result_fn)]
+mod tests {
+    use super::*;
+    use crate::{QueryEvaluator, QueryResults};
+    use oxrdf::{BlankNode, Dataset, GraphName, Literal, NamedOrBlankNode, Quad};
+    use spargebra::SparqlParser;
+    use std::collections::HashSet;
+    use std::error::Error;
+
+    fn ex(name: &str) -> NamedNode {
+        NamedNode::new_unchecked(format!("http://example.com/{name}"))
+    }
+
+    fn triple(
+        subject: impl Into<NamedOrBlankNode>,
+        predicate: &str,
+        object: impl Into<Term>,
+    ) -> Triple {
+        Triple::new(subject, ex(predicate), object)
+    }
+
+    /// Nested blank node structures around ex:a, the described resource
+    fn triples() -> Vec<Triple> {
+        let b1 = BlankNode::new_unchecked("b1");
+        let b2 = BlankNode::new_unchecked("b2");
+        let b3 = BlankNode::new_unchecked("b3");
+        let b4 = BlankNode::new_unchecked("b4");
+        vec![
+            triple(ex("a"), "p", b1.clone()),
+            triple(b1, "q", b2.clone()),
+            triple(b2, "r", Literal::from("x")),
+            triple(ex("a"), "v", ex("d")),
+            triple(ex("d"), "w", ex("e")),
+            triple(ex("c"), "s", ex("a")),
+            triple(b3.clone(), "t", ex("a")),
+            triple(b4.clone(), "u", b3),
+            triple(b4.clone(), "u", b4),
+        ]
+    }
+
+    fn dataset() -> Dataset {
+        let mut dataset = triples()
+            .into_iter()
+            .map(|t| t.in_graph(GraphName::DefaultGraph))
+            .collect::<Dataset>();
+        dataset.insert(&Quad::new(ex("a"), ex("g"), ex("f"), ex("g")));
+        dataset
+    }
+
+    fn describe(evaluator: &QueryEvaluator, query: &str) -> Result<Vec<Triple>, Box<dyn Error>> {
+        let query = SparqlParser::new().parse_query(query)?;
+        let dataset = dataset();
+        let QueryResults::Graph(triples) = evaluator.prepare(&query).execute(&dataset)? else {
+            return Err("DESCRIBE must return a graph".into());
+        };
+        Ok(triples.collect::<Result<_, _>>()?)
+    }
+
+    fn assert_same_triples(actual: &[Triple], expected: &[Triple]) {
+        assert_eq!(
+            actual.iter().collect::<HashSet<_>>(),
+            expected.iter().collect::<HashSet<_>>()
+        );
+        assert_eq!(actual.len(), expected.len(), "Duplicated triples");
+    }
+
+    #[test]
+    fn test_concise_bounded_description() -> Result<(), Box<dyn Error>> {
+        let triples = triples();
+        let evaluator = QueryEvaluator::new();
+        assert_same_triples(
+            &describe(&evaluator, "DESCRIBE <http://example.com/a>")?,
+            &triples[..4],
+        );
+        // The description is built from the query default graph
+        assert_same_triples(
+            &describe(
+                &evaluator,
+                "DESCRIBE <http://example.com/a> FROM <http://example.com/g>",
+            )?,
+            &[triple(ex("a"), "g", ex("f"))],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_symmetric_concise_bounded_description() -> Result<(), Box<dyn Error>> {
+        let triples = triples();
+        let evaluator =
+            QueryEvaluator::new().with_describe_strategy(DescribeStrategy::SymmetricCbd);
+        let mut expected = triples.clone();
+        expected.remove(4); // ex:d ex:w ex:e
+        assert_same_triples(
+            &describe(&evaluator, "DESCRIBE <http://example.com/a>")?,
+            &expected,
+        );
+        assert_same_triples(
+            &describe(
+                &evaluator,
+                "DESCRIBE ?a ?d WHERE { ?a <http://example.com/v> ?d }",
+            )?,
+            &triples,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_description() -> Result<(), Box<dyn Error>> {
+        let evaluator = QueryEvaluator::new().with_describe_strategy(DescribeStrategy::custom(
+            |resources, dataset| {
+                assert_eq!(resources, [ex("a").into(), ex("d").into()]);
+                resources
+                    .iter()
+                    .flat_map(|r| dataset.triples_for_pattern(None, None, Some(r)))
+                    .collect()
+            },
+        ));
+        assert_same_triples(
+            &describe(
+                &evaluator,
+                "DESCRIBE <http://example.com/a> ?d WHERE { <http://example.com/a> <http://example.com/v> ?d }",
+            )?,
+            &[
+                triple(ex("c"), "s", ex("a")),
+                triple(BlankNode::new_unchecked("b3"), "t", ex("a")),
+                triple(ex("a"), "v", ex("d")),
+            ],
+        );
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use oxrdf::{NamedNode, Term, Variable};
 use spargebra::SparqlSyntaxError;
 use std::convert::Infallible;
 use std::error::Error;
+use std::io;
 use std::ops::RangeInclusive;
 
 /// A SPARQL evaluation error
@@ -86,3 +87,32 @@ impl From<ExpressionEvaluationError<Self>> for QueryEvaluationError {
         }
     }
 }
+
+/// An error raised while writing [`QueryResults`](crate::QueryResults).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum QueryResultsSerializationError {
+    /// Error during the query evaluation
+    #[error(transparent)]
+    Evaluation(#[from] QueryEvaluationError),
+    /// Error while writing the results
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// `CONSTRUCT` or `DESCRIBE` results written with a query results format
+    #[error("CONSTRUCT and DESCRIBE results must be written with an RDF format")]
+    GraphResultsExpectRdfFormat,
+    /// `SELECT` or `ASK` results written with an RDF format
+    #[error("SELECT and ASK results must be written with a query results format")]
+    SolutionsOrBooleanExpectQueryResultsFormat,
+}
+
+impl From<QueryResultsSerializationError> for io::Error {
+    #[inline]
+    fn from(error: QueryResultsSerializationError) -> Self {
+        match error {
+            QueryResultsSerializationError::Io(error) => error,
+            QueryResultsSerializationError::Evaluation(error) => Self::other(error),
+            _ => Self::new(io::ErrorKind::InvalidInput, error),
+        }
+    }
+}
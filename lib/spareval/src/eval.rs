@@ -1,12 +1,14 @@
 #[cfg(feature = "sparql-12")]
 use crate::dataset::ExpressionTriple;
 use crate::dataset::{ExpressionTerm, InternalQuad, QueryableDataset};
+use crate::describe::{DescribeDataset, DescribeStrategy, TriplesForPattern};
 use crate::error::QueryEvaluationError;
 use crate::expression::{
     CustomFunctionRegistry, ExpressionEvaluator, ExpressionEvaluatorContext, NumericBinaryOperands,
-    build_expression_evaluator, partial_cmp_literals, try_build_internal_expression_evaluator,
+    build_expression_evaluator, is_nan, partial_cmp_literals,
+    try_build_internal_expression_evaluator,
 };
-use crate::model::{QuerySolutionIter, QueryTripleIter};
+use crate::model::{QueryQuadIter, QuerySolutionIter, QueryTripleIter};
 use crate::service::ServiceHandlerRegistry;
 use crate::{
     AggregateFunctionAccumulator, CustomAggregateFunctionRegistry, QueryDatasetSpecification,
@@ -15,24 +17,27 @@ use json_event_parser::{JsonEvent, WriterJsonSerializer};
 use oxiri::Iri;
 #[cfg(feature = "sparql-12")]
 use oxrdf::{BaseDirection, NamedOrBlankNode};
-use oxrdf::{BlankNode, GraphName, Literal, NamedNode, Term, Triple, Variable};
+use oxrdf::{BlankNode, GraphName, Literal, NamedNode, Quad, Term, Triple, Variable};
 use oxsdatatypes::{DateTime, DayTimeDuration, Decimal, Double, Float, Integer};
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet, FxHasher};
 use spargebra::algebra::{AggregateFunction, PropertyPathExpression};
 #[cfg(feature = "sparql-12")]
 use spargebra::term::GroundTriple;
 use spargebra::term::{
-    GroundTerm, GroundTermPattern, NamedNodePattern, TermPattern, TriplePattern,
+    GraphNamePattern, GroundTerm, GroundTermPattern, NamedNodePattern, QuadPattern, TermPattern,
+    TriplePattern,
 };
 use sparopt::algebra::{
     AggregateExpression, Expression, GraphPattern, JoinAlgorithm, LeftJoinAlgorithm,
     MinusAlgorithm, OrderExpression,
 };
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
 use std::hash::{Hash, Hasher};
-use std::iter::{Peekable, empty, once};
+use std::iter::{Fuse, Peekable, empty, once, once_with};
 use std::marker::PhantomData;
+use std::ops::Bound;
 use std::rc::Rc;
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, atomic};
@@ -100,17 +105,32 @@ impl<'a, D: QueryableDataset<'a>> EvalDataset<'a, D> {
         &self,
         subject: Option<&D::InternalTerm>,
         predicate: Option<&D::InternalTerm>,
-        object: Option<&D::InternalTerm>,
+        object: ObjectSelector<'_, D::InternalTerm>,
         graph_name: Option<Option<&D::InternalTerm>>,
-    ) -> impl Iterator<Item = Result<InternalQuad<D::InternalTerm>, QueryEvaluationError>> + use<'a, D>
+    ) -> Box<dyn Iterator<Item = Result<InternalQuad<D::InternalTerm>, QueryEvaluationError>> + 'a>
     {
         let cancellation_token = self.cancellation_token.clone();
-        self.dataset
-            .internal_quads_for_pattern(subject, predicate, object, graph_name)
-            .map(move |r| {
-                cancellation_token.ensure_alive()?;
-                r.map_err(|e| QueryEvaluationError::Dataset(Box::new(e)))
-            })
+        let map_result = move |r: Result<InternalQuad<D::InternalTerm>, D::Error>| {
+            cancellation_token.ensure_alive()?;
+            r.map_err(|e| QueryEvaluationError::Dataset(Box::new(e)))
+        };
+        match object {
+            ObjectSelector::Term(object) => Box::new(
+                self.dataset
+                    .internal_quads_for_pattern(subject, predicate, object, graph_name)
+                    .map(map_result),
+            ),
+            ObjectSelector::Range(object_range) => Box::new(
+                self.dataset
+                    .internal_quads_for_pattern_with_object_range(
+                        subject,
+                        predicate,
+                        object_range,
+                        graph_name,
+                    )
+                    .map(map_result),
+            ),
+        }
     }
 
     fn internal_quads_for_pattern(
@@ -120,6 +140,22 @@ impl<'a, D: QueryableDataset<'a>> EvalDataset<'a, D> {
         object: Option<&D::InternalTerm>,
         graph_name: Option<Option<&D::InternalTerm>>,
     ) -> Box<dyn Iterator<Item = Result<InternalQuad<D::InternalTerm>, QueryEvaluationError>> + 'a>
+    {
+        self.internal_quads_for_pattern_with_object_selector(
+            subject,
+            predicate,
+            ObjectSelector::Term(object),
+            graph_name,
+        )
+    }
+
+    fn internal_quads_for_pattern_with_object_selector(
+        &self,
+        subject: Option<&D::InternalTerm>,
+        predicate: Option<&D::InternalTerm>,
+        object: ObjectSelector<'_, D::InternalTerm>,
+        graph_name: Option<Option<&D::InternalTerm>>,
+    ) -> Box<dyn Iterator<Item = Result<InternalQuad<D::InternalTerm>, QueryEvaluationError>> + 'a>
     {
         if let Some(graph_name) = graph_name {
             // A graph (named or default), has been specified, we only query it
@@ -132,12 +168,12 @@ impl<'a, D: QueryableDataset<'a>> EvalDataset<'a, D> {
                     .is_none_or(|d| d.contains(graph_name))
                 {
                     // It is in the set of allowed named graphs (if this set exists), we query it
-                    Box::new(self.underlying_internal_quads_for_pattern(
+                    self.underlying_internal_quads_for_pattern(
                         subject,
                         predicate,
                         object,
                         Some(Some(graph_name)),
-                    ))
+                    )
                 } else {
                     Box::new(empty())
                 }
@@ -297,6 +333,22 @@ impl<'a, D: QueryableDataset<'a>> Clone for EvalDataset<'a, D> {
     }
 }
 
+/// Restriction on the object of a quad pattern
+enum ObjectSelector<'b, T> {
+    /// The object must be the given term if set
+    Term(Option<&'b T>),
+    /// The object value should be in the given range (it is only a hint)
+    Range((Bound<&'b T>, Bound<&'b T>)),
+}
+
+impl<T> Clone for ObjectSelector<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ObjectSelector<'_, T> {}
+
 #[derive(Clone)]
 struct EncodedDatasetSpec<T> {
     default: Option<Vec<Option<T>>>,
@@ -418,6 +470,7 @@ pub struct SimpleEvaluator<'a, D: QueryableDataset<'a>> {
     service_handler: Rc<ServiceHandlerRegistry>,
     custom_functions: Rc<CustomFunctionRegistry>,
     custom_aggregate_functions: Rc<CustomAggregateFunctionRegistry>,
+    blank_node_generator: Rc<BlankNodeGenerator>,
     run_stats: bool,
 }
 
@@ -430,6 +483,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
         custom_aggregate_functions: Rc<CustomAggregateFunctionRegistry>,
         cancellation_token: CancellationToken,
         dataset_spec: QueryDatasetSpecification,
+        blank_node_id_seed: Option<u128>,
         run_stats: bool,
     ) -> Result<Self, QueryEvaluationError> {
         Ok(Self {
@@ -439,6 +493,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
             service_handler,
             custom_functions,
             custom_aggregate_functions,
+            blank_node_generator: Rc::new(BlankNodeGenerator::new(blank_node_id_seed)),
             run_stats,
         })
     }
@@ -516,6 +571,38 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
     ) -> (
         Result<QueryTripleIter<'a>, QueryEvaluationError>,
         Rc<EvalNodeWithStats>,
+    ) {
+        let (iter, stats) = self.construct_iterator(pattern, template, &[], substitutions);
+        (
+            iter.map(|iter| QueryTripleIter::new(iter.map(|quad| quad.map(Triple::from)))),
+            stats,
+        )
+    }
+
+    pub fn evaluate_construct_quads(
+        &self,
+        pattern: &GraphPattern,
+        template: &[TriplePattern],
+        quad_template: &[QuadPattern],
+        substitutions: impl IntoIterator<Item = (Variable, Term)>,
+    ) -> (
+        Result<QueryQuadIter<'a>, QueryEvaluationError>,
+        Rc<EvalNodeWithStats>,
+    ) {
+        let (iter, stats) =
+            self.construct_iterator(pattern, template, quad_template, substitutions);
+        (iter.map(QueryQuadIter::new), stats)
+    }
+
+    fn construct_iterator(
+        &self,
+        pattern: &GraphPattern,
+        template: &[TriplePattern],
+        quad_template: &[QuadPattern],
+        substitutions: impl IntoIterator<Item = (Variable, Term)>,
+    ) -> (
+        Result<ConstructIterator<'a, D>, QueryEvaluationError>,
+        Rc<EvalNodeWithStats>,
     ) {
         let mut variables = Vec::new();
         let (eval, stats) = self.graph_pattern_evaluator(pattern, &mut variables);
@@ -526,23 +613,48 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
         let mut bnodes = Vec::new();
         let template = template
             .iter()
-            .filter_map(|t| {
-                Some(TripleTemplate {
-                    subject: TripleTemplateValue::from_term_or_variable(
-                        &t.subject,
-                        &mut variables,
-                        &mut bnodes,
-                    )?,
-                    predicate: TripleTemplateValue::from_named_node_or_variable(
-                        &t.predicate,
-                        &mut variables,
-                    ),
-                    object: TripleTemplateValue::from_term_or_variable(
-                        &t.object,
-                        &mut variables,
-                        &mut bnodes,
-                    )?,
-                })
+            .map(|t| (&t.subject, &t.predicate, &t.object, None))
+            .chain(quad_template.iter().map(|q| {
+                (
+                    &q.subject,
+                    &q.predicate,
+                    &q.object,
+                    match &q.graph_name {
+                        GraphNamePattern::NamedNode(graph_name) => {
+                            Some(NamedNodePattern::NamedNode(graph_name.clone()))
+                        }
+                        GraphNamePattern::Variable(graph_name) => {
+                            Some(NamedNodePattern::Variable(graph_name.clone()))
+                        }
+                        GraphNamePattern::DefaultGraph => None,
+                    },
+                )
+            }))
+            .filter_map(|(subject, predicate, object, graph_name)| {
+                Some((
+                    TripleTemplate {
+                        subject: TripleTemplateValue::from_term_or_variable(
+                            subject,
+                            &mut variables,
+                            &mut bnodes,
+                        )?,
+                        predicate: TripleTemplateValue::from_named_node_or_variable(
+                            predicate,
+                            &mut variables,
+                        ),
+                        object: TripleTemplateValue::from_term_or_variable(
+                            object,
+                            &mut variables,
+                            &mut bnodes,
+                        )?,
+                    },
+                    graph_name.map(|graph_name| {
+                        TripleTemplateValue::from_named_node_or_variable(
+                            &graph_name,
+                            &mut variables,
+                        )
+                    }),
+                ))
             })
             .collect();
         let from = match encode_initial_bindings(&self.dataset, &variables, substitutions) {
@@ -550,14 +662,14 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
             Err(e) => return (Err(e), stats),
         };
         (
-            Ok(QueryTripleIter::new(ConstructIterator {
+            Ok(ConstructIterator {
                 eval: self.clone(),
                 iter: eval(from),
                 template,
                 buffered_results: Vec::default(),
                 already_emitted_results: FxHashSet::default(),
                 bnodes: Vec::default(),
-            })),
+            }),
             stats,
         )
     }
@@ -565,6 +677,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
     pub fn evaluate_describe(
         &self,
         pattern: &GraphPattern,
+        strategy: &DescribeStrategy,
         substitutions: impl IntoIterator<Item = (Variable, Term)>,
     ) -> (
         Result<QueryTripleIter<'a>, QueryEvaluationError>,
@@ -580,13 +693,45 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
             Ok(from) => from,
             Err(e) => return (Err(e), stats),
         };
+        let tuples_to_describe = eval(from);
+        let symmetric = match strategy {
+            DescribeStrategy::ConciseBoundedDescription => false,
+            DescribeStrategy::SymmetricCbd => true,
+            DescribeStrategy::Custom(handler) => {
+                let eval = self.clone();
+                let handler = Arc::clone(handler);
+                return (
+                    Ok(QueryTripleIter::new(
+                        once_with(move || {
+                            let mut resources = Vec::new();
+                            let mut resources_set = FxHashSet::default();
+                            for tuple in tuples_to_describe {
+                                for node in tuple?.into_iter().flatten() {
+                                    if resources_set.insert(node.clone()) {
+                                        resources.push(eval.dataset.externalize_term(node)?);
+                                    }
+                                }
+                            }
+                            handler(&resources, &DescribeDataset::new(&eval))
+                        })
+                        .flat_map(|result| match result {
+                            Ok(triples) => triples.into_iter().map(Ok).collect::<Vec<_>>(),
+                            Err(e) => vec![Err(e)],
+                        }),
+                    )),
+                    stats,
+                );
+            }
+        };
         (
             Ok(QueryTripleIter::new(DescribeIterator {
                 eval: self.clone(),
-                tuples_to_describe: eval(from),
+                tuples_to_describe,
+                symmetric,
                 nodes_described: FxHashSet::default(),
                 nodes_to_describe: Vec::default(),
                 quads: Box::new(empty()),
+                quads_direction: DescribeDirection::Outbound,
             })),
             stats,
         )
@@ -599,10 +744,43 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
     ) -> (
         Result<InternalTupleEvaluator<'a, D::InternalTerm>, QueryEvaluationError>,
         Rc<EvalNodeWithStats>,
+    ) {
+        self.graph_pattern_evaluator_with_object_range(pattern, encoded_variables, None)
+    }
+
+    /// Same as [`graph_pattern_evaluator`](Self::graph_pattern_evaluator) but if the pattern is a quad pattern,
+    /// the lookup is restricted to objects in the given range of values
+    fn graph_pattern_evaluator_with_object_range(
+        &self,
+        pattern: &GraphPattern,
+        encoded_variables: &mut Vec<Variable>,
+        object_range: Option<(Bound<D::InternalTerm>, Bound<D::InternalTerm>)>,
+    ) -> (
+        Result<InternalTupleEvaluator<'a, D::InternalTerm>, QueryEvaluationError>,
+        Rc<EvalNodeWithStats>,
     ) {
         let mut stat_children = Vec::new();
-        let evaluator =
-            self.build_graph_pattern_evaluator(pattern, encoded_variables, &mut stat_children);
+        let evaluator = if let (
+            Some(object_range),
+            GraphPattern::QuadPattern {
+                subject,
+                predicate,
+                object,
+                graph_name,
+            },
+        ) = (object_range, pattern)
+        {
+            self.build_quad_pattern_evaluator(
+                subject,
+                predicate,
+                object,
+                graph_name.as_ref(),
+                Some(object_range),
+                encoded_variables,
+            )
+        } else {
+            self.build_graph_pattern_evaluator(pattern, encoded_variables, &mut stat_children)
+        };
         let stats = Rc::new(EvalNodeWithStats {
             label: eval_node_label(pattern),
             children: stat_children,
@@ -634,6 +812,137 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
         (Ok(evaluator), stats)
     }
 
+    fn build_quad_pattern_evaluator(
+        &self,
+        subject: &GroundTermPattern,
+        predicate: &NamedNodePattern,
+        object: &GroundTermPattern,
+        graph_name: Option<&NamedNodePattern>,
+        object_range: Option<(Bound<D::InternalTerm>, Bound<D::InternalTerm>)>,
+        encoded_variables: &mut Vec<Variable>,
+    ) -> Result<InternalTupleEvaluator<'a, D::InternalTerm>, QueryEvaluationError> {
+        let subject_selector =
+            TupleSelector::from_ground_term_pattern(subject, encoded_variables, &self.dataset)?;
+        let predicate_selector =
+            TupleSelector::from_named_node_pattern(predicate, encoded_variables, &self.dataset)?;
+        let object_selector =
+            TupleSelector::from_ground_term_pattern(object, encoded_variables, &self.dataset)?;
+        let graph_name_selector = if let Some(graph_name) = graph_name {
+            Some(TupleSelector::from_named_node_pattern(
+                graph_name,
+                encoded_variables,
+                &self.dataset,
+            )?)
+        } else {
+            None
+        };
+        let dataset = self.dataset.clone();
+        Ok(Rc::new(move |from| {
+            let input_subject = match subject_selector.get_pattern_value(
+                &from,
+                #[cfg(feature = "sparql-12")]
+                &dataset,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Box::new(once(Err(e))),
+            };
+            let input_predicate = match predicate_selector.get_pattern_value(
+                &from,
+                #[cfg(feature = "sparql-12")]
+                &dataset,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Box::new(once(Err(e))),
+            };
+            let input_object = match object_selector.get_pattern_value(
+                &from,
+                #[cfg(feature = "sparql-12")]
+                &dataset,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Box::new(once(Err(e))),
+            };
+            let input_graph_name = if let Some(graph_name_selector) = &graph_name_selector {
+                match graph_name_selector.get_pattern_value(
+                    &from,
+                    #[cfg(feature = "sparql-12")]
+                    &dataset,
+                ) {
+                    Ok(value) => value,
+                    Err(e) => return Box::new(once(Err(e))),
+                }
+                .map(Some)
+            } else {
+                Some(None) // default graph
+            };
+            let object = match (&input_object, &object_range) {
+                (None, Some((start, end))) => ObjectSelector::Range((start.as_ref(), end.as_ref())),
+                _ => ObjectSelector::Term(input_object.as_ref()),
+            };
+            let iter = dataset.internal_quads_for_pattern_with_object_selector(
+                input_subject.as_ref(),
+                input_predicate.as_ref(),
+                object,
+                input_graph_name.as_ref().map(|g| g.as_ref()),
+            );
+            let subject_selector = subject_selector.clone();
+            let predicate_selector = predicate_selector.clone();
+            let object_selector = object_selector.clone();
+            let graph_name_selector = graph_name_selector.clone();
+            #[cfg(feature = "sparql-12")]
+            let dataset = dataset.clone();
+            Box::new(
+                iter.map(move |quad| {
+                    let quad = quad?;
+                    let mut new_tuple = from.clone();
+                    if !put_pattern_value::<D>(
+                        &subject_selector,
+                        quad.subject,
+                        &mut new_tuple,
+                        #[cfg(feature = "sparql-12")]
+                        &dataset,
+                    )? {
+                        return Ok(None);
+                    }
+                    if !put_pattern_value::<D>(
+                        &predicate_selector,
+                        quad.predicate,
+                        &mut new_tuple,
+                        #[cfg(feature = "sparql-12")]
+                        &dataset,
+                    )? {
+                        return Ok(None);
+                    }
+                    if !put_pattern_value::<D>(
+                        &object_selector,
+                        quad.object,
+                        &mut new_tuple,
+                        #[cfg(feature = "sparql-12")]
+                        &dataset,
+                    )? {
+                        return Ok(None);
+                    }
+                    if let Some(graph_name_selector) = &graph_name_selector {
+                        let Some(quad_graph_name) = quad.graph_name else {
+                            return Err(QueryEvaluationError::UnexpectedDefaultGraph);
+                        };
+                        if !put_pattern_value::<D>(
+                            graph_name_selector,
+                            quad_graph_name,
+                            &mut new_tuple,
+                            #[cfg(feature = "sparql-12")]
+                            &dataset,
+                        )? {
+                            return Ok(None);
+                        }
+                    }
+                    Ok(Some(new_tuple))
+                })
+                .filter_map(Result::transpose),
+            )
+        }))
+    }
+
     fn build_graph_pattern_evaluator(
         &self,
         pattern: &GraphPattern,
@@ -689,133 +998,14 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                 predicate,
                 object,
                 graph_name,
-            } => {
-                let subject_selector = TupleSelector::from_ground_term_pattern(
-                    subject,
-                    encoded_variables,
-                    &self.dataset,
-                )?;
-                let predicate_selector = TupleSelector::from_named_node_pattern(
-                    predicate,
-                    encoded_variables,
-                    &self.dataset,
-                )?;
-                let object_selector = TupleSelector::from_ground_term_pattern(
-                    object,
-                    encoded_variables,
-                    &self.dataset,
-                )?;
-                let graph_name_selector = if let Some(graph_name) = graph_name.as_ref() {
-                    Some(TupleSelector::from_named_node_pattern(
-                        graph_name,
-                        encoded_variables,
-                        &self.dataset,
-                    )?)
-                } else {
-                    None
-                };
-                let dataset = self.dataset.clone();
-                Rc::new(move |from| {
-                    let input_subject = match subject_selector.get_pattern_value(
-                        &from,
-                        #[cfg(feature = "sparql-12")]
-                        &dataset,
-                    ) {
-                        Ok(value) => value,
-                        Err(e) => return Box::new(once(Err(e))),
-                    };
-                    let input_predicate = match predicate_selector.get_pattern_value(
-                        &from,
-                        #[cfg(feature = "sparql-12")]
-                        &dataset,
-                    ) {
-                        Ok(value) => value,
-                        Err(e) => return Box::new(once(Err(e))),
-                    };
-                    let input_object = match object_selector.get_pattern_value(
-                        &from,
-                        #[cfg(feature = "sparql-12")]
-                        &dataset,
-                    ) {
-                        Ok(value) => value,
-                        Err(e) => return Box::new(once(Err(e))),
-                    };
-                    let input_graph_name = if let Some(graph_name_selector) = &graph_name_selector {
-                        match graph_name_selector.get_pattern_value(
-                            &from,
-                            #[cfg(feature = "sparql-12")]
-                            &dataset,
-                        ) {
-                            Ok(value) => value,
-                            Err(e) => return Box::new(once(Err(e))),
-                        }
-                        .map(Some)
-                    } else {
-                        Some(None) // default graph
-                    };
-                    let iter = dataset.internal_quads_for_pattern(
-                        input_subject.as_ref(),
-                        input_predicate.as_ref(),
-                        input_object.as_ref(),
-                        input_graph_name.as_ref().map(|g| g.as_ref()),
-                    );
-                    let subject_selector = subject_selector.clone();
-                    let predicate_selector = predicate_selector.clone();
-                    let object_selector = object_selector.clone();
-                    let graph_name_selector = graph_name_selector.clone();
-                    #[cfg(feature = "sparql-12")]
-                    let dataset = dataset.clone();
-                    Box::new(
-                        iter.map(move |quad| {
-                            let quad = quad?;
-                            let mut new_tuple = from.clone();
-                            if !put_pattern_value::<D>(
-                                &subject_selector,
-                                quad.subject,
-                                &mut new_tuple,
-                                #[cfg(feature = "sparql-12")]
-                                &dataset,
-                            )? {
-                                return Ok(None);
-                            }
-                            if !put_pattern_value::<D>(
-                                &predicate_selector,
-                                quad.predicate,
-                                &mut new_tuple,
-                                #[cfg(feature = "sparql-12")]
-                                &dataset,
-                            )? {
-                                return Ok(None);
-                            }
-                            if !put_pattern_value::<D>(
-                                &object_selector,
-                                quad.object,
-                                &mut new_tuple,
-                                #[cfg(feature = "sparql-12")]
-                                &dataset,
-                            )? {
-                                return Ok(None);
-                            }
-                            if let Some(graph_name_selector) = &graph_name_selector {
-                                let Some(quad_graph_name) = quad.graph_name else {
-                                    return Err(QueryEvaluationError::UnexpectedDefaultGraph);
-                                };
-                                if !put_pattern_value::<D>(
-                                    graph_name_selector,
-                                    quad_graph_name,
-                                    &mut new_tuple,
-                                    #[cfg(feature = "sparql-12")]
-                                    &dataset,
-                                )? {
-                                    return Ok(None);
-                                }
-                            }
-                            Ok(Some(new_tuple))
-                        })
-                        .filter_map(Result::transpose),
-                    )
-                })
-            }
+            } => self.build_quad_pattern_evaluator(
+                subject,
+                predicate,
+                object,
+                graph_name.as_ref(),
+                None,
+                encoded_variables,
+            )?,
             GraphPattern::Path {
                 subject,
                 path,
@@ -1434,7 +1624,12 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                 }
             }
             GraphPattern::Filter { inner, expression } => {
-                let (child, child_stats) = self.graph_pattern_evaluator(inner, encoded_variables);
+                let object_range = self.filter_object_range(inner, expression)?;
+                let (child, child_stats) = self.graph_pattern_evaluator_with_object_range(
+                    inner,
+                    encoded_variables,
+                    object_range,
+                );
                 stat_children.push(child_stats);
                 let child = child?;
                 let expression = self.effective_boolean_value_expression_evaluator(
@@ -1516,27 +1711,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                 let (child, child_stats) = self.graph_pattern_evaluator(inner, encoded_variables);
                 stat_children.push(child_stats);
                 let child = child?;
-                let by = expression
-                    .iter()
-                    .map(|comp| {
-                        Ok(match comp {
-                            OrderExpression::Asc(expression) => {
-                                ComparatorFunction::Asc(self.expression_evaluator(
-                                    expression,
-                                    encoded_variables,
-                                    stat_children,
-                                )?)
-                            }
-                            OrderExpression::Desc(expression) => {
-                                ComparatorFunction::Desc(self.expression_evaluator(
-                                    expression,
-                                    encoded_variables,
-                                    stat_children,
-                                )?)
-                            }
-                        })
-                    })
-                    .collect::<Result<Vec<_>, QueryEvaluationError>>()?;
+                let by = self.comparator_functions(expression, encoded_variables, stat_children)?;
                 Rc::new(move |from| {
                     let mut errors = Vec::default();
                     let mut values = child(from)
@@ -1548,29 +1723,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                             }
                         })
                         .collect::<Vec<_>>();
-                    values.sort_unstable_by(|a, b| {
-                        for comp in &by {
-                            match comp {
-                                ComparatorFunction::Asc(expression) => {
-                                    match cmp_terms(expression(a).as_ref(), expression(b).as_ref())
-                                    {
-                                        Ordering::Greater => return Ordering::Greater,
-                                        Ordering::Less => return Ordering::Less,
-                                        Ordering::Equal => (),
-                                    }
-                                }
-                                ComparatorFunction::Desc(expression) => {
-                                    match cmp_terms(expression(a).as_ref(), expression(b).as_ref())
-                                    {
-                                        Ordering::Greater => return Ordering::Less,
-                                        Ordering::Less => return Ordering::Greater,
-                                        Ordering::Equal => (),
-                                    }
-                                }
-                            }
-                        }
-                        Ordering::Equal
-                    });
+                    values.sort_unstable_by(|a, b| cmp_tuples(&by, a, b));
                     Box::new(errors.into_iter().chain(values.into_iter().map(Ok)))
                 })
             }
@@ -1591,6 +1744,58 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                     })
                 })
             }
+            GraphPattern::Slice {
+                inner: sorted,
+                start,
+                length: Some(length),
+            } if matches!(**sorted, GraphPattern::OrderBy { .. }) => {
+                // We sort while keeping only the start + length first solutions in a bounded heap
+                let GraphPattern::OrderBy { inner, expression } = &**sorted else {
+                    unreachable!()
+                };
+                let (child, child_stats) = self.graph_pattern_evaluator(inner, encoded_variables);
+                stat_children.push(child_stats);
+                let child = child?;
+                let by = Rc::<[_]>::from(self.comparator_functions(
+                    expression,
+                    encoded_variables,
+                    stat_children,
+                )?);
+                #[expect(clippy::shadow_same)]
+                let start = *start;
+                #[expect(clippy::shadow_same)]
+                let length = *length;
+                let limit = start.saturating_add(length);
+                Rc::new(move |from| {
+                    let mut errors = Vec::default();
+                    let mut heap = BinaryHeap::new();
+                    for result in child(from) {
+                        match result {
+                            Ok(tuple) => {
+                                let entry = TopKEntry {
+                                    tuple,
+                                    by: Rc::clone(&by),
+                                };
+                                if heap.len() < limit {
+                                    heap.push(entry);
+                                } else if let Some(mut greatest) = heap.peek_mut() {
+                                    if entry < *greatest {
+                                        *greatest = entry;
+                                    }
+                                }
+                            }
+                            Err(error) => errors.push(Err(error)),
+                        }
+                    }
+                    Box::new(
+                        errors
+                            .into_iter()
+                            .chain(heap.into_sorted_vec().into_iter().map(|e| Ok(e.tuple)))
+                            .skip(start)
+                            .take(length),
+                    )
+                })
+            }
             GraphPattern::Slice {
                 inner,
                 start,
@@ -1806,6 +2011,27 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
         Ok(encode_bindings(self.dataset.clone(), variables, iter))
     }
 
+    fn comparator_functions(
+        &self,
+        expressions: &[OrderExpression],
+        encoded_variables: &mut Vec<Variable>,
+        stat_children: &mut Vec<Rc<EvalNodeWithStats>>,
+    ) -> Result<Vec<ComparatorFunction<'a, D::InternalTerm>>, QueryEvaluationError> {
+        expressions
+            .iter()
+            .map(|comp| {
+                Ok(match comp {
+                    OrderExpression::Asc(expression) => ComparatorFunction::Asc(
+                        self.expression_evaluator(expression, encoded_variables, stat_children)?,
+                    ),
+                    OrderExpression::Desc(expression) => ComparatorFunction::Desc(
+                        self.expression_evaluator(expression, encoded_variables, stat_children)?,
+                    ),
+                })
+            })
+            .collect()
+    }
+
     fn accumulator_builder(
         &self,
         expression: &AggregateExpression,
@@ -1828,6 +2054,7 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                 name,
                 distinct,
                 expr,
+                order_by,
             } => match name {
                 AggregateFunction::Count => {
                     if let Some(evaluator) =
@@ -1846,86 +2073,49 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                             })
                         });
                     }
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(CountAccumulator::default())),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(CountAccumulator::default())),
-                        })
-                    }
-                }
-                AggregateFunction::Sum => {
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(SumAccumulator::default())),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(SumAccumulator::default())),
-                        })
-                    }
-                }
-                AggregateFunction::Min => {
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(MinAccumulator::default())),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(MinAccumulator::default())),
-                        })
-                    }
-                }
-                AggregateFunction::Max => {
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(MaxAccumulator::default())),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(MaxAccumulator::default())),
-                        })
-                    }
-                }
-                AggregateFunction::Avg => {
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(AvgAccumulator::default())),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(AvgAccumulator::default())),
-                        })
-                    }
+                    self.expression_accumulator_builder(
+                        expr,
+                        *distinct,
+                        order_by,
+                        encoded_variables,
+                        stat_children,
+                        || Box::new(CountAccumulator::default()),
+                    )?
                 }
+                AggregateFunction::Sum => self.expression_accumulator_builder(
+                    expr,
+                    *distinct,
+                    order_by,
+                    encoded_variables,
+                    stat_children,
+                    || Box::new(SumAccumulator::default()),
+                )?,
+                AggregateFunction::Min => self.expression_accumulator_builder(
+                    expr,
+                    *distinct,
+                    order_by,
+                    encoded_variables,
+                    stat_children,
+                    || Box::new(MinAccumulator::default()),
+                )?,
+                AggregateFunction::Max => self.expression_accumulator_builder(
+                    expr,
+                    *distinct,
+                    order_by,
+                    encoded_variables,
+                    stat_children,
+                    || Box::new(MaxAccumulator::default()),
+                )?,
+                AggregateFunction::Avg => self.expression_accumulator_builder(
+                    expr,
+                    *distinct,
+                    order_by,
+                    encoded_variables,
+                    stat_children,
+                    || Box::new(AvgAccumulator::default()),
+                )?,
                 AggregateFunction::Sample => {
+                    // DISTINCT does not change the result of SAMPLE
                     let evaluator =
                         self.expression_evaluator(expr, encoded_variables, stat_children)?;
                     Box::new(move || AccumulatorWrapper::Sample {
@@ -1933,26 +2123,16 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                         value: None,
                     })
                 }
-                AggregateFunction::GroupConcat { separator } => {
-                    let separator = Rc::from(separator.as_deref().unwrap_or(" "));
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(GroupConcatAccumulator::new(Rc::clone(
-                                &separator,
-                            )))),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(GroupConcatAccumulator::new(Rc::clone(
-                                &separator,
-                            )))),
-                        })
-                    }
+                AggregateFunction::GroupConcat { separator, .. } => {
+                    let separator = Rc::<str>::from(separator.as_deref().unwrap_or(" "));
+                    self.expression_accumulator_builder(
+                        expr,
+                        *distinct,
+                        order_by,
+                        encoded_variables,
+                        stat_children,
+                        move || Box::new(GroupConcatAccumulator::new(Rc::clone(&separator))),
+                    )?
                 }
                 AggregateFunction::Custom(function_name) => {
                     let Some(function) = self.custom_aggregate_functions.get(function_name) else {
@@ -1960,26 +2140,68 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
                             function_name.clone(),
                         ));
                     };
-                    let evaluator =
-                        self.expression_evaluator(expr, encoded_variables, stat_children)?;
                     let function = Arc::clone(function);
-                    if *distinct {
-                        Box::new(move || AccumulatorWrapper::DistinctExpression {
-                            evaluator: Rc::clone(&evaluator),
-                            seen: FxHashSet::default(),
-                            accumulator: Some(Box::new(CustomAccumulator(function()))),
-                        })
-                    } else {
-                        Box::new(move || AccumulatorWrapper::Expression {
-                            evaluator: Rc::clone(&evaluator),
-                            accumulator: Some(Box::new(CustomAccumulator(function()))),
-                        })
-                    }
+                    self.expression_accumulator_builder(
+                        expr,
+                        *distinct,
+                        order_by,
+                        encoded_variables,
+                        stat_children,
+                        move || Box::new(CustomAccumulator(function())),
+                    )?
                 }
             },
         })
     }
 
+    /// Builds an aggregate accumulator that evaluates the given expression on each solution
+    ///
+    /// All aggregate functions share this code path so that DISTINCT and ORDER BY behave the same for all of them.
+    /// DISTINCT compares the actual RDF terms if possible, so `"1"^^xsd:integer` and `"01"^^xsd:integer` are distinct values.
+    fn expression_accumulator_builder(
+        &self,
+        expr: &Expression,
+        distinct: bool,
+        order_by: &[OrderExpression],
+        encoded_variables: &mut Vec<Variable>,
+        stat_children: &mut Vec<Rc<EvalNodeWithStats>>,
+        accumulator: impl Fn() -> Box<dyn Accumulator> + 'a,
+    ) -> Result<Box<dyn Fn() -> AccumulatorWrapper<'a, D::InternalTerm> + 'a>, QueryEvaluationError>
+    {
+        let evaluator = self.expression_evaluator(expr, encoded_variables, stat_children)?;
+        let distinct_evaluator = if distinct {
+            Some(self.internal_expression_evaluator(expr, encoded_variables, stat_children)?)
+        } else {
+            None
+        };
+        if !order_by.is_empty() {
+            let comparators: Rc<[_]> = self
+                .comparator_functions(order_by, encoded_variables, stat_children)?
+                .into();
+            return Ok(Box::new(move || AccumulatorWrapper::OrderedExpression {
+                seen: distinct_evaluator
+                    .as_ref()
+                    .map(|evaluator| DistinctValues::new(evaluator.clone())),
+                evaluator: Rc::clone(&evaluator),
+                comparators: Rc::clone(&comparators),
+                values: Some(Vec::new()),
+                accumulator: accumulator(),
+            }));
+        }
+        Ok(if let Some(distinct_evaluator) = distinct_evaluator {
+            Box::new(move || AccumulatorWrapper::DistinctExpression {
+                seen: DistinctValues::new(distinct_evaluator.clone()),
+                evaluator: Rc::clone(&evaluator),
+                accumulator: Some(accumulator()),
+            })
+        } else {
+            Box::new(move || AccumulatorWrapper::Expression {
+                evaluator: Rc::clone(&evaluator),
+                accumulator: Some(accumulator()),
+            })
+        })
+    }
+
     /// Evaluates an expression and returns an internal term
     ///
     /// Returns None if building such expression would mean to convert back to an internal term at the end.
@@ -2050,6 +2272,34 @@ impl<'a, D: QueryableDataset<'a>> SimpleEvaluator<'a, D> {
         self.dataset.internalize_term(term.into())
     }
 
+    /// If the pattern is a quad pattern with a variable object, extracts from the filter expression a range of values the object must be in
+    fn filter_object_range(
+        &self,
+        pattern: &GraphPattern,
+        expression: &Expression,
+    ) -> Result<Option<(Bound<D::InternalTerm>, Bound<D::InternalTerm>)>, QueryEvaluationError>
+    {
+        let GraphPattern::QuadPattern {
+            object: GroundTermPattern::Variable(object),
+            ..
+        } = pattern
+        else {
+            return Ok(None);
+        };
+        let (start, end) = variable_range_from_expression(expression, object);
+        if matches!((start, end), (Bound::Unbounded, Bound::Unbounded)) {
+            return Ok(None);
+        }
+        let encode_bound = |bound: Bound<&Literal>| {
+            Ok::<_, QueryEvaluationError>(match bound {
+                Bound::Included(value) => Bound::Included(self.encode_term(value.clone())?),
+                Bound::Excluded(value) => Bound::Excluded(self.encode_term(value.clone())?),
+                Bound::Unbounded => Bound::Unbounded,
+            })
+        };
+        Ok(Some((encode_bound(start)?, encode_bound(end)?)))
+    }
+
     #[cfg(feature = "sparql-12")]
     fn encode_triple(
         &self,
@@ -2105,6 +2355,7 @@ impl<'a, D: QueryableDataset<'a>> Clone for SimpleEvaluator<'a, D> {
             service_handler: Rc::clone(&self.service_handler),
             custom_functions: Rc::clone(&self.custom_functions),
             custom_aggregate_functions: Rc::clone(&self.custom_aggregate_functions),
+            blank_node_generator: Rc::clone(&self.blank_node_generator),
             run_stats: self.run_stats,
         }
     }
@@ -2148,7 +2399,37 @@ impl<'a, 'b, D: QueryableDataset<'a>> ExpressionEvaluatorContext<'a>
             .graph_pattern_evaluator(plan, self.encoded_variables);
         self.stat_children.push(stats);
         let eval = eval?;
-        Ok(move |tuple: &InternalTuple<D::InternalTerm>| eval(tuple.clone()).next().is_some())
+        // Only the variables used by the pattern are bound before evaluating it
+        let mut variables = Vec::new();
+        plan.lookup_used_variables(&mut |v| {
+            let v = encode_variable(self.encoded_variables, v);
+            if !variables.contains(&v) {
+                variables.push(v);
+            }
+        });
+        // The consecutive tuples often share the same values for these variables, we reuse the last result then
+        let last = RefCell::new(None::<(Vec<Option<D::InternalTerm>>, bool)>);
+        Ok(move |tuple: &InternalTuple<D::InternalTerm>| {
+            let values = variables
+                .iter()
+                .map(|v| tuple.get(*v).cloned())
+                .collect::<Vec<_>>();
+            if let Some((last_values, last_result)) = &*last.borrow() {
+                if *last_values == values {
+                    return *last_result;
+                }
+            }
+            let mut input = InternalTuple::with_capacity(tuple.capacity());
+            for (variable, value) in variables.iter().zip(&values) {
+                if let Some(value) = value {
+                    input.set(*variable, value.clone());
+                }
+            }
+            // We stop at the first solution
+            let result = eval(input).next().is_some();
+            *last.borrow_mut() = Some((values, result));
+            result
+        })
     }
 
     fn internalize_named_node(
@@ -2187,6 +2468,37 @@ impl<'a, 'b, D: QueryableDataset<'a>> ExpressionEvaluatorContext<'a>
     fn custom_functions(&mut self) -> &CustomFunctionRegistry {
         &self.evaluator.custom_functions
     }
+
+    fn build_blank_node_generator(&mut self) -> impl Fn() -> BlankNode + 'a {
+        let generator = Rc::clone(&self.evaluator.blank_node_generator);
+        move || generator.generate()
+    }
+}
+
+/// Mints the fresh blank nodes of a query evaluation.
+///
+/// If a seed is set, the blank nodes ids are derived from the seed and a counter.
+/// Otherwise, they are random.
+pub struct BlankNodeGenerator {
+    next_id: Option<Cell<u128>>,
+}
+
+impl BlankNodeGenerator {
+    pub fn new(seed: Option<u128>) -> Self {
+        Self {
+            next_id: seed.map(Cell::new),
+        }
+    }
+
+    pub fn generate(&self) -> BlankNode {
+        if let Some(next_id) = &self.next_id {
+            let id = next_id.get();
+            next_id.set(id.wrapping_add(1));
+            BlankNode::new_from_unique_id(id)
+        } else {
+            BlankNode::default()
+        }
+    }
 }
 
 #[cfg(feature = "sparql-12")]
@@ -2358,10 +2670,19 @@ enum AccumulatorWrapper<'a, T> {
         accumulator: Option<Box<dyn Accumulator>>,
     },
     DistinctExpression {
-        seen: FxHashSet<ExpressionTerm>,
+        seen: DistinctValues<'a, T>,
         evaluator: Rc<dyn Fn(&InternalTuple<T>) -> Option<ExpressionTerm> + 'a>,
         accumulator: Option<Box<dyn Accumulator>>,
     },
+    /// Buffers the values with their sort keys and feeds them sorted to the accumulator when finishing
+    OrderedExpression {
+        /// Only set if DISTINCT is used, the first sort keys seen for a given value are kept
+        seen: Option<DistinctValues<'a, T>>,
+        evaluator: Rc<dyn Fn(&InternalTuple<T>) -> Option<ExpressionTerm> + 'a>,
+        comparators: Rc<[ComparatorFunction<'a, T>]>,
+        values: Option<Vec<(Vec<Option<ExpressionTerm>>, ExpressionTerm)>>,
+        accumulator: Box<dyn Accumulator>,
+    },
 }
 
 impl<T: Clone + Eq + Hash> AccumulatorWrapper<'_, T> {
@@ -2429,10 +2750,42 @@ impl<T: Clone + Eq + Hash> AccumulatorWrapper<'_, T> {
                 let Some(accumulator) = accumulator else {
                     return;
                 };
-                if seen.insert(value.clone()) {
+                if seen.insert(tuple, &value) {
                     accumulator.accumulate(value);
                 }
             }
+            Self::OrderedExpression {
+                seen,
+                evaluator,
+                comparators,
+                values,
+                ..
+            } => {
+                if values.is_none() {
+                    return; // Already failed
+                }
+                let Some(value) = evaluator(tuple) else {
+                    *values = None;
+                    return;
+                };
+                let Some(values) = values else {
+                    return;
+                };
+                if let Some(seen) = seen {
+                    if !seen.insert(tuple, &value) {
+                        return;
+                    }
+                }
+                let keys = comparators
+                    .iter()
+                    .map(|comparator| {
+                        let (ComparatorFunction::Asc(expression)
+                        | ComparatorFunction::Desc(expression)) = comparator;
+                        expression(tuple)
+                    })
+                    .collect();
+                values.push((keys, value));
+            }
         }
     }
 
@@ -2448,6 +2801,68 @@ impl<T: Clone + Eq + Hash> AccumulatorWrapper<'_, T> {
             Self::Expression { accumulator, .. } | Self::DistinctExpression { accumulator, .. } => {
                 accumulator?.finish()
             }
+            Self::OrderedExpression {
+                comparators,
+                values,
+                mut accumulator,
+                ..
+            } => {
+                let mut values = values?;
+                // The sort is stable: values with the same keys are kept in arrival order
+                values.sort_by(|(a, _), (b, _)| {
+                    for (comparator, (a, b)) in comparators.iter().zip(a.iter().zip(b)) {
+                        let ordering = cmp_terms(a.as_ref(), b.as_ref());
+                        let ordering = match comparator {
+                            ComparatorFunction::Asc(_) => ordering,
+                            ComparatorFunction::Desc(_) => ordering.reverse(),
+                        };
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                    Ordering::Equal
+                });
+                for (_, value) in values {
+                    accumulator.accumulate(value);
+                }
+                accumulator.finish()
+            }
+        }
+    }
+}
+
+/// The values already given to an aggregate function with DISTINCT
+enum DistinctValues<'a, T> {
+    /// Compares the internal terms, i.e. the actual RDF terms
+    Internal {
+        evaluator: Rc<dyn Fn(&InternalTuple<T>) -> Option<T> + 'a>,
+        seen: FxHashSet<T>,
+    },
+    /// Used if the expression can't be evaluated to an internal term
+    Expression { seen: FxHashSet<ExpressionTerm> },
+}
+
+impl<'a, T: Eq + Hash> DistinctValues<'a, T> {
+    fn new(evaluator: Option<Rc<dyn Fn(&InternalTuple<T>) -> Option<T> + 'a>>) -> Self {
+        if let Some(evaluator) = evaluator {
+            Self::Internal {
+                evaluator,
+                seen: FxHashSet::default(),
+            }
+        } else {
+            Self::Expression {
+                seen: FxHashSet::default(),
+            }
+        }
+    }
+
+    /// Returns `true` if the value has not been seen yet
+    fn insert(&mut self, tuple: &InternalTuple<T>, value: &ExpressionTerm) -> bool {
+        match self {
+            Self::Internal { evaluator, seen } => {
+                evaluator(tuple).is_none_or(|term| seen.insert(term))
+            }
+            Self::Expression { seen } => seen.insert(value.clone()),
         }
     }
 }
@@ -2653,6 +3068,61 @@ impl Accumulator for CustomAccumulator {
     }
 }
 
+/// Extracts from a filter expression a range of values the variable must be in for the filter to succeed
+///
+/// Only the comparisons between the variable and a literal, possibly nested in `&&`, are considered.
+fn variable_range_from_expression<'b>(
+    expression: &'b Expression,
+    variable: &Variable,
+) -> (Bound<&'b Literal>, Bound<&'b Literal>) {
+    match expression {
+        Expression::And(inner) => {
+            let mut start = Bound::Unbounded;
+            let mut end = Bound::Unbounded;
+            for inner in inner {
+                let (inner_start, inner_end) = variable_range_from_expression(inner, variable);
+                if matches!(start, Bound::Unbounded) {
+                    start = inner_start;
+                }
+                if matches!(end, Bound::Unbounded) {
+                    end = inner_end;
+                }
+            }
+            (start, end)
+        }
+        Expression::Greater(left, right) => {
+            variable_range_from_greater(left, right, variable, Bound::Excluded)
+        }
+        Expression::GreaterOrEqual(left, right) => {
+            variable_range_from_greater(left, right, variable, Bound::Included)
+        }
+        Expression::Less(left, right) => {
+            variable_range_from_greater(right, left, variable, Bound::Excluded)
+        }
+        Expression::LessOrEqual(left, right) => {
+            variable_range_from_greater(right, left, variable, Bound::Included)
+        }
+        _ => (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
+fn variable_range_from_greater<'b>(
+    left: &'b Expression,
+    right: &'b Expression,
+    variable: &Variable,
+    bound: fn(&'b Literal) -> Bound<&'b Literal>,
+) -> (Bound<&'b Literal>, Bound<&'b Literal>) {
+    match (left, right) {
+        (Expression::Variable(v), Expression::Literal(l)) if v == variable => {
+            (bound(l), Bound::Unbounded)
+        }
+        (Expression::Literal(l), Expression::Variable(v)) if v == variable => {
+            (Bound::Unbounded, bound(l))
+        }
+        _ => (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
 fn encode_variable(variables: &mut Vec<Variable>, variable: &Variable) -> usize {
     if let Some(key) = slice_key(variables, variable) {
         key
@@ -2681,7 +3151,7 @@ fn slice_key<T: Eq>(slice: &[T], element: &T) -> Option<usize> {
 }
 
 /// Comparison for ordering
-fn cmp_terms(a: Option<&ExpressionTerm>, b: Option<&ExpressionTerm>) -> Ordering {
+pub fn cmp_terms(a: Option<&ExpressionTerm>, b: Option<&ExpressionTerm>) -> Ordering {
     match (a, b) {
         (Some(a), Some(b)) => {
             match a {
@@ -2708,14 +3178,28 @@ fn cmp_terms(a: Option<&ExpressionTerm>, b: Option<&ExpressionTerm>) -> Ordering
                     _ => {
                         if let Some(ord) = partial_cmp_literals(a, b) {
                             ord
+                        } else if let Some(ord) = cmp_nan_with_numeric(a, b) {
+                            ord
                         } else if let (Term::Literal(a), Term::Literal(b)) =
                             (a.clone().into(), b.clone().into())
                         {
-                            (a.value(), a.datatype(), a.language()).cmp(&(
-                                b.value(),
-                                b.datatype(),
-                                b.language(),
-                            ))
+                            #[cfg(feature = "sparql-12")]
+                            {
+                                (a.value(), a.datatype(), a.language(), a.direction()).cmp(&(
+                                    b.value(),
+                                    b.datatype(),
+                                    b.language(),
+                                    b.direction(),
+                                ))
+                            }
+                            #[cfg(not(feature = "sparql-12"))]
+                            {
+                                (a.value(), a.datatype(), a.language()).cmp(&(
+                                    b.value(),
+                                    b.datatype(),
+                                    b.language(),
+                                ))
+                            }
                         } else {
                             Ordering::Equal // Should never happen
                         }
@@ -2729,6 +3213,15 @@ fn cmp_terms(a: Option<&ExpressionTerm>, b: Option<&ExpressionTerm>) -> Ordering
     }
 }
 
+/// NaN is not comparable with the other numbers so we put it after all of them
+fn cmp_nan_with_numeric(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Ordering> {
+    match (is_nan(a)?, is_nan(b)?) {
+        (true, false) => Some(Ordering::Greater),
+        (false, true) => Some(Ordering::Less),
+        _ => None,
+    }
+}
+
 #[cfg(feature = "sparql-12")]
 fn cmp_triples(a: &ExpressionTriple, b: &ExpressionTriple) -> Ordering {
     match match &a.subject {
@@ -2999,15 +3492,17 @@ impl<'a, D: QueryableDataset<'a>> PathEvaluator<'a, D> {
                 } else {
                     look_in_transitive_closure(
                         self.eval_from_in_graph(p, start, graph_name),
-                        move |e| self.eval_from_in_graph(p, &e, graph_name),
+                        |e| self.eval_from_in_graph(p, &e, graph_name),
                         end,
+                        |e| self.eval_to_in_graph(p, &e, graph_name),
                     )?
                 }
             }
             PropertyPath::OneOrMore(p) => look_in_transitive_closure(
                 self.eval_from_in_graph(p, start, graph_name),
-                move |e| self.eval_from_in_graph(p, &e, graph_name),
+                |e| self.eval_from_in_graph(p, &e, graph_name),
                 end,
+                |e| self.eval_to_in_graph(p, &e, graph_name),
             )?,
             PropertyPath::ZeroOrOne(p) => {
                 if start == end {
@@ -3073,26 +3568,32 @@ impl<'a, D: QueryableDataset<'a>> PathEvaluator<'a, D> {
                         Some(Ok(start2.clone())),
                         |e| eval.eval_from_in_graph(&p, &e, graph_name.as_ref()),
                         &end,
+                        |e| eval.eval_to_in_graph(&p, &e, graph_name.as_ref()),
                     )
                     .map(|is_found| is_found.then_some(graph_name))
                     .transpose()
                 })
             }
             PropertyPath::OneOrMore(p) => {
+                // We run a single search per graph, starting from all the first steps in this graph
+                let first_steps = match group_by_graph(self.eval_from_in_unknown_graph(p, start)) {
+                    Ok(first_steps) => first_steps,
+                    Err(error) => return Box::new(once(Err(error))),
+                };
                 let eval = self.clone();
                 let end = end.clone();
                 let p = Rc::clone(p);
                 Box::new(
-                    self.eval_from_in_unknown_graph(&p, start)
-                        .filter_map(move |r| {
-                            r.and_then(|(start, graph_name)| {
-                                look_in_transitive_closure(
-                                    Some(Ok(start)),
-                                    |e| eval.eval_from_in_graph(&p, &e, graph_name.as_ref()),
-                                    &end,
-                                )
-                                .map(|is_found| is_found.then_some(graph_name))
-                            })
+                    first_steps
+                        .into_iter()
+                        .filter_map(move |(graph_name, first_steps)| {
+                            look_in_transitive_closure(
+                                first_steps.into_iter().map(Ok),
+                                |e| eval.eval_from_in_graph(&p, &e, graph_name.as_ref()),
+                                &end,
+                                |e| eval.eval_to_in_graph(&p, &e, graph_name.as_ref()),
+                            )
+                            .map(|is_found| is_found.then_some(graph_name))
                             .transpose()
                         }),
                 )
@@ -3497,31 +3998,47 @@ impl<'a, D: QueryableDataset<'a>> PathEvaluator<'a, D> {
                     .chain(self.eval_open_in_graph(b, graph_name)),
             )),
             PropertyPath::ZeroOrMore(p) => {
+                // We run a search from each node of the graph to only keep one visited set in memory at a time
                 let eval = self.clone();
                 let p = Rc::clone(p);
                 let graph_name2 = graph_name.cloned();
-                Box::new(transitive_closure(
-                    self.get_subject_or_object_identity_pairs_in_graph(graph_name),
-                    move |(start, middle)| {
-                        eval.eval_from_in_graph(&p, &middle, graph_name2.as_ref())
+                Box::new(
+                    hash_deduplicate(self.get_subjects_and_objects_in_graph(graph_name))
+                        .flat_map_ok(move |start| {
+                            let eval = eval.clone();
+                            let p = Rc::clone(&p);
+                            let graph_name = graph_name2.clone();
+                            transitive_closure(Some(Ok(start.clone())), move |e| {
+                                eval.eval_from_in_graph(&p, &e, graph_name.as_ref())
+                            })
                             .map(move |end| Ok((start.clone(), end?)))
-                    },
-                ))
+                        }),
+                )
             }
             PropertyPath::OneOrMore(p) => {
                 let eval = self.clone();
                 let p = Rc::clone(p);
                 let graph_name2 = graph_name.cloned();
-                Box::new(transitive_closure(
-                    self.eval_open_in_graph(&p, graph_name),
-                    move |(start, middle)| {
-                        eval.eval_from_in_graph(&p, &middle, graph_name2.as_ref())
-                            .map(move |end| Ok((start.clone(), end?)))
-                    },
-                ))
+                Box::new(
+                    hash_deduplicate(
+                        self.eval_open_in_graph(&p, graph_name)
+                            .map(|t| t.map(|(start, _)| start)),
+                    )
+                    .flat_map_ok(move |start| {
+                        let eval = eval.clone();
+                        let p = Rc::clone(&p);
+                        let graph_name = graph_name2.clone();
+                        transitive_closure(
+                            eval.eval_from_in_graph(&p, &start, graph_name.as_ref()),
+                            move |e| eval.eval_from_in_graph(&p, &e, graph_name.as_ref()),
+                        )
+                        .map(move |end| Ok((start.clone(), end?)))
+                    }),
+                )
             }
             PropertyPath::ZeroOrOne(p) => Box::new(hash_deduplicate(
-                self.get_subject_or_object_identity_pairs_in_graph(graph_name)
+                self.get_subjects_and_objects_in_graph(graph_name)
+                    .map(|t| t.map(|t| (t.clone(), t)))
                     .chain(self.eval_open_in_graph(p, graph_name)),
             )),
             PropertyPath::NegatedPropertySet(ps) => {
@@ -3583,29 +4100,46 @@ impl<'a, D: QueryableDataset<'a>> PathEvaluator<'a, D> {
                     .chain(self.eval_open_in_unknown_graph(b)),
             )),
             PropertyPath::ZeroOrMore(p) => {
+                // We run a search from each node of each graph to only keep one visited set in memory at a time
                 let eval = self.clone();
                 let p = Rc::clone(p);
-                Box::new(transitive_closure(
-                    self.get_subject_or_object_identity_pairs_in_dataset(),
-                    move |(start, middle, graph_name)| {
-                        eval.eval_from_in_graph(&p, &middle, graph_name.as_ref())
+                Box::new(
+                    hash_deduplicate(self.get_subjects_and_objects_in_dataset()).flat_map_ok(
+                        move |(start, graph_name)| {
+                            let eval = eval.clone();
+                            let p = Rc::clone(&p);
+                            let graph_name2 = graph_name.clone();
+                            transitive_closure(Some(Ok(start.clone())), move |e| {
+                                eval.eval_from_in_graph(&p, &e, graph_name2.as_ref())
+                            })
                             .map(move |end| Ok((start.clone(), end?, graph_name.clone())))
-                    },
-                ))
+                        },
+                    ),
+                )
             }
             PropertyPath::OneOrMore(p) => {
                 let eval = self.clone();
                 let p = Rc::clone(p);
-                Box::new(transitive_closure(
-                    self.eval_open_in_unknown_graph(&p),
-                    move |(start, middle, graph_name)| {
-                        eval.eval_from_in_graph(&p, &middle, graph_name.as_ref())
-                            .map(move |end| Ok((start.clone(), end?, graph_name.clone())))
-                    },
-                ))
+                Box::new(
+                    hash_deduplicate(
+                        self.eval_open_in_unknown_graph(&p)
+                            .map(|t| t.map(|(start, _, graph_name)| (start, graph_name))),
+                    )
+                    .flat_map_ok(move |(start, graph_name)| {
+                        let eval = eval.clone();
+                        let p = Rc::clone(&p);
+                        let graph_name2 = graph_name.clone();
+                        transitive_closure(
+                            eval.eval_from_in_graph(&p, &start, graph_name.as_ref()),
+                            move |e| eval.eval_from_in_graph(&p, &e, graph_name2.as_ref()),
+                        )
+                        .map(move |end| Ok((start.clone(), end?, graph_name.clone())))
+                    }),
+                )
             }
             PropertyPath::ZeroOrOne(p) => Box::new(hash_deduplicate(
-                self.get_subject_or_object_identity_pairs_in_dataset()
+                self.get_subjects_and_objects_in_dataset()
+                    .map(|t| t.map(|(t, g)| (t.clone(), t, g)))
                     .chain(self.eval_open_in_unknown_graph(p)),
             )),
             PropertyPath::NegatedPropertySet(ps) => {
@@ -3628,35 +4162,28 @@ impl<'a, D: QueryableDataset<'a>> PathEvaluator<'a, D> {
         }
     }
 
-    fn get_subject_or_object_identity_pairs_in_graph(
+    /// The graph nodes, i.e. the subjects and objects of the graph triples, possibly with duplicates
+    fn get_subjects_and_objects_in_graph(
         &self,
         graph_name: Option<&D::InternalTerm>,
-    ) -> impl Iterator<Item = Result<(D::InternalTerm, D::InternalTerm), QueryEvaluationError>>
-    + use<'a, D> {
+    ) -> impl Iterator<Item = Result<D::InternalTerm, QueryEvaluationError>> + use<'a, D> {
         self.dataset
             .internal_quads_for_pattern(None, None, None, Some(graph_name))
-            .flat_map_ok(|t| {
-                [
-                    Ok((t.subject.clone(), t.subject)),
-                    Ok((t.object.clone(), t.object)),
-                ]
-            })
+            .flat_map_ok(|t| [Ok(t.subject), Ok(t.object)])
     }
 
-    fn get_subject_or_object_identity_pairs_in_dataset(
+    /// The graph nodes of all the dataset graphs with their graph, possibly with duplicates
+    fn get_subjects_and_objects_in_dataset(
         &self,
     ) -> impl Iterator<
-        Item = Result<
-            (D::InternalTerm, D::InternalTerm, Option<D::InternalTerm>),
-            QueryEvaluationError,
-        >,
+        Item = Result<(D::InternalTerm, Option<D::InternalTerm>), QueryEvaluationError>,
     > + use<'a, D> {
         self.dataset
             .internal_quads_for_pattern(None, None, None, None)
             .flat_map_ok(|t| {
                 [
-                    Ok((t.subject.clone(), t.subject, t.graph_name.clone())),
-                    Ok((t.object.clone(), t.object, t.graph_name)),
+                    Ok((t.subject, t.graph_name.clone())),
+                    Ok((t.object, t.graph_name)),
                 ]
             })
     }
@@ -3955,14 +4482,15 @@ impl<T: Eq> Iterator for ConsecutiveDeduplication<'_, T> {
 struct ConstructIterator<'a, D: QueryableDataset<'a>> {
     eval: SimpleEvaluator<'a, D>,
     iter: InternalTuplesIterator<'a, D::InternalTerm>,
-    template: Vec<TripleTemplate>,
-    buffered_results: Vec<Result<Triple, QueryEvaluationError>>,
-    already_emitted_results: FxHashSet<Triple>,
+    /// The triple templates with their graph name, `None` for the default graph
+    template: Vec<(TripleTemplate, Option<TripleTemplateValue>)>,
+    buffered_results: Vec<Result<Quad, QueryEvaluationError>>,
+    already_emitted_results: FxHashSet<Quad>,
     bnodes: Vec<BlankNode>,
 }
 
 impl<'a, D: QueryableDataset<'a>> Iterator for ConstructIterator<'a, D> {
-    type Item = Result<Triple, QueryEvaluationError>;
+    type Item = Result<Quad, QueryEvaluationError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -3974,12 +4502,13 @@ impl<'a, D: QueryableDataset<'a>> Iterator for ConstructIterator<'a, D> {
                     Ok(tuple) => tuple,
                     Err(error) => return Some(Err(error)),
                 };
-                for template in &self.template {
-                    if let (Some(subject), Some(predicate), Some(object)) = (
+                for (template, graph_name) in &self.template {
+                    if let (Some(subject), Some(predicate), Some(object), Some(graph_name)) = (
                         get_triple_template_value(
                             &template.subject,
                             &tuple,
                             &mut self.bnodes,
+                            &self.eval.blank_node_generator,
                             &self.eval.dataset,
                         )
                         .and_then(|t| t.try_into().ok()),
@@ -3987,6 +4516,7 @@ impl<'a, D: QueryableDataset<'a>> Iterator for ConstructIterator<'a, D> {
                             &template.predicate,
                             &tuple,
                             &mut self.bnodes,
+                            &self.eval.blank_node_generator,
                             &self.eval.dataset,
                         )
                         .and_then(|t| t.try_into().ok()),
@@ -3994,27 +4524,42 @@ impl<'a, D: QueryableDataset<'a>> Iterator for ConstructIterator<'a, D> {
                             &template.object,
                             &tuple,
                             &mut self.bnodes,
+                            &self.eval.blank_node_generator,
                             &self.eval.dataset,
                         ),
+                        if let Some(graph_name) = graph_name {
+                            get_triple_template_value(
+                                graph_name,
+                                &tuple,
+                                &mut self.bnodes,
+                                &self.eval.blank_node_generator,
+                                &self.eval.dataset,
+                            )
+                            .and_then(|t| NamedNode::try_from(t).ok())
+                            .map(GraphName::from)
+                        } else {
+                            Some(GraphName::DefaultGraph)
+                        },
                     ) {
-                        let triple = Triple {
+                        let quad = Quad {
                             subject,
                             predicate,
                             object,
+                            graph_name,
                         };
                         // We allocate new blank nodes for each solution,
                         // triples with blank nodes are likely to be new.
                         #[cfg(feature = "sparql-12")]
-                        let new_triple = triple.subject.is_blank_node()
-                            || triple.object.is_blank_node()
-                            || triple.object.is_triple()
-                            || self.already_emitted_results.insert(triple.clone());
+                        let new_quad = quad.subject.is_blank_node()
+                            || quad.object.is_blank_node()
+                            || quad.object.is_triple()
+                            || self.already_emitted_results.insert(quad.clone());
                         #[cfg(not(feature = "sparql-12"))]
-                        let new_triple = triple.subject.is_blank_node()
-                            || triple.object.is_blank_node()
-                            || self.already_emitted_results.insert(triple.clone());
-                        if new_triple {
-                            self.buffered_results.push(Ok(triple));
+                        let new_quad = quad.subject.is_blank_node()
+                            || quad.object.is_blank_node()
+                            || self.already_emitted_results.insert(quad.clone());
+                        if new_quad {
+                            self.buffered_results.push(Ok(quad));
                             if self.already_emitted_results.len() > 1024 * 1024 {
                                 // We don't want to have a too big memory impact
                                 self.already_emitted_results.clear();
@@ -4110,6 +4655,7 @@ fn get_triple_template_value<'a, D: QueryableDataset<'a>>(
     selector: &TripleTemplateValue,
     tuple: &InternalTuple<D::InternalTerm>,
     bnodes: &mut Vec<BlankNode>,
+    blank_node_generator: &BlankNodeGenerator,
     dataset: &EvalDataset<'a, D>,
 ) -> Option<Term> {
     match selector {
@@ -4121,20 +4667,38 @@ fn get_triple_template_value<'a, D: QueryableDataset<'a>>(
         }
         TripleTemplateValue::BlankNode(bnode) => {
             if *bnode >= bnodes.len() {
-                bnodes.resize_with(*bnode + 1, BlankNode::default)
+                bnodes.resize_with(*bnode + 1, || blank_node_generator.generate())
             }
             Some(bnodes[*bnode].clone().into())
         }
         #[cfg(feature = "sparql-12")]
         TripleTemplateValue::Triple(triple) => Some(
             Triple {
-                subject: get_triple_template_value(&triple.subject, tuple, bnodes, dataset)?
-                    .try_into()
-                    .ok()?,
-                predicate: get_triple_template_value(&triple.predicate, tuple, bnodes, dataset)?
-                    .try_into()
-                    .ok()?,
-                object: get_triple_template_value(&triple.object, tuple, bnodes, dataset)?,
+                subject: get_triple_template_value(
+                    &triple.subject,
+                    tuple,
+                    bnodes,
+                    blank_node_generator,
+                    dataset,
+                )?
+                .try_into()
+                .ok()?,
+                predicate: get_triple_template_value(
+                    &triple.predicate,
+                    tuple,
+                    bnodes,
+                    blank_node_generator,
+                    dataset,
+                )?
+                .try_into()
+                .ok()?,
+                object: get_triple_template_value(
+                    &triple.object,
+                    tuple,
+                    bnodes,
+                    blank_node_generator,
+                    dataset,
+                )?,
             }
             .into(),
         ),
@@ -4144,10 +4708,28 @@ fn get_triple_template_value<'a, D: QueryableDataset<'a>>(
 struct DescribeIterator<'a, D: QueryableDataset<'a>> {
     eval: SimpleEvaluator<'a, D>,
     tuples_to_describe: InternalTuplesIterator<'a, D::InternalTerm>,
-    nodes_described: FxHashSet<D::InternalTerm>,
-    nodes_to_describe: Vec<D::InternalTerm>,
+    symmetric: bool,
+    nodes_described: FxHashSet<(D::InternalTerm, DescribeDirection)>,
+    nodes_to_describe: Vec<(D::InternalTerm, DescribeDirection)>,
     quads:
         Box<dyn Iterator<Item = Result<InternalQuad<D::InternalTerm>, QueryEvaluationError>> + 'a>,
+    quads_direction: DescribeDirection,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+enum DescribeDirection {
+    /// Triples with the node as subject
+    Outbound,
+    /// Triples with the node as object
+    Inbound,
+}
+
+impl<'a, D: QueryableDataset<'a>> DescribeIterator<'a, D> {
+    fn add_node_to_describe(&mut self, node: D::InternalTerm, direction: DescribeDirection) {
+        if self.nodes_described.insert((node.clone(), direction)) {
+            self.nodes_to_describe.push((node, direction));
+        }
+    }
 }
 
 impl<'a, D: QueryableDataset<'a>> Iterator for DescribeIterator<'a, D> {
@@ -4161,7 +4743,7 @@ impl<'a, D: QueryableDataset<'a>> Iterator for DescribeIterator<'a, D> {
                     Err(error) => return Some(Err(error)),
                 };
                 // We yield the triple
-                let subject = match self.eval.dataset.externalize_term(quad.subject) {
+                let subject = match self.eval.dataset.externalize_term(quad.subject.clone()) {
                     Ok(t) => t,
                     Err(e) => return Some(Err(e)),
                 };
@@ -4173,9 +4755,29 @@ impl<'a, D: QueryableDataset<'a>> Iterator for DescribeIterator<'a, D> {
                     Ok(t) => t,
                     Err(e) => return Some(Err(e)),
                 };
-                // If there is a blank node object, we need to describe it too
-                if object.is_blank_node() && self.nodes_described.insert(quad.object.clone()) {
-                    self.nodes_to_describe.push(quad.object);
+                match self.quads_direction {
+                    DescribeDirection::Outbound => {
+                        // If there is a blank node object, we need to describe it too
+                        if object.is_blank_node() {
+                            self.add_node_to_describe(quad.object, DescribeDirection::Outbound);
+                        }
+                    }
+                    DescribeDirection::Inbound => {
+                        // If there is a blank node subject, we need to describe it too
+                        if subject.is_blank_node() {
+                            self.add_node_to_describe(
+                                quad.subject.clone(),
+                                DescribeDirection::Inbound,
+                            );
+                        }
+                        if self
+                            .nodes_described
+                            .contains(&(quad.subject, DescribeDirection::Outbound))
+                        {
+                            // The triple is already returned by the subject description
+                            continue;
+                        }
+                    }
                 }
                 return Some(Ok(Triple {
                     subject: subject.try_into().ok()?,
@@ -4183,81 +4785,258 @@ impl<'a, D: QueryableDataset<'a>> Iterator for DescribeIterator<'a, D> {
                     object,
                 }));
             }
-            if let Some(node_to_describe) = self.nodes_to_describe.pop() {
+            if let Some((node_to_describe, direction)) = self.nodes_to_describe.pop() {
                 // We have a new node to describe
-                self.quads = self.eval.dataset.internal_quads_for_pattern(
-                    Some(&node_to_describe),
-                    None,
-                    None,
-                    Some(None),
-                );
+                self.quads_direction = direction;
+                self.quads = match direction {
+                    DescribeDirection::Outbound => self.eval.dataset.internal_quads_for_pattern(
+                        Some(&node_to_describe),
+                        None,
+                        None,
+                        Some(None),
+                    ),
+                    DescribeDirection::Inbound => self.eval.dataset.internal_quads_for_pattern(
+                        None,
+                        None,
+                        Some(&node_to_describe),
+                        Some(None),
+                    ),
+                };
             } else {
                 let tuple = match self.tuples_to_describe.next()? {
                     Ok(tuple) => tuple,
                     Err(error) => return Some(Err(error)),
                 };
                 for node in tuple.into_iter().flatten() {
-                    if self.nodes_described.insert(node.clone()) {
-                        self.nodes_to_describe.push(node);
+                    // The stack is LIFO: we push the inbound direction first to describe the outbound triples first
+                    if self.symmetric {
+                        self.add_node_to_describe(node.clone(), DescribeDirection::Inbound);
                     }
+                    self.add_node_to_describe(node, DescribeDirection::Outbound);
                 }
             }
         }
     }
 }
 
-fn transitive_closure<T: Clone + Eq + Hash, E, NI: Iterator<Item = Result<T, E>>>(
-    start: impl IntoIterator<Item = Result<T, E>>,
-    mut next: impl FnMut(T) -> NI,
-) -> impl Iterator<Item = Result<T, E>> {
-    let mut errors = Vec::new();
-    let mut todo = start
-        .into_iter()
-        .filter_map(|e| match e {
-            Ok(e) => Some(e),
-            Err(e) => {
-                errors.push(e);
-                None
-            }
-        })
-        .collect::<Vec<_>>();
-    let mut all = todo.iter().cloned().collect::<FxHashSet<_>>();
-    while let Some(e) = todo.pop() {
-        for e in next(e) {
-            match e {
-                Ok(e) => {
-                    if all.insert(e.clone()) {
-                        todo.push(e)
+impl<'a, D: QueryableDataset<'a>> TriplesForPattern for SimpleEvaluator<'a, D> {
+    fn triples_for_pattern<'b>(
+        &'b self,
+        subject: Option<&Term>,
+        predicate: Option<&NamedNode>,
+        object: Option<&Term>,
+    ) -> Box<dyn Iterator<Item = Result<Triple, QueryEvaluationError>> + 'b> {
+        let subject = match subject
+            .map(|t| self.dataset.internalize_term(t.clone()))
+            .transpose()
+        {
+            Ok(t) => t,
+            Err(e) => return Box::new(once(Err(e))),
+        };
+        let predicate = match predicate
+            .map(|t| self.dataset.internalize_term(t.clone().into()))
+            .transpose()
+        {
+            Ok(t) => t,
+            Err(e) => return Box::new(once(Err(e))),
+        };
+        let object = match object
+            .map(|t| self.dataset.internalize_term(t.clone()))
+            .transpose()
+        {
+            Ok(t) => t,
+            Err(e) => return Box::new(once(Err(e))),
+        };
+        Box::new(
+            self.dataset
+                .internal_quads_for_pattern(
+                    subject.as_ref(),
+                    predicate.as_ref(),
+                    object.as_ref(),
+                    Some(None),
+                )
+                .filter_map(|quad| {
+                    let quad = match quad {
+                        Ok(quad) => quad,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    let subject = match self.dataset.externalize_term(quad.subject) {
+                        Ok(t) => t,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    let predicate = match self.dataset.externalize_term(quad.predicate) {
+                        Ok(t) => t,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    let object = match self.dataset.externalize_term(quad.object) {
+                        Ok(t) => t,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    Some(Ok(Triple {
+                        subject: subject.try_into().ok()?,
+                        predicate: predicate.try_into().ok()?,
+                        object,
+                    }))
+                }),
+        )
+    }
+}
+
+/// Returns the start nodes and all the nodes reachable from them using `next`
+///
+/// It is a lazy breadth-first search: each node is returned once, as soon as it is discovered.
+fn transitive_closure<
+    T: Clone + Eq + Hash,
+    E,
+    S: IntoIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> NI,
+    NI: Iterator<Item = Result<T, E>>,
+>(
+    start: S,
+    next: F,
+) -> TransitiveClosure<T, E, S::IntoIter, F, NI> {
+    TransitiveClosure {
+        start: start.into_iter().fuse(),
+        next,
+        current: None,
+        queue: VecDeque::new(),
+        visited: FxHashSet::default(),
+    }
+}
+
+struct TransitiveClosure<
+    T,
+    E,
+    S: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> NI,
+    NI: Iterator<Item = Result<T, E>>,
+> {
+    start: Fuse<S>,
+    next: F,
+    current: Option<NI>,
+    queue: VecDeque<T>,
+    visited: FxHashSet<T>,
+}
+
+impl<
+    T: Clone + Eq + Hash,
+    E,
+    S: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> NI,
+    NI: Iterator<Item = Result<T, E>>,
+> Iterator for TransitiveClosure<T, E, S, F, NI>
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let candidate = if let Some(candidate) = self.start.next() {
+                candidate
+            } else if let Some(current) = &mut self.current {
+                let Some(candidate) = current.next() else {
+                    self.current = None;
+                    continue;
+                };
+                candidate
+            } else {
+                let node = self.queue.pop_front()?;
+                self.current = Some((self.next)(node));
+                continue;
+            };
+            match candidate {
+                Ok(node) => {
+                    if self.visited.insert(node.clone()) {
+                        self.queue.push_back(node.clone());
+                        return Some(Ok(node));
                     }
                 }
-                Err(e) => errors.push(e),
+                Err(e) => return Some(Err(e)),
             }
         }
     }
-    errors.into_iter().map(Err).chain(all.into_iter().map(Ok))
 }
 
-fn look_in_transitive_closure<T: Clone + Eq + Hash, E, NI: Iterator<Item = Result<T, E>>>(
+/// Checks if `target` is one of the start nodes or is reachable from them using `forward`
+///
+/// It is a bidirectional breadth-first search: `backward` must return the nodes from which the given node is reachable in one step.
+/// The smallest frontier is always expanded first.
+fn look_in_transitive_closure<
+    T: Clone + Eq + Hash,
+    E,
+    FI: Iterator<Item = Result<T, E>>,
+    BI: Iterator<Item = Result<T, E>>,
+>(
     start: impl IntoIterator<Item = Result<T, E>>,
-    mut next: impl FnMut(T) -> NI,
+    mut forward: impl FnMut(T) -> FI,
     target: &T,
+    mut backward: impl FnMut(T) -> BI,
 ) -> Result<bool, E> {
-    let mut todo = start.into_iter().collect::<Result<Vec<_>, _>>()?;
-    let mut all = todo.iter().cloned().collect::<FxHashSet<_>>();
-    while let Some(e) = todo.pop() {
-        if e == *target {
+    let mut forward_queue = start.into_iter().collect::<Result<VecDeque<_>, _>>()?;
+    let mut forward_visited = forward_queue.iter().cloned().collect::<FxHashSet<_>>();
+    if forward_visited.contains(target) {
+        return Ok(true);
+    }
+    let mut backward_queue = VecDeque::from([target.clone()]);
+    let mut backward_visited = FxHashSet::from_iter([target.clone()]);
+    while !forward_queue.is_empty() && !backward_queue.is_empty() {
+        let found = if forward_queue.len() <= backward_queue.len() {
+            expand_breadth_first_search_level(
+                &mut forward_queue,
+                &mut forward_visited,
+                &backward_visited,
+                &mut forward,
+            )?
+        } else {
+            expand_breadth_first_search_level(
+                &mut backward_queue,
+                &mut backward_visited,
+                &forward_visited,
+                &mut backward,
+            )?
+        };
+        if found {
             return Ok(true);
         }
-        for e in next(e) {
-            let e = e?;
-            if all.insert(e.clone()) {
-                todo.push(e);
+    }
+    Ok(false)
+}
+
+/// Visits all the nodes of the queue current level and returns if a node visited by the other search has been found
+fn expand_breadth_first_search_level<T: Clone + Eq + Hash, E, I: Iterator<Item = Result<T, E>>>(
+    queue: &mut VecDeque<T>,
+    visited: &mut FxHashSet<T>,
+    visited_by_other_search: &FxHashSet<T>,
+    next: &mut impl FnMut(T) -> I,
+) -> Result<bool, E> {
+    for _ in 0..queue.len() {
+        let Some(node) = queue.pop_front() else {
+            break;
+        };
+        for node in next(node) {
+            let node = node?;
+            if visited_by_other_search.contains(&node) {
+                return Ok(true);
+            }
+            if visited.insert(node.clone()) {
+                queue.push_back(node);
             }
         }
     }
     Ok(false)
 }
 
+/// Groups the nodes by the graph they have been found in
+fn group_by_graph<T: Eq + Hash, E>(
+    iter: impl Iterator<Item = Result<(T, Option<T>), E>>,
+) -> Result<FxHashMap<Option<T>, Vec<T>>, E> {
+    let mut groups = FxHashMap::<_, Vec<_>>::default();
+    for result in iter {
+        let (node, graph_name) = result?;
+        groups.entry(graph_name).or_default().push(node);
+    }
+    Ok(groups)
+}
+
 fn hash_deduplicate<T: Eq + Hash + Clone, E>(
     iter: impl Iterator<Item = Result<T, E>>,
 ) -> impl Iterator<Item = Result<T, E>> {
@@ -4343,6 +5122,58 @@ enum ComparatorFunction<'a, T> {
     Desc(Rc<dyn Fn(&InternalTuple<T>) -> Option<ExpressionTerm> + 'a>),
 }
 
+fn cmp_tuples<T>(
+    by: &[ComparatorFunction<'_, T>],
+    a: &InternalTuple<T>,
+    b: &InternalTuple<T>,
+) -> Ordering {
+    for comp in by {
+        match comp {
+            ComparatorFunction::Asc(expression) => {
+                match cmp_terms(expression(a).as_ref(), expression(b).as_ref()) {
+                    Ordering::Greater => return Ordering::Greater,
+                    Ordering::Less => return Ordering::Less,
+                    Ordering::Equal => (),
+                }
+            }
+            ComparatorFunction::Desc(expression) => {
+                match cmp_terms(expression(a).as_ref(), expression(b).as_ref()) {
+                    Ordering::Greater => return Ordering::Less,
+                    Ordering::Less => return Ordering::Greater,
+                    Ordering::Equal => (),
+                }
+            }
+        }
+    }
+    Ordering::Equal
+}
+
+/// A solution ordered following the ORDER BY comparators, used in the top-k heap
+struct TopKEntry<'a, T> {
+    tuple: InternalTuple<T>,
+    by: Rc<[ComparatorFunction<'a, T>]>,
+}
+
+impl<T> PartialEq for TopKEntry<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for TopKEntry<'_, T> {}
+
+impl<T> PartialOrd for TopKEntry<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TopKEntry<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_tuples(&self.by, &self.tuple, &other.tuple)
+    }
+}
+
 struct InternalTupleSet<T> {
     key: Vec<usize>,
     map: FxHashMap<u64, Vec<InternalTuple<T>>>,
@@ -4443,6 +5274,13 @@ impl EvalNodeWithStats {
         }
     }
 
+    pub(crate) fn max_exec_count(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| child.max_exec_count())
+            .fold(self.exec_count.get(), usize::max)
+    }
+
     pub fn json_node(
         &self,
         serializer: &mut WriterJsonSerializer<impl io::Write>,
@@ -4606,6 +5444,23 @@ fn eval_node_label(node: &GraphPattern) -> String {
                 format!("Service({name})")
             }
         }
+        GraphPattern::Slice {
+            inner,
+            start,
+            length: Some(length),
+        } if matches!(**inner, GraphPattern::OrderBy { .. }) => {
+            let GraphPattern::OrderBy { expression, .. } = &**inner else {
+                unreachable!()
+            };
+            format!(
+                "TopK(start = {start}, length = {length}, {})",
+                format_list(
+                    expression
+                        .iter()
+                        .map(spargebra::algebra::OrderExpression::from)
+                )
+            )
+        }
         GraphPattern::Slice { start, length, .. } => {
             if let Some(length) = length {
                 format!("Slice(start = {start}, length = {length})")
@@ -57,6 +57,7 @@ pub trait ExpressionEvaluatorContext<'a> {
     fn now(&mut self) -> DateTime;
     fn base_iri(&mut self) -> Option<Arc<Iri<String>>>;
     fn custom_functions(&mut self) -> &CustomFunctionRegistry;
+    fn build_blank_node_generator(&mut self) -> impl Fn() -> BlankNode + 'a;
 }
 
 pub type ExpressionEvaluator<'a, I, O> = Rc<dyn (Fn(&I) -> Option<O>) + 'a>;
@@ -173,7 +174,7 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
             let a = build_expression_evaluator(a, context)?;
             let b = build_expression_evaluator(b, context)?;
             Rc::new(move |tuple| {
-                Some((partial_cmp(&a(tuple)?, &b(tuple)?)? == Ordering::Greater).into())
+                Some((partial_cmp(&a(tuple)?, &b(tuple)?)? == Some(Ordering::Greater)).into())
             })
         }
         Expression::GreaterOrEqual(a, b) => {
@@ -181,10 +182,10 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
             let b = build_expression_evaluator(b, context)?;
             Rc::new(move |tuple| {
                 Some(
-                    match partial_cmp(&a(tuple)?, &b(tuple)?)? {
-                        Ordering::Greater | Ordering::Equal => true,
-                        Ordering::Less => false,
-                    }
+                    matches!(
+                        partial_cmp(&a(tuple)?, &b(tuple)?)?,
+                        Some(Ordering::Greater | Ordering::Equal)
+                    )
                     .into(),
                 )
             })
@@ -193,7 +194,7 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
             let a = build_expression_evaluator(a, context)?;
             let b = build_expression_evaluator(b, context)?;
             Rc::new(move |tuple| {
-                Some((partial_cmp(&a(tuple)?, &b(tuple)?)? == Ordering::Less).into())
+                Some((partial_cmp(&a(tuple)?, &b(tuple)?)? == Some(Ordering::Less)).into())
             })
         }
         Expression::LessOrEqual(a, b) => {
@@ -201,10 +202,10 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
             let b = build_expression_evaluator(b, context)?;
             Rc::new(move |tuple| {
                 Some(
-                    match partial_cmp(&a(tuple)?, &b(tuple)?)? {
-                        Ordering::Less | Ordering::Equal => true,
-                        Ordering::Greater => false,
-                    }
+                    matches!(
+                        partial_cmp(&a(tuple)?, &b(tuple)?)?,
+                        Some(Ordering::Less | Ordering::Equal)
+                    )
                     .into(),
                 )
             })
@@ -577,6 +578,8 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
                         }
                         #[cfg(feature = "sep-0002")]
                         ExpressionTerm::DayTimeDurationLiteral(_) => xsd::DAY_TIME_DURATION.into(),
+                        ExpressionTerm::HexBinaryLiteral(_) => xsd::HEX_BINARY.into(),
+                        ExpressionTerm::Base64BinaryLiteral(_) => xsd::BASE_64_BINARY.into(),
                         ExpressionTerm::OtherTypedLiteral { datatype, .. } => datatype,
                         ExpressionTerm::NamedNode(_) | ExpressionTerm::BlankNode(_) => {
                             return None;
@@ -603,8 +606,8 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
                     }))
                 })
             }
-            Function::BNode => match parameters.first() {
-                Some(id) => {
+            Function::BNode => {
+                if let Some(id) = parameters.first() {
                     let id = build_expression_evaluator(id, context)?;
                     Rc::new(move |tuple| {
                         let ExpressionTerm::StringLiteral(id) = id(tuple)? else {
@@ -612,9 +615,11 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
                         };
                         Some(ExpressionTerm::BlankNode(BlankNode::new(id).ok()?))
                     })
+                } else {
+                    let generate = context.build_blank_node_generator();
+                    Rc::new(move |_| Some(ExpressionTerm::BlankNode(generate())))
                 }
-                None => Rc::new(|_| Some(ExpressionTerm::BlankNode(BlankNode::default()))),
-            },
+            }
             Function::Rand => {
                 Rc::new(|_| Some(ExpressionTerm::DoubleLiteral(random::<f64>().into())))
             }
@@ -1589,6 +1594,28 @@ pub fn build_expression_evaluator<'a, C: ExpressionEvaluatorContext<'a>>(
                             }))
                         )
                     }
+                    xsd::HEX_BINARY => {
+                        cast_fn!(|t: ExpressionTerm| Some(ExpressionTerm::HexBinaryLiteral(
+                            match t {
+                                ExpressionTerm::HexBinaryLiteral(value) => value,
+                                ExpressionTerm::Base64BinaryLiteral(value) => value.into(),
+                                ExpressionTerm::StringLiteral(value) => value.parse().ok()?,
+                                _ => return None,
+                            }
+                        )))
+                    }
+                    xsd::BASE_64_BINARY => {
+                        cast_fn!(
+                            |t: ExpressionTerm| Some(ExpressionTerm::Base64BinaryLiteral(
+                                match t {
+                                    ExpressionTerm::Base64BinaryLiteral(value) => value,
+                                    ExpressionTerm::HexBinaryLiteral(value) => value.into(),
+                                    ExpressionTerm::StringLiteral(value) => value.parse().ok()?,
+                                    _ => return None,
+                                }
+                            ))
+                        )
+                    }
                     _ => {
                         return Err(ExpressionEvaluationError::UnsupportedCustomFunction(
                             function_name.clone(),
@@ -1935,6 +1962,16 @@ fn equals(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<bool> {
             ExpressionTerm::OtherTypedLiteral { .. } => None,
             _ => Some(false),
         },
+        ExpressionTerm::HexBinaryLiteral(a) => match b {
+            ExpressionTerm::HexBinaryLiteral(b) => Some(a == b),
+            ExpressionTerm::OtherTypedLiteral { .. } => None,
+            _ => Some(false),
+        },
+        ExpressionTerm::Base64BinaryLiteral(a) => match b {
+            ExpressionTerm::Base64BinaryLiteral(b) => Some(a == b),
+            ExpressionTerm::OtherTypedLiteral { .. } => None,
+            _ => Some(false),
+        },
         #[cfg(feature = "sparql-12")]
         ExpressionTerm::Triple(a) => {
             if let ExpressionTerm::Triple(b) = b {
@@ -1952,9 +1989,17 @@ fn triple_equals(a: &ExpressionTriple, b: &ExpressionTriple) -> Option<bool> {
 }
 
 /// Comparison for <, >, <= and >= operators
-fn partial_cmp(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Ordering> {
+///
+/// Returns `Some(None)` if the terms are numbers that are not ordered because one of them is NaN:
+/// all these operators [return false](https://www.w3.org/TR/xpath-functions-31/#func-numeric-less-than) in this case.
+fn partial_cmp(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Option<Ordering>> {
+    if let (Some(a_is_nan), Some(b_is_nan)) = (is_nan(a), is_nan(b)) {
+        if a_is_nan || b_is_nan {
+            return Some(None);
+        }
+    }
     if a == b {
-        return Some(Ordering::Equal);
+        return Some(Some(Ordering::Equal));
     }
     #[cfg(feature = "sparql-12")]
     if let ExpressionTerm::Triple(a) = a {
@@ -1964,7 +2009,17 @@ fn partial_cmp(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Ordering> {
             None
         };
     }
-    partial_cmp_literals(a, b)
+    partial_cmp_literals(a, b).map(Some)
+}
+
+/// Returns if the term is a NaN number or `None` if the term is not a number
+pub fn is_nan(term: &ExpressionTerm) -> Option<bool> {
+    match term {
+        ExpressionTerm::FloatLiteral(value) => Some(value.is_nan()),
+        ExpressionTerm::DoubleLiteral(value) => Some(value.is_nan()),
+        ExpressionTerm::IntegerLiteral(_) | ExpressionTerm::DecimalLiteral(_) => Some(false),
+        _ => None,
+    }
 }
 
 pub fn partial_cmp_literals(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Ordering> {
@@ -2128,7 +2183,7 @@ pub fn partial_cmp_literals(a: &ExpressionTerm, b: &ExpressionTerm) -> Option<Or
 }
 
 #[cfg(feature = "sparql-12")]
-fn partial_cmp_triples(a: &ExpressionTriple, b: &ExpressionTriple) -> Option<Ordering> {
+fn partial_cmp_triples(a: &ExpressionTriple, b: &ExpressionTriple) -> Option<Option<Ordering>> {
     // We compare subjects
     match (&a.subject, &b.subject) {
         (NamedOrBlankNode::NamedNode(a), NamedOrBlankNode::NamedNode(b)) => {
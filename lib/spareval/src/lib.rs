@@ -5,6 +5,7 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/oxigraph/oxigraph/main/logo.svg")]
 
 mod dataset;
+mod describe;
 mod error;
 mod eval;
 mod expression;
@@ -15,24 +16,29 @@ mod update;
 #[cfg(feature = "sparql-12")]
 pub use crate::dataset::ExpressionTriple;
 pub use crate::dataset::{ExpressionTerm, InternalQuad, QueryableDataset};
-pub use crate::error::QueryEvaluationError;
+pub use crate::describe::{CustomDescribeHandler, DescribeDataset, DescribeStrategy};
+pub use crate::error::{QueryEvaluationError, QueryResultsSerializationError};
 pub use crate::eval::CancellationToken;
 use crate::eval::{EvalNodeWithStats, SimpleEvaluator, Timer};
 use crate::expression::{
     CustomFunctionRegistry, ExpressionEvaluatorContext, build_expression_evaluator,
 };
-pub use crate::model::{QueryResults, QuerySolution, QuerySolutionIter, QueryTripleIter};
+pub use crate::model::{
+    QueryQuadIter, QueryResults, QuerySolution, QuerySolutionIter, QuerySolutionSet,
+    QueryTripleIter,
+};
 use crate::service::ServiceHandlerRegistry;
 pub use crate::service::{DefaultServiceHandler, ServiceHandler};
 pub use crate::update::{DeleteInsertIter, DeleteInsertQuad};
 use json_event_parser::{JsonEvent, WriterJsonSerializer};
 use oxiri::Iri;
-use oxrdf::{GraphName, Literal, NamedNode, NamedOrBlankNode, Term, Variable};
+use oxrdf::{BlankNode, GraphName, Literal, NamedNode, NamedOrBlankNode, Term, Variable};
 use oxsdatatypes::{DateTime, DayTimeDuration, Float};
 use spargebra::Query;
 use spargebra::algebra::QueryDataset;
 use spargebra::term::{GroundQuadPattern, QuadPattern};
 use sparopt::Optimizer;
+pub use sparopt::StatisticsProvider;
 use sparopt::algebra::GraphPattern;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -70,9 +76,12 @@ pub struct QueryEvaluator {
     service_handler: ServiceHandlerRegistry,
     custom_functions: CustomFunctionRegistry,
     custom_aggregate_functions: CustomAggregateFunctionRegistry,
+    describe_strategy: DescribeStrategy,
     without_optimizations: bool,
+    statistics: Option<Arc<dyn StatisticsProvider + Send + Sync>>,
     run_stats: bool,
     cancellation_token: Option<CancellationToken>,
+    blank_node_id_seed: Option<u128>,
 }
 
 impl QueryEvaluator {
@@ -322,6 +331,33 @@ impl QueryEvaluator {
         self
     }
 
+    /// Sets the way `DESCRIBE` queries build the descriptions of the resources.
+    ///
+    /// By default, the [Concise Bounded Description](DescribeStrategy::ConciseBoundedDescription) is used.
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+    /// use spareval::{DescribeStrategy, QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let s = NamedNode::new("http://example.com/s")?;
+    /// let p = NamedNode::new("http://example.com/p")?;
+    /// let o = NamedNode::new("http://example.com/o")?;
+    /// let dataset = Dataset::from_iter([Quad::new(s, p, o, GraphName::DefaultGraph)]);
+    /// let query = SparqlParser::new().parse_query("DESCRIBE <http://example.com/o>")?;
+    /// let evaluator = QueryEvaluator::new().with_describe_strategy(DescribeStrategy::SymmetricCbd);
+    /// if let QueryResults::Graph(triples) = evaluator.prepare(&query).execute(&dataset)? {
+    ///     assert_eq!(triples.count(), 1); // The inbound triple is returned
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_describe_strategy(mut self, strategy: DescribeStrategy) -> Self {
+        self.describe_strategy = strategy;
+        self
+    }
+
     /// Disables query optimizations and runs the query as it is.
     #[inline]
     #[must_use]
@@ -330,6 +366,66 @@ impl QueryEvaluator {
         self
     }
 
+    /// Sets statistics about the queried dataset used by the optimizer to order joins.
+    ///
+    /// Without statistics, the optimizer relies on rough heuristics based on which terms of the patterns are bound.
+    /// The statistics are ignored if optimizations are disabled with [`without_optimizations`](Self::without_optimizations).
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphName, NamedNode, NamedNodeRef, Quad};
+    /// use spareval::{QueryEvaluator, QueryResults, StatisticsProvider};
+    /// use spargebra::SparqlParser;
+    ///
+    /// struct DatasetStatistics {
+    ///     triple_count: usize,
+    /// }
+    ///
+    /// impl StatisticsProvider for DatasetStatistics {
+    ///     fn triple_count(&self) -> usize {
+    ///         self.triple_count
+    ///     }
+    ///
+    ///     fn predicate_triple_count(&self, _predicate: NamedNodeRef<'_>) -> Option<usize> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// let dataset = Dataset::from_iter([Quad::new(
+    ///     ex.clone(),
+    ///     ex.clone(),
+    ///     ex.clone(),
+    ///     GraphName::DefaultGraph,
+    /// )]);
+    /// let query = SparqlParser::new().parse_query("SELECT * WHERE { ?s ?p ?o . ?o ?p ?s }")?;
+    /// let evaluator = QueryEvaluator::new().with_statistics(DatasetStatistics {
+    ///     triple_count: dataset.len(),
+    /// });
+    /// if let QueryResults::Solutions(solutions) = evaluator.prepare(&query).execute(&dataset)? {
+    ///     assert_eq!(solutions.count(), 1);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_statistics(
+        mut self,
+        statistics: impl StatisticsProvider + Send + Sync + 'static,
+    ) -> Self {
+        self.statistics = Some(Arc::new(statistics));
+        self
+    }
+
+    fn optimize_graph_pattern(&self, pattern: GraphPattern) -> GraphPattern {
+        if self.without_optimizations {
+            pattern
+        } else if let Some(statistics) = &self.statistics {
+            Optimizer::optimize_graph_pattern_with_statistics(pattern, statistics.as_ref())
+        } else {
+            Optimizer::optimize_graph_pattern(pattern)
+        }
+    }
+
     /// Compute statistics during evaluation and fills them in the explanation tree.
     #[inline]
     #[must_use]
@@ -373,6 +469,42 @@ impl QueryEvaluator {
         self
     }
 
+    /// Derives the ids of the blank nodes minted by the evaluation from the given seed and a counter.
+    ///
+    /// It applies to the blank nodes created by `CONSTRUCT` templates and the `BNODE()` function.
+    /// Evaluating the same query twice on the same data with the same seed returns the same blank nodes,
+    /// which is useful to build reproducible pipelines.
+    ///
+    /// <div class="warning">
+    ///
+    /// This sacrifices the global uniqueness of the blank node ids: two evaluations with the same seed will return the same ids for different blank nodes.
+    /// It must not be used if the outputs of multiple runs are merged together.</div>
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+    /// use spareval::{QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// let dataset = Dataset::from_iter([Quad::new(ex.clone(), ex.clone(), ex, GraphName::DefaultGraph)]);
+    /// let query = SparqlParser::new().parse_query("CONSTRUCT { [] ?p ?o } WHERE { ?s ?p ?o }")?;
+    /// let evaluator = QueryEvaluator::new().with_deterministic_blank_node_ids(42);
+    /// let run = || -> Result<_, Box<dyn std::error::Error>> {
+    ///     let QueryResults::Graph(triples) = evaluator.prepare(&query).execute(&dataset)? else {
+    ///         unreachable!()
+    ///     };
+    ///     Ok(triples.collect::<Result<Vec<_>, _>>()?)
+    /// };
+    /// assert_eq!(run()?, run()?);
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_deterministic_blank_node_ids(mut self, seed: u128) -> Self {
+        self.blank_node_id_seed = Some(seed);
+        self
+    }
+
     // Internal helper: evaluates a SPARQL expression to an ExpressionTerm against an empty dataset
     fn eval_expression_term_with_substitutions<'a>(
         &self,
@@ -454,6 +586,10 @@ impl QueryEvaluator {
             fn custom_functions(&mut self) -> &CustomFunctionRegistry {
                 self.custom_functions
             }
+
+            fn build_blank_node_generator(&mut self) -> impl Fn() -> BlankNode + 'a {
+                BlankNode::default
+            }
         }
 
         build_expression_evaluator(
@@ -574,6 +710,7 @@ impl QueryEvaluator {
             Rc::new(self.custom_aggregate_functions.clone()),
             self.cancellation_token.clone().unwrap_or_default(),
             dataset_spec,
+            self.blank_node_id_seed,
             self.run_stats,
         )
     }
@@ -676,10 +813,9 @@ impl PreparedQuery<'_> {
             Query::Select {
                 pattern, base_iri, ..
             } => {
-                let mut pattern = GraphPattern::from(pattern);
-                if !self.evaluator.without_optimizations {
-                    pattern = Optimizer::optimize_graph_pattern(pattern);
-                }
+                let pattern = self
+                    .evaluator
+                    .optimize_graph_pattern(GraphPattern::from(pattern));
                 let planning_duration = start_planning.elapsed();
                 let (results, explanation) =
                     match self
@@ -698,10 +834,9 @@ impl PreparedQuery<'_> {
             Query::Ask {
                 pattern, base_iri, ..
             } => {
-                let mut pattern = GraphPattern::from(pattern);
-                if !self.evaluator.without_optimizations {
-                    pattern = Optimizer::optimize_graph_pattern(pattern);
-                }
+                let pattern = self
+                    .evaluator
+                    .optimize_graph_pattern(GraphPattern::from(pattern));
                 let planning_duration = start_planning.elapsed();
                 let (results, explanation) =
                     match self
@@ -719,45 +854,58 @@ impl PreparedQuery<'_> {
             }
             Query::Construct {
                 template,
+                quad_template,
                 pattern,
                 base_iri,
                 ..
             } => {
-                let mut pattern = GraphPattern::from(pattern);
-                if !self.evaluator.without_optimizations {
-                    pattern = Optimizer::optimize_graph_pattern(pattern);
-                }
+                let pattern = self
+                    .evaluator
+                    .optimize_graph_pattern(GraphPattern::from(pattern));
                 let planning_duration = start_planning.elapsed();
                 let (results, explanation) =
                     match self
                         .evaluator
                         .simple_evaluator(dataset, self.dataset, base_iri)
                     {
+                        Ok(evaluator) if quad_template.is_empty() => {
+                            let (results, explanation) = evaluator.evaluate_construct(
+                                &pattern,
+                                template,
+                                self.substitutions,
+                            );
+                            (results.map(QueryResults::Graph), explanation)
+                        }
                         Ok(evaluator) => {
-                            evaluator.evaluate_construct(&pattern, template, self.substitutions)
+                            let (results, explanation) = evaluator.evaluate_construct_quads(
+                                &pattern,
+                                template,
+                                quad_template,
+                                self.substitutions,
+                            );
+                            (results.map(QueryResults::Dataset), explanation)
                         }
                         Err(e) => (Err(e), Rc::new(EvalNodeWithStats::empty())),
                     };
-                (
-                    results.map(QueryResults::Graph),
-                    explanation,
-                    planning_duration,
-                )
+                (results, explanation, planning_duration)
             }
             Query::Describe {
                 pattern, base_iri, ..
             } => {
-                let mut pattern = GraphPattern::from(pattern);
-                if !self.evaluator.without_optimizations {
-                    pattern = Optimizer::optimize_graph_pattern(pattern);
-                }
+                let pattern = self
+                    .evaluator
+                    .optimize_graph_pattern(GraphPattern::from(pattern));
                 let planning_duration = start_planning.elapsed();
                 let (results, explanation) =
                     match self
                         .evaluator
                         .simple_evaluator(dataset, self.dataset, base_iri)
                     {
-                        Ok(evaluator) => evaluator.evaluate_describe(&pattern, self.substitutions),
+                        Ok(evaluator) => evaluator.evaluate_describe(
+                            &pattern,
+                            &self.evaluator.describe_strategy,
+                            self.substitutions,
+                        ),
                         Err(e) => (Err(e), Rc::new(EvalNodeWithStats::empty())),
                     };
                 (
@@ -856,10 +1004,9 @@ impl PreparedDeleteInsertUpdate<'_> {
         self,
         dataset: impl QueryableDataset<'b>,
     ) -> Result<DeleteInsertIter<'b>, QueryEvaluationError> {
-        let mut pattern = GraphPattern::from(self.pattern);
-        if !self.evaluator.without_optimizations {
-            pattern = Optimizer::optimize_graph_pattern(pattern);
-        }
+        let pattern = self
+            .evaluator
+            .optimize_graph_pattern(GraphPattern::from(self.pattern));
         let (solutions, _) = self
             .evaluator
             .simple_evaluator(dataset, self.dataset, &self.base_iri)?
@@ -1058,6 +1205,14 @@ impl QueryExplanation {
         self.inner.json_node(&mut serializer, self.with_stats)?;
         serializer.serialize_event(JsonEvent::EndObject)
     }
+
+    /// The biggest number of results returned by a step of the query plan.
+    ///
+    /// Returns `None` if the statistics have not been computed.
+    /// The results iterator should have been exhausted to get the final value.
+    pub fn max_intermediate_results(&self) -> Option<usize> {
+        self.with_stats.then(|| self.inner.max_exec_count())
+    }
 }
 
 impl fmt::Debug for QueryExplanation {
@@ -1260,4 +1415,372 @@ mod tests {
         let result = evaluator.evaluate_expression(&expr, std::iter::empty());
         assert!(result.is_none());
     }
+
+    #[cfg(feature = "sparql-12")]
+    #[test]
+    fn order_by_directional_language_tagged_strings() {
+        use oxrdf::{BaseDirection, Dataset, GraphName, NamedNode, Quad};
+        use spargebra::SparqlParser;
+
+        let ex = NamedNode::new_unchecked("http://example.com");
+        let dataset =
+            Dataset::from_iter([BaseDirection::Rtl, BaseDirection::Ltr].map(|direction| {
+                Quad::new(
+                    ex.clone(),
+                    ex.clone(),
+                    Literal::new_directional_language_tagged_literal_unchecked(
+                        "a", "en", direction,
+                    ),
+                    GraphName::DefaultGraph,
+                )
+            }));
+        let query = SparqlParser::new()
+            .parse_query("SELECT (LANGDIR(?o) AS ?d) WHERE { ?s ?p ?o } ORDER BY ?o")
+            .unwrap();
+        let QueryResults::Solutions(solutions) = QueryEvaluator::new()
+            .prepare(&query)
+            .execute(&dataset)
+            .unwrap()
+        else {
+            unreachable!()
+        };
+        let directions = solutions
+            .map(|s| s.unwrap()["d"].to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(directions, ["\"ltr\"", "\"rtl\""]);
+    }
+
+    #[test]
+    fn distinct_and_ordered_aggregates_do_not_depend_on_join_algorithm() {
+        use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+        use spargebra::SparqlParser;
+
+        let ex = |name: &str| NamedNode::new_unchecked(format!("http://example.com/{name}"));
+        let mut dataset = Dataset::new();
+        for (o, v, n) in [("o1", "b", 1), ("o2", "a", 2), ("o3", "b", 1)] {
+            dataset.insert(&Quad::new(ex("s"), ex("p"), ex(o), GraphName::DefaultGraph));
+            dataset.insert(&Quad::new(
+                ex(o),
+                ex("v"),
+                Literal::from(v),
+                GraphName::DefaultGraph,
+            ));
+            dataset.insert(&Quad::new(
+                ex(o),
+                ex("n"),
+                Literal::from(n),
+                GraphName::DefaultGraph,
+            ));
+        }
+        let query = SparqlParser::new()
+            .parse_query(
+                "PREFIX ex: <http://example.com/>
+                SELECT
+                    (GROUP_CONCAT(DISTINCT ?v ORDER BY ?v; SEPARATOR = \", \") AS ?distinct_concat)
+                    (GROUP_CONCAT(?v ORDER BY DESC(?v) ?n; SEPARATOR = \"\") AS ?concat)
+                    (SUM(DISTINCT ?n) AS ?sum)
+                    (AVG(DISTINCT ?n) AS ?avg)
+                    (COUNT(DISTINCT ?v) AS ?count)
+                WHERE { ?s ex:p ?o . ?o ex:v ?v . ?o ex:n ?n }
+                GROUP BY ?s",
+            )
+            .unwrap();
+        // Without optimizations hash joins are used, the optimizer picks for loop joins if sep-0006 is enabled
+        for evaluator in [
+            QueryEvaluator::new(),
+            QueryEvaluator::new().without_optimizations(),
+        ] {
+            let QueryResults::Solutions(solutions) =
+                evaluator.prepare(&query).execute(&dataset).unwrap()
+            else {
+                unreachable!()
+            };
+            let solutions = solutions.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(solutions.len(), 1);
+            assert_eq!(
+                solutions[0]["distinct_concat"],
+                Literal::from("a, b").into()
+            );
+            assert_eq!(solutions[0]["concat"], Literal::from("bba").into());
+            assert_eq!(solutions[0]["sum"], Literal::from(3).into());
+            assert_eq!(
+                solutions[0]["avg"],
+                Literal::new_typed_literal("1.5", xsd::DECIMAL).into()
+            );
+            assert_eq!(solutions[0]["count"], Literal::from(2).into());
+        }
+    }
+
+    #[test]
+    fn distinct_aggregates_compare_terms() {
+        use oxrdf::Dataset;
+        use spargebra::SparqlParser;
+
+        let query = SparqlParser::new()
+            .parse_query(
+                "SELECT (SUM(DISTINCT ?x) AS ?sum) (COUNT(DISTINCT ?x) AS ?count)
+                WHERE { VALUES ?x { 1 \"01\"^^<http://www.w3.org/2001/XMLSchema#integer> 1 } }",
+            )
+            .unwrap();
+        let dataset = Dataset::new();
+        let QueryResults::Solutions(solutions) = QueryEvaluator::new()
+            .prepare(&query)
+            .execute(&dataset)
+            .unwrap()
+        else {
+            unreachable!()
+        };
+        let solutions = solutions.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(solutions[0]["sum"], Literal::from(2).into());
+        assert_eq!(solutions[0]["count"], Literal::from(2).into());
+    }
+
+    #[test]
+    fn property_paths_in_graph_are_evaluated_per_graph() {
+        use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+        use spargebra::SparqlParser;
+
+        let ex = |name: &str| NamedNode::new_unchecked(format!("http://example.com/{name}"));
+        let dataset = Dataset::from_iter([
+            // The a -> b -> c -> d path only exists across the graphs
+            Quad::new(ex("a"), ex("p"), ex("b"), ex("g1")),
+            Quad::new(ex("b"), ex("p"), ex("c"), ex("g2")),
+            Quad::new(ex("c"), ex("p"), ex("d"), GraphName::DefaultGraph),
+            // Two x -> z paths in the same graph
+            Quad::new(ex("x"), ex("q"), ex("y1"), ex("g3")),
+            Quad::new(ex("x"), ex("q"), ex("y2"), ex("g3")),
+            Quad::new(ex("y1"), ex("q"), ex("z"), ex("g3")),
+            Quad::new(ex("y2"), ex("q"), ex("z"), ex("g3")),
+        ]);
+        for (query, expected) in [
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p+ ?o } }",
+                &[["a", "b", "g1"], ["b", "c", "g2"]][..],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ^ex:p+ ?o } }",
+                &[["b", "a", "g1"], ["c", "b", "g2"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s (!ex:q)+ ?o } }",
+                &[["a", "b", "g1"], ["b", "c", "g2"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p/ex:p ?o } }",
+                &[],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p+ ?m . ?m ex:p+ ?o } }",
+                &[],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) GRAPH ?g { ?s ex:p+ ?o } }",
+                &[["a", "b", "g1"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:c AS ?o) GRAPH ?g { ?s ex:p+ ?o } }",
+                &[["b", "c", "g2"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) BIND(ex:c AS ?o) GRAPH ?g { ?s ex:p+ ?o } }",
+                &[],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:a AS ?s) ?s ex:p+ ?o }",
+                &[["a", "b", ""], ["a", "c", ""]],
+            ),
+            // Each graph is returned once even if there are multiple paths
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:x AS ?s) BIND(ex:z AS ?o) GRAPH ?g { ?s ex:q+ ?o } }",
+                &[["x", "z", "g3"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:x AS ?s) BIND(ex:z AS ?o) GRAPH ?g { ?s ex:q* ?o } }",
+                &[["x", "z", "g3"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:z AS ?s) BIND(ex:x AS ?o) GRAPH ?g { ?s ^ex:q+ ?o } }",
+                &[["z", "x", "g3"]],
+            ),
+            // Zero-length paths only bind the graphs containing the node
+            (
+                "SELECT ?s ?o ?g WHERE { GRAPH ?g { ?s ex:p* ?o } }",
+                &[
+                    ["a", "a", "g1"],
+                    ["a", "b", "g1"],
+                    ["b", "b", "g1"],
+                    ["b", "b", "g2"],
+                    ["b", "c", "g2"],
+                    ["c", "c", "g2"],
+                    ["x", "x", "g3"],
+                    ["y1", "y1", "g3"],
+                    ["y2", "y2", "g3"],
+                    ["z", "z", "g3"],
+                ],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:b AS ?s) BIND(ex:b AS ?o) GRAPH ?g { ?s ex:p? ?o } }",
+                &[["b", "b", "g1"], ["b", "b", "g2"]],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { BIND(ex:d AS ?s) GRAPH ?g { ?s ex:p* ?o } }",
+                &[],
+            ),
+            (
+                "SELECT ?s ?o ?g WHERE { VALUES ?g { ex:g1 ex:g2 } BIND(ex:c AS ?s) GRAPH ?g { ?s ex:p* ?o } }",
+                &[["c", "c", "g2"]],
+            ),
+        ] {
+            let query = SparqlParser::new()
+                .with_prefix("ex", "http://example.com/")
+                .unwrap()
+                .parse_query(query)
+                .unwrap();
+            for evaluator in [
+                QueryEvaluator::new(),
+                QueryEvaluator::new().without_optimizations(),
+            ] {
+                let mut prepared = evaluator.prepare(&query);
+                prepared.dataset_mut().set_default_graph_as_union();
+                let QueryResults::Solutions(solutions) = prepared.execute(&dataset).unwrap() else {
+                    unreachable!()
+                };
+                let mut solutions = solutions
+                    .map(|s| {
+                        let s = s.unwrap();
+                        ["s", "o", "g"].map(|v| match s.get(v) {
+                            Some(Term::NamedNode(n)) => n
+                                .as_str()
+                                .trim_start_matches("http://example.com/")
+                                .to_owned(),
+                            _ => String::new(),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                solutions.sort();
+                assert_eq!(solutions, expected, "{query}");
+            }
+        }
+    }
+
+    #[test]
+    fn binary_literals_are_compared_by_value() {
+        use oxrdf::Dataset;
+        use spargebra::SparqlParser;
+
+        let dataset = Dataset::new();
+        for (expression, expected) in [
+            (
+                "\"0fb7\"^^xsd:hexBinary = \"0FB7\"^^xsd:hexBinary",
+                Some(Literal::from(true).into()),
+            ),
+            (
+                "\"Zm9v YmFy\"^^xsd:base64Binary = \"Zm9vYmFy\"^^xsd:base64Binary",
+                Some(Literal::from(true).into()),
+            ),
+            (
+                "xsd:hexBinary(\"D7c=\"^^xsd:base64Binary) = \"0FB7\"^^xsd:hexBinary",
+                Some(Literal::from(true).into()),
+            ),
+            (
+                "xsd:base64Binary(\"0fb7\"^^xsd:hexBinary) = \"D7c=\"^^xsd:base64Binary",
+                Some(Literal::from(true).into()),
+            ),
+            (
+                "\"0FB7\"^^xsd:hexBinary = \"D7c=\"^^xsd:base64Binary",
+                Some(Literal::from(false).into()),
+            ),
+            (
+                "sameTerm(\"0fb7\"^^xsd:hexBinary, \"0FB7\"^^xsd:hexBinary)",
+                Some(Literal::from(false).into()),
+            ),
+            (
+                "STR(\"0fb7\"^^xsd:hexBinary)",
+                Some(Literal::from("0FB7").into()),
+            ),
+            (
+                "STR(xsd:base64Binary(xsd:hexBinary(\"0fb7\")))",
+                Some(Literal::from("D7c=").into()),
+            ),
+            (
+                "xsd:string(xsd:hexBinary(\"D7c=\"^^xsd:base64Binary))",
+                Some(Literal::from("0FB7").into()),
+            ),
+            (
+                "DATATYPE(\"D7c=\"^^xsd:base64Binary)",
+                Some(xsd::BASE_64_BINARY.into_owned().into()),
+            ),
+            // Ill-formed lexical forms are errors
+            ("xsd:hexBinary(\"0fb\")", None),
+            ("xsd:base64Binary(\"D7c\")", None),
+            ("\"0g\"^^xsd:hexBinary = \"0G\"^^xsd:hexBinary", None),
+            ("xsd:hexBinary(\"0g\"^^xsd:hexBinary)", None),
+        ] {
+            let query = SparqlParser::new()
+                .with_prefix("xsd", "http://www.w3.org/2001/XMLSchema#")
+                .unwrap()
+                .parse_query(&format!("SELECT ?v WHERE {{ BIND({expression} AS ?v) }}"))
+                .unwrap();
+            let QueryResults::Solutions(mut solutions) = QueryEvaluator::new()
+                .prepare(&query)
+                .execute(&dataset)
+                .unwrap()
+            else {
+                unreachable!()
+            };
+            let value = solutions.next().unwrap().unwrap().get("v").cloned();
+            assert_eq!(value, expected, "{expression}");
+        }
+    }
+
+    #[test]
+    fn order_by_with_limit_keeps_top_solutions() {
+        use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+        use spargebra::SparqlParser;
+
+        let ex = NamedNode::new_unchecked("http://example.com/");
+        let dataset = (0..100)
+            .map(|i| {
+                Quad::new(
+                    ex.clone(),
+                    ex.clone(),
+                    Literal::from(i),
+                    GraphName::DefaultGraph,
+                )
+            })
+            .collect::<Dataset>();
+        for (query, expected) in [
+            (
+                "SELECT ?v WHERE { ?s ?p ?v } ORDER BY DESC(?v) LIMIT 3 OFFSET 2",
+                [97, 96, 95].as_slice(),
+            ),
+            ("SELECT ?v WHERE { ?s ?p ?v } ORDER BY ?v LIMIT 2", &[0, 1]),
+            (
+                "SELECT ?v WHERE { ?s ?p ?v } ORDER BY ?v LIMIT 2 OFFSET 99",
+                &[99],
+            ),
+            ("SELECT ?v WHERE { ?s ?p ?v } ORDER BY ?v LIMIT 0", &[]),
+        ] {
+            let query = SparqlParser::new().parse_query(query).unwrap();
+            let (results, explanation) = QueryEvaluator::new().prepare(&query).explain(&dataset);
+            let QueryResults::Solutions(solutions) = results.unwrap() else {
+                unreachable!()
+            };
+            let values = solutions
+                .map(|s| s.unwrap()["v"].clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                values,
+                expected
+                    .iter()
+                    .map(|i| Term::from(Literal::from(*i)))
+                    .collect::<Vec<_>>(),
+                "{query}"
+            );
+            assert!(
+                format!("{explanation:?}").contains("TopK"),
+                "{explanation:?}"
+            );
+        }
+    }
 }
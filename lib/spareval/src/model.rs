@@ -1,12 +1,21 @@
-use crate::error::QueryEvaluationError;
-use oxrdf::{Term, Triple, Variable};
+use crate::dataset::ExpressionTerm;
+use crate::error::{QueryEvaluationError, QueryResultsSerializationError};
+use crate::eval::cmp_terms;
+use oxrdf::{Quad, Term, Triple, Variable};
+use oxrdfio::RdfSerializer;
 pub use sparesults::QuerySolution;
+#[cfg(feature = "arrow")]
+use sparesults::arrow::ArrowRecordBatchIter;
+use sparesults::solution::VariableSolutionIndex;
 use sparesults::{
-    ReaderQueryResultsParserOutput, ReaderSolutionsParser, SliceQueryResultsParserOutput,
-    SliceSolutionsParser,
+    QueryResultsSerializer, ReaderQueryResultsParserOutput, ReaderSolutionsParser,
+    SliceQueryResultsParserOutput, SliceSolutionsParser,
 };
-use std::io::Read;
+use std::io::{self, Read, Write};
+use std::ops::Index;
+use std::slice;
 use std::sync::Arc;
+use std::vec;
 
 /// Results of a [SPARQL query](https://www.w3.org/TR/sparql11-query/).
 pub enum QueryResults<'a> {
@@ -16,6 +25,133 @@ pub enum QueryResults<'a> {
     Boolean(bool),
     /// Results of a [CONSTRUCT](https://www.w3.org/TR/sparql11-query/#construct) or [DESCRIBE](https://www.w3.org/TR/sparql11-query/#describe) query.
     Graph(QueryTripleIter<'a>),
+    /// Results of a [CONSTRUCT](https://www.w3.org/TR/sparql11-query/#construct) query with `GRAPH` blocks in its template.
+    ///
+    /// This is an Oxigraph extension enabled by [`SparqlParser::with_construct_quads`](spargebra::SparqlParser::with_construct_quads).
+    Dataset(QueryQuadIter<'a>),
+}
+
+impl QueryResults<'_> {
+    /// Loads all the results in memory to make them independent of the evaluated dataset.
+    ///
+    /// Fails on the first evaluation error.
+    ///
+    /// ```
+    /// use oxrdf::Dataset;
+    /// use spareval::{QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let query = SparqlParser::new().parse_query("SELECT ?v WHERE { VALUES ?v { 1 2 } }")?;
+    /// let results = {
+    ///     let dataset = Dataset::new();
+    ///     QueryEvaluator::new().prepare(&query).execute(&dataset)?.into_owned()?
+    /// };
+    /// if let QueryResults::Solutions(solutions) = results {
+    ///     assert_eq!(solutions.count(), 2);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn into_owned(self) -> Result<QueryResults<'static>, QueryEvaluationError> {
+        Ok(match self {
+            Self::Solutions(solutions) => {
+                QueryResults::Solutions(solutions.collect_owned()?.into())
+            }
+            Self::Boolean(value) => QueryResults::Boolean(value),
+            Self::Graph(triples) => QueryResults::Graph(QueryTripleIter::new(
+                triples.collect::<Result<Vec<_>, _>>()?.into_iter().map(Ok),
+            )),
+            Self::Dataset(quads) => QueryResults::Dataset(QueryQuadIter::new(
+                quads.collect::<Result<Vec<_>, _>>()?.into_iter().map(Ok),
+            )),
+        })
+    }
+
+    /// Writes the results of a `SELECT` or `ASK` query using the given [`QueryResultsSerializer`] or [`QueryResultsFormat`](sparesults::QueryResultsFormat).
+    ///
+    /// The solutions are streamed to the writer.
+    /// Fails with [`QueryResultsSerializationError::GraphResultsExpectRdfFormat`] on `CONSTRUCT` and `DESCRIBE` results, use [`write_graph`](Self::write_graph) for them.
+    ///
+    /// ```
+    /// use oxrdf::Dataset;
+    /// use sparesults::QueryResultsFormat;
+    /// use spareval::QueryEvaluator;
+    /// use spargebra::SparqlParser;
+    ///
+    /// let dataset = Dataset::new();
+    /// let query = SparqlParser::new().parse_query("SELECT ?v WHERE { VALUES ?v { 1 } }")?;
+    /// let results = QueryEvaluator::new().prepare(&query).execute(&dataset)?;
+    /// assert_eq!(results.write(Vec::new(), QueryResultsFormat::Tsv)?, b"?v\n1\n");
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn write<W: Write>(
+        self,
+        writer: W,
+        format: impl Into<QueryResultsSerializer>,
+    ) -> Result<W, QueryResultsSerializationError> {
+        let serializer = format.into();
+        match self {
+            Self::Solutions(solutions) => {
+                let mut serializer = serializer
+                    .serialize_solutions_to_writer(writer, solutions.variables().to_vec())?;
+                for solution in solutions {
+                    serializer.serialize(&solution?)?;
+                }
+                Ok(serializer.finish()?)
+            }
+            Self::Boolean(value) => Ok(serializer.serialize_boolean_to_writer(writer, value)?),
+            Self::Graph(_) | Self::Dataset(_) => {
+                Err(QueryResultsSerializationError::GraphResultsExpectRdfFormat)
+            }
+        }
+    }
+
+    /// Writes the results of a `CONSTRUCT` or `DESCRIBE` query using the given [`RdfSerializer`] or [`RdfFormat`](oxrdfio::RdfFormat).
+    ///
+    /// The triples are streamed to the writer.
+    /// [`Dataset`](Self::Dataset) results require a format supporting datasets like [N-Quads](oxrdfio::RdfFormat::NQuads).
+    /// Fails with [`QueryResultsSerializationError::SolutionsOrBooleanExpectQueryResultsFormat`] on `SELECT` and `ASK` results, use [`write`](Self::write) for them.
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+    /// use oxrdfio::RdfFormat;
+    /// use spareval::QueryEvaluator;
+    /// use spargebra::SparqlParser;
+    ///
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// let dataset = Dataset::from_iter([Quad::new(ex.clone(), ex.clone(), ex, GraphName::DefaultGraph)]);
+    /// let query = SparqlParser::new().parse_query("CONSTRUCT WHERE { ?s ?p ?o }")?;
+    /// let results = QueryEvaluator::new().prepare(&query).execute(&dataset)?;
+    /// assert_eq!(
+    ///     results.write_graph(Vec::new(), RdfFormat::NTriples)?,
+    ///     b"<http://example.com> <http://example.com> <http://example.com> .\n"
+    /// );
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn write_graph<W: Write>(
+        self,
+        writer: W,
+        format: impl Into<RdfSerializer>,
+    ) -> Result<W, QueryResultsSerializationError> {
+        let mut serializer = format.into().for_writer(writer);
+        match self {
+            Self::Graph(triples) => {
+                for triple in triples {
+                    serializer.serialize_triple(&triple?)?;
+                }
+            }
+            Self::Dataset(quads) => {
+                for quad in quads {
+                    serializer.serialize_quad(&quad?)?;
+                }
+            }
+            Self::Solutions(_) | Self::Boolean(_) => {
+                return Err(
+                    QueryResultsSerializationError::SolutionsOrBooleanExpectQueryResultsFormat,
+                );
+            }
+        }
+        Ok(serializer.finish()?)
+    }
 }
 
 impl<'a> From<QuerySolutionIter<'a>> for QueryResults<'a> {
@@ -39,6 +175,13 @@ impl<'a> From<QueryTripleIter<'a>> for QueryResults<'a> {
     }
 }
 
+impl<'a> From<QueryQuadIter<'a>> for QueryResults<'a> {
+    #[inline]
+    fn from(value: QueryQuadIter<'a>) -> Self {
+        Self::Dataset(value)
+    }
+}
+
 impl<'a, R: Read + 'a> From<ReaderQueryResultsParserOutput<R>> for QueryResults<'a> {
     #[inline]
     fn from(output: ReaderQueryResultsParserOutput<R>) -> Self {
@@ -128,6 +271,57 @@ impl<'a> QuerySolutionIter<'a> {
     pub fn variables(&self) -> &[Variable] {
         &self.variables
     }
+
+    /// Converts the solutions into Apache Arrow record batches of at most `batch_size` rows.
+    ///
+    /// See [`ArrowRecordBatchIter`] for the encoding of the RDF terms.
+    ///
+    /// ```
+    /// use oxrdf::{Dataset, GraphName, NamedNode, Quad};
+    /// use spareval::{QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let ex = NamedNode::new("http://example.com")?;
+    /// let dataset = Dataset::from_iter([Quad::new(ex.clone(), ex.clone(), ex, GraphName::DefaultGraph)]);
+    /// let query = SparqlParser::new().parse_query("SELECT ?s ?o WHERE { ?s ?p ?o }")?;
+    /// if let QueryResults::Solutions(solutions) = QueryEvaluator::new().prepare(&query).execute(&dataset)? {
+    ///     let batches = solutions.into_record_batches(1024).collect::<Result<Vec<_>, _>>()?;
+    ///     assert_eq!(batches.len(), 1);
+    ///     assert_eq!(batches[0].num_rows(), 1);
+    ///     assert_eq!(batches[0].num_columns(), 2);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[cfg(feature = "arrow")]
+    pub fn into_record_batches(self, batch_size: usize) -> ArrowRecordBatchIter<Self> {
+        let variables = Arc::clone(&self.variables);
+        ArrowRecordBatchIter::new(&variables, self, batch_size)
+    }
+
+    /// Loads all the solutions in memory.
+    ///
+    /// Fails on the first evaluation error.
+    ///
+    /// ```
+    /// use oxrdf::Dataset;
+    /// use spareval::{QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let query = SparqlParser::new().parse_query("SELECT ?v WHERE { VALUES ?v { 1 2 } }")?;
+    /// if let QueryResults::Solutions(solutions) =
+    ///     QueryEvaluator::new().prepare(&query).execute(&Dataset::new())?
+    /// {
+    ///     let solutions = solutions.collect_owned()?;
+    ///     assert_eq!(solutions.len(), 2);
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn collect_owned(self) -> Result<QuerySolutionSet, QueryEvaluationError> {
+        Ok(QuerySolutionSet {
+            variables: Arc::clone(&self.variables),
+            solutions: self.collect::<Result<_, _>>()?,
+        })
+    }
 }
 
 impl Iterator for QuerySolutionIter<'_> {
@@ -144,6 +338,13 @@ impl Iterator for QuerySolutionIter<'_> {
     }
 }
 
+impl From<QuerySolutionSet> for QuerySolutionIter<'static> {
+    #[inline]
+    fn from(solutions: QuerySolutionSet) -> Self {
+        Self::new(solutions.variables, solutions.solutions.into_iter().map(Ok))
+    }
+}
+
 impl<'a, R: Read + 'a> From<ReaderSolutionsParser<R>> for QuerySolutionIter<'a> {
     #[inline]
     fn from(parser: ReaderSolutionsParser<R>) -> Self {
@@ -168,6 +369,173 @@ impl<'a> From<SliceSolutionsParser<'a>> for QuerySolutionIter<'a> {
     }
 }
 
+/// A set of [`QuerySolution`]s loaded in memory.
+///
+/// Unlike [`QuerySolutionIter`], it does not borrow the evaluated dataset and might be cloned and sent to other threads.
+/// It is built using [`QuerySolutionIter::collect_owned`].
+///
+/// ```
+/// use oxrdf::{Dataset, Literal};
+/// use spareval::{QueryEvaluator, QueryResults};
+/// use spargebra::SparqlParser;
+///
+/// let query = SparqlParser::new().parse_query("SELECT ?v WHERE { VALUES ?v { 2 1 } }")?;
+/// if let QueryResults::Solutions(solutions) =
+///     QueryEvaluator::new().prepare(&query).execute(&Dataset::new())?
+/// {
+///     let mut solutions = solutions.collect_owned()?;
+///     solutions.sort_by_variable("v");
+///     assert_eq!(
+///         solutions.column("v").collect::<Vec<_>>(),
+///         [Some(&Literal::from(1).into()), Some(&Literal::from(2).into())]
+///     );
+/// }
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuerySolutionSet {
+    variables: Arc<[Variable]>,
+    solutions: Vec<QuerySolution>,
+}
+
+impl QuerySolutionSet {
+    /// The variables used in the solutions.
+    #[inline]
+    pub fn variables(&self) -> &[Variable] {
+        &self.variables
+    }
+
+    /// The number of solutions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Checks if there is no solution.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    /// Returns the solution at the given position.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&QuerySolution> {
+        self.solutions.get(index)
+    }
+
+    /// Returns an iterator over the solutions.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, QuerySolution> {
+        self.solutions.iter()
+    }
+
+    /// Returns the values of a given variable (or tuple position) in all the solutions.
+    ///
+    /// The iterator is empty if the variable does not exist.
+    pub fn column(
+        &self,
+        variable: impl VariableSolutionIndex,
+    ) -> impl Iterator<Item = Option<&Term>> {
+        self.variable_index(variable).into_iter().flat_map(|index| {
+            self.solutions
+                .iter()
+                .map(move |solution| solution.get(index))
+        })
+    }
+
+    /// Sorts the solutions by the values of the given variable (or tuple position).
+    ///
+    /// The values are compared like in an `ORDER BY` clause and the sort is stable.
+    pub fn sort_by_variable(&mut self, variable: impl VariableSolutionIndex) {
+        let Some(index) = self.variable_index(variable) else {
+            return;
+        };
+        let mut keyed = self
+            .solutions
+            .drain(..)
+            .map(|solution| {
+                (
+                    solution.get(index).cloned().map(ExpressionTerm::from),
+                    solution,
+                )
+            })
+            .collect::<Vec<_>>();
+        keyed.sort_by(|(a, _), (b, _)| cmp_terms(a.as_ref(), b.as_ref()));
+        self.solutions = keyed.into_iter().map(|(_, solution)| solution).collect();
+    }
+
+    /// Writes the solutions using the given [`QueryResultsSerializer`] or [`QueryResultsFormat`](sparesults::QueryResultsFormat).
+    ///
+    /// ```
+    /// use oxrdf::Dataset;
+    /// use sparesults::QueryResultsFormat;
+    /// use spareval::{QueryEvaluator, QueryResults};
+    /// use spargebra::SparqlParser;
+    ///
+    /// let query = SparqlParser::new().parse_query("SELECT ?v WHERE { VALUES ?v { 1 } }")?;
+    /// if let QueryResults::Solutions(solutions) =
+    ///     QueryEvaluator::new().prepare(&query).execute(&Dataset::new())?
+    /// {
+    ///     let solutions = solutions.collect_owned()?;
+    ///     assert_eq!(
+    ///         solutions.serialize_to_writer(QueryResultsFormat::Tsv, Vec::new())?,
+    ///         b"?v\n1\n"
+    ///     );
+    /// }
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    pub fn serialize_to_writer<W: Write>(
+        &self,
+        serializer: impl Into<QueryResultsSerializer>,
+        writer: W,
+    ) -> io::Result<W> {
+        let mut serializer = serializer
+            .into()
+            .serialize_solutions_to_writer(writer, self.variables.to_vec())?;
+        for solution in &self.solutions {
+            serializer.serialize(solution)?;
+        }
+        serializer.finish()
+    }
+
+    fn variable_index(&self, variable: impl VariableSolutionIndex) -> Option<usize> {
+        // We use an unbound solution to resolve the variable position
+        variable.index(&QuerySolution::from((
+            Arc::clone(&self.variables),
+            Vec::new(),
+        )))
+    }
+}
+
+impl Index<usize> for QuerySolutionSet {
+    type Output = QuerySolution;
+
+    #[inline]
+    fn index(&self, index: usize) -> &QuerySolution {
+        &self.solutions[index]
+    }
+}
+
+impl IntoIterator for QuerySolutionSet {
+    type Item = QuerySolution;
+    type IntoIter = vec::IntoIter<QuerySolution>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.solutions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a QuerySolutionSet {
+    type Item = &'a QuerySolution;
+    type IntoIter = slice::Iter<'a, QuerySolution>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.solutions.iter()
+    }
+}
+
 /// An iterator over the triples that compose a graph solution.
 ///
 /// ```
@@ -209,3 +577,48 @@ impl Iterator for QueryTripleIter<'_> {
         self.iter.size_hint()
     }
 }
+
+/// An iterator over the quads built by a `CONSTRUCT` query with `GRAPH` blocks in its template.
+///
+/// ```
+/// use oxrdf::{Dataset, NamedNode, Quad};
+/// use spareval::{QueryEvaluator, QueryResults};
+/// use spargebra::SparqlParser;
+///
+/// let ex = NamedNode::new("http://example.com")?;
+/// let quad = Quad::new(ex.clone(), ex.clone(), ex.clone(), ex);
+/// let dataset = Dataset::from_iter([quad.clone()]);
+/// let query = SparqlParser::new()
+///     .with_construct_quads()
+///     .parse_query("CONSTRUCT { GRAPH ?g { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }")?;
+/// let evaluator = QueryEvaluator::new();
+/// if let QueryResults::Dataset(quads) = evaluator.prepare(&query).execute(&dataset)? {
+///     assert_eq!(quads.collect::<Result<Vec<_>, _>>()?, [quad]);
+/// }
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub struct QueryQuadIter<'a> {
+    iter: Box<dyn Iterator<Item = Result<Quad, QueryEvaluationError>> + 'a>,
+}
+
+impl<'a> QueryQuadIter<'a> {
+    pub fn new(iter: impl Iterator<Item = Result<Quad, QueryEvaluationError>> + 'a) -> Self {
+        Self {
+            iter: Box::new(iter),
+        }
+    }
+}
+
+impl Iterator for QueryQuadIter<'_> {
+    type Item = Result<Quad, QueryEvaluationError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -1,6 +1,7 @@
 //! [SPARQL 1.1 Query Algebra](https://www.w3.org/TR/sparql11-query/#sparqlQuery) representation.
 
 use crate::term::*;
+use crate::transform::GraphPatternTransformer;
 use oxrdf::LiteralRef;
 use std::fmt;
 
@@ -146,6 +147,129 @@ pub enum Expression {
 }
 
 impl Expression {
+    /// Builds a [`||`](https://www.w3.org/TR/sparql11-query/#func-logical-or) expression.
+    #[inline]
+    pub fn or(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Or(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`&&`](https://www.w3.org/TR/sparql11-query/#func-logical-and) expression.
+    #[inline]
+    pub fn and(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::And(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`=`](https://www.w3.org/TR/sparql11-query/#func-RDFterm-equal) expression.
+    #[inline]
+    pub fn equal(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Equal(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`sameTerm`](https://www.w3.org/TR/sparql11-query/#func-sameTerm) expression.
+    #[inline]
+    pub fn same_term(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::SameTerm(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`>`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn greater(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Greater(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`>=`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn greater_or_equal(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::GreaterOrEqual(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`<`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn less(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Less(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`<=`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn less_or_equal(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::LessOrEqual(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`+`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn add(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Add(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`-`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn subtract(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Subtract(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`*`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn multiply(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Multiply(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a [`/`](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn divide(left: impl Into<Self>, right: impl Into<Self>) -> Self {
+        Self::Divide(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds an [`IN`](https://www.w3.org/TR/sparql11-query/#func-in) expression.
+    #[inline]
+    pub fn in_list(expression: impl Into<Self>, list: Vec<Self>) -> Self {
+        Self::In(Box::new(expression.into()), list)
+    }
+
+    /// Builds a [Unary plus](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn unary_plus(inner: impl Into<Self>) -> Self {
+        Self::UnaryPlus(Box::new(inner.into()))
+    }
+
+    /// Builds a [Unary minus](https://www.w3.org/TR/sparql11-query/#OperatorMapping) expression.
+    #[inline]
+    pub fn unary_minus(inner: impl Into<Self>) -> Self {
+        Self::UnaryMinus(Box::new(inner.into()))
+    }
+
+    /// Builds a [`!`](https://www.w3.org/TR/sparql11-query/#func-logical-not) expression.
+    #[inline]
+    pub fn not(inner: impl Into<Self>) -> Self {
+        Self::Not(Box::new(inner.into()))
+    }
+
+    /// Builds an [`EXISTS`](https://www.w3.org/TR/sparql11-query/#func-filter-exists) expression.
+    #[inline]
+    pub fn exists(pattern: GraphPattern) -> Self {
+        Self::Exists(Box::new(pattern))
+    }
+
+    /// Builds an [`IF`](https://www.w3.org/TR/sparql11-query/#func-if) expression.
+    #[inline]
+    pub fn if_cond(
+        condition: impl Into<Self>,
+        then: impl Into<Self>,
+        otherwise: impl Into<Self>,
+    ) -> Self {
+        Self::If(
+            Box::new(condition.into()),
+            Box::new(then.into()),
+            Box::new(otherwise.into()),
+        )
+    }
+
+    /// Rewrites the expression using the given [`GraphPatternTransformer`].
+    #[inline]
+    #[must_use]
+    pub fn transform(self, transformer: &mut (impl GraphPatternTransformer + ?Sized)) -> Self {
+        transformer.transform_expression(self)
+    }
+
     /// Formats using the [SPARQL S-Expression syntax](https://jena.apache.org/documentation/notes/sse.html).
     pub(crate) fn fmt_sse(&self, f: &mut impl fmt::Write) -> fmt::Result {
         match self {
@@ -800,6 +924,185 @@ impl Default for GraphPattern {
 }
 
 impl GraphPattern {
+    /// Builds a [basic graph pattern](https://www.w3.org/TR/sparql11-query/#defn_BasicGraphPattern).
+    #[inline]
+    pub fn bgp(patterns: Vec<TriplePattern>) -> Self {
+        Self::Bgp { patterns }
+    }
+
+    /// Builds a [property path pattern](https://www.w3.org/TR/sparql11-query/#defn_evalPP_predicate).
+    #[inline]
+    pub fn path(
+        subject: impl Into<TermPattern>,
+        path: impl Into<PropertyPathExpression>,
+        object: impl Into<TermPattern>,
+    ) -> Self {
+        Self::Path {
+            subject: subject.into(),
+            path: path.into(),
+            object: object.into(),
+        }
+    }
+
+    /// Builds a [join](https://www.w3.org/TR/sparql11-query/#defn_algJoin).
+    #[inline]
+    pub fn join(left: Self, right: Self) -> Self {
+        Self::Join {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Builds a [left join](https://www.w3.org/TR/sparql11-query/#defn_algLeftJoin).
+    #[inline]
+    pub fn left_join(left: Self, right: Self, expression: Option<Expression>) -> Self {
+        Self::LeftJoin {
+            left: Box::new(left),
+            right: Box::new(right),
+            expression,
+        }
+    }
+
+    /// Builds a lateral join as defined in [SEP-0006](https://github.com/w3c/sparql-12/blob/main/SEP/SEP-0006/sep-0006.md).
+    #[cfg(feature = "sep-0006")]
+    #[inline]
+    pub fn lateral(left: Self, right: Self) -> Self {
+        Self::Lateral {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Builds a [filter](https://www.w3.org/TR/sparql11-query/#defn_algFilter).
+    #[inline]
+    pub fn filter(inner: Self, expression: impl Into<Expression>) -> Self {
+        Self::Filter {
+            expr: expression.into(),
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Builds a [union](https://www.w3.org/TR/sparql11-query/#defn_algUnion).
+    #[inline]
+    pub fn union(left: Self, right: Self) -> Self {
+        Self::Union {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Builds a [`GRAPH`](https://www.w3.org/TR/sparql11-query/#queryDataset) pattern.
+    #[inline]
+    pub fn graph(inner: Self, name: impl Into<NamedNodePattern>) -> Self {
+        Self::Graph {
+            name: name.into(),
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Builds an [extend](https://www.w3.org/TR/sparql11-query/#defn_extend).
+    #[inline]
+    pub fn extend(inner: Self, variable: Variable, expression: impl Into<Expression>) -> Self {
+        Self::Extend {
+            inner: Box::new(inner),
+            variable,
+            expression: expression.into(),
+        }
+    }
+
+    /// Builds a [minus](https://www.w3.org/TR/sparql11-query/#defn_algMinus).
+    #[inline]
+    pub fn minus(left: Self, right: Self) -> Self {
+        Self::Minus {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Builds a table of [values](https://www.w3.org/TR/sparql11-query/#inline-data).
+    #[inline]
+    pub fn values(variables: Vec<Variable>, bindings: Vec<Vec<Option<GroundTerm>>>) -> Self {
+        Self::Values {
+            variables,
+            bindings,
+        }
+    }
+
+    /// Builds an [order by](https://www.w3.org/TR/sparql11-query/#defn_algOrdered).
+    #[inline]
+    pub fn order_by(inner: Self, expression: Vec<OrderExpression>) -> Self {
+        Self::OrderBy {
+            inner: Box::new(inner),
+            expression,
+        }
+    }
+
+    /// Builds a [projection](https://www.w3.org/TR/sparql11-query/#defn_algProjection).
+    #[inline]
+    pub fn project(inner: Self, variables: Vec<Variable>) -> Self {
+        Self::Project {
+            inner: Box::new(inner),
+            variables,
+        }
+    }
+
+    /// Builds a [distinct](https://www.w3.org/TR/sparql11-query/#defn_algDistinct).
+    #[inline]
+    pub fn distinct(inner: Self) -> Self {
+        Self::Distinct {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Builds a [reduced](https://www.w3.org/TR/sparql11-query/#defn_algReduced).
+    #[inline]
+    pub fn reduced(inner: Self) -> Self {
+        Self::Reduced {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Builds a [slice](https://www.w3.org/TR/sparql11-query/#defn_algSlice).
+    #[inline]
+    pub fn slice(inner: Self, start: usize, length: Option<usize>) -> Self {
+        Self::Slice {
+            inner: Box::new(inner),
+            start,
+            length,
+        }
+    }
+
+    /// Builds an [aggregation](https://www.w3.org/TR/sparql11-query/#aggregateAlgebra).
+    #[inline]
+    pub fn group(
+        inner: Self,
+        variables: Vec<Variable>,
+        aggregates: Vec<(Variable, AggregateExpression)>,
+    ) -> Self {
+        Self::Group {
+            inner: Box::new(inner),
+            variables,
+            aggregates,
+        }
+    }
+
+    /// Builds a [service call](https://www.w3.org/TR/sparql11-federated-query/#defn_evalService).
+    #[inline]
+    pub fn service(inner: Self, name: impl Into<NamedNodePattern>, silent: bool) -> Self {
+        Self::Service {
+            name: name.into(),
+            inner: Box::new(inner),
+            silent,
+        }
+    }
+
+    /// Rewrites the graph pattern using the given [`GraphPatternTransformer`].
+    #[inline]
+    #[must_use]
+    pub fn transform(self, transformer: &mut (impl GraphPatternTransformer + ?Sized)) -> Self {
+        transformer.transform_graph_pattern(self)
+    }
+
     /// Formats using the [SPARQL S-Expression syntax](https://jena.apache.org/documentation/notes/sse.html).
     pub(crate) fn fmt_sse(&self, f: &mut impl fmt::Write) -> fmt::Result {
         match self {
@@ -1346,7 +1649,8 @@ impl AggregateExpression {
             Self::FunctionCall {
                 name:
                     AggregateFunction::GroupConcat {
-                        separator: Some(separator),
+                        separator,
+                        order_by,
                     },
                 expr,
                 distinct,
@@ -1356,7 +1660,18 @@ impl AggregateExpression {
                     f.write_str("distinct ")?;
                 }
                 expr.fmt_sse(f)?;
-                write!(f, " {})", LiteralRef::new_simple_literal(separator))
+                if let Some(separator) = separator {
+                    write!(f, " {}", LiteralRef::new_simple_literal(separator))?;
+                }
+                if !order_by.is_empty() {
+                    f.write_str(" (order")?;
+                    for c in order_by {
+                        f.write_str(" ")?;
+                        c.fmt_sse(f)?;
+                    }
+                    f.write_str(")")?;
+                }
+                f.write_str(")")
             }
             Self::FunctionCall {
                 name,
@@ -1376,8 +1691,13 @@ impl AggregateExpression {
     }
 
     fn lookup_used_variables<'a>(&'a self, callback: &mut impl FnMut(&'a Variable)) {
-        if let Self::FunctionCall { expr, .. } = self {
+        if let Self::FunctionCall { name, expr, .. } = self {
             expr.lookup_used_variable(callback);
+            if let AggregateFunction::GroupConcat { order_by, .. } = name {
+                for e in order_by {
+                    e.lookup_used_variables(callback);
+                }
+            }
         }
     }
 }
@@ -1395,26 +1715,31 @@ impl fmt::Display for AggregateExpression {
             Self::FunctionCall {
                 name:
                     AggregateFunction::GroupConcat {
-                        separator: Some(separator),
+                        separator,
+                        order_by,
                     },
                 expr,
                 distinct,
             } => {
+                f.write_str("GROUP_CONCAT(")?;
                 if *distinct {
+                    f.write_str("DISTINCT ")?;
+                }
+                write!(f, "{expr}")?;
+                if !order_by.is_empty() {
+                    f.write_str(" ORDER BY")?;
+                    for c in order_by {
+                        write!(f, " {c}")?;
+                    }
+                }
+                if let Some(separator) = separator {
                     write!(
                         f,
-                        "GROUP_CONCAT(DISTINCT {}; SEPARATOR = {})",
-                        expr,
-                        LiteralRef::new_simple_literal(separator)
-                    )
-                } else {
-                    write!(
-                        f,
-                        "GROUP_CONCAT({}; SEPARATOR = {})",
-                        expr,
+                        "; SEPARATOR = {}",
                         LiteralRef::new_simple_literal(separator)
-                    )
+                    )?;
                 }
+                f.write_str(")")
             }
             Self::FunctionCall {
                 name,
@@ -1447,6 +1772,11 @@ pub enum AggregateFunction {
     /// [GroupConcat](https://www.w3.org/TR/sparql11-query/#defn_aggGroupConcat).
     GroupConcat {
         separator: Option<String>,
+        /// Order in which the values are concatenated, written `GROUP_CONCAT(?x ORDER BY ?x)`.
+        ///
+        /// This is an Oxigraph extension to SPARQL.
+        /// If empty, the values are concatenated in the order the solutions are produced by the query plan.
+        order_by: Vec<OrderExpression>,
     },
     /// [Sample](https://www.w3.org/TR/sparql11-query/#defn_aggSample).
     Sample,
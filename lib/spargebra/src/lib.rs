@@ -8,8 +8,9 @@ pub mod algebra;
 mod parser;
 mod query;
 pub mod term;
+pub mod transform;
 mod update;
 
-pub use parser::{SparqlParser, SparqlSyntaxError};
+pub use parser::{SparqlParser, SparqlSyntaxError, TextPosition};
 pub use query::*;
 pub use update::*;
@@ -15,6 +15,7 @@ use std::borrow::Cow;
 use std::char;
 use std::collections::{HashMap, HashSet};
 use std::mem::take;
+use std::ops::Range;
 #[cfg(feature = "standard-unicode-escaping")]
 use std::str::Chars;
 use std::str::FromStr;
@@ -35,6 +36,7 @@ pub struct SparqlParser {
     base_iri: Option<Iri<String>>,
     prefixes: HashMap<String, String>,
     custom_aggregate_functions: HashSet<NamedNode>,
+    construct_quads: bool,
 }
 
 impl SparqlParser {
@@ -104,6 +106,34 @@ impl SparqlParser {
         self
     }
 
+    /// Allows `GRAPH` blocks in `CONSTRUCT` templates to build quads.
+    ///
+    /// This is an Oxigraph extension to SPARQL.
+    /// The `GRAPH` blocks are returned in the `quad_template` field of [`Query::Construct`].
+    ///
+    /// ```
+    /// use spargebra::{Query, SparqlParser};
+    ///
+    /// let query = SparqlParser::new()
+    ///     .with_construct_quads()
+    ///     .parse_query("CONSTRUCT { GRAPH ?g { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }")?;
+    /// let Query::Construct { quad_template, .. } = query else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(quad_template.len(), 1);
+    ///
+    /// // Not allowed by default
+    /// assert!(SparqlParser::new()
+    ///     .parse_query("CONSTRUCT { GRAPH ?g { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }")
+    ///     .is_err());
+    /// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+    /// ```
+    #[inline]
+    pub fn with_construct_quads(mut self) -> Self {
+        self.construct_quads = true;
+        self
+    }
+
     /// Parse the given query string using the already set options.
     ///
     /// ```
@@ -123,10 +153,12 @@ impl SparqlParser {
             self.base_iri,
             self.prefixes,
             self.custom_aggregate_functions,
+            self.construct_quads,
         );
         #[cfg(feature = "standard-unicode-escaping")]
         let query = unescape_unicode_codepoints(query);
-        Ok(parser::QueryUnit(&query, &mut state).map_err(SparqlSyntaxErrorKind::Syntax)?)
+        parser::QueryUnit(&query, &mut state)
+            .map_err(|e| SparqlSyntaxError::from_syntax_error(&query, e))
     }
 
     /// Parse the given update string using the already set options.
@@ -148,11 +180,12 @@ impl SparqlParser {
             self.base_iri,
             self.prefixes,
             self.custom_aggregate_functions,
+            self.construct_quads,
         );
         #[cfg(feature = "standard-unicode-escaping")]
         let update = unescape_unicode_codepoints(update);
-        let operations =
-            parser::UpdateInit(&update, &mut state).map_err(SparqlSyntaxErrorKind::Syntax)?;
+        let operations = parser::UpdateInit(&update, &mut state)
+            .map_err(|e| SparqlSyntaxError::from_syntax_error(&update, e))?;
         check_if_insert_data_are_sharing_blank_nodes(&operations)?;
         Ok(Update {
             operations,
@@ -173,14 +206,104 @@ impl SparqlSyntaxError {
     pub(crate) fn from_bad_base_iri(e: IriParseError) -> Self {
         SparqlSyntaxErrorKind::InvalidBaseIri(e).into()
     }
+
+    fn from_syntax_error(input: &str, error: peg::error::ParseError<LineCol>) -> Self {
+        let start = TextPosition {
+            line: u64::try_from(error.location.line - 1).unwrap_or(u64::MAX),
+            column: u64::try_from(error.location.column - 1).unwrap_or(u64::MAX),
+            offset: u64::try_from(error.location.offset).unwrap_or(u64::MAX),
+        };
+        // The error range covers the unexpected character if there is one
+        let end = match input
+            .get(error.location.offset..)
+            .and_then(|remaining| remaining.chars().next())
+        {
+            Some('\n') => TextPosition {
+                line: start.line + 1,
+                column: 0,
+                offset: start.offset + 1,
+            },
+            Some(c) => TextPosition {
+                line: start.line,
+                column: start.column + 1,
+                offset: start.offset + u64::try_from(c.len_utf8()).unwrap_or(u64::MAX),
+            },
+            None => start,
+        };
+        SparqlSyntaxErrorKind::Syntax {
+            error,
+            location: start..end,
+        }
+        .into()
+    }
+
+    /// The location of the error inside of the query or update string.
+    ///
+    /// Returns `None` if the error is not related to a specific location, for example if the base IRI is invalid.
+    ///
+    /// If the `standard-unicode-escaping` feature is enabled,
+    /// positions are relative to the string after the decoding of the `\u` and `\U` escape sequences.
+    ///
+    /// ```
+    /// use spargebra::SparqlParser;
+    ///
+    /// let error = SparqlParser::new()
+    ///     .parse_query("SELECT * WHERE {\n  ?s ?p ?o .\n  ?s <http://example.com/p> }")
+    ///     .unwrap_err();
+    /// let location = error.location().unwrap();
+    /// assert_eq!((location.start.line, location.start.column), (2, 28));
+    /// assert_eq!(location.end.offset, location.start.offset + 1);
+    /// ```
+    pub fn location(&self) -> Option<Range<TextPosition>> {
+        match &self.kind {
+            SparqlSyntaxErrorKind::Syntax { location, .. } => Some(location.clone()),
+            SparqlSyntaxErrorKind::InvalidBaseIri(_)
+            | SparqlSyntaxErrorKind::SharedBlankNode(_) => None,
+        }
+    }
+
+    /// The tokens that would have been valid at the error [location](Self::location), sorted.
+    ///
+    /// Literal tokens are quoted like `"}"` and character classes are written like `['a'..='z']`.
+    ///
+    /// ```
+    /// use spargebra::SparqlParser;
+    ///
+    /// let error = SparqlParser::new()
+    ///     .parse_query("SELECT * WHERE { ?s ?p ?o ")
+    ///     .unwrap_err();
+    /// assert!(error.expected_tokens().contains(&"\"}\""));
+    /// ```
+    pub fn expected_tokens(&self) -> Vec<&'static str> {
+        match &self.kind {
+            SparqlSyntaxErrorKind::Syntax { error, .. } => {
+                let mut tokens = error.expected.tokens().collect::<Vec<_>>();
+                tokens.sort_unstable();
+                tokens
+            }
+            SparqlSyntaxErrorKind::InvalidBaseIri(_)
+            | SparqlSyntaxErrorKind::SharedBlankNode(_) => Vec::new(),
+        }
+    }
+}
+
+/// A position in a text i.e. a `line` number starting from 0, a `column` number starting from 0 (in number of code points) and a global file `offset` starting from 0 (in number of bytes).
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub struct TextPosition {
+    pub line: u64,
+    pub column: u64,
+    pub offset: u64,
 }
 
 #[derive(Debug, thiserror::Error)]
 enum SparqlSyntaxErrorKind {
     #[error("Invalid SPARQL base IRI provided: {0}")]
     InvalidBaseIri(#[from] IriParseError),
-    #[error(transparent)]
-    Syntax(#[from] peg::error::ParseError<LineCol>),
+    #[error("{error}")]
+    Syntax {
+        error: peg::error::ParseError<LineCol>,
+        location: Range<TextPosition>,
+    },
     #[error("The blank node {0} cannot be shared by multiple blocks")]
     SharedBlankNode(BlankNode),
 }
@@ -942,8 +1065,10 @@ pub struct ParserState {
     base_iri: Option<Iri<String>>,
     prefixes: HashMap<String, String>,
     custom_aggregate_functions: HashSet<NamedNode>,
+    construct_quads: bool,
     used_bnodes: HashSet<BlankNode>,
     currently_used_bnodes: HashSet<BlankNode>,
+    anonymous_reifiers: HashSet<BlankNode>,
     aggregates: Vec<Vec<(Variable, AggregateExpression)>>,
 }
 
@@ -952,13 +1077,16 @@ impl ParserState {
         base_iri: Option<Iri<String>>,
         prefixes: HashMap<String, String>,
         custom_aggregate_functions: HashSet<NamedNode>,
+        construct_quads: bool,
     ) -> Self {
         Self {
             base_iri,
             prefixes,
             custom_aggregate_functions,
+            construct_quads,
             used_bnodes: HashSet::new(),
             currently_used_bnodes: HashSet::new(),
+            anonymous_reifiers: HashSet::new(),
             aggregates: Vec::new(),
         }
     }
@@ -983,6 +1111,46 @@ impl ParserState {
                 new_var
             }))
     }
+
+    /// Blank node used as reifier when the query does not provide one
+    fn new_anonymous_reifier(&mut self) -> BlankNode {
+        let reifier = BlankNode::default();
+        self.anonymous_reifiers.insert(reifier.clone());
+        reifier
+    }
+
+    fn has_anonymous_reifier(&self, quads: &[QuadPattern]) -> bool {
+        let is_anonymous_reifier = |term: &TermPattern| {
+            if let TermPattern::BlankNode(b) = term {
+                self.anonymous_reifiers.contains(b)
+            } else {
+                false
+            }
+        };
+        quads
+            .iter()
+            .any(|q| is_anonymous_reifier(&q.subject) || is_anonymous_reifier(&q.object))
+    }
+
+    /// Replaces the anonymous reifiers by fresh variables so that DELETE WHERE can match them
+    fn anonymous_reifiers_to_variables(&self, quads: Vec<QuadPattern>) -> Vec<QuadPattern> {
+        let mut variables = HashMap::new();
+        let mut convert = |term| match term {
+            TermPattern::BlankNode(b) if self.anonymous_reifiers.contains(&b) => {
+                variables.entry(b).or_insert_with(variable).clone().into()
+            }
+            _ => term,
+        };
+        quads
+            .into_iter()
+            .map(|q| QuadPattern {
+                subject: convert(q.subject),
+                predicate: q.predicate,
+                object: convert(q.object),
+                graph_name: q.graph_name,
+            })
+            .collect()
+    }
 }
 
 fn unescape_iriref(mut input: &str) -> Result<String, &'static str> {
@@ -1117,8 +1285,10 @@ parser! {
 
         rule ConstructQuery() -> Query =
             i("CONSTRUCT") _ c:ConstructTemplate() ConstructQuery_clear() _ d:DatasetClauses() _ w:WhereClause() _ g:GroupClause()? _ h:HavingClause()? _ o:OrderClause()? _ l:LimitOffsetClauses()? _ v:ValuesClause() {?
+                let (template, quad_template) = c;
                 Ok(Query::Construct {
-                    template: c,
+                    template,
+                    quad_template,
                     dataset: d,
                     pattern: build_select(Selection::no_op(), w, g, h, o, l, v, state)?,
                     base_iri: state.base_iri.clone()
@@ -1127,6 +1297,7 @@ parser! {
             i("CONSTRUCT") _ d:DatasetClauses() _ i("WHERE") _ "{" _ c:ConstructQuery_optional_triple_template() _ "}" _ g:GroupClause()? _ h:HavingClause()? _ o:OrderClause()? _ l:LimitOffsetClauses()? _ v:ValuesClause() {?
                 Ok(Query::Construct {
                     template: c.clone(),
+                    quad_template: Vec::new(),
                     dataset: d,
                     pattern: build_select(
                         Selection::no_op(),
@@ -1265,6 +1436,7 @@ parser! {
         rule Update_clear() = {
             state.used_bnodes.clear();
             state.currently_used_bnodes.clear();
+            state.anonymous_reifiers.clear();
         }
 
         rule Update1() -> Vec<GraphUpdateOperation> = Load() / Clear() / Drop() / Add() / Move() / Copy() / Create() / InsertData() / DeleteData() / DeleteWhere() / Modify()
@@ -1326,6 +1498,7 @@ parser! {
         }
 
         rule DeleteWhere() -> Vec<GraphUpdateOperation> = i("DELETE") _ i("WHERE") _ d:QuadPattern() {?
+            let d = state.anonymous_reifiers_to_variables(d);
             let pattern = d.iter().map(|q| {
                 let bgp = GraphPattern::Bgp { patterns: vec![TriplePattern::new(q.subject.clone(), q.predicate.clone(), q.object.clone())] };
                 match &q.graph_name {
@@ -1411,7 +1584,10 @@ parser! {
         }
 
         rule DeleteClause() -> Vec<GroundQuadPattern> = i("DELETE") _ q:QuadPattern() {?
-            q.into_iter().map(GroundQuadPattern::try_from).collect::<Result<Vec<_>,_>>().map_err(|()| "Blank nodes are not allowed in DELETE WHERE")
+            if state.has_anonymous_reifier(&q) {
+                return Err("Reified triples in DELETE templates must have an explicit reifier like << ?s ?p ?o ~?r >>");
+            }
+            q.into_iter().map(GroundQuadPattern::try_from).collect::<Result<Vec<_>,_>>().map_err(|()| "Blank nodes are not allowed in DELETE templates")
         }
 
         rule InsertClause() -> Vec<QuadPattern> = i("INSERT") _ q:QuadPattern() { q }
@@ -1443,6 +1619,9 @@ parser! {
             q.into_iter().map(Quad::try_from).collect::<Result<Vec<_>, ()>>().map_err(|()| "Variables are not allowed in INSERT DATA")
         }
         rule GroundQuadData() -> Vec<GroundQuad> = "{" _ q:Quads() _ "}" {?
+            if state.has_anonymous_reifier(&q) {
+                return Err("Reified triples in DELETE DATA must have an explicit reifier like << :s :p :o ~:r >>");
+            }
             q.into_iter().map(|q| GroundQuad::try_from(Quad::try_from(q)?)).collect::<Result<Vec<_>, ()>>().map_err(|()| "Variables and blank nodes are not allowed in DELETE DATA")
         }
 
@@ -1622,7 +1801,7 @@ parser! {
             l:BooleanLiteral() { Some(l.into()) } /
             i("UNDEF") { None }
 
-        rule Reifier() -> TermPattern = "~" _ v:VarOrReifierId()? { v.unwrap_or_else(|| BlankNode::default().into()) }
+        rule Reifier() -> TermPattern = "~" _ v:VarOrReifierId()? { v.unwrap_or_else(|| state.new_anonymous_reifier().into()) }
 
         rule VarOrReifierId() -> TermPattern =
             v:Var() { v.into() } /
@@ -1664,7 +1843,23 @@ parser! {
             NIL() { Vec::new() }
         rule ExpressionList_item() -> Expression = e:Expression() _ { e }
 
-        rule ConstructTemplate() -> Vec<TriplePattern> = "{" _ t:ConstructTriples() _ "}" { t }
+        rule ConstructTemplate() -> (Vec<TriplePattern>, Vec<QuadPattern>) =
+            "{" _ t:ConstructTriples() _ "}" { (t, Vec::new()) } /
+            "{" _ q:Quads() _ "}" {?
+                if !state.construct_quads {
+                    return Err("GRAPH blocks are not allowed in CONSTRUCT templates");
+                }
+                let mut triples = Vec::new();
+                let mut quads = Vec::new();
+                for quad in q {
+                    if quad.graph_name == GraphNamePattern::DefaultGraph {
+                        triples.push(TriplePattern::new(quad.subject, quad.predicate, quad.object));
+                    } else {
+                        quads.push(quad);
+                    }
+                }
+                Ok((triples, quads))
+            }
 
         rule ConstructTriples() -> Vec<TriplePattern> = p:ConstructTriples_item() ** ("." _) "."? {
             p.into_iter().flatten().collect()
@@ -2025,7 +2220,7 @@ parser! {
                 Ok(output)
             } /
             a:AnnotationBlock() _ {?
-                let mut output: FocusedTriplePattern<TermPattern> = FocusedTriplePattern::new(BlankNode::default());
+                let mut output: FocusedTriplePattern<TermPattern> = FocusedTriplePattern::new(state.new_anonymous_reifier());
                 for (p, os) in a.focus {
                     for o in os {
                         add_to_triple_patterns(output.focus.clone(), p.clone(), o, &mut output.patterns)?;
@@ -2050,7 +2245,7 @@ parser! {
         rule ReifiedTriple() -> FocusedTriplePattern<TermPattern> = "<<" _ s:ReifiedTripleSubject() _ p:Verb() _ o:ReifiedTripleObject() _ r:Reifier()? _ ">>" {?
             #[cfg(feature = "sparql-12")]
             {
-                let r = r.unwrap_or_else(|| BlankNode::default().into());
+                let r = r.unwrap_or_else(|| state.new_anonymous_reifier().into());
                 let mut output = FocusedTriplePattern::new(r.clone());
                 output.patterns.push(TriplePattern {
                         subject: r,
@@ -2355,10 +2550,7 @@ parser! {
             i("AVG") _ "(" _ expr:Expression() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::Avg, expr, distinct: false } } /
             i("SAMPLE") _ "(" _ i("DISTINCT") _ expr:Expression() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::Sample, expr, distinct: true } } /
             i("SAMPLE") _ "(" _ expr:Expression() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::Sample, expr, distinct: false } } /
-            i("GROUP_CONCAT") _ "(" _ i("DISTINCT") _ expr:Expression() _ ";" _ i("SEPARATOR") _ "=" _ s:String() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::GroupConcat { separator: Some(s) }, expr, distinct: true } } /
-            i("GROUP_CONCAT") _ "(" _ i("DISTINCT") _ expr:Expression() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::GroupConcat { separator: None }, expr, distinct: true } } /
-            i("GROUP_CONCAT") _ "(" _ expr:Expression() _ ";" _ i("SEPARATOR") _ "=" _ s:String() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::GroupConcat { separator: Some(s) }, expr, distinct: false } } /
-            i("GROUP_CONCAT") _ "(" _ expr:Expression() _ ")" { AggregateExpression::FunctionCall { name: AggregateFunction::GroupConcat { separator: None }, expr, distinct: false } } /
+            i("GROUP_CONCAT") _ "(" _ distinct:(i("DISTINCT") _)? expr:Expression() _ order_by:OrderClause()? separator:Aggregate_separator()? ")" { AggregateExpression::FunctionCall { name: AggregateFunction::GroupConcat { separator, order_by: order_by.unwrap_or_default() }, expr, distinct: distinct.is_some() } } /
             name:iri() _ "(" _ i("DISTINCT") _ expr:Expression() _ ")" {?
                 if state.custom_aggregate_functions.contains(&name) {
                     Ok(AggregateExpression::FunctionCall { name: AggregateFunction::Custom(name), expr, distinct: true })
@@ -2373,6 +2565,7 @@ parser! {
                     Err("This custom function is a regular function and not an aggregate function")
                 }
             }
+        rule Aggregate_separator() -> String = ";" _ i("SEPARATOR") _ "=" _ s:String() _ { s }
 
         rule iriOrFunction() -> Expression = i: iri() _ a: ArgList()? {?
             if let Some(a) = a {
@@ -2566,12 +2759,159 @@ parser! {
         //comment
         rule comment() = quiet! { ['#'] (!['\r' | '\n'] [_])* }
 
-        rule i(literal: &'static str) = input: $([_]*<{literal.len()}>) {?
+        // The failure is reported at the keyword start and not after the consumed characters
+        rule i(literal: &'static str) = quiet! { input: $([_]*<{literal.len()}>) {?
             if input.eq_ignore_ascii_case(literal) {
                 Ok(())
             } else {
                 Err(literal)
             }
-        }
+        } } / expected!(literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_error(query: &str) -> SparqlSyntaxError {
+        SparqlParser::new().parse_query(query).unwrap_err()
+    }
+
+    fn start(error: &SparqlSyntaxError) -> (u64, u64, u64) {
+        let start = error.location().unwrap().start;
+        (start.line, start.column, start.offset)
+    }
+
+    #[test]
+    fn syntax_error_at_end_of_input() {
+        let error = query_error("SELECT * WHERE { ?s ?p ?o ");
+        let location = error.location().unwrap();
+        assert_eq!(location.start, location.end);
+        assert_eq!(location.start.offset, 26);
+        assert!(error.expected_tokens().contains(&"\"}\""));
+        assert!(error.expected_tokens().contains(&"OPTIONAL"));
+    }
+
+    #[test]
+    fn syntax_error_on_later_line() {
+        let error = query_error("SELECT * WHERE { ?s ?p ?o }\nLIMIT foo");
+        assert_eq!(start(&error), (1, 6, 34));
+        assert_eq!(error.location().unwrap().end.offset, 35);
+        assert_eq!(error.expected_tokens(), ["['0'..='9']"]);
+    }
+
+    #[test]
+    fn syntax_error_in_property_path() {
+        let error = query_error("SELECT * WHERE { ?s <http://e/p>/ ?o }");
+        assert_eq!(start(&error), (0, 34, 34));
+        assert!(error.expected_tokens().contains(&"\"^\""));
+
+        let error = query_error("SELECT * WHERE { ?s (<http://e/p>|^) ?o }");
+        assert_eq!(start(&error), (0, 35, 35));
+
+        let error = query_error("SELECT * WHERE { ?s <http://e/p>+* ?o }");
+        assert_eq!(start(&error), (0, 33, 33));
+    }
+
+    #[test]
+    fn syntax_error_in_values() {
+        let error = query_error("SELECT * WHERE {\n  VALUES ?x { 1 2 ?y }\n}");
+        assert_eq!(start(&error), (1, 18, 35));
+        assert!(error.expected_tokens().contains(&"UNDEF"));
+
+        // The row size is checked once the block is parsed
+        let error = query_error("SELECT * WHERE { VALUES (?x ?y) { (1) } }");
+        assert_eq!(start(&error), (0, 39, 39));
+    }
+
+    #[test]
+    fn syntax_error_after_multibyte_characters() {
+        let error = query_error("SELECT ?x WHERE { ?x <http://e/p> \"\u{e9}\" ; ?o }");
+        assert_eq!(start(&error), (0, 43, 44));
+    }
+
+    #[test]
+    fn syntax_error_in_update() {
+        let error = SparqlParser::new()
+            .parse_update("CLEAR ALL ;\nDROP GRAF <http://e/g>")
+            .unwrap_err();
+        assert_eq!(start(&error), (1, 5, 17));
+        assert!(error.expected_tokens().contains(&"GRAPH"));
+    }
+
+    #[test]
+    fn shared_blank_node_error_has_no_location() {
+        let error = SparqlParser::new()
+            .parse_update("INSERT DATA { _:a <http://e/p> 1 } ; INSERT DATA { _:a <http://e/p> 2 }")
+            .unwrap_err();
+        assert!(error.location().is_none());
+        assert!(error.expected_tokens().is_empty());
+    }
+
+    #[test]
+    fn group_concat_order_by() {
+        let query = SparqlParser::new()
+            .parse_query(
+                "SELECT (GROUP_CONCAT(DISTINCT ?x ORDER BY DESC(?y) ?x; SEPARATOR = \", \") AS ?c) WHERE { ?x <http://e/p> ?y }",
+            )
+            .unwrap();
+        let serialized = query.to_string();
+        assert!(
+            serialized.contains(
+                "GROUP_CONCAT(DISTINCT ?x ORDER BY DESC(?y) ASC(?x); SEPARATOR = \", \")"
+            )
+        );
+        SparqlParser::new().parse_query(&serialized).unwrap();
+        assert!(
+            query
+                .to_sse()
+                .contains("(group_concat distinct ?x \", \" (order (desc ?y) (asc ?x)))")
+        );
+
+        // The ORDER BY clause is optional and can be used without separator
+        let query = SparqlParser::new()
+            .parse_query("SELECT (GROUP_CONCAT(?x ORDER BY ?x) AS ?c) WHERE { ?x <http://e/p> ?y }")
+            .unwrap();
+        assert!(
+            query
+                .to_string()
+                .contains("GROUP_CONCAT(?x ORDER BY ASC(?x))")
+        );
+    }
+
+    #[test]
+    fn construct_quads() {
+        let query_str = "CONSTRUCT { ?s <http://e/p> ?g . GRAPH ?g { ?s ?p ?o } GRAPH <http://e/g> { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } }";
+        let error = query_error(query_str);
+        assert_eq!(start(&error), (0, 88, 88));
+        assert_eq!(
+            error.expected_tokens(),
+            ["GRAPH blocks are not allowed in CONSTRUCT templates"]
+        );
+
+        let query = SparqlParser::new()
+            .with_construct_quads()
+            .parse_query(query_str)
+            .unwrap();
+        let templates = |query: &Query| match query {
+            Query::Construct {
+                template,
+                quad_template,
+                ..
+            } => Some((template.clone(), quad_template.clone())),
+            _ => None,
+        };
+        let (template, quad_template) = templates(&query).unwrap();
+        assert_eq!(template.len(), 1);
+        assert_eq!(quad_template.len(), 2);
+        let reparsed = SparqlParser::new()
+            .with_construct_quads()
+            .parse_query(&query.to_string())
+            .unwrap();
+        assert_eq!(templates(&reparsed), Some((template, quad_template)));
+        assert!(query.to_sse().starts_with(
+            "(construct ((triple ?s <http://e/p> ?g) (graph ?g ((triple ?s ?p ?o))) (graph <http://e/g> ((triple ?s ?p ?o))))"
+        ));
     }
 }
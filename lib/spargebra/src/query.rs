@@ -2,6 +2,7 @@ use crate::SparqlParser;
 use crate::algebra::*;
 use crate::parser::SparqlSyntaxError;
 use crate::term::*;
+use crate::transform::GraphPatternTransformer;
 use oxiri::Iri;
 use std::fmt;
 use std::str::FromStr;
@@ -35,6 +36,10 @@ pub enum Query {
     Construct {
         /// The query construction template.
         template: Vec<TriplePattern>,
+        /// The `GRAPH` blocks of the query construction template.
+        ///
+        /// This is an Oxigraph extension allowed by [`SparqlParser::with_construct_quads`], it is always empty in standard queries.
+        quad_template: Vec<QuadPattern>,
         /// The [query dataset specification](https://www.w3.org/TR/sparql11-query/#specifyingDataset).
         dataset: Option<QueryDataset>,
         /// The query selection graph pattern.
@@ -108,6 +113,55 @@ impl Query {
         }
     }
 
+    /// Rewrites the query graph pattern using the given [`GraphPatternTransformer`].
+    ///
+    /// The `CONSTRUCT` template and the query dataset are kept unchanged.
+    #[must_use]
+    pub fn transform(self, transformer: &mut (impl GraphPatternTransformer + ?Sized)) -> Self {
+        match self {
+            Self::Select {
+                dataset,
+                pattern,
+                base_iri,
+            } => Self::Select {
+                dataset,
+                pattern: transformer.transform_graph_pattern(pattern),
+                base_iri,
+            },
+            Self::Construct {
+                template,
+                quad_template,
+                dataset,
+                pattern,
+                base_iri,
+            } => Self::Construct {
+                template,
+                quad_template,
+                dataset,
+                pattern: transformer.transform_graph_pattern(pattern),
+                base_iri,
+            },
+            Self::Describe {
+                dataset,
+                pattern,
+                base_iri,
+            } => Self::Describe {
+                dataset,
+                pattern: transformer.transform_graph_pattern(pattern),
+                base_iri,
+            },
+            Self::Ask {
+                dataset,
+                pattern,
+                base_iri,
+            } => Self::Ask {
+                dataset,
+                pattern: transformer.transform_graph_pattern(pattern),
+                base_iri,
+            },
+        }
+    }
+
     /// Formats using the [SPARQL S-Expression syntax](https://jena.apache.org/documentation/notes/sse.html).
     pub fn to_sse(&self) -> String {
         let mut buffer = String::new();
@@ -142,6 +196,7 @@ impl Query {
             }
             Self::Construct {
                 template,
+                quad_template,
                 dataset,
                 pattern,
                 base_iri,
@@ -156,6 +211,12 @@ impl Query {
                     }
                     t.fmt_sse(f)?;
                 }
+                for (i, q) in quad_template.iter().enumerate() {
+                    if i > 0 || !template.is_empty() {
+                        f.write_str(" ")?;
+                    }
+                    q.fmt_sse(f)?;
+                }
                 f.write_str(") ")?;
                 if let Some(dataset) = dataset {
                     f.write_str("(dataset ")?;
@@ -243,6 +304,7 @@ impl fmt::Display for Query {
             }
             Self::Construct {
                 template,
+                quad_template,
                 dataset,
                 pattern,
                 base_iri,
@@ -254,6 +316,9 @@ impl fmt::Display for Query {
                 for triple in template {
                     write!(f, "{triple} . ")?;
                 }
+                for quad in quad_template {
+                    write!(f, "{quad} ")?;
+                }
                 f.write_str("}")?;
                 if let Some(dataset) = dataset {
                     dataset.fmt(f)?;
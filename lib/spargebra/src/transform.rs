@@ -0,0 +1,648 @@
+//! Rewriting of the [SPARQL algebra](crate::algebra).
+//!
+//! [`GraphPatternTransformer`] and [`UpdateTransformer`] have one method per algebra node kind.
+//! Each of them rebuilds by default the node from its transformed children,
+//! so implementations only override the methods of the nodes they want to rewrite.
+//! The `walk_*` functions provide the default behaviors, for example to recurse from an overridden method.
+
+use crate::algebra::*;
+use crate::term::*;
+use crate::update::GraphUpdateOperation;
+
+/// Rewrites [graph patterns](GraphPattern) and the [expressions](Expression) they contain.
+///
+/// It is used by [`Query::transform`](crate::Query::transform), [`GraphPattern::transform`] and [`Expression::transform`].
+///
+/// Restricts all `GRAPH ?g` patterns to some given graphs:
+/// ```
+/// use oxrdf::NamedNode;
+/// use spargebra::SparqlParser;
+/// use spargebra::algebra::{Expression, GraphPattern};
+/// use spargebra::term::NamedNodePattern;
+/// use spargebra::transform::GraphPatternTransformer;
+///
+/// struct GraphRestriction {
+///     allowed: Vec<NamedNode>,
+/// }
+///
+/// impl GraphPatternTransformer for GraphRestriction {
+///     fn transform_graph(&mut self, inner: GraphPattern, name: NamedNodePattern) -> GraphPattern {
+///         let inner = self.transform_graph_pattern(inner);
+///         let NamedNodePattern::Variable(variable) = name else {
+///             return GraphPattern::graph(inner, name);
+///         };
+///         GraphPattern::filter(
+///             GraphPattern::graph(inner, variable.clone()),
+///             Expression::in_list(
+///                 variable,
+///                 self.allowed.iter().cloned().map(Into::into).collect(),
+///             ),
+///         )
+///     }
+/// }
+///
+/// let query = SparqlParser::new().parse_query("SELECT * WHERE { GRAPH ?g { ?s ?p ?o } }")?;
+/// let query = query.transform(&mut GraphRestriction {
+///     allowed: vec![NamedNode::new("http://example.com/g")?],
+/// });
+/// assert_eq!(
+///     query.to_sse(),
+///     "(project (?g ?o ?p ?s) (filter (in ?g <http://example.com/g>) (graph ?g (bgp (triple ?s ?p ?o)))))"
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub trait GraphPatternTransformer {
+    /// Transforms a graph pattern.
+    ///
+    /// By default, calls the method related to the graph pattern kind.
+    fn transform_graph_pattern(&mut self, pattern: GraphPattern) -> GraphPattern {
+        walk_graph_pattern(self, pattern)
+    }
+
+    /// Transforms a [basic graph pattern](GraphPattern::Bgp).
+    ///
+    /// By default, transforms each triple pattern with [`transform_triple_pattern`](Self::transform_triple_pattern).
+    fn transform_bgp(&mut self, patterns: Vec<TriplePattern>) -> GraphPattern {
+        GraphPattern::bgp(
+            patterns
+                .into_iter()
+                .map(|p| self.transform_triple_pattern(p))
+                .collect(),
+        )
+    }
+
+    /// Transforms a triple pattern of a [basic graph pattern](GraphPattern::Bgp).
+    fn transform_triple_pattern(&mut self, pattern: TriplePattern) -> TriplePattern {
+        pattern
+    }
+
+    /// Transforms a [property path pattern](GraphPattern::Path).
+    fn transform_path(
+        &mut self,
+        subject: TermPattern,
+        path: PropertyPathExpression,
+        object: TermPattern,
+    ) -> GraphPattern {
+        GraphPattern::path(subject, path, object)
+    }
+
+    /// Transforms a [join](GraphPattern::Join).
+    fn transform_join(&mut self, left: GraphPattern, right: GraphPattern) -> GraphPattern {
+        GraphPattern::join(
+            self.transform_graph_pattern(left),
+            self.transform_graph_pattern(right),
+        )
+    }
+
+    /// Transforms a [left join](GraphPattern::LeftJoin).
+    fn transform_left_join(
+        &mut self,
+        left: GraphPattern,
+        right: GraphPattern,
+        expression: Option<Expression>,
+    ) -> GraphPattern {
+        GraphPattern::left_join(
+            self.transform_graph_pattern(left),
+            self.transform_graph_pattern(right),
+            expression.map(|e| self.transform_expression(e)),
+        )
+    }
+
+    /// Transforms a [lateral join](GraphPattern::Lateral).
+    #[cfg(feature = "sep-0006")]
+    fn transform_lateral(&mut self, left: GraphPattern, right: GraphPattern) -> GraphPattern {
+        GraphPattern::lateral(
+            self.transform_graph_pattern(left),
+            self.transform_graph_pattern(right),
+        )
+    }
+
+    /// Transforms a [filter](GraphPattern::Filter).
+    fn transform_filter(&mut self, inner: GraphPattern, expression: Expression) -> GraphPattern {
+        GraphPattern::filter(
+            self.transform_graph_pattern(inner),
+            self.transform_expression(expression),
+        )
+    }
+
+    /// Transforms a [union](GraphPattern::Union).
+    fn transform_union(&mut self, left: GraphPattern, right: GraphPattern) -> GraphPattern {
+        GraphPattern::union(
+            self.transform_graph_pattern(left),
+            self.transform_graph_pattern(right),
+        )
+    }
+
+    /// Transforms a [`GRAPH`](GraphPattern::Graph) pattern.
+    fn transform_graph(&mut self, inner: GraphPattern, name: NamedNodePattern) -> GraphPattern {
+        GraphPattern::graph(self.transform_graph_pattern(inner), name)
+    }
+
+    /// Transforms an [extend](GraphPattern::Extend).
+    fn transform_extend(
+        &mut self,
+        inner: GraphPattern,
+        variable: Variable,
+        expression: Expression,
+    ) -> GraphPattern {
+        GraphPattern::extend(
+            self.transform_graph_pattern(inner),
+            variable,
+            self.transform_expression(expression),
+        )
+    }
+
+    /// Transforms a [minus](GraphPattern::Minus).
+    fn transform_minus(&mut self, left: GraphPattern, right: GraphPattern) -> GraphPattern {
+        GraphPattern::minus(
+            self.transform_graph_pattern(left),
+            self.transform_graph_pattern(right),
+        )
+    }
+
+    /// Transforms a [table of values](GraphPattern::Values).
+    fn transform_values(
+        &mut self,
+        variables: Vec<Variable>,
+        bindings: Vec<Vec<Option<GroundTerm>>>,
+    ) -> GraphPattern {
+        GraphPattern::values(variables, bindings)
+    }
+
+    /// Transforms an [order by](GraphPattern::OrderBy).
+    fn transform_order_by(
+        &mut self,
+        inner: GraphPattern,
+        expression: Vec<OrderExpression>,
+    ) -> GraphPattern {
+        GraphPattern::order_by(
+            self.transform_graph_pattern(inner),
+            expression
+                .into_iter()
+                .map(|e| match e {
+                    OrderExpression::Asc(e) => OrderExpression::Asc(self.transform_expression(e)),
+                    OrderExpression::Desc(e) => OrderExpression::Desc(self.transform_expression(e)),
+                })
+                .collect(),
+        )
+    }
+
+    /// Transforms a [projection](GraphPattern::Project).
+    fn transform_project(&mut self, inner: GraphPattern, variables: Vec<Variable>) -> GraphPattern {
+        GraphPattern::project(self.transform_graph_pattern(inner), variables)
+    }
+
+    /// Transforms a [distinct](GraphPattern::Distinct).
+    fn transform_distinct(&mut self, inner: GraphPattern) -> GraphPattern {
+        GraphPattern::distinct(self.transform_graph_pattern(inner))
+    }
+
+    /// Transforms a [reduced](GraphPattern::Reduced).
+    fn transform_reduced(&mut self, inner: GraphPattern) -> GraphPattern {
+        GraphPattern::reduced(self.transform_graph_pattern(inner))
+    }
+
+    /// Transforms a [slice](GraphPattern::Slice).
+    fn transform_slice(
+        &mut self,
+        inner: GraphPattern,
+        start: usize,
+        length: Option<usize>,
+    ) -> GraphPattern {
+        GraphPattern::slice(self.transform_graph_pattern(inner), start, length)
+    }
+
+    /// Transforms a [group](GraphPattern::Group).
+    fn transform_group(
+        &mut self,
+        inner: GraphPattern,
+        variables: Vec<Variable>,
+        aggregates: Vec<(Variable, AggregateExpression)>,
+    ) -> GraphPattern {
+        GraphPattern::group(
+            self.transform_graph_pattern(inner),
+            variables,
+            aggregates
+                .into_iter()
+                .map(|(variable, aggregate)| {
+                    (
+                        variable,
+                        match aggregate {
+                            AggregateExpression::FunctionCall {
+                                name,
+                                expr,
+                                distinct,
+                            } => AggregateExpression::FunctionCall {
+                                name,
+                                expr: self.transform_expression(expr),
+                                distinct,
+                            },
+                            AggregateExpression::CountSolutions { distinct } => {
+                                AggregateExpression::CountSolutions { distinct }
+                            }
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Transforms a [`SERVICE`](GraphPattern::Service) pattern.
+    fn transform_service(
+        &mut self,
+        inner: GraphPattern,
+        name: NamedNodePattern,
+        silent: bool,
+    ) -> GraphPattern {
+        GraphPattern::service(self.transform_graph_pattern(inner), name, silent)
+    }
+
+    /// Transforms an expression.
+    ///
+    /// By default, transforms the sub-expressions and calls [`transform_exists`](Self::transform_exists) on the `EXISTS` expressions.
+    fn transform_expression(&mut self, expression: Expression) -> Expression {
+        walk_expression(self, expression)
+    }
+
+    /// Transforms the graph pattern of an [`EXISTS`](Expression::Exists) expression.
+    fn transform_exists(&mut self, pattern: GraphPattern) -> Expression {
+        Expression::exists(self.transform_graph_pattern(pattern))
+    }
+}
+
+/// Rewrites [update operations](GraphUpdateOperation) and the [graph patterns](GraphPattern) they contain.
+///
+/// It is used by [`Update::transform`](crate::Update::transform).
+///
+/// Rewrites a deprecated predicate everywhere in an update:
+/// ```
+/// use oxrdf::NamedNode;
+/// use spargebra::algebra::{GraphPattern, QueryDataset};
+/// use spargebra::term::{GroundQuadPattern, NamedNodePattern, QuadPattern, TriplePattern};
+/// use spargebra::transform::{GraphPatternTransformer, UpdateTransformer};
+/// use spargebra::{GraphUpdateOperation, SparqlParser};
+///
+/// struct PredicateRenaming {
+///     from: NamedNode,
+///     to: NamedNode,
+/// }
+///
+/// impl PredicateRenaming {
+///     fn rename(&self, predicate: NamedNodePattern) -> NamedNodePattern {
+///         if predicate == self.from.clone().into() {
+///             self.to.clone().into()
+///         } else {
+///             predicate
+///         }
+///     }
+/// }
+///
+/// impl GraphPatternTransformer for PredicateRenaming {
+///     fn transform_triple_pattern(&mut self, pattern: TriplePattern) -> TriplePattern {
+///         TriplePattern {
+///             predicate: self.rename(pattern.predicate),
+///             ..pattern
+///         }
+///     }
+/// }
+///
+/// impl UpdateTransformer for PredicateRenaming {
+///     fn transform_delete_insert(
+///         &mut self,
+///         delete: Vec<GroundQuadPattern>,
+///         insert: Vec<QuadPattern>,
+///         using: Option<QueryDataset>,
+///         pattern: GraphPattern,
+///     ) -> GraphUpdateOperation {
+///         GraphUpdateOperation::DeleteInsert {
+///             delete: delete
+///                 .into_iter()
+///                 .map(|q| GroundQuadPattern {
+///                     predicate: self.rename(q.predicate),
+///                     ..q
+///                 })
+///                 .collect(),
+///             insert: insert
+///                 .into_iter()
+///                 .map(|q| QuadPattern {
+///                     predicate: self.rename(q.predicate),
+///                     ..q
+///                 })
+///                 .collect(),
+///             using,
+///             pattern: Box::new(self.transform_graph_pattern(pattern)),
+///         }
+///     }
+/// }
+///
+/// let update = SparqlParser::new().parse_update(
+///     "DELETE { ?s <http://example.com/old> ?o } INSERT { ?s <http://example.com/new> ?o } WHERE { ?s <http://example.com/old> ?o }",
+/// )?;
+/// let update = update.transform(&mut PredicateRenaming {
+///     from: NamedNode::new("http://example.com/old")?,
+///     to: NamedNode::new("http://example.com/new")?,
+/// });
+/// assert_eq!(
+///     update.to_sse(),
+///     "(update (modify (bgp (triple ?s <http://example.com/new> ?o)) (delete ((triple ?s <http://example.com/new> ?o))) (insert ((triple ?s <http://example.com/new> ?o)))))"
+/// );
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub trait UpdateTransformer: GraphPatternTransformer {
+    /// Transforms an update operation.
+    ///
+    /// By default, calls the method related to the operation kind.
+    fn transform_operation(&mut self, operation: GraphUpdateOperation) -> GraphUpdateOperation {
+        walk_update_operation(self, operation)
+    }
+
+    /// Transforms an [insert data](GraphUpdateOperation::InsertData) operation.
+    fn transform_insert_data(&mut self, data: Vec<Quad>) -> GraphUpdateOperation {
+        GraphUpdateOperation::InsertData { data }
+    }
+
+    /// Transforms a [delete data](GraphUpdateOperation::DeleteData) operation.
+    fn transform_delete_data(&mut self, data: Vec<GroundQuad>) -> GraphUpdateOperation {
+        GraphUpdateOperation::DeleteData { data }
+    }
+
+    /// Transforms a [delete insert](GraphUpdateOperation::DeleteInsert) operation.
+    ///
+    /// By default, only the `WHERE` graph pattern is transformed.
+    fn transform_delete_insert(
+        &mut self,
+        delete: Vec<GroundQuadPattern>,
+        insert: Vec<QuadPattern>,
+        using: Option<QueryDataset>,
+        pattern: GraphPattern,
+    ) -> GraphUpdateOperation {
+        GraphUpdateOperation::DeleteInsert {
+            delete,
+            insert,
+            using,
+            pattern: Box::new(self.transform_graph_pattern(pattern)),
+        }
+    }
+
+    /// Transforms a [load](GraphUpdateOperation::Load) operation.
+    fn transform_load(
+        &mut self,
+        silent: bool,
+        source: NamedNode,
+        destination: GraphName,
+    ) -> GraphUpdateOperation {
+        GraphUpdateOperation::Load {
+            silent,
+            source,
+            destination,
+        }
+    }
+
+    /// Transforms a [clear](GraphUpdateOperation::Clear) operation.
+    fn transform_clear(&mut self, silent: bool, graph: GraphTarget) -> GraphUpdateOperation {
+        GraphUpdateOperation::Clear { silent, graph }
+    }
+
+    /// Transforms a [create](GraphUpdateOperation::Create) operation.
+    fn transform_create(&mut self, silent: bool, graph: NamedNode) -> GraphUpdateOperation {
+        GraphUpdateOperation::Create { silent, graph }
+    }
+
+    /// Transforms a [drop](GraphUpdateOperation::Drop) operation.
+    fn transform_drop(&mut self, silent: bool, graph: GraphTarget) -> GraphUpdateOperation {
+        GraphUpdateOperation::Drop { silent, graph }
+    }
+}
+
+/// Calls the [`GraphPatternTransformer`] method related to the kind of `pattern`.
+///
+/// It is the default implementation of [`GraphPatternTransformer::transform_graph_pattern`].
+pub fn walk_graph_pattern<T: GraphPatternTransformer + ?Sized>(
+    transformer: &mut T,
+    pattern: GraphPattern,
+) -> GraphPattern {
+    match pattern {
+        GraphPattern::Bgp { patterns } => transformer.transform_bgp(patterns),
+        GraphPattern::Path {
+            subject,
+            path,
+            object,
+        } => transformer.transform_path(subject, path, object),
+        GraphPattern::Join { left, right } => transformer.transform_join(*left, *right),
+        GraphPattern::LeftJoin {
+            left,
+            right,
+            expression,
+        } => transformer.transform_left_join(*left, *right, expression),
+        #[cfg(feature = "sep-0006")]
+        GraphPattern::Lateral { left, right } => transformer.transform_lateral(*left, *right),
+        GraphPattern::Filter { expr, inner } => transformer.transform_filter(*inner, expr),
+        GraphPattern::Union { left, right } => transformer.transform_union(*left, *right),
+        GraphPattern::Graph { name, inner } => transformer.transform_graph(*inner, name),
+        GraphPattern::Extend {
+            inner,
+            variable,
+            expression,
+        } => transformer.transform_extend(*inner, variable, expression),
+        GraphPattern::Minus { left, right } => transformer.transform_minus(*left, *right),
+        GraphPattern::Values {
+            variables,
+            bindings,
+        } => transformer.transform_values(variables, bindings),
+        GraphPattern::OrderBy { inner, expression } => {
+            transformer.transform_order_by(*inner, expression)
+        }
+        GraphPattern::Project { inner, variables } => {
+            transformer.transform_project(*inner, variables)
+        }
+        GraphPattern::Distinct { inner } => transformer.transform_distinct(*inner),
+        GraphPattern::Reduced { inner } => transformer.transform_reduced(*inner),
+        GraphPattern::Slice {
+            inner,
+            start,
+            length,
+        } => transformer.transform_slice(*inner, start, length),
+        GraphPattern::Group {
+            inner,
+            variables,
+            aggregates,
+        } => transformer.transform_group(*inner, variables, aggregates),
+        GraphPattern::Service {
+            name,
+            inner,
+            silent,
+        } => transformer.transform_service(*inner, name, silent),
+    }
+}
+
+/// Transforms the sub-expressions of `expression` with [`GraphPatternTransformer::transform_expression`]
+/// and the graph patterns of the `EXISTS` expressions with [`GraphPatternTransformer::transform_exists`].
+///
+/// It is the default implementation of [`GraphPatternTransformer::transform_expression`].
+pub fn walk_expression<T: GraphPatternTransformer + ?Sized>(
+    transformer: &mut T,
+    expression: Expression,
+) -> Expression {
+    let mut t = |e: Box<Expression>| Box::new(transformer.transform_expression(*e));
+    match expression {
+        e @ (Expression::NamedNode(_)
+        | Expression::Literal(_)
+        | Expression::Variable(_)
+        | Expression::Bound(_)) => e,
+        Expression::Or(l, r) => Expression::Or(t(l), t(r)),
+        Expression::And(l, r) => Expression::And(t(l), t(r)),
+        Expression::Equal(l, r) => Expression::Equal(t(l), t(r)),
+        Expression::SameTerm(l, r) => Expression::SameTerm(t(l), t(r)),
+        Expression::Greater(l, r) => Expression::Greater(t(l), t(r)),
+        Expression::GreaterOrEqual(l, r) => Expression::GreaterOrEqual(t(l), t(r)),
+        Expression::Less(l, r) => Expression::Less(t(l), t(r)),
+        Expression::LessOrEqual(l, r) => Expression::LessOrEqual(t(l), t(r)),
+        Expression::Add(l, r) => Expression::Add(t(l), t(r)),
+        Expression::Subtract(l, r) => Expression::Subtract(t(l), t(r)),
+        Expression::Multiply(l, r) => Expression::Multiply(t(l), t(r)),
+        Expression::Divide(l, r) => Expression::Divide(t(l), t(r)),
+        Expression::UnaryPlus(e) => Expression::UnaryPlus(t(e)),
+        Expression::UnaryMinus(e) => Expression::UnaryMinus(t(e)),
+        Expression::Not(e) => Expression::Not(t(e)),
+        Expression::If(c, l, r) => Expression::If(t(c), t(l), t(r)),
+        Expression::In(e, l) => Expression::In(
+            t(e),
+            l.into_iter()
+                .map(|e| transformer.transform_expression(e))
+                .collect(),
+        ),
+        Expression::Coalesce(l) => Expression::Coalesce(
+            l.into_iter()
+                .map(|e| transformer.transform_expression(e))
+                .collect(),
+        ),
+        Expression::FunctionCall(f, l) => Expression::FunctionCall(
+            f,
+            l.into_iter()
+                .map(|e| transformer.transform_expression(e))
+                .collect(),
+        ),
+        Expression::Exists(p) => transformer.transform_exists(*p),
+    }
+}
+
+/// Calls the [`UpdateTransformer`] method related to the kind of `operation`.
+///
+/// It is the default implementation of [`UpdateTransformer::transform_operation`].
+pub fn walk_update_operation<T: UpdateTransformer + ?Sized>(
+    transformer: &mut T,
+    operation: GraphUpdateOperation,
+) -> GraphUpdateOperation {
+    match operation {
+        GraphUpdateOperation::InsertData { data } => transformer.transform_insert_data(data),
+        GraphUpdateOperation::DeleteData { data } => transformer.transform_delete_data(data),
+        GraphUpdateOperation::DeleteInsert {
+            delete,
+            insert,
+            using,
+            pattern,
+        } => transformer.transform_delete_insert(delete, insert, using, *pattern),
+        GraphUpdateOperation::Load {
+            silent,
+            source,
+            destination,
+        } => transformer.transform_load(silent, source, destination),
+        GraphUpdateOperation::Clear { silent, graph } => transformer.transform_clear(silent, graph),
+        GraphUpdateOperation::Create { silent, graph } => {
+            transformer.transform_create(silent, graph)
+        }
+        GraphUpdateOperation::Drop { silent, graph } => transformer.transform_drop(silent, graph),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SparqlParser;
+
+    struct Identity;
+
+    impl GraphPatternTransformer for Identity {}
+
+    impl UpdateTransformer for Identity {}
+
+    struct GraphRestriction {
+        allowed: Vec<NamedNode>,
+    }
+
+    impl GraphPatternTransformer for GraphRestriction {
+        fn transform_graph(&mut self, inner: GraphPattern, name: NamedNodePattern) -> GraphPattern {
+            let inner = self.transform_graph_pattern(inner);
+            let NamedNodePattern::Variable(variable) = name else {
+                return GraphPattern::graph(inner, name);
+            };
+            GraphPattern::filter(
+                GraphPattern::graph(inner, variable.clone()),
+                Expression::in_list(
+                    variable,
+                    self.allowed.iter().cloned().map(Into::into).collect(),
+                ),
+            )
+        }
+    }
+
+    impl UpdateTransformer for GraphRestriction {}
+
+    #[test]
+    fn identity_keeps_query() {
+        let query = SparqlParser::new()
+            .parse_query(
+                "SELECT DISTINCT ?s (COUNT(?o) AS ?c) WHERE { \
+                    { ?s <http://e/p>+ ?o } UNION { GRAPH ?g { ?s ?p ?o OPTIONAL { ?o ?p2 ?o2 FILTER(?o2 > 1) } } } \
+                    MINUS { ?s <http://e/q> ?x } \
+                    BIND(IF(BOUND(?o), -?o, COALESCE(?p, 2)) AS ?b) \
+                    FILTER(NOT EXISTS { SERVICE SILENT <http://e/s> { ?s ?p ?o } } || ?s IN (<http://e/a>, <http://e/b>)) \
+                    VALUES ?s { <http://e/a> UNDEF } \
+                } GROUP BY ?s ORDER BY DESC(?c) LIMIT 10 OFFSET 2",
+            )
+            .unwrap();
+        assert_eq!(query.clone().transform(&mut Identity), query);
+    }
+
+    #[test]
+    fn graph_restriction_in_query() {
+        let query = SparqlParser::new()
+            .parse_query(
+                "SELECT * WHERE { GRAPH <http://e/g> { ?s ?p ?o } \
+                    OPTIONAL { GRAPH ?g { ?s ?p ?o } } \
+                    FILTER EXISTS { GRAPH ?g2 { ?s ?p ?o } } }",
+            )
+            .unwrap();
+        let query = query.transform(&mut GraphRestriction {
+            allowed: vec![
+                NamedNode::new_unchecked("http://e/g1"),
+                NamedNode::new_unchecked("http://e/g2"),
+            ],
+        });
+        assert_eq!(
+            query.to_sse(),
+            "(project (?g ?o ?p ?s) (filter (exists (filter (in ?g2 <http://e/g1> <http://e/g2>) (graph ?g2 (bgp (triple ?s ?p ?o))))) (leftjoin (graph <http://e/g> (bgp (triple ?s ?p ?o))) (filter (in ?g <http://e/g1> <http://e/g2>) (graph ?g (bgp (triple ?s ?p ?o)))))))"
+        );
+        let reparsed = SparqlParser::new().parse_query(&query.to_string()).unwrap();
+        assert_eq!(reparsed.to_string(), query.to_string());
+    }
+
+    #[test]
+    fn graph_restriction_in_update() {
+        let update = SparqlParser::new()
+            .parse_update(
+                "INSERT DATA { <http://e/s> <http://e/p> <http://e/o> } ; \
+                DELETE { GRAPH ?g { ?s ?p ?o } } WHERE { GRAPH ?g { ?s ?p ?o } } ; \
+                CLEAR SILENT ALL",
+            )
+            .unwrap();
+        assert_eq!(update.clone().transform(&mut Identity), update);
+        let update = update.transform(&mut GraphRestriction {
+            allowed: vec![NamedNode::new_unchecked("http://e/g")],
+        });
+        assert_eq!(
+            update.to_sse(),
+            "(update (insertData ((triple <http://e/s> <http://e/p> <http://e/o>))) (modify (filter (in ?g <http://e/g>) (graph ?g (bgp (triple ?s ?p ?o)))) (delete ((graph ?g ((triple ?s ?p ?o)))))) (clear silent all))"
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::SparqlParser;
 use crate::algebra::*;
 use crate::parser::SparqlSyntaxError;
 use crate::term::*;
+use crate::transform::UpdateTransformer;
 use oxiri::Iri;
 use std::fmt;
 use std::str::FromStr;
@@ -41,6 +42,19 @@ impl Update {
         parser.parse_update(update)
     }
 
+    /// Rewrites each update operation using the given [`UpdateTransformer`].
+    #[must_use]
+    pub fn transform(self, transformer: &mut (impl UpdateTransformer + ?Sized)) -> Self {
+        Self {
+            base_iri: self.base_iri,
+            operations: self
+                .operations
+                .into_iter()
+                .map(|o| transformer.transform_operation(o))
+                .collect(),
+        }
+    }
+
     /// Formats using the [SPARQL S-Expression syntax](https://jena.apache.org/documentation/notes/sse.html).
     pub fn to_sse(&self) -> String {
         let mut buffer = String::new();
@@ -1596,6 +1596,10 @@ pub enum AggregateExpression {
         name: AggregateFunction,
         expr: Expression,
         distinct: bool,
+        /// Order in which the values are given to the aggregate function.
+        ///
+        /// Only set for `GROUP_CONCAT`, the `order_by` field of [`AggregateFunction::GroupConcat`] is always left empty.
+        order_by: Vec<OrderExpression>,
     },
 }
 
@@ -1612,11 +1616,32 @@ impl AggregateExpression {
                 name,
                 expr,
                 distinct,
-            } => Self::FunctionCall {
-                name: name.clone(),
-                expr: Expression::from_sparql_algebra(expr, graph_name),
-                distinct: *distinct,
-            },
+            } => {
+                let (name, order_by) = if let AggregateFunction::GroupConcat {
+                    separator,
+                    order_by,
+                } = name
+                {
+                    (
+                        AggregateFunction::GroupConcat {
+                            separator: separator.clone(),
+                            order_by: Vec::new(),
+                        },
+                        order_by
+                            .iter()
+                            .map(|e| OrderExpression::from_sparql_algebra(e, graph_name))
+                            .collect(),
+                    )
+                } else {
+                    (name.clone(), Vec::new())
+                };
+                Self::FunctionCall {
+                    name,
+                    expr: Expression::from_sparql_algebra(expr, graph_name),
+                    distinct: *distinct,
+                    order_by,
+                }
+            }
         }
     }
 }
@@ -1631,8 +1656,16 @@ impl From<&AggregateExpression> for AlAggregateExpression {
                 name,
                 expr,
                 distinct,
+                order_by,
             } => Self::FunctionCall {
-                name: name.clone(),
+                name: if let AggregateFunction::GroupConcat { separator, .. } = name {
+                    AggregateFunction::GroupConcat {
+                        separator: separator.clone(),
+                        order_by: order_by.iter().map(Into::into).collect(),
+                    }
+                } else {
+                    name.clone()
+                },
                 expr: expr.into(),
                 distinct: *distinct,
             },
@@ -1649,6 +1682,20 @@ pub enum OrderExpression {
     Desc(Expression),
 }
 
+impl OrderExpression {
+    fn from_sparql_algebra(
+        expression: &AlOrderExpression,
+        graph_name: Option<&NamedNodePattern>,
+    ) -> Self {
+        match expression {
+            AlOrderExpression::Asc(e) => Self::Asc(Expression::from_sparql_algebra(e, graph_name)),
+            AlOrderExpression::Desc(e) => {
+                Self::Desc(Expression::from_sparql_algebra(e, graph_name))
+            }
+        }
+    }
+}
+
 impl From<&OrderExpression> for AlOrderExpression {
     fn from(expression: &OrderExpression) -> Self {
         match expression {
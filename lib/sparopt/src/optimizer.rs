@@ -1,10 +1,11 @@
+use crate::StatisticsProvider;
 use crate::algebra::{
     Expression, GraphPattern, JoinAlgorithm, LeftJoinAlgorithm, MinusAlgorithm, OrderExpression,
 };
 use crate::type_inference::{
     VariableType, VariableTypes, infer_expression_type, infer_graph_pattern_types,
 };
-use oxrdf::Variable;
+use oxrdf::{NamedNodeRef, Variable};
 use spargebra::algebra::PropertyPathExpression;
 use spargebra::term::{GroundTermPattern, NamedNodePattern};
 use std::cmp::{max, min};
@@ -13,8 +14,25 @@ pub struct Optimizer;
 
 impl Optimizer {
     pub fn optimize_graph_pattern(pattern: GraphPattern) -> GraphPattern {
+        Self::optimize(pattern, None)
+    }
+
+    /// Optimizes the pattern using statistics about the queried dataset to estimate the patterns cardinalities.
+    ///
+    /// The statistics are used to order the joins and to pick between hash joins and for loop joins.
+    pub fn optimize_graph_pattern_with_statistics(
+        pattern: GraphPattern,
+        statistics: &dyn StatisticsProvider,
+    ) -> GraphPattern {
+        Self::optimize(pattern, Some(statistics))
+    }
+
+    fn optimize(
+        pattern: GraphPattern,
+        statistics: Option<&dyn StatisticsProvider>,
+    ) -> GraphPattern {
         let pattern = Self::normalize_pattern(pattern, &VariableTypes::default());
-        let pattern = Self::reorder_joins(pattern, &VariableTypes::default());
+        let pattern = Self::reorder_joins(pattern, &VariableTypes::default(), statistics);
         Self::push_filters(pattern, Vec::new(), &VariableTypes::default())
     }
 
@@ -153,7 +171,14 @@ impl Optimizer {
                 inner,
                 start,
                 length,
-            } => GraphPattern::slice(Self::normalize_pattern(*inner, input_types), start, length),
+            } => match Self::normalize_pattern(*inner, input_types) {
+                // The projection keeps the order and the number of solutions,
+                // we move the slice below it to allow the evaluator to merge it with an ORDER BY
+                GraphPattern::Project { inner, variables } => {
+                    GraphPattern::project(GraphPattern::slice(*inner, start, length), variables)
+                }
+                inner => GraphPattern::slice(inner, start, length),
+            },
             GraphPattern::Group {
                 inner,
                 variables,
@@ -476,7 +501,11 @@ impl Optimizer {
         }
     }
 
-    fn reorder_joins(pattern: GraphPattern, input_types: &VariableTypes) -> GraphPattern {
+    fn reorder_joins(
+        pattern: GraphPattern,
+        input_types: &VariableTypes,
+        statistics: Option<&dyn StatisticsProvider>,
+    ) -> GraphPattern {
         match pattern {
             GraphPattern::QuadPattern { .. }
             | GraphPattern::Path { .. }
@@ -510,7 +539,9 @@ impl Optimizer {
                     .enumerate()
                     .filter(|(_, v)| **v)
                     .map(|(i, _)| i)
-                    .min_by_key(|i| estimate_graph_pattern_size(&to_reorder[*i], input_types))
+                    .min_by_key(|i| {
+                        estimate_graph_pattern_size(&to_reorder[*i], input_types, statistics)
+                    })
                 {
                     not_yet_reordered_ids[next_entry_id] = false; // It's now done
                     let mut output = to_reorder[next_entry_id].clone();
@@ -538,6 +569,7 @@ impl Optimizer {
                                     &output_types,
                                     &to_reorder[*i],
                                     input_types,
+                                    statistics,
                                 )
                             } else {
                                 estimate_join_cost(
@@ -551,6 +583,7 @@ impl Optimizer {
                                         ),
                                     },
                                     input_types,
+                                    statistics,
                                 )
                             }
                         })
@@ -560,7 +593,13 @@ impl Optimizer {
                         #[cfg(feature = "sep-0006")]
                         {
                             output = if is_fit_for_for_loop_join(&next, input_types, &output_types)
-                            {
+                                && is_for_loop_join_cheaper(
+                                    &output,
+                                    &output_types,
+                                    &next,
+                                    input_types,
+                                    statistics,
+                                ) {
                                 GraphPattern::lateral(output, next)
                             } else {
                                 GraphPattern::join(
@@ -602,8 +641,8 @@ impl Optimizer {
                             &infer_graph_pattern_types(&right, input_types.clone()),
                             input_types,
                         );
-                        if estimate_graph_pattern_size(&left, input_types)
-                            <= estimate_graph_pattern_size(&right, input_types)
+                        if estimate_graph_pattern_size(&left, input_types, statistics)
+                            <= estimate_graph_pattern_size(&right, input_types, statistics)
                         {
                             GraphPattern::join(
                                 left,
@@ -624,8 +663,8 @@ impl Optimizer {
             GraphPattern::Lateral { left, right } => {
                 let left_types = infer_graph_pattern_types(&left, input_types.clone());
                 GraphPattern::lateral(
-                    Self::reorder_joins(*left, input_types),
-                    Self::reorder_joins(*right, &left_types),
+                    Self::reorder_joins(*left, input_types, statistics),
+                    Self::reorder_joins(*right, &left_types, statistics),
                 )
             }
             GraphPattern::LeftJoin {
@@ -634,9 +673,9 @@ impl Optimizer {
                 expression,
                 ..
             } => {
-                let left = Self::reorder_joins(*left, input_types);
+                let left = Self::reorder_joins(*left, input_types, statistics);
                 let left_types = infer_graph_pattern_types(&left, input_types.clone());
-                let right = Self::reorder_joins(*right, input_types);
+                let right = Self::reorder_joins(*right, input_types, statistics);
                 let right_types = infer_graph_pattern_types(&right, input_types.clone());
                 #[cfg(feature = "sep-0006")]
                 {
@@ -664,9 +703,9 @@ impl Optimizer {
                 )
             }
             GraphPattern::Minus { left, right, .. } => {
-                let left = Self::reorder_joins(*left, input_types);
+                let left = Self::reorder_joins(*left, input_types, statistics);
                 let left_types = infer_graph_pattern_types(&left, input_types.clone());
-                let right = Self::reorder_joins(*right, input_types);
+                let right = Self::reorder_joins(*right, input_types, statistics);
                 let right_types = infer_graph_pattern_types(&right, input_types.clone());
                 GraphPattern::minus(
                     left,
@@ -680,36 +719,51 @@ impl Optimizer {
                 inner,
                 expression,
                 variable,
-            } => GraphPattern::extend(
-                Self::reorder_joins(*inner, input_types),
-                variable,
-                expression,
-            ),
+            } => {
+                let inner = Self::reorder_joins(*inner, input_types, statistics);
+                let inner_types = infer_graph_pattern_types(&inner, input_types.clone());
+                GraphPattern::extend(
+                    inner,
+                    variable,
+                    Self::optimize_exists_patterns(expression, &inner_types, statistics),
+                )
+            }
             GraphPattern::Filter { inner, expression } => {
-                GraphPattern::filter(Self::reorder_joins(*inner, input_types), expression)
+                let inner = Self::reorder_joins(*inner, input_types, statistics);
+                let inner_types = infer_graph_pattern_types(&inner, input_types.clone());
+                GraphPattern::filter(
+                    inner,
+                    Self::optimize_exists_patterns(expression, &inner_types, statistics),
+                )
             }
             GraphPattern::Union { inner } => GraphPattern::union_all(
                 inner
                     .into_iter()
-                    .map(|c| Self::reorder_joins(c, input_types)),
+                    .map(|c| Self::reorder_joins(c, input_types, statistics)),
             ),
             GraphPattern::Slice {
                 inner,
                 start,
                 length,
-            } => GraphPattern::slice(Self::reorder_joins(*inner, input_types), start, length),
+            } => GraphPattern::slice(
+                Self::reorder_joins(*inner, input_types, statistics),
+                start,
+                length,
+            ),
             GraphPattern::Distinct { inner } => {
-                GraphPattern::distinct(Self::reorder_joins(*inner, input_types))
+                GraphPattern::distinct(Self::reorder_joins(*inner, input_types, statistics))
             }
             GraphPattern::Reduced { inner } => {
-                GraphPattern::reduced(Self::reorder_joins(*inner, input_types))
-            }
-            GraphPattern::Project { inner, variables } => {
-                GraphPattern::project(Self::reorder_joins(*inner, input_types), variables)
-            }
-            GraphPattern::OrderBy { inner, expression } => {
-                GraphPattern::order_by(Self::reorder_joins(*inner, input_types), expression)
+                GraphPattern::reduced(Self::reorder_joins(*inner, input_types, statistics))
             }
+            GraphPattern::Project { inner, variables } => GraphPattern::project(
+                Self::reorder_joins(*inner, input_types, statistics),
+                variables,
+            ),
+            GraphPattern::OrderBy { inner, expression } => GraphPattern::order_by(
+                Self::reorder_joins(*inner, input_types, statistics),
+                expression,
+            ),
             GraphPattern::Service { .. } => {
                 // We don't do join reordering inside of SERVICE calls, we don't know about cardinalities
                 pattern
@@ -719,12 +773,79 @@ impl Optimizer {
                 variables,
                 aggregates,
             } => GraphPattern::group(
-                Self::reorder_joins(*inner, input_types),
+                Self::reorder_joins(*inner, input_types, statistics),
                 variables,
                 aggregates,
             ),
         }
     }
+
+    /// Optimizes the patterns of the EXISTS inside the expression.
+    ///
+    /// They are evaluated once per input tuple so the variables bound in `input_types` are used to order their joins.
+    fn optimize_exists_patterns(
+        expression: Expression,
+        input_types: &VariableTypes,
+        statistics: Option<&dyn StatisticsProvider>,
+    ) -> Expression {
+        let optimize = |e: Expression| Self::optimize_exists_patterns(e, input_types, statistics);
+        let optimize_box = |e: Box<Expression>| Box::new(optimize(*e));
+        match expression {
+            Expression::NamedNode(_)
+            | Expression::Literal(_)
+            | Expression::Variable(_)
+            | Expression::Bound(_) => expression,
+            Expression::Exists(inner) => Expression::Exists(Box::new(Self::push_filters(
+                Self::reorder_joins(*inner, input_types, statistics),
+                Vec::new(),
+                input_types,
+            ))),
+            Expression::Or(inner) => Expression::Or(inner.into_iter().map(optimize).collect()),
+            Expression::And(inner) => Expression::And(inner.into_iter().map(optimize).collect()),
+            Expression::Coalesce(inner) => {
+                Expression::Coalesce(inner.into_iter().map(optimize).collect())
+            }
+            Expression::FunctionCall(name, args) => {
+                Expression::FunctionCall(name, args.into_iter().map(optimize).collect())
+            }
+            Expression::Equal(left, right) => {
+                Expression::Equal(optimize_box(left), optimize_box(right))
+            }
+            Expression::SameTerm(left, right) => {
+                Expression::SameTerm(optimize_box(left), optimize_box(right))
+            }
+            Expression::Greater(left, right) => {
+                Expression::Greater(optimize_box(left), optimize_box(right))
+            }
+            Expression::GreaterOrEqual(left, right) => {
+                Expression::GreaterOrEqual(optimize_box(left), optimize_box(right))
+            }
+            Expression::Less(left, right) => {
+                Expression::Less(optimize_box(left), optimize_box(right))
+            }
+            Expression::LessOrEqual(left, right) => {
+                Expression::LessOrEqual(optimize_box(left), optimize_box(right))
+            }
+            Expression::Add(left, right) => {
+                Expression::Add(optimize_box(left), optimize_box(right))
+            }
+            Expression::Subtract(left, right) => {
+                Expression::Subtract(optimize_box(left), optimize_box(right))
+            }
+            Expression::Multiply(left, right) => {
+                Expression::Multiply(optimize_box(left), optimize_box(right))
+            }
+            Expression::Divide(left, right) => {
+                Expression::Divide(optimize_box(left), optimize_box(right))
+            }
+            Expression::UnaryPlus(inner) => Expression::UnaryPlus(optimize_box(inner)),
+            Expression::UnaryMinus(inner) => Expression::UnaryMinus(optimize_box(inner)),
+            Expression::Not(inner) => Expression::Not(optimize_box(inner)),
+            Expression::If(cond, then, els) => {
+                Expression::If(optimize_box(cond), optimize_box(then), optimize_box(els))
+            }
+        }
+    }
 }
 
 fn is_fit_for_for_loop_join(
@@ -889,7 +1010,11 @@ fn join_key_variables(
         .collect()
 }
 
-fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTypes) -> usize {
+fn estimate_graph_pattern_size(
+    pattern: &GraphPattern,
+    input_types: &VariableTypes,
+    statistics: Option<&dyn StatisticsProvider>,
+) -> usize {
     match pattern {
         GraphPattern::Values { bindings, .. } => bindings.len(),
         GraphPattern::QuadPattern {
@@ -899,8 +1024,13 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
             ..
         } => estimate_triple_pattern_size(
             is_term_pattern_bound(subject, input_types),
+            match predicate {
+                NamedNodePattern::NamedNode(p) => Some(p.as_ref()),
+                NamedNodePattern::Variable(_) => None,
+            },
             is_named_node_pattern_bound(predicate, input_types),
             is_term_pattern_bound(object, input_types),
+            statistics,
         ),
         GraphPattern::Path {
             subject,
@@ -911,6 +1041,7 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
             is_term_pattern_bound(subject, input_types),
             path,
             is_term_pattern_bound(object, input_types),
+            statistics,
         ),
         GraphPattern::Graph { graph_name } => {
             if is_named_node_pattern_bound(graph_name, input_types) {
@@ -923,7 +1054,7 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
             left,
             right,
             algorithm,
-        } => estimate_join_cost(left, right, algorithm, input_types),
+        } => estimate_join_cost(left, right, algorithm, input_types, statistics),
         GraphPattern::LeftJoin {
             left,
             right,
@@ -931,13 +1062,14 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
             ..
         } => match algorithm {
             LeftJoinAlgorithm::HashBuildRightProbeLeft { keys } => {
-                let left_size = estimate_graph_pattern_size(left, input_types);
+                let left_size = estimate_graph_pattern_size(left, input_types, statistics);
                 max(
                     left_size,
                     left_size
                         .saturating_mul(estimate_graph_pattern_size(
                             right,
                             &infer_graph_pattern_types(right, input_types.clone()),
+                            statistics,
                         ))
                         .saturating_div(1_000_usize.saturating_pow(keys.len().try_into().unwrap())),
                 )
@@ -949,12 +1081,15 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
             &infer_graph_pattern_types(left, input_types.clone()),
             right,
             input_types,
+            statistics,
         ),
         GraphPattern::Union { inner } => inner
             .iter()
-            .map(|inner| estimate_graph_pattern_size(inner, input_types))
+            .map(|inner| estimate_graph_pattern_size(inner, input_types, statistics))
             .fold(0, usize::saturating_add),
-        GraphPattern::Minus { left, .. } => estimate_graph_pattern_size(left, input_types),
+        GraphPattern::Minus { left, .. } => {
+            estimate_graph_pattern_size(left, input_types, statistics)
+        }
         GraphPattern::Filter { inner, .. }
         | GraphPattern::Extend { inner, .. }
         | GraphPattern::OrderBy { inner, .. }
@@ -962,13 +1097,15 @@ fn estimate_graph_pattern_size(pattern: &GraphPattern, input_types: &VariableTyp
         | GraphPattern::Distinct { inner, .. }
         | GraphPattern::Reduced { inner, .. }
         | GraphPattern::Group { inner, .. }
-        | GraphPattern::Service { inner, .. } => estimate_graph_pattern_size(inner, input_types),
+        | GraphPattern::Service { inner, .. } => {
+            estimate_graph_pattern_size(inner, input_types, statistics)
+        }
         GraphPattern::Slice {
             inner,
             start,
             length,
         } => {
-            let inner = estimate_graph_pattern_size(inner, input_types);
+            let inner = estimate_graph_pattern_size(inner, input_types, statistics);
             if let Some(length) = length {
                 min(inner, *length - *start)
             } else {
@@ -983,11 +1120,12 @@ fn estimate_join_cost(
     right: &GraphPattern,
     algorithm: &JoinAlgorithm,
     input_types: &VariableTypes,
+    statistics: Option<&dyn StatisticsProvider>,
 ) -> usize {
     match algorithm {
         JoinAlgorithm::HashBuildLeftProbeRight { keys } => {
-            estimate_graph_pattern_size(left, input_types)
-                .saturating_mul(estimate_graph_pattern_size(right, input_types))
+            estimate_graph_pattern_size(left, input_types, statistics)
+                .saturating_mul(estimate_graph_pattern_size(right, input_types, statistics))
                 .saturating_div(1_000_usize.saturating_pow(keys.len().try_into().unwrap()))
         }
     }
@@ -997,12 +1135,96 @@ fn estimate_lateral_cost(
     left_types: &VariableTypes,
     right: &GraphPattern,
     input_types: &VariableTypes,
+    statistics: Option<&dyn StatisticsProvider>,
 ) -> usize {
-    estimate_graph_pattern_size(left, input_types)
-        .saturating_mul(estimate_graph_pattern_size(right, left_types))
+    estimate_graph_pattern_size(left, input_types, statistics)
+        .saturating_mul(estimate_graph_pattern_size(right, left_types, statistics))
+}
+
+/// Checks if evaluating `right` once per `left` solution is cheaper than building a hash table
+///
+/// Without statistics we always prefer for loop joins as the cardinality estimations are too coarse.
+#[cfg(feature = "sep-0006")]
+fn is_for_loop_join_cheaper(
+    left: &GraphPattern,
+    left_types: &VariableTypes,
+    right: &GraphPattern,
+    input_types: &VariableTypes,
+    statistics: Option<&dyn StatisticsProvider>,
+) -> bool {
+    if statistics.is_none() {
+        return true;
+    }
+    let left_size = estimate_graph_pattern_size(left, input_types, statistics);
+    // Each evaluation of the right side costs at least an index lookup
+    let for_loop_cost = left_size.saturating_mul(max(
+        estimate_graph_pattern_size(right, left_types, statistics),
+        1,
+    ));
+    // The hash join reads each side once
+    let hash_cost =
+        left_size.saturating_add(estimate_graph_pattern_size(right, input_types, statistics));
+    for_loop_cost <= hash_cost
 }
 
 fn estimate_triple_pattern_size(
+    subject_bound: bool,
+    predicate: Option<NamedNodeRef<'_>>,
+    predicate_bound: bool,
+    object_bound: bool,
+    statistics: Option<&dyn StatisticsProvider>,
+) -> usize {
+    let Some(statistics) = statistics else {
+        return default_triple_pattern_size(subject_bound, predicate_bound, object_bound);
+    };
+    if let Some(predicate) = predicate {
+        if let Some(count) = statistics.predicate_triple_count(predicate) {
+            return estimate_triple_pattern_size_from_predicate_statistics(
+                subject_bound,
+                predicate,
+                object_bound,
+                count,
+                statistics,
+            );
+        }
+    }
+    min(
+        default_triple_pattern_size(subject_bound, predicate_bound, object_bound),
+        statistics.triple_count(),
+    )
+}
+
+fn estimate_triple_pattern_size_from_predicate_statistics(
+    subject_bound: bool,
+    predicate: NamedNodeRef<'_>,
+    object_bound: bool,
+    count: usize,
+    statistics: &dyn StatisticsProvider,
+) -> usize {
+    // We assume the triples are uniformly distributed between the subjects and the objects
+    let subject_selectivity = || {
+        statistics
+            .predicate_distinct_subject_count(predicate)
+            .filter(|c| *c > 0)
+            .map(|c| count.div_ceil(c))
+    };
+    let object_selectivity = || {
+        statistics
+            .predicate_distinct_object_count(predicate)
+            .filter(|c| *c > 0)
+            .map(|c| count.div_ceil(c))
+    };
+    match (subject_bound, object_bound) {
+        (true, true) => min(count, 1),
+        (true, false) => subject_selectivity()
+            .unwrap_or_else(|| min(count, default_triple_pattern_size(true, true, false))),
+        (false, true) => object_selectivity()
+            .unwrap_or_else(|| min(count, default_triple_pattern_size(false, true, true))),
+        (false, false) => count,
+    }
+}
+
+fn default_triple_pattern_size(
     subject_bound: bool,
     predicate_bound: bool,
     object_bound: bool,
@@ -1019,28 +1241,37 @@ fn estimate_triple_pattern_size(
     }
 }
 
-fn estimate_path_size(start_bound: bool, path: &PropertyPathExpression, end_bound: bool) -> usize {
+fn estimate_path_size(
+    start_bound: bool,
+    path: &PropertyPathExpression,
+    end_bound: bool,
+    statistics: Option<&dyn StatisticsProvider>,
+) -> usize {
     match path {
-        PropertyPathExpression::NamedNode(_) => {
-            estimate_triple_pattern_size(start_bound, true, end_bound)
+        PropertyPathExpression::NamedNode(p) => {
+            estimate_triple_pattern_size(start_bound, Some(p.as_ref()), true, end_bound, statistics)
+        }
+        PropertyPathExpression::Reverse(p) => {
+            estimate_path_size(end_bound, p, start_bound, statistics)
         }
-        PropertyPathExpression::Reverse(p) => estimate_path_size(end_bound, p, start_bound),
         PropertyPathExpression::Sequence(a, b) => {
             // We do a for loop join in the best direction
             min(
-                estimate_path_size(start_bound, a, false)
-                    .saturating_mul(estimate_path_size(true, b, end_bound)),
-                estimate_path_size(start_bound, a, true)
-                    .saturating_mul(estimate_path_size(false, b, end_bound)),
+                estimate_path_size(start_bound, a, false, statistics)
+                    .saturating_mul(estimate_path_size(true, b, end_bound, statistics)),
+                estimate_path_size(start_bound, a, true, statistics)
+                    .saturating_mul(estimate_path_size(false, b, end_bound, statistics)),
             )
         }
-        PropertyPathExpression::Alternative(a, b) => estimate_path_size(start_bound, a, end_bound)
-            .saturating_add(estimate_path_size(start_bound, b, end_bound)),
+        PropertyPathExpression::Alternative(a, b) => {
+            estimate_path_size(start_bound, a, end_bound, statistics)
+                .saturating_add(estimate_path_size(start_bound, b, end_bound, statistics))
+        }
         PropertyPathExpression::ZeroOrMore(p) => {
             if start_bound && end_bound {
                 1
             } else if start_bound || end_bound {
-                estimate_path_size(start_bound, p, end_bound).saturating_mul(1000)
+                estimate_path_size(start_bound, p, end_bound, statistics).saturating_mul(1000)
             } else {
                 1_000_000_000
             }
@@ -1049,20 +1280,20 @@ fn estimate_path_size(start_bound: bool, path: &PropertyPathExpression, end_boun
             if start_bound && end_bound {
                 1
             } else {
-                estimate_path_size(start_bound, p, end_bound).saturating_mul(1000)
+                estimate_path_size(start_bound, p, end_bound, statistics).saturating_mul(1000)
             }
         }
         PropertyPathExpression::ZeroOrOne(p) => {
             if start_bound && end_bound {
                 1
             } else if start_bound || end_bound {
-                estimate_path_size(start_bound, p, end_bound)
+                estimate_path_size(start_bound, p, end_bound, statistics)
             } else {
                 1_000_000_000
             }
         }
         PropertyPathExpression::NegatedPropertySet(_) => {
-            estimate_triple_pattern_size(start_bound, false, end_bound)
+            estimate_triple_pattern_size(start_bound, None, false, end_bound, statistics)
         }
     }
 }
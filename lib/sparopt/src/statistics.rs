@@ -0,0 +1,65 @@
+use oxrdf::NamedNodeRef;
+
+/// Statistics about the queried dataset used by the [`Optimizer`](crate::Optimizer) to estimate the cardinality of the patterns.
+///
+/// They are only estimations: the optimizer output is correct even if the statistics are stale or wrong,
+/// but the join order might be worse.
+///
+/// Usage example:
+/// ```
+/// use oxrdf::NamedNodeRef;
+/// use sparopt::{Optimizer, StatisticsProvider};
+/// use spargebra::SparqlParser;
+///
+/// struct Statistics;
+///
+/// impl StatisticsProvider for Statistics {
+///     fn triple_count(&self) -> usize {
+///         1000
+///     }
+///
+///     fn predicate_triple_count(&self, predicate: NamedNodeRef<'_>) -> Option<usize> {
+///         Some(if predicate.as_str() == "http://example.com/rare" {
+///             1
+///         } else {
+///             500
+///         })
+///     }
+/// }
+///
+/// let query = SparqlParser::new().parse_query(
+///     "SELECT * WHERE { ?s <http://example.com/common> ?o . ?s <http://example.com/rare> ?o2 }",
+/// )?;
+/// let spargebra::Query::Select { pattern, .. } = query else {
+///     unreachable!()
+/// };
+/// let pattern =
+///     Optimizer::optimize_graph_pattern_with_statistics((&pattern).into(), &Statistics);
+/// // The most selective pattern is evaluated first
+/// let plan = format!("{pattern:?}");
+/// assert!(plan.find("rare") < plan.find("common"));
+/// # Result::<_, Box<dyn std::error::Error>>::Ok(())
+/// ```
+pub trait StatisticsProvider {
+    /// The number of triples in the dataset.
+    fn triple_count(&self) -> usize;
+
+    /// The number of triples with the given predicate.
+    ///
+    /// Returns `None` if unknown.
+    fn predicate_triple_count(&self, predicate: NamedNodeRef<'_>) -> Option<usize>;
+
+    /// The number of distinct subjects of the triples with the given predicate.
+    ///
+    /// Returns `None` if unknown.
+    fn predicate_distinct_subject_count(&self, _predicate: NamedNodeRef<'_>) -> Option<usize> {
+        None
+    }
+
+    /// The number of distinct objects of the triples with the given predicate.
+    ///
+    /// Returns `None` if unknown.
+    fn predicate_distinct_object_count(&self, _predicate: NamedNodeRef<'_>) -> Option<usize> {
+        None
+    }
+}
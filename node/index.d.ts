@@ -0,0 +1,171 @@
+export type Term = BlankNode | DefaultGraph | Literal | NamedNode | Quad | Variable;
+
+export class BlankNode {
+    readonly termType: "BlankNode";
+    readonly value: string;
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export class DefaultGraph {
+    readonly termType: "DefaultGraph";
+    readonly value: "";
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export class Literal {
+    readonly termType: "Literal";
+    readonly value: string;
+    readonly language: string;
+    readonly direction: "ltr" | "rtl" | "";
+    readonly datatype: NamedNode;
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export class NamedNode {
+    readonly termType: "NamedNode";
+    readonly value: string;
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export class Quad {
+    readonly termType: "Quad";
+    readonly value: "";
+    readonly subject: NamedNode | BlankNode | Quad | Variable;
+    readonly predicate: NamedNode | Variable;
+    readonly object: NamedNode | BlankNode | Literal | Quad | Variable;
+    readonly graph: NamedNode | BlankNode | DefaultGraph | Variable;
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export class Variable {
+    readonly termType: "Variable";
+    readonly value: string;
+
+    equals(other: Term | null | undefined): boolean;
+    toString(): string;
+}
+
+export function blankNode(value?: string): BlankNode;
+export function defaultGraph(): DefaultGraph;
+export function literal(
+    value?: string,
+    languageOrDatatype?: string | NamedNode | { language: string; direction?: "ltr" | "rtl" },
+): Literal;
+export function namedNode(value: string): NamedNode;
+export function quad(subject: Term, predicate: Term, object: Term, graph?: Term): Quad;
+export function triple(subject: Term, predicate: Term, object: Term): Quad;
+export function variable(value: string): Variable;
+
+type GraphName = BlankNode | DefaultGraph | NamedNode;
+
+export interface QueryOptions {
+    base_iri?: NamedNode | string;
+    results_format?: string;
+    default_graph?: GraphName | GraphName[];
+    named_graphs?: (BlankNode | NamedNode)[];
+    use_default_graph_as_union?: boolean;
+}
+
+export interface UpdateOptions {
+    base_iri?: NamedNode | string;
+}
+
+export interface LoadOptions {
+    base_iri?: NamedNode | string;
+    format: string;
+    no_transaction?: boolean;
+    to_graph_name?: GraphName;
+    lenient?: boolean;
+}
+
+export interface DumpOptions {
+    format: string;
+    from_graph_name?: GraphName;
+}
+
+export type QueryResults = boolean | Map<string, Term>[] | Quad[] | string;
+
+export class Loader {
+    /**
+     * Sends a chunk of data to the parser.
+     *
+     * Resolves to `false` if the parser has stopped, the error being then returned by `end`.
+     */
+    write(chunk: string | Uint8Array): Promise<boolean>;
+
+    /** Signals the end of the data and resolves when all the quads have been loaded. */
+    end(): Promise<void>;
+
+    /** Stops the load. The quads already loaded are kept. */
+    abort(): void;
+}
+
+export class Store {
+    readonly size: number;
+
+    constructor(options?: { path?: string; read_only?: boolean });
+
+    [Symbol.iterator](): Iterator<Quad>;
+
+    add(quad: Quad): void;
+
+    delete(quad: Quad): void;
+
+    dump(options: DumpOptions): string;
+
+    dumpAsync(options: DumpOptions): Promise<string>;
+
+    has(quad: Quad): boolean;
+
+    load(input: string | Uint8Array, options: LoadOptions): void;
+
+    loadAsync(input: string | Uint8Array, options: LoadOptions): Promise<void>;
+
+    /**
+     * Loads the chunks of a Node.js Readable stream or any other async iterable.
+     *
+     * The quads are loaded with the bulk loader: the load is not atomic.
+     */
+    loadStream(
+        stream: AsyncIterable<string | Uint8Array>,
+        options: Omit<LoadOptions, "no_transaction">,
+    ): Promise<void>;
+
+    match(
+        subject?: Term | null,
+        predicate?: Term | null,
+        object?: Term | null,
+        graph?: Term | null,
+    ): Quad[];
+
+    query(query: string, options?: QueryOptions): QueryResults;
+
+    queryAsync(query: string, options?: QueryOptions): Promise<QueryResults>;
+
+    renameGraph(from: GraphName, to: GraphName, options?: { overwrite?: boolean }): boolean;
+
+    setObject(
+        subject: BlankNode | NamedNode,
+        predicate: NamedNode,
+        objects: Iterable<Term>,
+        graph?: GraphName | null,
+    ): Term[];
+
+    startLoad(options: Omit<LoadOptions, "no_transaction">): Loader;
+
+    update(update: string, options?: UpdateOptions): void;
+
+    updateAsync(update: string, options?: UpdateOptions): Promise<void>;
+
+    values(): Quad[];
+}
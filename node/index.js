@@ -0,0 +1,64 @@
+"use strict";
+
+const binding = require("./binding.js");
+
+const { Store } = binding;
+const nativeQuery = Store.prototype.query;
+const nativeQueryAsync = Store.prototype.queryAsync;
+const nativeSetObject = Store.prototype.setObject;
+
+function convertQueryResults(results) {
+    if (results.serialized !== undefined && results.serialized !== null) {
+        return results.serialized;
+    }
+    if (results.boolean !== undefined && results.boolean !== null) {
+        return results.boolean;
+    }
+    if (results.quads !== undefined && results.quads !== null) {
+        return results.quads;
+    }
+    return results.solutions.map((values) => {
+        const solution = new Map();
+        values.forEach((value, i) => {
+            if (value !== undefined && value !== null) {
+                solution.set(results.variables[i], value);
+            }
+        });
+        return solution;
+    });
+}
+
+Store.prototype.query = function query(query, options) {
+    return convertQueryResults(nativeQuery.call(this, query, options));
+};
+
+Store.prototype.queryAsync = async function queryAsync(query, options) {
+    return convertQueryResults(await nativeQueryAsync.call(this, query, options));
+};
+
+Store.prototype.setObject = function setObject(subject, predicate, objects, graph) {
+    return nativeSetObject.call(this, subject, predicate, Array.from(objects), graph);
+};
+
+Store.prototype[Symbol.iterator] = function iterator() {
+    return this.values()[Symbol.iterator]();
+};
+
+Store.prototype.loadStream = async function loadStream(stream, options) {
+    const loader = this.startLoad(options);
+    try {
+        for await (const chunk of stream) {
+            if (!(await loader.write(chunk))) {
+                // The parser has stopped, end() returns its error
+                break;
+            }
+        }
+    } catch (error) {
+        loader.abort();
+        await loader.end().catch(() => undefined);
+        throw error;
+    }
+    await loader.end();
+};
+
+module.exports = binding;
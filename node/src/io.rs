@@ -0,0 +1,76 @@
+use crate::format_err;
+use oxigraph::io::RdfFormat;
+use oxigraph::sparql::results::QueryResultsFormat;
+use std::io::{self, Read};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+
+pub fn rdf_format(format: &str) -> napi::Result<RdfFormat> {
+    if format.contains('/') {
+        RdfFormat::from_media_type(format)
+            .ok_or_else(|| format_err!("Not supported RDF format media type: {}", format))
+    } else {
+        RdfFormat::from_extension(format)
+            .ok_or_else(|| format_err!("Not supported RDF format extension: {}", format))
+    }
+}
+
+pub fn query_results_format(format: &str) -> napi::Result<QueryResultsFormat> {
+    if format.contains('/') {
+        QueryResultsFormat::from_media_type(format).ok_or_else(|| {
+            format_err!(
+                "Not supported SPARQL query results format media type: {}",
+                format
+            )
+        })
+    } else {
+        QueryResultsFormat::from_extension(format).ok_or_else(|| {
+            format_err!(
+                "Not supported SPARQL query results format extension: {}",
+                format
+            )
+        })
+    }
+}
+
+/// Exposes the chunks sent from the JS thread as a blocking [`Read`] implementation.
+///
+/// The input ends when all the senders are dropped.
+pub struct ChannelReader {
+    receiver: Receiver<Vec<u8>>,
+    current: Vec<u8>,
+    position: usize,
+    aborted: Arc<AtomicBool>,
+}
+
+impl ChannelReader {
+    pub fn new(receiver: Receiver<Vec<u8>>, aborted: Arc<AtomicBool>) -> Self {
+        Self {
+            receiver,
+            current: Vec::new(),
+            position: 0,
+            aborted,
+        }
+    }
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.current.len() {
+            let Ok(chunk) = self.receiver.recv() else {
+                break;
+            };
+            self.current = chunk;
+            self.position = 0;
+        }
+        if self.aborted.load(Ordering::Relaxed) {
+            return Err(io::Error::other("The load has been aborted"));
+        }
+        let chunk = &self.current[self.position..];
+        let len = chunk.len().min(buf.len());
+        buf[..len].copy_from_slice(&chunk[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
@@ -0,0 +1,4 @@
+mod io;
+mod model;
+mod store;
+mod utils;
@@ -0,0 +1,704 @@
+use crate::format_err;
+use crate::utils::to_err;
+use napi::bindgen_prelude::*;
+use napi::{check_status, sys};
+use napi_derive::napi;
+#[cfg(feature = "rdf-12")]
+use oxigraph::model::BaseDirection;
+use oxigraph::model::*;
+use std::ffi::CString;
+use std::ptr;
+
+#[napi(js_name = "namedNode")]
+pub fn named_node(value: String) -> Result<JsNamedNode> {
+    Ok(NamedNode::new(value).map_err(to_err)?.into())
+}
+
+#[napi(js_name = "blankNode")]
+pub fn blank_node(value: Option<String>) -> Result<JsBlankNode> {
+    Ok(if let Some(value) = value {
+        BlankNode::new(value).map_err(to_err)?
+    } else {
+        BlankNode::default()
+    }
+    .into())
+}
+
+#[napi(
+    ts_args_type = "value?: string, languageOrDatatype?: string | NamedNode | {language: string, direction?: \"ltr\" | \"rtl\"}"
+)]
+pub fn literal(
+    value: Option<String>,
+    language_or_datatype: Option<LanguageOrDatatype>,
+) -> Result<JsLiteral> {
+    let value = value.unwrap_or_default();
+    Ok(match language_or_datatype {
+        None => Literal::new_simple_literal(value),
+        Some(LanguageOrDatatype::Language {
+            language,
+            direction: None,
+        }) => Literal::new_language_tagged_literal(value, language).map_err(to_err)?,
+        #[cfg(feature = "rdf-12")]
+        Some(LanguageOrDatatype::Language {
+            language,
+            direction: Some(direction),
+        }) => Literal::new_directional_language_tagged_literal(
+            value,
+            language,
+            parse_direction(&direction)?,
+        )
+        .map_err(to_err)?,
+        #[cfg(not(feature = "rdf-12"))]
+        Some(LanguageOrDatatype::Language {
+            direction: Some(direction),
+            ..
+        }) => {
+            return Err(format_err!(
+                "RDF 1.2 is not enabled, {direction} direction is not supported"
+            ));
+        }
+        Some(LanguageOrDatatype::Datatype(datatype)) => Literal::new_typed_literal(value, datatype),
+    }
+    .into())
+}
+
+#[napi(js_name = "defaultGraph")]
+pub fn default_graph() -> JsDefaultGraph {
+    JsDefaultGraph {}
+}
+
+#[napi]
+pub fn variable(value: String) -> Result<JsVariable> {
+    Ok(Variable::new(value).map_err(to_err)?.into())
+}
+
+#[napi(ts_args_type = "subject: Term, predicate: Term, object: Term")]
+pub fn triple(subject: JsTerm, predicate: JsTerm, object: JsTerm) -> Result<JsQuad> {
+    quad(subject, predicate, object, None)
+}
+
+#[napi(ts_args_type = "subject: Term, predicate: Term, object: Term, graph?: Term")]
+pub fn quad(
+    subject: JsTerm,
+    predicate: JsTerm,
+    object: JsTerm,
+    graph: Option<JsTerm>,
+) -> Result<JsQuad> {
+    Ok(Quad::new(
+        NamedOrBlankNode::try_from(subject)?,
+        NamedNode::try_from(predicate)?,
+        Term::try_from(object)?,
+        if let Some(graph) = graph {
+            GraphName::try_from(graph)?
+        } else {
+            GraphName::DefaultGraph
+        },
+    )
+    .into())
+}
+
+#[napi(js_name = "NamedNode")]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct JsNamedNode {
+    inner: NamedNode,
+}
+
+#[napi]
+impl JsNamedNode {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "NamedNode"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> String {
+        self.inner.as_str().to_owned()
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::NamedNode(other) if other == self.inner)
+    }
+}
+
+impl From<NamedNode> for JsNamedNode {
+    fn from(inner: NamedNode) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<JsNamedNode> for NamedNode {
+    fn from(node: JsNamedNode) -> Self {
+        node.inner
+    }
+}
+
+#[napi(js_name = "BlankNode")]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct JsBlankNode {
+    inner: BlankNode,
+}
+
+#[napi]
+impl JsBlankNode {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "BlankNode"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> String {
+        self.inner.as_str().to_owned()
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::BlankNode(other) if other == self.inner)
+    }
+}
+
+impl From<BlankNode> for JsBlankNode {
+    fn from(inner: BlankNode) -> Self {
+        Self { inner }
+    }
+}
+
+#[napi(js_name = "Literal")]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct JsLiteral {
+    inner: Literal,
+}
+
+#[napi]
+impl JsLiteral {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "Literal"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> String {
+        self.inner.value().to_owned()
+    }
+
+    #[napi(getter)]
+    pub fn language(&self) -> String {
+        self.inner.language().unwrap_or("").to_owned()
+    }
+
+    #[napi(getter, ts_return_type = "\"ltr\" | \"rtl\" | \"\"")]
+    pub fn direction(&self) -> &'static str {
+        #[cfg(feature = "rdf-12")]
+        if let Some(direction) = self.inner.direction() {
+            return match direction {
+                BaseDirection::Ltr => "ltr",
+                BaseDirection::Rtl => "rtl",
+            };
+        }
+        ""
+    }
+
+    #[napi(getter)]
+    pub fn datatype(&self) -> JsNamedNode {
+        self.inner.datatype().into_owned().into()
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::Literal(other) if other == self.inner)
+    }
+}
+
+impl From<Literal> for JsLiteral {
+    fn from(inner: Literal) -> Self {
+        Self { inner }
+    }
+}
+
+#[napi(js_name = "DefaultGraph")]
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub struct JsDefaultGraph {}
+
+#[napi]
+impl JsDefaultGraph {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "DefaultGraph"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> &'static str {
+        ""
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        "DEFAULT".to_owned()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::DefaultGraph)
+    }
+}
+
+#[napi(js_name = "Variable")]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct JsVariable {
+    inner: Variable,
+}
+
+#[napi]
+impl JsVariable {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "Variable"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> String {
+        self.inner.as_str().to_owned()
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::Variable(other) if other == self.inner)
+    }
+}
+
+impl From<Variable> for JsVariable {
+    fn from(inner: Variable) -> Self {
+        Self { inner }
+    }
+}
+
+#[napi(js_name = "Quad")]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct JsQuad {
+    inner: Quad,
+}
+
+#[napi]
+impl JsQuad {
+    #[napi(getter, js_name = "termType")]
+    pub fn term_type(&self) -> &'static str {
+        "Quad"
+    }
+
+    #[napi(getter)]
+    pub fn value(&self) -> &'static str {
+        ""
+    }
+
+    #[napi(getter, ts_return_type = "NamedNode | BlankNode")]
+    pub fn subject(&self) -> JsTerm {
+        self.inner.subject.clone().into()
+    }
+
+    #[napi(getter, ts_return_type = "NamedNode")]
+    pub fn predicate(&self) -> JsTerm {
+        self.inner.predicate.clone().into()
+    }
+
+    #[napi(getter, ts_return_type = "NamedNode | BlankNode | Literal | Quad")]
+    pub fn object(&self) -> JsTerm {
+        self.inner.object.clone().into()
+    }
+
+    #[napi(getter, ts_return_type = "NamedNode | BlankNode | DefaultGraph")]
+    pub fn graph(&self) -> JsTerm {
+        self.inner.graph_name.clone().into()
+    }
+
+    #[napi(js_name = "toString")]
+    #[expect(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[napi(ts_args_type = "other: Term")]
+    pub fn equals(&self, other: JsTerm) -> bool {
+        matches!(other, JsTerm::Quad(other) if *other == self.inner)
+    }
+}
+
+impl From<Quad> for JsQuad {
+    fn from(inner: Quad) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<JsQuad> for Quad {
+    fn from(quad: JsQuad) -> Self {
+        quad.inner
+    }
+}
+
+/// Any RDF/JS term, built from a native class instance or from an object following the RDF/JS data model
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub enum JsTerm {
+    NamedNode(NamedNode),
+    BlankNode(BlankNode),
+    Literal(Literal),
+    DefaultGraph,
+    Variable(Variable),
+    Quad(Box<Quad>),
+}
+
+impl TypeName for JsTerm {
+    fn type_name() -> &'static str {
+        "Term"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Object
+    }
+}
+
+impl ValidateNapiValue for JsTerm {}
+
+impl ToNapiValue for JsTerm {
+    unsafe fn to_napi_value(env: sys::napi_env, value: Self) -> Result<sys::napi_value> {
+        unsafe {
+            match value {
+                Self::NamedNode(inner) => JsNamedNode::to_napi_value(env, JsNamedNode { inner }),
+                Self::BlankNode(inner) => JsBlankNode::to_napi_value(env, JsBlankNode { inner }),
+                Self::Literal(inner) => JsLiteral::to_napi_value(env, JsLiteral { inner }),
+                Self::DefaultGraph => JsDefaultGraph::to_napi_value(env, JsDefaultGraph {}),
+                Self::Variable(inner) => JsVariable::to_napi_value(env, JsVariable { inner }),
+                Self::Quad(inner) => JsQuad::to_napi_value(env, JsQuad { inner: *inner }),
+            }
+        }
+    }
+}
+
+impl FromNapiValue for JsTerm {
+    unsafe fn from_napi_value(env: sys::napi_env, value: sys::napi_value) -> Result<Self> {
+        if unsafe { type_of(env, value)? } != sys::ValueType::napi_object {
+            return Err(format_err!("RDF/JS terms must be objects"));
+        }
+        let term_type = unsafe { get_property::<String>(env, value, "termType")? }
+            .ok_or_else(|| format_err!("The object does not have a termType field"))?;
+        let term_value = || {
+            unsafe { get_property::<String>(env, value, "value") }?
+                .ok_or_else(|| format_err!("The {term_type} does not have a string value field"))
+        };
+        Ok(match term_type.as_str() {
+            "NamedNode" => Self::NamedNode(NamedNode::new(term_value()?).map_err(to_err)?),
+            "BlankNode" => Self::BlankNode(BlankNode::new(term_value()?).map_err(to_err)?),
+            "Literal" => {
+                let literal_value = term_value()?;
+                let datatype = unsafe { get_property::<Self>(env, value, "datatype")? };
+                let language = unsafe { get_property::<String>(env, value, "language")? }
+                    .filter(|language| !language.is_empty());
+                Self::Literal(if let Some(language) = language {
+                    #[cfg(feature = "rdf-12")]
+                    if let Some(direction) =
+                        unsafe { get_property::<String>(env, value, "direction")? }
+                            .filter(|direction| !direction.is_empty())
+                    {
+                        return Ok(Self::Literal(
+                            Literal::new_directional_language_tagged_literal(
+                                literal_value,
+                                language,
+                                parse_direction(&direction)?,
+                            )
+                            .map_err(to_err)?,
+                        ));
+                    }
+                    Literal::new_language_tagged_literal(literal_value, language).map_err(to_err)?
+                } else if let Some(datatype) = datatype {
+                    Literal::new_typed_literal(literal_value, NamedNode::try_from(datatype)?)
+                } else {
+                    Literal::new_simple_literal(literal_value)
+                })
+            }
+            "DefaultGraph" => Self::DefaultGraph,
+            "Variable" => Self::Variable(Variable::new(term_value()?).map_err(to_err)?),
+            "Quad" => {
+                let component = |name: &str| {
+                    unsafe { get_property::<Self>(env, value, name) }?
+                        .ok_or_else(|| format_err!("Quad has no {name} field"))
+                };
+                Self::Quad(Box::new(Quad::new(
+                    NamedOrBlankNode::try_from(component("subject")?)?,
+                    NamedNode::try_from(component("predicate")?)?,
+                    Term::try_from(component("object")?)?,
+                    if let Some(graph) = unsafe { get_property::<Self>(env, value, "graph")? } {
+                        GraphName::try_from(graph)?
+                    } else {
+                        GraphName::DefaultGraph
+                    },
+                )))
+            }
+            _ => return Err(format_err!("Unexpected term type: {term_type}")),
+        })
+    }
+}
+
+impl From<NamedNode> for JsTerm {
+    fn from(node: NamedNode) -> Self {
+        Self::NamedNode(node)
+    }
+}
+
+impl From<BlankNode> for JsTerm {
+    fn from(node: BlankNode) -> Self {
+        Self::BlankNode(node)
+    }
+}
+
+impl From<Literal> for JsTerm {
+    fn from(literal: Literal) -> Self {
+        Self::Literal(literal)
+    }
+}
+
+impl From<NamedOrBlankNode> for JsTerm {
+    fn from(node: NamedOrBlankNode) -> Self {
+        match node {
+            NamedOrBlankNode::NamedNode(node) => node.into(),
+            NamedOrBlankNode::BlankNode(node) => node.into(),
+        }
+    }
+}
+
+impl From<Term> for JsTerm {
+    fn from(term: Term) -> Self {
+        match term {
+            Term::NamedNode(node) => node.into(),
+            Term::BlankNode(node) => node.into(),
+            Term::Literal(literal) => literal.into(),
+            #[cfg(feature = "rdf-12")]
+            Term::Triple(triple) => Self::Quad(Box::new(triple.in_graph(GraphName::DefaultGraph))),
+        }
+    }
+}
+
+impl From<GraphName> for JsTerm {
+    fn from(name: GraphName) -> Self {
+        match name {
+            GraphName::NamedNode(node) => node.into(),
+            GraphName::BlankNode(node) => node.into(),
+            GraphName::DefaultGraph => Self::DefaultGraph,
+        }
+    }
+}
+
+impl From<Quad> for JsTerm {
+    fn from(quad: Quad) -> Self {
+        Self::Quad(Box::new(quad))
+    }
+}
+
+impl TryFrom<JsTerm> for NamedNode {
+    type Error = Error;
+
+    fn try_from(value: JsTerm) -> Result<Self> {
+        match value {
+            JsTerm::NamedNode(node) => Ok(node),
+            _ => Err(format_err!("{} is not a named node", display_term(&value))),
+        }
+    }
+}
+
+impl TryFrom<JsTerm> for NamedOrBlankNode {
+    type Error = Error;
+
+    fn try_from(value: JsTerm) -> Result<Self> {
+        match value {
+            JsTerm::NamedNode(node) => Ok(node.into()),
+            JsTerm::BlankNode(node) => Ok(node.into()),
+            _ => Err(format_err!(
+                "{} is not a named or blank node",
+                display_term(&value)
+            )),
+        }
+    }
+}
+
+impl TryFrom<JsTerm> for Term {
+    type Error = Error;
+
+    fn try_from(value: JsTerm) -> Result<Self> {
+        match value {
+            JsTerm::NamedNode(node) => Ok(node.into()),
+            JsTerm::BlankNode(node) => Ok(node.into()),
+            JsTerm::Literal(literal) => Ok(literal.into()),
+            #[cfg(feature = "rdf-12")]
+            JsTerm::Quad(quad) if quad.graph_name.is_default_graph() => {
+                Ok(Triple::from(*quad).into())
+            }
+            _ => Err(format_err!(
+                "{} is not a named node, blank node, literal or triple",
+                display_term(&value)
+            )),
+        }
+    }
+}
+
+impl TryFrom<JsTerm> for GraphName {
+    type Error = Error;
+
+    fn try_from(value: JsTerm) -> Result<Self> {
+        match value {
+            JsTerm::NamedNode(node) => Ok(node.into()),
+            JsTerm::BlankNode(node) => Ok(node.into()),
+            JsTerm::DefaultGraph => Ok(Self::DefaultGraph),
+            _ => Err(format_err!(
+                "{} is not a possible graph name",
+                display_term(&value)
+            )),
+        }
+    }
+}
+
+impl TryFrom<JsTerm> for Quad {
+    type Error = Error;
+
+    fn try_from(value: JsTerm) -> Result<Self> {
+        match value {
+            JsTerm::Quad(quad) => Ok(*quad),
+            _ => Err(format_err!("{} is not a quad", display_term(&value))),
+        }
+    }
+}
+
+fn display_term(term: &JsTerm) -> String {
+    match term {
+        JsTerm::NamedNode(node) => node.to_string(),
+        JsTerm::BlankNode(node) => node.to_string(),
+        JsTerm::Literal(literal) => literal.to_string(),
+        JsTerm::DefaultGraph => "DEFAULT".to_owned(),
+        JsTerm::Variable(variable) => variable.to_string(),
+        JsTerm::Quad(quad) => quad.to_string(),
+    }
+}
+
+/// The second argument of the `literal` factory
+pub enum LanguageOrDatatype {
+    Language {
+        language: String,
+        direction: Option<String>,
+    },
+    Datatype(NamedNode),
+}
+
+impl TypeName for LanguageOrDatatype {
+    fn type_name() -> &'static str {
+        "LanguageOrDatatype"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Unknown
+    }
+}
+
+impl ValidateNapiValue for LanguageOrDatatype {}
+
+impl FromNapiValue for LanguageOrDatatype {
+    unsafe fn from_napi_value(env: sys::napi_env, value: sys::napi_value) -> Result<Self> {
+        if unsafe { type_of(env, value)? } == sys::ValueType::napi_string {
+            return Ok(Self::Language {
+                language: unsafe { String::from_napi_value(env, value)? },
+                direction: None,
+            });
+        }
+        if let Some(language) = unsafe { get_property::<String>(env, value, "language")? } {
+            return Ok(Self::Language {
+                language,
+                direction: unsafe { get_property::<String>(env, value, "direction")? },
+            });
+        }
+        match unsafe { JsTerm::from_napi_value(env, value)? } {
+            JsTerm::NamedNode(datatype) => Ok(Self::Datatype(datatype)),
+            _ => Err(format_err!("The literal datatype should be a NamedNode")),
+        }
+    }
+}
+
+/// A base IRI given either as a string or as a `NamedNode`
+pub struct BaseIri(pub String);
+
+impl TypeName for BaseIri {
+    fn type_name() -> &'static str {
+        "BaseIri"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Unknown
+    }
+}
+
+impl ValidateNapiValue for BaseIri {}
+
+impl FromNapiValue for BaseIri {
+    unsafe fn from_napi_value(env: sys::napi_env, value: sys::napi_value) -> Result<Self> {
+        if unsafe { type_of(env, value)? } == sys::ValueType::napi_string {
+            return Ok(Self(unsafe { String::from_napi_value(env, value)? }));
+        }
+        if let Ok(JsTerm::NamedNode(value)) = unsafe { JsTerm::from_napi_value(env, value) } {
+            Ok(Self(value.into_string()))
+        } else {
+            Err(format_err!(
+                "If provided, the base IRI must be a NamedNode or a string"
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "rdf-12")]
+fn parse_direction(direction: &str) -> Result<BaseDirection> {
+    match direction {
+        "ltr" => Ok(BaseDirection::Ltr),
+        "rtl" => Ok(BaseDirection::Rtl),
+        _ => Err(format_err!("Invalid direction: {direction}")),
+    }
+}
+
+unsafe fn type_of(env: sys::napi_env, value: sys::napi_value) -> Result<sys::napi_valuetype> {
+    let mut value_type = sys::ValueType::napi_undefined;
+    check_status!(unsafe { sys::napi_typeof(env, value, &mut value_type) })?;
+    Ok(value_type)
+}
+
+/// Reads an object property, returning `None` if it is `undefined` or `null`
+unsafe fn get_property<T: FromNapiValue>(
+    env: sys::napi_env,
+    object: sys::napi_value,
+    name: &str,
+) -> Result<Option<T>> {
+    let name = CString::new(name).map_err(to_err)?;
+    let mut value = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_named_property(env, object, name.as_ptr(), &mut value) })?;
+    let value_type = unsafe { type_of(env, value)? };
+    if value_type == sys::ValueType::napi_undefined || value_type == sys::ValueType::napi_null {
+        return Ok(None);
+    }
+    unsafe { T::from_napi_value(env, value) }.map(Some)
+}
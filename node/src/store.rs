@@ -0,0 +1,787 @@
+use crate::format_err;
+use crate::io::{ChannelReader, query_results_format, rdf_format};
+use crate::model::{BaseIri, JsQuad, JsTerm};
+use crate::utils::to_err;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use oxigraph::io::{RdfFormat, RdfParser, RdfSerializer};
+use oxigraph::model::*;
+use oxigraph::sparql::results::QueryResultsSerializer;
+use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+use oxigraph::store::{SerializerError, Store};
+#[cfg(feature = "geosparql")]
+use spargeo::GEOSPARQL_EXTENSION_FUNCTIONS;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{SyncSender, sync_channel};
+use std::thread::{self, JoinHandle};
+
+/// Number of chunks buffered by a streaming load before `Loader.write` waits for the parser
+const LOADER_CHANNEL_CAPACITY: usize = 16;
+
+#[napi(object, object_to_js = false)]
+pub struct StoreOptions {
+    /// Directory of the on-disk store. An in-memory store is created if not set.
+    pub path: Option<String>,
+    /// Opens the store at `path` in read-only mode
+    #[napi(js_name = "read_only")]
+    pub read_only: Option<bool>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct QueryOptions {
+    #[napi(js_name = "base_iri", ts_type = "NamedNode | string")]
+    pub base_iri: Option<BaseIri>,
+    #[napi(js_name = "results_format")]
+    pub results_format: Option<String>,
+    #[napi(
+        js_name = "default_graph",
+        ts_type = "BlankNode | DefaultGraph | NamedNode | (BlankNode | DefaultGraph | NamedNode)[]"
+    )]
+    pub default_graph: Option<Either<Vec<JsTerm>, JsTerm>>,
+    #[napi(js_name = "named_graphs", ts_type = "(BlankNode | NamedNode)[]")]
+    pub named_graphs: Option<Vec<JsTerm>>,
+    #[napi(js_name = "use_default_graph_as_union")]
+    pub use_default_graph_as_union: Option<bool>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct UpdateOptions {
+    #[napi(js_name = "base_iri", ts_type = "NamedNode | string")]
+    pub base_iri: Option<BaseIri>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct LoadOptions {
+    pub format: String,
+    #[napi(js_name = "base_iri", ts_type = "NamedNode | string")]
+    pub base_iri: Option<BaseIri>,
+    #[napi(
+        js_name = "to_graph_name",
+        ts_type = "BlankNode | DefaultGraph | NamedNode"
+    )]
+    pub to_graph_name: Option<JsTerm>,
+    pub lenient: Option<bool>,
+    #[napi(js_name = "no_transaction")]
+    pub no_transaction: Option<bool>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct DumpOptions {
+    pub format: String,
+    #[napi(
+        js_name = "from_graph_name",
+        ts_type = "BlankNode | DefaultGraph | NamedNode"
+    )]
+    pub from_graph_name: Option<JsTerm>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct RenameGraphOptions {
+    pub overwrite: Option<bool>,
+}
+
+/// Raw query results, converted to the public shape by the JS wrapper
+#[napi(object, object_from_js = false)]
+pub struct JsQueryResults {
+    pub boolean: Option<bool>,
+    pub variables: Option<Vec<String>>,
+    #[napi(ts_type = "(Term | undefined)[][]")]
+    pub solutions: Option<Vec<Vec<Option<JsTerm>>>>,
+    pub quads: Option<Vec<JsQuad>>,
+    pub serialized: Option<String>,
+}
+
+#[napi(js_name = "Store")]
+pub struct JsStore {
+    store: Store,
+}
+
+#[napi]
+impl JsStore {
+    #[napi(constructor)]
+    pub fn new(options: Option<StoreOptions>) -> Result<Self> {
+        let options = options.unwrap_or(StoreOptions {
+            path: None,
+            read_only: None,
+        });
+        let store = match (options.path, options.read_only.unwrap_or(false)) {
+            (Some(path), false) => Store::open(path),
+            (Some(path), true) => Store::open_read_only(path),
+            (None, false) => Store::new(),
+            (None, true) => return Err(format_err!("A read-only store requires a path")),
+        }
+        .map_err(to_err)?;
+        Ok(Self { store })
+    }
+
+    #[napi(getter)]
+    pub fn size(&self) -> Result<i64> {
+        i64::try_from(self.store.len().map_err(to_err)?).map_err(to_err)
+    }
+
+    #[napi(ts_args_type = "quad: Quad")]
+    pub fn add(&self, quad: JsTerm) -> Result<()> {
+        self.store.insert(&Quad::try_from(quad)?).map_err(to_err)?;
+        Ok(())
+    }
+
+    #[napi(ts_args_type = "quad: Quad")]
+    pub fn delete(&self, quad: JsTerm) -> Result<()> {
+        self.store.remove(&Quad::try_from(quad)?).map_err(to_err)?;
+        Ok(())
+    }
+
+    #[napi(ts_args_type = "quad: Quad")]
+    pub fn has(&self, quad: JsTerm) -> Result<bool> {
+        self.store.contains(&Quad::try_from(quad)?).map_err(to_err)
+    }
+
+    /// Returns all the quads of the store
+    #[napi]
+    pub fn values(&self) -> Result<Vec<JsQuad>> {
+        self.store
+            .iter()
+            .map(|quad| quad.map(JsQuad::from))
+            .collect::<Result<_, _>>()
+            .map_err(to_err)
+    }
+
+    #[napi(
+        js_name = "match",
+        ts_args_type = "subject?: Term | null, predicate?: Term | null, object?: Term | null, graph?: Term | null"
+    )]
+    pub fn match_quads(
+        &self,
+        subject: Option<JsTerm>,
+        predicate: Option<JsTerm>,
+        object: Option<JsTerm>,
+        graph_name: Option<JsTerm>,
+    ) -> Result<Vec<JsQuad>> {
+        let subject = subject.map(NamedOrBlankNode::try_from).transpose()?;
+        let predicate = predicate.map(NamedNode::try_from).transpose()?;
+        let object = object.map(Term::try_from).transpose()?;
+        let graph_name = graph_name.map(GraphName::try_from).transpose()?;
+        self.store
+            .quads_for_pattern(
+                subject.as_ref().map(Into::into),
+                predicate.as_ref().map(Into::into),
+                object.as_ref().map(Into::into),
+                graph_name.as_ref().map(Into::into),
+            )
+            .map(|quad| quad.map(JsQuad::from))
+            .collect::<Result<_, _>>()
+            .map_err(to_err)
+    }
+
+    #[napi]
+    pub fn query(&self, query: String, options: Option<QueryOptions>) -> Result<JsQueryResults> {
+        QuerySettings::try_from(options)?.evaluate(&self.store, &query)
+    }
+
+    /// Evaluates the query on the libuv thread pool
+    #[napi(js_name = "queryAsync")]
+    pub fn query_async(
+        &self,
+        query: String,
+        options: Option<QueryOptions>,
+    ) -> Result<AsyncTask<QueryTask>> {
+        Ok(AsyncTask::new(QueryTask {
+            store: self.store.clone(),
+            query,
+            settings: QuerySettings::try_from(options)?,
+        }))
+    }
+
+    #[napi]
+    pub fn update(&self, update: String, options: Option<UpdateOptions>) -> Result<()> {
+        execute_update(&self.store, &update, base_iri(options))
+    }
+
+    /// Executes the update on the libuv thread pool
+    #[napi(js_name = "updateAsync")]
+    pub fn update_async(
+        &self,
+        update: String,
+        options: Option<UpdateOptions>,
+    ) -> AsyncTask<UpdateTask> {
+        AsyncTask::new(UpdateTask {
+            store: self.store.clone(),
+            update,
+            base_iri: base_iri(options),
+        })
+    }
+
+    #[napi]
+    pub fn load(&self, data: Either<String, Uint8Array>, options: LoadOptions) -> Result<()> {
+        LoadSettings::try_from(options)?.load(&self.store, &data_to_bytes(data))
+    }
+
+    /// Parses and loads the data on the libuv thread pool
+    #[napi(js_name = "loadAsync")]
+    pub fn load_async(
+        &self,
+        data: Either<String, Uint8Array>,
+        options: LoadOptions,
+    ) -> Result<AsyncTask<LoadTask>> {
+        Ok(AsyncTask::new(LoadTask {
+            store: self.store.clone(),
+            data: data_to_bytes(data),
+            settings: LoadSettings::try_from(options)?,
+        }))
+    }
+
+    /// Starts a streaming load, the data chunks being given with `Loader.write`.
+    ///
+    /// The quads are loaded with the bulk loader: the load is not atomic.
+    #[napi(js_name = "startLoad")]
+    pub fn start_load(&self, options: LoadOptions) -> Result<JsLoader> {
+        let parser = LoadSettings::try_from(options)?.parser()?;
+        let (sender, receiver) = sync_channel(LOADER_CHANNEL_CAPACITY);
+        let aborted = Arc::new(AtomicBool::new(false));
+        let reader = ChannelReader::new(receiver, Arc::clone(&aborted));
+        let store = self.store.clone();
+        // The parser blocks waiting for chunks so it gets its own thread instead of a libuv worker
+        let worker = thread::spawn(move || {
+            let mut loader = store.bulk_loader();
+            loader
+                .load_from_reader(parser, reader)
+                .map_err(|e| e.to_string())?;
+            loader.commit().map_err(|e| e.to_string())
+        });
+        Ok(JsLoader {
+            sender: Some(sender),
+            worker: Some(worker),
+            aborted,
+        })
+    }
+
+    #[napi]
+    pub fn dump(&self, options: DumpOptions) -> Result<String> {
+        DumpSettings::try_from(options)?.dump(&self.store)
+    }
+
+    /// Serializes the data on the libuv thread pool
+    #[napi(js_name = "dumpAsync")]
+    pub fn dump_async(&self, options: DumpOptions) -> Result<AsyncTask<DumpTask>> {
+        Ok(AsyncTask::new(DumpTask {
+            store: self.store.clone(),
+            settings: DumpSettings::try_from(options)?,
+        }))
+    }
+
+    #[napi(
+        js_name = "setObject",
+        ts_args_type = "subject: BlankNode | NamedNode, predicate: NamedNode, objects: Term[], graph?: BlankNode | DefaultGraph | NamedNode | null"
+    )]
+    pub fn set_object(
+        &self,
+        subject: JsTerm,
+        predicate: JsTerm,
+        objects: Vec<JsTerm>,
+        graph_name: Option<JsTerm>,
+    ) -> Result<Vec<JsTerm>> {
+        let subject = NamedOrBlankNode::try_from(subject)?;
+        let predicate = NamedNode::try_from(predicate)?;
+        let objects = objects
+            .into_iter()
+            .map(Term::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let graph_name = graph_name
+            .map(GraphName::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        Ok(self
+            .store
+            .set_object(&subject, &predicate, objects, &graph_name)
+            .map_err(to_err)?
+            .into_iter()
+            .map(JsTerm::from)
+            .collect())
+    }
+
+    #[napi(
+        js_name = "renameGraph",
+        ts_args_type = "from: BlankNode | DefaultGraph | NamedNode, to: BlankNode | DefaultGraph | NamedNode, options?: RenameGraphOptions"
+    )]
+    pub fn rename_graph(
+        &self,
+        from: JsTerm,
+        to: JsTerm,
+        options: Option<RenameGraphOptions>,
+    ) -> Result<bool> {
+        let from = GraphName::try_from(from)?;
+        let to = GraphName::try_from(to)?;
+        let overwrite = options.and_then(|o| o.overwrite).unwrap_or(false);
+        self.store
+            .rename_graph(&from, &to, overwrite)
+            .map_err(to_err)
+    }
+}
+
+/// A streaming load started by `Store.startLoad`
+#[napi(js_name = "Loader")]
+pub struct JsLoader {
+    sender: Option<SyncSender<Vec<u8>>>,
+    worker: Option<JoinHandle<std::result::Result<(), String>>>,
+    aborted: Arc<AtomicBool>,
+}
+
+#[napi]
+impl JsLoader {
+    /// Sends a chunk of data to the parser.
+    ///
+    /// The returned promise is resolved when the chunk has been buffered, making it possible to apply backpressure.
+    /// It resolves to `false` if the parser has stopped, the error being then returned by `end`.
+    #[napi]
+    pub fn write(&self, chunk: Either<String, Uint8Array>) -> Result<AsyncTask<WriteTask>> {
+        let sender = self
+            .sender
+            .clone()
+            .ok_or_else(|| format_err!("The loader has already been ended or aborted"))?;
+        Ok(AsyncTask::new(WriteTask {
+            sender,
+            chunk: Some(data_to_bytes(chunk)),
+        }))
+    }
+
+    /// Signals the end of the data.
+    ///
+    /// The returned promise is resolved when all the quads have been loaded.
+    #[napi]
+    pub fn end(&mut self) -> Result<AsyncTask<EndTask>> {
+        self.sender = None;
+        let worker = self
+            .worker
+            .take()
+            .ok_or_else(|| format_err!("The loader has already been ended"))?;
+        Ok(AsyncTask::new(EndTask {
+            worker: Some(worker),
+        }))
+    }
+
+    /// Stops the load. The quads already loaded are kept.
+    #[napi]
+    pub fn abort(&mut self) {
+        self.aborted.store(true, Ordering::Relaxed);
+        self.sender = None;
+    }
+}
+
+pub struct QueryTask {
+    store: Store,
+    query: String,
+    settings: QuerySettings,
+}
+
+#[napi]
+impl Task for QueryTask {
+    type Output = JsQueryResults;
+    type JsValue = JsQueryResults;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        self.settings.evaluate(&self.store, &self.query)
+    }
+
+    fn resolve(&mut self, _: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+pub struct UpdateTask {
+    store: Store,
+    update: String,
+    base_iri: Option<String>,
+}
+
+#[napi]
+impl Task for UpdateTask {
+    type Output = ();
+    type JsValue = ();
+
+    fn compute(&mut self) -> Result<()> {
+        execute_update(&self.store, &self.update, self.base_iri.take())
+    }
+
+    fn resolve(&mut self, _: Env, (): ()) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub struct LoadTask {
+    store: Store,
+    data: Vec<u8>,
+    settings: LoadSettings,
+}
+
+#[napi]
+impl Task for LoadTask {
+    type Output = ();
+    type JsValue = ();
+
+    fn compute(&mut self) -> Result<()> {
+        self.settings.load(&self.store, &self.data)
+    }
+
+    fn resolve(&mut self, _: Env, (): ()) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub struct DumpTask {
+    store: Store,
+    settings: DumpSettings,
+}
+
+#[napi]
+impl Task for DumpTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<String> {
+        self.settings.dump(&self.store)
+    }
+
+    fn resolve(&mut self, _: Env, output: String) -> Result<String> {
+        Ok(output)
+    }
+}
+
+pub struct WriteTask {
+    sender: SyncSender<Vec<u8>>,
+    chunk: Option<Vec<u8>>,
+}
+
+#[napi]
+impl Task for WriteTask {
+    type Output = bool;
+    type JsValue = bool;
+
+    fn compute(&mut self) -> Result<bool> {
+        let Some(chunk) = self.chunk.take() else {
+            return Ok(true);
+        };
+        // The parser thread only stops early on errors, these are reported by Loader.end
+        Ok(self.sender.send(chunk).is_ok())
+    }
+
+    fn resolve(&mut self, _: Env, output: bool) -> Result<bool> {
+        Ok(output)
+    }
+}
+
+pub struct EndTask {
+    worker: Option<JoinHandle<std::result::Result<(), String>>>,
+}
+
+#[napi]
+impl Task for EndTask {
+    type Output = ();
+    type JsValue = ();
+
+    fn compute(&mut self) -> Result<()> {
+        let Some(worker) = self.worker.take() else {
+            return Ok(());
+        };
+        worker
+            .join()
+            .map_err(|_| format_err!("The loader thread has panicked"))?
+            .map_err(Error::from_reason)
+    }
+
+    fn resolve(&mut self, _: Env, (): ()) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct QuerySettings {
+    base_iri: Option<String>,
+    results_format: Option<String>,
+    default_graph: Option<Vec<GraphName>>,
+    named_graphs: Option<Vec<NamedOrBlankNode>>,
+    use_default_graph_as_union: bool,
+}
+
+impl TryFrom<Option<QueryOptions>> for QuerySettings {
+    type Error = Error;
+
+    fn try_from(options: Option<QueryOptions>) -> Result<Self> {
+        let Some(options) = options else {
+            return Ok(Self {
+                base_iri: None,
+                results_format: None,
+                default_graph: None,
+                named_graphs: None,
+                use_default_graph_as_union: false,
+            });
+        };
+        Ok(Self {
+            base_iri: options.base_iri.map(|iri| iri.0),
+            results_format: options.results_format,
+            default_graph: options
+                .default_graph
+                .map(|default_graph| match default_graph {
+                    Either::A(graphs) => graphs.into_iter().map(GraphName::try_from).collect(),
+                    Either::B(graph) => Ok(vec![GraphName::try_from(graph)?]),
+                })
+                .transpose()?,
+            named_graphs: options
+                .named_graphs
+                .map(|graphs| {
+                    graphs
+                        .into_iter()
+                        .map(NamedOrBlankNode::try_from)
+                        .collect::<Result<_>>()
+                })
+                .transpose()?,
+            use_default_graph_as_union: options.use_default_graph_as_union.unwrap_or(false),
+        })
+    }
+}
+
+impl QuerySettings {
+    fn evaluate(&self, store: &Store, query: &str) -> Result<JsQueryResults> {
+        let mut prepared_query = evaluator(self.base_iri.clone())?
+            .parse_query(query)
+            .map_err(to_err)?;
+        if self.use_default_graph_as_union {
+            prepared_query.dataset_mut().set_default_graph_as_union();
+        }
+        if let Some(default_graph) = &self.default_graph {
+            prepared_query
+                .dataset_mut()
+                .set_default_graph(default_graph.clone());
+        }
+        if let Some(named_graphs) = &self.named_graphs {
+            prepared_query
+                .dataset_mut()
+                .set_available_named_graphs(named_graphs.clone());
+        }
+        let results = prepared_query.on_store(store).execute().map_err(to_err)?;
+
+        let mut output = JsQueryResults {
+            boolean: None,
+            variables: None,
+            solutions: None,
+            quads: None,
+            serialized: None,
+        };
+        match results {
+            QueryResults::Solutions(solutions) => {
+                if let Some(results_format) = &self.results_format {
+                    let mut serializer =
+                        QueryResultsSerializer::from_format(query_results_format(results_format)?)
+                            .serialize_solutions_to_writer(Vec::new(), solutions.variables().into())
+                            .map_err(to_err)?;
+                    for solution in solutions {
+                        serializer
+                            .serialize(&solution.map_err(to_err)?)
+                            .map_err(to_err)?;
+                    }
+                    output.serialized = Some(
+                        String::from_utf8(serializer.finish().map_err(to_err)?).map_err(to_err)?,
+                    );
+                } else {
+                    output.variables = Some(
+                        solutions
+                            .variables()
+                            .iter()
+                            .map(|v| v.as_str().to_owned())
+                            .collect(),
+                    );
+                    output.solutions = Some(
+                        solutions
+                            .map(|solution| {
+                                Ok(solution
+                                    .map_err(to_err)?
+                                    .values()
+                                    .iter()
+                                    .map(|value| value.clone().map(JsTerm::from))
+                                    .collect())
+                            })
+                            .collect::<Result<_>>()?,
+                    );
+                }
+            }
+            QueryResults::Graph(triples) => {
+                if let Some(results_format) = &self.results_format {
+                    let mut serializer = RdfSerializer::from_format(rdf_format(results_format)?)
+                        .for_writer(Vec::new());
+                    for triple in triples {
+                        serializer
+                            .serialize_triple(&triple.map_err(to_err)?)
+                            .map_err(to_err)?;
+                    }
+                    output.serialized = Some(
+                        String::from_utf8(serializer.finish().map_err(to_err)?).map_err(to_err)?,
+                    );
+                } else {
+                    output.quads = Some(
+                        triples
+                            .map(|triple| {
+                                Ok(triple
+                                    .map_err(to_err)?
+                                    .in_graph(GraphName::DefaultGraph)
+                                    .into())
+                            })
+                            .collect::<Result<_>>()?,
+                    );
+                }
+            }
+            QueryResults::Dataset(quads) => {
+                if let Some(results_format) = &self.results_format {
+                    let mut serializer = RdfSerializer::from_format(rdf_format(results_format)?)
+                        .for_writer(Vec::new());
+                    for quad in quads {
+                        serializer
+                            .serialize_quad(&quad.map_err(to_err)?)
+                            .map_err(to_err)?;
+                    }
+                    output.serialized = Some(
+                        String::from_utf8(serializer.finish().map_err(to_err)?).map_err(to_err)?,
+                    );
+                } else {
+                    output.quads = Some(
+                        quads
+                            .map(|quad| Ok(quad.map_err(to_err)?.into()))
+                            .collect::<Result<_>>()?,
+                    );
+                }
+            }
+            QueryResults::Boolean(b) => {
+                if let Some(results_format) = &self.results_format {
+                    output.serialized = Some(
+                        String::from_utf8(
+                            QueryResultsSerializer::from_format(query_results_format(
+                                results_format,
+                            )?)
+                            .serialize_boolean_to_writer(Vec::new(), b)
+                            .map_err(to_err)?,
+                        )
+                        .map_err(to_err)?,
+                    );
+                } else {
+                    output.boolean = Some(b);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+struct LoadSettings {
+    format: RdfFormat,
+    base_iri: Option<String>,
+    to_graph_name: Option<GraphName>,
+    lenient: bool,
+    no_transaction: bool,
+}
+
+impl TryFrom<LoadOptions> for LoadSettings {
+    type Error = Error;
+
+    fn try_from(options: LoadOptions) -> Result<Self> {
+        Ok(Self {
+            format: rdf_format(&options.format)?,
+            base_iri: options.base_iri.map(|iri| iri.0),
+            to_graph_name: options.to_graph_name.map(GraphName::try_from).transpose()?,
+            lenient: options.lenient.unwrap_or(false),
+            no_transaction: options.no_transaction.unwrap_or(false),
+        })
+    }
+}
+
+impl LoadSettings {
+    fn parser(&self) -> Result<RdfParser> {
+        let mut parser = RdfParser::from_format(self.format);
+        if let Some(to_graph_name) = &self.to_graph_name {
+            parser = parser.with_default_graph(to_graph_name.clone());
+        }
+        if let Some(base_iri) = &self.base_iri {
+            parser = parser.with_base_iri(base_iri.as_str()).map_err(to_err)?;
+        }
+        if self.lenient {
+            parser = parser.lenient();
+        }
+        Ok(parser)
+    }
+
+    fn load(&self, store: &Store, data: &[u8]) -> Result<()> {
+        let parser = self.parser()?;
+        if self.no_transaction {
+            let mut loader = store.bulk_loader();
+            loader.load_from_slice(parser, data).map_err(to_err)?;
+            loader.commit().map_err(to_err)
+        } else {
+            store.load_from_slice(parser, data).map_err(to_err)
+        }
+    }
+}
+
+struct DumpSettings {
+    format: RdfFormat,
+    from_graph_name: Option<GraphName>,
+}
+
+impl TryFrom<DumpOptions> for DumpSettings {
+    type Error = Error;
+
+    fn try_from(options: DumpOptions) -> Result<Self> {
+        let format = rdf_format(&options.format)?;
+        let from_graph_name = options
+            .from_graph_name
+            .map(GraphName::try_from)
+            .transpose()?;
+        if from_graph_name.is_none() && !format.supports_datasets() {
+            return Err(to_err(SerializerError::DatasetFormatExpected(format)));
+        }
+        Ok(Self {
+            format,
+            from_graph_name,
+        })
+    }
+}
+
+impl DumpSettings {
+    fn dump(&self, store: &Store) -> Result<String> {
+        let buffer = if let Some(from_graph_name) = &self.from_graph_name {
+            store.dump_graph_to_writer(from_graph_name, self.format, Vec::new())
+        } else {
+            store.dump_to_writer(self.format, Vec::new())
+        }
+        .map_err(to_err)?;
+        String::from_utf8(buffer).map_err(to_err)
+    }
+}
+
+fn execute_update(store: &Store, update: &str, base_iri: Option<String>) -> Result<()> {
+    evaluator(base_iri)?
+        .parse_update(update)
+        .map_err(to_err)?
+        .on_store(store)
+        .execute()
+        .map_err(to_err)
+}
+
+fn evaluator(base_iri: Option<String>) -> Result<SparqlEvaluator> {
+    let mut evaluator = SparqlEvaluator::new();
+    #[cfg(feature = "geosparql")]
+    for (name, implementation) in GEOSPARQL_EXTENSION_FUNCTIONS {
+        evaluator = evaluator.with_custom_function(name.into(), implementation)
+    }
+    if let Some(base_iri) = base_iri {
+        evaluator = evaluator.with_base_iri(base_iri).map_err(to_err)?;
+    }
+    Ok(evaluator)
+}
+
+fn base_iri(options: Option<UpdateOptions>) -> Option<String> {
+    options.and_then(|o| o.base_iri).map(|iri| iri.0)
+}
+
+fn data_to_bytes(data: Either<String, Uint8Array>) -> Vec<u8> {
+    match data {
+        Either::A(data) => data.into_bytes(),
+        Either::B(data) => data.to_vec(),
+    }
+}
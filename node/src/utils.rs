@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[macro_export]
+macro_rules! format_err {
+    ($msg:literal $(,)?) => {
+        ::napi::Error::from_reason(format!($msg))
+    };
+    ($fmt:literal, $($arg:tt)*) => {
+        ::napi::Error::from_reason(format!($fmt, $($arg)*))
+    };
+}
+
+/// Converts a Rust error into a JS `Error` with the same message
+pub fn to_err(error: impl fmt::Display) -> napi::Error {
+    napi::Error::from_reason(error.to_string())
+}
@@ -0,0 +1,150 @@
+import assert from "node:assert";
+import { mkdtempSync, rmSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { Readable } from "node:stream";
+// @ts-expect-error
+import dataModel from "@rdfjs/data-model";
+import { afterEach, describe, it } from "vitest";
+import * as oxigraph from "../index.js";
+import { Store } from "../index.js";
+
+const ex = dataModel.namedNode("http://example.com");
+const ex2 = dataModel.namedNode("http://example.com/2");
+
+describe("model", () => {
+    it("terms should follow the RDF/JS data model", () => {
+        const literal = oxigraph.literal("foo", "en");
+        assert.strictEqual(literal.termType, "Literal");
+        assert.strictEqual(literal.value, "foo");
+        assert.strictEqual(literal.language, "en");
+        assert.strictEqual(literal.datatype.value, "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString");
+        assert(oxigraph.namedNode("http://example.com").equals(ex));
+        assert(!oxigraph.namedNode("http://example.com").equals(ex2));
+        assert.strictEqual(oxigraph.defaultGraph().toString(), "DEFAULT");
+    });
+
+    it("quads should accept RDF/JS terms from other libraries", () => {
+        const quad = oxigraph.quad(ex, ex, dataModel.literal("1", ex2), ex2);
+        assert.strictEqual(quad.object.termType, "Literal");
+        assert(quad.graph.equals(ex2));
+    });
+});
+
+describe("Store", () => {
+    const directories: string[] = [];
+    afterEach(() => {
+        for (const directory of directories.splice(0)) {
+            rmSync(directory, { recursive: true, force: true });
+        }
+    });
+    function tempDirectory(): string {
+        const directory = mkdtempSync(join(tmpdir(), "oxigraph-"));
+        directories.push(directory);
+        return directory;
+    }
+
+    it("add, has and delete", () => {
+        const store = new Store();
+        store.add(dataModel.quad(ex, ex, ex2, ex));
+        assert(store.has(dataModel.quad(ex, ex, ex2, ex)));
+        assert.strictEqual(store.size, 1);
+        assert.strictEqual([...store].length, 1);
+        store.delete(dataModel.quad(ex, ex, ex2, ex));
+        assert(!store.has(dataModel.quad(ex, ex, ex2, ex)));
+    });
+
+    it("match", () => {
+        const store = new Store();
+        store.add(dataModel.quad(ex, ex, ex2));
+        store.add(dataModel.quad(ex2, ex, ex));
+        assert.strictEqual(store.match(ex2).length, 1);
+        assert.strictEqual(store.match(null, ex).length, 2);
+    });
+
+    it("should persist data on disk", () => {
+        const path = tempDirectory();
+        const store = new Store({ path });
+        store.add(dataModel.quad(ex, ex, ex2));
+        assert.strictEqual(new Store({ path: path, read_only: true }).size, 1);
+    });
+
+    it("query", () => {
+        const store = new Store();
+        store.add(dataModel.quad(ex, ex, ex2));
+        const results = store.query("SELECT ?s ?o WHERE { ?s ?p ?o OPTIONAL { ?o ?p ?o2 } }");
+        assert(Array.isArray(results));
+        const solution = results[0] as Map<string, oxigraph.Term>;
+        assert(solution.get("s")?.equals(ex));
+        assert(solution.get("o")?.equals(ex2));
+        assert.strictEqual(store.query("ASK { ?s ?p ?o }"), true);
+        assert.strictEqual(
+            store.query("CONSTRUCT { ?s ?p ?o } WHERE { ?s ?p ?o }", { results_format: "nt" }),
+            "<http://example.com> <http://example.com> <http://example.com/2> .\n",
+        );
+    });
+
+    it("async methods", async () => {
+        const store = new Store();
+        await store.loadAsync("<http://example.com> <http://example.com> <http://example.com/2> .", {
+            format: "nt",
+        });
+        await store.updateAsync("INSERT DATA { <http://example.com/2> <http://example.com> <http://example.com> }");
+        assert.strictEqual(await store.queryAsync("ASK { ?s ?p ?o }"), true);
+        assert.strictEqual(
+            await store.dumpAsync({ format: "nt", from_graph_name: oxigraph.defaultGraph() }),
+            store.dump({ format: "nt", from_graph_name: oxigraph.defaultGraph() }),
+        );
+        assert.strictEqual(store.size, 2);
+    });
+
+    it("load and dump", () => {
+        const store = new Store();
+        store.load(Buffer.from("<http://example.com> <http://example.com> <http://example.com/2> ."), {
+            format: "nt",
+            to_graph_name: ex,
+        });
+        assert(store.has(dataModel.quad(ex, ex, ex2, ex)));
+        assert.strictEqual(
+            store.dump({ format: "application/n-quads" }),
+            "<http://example.com> <http://example.com> <http://example.com/2> <http://example.com> .\n",
+        );
+    });
+
+    it("loadStream should load all the chunks", async () => {
+        const store = new Store();
+        await store.loadStream(
+            Readable.from([
+                Buffer.from("<http://example.com> <http://exa"),
+                "mple.com> <http://example.com/2> .\n",
+                "<http://example.com/2> <http://example.com> <http://example.com> .",
+            ]),
+            { format: "nt" },
+        );
+        assert.strictEqual(store.size, 2);
+    });
+
+    it("loadStream should report parse errors", async () => {
+        const store = new Store();
+        await assert.rejects(store.loadStream(Readable.from(["<foo"]), { format: "nt" }));
+    });
+
+    it("renameGraph", () => {
+        const store = new Store();
+        store.add(dataModel.quad(ex, ex, ex, ex));
+        assert(store.renameGraph(ex, ex2));
+        assert(store.has(dataModel.quad(ex, ex, ex, ex2)));
+    });
+
+    it("setObject", () => {
+        const store = new Store();
+        store.add(dataModel.quad(ex, ex, ex, ex2));
+        store.add(dataModel.quad(ex, ex, ex2, ex2));
+        const removed = store.setObject(ex, ex, new Set([ex2]), ex2);
+        assert.strictEqual(removed.length, 1);
+        assert(ex.equals(removed[0]));
+        assert.strictEqual(store.match(ex, ex, null, ex2).length, 1);
+        assert.strictEqual(store.setObject(ex, ex, [], ex2).length, 1);
+        assert.strictEqual(store.size, 0);
+    });
+});
@@ -1,8 +1,14 @@
+use crate::io::{
+    PyRdfFormatInput, PyReadable, PyReadableInput, PyWritable, PyWritableOutput, lookup_rdf_format,
+    map_parse_error,
+};
 use crate::model::{PyGraphNameRef, PyNamedNodeRef, PyNamedOrBlankNodeRef, PyQuad, PyTermRef};
+use oxigraph::io::DatasetExt;
 use oxigraph::model::dataset::{CanonicalizationAlgorithm, CanonicalizationHashAlgorithm, Dataset};
 use oxigraph::model::{Quad, QuadRef};
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyKeyError, PyValueError};
 use pyo3::prelude::*;
+use std::path::PathBuf;
 
 /// An in-memory `RDF dataset <https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-dataset>`_.
 ///
@@ -217,6 +223,89 @@ impl PyDataset {
         self.inner.canonicalize(algorithm.inner)
     }
 
+    /// Parses an RDF serialization into a new dataset.
+    ///
+    /// The triples of the graph formats are added to the default graph.
+    /// Use :py:func:`parse` to get more control on the parsing.
+    ///
+    /// :param input: The :py:class:`str`, :py:class:`bytes`, I/O object or :py:class:`os.PathLike` file path to read from. For example, it could be the file content as a string, a file reader opened in binary or text mode with ``open('my_file.ttl', 'rb')`` or a ``pathlib.Path('my_file.ttl')``.
+    /// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or os.PathLike[str] or None, optional
+    /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
+    /// :type format: RdfFormat or None, optional
+    /// :param path: The file path to read from. Replace the ``input`` parameter. Gzip compressed files with a ``.gz`` extension like ``my_file.ttl.gz`` are decompressed.
+    /// :type path: str or os.PathLike[str] or None, optional
+    /// :param base_iri: the base IRI used to resolve the relative IRIs in the file or :py:const:`None` if relative IRI resolution should not be done.
+    /// :type base_iri: str or None, optional
+    /// :return: the parsed dataset.
+    /// :rtype: Dataset
+    /// :raises ValueError: if the format is not supported.
+    /// :raises SyntaxError: if the provided data or the base IRI is invalid.
+    /// :raises OSError: if a system error happens while reading the file.
+    ///
+    /// >>> dataset = Dataset.parse('<g> { <s> <p> "1" }', RdfFormat.TRIG, base_iri="http://example.com/")
+    /// >>> list(dataset)
+    /// [<Quad subject=<NamedNode value=http://example.com/s> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<NamedNode value=http://example.com/g>>]
+    #[staticmethod]
+    #[pyo3(signature = (input = None, format = None, *, path = None, base_iri = None))]
+    fn parse(
+        input: Option<PyReadableInput>,
+        format: Option<PyRdfFormatInput>,
+        path: Option<PathBuf>,
+        base_iri: Option<&str>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
+        let (input, path) = PyReadable::from_args(path, input, py)?;
+        let format = lookup_rdf_format(format, path.as_deref())?;
+        py.detach(|| {
+            Ok(Self {
+                inner: Dataset::from_read(input, format, base_iri)
+                    .map_err(|e| map_parse_error(e, path))?,
+            })
+        })
+    }
+
+    /// Serializes the dataset.
+    ///
+    /// Use :py:func:`serialize` to get more control on the serialization.
+    ///
+    /// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+    /// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
+    /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
+    /// :type format: RdfFormat or None, optional
+    /// :return: :py:class:`bytes` with the serialization if the ``output`` parameter is :py:const:`None`, :py:const:`None` if ``output`` is set.
+    /// :rtype: bytes or None
+    /// :raises ValueError: if the format is not supported or if the dataset contains named graphs and the format only supports graphs.
+    /// :raises OSError: if a system error happens while writing the file.
+    ///
+    /// >>> Dataset([Quad(NamedNode('http://example.com/s'), NamedNode('http://example.com/p'), Literal('1'))]).serialize(format=RdfFormat.N_TRIPLES)
+    /// b'<http://example.com/s> <http://example.com/p> "1" .\n'
+    #[pyo3(signature = (output = None, format = None))]
+    fn serialize(
+        &self,
+        output: Option<PyWritableOutput>,
+        format: Option<PyRdfFormatInput>,
+        py: Python<'_>,
+    ) -> PyResult<Option<Vec<u8>>> {
+        PyWritable::do_write(
+            |output, file_path| {
+                let format = lookup_rdf_format(format, file_path.as_deref())?;
+                if !format.supports_datasets()
+                    && self
+                        .inner
+                        .iter()
+                        .any(|quad| !quad.graph_name.is_default_graph())
+                {
+                    return Err(PyValueError::new_err(format!(
+                        "The {format} format does not support named graphs"
+                    )));
+                }
+                py.detach(|| Ok(self.inner.write_to(output, format)?))
+            },
+            output,
+            py,
+        )
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
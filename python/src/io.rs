@@ -1,19 +1,21 @@
 use crate::model::{PyQuad, PyTriple};
 use oxigraph::io::{
-    JsonLdProfile, JsonLdProfileSet, RdfFormat, RdfParseError, RdfParser, RdfSerializer,
-    ReaderQuadParser,
+    ContentEncoding, DecodingReader, JsonLdProfile, JsonLdProfileSet, RdfFormat, RdfParseError,
+    RdfParser, RdfSerializer, ReaderQuadParser,
 };
-use oxigraph::model::QuadRef;
+use oxigraph::model::{Quad, QuadRef};
 use pyo3::exceptions::{PyDeprecationWarning, PySyntaxError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::pybacked::{PyBackedBytes, PyBackedStr};
+use pyo3::types::PyIterator;
 use std::cmp::max;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::ffi::{CString, OsStr};
 use std::fs::File;
 use std::io::{self, BufWriter, Cursor, Read, Write};
 use std::path::{Path, PathBuf};
+use std::str;
 use std::sync::OnceLock;
 
 /// Parses RDF graph and dataset serialization formats.
@@ -28,11 +30,11 @@ use std::sync::OnceLock;
 /// * `N3 <https://w3c.github.io/N3/spec/>`_ (:py:attr:`RdfFormat.N3`)
 /// * `RDF/XML <https://www.w3.org/TR/rdf-syntax-grammar/>`_ (:py:attr:`RdfFormat.RDF_XML`)
 ///
-/// :param input: The :py:class:`str`, :py:class:`bytes` or I/O object to read from. For example, it could be the file content as a string or a file reader opened in binary mode with ``open('my_file.ttl', 'rb')``.
-/// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or None, optional
+/// :param input: The :py:class:`str`, :py:class:`bytes`, I/O object or :py:class:`os.PathLike` file path to read from. For example, it could be the file content as a string, a file reader opened in binary or text mode with ``open('my_file.ttl', 'rb')`` or a ``pathlib.Path('my_file.ttl')``.
+/// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or os.PathLike[str] or None, optional
 /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
 /// :type format: RdfFormat or None, optional
-/// :param path: The file path to read from. Replace the ``input`` parameter.
+/// :param path: The file path to read from. Replace the ``input`` parameter. Gzip compressed files with a ``.gz`` extension like ``my_file.ttl.gz`` are decompressed.
 /// :type path: str or os.PathLike[str] or None, optional
 /// :param base_iri: the base IRI used to resolve the relative IRIs in the file or :py:const:`None` if relative IRI resolution should not be done.
 /// :type base_iri: str or None, optional
@@ -62,7 +64,7 @@ pub fn parse(
     lenient: bool,
     py: Python<'_>,
 ) -> PyResult<PyQuadParser> {
-    let input = PyReadable::from_args(&path, input, py)?;
+    let (input, path) = PyReadable::from_args(path, input, py)?;
     let format = lookup_rdf_format(format, path.as_deref())?;
     let mut parser = RdfParser::from_format(format);
     if let Some(base_iri) = base_iri {
@@ -80,8 +82,10 @@ pub fn parse(
         parser = parser.lenient();
     }
     Ok(PyQuadParser {
-        inner: parser.for_reader(input),
+        inner: Some(parser.for_reader(input)),
         file_path: path,
+        buffer: VecDeque::new(),
+        error: None,
     })
 }
 
@@ -99,8 +103,8 @@ pub fn parse(
 ///
 /// :param input: the RDF triples and quads to serialize.
 /// :type input: collections.abc.Iterable[Triple] or collections.abc.Iterable[Quad]
-/// :param output: The binary I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
-/// :type output: typing.IO[bytes] or str or os.PathLike[str] or None, optional
+/// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+/// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
 /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
 /// :type format: RdfFormat or None, optional
 /// :param prefixes: the prefixes used in the serialization if the format supports it.
@@ -176,6 +180,18 @@ pub fn serialize<'py>(
 
 /// An iterator of :py:class:`Quad` returned by :py:func:`parse`.
 ///
+/// The quads are parsed by chunks while the GIL is released.
+///
+/// It is also a context manager closing the parsed file on exit:
+///
+/// >>> import tempfile
+/// >>> with tempfile.NamedTemporaryFile(suffix='.nt') as file:
+/// ...     _ = file.write(b'<http://example.com/s> <http://example.com/p> "1" .')
+/// ...     file.flush()
+/// ...     with parse(path=file.name) as quads:
+/// ...         list(quads)
+/// [<Quad subject=<NamedNode value=http://example.com/s> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<DefaultGraph>>]
+///
 /// >>> store = Store()
 /// >>> store.add(Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1')))
 /// >>> quads = parse(input=b'<foo> <p> "1" .', format=RdfFormat.TURTLE, base_iri="http://example.com/")
@@ -183,10 +199,17 @@ pub fn serialize<'py>(
 /// <Quad subject=<NamedNode value=http://example.com/foo> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<DefaultGraph>>
 #[pyclass(name = "QuadParser", module = "pyoxigraph")]
 pub struct PyQuadParser {
-    inner: ReaderQuadParser<PyReadable>,
+    inner: Option<ReaderQuadParser<PyReadable>>,
     file_path: Option<PathBuf>,
+    /// Quads already parsed but not returned yet
+    buffer: VecDeque<Quad>,
+    /// Error to return after the buffered quads
+    error: Option<PyErr>,
 }
 
+/// Number of quads parsed at once while the GIL is released
+const PARSE_CHUNK_SIZE: usize = 1024;
+
 #[pymethods]
 impl PyQuadParser {
     /// The list of IRI prefixes considered at the current step of the parsing.
@@ -206,8 +229,8 @@ impl PyQuadParser {
     /// >>> quads.prefixes
     /// {'ex': 'http://example.com/'}
     #[getter]
-    pub fn prefixes(&self) -> BTreeMap<&str, &str> {
-        self.inner.prefixes().collect()
+    pub fn prefixes(&self) -> PyResult<BTreeMap<&str, &str>> {
+        Ok(self.open_parser()?.prefixes().collect())
     }
 
     /// The base IRI considered at the current step of the parsing.
@@ -222,8 +245,33 @@ impl PyQuadParser {
     /// >>> quads.base_iri
     /// 'http://example.com/'
     #[getter]
-    pub fn base_iri(&self) -> Option<&str> {
-        self.inner.base_iri()
+    pub fn base_iri(&self) -> PyResult<Option<&str>> {
+        Ok(self.open_parser()?.base_iri())
+    }
+
+    /// Closes the parser and the file it reads from.
+    ///
+    /// Any later use of the parser raises a :py:class:`ValueError`.
+    ///
+    /// :rtype: None
+    fn close(&mut self) {
+        self.inner = None;
+        self.buffer.clear();
+        self.error = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.open_parser()?;
+        Ok(slf)
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<Bound<'_, PyAny>>,
+        _exc_value: Option<Bound<'_, PyAny>>,
+        _traceback: Option<Bound<'_, PyAny>>,
+    ) {
+        self.close();
     }
 
     fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
@@ -231,17 +279,45 @@ impl PyQuadParser {
     }
 
     fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyQuad>> {
-        py.detach(|| {
-            Ok(self
-                .inner
-                .next()
-                .transpose()
-                .map_err(|e| map_parse_error(e, self.file_path.clone()))?
-                .map(PyQuad::from))
-        })
+        if self.buffer.is_empty() {
+            if let Some(error) = self.error.take() {
+                return Err(error);
+            }
+            let Some(parser) = &mut self.inner else {
+                return Err(closed_parser_error());
+            };
+            let file_path = &self.file_path;
+            let buffer = &mut self.buffer;
+            self.error = py.detach(|| {
+                for quad in parser.by_ref().take(PARSE_CHUNK_SIZE) {
+                    match quad {
+                        Ok(quad) => buffer.push_back(quad),
+                        Err(e) => return Some(map_parse_error(e, file_path.clone())),
+                    }
+                }
+                None
+            });
+            if self.buffer.is_empty() {
+                if let Some(error) = self.error.take() {
+                    return Err(error);
+                }
+            }
+        }
+        Ok(self.buffer.pop_front().map(PyQuad::from))
     }
 }
 
+#[expect(clippy::multiple_inherent_impl)]
+impl PyQuadParser {
+    fn open_parser(&self) -> PyResult<&ReaderQuadParser<PyReadable>> {
+        self.inner.as_ref().ok_or_else(closed_parser_error)
+    }
+}
+
+fn closed_parser_error() -> PyErr {
+    PyValueError::new_err("I/O operation on closed parser")
+}
+
 /// RDF serialization formats.
 ///
 /// The following formats are supported:
@@ -447,23 +523,37 @@ pub enum PyReadable {
     String(Cursor<PyBackedStr>),
     Bytes(Cursor<PyBackedBytes>),
     Io(PyIo),
-    File(File),
+    File(DecodingReader<File>),
 }
 
 impl PyReadable {
+    /// Opens the ``input`` or ``path`` parameters, ``input`` might also be an :py:class:`os.PathLike` object
+    ///
+    /// Returns the path of the opened file if any.
+    /// Compressed files are decompressed according to their extension.
     pub fn from_args(
-        path: &Option<PathBuf>,
+        path: Option<PathBuf>,
         input: Option<PyReadableInput>,
         py: Python<'_>,
-    ) -> PyResult<Self> {
+    ) -> PyResult<(Self, Option<PathBuf>)> {
         match (path, input) {
             (Some(_), Some(_)) => Err(PyValueError::new_err(
-                "input and file_path can't be both set at the same time",
+                "The input and path parameters can't be both set at the same time",
             )),
-            (Some(path), None) => Ok(Self::File(py.detach(|| File::open(path))?)),
-            (None, Some(input)) => Ok(input.into()),
+            (Some(path), None) | (None, Some(PyReadableInput::Path(path))) => {
+                let encoding = path_content_encoding(&path);
+                let file = py.detach(|| File::open(&path))?;
+                Ok((Self::File(DecodingReader::new(file, encoding)), Some(path)))
+            }
+            (None, Some(PyReadableInput::String(string))) => {
+                Ok((Self::String(Cursor::new(string)), None))
+            }
+            (None, Some(PyReadableInput::Bytes(bytes))) => {
+                Ok((Self::Bytes(Cursor::new(bytes)), None))
+            }
+            (None, Some(PyReadableInput::Io(io))) => Ok((Self::Io(PyIo(io)), None)),
             (None, None) => Err(PyValueError::new_err(
-                "Either input or file_path must be set",
+                "Either the input or the path parameter must be set",
             )),
         }
     }
@@ -480,19 +570,72 @@ impl Read for PyReadable {
     }
 }
 
+/// Reads the :py:class:`str` or :py:class:`bytes` chunks returned by a Python iterator
+pub struct PyIterableReader {
+    iter: Py<PyIterator>,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl PyIterableReader {
+    pub fn new(iter: Py<PyIterator>) -> Self {
+        Self {
+            iter,
+            chunk: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+impl Read for PyIterableReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.chunk.len() {
+            let Some(chunk) = Python::attach(|py| {
+                let Some(chunk) = self.iter.bind(py).clone().next().transpose()? else {
+                    return Ok::<_, PyErr>(None);
+                };
+                Ok(Some(if let Ok(bytes) = chunk.extract::<&[u8]>() {
+                    bytes.to_vec()
+                } else {
+                    chunk.extract::<PyBackedStr>()?.as_bytes().to_vec()
+                }))
+            })?
+            else {
+                return Ok(0);
+            };
+            self.chunk = chunk;
+            self.position = 0;
+        }
+        let len = buf.len().min(self.chunk.len() - self.position);
+        buf[..len].copy_from_slice(&self.chunk[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// The ``input`` parameter of parsing functions
+///
+/// :py:class:`str` and :py:class:`bytes` are the content to parse, :py:class:`os.PathLike` objects are file paths.
 #[derive(FromPyObject)]
 pub enum PyReadableInput {
     String(PyBackedStr),
     Bytes(PyBackedBytes),
+    Path(PathBuf),
     Io(Py<PyAny>),
 }
 
-impl From<PyReadableInput> for PyReadable {
-    fn from(input: PyReadableInput) -> Self {
-        match input {
-            PyReadableInput::String(string) => Self::String(Cursor::new(string)),
-            PyReadableInput::Bytes(bytes) => Self::Bytes(Cursor::new(bytes)),
-            PyReadableInput::Io(io) => Self::Io(PyIo(io)),
+impl PyReadableInput {
+    /// Moves an :py:class:`os.PathLike` ``input`` to the ``path`` parameter
+    pub fn split_path(
+        input: Option<Self>,
+        path: Option<PathBuf>,
+    ) -> PyResult<(Option<Self>, Option<PathBuf>)> {
+        match (input, path) {
+            (Some(Self::Path(_)), Some(_)) => Err(PyValueError::new_err(
+                "The input parameter is a file path, the path parameter can't be set at the same time",
+            )),
+            (Some(Self::Path(path)), None) => Ok((None, Some(path))),
+            (input, path) => Ok((input, path)),
         }
     }
 }
@@ -500,6 +643,7 @@ impl From<PyReadableInput> for PyReadable {
 pub enum PyWritable {
     Bytes(Vec<u8>),
     Io(PyIo),
+    TextIo(PyTextIo),
     File(File),
 }
 
@@ -510,11 +654,32 @@ impl PyWritable {
         py: Python<'_>,
     ) -> PyResult<Option<Vec<u8>>> {
         let (output, file_path) = match output {
-            Some(PyWritableOutput::Path(file_path)) => (
-                Self::File(py.detach(|| File::create(&file_path))?),
-                Some(file_path),
+            Some(PyWritableOutput::Path(file_path)) => {
+                if path_content_encoding(&file_path) != ContentEncoding::Identity {
+                    return Err(PyValueError::new_err(format!(
+                        "Writing compressed files is not supported, the output path {} has a compression extension",
+                        file_path.display()
+                    )));
+                }
+                (
+                    Self::File(py.detach(|| File::create(&file_path))?),
+                    Some(file_path),
+                )
+            }
+            Some(PyWritableOutput::Io(object)) => (
+                if object
+                    .bind(py)
+                    .is_instance(&py.import(intern!(py, "io"))?.getattr("TextIOBase")?)?
+                {
+                    Self::TextIo(PyTextIo {
+                        io: PyIo(object),
+                        incomplete: Vec::new(),
+                    })
+                } else {
+                    Self::Io(PyIo(object))
+                },
+                None,
             ),
-            Some(PyWritableOutput::Io(object)) => (Self::Io(PyIo(object)), None),
             None => (Self::Bytes(Vec::new()), None),
         };
         let serializer = write(BufWriter::new(output), file_path)?;
@@ -535,6 +700,15 @@ impl PyWritable {
                 py.detach(|| io.flush())?;
                 Ok(None)
             }
+            Self::TextIo(mut io) => {
+                if !io.incomplete.is_empty() {
+                    return Err(PyValueError::new_err(
+                        "The serialization is not valid UTF-8 and can't be written to a text I/O object",
+                    ));
+                }
+                py.detach(|| io.flush())?;
+                Ok(None)
+            }
         }
     }
 }
@@ -544,6 +718,7 @@ impl Write for PyWritable {
         match self {
             Self::Bytes(bytes) => bytes.write(buf),
             Self::Io(io) => io.write(buf),
+            Self::TextIo(io) => io.write(buf),
             Self::File(file) => file.write(buf),
         }
     }
@@ -552,6 +727,7 @@ impl Write for PyWritable {
         match self {
             Self::Bytes(_) => Ok(()),
             Self::Io(io) => io.flush(),
+            Self::TextIo(io) => io.flush(),
             Self::File(file) => file.flush(),
         }
     }
@@ -563,6 +739,38 @@ pub enum PyWritableOutput {
     Io(Py<PyAny>),
 }
 
+/// Writes into a text I/O object, decoding the written UTF-8 bytes on the fly
+pub struct PyTextIo {
+    io: PyIo,
+    /// The bytes of a character split between two writes
+    incomplete: Vec<u8>,
+}
+
+impl Write for PyTextIo {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.incomplete.extend_from_slice(buf);
+        let valid_len = match str::from_utf8(&self.incomplete) {
+            Ok(_) => self.incomplete.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let text = str::from_utf8(&self.incomplete[..valid_len]).map_err(io::Error::other)?;
+        Python::attach(|py| {
+            self.io
+                .0
+                .bind(py)
+                .call_method1(intern!(py, "write"), (text,))?;
+            Ok::<_, PyErr>(())
+        })?;
+        self.incomplete.drain(..valid_len);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.io.flush()
+    }
+}
+
 pub struct PyIo(Py<PyAny>);
 
 impl Read for PyIo {
@@ -631,16 +839,34 @@ pub fn lookup_rdf_format(
             "The format parameter is required when a file path is not given",
         ));
     };
-    let Some(ext) = path.extension().and_then(OsStr::to_str) else {
-        return Err(PyValueError::new_err(format!(
-            "The file name {} has no extension to guess a file format from",
-            path.display()
-        )));
-    };
+    let ext = format_extension(path)?;
     RdfFormat::from_extension(ext)
         .ok_or_else(|| PyValueError::new_err(format!("Not supported RDF format extension: {ext}")))
 }
 
+/// The file name extension to guess a format from, including the compression extension if any (e.g. ``ttl.gz``)
+pub fn format_extension(path: &Path) -> PyResult<&str> {
+    let name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+    match name.rsplitn(3, '.').collect::<Vec<_>>().as_slice() {
+        [ext, _, stem] if ContentEncoding::from_extension(ext).is_some() => {
+            Ok(&name[stem.len() + 1..])
+        }
+        [ext, stem, ..] if !stem.is_empty() => Ok(*ext),
+        _ => Err(PyValueError::new_err(format!(
+            "The file name {} has no extension to guess a file format from",
+            path.display()
+        ))),
+    }
+}
+
+/// The compression format of a file according to its extension
+pub fn path_content_encoding(path: &Path) -> ContentEncoding {
+    path.extension()
+        .and_then(OsStr::to_str)
+        .and_then(ContentEncoding::from_extension)
+        .unwrap_or(ContentEncoding::Identity)
+}
+
 #[derive(FromPyObject)]
 pub enum PyRdfFormatInput {
     Object(PyRdfFormat),
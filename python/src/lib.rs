@@ -23,6 +23,10 @@ pub mod pyoxigraph {
     #[expect(non_upper_case_globals)]
     #[pymodule_export]
     const __version__: &str = env!("CARGO_PKG_VERSION");
+    /// If the module has been built without debug assertions, used to only run the performance tests on release builds
+    #[expect(non_upper_case_globals)]
+    #[pymodule_export]
+    const _release_build: bool = !cfg!(debug_assertions);
     #[cfg(feature = "rdf-12")]
     #[pymodule_export]
     use super::PyBaseDirection;
@@ -1,8 +1,10 @@
 use crate::io::*;
 use crate::model::*;
 use crate::store::map_storage_error;
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
 use oxigraph::io::RdfSerializer;
 use oxigraph::model::Term;
+use oxigraph::sparql::results::arrow::ArrowRecordBatchIter;
 use oxigraph::sparql::results::{
     QueryResultsFormat, QueryResultsParseError, QueryResultsParser, QueryResultsSerializer,
     ReaderQueryResultsParserOutput, ReaderSolutionsParser,
@@ -16,14 +18,17 @@ use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::{PyRuntimeError, PySyntaxError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
-use pyo3::types::PyTuple;
+use pyo3::types::{PyCapsule, PyTuple};
 #[cfg(feature = "geosparql")]
 use spargeo::GEOSPARQL_EXTENSION_FUNCTIONS;
 use std::collections::HashMap;
 use std::error::Error;
-use std::ffi::OsStr;
+use std::ffi::CString;
 use std::io;
+use std::iter::empty;
+use std::mem::replace;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::vec::IntoIter;
 
 pub fn prepare_sparql_query(
@@ -188,6 +193,9 @@ pub fn query_results_to_python<'py>(
         }
         .into_bound_py_any(py),
         QueryResults::Boolean(inner) => PyQueryBoolean { inner }.into_bound_py_any(py),
+        QueryResults::Dataset(_) => Err(PyValueError::new_err(
+            "CONSTRUCT queries returning quads are not supported",
+        )),
     }
 }
 
@@ -315,6 +323,14 @@ struct UngilQuerySolutionIter(QuerySolutionIter<'static>);
 // SAFETY: To derive Ungil
 unsafe impl Send for UngilQuerySolutionIter {}
 
+impl Iterator for UngilQuerySolutionIter {
+    type Item = Result<QuerySolution, QueryEvaluationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
 #[pymethods]
 impl PyQuerySolutions {
     /// :return: the ordered list of all variables that could appear in the query results
@@ -347,8 +363,8 @@ impl PyQuerySolutions {
     /// * `CSV <https://www.w3.org/TR/sparql11-results-csv-tsv/>`_ (:py:attr:`QueryResultsFormat.CSV`)
     /// * `TSV <https://www.w3.org/TR/sparql11-results-csv-tsv/>`_ (:py:attr:`QueryResultsFormat.TSV`)
     ///
-    /// :param output: The binary I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
-    /// :type output: typing.IO[bytes] or str or os.PathLike[str] or None, optional
+    /// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+    /// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
     /// :param format: the format of the query results serialization. If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: QueryResultsFormat or None, optional
     /// :rtype: bytes or None
@@ -407,6 +423,80 @@ impl PyQuerySolutions {
         )
     }
 
+    /// Converts the solutions into a `pyarrow.Table <https://arrow.apache.org/docs/python/generated/pyarrow.Table.html>`_.
+    ///
+    /// It requires the `pyarrow <https://arrow.apache.org/docs/python/>`_ package to be installed.
+    ///
+    /// There is one column per variable.
+    /// Each RDF term is encoded as a struct with the following fields:
+    ///
+    /// * ``type``: the kind of term, one of ``named_node``, ``blank_node``, ``literal`` or ``triple``.
+    /// * ``value``: the IRI of named nodes, the identifier of blank nodes, the lexical form of literals and the N-Triples serialization of triples.
+    /// * ``datatype``: the datatype IRI of literals, null otherwise.
+    /// * ``language``: the language tag of language-tagged string literals, null otherwise.
+    /// * ``direction``: the base direction (``ltr`` or ``rtl``) of directional language-tagged string literals, null otherwise.
+    ///
+    /// Unbound values are nulls.
+    ///
+    /// :param batch_size: the maximal number of rows of each record batch of the table.
+    /// :type batch_size: int, optional
+    /// :rtype: pyarrow.Table
+    /// :raises ImportError: if pyarrow is not installed.
+    #[expect(clippy::wrong_self_convention)]
+    #[pyo3(signature = (batch_size = 10_000))]
+    fn to_arrow<'py>(&mut self, batch_size: usize, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let record_batch_reader = py.import("pyarrow")?.getattr("RecordBatchReader")?;
+        // The solutions are moved into the stream and are read by batches while pyarrow consumes it
+        let variables: Arc<[Variable]> = match &self.inner {
+            PyQuerySolutionsVariant::Query(inner) => inner.0.variables().into(),
+            PyQuerySolutionsVariant::Reader { iter, .. } => iter.variables().into(),
+        };
+        let stream = match replace(
+            &mut self.inner,
+            PyQuerySolutionsVariant::Query(UngilQuerySolutionIter(QuerySolutionIter::new(
+                Arc::clone(&variables),
+                empty(),
+            ))),
+        ) {
+            PyQuerySolutionsVariant::Query(inner) => solutions_to_arrow_stream(
+                &variables,
+                inner.map(|solution| solution.map_err(map_evaluation_error)),
+                batch_size,
+            ),
+            PyQuerySolutionsVariant::Reader { iter, file_path } => solutions_to_arrow_stream(
+                &variables,
+                iter.map(move |solution| {
+                    solution.map_err(|e| map_query_results_parse_error(e, file_path.clone()))
+                }),
+                batch_size,
+            ),
+        };
+        let capsule = PyCapsule::new(py, stream, Some(CString::from(c"arrow_array_stream")))?;
+        record_batch_reader
+            .call_method1("_import_from_c_capsule", (capsule,))?
+            .call_method0("read_all")
+    }
+
+    /// Converts the solutions into a `pandas.DataFrame <https://pandas.pydata.org/docs/reference/api/pandas.DataFrame.html>`_.
+    ///
+    /// It requires the `pyarrow <https://arrow.apache.org/docs/python/>`_ and `pandas <https://pandas.pydata.org/>`_ packages to be installed.
+    ///
+    /// The columns are built like in :py:meth:`to_arrow`: each RDF term is a :py:class:`dict` with the ``type``, ``value``, ``datatype``, ``language`` and ``direction`` keys and unbound values are :py:const:`None`.
+    ///
+    /// :param batch_size: the maximal number of rows of each intermediate Arrow record batch.
+    /// :type batch_size: int, optional
+    /// :rtype: pandas.DataFrame
+    /// :raises ImportError: if pyarrow or pandas are not installed.
+    #[expect(clippy::wrong_self_convention)]
+    #[pyo3(signature = (batch_size = 10_000))]
+    fn to_pandas<'py>(
+        &mut self,
+        batch_size: usize,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.to_arrow(batch_size, py)?.call_method0("to_pandas")
+    }
+
     fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
@@ -426,6 +516,16 @@ impl PyQuerySolutions {
     }
 }
 
+fn solutions_to_arrow_stream(
+    variables: &[Variable],
+    solutions: impl Iterator<Item = PyResult<QuerySolution>> + Send + 'static,
+    batch_size: usize,
+) -> FFI_ArrowArrayStream {
+    FFI_ArrowArrayStream::new(Box::new(ArrowRecordBatchIter::new(
+        variables, solutions, batch_size,
+    )))
+}
+
 /// A boolean returned by a SPARQL ``ASK`` query.
 ///
 /// It can be easily casted to a regular boolean using the :py:func:`bool` function.
@@ -451,8 +551,8 @@ impl PyQueryBoolean {
     /// * `CSV <https://www.w3.org/TR/sparql11-results-csv-tsv/>`_ (:py:attr:`QueryResultsFormat.CSV`)
     /// * `TSV <https://www.w3.org/TR/sparql11-results-csv-tsv/>`_ (:py:attr:`QueryResultsFormat.TSV`)
     ///
-    /// :param output: The binary I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
-    /// :type output: typing.IO[bytes] or str or os.PathLike[str] or None, optional
+    /// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+    /// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
     /// :param format: the format of the query results serialization. If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: QueryResultsFormat or None, optional
     /// :rtype: bytes or None
@@ -524,8 +624,8 @@ impl PyQueryTriples {
     /// * `N3 <https://w3c.github.io/N3/spec/>`_ (:py:attr:`RdfFormat.N3`)
     /// * `RDF/XML <https://www.w3.org/TR/rdf-syntax-grammar/>`_ (:py:attr:`RdfFormat.RDF_XML`)
     ///
-    /// :param output: The binary I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
-    /// :type output: typing.IO[bytes] or str or os.PathLike[str] or None, optional
+    /// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+    /// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
     /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: RdfFormat or None, optional
     /// :rtype: bytes or None
@@ -581,11 +681,11 @@ impl PyQueryTriples {
 /// * `JSON <https://www.w3.org/TR/sparql11-results-json/>`_ (:py:attr:`QueryResultsFormat.JSON`)
 /// * `TSV <https://www.w3.org/TR/sparql11-results-csv-tsv/>`_ (:py:attr:`QueryResultsFormat.TSV`)
 ///
-/// :param input: The :py:class:`str`, :py:class:`bytes` or I/O object to read from. For example, it could be the file content as a string or a file reader opened in binary mode with ``open('my_file.ttl', 'rb')``.
-/// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or None, optional
+/// :param input: The :py:class:`str`, :py:class:`bytes`, I/O object or :py:class:`os.PathLike` file path to read from. For example, it could be the file content as a string, a file reader opened in binary or text mode with ``open('my_file.ttl', 'rb')`` or a ``pathlib.Path('my_file.ttl')``.
+/// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or os.PathLike[str] or None, optional
 /// :param format: the format of the query results serialization. If :py:const:`None`, the format is guessed from the file name extension.
 /// :type format: QueryResultsFormat or None, optional
-/// :param path: The file path to read from. Replaces the ``input`` parameter.
+/// :param path: The file path to read from. Replaces the ``input`` parameter. Gzip compressed files with a ``.gz`` extension like ``my_file.ttl.gz`` are decompressed.
 /// :type path: str or os.PathLike[str] or None, optional
 /// :return: an iterator of :py:class:`QuerySolution` or a :py:class:`bool`.
 /// :rtype: QuerySolutions or QueryBoolean
@@ -606,7 +706,7 @@ pub fn parse_query_results(
     path: Option<PathBuf>,
     py: Python<'_>,
 ) -> PyResult<Bound<'_, PyAny>> {
-    let input = PyReadable::from_args(&path, input, py)?;
+    let (input, path) = PyReadable::from_args(path, input, py)?;
     let format = lookup_query_results_format(format, path.as_deref())?;
     let results = QueryResultsParser::from_format(format)
         .for_reader(input)
@@ -791,12 +891,7 @@ fn lookup_query_results_format(
             "The format parameter is required when a file path is not given",
         ));
     };
-    let Some(ext) = path.extension().and_then(OsStr::to_str) else {
-        return Err(PyValueError::new_err(format!(
-            "The file name {} has no extension to guess a file format from",
-            path.display()
-        )));
-    };
+    let ext = format_extension(path)?;
     QueryResultsFormat::from_extension(ext)
         .ok_or_else(|| PyValueError::new_err(format!("Not supported RDF format extension: {ext}")))
 }
@@ -1,17 +1,26 @@
 use crate::io::{
-    PyRdfFormatInput, PyReadable, PyReadableInput, PyWritable, PyWritableOutput, lookup_rdf_format,
-    map_parse_error,
+    PyIterableReader, PyRdfFormatInput, PyReadable, PyReadableInput, PyWritable, PyWritableOutput,
+    lookup_rdf_format, map_parse_error,
 };
 use crate::model::*;
 use crate::sparql::*;
 use oxigraph::io::{RdfParser, RdfSerializer};
-use oxigraph::model::GraphNameRef;
+use oxigraph::model::{
+    GraphName, GraphNameRef, NamedNode, NamedNodeRef, NamedOrBlankNode, NamedOrBlankNodeRef, Quad,
+    Term, Triple,
+};
 use oxigraph::sparql::QueryResults;
-use oxigraph::store::{self, LoaderError, SerializerError, StorageError, Store};
-use pyo3::exceptions::{PyRuntimeError, PySyntaxError, PyValueError};
+use oxigraph::store::{
+    self, BulkLoader, LoaderError, RenameGraphError, SerializerError, StorageError, Store,
+};
+use pyo3::exceptions::{PyRuntimeError, PySyntaxError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use pyo3::types::PyIterator;
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{PoisonError, RwLock};
 
 /// RDF store.
 ///
@@ -31,6 +40,13 @@ use std::path::PathBuf;
 /// :type path: str or os.PathLike[str] or None, optional
 /// :raises OSError: if the target directory contains invalid data or could not be accessed.
 ///
+/// The store is also a context manager that closes it on exit, see :py:func:`Store.close`:
+///
+/// >>> with Store() as store:
+/// ...     store.add(Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1')))
+/// ...     len(store)
+/// 1
+///
 /// The :py:class:`str` function provides a serialization of the store in NQuads:
 ///
 /// >>> store = Store()
@@ -39,7 +55,9 @@ use std::path::PathBuf;
 /// '<http://example.com> <http://example.com/p> "1" <http://example.com/g> .\n'
 #[pyclass(frozen, name = "Store", module = "pyoxigraph")]
 pub struct PyStore {
-    inner: Store,
+    /// `None` if the store has been closed
+    inner: RwLock<Option<Store>>,
+    read_only: bool,
 }
 
 #[pymethods]
@@ -49,14 +67,15 @@ impl PyStore {
     #[pyo3(signature = (path = None))]
     fn new(path: Option<PathBuf>, py: Python<'_>) -> PyResult<Self> {
         py.detach(|| {
-            Ok(Self {
-                inner: if let Some(path) = path {
+            Ok(Self::from_store(
+                if let Some(path) = path {
                     Store::open(path)
                 } else {
                     Store::new()
                 }
                 .map_err(map_storage_error)?,
-            })
+                false,
+            ))
         })
     }
 
@@ -64,9 +83,10 @@ impl PyStore {
     #[new]
     fn new(py: Python<'_>) -> PyResult<Self> {
         py.detach(|| {
-            Ok(Self {
-                inner: Store::new().map_err(map_storage_error)?,
-            })
+            Ok(Self::from_store(
+                Store::new().map_err(map_storage_error)?,
+                false,
+            ))
         })
     }
 
@@ -83,9 +103,10 @@ impl PyStore {
     #[staticmethod]
     fn read_only(path: &str, py: Python<'_>) -> PyResult<Self> {
         py.detach(|| {
-            Ok(Self {
-                inner: Store::open_read_only(path).map_err(map_storage_error)?,
-            })
+            Ok(Self::from_store(
+                Store::open_read_only(path).map_err(map_storage_error)?,
+                true,
+            ))
         })
     }
 
@@ -102,7 +123,7 @@ impl PyStore {
     /// [<Quad subject=<NamedNode value=http://example.com> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<NamedNode value=http://example.com/g>>]
     fn add(&self, quad: &PyQuad, py: Python<'_>) -> PyResult<()> {
         py.detach(|| {
-            self.inner.insert(quad).map_err(map_storage_error)?;
+            self.inner()?.insert(quad).map_err(map_storage_error)?;
             Ok(())
         })
     }
@@ -127,7 +148,7 @@ impl PyStore {
             .map(|q| Ok(q?.extract()?))
             .collect::<PyResult<Vec<PyQuad>>>()?;
         py.detach(|| {
-            self.inner.extend(quads).map_err(map_storage_error)?;
+            self.inner()?.extend(quads).map_err(map_storage_error)?;
             Ok(())
         })
     }
@@ -136,23 +157,104 @@ impl PyStore {
     ///
     /// It always writes new files to disk, the :py:func:`extend` method is also available for fast insertion of a small number of quads.
     ///
+    /// The quads are read from the iterable by batches while holding the GIL and are all given to the same bulk loading process.
+    ///
+    /// To avoid building Python term objects, quads might also be given as tuples of 3 (triple in the default graph) or 4 (quad) strings
+    /// using the `N-Triples <https://www.w3.org/TR/n-triples/>`_ term syntax, i.e. ``<http://example.com>`` for IRIs, ``_:b`` for blank nodes and ``"foo"@en`` for literals.
+    ///
     /// :param quads: the quads to add.
-    /// :type quads: collections.abc.Iterable[Quad]
+    /// :type quads: collections.abc.Iterable[Quad or Triple or tuple[str, str, str] or tuple[str, str, str, str]]
     /// :rtype: None
+    /// :raises ValueError: if a tuple contains an invalid term.
+    /// :raises TypeError: if an element of the iterable is not a quad, a triple or a tuple of strings.
     /// :raises OSError: if an error happens during the quad insertion.
     ///
     /// >>> store = Store()
     /// >>> store.bulk_extend([Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1'), NamedNode('http://example.com/g'))])
     /// >>> list(store)
     /// [<Quad subject=<NamedNode value=http://example.com> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<NamedNode value=http://example.com/g>>]
+    ///
+    /// >>> store = Store()
+    /// >>> store.bulk_extend([('<http://example.com>', '<http://example.com/p>', '"1"')])
+    /// >>> list(store)
+    /// [<Quad subject=<NamedNode value=http://example.com> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<DefaultGraph>>]
     #[cfg(not(target_family = "wasm"))]
-    fn bulk_extend(&self, quads: &Bound<'_, PyAny>) -> PyResult<()> {
-        let mut loader = self.inner.bulk_loader();
-        loader.load_ok_quads::<PyErr, PythonOrStorageError>(
-            quads.try_iter()?.map(|q| Ok(q?.extract::<PyQuad>()?)),
-        )?;
-        loader.commit().map_err(map_storage_error)?;
-        Ok(())
+    fn bulk_extend(&self, quads: &Bound<'_, PyAny>, py: Python<'_>) -> PyResult<()> {
+        let store = self.inner()?;
+        let mut loader = UngilBulkLoader(store.bulk_loader());
+        let quads = PyQuadsIter::new(quads.try_iter()?.unbind());
+        py.detach(|| {
+            loader.load_quads(quads)?;
+            loader.commit()
+        })
+    }
+
+    /// Adds quads or some RDF serialization chunks read from an iterable.
+    ///
+    /// If ``format`` is :py:const:`None`, the iterable must yield quads, triples (added to the default graph)
+    /// or tuples of strings like :py:func:`bulk_extend`.
+    /// Otherwise, it must yield :py:class:`str` or :py:class:`bytes` chunks of a serialization in the given format.
+    ///
+    /// The quads are read from the iterable by batches and inserted while the GIL is released.
+    /// If the store is empty, all the quads are given to the same bulk loading process and the insertion is not atomic.
+    /// Otherwise, each batch is inserted in its own transaction.
+    ///
+    /// :param iterable: the quads or the serialization chunks to add.
+    /// :type iterable: collections.abc.Iterable[Quad or Triple or tuple[str, str, str] or tuple[str, str, str, str]] or collections.abc.Iterable[str] or collections.abc.Iterable[bytes]
+    /// :param format: the format of the RDF serialization chunks or :py:const:`None` if the iterable yields quads.
+    /// :type format: RdfFormat or None, optional
+    /// :rtype: None
+    /// :raises ValueError: if the format is not supported or if a tuple contains an invalid term.
+    /// :raises TypeError: if an element of the iterable has not the expected type.
+    /// :raises SyntaxError: if the provided serialization is invalid.
+    /// :raises OSError: if an error happens during a quad insertion.
+    ///
+    /// >>> store = Store()
+    /// >>> store.load_from_iterable([b'<http://example.com> <http://example.com/p> ', b'"1" .'], RdfFormat.N_TRIPLES)
+    /// >>> list(store)
+    /// [<Quad subject=<NamedNode value=http://example.com> predicate=<NamedNode value=http://example.com/p> object=<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<DefaultGraph>>]
+    #[pyo3(signature = (iterable, format = None))]
+    fn load_from_iterable(
+        &self,
+        iterable: &Bound<'_, PyAny>,
+        format: Option<PyRdfFormatInput>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let store = self.inner()?;
+        let use_bulk_loader = py.detach(|| store.is_empty().map_err(map_storage_error))?;
+        let mut iter = iterable.try_iter()?;
+        if format.is_some() {
+            let parser = RdfParser::from_format(lookup_rdf_format(format, None)?);
+            let input = PyIterableReader::new(iter.unbind());
+            return py.detach(|| {
+                if use_bulk_loader {
+                    let mut loader = store.bulk_loader();
+                    loader
+                        .load_from_reader(parser, input)
+                        .map_err(|e| map_loader_error(e, None))?;
+                    loader.commit().map_err(map_storage_error)
+                } else {
+                    store
+                        .load_from_reader(parser, input)
+                        .map_err(|e| map_loader_error(e, None))
+                }
+            });
+        }
+        if use_bulk_loader {
+            let mut loader = UngilBulkLoader(store.bulk_loader());
+            let quads = PyQuadsIter::new(iter.unbind());
+            return py.detach(|| {
+                loader.load_quads(quads)?;
+                loader.commit()
+            });
+        }
+        loop {
+            let batch = next_quads_batch(&mut iter)?;
+            if batch.is_empty() {
+                return Ok(());
+            }
+            py.detach(|| store.extend(batch).map_err(map_storage_error))?;
+        }
     }
 
     /// Removes a quad from the store.
@@ -170,11 +272,65 @@ impl PyStore {
     /// []
     fn remove(&self, quad: &PyQuad, py: Python<'_>) -> PyResult<()> {
         py.detach(|| {
-            self.inner.remove(quad).map_err(map_storage_error)?;
+            self.inner()?.remove(quad).map_err(map_storage_error)?;
             Ok(())
         })
     }
 
+    /// Replaces all the objects of a subject and predicate pair in a graph and returns the removed objects.
+    ///
+    /// The previous objects are read and replaced atomically: concurrent calls never leave a mix of their objects.
+    /// An empty ``objects`` list removes all the objects of the pair.
+    ///
+    /// :param subject: the subject of the pair.
+    /// :type subject: NamedNode or BlankNode
+    /// :param predicate: the predicate of the pair.
+    /// :type predicate: NamedNode
+    /// :param objects: the new objects.
+    /// :type objects: list[NamedNode or BlankNode or Literal or Triple]
+    /// :param graph_name: the graph to modify. By default, the default graph.
+    /// :type graph_name: NamedNode or BlankNode or DefaultGraph or None, optional
+    /// :return: the previous objects that are not in ``objects``.
+    /// :rtype: list[NamedNode or BlankNode or Literal or Triple]
+    /// :raises OSError: if an error happens during the update.
+    ///
+    /// >>> store = Store()
+    /// >>> store.add(Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1')))
+    /// >>> store.set_object(NamedNode('http://example.com'), NamedNode('http://example.com/p'), [Literal('2')])
+    /// [<Literal value=1 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>>]
+    /// >>> list(store)
+    /// [<Quad subject=<NamedNode value=http://example.com> predicate=<NamedNode value=http://example.com/p> object=<Literal value=2 datatype=<NamedNode value=http://www.w3.org/2001/XMLSchema#string>> graph_name=<DefaultGraph>>]
+    #[expect(clippy::needless_pass_by_value)]
+    #[pyo3(signature = (subject, predicate, objects, graph_name = None))]
+    fn set_object(
+        &self,
+        subject: PyNamedOrBlankNodeRef<'_>,
+        predicate: PyNamedNodeRef<'_>,
+        objects: Vec<PyTerm>,
+        graph_name: Option<PyGraphNameRef<'_>>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<PyTerm>> {
+        let subject = NamedOrBlankNodeRef::from(&subject);
+        let predicate = NamedNodeRef::from(&predicate);
+        let graph_name = graph_name
+            .as_ref()
+            .map_or(GraphNameRef::DefaultGraph, Into::into);
+        py.detach(|| {
+            Ok(self
+                .inner()?
+                .set_object(
+                    subject,
+                    predicate,
+                    objects.into_iter().map(Term::from),
+                    graph_name,
+                )
+                .map_err(map_storage_error)?
+                .into_iter()
+                .map(PyTerm::from)
+                .collect())
+        })
+    }
+
     /// Looks for the quads matching a given pattern.
     ///
     /// :param subject: the quad subject or :py:const:`None` to match everything.
@@ -201,15 +357,15 @@ impl PyStore {
         predicate: Option<PyNamedNodeRef<'_>>,
         object: Option<PyTermRef<'_>>,
         graph_name: Option<PyGraphNameRef<'_>>,
-    ) -> QuadIter {
-        QuadIter {
-            inner: self.inner.quads_for_pattern(
+    ) -> PyResult<QuadIter> {
+        Ok(QuadIter {
+            inner: self.inner()?.quads_for_pattern(
                 subject.as_ref().map(Into::into),
                 predicate.as_ref().map(Into::into),
                 object.as_ref().map(Into::into),
                 graph_name.as_ref().map(Into::into),
             ),
-        }
+        })
     }
 
     /// Executes a `SPARQL 1.1 query <https://www.w3.org/TR/sparql11-query/>`_.
@@ -290,7 +446,7 @@ impl PyStore {
             default_graph,
             named_graphs,
         )?
-        .on_store(&self.inner);
+        .on_store(&self.inner()?);
         if let Some(substitutions) = substitutions {
             for (variable, term) in substitutions {
                 evaluator = evaluator.substitute_variable(variable, term);
@@ -362,7 +518,7 @@ impl PyStore {
             )?
             .parse_update(update)
             .map_err(|e| PySyntaxError::new_err(e.to_string()))?
-            .on_store(&self.inner)
+            .on_store(&self.inner()?)
             .execute()
             .map_err(map_update_evaluation_error)
         })
@@ -385,11 +541,11 @@ impl PyStore {
     /// * `N3 <https://w3c.github.io/N3/spec/>`_ (:py:attr:`RdfFormat.N3`)
     /// * `RDF/XML <https://www.w3.org/TR/rdf-syntax-grammar/>`_ (:py:attr:`RdfFormat.RDF_XML`)
     ///
-    /// :param input: The :py:class:`str`, :py:class:`bytes` or I/O object to read from. For example, it could be the file content as a string or a file reader opened in binary mode with ``open('my_file.ttl', 'rb')``.
-    /// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or None, optional
+    /// :param input: The :py:class:`str`, :py:class:`bytes`, I/O object or :py:class:`os.PathLike` file path to read from. For example, it could be the file content as a string, a file reader opened in binary or text mode with ``open('my_file.ttl', 'rb')`` or a ``pathlib.Path('my_file.ttl')``.
+    /// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or os.PathLike[str] or None, optional
     /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: RdfFormat or None, optional
-    /// :param path: The file path to read from. Replace the ``input`` parameter.
+    /// :param path: The file path to read from. Replace the ``input`` parameter. Gzip compressed files with a ``.gz`` extension like ``my_file.ttl.gz`` are decompressed.
     /// :type path: str or os.PathLike[str] or None, optional
     /// :param base_iri: the base IRI used to resolve the relative IRIs in the file or :py:const:`None` if relative IRI resolution should not be done.
     /// :type base_iri: str or None, optional
@@ -419,7 +575,7 @@ impl PyStore {
         py: Python<'_>,
     ) -> PyResult<()> {
         let to_graph_name = to_graph.as_ref().map(GraphNameRef::from);
-        let input = PyReadable::from_args(&path, input, py)?;
+        let (input, path) = PyReadable::from_args(path, input, py)?;
         let format = lookup_rdf_format(format, path.as_deref())?;
         py.detach(|| {
             let mut parser = RdfParser::from_format(format);
@@ -434,7 +590,7 @@ impl PyStore {
             if lenient {
                 parser = parser.lenient();
             }
-            self.inner
+            self.inner()?
                 .load_from_reader(parser, input)
                 .map_err(|e| map_loader_error(e, path))
         })
@@ -456,11 +612,11 @@ impl PyStore {
     /// * `N3 <https://w3c.github.io/N3/spec/>`_ (:py:attr:`RdfFormat.N3`)
     /// * `RDF/XML <https://www.w3.org/TR/rdf-syntax-grammar/>`_ (:py:attr:`RdfFormat.RDF_XML`)
     ///
-    /// :param input: The :py:class:`str`, :py:class:`bytes` or I/O object to read from. For example, it could be the file content as a string or a file reader opened in binary mode with ``open('my_file.ttl', 'rb')``.
-    /// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or None, optional
+    /// :param input: The :py:class:`str`, :py:class:`bytes`, I/O object or :py:class:`os.PathLike` file path to read from. For example, it could be the file content as a string, a file reader opened in binary or text mode with ``open('my_file.ttl', 'rb')`` or a ``pathlib.Path('my_file.ttl')``.
+    /// :type input: bytes or str or typing.IO[bytes] or typing.IO[str] or os.PathLike[str] or None, optional
     /// :param format: the format of the RDF serialization. If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: RdfFormat or None, optional
-    /// :param path: The file path to read from. Replace the ``input`` parameter.
+    /// :param path: The file path to read from. Replace the ``input`` parameter. Gzip compressed files with a ``.gz`` extension like ``my_file.ttl.gz`` are decompressed.
     /// :type path: str or os.PathLike[str] or None, optional
     /// :param base_iri: the base IRI used to resolve the relative IRIs in the file or :py:const:`None` if relative IRI resolution should not be done.
     /// :type base_iri: str or None, optional
@@ -490,6 +646,7 @@ impl PyStore {
         py: Python<'_>,
     ) -> PyResult<()> {
         let to_graph_name = to_graph.as_ref().map(GraphNameRef::from);
+        let (input, path) = PyReadableInput::split_path(input, path)?;
         let format = lookup_rdf_format(format, path.as_deref())?;
         let mut parser = RdfParser::from_format(format);
         if let Some(base_iri) = base_iri {
@@ -503,19 +660,25 @@ impl PyStore {
         if lenient {
             parser = parser.lenient();
         }
+        let store = self.inner()?;
         match (path, input) {
             #[cfg(not(target_family = "wasm"))]
-            (Some(path), None) => py.detach(|| {
-                let mut loader = self.inner.bulk_loader();
-                loader
-                    .parallel_load_from_file(parser, &path)
-                    .map_err(|e| map_loader_error(e, Some(path)))?;
-                loader.commit().map_err(map_storage_error)?;
-                Ok(())
-            }),
+            (Some(path), None)
+                if crate::io::path_content_encoding(&path)
+                    == oxigraph::io::ContentEncoding::Identity =>
+            {
+                py.detach(|| {
+                    let mut loader = store.bulk_loader();
+                    loader
+                        .parallel_load_from_file(parser, &path)
+                        .map_err(|e| map_loader_error(e, Some(path)))?;
+                    loader.commit().map_err(map_storage_error)?;
+                    Ok(())
+                })
+            }
             #[cfg(not(target_family = "wasm"))]
             (None, Some(PyReadableInput::Bytes(input))) => py.detach(|| {
-                let mut loader = self.inner.bulk_loader();
+                let mut loader = store.bulk_loader();
                 loader
                     .parallel_load_from_slice(parser, &input)
                     .map_err(|e| map_loader_error(e, None))?;
@@ -524,7 +687,7 @@ impl PyStore {
             }),
             #[cfg(not(target_family = "wasm"))]
             (None, Some(PyReadableInput::String(input))) => py.detach(|| {
-                let mut loader = self.inner.bulk_loader();
+                let mut loader = store.bulk_loader();
                 loader
                     .parallel_load_from_slice(parser, &input)
                     .map_err(|e| map_loader_error(e, None))?;
@@ -532,9 +695,9 @@ impl PyStore {
                 Ok(())
             }),
             (path, input) => {
-                let input = PyReadable::from_args(&path, input, py)?;
+                let (input, path) = PyReadable::from_args(path, input, py)?;
                 py.detach(|| {
-                    let mut loader = self.inner.bulk_loader();
+                    let mut loader = store.bulk_loader();
                     loader
                         .load_from_reader(parser, input)
                         .map_err(|e| map_loader_error(e, path))?;
@@ -557,8 +720,8 @@ impl PyStore {
     /// * `N3 <https://w3c.github.io/N3/spec/>`_ (:py:attr:`RdfFormat.N3`)
     /// * `RDF/XML <https://www.w3.org/TR/rdf-syntax-grammar/>`_ (:py:attr:`RdfFormat.RDF_XML`)
     ///
-    /// :param output: The binary I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
-    /// :type output: typing.IO[bytes] or str or os.PathLike[str] or None, optional
+    /// :param output: The I/O object or file path to write to. For example, it could be a file path as a string or a file writer opened in binary or text mode with ``open('my_file.ttl', 'wb')``. If :py:const:`None`, a :py:class:`bytes` buffer is returned with the serialized content.
+    /// :type output: typing.IO[bytes] or typing.IO[str] or str or os.PathLike[str] or None, optional
     /// :param format: the format of the RDF serialization.  If :py:const:`None`, the format is guessed from the file name extension.
     /// :type format: RdfFormat or None, optional
     /// :param from_graph: the store graph from which dump the triples. Required if the serialization format does not support named graphs. If it does supports named graphs the full dataset is written.
@@ -619,10 +782,10 @@ impl PyStore {
                         })?;
                     }
                     if let Some(from_graph_name) = from_graph_name {
-                        self.inner
+                        self.inner()?
                             .dump_graph_to_writer(from_graph_name, serializer, output)
                     } else {
-                        self.inner.dump_to_writer(serializer, output)
+                        self.inner()?.dump_to_writer(serializer, output)
                     }
                     .map_err(map_serializer_error)
                 })
@@ -642,10 +805,10 @@ impl PyStore {
     /// >>> store.add(Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1'), NamedNode('http://example.com/g')))
     /// >>> list(store.named_graphs())
     /// [<NamedNode value=http://example.com/g>]
-    fn named_graphs(&self) -> GraphNameIter {
-        GraphNameIter {
-            inner: self.inner.named_graphs(),
-        }
+    fn named_graphs(&self) -> PyResult<GraphNameIter> {
+        Ok(GraphNameIter {
+            inner: self.inner()?.named_graphs(),
+        })
     }
 
     /// Returns if the store contains the given named graph.
@@ -667,10 +830,11 @@ impl PyStore {
     ) -> PyResult<bool> {
         let graph_name = GraphNameRef::from(&graph_name);
         py.detach(|| {
+            let store = self.inner()?;
             match graph_name {
                 GraphNameRef::DefaultGraph => Ok(true),
-                GraphNameRef::NamedNode(graph_name) => self.inner.contains_named_graph(graph_name),
-                GraphNameRef::BlankNode(graph_name) => self.inner.contains_named_graph(graph_name),
+                GraphNameRef::NamedNode(graph_name) => store.contains_named_graph(graph_name),
+                GraphNameRef::BlankNode(graph_name) => store.contains_named_graph(graph_name),
             }
             .map_err(map_storage_error)
         })
@@ -691,10 +855,11 @@ impl PyStore {
     fn add_graph(&self, graph_name: PyGraphNameRef<'_>, py: Python<'_>) -> PyResult<()> {
         let graph_name = GraphNameRef::from(&graph_name);
         py.detach(|| {
+            let store = self.inner()?;
             match graph_name {
                 GraphNameRef::DefaultGraph => Ok(()),
-                GraphNameRef::NamedNode(graph_name) => self.inner.insert_named_graph(graph_name),
-                GraphNameRef::BlankNode(graph_name) => self.inner.insert_named_graph(graph_name),
+                GraphNameRef::NamedNode(graph_name) => store.insert_named_graph(graph_name),
+                GraphNameRef::BlankNode(graph_name) => store.insert_named_graph(graph_name),
             }
             .map_err(map_storage_error)
         })
@@ -718,7 +883,7 @@ impl PyStore {
     fn clear_graph(&self, graph_name: PyGraphNameRef<'_>, py: Python<'_>) -> PyResult<()> {
         let graph_name = GraphNameRef::from(&graph_name);
         py.detach(|| {
-            self.inner
+            self.inner()?
                 .clear_graph(graph_name)
                 .map_err(map_storage_error)
         })
@@ -742,15 +907,55 @@ impl PyStore {
     fn remove_graph(&self, graph_name: PyGraphNameRef<'_>, py: Python<'_>) -> PyResult<()> {
         let graph_name = GraphNameRef::from(&graph_name);
         py.detach(|| {
+            let store = self.inner()?;
             match graph_name {
-                GraphNameRef::DefaultGraph => self.inner.clear_graph(GraphNameRef::DefaultGraph),
-                GraphNameRef::NamedNode(graph_name) => self.inner.remove_named_graph(graph_name),
-                GraphNameRef::BlankNode(graph_name) => self.inner.remove_named_graph(graph_name),
+                GraphNameRef::DefaultGraph => store.clear_graph(GraphNameRef::DefaultGraph),
+                GraphNameRef::NamedNode(graph_name) => store.remove_named_graph(graph_name),
+                GraphNameRef::BlankNode(graph_name) => store.remove_named_graph(graph_name),
             }
             .map_err(map_storage_error)
         })
     }
 
+    /// Renames a graph of the store.
+    ///
+    /// The operation is atomic. The default graph exists if it is not empty.
+    ///
+    /// :param from_graph: the name of the graph to rename.
+    /// :type from_graph: NamedNode or BlankNode or DefaultGraph
+    /// :param to_graph: the new name of the graph.
+    /// :type to_graph: NamedNode or BlankNode or DefaultGraph
+    /// :param overwrite: if the graph ``to_graph`` content should be replaced if it already exists.
+    /// :type overwrite: bool, optional
+    /// :return: if the graph ``from_graph`` exists and has been renamed.
+    /// :rtype: bool
+    /// :raises ValueError: if ``to_graph`` already exists and ``overwrite`` is not set.
+    /// :raises OSError: if an error happens during the operation.
+    ///
+    /// >>> store = Store()
+    /// >>> store.add(Quad(NamedNode('http://example.com'), NamedNode('http://example.com/p'), Literal('1'), NamedNode('http://example.com/g')))
+    /// >>> store.rename_graph(NamedNode('http://example.com/g'), NamedNode('http://example.com/g2'))
+    /// True
+    /// >>> list(store.named_graphs())
+    /// [<NamedNode value=http://example.com/g2>]
+    #[pyo3(signature = (from_graph, to_graph, *, overwrite = false))]
+    #[expect(clippy::needless_pass_by_value)]
+    fn rename_graph(
+        &self,
+        from_graph: PyGraphNameRef<'_>,
+        to_graph: PyGraphNameRef<'_>,
+        overwrite: bool,
+        py: Python<'_>,
+    ) -> PyResult<bool> {
+        let from_graph = GraphNameRef::from(&from_graph);
+        let to_graph = GraphNameRef::from(&to_graph);
+        py.detach(|| {
+            self.inner()?
+                .rename_graph(from_graph, to_graph, overwrite)
+                .map_err(map_rename_graph_error)
+        })
+    }
+
     /// Clears the store by removing all its contents.
     ///
     /// :rtype: None
@@ -764,7 +969,10 @@ impl PyStore {
     /// >>> list(store.named_graphs())
     /// []
     fn clear(&self, py: Python<'_>) -> PyResult<()> {
-        py.detach(|| self.inner.clear().map_err(map_storage_error))
+        py.detach(|| {
+            self.inner()?.clear().map_err(map_storage_error)?;
+            Ok(())
+        })
     }
 
     /// Flushes all buffers and ensures that all writes are saved on disk.
@@ -775,7 +983,7 @@ impl PyStore {
     /// :raises OSError: if an error happens during the flush.
     #[cfg(not(target_family = "wasm"))]
     fn flush(&self, py: Python<'_>) -> PyResult<()> {
-        py.detach(|| self.inner.flush().map_err(map_storage_error))
+        py.detach(|| self.inner()?.flush().map_err(map_storage_error))
     }
 
     /// Optimizes the database for future workload.
@@ -786,7 +994,7 @@ impl PyStore {
     /// :raises OSError: if an error happens during the optimization.
     #[cfg(not(target_family = "wasm"))]
     fn optimize(&self, py: Python<'_>) -> PyResult<()> {
-        py.detach(|| self.inner.optimize().map_err(map_storage_error))
+        py.detach(|| self.inner()?.optimize().map_err(map_storage_error))
     }
 
     /// Creates database backup into the `target_directory`.
@@ -813,33 +1021,103 @@ impl PyStore {
     #[cfg(not(target_family = "wasm"))]
     fn backup(&self, target_directory: PathBuf, py: Python<'_>) -> PyResult<()> {
         py.detach(|| {
-            self.inner
+            self.inner()?
                 .backup(target_directory)
                 .map_err(map_storage_error)
         })
     }
 
-    fn __str__(&self, py: Python<'_>) -> String {
-        py.detach(|| self.inner.to_string())
+    /// Closes the store.
+    ///
+    /// Pending writes are flushed to disk and the database lock is released,
+    /// allowing another :py:class:`Store` to open the same directory.
+    /// Calling this method again is a no-op.
+    ///
+    /// Any later use of the store raises a :py:class:`ValueError`.
+    /// Iterators and query results created before closing stay usable and keep the database open until they are dropped.
+    ///
+    /// :rtype: None
+    /// :raises OSError: if an error happens during the flush.
+    ///
+    /// >>> store = Store()
+    /// >>> store.close()
+    /// >>> len(store)
+    /// Traceback (most recent call last):
+    /// ...
+    /// ValueError: The store is closed
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        py.detach(|| {
+            let Some(store) = self
+                .inner
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take()
+            else {
+                return Ok(());
+            };
+            #[cfg(not(target_family = "wasm"))]
+            if !self.read_only {
+                store.flush().map_err(map_storage_error)?;
+            }
+            drop(store);
+            Ok(())
+        })
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.inner()?;
+        Ok(slf)
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: Option<Bound<'_, PyAny>>,
+        _exc_value: Option<Bound<'_, PyAny>>,
+        _traceback: Option<Bound<'_, PyAny>>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        self.close(py)
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        py.detach(|| Ok(self.inner()?.to_string()))
     }
 
     fn __bool__(&self) -> PyResult<bool> {
-        Ok(!self.inner.is_empty().map_err(map_storage_error)?)
+        Ok(!self.inner()?.is_empty().map_err(map_storage_error)?)
     }
 
     fn __len__(&self) -> PyResult<usize> {
-        self.inner.len().map_err(map_storage_error)
+        self.inner()?.len().map_err(map_storage_error)
     }
 
     fn __contains__(&self, quad: &PyQuad) -> PyResult<bool> {
-        self.inner.contains(quad).map_err(map_storage_error)
+        self.inner()?.contains(quad).map_err(map_storage_error)
+    }
+
+    fn __iter__(&self) -> PyResult<QuadIter> {
+        Ok(QuadIter {
+            inner: self.inner()?.iter(),
+        })
     }
+}
 
-    fn __iter__(&self) -> QuadIter {
-        QuadIter {
-            inner: self.inner.iter(),
+#[expect(clippy::multiple_inherent_impl)]
+impl PyStore {
+    fn from_store(store: Store, read_only: bool) -> Self {
+        Self {
+            inner: RwLock::new(Some(store)),
+            read_only,
         }
     }
+
+    fn inner(&self) -> PyResult<Store> {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or_else(|| PyValueError::new_err("The store is closed"))
+    }
 }
 
 #[pyclass(unsendable, module = "pyoxigraph")]
@@ -880,50 +1158,172 @@ impl GraphNameIter {
     }
 }
 
-pub fn map_storage_error(error: StorageError) -> PyErr {
-    match error {
-        StorageError::Io(error) => error.into(),
-        _ => PyRuntimeError::new_err(error.to_string()),
+struct UngilBulkLoader<'a>(BulkLoader<'a>);
+
+#[expect(unsafe_code)]
+// SAFETY: To derive Ungil
+unsafe impl Send for UngilBulkLoader<'_> {}
+
+impl UngilBulkLoader<'_> {
+    fn load_quads(&mut self, quads: PyQuadsIter) -> PyResult<()> {
+        self.0
+            .load_ok_quads::<PyErr, LoadQuadsError>(quads)
+            .map_err(|e| match e {
+                LoadQuadsError::Python(e) => e,
+                LoadQuadsError::Storage(e) => map_storage_error(e),
+            })
+    }
+
+    fn commit(self) -> PyResult<()> {
+        self.0.commit().map_err(map_storage_error)
     }
 }
 
-pub fn map_loader_error(error: LoaderError, file_path: Option<PathBuf>) -> PyErr {
-    match error {
-        LoaderError::Storage(error) => map_storage_error(error),
-        LoaderError::Parsing(error) => map_parse_error(error, file_path),
-        LoaderError::InvalidBaseIri { .. } => PyValueError::new_err(error.to_string()),
+/// Number of quads read from Python iterables before inserting them while the GIL is released
+const INSERTION_BATCH_SIZE: usize = 10_000;
+
+fn next_quads_batch(quads: &mut Bound<'_, PyIterator>) -> PyResult<Vec<Quad>> {
+    quads
+        .take(INSERTION_BATCH_SIZE)
+        .map(|quad| extract_quad(&quad?))
+        .collect()
+}
+
+/// Iterator on the quads of a Python iterable that takes the GIL to read each batch of quads
+struct PyQuadsIter {
+    iter: Py<PyIterator>,
+    batch: std::vec::IntoIter<Quad>,
+}
+
+impl PyQuadsIter {
+    fn new(iter: Py<PyIterator>) -> Self {
+        Self {
+            iter,
+            batch: Vec::new().into_iter(),
+        }
     }
 }
 
-pub fn map_serializer_error(error: SerializerError) -> PyErr {
-    match error {
-        SerializerError::Storage(error) => map_storage_error(error),
-        SerializerError::Io(error) => error.into(),
-        SerializerError::DatasetFormatExpected(_) => PyValueError::new_err(error.to_string()),
+impl Iterator for PyQuadsIter {
+    type Item = PyResult<Quad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(quad) = self.batch.next() {
+            return Some(Ok(quad));
+        }
+        match Python::attach(|py| next_quads_batch(&mut self.iter.bind(py).clone())) {
+            Ok(batch) => {
+                self.batch = batch.into_iter();
+                Some(Ok(self.batch.next()?))
+            }
+            Err(e) => Some(Err(e)),
+        }
     }
 }
 
-enum PythonOrStorageError {
+enum LoadQuadsError {
     Python(PyErr),
     Storage(StorageError),
 }
 
-impl From<PyErr> for PythonOrStorageError {
+impl From<PyErr> for LoadQuadsError {
     fn from(error: PyErr) -> Self {
         Self::Python(error)
     }
 }
 
-impl From<StorageError> for PythonOrStorageError {
+impl From<StorageError> for LoadQuadsError {
     fn from(error: StorageError) -> Self {
         Self::Storage(error)
     }
 }
-impl From<PythonOrStorageError> for PyErr {
-    fn from(error: PythonOrStorageError) -> Self {
-        match error {
-            PythonOrStorageError::Python(error) => error,
-            PythonOrStorageError::Storage(error) => map_storage_error(error),
+
+/// Extracts a quad from a :py:class:`Quad`, a :py:class:`Triple` or a tuple of N-Triples terms
+fn extract_quad(quad: &Bound<'_, PyAny>) -> PyResult<Quad> {
+    if let Ok(quad) = quad.extract::<PyQuad>() {
+        return Ok(quad.into());
+    }
+    if let Ok(triple) = quad.extract::<PyTriple>() {
+        return Ok(Triple::from(triple).in_graph(GraphName::DefaultGraph));
+    }
+    let Ok(terms) = quad.extract::<Vec<PyBackedStr>>() else {
+        return Err(PyTypeError::new_err(format!(
+            "{} is not a Quad, a Triple or a tuple of strings",
+            quad.repr()?
+        )));
+    };
+    let (subject, predicate, object, graph_name) = match terms.as_slice() {
+        [subject, predicate, object] => (subject, predicate, object, None),
+        [subject, predicate, object, graph_name] => (subject, predicate, object, Some(graph_name)),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Quads must be tuples of 3 or 4 terms, found {}",
+                terms.len()
+            )));
+        }
+    };
+    Ok(Quad::new(
+        match parse_term(subject)? {
+            Term::NamedNode(node) => NamedOrBlankNode::from(node),
+            Term::BlankNode(node) => node.into(),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "{subject} is not a valid subject"
+                )));
+            }
+        },
+        NamedNode::from_str(predicate).map_err(|e| {
+            PyValueError::new_err(format!("{predicate} is not a valid predicate: {e}"))
+        })?,
+        parse_term(object)?,
+        match graph_name.map(|g| parse_term(g)).transpose()? {
+            None => GraphName::DefaultGraph,
+            Some(Term::NamedNode(node)) => node.into(),
+            Some(Term::BlankNode(node)) => node.into(),
+            Some(_) => {
+                return Err(PyValueError::new_err(format!(
+                    "{} is not a valid graph name",
+                    graph_name.map_or("", |g| &**g)
+                )));
+            }
+        },
+    ))
+}
+
+fn parse_term(term: &str) -> PyResult<Term> {
+    Term::from_str(term)
+        .map_err(|e| PyValueError::new_err(format!("{term} is not a valid term: {e}")))
+}
+
+pub fn map_storage_error(error: StorageError) -> PyErr {
+    match error {
+        StorageError::Io(error) => error.into(),
+        _ => PyRuntimeError::new_err(error.to_string()),
+    }
+}
+
+pub fn map_loader_error(error: LoaderError, file_path: Option<PathBuf>) -> PyErr {
+    match error {
+        LoaderError::Storage(error) => map_storage_error(error),
+        LoaderError::Parsing(error) => map_parse_error(error, file_path),
+        LoaderError::InvalidBaseIri { .. } | LoaderError::UnsupportedCheckpointFormat(_) => {
+            PyValueError::new_err(error.to_string())
         }
+        LoaderError::Checkpoint { error, .. } => error.into(),
+    }
+}
+
+fn map_rename_graph_error(error: RenameGraphError) -> PyErr {
+    match error {
+        RenameGraphError::Storage(error) => map_storage_error(error),
+        RenameGraphError::TargetGraphAlreadyExists(_) => PyValueError::new_err(error.to_string()),
+    }
+}
+
+pub fn map_serializer_error(error: SerializerError) -> PyErr {
+    match error {
+        SerializerError::Storage(error) => map_storage_error(error),
+        SerializerError::Io(error) => error.into(),
+        SerializerError::DatasetFormatExpected(_) => PyValueError::new_err(error.to_string()),
     }
 }
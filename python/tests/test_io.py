@@ -1,9 +1,13 @@
+import gzip
 import sys
 import unittest
+from itertools import islice
 from io import BytesIO, StringIO, UnsupportedOperation
+from pathlib import Path
 from tempfile import NamedTemporaryFile, TemporaryFile
 
 from pyoxigraph import (
+    Dataset,
     Literal,
     NamedNode,
     Quad,
@@ -43,6 +47,38 @@ class TestParse(unittest.TestCase):
         with self.assertRaises(IOError) as _:
             parse(path="/tmp/not-existing-oxigraph-file.ttl", format=RdfFormat.TURTLE)
 
+    def test_parse_path_object(self) -> None:
+        with NamedTemporaryFile(suffix=".ttl") as fp:
+            fp.write('<foo> <p> "éù" .'.encode())
+            fp.flush()
+            self.assertEqual(
+                list(parse(Path(fp.name), base_iri="http://example.com/")),
+                [EXAMPLE_TRIPLE],
+            )
+            self.assertEqual(
+                list(parse(path=Path(fp.name), base_iri="http://example.com/")),
+                [EXAMPLE_TRIPLE],
+            )
+
+    def test_parse_gzip_file(self) -> None:
+        with NamedTemporaryFile(suffix=".ttl.gz") as fp:
+            fp.write(gzip.compress('<foo> <p> "éù" .'.encode()))
+            fp.flush()
+            self.assertEqual(
+                list(parse(path=fp.name, base_iri="http://example.com/")),
+                [EXAMPLE_TRIPLE],
+            )
+
+    def test_parse_input_and_path(self) -> None:
+        with self.assertRaises(ValueError):
+            parse(b"", RdfFormat.TURTLE, path="foo.ttl")
+        with self.assertRaises(ValueError):
+            parse(Path("foo.ttl"), path="foo.ttl")
+
+    def test_parse_no_input(self) -> None:
+        with self.assertRaises(ValueError):
+            parse(format=RdfFormat.TURTLE)
+
     def test_parse_str(self) -> None:
         self.assertEqual(
             list(
@@ -170,6 +206,41 @@ class TestParse(unittest.TestCase):
             b'<foo> <p> "a"@abcdefghijklmnop .\n',
         )
 
+    def test_parse_context_manager(self) -> None:
+        with NamedTemporaryFile(suffix=".nq") as fp:
+            fp.write(b'<http://example.com/foo> <http://example.com/p> "1" <http://example.com/g> .')
+            fp.flush()
+            with parse(path=fp.name) as quads:
+                self.assertEqual(list(quads), [EXAMPLE_QUAD])
+            with self.assertRaises(ValueError):
+                next(quads)
+            with self.assertRaises(ValueError):
+                _ = quads.prefixes
+
+    def test_parse_close(self) -> None:
+        quads = parse('<http://example.com/foo> <http://example.com/p> "1" .', RdfFormat.N_TRIPLES)
+        quads.close()
+        quads.close()
+        with self.assertRaises(ValueError):
+            next(quads)
+        with self.assertRaises(ValueError), quads:
+            pass
+
+    def test_parse_itertools(self) -> None:
+        data = "".join(f'<http://example.com/s> <http://example.com/p> "{i}" .\n' for i in range(3000))
+        quads = parse(data, RdfFormat.N_TRIPLES)
+        self.assertEqual([q.object for q in islice(quads, 2)], [Literal("0"), Literal("1")])
+        self.assertEqual(sum(1 for _ in quads), 2998)
+
+    def test_parse_syntax_error_after_valid_quads(self) -> None:
+        quads = parse(
+            '<http://example.com/foo> <http://example.com/p> "1" <http://example.com/g> .\n<foo> "p" "1" .\n',
+            RdfFormat.N_QUADS,
+        )
+        self.assertEqual(next(quads), EXAMPLE_QUAD)
+        with self.assertRaises(SyntaxError):
+            next(quads)
+
 
 class TestSerialize(unittest.TestCase):
     def test_serialize_to_bytes(self) -> None:
@@ -194,6 +265,26 @@ class TestSerialize(unittest.TestCase):
                 '<http://example.com/foo> <http://example.com/p> "éù" .\n',
             )
 
+    def test_serialize_to_str_io(self) -> None:
+        output = StringIO()
+        serialize([EXAMPLE_TRIPLE.triple] * 1024, output, RdfFormat.N_TRIPLES)
+        self.assertEqual(
+            output.getvalue(),
+            '<http://example.com/foo> <http://example.com/p> "éù" .\n' * 1024,
+        )
+
+    def test_serialize_to_path_object(self) -> None:
+        with NamedTemporaryFile(suffix=".nt") as fp:
+            serialize([EXAMPLE_TRIPLE], Path(fp.name))
+            self.assertEqual(
+                fp.read().decode(),
+                '<http://example.com/foo> <http://example.com/p> "éù" .\n',
+            )
+
+    def test_serialize_to_compressed_file(self) -> None:
+        with NamedTemporaryFile(suffix=".nt.gz") as fp, self.assertRaises(ValueError):
+            serialize([EXAMPLE_TRIPLE], fp.name)
+
     def test_serialize_io_error(self) -> None:
         with self.assertRaises(UnsupportedOperation) as _, TemporaryFile("rb") as fp:
             serialize([EXAMPLE_TRIPLE], fp, RdfFormat.TURTLE)
@@ -207,6 +298,69 @@ class TestSerialize(unittest.TestCase):
         )
 
 
+class TestDatasetParseSerialize(unittest.TestCase):
+    def test_parse(self) -> None:
+        self.assertEqual(
+            Dataset.parse(
+                '<g> { <foo> <p> "1" }',
+                RdfFormat.TRIG,
+                base_iri="http://example.com/",
+            ),
+            Dataset([EXAMPLE_QUAD]),
+        )
+
+    def test_parse_graph_format(self) -> None:
+        self.assertEqual(
+            Dataset.parse(
+                '<http://example.com/foo> <http://example.com/p> "éù" .',
+                RdfFormat.N_TRIPLES,
+            ),
+            Dataset([EXAMPLE_TRIPLE]),
+        )
+
+    def test_parse_file(self) -> None:
+        with NamedTemporaryFile(suffix=".nq") as fp:
+            fp.write(b'<http://example.com/foo> <http://example.com/p> "1" <http://example.com/g> .')
+            fp.flush()
+            self.assertEqual(Dataset.parse(path=fp.name), Dataset([EXAMPLE_QUAD]))
+
+    def test_parse_syntax_error(self) -> None:
+        with self.assertRaises(SyntaxError) as ctx:
+            Dataset.parse('<foo> "p" "1"', RdfFormat.TURTLE, base_iri="http://example.com/")
+        self.assertEqual(ctx.exception.lineno, 1)
+
+    def test_parse_invalid_base_iri(self) -> None:
+        with self.assertRaises(SyntaxError):
+            Dataset.parse('<foo> <p> "1"', RdfFormat.TURTLE, base_iri="foo")
+
+    def test_parse_io_error(self) -> None:
+        with self.assertRaises(UnsupportedOperation) as _, TemporaryFile("wb") as fp:
+            Dataset.parse(fp, RdfFormat.N_TRIPLES)
+
+    def test_serialize(self) -> None:
+        self.assertEqual(
+            Dataset([EXAMPLE_QUAD]).serialize(format=RdfFormat.N_QUADS),
+            b'<http://example.com/foo> <http://example.com/p> "1" <http://example.com/g> .\n',
+        )
+
+    def test_serialize_to_file(self) -> None:
+        with NamedTemporaryFile(suffix=".nt") as fp:
+            Dataset([EXAMPLE_TRIPLE]).serialize(fp.name)
+            self.assertEqual(
+                fp.read().decode(),
+                '<http://example.com/foo> <http://example.com/p> "éù" .\n',
+            )
+
+    def test_serialize_named_graph_to_graph_format(self) -> None:
+        with self.assertRaises(ValueError):
+            Dataset([EXAMPLE_QUAD]).serialize(format=RdfFormat.TURTLE)
+
+    def test_round_trip(self) -> None:
+        dataset = Dataset([EXAMPLE_TRIPLE, EXAMPLE_QUAD])
+        for format in (RdfFormat.N_QUADS, RdfFormat.TRIG, RdfFormat.JSON_LD):
+            self.assertEqual(Dataset.parse(dataset.serialize(format=format), format), dataset)
+
+
 class TestParseQuerySolutions(unittest.TestCase):
     def test_parse_file(self) -> None:
         with NamedTemporaryFile(suffix=".tsv") as fp:
@@ -1,11 +1,14 @@
 import gc
+import importlib.util
 import sys
 import unittest
 from io import BytesIO, StringIO, UnsupportedOperation
 from pathlib import Path
 from tempfile import NamedTemporaryFile, TemporaryDirectory, TemporaryFile
+from time import perf_counter
 from typing import List, Union
 
+import pyoxigraph
 from pyoxigraph import (
     BlankNode,
     DefaultGraph,
@@ -29,6 +32,8 @@ baz = NamedNode("http://baz")
 triple = Triple(foo, foo, foo)
 graph = NamedNode("http://graph")
 is_wasm = sys.platform == "emscripten"
+has_pyarrow = importlib.util.find_spec("pyarrow") is not None
+has_pandas = has_pyarrow and importlib.util.find_spec("pandas") is not None
 
 
 class TestStore(unittest.TestCase):
@@ -52,6 +57,95 @@ class TestStore(unittest.TestCase):
             )
             self.assertEqual(len(store), 2)
 
+    def test_extend_tuples(self) -> None:
+        for fn in ("bulk_extend", "load_from_iterable"):
+            store = Store()
+            getattr(store, fn)(
+                iter(
+                    [
+                        ("<http://foo>", "<http://bar>", "<http://baz>"),
+                        ("<http://foo>", "<http://bar>", "<http://baz>", "<http://graph>"),
+                        ("_:b", "<http://bar>", '"baz"@en'),
+                        triple,
+                    ]
+                )
+            )
+            self.assertEqual(
+                set(store),
+                {
+                    Quad(foo, bar, baz),
+                    Quad(foo, bar, baz, graph),
+                    Quad(BlankNode("b"), bar, Literal("baz", language="en")),
+                    Quad(foo, foo, foo),
+                },
+            )
+
+    def test_extend_invalid_tuples(self) -> None:
+        for fn in ("bulk_extend", "load_from_iterable"):
+            store = Store()
+            with self.assertRaises(ValueError):
+                getattr(store, fn)([("<http://foo>", "<http://bar>")])
+            with self.assertRaises(ValueError):
+                getattr(store, fn)([('"foo"', "<http://bar>", "<http://baz>")])
+            with self.assertRaises(ValueError):
+                getattr(store, fn)([("<http://foo>", "_:b", "<http://baz>")])
+            with self.assertRaises(ValueError):
+                getattr(store, fn)([("<http://foo>", "<http://bar>", "baz")])
+            with self.assertRaises(TypeError):
+                getattr(store, fn)([1])
+
+    def test_load_from_iterable(self) -> None:
+        store = Store()
+        store.add(Quad(foo, bar, baz))
+        store.load_from_iterable(
+            Quad(foo, bar, NamedNode(f"http://baz/{i}"), graph) for i in range(25_000)
+        )
+        self.assertEqual(len(store), 25_001)
+
+    def test_load_from_iterable_chunks(self) -> None:
+        for empty in (True, False):
+            store = Store()
+            if not empty:
+                store.add(Quad(foo, foo, foo))
+            store.load_from_iterable(
+                [
+                    "<http://foo> <http://bar> ",
+                    b"<http://baz> <http://graph> .\n<http://foo>",
+                    " <http://bar> <http://baz> .",
+                ],
+                RdfFormat.N_QUADS,
+            )
+            self.assertIn(Quad(foo, bar, baz, graph), store)
+            self.assertIn(Quad(foo, bar, baz), store)
+            self.assertEqual(len(store), 2 if empty else 3)
+
+    def test_load_from_iterable_chunks_syntax_error(self) -> None:
+        with self.assertRaises(SyntaxError):
+            Store().load_from_iterable(["<http://foo> <http://bar> ."], RdfFormat.N_TRIPLES)
+
+    @unittest.skipIf(is_wasm, "Not supported with WASM")
+    @unittest.skipUnless(pyoxigraph._release_build, "Performance is only checked on release builds")
+    def test_bulk_extend_speedup(self) -> None:
+        count = 20_000
+
+        def add(store: Store) -> None:
+            for i in range(count):
+                store.add(Quad(NamedNode(f"http://foo/{i}"), bar, Literal(str(i))))
+
+        def bulk_extend(store: Store) -> None:
+            store.bulk_extend((f"<http://foo/{i}>", "<http://bar>", f'"{i}"') for i in range(count))
+
+        durations = []
+        with TemporaryDirectory() as dir:
+            for i, fn in enumerate((add, bulk_extend)):
+                store = Store(f"{dir}/{i}")
+                start = perf_counter()
+                fn(store)
+                durations.append(perf_counter() - start)
+                self.assertEqual(len(store), count)
+                del store
+        self.assertGreater(durations[0], 5 * durations[1])
+
     def test_remove(self) -> None:
         store = Store()
         store.add(Quad(foo, bar, baz))
@@ -140,6 +234,49 @@ class TestStore(unittest.TestCase):
         self.assertEqual(s, foo)
         self.assertEqual(o, baz)
 
+    @unittest.skipUnless(has_pyarrow, "pyarrow is not installed")
+    def test_select_query_to_arrow(self) -> None:
+        store = Store()
+        store.add(Quad(foo, bar, BlankNode("b")))
+        store.add(Quad(foo, baz, Literal("1", language="en")))
+        store.add(Quad(foo, foo, triple))
+        table = store.query("SELECT ?s ?o ?u WHERE { ?s ?p ?o } ORDER BY ?o").to_arrow(batch_size=2)
+        self.assertEqual(table.column_names, ["s", "o", "u"])
+        self.assertEqual(table.num_rows, 3)
+        self.assertEqual(
+            table.column("o").to_pylist(),
+            [
+                {"type": "blank_node", "value": "b", "datatype": None, "language": None, "direction": None},
+                {
+                    "type": "literal",
+                    "value": "1",
+                    "datatype": "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString",
+                    "language": "en",
+                    "direction": None,
+                },
+                {
+                    "type": "triple",
+                    "value": "<<( <http://foo> <http://foo> <http://foo> )>>",
+                    "datatype": None,
+                    "language": None,
+                    "direction": None,
+                },
+            ],
+        )
+        self.assertEqual(
+            table.column("s").to_pylist()[0],
+            {"type": "named_node", "value": "http://foo", "datatype": None, "language": None, "direction": None},
+        )
+        self.assertEqual(table.column("u").to_pylist(), [None, None, None])
+
+    @unittest.skipUnless(has_pandas, "pandas is not installed")
+    def test_select_query_to_pandas(self) -> None:
+        store = Store()
+        store.add(Quad(foo, bar, baz))
+        frame = store.query("SELECT ?s ?o WHERE { ?s ?p ?o }").to_pandas()
+        self.assertEqual(list(frame.columns), ["s", "o"])
+        self.assertEqual(frame["o"][0]["value"], "http://baz")
+
     def test_select_query_union_default_graph(self) -> None:
         store = Store()
         store.add(Quad(foo, bar, baz, graph))
@@ -456,6 +593,30 @@ class TestStore(unittest.TestCase):
         self.assertEqual(list(store.named_graphs()), [])
         self.assertEqual(list(store), [])
 
+    def test_rename_graph(self) -> None:
+        store = Store()
+        store.add(Quad(foo, bar, baz, graph))
+        store.add_graph(NamedNode("http://graph2"))
+        self.assertFalse(store.rename_graph(NamedNode("http://graph3"), graph))
+        with self.assertRaises(ValueError):
+            store.rename_graph(graph, NamedNode("http://graph2"))
+        self.assertTrue(store.rename_graph(graph, NamedNode("http://graph2"), overwrite=True))
+        self.assertEqual(list(store.named_graphs()), [NamedNode("http://graph2")])
+        self.assertEqual(list(store), [Quad(foo, bar, baz, NamedNode("http://graph2"))])
+
+    def test_set_object(self) -> None:
+        store = Store()
+        store.add(Quad(foo, bar, baz))
+        store.add(Quad(foo, bar, foo))
+        store.add(Quad(foo, bar, baz, graph))
+        self.assertEqual(store.set_object(foo, bar, [baz, bar]), [foo])
+        self.assertEqual(
+            set(store.quads_for_pattern(foo, bar, None, DefaultGraph())),
+            {Quad(foo, bar, baz), Quad(foo, bar, bar)},
+        )
+        self.assertEqual(store.set_object(foo, bar, [], graph), [baz])
+        self.assertEqual(len(store), 2)
+
     @unittest.skipIf(is_wasm, "Not supported with WASM")
     def test_read_only(self) -> None:
         quad = Quad(foo, bar, baz, graph)
@@ -467,6 +628,34 @@ class TestStore(unittest.TestCase):
             store = Store.read_only(dir)
             self.assertEqual(list(store), [quad])
 
+    def test_context_manager(self) -> None:
+        with Store() as store:
+            store.add(Quad(foo, bar, baz))
+            quads = iter(store)
+        self.assertEqual(list(quads), [Quad(foo, bar, baz)])
+        with self.assertRaises(ValueError):
+            len(store)
+        with self.assertRaises(ValueError):
+            store.add(Quad(foo, bar, baz))
+        with self.assertRaises(ValueError), store:
+            pass
+
+    def test_close(self) -> None:
+        store = Store()
+        store.close()
+        store.close()
+        with self.assertRaises(ValueError):
+            store.query("SELECT * WHERE { ?s ?p ?o }")
+
+    @unittest.skipIf(is_wasm, "Not supported with WASM")
+    def test_close_releases_lock(self) -> None:
+        quad = Quad(foo, bar, baz, graph)
+        with TemporaryDirectory() as dir:
+            with Store(dir) as store:
+                store.add(quad)
+            with Store(dir) as store:
+                self.assertEqual(list(store), [quad])
+
 
 if __name__ == "__main__":
     unittest.main()
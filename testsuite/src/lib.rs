@@ -6,6 +6,7 @@ pub mod files;
 pub mod manifest;
 pub mod parser_evaluator;
 pub mod report;
+pub mod shacl_evaluator;
 pub mod sparql_evaluator;
 mod vocab;
 
@@ -13,6 +14,7 @@ use crate::canonicalization_evaluator::register_canonicalization_tests;
 use crate::evaluator::TestEvaluator;
 use crate::manifest::TestManifest;
 use crate::parser_evaluator::register_parser_tests;
+use crate::shacl_evaluator::register_shacl_tests;
 use crate::sparql_evaluator::register_sparql_tests;
 use anyhow::Result;
 
@@ -22,6 +24,7 @@ pub fn check_testsuite(manifest_url: &str, ignored_tests: &[&str]) -> Result<()>
     register_parser_tests(&mut evaluator);
     register_canonicalization_tests(&mut evaluator);
     register_sparql_tests(&mut evaluator);
+    register_shacl_tests(&mut evaluator);
 
     let manifest = TestManifest::new([manifest_url]);
     let results = evaluator.evaluate(manifest)?;
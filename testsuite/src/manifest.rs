@@ -15,10 +15,12 @@ pub struct Test {
     pub query: Option<String>,
     pub update: Option<String>,
     pub data: Option<String>,
+    pub shapes: Option<String>,
     pub graph_data: Vec<(NamedNode, String)>,
     pub service_data: Vec<(String, String)>,
     pub result: Option<String>,
     pub result_graph_data: Vec<(NamedNode, String)>,
+    pub result_report: Option<Graph>,
     pub option: HashMap<NamedNode, Term>,
 }
 
@@ -42,6 +44,9 @@ impl fmt::Display for Test {
         if let Some(data) = &self.data {
             write!(f, " with data {data}")?;
         }
+        if let Some(shapes) = &self.shapes {
+            write!(f, " with shapes {shapes}")?;
+        }
         for (_, data) in &self.graph_data {
             write!(f, " and graph data {data}")?;
         }
@@ -133,7 +138,7 @@ impl TestManifest {
             } else {
                 None
             };
-            let (action, query, update, data, graph_data, service_data) = match self
+            let (action, query, update, data, shapes, graph_data, service_data) = match self
                 .graph
                 .object_for_subject_predicate(&test_node, mf::ACTION)
             {
@@ -142,6 +147,7 @@ impl TestManifest {
                     None,
                     None,
                     None,
+                    None,
                     vec![],
                     vec![],
                 ),
@@ -158,6 +164,14 @@ impl TestManifest {
                         .graph
                         .object_for_subject_predicate(n, qt::DATA)
                         .or_else(|| self.graph.object_for_subject_predicate(n, ut::DATA))
+                        .or_else(|| self.graph.object_for_subject_predicate(n, sht::DATA_GRAPH))
+                    {
+                        Some(TermRef::NamedNode(q)) => Some(q.as_str().to_owned()),
+                        _ => None,
+                    };
+                    let shapes = match self
+                        .graph
+                        .object_for_subject_predicate(n, sht::SHAPES_GRAPH)
                     {
                         Some(TermRef::NamedNode(q)) => Some(q.as_str().to_owned()),
                         _ => None,
@@ -223,7 +237,7 @@ impl TestManifest {
                             }
                         })
                         .collect();
-                    (None, query, update, data, graph_data, service_data)
+                    (None, query, update, data, shapes, graph_data, service_data)
                 }
                 Some(_) => bail!("invalid action"),
                 None => {
@@ -284,6 +298,19 @@ impl TestManifest {
                 Some(TermRef::Triple(_)) => bail!("invalid result"),
                 None => (None, Vec::new()),
             };
+            let result_report = match self
+                .graph
+                .object_for_subject_predicate(&test_node, mf::RESULT)
+            {
+                Some(TermRef::BlankNode(n))
+                    if self
+                        .graph
+                        .contains(TripleRef::new(n, rdf::TYPE, sh::VALIDATION_REPORT)) =>
+                {
+                    Some(self.extract_validation_report(n.into()))
+                }
+                _ => None,
+            };
             let mut option = match self
                 .graph
                 .object_for_subject_predicate(&test_node, jld::OPTION)
@@ -314,15 +341,33 @@ impl TestManifest {
                 query,
                 update,
                 data,
+                shapes,
                 graph_data,
                 service_data,
                 result,
                 result_graph_data,
+                result_report,
                 option,
             }));
         }
     }
 
+    /// Copies the validation report description, without the description of the shapes it refers to
+    fn extract_validation_report(&self, report: NamedOrBlankNodeRef<'_>) -> Graph {
+        let mut graph = Graph::new();
+        let mut to_copy = vec![report];
+        while let Some(node) = to_copy.pop() {
+            for triple in self.graph.triples_for_subject(node) {
+                if graph.insert(triple) && triple.predicate != sh::SOURCE_SHAPE {
+                    if let TermRef::BlankNode(object) = triple.object {
+                        to_copy.push(object.into());
+                    }
+                }
+            }
+        }
+        graph
+    }
+
     fn load_next_manifest(&mut self) -> Result<Option<()>> {
         let Some(url) = self.manifests_to_do.pop_front() else {
             return Ok(None);
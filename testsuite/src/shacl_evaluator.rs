@@ -0,0 +1,59 @@
+use crate::evaluator::TestEvaluator;
+use crate::files::{guess_rdf_format, load_graph};
+use crate::manifest::Test;
+use crate::report::format_diff;
+use crate::vocab::sh;
+use anyhow::{Context, Result, ensure};
+use oxigraph::model::dataset::CanonicalizationAlgorithm;
+use oxigraph::model::graph::CanonicalizationHashAlgorithm;
+use oxigraph::model::{Graph, TripleRef};
+use oxigraph::shacl::ShaclValidator;
+
+pub fn register_shacl_tests(evaluator: &mut TestEvaluator) {
+    evaluator.register(
+        "http://www.w3.org/ns/shacl-test#Validate",
+        evaluate_validation_test,
+    );
+}
+
+fn evaluate_validation_test(test: &Test) -> Result<()> {
+    let data = test.data.as_deref().context("No data graph found")?;
+    let data = load_graph(data, guess_rdf_format(data)?, false)
+        .with_context(|| format!("Parse error on file {data}"))?;
+    let shapes = test.shapes.as_deref().context("No shapes graph found")?;
+    let shapes = load_graph(shapes, guess_rdf_format(shapes)?, false)
+        .with_context(|| format!("Parse error on file {shapes}"))?;
+    let expected = test
+        .result_report
+        .as_ref()
+        .context("No validation report found")?;
+
+    let report = ShaclValidator::new(&shapes)
+        .context("Invalid shapes graph")?
+        .validate_graph(&data);
+    let expected = normalize_report(expected);
+    let actual = normalize_report(&report.to_graph());
+    ensure!(
+        expected == actual,
+        "The two validation reports are not equal. Diff:\n{}",
+        format_diff(&expected, &actual, "triples")
+    );
+    Ok(())
+}
+
+/// Canonical N-Triples serialization of the report, without the messages that are implementation-specific
+fn normalize_report(report: &Graph) -> String {
+    let mut report = report
+        .iter()
+        .filter(|t| t.predicate != sh::RESULT_MESSAGE)
+        .collect::<Graph>();
+    report.canonicalize(CanonicalizationAlgorithm::Rdfc10 {
+        hash_algorithm: CanonicalizationHashAlgorithm::Sha256,
+    });
+    let mut triples = report
+        .iter()
+        .map(|t: TripleRef<'_>| format!("{t} .\n"))
+        .collect::<Vec<_>>();
+    triples.sort();
+    triples.concat()
+}
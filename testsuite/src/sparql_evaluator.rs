@@ -371,6 +371,7 @@ impl DefaultServiceHandler for StaticServiceHandler {
 fn to_graph(result: QueryResults<'_>, with_order: bool) -> Result<Graph> {
     Ok(match result {
         QueryResults::Graph(graph) => graph.collect::<Result<Graph, _>>()?,
+        QueryResults::Dataset(_) => bail!("Quads are not supported in the test results"),
         QueryResults::Boolean(value) => {
             let mut graph = Graph::new();
             let result_set = BlankNode::default();
@@ -118,3 +118,23 @@ pub mod rdfc {
     pub const HASH_ALGORITHM: NamedNodeRef<'_> =
         NamedNodeRef::new_unchecked("https://w3c.github.io/rdf-canon/tests/vocab#hashAlgorithm");
 }
+
+pub mod sht {
+    use oxigraph::model::NamedNodeRef;
+
+    pub const DATA_GRAPH: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#dataGraph");
+    pub const SHAPES_GRAPH: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl-test#shapesGraph");
+}
+
+pub mod sh {
+    use oxigraph::model::NamedNodeRef;
+
+    pub const RESULT_MESSAGE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#resultMessage");
+    pub const SOURCE_SHAPE: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#sourceShape");
+    pub const VALIDATION_REPORT: NamedNodeRef<'_> =
+        NamedNodeRef::new_unchecked("http://www.w3.org/ns/shacl#ValidationReport");
+}
@@ -0,0 +1,13 @@
+#![cfg(test)]
+
+use anyhow::Result;
+use oxigraph_testsuite::check_testsuite;
+
+#[test]
+#[ignore = "requires the w3c/data-shapes repository in the testsuite/data-shapes directory"]
+fn shacl_w3c_core_testsuite() -> Result<()> {
+    check_testsuite(
+        "https://w3c.github.io/data-shapes/data-shapes-test-suite/tests/core/manifest.ttl",
+        &[],
+    )
+}